@@ -0,0 +1,110 @@
+//! TXO encoding benchmarks
+//!
+//! Compares `TXO::to_cbor()` with the fixed-buffer `TXO::encode_into()` and
+//! fails if the fixed-buffer path touches the heap. Run against the enclave
+//! feature set with:
+//!
+//! ```text
+//! cargo bench --bench txo_encode --no-default-features
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use aethernet::txo::{
+    AuditEntry, IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, Signature,
+    SignatureType, TXO,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// System allocator that counts allocations
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Allocations made while running `f`
+fn allocations_in(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn signed_txo() -> TXO {
+    let sender = Sender {
+        identity_type: IdentityType::Operator,
+        id: [1u8; 16],
+        biokey_present: true,
+        fido2_signed: true,
+        zk_proof: Some(vec![0xab; 128]),
+    };
+    let receiver = Receiver { identity_type: IdentityType::Node, id: [2u8; 16] };
+    let payload = Payload {
+        payload_type: PayloadType::Genome,
+        content_hash: [3u8; 32],
+        encrypted: true,
+        envelope: None,
+    };
+
+    let mut txo = TXO::new([4u8; 16], sender, receiver, OperationClass::Genomic, payload);
+    txo.timestamp = 1_700_000_000;
+    txo.dual_control_required = true;
+    for signer in 0..2u8 {
+        txo.add_signature(Signature {
+            sig_type: SignatureType::Fido2,
+            signer_id: [signer; 16],
+            signature: vec![0x5c; 64],
+            consent_ref: Some([signer; 32]),
+        });
+    }
+    txo.add_audit_entry(AuditEntry {
+        actor_id: [1u8; 16],
+        action: String::from("EXECUTE"),
+        timestamp: 1_700_000_001,
+    });
+    txo
+}
+
+fn bench_txo_encode(c: &mut Criterion) {
+    let txo = signed_txo();
+    let mut buf = [0u8; 2048];
+
+    let fixed = allocations_in(|| {
+        for _ in 0..1_000 {
+            let size = black_box(&txo).encoded_size();
+            let written = black_box(&txo).encode_into(black_box(&mut buf[..])).unwrap();
+            assert_eq!(size, written);
+        }
+    });
+    assert_eq!(fixed, 0, "fixed-buffer TXO encoding allocated");
+
+    let mut group = c.benchmark_group("txo_encode");
+    group.bench_function("to_cbor", |b| b.iter(|| black_box(&txo).to_cbor().unwrap()));
+    group.bench_function("encode_into", |b| {
+        b.iter(|| black_box(&txo).encode_into(black_box(&mut buf[..])).unwrap())
+    });
+    group.bench_function("encoded_size", |b| b.iter(|| black_box(&txo).encoded_size()));
+    group.finish();
+}
+
+criterion_group!(benches, bench_txo_encode);
+criterion_main!(benches);
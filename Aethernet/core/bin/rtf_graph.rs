@@ -0,0 +1,113 @@
+//! RTF Workflow Graph CLI
+//!
+//! Renders the zone rules, TXO lifecycle and (optionally) a policy bundle as
+//! DOT or Mermaid text for documentation and dashboards.
+//!
+//! ```text
+//! rtf-graph --format mermaid --zone Z2 --policy policy.rules --out rtf.mmd
+//! rtf-graph --format dot | dot -Tsvg > rtf.svg
+//! ```
+
+use aethernet::rtf::graph::{GraphFormat, WorkflowGraph};
+use aethernet::rtf::policy::PolicyBundle;
+use aethernet::Zone;
+use std::env;
+use std::fs;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let mut format = GraphFormat::Dot;
+    let mut zone = None;
+    let mut policy_path = None;
+    let mut policy_version = 1u64;
+    let mut out = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        let value = |i: usize| {
+            args.get(i + 1).cloned().unwrap_or_else(|| fail(&format!("{} requires a value", args[i])))
+        };
+        match args[i].as_str() {
+            "--format" => {
+                let name = value(i);
+                format = GraphFormat::parse(&name)
+                    .unwrap_or_else(|| fail(&format!("Unknown format: {} (expected dot or mermaid)", name)));
+                i += 1;
+            }
+            "--zone" => {
+                let name = value(i);
+                zone = Some(
+                    Zone::ALL
+                        .into_iter()
+                        .find(|z| z.name().eq_ignore_ascii_case(&name))
+                        .unwrap_or_else(|| fail(&format!("Unknown zone: {} (expected Z0-Z3)", name))),
+                );
+                i += 1;
+            }
+            "--policy" => {
+                policy_path = Some(value(i));
+                i += 1;
+            }
+            "--policy-version" => {
+                let v = value(i);
+                policy_version = v.parse().unwrap_or_else(|_| fail(&format!("Invalid version: {}", v)));
+                i += 1;
+            }
+            "--out" => {
+                out = Some(value(i));
+                i += 1;
+            }
+            "--help" | "-h" => {
+                print_usage();
+                process::exit(0);
+            }
+            other => fail(&format!("Unknown option: {}", other)),
+        }
+        i += 1;
+    }
+
+    let bundle = policy_path.map(|path| {
+        let source = fs::read_to_string(&path).unwrap_or_else(|e| fail(&format!("Cannot read {}: {}", path, e)));
+        PolicyBundle::parse(policy_version, &source)
+            .unwrap_or_else(|e| fail(&format!("Invalid policy bundle {}: {:?}", path, e)))
+    });
+
+    let mut graph = WorkflowGraph::new();
+    if let Some(zone) = zone {
+        graph = graph.with_zone(zone);
+    }
+    if let Some(bundle) = bundle.as_ref() {
+        graph = graph.with_policy(bundle);
+    }
+    let text = graph.render(format);
+
+    match out {
+        Some(path) => {
+            fs::write(&path, text).unwrap_or_else(|e| fail(&format!("Cannot write {}: {}", path, e)));
+            eprintln!("Wrote {}", path);
+        }
+        None => print!("{}", text),
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {}", message);
+    print_usage();
+    process::exit(1);
+}
+
+fn print_usage() {
+    println!("RTF Workflow Graph Exporter");
+    println!();
+    println!("USAGE:");
+    println!("    rtf-graph [OPTIONS]");
+    println!();
+    println!("OPTIONS:");
+    println!("    --format <dot|mermaid>    Output format (default: dot)");
+    println!("    --zone <Z0-Z3>            Highlight the current zone");
+    println!("    --policy <FILE>           Policy bundle source to render per zone");
+    println!("    --policy-version <N>      Version of the policy bundle (default: 1)");
+    println!("    --out <FILE>              Write to FILE instead of stdout");
+}
@@ -212,6 +212,22 @@ impl EphemeralBiokey {
         &self.key_material
     }
     
+    /// Public commitment to the key material
+    ///
+    /// SHA3-256 over a domain tag and the key material. Safe to publish
+    /// (e.g. in consent TXOs) without revealing the key itself.
+    pub fn public_hash(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"AETHERNET-BIOKEY-PUBLIC-v1");
+        hasher.update(self.key_material);
+        hasher.update(self.rotation_meta.generation.to_le_bytes());
+        
+        let result = hasher.finalize();
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&result);
+        hash
+    }
+    
     /// Get generation number for audit trail
     pub fn generation(&self) -> u32 {
         self.rotation_meta.generation
@@ -519,6 +535,27 @@ mod tests {
         assert_eq!(zkp.len(), 32);
     }
     
+    #[test]
+    fn test_public_hash_hides_key_material() {
+        let loci = [
+            SNPLocus {
+                chromosome: 1,
+                position: 12345,
+                ref_allele: b'A',
+                alt_allele: b'G',
+            },
+        ];
+        
+        let puf = create_test_puf();
+        let biokey = EphemeralBiokey::derive(&loci, &puf, b"test-salt", create_test_nonce(1000), 60);
+        let other = EphemeralBiokey::derive(&loci, &puf, b"other-salt", create_test_nonce(1000), 60);
+        
+        // Deterministic, distinct per key, and not a prefix of the key material
+        assert_eq!(biokey.public_hash(), biokey.public_hash());
+        assert_ne!(biokey.public_hash(), other.public_hash());
+        assert_ne!(&biokey.public_hash()[..], &biokey.key_material[..32]);
+    }
+    
     #[test]
     fn test_secure_compare() {
         let key1 = [1u8; 64];
@@ -18,7 +18,7 @@ use alloc::collections::BTreeSet;
 use sha3::{Digest, Sha3_256};
 
 /// ZKP verification result
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VerificationResult {
     /// Proof is valid
     Valid,
@@ -200,6 +200,30 @@ pub fn verify_zkp(
         return VerificationResult::ReplayDetected;
     }
     
+    let result = check_zkp_commitment(proof, commitment);
+    if result == VerificationResult::Valid {
+        // Mark proof as seen to prevent replay
+        if replay_cache.mark_seen(proof_id).is_err() {
+            // Cache full - cleanup and retry
+            replay_cache.cleanup(current_time);
+            let _ = replay_cache.mark_seen(proof_id);
+        }
+    }
+    result
+}
+
+/// Stateless proof check against a commitment
+///
+/// The cryptographic part of `verify_zkp` without temporal bounds or replay
+/// tracking, for light clients that verify already-committed outcomes.
+///
+/// # Returns
+/// * `Valid`, `Invalid`, or `FormatError`
+pub fn check_zkp_commitment(proof: &ZKProof, commitment: &[u8]) -> VerificationResult {
+    if proof.proof_data.is_empty() || proof.public_inputs.is_empty() {
+        return VerificationResult::FormatError;
+    }
+    
     // Deterministic verification
     // In production, this would:
     // 1. Load verification key
@@ -212,13 +236,6 @@ pub fn verify_zkp(
     let computed_commitment = hasher.finalize();
     
     if computed_commitment.as_slice() == commitment {
-        // Mark proof as seen to prevent replay
-        if replay_cache.mark_seen(proof_id).is_err() {
-            // Cache full - cleanup and retry
-            replay_cache.cleanup(current_time);
-            let _ = replay_cache.mark_seen(proof_id);
-        }
-        
         VerificationResult::Valid
     } else {
         VerificationResult::Invalid
@@ -27,6 +27,361 @@ pub enum LawfulBasis {
     LegitimateInterests,
 }
 
+/// GDPR Article 7 - Conditions for Consent
+///
+/// Genomic consent is bound to the data subject's ephemeral biokey: a biokey
+/// derivation event produces a consent-scoped TXO (public key hash, purpose,
+/// expiry) recorded through RTF. Withdrawal under Article 7(3) revokes the
+/// record, emits a revocation TXO, and invalidates every downstream signature
+/// that referenced the consent.
+pub mod consent {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use sha3::{Digest, Sha3_256};
+    
+    use crate::biokey::derivation::EphemeralBiokey;
+    use crate::rtf::api::{RTFContext, RTFError};
+    use crate::txo::{
+        AuditEntry, IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender,
+        Signature, TXO,
+    };
+    
+    /// Processing purpose a consent record is scoped to
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ConsentPurpose {
+        /// Clinical care or diagnosis
+        Clinical,
+        /// Research use of genomic data
+        Research,
+        /// Sequencing pipeline execution (VITRA-E0)
+        Sequencing,
+        /// Disclosure to a third party
+        ThirdPartySharing,
+    }
+    
+    impl ConsentPurpose {
+        /// Stable wire code used in consent hashing
+        pub fn code(self) -> u8 {
+            match self {
+                ConsentPurpose::Clinical => 0,
+                ConsentPurpose::Research => 1,
+                ConsentPurpose::Sequencing => 2,
+                ConsentPurpose::ThirdPartySharing => 3,
+            }
+        }
+    }
+    
+    /// Consent lifecycle status
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ConsentStatus {
+        /// Consent is in force
+        Active,
+        /// Consent lapsed at its expiry
+        Expired,
+        /// Consent was withdrawn (Article 7(3))
+        Revoked,
+    }
+    
+    /// Consent record produced from a biokey derivation event
+    #[derive(Debug, Clone)]
+    pub struct ConsentRecord {
+        /// Consent identifier (SHA3-256 commitment, embedded in the grant TXO)
+        pub consent_id: [u8; 32],
+        /// Data subject UUID
+        pub subject_id: [u8; 16],
+        /// Public hash of the biokey that granted consent
+        pub biokey_hash: [u8; 32],
+        /// Purpose the consent is scoped to
+        pub purpose: ConsentPurpose,
+        /// Grant timestamp
+        pub granted_at: u64,
+        /// Expiry timestamp
+        pub expires_at: u64,
+        /// TXO that recorded the grant
+        pub grant_txo_id: [u8; 16],
+        /// Revocation timestamp, if withdrawn
+        pub revoked_at: Option<u64>,
+        /// TXO that recorded the revocation, if withdrawn
+        pub revocation_txo_id: Option<[u8; 16]>,
+    }
+    
+    impl ConsentRecord {
+        /// Status of the consent at `now`
+        pub fn status(&self, now: u64) -> ConsentStatus {
+            match self.revoked_at {
+                Some(revoked_at) if now >= revoked_at => ConsentStatus::Revoked,
+                _ if now >= self.expires_at => ConsentStatus::Expired,
+                _ => ConsentStatus::Active,
+            }
+        }
+    }
+    
+    /// Downstream signature that referenced a consent record
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ConsentBoundSignature {
+        /// TXO carrying the signature
+        pub txo_id: [u8; 16],
+        /// Signer UUID
+        pub signer_id: [u8; 16],
+    }
+    
+    /// Result of an Article 7(3) withdrawal
+    #[derive(Debug, Clone)]
+    pub struct RevocationOutcome {
+        /// Revoked consent
+        pub consent_id: [u8; 32],
+        /// TXO that recorded the revocation
+        pub revocation_txo_id: [u8; 16],
+        /// Signatures invalidated by the revocation
+        pub invalidated: Vec<ConsentBoundSignature>,
+    }
+    
+    /// Consent registry
+    ///
+    /// GDPR engine state for the consent lifecycle: grants, revocations, and
+    /// the downstream signatures bound to each consent.
+    #[derive(Debug, Default)]
+    pub struct ConsentRegistry {
+        records: BTreeMap<[u8; 32], ConsentRecord>,
+        bound: BTreeMap<[u8; 32], Vec<ConsentBoundSignature>>,
+    }
+    
+    impl ConsentRegistry {
+        /// Create an empty registry
+        pub fn new() -> Self {
+            Self::default()
+        }
+        
+        /// Compute the consent identifier
+        ///
+        /// Commits to the biokey public hash, purpose, and expiry so the grant
+        /// TXO payload reveals nothing about the underlying SNP loci.
+        pub fn consent_id(
+            biokey_hash: &[u8; 32],
+            purpose: ConsentPurpose,
+            granted_at: u64,
+            expires_at: u64,
+        ) -> [u8; 32] {
+            let mut hasher = Sha3_256::new();
+            hasher.update(b"AETHERNET-GDPR-CONSENT-v1");
+            hasher.update(biokey_hash);
+            hasher.update([purpose.code()]);
+            hasher.update(granted_at.to_le_bytes());
+            hasher.update(expires_at.to_le_bytes());
+            
+            let result = hasher.finalize();
+            let mut id = [0u8; 32];
+            id.copy_from_slice(&result);
+            id
+        }
+        
+        /// Record consent from a biokey derivation event
+        ///
+        /// Builds a consent-scoped Compliance TXO, attaches `signatures`, and
+        /// executes and commits it through `ctx` so zone policy applies.
+        ///
+        /// # Returns
+        /// * The stored consent record
+        /// * `Err(RTFError)` if the expiry is not after the grant or RTF rejects the TXO
+        #[allow(clippy::too_many_arguments)]
+        pub fn grant_from_biokey(
+            &mut self,
+            ctx: &mut RTFContext,
+            biokey: &EphemeralBiokey,
+            subject_id: [u8; 16],
+            purpose: ConsentPurpose,
+            granted_at: u64,
+            expires_at: u64,
+            txo_id: [u8; 16],
+            signatures: Vec<Signature>,
+        ) -> Result<ConsentRecord, RTFError> {
+            if expires_at <= granted_at {
+                return Err(RTFError::ConsentInvalid);
+            }
+            
+            let biokey_hash = biokey.public_hash();
+            let consent_id = Self::consent_id(&biokey_hash, purpose, granted_at, expires_at);
+            
+            let mut txo = consent_txo(txo_id, subject_id, consent_id, PayloadType::Metadata, granted_at);
+            for signature in signatures {
+                txo.add_signature(signature);
+            }
+            // Before commit, so the ledger entry covers it
+            txo.add_audit_entry(AuditEntry {
+                actor_id: subject_id,
+                action: String::from("CONSENT_GRANT"),
+                timestamp: granted_at,
+            });
+            
+            ctx.execute_txo(&mut txo)?;
+            ctx.commit_txo(&mut txo)?;
+            
+            let record = ConsentRecord {
+                consent_id,
+                subject_id,
+                biokey_hash,
+                purpose,
+                granted_at,
+                expires_at,
+                grant_txo_id: txo_id,
+                revoked_at: None,
+                revocation_txo_id: None,
+            };
+            self.records.insert(consent_id, record.clone());
+            
+            Ok(record)
+        }
+        
+        /// Look up a consent record
+        pub fn get(&self, consent_id: &[u8; 32]) -> Option<&ConsentRecord> {
+            self.records.get(consent_id)
+        }
+        
+        /// Check whether a consent is in force at `now`
+        pub fn is_active(&self, consent_id: &[u8; 32], now: u64) -> bool {
+            self.records
+                .get(consent_id)
+                .map(|r| r.status(now) == ConsentStatus::Active)
+                .unwrap_or(false)
+        }
+        
+        /// Bind a TXO's consent-referencing signatures to their consents
+        ///
+        /// Call once the downstream TXO has been signed. Every signature with a
+        /// `consent_ref` must reference a consent that is active at `now`.
+        ///
+        /// # Returns
+        /// * Number of signatures bound
+        /// * `Err(RTFError::ConsentInvalid)` if any reference is unknown or inactive
+        pub fn bind_signatures(&mut self, txo: &TXO, now: u64) -> Result<usize, RTFError> {
+            if txo.signatures.iter()
+                .filter_map(|s| s.consent_ref)
+                .any(|id| !self.is_active(&id, now))
+            {
+                return Err(RTFError::ConsentInvalid);
+            }
+            
+            let mut count = 0;
+            for signature in &txo.signatures {
+                if let Some(consent_id) = signature.consent_ref {
+                    let entry = ConsentBoundSignature {
+                        txo_id: txo.txo_id,
+                        signer_id: signature.signer_id,
+                    };
+                    let bound = self.bound.entry(consent_id).or_default();
+                    if !bound.contains(&entry) {
+                        bound.push(entry);
+                    }
+                    count += 1;
+                }
+            }
+            
+            Ok(count)
+        }
+        
+        /// Withdraw consent (Article 7(3))
+        ///
+        /// Records a revocation Audit TXO through `ctx` and invalidates every
+        /// signature previously bound to the consent.
+        ///
+        /// # Returns
+        /// * The revocation outcome with invalidated signatures
+        /// * `Err(RTFError::ConsentInvalid)` if the consent is unknown or already revoked
+        pub fn revoke(
+            &mut self,
+            ctx: &mut RTFContext,
+            consent_id: &[u8; 32],
+            revoked_at: u64,
+            txo_id: [u8; 16],
+            signatures: Vec<Signature>,
+        ) -> Result<RevocationOutcome, RTFError> {
+            let subject_id = match self.records.get(consent_id) {
+                Some(record) if record.revoked_at.is_none() => record.subject_id,
+                _ => return Err(RTFError::ConsentInvalid),
+            };
+            
+            let mut txo = consent_txo(txo_id, subject_id, revocation_hash(consent_id, revoked_at), PayloadType::Audit, revoked_at);
+            for signature in signatures {
+                txo.add_signature(signature);
+            }
+            
+            ctx.execute_txo(&mut txo)?;
+            ctx.commit_txo(&mut txo)?;
+            
+            if let Some(record) = self.records.get_mut(consent_id) {
+                record.revoked_at = Some(revoked_at);
+                record.revocation_txo_id = Some(txo_id);
+            }
+            
+            Ok(RevocationOutcome {
+                consent_id: *consent_id,
+                revocation_txo_id: txo_id,
+                invalidated: self.bound.remove(consent_id).unwrap_or_default(),
+            })
+        }
+        
+        /// Strip signatures whose consent is no longer in force
+        ///
+        /// Run before `RTFContext::execute_txo` so dual-control checks only
+        /// count signatures backed by active consent.
+        ///
+        /// # Returns
+        /// * Number of signatures removed
+        pub fn enforce(&self, txo: &mut TXO, now: u64) -> usize {
+            let before = txo.signatures.len();
+            txo.signatures.retain(|s| match s.consent_ref {
+                Some(consent_id) => self.is_active(&consent_id, now),
+                None => true,
+            });
+            before - txo.signatures.len()
+        }
+    }
+    
+    /// Build a subject-originated Compliance TXO carrying `content_hash`
+    fn consent_txo(
+        txo_id: [u8; 16],
+        subject_id: [u8; 16],
+        content_hash: [u8; 32],
+        payload_type: PayloadType,
+        timestamp: u64,
+    ) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: subject_id,
+            biokey_present: true,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        let receiver = Receiver {
+            identity_type: IdentityType::System,
+            id: [0u8; 16],
+        };
+        let payload = Payload {
+            payload_type,
+            content_hash,
+            encrypted: false,
+            envelope: None,
+        };
+        
+        let mut txo = TXO::new(txo_id, sender, receiver, OperationClass::Compliance, payload);
+        txo.timestamp = timestamp;
+        txo
+    }
+    
+    /// Commitment embedded in the revocation TXO payload
+    fn revocation_hash(consent_id: &[u8; 32], revoked_at: u64) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"AETHERNET-GDPR-REVOKE-v1");
+        hasher.update(consent_id);
+        hasher.update(revoked_at.to_le_bytes());
+        
+        let result = hasher.finalize();
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&result);
+        hash
+    }
+}
+
 /// GDPR Article 9 - Special Categories of Personal Data
 pub mod special_categories {
     use super::*;
@@ -91,6 +446,9 @@ pub mod data_subject_rights {
     }
     
     /// Article 17 - Right to erasure ("right to be forgotten")
+    ///
+    /// See [`erasure::GdprErasureRequest`](super::erasure::GdprErasureRequest)
+    /// for the ledger-backed erasure pipeline.
     pub fn handle_erasure_request(
         subject_id: &[u8; 16],
         erasure_scope: &str,
@@ -149,6 +507,332 @@ pub mod data_subject_rights {
     }
 }
 
+/// GDPR Article 17 - Erasure by cryptographic shredding
+///
+/// Personal data TXOs are append-only in the ledger, so erasure destroys the
+/// per-record encryption key instead of the record: the ciphertext stays
+/// hash-chained but becomes unrecoverable. Each shredded record leaves a
+/// `CryptographicTombstone` committing to the TXO and the destroyed key, and
+/// the controller signs an erasure-proof TXO over the tombstones so the
+/// subject (or a supervisory authority) can verify the `ErasureReport`.
+pub mod erasure {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use core::ptr;
+    use sha3::{Digest, Sha3_256};
+    
+    use crate::rtf::api::{RTFContext, RTFError};
+    use crate::txo::{
+        AuditEntry, IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender,
+        SignatureType, TXO,
+    };
+    
+    /// Erasure failure
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum ErasureError {
+        /// Record payload is not encrypted, so it cannot be crypto-shredded
+        NotEncrypted,
+        /// Record is already registered
+        AlreadyRegistered,
+        /// Subject has no unerased personal data
+        NoPersonalData,
+        /// RTF rejected the erasure-proof TXO; no key was shredded
+        Rtf(RTFError),
+    }
+    
+    impl From<RTFError> for ErasureError {
+        fn from(err: RTFError) -> Self {
+            ErasureError::Rtf(err)
+        }
+    }
+    
+    /// Personal data TXO and the key encrypting its payload
+    #[derive(Debug)]
+    pub struct PersonalDataRecord {
+        /// TXO carrying the personal data
+        pub txo_id: [u8; 16],
+        /// Data subject UUID
+        pub subject_id: [u8; 16],
+        /// TXO hash at registration
+        pub txo_hash: [u8; 32],
+        /// Commitment to the record key, kept after the key is shredded
+        pub key_commitment: [u8; 32],
+        /// Erasure timestamp, once shredded
+        pub erased_at: Option<u64>,
+        key: Option<[u8; 32]>,
+    }
+    
+    impl PersonalDataRecord {
+        /// Record encryption key, `None` once shredded
+        pub fn key(&self) -> Option<&[u8; 32]> {
+            self.key.as_ref()
+        }
+        
+        /// Overwrite and drop the record key
+        fn shred(&mut self, erased_at: u64) {
+            if let Some(key) = self.key.as_mut() {
+                for byte in key.iter_mut() {
+                    unsafe {
+                        ptr::write_volatile(byte, 0);
+                    }
+                }
+            }
+            self.key = None;
+            self.erased_at = Some(erased_at);
+        }
+    }
+    
+    /// Index of personal data TXOs by subject, holding per-record keys
+    #[derive(Debug, Default)]
+    pub struct PersonalDataVault {
+        records: BTreeMap<[u8; 16], PersonalDataRecord>,
+    }
+    
+    impl PersonalDataVault {
+        /// Create an empty vault
+        pub fn new() -> Self {
+            Self::default()
+        }
+        
+        /// Register a personal data TXO with the key encrypting its payload
+        ///
+        /// # Returns
+        /// * `Err(ErasureError::NotEncrypted)` if the payload is not encrypted
+        /// * `Err(ErasureError::AlreadyRegistered)` if the TXO is already held
+        pub fn register(
+            &mut self,
+            txo: &TXO,
+            subject_id: [u8; 16],
+            record_key: [u8; 32],
+        ) -> Result<(), ErasureError> {
+            if !txo.payload.encrypted {
+                return Err(ErasureError::NotEncrypted);
+            }
+            if self.records.contains_key(&txo.txo_id) {
+                return Err(ErasureError::AlreadyRegistered);
+            }
+            
+            self.records.insert(txo.txo_id, PersonalDataRecord {
+                txo_id: txo.txo_id,
+                subject_id,
+                txo_hash: txo.compute_hash(),
+                key_commitment: key_commitment(&txo.txo_id, &record_key),
+                erased_at: None,
+                key: Some(record_key),
+            });
+            Ok(())
+        }
+        
+        /// Look up a record
+        pub fn get(&self, txo_id: &[u8; 16]) -> Option<&PersonalDataRecord> {
+            self.records.get(txo_id)
+        }
+        
+        /// Unerased personal data TXOs of a subject
+        pub fn locate(&self, subject_id: &[u8; 16]) -> Vec<[u8; 16]> {
+            self.records
+                .values()
+                .filter(|r| r.subject_id == *subject_id && r.key.is_some())
+                .map(|r| r.txo_id)
+                .collect()
+        }
+    }
+    
+    /// Proof that one record's key was destroyed
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct CryptographicTombstone {
+        /// Erased TXO
+        pub txo_id: [u8; 16],
+        /// Erased TXO hash
+        pub txo_hash: [u8; 32],
+        /// Commitment to the destroyed key
+        pub key_commitment: [u8; 32],
+        /// Erasure timestamp
+        pub erased_at: u64,
+        /// Hash over the fields above
+        pub tombstone_hash: [u8; 32],
+    }
+    
+    impl CryptographicTombstone {
+        /// Tombstone for a registered record
+        pub fn new(record: &PersonalDataRecord, erased_at: u64) -> Self {
+            let mut tombstone = Self {
+                txo_id: record.txo_id,
+                txo_hash: record.txo_hash,
+                key_commitment: record.key_commitment,
+                erased_at,
+                tombstone_hash: [0u8; 32],
+            };
+            tombstone.tombstone_hash = tombstone.compute_hash();
+            tombstone
+        }
+        
+        /// Recompute the tombstone hash
+        pub fn compute_hash(&self) -> [u8; 32] {
+            let mut hasher = Sha3_256::new();
+            hasher.update(b"AETHERNET-GDPR-TOMBSTONE-v1");
+            hasher.update(self.txo_id);
+            hasher.update(self.txo_hash);
+            hasher.update(self.key_commitment);
+            hasher.update(self.erased_at.to_le_bytes());
+            hasher.finalize().into()
+        }
+    }
+    
+    /// Data subject's Article 17 request
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct GdprErasureRequest {
+        /// Request identifier
+        pub request_id: [u8; 16],
+        /// Data subject UUID
+        pub subject_id: [u8; 16],
+        /// Time the request is executed
+        pub erased_at: u64,
+    }
+    
+    /// Verifiable outcome of an erasure request
+    #[derive(Debug, Clone)]
+    pub struct ErasureReport {
+        /// Request identifier
+        pub request_id: [u8; 16],
+        /// Data subject UUID
+        pub subject_id: [u8; 16],
+        /// One tombstone per shredded record
+        pub tombstones: Vec<CryptographicTombstone>,
+        /// Commitment over the request and all tombstones
+        pub erasure_root: [u8; 32],
+        /// Signed erasure-proof TXO as committed
+        pub proof_txo: TXO,
+        /// Ledger root after the proof TXO was committed
+        pub ledger_root: [u8; 32],
+    }
+    
+    impl ErasureReport {
+        /// Verify the report against the controller's Ed25519 public key
+        ///
+        /// Checks every tombstone hash, the erasure root, that the proof TXO
+        /// carries the root, and that the controller signed the proof TXO.
+        pub fn verify(&self, controller_public_key: &[u8; 32]) -> bool {
+            if self.tombstones.iter().any(|t| t.tombstone_hash != t.compute_hash()) {
+                return false;
+            }
+            let root = erasure_root(&self.request_id, &self.subject_id, &self.tombstones);
+            if root != self.erasure_root || self.proof_txo.payload.content_hash != root {
+                return false;
+            }
+            
+            let signed = self.proof_txo.signing_bytes();
+            self.proof_txo.signatures.iter().any(|s| {
+                s.signer_id == self.proof_txo.sender.id
+                    && self.proof_txo.verify_ed25519(&signed, s, controller_public_key).is_ok()
+            })
+        }
+    }
+    
+    impl GdprErasureRequest {
+        /// Create a request
+        pub fn new(request_id: [u8; 16], subject_id: [u8; 16], erased_at: u64) -> Self {
+            Self { request_id, subject_id, erased_at }
+        }
+        
+        /// Run the erasure pipeline
+        ///
+        /// Locates the subject's personal data TXOs, tombstones them, commits
+        /// a controller-signed erasure-proof TXO through `ctx`, and only then
+        /// shreds the record keys, so a rejected proof leaves data intact.
+        ///
+        /// # Returns
+        /// * The erasure report
+        /// * `Err(ErasureError::NoPersonalData)` if nothing is left to erase
+        /// * `Err(ErasureError::Rtf)` if RTF rejects the proof TXO
+        pub fn execute(
+            &self,
+            vault: &mut PersonalDataVault,
+            ctx: &mut RTFContext,
+            proof_txo_id: [u8; 16],
+            controller_id: [u8; 16],
+            controller_secret_key: &[u8; 32],
+        ) -> Result<ErasureReport, ErasureError> {
+            let located = vault.locate(&self.subject_id);
+            if located.is_empty() {
+                return Err(ErasureError::NoPersonalData);
+            }
+            
+            let tombstones: Vec<CryptographicTombstone> = located
+                .iter()
+                .filter_map(|id| vault.get(id))
+                .map(|record| CryptographicTombstone::new(record, self.erased_at))
+                .collect();
+            let root = erasure_root(&self.request_id, &self.subject_id, &tombstones);
+            
+            let mut txo = TXO::new(
+                proof_txo_id,
+                Sender {
+                    identity_type: IdentityType::System,
+                    id: controller_id,
+                    biokey_present: false,
+                    fido2_signed: false,
+                    zk_proof: None,
+                },
+                Receiver { identity_type: IdentityType::Operator, id: self.subject_id },
+                OperationClass::Compliance,
+                Payload { payload_type: PayloadType::Audit, content_hash: root, encrypted: false, envelope: None },
+            );
+            txo.timestamp = self.erased_at;
+            txo.sign_ed25519(SignatureType::Fido2, controller_id, controller_secret_key);
+            txo.add_audit_entry(AuditEntry {
+                actor_id: controller_id,
+                action: String::from("GDPR_ERASURE"),
+                timestamp: self.erased_at,
+            });
+            
+            ctx.execute_txo(&mut txo)?;
+            ctx.commit_txo(&mut txo)?;
+            
+            for id in &located {
+                if let Some(record) = vault.records.get_mut(id) {
+                    record.shred(self.erased_at);
+                }
+            }
+            
+            Ok(ErasureReport {
+                request_id: self.request_id,
+                subject_id: self.subject_id,
+                tombstones,
+                erasure_root: root,
+                proof_txo: txo,
+                ledger_root: ctx.ledger.get_current_root(),
+            })
+        }
+    }
+    
+    /// Commitment to a record key, bound to its TXO
+    fn key_commitment(txo_id: &[u8; 16], key: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"AETHERNET-GDPR-RECORD-KEY-v1");
+        hasher.update(txo_id);
+        hasher.update(key);
+        hasher.finalize().into()
+    }
+    
+    /// Commitment carried by the erasure-proof TXO
+    fn erasure_root(
+        request_id: &[u8; 16],
+        subject_id: &[u8; 16],
+        tombstones: &[CryptographicTombstone],
+    ) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"AETHERNET-GDPR-ERASURE-v1");
+        hasher.update(request_id);
+        hasher.update(subject_id);
+        hasher.update((tombstones.len() as u64).to_le_bytes());
+        for tombstone in tombstones {
+            hasher.update(tombstone.tombstone_hash);
+        }
+        hasher.finalize().into()
+    }
+}
+
 /// GDPR Article 25 - Data Protection by Design and Default
 pub mod data_protection_by_design {
     use super::*;
@@ -401,6 +1085,181 @@ pub fn verify_gdpr_compliance(
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_genomic_consent_lifecycle() {
+        use consent::{ConsentPurpose, ConsentRegistry, ConsentStatus};
+        use crate::biokey::derivation::{DevicePUF, EphemeralBiokey, SNPLocus, TemporalNonce};
+        use crate::ledger::MerkleLedger;
+        use crate::rtf::api::{RTFContext, RTFError, Zone};
+        use crate::txo::{
+            AuditEntry, IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, Signature,
+            SignatureType, TXO,
+        };
+        
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]));
+        let mut registry = ConsentRegistry::new();
+        
+        // Biokey derivation event produces the consent TXO
+        let loci = [SNPLocus { chromosome: 7, position: 117_559_590, ref_allele: b'C', alt_allele: b'T' }];
+        let puf = DevicePUF { cr_hash: [0x42u8; 32], device_id: [0x01u8; 16] };
+        let nonce = TemporalNonce { timestamp: 1000, counter: 1, epoch_id: 1 };
+        let biokey = EphemeralBiokey::derive(&loci, &puf, b"session-salt", nonce, 60);
+        
+        let subject = [9u8; 16];
+        let record = registry
+            .grant_from_biokey(&mut ctx, &biokey, subject, ConsentPurpose::Research, 1000, 5000, [1u8; 16], Vec::new())
+            .unwrap();
+        assert_eq!(record.biokey_hash, biokey.public_hash());
+        assert_eq!(record.status(2000), ConsentStatus::Active);
+        assert_eq!(ctx.ledger.node_count(), 1);
+        
+        // The committed grant TXO carries the consent audit entry
+        let mut grant = TXO::new(
+            record.grant_txo_id,
+            Sender { identity_type: IdentityType::Operator, id: subject, biokey_present: true, fido2_signed: false, zk_proof: None },
+            Receiver { identity_type: IdentityType::System, id: [0u8; 16] },
+            OperationClass::Compliance,
+            Payload { payload_type: PayloadType::Metadata, content_hash: record.consent_id, encrypted: false, envelope: None },
+        );
+        grant.timestamp = 1000;
+        for action in ["CONSENT_GRANT", "EXECUTE"] {
+            grant.add_audit_entry(AuditEntry { actor_id: subject, action: String::from(action), timestamp: 1000 });
+        }
+        assert_eq!(ctx.ledger.nodes()[0].txo_hash, grant.compute_hash());
+        
+        // Downstream genomic TXO dual-signed under the consent
+        let mut genomic = TXO::new(
+            [2u8; 16],
+            Sender { identity_type: IdentityType::Operator, id: [3u8; 16], biokey_present: false, fido2_signed: true, zk_proof: None },
+            Receiver { identity_type: IdentityType::Node, id: [4u8; 16] },
+            OperationClass::Genomic,
+            Payload { payload_type: PayloadType::Genome, content_hash: [5u8; 32], encrypted: true, envelope: None },
+        );
+        genomic.dual_control_required = true;
+        for signer in [[6u8; 16], [7u8; 16]] {
+            genomic.add_signature(Signature {
+                sig_type: SignatureType::Fido2,
+                signer_id: signer,
+                signature: vec![0u8; 64],
+                consent_ref: Some(record.consent_id),
+            });
+        }
+        assert_eq!(registry.bind_signatures(&genomic, 2000), Ok(2));
+        assert_eq!(registry.enforce(&mut genomic, 2000), 0);
+        assert!(genomic.verify_dual_control());
+        
+        // Withdrawal invalidates both signatures
+        let outcome = registry.revoke(&mut ctx, &record.consent_id, 3000, [8u8; 16], Vec::new()).unwrap();
+        assert_eq!(outcome.invalidated.len(), 2);
+        assert_eq!(ctx.ledger.node_count(), 2);
+        assert_eq!(registry.get(&record.consent_id).unwrap().status(3000), ConsentStatus::Revoked);
+        
+        assert_eq!(registry.enforce(&mut genomic, 3000), 2);
+        assert_eq!(ctx.execute_txo(&mut genomic), Err(RTFError::DualControlFailure));
+        
+        // Revoked consent cannot be revoked again or bound to new signatures
+        assert!(registry.revoke(&mut ctx, &record.consent_id, 3100, [10u8; 16], Vec::new()).is_err());
+        genomic.add_signature(Signature {
+            sig_type: SignatureType::Biokey,
+            signer_id: [11u8; 16],
+            signature: vec![0u8; 64],
+            consent_ref: Some(record.consent_id),
+        });
+        assert_eq!(registry.bind_signatures(&genomic, 3100), Err(RTFError::ConsentInvalid));
+    }
+    
+    #[test]
+    fn test_consent_expiry() {
+        use consent::{ConsentPurpose, ConsentRegistry};
+        use crate::biokey::derivation::{DevicePUF, EphemeralBiokey, SNPLocus, TemporalNonce};
+        use crate::ledger::MerkleLedger;
+        use crate::rtf::api::{RTFContext, RTFError, Zone};
+        
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]));
+        let mut registry = ConsentRegistry::new();
+        
+        let loci = [SNPLocus { chromosome: 1, position: 12345, ref_allele: b'A', alt_allele: b'G' }];
+        let puf = DevicePUF { cr_hash: [0x42u8; 32], device_id: [0x01u8; 16] };
+        let nonce = TemporalNonce { timestamp: 1000, counter: 1, epoch_id: 1 };
+        let biokey = EphemeralBiokey::derive(&loci, &puf, b"salt", nonce, 60);
+        
+        // Expiry must follow the grant
+        assert_eq!(
+            registry.grant_from_biokey(&mut ctx, &biokey, [1u8; 16], ConsentPurpose::Clinical, 1000, 1000, [1u8; 16], Vec::new()).unwrap_err(),
+            RTFError::ConsentInvalid
+        );
+        
+        let record = registry
+            .grant_from_biokey(&mut ctx, &biokey, [1u8; 16], ConsentPurpose::Clinical, 1000, 2000, [2u8; 16], Vec::new())
+            .unwrap();
+        assert!(registry.is_active(&record.consent_id, 1999));
+        assert!(!registry.is_active(&record.consent_id, 2000));
+    }
+    
+    #[test]
+    fn test_erasure_shreds_keys_and_proves_erasure() {
+        use erasure::{ErasureError, GdprErasureRequest, PersonalDataVault};
+        use crate::ledger::MerkleLedger;
+        use crate::rtf::api::{RTFContext, Zone};
+        use crate::txo::{IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, TXO};
+        
+        let genomic = |id: u8, encrypted: bool| {
+            TXO::new(
+                [id; 16],
+                Sender { identity_type: IdentityType::Operator, id: [3u8; 16], biokey_present: false, fido2_signed: true, zk_proof: None },
+                Receiver { identity_type: IdentityType::Node, id: [4u8; 16] },
+                OperationClass::Genomic,
+                Payload { payload_type: PayloadType::Genome, content_hash: [id; 32], encrypted, envelope: None },
+            )
+        };
+        
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]));
+        let mut vault = PersonalDataVault::new();
+        let subject = [9u8; 16];
+        let other = [10u8; 16];
+        vault.register(&genomic(1, true), subject, [0x11; 32]).unwrap();
+        vault.register(&genomic(2, true), subject, [0x22; 32]).unwrap();
+        vault.register(&genomic(3, true), other, [0x33; 32]).unwrap();
+        assert_eq!(vault.register(&genomic(4, false), subject, [0x44; 32]), Err(ErasureError::NotEncrypted));
+        assert_eq!(vault.register(&genomic(1, true), subject, [0x11; 32]), Err(ErasureError::AlreadyRegistered));
+        assert_eq!(vault.locate(&subject).len(), 2);
+        
+        let controller_secret = [0x5Au8; 32];
+        let controller_public = ed25519_dalek::SigningKey::from_bytes(&controller_secret)
+            .verifying_key()
+            .to_bytes();
+        let request = GdprErasureRequest::new([20u8; 16], subject, 4000);
+        let report = request
+            .execute(&mut vault, &mut ctx, [21u8; 16], [22u8; 16], &controller_secret)
+            .unwrap();
+        
+        // Keys are shredded; the other subject's data is untouched
+        assert_eq!(report.tombstones.len(), 2);
+        assert!(vault.locate(&subject).is_empty());
+        assert!(vault.get(&[1u8; 16]).unwrap().key().is_none());
+        assert_eq!(vault.get(&[2u8; 16]).unwrap().erased_at, Some(4000));
+        assert_eq!(vault.get(&[3u8; 16]).unwrap().key(), Some(&[0x33; 32]));
+        
+        // Proof TXO is on the ledger and the report verifies
+        assert_eq!(ctx.ledger.node_count(), 1);
+        assert_eq!(report.ledger_root, ctx.ledger.get_current_root());
+        assert!(report.verify(&controller_public));
+        assert!(!report.verify(&[0u8; 32]));
+        
+        let mut tampered = report.clone();
+        tampered.tombstones.pop();
+        assert!(!tampered.verify(&controller_public));
+        let mut tampered = report.clone();
+        tampered.tombstones[0].erased_at = 5000;
+        assert!(!tampered.verify(&controller_public));
+        
+        // Nothing left to erase
+        assert_eq!(
+            request.execute(&mut vault, &mut ctx, [23u8; 16], [22u8; 16], &controller_secret).unwrap_err(),
+            ErasureError::NoPersonalData
+        );
+    }
+    
     #[test]
     fn test_special_category_processing() {
         // Genetic data with explicit consent should be allowed
@@ -0,0 +1,195 @@
+//! Golden Wire Fixtures
+//!
+//! Serializes canonical instances of every public wire type and compares the
+//! bytes against fixtures in `fixtures/golden/`. Any byte-level change fails
+//! the test, so wire-format breaks cannot land silently.
+//!
+//! After an intentional format change, regenerate and commit the fixtures:
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test golden
+//! ```
+//!
+//! CBOR fixtures are stored as hex (32 bytes per line) so diffs stay
+//! readable; JSON fixtures are stored verbatim.
+
+extern crate std;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::path::PathBuf;
+
+use crate::txo::{
+    AuditEntry, IdentityType, OperationClass, Payload, PayloadType, Receiver, RollbackEntry,
+    Sender, Signature, SignatureType, TXO,
+};
+
+/// Fixture directory
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/golden").join(name)
+}
+
+/// Render bytes as the fixture text format
+fn render(name: &str, bytes: &[u8]) -> String {
+    if name.ends_with(".json") {
+        let mut text = String::from_utf8(bytes.to_vec()).expect("JSON fixture is UTF-8");
+        text.push('\n');
+        return text;
+    }
+    let mut text = String::new();
+    for line in bytes.chunks(32) {
+        for byte in line {
+            text.push_str(&alloc::format!("{:02x}", byte));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Parse fixture text back into bytes
+fn parse(name: &str, text: &str) -> Vec<u8> {
+    if name.ends_with(".json") {
+        return text.trim_end_matches('\n').as_bytes().to_vec();
+    }
+    let digits: Vec<u8> = text.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let pair = core::str::from_utf8(pair).expect("hex fixture is ASCII");
+            u8::from_str_radix(pair, 16).expect("hex fixture digit")
+        })
+        .collect()
+}
+
+/// Compare bytes against a fixture (or rewrite it with `UPDATE_GOLDEN=1`)
+///
+/// Returns the fixture bytes so callers can also check decoding.
+fn check(name: &str, bytes: &[u8]) -> Vec<u8> {
+    let path = fixture_path(name);
+    let rendered = render(name, bytes);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().expect("fixture directory")).expect("create fixture directory");
+        std::fs::write(&path, &rendered).expect("write fixture");
+        return bytes.to_vec();
+    }
+
+    let stored = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("missing golden fixture {}; run with UPDATE_GOLDEN=1 to create it", path.display())
+    });
+    let expected = parse(name, &stored);
+    if expected != bytes {
+        let offset = expected.iter().zip(bytes).take_while(|(a, b)| a == b).count();
+        panic!(
+            "wire format of {} changed at byte {} ({} -> {} bytes); if intentional, \
+             rerun with UPDATE_GOLDEN=1 and commit the fixture",
+            name,
+            offset,
+            expected.len(),
+            bytes.len()
+        );
+    }
+    expected
+}
+
+/// Canonical TXO exercising every optional and repeated field
+fn canonical_txo() -> TXO {
+    let sender = Sender {
+        identity_type: IdentityType::Operator,
+        id: [0x11; 16],
+        biokey_present: true,
+        fido2_signed: true,
+        zk_proof: Some(alloc::vec![0xA5; 8]),
+    };
+    let receiver = Receiver {
+        identity_type: IdentityType::Node,
+        id: [0x22; 16],
+    };
+    let payload = Payload {
+        payload_type: PayloadType::Genome,
+        content_hash: [0x33; 32],
+        encrypted: true,
+        envelope: None,
+    };
+
+    let mut txo = TXO::new([0x44; 16], sender, receiver, OperationClass::Genomic, payload);
+    txo.timestamp = 1_700_000_000;
+    txo.epoch_id = 7;
+    txo.container_hash = [0x55; 32];
+    txo.dual_control_required = true;
+    txo.add_signature(Signature {
+        sig_type: SignatureType::Fido2,
+        signer_id: [0x66; 16],
+        signature: alloc::vec![0x77; 64],
+        consent_ref: Some([0x88; 32]),
+    });
+    txo.add_signature(Signature {
+        sig_type: SignatureType::Biokey,
+        signer_id: [0x99; 16],
+        signature: alloc::vec![0xAA; 64],
+        consent_ref: None,
+    });
+    txo.add_rollback_entry(RollbackEntry {
+        from_epoch: 7,
+        to_epoch: 6,
+        reason: String::from("golden rollback"),
+    });
+    txo.add_audit_entry(AuditEntry {
+        actor_id: [0xBB; 16],
+        action: String::from("golden audit"),
+        timestamp: 1_700_000_001,
+    });
+    txo
+}
+
+#[test]
+fn golden_txo_cbor() {
+    let txo = canonical_txo();
+    let fixture = check("txo.cbor.hex", &txo.to_cbor().unwrap());
+
+    let decoded = TXO::from_cbor(&fixture).unwrap();
+    assert_eq!(decoded.to_cbor().unwrap(), fixture);
+}
+
+#[test]
+fn golden_txo_signing_bytes() {
+    check("txo_signing_bytes.cbor.hex", &canonical_txo().signing_bytes());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn golden_txo_json() {
+    let json = serde_json::to_string(&canonical_txo()).unwrap();
+    let fixture = check("txo.json", json.as_bytes());
+
+    let decoded: TXO = serde_json::from_slice(&fixture).unwrap();
+    assert_eq!(decoded.to_cbor().unwrap(), canonical_txo().to_cbor().unwrap());
+}
+
+#[cfg(feature = "runtime")]
+#[test]
+fn golden_ledger_export() {
+    use crate::ledger::{EpochSnapshot, LedgerNode, MerkleLedger};
+    use crate::rtf::api::Zone;
+
+    let mut ledger = MerkleLedger::new([0x01; 32]);
+    ledger.append_txo(&canonical_txo(), Zone::Z1);
+    ledger.create_snapshot(7, 1_700_000_002);
+    check("ledger.cbor.hex", &ledger.to_cbor().unwrap());
+
+    let node = LedgerNode::new([0x01; 32], [0x02; 32], 7, Zone::Z2, 1_700_000_000);
+    let fixture = check("ledger_node.cbor.hex", &minicbor::to_vec(&node).unwrap());
+    let decoded: LedgerNode = minicbor::decode(&fixture).unwrap();
+    assert_eq!(decoded.node_hash, node.node_hash);
+
+    let snapshot = EpochSnapshot {
+        epoch_id: 7,
+        merkle_root: [0x03; 32],
+        node_count: 1,
+        zone: 1,
+        timestamp: 1_700_000_002,
+    };
+    let fixture = check("epoch_snapshot.cbor.hex", &minicbor::to_vec(&snapshot).unwrap());
+    let decoded: EpochSnapshot = minicbor::decode(&fixture).unwrap();
+    assert_eq!(minicbor::to_vec(&decoded).unwrap(), fixture);
+}
@@ -0,0 +1,145 @@
+//! Streaming SHA3-256 Hashing
+//!
+//! Facade over SHA3-256 for the ledger and TXO hashing paths:
+//!
+//! - `StreamingHasher`: a reusable context that TXO CBOR is encoded into
+//!   directly (no intermediate buffer) and that a `MerkleLedger` keeps
+//!   across appends
+//! - `HashStats`: bytes absorbed and digests produced, for throughput
+//!   monitoring
+//! - `Backend`: which Keccak-f[1600] implementation this process uses
+//!
+//! Hardware acceleration is opt-in through the `hw-accel` feature, which
+//! enables the ARMv8.2 SHA3 instructions (EOR3/RAX1/XAR/BCAX) on aarch64
+//! with runtime detection and portable fallback. x86 SHA extensions only
+//! cover SHA-1 and SHA-256, so x86 always runs the portable permutation.
+//! Every backend computes the same function: digests are identical
+//! regardless of where they were produced.
+
+extern crate alloc;
+
+use core::convert::Infallible;
+use sha3::{Digest, Sha3_256};
+
+#[cfg(all(target_arch = "aarch64", feature = "hw-accel"))]
+cpufeatures::new!(armv8_sha3, "sha3");
+
+/// Keccak-f[1600] implementation in use
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Portable Rust permutation
+    Portable,
+    /// ARMv8.2 SHA3 instructions
+    Armv8Sha3,
+}
+
+impl Backend {
+    /// Backend selected for this process
+    ///
+    /// Mirrors the runtime check the permutation itself performs, so the
+    /// answer matches the code path actually taken.
+    pub fn detect() -> Self {
+        #[cfg(all(target_arch = "aarch64", feature = "hw-accel"))]
+        if armv8_sha3::get() {
+            return Backend::Armv8Sha3;
+        }
+        Backend::Portable
+    }
+}
+
+/// Hashing throughput counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HashStats {
+    /// Bytes absorbed
+    pub bytes: u64,
+    /// Digests produced
+    pub digests: u64,
+}
+
+/// Reusable SHA3-256 context
+#[derive(Clone, Default)]
+pub struct StreamingHasher {
+    state: Sha3_256,
+    stats: HashStats,
+}
+
+impl StreamingHasher {
+    /// Create a fresh context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorb `data`
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+        self.stats.bytes += data.len() as u64;
+    }
+
+    /// Produce the digest of everything absorbed and reset for the next message
+    pub fn finalize_reset(&mut self) -> [u8; 32] {
+        self.stats.digests += 1;
+        self.state.finalize_reset().into()
+    }
+
+    /// Hash a complete message with this context
+    pub fn digest(&mut self, data: &[u8]) -> [u8; 32] {
+        self.update(data);
+        self.finalize_reset()
+    }
+
+    /// Counters accumulated by this context
+    pub fn stats(&self) -> HashStats {
+        self.stats
+    }
+}
+
+/// TXO encoders write straight into the sponge
+impl minicbor::encode::Write for StreamingHasher {
+    type Error = Infallible;
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
+        self.update(buf);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_matches_one_shot_sha3() {
+        let mut hasher = StreamingHasher::new();
+        assert_eq!(
+            hasher.digest(b"abc"),
+            [
+                0x3a, 0x98, 0x5d, 0xa7, 0x4f, 0xe2, 0x25, 0xb2, 0x04, 0x5c, 0x17, 0x2d, 0x6b, 0xd3,
+                0x90, 0xbd, 0x85, 0x5f, 0x08, 0x6e, 0x3e, 0x9d, 0x52, 0x5b, 0x46, 0xbf, 0xe2, 0x45,
+                0x11, 0x43, 0x15, 0x32,
+            ]
+        );
+
+        // Chunking across the 136-byte rate and context reuse leave digests unchanged
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+        let expected: [u8; 32] = Sha3_256::digest(&data).into();
+        for chunk in [1, 7, 135, 136, 137, 1000] {
+            for piece in data.chunks(chunk) {
+                hasher.update(piece);
+            }
+            assert_eq!(hasher.finalize_reset(), expected);
+        }
+
+        let stats = hasher.stats();
+        assert_eq!(stats.digests, 7);
+        assert_eq!(stats.bytes, 3 + 6 * 1000);
+    }
+
+    #[test]
+    fn test_backend_detection_is_stable() {
+        let backend = Backend::detect();
+        assert_eq!(backend, Backend::detect());
+        #[cfg(not(all(target_arch = "aarch64", feature = "hw-accel")))]
+        assert_eq!(backend, Backend::Portable);
+    }
+}
@@ -0,0 +1,364 @@
+//! Ledger Analytics
+//!
+//! Rolling statistics over committed TXOs: throughput, distribution by
+//! operation class and zone, rollback frequency and commit latency. Figures
+//! are integers derived only from recorded samples (TXO timestamps and the
+//! commit times the caller supplied), never from a local clock or floating
+//! point, so two auditors replaying the same commits derive identical
+//! reports and identical report digests.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::hash::StreamingHasher;
+use crate::rtf::api::Zone;
+use crate::txo::{OperationClass, TXO};
+
+/// Operation classes in report order
+const CLASSES: [OperationClass; 4] = [
+    OperationClass::Genomic,
+    OperationClass::Network,
+    OperationClass::Compliance,
+    OperationClass::Admin,
+];
+
+/// One committed TXO
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CommitSample {
+    /// Epoch the TXO committed in
+    pub epoch_id: u64,
+    /// Operation class
+    pub operation_class: OperationClass,
+    /// Zone the TXO committed in
+    pub zone: Zone,
+    /// TXO timestamp (Unix seconds)
+    pub submitted_at: u64,
+    /// Commit time (Unix seconds)
+    pub committed_at: u64,
+}
+
+/// One ledger rollback
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RollbackSample {
+    /// Epoch rolled back to
+    pub to_epoch: u64,
+    /// Ledger nodes removed
+    pub nodes_dropped: u64,
+    /// Latest commit time seen before the rollback (Unix seconds)
+    pub at: u64,
+}
+
+/// Statistics over one window
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct AnalyticsReport {
+    /// Window start (exclusive, Unix seconds)
+    pub window_start: u64,
+    /// Window end (inclusive, Unix seconds)
+    pub window_end: u64,
+    /// TXOs committed in the window
+    pub commits: u64,
+    /// Throughput in thousandths of a TXO per second
+    pub throughput_milli_tps: u64,
+    /// Commits by operation class: Genomic, Network, Compliance, Admin
+    pub by_class: [u64; 4],
+    /// Commits by zone: Z0-Z3
+    pub by_zone: [u64; 4],
+    /// Rollbacks in the window
+    pub rollbacks: u64,
+    /// Ledger nodes removed by those rollbacks
+    pub nodes_rolled_back: u64,
+    /// Rollbacks per 10,000 commits (0 when nothing committed)
+    pub rollback_rate_bp: u64,
+    /// Mean commit latency in milliseconds, rounded down
+    pub mean_commit_latency_ms: u64,
+    /// Worst commit latency in seconds
+    pub max_commit_latency_secs: u64,
+}
+
+impl AnalyticsReport {
+    /// Commits of one operation class
+    pub fn class_count(&self, class: OperationClass) -> u64 {
+        self.by_class[class as usize]
+    }
+
+    /// Commits in one zone
+    pub fn zone_count(&self, zone: Zone) -> u64 {
+        self.by_zone[zone as usize]
+    }
+
+    /// SHA3-256 over every figure, for comparing reports between auditors
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = StreamingHasher::new();
+        hasher.update(b"AETHERNET-ANALYTICS-v1");
+        let figures = [
+            self.window_start,
+            self.window_end,
+            self.commits,
+            self.throughput_milli_tps,
+            self.rollbacks,
+            self.nodes_rolled_back,
+            self.rollback_rate_bp,
+            self.mean_commit_latency_ms,
+            self.max_commit_latency_secs,
+        ];
+        for value in figures.iter().chain(&self.by_class).chain(&self.by_zone) {
+            hasher.update(&value.to_le_bytes());
+        }
+        hasher.finalize_reset()
+    }
+
+    /// Render in Prometheus text exposition format for the metrics endpoint
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let mut gauge = |name: &str, help: &str, samples: &[(&str, u64)]| {
+            out.push_str(&format!("# HELP aethernet_ledger_{} {}\n", name, help));
+            out.push_str(&format!("# TYPE aethernet_ledger_{} gauge\n", name));
+            for (labels, value) in samples {
+                out.push_str(&format!("aethernet_ledger_{}{} {}\n", name, labels, value));
+            }
+        };
+
+        gauge("commits", "TXOs committed in the window", &[("", self.commits)]);
+        gauge(
+            "throughput_milli_tps",
+            "Committed TXOs per second, x1000",
+            &[("", self.throughput_milli_tps)],
+        );
+        let by_class: Vec<(String, u64)> = CLASSES
+            .iter()
+            .map(|class| (format!("{{class=\"{:?}\"}}", class), self.class_count(*class)))
+            .collect();
+        let by_class: Vec<(&str, u64)> = by_class.iter().map(|(l, v)| (l.as_str(), *v)).collect();
+        gauge("commits_by_class", "TXOs committed per operation class", &by_class);
+        let by_zone: Vec<(String, u64)> = Zone::ALL
+            .iter()
+            .map(|zone| (format!("{{zone=\"{}\"}}", zone.name()), self.zone_count(*zone)))
+            .collect();
+        let by_zone: Vec<(&str, u64)> = by_zone.iter().map(|(l, v)| (l.as_str(), *v)).collect();
+        gauge("commits_by_zone", "TXOs committed per zone", &by_zone);
+        gauge("rollbacks", "Rollbacks in the window", &[("", self.rollbacks)]);
+        gauge(
+            "nodes_rolled_back",
+            "Ledger nodes removed by rollbacks in the window",
+            &[("", self.nodes_rolled_back)],
+        );
+        gauge("rollback_rate_bp", "Rollbacks per 10000 commits", &[("", self.rollback_rate_bp)]);
+        gauge(
+            "commit_latency_mean_ms",
+            "Mean time from TXO timestamp to commit",
+            &[("", self.mean_commit_latency_ms)],
+        );
+        gauge(
+            "commit_latency_max_seconds",
+            "Worst time from TXO timestamp to commit",
+            &[("", self.max_commit_latency_secs)],
+        );
+        out
+    }
+}
+
+/// Rolling ledger statistics
+#[derive(Debug, Clone, Default)]
+pub struct LedgerAnalytics {
+    /// Window length in seconds
+    window_secs: u64,
+    /// Commits in record order
+    commits: Vec<CommitSample>,
+    /// Rollbacks in record order
+    rollbacks: Vec<RollbackSample>,
+    /// Latest commit time recorded
+    latest: u64,
+}
+
+impl LedgerAnalytics {
+    /// Create analytics reporting over a rolling window (at least 1 second)
+    pub fn new(window_secs: u64) -> Self {
+        Self { window_secs: window_secs.max(1), ..Self::default() }
+    }
+
+    /// Window length in seconds
+    pub fn window_secs(&self) -> u64 {
+        self.window_secs
+    }
+
+    /// Record a committed TXO
+    ///
+    /// # Arguments
+    /// * `txo` - TXO as committed
+    /// * `zone` - Zone it committed in
+    /// * `committed_at` - Commit time (Unix seconds)
+    pub fn record_commit(&mut self, txo: &TXO, zone: Zone, committed_at: u64) {
+        self.latest = self.latest.max(committed_at);
+        self.commits.push(CommitSample {
+            epoch_id: txo.epoch_id,
+            operation_class: txo.operation_class,
+            zone,
+            submitted_at: txo.timestamp,
+            committed_at,
+        });
+    }
+
+    /// Record a rollback, timestamped at the latest commit seen
+    ///
+    /// Rolled-back commits stay in the statistics: they happened, and the
+    /// rollback rate is measured against them.
+    pub fn record_rollback(&mut self, to_epoch: u64, nodes_dropped: u64) {
+        self.rollbacks.push(RollbackSample { to_epoch, nodes_dropped, at: self.latest });
+    }
+
+    /// Recorded commits
+    pub fn commits(&self) -> &[CommitSample] {
+        &self.commits
+    }
+
+    /// Recorded rollbacks
+    pub fn rollbacks(&self) -> &[RollbackSample] {
+        &self.rollbacks
+    }
+
+    /// Report over the window ending at `now`
+    pub fn report(&self, now: u64) -> AnalyticsReport {
+        let start = now.saturating_sub(self.window_secs);
+        let in_window = |at: u64| at > start && at <= now;
+
+        let mut report = AnalyticsReport {
+            window_start: start,
+            window_end: now,
+            commits: 0,
+            throughput_milli_tps: 0,
+            by_class: [0; 4],
+            by_zone: [0; 4],
+            rollbacks: 0,
+            nodes_rolled_back: 0,
+            rollback_rate_bp: 0,
+            mean_commit_latency_ms: 0,
+            max_commit_latency_secs: 0,
+        };
+
+        let mut latency_total: u64 = 0;
+        for sample in self.commits.iter().filter(|s| in_window(s.committed_at)) {
+            let latency = sample.committed_at.saturating_sub(sample.submitted_at);
+            report.commits += 1;
+            report.by_class[sample.operation_class as usize] += 1;
+            report.by_zone[sample.zone as usize] += 1;
+            report.max_commit_latency_secs = report.max_commit_latency_secs.max(latency);
+            latency_total = latency_total.saturating_add(latency);
+        }
+        for sample in self.rollbacks.iter().filter(|s| in_window(s.at)) {
+            report.rollbacks += 1;
+            report.nodes_rolled_back += sample.nodes_dropped;
+        }
+
+        let span = now - start;
+        report.throughput_milli_tps = report.commits * 1000 / span.max(1);
+        report.rollback_rate_bp = (report.rollbacks * 10_000).checked_div(report.commits).unwrap_or(0);
+        report.mean_commit_latency_ms =
+            latency_total.saturating_mul(1000).checked_div(report.commits).unwrap_or(0);
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::RTFContext;
+    use crate::txo::{IdentityType, Payload, PayloadType, Receiver, Sender, SignatureType};
+
+    fn make_txo(id: u8, class: OperationClass, timestamp: u64) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [1u8; 16],
+            biokey_present: false,
+            fido2_signed: true,
+            zk_proof: None,
+        };
+        let receiver = Receiver { identity_type: IdentityType::Node, id: [2u8; 16] };
+        let payload = Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: [id; 32],
+            encrypted: false,
+            envelope: None,
+        };
+        let mut txo = TXO::new([id; 16], sender, receiver, class, payload);
+        txo.timestamp = timestamp;
+        txo.sign_ed25519(SignatureType::Fido2, [3u8; 16], &[11u8; 32]);
+        txo
+    }
+
+    #[test]
+    fn test_report_through_context() {
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]))
+            .with_analytics(LedgerAnalytics::new(60));
+
+        // Latencies 2s, 4s, 0s
+        let mut a = make_txo(1, OperationClass::Genomic, 100);
+        ctx.execute_txo(&mut a).unwrap();
+        ctx.commit_txo_at(&mut a, 102).unwrap();
+        ctx.ledger.create_snapshot(1, 102);
+        ctx.current_epoch = 1;
+        let mut b = make_txo(2, OperationClass::Genomic, 100);
+        ctx.execute_txo(&mut b).unwrap();
+        ctx.commit_txo_at(&mut b, 104).unwrap();
+        let mut c = make_txo(3, OperationClass::Network, 110);
+        ctx.execute_txo(&mut c).unwrap();
+        ctx.commit_txo(&mut c).unwrap();
+        ctx.rollback_txo(0, String::from("bad batch")).unwrap();
+
+        let report = ctx.analytics.as_ref().unwrap().report(120);
+        assert_eq!(report.commits, 3);
+        assert_eq!(report.throughput_milli_tps, 50);
+        assert_eq!(report.class_count(OperationClass::Genomic), 2);
+        assert_eq!(report.class_count(OperationClass::Network), 1);
+        assert_eq!(report.zone_count(Zone::Z1), 3);
+        assert_eq!(report.rollbacks, 1);
+        assert_eq!(report.nodes_rolled_back, 3);
+        assert_eq!(report.rollback_rate_bp, 3333);
+        assert_eq!(report.mean_commit_latency_ms, 2000);
+        assert_eq!(report.max_commit_latency_secs, 4);
+
+        let metrics = report.to_prometheus();
+        assert!(metrics.contains("aethernet_ledger_commits_by_class{class=\"Genomic\"} 2\n"));
+        assert!(metrics.contains("aethernet_ledger_rollback_rate_bp 3333\n"));
+
+        // Samples age out of the rolling window
+        let later = ctx.analytics.as_ref().unwrap().report(170);
+        assert_eq!(later.commits, 0);
+        assert_eq!(later.rollbacks, 0);
+        assert_eq!(later.rollback_rate_bp, 0);
+    }
+
+    #[test]
+    fn test_auditors_derive_identical_reports() {
+        let commits = [
+            (OperationClass::Compliance, Zone::Z2, 10, 11),
+            (OperationClass::Admin, Zone::Z2, 12, 15),
+            (OperationClass::Network, Zone::Z1, 13, 13),
+        ];
+        let build = |order: &[usize]| {
+            let mut analytics = LedgerAnalytics::new(3600);
+            for &i in order {
+                let (class, zone, submitted, committed) = commits[i];
+                analytics.record_commit(&make_txo(i as u8, class, submitted), zone, committed);
+            }
+            analytics.report(20)
+        };
+
+        let first = build(&[0, 1, 2]);
+        let second = build(&[2, 0, 1]);
+        assert_eq!(first, second);
+        assert_eq!(first.digest(), second.digest());
+        assert_eq!(first.mean_commit_latency_ms, 1333);
+
+        let mut tampered = first.clone();
+        tampered.by_zone[1] += 1;
+        assert_ne!(tampered.digest(), first.digest());
+    }
+}
@@ -0,0 +1,446 @@
+//! Rollback Snapshot Diff
+//!
+//! Forensic report of what a rollback undoes. `RollbackDiff::compute`
+//! compares the live ledger (pre-rollback) with the snapshot it would revert
+//! to (post-rollback) and lists the removed entries, the roots reverted and
+//! the snapshots discarded. Ledger nodes carry only TXO hashes, so affected
+//! identities are filled in by `resolve` from whatever committed TXOs the
+//! operator still holds.
+//!
+//! Reports export as JSON or human-readable text, and `rollback_txo` builds
+//! a TXO carrying the report digest so the ledger links to the report that
+//! explains each rollback.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::hash::StreamingHasher;
+use crate::ledger::MerkleLedger;
+use crate::rtf::api::{RTFError, Zone};
+use crate::txo::{
+    IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, TXO,
+};
+
+/// TXO details recovered for a removed entry
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemovedTxo {
+    /// TXO UUID
+    pub txo_id: [u8; 16],
+    /// Operation class
+    pub operation_class: OperationClass,
+    /// Sender UUID
+    pub sender_id: [u8; 16],
+    /// Receiver UUID
+    pub receiver_id: [u8; 16],
+}
+
+/// Ledger entry a rollback removes
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemovedEntry {
+    /// Position in the ledger (0 = first node after genesis)
+    pub position: usize,
+    /// Node hash, i.e. the ledger root this entry produced
+    pub node_hash: [u8; 32],
+    /// Committed TXO hash
+    pub txo_hash: [u8; 32],
+    /// Epoch the TXO committed in
+    pub epoch_id: u64,
+    /// Zone byte (0-3)
+    pub zone: u8,
+    /// TXO timestamp
+    pub timestamp: u64,
+    /// TXO details, once resolved
+    pub txo: Option<RemovedTxo>,
+}
+
+/// Difference between the pre- and post-rollback ledger
+#[derive(Debug, Clone, PartialEq)]
+pub struct RollbackDiff {
+    /// Latest epoch before the rollback
+    pub from_epoch: u64,
+    /// Epoch rolled back to
+    pub to_epoch: u64,
+    /// Operator-supplied reason
+    pub reason: String,
+    /// Ledger root before the rollback
+    pub root_before: [u8; 32],
+    /// Ledger root after the rollback
+    pub root_after: [u8; 32],
+    /// Snapshots the rollback discards, as (epoch, root)
+    pub discarded_snapshots: Vec<(u64, [u8; 32])>,
+    /// Removed entries, oldest first
+    pub removed: Vec<RemovedEntry>,
+}
+
+impl RollbackDiff {
+    /// Diff the ledger against the snapshot of `target_epoch`
+    ///
+    /// Call before `RTFContext::rollback_txo`, or use
+    /// `RTFContext::rollback_with_report`.
+    ///
+    /// # Returns
+    /// * `Ok(RollbackDiff)` describing what rolling back would undo
+    /// * `Err(RTFError::EpochNotFound)` if no snapshot exists for the epoch
+    pub fn compute(ledger: &MerkleLedger, target_epoch: u64, reason: String) -> Result<Self, RTFError> {
+        let snapshot = ledger
+            .snapshots()
+            .iter()
+            .find(|s| s.epoch_id == target_epoch)
+            .ok_or(RTFError::EpochNotFound)?;
+
+        let removed = ledger.nodes()[snapshot.node_count..]
+            .iter()
+            .enumerate()
+            .map(|(offset, node)| RemovedEntry {
+                position: snapshot.node_count + offset,
+                node_hash: node.node_hash,
+                txo_hash: node.txo_hash,
+                epoch_id: node.epoch_id,
+                zone: node.zone,
+                timestamp: node.timestamp,
+                txo: None,
+            })
+            .collect();
+        let discarded_snapshots = ledger
+            .snapshots()
+            .iter()
+            .filter(|s| s.epoch_id > target_epoch)
+            .map(|s| (s.epoch_id, s.merkle_root))
+            .collect();
+
+        Ok(Self {
+            from_epoch: ledger.latest_epoch(),
+            to_epoch: target_epoch,
+            reason,
+            root_before: ledger.get_current_root(),
+            root_after: snapshot.merkle_root,
+            discarded_snapshots,
+            removed,
+        })
+    }
+
+    /// Attach TXO details to removed entries whose hash matches
+    ///
+    /// Accepts TXOs as appended or as returned by `commit_txo` (with the
+    /// trailing COMMIT audit entry). Returns the number of entries resolved.
+    pub fn resolve(&mut self, txos: &[TXO]) -> usize {
+        let mut hashes: Vec<([u8; 32], RemovedTxo)> = Vec::with_capacity(txos.len() * 2);
+        for txo in txos {
+            let details = RemovedTxo {
+                txo_id: txo.txo_id,
+                operation_class: txo.operation_class,
+                sender_id: txo.sender.id,
+                receiver_id: txo.receiver.id,
+            };
+            hashes.push((txo.compute_hash(), details));
+            if txo.audit_trail.last().is_some_and(|e| e.action == "COMMIT") {
+                let mut appended = txo.clone();
+                appended.audit_trail.pop();
+                hashes.push((appended.compute_hash(), details));
+            }
+        }
+
+        for entry in self.removed.iter_mut().filter(|e| e.txo.is_none()) {
+            entry.txo = hashes.iter().find(|(h, _)| *h == entry.txo_hash).map(|(_, d)| *d);
+        }
+        self.removed.iter().filter(|e| e.txo.is_some()).count()
+    }
+
+    /// Senders and receivers of resolved entries, sorted and deduplicated
+    pub fn affected_identities(&self) -> Vec<[u8; 16]> {
+        let mut ids: Vec<[u8; 16]> = self
+            .removed
+            .iter()
+            .filter_map(|e| e.txo)
+            .flat_map(|t| [t.sender_id, t.receiver_id])
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// Roots the ledger passed through that the rollback reverts, newest first
+    pub fn reverted_roots(&self) -> Vec<[u8; 32]> {
+        self.removed.iter().rev().map(|e| e.node_hash).collect()
+    }
+
+    /// SHA3-256 over the ledger facts of the report
+    ///
+    /// Covers epochs, roots, discarded snapshots, removed entries and the
+    /// reason; resolved TXO details are derived data and excluded, so the
+    /// digest is the same whichever TXOs an operator could resolve.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = StreamingHasher::new();
+        hasher.update(b"AETHERNET-ROLLBACK-DIFF-v1");
+        hasher.update(&self.from_epoch.to_le_bytes());
+        hasher.update(&self.to_epoch.to_le_bytes());
+        hasher.update(&(self.reason.len() as u64).to_le_bytes());
+        hasher.update(self.reason.as_bytes());
+        hasher.update(&self.root_before);
+        hasher.update(&self.root_after);
+        hasher.update(&(self.discarded_snapshots.len() as u64).to_le_bytes());
+        for (epoch, root) in &self.discarded_snapshots {
+            hasher.update(&epoch.to_le_bytes());
+            hasher.update(root);
+        }
+        hasher.update(&(self.removed.len() as u64).to_le_bytes());
+        for entry in &self.removed {
+            hasher.update(&(entry.position as u64).to_le_bytes());
+            hasher.update(&entry.node_hash);
+            hasher.update(&entry.txo_hash);
+            hasher.update(&entry.epoch_id.to_le_bytes());
+            hasher.update(&[entry.zone]);
+            hasher.update(&entry.timestamp.to_le_bytes());
+        }
+        hasher.finalize_reset()
+    }
+
+    /// Build the unsigned rollback TXO linking the ledger to this report
+    ///
+    /// The payload content hash is the report digest. The TXO is
+    /// irreversible so the record survives later rollbacks; the operator
+    /// signs it and commits it after the rollback.
+    pub fn rollback_txo(&self, operator_id: [u8; 16], timestamp: u64) -> TXO {
+        let digest = self.digest();
+        let mut txo_id = [0u8; 16];
+        txo_id.copy_from_slice(&digest[..16]);
+
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: operator_id,
+            biokey_present: false,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        let receiver = Receiver { identity_type: IdentityType::System, id: [0u8; 16] };
+        let payload = Payload {
+            payload_type: PayloadType::Audit,
+            content_hash: digest,
+            encrypted: false,
+            envelope: None,
+        };
+
+        let mut txo = TXO::new(txo_id, sender, receiver, OperationClass::Compliance, payload);
+        txo.timestamp = timestamp;
+        txo.reversibility_flag = false;
+        txo
+    }
+
+    /// JSON export (fixed key order, hashes as lowercase hex)
+    pub fn to_json(&self) -> String {
+        let snapshots: Vec<String> = self
+            .discarded_snapshots
+            .iter()
+            .map(|(epoch, root)| format!("{{\"epoch_id\":{},\"root\":\"{}\"}}", epoch, hex(root)))
+            .collect();
+        let removed: Vec<String> = self
+            .removed
+            .iter()
+            .map(|e| {
+                let txo = match &e.txo {
+                    Some(t) => format!(
+                        "{{\"txo_id\":\"{}\",\"operation_class\":\"{:?}\",\"sender\":\"{}\",\"receiver\":\"{}\"}}",
+                        hex(&t.txo_id),
+                        t.operation_class,
+                        hex(&t.sender_id),
+                        hex(&t.receiver_id)
+                    ),
+                    None => String::from("null"),
+                };
+                format!(
+                    concat!(
+                        "{{\"position\":{},\"node_hash\":\"{}\",\"txo_hash\":\"{}\",",
+                        "\"epoch_id\":{},\"zone\":\"{}\",\"timestamp\":{},\"txo\":{}}}"
+                    ),
+                    e.position,
+                    hex(&e.node_hash),
+                    hex(&e.txo_hash),
+                    e.epoch_id,
+                    zone_name(e.zone),
+                    e.timestamp,
+                    txo
+                )
+            })
+            .collect();
+        let identities: Vec<String> =
+            self.affected_identities().iter().map(|id| format!("\"{}\"", hex(id))).collect();
+
+        format!(
+            concat!(
+                "{{\"from_epoch\":{},\"to_epoch\":{},\"reason\":\"{}\",\"root_before\":\"{}\",",
+                "\"root_after\":\"{}\",\"discarded_snapshots\":[{}],\"removed\":[{}],",
+                "\"affected_identities\":[{}],\"digest\":\"{}\"}}"
+            ),
+            self.from_epoch,
+            self.to_epoch,
+            json_escape(&self.reason),
+            hex(&self.root_before),
+            hex(&self.root_after),
+            snapshots.join(","),
+            removed.join(","),
+            identities.join(","),
+            hex(&self.digest()),
+        )
+    }
+
+    /// Human-readable report
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("Rollback: epoch {} -> {}\n", self.from_epoch, self.to_epoch));
+        out.push_str(&format!("Reason: {}\n", self.reason));
+        out.push_str(&format!("Root before: {}\n", hex(&self.root_before)));
+        out.push_str(&format!("Root after:  {}\n", hex(&self.root_after)));
+
+        out.push_str(&format!("Snapshots discarded: {}\n", self.discarded_snapshots.len()));
+        for (epoch, root) in &self.discarded_snapshots {
+            out.push_str(&format!("  epoch {} root {}\n", epoch, hex(root)));
+        }
+
+        let resolved = self.removed.iter().filter(|e| e.txo.is_some()).count();
+        out.push_str(&format!(
+            "Entries removed: {} ({} resolved)\n",
+            self.removed.len(),
+            resolved
+        ));
+        for e in &self.removed {
+            out.push_str(&format!(
+                "  #{} epoch {} {} t={} txo {}",
+                e.position,
+                e.epoch_id,
+                zone_name(e.zone),
+                e.timestamp,
+                hex(&e.txo_hash)
+            ));
+            if let Some(t) = &e.txo {
+                out.push_str(&format!(
+                    " {:?} {} -> {}",
+                    t.operation_class,
+                    hex(&t.sender_id),
+                    hex(&t.receiver_id)
+                ));
+            }
+            out.push('\n');
+        }
+
+        let identities = self.affected_identities();
+        out.push_str(&format!("Affected identities: {}\n", identities.len()));
+        for id in &identities {
+            out.push_str(&format!("  {}\n", hex(id)));
+        }
+        out.push_str(&format!("Report digest: {}\n", hex(&self.digest())));
+        out
+    }
+}
+
+/// Lowercase hex encoding
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn zone_name(zone: u8) -> &'static str {
+    Zone::ALL.get(zone as usize).map_or("?", |z| z.name())
+}
+
+/// Escape a string for a JSON string literal
+fn json_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtf::api::RTFContext;
+    use crate::txo::SignatureType;
+
+    fn make_txo(id: u8, sender: u8, timestamp: u64) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [sender; 16],
+            biokey_present: false,
+            fido2_signed: true,
+            zk_proof: None,
+        };
+        let receiver = Receiver { identity_type: IdentityType::Node, id: [9u8; 16] };
+        let payload = Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: [id; 32],
+            encrypted: false,
+            envelope: None,
+        };
+        let mut txo = TXO::new([id; 16], sender, receiver, OperationClass::Network, payload);
+        txo.timestamp = timestamp;
+        txo.sign_ed25519(SignatureType::Fido2, [3u8; 16], &[11u8; 32]);
+        txo
+    }
+
+    #[test]
+    fn test_rollback_report() {
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]));
+        let mut kept = make_txo(1, 1, 100);
+        ctx.execute_txo(&mut kept).unwrap();
+        ctx.commit_txo(&mut kept).unwrap();
+        ctx.ledger.create_snapshot(1, 100);
+        ctx.current_epoch = 1;
+        let root_at_snapshot = ctx.ledger.get_current_root();
+
+        let mut committed = Vec::new();
+        for (id, sender) in [(2u8, 4u8), (3, 5)] {
+            let mut txo = make_txo(id, sender, 100 + id as u64);
+            ctx.execute_txo(&mut txo).unwrap();
+            ctx.commit_txo(&mut txo).unwrap();
+            committed.push(txo);
+        }
+        ctx.ledger.create_snapshot(2, 110);
+        let root_before = ctx.ledger.get_current_root();
+
+        let mut diff = ctx.rollback_with_report(1, String::from("bad \"batch\"")).unwrap();
+        assert_eq!(ctx.ledger.get_current_root(), root_at_snapshot);
+        assert_eq!((diff.from_epoch, diff.to_epoch), (2, 1));
+        assert_eq!(diff.root_before, root_before);
+        assert_eq!(diff.root_after, root_at_snapshot);
+        assert_eq!(diff.discarded_snapshots, alloc::vec![(2, root_before)]);
+        assert_eq!(diff.removed.len(), 2);
+        assert_eq!(diff.removed[0].position, 1);
+        assert_eq!(diff.reverted_roots()[0], root_before);
+
+        // Identities come from TXOs the operator still holds
+        let digest = diff.digest();
+        assert!(diff.affected_identities().is_empty());
+        assert_eq!(diff.resolve(&committed), 2);
+        assert_eq!(diff.affected_identities(), alloc::vec![[4u8; 16], [5u8; 16], [9u8; 16]]);
+        assert_eq!(diff.digest(), digest);
+
+        let json = diff.to_json();
+        assert!(json.starts_with("{\"from_epoch\":2,\"to_epoch\":1,\"reason\":\"bad \\\"batch\\\"\""));
+        assert!(json.contains("\"operation_class\":\"Network\""));
+        let text = diff.to_text();
+        assert!(text.contains("Entries removed: 2 (2 resolved)\n"));
+        assert!(text.contains(&format!("Report digest: {}\n", hex(&digest))));
+
+        // The rollback TXO commits the report digest
+        let mut record = diff.rollback_txo([7u8; 16], 120);
+        record.sign_ed25519(SignatureType::Fido2, [7u8; 16], &[12u8; 32]);
+        ctx.execute_txo(&mut record).unwrap();
+        ctx.commit_txo(&mut record).unwrap();
+        assert_eq!(record.payload.content_hash, digest);
+        assert!(!record.reversibility_flag);
+
+        assert_eq!(
+            RollbackDiff::compute(&ctx.ledger, 5, String::new()).unwrap_err(),
+            RTFError::EpochNotFound
+        );
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Append-only, zone-aware, reversible ledger with Merkle tree structure.
 //! Implements zone promotion logic (Z0→Z1→Z2→Z3) and rollback capability.
+//! `ReplayCursor` walks the chain from any snapshot, re-deriving each node
+//! hash, so a node can recover from a crash or import a peer's export.
 
 #![no_std]
 
@@ -9,10 +11,11 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 use alloc::string::String;
-use sha3::{Digest, Sha3_256};
 use minicbor::{Encode, Decode};
 
 use crate::txo::TXO;
+use crate::hash::{HashStats, StreamingHasher};
+use crate::verify::ledger_node_hash_with;
 use crate::rtf::api::{Zone, RTFError};
 
 /// Merkle ledger node
@@ -51,6 +54,18 @@ impl LedgerNode {
         epoch_id: u64,
         zone: Zone,
         timestamp: u64,
+    ) -> Self {
+        Self::new_with(&mut StreamingHasher::new(), parent_hash, txo_hash, epoch_id, zone, timestamp)
+    }
+    
+    /// Create a ledger node, hashing on a reusable context
+    fn new_with(
+        hasher: &mut StreamingHasher,
+        parent_hash: [u8; 32],
+        txo_hash: [u8; 32],
+        epoch_id: u64,
+        zone: Zone,
+        timestamp: u64,
     ) -> Self {
         let zone_id = match zone {
             Zone::Z0 => 0,
@@ -59,17 +74,8 @@ impl LedgerNode {
             Zone::Z3 => 3,
         };
         
-        // Compute node hash
-        let mut hasher = Sha3_256::new();
-        hasher.update(&parent_hash);
-        hasher.update(&txo_hash);
-        hasher.update(&epoch_id.to_le_bytes());
-        hasher.update(&[zone_id]);
-        hasher.update(&timestamp.to_le_bytes());
-        
-        let result = hasher.finalize();
-        let mut node_hash = [0u8; 32];
-        node_hash.copy_from_slice(&result);
+        // Compute node hash (same function light clients use)
+        let node_hash = ledger_node_hash_with(hasher, &parent_hash, &txo_hash, epoch_id, zone_id, timestamp);
         
         Self {
             node_hash,
@@ -82,6 +88,11 @@ impl LedgerNode {
     }
 }
 
+/// Zone for a ledger node or snapshot zone byte
+fn zone_from_id(zone: u8) -> Option<Zone> {
+    Zone::ALL.get(zone as usize).copied()
+}
+
 /// Epoch snapshot for rollback
 #[derive(Debug, Clone, Encode, Decode)]
 pub struct EpochSnapshot {
@@ -106,6 +117,86 @@ pub struct EpochSnapshot {
     pub timestamp: u64,
 }
 
+/// Incremental verifier over a run of ledger nodes
+///
+/// Each node must link to the previous root and its hash must re-derive from
+/// its fields. The cursor yields `Err(RTFError::LedgerCorrupted)` once at
+/// the first node that fails and then stops.
+pub struct ReplayCursor<'a> {
+    /// Nodes still to replay
+    nodes: &'a [LedgerNode],
+    
+    /// Index of the next node
+    next: usize,
+    
+    /// Root after the last verified node
+    root: [u8; 32],
+    
+    /// Hashing context reused across nodes
+    hasher: StreamingHasher,
+    
+    /// Set once a node fails verification
+    failed: bool,
+}
+
+impl<'a> ReplayCursor<'a> {
+    /// Create a cursor over `nodes`, the first of which links to `anchor`
+    ///
+    /// # Arguments
+    /// * `anchor` - Root the run extends (genesis root or snapshot root)
+    /// * `nodes` - Ledger nodes in append order
+    pub fn new(anchor: [u8; 32], nodes: &'a [LedgerNode]) -> Self {
+        Self {
+            nodes,
+            next: 0,
+            root: anchor,
+            hasher: StreamingHasher::new(),
+            failed: false,
+        }
+    }
+    
+    /// Root after the last verified node
+    pub fn root(&self) -> [u8; 32] {
+        self.root
+    }
+    
+    /// Number of nodes verified so far
+    pub fn position(&self) -> usize {
+        self.next
+    }
+}
+
+impl<'a> Iterator for ReplayCursor<'a> {
+    type Item = Result<&'a LedgerNode, RTFError>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let node = self.nodes.get(self.next)?;
+        
+        let expected = ledger_node_hash_with(
+            &mut self.hasher,
+            &node.parent_hash,
+            &node.txo_hash,
+            node.epoch_id,
+            node.zone,
+            node.timestamp,
+        );
+        if node.parent_hash != self.root
+            || node.node_hash != expected
+            || zone_from_id(node.zone).is_none()
+        {
+            self.failed = true;
+            return Some(Err(RTFError::LedgerCorrupted));
+        }
+        
+        self.root = node.node_hash;
+        self.next += 1;
+        Some(Ok(node))
+    }
+}
+
 /// Merkle ledger - append-only with zone awareness
 pub struct MerkleLedger {
     /// Genesis root (immutable anchor)
@@ -122,6 +213,9 @@ pub struct MerkleLedger {
     
     /// Current zone
     current_zone: Zone,
+    
+    /// Hashing context reused across appends
+    hasher: StreamingHasher,
 }
 
 impl MerkleLedger {
@@ -145,6 +239,7 @@ impl MerkleLedger {
             nodes: Vec::new(),
             snapshots: alloc::vec![genesis_snapshot],
             current_zone: Zone::Z0,
+            hasher: StreamingHasher::new(),
         }
     }
     
@@ -154,9 +249,10 @@ impl MerkleLedger {
     /// * `txo` - Transaction object to append
     /// * `zone` - Current zone
     pub fn append_txo(&mut self, txo: &TXO, zone: Zone) {
-        let txo_hash = txo.compute_hash();
+        let txo_hash = txo.compute_hash_with(&mut self.hasher);
         
-        let node = LedgerNode::new(
+        let node = LedgerNode::new_with(
+            &mut self.hasher,
             self.current_root,
             txo_hash,
             txo.epoch_id,
@@ -230,14 +326,7 @@ impl MerkleLedger {
     /// * `Err(RTFError)` if promotion fails
     pub fn promote_zone(&mut self, target_zone: Zone) -> Result<(), RTFError> {
         // Validate zone transition
-        let valid_transition = match (self.current_zone, target_zone) {
-            (Zone::Z0, Zone::Z1) => true,
-            (Zone::Z1, Zone::Z2) => true,
-            (Zone::Z2, Zone::Z3) => true,
-            _ => false,
-        };
-        
-        if !valid_transition {
+        if self.current_zone.promotion_target() != Some(target_zone) {
             return Err(RTFError::InvalidZoneTransition);
         }
         
@@ -262,6 +351,11 @@ impl MerkleLedger {
         self.nodes.len()
     }
     
+    /// Hashing throughput counters for this ledger's appends
+    pub fn hash_stats(&self) -> HashStats {
+        self.hasher.stats()
+    }
+    
     /// Get current zone
     pub fn current_zone(&self) -> Zone {
         self.current_zone
@@ -298,6 +392,47 @@ impl MerkleLedger {
         true
     }
     
+    /// Replay the whole ledger from genesis, verifying each node
+    ///
+    /// # Arguments
+    /// * `visit` - Called with each node once it has been verified
+    ///
+    /// # Returns
+    /// * `Ok(count)` with the number of nodes replayed
+    /// * `Err(RTFError::LedgerCorrupted)` at the first node that fails, or if
+    ///   the chain does not end at the current root
+    pub fn replay<F: FnMut(&LedgerNode)>(&self, mut visit: F) -> Result<usize, RTFError> {
+        let mut cursor = ReplayCursor::new(self.genesis_root, &self.nodes);
+        for node in cursor.by_ref() {
+            visit(node?);
+        }
+        
+        if cursor.root() != self.current_root {
+            return Err(RTFError::LedgerCorrupted);
+        }
+        Ok(cursor.position())
+    }
+    
+    /// Cursor over the nodes appended after an epoch snapshot
+    ///
+    /// # Arguments
+    /// * `epoch_id` - Snapshot to start from
+    ///
+    /// # Returns
+    /// * `Ok(ReplayCursor)` anchored at the snapshot root
+    /// * `Err(RTFError::EpochNotFound)` if no snapshot exists for the epoch
+    pub fn replay_from(&self, epoch_id: u64) -> Result<ReplayCursor<'_>, RTFError> {
+        let snapshot = self.snapshots
+            .iter()
+            .find(|s| s.epoch_id == epoch_id)
+            .ok_or(RTFError::EpochNotFound)?;
+        let nodes = self.nodes
+            .get(snapshot.node_count..)
+            .ok_or(RTFError::LedgerCorrupted)?;
+        
+        Ok(ReplayCursor::new(snapshot.merkle_root, nodes))
+    }
+    
     /// Export ledger to CBOR
     pub fn to_cbor(&self) -> Result<Vec<u8>, minicbor::encode::Error<core::convert::Infallible>> {
         let mut buffer = Vec::new();
@@ -321,6 +456,87 @@ impl MerkleLedger {
         
         Ok(buffer)
     }
+    
+    /// Import a ledger exported by `to_cbor`
+    ///
+    /// Every node is replayed and verified before the ledger is returned.
+    /// The current zone is the highest zone recorded by a node or snapshot.
+    ///
+    /// # Arguments
+    /// * `bytes` - CBOR export, e.g. from a crash-safe store or a peer
+    ///
+    /// # Returns
+    /// * `Ok(MerkleLedger)` if the export decodes and its chain verifies
+    /// * `Err(RTFError::LedgerCorrupted)` otherwise
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, RTFError> {
+        let mut decoder = minicbor::Decoder::new(bytes);
+        let corrupted = |_| RTFError::LedgerCorrupted;
+        
+        if decoder.array().map_err(corrupted)? != Some(3) {
+            return Err(RTFError::LedgerCorrupted);
+        }
+        let genesis_root: [u8; 32] = decoder
+            .bytes()
+            .map_err(corrupted)?
+            .try_into()
+            .map_err(|_| RTFError::LedgerCorrupted)?;
+        
+        let node_count = decoder.array().map_err(corrupted)?.ok_or(RTFError::LedgerCorrupted)?;
+        let mut nodes = Vec::new();
+        for _ in 0..node_count {
+            nodes.push(decoder.decode::<LedgerNode>().map_err(corrupted)?);
+        }
+        
+        let snapshot_count = decoder.array().map_err(corrupted)?.ok_or(RTFError::LedgerCorrupted)?;
+        let mut snapshots = Vec::new();
+        for _ in 0..snapshot_count {
+            snapshots.push(decoder.decode::<EpochSnapshot>().map_err(corrupted)?);
+        }
+        
+        let mut cursor = ReplayCursor::new(genesis_root, &nodes);
+        let mut zone = 0;
+        for node in cursor.by_ref() {
+            zone = zone.max(node?.zone);
+        }
+        let current_root = cursor.root();
+        
+        for snapshot in &snapshots {
+            let root = match snapshot.node_count {
+                0 => Some(genesis_root),
+                n => nodes.get(n - 1).map(|node| node.node_hash),
+            };
+            if root != Some(snapshot.merkle_root) || zone_from_id(snapshot.zone).is_none() {
+                return Err(RTFError::LedgerCorrupted);
+            }
+            zone = zone.max(snapshot.zone);
+        }
+        
+        Ok(Self {
+            genesis_root,
+            current_root,
+            nodes,
+            snapshots,
+            current_zone: zone_from_id(zone).ok_or(RTFError::LedgerCorrupted)?,
+            hasher: StreamingHasher::new(),
+        })
+    }
+    
+    /// Ledger nodes, oldest first
+    pub fn nodes(&self) -> &[LedgerNode] {
+        &self.nodes
+    }
+    
+    /// Epoch snapshots, oldest first
+    pub fn snapshots(&self) -> &[EpochSnapshot] {
+        &self.snapshots
+    }
+    
+    /// Highest epoch recorded by a node or snapshot
+    pub fn latest_epoch(&self) -> u64 {
+        let nodes = self.nodes.iter().map(|n| n.epoch_id);
+        let snapshots = self.snapshots.iter().map(|s| s.epoch_id);
+        nodes.chain(snapshots).max().unwrap_or(0)
+    }
 }
 
 #[cfg(test)]
@@ -360,6 +576,7 @@ mod tests {
             payload_type: PayloadType::Genome,
             content_hash: [4u8; 32],
             encrypted: true,
+            envelope: None,
         };
         
         let txo = TXO::new(
@@ -374,6 +591,15 @@ mod tests {
         
         assert_eq!(ledger.node_count(), 1);
         assert_ne!(ledger.get_current_root(), genesis_root);
+        
+        // The reused hashing context produces the same node as fresh hashing
+        let fresh = LedgerNode::new(genesis_root, txo.compute_hash(), txo.epoch_id, Zone::Z1, txo.timestamp);
+        assert_eq!(ledger.get_current_root(), fresh.node_hash);
+        
+        ledger.append_txo(&txo, Zone::Z1);
+        let stats = ledger.hash_stats();
+        assert_eq!(stats.digests, 4);
+        assert_eq!(stats.bytes, 2 * (txo.encoded_size() as u64 + 32 + 32 + 8 + 1 + 8));
     }
     
     #[test]
@@ -398,6 +624,7 @@ mod tests {
             payload_type: PayloadType::Genome,
             content_hash: [4u8; 32],
             encrypted: true,
+            envelope: None,
         };
         
         // Append multiple TXOs
@@ -439,6 +666,7 @@ mod tests {
             payload_type: PayloadType::Genome,
             content_hash: [4u8; 32],
             encrypted: true,
+            envelope: None,
         };
         
         // Append TXO and create snapshot at epoch 1
@@ -493,4 +721,87 @@ mod tests {
         // Z2 -> Z0 (invalid)
         assert_eq!(ledger.promote_zone(Zone::Z0), Err(RTFError::InvalidZoneTransition));
     }
+    
+    fn sample_txo(seed: u8, epoch_id: u64) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [2u8; 16],
+            biokey_present: false,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        let receiver = Receiver {
+            identity_type: IdentityType::Node,
+            id: [3u8; 16],
+        };
+        let payload = Payload {
+            payload_type: PayloadType::Genome,
+            content_hash: [seed; 32],
+            encrypted: true,
+            envelope: None,
+        };
+        
+        let mut txo = TXO::new([seed; 16], sender, receiver, OperationClass::Genomic, payload);
+        txo.epoch_id = epoch_id;
+        txo
+    }
+    
+    #[test]
+    fn test_replay_from_snapshot() {
+        let mut ledger = MerkleLedger::new([1u8; 32]);
+        ledger.promote_zone(Zone::Z1).unwrap();
+        for i in 0..3 {
+            ledger.append_txo(&sample_txo(i, 1), Zone::Z1);
+        }
+        ledger.create_snapshot(1, 1000);
+        for i in 3..5 {
+            ledger.append_txo(&sample_txo(i, 2), Zone::Z1);
+        }
+        
+        let mut visited = Vec::new();
+        assert_eq!(ledger.replay(|node| visited.push(node.node_hash)), Ok(5));
+        assert_eq!(visited.last(), Some(&ledger.get_current_root()));
+        
+        // Replay from the epoch 1 snapshot covers only the later nodes
+        let mut cursor = ledger.replay_from(1).unwrap();
+        assert!(cursor.by_ref().all(|node| node.is_ok()));
+        assert_eq!(cursor.position(), 2);
+        assert_eq!(cursor.root(), ledger.get_current_root());
+        assert_eq!(ledger.replay_from(7).err(), Some(RTFError::EpochNotFound));
+        
+        // A tampered node stops the replay at that node
+        ledger.nodes[3].txo_hash = [9u8; 32];
+        let mut count = 0;
+        assert_eq!(ledger.replay(|_| count += 1), Err(RTFError::LedgerCorrupted));
+        assert_eq!(count, 3);
+        let mut cursor = ledger.replay_from(1).unwrap();
+        assert_eq!(cursor.next().map(|r| r.is_err()), Some(true));
+        assert!(cursor.next().is_none());
+    }
+    
+    #[test]
+    fn test_cbor_import() {
+        let mut ledger = MerkleLedger::new([1u8; 32]);
+        ledger.promote_zone(Zone::Z1).unwrap();
+        ledger.promote_zone(Zone::Z2).unwrap();
+        for i in 0..4 {
+            ledger.append_txo(&sample_txo(i, 2), Zone::Z2);
+        }
+        ledger.create_snapshot(2, 2000);
+        
+        let imported = MerkleLedger::from_cbor(&ledger.to_cbor().unwrap()).unwrap();
+        assert_eq!(imported.get_current_root(), ledger.get_current_root());
+        assert_eq!(imported.node_count(), 4);
+        assert_eq!(imported.current_zone(), Zone::Z2);
+        assert_eq!(imported.latest_epoch(), 2);
+        assert!(imported.verify_chain());
+        
+        // Tampering with an exported node is caught on import
+        ledger.nodes[1].timestamp += 1;
+        assert_eq!(
+            MerkleLedger::from_cbor(&ledger.to_cbor().unwrap()).err(),
+            Some(RTFError::LedgerCorrupted)
+        );
+        assert_eq!(MerkleLedger::from_cbor(&[0x80]).err(), Some(RTFError::LedgerCorrupted));
+    }
 }
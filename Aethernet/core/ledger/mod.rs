@@ -1,5 +1,7 @@
 //! Merkle ledger module
 
+pub mod analytics;
+pub mod diff;
 pub mod merkle_ledger;
 
 pub use merkle_ledger::*;
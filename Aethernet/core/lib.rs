@@ -7,8 +7,20 @@
 //! - **TXO (Transaction Object)**: CBOR-primary encoding with dual-control signatures
 //! - **RTF (Reversible Transaction Framework)**: Zone-aware execution with rollback
 //! - **Biokey**: Ephemeral key derivation from SNP loci with ZK proofs
-//! - **Merkle Ledger**: Append-only, snapshot-based rollback capability
-//! - **Compliance**: HIPAA and GDPR modules
+//! - **Merkle Ledger**: Append-only, snapshot-based rollback capability, with rolling analytics and rollback diff reports
+//! - **Compliance**: HIPAA and GDPR modules, with PHI/PII detection scanners and data residency enforcement
+//! - **Light Verify**: Stateless outcome verification for relying parties
+//! - **Notary**: Ledger-anchored receipts for arbitrary external documents
+//!
+//! # Cargo Features
+//!
+//! - `runtime` (default): RTF execution, Merkle ledger, compliance modules
+//! - `light-verify`: `verify::verify_outcome` only; build with
+//!   `default-features = false, features = ["light-verify"]` for a minimal
+//!   no_std verifier with no execution or ledger mutation paths
+//! - `webhooks`: HTTPS sinks for `rtf::dispatch` (file and Unix socket
+//!   sinks need only `std`)
+//! - `hw-accel`: hardware SHA3 (ARMv8.2) with runtime detection; see `hash`
 //!
 //! # Example
 //!
@@ -36,28 +48,49 @@ extern crate alloc;
 /// Transaction Object (TXO) module
 pub mod txo;
 
+/// Streaming SHA3-256 hashing module
+pub mod hash;
+
 /// Reversible Transaction Framework (RTF) module
+#[cfg(feature = "runtime")]
 pub mod rtf;
 
 /// Biokey derivation and ZKP verification module
 pub mod biokey;
 
 /// Merkle ledger module
+#[cfg(feature = "runtime")]
 pub mod ledger;
 
+/// Light-client outcome verification module
+#[cfg(feature = "light-verify")]
+pub mod verify;
+
+/// External document notarization module
+#[cfg(feature = "light-verify")]
+pub mod notary;
+
 /// HIPAA compliance module
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "runtime"))]
 pub mod hipaa;
 
 /// GDPR compliance module
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "runtime"))]
 pub mod gdpr;
 
+/// Golden wire-format fixtures
+#[cfg(test)]
+mod golden;
+
 // Re-export commonly used types
 pub use txo::{TXO, IdentityType, OperationClass, PayloadType, SignatureType};
+#[cfg(feature = "runtime")]
 pub use rtf::api::{RTFContext, Zone, RTFError};
+#[cfg(feature = "runtime")]
 pub use ledger::MerkleLedger;
 pub use biokey::derivation::EphemeralBiokey;
+#[cfg(feature = "light-verify")]
+pub use verify::verify_outcome;
 
 /// Aethernet version
 pub const VERSION: &str = "1.0.0";
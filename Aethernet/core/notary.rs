@@ -0,0 +1,322 @@
+//! Document Notarization
+//!
+//! Anchors arbitrary external documents (PDFs, datasets, ...) in the ledger
+//! without callers building TXOs by hand. `notarize` hashes the document,
+//! binds the digest and its metadata into a notary-signed Compliance TXO,
+//! commits it, and returns a self-contained receipt. `verify_receipt` checks
+//! a receipt against the document and is available to light clients.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::hash::StreamingHasher;
+use crate::txo::TXO;
+use crate::verify::{verify_outcome, InclusionProof, ProofBundle, TrustedRoots, VerifyError};
+
+#[cfg(feature = "runtime")]
+use crate::rtf::api::{RTFContext, RTFError, Zone};
+#[cfg(feature = "runtime")]
+use crate::txo::{
+    AuditEntry, IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender,
+    SignatureType,
+};
+
+/// Document to notarize or verify
+#[derive(Debug, Clone, Copy)]
+pub enum Document<'a> {
+    /// Raw document bytes, hashed with SHA3-256
+    Bytes(&'a [u8]),
+    /// SHA3-256 digest computed by the caller
+    Hash([u8; 32]),
+}
+
+impl Document<'_> {
+    /// SHA3-256 digest of the document
+    pub fn digest(&self) -> [u8; 32] {
+        match self {
+            Document::Bytes(bytes) => StreamingHasher::new().digest(bytes),
+            Document::Hash(hash) => *hash,
+        }
+    }
+}
+
+/// Caller-supplied description bound into the notarization
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotarizationMetadata {
+    /// Human-readable document name
+    pub name: String,
+    /// MIME type (e.g. `application/pdf`)
+    pub media_type: String,
+    /// Notarization time (Unix seconds), used as the TXO timestamp
+    pub notarized_at: u64,
+    /// Free-form key/value attributes, committed in order
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Proof that a document was notarized
+#[derive(Debug, Clone)]
+pub struct NotarizationReceipt {
+    /// SHA3-256 digest of the document
+    pub document_hash: [u8; 32],
+    /// Metadata as notarized
+    pub metadata: NotarizationMetadata,
+    /// Commitment over the digest and metadata, carried as the TXO content hash
+    pub commitment: [u8; 32],
+    /// Signed notarization TXO as committed
+    pub txo: TXO,
+    /// Ledger root after the notarization TXO was committed
+    pub ledger_root: [u8; 32],
+    /// Inclusion of the TXO under `ledger_root`
+    ///
+    /// Carries no links at issue time; append `ChainLink`s for later nodes
+    /// to verify against a newer published root.
+    pub inclusion: InclusionProof,
+}
+
+/// Receipt verification failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotaryError {
+    /// Document digest differs from the receipt
+    DocumentMismatch,
+    /// Commitment does not match the digest, metadata, or TXO payload
+    CommitmentMismatch,
+    /// Notarization TXO failed signature or inclusion checks
+    Verify(VerifyError),
+}
+
+impl From<VerifyError> for NotaryError {
+    fn from(err: VerifyError) -> Self {
+        NotaryError::Verify(err)
+    }
+}
+
+/// Notarize a document
+///
+/// The TXO ID is derived from the commitment, so notarizing the same
+/// document with the same metadata twice yields the same TXO.
+///
+/// # Arguments
+/// * `ctx` - RTF context to commit through
+/// * `document` - Document bytes or precomputed digest
+/// * `metadata` - Description bound into the commitment
+/// * `notary_id` - Notary UUID (TXO sender and signer)
+/// * `notary_secret_key` - Notary Ed25519 secret key
+///
+/// # Returns
+/// * The notarization receipt
+/// * `Err(RTFError)` if RTF rejects the notarization TXO
+#[cfg(feature = "runtime")]
+pub fn notarize(
+    ctx: &mut RTFContext,
+    document: Document<'_>,
+    metadata: NotarizationMetadata,
+    notary_id: [u8; 16],
+    notary_secret_key: &[u8; 32],
+) -> Result<NotarizationReceipt, RTFError> {
+    let document_hash = document.digest();
+    let commitment = notarization_commitment(&document_hash, &metadata);
+
+    let mut txo_id = [0u8; 16];
+    txo_id.copy_from_slice(&commitment[..16]);
+
+    let mut txo = TXO::new(
+        txo_id,
+        Sender {
+            identity_type: IdentityType::System,
+            id: notary_id,
+            biokey_present: false,
+            fido2_signed: false,
+            zk_proof: None,
+        },
+        Receiver { identity_type: IdentityType::System, id: [0u8; 16] },
+        OperationClass::Compliance,
+        Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: commitment,
+            encrypted: false,
+            envelope: None,
+        },
+    );
+    txo.timestamp = metadata.notarized_at;
+    txo.sign_ed25519(SignatureType::Fido2, notary_id, notary_secret_key);
+
+    ctx.execute_txo(&mut txo)?;
+    let parent_hash = ctx.ledger.get_current_root();
+    let audit_len = txo.audit_trail.len();
+    ctx.commit_txo(&mut txo)?;
+    txo.add_audit_entry(AuditEntry {
+        actor_id: notary_id,
+        action: String::from("NOTARIZE"),
+        timestamp: metadata.notarized_at,
+    });
+
+    let zone = match ctx.current_zone {
+        Zone::Z0 => 0,
+        Zone::Z1 => 1,
+        Zone::Z2 => 2,
+        Zone::Z3 => 3,
+    };
+
+    Ok(NotarizationReceipt {
+        document_hash,
+        metadata,
+        commitment,
+        txo,
+        ledger_root: ctx.ledger.get_current_root(),
+        inclusion: InclusionProof { parent_hash, zone, audit_len, links: Vec::new() },
+    })
+}
+
+/// Verify a notarization receipt
+///
+/// Checks that `document` matches the receipt, that the commitment binds
+/// the digest and metadata and is what the TXO carries, that the notary
+/// signed the TXO, and that the TXO chains to one of `roots`.
+///
+/// # Arguments
+/// * `receipt` - Receipt returned by `notarize`
+/// * `document` - Document bytes or digest being checked
+/// * `notary_public_key` - Notary Ed25519 public key
+/// * `roots` - Trusted ledger roots (typically includes `receipt.ledger_root`)
+pub fn verify_receipt(
+    receipt: &NotarizationReceipt,
+    document: Document<'_>,
+    notary_public_key: &[u8; 32],
+    roots: &TrustedRoots,
+) -> Result<(), NotaryError> {
+    if document.digest() != receipt.document_hash {
+        return Err(NotaryError::DocumentMismatch);
+    }
+
+    let commitment = notarization_commitment(&receipt.document_hash, &receipt.metadata);
+    if commitment != receipt.commitment || receipt.txo.payload.content_hash != commitment {
+        return Err(NotaryError::CommitmentMismatch);
+    }
+
+    let bundle = ProofBundle {
+        signer_keys: alloc::vec![(receipt.txo.sender.id, *notary_public_key)],
+        inclusion: receipt.inclusion.clone(),
+        attestation: None,
+    };
+    verify_outcome(&receipt.txo, &bundle, roots)?;
+    Ok(())
+}
+
+/// Commitment carried by the notarization TXO
+///
+/// Strings are length-prefixed so distinct metadata never collide.
+fn notarization_commitment(document_hash: &[u8; 32], metadata: &NotarizationMetadata) -> [u8; 32] {
+    fn field(hasher: &mut StreamingHasher, value: &str) {
+        hasher.update(&(value.len() as u64).to_le_bytes());
+        hasher.update(value.as_bytes());
+    }
+
+    let mut hasher = StreamingHasher::new();
+    hasher.update(b"AETHERNET-NOTARY-v1");
+    hasher.update(document_hash);
+    field(&mut hasher, &metadata.name);
+    field(&mut hasher, &metadata.media_type);
+    hasher.update(&metadata.notarized_at.to_le_bytes());
+    hasher.update(&(metadata.attributes.len() as u64).to_le_bytes());
+    for (key, value) in &metadata.attributes {
+        field(&mut hasher, key);
+        field(&mut hasher, value);
+    }
+    hasher.finalize_reset()
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::verify::ChainLink;
+    use ed25519_dalek::SigningKey;
+
+    const NOTARY_SECRET: [u8; 32] = [21u8; 32];
+    const NOTARY_ID: [u8; 16] = [5u8; 16];
+
+    fn metadata() -> NotarizationMetadata {
+        NotarizationMetadata {
+            name: String::from("trial-protocol.pdf"),
+            media_type: String::from("application/pdf"),
+            notarized_at: 1_700_000_000,
+            attributes: alloc::vec![(String::from("revision"), String::from("3"))],
+        }
+    }
+
+    #[test]
+    fn test_notarize_and_verify_receipt() {
+        let public = SigningKey::from_bytes(&NOTARY_SECRET).verifying_key().to_bytes();
+        let document = b"%PDF-1.7 protocol body";
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]));
+
+        let receipt = notarize(&mut ctx, Document::Bytes(document), metadata(), NOTARY_ID, &NOTARY_SECRET)
+            .unwrap();
+        assert_eq!(receipt.ledger_root, ctx.ledger.get_current_root());
+
+        let roots = TrustedRoots { ledger_roots: alloc::vec![receipt.ledger_root], attestation_commitment: None };
+        assert_eq!(verify_receipt(&receipt, Document::Bytes(document), &public, &roots), Ok(()));
+
+        // A caller-side digest verifies the same receipt
+        let digest = Document::Bytes(document).digest();
+        assert_eq!(verify_receipt(&receipt, Document::Hash(digest), &public, &roots), Ok(()));
+
+        // Different document
+        assert_eq!(
+            verify_receipt(&receipt, Document::Bytes(b"%PDF-1.7 forged"), &public, &roots),
+            Err(NotaryError::DocumentMismatch)
+        );
+
+        // Edited metadata no longer matches the commitment
+        let mut edited = receipt.clone();
+        edited.metadata.name = String::from("other.pdf");
+        assert_eq!(
+            verify_receipt(&edited, Document::Bytes(document), &public, &roots),
+            Err(NotaryError::CommitmentMismatch)
+        );
+
+        // Wrong notary key
+        let other = SigningKey::from_bytes(&[22u8; 32]).verifying_key().to_bytes();
+        assert!(matches!(
+            verify_receipt(&receipt, Document::Bytes(document), &other, &roots),
+            Err(NotaryError::Verify(VerifyError::Signature(_)))
+        ));
+
+        // Untrusted root
+        assert_eq!(
+            verify_receipt(&receipt, Document::Bytes(document), &public, &TrustedRoots::default()),
+            Err(NotaryError::Verify(VerifyError::NotIncluded))
+        );
+    }
+
+    #[test]
+    fn test_receipt_extends_to_later_root() {
+        let public = SigningKey::from_bytes(&NOTARY_SECRET).verifying_key().to_bytes();
+        let digest = [7u8; 32];
+        let mut ctx = RTFContext::new(Zone::Z2, MerkleLedger::new([0u8; 32]));
+
+        let mut receipt =
+            notarize(&mut ctx, Document::Hash(digest), metadata(), NOTARY_ID, &NOTARY_SECRET).unwrap();
+        let mut later = metadata();
+        later.notarized_at += 60;
+        let next = notarize(&mut ctx, Document::Hash([8u8; 32]), later, NOTARY_ID, &NOTARY_SECRET).unwrap();
+
+        let roots = TrustedRoots { ledger_roots: alloc::vec![next.ledger_root], attestation_commitment: None };
+        assert_eq!(
+            verify_receipt(&receipt, Document::Hash(digest), &public, &roots),
+            Err(NotaryError::Verify(VerifyError::NotIncluded))
+        );
+
+        let mut committed = next.txo.clone();
+        committed.audit_trail.truncate(next.inclusion.audit_len);
+        receipt.inclusion.links.push(ChainLink {
+            txo_hash: committed.compute_hash(),
+            epoch_id: next.txo.epoch_id,
+            zone: next.inclusion.zone,
+            timestamp: next.txo.timestamp,
+        });
+        assert_eq!(verify_receipt(&receipt, Document::Hash(digest), &public, &roots), Ok(()));
+    }
+}
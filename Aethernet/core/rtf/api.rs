@@ -12,7 +12,17 @@ use alloc::string::String;
 use core::result::Result;
 
 use crate::txo::{TXO, OperationClass, IdentityType};
+use crate::txo::ThresholdQuorum;
 use crate::ledger::MerkleLedger;
+use crate::rtf::capability::{
+    revoked_token, CapabilityAuthority, CapabilityToken, RevocationRecord, Submission,
+};
+use crate::ledger::analytics::LedgerAnalytics;
+use crate::ledger::diff::RollbackDiff;
+use crate::rtf::nonce::NonceAccounts;
+use crate::rtf::policy::PolicyEngine;
+use crate::rtf::residency::ResidencyPolicy;
+use crate::rtf::preauth::{PreAuthReceipt, PreAuthRecord, PreAuthRegistry, PreAuthorization};
 
 /// Zone identifier (Z0-Z3)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,6 +33,60 @@ pub enum Zone {
     Z3,  // Archive - air-gapped
 }
 
+impl Zone {
+    /// All zones in promotion order
+    pub const ALL: [Zone; 4] = [Zone::Z0, Zone::Z1, Zone::Z2, Zone::Z3];
+
+    /// Zone name as used in policy facts
+    pub fn name(self) -> &'static str {
+        match self {
+            Zone::Z0 => "Z0",
+            Zone::Z1 => "Z1",
+            Zone::Z2 => "Z2",
+            Zone::Z3 => "Z3",
+        }
+    }
+
+    /// Zone reachable from this one through `promote_zone`
+    pub fn promotion_target(self) -> Option<Zone> {
+        match self {
+            Zone::Z0 => Some(Zone::Z1),
+            Zone::Z1 => Some(Zone::Z2),
+            Zone::Z2 => Some(Zone::Z3),
+            Zone::Z3 => None,
+        }
+    }
+
+    /// Whether `rollback_txo` is permitted
+    pub fn allows_rollback(self) -> bool {
+        match self {
+            Zone::Z0 => false,  // Genesis is immutable
+            Zone::Z1 => true,   // Staging allows rollback
+            Zone::Z2 => true,   // Production allows emergency rollback
+            Zone::Z3 => false,  // Archive is immutable
+        }
+    }
+
+    /// Signatures a TXO must carry to execute
+    pub fn required_signatures(self) -> usize {
+        match self {
+            Zone::Z0 | Zone::Z1 => 0,
+            Zone::Z2 => 1,
+            Zone::Z3 => 2,
+        }
+    }
+
+    /// Whether TXOs of this operation class may execute
+    pub fn permits(self, operation: OperationClass) -> bool {
+        match self {
+            Zone::Z0 => operation == OperationClass::Admin,       // Genesis operations only
+            Zone::Z1 => true,                                     // Staging allows everything
+            Zone::Z2 => operation != OperationClass::Admin,       // No admin in production
+            Zone::Z3 => operation == OperationClass::Compliance,  // Audit operations only
+        }
+    }
+}
+
 /// RTF Error types
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RTFError {
@@ -42,6 +106,43 @@ pub enum RTFError {
     InvalidZoneTransition,
     /// Operation not allowed in current zone
     OperationNotAllowed,
+    /// Referenced consent is unknown, expired, or revoked
+    ConsentInvalid,
+    /// Active policy bundle did not authorize the TXO
+    PolicyDenied,
+    /// Capability token missing, invalid, revoked, or its caveats not met
+    CapabilityDenied,
+    /// Pre-authorization unknown, revoked, expired, exhausted, or out of scope
+    PreAuthorizationDenied,
+    /// Admin TXO lacks the configured M-of-N quorum signatures
+    QuorumNotMet,
+    /// Ledger node failed hash or chain-linkage verification
+    LedgerCorrupted,
+    /// HIPAA guard denied access to a PHI payload
+    PhiAccessDenied,
+    /// Nonce accounts are enabled but the TXO carries no nonce
+    MissingNonce,
+    /// Nonce already consumed (or already held) for this sender
+    StaleNonce {
+        /// Next nonce the sender's account accepts
+        expected: u64,
+        /// Nonce the TXO carried
+        got: u64,
+    },
+    /// Nonce ahead of the sender's account (beyond the reordering window on submission)
+    FutureNonce {
+        /// Next nonce the sender's account accepts
+        expected: u64,
+        /// Nonce the TXO carried
+        got: u64,
+    },
+    /// A due TXO from this sender failed; resubmit `nonce` before later ones
+    SenderStalled {
+        /// Nonce whose TXO failed and must be replaced
+        nonce: u64,
+    },
+    /// Payload residency requirement forbids the destination
+    ResidencyViolation,
 }
 
 /// RTF execution context
@@ -52,6 +153,20 @@ pub struct RTFContext {
     pub ledger: MerkleLedger,
     /// Current epoch
     pub current_epoch: u64,
+    /// Optional policy engine consulted on execution
+    pub policy: Option<PolicyEngine>,
+    /// Optional capability authority for delegated submission
+    pub capabilities: Option<CapabilityAuthority>,
+    /// Committed pre-authorizations and their remaining budgets
+    pub preauthorizations: PreAuthRegistry,
+    /// Optional M-of-N quorum required for admin (governance-level) TXOs
+    pub quorum: Option<ThresholdQuorum>,
+    /// Optional per-sender nonce accounts enforcing submission order
+    pub nonces: Option<NonceAccounts>,
+    /// Optional rolling statistics over commits and rollbacks
+    pub analytics: Option<LedgerAnalytics>,
+    /// Optional data residency policy checked when routing to the receiver
+    pub residency: Option<ResidencyPolicy>,
 }
 
 impl RTFContext {
@@ -61,8 +176,72 @@ impl RTFContext {
             current_zone: zone,
             ledger,
             current_epoch: 0,
+            policy: None,
+            capabilities: None,
+            preauthorizations: PreAuthRegistry::new(),
+            quorum: None,
+            nonces: None,
+            analytics: None,
+            residency: None,
         }
     }
+
+    /// Rebuild a context from a recovered or imported ledger
+    ///
+    /// The ledger is replayed from genesis; zone and epoch are restored from
+    /// the latest values it records. Policy, capabilities, quorum and
+    /// pre-authorizations are not part of the ledger and start empty, as do
+    /// nonce accounts, analytics and residency policy.
+    ///
+    /// # Arguments
+    /// * `ledger` - Ledger loaded from local storage or `MerkleLedger::from_cbor`
+    ///
+    /// # Returns
+    /// * `Ok(RTFContext)` if the ledger replays cleanly
+    /// * `Err(RTFError::LedgerCorrupted)` if any node fails verification
+    pub fn recover(ledger: MerkleLedger) -> Result<Self, RTFError> {
+        ledger.replay(|_| {})?;
+        
+        let mut ctx = Self::new(ledger.current_zone(), ledger);
+        ctx.current_epoch = ctx.ledger.latest_epoch();
+        Ok(ctx)
+    }
+
+    /// Attach a policy engine evaluated by `execute_txo`
+    pub fn with_policy(mut self, engine: PolicyEngine) -> Self {
+        self.policy = Some(engine);
+        self
+    }
+
+    /// Require an M-of-N threshold approval on admin TXOs
+    pub fn with_quorum(mut self, quorum: ThresholdQuorum) -> Self {
+        self.quorum = Some(quorum);
+        self
+    }
+
+    /// Require every executed TXO to carry its sender's next nonce
+    pub fn with_nonces(mut self, accounts: NonceAccounts) -> Self {
+        self.nonces = Some(accounts);
+        self
+    }
+
+    /// Record commits and rollbacks into ledger analytics
+    pub fn with_analytics(mut self, analytics: LedgerAnalytics) -> Self {
+        self.analytics = Some(analytics);
+        self
+    }
+
+    /// Refuse to route residency-tagged payloads to non-compliant receivers
+    pub fn with_residency(mut self, policy: ResidencyPolicy) -> Self {
+        self.residency = Some(policy);
+        self
+    }
+
+    /// Attach a capability authority checked by `execute_delegated`
+    pub fn with_capabilities(mut self, authority: CapabilityAuthority) -> Self {
+        self.capabilities = Some(authority);
+        self
+    }
     
     /// Execute a TXO - validate and prepare for commit
     ///
@@ -73,17 +252,41 @@ impl RTFContext {
     /// * `Ok(())` if execution succeeds
     /// * `Err(RTFError)` if validation fails
     pub fn execute_txo(&mut self, txo: &mut TXO) -> Result<(), RTFError> {
-        // Validate zone policy
+        // Validate zone policy (signatures included)
         self.validate_zone_policy(txo)?;
         
-        // Validate signatures
-        self.validate_signatures(txo)?;
+        self.admit(txo)
+    }
+
+    /// Dual control, policy and execution bookkeeping shared by all entry points
+    fn admit(&mut self, txo: &mut TXO) -> Result<(), RTFError> {
+        // Sender nonce must be next in line, if accounts are enabled
+        if let Some(accounts) = &self.nonces {
+            accounts.check(txo)?;
+        }
         
         // Check dual control if required
         if txo.dual_control_required && !txo.verify_dual_control() {
             return Err(RTFError::DualControlFailure);
         }
         
+        // Governance-level TXOs need the configured quorum, if any
+        if let (Some(quorum), OperationClass::Admin) = (&self.quorum, txo.operation_class) {
+            txo.verify_threshold(quorum).map_err(|_| RTFError::QuorumNotMet)?;
+        }
+        
+        // Evaluate declarative policy, if configured
+        if let Some(engine) = self.policy.as_mut() {
+            if !engine.authorize(txo, self.current_zone) {
+                return Err(RTFError::PolicyDenied);
+            }
+        }
+        
+        // Payload must be allowed to reside at the receiver, if constrained
+        if let Some(residency) = self.residency.as_mut() {
+            residency.check_route(txo)?;
+        }
+        
         // Set epoch from current context
         txo.epoch_id = self.current_epoch;
         
@@ -95,9 +298,211 @@ impl RTFContext {
         };
         txo.add_audit_entry(audit_entry);
         
+        // Consume the nonce only once every check has passed
+        if let Some(accounts) = self.nonces.as_mut() {
+            accounts.advance(txo.sender.id);
+        }
+        
         Ok(())
     }
     
+    /// Submit a TXO through its sender's nonce reordering window
+    ///
+    /// The TXO is held until every lower nonce from the same sender has
+    /// executed; then it and any held successors are executed and committed
+    /// in nonce order.
+    ///
+    /// # Arguments
+    /// * `txo` - Transaction object carrying a nonce
+    ///
+    /// # Returns
+    /// * TXOs committed by this submission, in nonce order (empty if held)
+    /// * `Err(RTFError::MissingNonce)` if nonce accounts are not enabled or the TXO has none
+    /// * `Err(RTFError::StaleNonce)` / `Err(RTFError::FutureNonce)` if the nonce is rejected
+    /// * `Err(RTFError::SenderStalled)` if an earlier due TXO failed and this is not its replacement
+    /// * The execution error of a due TXO; it is dropped, the sender is
+    ///   stalled and later ones stay held until that nonce is resubmitted
+    pub fn submit_txo(&mut self, txo: TXO) -> Result<Vec<TXO>, RTFError> {
+        let sender_id = txo.sender.id;
+        self.nonces.as_mut().ok_or(RTFError::MissingNonce)?.hold(txo)?;
+        
+        let mut committed = Vec::new();
+        while let Some(mut next) = self.nonces.as_mut().and_then(|n| n.pop_ready(&sender_id)) {
+            if let Err(e) = self.execute_txo(&mut next) {
+                if let Some(accounts) = self.nonces.as_mut() {
+                    accounts.stall(sender_id);
+                }
+                return Err(e);
+            }
+            self.commit_txo(&mut next)?;
+            committed.push(next);
+        }
+        Ok(committed)
+    }
+    
+    /// Execute a TXO submitted under a delegated capability token
+    ///
+    /// # Arguments
+    /// * `txo` - Transaction object to execute
+    /// * `token` - Capability token presented with the submission
+    /// * `payload_len` - Size of the submitted payload in bytes
+    /// * `received_at` - Ingestion time (Unix seconds), checked against expiry caveats
+    ///
+    /// # Returns
+    /// * `Ok(())` if the token authorizes the TXO and execution succeeds
+    /// * `Err(RTFError::CapabilityDenied)` if no authority is configured or the token fails
+    pub fn execute_delegated(
+        &mut self,
+        txo: &mut TXO,
+        token: &CapabilityToken,
+        payload_len: u64,
+        received_at: u64,
+    ) -> Result<(), RTFError> {
+        let authority = self.capabilities.as_ref().ok_or(RTFError::CapabilityDenied)?;
+        let submission = Submission {
+            operation: txo.operation_class,
+            zone: self.current_zone,
+            received_at,
+            payload_len,
+        };
+        authority
+            .verify(token, &submission)
+            .map_err(|_| RTFError::CapabilityDenied)?;
+
+        self.execute_txo(txo)
+    }
+
+    /// Commit a capability revocation and blacklist the token
+    ///
+    /// # Arguments
+    /// * `txo` - Signed TXO from `CapabilityAuthority::revocation_txo`
+    ///
+    /// # Returns
+    /// * `Ok(RevocationRecord)` once the revocation is on the ledger
+    /// * `Err(RTFError)` if the TXO is not a revocation or execution fails
+    pub fn revoke_capability(&mut self, txo: &mut TXO) -> Result<RevocationRecord, RTFError> {
+        let token_id = revoked_token(txo).map_err(|_| RTFError::CapabilityDenied)?;
+        if self.capabilities.is_none() {
+            return Err(RTFError::CapabilityDenied);
+        }
+        self.execute_txo(txo)?;
+        self.commit_txo(txo)?;
+
+        let ledger_root = self.ledger.get_current_root();
+        if let Some(authority) = self.capabilities.as_mut() {
+            authority.observe(txo, ledger_root);
+        }
+        Ok(RevocationRecord {
+            token_id,
+            txo_id: txo.txo_id,
+            ledger_root,
+        })
+    }
+
+    /// Commit a pre-authorization grant
+    ///
+    /// # Arguments
+    /// * `txo` - Issuer-signed TXO from `PreAuthorization::grant_txo`
+    /// * `grant` - Grant document the TXO commits to
+    ///
+    /// # Returns
+    /// * `Ok(PreAuthRecord)` once the grant is on the ledger
+    /// * `Err(RTFError)` if the TXO does not commit to the grant or execution fails
+    pub fn grant_preauthorization(
+        &mut self,
+        txo: &mut TXO,
+        grant: PreAuthorization,
+    ) -> Result<PreAuthRecord, RTFError> {
+        self.preauthorizations
+            .check_grant(txo, &grant)
+            .map_err(|_| RTFError::PreAuthorizationDenied)?;
+        self.execute_txo(txo)?;
+        self.commit_txo(txo)?;
+
+        let grant_id = grant.grant_id;
+        self.preauthorizations
+            .register(txo, grant)
+            .map_err(|_| RTFError::PreAuthorizationDenied)?;
+        Ok(PreAuthRecord {
+            grant_id,
+            txo_id: txo.txo_id,
+            ledger_root: self.ledger.get_current_root(),
+        })
+    }
+
+    /// Execute and commit a TXO under a pre-authorization
+    ///
+    /// The grant stands in for the zone's per-TXO signature requirement;
+    /// operation-class, dual-control and policy checks still apply.
+    ///
+    /// # Arguments
+    /// * `txo` - Transaction object to execute
+    /// * `grant_id` - Pre-authorization presented with the submission
+    /// * `payload_len` - Size of the submitted payload in bytes
+    /// * `received_at` - Ingestion time (Unix seconds), checked against the grant expiry
+    ///
+    /// # Returns
+    /// * `Ok(PreAuthReceipt)` with the remaining budget once the TXO is on the ledger
+    /// * `Err(RTFError::PreAuthorizationDenied)` if the grant does not cover the TXO
+    pub fn commit_preauthorized(
+        &mut self,
+        txo: &mut TXO,
+        grant_id: &[u8; 16],
+        payload_len: u64,
+        received_at: u64,
+    ) -> Result<PreAuthReceipt, RTFError> {
+        let submission = Submission {
+            operation: txo.operation_class,
+            zone: self.current_zone,
+            received_at,
+            payload_len,
+        };
+        self.preauthorizations
+            .authorize(grant_id, &txo.sender.id, &submission)
+            .map_err(|_| RTFError::PreAuthorizationDenied)?;
+        if !self.current_zone.permits(txo.operation_class) {
+            return Err(RTFError::OperationNotAllowed);
+        }
+        self.admit(txo)?;
+
+        let remaining = self
+            .preauthorizations
+            .consume(grant_id, txo)
+            .map_err(|_| RTFError::PreAuthorizationDenied)?;
+        self.commit_txo(txo)?;
+        Ok(PreAuthReceipt {
+            grant_id: *grant_id,
+            txo_id: txo.txo_id,
+            remaining,
+            ledger_root: self.ledger.get_current_root(),
+        })
+    }
+
+    /// Commit a counter-signed pre-authorization revocation
+    ///
+    /// # Arguments
+    /// * `txo` - TXO from `PreAuthorization::revocation_txo`, signed by the
+    ///   issuer and a second signer
+    ///
+    /// # Returns
+    /// * `Ok(PreAuthRecord)` once the revocation is on the ledger
+    /// * `Err(RTFError)` if the TXO is not a valid revocation or execution fails
+    pub fn revoke_preauthorization(&mut self, txo: &mut TXO) -> Result<PreAuthRecord, RTFError> {
+        let grant_id = self
+            .preauthorizations
+            .check_revocation(txo)
+            .map_err(|_| RTFError::PreAuthorizationDenied)?;
+        self.execute_txo(txo)?;
+        self.commit_txo(txo)?;
+
+        self.preauthorizations.observe(txo);
+        Ok(PreAuthRecord {
+            grant_id,
+            txo_id: txo.txo_id,
+            ledger_root: self.ledger.get_current_root(),
+        })
+    }
+
     /// Commit a TXO to the ledger
     ///
     /// # Arguments
@@ -107,6 +512,21 @@ impl RTFContext {
     /// * `Ok(())` if commit succeeds
     /// * `Err(RTFError)` if commit fails
     pub fn commit_txo(&mut self, txo: &mut TXO) -> Result<(), RTFError> {
+        let committed_at = txo.timestamp;
+        self.commit_txo_at(txo, committed_at)
+    }
+    
+    /// Commit a TXO to the ledger at an explicit commit time
+    ///
+    /// # Arguments
+    /// * `txo` - Transaction object to commit
+    /// * `committed_at` - Commit time (Unix seconds), recorded in the audit
+    ///   trail and used for commit latency in analytics
+    ///
+    /// # Returns
+    /// * `Ok(())` if commit succeeds
+    /// * `Err(RTFError)` if commit fails
+    pub fn commit_txo_at(&mut self, txo: &mut TXO, committed_at: u64) -> Result<(), RTFError> {
         // Add to ledger
         self.ledger.append_txo(txo, self.current_zone);
         
@@ -114,10 +534,14 @@ impl RTFContext {
         let audit_entry = crate::txo::AuditEntry {
             actor_id: txo.sender.id,
             action: String::from("COMMIT"),
-            timestamp: txo.timestamp,
+            timestamp: committed_at,
         };
         txo.add_audit_entry(audit_entry);
         
+        if let Some(analytics) = self.analytics.as_mut() {
+            analytics.record_commit(txo, self.current_zone, committed_at);
+        }
+        
         Ok(())
     }
     
@@ -142,7 +566,11 @@ impl RTFContext {
         }
         
         // Perform rollback on ledger
+        let nodes_before = self.ledger.node_count();
         self.ledger.rollback_to_epoch(target_epoch)?;
+        if let Some(analytics) = self.analytics.as_mut() {
+            analytics.record_rollback(target_epoch, (nodes_before - self.ledger.node_count()) as u64);
+        }
         
         // Update current epoch
         self.current_epoch = target_epoch;
@@ -150,42 +578,22 @@ impl RTFContext {
         Ok(())
     }
     
+    /// Rollback to a previous epoch, returning the forensic diff
+    ///
+    /// Same checks and effect as `rollback_txo`. The returned report lists
+    /// what was undone; commit its `rollback_txo` to link it from the ledger.
+    pub fn rollback_with_report(&mut self, target_epoch: u64, reason: String) -> Result<RollbackDiff, RTFError> {
+        let diff = RollbackDiff::compute(&self.ledger, target_epoch, reason.clone())?;
+        self.rollback_txo(target_epoch, reason)?;
+        Ok(diff)
+    }
+    
     /// Validate zone policy for TXO
     fn validate_zone_policy(&self, txo: &TXO) -> Result<(), RTFError> {
-        match self.current_zone {
-            Zone::Z0 => {
-                // Genesis zone - only genesis operations
-                if txo.operation_class != OperationClass::Admin {
-                    return Err(RTFError::OperationNotAllowed);
-                }
-            }
-            Zone::Z1 => {
-                // Staging zone - all operations allowed
-                // No signature requirements
-            }
-            Zone::Z2 => {
-                // Production zone - most operations allowed
-                // Single signature required
-                if txo.signatures.is_empty() {
-                    return Err(RTFError::MissingSignature);
-                }
-                // Admin operations not allowed
-                if txo.operation_class == OperationClass::Admin {
-                    return Err(RTFError::OperationNotAllowed);
-                }
-            }
-            Zone::Z3 => {
-                // Archive zone - only audit operations
-                // Dual signatures required
-                if txo.signatures.len() < 2 {
-                    return Err(RTFError::DualControlFailure);
-                }
-                if txo.operation_class != OperationClass::Compliance {
-                    return Err(RTFError::OperationNotAllowed);
-                }
-            }
+        self.validate_signatures(txo)?;
+        if !self.current_zone.permits(txo.operation_class) {
+            return Err(RTFError::OperationNotAllowed);
         }
-        
         Ok(())
     }
     
@@ -193,37 +601,17 @@ impl RTFContext {
     fn validate_signatures(&self, txo: &TXO) -> Result<(), RTFError> {
         // In production, this would verify Ed25519 signatures
         // For now, just check that required signatures exist
-        
-        match self.current_zone {
-            Zone::Z0 | Zone::Z1 => {
-                // No signature requirements
-                Ok(())
-            }
-            Zone::Z2 => {
-                // Single signature required
-                if txo.signatures.is_empty() {
-                    return Err(RTFError::MissingSignature);
-                }
-                Ok(())
-            }
-            Zone::Z3 => {
-                // Dual signatures required
-                if txo.signatures.len() < 2 {
-                    return Err(RTFError::DualControlFailure);
-                }
-                Ok(())
-            }
+        match self.current_zone.required_signatures() {
+            0 => Ok(()),
+            1 if txo.signatures.is_empty() => Err(RTFError::MissingSignature),
+            required if txo.signatures.len() < required => Err(RTFError::DualControlFailure),
+            _ => Ok(()),
         }
     }
     
     /// Check if current zone allows rollback
     fn zone_allows_rollback(&self) -> bool {
-        match self.current_zone {
-            Zone::Z0 => false,  // Genesis is immutable
-            Zone::Z1 => true,   // Staging allows rollback
-            Zone::Z2 => true,   // Production allows emergency rollback
-            Zone::Z3 => false,  // Archive is immutable
-        }
+        self.current_zone.allows_rollback()
     }
     
     /// Promote to next zone
@@ -236,14 +624,7 @@ impl RTFContext {
     /// * `Err(RTFError)` if promotion fails
     pub fn promote_zone(&mut self, target_zone: Zone) -> Result<(), RTFError> {
         // Validate zone transition
-        let valid_transition = match (self.current_zone, target_zone) {
-            (Zone::Z0, Zone::Z1) => true,
-            (Zone::Z1, Zone::Z2) => true,
-            (Zone::Z2, Zone::Z3) => true,
-            _ => false,
-        };
-        
-        if !valid_transition {
+        if self.current_zone.promotion_target() != Some(target_zone) {
             return Err(RTFError::InvalidZoneTransition);
         }
         
@@ -287,6 +668,7 @@ mod tests {
             payload_type: PayloadType::Genome,
             content_hash: [3u8; 32],
             encrypted: true,
+            envelope: None,
         };
         
         let mut txo = TXO::new(
@@ -323,6 +705,7 @@ mod tests {
             payload_type: PayloadType::Genome,
             content_hash: [3u8; 32],
             encrypted: true,
+            envelope: None,
         };
         
         let mut txo = TXO::new(
@@ -341,12 +724,50 @@ mod tests {
             sig_type: SignatureType::Fido2,
             signer_id: [5u8; 16],
             signature: vec![0u8; 64],
+            consent_ref: None,
         });
         
         // Should succeed with signature
         assert!(ctx.execute_txo(&mut txo).is_ok());
     }
     
+    #[test]
+    fn test_admin_requires_quorum() {
+        let secrets = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let members = secrets
+            .iter()
+            .enumerate()
+            .map(|(i, s)| ([i as u8; 16], ed25519_dalek::SigningKey::from_bytes(s).verifying_key().to_bytes()))
+            .collect();
+        let quorum = ThresholdQuorum::new(members, 2).unwrap();
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32])).with_quorum(quorum);
+        
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [1u8; 16],
+            biokey_present: false,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        let receiver = Receiver {
+            identity_type: IdentityType::System,
+            id: [2u8; 16],
+        };
+        let payload = Payload {
+            payload_type: PayloadType::Control,
+            content_hash: [3u8; 32],
+            encrypted: false,
+            envelope: None,
+        };
+        let mut txo = TXO::new([4u8; 16], sender, receiver, OperationClass::Admin, payload);
+        
+        txo.sign_ed25519(SignatureType::Threshold, [0u8; 16], &secrets[0]);
+        assert_eq!(ctx.execute_txo(&mut txo), Err(RTFError::QuorumNotMet));
+        
+        txo.sign_ed25519(SignatureType::Threshold, [2u8; 16], &secrets[2]);
+        assert!(ctx.execute_txo(&mut txo).is_ok());
+    }
+    
     #[test]
     fn test_zone_promotion() {
         let ledger = MerkleLedger::new([0u8; 32]);
@@ -376,6 +797,42 @@ mod tests {
         assert_eq!(ctx.current_epoch, 3);
     }
     
+    #[test]
+    fn test_recover_from_export() {
+        let ledger = MerkleLedger::new([0u8; 32]);
+        let mut ctx = RTFContext::new(Zone::Z0, ledger);
+        ctx.promote_zone(Zone::Z1).unwrap();
+        
+        for i in 0..3u8 {
+            let sender = Sender {
+                identity_type: IdentityType::Operator,
+                id: [1u8; 16],
+                biokey_present: false,
+                fido2_signed: false,
+                zk_proof: None,
+            };
+            let receiver = Receiver {
+                identity_type: IdentityType::Node,
+                id: [2u8; 16],
+            };
+            let payload = Payload {
+                payload_type: PayloadType::Genome,
+                content_hash: [i; 32],
+                encrypted: true,
+                envelope: None,
+            };
+            let mut txo = TXO::new([i; 16], sender, receiver, OperationClass::Genomic, payload);
+            ctx.execute_txo(&mut txo).unwrap();
+            ctx.commit_txo(&mut txo).unwrap();
+        }
+        
+        let export = ctx.ledger.to_cbor().unwrap();
+        let recovered = RTFContext::recover(MerkleLedger::from_cbor(&export).unwrap()).unwrap();
+        assert_eq!(recovered.current_zone, Zone::Z1);
+        assert_eq!(recovered.current_epoch, ctx.current_epoch);
+        assert_eq!(recovered.ledger.get_current_root(), ctx.ledger.get_current_root());
+    }
+    
     #[test]
     fn test_rollback_in_z0_fails() {
         let ledger = MerkleLedger::new([0u8; 32]);
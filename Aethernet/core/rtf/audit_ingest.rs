@@ -0,0 +1,204 @@
+//! RTF Audit Ingest
+//!
+//! Commits externally produced audit batches (e.g. Q-Substrate audit log
+//! exports) to the ledger as signed Compliance/Audit TXOs.
+//!
+//! The batch itself stays off-ledger: the TXO payload commits to its
+//! SHA3-256 hash, and the TXO id is derived from that hash and the batch
+//! number so resubmitting the same batch yields the same id. The returned
+//! `AuditReceipt` is what the producer records next to its local entries.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use sha3::{Digest, Sha3_256};
+
+use crate::rtf::api::{RTFContext, RTFError};
+use crate::txo::{
+    IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, SignatureType, TXO,
+};
+
+/// Domain separator for audit TXO ids
+const AUDIT_ID_DOMAIN: &[u8] = b"AETHERNET-AUDIT-BATCH-v1";
+
+/// Key used to sign audit TXOs
+#[derive(Clone)]
+pub struct AuditSigner {
+    /// Signer UUID
+    pub signer_id: [u8; 16],
+    /// Ed25519 secret key seed
+    pub secret_key: [u8; 32],
+}
+
+/// Ledger reference for a committed audit batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditReceipt {
+    /// Committed TXO id
+    pub txo_id: [u8; 16],
+    /// SHA3-256 of the batch bytes
+    pub content_hash: [u8; 32],
+    /// Ledger root after the commit
+    pub ledger_root: [u8; 32],
+    /// Epoch the TXO was executed in
+    pub epoch: u64,
+}
+
+/// Builds, signs and commits audit TXOs for one producer
+pub struct AuditIngestor {
+    /// Producing node
+    node_id: [u8; 16],
+    /// Signing keys; two or more make the TXO dual-control
+    signers: Vec<AuditSigner>,
+}
+
+impl AuditIngestor {
+    /// Create an ingestor for a producing node
+    ///
+    /// # Arguments
+    /// * `node_id` - Sender id recorded on every audit TXO
+    /// * `signers` - Keys that sign each TXO
+    pub fn new(node_id: [u8; 16], signers: Vec<AuditSigner>) -> Self {
+        Self { node_id, signers }
+    }
+
+    /// Build the signed audit TXO for a batch without committing it
+    ///
+    /// # Arguments
+    /// * `batch_id` - Producer-assigned batch number
+    /// * `batch` - Canonical batch bytes
+    /// * `timestamp` - Unix timestamp for the TXO
+    pub fn build_txo(&self, batch_id: u64, batch: &[u8], timestamp: u64) -> TXO {
+        let content_hash: [u8; 32] = Sha3_256::digest(batch).into();
+
+        let mut hasher = Sha3_256::new();
+        hasher.update(AUDIT_ID_DOMAIN);
+        hasher.update(self.node_id);
+        hasher.update(batch_id.to_le_bytes());
+        hasher.update(content_hash);
+        let digest = hasher.finalize();
+        let mut txo_id = [0u8; 16];
+        txo_id.copy_from_slice(&digest[..16]);
+
+        let sender = Sender {
+            identity_type: IdentityType::Node,
+            id: self.node_id,
+            biokey_present: false,
+            fido2_signed: !self.signers.is_empty(),
+            zk_proof: None,
+        };
+        let receiver = Receiver {
+            identity_type: IdentityType::System,
+            id: [0u8; 16],
+        };
+        let payload = Payload {
+            payload_type: PayloadType::Audit,
+            content_hash,
+            encrypted: false,
+            envelope: None,
+        };
+
+        let mut txo = TXO::new(txo_id, sender, receiver, OperationClass::Compliance, payload);
+        txo.timestamp = timestamp;
+        // Audit records are append-only
+        txo.reversibility_flag = false;
+        txo.dual_control_required = self.signers.len() >= 2;
+
+        for signer in &self.signers {
+            txo.sign_ed25519(SignatureType::Fido2, signer.signer_id, &signer.secret_key);
+        }
+        txo
+    }
+
+    /// Execute and commit a batch through the RTF context
+    ///
+    /// # Arguments
+    /// * `ctx` - Target RTF context
+    /// * `batch_id` - Producer-assigned batch number
+    /// * `batch` - Canonical batch bytes
+    /// * `timestamp` - Unix timestamp for the TXO
+    ///
+    /// # Returns
+    /// * `Ok(AuditReceipt)` referencing the committed TXO
+    /// * `Err(RTFError)` if the zone, signatures or policy reject it
+    pub fn ingest(
+        &self,
+        ctx: &mut RTFContext,
+        batch_id: u64,
+        batch: &[u8],
+        timestamp: u64,
+    ) -> Result<AuditReceipt, RTFError> {
+        let mut txo = self.build_txo(batch_id, batch, timestamp);
+        ctx.execute_txo(&mut txo)?;
+        ctx.commit_txo(&mut txo)?;
+
+        Ok(AuditReceipt {
+            txo_id: txo.txo_id,
+            content_hash: txo.payload.content_hash,
+            ledger_root: ctx.ledger.get_current_root(),
+            epoch: txo.epoch_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::Zone;
+    use alloc::vec;
+    use ed25519_dalek::SigningKey;
+
+    fn signer(n: u8) -> AuditSigner {
+        AuditSigner {
+            signer_id: [n; 16],
+            secret_key: [n; 32],
+        }
+    }
+
+    fn archive_context() -> RTFContext {
+        let mut ctx = RTFContext::new(Zone::Z0, MerkleLedger::new([0u8; 32]));
+        ctx.promote_zone(Zone::Z1).unwrap();
+        ctx.promote_zone(Zone::Z2).unwrap();
+        ctx.promote_zone(Zone::Z3).unwrap();
+        ctx
+    }
+
+    #[test]
+    fn test_ingest_dual_signed_batch_in_archive() {
+        let mut ctx = archive_context();
+        let before = ctx.ledger.get_current_root();
+        let ingestor = AuditIngestor::new([9u8; 16], vec![signer(1), signer(2)]);
+
+        let receipt = ingestor.ingest(&mut ctx, 0, b"batch-0", 1_700_000_000).unwrap();
+        assert_ne!(receipt.ledger_root, before);
+        assert_eq!(receipt.ledger_root, ctx.ledger.get_current_root());
+        assert_eq!(receipt.epoch, ctx.current_epoch);
+        assert_eq!(receipt.content_hash, <[u8; 32]>::from(Sha3_256::digest(b"batch-0")));
+
+        // Signatures verify against the canonical signing bytes
+        let txo = ingestor.build_txo(0, b"batch-0", 1_700_000_000);
+        assert_eq!(txo.txo_id, receipt.txo_id);
+        let public = SigningKey::from_bytes(&[1u8; 32]).verifying_key().to_bytes();
+        assert!(txo.verify_ed25519(&txo.signing_bytes(), &txo.signatures[0], &public).is_ok());
+    }
+
+    #[test]
+    fn test_ids_distinguish_batches() {
+        let ingestor = AuditIngestor::new([9u8; 16], vec![signer(1)]);
+        let a = ingestor.build_txo(0, b"same", 1);
+        let b = ingestor.build_txo(1, b"same", 1);
+        assert_ne!(a.txo_id, b.txo_id);
+        assert!(!a.reversibility_flag);
+        assert!(!a.dual_control_required);
+    }
+
+    #[test]
+    fn test_single_signer_rejected_in_archive() {
+        let mut ctx = archive_context();
+        let ingestor = AuditIngestor::new([9u8; 16], vec![signer(1)]);
+        assert_eq!(
+            ingestor.ingest(&mut ctx, 0, b"batch-0", 1),
+            Err(RTFError::DualControlFailure)
+        );
+    }
+}
@@ -0,0 +1,466 @@
+//! RTF Capability Tokens
+//!
+//! Macaroon-style delegation of TXO submission rights. A root key holder
+//! mints a token for a service; the service (or anyone it hands the token
+//! to) may append further caveats but can never remove one. Each caveat
+//! extends a chain of HMAC-SHA3-256 tags:
+//!
+//! ```text
+//! tag_0 = HMAC(root_key, "AETHERNET-CAPABILITY-v1" || token_id)
+//! tag_i = HMAC(tag_{i-1}, cbor(caveat_i))
+//! ```
+//!
+//! `RTFContext::execute_delegated` recomputes the chain from the root key
+//! and checks every caveat against the submission before normal execution.
+//! Caveats only narrow: a token is valid for a request iff all of its
+//! caveats hold.
+//!
+//! Tokens are revoked by committing a non-reversible Compliance/Control TXO
+//! naming the token id. The blacklist is rebuilt from those TXOs on replay
+//! (`CapabilityAuthority::observe`) and survives ledger rollback.
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256};
+
+use crate::rtf::api::Zone;
+use crate::txo::{IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, TXO};
+
+/// Domain separator for the first tag in the chain
+const TOKEN_DOMAIN: &[u8] = b"AETHERNET-CAPABILITY-v1";
+
+/// Domain separator for revocation payload commitments
+const REVOCATION_DOMAIN: &[u8] = b"AETHERNET-CAPABILITY-REVOKE-v1";
+
+/// SHA3-256 rate in bytes (HMAC block size)
+const HMAC_BLOCK: usize = 136;
+
+/// Restriction attached to a capability token
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+pub enum Caveat {
+    /// TXO must have this operation class
+    #[n(0)]
+    Operation(#[n(0)] OperationClass),
+    /// Context must be in this zone (0=Z0 .. 3=Z3)
+    #[n(1)]
+    Zone(#[n(0)] u8),
+    /// Submission must arrive strictly before this Unix timestamp
+    #[n(2)]
+    ExpiresAt(#[n(0)] u64),
+    /// Submitted payload may not exceed this many bytes
+    #[n(3)]
+    MaxPayloadSize(#[n(0)] u64),
+}
+
+/// Bearer token granting narrowed TXO submission rights
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct CapabilityToken {
+    /// Token identifier (revocation handle)
+    #[n(0)]
+    pub token_id: [u8; 16],
+
+    /// Caveats in chain order
+    #[n(1)]
+    pub caveats: Vec<Caveat>,
+
+    /// Final tag of the HMAC chain
+    #[n(2)]
+    pub tag: [u8; 32],
+}
+
+impl CapabilityToken {
+    /// Append a caveat, narrowing the token
+    ///
+    /// Needs no root key: the new tag is keyed by the current one.
+    pub fn attenuate(mut self, caveat: Caveat) -> Self {
+        self.tag = hmac(&self.tag, &caveat_bytes(&caveat));
+        self.caveats.push(caveat);
+        self
+    }
+
+    /// Serialize to CBOR
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserialize from CBOR
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, CapabilityError> {
+        minicbor::decode(bytes).map_err(|_| CapabilityError::Malformed)
+    }
+}
+
+/// Capability verification failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapabilityError {
+    /// Token bytes are not a CBOR capability token
+    Malformed,
+    /// HMAC chain does not verify under the root key
+    InvalidTag,
+    /// Token id is on the revocation blacklist
+    Revoked,
+    /// An expiry caveat has passed
+    Expired,
+    /// An operation caveat excludes the TXO's operation class
+    OperationNotPermitted,
+    /// A zone caveat excludes the current zone
+    ZoneNotPermitted,
+    /// A payload-size caveat is exceeded
+    PayloadTooLarge,
+    /// TXO is not a capability revocation
+    NotRevocation,
+}
+
+/// Submission attributes checked against caveats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Submission {
+    /// Operation class of the submitted TXO
+    pub operation: OperationClass,
+    /// Zone of the ingesting context
+    pub zone: Zone,
+    /// Ingestion time (Unix seconds)
+    pub received_at: u64,
+    /// Size of the submitted payload in bytes
+    pub payload_len: u64,
+}
+
+/// Blacklist entry backed by a committed revocation TXO
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RevocationRecord {
+    /// Revoked token
+    pub token_id: [u8; 16],
+    /// Revocation TXO id
+    pub txo_id: [u8; 16],
+    /// Ledger root after the revocation was committed
+    pub ledger_root: [u8; 32],
+}
+
+/// Root key holder: mints tokens and verifies them at ingestion
+#[derive(Clone)]
+pub struct CapabilityAuthority {
+    root_key: [u8; 32],
+    revoked: BTreeSet<[u8; 16]>,
+    revocations: Vec<RevocationRecord>,
+}
+
+impl CapabilityAuthority {
+    /// Create an authority over a root key
+    pub fn new(root_key: [u8; 32]) -> Self {
+        Self {
+            root_key,
+            revoked: BTreeSet::new(),
+            revocations: Vec::new(),
+        }
+    }
+
+    /// Mint a token with initial caveats
+    ///
+    /// # Arguments
+    /// * `token_id` - Unique token identifier
+    /// * `caveats` - Restrictions applied at mint time
+    pub fn mint(&self, token_id: [u8; 16], caveats: &[Caveat]) -> CapabilityToken {
+        let mut seed = Vec::with_capacity(TOKEN_DOMAIN.len() + 16);
+        seed.extend_from_slice(TOKEN_DOMAIN);
+        seed.extend_from_slice(&token_id);
+
+        let root = CapabilityToken {
+            token_id,
+            caveats: Vec::new(),
+            tag: hmac(&self.root_key, &seed),
+        };
+        caveats.iter().fold(root, |token, caveat| token.attenuate(*caveat))
+    }
+
+    /// Verify a token for a submission
+    ///
+    /// # Returns
+    /// * `Ok(())` if the chain verifies, the token is not revoked and every caveat holds
+    /// * `Err(CapabilityError)` naming the first failed check
+    pub fn verify(&self, token: &CapabilityToken, submission: &Submission) -> Result<(), CapabilityError> {
+        let expected = self.mint(token.token_id, &token.caveats);
+        if !constant_time_eq(&expected.tag, &token.tag) {
+            return Err(CapabilityError::InvalidTag);
+        }
+        if self.revoked.contains(&token.token_id) {
+            return Err(CapabilityError::Revoked);
+        }
+
+        check_caveats(&token.caveats, submission)
+    }
+
+    /// Check whether a token id is blacklisted
+    pub fn is_revoked(&self, token_id: &[u8; 16]) -> bool {
+        self.revoked.contains(token_id)
+    }
+
+    /// Build the unsigned revocation TXO for a token
+    ///
+    /// Callers sign it as the target zone requires, then commit it with
+    /// `RTFContext::revoke_capability`.
+    ///
+    /// # Arguments
+    /// * `token_id` - Token to revoke
+    /// * `issuer_id` - Sender id recorded on the TXO
+    /// * `timestamp` - Unix timestamp for the TXO
+    pub fn revocation_txo(&self, token_id: [u8; 16], issuer_id: [u8; 16], timestamp: u64) -> TXO {
+        let content_hash = revocation_commitment(&token_id);
+        let mut txo_id = [0u8; 16];
+        txo_id.copy_from_slice(&content_hash[..16]);
+
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: issuer_id,
+            biokey_present: false,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        // The revoked token id travels in the receiver so replay can recover it
+        let receiver = Receiver {
+            identity_type: IdentityType::System,
+            id: token_id,
+        };
+        let payload = Payload {
+            payload_type: PayloadType::Control,
+            content_hash,
+            encrypted: false,
+            envelope: None,
+        };
+
+        let mut txo = TXO::new(txo_id, sender, receiver, OperationClass::Compliance, payload);
+        txo.timestamp = timestamp;
+        // Revocations must outlive any rollback
+        txo.reversibility_flag = false;
+        txo
+    }
+
+    /// Record a committed TXO, blacklisting the token if it is a revocation
+    ///
+    /// Used both after `RTFContext::revoke_capability` commits and when
+    /// replaying ledger TXOs on restart.
+    ///
+    /// # Returns
+    /// * `Some(token_id)` if the TXO revoked a token
+    pub fn observe(&mut self, txo: &TXO, ledger_root: [u8; 32]) -> Option<[u8; 16]> {
+        let token_id = revoked_token(txo).ok()?;
+        if self.revoked.insert(token_id) {
+            self.revocations.push(RevocationRecord {
+                token_id,
+                txo_id: txo.txo_id,
+                ledger_root,
+            });
+        }
+        Some(token_id)
+    }
+
+    /// Applied revocations, oldest first
+    pub fn revocations(&self) -> &[RevocationRecord] {
+        &self.revocations
+    }
+}
+
+/// Token id revoked by a TXO
+///
+/// # Returns
+/// * `Err(CapabilityError::NotRevocation)` unless the TXO is a well-formed revocation
+pub fn revoked_token(txo: &TXO) -> Result<[u8; 16], CapabilityError> {
+    let token_id = txo.receiver.id;
+    let well_formed = txo.operation_class == OperationClass::Compliance
+        && txo.payload.payload_type == PayloadType::Control
+        && txo.receiver.identity_type == IdentityType::System
+        && !txo.reversibility_flag
+        && txo.payload.content_hash == revocation_commitment(&token_id);
+    if well_formed {
+        Ok(token_id)
+    } else {
+        Err(CapabilityError::NotRevocation)
+    }
+}
+
+/// Check every caveat against a submission
+///
+/// # Returns
+/// * `Err(CapabilityError)` naming the first caveat that does not hold
+pub(crate) fn check_caveats(caveats: &[Caveat], submission: &Submission) -> Result<(), CapabilityError> {
+    let zone = zone_index(submission.zone);
+    for caveat in caveats {
+        match *caveat {
+            Caveat::Operation(op) if op != submission.operation => {
+                return Err(CapabilityError::OperationNotPermitted)
+            }
+            Caveat::Zone(z) if z != zone => return Err(CapabilityError::ZoneNotPermitted),
+            Caveat::ExpiresAt(t) if submission.received_at >= t => {
+                return Err(CapabilityError::Expired)
+            }
+            Caveat::MaxPayloadSize(max) if submission.payload_len > max => {
+                return Err(CapabilityError::PayloadTooLarge)
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Payload commitment of a revocation TXO
+fn revocation_commitment(token_id: &[u8; 16]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(REVOCATION_DOMAIN);
+    hasher.update(token_id);
+    hasher.finalize().into()
+}
+
+/// Ledger zone encoding
+fn zone_index(zone: Zone) -> u8 {
+    match zone {
+        Zone::Z0 => 0,
+        Zone::Z1 => 1,
+        Zone::Z2 => 2,
+        Zone::Z3 => 3,
+    }
+}
+
+/// MAC input for a caveat
+fn caveat_bytes(caveat: &Caveat) -> Vec<u8> {
+    minicbor::to_vec(caveat).unwrap_or_default()
+}
+
+/// HMAC-SHA3-256 (RFC 2104)
+fn hmac(key: &[u8; 32], message: &[u8]) -> [u8; 32] {
+    let mut ipad = [0x36u8; HMAC_BLOCK];
+    let mut opad = [0x5cu8; HMAC_BLOCK];
+    for (i, byte) in key.iter().enumerate() {
+        ipad[i] ^= byte;
+        opad[i] ^= byte;
+    }
+
+    let inner = Sha3_256::new().chain_update(ipad).chain_update(message).finalize();
+    Sha3_256::new().chain_update(opad).chain_update(inner).finalize().into()
+}
+
+/// Compare tags without an early exit
+fn constant_time_eq(a: &[u8; 32], b: &[u8; 32]) -> bool {
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::{RTFContext, RTFError};
+    use crate::txo::SignatureType;
+    use alloc::string::String;
+
+    fn submission() -> Submission {
+        Submission {
+            operation: OperationClass::Genomic,
+            zone: Zone::Z1,
+            received_at: 1_000,
+            payload_len: 512,
+        }
+    }
+
+    fn service_txo() -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Node,
+            id: [1u8; 16],
+            biokey_present: false,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        let receiver = Receiver {
+            identity_type: IdentityType::Node,
+            id: [2u8; 16],
+        };
+        let payload = Payload {
+            payload_type: PayloadType::Genome,
+            content_hash: [3u8; 32],
+            encrypted: true,
+            envelope: None,
+        };
+        TXO::new([4u8; 16], sender, receiver, OperationClass::Genomic, payload)
+    }
+
+    #[test]
+    fn test_caveats_narrow_token() {
+        let authority = CapabilityAuthority::new([7u8; 32]);
+        let token = authority.mint([1u8; 16], &[Caveat::Operation(OperationClass::Genomic)]);
+        assert_eq!(authority.verify(&token, &submission()), Ok(()));
+
+        // The holder attenuates without the root key
+        let token = token
+            .attenuate(Caveat::Zone(1))
+            .attenuate(Caveat::ExpiresAt(1_000))
+            .attenuate(Caveat::MaxPayloadSize(256));
+        let decoded = CapabilityToken::from_cbor(&token.to_cbor()).unwrap();
+        assert_eq!(decoded, token);
+
+        let mut request = submission();
+        assert_eq!(authority.verify(&token, &request), Err(CapabilityError::Expired));
+        request.received_at = 999;
+        assert_eq!(authority.verify(&token, &request), Err(CapabilityError::PayloadTooLarge));
+        request.payload_len = 256;
+        assert_eq!(authority.verify(&token, &request), Ok(()));
+        request.zone = Zone::Z2;
+        assert_eq!(authority.verify(&token, &request), Err(CapabilityError::ZoneNotPermitted));
+        request.zone = Zone::Z1;
+        request.operation = OperationClass::Admin;
+        assert_eq!(authority.verify(&token, &request), Err(CapabilityError::OperationNotPermitted));
+    }
+
+    #[test]
+    fn test_tampered_chain_rejected() {
+        let authority = CapabilityAuthority::new([7u8; 32]);
+        let token = authority
+            .mint([1u8; 16], &[Caveat::Operation(OperationClass::Genomic)])
+            .attenuate(Caveat::MaxPayloadSize(16));
+
+        // Dropping or editing a caveat breaks the chain
+        let mut stripped = token.clone();
+        stripped.caveats.pop();
+        assert_eq!(authority.verify(&stripped, &submission()), Err(CapabilityError::InvalidTag));
+
+        let mut edited = token.clone();
+        edited.caveats[1] = Caveat::MaxPayloadSize(u64::MAX);
+        assert_eq!(authority.verify(&edited, &submission()), Err(CapabilityError::InvalidTag));
+
+        let forged = CapabilityAuthority::new([8u8; 32]).mint([1u8; 16], &[]);
+        assert_eq!(authority.verify(&forged, &submission()), Err(CapabilityError::InvalidTag));
+    }
+
+    #[test]
+    fn test_delegated_execution_and_revocation() {
+        let authority = CapabilityAuthority::new([7u8; 32]);
+        let token = authority.mint([1u8; 16], &[Caveat::Operation(OperationClass::Genomic)]);
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]))
+            .with_capabilities(authority.clone());
+
+        let mut txo = service_txo();
+        assert!(ctx.execute_delegated(&mut txo, &token, 512, 1_000).is_ok());
+
+        let mut revocation = authority.revocation_txo([1u8; 16], [9u8; 16], 1_001);
+        revocation.sign_ed25519(SignatureType::Fido2, [9u8; 16], &[9u8; 32]);
+        let record = ctx.revoke_capability(&mut revocation).unwrap();
+        assert_eq!(record.ledger_root, ctx.ledger.get_current_root());
+
+        let mut txo = service_txo();
+        assert_eq!(
+            ctx.execute_delegated(&mut txo, &token, 512, 1_002),
+            Err(RTFError::CapabilityDenied)
+        );
+
+        // Replaying the committed revocation rebuilds the blacklist
+        let mut replayed = CapabilityAuthority::new([7u8; 32]);
+        assert_eq!(replayed.observe(&revocation, record.ledger_root), Some([1u8; 16]));
+        assert!(replayed.is_revoked(&[1u8; 16]));
+        assert_eq!(replayed.observe(&service_txo(), [0u8; 32]), None);
+
+        // Revocations survive rolling the ledger back past them
+        assert!(ctx.rollback_txo(0, String::from("rewind")).is_ok());
+        let mut txo = service_txo();
+        assert_eq!(
+            ctx.execute_delegated(&mut txo, &token, 512, 1_003),
+            Err(RTFError::CapabilityDenied)
+        );
+    }
+}
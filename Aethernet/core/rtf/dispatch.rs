@@ -0,0 +1,885 @@
+//! RTF Event Dispatcher
+//!
+//! Pushes committed TXOs to operator-configured sinks: HTTPS webhooks with
+//! HMAC-SHA3-256 signing, local Unix sockets, and append-only files. Each
+//! sink selects TXOs with a filter expression over operation class, zone and
+//! identity:
+//!
+//! ```text
+//! class = Compliance & zone = Z2
+//! (sender = 05050505050505050505050505050505 | identity = System) & !class = Admin
+//! ```
+//!
+//! Failed deliveries are retried with exponential backoff on a caller-driven
+//! clock (`poll`), and every attempt is recorded in the delivery audit log.
+//! HTTPS delivery needs the `webhooks` feature; the other sinks need only std.
+
+use std::boxed::Box;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::string::String;
+use std::vec::Vec;
+
+use sha3::{Digest, Sha3_256};
+
+use crate::rtf::api::Zone;
+use crate::txo::{IdentityType, OperationClass, PayloadType, TXO};
+
+/// SHA3-256 rate in bytes (HMAC block size)
+const HMAC_BLOCK: usize = 136;
+
+/// Dispatcher configuration error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DispatchError {
+    /// Filter expression failed to parse at the given byte offset
+    Filter {
+        /// 0-based byte offset
+        offset: usize,
+    },
+    /// A sink with this name is already registered
+    DuplicateSink(String),
+    /// Webhook URL is not `https://`
+    InsecureWebhook,
+    /// Webhook has an empty HMAC secret
+    MissingSecret,
+    /// Retry policy allows no attempts
+    InvalidRetryPolicy,
+    /// Transport cannot deliver to this sink kind
+    UnsupportedSink,
+}
+
+/// Single delivery attempt failure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryError {
+    /// Connection or write failed
+    Io(String),
+    /// Webhook answered with a non-success HTTP status
+    Status(u16),
+    /// Sink kind not available in this build or platform
+    Unsupported,
+}
+
+/// TXO selection expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpr {
+    /// Matches every TXO (`*` or an empty expression)
+    Any,
+    /// `class = <OperationClass>`
+    Class(OperationClass),
+    /// `zone = <Z0..Z3>`, the zone the TXO committed in
+    Zone(Zone),
+    /// `identity = <IdentityType>`, the sender's identity type
+    Identity(IdentityType),
+    /// `sender = <32 hex>`
+    Sender([u8; 16]),
+    /// `receiver = <32 hex>`
+    Receiver([u8; 16]),
+    /// `!expr`
+    Not(Box<FilterExpr>),
+    /// `a & b`
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    /// `a | b`
+    Or(Box<FilterExpr>, Box<FilterExpr>),
+}
+
+impl FilterExpr {
+    /// Parse a filter expression
+    ///
+    /// `!` binds tightest, then `&`, then `|`; parentheses group.
+    pub fn parse(source: &str) -> Result<Self, DispatchError> {
+        let mut parser = FilterParser { src: source.as_bytes(), pos: 0 };
+        parser.skip_ws();
+        if parser.pos == parser.src.len() {
+            return Ok(FilterExpr::Any);
+        }
+        let expr = parser.or()?;
+        parser.skip_ws();
+        if parser.pos != parser.src.len() {
+            return Err(parser.error());
+        }
+        Ok(expr)
+    }
+
+    /// Check whether a TXO committed in `zone` matches
+    pub fn matches(&self, txo: &TXO, zone: Zone) -> bool {
+        match self {
+            FilterExpr::Any => true,
+            FilterExpr::Class(class) => txo.operation_class == *class,
+            FilterExpr::Zone(z) => zone == *z,
+            FilterExpr::Identity(identity) => txo.sender.identity_type == *identity,
+            FilterExpr::Sender(id) => txo.sender.id == *id,
+            FilterExpr::Receiver(id) => txo.receiver.id == *id,
+            FilterExpr::Not(inner) => !inner.matches(txo, zone),
+            FilterExpr::And(a, b) => a.matches(txo, zone) && b.matches(txo, zone),
+            FilterExpr::Or(a, b) => a.matches(txo, zone) || b.matches(txo, zone),
+        }
+    }
+}
+
+/// Sink destination
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SinkTarget {
+    /// HTTPS POST, signed with HMAC-SHA3-256 under `secret`
+    Webhook {
+        /// `https://` endpoint
+        url: String,
+        /// Shared HMAC secret
+        secret: Vec<u8>,
+    },
+    /// Newline-delimited JSON written to a Unix domain socket
+    UnixSocket(PathBuf),
+    /// Newline-delimited JSON appended to a file
+    File(PathBuf),
+}
+
+/// Retry schedule for failed deliveries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts before a delivery is abandoned (including the first)
+    pub max_attempts: u32,
+    /// Delay after the first failure (milliseconds)
+    pub initial_backoff_ms: u64,
+    /// Upper bound on the delay (milliseconds)
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_attempts: 5, initial_backoff_ms: 1_000, max_backoff_ms: 60_000 }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay after the `attempt`-th failed attempt (1-based), doubling each time
+    pub fn backoff(&self, attempt: u32) -> u64 {
+        let shift = attempt.saturating_sub(1).min(32);
+        self.initial_backoff_ms.saturating_mul(1u64 << shift).min(self.max_backoff_ms)
+    }
+}
+
+/// Sink configuration
+#[derive(Debug, Clone)]
+pub struct SinkConfig {
+    /// Unique sink name (appears in audit records)
+    pub name: String,
+    /// Destination
+    pub target: SinkTarget,
+    /// TXOs to deliver
+    pub filter: FilterExpr,
+    /// Retry schedule
+    pub retry: RetryPolicy,
+}
+
+/// Event handed to a transport
+#[derive(Debug, Clone)]
+pub struct Delivery {
+    /// Dispatcher-assigned delivery ID (stable across retries)
+    pub delivery_id: u64,
+    /// TXO identifier
+    pub txo_id: [u8; 16],
+    /// JSON event body
+    pub body: String,
+}
+
+/// Transport that moves a delivery to its sink
+pub trait SinkTransport {
+    /// Whether this transport can deliver to `target`
+    fn supports(&self, _target: &SinkTarget) -> bool {
+        true
+    }
+
+    /// Attempt one delivery at `now_ms`
+    fn deliver(&mut self, target: &SinkTarget, delivery: &Delivery, now_ms: u64) -> Result<(), DeliveryError>;
+}
+
+/// Transport over the standard library (and `ureq` for webhooks)
+#[derive(Debug, Default)]
+pub struct StdTransport;
+
+impl SinkTransport for StdTransport {
+    fn supports(&self, target: &SinkTarget) -> bool {
+        match target {
+            SinkTarget::Webhook { .. } => cfg!(feature = "webhooks"),
+            SinkTarget::UnixSocket(_) => cfg!(unix),
+            SinkTarget::File(_) => true,
+        }
+    }
+
+    fn deliver(&mut self, target: &SinkTarget, delivery: &Delivery, now_ms: u64) -> Result<(), DeliveryError> {
+        match target {
+            SinkTarget::Webhook { url, secret } => post_webhook(url, secret, delivery, now_ms),
+            SinkTarget::UnixSocket(path) => write_socket(path, &delivery.body),
+            SinkTarget::File(path) => {
+                let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(io_error)?;
+                file.write_all(line(&delivery.body).as_bytes()).map_err(io_error)
+            }
+        }
+    }
+}
+
+/// Result of one delivery attempt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryOutcome {
+    /// Sink accepted the event
+    Delivered,
+    /// Attempt failed; retried at `next_attempt_at`
+    Retrying {
+        /// Failure cause
+        error: DeliveryError,
+        /// Scheduled retry time (milliseconds)
+        next_attempt_at: u64,
+    },
+    /// Attempt failed and the retry budget is spent
+    Abandoned {
+        /// Failure cause
+        error: DeliveryError,
+    },
+}
+
+/// Delivery audit record (one per attempt)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryRecord {
+    /// Delivery ID
+    pub delivery_id: u64,
+    /// Sink name
+    pub sink: String,
+    /// TXO identifier
+    pub txo_id: [u8; 16],
+    /// SHA3-256 of the delivered body
+    pub body_hash: [u8; 32],
+    /// 1-based attempt number
+    pub attempt: u32,
+    /// Attempt time (milliseconds)
+    pub attempted_at: u64,
+    /// Outcome
+    pub outcome: DeliveryOutcome,
+}
+
+/// Queued delivery awaiting its next attempt
+#[derive(Debug, Clone)]
+struct PendingDelivery {
+    sink: usize,
+    delivery: Delivery,
+    attempts: u32,
+    next_attempt_at: u64,
+}
+
+/// Committed-TXO event dispatcher
+pub struct EventDispatcher<T: SinkTransport = StdTransport> {
+    /// Delivery transport
+    transport: T,
+    /// Registered sinks
+    sinks: Vec<SinkConfig>,
+    /// Deliveries not yet delivered or abandoned
+    pending: Vec<PendingDelivery>,
+    /// Delivery audit log
+    records: Vec<DeliveryRecord>,
+    /// Next delivery ID
+    next_delivery_id: u64,
+}
+
+impl EventDispatcher<StdTransport> {
+    /// Create a dispatcher over the standard transport
+    pub fn new() -> Self {
+        Self::with_transport(StdTransport)
+    }
+}
+
+impl Default for EventDispatcher<StdTransport> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: SinkTransport> EventDispatcher<T> {
+    /// Create a dispatcher over a custom transport
+    pub fn with_transport(transport: T) -> Self {
+        Self {
+            transport,
+            sinks: Vec::new(),
+            pending: Vec::new(),
+            records: Vec::new(),
+            next_delivery_id: 1,
+        }
+    }
+
+    /// Register a sink
+    pub fn add_sink(&mut self, sink: SinkConfig) -> Result<(), DispatchError> {
+        if self.sinks.iter().any(|s| s.name == sink.name) {
+            return Err(DispatchError::DuplicateSink(sink.name));
+        }
+        if sink.retry.max_attempts == 0 {
+            return Err(DispatchError::InvalidRetryPolicy);
+        }
+        if let SinkTarget::Webhook { url, secret } = &sink.target {
+            if !url.starts_with("https://") {
+                return Err(DispatchError::InsecureWebhook);
+            }
+            if secret.is_empty() {
+                return Err(DispatchError::MissingSecret);
+            }
+        }
+        if !self.transport.supports(&sink.target) {
+            return Err(DispatchError::UnsupportedSink);
+        }
+        self.sinks.push(sink);
+        Ok(())
+    }
+
+    /// Remove a sink and drop its pending deliveries
+    pub fn remove_sink(&mut self, name: &str) -> bool {
+        let Some(index) = self.sinks.iter().position(|s| s.name == name) else {
+            return false;
+        };
+        self.sinks.remove(index);
+        self.pending.retain(|p| p.sink != index);
+        for pending in &mut self.pending {
+            if pending.sink > index {
+                pending.sink -= 1;
+            }
+        }
+        true
+    }
+
+    /// Queue a committed TXO for every matching sink and attempt delivery
+    ///
+    /// # Arguments
+    /// * `txo` - TXO as committed
+    /// * `zone` - Zone it was committed in
+    /// * `ledger_root` - Ledger root after the commit
+    /// * `now_ms` - Current time (milliseconds)
+    ///
+    /// # Returns
+    /// * Number of sinks the event was queued for
+    pub fn dispatch(&mut self, txo: &TXO, zone: Zone, ledger_root: [u8; 32], now_ms: u64) -> usize {
+        let mut queued = 0;
+        let mut body = None;
+
+        for (index, sink) in self.sinks.iter().enumerate() {
+            if !sink.filter.matches(txo, zone) {
+                continue;
+            }
+            let body = body.get_or_insert_with(|| event_json(txo, zone, &ledger_root));
+            self.pending.push(PendingDelivery {
+                sink: index,
+                delivery: Delivery {
+                    delivery_id: self.next_delivery_id,
+                    txo_id: txo.txo_id,
+                    body: body.clone(),
+                },
+                attempts: 0,
+                next_attempt_at: now_ms,
+            });
+            self.next_delivery_id += 1;
+            queued += 1;
+        }
+
+        self.poll(now_ms);
+        queued
+    }
+
+    /// Attempt every delivery due at `now_ms`
+    ///
+    /// # Returns
+    /// * Number of deliveries that succeeded
+    pub fn poll(&mut self, now_ms: u64) -> usize {
+        let mut delivered = 0;
+        let mut remaining = Vec::with_capacity(self.pending.len());
+
+        for mut pending in core::mem::take(&mut self.pending) {
+            if pending.next_attempt_at > now_ms {
+                remaining.push(pending);
+                continue;
+            }
+
+            let sink = &self.sinks[pending.sink];
+            pending.attempts += 1;
+            let outcome = match self.transport.deliver(&sink.target, &pending.delivery, now_ms) {
+                Ok(()) => DeliveryOutcome::Delivered,
+                Err(error) if pending.attempts >= sink.retry.max_attempts => {
+                    DeliveryOutcome::Abandoned { error }
+                }
+                Err(error) => {
+                    pending.next_attempt_at = now_ms + sink.retry.backoff(pending.attempts);
+                    DeliveryOutcome::Retrying { error, next_attempt_at: pending.next_attempt_at }
+                }
+            };
+
+            self.records.push(DeliveryRecord {
+                delivery_id: pending.delivery.delivery_id,
+                sink: sink.name.clone(),
+                txo_id: pending.delivery.txo_id,
+                body_hash: Sha3_256::digest(pending.delivery.body.as_bytes()).into(),
+                attempt: pending.attempts,
+                attempted_at: now_ms,
+                outcome: outcome.clone(),
+            });
+
+            match outcome {
+                DeliveryOutcome::Delivered => delivered += 1,
+                DeliveryOutcome::Retrying { .. } => remaining.push(pending),
+                DeliveryOutcome::Abandoned { .. } => {}
+            }
+        }
+
+        self.pending = remaining;
+        delivered
+    }
+
+    /// Deliveries awaiting an attempt
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Earliest scheduled retry, if any
+    pub fn next_attempt_at(&self) -> Option<u64> {
+        self.pending.iter().map(|p| p.next_attempt_at).min()
+    }
+
+    /// Delivery audit log, oldest first
+    pub fn audit_log(&self) -> &[DeliveryRecord] {
+        &self.records
+    }
+
+    /// Underlying transport
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+}
+
+/// Webhook signature: HMAC-SHA3-256 over `"<timestamp_ms>." || body`
+///
+/// Sent as `X-Aethernet-Signature: sha3-256=<hex>` alongside
+/// `X-Aethernet-Timestamp`, so receivers can reject replays.
+pub fn sign_payload(secret: &[u8], timestamp_ms: u64, body: &str) -> [u8; 32] {
+    let mut message = Vec::with_capacity(body.len() + 21);
+    message.extend_from_slice(std::format!("{}.", timestamp_ms).as_bytes());
+    message.extend_from_slice(body.as_bytes());
+    hmac_sha3_256(secret, &message)
+}
+
+/// Check a webhook signature in constant time
+pub fn verify_payload(secret: &[u8], timestamp_ms: u64, body: &str, signature: &[u8; 32]) -> bool {
+    let expected = sign_payload(secret, timestamp_ms, body);
+    expected.iter().zip(signature).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+/// HMAC (RFC 2104) over SHA3-256
+fn hmac_sha3_256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block = [0u8; HMAC_BLOCK];
+    if key.len() > HMAC_BLOCK {
+        block[..32].copy_from_slice(&Sha3_256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha3_256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+    let inner = inner.finalize();
+
+    let mut outer = Sha3_256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner);
+    outer.finalize().into()
+}
+
+/// JSON event body for a committed TXO (fixed key order)
+fn event_json(txo: &TXO, zone: Zone, ledger_root: &[u8; 32]) -> String {
+    std::format!(
+        concat!(
+            "{{\"txo_id\":\"{}\",\"epoch_id\":{},\"timestamp\":{},\"zone\":\"{}\",",
+            "\"operation_class\":\"{}\",\"payload_type\":\"{}\",\"sender\":\"{}\",",
+            "\"sender_type\":\"{}\",\"receiver\":\"{}\",\"content_hash\":\"{}\",\"ledger_root\":\"{}\"}}"
+        ),
+        hex(&txo.txo_id),
+        txo.epoch_id,
+        txo.timestamp,
+        zone.name(),
+        class_name(txo.operation_class),
+        payload_name(txo.payload.payload_type),
+        hex(&txo.sender.id),
+        identity_name(txo.sender.identity_type),
+        hex(&txo.receiver.id),
+        hex(&txo.payload.content_hash),
+        hex(ledger_root),
+    )
+}
+
+#[cfg(feature = "webhooks")]
+fn post_webhook(url: &str, secret: &[u8], delivery: &Delivery, now_ms: u64) -> Result<(), DeliveryError> {
+    let signature = sign_payload(secret, now_ms, &delivery.body);
+    let result = ureq::post(url)
+        .set("Content-Type", "application/json")
+        .set("X-Aethernet-Delivery", &delivery.delivery_id.to_string())
+        .set("X-Aethernet-Timestamp", &now_ms.to_string())
+        .set("X-Aethernet-Signature", &std::format!("sha3-256={}", hex(&signature)))
+        .send_string(&delivery.body);
+    match result {
+        Ok(_) => Ok(()),
+        Err(ureq::Error::Status(code, _)) => Err(DeliveryError::Status(code)),
+        Err(err) => Err(DeliveryError::Io(err.to_string())),
+    }
+}
+
+#[cfg(not(feature = "webhooks"))]
+fn post_webhook(_url: &str, _secret: &[u8], _delivery: &Delivery, _now_ms: u64) -> Result<(), DeliveryError> {
+    Err(DeliveryError::Unsupported)
+}
+
+#[cfg(unix)]
+fn write_socket(path: &std::path::Path, body: &str) -> Result<(), DeliveryError> {
+    let mut stream = std::os::unix::net::UnixStream::connect(path).map_err(io_error)?;
+    stream.write_all(line(body).as_bytes()).map_err(io_error)
+}
+
+#[cfg(not(unix))]
+fn write_socket(_path: &std::path::Path, _body: &str) -> Result<(), DeliveryError> {
+    Err(DeliveryError::Unsupported)
+}
+
+fn line(body: &str) -> String {
+    let mut line = String::with_capacity(body.len() + 1);
+    line.push_str(body);
+    line.push('\n');
+    line
+}
+
+fn io_error(err: std::io::Error) -> DeliveryError {
+    DeliveryError::Io(err.to_string())
+}
+
+/// Lowercase hex encoding
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| std::format!("{:02x}", b)).collect()
+}
+
+fn class_name(class: OperationClass) -> &'static str {
+    match class {
+        OperationClass::Genomic => "Genomic",
+        OperationClass::Network => "Network",
+        OperationClass::Compliance => "Compliance",
+        OperationClass::Admin => "Admin",
+    }
+}
+
+fn payload_name(payload_type: PayloadType) -> &'static str {
+    match payload_type {
+        PayloadType::Genome => "Genome",
+        PayloadType::Metadata => "Metadata",
+        PayloadType::Control => "Control",
+        PayloadType::Audit => "Audit",
+    }
+}
+
+fn identity_name(identity: IdentityType) -> &'static str {
+    match identity {
+        IdentityType::Operator => "Operator",
+        IdentityType::Node => "Node",
+        IdentityType::System => "System",
+    }
+}
+
+/// Recursive-descent parser over filter expressions
+struct FilterParser<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> FilterParser<'a> {
+    fn error(&self) -> DispatchError {
+        DispatchError::Filter { offset: self.pos }
+    }
+
+    fn skip_ws(&mut self) {
+        while self.src.get(self.pos).is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, token: u8) -> bool {
+        self.skip_ws();
+        if self.src.get(self.pos) == Some(&token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> Result<FilterExpr, DispatchError> {
+        let mut expr = self.and()?;
+        while self.eat(b'|') {
+            expr = FilterExpr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<FilterExpr, DispatchError> {
+        let mut expr = self.unary()?;
+        while self.eat(b'&') {
+            expr = FilterExpr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<FilterExpr, DispatchError> {
+        if self.eat(b'!') {
+            return Ok(FilterExpr::Not(Box::new(self.unary()?)));
+        }
+        if self.eat(b'(') {
+            let expr = self.or()?;
+            return if self.eat(b')') { Ok(expr) } else { Err(self.error()) };
+        }
+        if self.eat(b'*') {
+            return Ok(FilterExpr::Any);
+        }
+
+        self.skip_ws();
+        let start = self.pos;
+        let key = self.word();
+        if !self.eat(b'=') {
+            return Err(self.error());
+        }
+        self.skip_ws();
+        let value_start = self.pos;
+        let value = self.word();
+        let expr = match key {
+            "class" => match value {
+                "Genomic" => Some(FilterExpr::Class(OperationClass::Genomic)),
+                "Network" => Some(FilterExpr::Class(OperationClass::Network)),
+                "Compliance" => Some(FilterExpr::Class(OperationClass::Compliance)),
+                "Admin" => Some(FilterExpr::Class(OperationClass::Admin)),
+                _ => None,
+            },
+            "zone" => Zone::ALL.into_iter().find(|z| z.name() == value).map(FilterExpr::Zone),
+            "identity" => match value {
+                "Operator" => Some(FilterExpr::Identity(IdentityType::Operator)),
+                "Node" => Some(FilterExpr::Identity(IdentityType::Node)),
+                "System" => Some(FilterExpr::Identity(IdentityType::System)),
+                _ => None,
+            },
+            "sender" => parse_id(value).map(FilterExpr::Sender),
+            "receiver" => parse_id(value).map(FilterExpr::Receiver),
+            _ => return Err(DispatchError::Filter { offset: start }),
+        };
+        expr.ok_or(DispatchError::Filter { offset: value_start })
+    }
+
+    fn word(&mut self) -> &'a str {
+        self.skip_ws();
+        let start = self.pos;
+        while self.src.get(self.pos).is_some_and(|c| c.is_ascii_alphanumeric() || *c == b'_') {
+            self.pos += 1;
+        }
+        // Word bytes are ASCII by construction
+        let src: &'a [u8] = self.src;
+        core::str::from_utf8(&src[start..self.pos]).unwrap_or("")
+    }
+}
+
+/// Parse a 32-digit hex UUID
+fn parse_id(value: &str) -> Option<[u8; 16]> {
+    if value.len() != 32 {
+        return None;
+    }
+    let mut id = [0u8; 16];
+    for (i, byte) in id.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(value.get(2 * i..2 * i + 2)?, 16).ok()?;
+    }
+    Some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::txo::{Payload, Receiver, Sender};
+
+    fn make_txo(class: OperationClass, identity: IdentityType) -> TXO {
+        let sender = Sender {
+            identity_type: identity,
+            id: [5u8; 16],
+            biokey_present: false,
+            fido2_signed: true,
+            zk_proof: None,
+        };
+        let receiver = Receiver { identity_type: IdentityType::Node, id: [6u8; 16] };
+        let payload = Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: [7u8; 32],
+            encrypted: false,
+            envelope: None,
+        };
+        TXO::new([1u8; 16], sender, receiver, class, payload)
+    }
+
+    /// Transport that fails the first `failures` attempts
+    #[derive(Default)]
+    struct FlakyTransport {
+        failures: usize,
+        attempts: Vec<(u64, u64)>,
+    }
+
+    impl SinkTransport for FlakyTransport {
+        fn deliver(&mut self, _target: &SinkTarget, delivery: &Delivery, now_ms: u64) -> Result<(), DeliveryError> {
+            self.attempts.push((delivery.delivery_id, now_ms));
+            if self.attempts.len() <= self.failures {
+                Err(DeliveryError::Status(503))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn sink(name: &str, target: SinkTarget, filter: &str) -> SinkConfig {
+        SinkConfig {
+            name: String::from(name),
+            target,
+            filter: FilterExpr::parse(filter).unwrap(),
+            retry: RetryPolicy { max_attempts: 3, initial_backoff_ms: 100, max_backoff_ms: 150 },
+        }
+    }
+
+    #[test]
+    fn test_filter_expressions() {
+        let compliance = make_txo(OperationClass::Compliance, IdentityType::System);
+        let genomic = make_txo(OperationClass::Genomic, IdentityType::Operator);
+
+        let filter = FilterExpr::parse("class = Compliance & zone = Z2").unwrap();
+        assert!(filter.matches(&compliance, Zone::Z2));
+        assert!(!filter.matches(&compliance, Zone::Z1));
+        assert!(!filter.matches(&genomic, Zone::Z2));
+
+        let filter = FilterExpr::parse(
+            "(sender = 05050505050505050505050505050505 | identity = Node) & !class = Admin",
+        )
+        .unwrap();
+        assert!(filter.matches(&genomic, Zone::Z1));
+        assert!(!filter.matches(&make_txo(OperationClass::Admin, IdentityType::Node), Zone::Z1));
+
+        assert_eq!(FilterExpr::parse("  ").unwrap(), FilterExpr::Any);
+        assert_eq!(FilterExpr::parse("class = Payroll"), Err(DispatchError::Filter { offset: 8 }));
+        assert_eq!(FilterExpr::parse("colour = red"), Err(DispatchError::Filter { offset: 0 }));
+        assert!(FilterExpr::parse("(zone = Z1").is_err());
+        assert!(FilterExpr::parse("receiver = 0606").is_err());
+    }
+
+    #[test]
+    fn test_retry_backoff_and_audit() {
+        let mut dispatcher = EventDispatcher::with_transport(FlakyTransport { failures: 2, ..Default::default() });
+        dispatcher.add_sink(sink("audit", SinkTarget::File(PathBuf::from("unused")), "class = Compliance")).unwrap();
+
+        let txo = make_txo(OperationClass::Compliance, IdentityType::System);
+        assert_eq!(dispatcher.dispatch(&txo, Zone::Z2, [9u8; 32], 1_000), 1);
+        assert_eq!(dispatcher.next_attempt_at(), Some(1_100));
+
+        // Not due yet, then due with the backoff doubled and capped
+        assert_eq!(dispatcher.poll(1_050), 0);
+        assert_eq!(dispatcher.poll(1_100), 0);
+        assert_eq!(dispatcher.next_attempt_at(), Some(1_250));
+        assert_eq!(dispatcher.poll(1_250), 1);
+        assert_eq!(dispatcher.pending_count(), 0);
+
+        let log = dispatcher.audit_log();
+        assert_eq!(log.len(), 3);
+        assert_eq!(log[0].outcome, DeliveryOutcome::Retrying { error: DeliveryError::Status(503), next_attempt_at: 1_100 });
+        assert_eq!(log[2].outcome, DeliveryOutcome::Delivered);
+        assert_eq!(log[2].attempt, 3);
+        assert!(log.iter().all(|r| r.delivery_id == 1 && r.sink == "audit" && r.body_hash == log[0].body_hash));
+
+        // Filtered out entirely
+        let genomic = make_txo(OperationClass::Genomic, IdentityType::Operator);
+        assert_eq!(dispatcher.dispatch(&genomic, Zone::Z2, [9u8; 32], 2_000), 0);
+
+        // Retry budget exhausted
+        let mut dispatcher = EventDispatcher::with_transport(FlakyTransport { failures: 10, ..Default::default() });
+        dispatcher.add_sink(sink("audit", SinkTarget::File(PathBuf::from("unused")), "*")).unwrap();
+        dispatcher.dispatch(&txo, Zone::Z1, [0u8; 32], 0);
+        dispatcher.poll(100);
+        dispatcher.poll(250);
+        assert_eq!(dispatcher.pending_count(), 0);
+        assert_eq!(
+            dispatcher.audit_log().last().unwrap().outcome,
+            DeliveryOutcome::Abandoned { error: DeliveryError::Status(503) }
+        );
+        assert_eq!(dispatcher.transport().attempts.len(), 3);
+    }
+
+    #[test]
+    fn test_sink_validation() {
+        let mut dispatcher = EventDispatcher::with_transport(FlakyTransport::default());
+        let webhook = |url: &str, secret: &[u8]| SinkTarget::Webhook { url: String::from(url), secret: secret.to_vec() };
+
+        assert_eq!(
+            dispatcher.add_sink(sink("hook", webhook("http://example.org/hook", b"k"), "*")),
+            Err(DispatchError::InsecureWebhook)
+        );
+        assert_eq!(
+            dispatcher.add_sink(sink("hook", webhook("https://example.org/hook", b""), "*")),
+            Err(DispatchError::MissingSecret)
+        );
+        dispatcher.add_sink(sink("hook", webhook("https://example.org/hook", b"k"), "*")).unwrap();
+        assert_eq!(
+            dispatcher.add_sink(sink("hook", SinkTarget::File(PathBuf::from("x")), "*")),
+            Err(DispatchError::DuplicateSink(String::from("hook")))
+        );
+        assert!(dispatcher.remove_sink("hook"));
+        assert!(!dispatcher.remove_sink("hook"));
+
+        let std_dispatcher = EventDispatcher::new();
+        assert_eq!(
+            std_dispatcher.transport().supports(&webhook("https://example.org/hook", b"k")),
+            cfg!(feature = "webhooks")
+        );
+    }
+
+    #[test]
+    fn test_hmac_signing() {
+        // NIST HMAC-SHA3-256 sample, key length < block length
+        let key: Vec<u8> = (0u8..32).collect();
+        let mac = hmac_sha3_256(&key, b"Sample message for keylen<blocklen");
+        assert_eq!(hex(&mac), "4fe8e202c4f058e8dddc23d8c34e467343e23555e24fc2f025d598f558f67205");
+
+        let signature = sign_payload(b"secret", 1_700_000_000_000, "{}");
+        assert!(verify_payload(b"secret", 1_700_000_000_000, "{}", &signature));
+        assert!(!verify_payload(b"secret", 1_700_000_000_001, "{}", &signature));
+        assert!(!verify_payload(b"other", 1_700_000_000_000, "{}", &signature));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_file_and_socket_sinks() {
+        use std::io::{BufRead, BufReader};
+        use std::os::unix::net::UnixListener;
+
+        let dir = std::env::temp_dir().join(std::format!("aethernet-dispatch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("events.jsonl");
+        let socket = dir.join("events.sock");
+        let _ = std::fs::remove_file(&file);
+        let _ = std::fs::remove_file(&socket);
+        let listener = UnixListener::bind(&socket).unwrap();
+
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.add_sink(sink("file", SinkTarget::File(file.clone()), "*")).unwrap();
+        dispatcher.add_sink(sink("socket", SinkTarget::UnixSocket(socket.clone()), "zone = Z2")).unwrap();
+
+        let txo = make_txo(OperationClass::Network, IdentityType::Node);
+        assert_eq!(dispatcher.dispatch(&txo, Zone::Z2, [9u8; 32], 0), 2);
+        assert_eq!(dispatcher.dispatch(&txo, Zone::Z1, [9u8; 32], 0), 1);
+
+        let (stream, _) = listener.accept().unwrap();
+        let mut received = String::new();
+        BufReader::new(stream).read_line(&mut received).unwrap();
+        assert!(received.contains("\"zone\":\"Z2\""));
+        assert!(received.contains("\"operation_class\":\"Network\""));
+        assert!(received.contains(&std::format!("\"ledger_root\":\"{}\"", hex(&[9u8; 32]))));
+
+        let appended = std::fs::read_to_string(&file).unwrap();
+        assert_eq!(appended.lines().count(), 2);
+        assert!(dispatcher.audit_log().iter().all(|r| r.outcome == DeliveryOutcome::Delivered));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -16,8 +16,7 @@ extern crate alloc;
 use core::ptr;
 
 // Import RTF API
-#[cfg(feature = "std")]
-use crate::rtf::api::{RTFContext, Zone};
+use crate::rtf::api::Zone;
 #[cfg(feature = "std")]
 use crate::txo::{TXO, Sender, Receiver, Payload, IdentityType, OperationClass, PayloadType};
 #[cfg(feature = "std")]
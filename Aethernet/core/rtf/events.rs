@@ -0,0 +1,485 @@
+//! RTF Event API
+//!
+//! Ledger event stream for subscribers, with server-side redaction. Before a
+//! committed TXO leaves the event API it is filtered per subscription and
+//! redacted according to the subscriber's zone and identity type: each field
+//! is kept, hashed, or stripped. Every redaction decision is itself logged so
+//! exposure to lower-trust zones remains auditable.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use sha3::{Digest, Sha3_256};
+
+use crate::rtf::api::Zone;
+use crate::txo::{IdentityType, OperationClass, PayloadType, TXO};
+
+/// TXO field subject to redaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventField {
+    /// Sender UUID
+    SenderId,
+    /// Receiver UUID
+    ReceiverId,
+    /// Payload content hash
+    ContentHash,
+    /// Execution container hash
+    ContainerHash,
+    /// Signature bytes and signer IDs
+    Signatures,
+    /// Audit trail
+    AuditTrail,
+    /// Sender biokey zero-knowledge proof
+    ZkProof,
+}
+
+impl EventField {
+    /// All redactable fields, in evaluation order
+    pub const ALL: [EventField; 7] = [
+        EventField::SenderId,
+        EventField::ReceiverId,
+        EventField::ContentHash,
+        EventField::ContainerHash,
+        EventField::Signatures,
+        EventField::AuditTrail,
+        EventField::ZkProof,
+    ];
+}
+
+/// Redaction action for a field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactionAction {
+    /// Deliver the field unchanged
+    Keep,
+    /// Replace the field with a salted SHA3-256 digest
+    Hash,
+    /// Remove the field
+    Strip,
+}
+
+/// Redaction rule
+///
+/// Matches a subscriber zone and, optionally, identity type. Rules naming an
+/// identity type take precedence over zone-wide rules.
+#[derive(Debug, Clone, Copy)]
+pub struct RedactionRule {
+    /// Subscriber zone the rule applies to
+    pub zone: Zone,
+    /// Subscriber identity type (`None` = any)
+    pub identity_type: Option<IdentityType>,
+    /// Field the rule governs
+    pub field: EventField,
+    /// Action to apply
+    pub action: RedactionAction,
+}
+
+/// Redaction policy engine
+#[derive(Debug, Clone)]
+pub struct RedactionPolicy {
+    /// Action when no rule matches
+    default_action: RedactionAction,
+    /// Ordered rules
+    rules: Vec<RedactionRule>,
+    /// Salt for hashed fields (prevents dictionary reversal of short IDs)
+    salt: [u8; 32],
+}
+
+impl RedactionPolicy {
+    /// Create a policy with a default action and hashing salt
+    pub fn new(default_action: RedactionAction, salt: [u8; 32]) -> Self {
+        Self {
+            default_action,
+            rules: Vec::new(),
+            salt,
+        }
+    }
+
+    /// Add a rule
+    pub fn with_rule(mut self, rule: RedactionRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Resolve the action for a field
+    ///
+    /// # Returns
+    /// * The action and the index of the rule that decided it (`None` = default)
+    pub fn resolve(
+        &self,
+        zone: Zone,
+        identity_type: IdentityType,
+        field: EventField,
+    ) -> (RedactionAction, Option<usize>) {
+        let matching = |specific: bool| {
+            self.rules.iter().position(|r| {
+                r.zone == zone
+                    && r.field == field
+                    && match r.identity_type {
+                        Some(t) => specific && t == identity_type,
+                        None => !specific,
+                    }
+            })
+        };
+
+        match matching(true).or_else(|| matching(false)) {
+            Some(index) => (self.rules[index].action, Some(index)),
+            None => (self.default_action, None),
+        }
+    }
+
+    /// Salted digest of a field value
+    fn digest(&self, field: EventField, value: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"AETHERNET-EVENT-REDACT-v1");
+        hasher.update(self.salt);
+        hasher.update([field as u8]);
+        hasher.update(value);
+
+        let result = hasher.finalize();
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&result);
+        hash
+    }
+}
+
+/// Subscription filter (empty lists match everything)
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionFilter {
+    /// Operation classes to deliver
+    pub operation_classes: Vec<OperationClass>,
+    /// Payload types to deliver
+    pub payload_types: Vec<PayloadType>,
+}
+
+impl SubscriptionFilter {
+    /// Check whether a TXO passes the filter
+    pub fn matches(&self, txo: &TXO) -> bool {
+        (self.operation_classes.is_empty() || self.operation_classes.contains(&txo.operation_class))
+            && (self.payload_types.is_empty() || self.payload_types.contains(&txo.payload.payload_type))
+    }
+}
+
+/// Ledger stream subscription
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    /// Subscriber UUID
+    pub subscriber_id: [u8; 16],
+    /// Zone the subscriber operates in
+    pub zone: Zone,
+    /// Subscriber identity type
+    pub identity_type: IdentityType,
+    /// Event filter
+    pub filter: SubscriptionFilter,
+}
+
+/// Redacted field value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+    /// Unredacted value
+    Clear(Vec<u8>),
+    /// Salted SHA3-256 digest of the value
+    Hashed([u8; 32]),
+    /// Value removed
+    Stripped,
+}
+
+/// Event delivered to a subscriber
+#[derive(Debug, Clone)]
+pub struct RedactedEvent {
+    /// TXO identifier
+    pub txo_id: [u8; 16],
+    /// Ledger epoch
+    pub epoch_id: u64,
+    /// TXO timestamp
+    pub timestamp: u64,
+    /// Operation class
+    pub operation_class: OperationClass,
+    /// Payload type
+    pub payload_type: PayloadType,
+    /// Redacted fields, in `EventField::ALL` order
+    pub fields: Vec<(EventField, FieldValue)>,
+}
+
+impl RedactedEvent {
+    /// Look up a field value
+    pub fn field(&self, field: EventField) -> Option<&FieldValue> {
+        self.fields.iter().find(|(f, _)| *f == field).map(|(_, v)| v)
+    }
+}
+
+/// Logged redaction decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedactionDecision {
+    /// TXO identifier
+    pub txo_id: [u8; 16],
+    /// Subscriber UUID
+    pub subscriber_id: [u8; 16],
+    /// Field decided
+    pub field: EventField,
+    /// Action applied
+    pub action: RedactionAction,
+    /// Deciding rule index (`None` = policy default)
+    pub rule_index: Option<usize>,
+}
+
+/// RTF event API
+pub struct EventApi {
+    /// Redaction policy
+    policy: RedactionPolicy,
+    /// Active subscriptions
+    subscriptions: Vec<Subscription>,
+    /// Pending events per subscriber
+    queues: BTreeMap<[u8; 16], Vec<RedactedEvent>>,
+    /// Redaction decision log
+    decisions: Vec<RedactionDecision>,
+}
+
+impl EventApi {
+    /// Create an event API with a redaction policy
+    pub fn new(policy: RedactionPolicy) -> Self {
+        Self {
+            policy,
+            subscriptions: Vec::new(),
+            queues: BTreeMap::new(),
+            decisions: Vec::new(),
+        }
+    }
+
+    /// Register a subscription (replaces any existing one for the subscriber)
+    pub fn subscribe(&mut self, subscription: Subscription) {
+        self.unsubscribe(&subscription.subscriber_id);
+        self.queues.insert(subscription.subscriber_id, Vec::new());
+        self.subscriptions.push(subscription);
+    }
+
+    /// Remove a subscription and drop its pending events
+    pub fn unsubscribe(&mut self, subscriber_id: &[u8; 16]) {
+        self.subscriptions.retain(|s| s.subscriber_id != *subscriber_id);
+        self.queues.remove(subscriber_id);
+    }
+
+    /// Publish a committed TXO to all matching subscribers
+    ///
+    /// # Returns
+    /// * Number of subscribers the event was delivered to
+    pub fn publish(&mut self, txo: &TXO) -> usize {
+        let mut delivered = 0;
+
+        for subscription in &self.subscriptions {
+            if !subscription.filter.matches(txo) {
+                continue;
+            }
+
+            let mut fields = Vec::with_capacity(EventField::ALL.len());
+            for field in EventField::ALL {
+                let (action, rule_index) =
+                    self.policy.resolve(subscription.zone, subscription.identity_type, field);
+                let value = match action {
+                    RedactionAction::Keep => FieldValue::Clear(field_bytes(txo, field)),
+                    RedactionAction::Hash => {
+                        FieldValue::Hashed(self.policy.digest(field, &field_bytes(txo, field)))
+                    }
+                    RedactionAction::Strip => FieldValue::Stripped,
+                };
+                fields.push((field, value));
+
+                self.decisions.push(RedactionDecision {
+                    txo_id: txo.txo_id,
+                    subscriber_id: subscription.subscriber_id,
+                    field,
+                    action,
+                    rule_index,
+                });
+            }
+
+            if let Some(queue) = self.queues.get_mut(&subscription.subscriber_id) {
+                queue.push(RedactedEvent {
+                    txo_id: txo.txo_id,
+                    epoch_id: txo.epoch_id,
+                    timestamp: txo.timestamp,
+                    operation_class: txo.operation_class,
+                    payload_type: txo.payload.payload_type,
+                    fields,
+                });
+                delivered += 1;
+            }
+        }
+
+        delivered
+    }
+
+    /// Take all pending events for a subscriber
+    pub fn drain(&mut self, subscriber_id: &[u8; 16]) -> Vec<RedactedEvent> {
+        self.queues
+            .get_mut(subscriber_id)
+            .map(core::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Redaction decision log
+    pub fn decision_log(&self) -> &[RedactionDecision] {
+        &self.decisions
+    }
+}
+
+/// Canonical byte form of a TXO field
+fn field_bytes(txo: &TXO, field: EventField) -> Vec<u8> {
+    match field {
+        EventField::SenderId => txo.sender.id.to_vec(),
+        EventField::ReceiverId => txo.receiver.id.to_vec(),
+        EventField::ContentHash => txo.payload.content_hash.to_vec(),
+        EventField::ContainerHash => txo.container_hash.to_vec(),
+        EventField::Signatures => {
+            let mut bytes = Vec::new();
+            for signature in &txo.signatures {
+                bytes.extend_from_slice(&signature.signer_id);
+                bytes.extend_from_slice(&signature.signature);
+            }
+            bytes
+        }
+        EventField::AuditTrail => {
+            let mut bytes = Vec::new();
+            for entry in &txo.audit_trail {
+                bytes.extend_from_slice(&entry.actor_id);
+                bytes.extend_from_slice(entry.action.as_bytes());
+                bytes.extend_from_slice(&entry.timestamp.to_le_bytes());
+            }
+            bytes
+        }
+        EventField::ZkProof => txo.sender.zk_proof.clone().unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::txo::{Payload, Receiver, Sender};
+
+    fn make_txo(class: OperationClass) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [1u8; 16],
+            biokey_present: true,
+            fido2_signed: false,
+            zk_proof: Some(vec![9u8; 32]),
+        };
+
+        let receiver = Receiver {
+            identity_type: IdentityType::Node,
+            id: [2u8; 16],
+        };
+
+        let payload = Payload {
+            payload_type: PayloadType::Genome,
+            content_hash: [3u8; 32],
+            encrypted: true,
+            envelope: None,
+        };
+
+        TXO::new([4u8; 16], sender, receiver, class, payload)
+    }
+
+    fn subscription(id: u8, zone: Zone, identity_type: IdentityType) -> Subscription {
+        Subscription {
+            subscriber_id: [id; 16],
+            zone,
+            identity_type,
+            filter: SubscriptionFilter::default(),
+        }
+    }
+
+    #[test]
+    fn test_redaction_by_zone_and_identity() {
+        let policy = RedactionPolicy::new(RedactionAction::Keep, [0xAAu8; 32])
+            .with_rule(RedactionRule {
+                zone: Zone::Z1,
+                identity_type: None,
+                field: EventField::SenderId,
+                action: RedactionAction::Hash,
+            })
+            .with_rule(RedactionRule {
+                zone: Zone::Z1,
+                identity_type: None,
+                field: EventField::ZkProof,
+                action: RedactionAction::Strip,
+            })
+            .with_rule(RedactionRule {
+                zone: Zone::Z1,
+                identity_type: Some(IdentityType::System),
+                field: EventField::SenderId,
+                action: RedactionAction::Keep,
+            });
+
+        let mut api = EventApi::new(policy);
+        api.subscribe(subscription(10, Zone::Z1, IdentityType::Operator));
+        api.subscribe(subscription(11, Zone::Z1, IdentityType::System));
+        api.subscribe(subscription(12, Zone::Z2, IdentityType::Operator));
+
+        assert_eq!(api.publish(&make_txo(OperationClass::Genomic)), 3);
+
+        let operator = api.drain(&[10u8; 16]);
+        assert!(matches!(operator[0].field(EventField::SenderId), Some(FieldValue::Hashed(_))));
+        assert_eq!(operator[0].field(EventField::ZkProof), Some(&FieldValue::Stripped));
+
+        // Identity-specific rule overrides the zone-wide hash
+        let system = api.drain(&[11u8; 16]);
+        assert_eq!(system[0].field(EventField::SenderId), Some(&FieldValue::Clear(vec![1u8; 16])));
+        assert_eq!(system[0].field(EventField::ZkProof), Some(&FieldValue::Stripped));
+
+        // Other zones fall through to the default
+        let production = api.drain(&[12u8; 16]);
+        assert_eq!(production[0].field(EventField::ZkProof), Some(&FieldValue::Clear(vec![9u8; 32])));
+
+        // Queues are drained
+        assert!(api.drain(&[10u8; 16]).is_empty());
+    }
+
+    #[test]
+    fn test_decisions_are_logged() {
+        let policy = RedactionPolicy::new(RedactionAction::Strip, [0u8; 32]).with_rule(RedactionRule {
+            zone: Zone::Z2,
+            identity_type: None,
+            field: EventField::ContentHash,
+            action: RedactionAction::Keep,
+        });
+
+        let mut api = EventApi::new(policy);
+        api.subscribe(subscription(10, Zone::Z2, IdentityType::Node));
+        api.publish(&make_txo(OperationClass::Network));
+
+        let log = api.decision_log();
+        assert_eq!(log.len(), EventField::ALL.len());
+        let content = log.iter().find(|d| d.field == EventField::ContentHash).unwrap();
+        assert_eq!(content.action, RedactionAction::Keep);
+        assert_eq!(content.rule_index, Some(0));
+        assert!(log
+            .iter()
+            .filter(|d| d.field != EventField::ContentHash)
+            .all(|d| d.action == RedactionAction::Strip && d.rule_index.is_none()));
+    }
+
+    #[test]
+    fn test_subscription_filter() {
+        let mut api = EventApi::new(RedactionPolicy::new(RedactionAction::Keep, [0u8; 32]));
+        let mut sub = subscription(10, Zone::Z2, IdentityType::Node);
+        sub.filter.operation_classes = vec![OperationClass::Compliance];
+        api.subscribe(sub);
+
+        assert_eq!(api.publish(&make_txo(OperationClass::Genomic)), 0);
+        assert_eq!(api.publish(&make_txo(OperationClass::Compliance)), 1);
+        assert_eq!(api.decision_log().len(), EventField::ALL.len());
+
+        api.unsubscribe(&[10u8; 16]);
+        assert_eq!(api.publish(&make_txo(OperationClass::Compliance)), 0);
+    }
+
+    #[test]
+    fn test_hashing_is_salted() {
+        let txo = make_txo(OperationClass::Genomic);
+        let a = RedactionPolicy::new(RedactionAction::Hash, [1u8; 32]);
+        let b = RedactionPolicy::new(RedactionAction::Hash, [2u8; 32]);
+        let bytes = field_bytes(&txo, EventField::SenderId);
+        assert_ne!(a.digest(EventField::SenderId, &bytes), b.digest(EventField::SenderId, &bytes));
+    }
+}
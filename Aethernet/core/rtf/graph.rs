@@ -0,0 +1,416 @@
+//! RTF Workflow Graphs
+//!
+//! Renders the rules the runtime actually enforces as DOT or Mermaid graph
+//! text for documentation and dashboards:
+//!
+//! - Zones: promotion edges, rollback, signature and operation-class rules
+//!   read from `Zone`, with the context's current zone highlighted
+//! - TXO lifecycle: execute, commit, rollback (in the zones that allow it)
+//!   and saga compensation
+//! - Policy: the `allow`/`deny` rules of the active bundle that can fire in
+//!   each zone
+//! - Sagas: forward steps and the reverse-order compensation chain
+//!
+//! ```text
+//! let text = WorkflowGraph::from_context(&ctx)
+//!     .with_saga(&saga)
+//!     .render(GraphFormat::Mermaid);
+//! ```
+//!
+//! Node identifiers are stable across renders, so generated diagrams diff
+//! cleanly when the configuration changes.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::rtf::api::{RTFContext, Zone};
+use crate::rtf::policy::{PolicyBundle, Rule, Term};
+use crate::rtf::saga::{hex16, Saga};
+use crate::txo::OperationClass;
+
+/// Graph text format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+impl GraphFormat {
+    /// Parse a format name (`dot` or `mermaid`)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "dot" | "graphviz" => Some(GraphFormat::Dot),
+            "mermaid" | "mmd" => Some(GraphFormat::Mermaid),
+            _ => None,
+        }
+    }
+
+    /// Conventional file extension
+    pub fn extension(self) -> &'static str {
+        match self {
+            GraphFormat::Dot => "dot",
+            GraphFormat::Mermaid => "mmd",
+        }
+    }
+}
+
+const OPERATIONS: [OperationClass; 4] = [
+    OperationClass::Genomic,
+    OperationClass::Network,
+    OperationClass::Compliance,
+    OperationClass::Admin,
+];
+
+struct Node {
+    id: String,
+    lines: Vec<String>,
+    highlight: bool,
+}
+
+struct Edge {
+    from: String,
+    to: String,
+    label: String,
+    dashed: bool,
+}
+
+struct Cluster {
+    id: String,
+    title: String,
+    nodes: Vec<Node>,
+}
+
+/// Workflow graph over an RTF configuration
+#[derive(Default)]
+pub struct WorkflowGraph<'a> {
+    current_zone: Option<Zone>,
+    policy: Option<&'a PolicyBundle>,
+    sagas: Vec<&'a Saga>,
+}
+
+impl<'a> WorkflowGraph<'a> {
+    /// Graph of the zone rules and TXO lifecycle only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Graph of a context: its current zone and active policy bundle
+    pub fn from_context(ctx: &'a RTFContext) -> Self {
+        Self {
+            current_zone: Some(ctx.current_zone),
+            policy: ctx.policy.as_ref().map(|engine| engine.active_bundle()),
+            sagas: Vec::new(),
+        }
+    }
+
+    /// Highlight `zone` as current
+    pub fn with_zone(mut self, zone: Zone) -> Self {
+        self.current_zone = Some(zone);
+        self
+    }
+
+    /// Include per-zone rules from `bundle`
+    pub fn with_policy(mut self, bundle: &'a PolicyBundle) -> Self {
+        self.policy = Some(bundle);
+        self
+    }
+
+    /// Include a saga definition
+    pub fn with_saga(mut self, saga: &'a Saga) -> Self {
+        self.sagas.push(saga);
+        self
+    }
+
+    /// Render as graph text
+    pub fn render(&self, format: GraphFormat) -> String {
+        let (clusters, edges) = self.build();
+        match format {
+            GraphFormat::Dot => render_dot(&clusters, &edges),
+            GraphFormat::Mermaid => render_mermaid(&clusters, &edges),
+        }
+    }
+
+    fn build(&self) -> (Vec<Cluster>, Vec<Edge>) {
+        let mut clusters = Vec::new();
+        let mut edges = Vec::new();
+
+        // Zones and promotions
+        let zones = Zone::ALL
+            .iter()
+            .map(|zone| Node {
+                id: String::from(zone.name()),
+                lines: Vec::from([
+                    String::from(zone.name()),
+                    format!("rollback: {}", if zone.allows_rollback() { "yes" } else { "no" }),
+                    format!("signatures: {}", zone.required_signatures()),
+                    format!("operations: {}", operations(*zone)),
+                ]),
+                highlight: self.current_zone == Some(*zone),
+            })
+            .collect();
+        clusters.push(Cluster { id: String::from("zones"), title: String::from("Zones"), nodes: zones });
+        for zone in Zone::ALL {
+            if let Some(target) = zone.promotion_target() {
+                edges.push(edge(zone.name(), target.name(), "promote_zone", false));
+            }
+        }
+
+        // TXO lifecycle
+        let rollback_zones: Vec<&str> =
+            Zone::ALL.iter().filter(|z| z.allows_rollback()).map(|z| z.name()).collect();
+        let stages = ["Submitted", "Rejected", "Executed", "Committed", "RolledBack", "Compensated"]
+            .iter()
+            .map(|stage| Node {
+                id: format!("txo_{}", stage),
+                lines: Vec::from([String::from(*stage)]),
+                highlight: false,
+            })
+            .collect();
+        clusters.push(Cluster { id: String::from("lifecycle"), title: String::from("TXO lifecycle"), nodes: stages });
+        edges.push(edge("txo_Submitted", "txo_Executed", "execute_txo", false));
+        edges.push(edge("txo_Submitted", "txo_Rejected", "zone, signature or policy check", true));
+        edges.push(edge("txo_Executed", "txo_Committed", "commit_txo", false));
+        edges.push(edge(
+            "txo_Committed",
+            "txo_RolledBack",
+            &format!("rollback_txo ({})", rollback_zones.join(", ")),
+            true,
+        ));
+        edges.push(edge("txo_Committed", "txo_Compensated", "saga compensation", true));
+
+        // Policy rules that can fire in each zone
+        if let Some(bundle) = self.policy {
+            let mut nodes = Vec::new();
+            for zone in Zone::ALL {
+                let rules: Vec<&Rule> = bundle
+                    .rules
+                    .iter()
+                    .filter(|rule| matches!(rule.head.predicate.as_str(), "allow" | "deny"))
+                    .filter(|rule| rule_applies(rule, zone))
+                    .collect();
+                let mut lines = Vec::from([format!("policy v{} in {}", bundle.version, zone.name())]);
+                lines.extend(rules.iter().map(|rule| format!("{}", rule)));
+                if !rules.iter().any(|rule| rule.head.predicate == "allow") {
+                    lines.push(String::from("(no allow rule: every TXO denied)"));
+                }
+                let id = format!("policy_{}", zone.name());
+                edges.push(edge(zone.name(), &id, "authorize", true));
+                nodes.push(Node { id, lines, highlight: false });
+            }
+            clusters.push(Cluster { id: String::from("policy"), title: String::from("Policy"), nodes });
+        }
+
+        // Saga steps and compensations
+        for (index, saga) in self.sagas.iter().enumerate() {
+            let step_id = |i: usize| format!("saga{}_step{}", index, i);
+            let comp_id = |i: usize| format!("saga{}_comp{}", index, i);
+            let steps = saga.steps();
+            let mut nodes = Vec::new();
+            for (i, step) in steps.iter().enumerate() {
+                nodes.push(Node { id: step_id(i), lines: Vec::from([step.name.clone()]), highlight: false });
+                // The last step is never compensated: nothing runs after it
+                if i + 1 < steps.len() {
+                    nodes.push(Node {
+                        id: comp_id(i),
+                        lines: Vec::from([format!("compensate {}", step.name)]),
+                        highlight: false,
+                    });
+                }
+                if i > 0 {
+                    edges.push(edge(&step_id(i - 1), &step_id(i), "commit", false));
+                    edges.push(edge(&step_id(i), &comp_id(i - 1), "on failure", true));
+                }
+                if i > 1 {
+                    edges.push(edge(&comp_id(i - 1), &comp_id(i - 2), "compensate", true));
+                }
+            }
+            clusters.push(Cluster {
+                id: format!("saga{}", index),
+                title: format!("Saga {}", hex16(&saga.id())),
+                nodes,
+            });
+        }
+
+        (clusters, edges)
+    }
+}
+
+fn edge(from: &str, to: &str, label: &str, dashed: bool) -> Edge {
+    Edge {
+        from: String::from(from),
+        to: String::from(to),
+        label: String::from(label),
+        dashed,
+    }
+}
+
+/// Operation classes a zone permits
+fn operations(zone: Zone) -> String {
+    let permitted: Vec<String> =
+        OPERATIONS.iter().filter(|op| zone.permits(**op)).map(|op| format!("{:?}", op)).collect();
+    if permitted.len() == OPERATIONS.len() {
+        String::from("all")
+    } else {
+        permitted.join(", ")
+    }
+}
+
+/// Whether a rule's zone literals allow it to fire in `zone`
+fn rule_applies(rule: &Rule, zone: Zone) -> bool {
+    rule.body.iter().all(|literal| {
+        if literal.atom.predicate != "zone" {
+            return true;
+        }
+        match literal.atom.args.first() {
+            Some(Term::Const(name)) => (name == zone.name()) != literal.negated,
+            _ => true,
+        }
+    })
+}
+
+fn render_dot(clusters: &[Cluster], edges: &[Edge]) -> String {
+    let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+    let mut out = String::from("digraph rtf {\n  rankdir=LR;\n  node [shape=box, fontname=\"Helvetica\"];\n");
+    for cluster in clusters {
+        out.push_str(&format!("  subgraph cluster_{} {{\n", cluster.id));
+        out.push_str(&format!("    label=\"{}\";\n", escape(&cluster.title)));
+        for node in &cluster.nodes {
+            let label: Vec<String> = node.lines.iter().map(|l| escape(l)).collect();
+            let style = if node.highlight { ", style=bold, penwidth=3" } else { "" };
+            out.push_str(&format!("    {} [label=\"{}\"{}];\n", node.id, label.join("\\n"), style));
+        }
+        out.push_str("  }\n");
+    }
+    for e in edges {
+        let style = if e.dashed { ", style=dashed" } else { "" };
+        out.push_str(&format!("  {} -> {} [label=\"{}\"{}];\n", e.from, e.to, escape(&e.label), style));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn render_mermaid(clusters: &[Cluster], edges: &[Edge]) -> String {
+    let escape = |s: &str| {
+        s.replace('&', "#amp;").replace('"', "#quot;").replace('<', "#lt;").replace('>', "#gt;")
+    };
+    let mut out = String::from("flowchart LR\n");
+    let mut highlighted = Vec::new();
+    for cluster in clusters {
+        out.push_str(&format!("  subgraph {}[\"{}\"]\n", cluster.id, escape(&cluster.title)));
+        for node in &cluster.nodes {
+            let label: Vec<String> = node.lines.iter().map(|l| escape(l)).collect();
+            out.push_str(&format!("    {}[\"{}\"]\n", node.id, label.join("<br/>")));
+            if node.highlight {
+                highlighted.push(node.id.as_str());
+            }
+        }
+        out.push_str("  end\n");
+    }
+    for e in edges {
+        let arrow = if e.dashed { "-.->" } else { "-->" };
+        out.push_str(&format!("  {} {}|\"{}\"| {}\n", e.from, arrow, escape(&e.label), e.to));
+    }
+    if !highlighted.is_empty() {
+        out.push_str("  classDef current stroke-width:3px;\n");
+        out.push_str(&format!("  class {} current;\n", highlighted.join(",")));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::policy::{PolicyEngine, PolicyGovernance};
+    use crate::txo::{IdentityType, Payload, PayloadType, Receiver, Sender, TXO};
+
+    fn make_txo(id: u8) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [1u8; 16],
+            biokey_present: false,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        let receiver = Receiver { identity_type: IdentityType::Node, id: [2u8; 16] };
+        let payload = Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: [id; 32],
+            encrypted: false,
+            envelope: None,
+        };
+        TXO::new([id; 16], sender, receiver, OperationClass::Network, payload)
+    }
+
+    fn context() -> RTFContext {
+        let bundle = PolicyBundle::parse(
+            3,
+            r#"
+            allow :- zone("Z1").
+            allow :- zone("Z2"), operation("Genomic"), encrypted("true").
+            deny("untrusted signer") :- signer(S), not trusted(S).
+            trusted("05050505050505050505050505050505").
+            "#,
+        )
+        .unwrap();
+        let governance = PolicyGovernance::new(Vec::from([([9u8; 16], [0u8; 32])]), 1).unwrap();
+        RTFContext::new(Zone::Z2, MerkleLedger::new([0u8; 32]))
+            .with_policy(PolicyEngine::new(bundle, governance))
+    }
+
+    #[test]
+    fn test_dot_reflects_runtime_configuration() {
+        let ctx = context();
+        let saga = Saga::new([7u8; 16])
+            .step("reserve", make_txo(1), |t: &TXO| t.clone())
+            .step("transfer", make_txo(2), |t: &TXO| t.clone())
+            .step("notify", make_txo(3), |t: &TXO| t.clone());
+        let dot = WorkflowGraph::from_context(&ctx).with_saga(&saga).render(GraphFormat::Dot);
+
+        assert!(dot.starts_with("digraph rtf {"));
+        assert!(dot.contains("Z0 -> Z1 [label=\"promote_zone\"];"));
+        assert!(dot.contains("Z2 -> Z3 [label=\"promote_zone\"];"));
+        assert_eq!(dot.matches("promote_zone").count(), 3);
+        assert!(dot.contains("Z2 [label=\"Z2\\nrollback: yes\\nsignatures: 1\\noperations: Genomic, Network, Compliance\", style=bold"));
+        assert!(dot.contains("Z3 [label=\"Z3\\nrollback: no\\nsignatures: 2\\noperations: Compliance\"]"));
+        assert!(dot.contains("label=\"rollback_txo (Z1, Z2)\""));
+
+        // Z2 sees its own allow rule and the zone-independent deny, not Z1's
+        assert!(dot.contains("policy_Z2 [label=\"policy v3 in Z2\\nallow :- zone(\\\"Z2\\\")"));
+        assert!(dot.contains("policy_Z0 [label=\"policy v3 in Z0\\ndeny("));
+        assert!(!dot.contains("policy_Z0 [label=\"policy v3 in Z0\\nallow"));
+        assert!(dot.contains("(no allow rule: every TXO denied)\"];"));
+
+        assert!(dot.contains(&format!("label=\"Saga {}\";", hex16(&[7u8; 16]))));
+        assert!(dot.contains("saga0_step0 -> saga0_step1 [label=\"commit\"];"));
+        assert!(dot.contains("saga0_step2 -> saga0_comp1 [label=\"on failure\", style=dashed];"));
+        assert!(dot.contains("saga0_comp1 -> saga0_comp0 [label=\"compensate\", style=dashed];"));
+        assert!(!dot.contains("saga0_comp2"));
+    }
+
+    #[test]
+    fn test_mermaid_escapes_and_highlights() {
+        let ctx = context();
+        let mermaid = WorkflowGraph::from_context(&ctx).render(GraphFormat::Mermaid);
+
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("  Z1 -->|\"promote_zone\"| Z2\n"));
+        assert!(mermaid.contains("allow :- zone(#quot;Z1#quot;)."));
+        assert!(!mermaid.contains("zone(\"Z1\")"));
+        assert!(mermaid.contains("  class Z2 current;\n"));
+        assert_eq!(mermaid.matches("subgraph").count(), mermaid.matches("  end\n").count());
+
+        let bare = WorkflowGraph::new().render(GraphFormat::Mermaid);
+        assert!(!bare.contains("policy_"));
+        assert!(!bare.contains("classDef"));
+
+        assert_eq!(GraphFormat::parse("DOT"), Some(GraphFormat::Dot));
+        assert_eq!(GraphFormat::parse("mermaid"), Some(GraphFormat::Mermaid));
+        assert_eq!(GraphFormat::parse("svg"), None);
+    }
+}
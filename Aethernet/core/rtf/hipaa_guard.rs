@@ -0,0 +1,402 @@
+//! RTF HIPAA Minimum-Necessary Guard
+//!
+//! Middleware in front of `RTFContext::execute_txo` for TXOs whose payload
+//! type carries Protected Health Information. Every PHI payload must be
+//! tagged (by content hash) with a sensitivity level; the sender's role and
+//! the declared `AccessPurpose` must match a minimum-necessary rule that
+//! covers that sensitivity (45 CFR 164.502(b), 164.514(d)):
+//!
+//! ```text
+//! grant  ⇔  tag(content_hash) ∧ role(sender) ∧ rule(role, purpose)
+//!           ∧ (purpose = Treatment ∨ sensitivity ≤ rule.max_sensitivity)
+//!           ∧ (sensitivity ≠ Restricted ∨ subject signed with a consent_ref)
+//! ```
+//!
+//! Disclosures for treatment are exempt from the minimum-necessary ceiling
+//! but still need a rule for the role. The guard fails closed: untagged PHI
+//! and unknown senders are denied. Restricted PHI needs an Ed25519 signature
+//! over the TXO by the data subject themselves, verified against the key
+//! registered with `register_subject_key`. Each PHI decision, granted or
+//! denied, is recorded as an `AccessAuditRecord` together with the execution
+//! outcome; non-PHI TXOs pass straight through.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::rtf::api::{RTFContext, RTFError};
+use crate::txo::{AuditEntry, PayloadType, TXO};
+
+/// Permitted use or disclosure of PHI (45 CFR 164.506, 164.512)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessPurpose {
+    /// Treatment by a healthcare provider
+    Treatment,
+    /// Payment processing
+    Payment,
+    /// Healthcare operations (quality review, training)
+    HealthcareOperations,
+    /// IRB-approved research
+    Research,
+    /// Public health activities
+    PublicHealth,
+    /// Required by law
+    RequiredByLaw,
+}
+
+/// PHI sensitivity, ordered from least to most sensitive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PhiSensitivity {
+    /// Limited data set
+    Low,
+    /// Identifiable clinical data
+    Medium,
+    /// Genomic or other high-risk data
+    High,
+    /// Requires the individual's authorization for every access
+    Restricted,
+}
+
+/// PHI tag registered for a payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhiTag {
+    /// Payload content hash the tag applies to
+    pub content_hash: [u8; 32],
+    /// Individual the PHI is about
+    pub subject_id: [u8; 16],
+    /// Sensitivity level
+    pub sensitivity: PhiSensitivity,
+}
+
+/// Minimum-necessary rule: a role may access PHI up to a sensitivity for a purpose
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinimumNecessaryRule {
+    /// Role name
+    pub role: String,
+    /// Purpose the rule covers
+    pub purpose: AccessPurpose,
+    /// Most sensitive PHI the role may access for the purpose
+    pub max_sensitivity: PhiSensitivity,
+}
+
+/// Why PHI access was denied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DenialReason {
+    /// PHI payload has no registered tag
+    UntaggedPhi,
+    /// Sender has no assigned role
+    UnknownRole,
+    /// No rule covers the role and purpose
+    PurposeNotPermitted,
+    /// PHI is more sensitive than the rule allows
+    ExceedsMinimumNecessary,
+    /// Restricted PHI without a verified authorization signed by the subject
+    AuthorizationRequired,
+}
+
+/// Audit record of one PHI access decision (45 CFR 164.312(b))
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessAuditRecord {
+    /// TXO that requested access
+    pub txo_id: [u8; 16],
+    /// Sender of the TXO
+    pub accessor_id: [u8; 16],
+    /// Sender's role, if assigned
+    pub role: Option<String>,
+    /// Declared purpose
+    pub purpose: AccessPurpose,
+    /// PHI payload content hash
+    pub content_hash: [u8; 32],
+    /// TXO timestamp
+    pub timestamp: u64,
+    /// Whether the guard granted access
+    pub granted: bool,
+    /// Denial reason, if denied
+    pub denial: Option<DenialReason>,
+    /// Whether RTF executed the TXO (PHI was actually accessed)
+    pub executed: bool,
+    /// RTF error if a granted TXO failed to execute
+    pub execution_error: Option<RTFError>,
+}
+
+/// Whether a payload type carries PHI
+pub fn is_phi(payload_type: PayloadType) -> bool {
+    matches!(payload_type, PayloadType::Genome)
+}
+
+/// Minimum-necessary enforcement layer for RTF execution
+#[derive(Debug, Default)]
+pub struct HipaaGuard {
+    tags: BTreeMap<[u8; 32], PhiTag>,
+    roles: BTreeMap<[u8; 16], String>,
+    subject_keys: BTreeMap<[u8; 16], [u8; 32]>,
+    rules: Vec<MinimumNecessaryRule>,
+    audit_log: Vec<AccessAuditRecord>,
+}
+
+impl HipaaGuard {
+    /// Create a guard with no tags, roles or rules (denies all PHI)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a PHI tag
+    pub fn tag_phi(&mut self, tag: PhiTag) {
+        self.tags.insert(tag.content_hash, tag);
+    }
+
+    /// Assign a role to a sender identity
+    pub fn assign_role(&mut self, identity: [u8; 16], role: impl Into<String>) {
+        self.roles.insert(identity, role.into());
+    }
+
+    /// Register a data subject's Ed25519 public key for authorizations
+    pub fn register_subject_key(&mut self, subject_id: [u8; 16], public_key: [u8; 32]) {
+        self.subject_keys.insert(subject_id, public_key);
+    }
+
+    /// Add a minimum-necessary rule
+    pub fn permit(&mut self, role: impl Into<String>, purpose: AccessPurpose, max_sensitivity: PhiSensitivity) {
+        self.rules.push(MinimumNecessaryRule { role: role.into(), purpose, max_sensitivity });
+    }
+
+    /// Audit records of all PHI access decisions
+    pub fn audit_log(&self) -> &[AccessAuditRecord] {
+        &self.audit_log
+    }
+
+    /// Decide whether `txo` may access its PHI payload for `purpose`
+    pub fn evaluate(&self, txo: &TXO, purpose: AccessPurpose) -> Result<(), DenialReason> {
+        let tag = self.tags.get(&txo.payload.content_hash).ok_or(DenialReason::UntaggedPhi)?;
+        let role = self.roles.get(&txo.sender.id).ok_or(DenialReason::UnknownRole)?;
+        let rule = self
+            .rules
+            .iter()
+            .find(|r| &r.role == role && r.purpose == purpose)
+            .ok_or(DenialReason::PurposeNotPermitted)?;
+
+        if purpose != AccessPurpose::Treatment && tag.sensitivity > rule.max_sensitivity {
+            return Err(DenialReason::ExceedsMinimumNecessary);
+        }
+        if tag.sensitivity == PhiSensitivity::Restricted && !self.subject_authorized(txo, tag) {
+            return Err(DenialReason::AuthorizationRequired);
+        }
+        Ok(())
+    }
+
+    /// Whether the tagged subject signed `txo` under a consent reference
+    fn subject_authorized(&self, txo: &TXO, tag: &PhiTag) -> bool {
+        let Some(key) = self.subject_keys.get(&tag.subject_id) else {
+            return false;
+        };
+        let signed = txo.signing_bytes();
+        txo.signatures.iter().any(|s| {
+            s.signer_id == tag.subject_id
+                && s.consent_ref.is_some()
+                && txo.verify_ed25519(&signed, s, key).is_ok()
+        })
+    }
+
+    /// Execute a TXO through the guard
+    ///
+    /// # Arguments
+    /// * `ctx` - RTF context that executes the TXO once access is granted
+    /// * `txo` - Transaction object to execute
+    /// * `purpose` - Declared purpose of the access
+    ///
+    /// # Returns
+    /// * `Ok(())` if the TXO carries no PHI or access is granted and RTF executes it
+    /// * `Err(RTFError::PhiAccessDenied)` if the minimum-necessary check fails
+    /// * `Err(RTFError)` from `RTFContext::execute_txo` otherwise
+    pub fn execute(
+        &mut self,
+        ctx: &mut RTFContext,
+        txo: &mut TXO,
+        purpose: AccessPurpose,
+    ) -> Result<(), RTFError> {
+        if !is_phi(txo.payload.payload_type) {
+            return ctx.execute_txo(txo);
+        }
+
+        let decision = self.evaluate(txo, purpose);
+        let mut record = AccessAuditRecord {
+            txo_id: txo.txo_id,
+            accessor_id: txo.sender.id,
+            role: self.roles.get(&txo.sender.id).cloned(),
+            purpose,
+            content_hash: txo.payload.content_hash,
+            timestamp: txo.timestamp,
+            granted: decision.is_ok(),
+            denial: decision.err(),
+            executed: false,
+            execution_error: None,
+        };
+        if decision.is_err() {
+            self.audit_log.push(record);
+            return Err(RTFError::PhiAccessDenied);
+        }
+
+        txo.add_audit_entry(AuditEntry {
+            actor_id: txo.sender.id,
+            action: format!("HIPAA_ACCESS purpose={:?}", purpose),
+            timestamp: txo.timestamp,
+        });
+        let result = ctx.execute_txo(txo);
+        record.executed = result.is_ok();
+        record.execution_error = result.err();
+        self.audit_log.push(record);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::Zone;
+    use crate::txo::{IdentityType, OperationClass, Payload, Receiver, Sender, SignatureType};
+    use ed25519_dalek::SigningKey;
+
+    const SUBJECT: [u8; 16] = [7u8; 16];
+    const SUBJECT_SECRET: [u8; 32] = [17u8; 32];
+
+    fn txo(sender: u8, payload_type: PayloadType, content: u8) -> TXO {
+        TXO::new(
+            [content; 16],
+            Sender { identity_type: IdentityType::Operator, id: [sender; 16], biokey_present: false, fido2_signed: true, zk_proof: None },
+            Receiver { identity_type: IdentityType::Node, id: [9u8; 16] },
+            OperationClass::Genomic,
+            Payload { payload_type, content_hash: [content; 32], encrypted: true, envelope: None },
+        )
+    }
+
+    /// Physician (sender 1) may treat up to Medium; billing (sender 2) may
+    /// bill up to Medium. Content 1 is High, content 2 Restricted.
+    fn guard() -> HipaaGuard {
+        let mut guard = HipaaGuard::new();
+        guard.tag_phi(PhiTag { content_hash: [1u8; 32], subject_id: SUBJECT, sensitivity: PhiSensitivity::High });
+        guard.tag_phi(PhiTag { content_hash: [2u8; 32], subject_id: SUBJECT, sensitivity: PhiSensitivity::Restricted });
+        guard.assign_role([1u8; 16], "physician");
+        guard.assign_role([2u8; 16], "billing");
+        guard.permit("physician", AccessPurpose::Treatment, PhiSensitivity::Medium);
+        guard.permit("billing", AccessPurpose::Payment, PhiSensitivity::Medium);
+        guard.register_subject_key(SUBJECT, SigningKey::from_bytes(&SUBJECT_SECRET).verifying_key().to_bytes());
+        guard
+    }
+
+    fn ctx() -> RTFContext {
+        RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]))
+    }
+
+    /// Sign `txo` as `signer_id` with `secret` under a consent reference
+    fn consent(txo: &mut TXO, signer_id: [u8; 16], secret: &[u8; 32]) {
+        txo.sign_ed25519(SignatureType::Biokey, signer_id, secret);
+        txo.signatures.last_mut().unwrap().consent_ref = Some([8u8; 32]);
+    }
+
+    #[test]
+    fn test_treatment_exempt_from_ceiling() {
+        let (mut ctx, mut guard) = (ctx(), guard());
+        let mut access = txo(1, PayloadType::Genome, 1);
+        assert_eq!(guard.execute(&mut ctx, &mut access, AccessPurpose::Treatment), Ok(()));
+        assert!(access.audit_trail.iter().any(|e| e.action == "HIPAA_ACCESS purpose=Treatment"));
+
+        let record = &guard.audit_log()[0];
+        assert!(record.granted && record.executed);
+        assert_eq!((record.denial, record.execution_error), (None, None));
+        assert_eq!(record.role.as_deref(), Some("physician"));
+    }
+
+    #[test]
+    fn test_untagged_phi_denied() {
+        let (mut ctx, mut guard) = (ctx(), guard());
+        assert_eq!(guard.execute(&mut ctx, &mut txo(1, PayloadType::Genome, 3), AccessPurpose::Treatment), Err(RTFError::PhiAccessDenied));
+        let record = &guard.audit_log()[0];
+        assert_eq!((record.granted, record.executed, record.denial), (false, false, Some(DenialReason::UntaggedPhi)));
+    }
+
+    #[test]
+    fn test_unknown_role_denied() {
+        let (mut ctx, mut guard) = (ctx(), guard());
+        assert_eq!(guard.execute(&mut ctx, &mut txo(3, PayloadType::Genome, 1), AccessPurpose::Treatment), Err(RTFError::PhiAccessDenied));
+        let record = &guard.audit_log()[0];
+        assert_eq!((record.denial, record.role.as_deref()), (Some(DenialReason::UnknownRole), None));
+        assert!(!record.executed);
+    }
+
+    #[test]
+    fn test_purpose_not_permitted() {
+        let (mut ctx, mut guard) = (ctx(), guard());
+        assert_eq!(guard.execute(&mut ctx, &mut txo(2, PayloadType::Genome, 1), AccessPurpose::Treatment), Err(RTFError::PhiAccessDenied));
+        let record = &guard.audit_log()[0];
+        assert_eq!((record.denial, record.role.as_deref()), (Some(DenialReason::PurposeNotPermitted), Some("billing")));
+    }
+
+    #[test]
+    fn test_exceeds_minimum_necessary() {
+        let (mut ctx, mut guard) = (ctx(), guard());
+        assert_eq!(guard.execute(&mut ctx, &mut txo(2, PayloadType::Genome, 1), AccessPurpose::Payment), Err(RTFError::PhiAccessDenied));
+        let record = &guard.audit_log()[0];
+        assert_eq!((record.granted, record.denial), (false, Some(DenialReason::ExceedsMinimumNecessary)));
+        assert_eq!(ctx.ledger.node_count(), 0);
+    }
+
+    #[test]
+    fn test_restricted_requires_subject_signature() {
+        let (mut ctx, mut guard) = (ctx(), guard());
+
+        let mut unsigned = txo(1, PayloadType::Genome, 2);
+        assert_eq!(guard.evaluate(&unsigned, AccessPurpose::Treatment), Err(DenialReason::AuthorizationRequired));
+
+        // A consent reference without a valid subject signature is not authorization
+        unsigned.add_signature(crate::txo::Signature {
+            sig_type: SignatureType::Biokey,
+            signer_id: SUBJECT,
+            signature: alloc::vec![0u8; 64],
+            consent_ref: Some([8u8; 32]),
+        });
+        assert_eq!(guard.evaluate(&unsigned, AccessPurpose::Treatment), Err(DenialReason::AuthorizationRequired));
+
+        let mut authorized = txo(1, PayloadType::Genome, 2);
+        consent(&mut authorized, SUBJECT, &SUBJECT_SECRET);
+        assert_eq!(guard.execute(&mut ctx, &mut authorized, AccessPurpose::Treatment), Ok(()));
+        assert!(guard.audit_log()[0].executed);
+    }
+
+    #[test]
+    fn test_consent_from_non_subject_denied() {
+        let (mut ctx, mut guard) = (ctx(), guard());
+
+        // The physician consents on the subject's behalf with their own key
+        let mut txo = txo(1, PayloadType::Genome, 2);
+        consent(&mut txo, [1u8; 16], &[21u8; 32]);
+        // Claiming the subject's id does not help without the subject's key
+        consent(&mut txo, SUBJECT, &[21u8; 32]);
+
+        assert_eq!(guard.execute(&mut ctx, &mut txo, AccessPurpose::Treatment), Err(RTFError::PhiAccessDenied));
+        let record = &guard.audit_log()[0];
+        assert_eq!((record.denial, record.executed), (Some(DenialReason::AuthorizationRequired), false));
+    }
+
+    #[test]
+    fn test_failed_execution_recorded() {
+        let (mut ctx, mut guard) = (ctx(), guard());
+        let mut access = txo(1, PayloadType::Genome, 1);
+        access.dual_control_required = true;
+
+        assert_eq!(guard.execute(&mut ctx, &mut access, AccessPurpose::Treatment), Err(RTFError::DualControlFailure));
+        let record = &guard.audit_log()[0];
+        assert!(record.granted && !record.executed);
+        assert_eq!(record.execution_error, Some(RTFError::DualControlFailure));
+    }
+
+    #[test]
+    fn test_non_phi_passes_unaudited() {
+        let (mut ctx, mut guard) = (ctx(), guard());
+        assert_eq!(guard.execute(&mut ctx, &mut txo(3, PayloadType::Metadata, 4), AccessPurpose::Payment), Ok(()));
+        assert!(guard.audit_log().is_empty());
+    }
+}
@@ -1,6 +1,19 @@
 //! RTF (Reversible Transaction Framework) module
 
 pub mod api;
+pub mod audit_ingest;
+pub mod capability;
+#[cfg(feature = "std")]
+pub mod dispatch;
 pub mod enclave_main;
+pub mod events;
+pub mod graph;
+pub mod hipaa_guard;
+pub mod nonce;
+pub mod policy;
+pub mod preauth;
+pub mod residency;
+pub mod saga;
+pub mod scanner;
 
 pub use api::*;
@@ -0,0 +1,273 @@
+//! RTF Nonce Accounts
+//!
+//! Per-identity ordering for TXO submission. Each sender carries a nonce
+//! account; once accounts are enabled on an `RTFContext`, every executed TXO
+//! must carry the sender's next nonce (starting at 0), so TXOs from one
+//! identity execute exactly once and in the order the sender numbered them.
+//!
+//! Concurrent submissions that arrive out of order are held in a reordering
+//! window: `RTFContext::submit_txo` buffers a TXO up to `window` nonces ahead
+//! of the next expected one and executes held TXOs as soon as the gap before
+//! them closes. If a due TXO fails to execute, its sender is stalled: later
+//! submissions are refused with `SenderStalled` until the failed nonce is
+//! resubmitted, so held TXOs never wait silently. Nonces are bound into the signing bytes (field 14), so a
+//! relay cannot renumber a signed TXO. Rollback does not rewind accounts;
+//! a consumed nonce stays consumed.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::rtf::api::RTFError;
+use crate::txo::TXO;
+
+/// One sender's nonce state
+#[derive(Debug, Clone, Default)]
+struct NonceAccount {
+    /// Next nonce `execute_txo` accepts
+    next: u64,
+    /// Out-of-order submissions awaiting their turn, by nonce
+    held: BTreeMap<u64, TXO>,
+    /// The TXO carrying `next` failed and must be resubmitted
+    stalled: bool,
+}
+
+/// Nonce accounts for every sender seen
+#[derive(Debug, Clone, Default)]
+pub struct NonceAccounts {
+    /// Nonces a submission may run ahead of the next expected one
+    window: u64,
+    /// Accounts by sender UUID
+    accounts: BTreeMap<[u8; 16], NonceAccount>,
+}
+
+impl NonceAccounts {
+    /// Create accounts with a reordering window (0 = strictly in order)
+    pub fn new(window: u64) -> Self {
+        Self { window, accounts: BTreeMap::new() }
+    }
+
+    /// Reordering window
+    pub fn window(&self) -> u64 {
+        self.window
+    }
+
+    /// Next nonce expected from `sender_id`
+    pub fn expected(&self, sender_id: &[u8; 16]) -> u64 {
+        self.accounts.get(sender_id).map_or(0, |a| a.next)
+    }
+
+    /// Validate that a TXO carries its sender's next nonce
+    ///
+    /// # Returns
+    /// * `Ok(nonce)` if the TXO may execute now
+    /// * `Err(RTFError::MissingNonce)` if the TXO has no nonce
+    /// * `Err(RTFError::StaleNonce)` if the nonce is already consumed
+    /// * `Err(RTFError::FutureNonce)` if earlier nonces are still outstanding
+    pub fn check(&self, txo: &TXO) -> Result<u64, RTFError> {
+        let got = txo.nonce.ok_or(RTFError::MissingNonce)?;
+        let expected = self.expected(&txo.sender.id);
+        if got < expected {
+            Err(RTFError::StaleNonce { expected, got })
+        } else if got > expected {
+            Err(RTFError::FutureNonce { expected, got })
+        } else {
+            Ok(got)
+        }
+    }
+
+    /// Consume the sender's next nonce after successful execution
+    pub(crate) fn advance(&mut self, sender_id: [u8; 16]) {
+        self.accounts.entry(sender_id).or_default().next += 1;
+    }
+
+    /// Mark `sender_id` stalled after its due TXO failed to execute
+    pub(crate) fn stall(&mut self, sender_id: [u8; 16]) {
+        self.accounts.entry(sender_id).or_default().stalled = true;
+    }
+
+    /// Nonce that must be resubmitted before `sender_id` can progress, if stalled
+    pub fn stalled(&self, sender_id: &[u8; 16]) -> Option<u64> {
+        self.accounts.get(sender_id).filter(|a| a.stalled).map(|a| a.next)
+    }
+
+    /// Hold a submission until its nonce is due
+    ///
+    /// # Returns
+    /// * `Ok(())` if the TXO is buffered (it may already be due)
+    /// * `Err(RTFError::SenderStalled)` if the sender is stalled and this is not the failed nonce
+    /// * `Err(RTFError::StaleNonce)` if the nonce is consumed or already held
+    /// * `Err(RTFError::FutureNonce)` if the nonce lies beyond the window
+    pub fn hold(&mut self, txo: TXO) -> Result<(), RTFError> {
+        let got = txo.nonce.ok_or(RTFError::MissingNonce)?;
+        let account = self.accounts.entry(txo.sender.id).or_default();
+        let expected = account.next;
+        if account.stalled {
+            if got != expected {
+                return Err(RTFError::SenderStalled { nonce: expected });
+            }
+            account.stalled = false;
+        }
+        if got < expected || account.held.contains_key(&got) {
+            return Err(RTFError::StaleNonce { expected, got });
+        }
+        if got - expected > self.window {
+            return Err(RTFError::FutureNonce { expected, got });
+        }
+        account.held.insert(got, txo);
+        Ok(())
+    }
+
+    /// Take the held TXO carrying `sender_id`'s next nonce, if any
+    pub fn pop_ready(&mut self, sender_id: &[u8; 16]) -> Option<TXO> {
+        let account = self.accounts.get_mut(sender_id)?;
+        account.held.remove(&account.next)
+    }
+
+    /// Missing nonces between the next expected and the highest held
+    ///
+    /// Non-empty means later submissions are blocked waiting on these.
+    pub fn gaps(&self, sender_id: &[u8; 16]) -> Vec<u64> {
+        let Some(account) = self.accounts.get(sender_id) else {
+            return Vec::new();
+        };
+        let Some(&highest) = account.held.keys().next_back() else {
+            return Vec::new();
+        };
+        (account.next..highest).filter(|n| !account.held.contains_key(n)).collect()
+    }
+
+    /// Number of TXOs held for `sender_id`
+    pub fn held_count(&self, sender_id: &[u8; 16]) -> usize {
+        self.accounts.get(sender_id).map_or(0, |a| a.held.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::{RTFContext, Zone};
+    use crate::txo::{
+        IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, SignatureType,
+    };
+    use ed25519_dalek::SigningKey;
+
+    fn make_txo(sender: u8, nonce: Option<u64>) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [sender; 16],
+            biokey_present: false,
+            fido2_signed: true,
+            zk_proof: None,
+        };
+        let receiver = Receiver { identity_type: IdentityType::Node, id: [2u8; 16] };
+        let payload = Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: [nonce.unwrap_or(0) as u8; 32],
+            encrypted: false,
+            envelope: None,
+        };
+        let mut txo = TXO::new([nonce.unwrap_or(99) as u8; 16], sender, receiver, OperationClass::Network, payload);
+        txo.nonce = nonce;
+        txo.sign_ed25519(SignatureType::Fido2, [3u8; 16], &[11u8; 32]);
+        txo
+    }
+
+    #[test]
+    fn test_execution_enforces_order() {
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32])).with_nonces(NonceAccounts::new(0));
+
+        assert_eq!(ctx.execute_txo(&mut make_txo(1, None)), Err(RTFError::MissingNonce));
+        assert_eq!(
+            ctx.execute_txo(&mut make_txo(1, Some(1))),
+            Err(RTFError::FutureNonce { expected: 0, got: 1 })
+        );
+        ctx.execute_txo(&mut make_txo(1, Some(0))).unwrap();
+        assert_eq!(
+            ctx.execute_txo(&mut make_txo(1, Some(0))),
+            Err(RTFError::StaleNonce { expected: 1, got: 0 })
+        );
+
+        // Accounts are per identity
+        ctx.execute_txo(&mut make_txo(2, Some(0))).unwrap();
+        let accounts = ctx.nonces.as_ref().unwrap();
+        assert_eq!(accounts.expected(&[1u8; 16]), 1);
+        assert_eq!(accounts.expected(&[2u8; 16]), 1);
+
+        // Renumbering a signed TXO breaks its signature
+        let public = SigningKey::from_bytes(&[11u8; 32]).verifying_key().to_bytes();
+        let mut relayed = make_txo(1, Some(5));
+        let signature = relayed.signatures[0].clone();
+        assert!(relayed.verify_ed25519(&relayed.signing_bytes(), &signature, &public).is_ok());
+        relayed.nonce = Some(1);
+        assert!(relayed.verify_ed25519(&relayed.signing_bytes(), &signature, &public).is_err());
+    }
+
+    #[test]
+    fn test_reordering_window_and_gaps() {
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32])).with_nonces(NonceAccounts::new(2));
+        let sender = [1u8; 16];
+
+        // Arrives ahead of its predecessors: held, gaps reported
+        assert!(ctx.submit_txo(make_txo(1, Some(2))).unwrap().is_empty());
+        let accounts = ctx.nonces.as_ref().unwrap();
+        assert_eq!(accounts.gaps(&sender), vec![0, 1]);
+        assert_eq!(accounts.held_count(&sender), 1);
+
+        assert_eq!(
+            ctx.submit_txo(make_txo(1, Some(3))).err(),
+            Some(RTFError::FutureNonce { expected: 0, got: 3 })
+        );
+        assert_eq!(
+            ctx.submit_txo(make_txo(1, Some(2))).err(),
+            Some(RTFError::StaleNonce { expected: 0, got: 2 })
+        );
+
+        assert!(ctx.submit_txo(make_txo(1, Some(1))).unwrap().is_empty());
+        assert_eq!(ctx.nonces.as_ref().unwrap().gaps(&sender), vec![0]);
+
+        // Closing the gap commits everything held, in nonce order
+        let committed = ctx.submit_txo(make_txo(1, Some(0))).unwrap();
+        let order: Vec<Option<u64>> = committed.iter().map(|t| t.nonce).collect();
+        assert_eq!(order, vec![Some(0), Some(1), Some(2)]);
+        assert_eq!(ctx.ledger.node_count(), 3);
+
+        let accounts = ctx.nonces.as_ref().unwrap();
+        assert_eq!(accounts.expected(&sender), 3);
+        assert!(accounts.gaps(&sender).is_empty());
+        assert_eq!(
+            ctx.submit_txo(make_txo(1, Some(1))).err(),
+            Some(RTFError::StaleNonce { expected: 3, got: 1 })
+        );
+    }
+
+    #[test]
+    fn test_failed_due_txo_stalls_sender() {
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32])).with_nonces(NonceAccounts::new(2));
+        let sender = [1u8; 16];
+
+        assert!(ctx.submit_txo(make_txo(1, Some(1))).unwrap().is_empty());
+
+        // Nonce 0 is due but lacks its second approval, so it fails and the sender stalls
+        let mut failing = make_txo(1, Some(0));
+        failing.dual_control_required = true;
+        assert_eq!(ctx.submit_txo(failing).err(), Some(RTFError::DualControlFailure));
+        let accounts = ctx.nonces.as_ref().unwrap();
+        assert_eq!(accounts.stalled(&sender), Some(0));
+        assert_eq!(accounts.held_count(&sender), 1);
+        assert_eq!(
+            ctx.submit_txo(make_txo(1, Some(2))).err(),
+            Some(RTFError::SenderStalled { nonce: 0 })
+        );
+
+        // Resubmitting the failed nonce clears the stall and drains the queue
+        let committed = ctx.submit_txo(make_txo(1, Some(0))).unwrap();
+        let order: Vec<Option<u64>> = committed.iter().map(|t| t.nonce).collect();
+        assert_eq!(order, vec![Some(0), Some(1)]);
+        let accounts = ctx.nonces.as_ref().unwrap();
+        assert_eq!(accounts.stalled(&sender), None);
+        assert_eq!(accounts.expected(&sender), 2);
+    }
+}
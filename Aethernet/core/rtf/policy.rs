@@ -0,0 +1,1016 @@
+//! RTF Policy Engine
+//!
+//! Declarative, Datalog-style authorization evaluated at TXO execution time.
+//! Deployments write rules over request attributes; `RTFContext::execute_txo`
+//! consults the active bundle after zone and signature checks.
+//!
+//! ```text
+//! % Staging accepts everything, production needs encrypted genomic payloads
+//! allow :- zone("Z1").
+//! allow :- zone("Z2"), operation("Genomic"), encrypted("true").
+//! deny("untrusted signer") :- signer(S), not trusted(S).
+//! trusted("05050505050505050505050505050505").
+//! ```
+//!
+//! A TXO is authorized iff some `allow` fact is derived and no `deny` fact is.
+//! Negation is only permitted over predicates no rule derives, so naive
+//! fixpoint evaluation is sound. Request predicates are reserved: rules may
+//! read them but never derive them.
+//!
+//! Every decision is logged against the SHA3-256 hash of the active bundle.
+//! Bundles are replaced only through governance: an Admin/Control TXO whose
+//! payload hash commits to the new bundle and which carries Ed25519
+//! signatures from a threshold of registered governors.
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use sha3::{Digest, Sha3_256};
+
+use crate::rtf::api::Zone;
+use crate::rtf::saga::hex16;
+use crate::txo::{OperationClass, PayloadType, TXO};
+
+/// Domain separator for bundle hashing
+const BUNDLE_DOMAIN: &[u8] = b"AETHERNET-POLICY-BUNDLE-v1";
+
+/// Upper bound on facts held during a single evaluation
+pub const MAX_FACTS: usize = 4096;
+
+/// Predicates populated from the request; rules may not derive them
+pub const REQUEST_PREDICATES: &[&str] = &[
+    "txo",
+    "zone",
+    "operation",
+    "sender",
+    "sender_type",
+    "receiver",
+    "receiver_type",
+    "payload_type",
+    "encrypted",
+    "biokey",
+    "fido2",
+    "dual_control",
+    "reversible",
+    "signer",
+    "signature_count",
+];
+
+/// Policy error types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyError {
+    /// Bundle source failed to parse at the given line
+    Parse {
+        /// 1-based source line
+        line: u32,
+    },
+    /// A head or negated variable is not bound by a positive body literal
+    UnsafeVariable,
+    /// Negation over a predicate derived by some rule
+    NegatedDerivedPredicate,
+    /// A rule derives a reserved request predicate
+    ReservedPredicate,
+    /// Governor set or threshold is unusable
+    InvalidThreshold,
+    /// Update TXO is not an Admin-class Control payload
+    NotPolicyProposal,
+    /// Update TXO payload hash does not commit to the bundle
+    BundleHashMismatch,
+    /// Bundle version does not advance the active version
+    StaleVersion,
+    /// Fewer than `threshold` distinct governors signed the update
+    InsufficientApprovals,
+}
+
+/// Rule term
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Term {
+    /// Constant string value
+    Const(String),
+    /// Variable (uppercase or `_`-prefixed identifier)
+    Var(String),
+}
+
+/// Predicate applied to terms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Atom {
+    /// Predicate name
+    pub predicate: String,
+    /// Arguments
+    pub args: Vec<Term>,
+}
+
+/// Body literal, optionally negated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Literal {
+    /// `not` prefix present
+    pub negated: bool,
+    /// Underlying atom
+    pub atom: Atom,
+}
+
+/// Horn clause `head :- body`; an empty body is a ground fact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// Derived atom
+    pub head: Atom,
+    /// Conjunction of literals
+    pub body: Vec<Literal>,
+}
+
+/// Ground fact
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fact {
+    /// Predicate name
+    pub predicate: String,
+    /// Constant arguments
+    pub args: Vec<String>,
+}
+
+impl Fact {
+    fn new(predicate: &str, args: &[&str]) -> Self {
+        Self {
+            predicate: String::from(predicate),
+            args: args.iter().map(|a| String::from(*a)).collect(),
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Const(value) => write!(f, "\"{}\"", value),
+            Term::Var(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.predicate)?;
+        if !self.args.is_empty() {
+            write!(f, "(")?;
+            for (i, arg) in self.args.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", arg)?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.head)?;
+        for (i, literal) in self.body.iter().enumerate() {
+            write!(f, "{}", if i == 0 { " :- " } else { ", " })?;
+            if literal.negated {
+                write!(f, "not ")?;
+            }
+            write!(f, "{}", literal.atom)?;
+        }
+        write!(f, ".")
+    }
+}
+
+impl fmt::Display for Fact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.predicate)?;
+        if !self.args.is_empty() {
+            write!(f, "(")?;
+            for (i, arg) in self.args.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "\"{}\"", arg)?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Versioned, validated rule set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyBundle {
+    /// Monotonic bundle version
+    pub version: u64,
+    /// Rules in source order
+    pub rules: Vec<Rule>,
+}
+
+impl PolicyBundle {
+    /// Parse and validate a bundle from rule source
+    ///
+    /// # Arguments
+    /// * `version` - Bundle version
+    /// * `source` - Rule text (`%` starts a line comment)
+    ///
+    /// # Returns
+    /// * `Ok(PolicyBundle)` if every rule parses and is safe
+    pub fn parse(version: u64, source: &str) -> Result<Self, PolicyError> {
+        let rules = Parser::new(source).rules()?;
+        let bundle = Self { version, rules };
+        bundle.validate()?;
+        Ok(bundle)
+    }
+
+    /// SHA3-256 over the version and canonical rendering of every rule
+    ///
+    /// Formatting and comments in the source do not affect the hash.
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(BUNDLE_DOMAIN);
+        hasher.update(self.version.to_le_bytes());
+        for rule in &self.rules {
+            hasher.update(format!("{}", rule).as_bytes());
+            hasher.update(b"\n");
+        }
+        hasher.finalize().into()
+    }
+
+    fn validate(&self) -> Result<(), PolicyError> {
+        let derived: BTreeSet<&str> = self
+            .rules
+            .iter()
+            .filter(|rule| !rule.body.is_empty())
+            .map(|rule| rule.head.predicate.as_str())
+            .collect();
+
+        for rule in &self.rules {
+            if REQUEST_PREDICATES.contains(&rule.head.predicate.as_str()) {
+                return Err(PolicyError::ReservedPredicate);
+            }
+
+            let bound: BTreeSet<&str> = rule
+                .body
+                .iter()
+                .filter(|literal| !literal.negated)
+                .flat_map(|literal| vars(&literal.atom))
+                .collect();
+
+            if vars(&rule.head).any(|v| !bound.contains(v)) {
+                return Err(PolicyError::UnsafeVariable);
+            }
+            for literal in rule.body.iter().filter(|literal| literal.negated) {
+                if derived.contains(literal.atom.predicate.as_str()) {
+                    return Err(PolicyError::NegatedDerivedPredicate);
+                }
+                if vars(&literal.atom).any(|v| !bound.contains(v)) {
+                    return Err(PolicyError::UnsafeVariable);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluate the bundle over `request` facts to a fixpoint
+    ///
+    /// Returns `None` if evaluation would exceed `MAX_FACTS`.
+    fn evaluate(&self, request: Vec<Fact>) -> Option<BTreeSet<Fact>> {
+        // Ground facts are extensional: seed them so negation sees them
+        let mut facts: BTreeSet<Fact> = request.into_iter().collect();
+        for rule in self.rules.iter().filter(|rule| rule.body.is_empty()) {
+            facts.insert(ground(&rule.head, &[]));
+        }
+
+        loop {
+            let mut derived = Vec::new();
+            for rule in self.rules.iter().filter(|rule| !rule.body.is_empty()) {
+                let mut bindings = Vec::new();
+                solve(rule, 0, &facts, &mut bindings, &mut derived);
+            }
+
+            let before = facts.len();
+            facts.extend(derived);
+            if facts.len() > MAX_FACTS {
+                return None;
+            }
+            if facts.len() == before {
+                return Some(facts);
+            }
+        }
+    }
+}
+
+fn vars(atom: &Atom) -> impl Iterator<Item = &str> {
+    atom.args.iter().filter_map(|term| match term {
+        Term::Var(name) => Some(name.as_str()),
+        Term::Const(_) => None,
+    })
+}
+
+/// Depth-first join of positive literals, then negation checks
+fn solve(
+    rule: &Rule,
+    index: usize,
+    facts: &BTreeSet<Fact>,
+    bindings: &mut Vec<(String, String)>,
+    out: &mut Vec<Fact>,
+) {
+    let positives = rule.body.iter().filter(|literal| !literal.negated);
+    let Some(literal) = positives.clone().nth(index) else {
+        let blocked = rule
+            .body
+            .iter()
+            .filter(|literal| literal.negated)
+            .any(|literal| facts.contains(&ground(&literal.atom, bindings)));
+        if !blocked {
+            out.push(ground(&rule.head, bindings));
+        }
+        return;
+    };
+
+    for fact in facts.iter() {
+        if fact.predicate != literal.atom.predicate || fact.args.len() != literal.atom.args.len() {
+            continue;
+        }
+        let mark = bindings.len();
+        if unify(&literal.atom, fact, bindings) {
+            solve(rule, index + 1, facts, bindings, out);
+        }
+        bindings.truncate(mark);
+    }
+}
+
+fn lookup<'a>(bindings: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    bindings.iter().find(|(var, _)| var == name).map(|(_, value)| value.as_str())
+}
+
+fn unify(atom: &Atom, fact: &Fact, bindings: &mut Vec<(String, String)>) -> bool {
+    for (term, value) in atom.args.iter().zip(fact.args.iter()) {
+        match term {
+            Term::Const(expected) => {
+                if expected != value {
+                    return false;
+                }
+            }
+            Term::Var(name) if name == "_" => {}
+            Term::Var(name) => match lookup(bindings, name) {
+                Some(bound) if bound != value => return false,
+                Some(_) => {}
+                None => bindings.push((name.clone(), value.clone())),
+            },
+        }
+    }
+    true
+}
+
+fn ground(atom: &Atom, bindings: &[(String, String)]) -> Fact {
+    Fact {
+        predicate: atom.predicate.clone(),
+        args: atom
+            .args
+            .iter()
+            .map(|term| match term {
+                Term::Const(value) => value.clone(),
+                Term::Var(name) => String::from(lookup(bindings, name).unwrap_or("")),
+            })
+            .collect(),
+    }
+}
+
+/// Request facts for a TXO executing in `zone`
+///
+/// # Arguments
+/// * `txo` - Transaction being executed
+/// * `zone` - Current RTF zone
+///
+/// # Returns
+/// * Facts over `REQUEST_PREDICATES`
+pub fn request_facts(txo: &TXO, zone: Zone) -> Vec<Fact> {
+    let flag = |value: bool| if value { "true" } else { "false" };
+    let zone = zone.name();
+    let operation = match txo.operation_class {
+        OperationClass::Genomic => "Genomic",
+        OperationClass::Network => "Network",
+        OperationClass::Compliance => "Compliance",
+        OperationClass::Admin => "Admin",
+    };
+    let payload_type = match txo.payload.payload_type {
+        PayloadType::Genome => "Genome",
+        PayloadType::Metadata => "Metadata",
+        PayloadType::Control => "Control",
+        PayloadType::Audit => "Audit",
+    };
+
+    let mut facts = Vec::from([
+        Fact::new("txo", &[&hex16(&txo.txo_id)]),
+        Fact::new("zone", &[zone]),
+        Fact::new("operation", &[operation]),
+        Fact::new("sender", &[&hex16(&txo.sender.id)]),
+        Fact::new("sender_type", &[&format!("{:?}", txo.sender.identity_type)]),
+        Fact::new("receiver", &[&hex16(&txo.receiver.id)]),
+        Fact::new("receiver_type", &[&format!("{:?}", txo.receiver.identity_type)]),
+        Fact::new("payload_type", &[payload_type]),
+        Fact::new("encrypted", &[flag(txo.payload.encrypted)]),
+        Fact::new("biokey", &[flag(txo.sender.biokey_present)]),
+        Fact::new("fido2", &[flag(txo.sender.fido2_signed)]),
+        Fact::new("dual_control", &[flag(txo.dual_control_required)]),
+        Fact::new("reversible", &[flag(txo.reversibility_flag)]),
+        Fact::new("signature_count", &[&format!("{}", txo.signatures.len())]),
+    ]);
+    for signature in &txo.signatures {
+        facts.push(Fact::new("signer", &[&hex16(&signature.signer_id)]));
+    }
+    facts
+}
+
+/// Governors allowed to approve bundle updates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyGovernance {
+    governors: Vec<([u8; 16], [u8; 32])>,
+    threshold: usize,
+}
+
+impl PolicyGovernance {
+    /// Register governors as `(signer_id, ed25519_public_key)` pairs
+    ///
+    /// # Arguments
+    /// * `governors` - Governor identities and keys
+    /// * `threshold` - Distinct governor signatures required per update
+    pub fn new(governors: Vec<([u8; 16], [u8; 32])>, threshold: usize) -> Result<Self, PolicyError> {
+        if threshold == 0 || threshold > governors.len() {
+            return Err(PolicyError::InvalidThreshold);
+        }
+        Ok(Self { governors, threshold })
+    }
+
+    /// Count distinct governors with a valid signature on `proposal`
+    fn approvals(&self, proposal: &TXO) -> usize {
+        let signed = proposal.signing_bytes();
+        self.governors
+            .iter()
+            .filter(|(id, key)| {
+                proposal
+                    .signatures
+                    .iter()
+                    .filter(|sig| sig.signer_id == *id)
+                    .any(|sig| proposal.verify_ed25519(&signed, sig, key).is_ok())
+            })
+            .count()
+    }
+}
+
+/// Outcome of evaluating one TXO
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decision {
+    /// Authorization result
+    pub allowed: bool,
+    /// Derived `allow`/`deny` facts, rendered
+    pub reasons: Vec<String>,
+}
+
+/// Decision log entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionRecord {
+    /// Evaluated TXO
+    pub txo_id: [u8; 16],
+    /// TXO timestamp
+    pub timestamp: u64,
+    /// Zone at evaluation
+    pub zone: Zone,
+    /// Active bundle version
+    pub bundle_version: u64,
+    /// Active bundle hash
+    pub bundle_hash: [u8; 32],
+    /// Decision
+    pub decision: Decision,
+}
+
+/// Record of an applied bundle update
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolicyUpdateRecord {
+    /// Governance proposal TXO
+    pub proposal_id: [u8; 16],
+    /// Version replaced
+    pub from_version: u64,
+    /// Version installed
+    pub to_version: u64,
+    /// Hash of the installed bundle
+    pub bundle_hash: [u8; 32],
+}
+
+/// Active bundle plus governance and logs
+#[derive(Debug, Clone)]
+pub struct PolicyEngine {
+    bundle: PolicyBundle,
+    bundle_hash: [u8; 32],
+    governance: PolicyGovernance,
+    decisions: Vec<DecisionRecord>,
+    updates: Vec<PolicyUpdateRecord>,
+}
+
+impl PolicyEngine {
+    /// Create an engine with a deployment-provisioned genesis bundle
+    pub fn new(genesis: PolicyBundle, governance: PolicyGovernance) -> Self {
+        Self {
+            bundle_hash: genesis.hash(),
+            bundle: genesis,
+            governance,
+            decisions: Vec::new(),
+            updates: Vec::new(),
+        }
+    }
+
+    /// Active bundle version
+    pub fn active_version(&self) -> u64 {
+        self.bundle.version
+    }
+
+    /// Active bundle hash
+    pub fn active_hash(&self) -> [u8; 32] {
+        self.bundle_hash
+    }
+
+    /// Active bundle
+    pub fn active_bundle(&self) -> &PolicyBundle {
+        &self.bundle
+    }
+
+    /// Evaluate without logging
+    ///
+    /// # Arguments
+    /// * `txo` - Transaction to authorize
+    /// * `zone` - Current RTF zone
+    ///
+    /// # Returns
+    /// * `Decision`; evaluation overflow denies
+    pub fn evaluate(&self, txo: &TXO, zone: Zone) -> Decision {
+        let Some(facts) = self.bundle.evaluate(request_facts(txo, zone)) else {
+            return Decision {
+                allowed: false,
+                reasons: Vec::from([String::from("deny(\"evaluation limit\")")]),
+            };
+        };
+
+        let mut allowed = false;
+        let mut denied = false;
+        let mut reasons = Vec::new();
+        for fact in &facts {
+            match fact.predicate.as_str() {
+                "allow" => allowed = true,
+                "deny" => denied = true,
+                _ => continue,
+            }
+            reasons.push(format!("{}", fact));
+        }
+
+        Decision {
+            allowed: allowed && !denied,
+            reasons,
+        }
+    }
+
+    /// Evaluate and append to the decision log
+    ///
+    /// # Returns
+    /// * `true` if the TXO is authorized
+    pub fn authorize(&mut self, txo: &TXO, zone: Zone) -> bool {
+        let decision = self.evaluate(txo, zone);
+        let allowed = decision.allowed;
+        self.decisions.push(DecisionRecord {
+            txo_id: txo.txo_id,
+            timestamp: txo.timestamp,
+            zone,
+            bundle_version: self.bundle.version,
+            bundle_hash: self.bundle_hash,
+            decision,
+        });
+        allowed
+    }
+
+    /// Replace the active bundle under governance approval
+    ///
+    /// # Arguments
+    /// * `proposal` - Admin-class Control TXO whose payload hash is `bundle.hash()`
+    /// * `bundle` - Replacement bundle
+    ///
+    /// # Returns
+    /// * `Ok(PolicyUpdateRecord)` once the bundle is active
+    pub fn apply_update(
+        &mut self,
+        proposal: &TXO,
+        bundle: PolicyBundle,
+    ) -> Result<PolicyUpdateRecord, PolicyError> {
+        if proposal.operation_class != OperationClass::Admin
+            || proposal.payload.payload_type != PayloadType::Control
+        {
+            return Err(PolicyError::NotPolicyProposal);
+        }
+        let bundle_hash = bundle.hash();
+        if proposal.payload.content_hash != bundle_hash {
+            return Err(PolicyError::BundleHashMismatch);
+        }
+        if bundle.version <= self.bundle.version {
+            return Err(PolicyError::StaleVersion);
+        }
+        if self.governance.approvals(proposal) < self.governance.threshold {
+            return Err(PolicyError::InsufficientApprovals);
+        }
+
+        let record = PolicyUpdateRecord {
+            proposal_id: proposal.txo_id,
+            from_version: self.bundle.version,
+            to_version: bundle.version,
+            bundle_hash,
+        };
+        self.bundle = bundle;
+        self.bundle_hash = bundle_hash;
+        self.updates.push(record);
+        Ok(record)
+    }
+
+    /// Decision log, oldest first
+    pub fn decision_log(&self) -> &[DecisionRecord] {
+        &self.decisions
+    }
+
+    /// Remove and return logged decisions for export
+    pub fn drain_decisions(&mut self) -> Vec<DecisionRecord> {
+        core::mem::take(&mut self.decisions)
+    }
+
+    /// Applied bundle updates, oldest first
+    pub fn update_history(&self) -> &[PolicyUpdateRecord] {
+        &self.updates
+    }
+}
+
+/// Recursive-descent parser over rule source
+struct Parser<'a> {
+    src: &'a [u8],
+    pos: usize,
+    line: u32,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            src: source.as_bytes(),
+            pos: 0,
+            line: 1,
+        }
+    }
+
+    fn error(&self) -> PolicyError {
+        PolicyError::Parse { line: self.line }
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(&c) = self.src.get(self.pos) {
+            match c {
+                b'\n' => {
+                    self.line += 1;
+                    self.pos += 1;
+                }
+                b'%' => {
+                    while self.src.get(self.pos).is_some_and(|&c| c != b'\n') {
+                        self.pos += 1;
+                    }
+                }
+                c if c.is_ascii_whitespace() => self.pos += 1,
+                _ => break,
+            }
+        }
+    }
+
+    fn eat(&mut self, token: &[u8]) -> bool {
+        self.skip_ws();
+        if self.src[self.pos..].starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &[u8]) -> Result<(), PolicyError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, PolicyError> {
+        self.skip_ws();
+        let start = self.pos;
+        while self
+            .src
+            .get(self.pos)
+            .is_some_and(|c| c.is_ascii_alphanumeric() || *c == b'_')
+        {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(self.error());
+        }
+        // Identifier bytes are ASCII by construction
+        Ok(String::from(core::str::from_utf8(&self.src[start..self.pos]).unwrap_or("")))
+    }
+
+    fn term(&mut self) -> Result<Term, PolicyError> {
+        self.skip_ws();
+        if self.eat(b"\"") {
+            let start = self.pos;
+            while self.src.get(self.pos).is_some_and(|&c| c != b'"' && c != b'\n') {
+                self.pos += 1;
+            }
+            let value = core::str::from_utf8(&self.src[start..self.pos]).map_err(|_| self.error())?;
+            let value = String::from(value);
+            self.expect(b"\"")?;
+            return Ok(Term::Const(value));
+        }
+
+        let name = self.ident()?;
+        let first = name.as_bytes()[0];
+        if first.is_ascii_uppercase() || first == b'_' {
+            Ok(Term::Var(name))
+        } else {
+            Ok(Term::Const(name))
+        }
+    }
+
+    fn atom(&mut self) -> Result<Atom, PolicyError> {
+        let predicate = self.ident()?;
+        if !predicate.as_bytes()[0].is_ascii_lowercase() {
+            return Err(self.error());
+        }
+        let mut args = Vec::new();
+        if self.eat(b"(") {
+            loop {
+                args.push(self.term()?);
+                if self.eat(b")") {
+                    break;
+                }
+                self.expect(b",")?;
+            }
+        }
+        Ok(Atom { predicate, args })
+    }
+
+    fn rules(&mut self) -> Result<Vec<Rule>, PolicyError> {
+        let mut rules = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.pos == self.src.len() {
+                return Ok(rules);
+            }
+
+            let head = self.atom()?;
+            let mut body = Vec::new();
+            if self.eat(b":-") {
+                loop {
+                    self.skip_ws();
+                    let negated = self.src[self.pos..].starts_with(b"not ");
+                    if negated {
+                        self.pos += 4;
+                    }
+                    body.push(Literal {
+                        negated,
+                        atom: self.atom()?,
+                    });
+                    if !self.eat(b",") {
+                        break;
+                    }
+                }
+            }
+            self.expect(b".")?;
+            rules.push(Rule { head, body });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::{RTFContext, RTFError};
+    use crate::txo::{IdentityType, Payload, Receiver, Sender, SignatureType};
+    use ed25519_dalek::SigningKey;
+
+    const GOV_A: [u8; 32] = [21u8; 32];
+    const GOV_B: [u8; 32] = [22u8; 32];
+    const OUTSIDER: [u8; 32] = [23u8; 32];
+
+    const BASE_POLICY: &str = r#"
+        % staging is open, production requires encryption
+        allow :- zone("Z1").
+        allow :- zone("Z2"), operation("Genomic"), encrypted("true").
+        deny("untrusted signer") :- signer(S), not trusted(S).
+        trusted("05050505050505050505050505050505").
+    "#;
+
+    fn public(secret: &[u8; 32]) -> [u8; 32] {
+        SigningKey::from_bytes(secret).verifying_key().to_bytes()
+    }
+
+    fn governance() -> PolicyGovernance {
+        PolicyGovernance::new(
+            Vec::from([([7u8; 16], public(&GOV_A)), ([8u8; 16], public(&GOV_B))]),
+            2,
+        )
+        .unwrap()
+    }
+
+    fn make_txo(class: OperationClass, payload_type: PayloadType, encrypted: bool) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [1u8; 16],
+            biokey_present: false,
+            fido2_signed: true,
+            zk_proof: None,
+        };
+        let receiver = Receiver {
+            identity_type: IdentityType::Node,
+            id: [2u8; 16],
+        };
+        let payload = Payload {
+            payload_type,
+            content_hash: [3u8; 32],
+            encrypted,
+            envelope: None,
+        };
+        TXO::new([4u8; 16], sender, receiver, class, payload)
+    }
+
+    fn signed(mut txo: TXO, signer: u8) -> TXO {
+        txo.sign_ed25519(SignatureType::Fido2, [signer; 16], &GOV_A);
+        txo
+    }
+
+    fn proposal(bundle: &PolicyBundle) -> TXO {
+        let mut txo = make_txo(OperationClass::Admin, PayloadType::Control, false);
+        txo.payload.content_hash = bundle.hash();
+        txo.dual_control_required = true;
+        txo
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let bundle = PolicyBundle::parse(1, BASE_POLICY).unwrap();
+        assert_eq!(bundle.rules.len(), 4);
+        assert_eq!(
+            format!("{}", bundle.rules[2]),
+            "deny(\"untrusted signer\") :- signer(S), not trusted(S)."
+        );
+
+        assert_eq!(
+            PolicyBundle::parse(1, "allow :- zone(\"Z1\")\nallow."),
+            Err(PolicyError::Parse { line: 2 })
+        );
+    }
+
+    #[test]
+    fn test_bundle_hash_ignores_formatting() {
+        let compact = "allow:-zone(\"Z1\").";
+        let spaced = "% comment\nallow :-\n    zone( \"Z1\" ) .\n";
+        let a = PolicyBundle::parse(1, compact).unwrap();
+        let b = PolicyBundle::parse(1, spaced).unwrap();
+        assert_eq!(a.hash(), b.hash());
+
+        let bumped = PolicyBundle::parse(2, compact).unwrap();
+        assert_ne!(a.hash(), bumped.hash());
+    }
+
+    #[test]
+    fn test_validation_rejects_unsafe_rules() {
+        assert_eq!(
+            PolicyBundle::parse(1, "allow(X) :- zone(\"Z1\")."),
+            Err(PolicyError::UnsafeVariable)
+        );
+        assert_eq!(
+            PolicyBundle::parse(1, "deny :- zone(Z), not seen(Y)."),
+            Err(PolicyError::UnsafeVariable)
+        );
+        assert_eq!(
+            PolicyBundle::parse(1, "ok(S) :- signer(S).\ndeny :- signer(S), not ok(S)."),
+            Err(PolicyError::NegatedDerivedPredicate)
+        );
+        assert_eq!(
+            PolicyBundle::parse(1, "zone(\"Z1\")."),
+            Err(PolicyError::ReservedPredicate)
+        );
+    }
+
+    #[test]
+    fn test_evaluation_with_recursion() {
+        let source = r#"
+            delegates("a", "b").
+            delegates("b", "05050505050505050505050505050505").
+            reaches(X, Y) :- delegates(X, Y).
+            reaches(X, Z) :- reaches(X, Y), delegates(Y, Z).
+            allow :- signer(S), reaches("a", S).
+        "#;
+        let engine = PolicyEngine::new(PolicyBundle::parse(1, source).unwrap(), governance());
+
+        let unsigned = make_txo(OperationClass::Network, PayloadType::Metadata, false);
+        assert!(!engine.evaluate(&unsigned, Zone::Z2).allowed);
+
+        let txo = signed(unsigned, 5);
+        let decision = engine.evaluate(&txo, Zone::Z2);
+        assert!(decision.allowed);
+        assert_eq!(decision.reasons, Vec::from([String::from("allow")]));
+    }
+
+    #[test]
+    fn test_deny_overrides_allow_and_is_logged() {
+        let mut engine = PolicyEngine::new(PolicyBundle::parse(1, BASE_POLICY).unwrap(), governance());
+
+        let trusted = signed(make_txo(OperationClass::Genomic, PayloadType::Genome, true), 5);
+        assert!(engine.authorize(&trusted, Zone::Z2));
+
+        let untrusted = signed(make_txo(OperationClass::Genomic, PayloadType::Genome, true), 6);
+        assert!(!engine.authorize(&untrusted, Zone::Z2));
+
+        let plaintext = signed(make_txo(OperationClass::Genomic, PayloadType::Genome, false), 5);
+        assert!(!engine.authorize(&plaintext, Zone::Z2));
+
+        let log = engine.decision_log();
+        assert_eq!(log.len(), 3);
+        assert_eq!(log[1].bundle_hash, engine.active_hash());
+        assert_eq!(
+            log[1].decision.reasons,
+            Vec::from([String::from("allow"), String::from("deny(\"untrusted signer\")")])
+        );
+        assert!(log[2].decision.reasons.is_empty());
+
+        assert_eq!(engine.drain_decisions().len(), 3);
+        assert!(engine.decision_log().is_empty());
+    }
+
+    #[test]
+    fn test_governed_update() {
+        let mut engine = PolicyEngine::new(PolicyBundle::parse(1, BASE_POLICY).unwrap(), governance());
+        let next = PolicyBundle::parse(2, "allow.").unwrap();
+
+        // One governor is not enough
+        let mut txo = proposal(&next);
+        txo.sign_ed25519(SignatureType::Fido2, [7u8; 16], &GOV_A);
+        assert_eq!(
+            engine.apply_update(&txo, next.clone()),
+            Err(PolicyError::InsufficientApprovals)
+        );
+
+        // A signature under a governor id but the wrong key does not count
+        let mut forged = txo.clone();
+        forged.sign_ed25519(SignatureType::Fido2, [8u8; 16], &OUTSIDER);
+        assert_eq!(
+            engine.apply_update(&forged, next.clone()),
+            Err(PolicyError::InsufficientApprovals)
+        );
+
+        txo.sign_ed25519(SignatureType::Fido2, [8u8; 16], &GOV_B);
+
+        let other = PolicyBundle::parse(2, "allow :- zone(\"Z1\").").unwrap();
+        assert_eq!(
+            engine.apply_update(&txo, other),
+            Err(PolicyError::BundleHashMismatch)
+        );
+
+        let record = engine.apply_update(&txo, next.clone()).unwrap();
+        assert_eq!((record.from_version, record.to_version), (1, 2));
+        assert_eq!(engine.active_hash(), next.hash());
+        assert_eq!(engine.update_history(), &[record]);
+
+        // Replay of the same approved proposal is stale
+        assert_eq!(engine.apply_update(&txo, next), Err(PolicyError::StaleVersion));
+
+        let mut network = proposal(&PolicyBundle::parse(3, "allow.").unwrap());
+        network.operation_class = OperationClass::Network;
+        assert_eq!(
+            engine.apply_update(&network, PolicyBundle::parse(3, "allow.").unwrap()),
+            Err(PolicyError::NotPolicyProposal)
+        );
+
+        assert_eq!(
+            PolicyGovernance::new(Vec::new(), 1),
+            Err(PolicyError::InvalidThreshold)
+        );
+    }
+
+    #[test]
+    fn test_execute_txo_consults_policy() {
+        let engine = PolicyEngine::new(PolicyBundle::parse(1, BASE_POLICY).unwrap(), governance());
+        let mut ctx = RTFContext::new(Zone::Z2, MerkleLedger::new([0u8; 32])).with_policy(engine);
+
+        let mut allowed = signed(make_txo(OperationClass::Genomic, PayloadType::Genome, true), 5);
+        assert!(ctx.execute_txo(&mut allowed).is_ok());
+
+        let mut denied = signed(make_txo(OperationClass::Network, PayloadType::Metadata, true), 5);
+        assert_eq!(ctx.execute_txo(&mut denied), Err(RTFError::PolicyDenied));
+        assert_eq!(denied.audit_trail.len(), 0);
+
+        let log = ctx.policy.as_ref().unwrap().decision_log();
+        assert_eq!(log.len(), 2);
+        assert!(log[0].decision.allowed && !log[1].decision.allowed);
+    }
+}
@@ -0,0 +1,573 @@
+//! RTF Pre-Authorization TXOs
+//!
+//! Lets an operator approve routine work once instead of signing every TXO:
+//! "up to N operations of class X until T". The grant is committed as a
+//! signed, non-reversible Compliance/Control TXO whose payload hash commits
+//! to the grant document:
+//!
+//! ```text
+//! content_hash = SHA3-256("AETHERNET-PREAUTH-v1" || cbor(grant))
+//! ```
+//!
+//! `RTFContext::commit_preauthorized` checks the grant scope (grantee,
+//! operation class, expiry and any extra caveats) at execution time, waives
+//! the per-TXO zone signature requirement, and decrements the remaining
+//! budget. Each consumption is recorded in the committed TXO's audit trail
+//! (`actor_id` = grant id, action `PREAUTH remaining=<n>`), so the budget can
+//! be recounted from the ledger. Consumed budget is not refunded by rollback.
+//!
+//! A grant is revoked before expiry by committing a revocation TXO signed by
+//! the issuer and counter-signed by a second, distinct signer.
+//! `PreAuthRegistry::observe` replays revocations and consumption entries;
+//! grants themselves are replayed with `PreAuthRegistry::register`, since
+//! the ledger only holds their commitment.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256};
+
+use crate::rtf::capability::{check_caveats, Caveat, CapabilityError, Submission};
+use crate::txo::{
+    AuditEntry, IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, TXO,
+};
+
+/// Domain separator for grant payload commitments
+const GRANT_DOMAIN: &[u8] = b"AETHERNET-PREAUTH-v1";
+
+/// Domain separator for revocation payload commitments
+const REVOCATION_DOMAIN: &[u8] = b"AETHERNET-PREAUTH-REVOKE-v1";
+
+/// Audit action prefix recording budget consumption
+const CONSUME_ACTION: &str = "PREAUTH remaining=";
+
+/// Standing approval for a bounded number of operations
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct PreAuthorization {
+    /// Grant identifier (revocation handle)
+    #[n(0)]
+    pub grant_id: [u8; 16],
+
+    /// Operator approving the operations
+    #[n(1)]
+    pub issuer_id: [u8; 16],
+
+    /// Sender allowed to submit under the grant
+    #[n(2)]
+    pub grantee_id: [u8; 16],
+
+    /// Operation class covered by the grant
+    #[n(3)]
+    pub operation: OperationClass,
+
+    /// Number of operations approved
+    #[n(4)]
+    pub max_operations: u32,
+
+    /// Submissions must arrive strictly before this Unix timestamp
+    #[n(5)]
+    pub expires_at: u64,
+
+    /// Additional scope restrictions
+    #[n(6)]
+    pub caveats: Vec<Caveat>,
+}
+
+impl PreAuthorization {
+    /// Create a grant for `max_operations` operations of class `operation`
+    ///
+    /// # Arguments
+    /// * `grant_id` - Unique grant identifier
+    /// * `issuer_id` - Approving operator
+    /// * `grantee_id` - Sender allowed to use the grant
+    /// * `operation` - Covered operation class
+    /// * `max_operations` - Operation budget
+    /// * `expires_at` - Expiry (Unix seconds)
+    pub fn new(
+        grant_id: [u8; 16],
+        issuer_id: [u8; 16],
+        grantee_id: [u8; 16],
+        operation: OperationClass,
+        max_operations: u32,
+        expires_at: u64,
+    ) -> Self {
+        Self {
+            grant_id,
+            issuer_id,
+            grantee_id,
+            operation,
+            max_operations,
+            expires_at,
+            caveats: Vec::new(),
+        }
+    }
+
+    /// Add a scope caveat (zone, payload size, ...)
+    pub fn with_caveat(mut self, caveat: Caveat) -> Self {
+        self.caveats.push(caveat);
+        self
+    }
+
+    /// Payload commitment of the grant TXO
+    pub fn commitment(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(GRANT_DOMAIN);
+        hasher.update(minicbor::to_vec(self).unwrap_or_default());
+        hasher.finalize().into()
+    }
+
+    /// Build the unsigned grant TXO
+    ///
+    /// The issuer signs it once, then commits it with
+    /// `RTFContext::grant_preauthorization`.
+    pub fn grant_txo(&self, timestamp: u64) -> TXO {
+        control_txo(self.grant_id, self.issuer_id, self.commitment(), timestamp)
+    }
+
+    /// Build the unsigned revocation TXO
+    ///
+    /// Must be signed by the issuer and counter-signed by a second signer,
+    /// then committed with `RTFContext::revoke_preauthorization`.
+    pub fn revocation_txo(&self, timestamp: u64) -> TXO {
+        let mut txo = control_txo(
+            self.grant_id,
+            self.issuer_id,
+            revocation_commitment(&self.grant_id),
+            timestamp,
+        );
+        txo.dual_control_required = true;
+        txo
+    }
+}
+
+/// Pre-authorization failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreAuthError {
+    /// TXO does not commit to the grant document
+    NotGrant,
+    /// Grant TXO lacks the issuer's signature
+    MissingIssuerSignature,
+    /// Grant id is already registered
+    DuplicateGrant,
+    /// Grant id is not registered
+    UnknownGrant,
+    /// Grant was revoked
+    Revoked,
+    /// Grant expired
+    Expired,
+    /// Operation budget is used up
+    Exhausted,
+    /// Submitting sender is not the grantee
+    WrongGrantee,
+    /// TXO operation class is not covered by the grant
+    OperationNotPermitted,
+    /// A scope caveat does not hold
+    CaveatFailed(CapabilityError),
+    /// TXO is not a pre-authorization revocation
+    NotRevocation,
+    /// Revocation lacks the issuer's signature or a distinct counter-signature
+    MissingCounterSignature,
+}
+
+/// Committed grant or revocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreAuthRecord {
+    /// Grant concerned
+    pub grant_id: [u8; 16],
+    /// Committed TXO id
+    pub txo_id: [u8; 16],
+    /// Ledger root after the TXO was committed
+    pub ledger_root: [u8; 32],
+}
+
+/// Operation committed under a grant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreAuthReceipt {
+    /// Grant consumed
+    pub grant_id: [u8; 16],
+    /// Committed TXO id
+    pub txo_id: [u8; 16],
+    /// Operations left on the grant
+    pub remaining: u32,
+    /// Ledger root after the TXO was committed
+    pub ledger_root: [u8; 32],
+}
+
+#[derive(Debug, Clone)]
+struct GrantState {
+    grant: PreAuthorization,
+    remaining: u32,
+    revoked: bool,
+}
+
+/// Registered grants and their remaining budgets
+#[derive(Debug, Clone, Default)]
+pub struct PreAuthRegistry {
+    grants: BTreeMap<[u8; 16], GrantState>,
+}
+
+impl PreAuthRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check that a TXO is a signed commitment to `grant`
+    pub fn check_grant(&self, txo: &TXO, grant: &PreAuthorization) -> Result<(), PreAuthError> {
+        let well_formed = is_control_txo(txo)
+            && txo.receiver.id == grant.grant_id
+            && txo.sender.id == grant.issuer_id
+            && txo.payload.content_hash == grant.commitment();
+        if !well_formed {
+            return Err(PreAuthError::NotGrant);
+        }
+        if !signed_by(txo, &grant.issuer_id) {
+            return Err(PreAuthError::MissingIssuerSignature);
+        }
+        if self.grants.contains_key(&grant.grant_id) {
+            return Err(PreAuthError::DuplicateGrant);
+        }
+        Ok(())
+    }
+
+    /// Register a committed grant with its full budget
+    ///
+    /// Used both after `RTFContext::grant_preauthorization` commits and when
+    /// replaying ledger TXOs on restart.
+    pub fn register(&mut self, txo: &TXO, grant: PreAuthorization) -> Result<(), PreAuthError> {
+        self.check_grant(txo, &grant)?;
+        self.grants.insert(
+            grant.grant_id,
+            GrantState {
+                remaining: grant.max_operations,
+                grant,
+                revoked: false,
+            },
+        );
+        Ok(())
+    }
+
+    /// Check a submission against a grant without consuming budget
+    ///
+    /// # Arguments
+    /// * `grant_id` - Grant presented with the submission
+    /// * `sender_id` - Submitting sender
+    /// * `submission` - Submission attributes
+    pub fn authorize(
+        &self,
+        grant_id: &[u8; 16],
+        sender_id: &[u8; 16],
+        submission: &Submission,
+    ) -> Result<(), PreAuthError> {
+        let state = self.grants.get(grant_id).ok_or(PreAuthError::UnknownGrant)?;
+        let grant = &state.grant;
+        if state.revoked {
+            return Err(PreAuthError::Revoked);
+        }
+        if submission.received_at >= grant.expires_at {
+            return Err(PreAuthError::Expired);
+        }
+        if state.remaining == 0 {
+            return Err(PreAuthError::Exhausted);
+        }
+        if *sender_id != grant.grantee_id {
+            return Err(PreAuthError::WrongGrantee);
+        }
+        if submission.operation != grant.operation {
+            return Err(PreAuthError::OperationNotPermitted);
+        }
+        check_caveats(&grant.caveats, submission).map_err(PreAuthError::CaveatFailed)
+    }
+
+    /// Consume one operation and record it in the TXO's audit trail
+    ///
+    /// # Returns
+    /// * Operations left on the grant
+    pub fn consume(&mut self, grant_id: &[u8; 16], txo: &mut TXO) -> Result<u32, PreAuthError> {
+        let state = self.grants.get_mut(grant_id).ok_or(PreAuthError::UnknownGrant)?;
+        state.remaining = state.remaining.checked_sub(1).ok_or(PreAuthError::Exhausted)?;
+        txo.add_audit_entry(AuditEntry {
+            actor_id: *grant_id,
+            action: consume_action(state.remaining),
+            timestamp: txo.timestamp,
+        });
+        Ok(state.remaining)
+    }
+
+    /// Check that a TXO is a counter-signed revocation of a live grant
+    ///
+    /// # Returns
+    /// * Revoked grant id
+    pub fn check_revocation(&self, txo: &TXO) -> Result<[u8; 16], PreAuthError> {
+        let grant_id = txo.receiver.id;
+        if !is_control_txo(txo) || txo.payload.content_hash != revocation_commitment(&grant_id) {
+            return Err(PreAuthError::NotRevocation);
+        }
+        let state = self.grants.get(&grant_id).ok_or(PreAuthError::UnknownGrant)?;
+        if state.revoked {
+            return Err(PreAuthError::Revoked);
+        }
+        if txo.timestamp >= state.grant.expires_at {
+            return Err(PreAuthError::Expired);
+        }
+        let issuer = state.grant.issuer_id;
+        let counter_signed = txo.signatures.iter().any(|sig| sig.signer_id != issuer);
+        if !signed_by(txo, &issuer) || !counter_signed {
+            return Err(PreAuthError::MissingCounterSignature);
+        }
+        Ok(grant_id)
+    }
+
+    /// Record a committed TXO: revocations and consumption entries
+    ///
+    /// Grants must be registered before the TXOs that use them are replayed.
+    ///
+    /// # Returns
+    /// * `Some(grant_id)` if the TXO revoked a grant
+    pub fn observe(&mut self, txo: &TXO) -> Option<[u8; 16]> {
+        for entry in &txo.audit_trail {
+            if let Some(remaining) = parse_consume_action(&entry.action) {
+                if let Some(state) = self.grants.get_mut(&entry.actor_id) {
+                    state.remaining = state.remaining.min(remaining);
+                }
+            }
+        }
+
+        let grant_id = self.check_revocation(txo).ok()?;
+        if let Some(state) = self.grants.get_mut(&grant_id) {
+            state.revoked = true;
+        }
+        Some(grant_id)
+    }
+
+    /// Operations left on a grant
+    pub fn remaining(&self, grant_id: &[u8; 16]) -> Option<u32> {
+        self.grants.get(grant_id).map(|state| state.remaining)
+    }
+
+    /// Check whether a grant was revoked
+    pub fn is_revoked(&self, grant_id: &[u8; 16]) -> bool {
+        self.grants.get(grant_id).is_some_and(|state| state.revoked)
+    }
+}
+
+/// Non-reversible Compliance/Control TXO addressed to a grant
+fn control_txo(grant_id: [u8; 16], issuer_id: [u8; 16], content_hash: [u8; 32], timestamp: u64) -> TXO {
+    let mut txo_id = [0u8; 16];
+    txo_id.copy_from_slice(&content_hash[..16]);
+
+    let sender = Sender {
+        identity_type: IdentityType::Operator,
+        id: issuer_id,
+        biokey_present: false,
+        fido2_signed: false,
+        zk_proof: None,
+    };
+    // The grant id travels in the receiver so replay can recover it
+    let receiver = Receiver {
+        identity_type: IdentityType::System,
+        id: grant_id,
+    };
+    let payload = Payload {
+        payload_type: PayloadType::Control,
+        content_hash,
+        encrypted: false,
+        envelope: None,
+    };
+
+    let mut txo = TXO::new(txo_id, sender, receiver, OperationClass::Compliance, payload);
+    txo.timestamp = timestamp;
+    // Grants and revocations must outlive any rollback
+    txo.reversibility_flag = false;
+    txo
+}
+
+fn is_control_txo(txo: &TXO) -> bool {
+    txo.operation_class == OperationClass::Compliance
+        && txo.payload.payload_type == PayloadType::Control
+        && txo.receiver.identity_type == IdentityType::System
+        && !txo.reversibility_flag
+}
+
+fn signed_by(txo: &TXO, signer_id: &[u8; 16]) -> bool {
+    txo.signatures.iter().any(|sig| sig.signer_id == *signer_id)
+}
+
+/// Payload commitment of a revocation TXO
+fn revocation_commitment(grant_id: &[u8; 16]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(REVOCATION_DOMAIN);
+    hasher.update(grant_id);
+    hasher.finalize().into()
+}
+
+fn consume_action(remaining: u32) -> String {
+    format!("{}{}", CONSUME_ACTION, remaining)
+}
+
+fn parse_consume_action(action: &str) -> Option<u32> {
+    action.strip_prefix(CONSUME_ACTION)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::{RTFContext, RTFError, Zone};
+    use crate::txo::SignatureType;
+
+    const ISSUER: [u8; 16] = [9u8; 16];
+    const SERVICE: [u8; 16] = [1u8; 16];
+
+    fn grant() -> PreAuthorization {
+        PreAuthorization::new([5u8; 16], ISSUER, SERVICE, OperationClass::Genomic, 2, 2_000)
+            .with_caveat(Caveat::MaxPayloadSize(1_024))
+    }
+
+    fn service_txo(id: u8) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Node,
+            id: SERVICE,
+            biokey_present: false,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        let receiver = Receiver {
+            identity_type: IdentityType::Node,
+            id: [2u8; 16],
+        };
+        let payload = Payload {
+            payload_type: PayloadType::Genome,
+            content_hash: [3u8; 32],
+            encrypted: true,
+            envelope: None,
+        };
+        TXO::new([id; 16], sender, receiver, OperationClass::Genomic, payload)
+    }
+
+    fn production() -> RTFContext {
+        let mut ctx = RTFContext::new(Zone::Z2, MerkleLedger::new([0u8; 32]));
+        let grant = grant();
+        let mut txo = grant.grant_txo(1_000);
+        txo.sign_ed25519(SignatureType::Fido2, ISSUER, &[9u8; 32]);
+        ctx.grant_preauthorization(&mut txo, grant).unwrap();
+        ctx
+    }
+
+    #[test]
+    fn test_budget_enforced_without_per_txo_signatures() {
+        let mut ctx = production();
+
+        // Unsigned TXOs are rejected in Z2 unless pre-authorized
+        assert_eq!(ctx.execute_txo(&mut service_txo(4)), Err(RTFError::MissingSignature));
+
+        let mut txo = service_txo(4);
+        let receipt = ctx.commit_preauthorized(&mut txo, &[5u8; 16], 512, 1_500).unwrap();
+        assert_eq!(receipt.remaining, 1);
+        assert_eq!(receipt.ledger_root, ctx.ledger.get_current_root());
+        assert!(txo.audit_trail.iter().any(|e| e.actor_id == [5u8; 16]));
+
+        // Scope: payload cap, operation class, grantee and expiry
+        assert_eq!(
+            ctx.preauthorizations.authorize(
+                &[5u8; 16],
+                &SERVICE,
+                &Submission {
+                    operation: OperationClass::Genomic,
+                    zone: Zone::Z2,
+                    received_at: 1_500,
+                    payload_len: 4_096,
+                }
+            ),
+            Err(PreAuthError::CaveatFailed(CapabilityError::PayloadTooLarge))
+        );
+        let mut other = service_txo(6);
+        other.operation_class = OperationClass::Network;
+        assert_eq!(
+            ctx.commit_preauthorized(&mut other, &[5u8; 16], 512, 1_500),
+            Err(RTFError::PreAuthorizationDenied)
+        );
+        let mut stranger = service_txo(6);
+        stranger.sender.id = [7u8; 16];
+        assert!(ctx.commit_preauthorized(&mut stranger, &[5u8; 16], 512, 1_500).is_err());
+        assert!(ctx.commit_preauthorized(&mut service_txo(6), &[5u8; 16], 512, 2_000).is_err());
+
+        assert!(ctx.commit_preauthorized(&mut service_txo(7), &[5u8; 16], 512, 1_600).is_ok());
+        assert_eq!(ctx.preauthorizations.remaining(&[5u8; 16]), Some(0));
+        assert_eq!(
+            ctx.commit_preauthorized(&mut service_txo(8), &[5u8; 16], 512, 1_700),
+            Err(RTFError::PreAuthorizationDenied)
+        );
+
+        // The budget is recounted from committed TXOs on replay
+        let mut replayed = PreAuthRegistry::new();
+        let grant = grant();
+        let mut grant_txo = grant.grant_txo(1_000);
+        grant_txo.sign_ed25519(SignatureType::Fido2, ISSUER, &[9u8; 32]);
+        replayed.register(&grant_txo, grant).unwrap();
+        assert_eq!(replayed.observe(&txo), None);
+        assert_eq!(replayed.remaining(&[5u8; 16]), Some(1));
+    }
+
+    #[test]
+    fn test_grant_requires_issuer_signature() {
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]));
+        let grant = grant();
+        let mut unsigned = grant.grant_txo(1_000);
+        assert_eq!(
+            ctx.grant_preauthorization(&mut unsigned, grant.clone()),
+            Err(RTFError::PreAuthorizationDenied)
+        );
+
+        // A TXO committing to a different budget does not register this grant
+        let mut inflated = grant.clone();
+        inflated.max_operations = 1_000;
+        let mut txo = inflated.grant_txo(1_000);
+        txo.sign_ed25519(SignatureType::Fido2, ISSUER, &[9u8; 32]);
+        assert_eq!(
+            ctx.grant_preauthorization(&mut txo, grant),
+            Err(RTFError::PreAuthorizationDenied)
+        );
+        assert_eq!(ctx.preauthorizations.remaining(&[5u8; 16]), None);
+    }
+
+    #[test]
+    fn test_counter_signed_revocation() {
+        let mut ctx = production();
+        assert!(ctx.commit_preauthorized(&mut service_txo(4), &[5u8; 16], 512, 1_100).is_ok());
+
+        // The issuer alone cannot revoke
+        let mut revocation = grant().revocation_txo(1_200);
+        revocation.sign_ed25519(SignatureType::Fido2, ISSUER, &[9u8; 32]);
+        assert_eq!(
+            ctx.preauthorizations.check_revocation(&revocation),
+            Err(PreAuthError::MissingCounterSignature)
+        );
+        assert!(ctx.revoke_preauthorization(&mut revocation.clone()).is_err());
+
+        revocation.sign_ed25519(SignatureType::Fido2, [8u8; 16], &[8u8; 32]);
+        let record = ctx.revoke_preauthorization(&mut revocation).unwrap();
+        assert_eq!(record.grant_id, [5u8; 16]);
+        assert_eq!(record.ledger_root, ctx.ledger.get_current_root());
+        assert!(ctx.preauthorizations.is_revoked(&[5u8; 16]));
+        assert_eq!(
+            ctx.commit_preauthorized(&mut service_txo(6), &[5u8; 16], 512, 1_300),
+            Err(RTFError::PreAuthorizationDenied)
+        );
+
+        // Expired grants cannot be revoked
+        let late = PreAuthorization::new([6u8; 16], ISSUER, SERVICE, OperationClass::Genomic, 1, 10);
+        let mut registry = PreAuthRegistry::new();
+        let mut txo = late.grant_txo(1);
+        txo.sign_ed25519(SignatureType::Fido2, ISSUER, &[9u8; 32]);
+        registry.register(&txo, late.clone()).unwrap();
+        let mut revocation = late.revocation_txo(10);
+        revocation.sign_ed25519(SignatureType::Fido2, ISSUER, &[9u8; 32]);
+        revocation.sign_ed25519(SignatureType::Fido2, [8u8; 16], &[8u8; 32]);
+        assert_eq!(registry.check_revocation(&revocation), Err(PreAuthError::Expired));
+    }
+}
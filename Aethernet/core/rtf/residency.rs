@@ -0,0 +1,319 @@
+//! RTF Data Residency
+//!
+//! Geographic constraints for GDPR deployments. Nodes and dispatch channels
+//! carry residency labels (region codes such as `"DE"`), and named groups
+//! (`"EU"`) stand for sets of labels. A payload tagged with a residency
+//! requirement, by content hash, may only travel to destinations whose label
+//! the requirement allows; unlabeled destinations never qualify.
+//!
+//! Enforcement points:
+//! - Transport: with `RTFContext::with_residency`, executing a TXO routes its
+//!   payload to the receiver node; non-compliant routes are refused with
+//!   `RTFError::ResidencyViolation` before anything is committed.
+//! - Ledger: `replicable` filters the TXOs shipped to a replica node, and
+//!   `check_channel` gates delivery to a labeled dispatch sink.
+//!
+//! Every refusal is recorded as a `ResidencyViolation`. Its `violation_txo`
+//! is an irreversible Compliance TXO carrying the violation digest, for the
+//! operator to sign and commit so the ledger shows what was blocked.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::hash::StreamingHasher;
+use crate::rtf::api::RTFError;
+use crate::txo::{
+    IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, TXO,
+};
+
+/// Where a payload would be sent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Destination {
+    /// Node, by UUID (TXO receiver or ledger replica)
+    Node([u8; 16]),
+    /// Dispatch channel, by sink name
+    Channel(String),
+}
+
+/// Refused transfer of a residency-tagged payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResidencyViolation {
+    /// TXO whose payload would have moved
+    pub txo_id: [u8; 16],
+    /// Payload content hash
+    pub content_hash: [u8; 32],
+    /// Refused destination
+    pub destination: Destination,
+    /// Destination label, if it has one
+    pub destination_region: Option<String>,
+    /// Regions and groups the requirement allows
+    pub allowed: Vec<String>,
+    /// TXO timestamp
+    pub timestamp: u64,
+}
+
+impl ResidencyViolation {
+    /// SHA3-256 over the violation fields
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = StreamingHasher::new();
+        hasher.update(b"AETHERNET-RESIDENCY-VIOLATION-v1");
+        hasher.update(&self.txo_id);
+        hasher.update(&self.content_hash);
+        match &self.destination {
+            Destination::Node(id) => {
+                hasher.update(&[0]);
+                hasher.update(id);
+            }
+            Destination::Channel(name) => {
+                hasher.update(&[1]);
+                hash_str(&mut hasher, name);
+            }
+        }
+        match &self.destination_region {
+            Some(region) => {
+                hasher.update(&[1]);
+                hash_str(&mut hasher, region);
+            }
+            None => hasher.update(&[0]),
+        }
+        hasher.update(&(self.allowed.len() as u64).to_le_bytes());
+        for region in &self.allowed {
+            hash_str(&mut hasher, region);
+        }
+        hasher.update(&self.timestamp.to_le_bytes());
+        hasher.finalize_reset()
+    }
+
+    /// Build the unsigned violation TXO
+    ///
+    /// The payload content hash is the violation digest. The TXO is
+    /// irreversible so the record survives rollback; the operator signs and
+    /// commits it.
+    pub fn violation_txo(&self, operator_id: [u8; 16]) -> TXO {
+        let digest = self.digest();
+        let mut txo_id = [0u8; 16];
+        txo_id.copy_from_slice(&digest[..16]);
+
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: operator_id,
+            biokey_present: false,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        let receiver = Receiver { identity_type: IdentityType::System, id: [0u8; 16] };
+        let payload = Payload {
+            payload_type: PayloadType::Audit,
+            content_hash: digest,
+            encrypted: false,
+            envelope: None,
+        };
+
+        let mut txo = TXO::new(txo_id, sender, receiver, OperationClass::Compliance, payload);
+        txo.timestamp = self.timestamp;
+        txo.reversibility_flag = false;
+        txo
+    }
+}
+
+fn hash_str(hasher: &mut StreamingHasher, value: &str) {
+    hasher.update(&(value.len() as u64).to_le_bytes());
+    hasher.update(value.as_bytes());
+}
+
+/// Residency labels, requirements and the violation log
+#[derive(Debug, Clone, Default)]
+pub struct ResidencyPolicy {
+    nodes: BTreeMap<[u8; 16], String>,
+    channels: BTreeMap<String, String>,
+    groups: BTreeMap<String, Vec<String>>,
+    requirements: BTreeMap<[u8; 32], Vec<String>>,
+    violations: Vec<ResidencyViolation>,
+}
+
+impl ResidencyPolicy {
+    /// Create a policy with no labels or requirements (permits everything)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Label a node with the region it runs in
+    pub fn label_node(&mut self, node_id: [u8; 16], region: impl Into<String>) {
+        self.nodes.insert(node_id, region.into());
+    }
+
+    /// Label a dispatch channel with the region it delivers to
+    pub fn label_channel(&mut self, name: impl Into<String>, region: impl Into<String>) {
+        self.channels.insert(name.into(), region.into());
+    }
+
+    /// Name a group of regions, usable in requirements
+    pub fn define_group(&mut self, name: impl Into<String>, members: &[&str]) {
+        self.groups.insert(name.into(), members.iter().map(|m| String::from(*m)).collect());
+    }
+
+    /// Restrict a payload to the listed regions or groups
+    pub fn require(&mut self, content_hash: [u8; 32], allowed: &[&str]) {
+        self.requirements.insert(content_hash, allowed.iter().map(|a| String::from(*a)).collect());
+    }
+
+    /// Label of a destination
+    pub fn region_of(&self, destination: &Destination) -> Option<&str> {
+        match destination {
+            Destination::Node(id) => self.nodes.get(id),
+            Destination::Channel(name) => self.channels.get(name),
+        }
+        .map(String::as_str)
+    }
+
+    /// Whether `txo`'s payload may go to `destination`, without recording
+    pub fn permits(&self, txo: &TXO, destination: &Destination) -> bool {
+        let Some(allowed) = self.requirements.get(&txo.payload.content_hash) else {
+            return true;
+        };
+        self.region_of(destination).is_some_and(|region| {
+            allowed.iter().any(|entry| {
+                entry == region || self.groups.get(entry).is_some_and(|members| members.iter().any(|m| m == region))
+            })
+        })
+    }
+
+    /// Check a transfer, recording a violation if it is refused
+    ///
+    /// # Returns
+    /// * `Ok(())` if the payload is untagged or the destination is allowed
+    /// * `Err(RTFError::ResidencyViolation)` otherwise
+    pub fn check(&mut self, txo: &TXO, destination: Destination) -> Result<(), RTFError> {
+        if self.permits(txo, &destination) {
+            return Ok(());
+        }
+        self.violations.push(ResidencyViolation {
+            txo_id: txo.txo_id,
+            content_hash: txo.payload.content_hash,
+            destination_region: self.region_of(&destination).map(String::from),
+            destination,
+            allowed: self.requirements[&txo.payload.content_hash].clone(),
+            timestamp: txo.timestamp,
+        });
+        Err(RTFError::ResidencyViolation)
+    }
+
+    /// Check routing `txo` to its receiver node
+    pub fn check_route(&mut self, txo: &TXO) -> Result<(), RTFError> {
+        self.check(txo, Destination::Node(txo.receiver.id))
+    }
+
+    /// Check delivering `txo` to a dispatch channel
+    pub fn check_channel(&mut self, txo: &TXO, channel: &str) -> Result<(), RTFError> {
+        self.check(txo, Destination::Channel(String::from(channel)))
+    }
+
+    /// TXOs that may be replicated to `replica`; the rest are recorded as violations
+    pub fn replicable<'a>(&mut self, txos: &'a [TXO], replica: [u8; 16]) -> Vec<&'a TXO> {
+        txos.iter().filter(|txo| self.check(txo, Destination::Node(replica)).is_ok()).collect()
+    }
+
+    /// Recorded violations, oldest first
+    pub fn violations(&self) -> &[ResidencyViolation] {
+        &self.violations
+    }
+
+    /// Take the recorded violations, e.g. to commit their TXOs
+    pub fn drain_violations(&mut self) -> Vec<ResidencyViolation> {
+        core::mem::take(&mut self.violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::{RTFContext, Zone};
+    use crate::txo::SignatureType;
+
+    const FRANKFURT: [u8; 16] = [1u8; 16];
+    const PARIS: [u8; 16] = [2u8; 16];
+    const VIRGINIA: [u8; 16] = [3u8; 16];
+
+    fn make_txo(content: u8, receiver: [u8; 16]) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [5u8; 16],
+            biokey_present: false,
+            fido2_signed: true,
+            zk_proof: None,
+        };
+        let receiver = Receiver { identity_type: IdentityType::Node, id: receiver };
+        let payload = Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: [content; 32],
+            encrypted: true,
+            envelope: None,
+        };
+        let mut txo = TXO::new([content; 16], sender, receiver, OperationClass::Network, payload);
+        txo.timestamp = 100 + content as u64;
+        txo.sign_ed25519(SignatureType::Fido2, [3u8; 16], &[11u8; 32]);
+        txo
+    }
+
+    fn policy() -> ResidencyPolicy {
+        let mut policy = ResidencyPolicy::new();
+        policy.define_group("EU", &["DE", "FR"]);
+        policy.label_node(FRANKFURT, "DE");
+        policy.label_node(PARIS, "FR");
+        policy.label_node(VIRGINIA, "US");
+        policy.label_channel("siem-eu", "DE");
+        policy.label_channel("siem-us", "US");
+        policy.require([1u8; 32], &["EU"]);
+        policy.require([2u8; 32], &["DE"]);
+        policy
+    }
+
+    #[test]
+    fn test_routing_enforced_at_execution() {
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32])).with_residency(policy());
+
+        ctx.execute_txo(&mut make_txo(1, PARIS)).unwrap();
+        ctx.execute_txo(&mut make_txo(3, VIRGINIA)).unwrap(); // untagged
+        assert_eq!(ctx.execute_txo(&mut make_txo(2, PARIS)), Err(RTFError::ResidencyViolation));
+        let mut blocked = make_txo(1, VIRGINIA);
+        assert_eq!(ctx.execute_txo(&mut blocked), Err(RTFError::ResidencyViolation));
+        assert!(blocked.audit_trail.is_empty());
+
+        let violations = ctx.residency.as_mut().unwrap().drain_violations();
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[1].destination, Destination::Node(VIRGINIA));
+        assert_eq!(violations[1].destination_region.as_deref(), Some("US"));
+        assert_eq!(violations[1].allowed, ["EU"]);
+
+        // The violation is committed to the ledger as an irreversible record
+        let mut record = violations[1].violation_txo([7u8; 16]);
+        record.sign_ed25519(SignatureType::Fido2, [7u8; 16], &[12u8; 32]);
+        ctx.execute_txo(&mut record).unwrap();
+        ctx.commit_txo(&mut record).unwrap();
+        assert_eq!(record.payload.content_hash, violations[1].digest());
+        assert!(!record.reversibility_flag);
+        assert_ne!(violations[0].digest(), violations[1].digest());
+    }
+
+    #[test]
+    fn test_replication_and_channels() {
+        let mut policy = policy();
+        let txos = [make_txo(1, FRANKFURT), make_txo(2, FRANKFURT), make_txo(3, FRANKFURT)];
+
+        let to_paris: Vec<[u8; 16]> = policy.replicable(&txos, PARIS).iter().map(|t| t.txo_id).collect();
+        assert_eq!(to_paris, [[1u8; 16], [3u8; 16]]);
+        assert_eq!(policy.replicable(&txos, VIRGINIA).len(), 1);
+
+        // Unlabeled destinations fail closed
+        assert!(policy.check(&txos[0], Destination::Node([9u8; 16])).is_err());
+        assert_eq!(policy.violations().last().unwrap().destination_region, None);
+
+        assert!(policy.check_channel(&txos[1], "siem-eu").is_ok());
+        assert_eq!(policy.check_channel(&txos[1], "siem-us"), Err(RTFError::ResidencyViolation));
+        assert_eq!(policy.violations().len(), 5);
+    }
+}
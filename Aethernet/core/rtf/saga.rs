@@ -0,0 +1,385 @@
+//! RTF Saga Coordinator
+//!
+//! Multi-TXO workflows where later failures must compensate earlier commits.
+//! Each saga step pairs a forward TXO with a compensation generator. Steps are
+//! executed and committed through `RTFContext`; if a step fails, compensation
+//! TXOs for every committed step are executed in reverse order.
+//!
+//! Compensation is forward recovery: compensating TXOs are appended to the
+//! ledger rather than rolling it back, so sagas also work in Z2 where rollback
+//! is reserved for emergencies. Every saga TXO carries a saga audit entry, and
+//! the `SagaLog` links each committed TXO to the ledger root it extended.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::rtf::api::{RTFContext, RTFError};
+use crate::txo::{AuditEntry, RollbackEntry, TXO};
+
+/// Compensation generator: builds the compensating TXO from the committed forward TXO
+pub type CompensationFn = Box<dyn Fn(&TXO) -> TXO>;
+
+/// Single saga step
+pub struct SagaStep {
+    /// Step name (recorded in audit entries)
+    pub name: String,
+    /// Forward TXO
+    pub txo: TXO,
+    /// Compensation generator
+    pub compensate: CompensationFn,
+}
+
+/// Kind of saga log entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SagaEntryKind {
+    /// Forward step commit
+    Forward,
+    /// Compensation commit
+    Compensation,
+}
+
+/// Committed saga TXO, linked to the ledger
+#[derive(Debug, Clone)]
+pub struct SagaLogEntry {
+    /// Step index in the saga definition
+    pub step: usize,
+    /// Forward or compensation
+    pub kind: SagaEntryKind,
+    /// TXO identifier
+    pub txo_id: [u8; 16],
+    /// Ledger root before the TXO was appended
+    pub prev_root: [u8; 32],
+    /// Ledger root after the TXO was appended
+    pub ledger_root: [u8; 32],
+}
+
+/// Linked record of every TXO a saga committed
+#[derive(Debug, Clone)]
+pub struct SagaLog {
+    /// Saga identifier
+    pub saga_id: [u8; 16],
+    /// Committed TXOs in commit order
+    pub entries: Vec<SagaLogEntry>,
+}
+
+impl SagaLog {
+    /// Verify that each entry extends the ledger root of the previous entry
+    ///
+    /// # Returns
+    /// * `true` if the saga was committed as a contiguous chain
+    pub fn verify_chain(&self) -> bool {
+        self.entries
+            .windows(2)
+            .all(|pair| pair[1].prev_root == pair[0].ledger_root)
+    }
+
+    /// Ledger root after the last saga commit
+    pub fn head(&self) -> Option<[u8; 32]> {
+        self.entries.last().map(|e| e.ledger_root)
+    }
+}
+
+/// Final saga status
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SagaStatus {
+    /// All steps committed
+    Completed,
+    /// A step failed and all committed steps were compensated
+    Compensated {
+        /// Index of the failed step
+        failed_step: usize,
+        /// RTF error that failed the step
+        error: RTFError,
+    },
+    /// A compensation itself failed; remaining compensations were not attempted
+    CompensationFailed {
+        /// Index of the failed step
+        failed_step: usize,
+        /// RTF error that failed the step
+        error: RTFError,
+        /// Step whose compensation failed
+        compensation_step: usize,
+        /// RTF error that failed the compensation
+        compensation_error: RTFError,
+    },
+}
+
+/// Saga execution result
+#[derive(Debug, Clone)]
+pub struct SagaOutcome {
+    /// Final status
+    pub status: SagaStatus,
+    /// Linked commit log
+    pub log: SagaLog,
+}
+
+/// Saga definition
+pub struct Saga {
+    saga_id: [u8; 16],
+    steps: Vec<SagaStep>,
+}
+
+impl Saga {
+    /// Create an empty saga
+    pub fn new(saga_id: [u8; 16]) -> Self {
+        Self {
+            saga_id,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Append a step with its compensation generator
+    pub fn step<F>(mut self, name: &str, txo: TXO, compensate: F) -> Self
+    where
+        F: Fn(&TXO) -> TXO + 'static,
+    {
+        self.steps.push(SagaStep {
+            name: String::from(name),
+            txo,
+            compensate: Box::new(compensate),
+        });
+        self
+    }
+
+    /// Saga identifier
+    pub fn id(&self) -> [u8; 16] {
+        self.saga_id
+    }
+
+    /// Steps in execution order
+    pub fn steps(&self) -> &[SagaStep] {
+        &self.steps
+    }
+
+    /// Number of steps
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether the saga has no steps
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Execute the saga through `ctx`
+    ///
+    /// # Returns
+    /// * `SagaOutcome` with the final status and linked commit log
+    pub fn execute(self, ctx: &mut RTFContext) -> SagaOutcome {
+        let mut log = SagaLog {
+            saga_id: self.saga_id,
+            entries: Vec::new(),
+        };
+        let mut committed: Vec<(usize, TXO)> = Vec::new();
+
+        for (index, step) in self.steps.iter().enumerate() {
+            let mut txo = step.txo.clone();
+            match self.run(ctx, &mut txo, index, &step.name, SagaEntryKind::Forward, &mut log) {
+                Ok(()) => committed.push((index, txo)),
+                Err(error) => {
+                    let status = self.compensate(ctx, committed, index, error, &mut log);
+                    return SagaOutcome { status, log };
+                }
+            }
+        }
+
+        SagaOutcome {
+            status: SagaStatus::Completed,
+            log,
+        }
+    }
+
+    /// Run compensations for committed steps in reverse order
+    fn compensate(
+        &self,
+        ctx: &mut RTFContext,
+        committed: Vec<(usize, TXO)>,
+        failed_step: usize,
+        error: RTFError,
+        log: &mut SagaLog,
+    ) -> SagaStatus {
+        for (index, forward) in committed.into_iter().rev() {
+            let step = &self.steps[index];
+            let mut txo = (step.compensate)(&forward);
+            txo.add_rollback_entry(RollbackEntry {
+                from_epoch: ctx.current_epoch,
+                to_epoch: forward.epoch_id,
+                reason: format!("Saga compensation for step {} ({})", index, step.name),
+            });
+
+            if let Err(compensation_error) =
+                self.run(ctx, &mut txo, index, &step.name, SagaEntryKind::Compensation, log)
+            {
+                return SagaStatus::CompensationFailed {
+                    failed_step,
+                    error,
+                    compensation_step: index,
+                    compensation_error,
+                };
+            }
+        }
+
+        SagaStatus::Compensated { failed_step, error }
+    }
+
+    /// Execute and commit one saga TXO, recording it in the log
+    fn run(
+        &self,
+        ctx: &mut RTFContext,
+        txo: &mut TXO,
+        index: usize,
+        name: &str,
+        kind: SagaEntryKind,
+        log: &mut SagaLog,
+    ) -> Result<(), RTFError> {
+        let tag = match kind {
+            SagaEntryKind::Forward => "FORWARD",
+            SagaEntryKind::Compensation => "COMPENSATE",
+        };
+        txo.add_audit_entry(AuditEntry {
+            actor_id: txo.sender.id,
+            action: format!("SAGA_{} {} step={} name={}", tag, hex16(&self.saga_id), index, name),
+            timestamp: txo.timestamp,
+        });
+
+        ctx.execute_txo(txo)?;
+        let prev_root = ctx.ledger.get_current_root();
+        ctx.commit_txo(txo)?;
+
+        log.entries.push(SagaLogEntry {
+            step: index,
+            kind,
+            txo_id: txo.txo_id,
+            prev_root,
+            ledger_root: ctx.ledger.get_current_root(),
+        });
+        Ok(())
+    }
+}
+
+/// Lowercase hex encoding of a 16-byte identifier
+pub(crate) fn hex16(bytes: &[u8; 16]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::Zone;
+    use crate::txo::{
+        IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, Signature,
+        SignatureType,
+    };
+
+    fn make_txo(id: u8, signed: bool) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [1u8; 16],
+            biokey_present: false,
+            fido2_signed: signed,
+            zk_proof: None,
+        };
+
+        let receiver = Receiver {
+            identity_type: IdentityType::Node,
+            id: [2u8; 16],
+        };
+
+        let payload = Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: [id; 32],
+            encrypted: false,
+            envelope: None,
+        };
+
+        let mut txo = TXO::new([id; 16], sender, receiver, OperationClass::Network, payload);
+        if signed {
+            txo.add_signature(Signature {
+                sig_type: SignatureType::Fido2,
+                signer_id: [3u8; 16],
+                signature: vec![0u8; 64],
+                consent_ref: None,
+            });
+        }
+        txo
+    }
+
+    fn compensation(forward: &TXO) -> TXO {
+        make_txo(forward.txo_id[0] + 100, true)
+    }
+
+    #[test]
+    fn test_saga_completes() {
+        let mut ctx = RTFContext::new(Zone::Z2, MerkleLedger::new([0u8; 32]));
+
+        let outcome = Saga::new([7u8; 16])
+            .step("reserve", make_txo(1, true), compensation)
+            .step("transfer", make_txo(2, true), compensation)
+            .execute(&mut ctx);
+
+        assert_eq!(outcome.status, SagaStatus::Completed);
+        assert_eq!(outcome.log.entries.len(), 2);
+        assert!(outcome.log.verify_chain());
+        assert_eq!(outcome.log.head(), Some(ctx.ledger.get_current_root()));
+        assert_eq!(ctx.ledger.node_count(), 2);
+    }
+
+    #[test]
+    fn test_saga_compensates_in_reverse() {
+        let mut ctx = RTFContext::new(Zone::Z2, MerkleLedger::new([0u8; 32]));
+
+        // Third step is unsigned and rejected in Z2
+        let outcome = Saga::new([7u8; 16])
+            .step("reserve", make_txo(1, true), compensation)
+            .step("transfer", make_txo(2, true), compensation)
+            .step("notify", make_txo(3, false), compensation)
+            .execute(&mut ctx);
+
+        assert_eq!(
+            outcome.status,
+            SagaStatus::Compensated { failed_step: 2, error: RTFError::MissingSignature }
+        );
+
+        let kinds: Vec<(usize, SagaEntryKind)> = outcome.log.entries.iter().map(|e| (e.step, e.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (0, SagaEntryKind::Forward),
+                (1, SagaEntryKind::Forward),
+                (1, SagaEntryKind::Compensation),
+                (0, SagaEntryKind::Compensation),
+            ]
+        );
+        assert_eq!(outcome.log.entries[2].txo_id, [102u8; 16]);
+        assert!(outcome.log.verify_chain());
+        assert_eq!(ctx.ledger.node_count(), 4);
+        assert!(ctx.ledger.verify_chain());
+    }
+
+    #[test]
+    fn test_saga_compensation_failure_stops() {
+        let mut ctx = RTFContext::new(Zone::Z2, MerkleLedger::new([0u8; 32]));
+
+        let outcome = Saga::new([7u8; 16])
+            .step("reserve", make_txo(1, true), compensation)
+            .step("transfer", make_txo(2, true), |forward: &TXO| make_txo(forward.txo_id[0] + 100, false))
+            .step("notify", make_txo(3, false), compensation)
+            .execute(&mut ctx);
+
+        assert_eq!(
+            outcome.status,
+            SagaStatus::CompensationFailed {
+                failed_step: 2,
+                error: RTFError::MissingSignature,
+                compensation_step: 1,
+                compensation_error: RTFError::MissingSignature,
+            }
+        );
+        assert_eq!(outcome.log.entries.len(), 2);
+    }
+}
@@ -0,0 +1,679 @@
+//! PHI/PII Detection Scanners
+//!
+//! The HIPAA guard and GDPR vault trust callers to tag payloads. A
+//! `Scanner` runs detectors over ingested payload plaintext and proposes the
+//! classification instead: a `PhiTag` sensitivity for the HIPAA guard and a
+//! personal-data flag (Article 9 special category for genomic identifiers)
+//! for the GDPR vault, each with a confidence score.
+//!
+//! Detectors implement the `Detector` trait. `RuleDetector` covers the
+//! common case with a `Pattern`, a small dependency-free regex subset:
+//!
+//! ```text
+//! \d \w \s        digit, word byte, whitespace    \x   literal x
+//! [a-z0-9_.]      byte class ([^...] negates)      .    any byte
+//! ? * + {n} {n,} {n,m}                             greedy quantifiers
+//! ```
+//!
+//! Matches never start or end inside a word, so `\d{3}-\d{2}-\d{4}` does not
+//! fire on a longer digit run. `packs` holds ready-made rules for SSNs,
+//! medical record numbers, email addresses and genomic identifiers.
+//!
+//! Proposals at or above the scanner's auto-accept confidence are accepted
+//! directly; the rest wait in a review queue for a human decision. Findings
+//! record byte spans, never the matched text, so the queue holds no copy of
+//! the identifiers it flags.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::rtf::hipaa_guard::{PhiSensitivity, PhiTag};
+#[cfg(feature = "std")]
+use crate::gdpr::erasure::{ErasureError, PersonalDataVault};
+#[cfg(feature = "std")]
+use crate::txo::TXO;
+
+/// Scanner configuration error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanError {
+    /// Pattern syntax error at the byte offset
+    InvalidPattern(usize),
+}
+
+/// Kind of identifier a detector recognises
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IdentifierKind {
+    /// US Social Security number
+    Ssn,
+    /// Medical record number
+    MedicalRecordNumber,
+    /// Email address
+    Email,
+    /// Genomic identifier (sequencing accession, variant ID)
+    GenomicIdentifier,
+    /// Any other identifier, from a custom detector
+    Other,
+}
+
+impl IdentifierKind {
+    /// PHI sensitivity proposed for payloads containing this identifier
+    pub fn sensitivity(&self) -> PhiSensitivity {
+        match self {
+            IdentifierKind::Email => PhiSensitivity::Low,
+            IdentifierKind::MedicalRecordNumber | IdentifierKind::Other => PhiSensitivity::Medium,
+            IdentifierKind::Ssn | IdentifierKind::GenomicIdentifier => PhiSensitivity::High,
+        }
+    }
+
+    /// Whether the identifier is GDPR Article 9 special category data
+    pub fn special_category(&self) -> bool {
+        matches!(self, IdentifierKind::GenomicIdentifier)
+    }
+}
+
+/// One detected identifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// Identifier kind
+    pub kind: IdentifierKind,
+    /// Name of the detector that fired
+    pub detector: String,
+    /// Start byte offset in the payload
+    pub start: usize,
+    /// End byte offset (exclusive)
+    pub end: usize,
+    /// Confidence, 0-100
+    pub confidence: u8,
+}
+
+/// Pluggable identifier detector
+pub trait Detector {
+    /// Detector name, recorded on its findings
+    fn name(&self) -> &str;
+
+    /// Findings in `content`, in any order
+    fn detect(&self, content: &[u8]) -> Vec<Finding>;
+}
+
+/// Byte-class regex subset (see module docs)
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    atoms: Vec<Atom>,
+}
+
+/// Byte class repeated `min..=max` times
+#[derive(Debug, Clone)]
+struct Atom {
+    class: [u64; 4],
+    min: usize,
+    max: usize,
+}
+
+impl Atom {
+    fn accepts(&self, byte: u8) -> bool {
+        self.class[(byte >> 6) as usize] >> (byte & 63) & 1 == 1
+    }
+}
+
+impl Pattern {
+    /// Compile a pattern
+    ///
+    /// # Returns
+    /// * `Err(ScanError::InvalidPattern)` with the offset of the first error
+    pub fn new(source: &str) -> Result<Self, ScanError> {
+        let bytes = source.as_bytes();
+        let mut atoms = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let (class, next) = match bytes[i] {
+                b'\\' => (escape_class(*bytes.get(i + 1).ok_or(ScanError::InvalidPattern(i))?), i + 2),
+                b'[' => parse_class(bytes, i)?,
+                b'.' => ([u64::MAX; 4], i + 1),
+                b'?' | b'*' | b'+' | b'{' | b'}' | b']' => return Err(ScanError::InvalidPattern(i)),
+                byte => (single(byte), i + 1),
+            };
+            let (min, max, next) = parse_quantifier(bytes, next)?;
+            atoms.push(Atom { class, min, max });
+            i = next;
+        }
+        if atoms.is_empty() {
+            return Err(ScanError::InvalidPattern(0));
+        }
+        Ok(Self { atoms })
+    }
+
+    /// Non-overlapping matches as (start, end) byte spans, leftmost first
+    pub fn find_all(&self, content: &[u8]) -> Vec<(usize, usize)> {
+        let mut spans = Vec::new();
+        let mut start = 0;
+        while start < content.len() {
+            if at_boundary(content, start) {
+                if let Some(end) = self.match_from(content, start, 0, start) {
+                    if end > start {
+                        spans.push((start, end));
+                        start = end;
+                        continue;
+                    }
+                }
+            }
+            start += 1;
+        }
+        spans
+    }
+
+    /// Greedy backtracking match of atoms[atom..] at `pos`
+    fn match_from(&self, content: &[u8], start: usize, atom: usize, pos: usize) -> Option<usize> {
+        let Some(a) = self.atoms.get(atom) else {
+            return (pos == start || at_boundary(content, pos)).then_some(pos);
+        };
+        let mut n = 0;
+        while n < a.max && pos + n < content.len() && a.accepts(content[pos + n]) {
+            n += 1;
+        }
+        if n < a.min {
+            return None;
+        }
+        (a.min..=n).rev().find_map(|k| self.match_from(content, start, atom + 1, pos + k))
+    }
+}
+
+fn is_word(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// True unless `pos` falls between two word bytes
+fn at_boundary(content: &[u8], pos: usize) -> bool {
+    pos == 0 || pos >= content.len() || !(is_word(content[pos - 1]) && is_word(content[pos]))
+}
+
+fn single(byte: u8) -> [u64; 4] {
+    let mut class = [0u64; 4];
+    class[(byte >> 6) as usize] |= 1 << (byte & 63);
+    class
+}
+
+fn union(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+    [a[0] | b[0], a[1] | b[1], a[2] | b[2], a[3] | b[3]]
+}
+
+fn range(lo: u8, hi: u8) -> [u64; 4] {
+    (lo..=hi).fold([0u64; 4], |class, byte| union(class, single(byte)))
+}
+
+fn escape_class(byte: u8) -> [u64; 4] {
+    match byte {
+        b'd' => range(b'0', b'9'),
+        b'w' => union(union(range(b'a', b'z'), range(b'A', b'Z')), union(range(b'0', b'9'), single(b'_'))),
+        b's' => [b' ', b'\t', b'\n', b'\r'].iter().fold([0u64; 4], |class, &b| union(class, single(b))),
+        other => single(other),
+    }
+}
+
+/// Parse `[...]` starting at `bytes[i] == b'['`
+fn parse_class(bytes: &[u8], i: usize) -> Result<([u64; 4], usize), ScanError> {
+    let mut j = i + 1;
+    let negate = bytes.get(j) == Some(&b'^');
+    if negate {
+        j += 1;
+    }
+    let mut class = [0u64; 4];
+    loop {
+        match bytes.get(j) {
+            None => return Err(ScanError::InvalidPattern(i)),
+            Some(b']') => break,
+            Some(b'\\') => {
+                class = union(class, escape_class(*bytes.get(j + 1).ok_or(ScanError::InvalidPattern(j))?));
+                j += 2;
+            }
+            Some(&lo) => match (bytes.get(j + 1), bytes.get(j + 2)) {
+                (Some(b'-'), Some(&hi)) if hi != b']' => {
+                    if hi < lo {
+                        return Err(ScanError::InvalidPattern(j));
+                    }
+                    class = union(class, range(lo, hi));
+                    j += 3;
+                }
+                _ => {
+                    class = union(class, single(lo));
+                    j += 1;
+                }
+            },
+        }
+    }
+    if negate {
+        class = class.map(|word| !word);
+    }
+    Ok((class, j + 1))
+}
+
+/// Parse an optional quantifier at `bytes[i]`
+fn parse_quantifier(bytes: &[u8], i: usize) -> Result<(usize, usize, usize), ScanError> {
+    match bytes.get(i) {
+        Some(b'?') => Ok((0, 1, i + 1)),
+        Some(b'*') => Ok((0, usize::MAX, i + 1)),
+        Some(b'+') => Ok((1, usize::MAX, i + 1)),
+        Some(b'{') => {
+            let (min, j) = parse_number(bytes, i + 1).ok_or(ScanError::InvalidPattern(i))?;
+            let (max, j) = match bytes.get(j) {
+                Some(b'}') => (min, j),
+                Some(b',') => match parse_number(bytes, j + 1) {
+                    Some((max, k)) => (max, k),
+                    None => (usize::MAX, j + 1),
+                },
+                _ => return Err(ScanError::InvalidPattern(j)),
+            };
+            if bytes.get(j) != Some(&b'}') || max < min {
+                return Err(ScanError::InvalidPattern(i));
+            }
+            Ok((min, max, j + 1))
+        }
+        _ => Ok((1, 1, i)),
+    }
+}
+
+fn parse_number(bytes: &[u8], i: usize) -> Option<(usize, usize)> {
+    let digits = bytes[i.min(bytes.len())..].iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    let value = bytes[i..i + digits].iter().try_fold(0usize, |acc, b| {
+        acc.checked_mul(10)?.checked_add((b - b'0') as usize)
+    })?;
+    Some((value, i + digits))
+}
+
+/// Bytes before a match searched for context keywords
+const CONTEXT_WINDOW: usize = 32;
+
+/// Pattern-based detector with optional validation and context boost
+#[derive(Debug, Clone)]
+pub struct RuleDetector {
+    name: String,
+    kind: IdentifierKind,
+    pattern: Pattern,
+    confidence: u8,
+    context: Vec<String>,
+    context_boost: u8,
+    validator: Option<fn(&[u8]) -> bool>,
+}
+
+impl RuleDetector {
+    /// Create a rule reporting `kind` at a base confidence (0-100)
+    pub fn new(
+        name: impl Into<String>,
+        kind: IdentifierKind,
+        pattern: &str,
+        confidence: u8,
+    ) -> Result<Self, ScanError> {
+        Ok(Self {
+            name: name.into(),
+            kind,
+            pattern: Pattern::new(pattern)?,
+            confidence: confidence.min(100),
+            context: Vec::new(),
+            context_boost: 0,
+            validator: None,
+        })
+    }
+
+    /// Raise confidence by `boost` when a keyword (ASCII case-insensitive)
+    /// appears shortly before the match
+    pub fn with_context(mut self, keywords: &[&str], boost: u8) -> Self {
+        self.context = keywords.iter().map(|k| String::from(*k)).collect();
+        self.context_boost = boost;
+        self
+    }
+
+    /// Drop matches the validator rejects (checksums, reserved ranges)
+    pub fn with_validator(mut self, validator: fn(&[u8]) -> bool) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    fn has_context(&self, content: &[u8], start: usize) -> bool {
+        let window = &content[start.saturating_sub(CONTEXT_WINDOW)..start];
+        self.context.iter().any(|keyword| {
+            let keyword = keyword.as_bytes();
+            !keyword.is_empty() && window.windows(keyword.len()).any(|w| w.eq_ignore_ascii_case(keyword))
+        })
+    }
+}
+
+impl Detector for RuleDetector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn detect(&self, content: &[u8]) -> Vec<Finding> {
+        self.pattern
+            .find_all(content)
+            .into_iter()
+            .filter(|&(start, end)| self.validator.is_none_or(|valid| valid(&content[start..end])))
+            .map(|(start, end)| {
+                let boost = if self.has_context(content, start) { self.context_boost } else { 0 };
+                Finding {
+                    kind: self.kind,
+                    detector: self.name.clone(),
+                    start,
+                    end,
+                    confidence: self.confidence.saturating_add(boost).min(100),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Built-in rule packs
+pub mod packs {
+    use super::{IdentifierKind, RuleDetector};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// US Social Security numbers (ddd-dd-dddd, SSA-issuable ranges only)
+    pub fn ssn() -> RuleDetector {
+        RuleDetector::new("ssn", IdentifierKind::Ssn, r"\d{3}-\d{2}-\d{4}", 80)
+            .expect("valid pattern")
+            .with_context(&["ssn", "social security"], 15)
+            .with_validator(valid_ssn)
+    }
+
+    /// Medical record numbers introduced by "MRN"
+    pub fn mrn() -> RuleDetector {
+        RuleDetector::new("mrn", IdentifierKind::MedicalRecordNumber, r"[Mm][Rr][Nn][:#]?\s?\d{6,10}", 90)
+            .expect("valid pattern")
+    }
+
+    /// Email addresses
+    pub fn email() -> RuleDetector {
+        RuleDetector::new("email", IdentifierKind::Email, r"[\w.+\-]+@[\w.\-]+\.[A-Za-z]{2,}", 95)
+            .expect("valid pattern")
+    }
+
+    /// Sequencing run accessions (SRA/ENA/DDBJ) and BioSample IDs
+    pub fn genomic_accessions() -> Vec<RuleDetector> {
+        vec![
+            RuleDetector::new("sra-run", IdentifierKind::GenomicIdentifier, r"[SED]RR\d{6,9}", 85)
+                .expect("valid pattern"),
+            RuleDetector::new("biosample", IdentifierKind::GenomicIdentifier, r"SAM[NED][A-Z]?\d{5,9}", 85)
+                .expect("valid pattern"),
+        ]
+    }
+
+    /// dbSNP variant IDs; weak alone, strong next to genotype context
+    pub fn rsid() -> RuleDetector {
+        RuleDetector::new("rsid", IdentifierKind::GenomicIdentifier, r"rs\d{3,10}", 50)
+            .expect("valid pattern")
+            .with_context(&["snp", "genotype", "allele", "variant"], 30)
+    }
+
+    /// HIPAA direct identifiers: SSN, MRN, email
+    pub fn hipaa() -> Vec<RuleDetector> {
+        vec![ssn(), mrn(), email()]
+    }
+
+    /// Genomic identifiers: accessions and variant IDs
+    pub fn genomic() -> Vec<RuleDetector> {
+        let mut rules = genomic_accessions();
+        rules.push(rsid());
+        rules
+    }
+
+    /// Every built-in rule
+    pub fn all() -> Vec<RuleDetector> {
+        let mut rules = hipaa();
+        rules.extend(genomic());
+        rules
+    }
+
+    /// Reject area 000/666/9xx, group 00 and serial 0000
+    fn valid_ssn(ssn: &[u8]) -> bool {
+        let (area, group, serial) = (&ssn[0..3], &ssn[4..6], &ssn[7..11]);
+        area != b"000" && area != b"666" && area[0] != b'9' && group != b"00" && serial != b"0000"
+    }
+}
+
+/// Proposed classification of one payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proposal {
+    /// Review queue identifier
+    pub id: u64,
+    /// Payload content hash
+    pub content_hash: [u8; 32],
+    /// Data subject the payload is about
+    pub subject_id: [u8; 16],
+    /// Findings, by start offset
+    pub findings: Vec<Finding>,
+    /// Highest finding confidence
+    pub confidence: u8,
+    /// Proposed PHI sensitivity (most sensitive finding)
+    pub sensitivity: PhiSensitivity,
+    /// Whether any finding is GDPR Article 9 special category data
+    pub special_category: bool,
+}
+
+impl Proposal {
+    /// PHI tag for `HipaaGuard::tag_phi`
+    pub fn phi_tag(&self) -> PhiTag {
+        PhiTag {
+            content_hash: self.content_hash,
+            subject_id: self.subject_id,
+            sensitivity: self.sensitivity,
+        }
+    }
+
+    /// Register the TXO carrying the scanned payload as personal data
+    #[cfg(feature = "std")]
+    pub fn register_personal_data(
+        &self,
+        vault: &mut PersonalDataVault,
+        txo: &TXO,
+        record_key: [u8; 32],
+    ) -> Result<(), ErasureError> {
+        vault.register(txo, self.subject_id, record_key)
+    }
+}
+
+/// Result of ingesting a payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanOutcome {
+    /// No identifiers found
+    Clean,
+    /// Confidence met the auto-accept threshold
+    Accepted(Proposal),
+    /// Held for human review under this ID
+    Queued(u64),
+}
+
+/// Runs detectors over ingested payloads and queues uncertain proposals
+pub struct Scanner {
+    detectors: Vec<Box<dyn Detector>>,
+    auto_accept: u8,
+    pending: BTreeMap<u64, Proposal>,
+    next_id: u64,
+}
+
+impl Scanner {
+    /// Create a scanner with no detectors
+    ///
+    /// Proposals with confidence ≥ `auto_accept` skip review.
+    pub fn new(auto_accept: u8) -> Self {
+        Self { detectors: Vec::new(), auto_accept, pending: BTreeMap::new(), next_id: 0 }
+    }
+
+    /// Create a scanner with every built-in rule pack
+    pub fn with_default_packs(auto_accept: u8) -> Self {
+        let mut scanner = Self::new(auto_accept);
+        for rule in packs::all() {
+            scanner.add_detector(Box::new(rule));
+        }
+        scanner
+    }
+
+    /// Add a detector
+    pub fn add_detector(&mut self, detector: Box<dyn Detector>) {
+        self.detectors.push(detector);
+    }
+
+    /// Run all detectors, keeping the most confident of overlapping findings
+    pub fn scan(&self, content: &[u8]) -> Vec<Finding> {
+        let mut candidates: Vec<Finding> = self.detectors.iter().flat_map(|d| d.detect(content)).collect();
+        candidates.sort_by(|a, b| b.confidence.cmp(&a.confidence).then(a.start.cmp(&b.start)));
+
+        let mut findings: Vec<Finding> = Vec::new();
+        for candidate in candidates {
+            if findings.iter().all(|f| candidate.end <= f.start || f.end <= candidate.start) {
+                findings.push(candidate);
+            }
+        }
+        findings.sort_by_key(|f| f.start);
+        findings
+    }
+
+    /// Scan an ingested payload and propose its classification
+    ///
+    /// # Arguments
+    /// * `content_hash` - Payload content hash the tag will apply to
+    /// * `subject_id` - Data subject the payload is about
+    /// * `content` - Payload plaintext
+    pub fn ingest(&mut self, content_hash: [u8; 32], subject_id: [u8; 16], content: &[u8]) -> ScanOutcome {
+        let findings = self.scan(content);
+        let Some(confidence) = findings.iter().map(|f| f.confidence).max() else {
+            return ScanOutcome::Clean;
+        };
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let proposal = Proposal {
+            id,
+            content_hash,
+            subject_id,
+            sensitivity: findings.iter().map(|f| f.kind.sensitivity()).max().unwrap_or(PhiSensitivity::Low),
+            special_category: findings.iter().any(|f| f.kind.special_category()),
+            findings,
+            confidence,
+        };
+        if confidence >= self.auto_accept {
+            ScanOutcome::Accepted(proposal)
+        } else {
+            self.pending.insert(id, proposal);
+            ScanOutcome::Queued(id)
+        }
+    }
+
+    /// Proposals awaiting review, oldest first
+    pub fn review_queue(&self) -> impl Iterator<Item = &Proposal> {
+        self.pending.values()
+    }
+
+    /// Accept a queued proposal, removing it from the queue
+    pub fn approve(&mut self, id: u64) -> Option<Proposal> {
+        self.pending.remove(&id)
+    }
+
+    /// Reject a queued proposal; returns whether it was queued
+    pub fn reject(&mut self, id: u64) -> bool {
+        self.pending.remove(&id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtf::hipaa_guard::HipaaGuard;
+
+    #[test]
+    fn test_pattern_matching() {
+        let ssn = Pattern::new(r"\d{3}-\d{2}-\d{4}").unwrap();
+        assert_eq!(ssn.find_all(b"SSN 123-45-6789, alt 9123-45-6789"), [(4, 15)]);
+
+        let email = packs::email();
+        let text = b"mail a.b+c@lab.example.org.";
+        let found = email.detect(text);
+        assert_eq!(&text[found[0].start..found[0].end], b"a.b+c@lab.example.org");
+
+        let class = Pattern::new("[^0-9]+x?").unwrap();
+        assert_eq!(class.find_all(b"ab12"), []);
+        assert_eq!(class.find_all(b"ab 12"), [(0, 3)]);
+
+        assert_eq!(Pattern::new("").unwrap_err(), ScanError::InvalidPattern(0));
+        assert_eq!(Pattern::new("a{3,1}").unwrap_err(), ScanError::InvalidPattern(1));
+        assert_eq!(Pattern::new("[z-a]").unwrap_err(), ScanError::InvalidPattern(1));
+        assert_eq!(Pattern::new("+").unwrap_err(), ScanError::InvalidPattern(0));
+
+        // Reserved SSN ranges are rejected; context raises confidence
+        assert!(packs::ssn().detect(b"000-12-3456").is_empty());
+        assert_eq!(packs::ssn().detect(b"id 123-45-6789")[0].confidence, 80);
+        assert_eq!(packs::ssn().detect(b"social security: 123-45-6789")[0].confidence, 95);
+    }
+
+    struct Keyword;
+
+    impl Detector for Keyword {
+        fn name(&self) -> &str {
+            "patient-name"
+        }
+
+        fn detect(&self, content: &[u8]) -> Vec<Finding> {
+            content
+                .windows(8)
+                .position(|w| w == b"Jane Doe")
+                .map(|start| Finding {
+                    kind: IdentifierKind::Other,
+                    detector: String::from("patient-name"),
+                    start,
+                    end: start + 8,
+                    confidence: 70,
+                })
+                .into_iter()
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_ingest_and_review_queue() {
+        let mut scanner = Scanner::with_default_packs(80);
+        scanner.add_detector(Box::new(Keyword));
+        let subject = [7u8; 16];
+
+        assert_eq!(scanner.ingest([0u8; 32], subject, b"no identifiers here"), ScanOutcome::Clean);
+
+        // High-confidence findings are accepted and tag the payload
+        let ScanOutcome::Accepted(proposal) =
+            scanner.ingest([1u8; 32], subject, b"MRN: 00123456 run SRR1234567 contact j@x.org")
+        else {
+            panic!("expected auto-accept");
+        };
+        let kinds: Vec<IdentifierKind> = proposal.findings.iter().map(|f| f.kind).collect();
+        assert_eq!(
+            kinds,
+            [IdentifierKind::MedicalRecordNumber, IdentifierKind::GenomicIdentifier, IdentifierKind::Email]
+        );
+        assert_eq!(proposal.confidence, 95);
+        assert_eq!(proposal.sensitivity, PhiSensitivity::High);
+        assert!(proposal.special_category);
+        let mut guard = HipaaGuard::new();
+        guard.tag_phi(proposal.phi_tag());
+
+        // Weak findings wait for review; custom detectors take part
+        let rsid = scanner.ingest([2u8; 32], subject, b"rs12345 noted");
+        let name = scanner.ingest([3u8; 32], subject, b"patient Jane Doe");
+        assert_eq!((rsid.clone(), name.clone()), (ScanOutcome::Queued(1), ScanOutcome::Queued(2)));
+        assert_eq!(scanner.review_queue().count(), 2);
+
+        let approved = scanner.approve(2).unwrap();
+        assert_eq!(approved.findings[0].detector, "patient-name");
+        assert_eq!(approved.sensitivity, PhiSensitivity::Medium);
+        assert!(!approved.special_category);
+        assert!(scanner.reject(1));
+        assert!(!scanner.reject(1));
+        assert_eq!(scanner.review_queue().count(), 0);
+
+        // Genotype context lifts an rsID over the threshold
+        let outcome = scanner.ingest([4u8; 32], subject, b"genotype at rs12345: AG");
+        assert!(matches!(outcome, ScanOutcome::Accepted(p) if p.confidence == 80));
+    }
+}
@@ -0,0 +1,447 @@
+//! TXO Attachments
+//!
+//! Large external blobs (genomic results, simulation outputs) are not embedded
+//! in TXOs. Instead the blob is split into fixed-size chunks, each chunk is
+//! hashed, and the chunk hashes form a Merkle tree. The TXO payload commits to
+//! the manifest (root, length, chunk size) through `Payload::content_hash`, so
+//! the attachment is covered by the TXO's signing bytes.
+//!
+//! On retrieval the manifest is checked against the committed hash and chunks
+//! are verified as they stream in, without buffering the whole blob.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use sha3::{Digest, Sha3_256};
+
+use super::txo::{PayloadType, TXO};
+
+/// Default chunk size (256 KiB)
+pub const DEFAULT_CHUNK_SIZE: u32 = 256 * 1024;
+
+/// Domain tag for leaf (chunk) hashes
+const LEAF_TAG: u8 = 0x00;
+
+/// Domain tag for interior node hashes
+const NODE_TAG: u8 = 0x01;
+
+/// Attachment verification failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachmentError {
+    /// Chunk size is zero
+    InvalidChunkSize,
+    /// Manifest does not match the commitment in the TXO payload
+    CommitmentMismatch,
+    /// Chunk hashes do not form the manifest root
+    RootMismatch,
+    /// Chunk count inconsistent with total length and chunk size
+    ChunkCountMismatch,
+    /// Chunk does not match its manifest hash
+    ChunkHashMismatch {
+        /// Chunk index
+        index: usize,
+    },
+    /// Chunk has the wrong length for its position
+    ChunkLengthMismatch {
+        /// Chunk index
+        index: usize,
+    },
+    /// More chunks received than the manifest lists
+    UnexpectedChunk,
+    /// Stream ended before all chunks were received
+    Incomplete,
+    /// Merkle proof does not lead to the root
+    InvalidProof,
+}
+
+/// Attachment manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentManifest {
+    /// Chunk size in bytes (last chunk may be shorter)
+    pub chunk_size: u32,
+    /// Total blob length in bytes
+    pub total_len: u64,
+    /// Hash of each chunk, in order
+    pub chunk_hashes: Vec<[u8; 32]>,
+    /// Merkle root over `chunk_hashes`
+    pub root: [u8; 32],
+}
+
+impl AttachmentManifest {
+    /// Chunk and hash a blob
+    ///
+    /// # Arguments
+    /// * `data` - Blob contents
+    /// * `chunk_size` - Chunk size in bytes
+    ///
+    /// # Returns
+    /// * Manifest, or `InvalidChunkSize` if `chunk_size` is zero
+    pub fn from_blob(data: &[u8], chunk_size: u32) -> Result<Self, AttachmentError> {
+        if chunk_size == 0 {
+            return Err(AttachmentError::InvalidChunkSize);
+        }
+
+        let chunk_hashes: Vec<[u8; 32]> = data
+            .chunks(chunk_size as usize)
+            .enumerate()
+            .map(|(index, chunk)| chunk_hash(index as u64, chunk))
+            .collect();
+        let root = merkle_root(&chunk_hashes);
+
+        Ok(Self {
+            chunk_size,
+            total_len: data.len() as u64,
+            chunk_hashes,
+            root,
+        })
+    }
+
+    /// Number of chunks
+    pub fn chunk_count(&self) -> usize {
+        self.chunk_hashes.len()
+    }
+
+    /// Commitment embedded in `Payload::content_hash`
+    ///
+    /// Binds the Merkle root to the blob length and chunk size so a manifest
+    /// cannot be reinterpreted with different chunk boundaries.
+    pub fn commitment(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"AETHERNET-ATTACHMENT-v1");
+        hasher.update(self.root);
+        hasher.update(self.total_len.to_le_bytes());
+        hasher.update(self.chunk_size.to_le_bytes());
+
+        let result = hasher.finalize();
+        let mut commitment = [0u8; 32];
+        commitment.copy_from_slice(&result);
+        commitment
+    }
+
+    /// Check the manifest is internally consistent and matches `commitment`
+    pub fn verify(&self, commitment: &[u8; 32]) -> Result<(), AttachmentError> {
+        if self.chunk_size == 0 {
+            return Err(AttachmentError::InvalidChunkSize);
+        }
+        let expected_chunks = self.total_len.div_ceil(self.chunk_size as u64);
+        if self.chunk_hashes.len() as u64 != expected_chunks {
+            return Err(AttachmentError::ChunkCountMismatch);
+        }
+        if merkle_root(&self.chunk_hashes) != self.root {
+            return Err(AttachmentError::RootMismatch);
+        }
+        if self.commitment() != *commitment {
+            return Err(AttachmentError::CommitmentMismatch);
+        }
+        Ok(())
+    }
+
+    /// Expected length of chunk `index`
+    fn chunk_len(&self, index: usize) -> u64 {
+        let start = index as u64 * self.chunk_size as u64;
+        (self.total_len - start).min(self.chunk_size as u64)
+    }
+
+    /// Merkle inclusion proof for chunk `index` (sibling hashes, leaf to root)
+    pub fn proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.chunk_hashes.len() {
+            return None;
+        }
+
+        let mut proof = Vec::new();
+        let mut level = self.chunk_hashes.clone();
+        let mut position = index;
+        while level.len() > 1 {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                proof.push(level[sibling]);
+            }
+            level = next_level(&level);
+            position /= 2;
+        }
+        Some(proof)
+    }
+}
+
+impl TXO {
+    /// Commit an attachment manifest in the payload
+    ///
+    /// Sets `payload.content_hash` to the manifest commitment. Must be called
+    /// before signing.
+    pub fn attach(&mut self, manifest: &AttachmentManifest, payload_type: PayloadType) {
+        self.payload.payload_type = payload_type;
+        self.payload.content_hash = manifest.commitment();
+    }
+
+    /// Start verifying an attachment retrieved for this TXO
+    ///
+    /// # Returns
+    /// * Stream verifier if `manifest` matches the committed payload hash
+    pub fn verify_attachment<'a>(
+        &self,
+        manifest: &'a AttachmentManifest,
+    ) -> Result<AttachmentStream<'a>, AttachmentError> {
+        AttachmentStream::new(manifest, &self.payload.content_hash)
+    }
+}
+
+/// Incremental verifier for a retrieved attachment
+///
+/// Chunks are checked one at a time in order; nothing is buffered.
+#[derive(Debug)]
+pub struct AttachmentStream<'a> {
+    manifest: &'a AttachmentManifest,
+    next_index: usize,
+}
+
+impl<'a> AttachmentStream<'a> {
+    /// Create a verifier after checking the manifest against `commitment`
+    pub fn new(manifest: &'a AttachmentManifest, commitment: &[u8; 32]) -> Result<Self, AttachmentError> {
+        manifest.verify(commitment)?;
+        Ok(Self {
+            manifest,
+            next_index: 0,
+        })
+    }
+
+    /// Verify the next chunk
+    pub fn push_chunk(&mut self, chunk: &[u8]) -> Result<(), AttachmentError> {
+        let index = self.next_index;
+        let expected = self
+            .manifest
+            .chunk_hashes
+            .get(index)
+            .ok_or(AttachmentError::UnexpectedChunk)?;
+        if chunk.len() as u64 != self.manifest.chunk_len(index) {
+            return Err(AttachmentError::ChunkLengthMismatch { index });
+        }
+        if chunk_hash(index as u64, chunk) != *expected {
+            return Err(AttachmentError::ChunkHashMismatch { index });
+        }
+        self.next_index += 1;
+        Ok(())
+    }
+
+    /// Chunks verified so far
+    pub fn verified_chunks(&self) -> usize {
+        self.next_index
+    }
+
+    /// Confirm every chunk was received
+    pub fn finish(self) -> Result<(), AttachmentError> {
+        if self.next_index == self.manifest.chunk_count() {
+            Ok(())
+        } else {
+            Err(AttachmentError::Incomplete)
+        }
+    }
+}
+
+/// Verify a single chunk against a Merkle root (random-access retrieval)
+///
+/// # Arguments
+/// * `root` - Manifest Merkle root
+/// * `chunk_count` - Number of chunks in the attachment
+/// * `index` - Chunk index
+/// * `chunk` - Chunk bytes
+/// * `proof` - Sibling hashes from `AttachmentManifest::proof`
+pub fn verify_chunk(
+    root: &[u8; 32],
+    chunk_count: usize,
+    index: usize,
+    chunk: &[u8],
+    proof: &[[u8; 32]],
+) -> Result<(), AttachmentError> {
+    if index >= chunk_count {
+        return Err(AttachmentError::InvalidProof);
+    }
+
+    let mut hash = chunk_hash(index as u64, chunk);
+    let mut position = index;
+    let mut width = chunk_count;
+    let mut siblings = proof.iter();
+
+    while width > 1 {
+        let sibling = position ^ 1;
+        if sibling < width {
+            let sibling_hash = siblings.next().ok_or(AttachmentError::InvalidProof)?;
+            hash = if position & 1 == 0 {
+                node_hash(&hash, sibling_hash)
+            } else {
+                node_hash(sibling_hash, &hash)
+            };
+        }
+        position /= 2;
+        width = width.div_ceil(2);
+    }
+
+    if siblings.next().is_some() || hash != *root {
+        return Err(AttachmentError::InvalidProof);
+    }
+    Ok(())
+}
+
+/// Leaf hash, bound to the chunk position
+fn chunk_hash(index: u64, chunk: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update([LEAF_TAG]);
+    hasher.update(index.to_le_bytes());
+    hasher.update(chunk);
+
+    let result = hasher.finalize();
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&result);
+    hash
+}
+
+/// Interior node hash
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update([NODE_TAG]);
+    hasher.update(left);
+    hasher.update(right);
+
+    let result = hasher.finalize();
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&result);
+    hash
+}
+
+/// Pair up a tree level; an odd trailing node is promoted unchanged
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => *single,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Merkle root over chunk hashes (empty attachment hashes to the leaf of no data)
+fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return chunk_hash(0, &[]);
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::txo::{IdentityType, OperationClass, Payload, Receiver, Sender};
+
+    fn blob(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 31 % 251) as u8).collect()
+    }
+
+    fn make_txo() -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::System,
+            id: [1u8; 16],
+            biokey_present: false,
+            fido2_signed: true,
+            zk_proof: None,
+        };
+        let receiver = Receiver {
+            identity_type: IdentityType::Node,
+            id: [2u8; 16],
+        };
+        let payload = Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: [0u8; 32],
+            encrypted: false,
+            envelope: None,
+        };
+        TXO::new([3u8; 16], sender, receiver, OperationClass::Genomic, payload)
+    }
+
+    #[test]
+    fn test_attachment_stream_verify() {
+        let data = blob(1000);
+        let manifest = AttachmentManifest::from_blob(&data, 128).unwrap();
+        assert_eq!(manifest.chunk_count(), 8);
+
+        let mut txo = make_txo();
+        txo.attach(&manifest, PayloadType::Genome);
+        assert_eq!(txo.payload.content_hash, manifest.commitment());
+
+        let mut stream = txo.verify_attachment(&manifest).unwrap();
+        for chunk in data.chunks(128) {
+            stream.push_chunk(chunk).unwrap();
+        }
+        assert_eq!(stream.verified_chunks(), 8);
+        assert_eq!(stream.finish(), Ok(()));
+    }
+
+    #[test]
+    fn test_attachment_rejects_tampering() {
+        let data = blob(1000);
+        let manifest = AttachmentManifest::from_blob(&data, 128).unwrap();
+        let mut txo = make_txo();
+        txo.attach(&manifest, PayloadType::Genome);
+
+        // Corrupted chunk
+        let mut stream = txo.verify_attachment(&manifest).unwrap();
+        stream.push_chunk(&data[..128]).unwrap();
+        let mut bad = data[128..256].to_vec();
+        bad[0] ^= 1;
+        assert_eq!(stream.push_chunk(&bad), Err(AttachmentError::ChunkHashMismatch { index: 1 }));
+
+        // Reordered chunks
+        let mut stream = txo.verify_attachment(&manifest).unwrap();
+        assert_eq!(
+            stream.push_chunk(&data[128..256]),
+            Err(AttachmentError::ChunkHashMismatch { index: 0 })
+        );
+
+        // Truncated stream
+        let mut stream = txo.verify_attachment(&manifest).unwrap();
+        stream.push_chunk(&data[..128]).unwrap();
+        assert_eq!(stream.finish(), Err(AttachmentError::Incomplete));
+
+        // Manifest re-chunked with a different size does not match the commitment
+        let other = AttachmentManifest::from_blob(&data, 256).unwrap();
+        assert_eq!(txo.verify_attachment(&other).unwrap_err(), AttachmentError::CommitmentMismatch);
+
+        // Manifest with a substituted chunk hash
+        let mut forged = manifest.clone();
+        forged.chunk_hashes[3] = [0u8; 32];
+        assert_eq!(txo.verify_attachment(&forged).unwrap_err(), AttachmentError::RootMismatch);
+    }
+
+    #[test]
+    fn test_chunk_proofs() {
+        // Odd chunk count exercises node promotion
+        let data = blob(700);
+        let manifest = AttachmentManifest::from_blob(&data, 100).unwrap();
+        assert_eq!(manifest.chunk_count(), 7);
+
+        for (index, chunk) in data.chunks(100).enumerate() {
+            let proof = manifest.proof(index).unwrap();
+            assert_eq!(verify_chunk(&manifest.root, 7, index, chunk, &proof), Ok(()));
+        }
+
+        let proof = manifest.proof(2).unwrap();
+        assert_eq!(
+            verify_chunk(&manifest.root, 7, 3, &data[300..400], &proof),
+            Err(AttachmentError::InvalidProof)
+        );
+        assert_eq!(manifest.proof(7), None);
+    }
+
+    #[test]
+    fn test_empty_and_invalid() {
+        assert_eq!(AttachmentManifest::from_blob(&[1, 2], 0), Err(AttachmentError::InvalidChunkSize));
+
+        let empty = AttachmentManifest::from_blob(&[], DEFAULT_CHUNK_SIZE).unwrap();
+        assert_eq!(empty.chunk_count(), 0);
+        let stream = AttachmentStream::new(&empty, &empty.commitment()).unwrap();
+        assert_eq!(stream.finish(), Ok(()));
+    }
+}
@@ -0,0 +1,240 @@
+//! Fixed-Buffer TXO Encoding
+//!
+//! Heap-free CBOR encoding for enclave builds. `TXO::encoded_size()` walks
+//! the TXO and returns the exact length of its CBOR encoding without
+//! encoding it; `TXO::encode_into()` writes that encoding into a
+//! caller-provided slice and fails with `EncodeError::BufferTooSmall`
+//! instead of growing anything. The bytes are identical to `TXO::to_cbor()`.
+//!
+//! Sizes follow the minicbor derive layout: every struct is an array
+//! indexed by field number, trailing `None` fields are omitted, `None`
+//! elsewhere is `null`, and fixed-size byte arrays and `Vec<u8>` are arrays
+//! of integers.
+
+use core::fmt;
+use minicbor::encode::write::Cursor;
+use minicbor::{Encode, Encoder};
+
+use super::envelope::PayloadEnvelope;
+use super::txo::{AuditEntry, Payload, Receiver, RollbackEntry, Sender, Signature, TXO};
+
+/// Fixed-buffer encoding failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeError {
+    /// Buffer cannot hold the encoding; nothing was written
+    BufferTooSmall {
+        /// Bytes the encoding needs
+        needed: usize,
+        /// Bytes the buffer provides
+        available: usize,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::BufferTooSmall { needed, available } => {
+                write!(f, "TXO encoding needs {} bytes, buffer holds {}", needed, available)
+            }
+        }
+    }
+}
+
+/// Length of a CBOR head carrying argument `n`
+fn head(n: u64) -> usize {
+    match n {
+        0..=23 => 1,
+        24..=0xff => 2,
+        0x100..=0xffff => 3,
+        0x1_0000..=0xffff_ffff => 5,
+        _ => 9,
+    }
+}
+
+/// Bytes encoded as an array of unsigned integers
+fn byte_array(bytes: &[u8]) -> usize {
+    head(bytes.len() as u64) + bytes.iter().map(|b| head(*b as u64)).sum::<usize>()
+}
+
+fn text(s: &str) -> usize {
+    head(s.len() as u64) + s.len()
+}
+
+/// `true`, `false` and `null`
+const SIMPLE: usize = 1;
+
+fn sender(s: &Sender) -> usize {
+    let fields = head(s.identity_type as u64) + byte_array(&s.id) + SIMPLE + SIMPLE;
+    match &s.zk_proof {
+        Some(proof) => head(5) + fields + byte_array(proof),
+        None => head(4) + fields,
+    }
+}
+
+fn receiver(r: &Receiver) -> usize {
+    head(2) + head(r.identity_type as u64) + byte_array(&r.id)
+}
+
+fn payload(p: &Payload) -> usize {
+    let fields = head(p.payload_type as u64) + byte_array(&p.content_hash) + SIMPLE;
+    match &p.envelope {
+        Some(envelope) => head(4) + fields + envelope_size(envelope),
+        None => head(3) + fields,
+    }
+}
+
+fn envelope_size(e: &PayloadEnvelope) -> usize {
+    head(3) + byte_array(&e.kem_ciphertext) + byte_array(&e.ciphertext) + byte_array(&e.tag)
+}
+
+fn signature(s: &Signature) -> usize {
+    let fields = head(s.sig_type as u64) + byte_array(&s.signer_id) + byte_array(&s.signature);
+    match &s.consent_ref {
+        Some(consent) => head(4) + fields + byte_array(consent),
+        None => head(3) + fields,
+    }
+}
+
+fn rollback_entry(r: &RollbackEntry) -> usize {
+    head(3) + head(r.from_epoch) + head(r.to_epoch) + text(&r.reason)
+}
+
+fn audit_entry(a: &AuditEntry) -> usize {
+    head(3) + byte_array(&a.actor_id) + text(&a.action) + head(a.timestamp)
+}
+
+fn list<T>(items: &[T], size: fn(&T) -> usize) -> usize {
+    head(items.len() as u64) + items.iter().map(size).sum::<usize>()
+}
+
+impl TXO {
+    /// Exact length of `to_cbor()` output, computed without allocating
+    pub fn encoded_size(&self) -> usize {
+        let fields = if self.nonce.is_some() { 15 } else { 14 };
+        head(fields)
+            + head(self.version as u64)
+            + byte_array(&self.txo_id)
+            + head(self.timestamp)
+            + head(self.epoch_id)
+            + byte_array(&self.container_hash)
+            + sender(&self.sender)
+            + receiver(&self.receiver)
+            + head(self.operation_class as u64)
+            + SIMPLE
+            + payload(&self.payload)
+            + SIMPLE
+            + list(&self.signatures, signature)
+            + list(&self.rollback_history, rollback_entry)
+            + list(&self.audit_trail, audit_entry)
+            + self.nonce.map_or(0, head)
+    }
+
+    /// Encode into `buf` without heap allocation
+    ///
+    /// # Returns
+    /// * Number of bytes written (always `encoded_size()`)
+    ///
+    /// # Errors
+    /// * `BufferTooSmall` if `buf` is shorter than `encoded_size()`; `buf` is untouched
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        let needed = self.encoded_size();
+        let overflow = EncodeError::BufferTooSmall { needed, available: buf.len() };
+        if buf.len() < needed {
+            return Err(overflow);
+        }
+
+        let mut encoder = Encoder::new(Cursor::new(&mut buf[..needed]));
+        self.encode(&mut encoder, &mut ()).map_err(|_| overflow)?;
+        Ok(encoder.into_writer().position())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::txo::{IdentityType, OperationClass, PayloadType, SignatureType};
+    use alloc::string::String;
+    use alloc::vec;
+
+    fn fixture() -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [1u8; 16],
+            biokey_present: true,
+            fido2_signed: false,
+            zk_proof: None,
+        };
+        let receiver = Receiver { identity_type: IdentityType::Node, id: [200u8; 16] };
+        let payload = Payload {
+            payload_type: PayloadType::Audit,
+            content_hash: [0x5a; 32],
+            encrypted: true,
+            envelope: None,
+        };
+        TXO::new([9u8; 16], sender, receiver, OperationClass::Compliance, payload)
+    }
+
+    #[test]
+    fn test_encoded_size_matches_to_cbor() {
+        let mut txo = fixture();
+        let mut cases = vec![txo.clone()];
+
+        txo.timestamp = 1_700_000_000;
+        txo.epoch_id = u64::MAX;
+        txo.sender.zk_proof = Some((0..=255).collect());
+        cases.push(txo.clone());
+
+        txo.payload.envelope = Some(PayloadEnvelope {
+            kem_ciphertext: vec![0x80; 1568],
+            ciphertext: (0..=255).collect(),
+            tag: [0xa5; 16],
+        });
+        cases.push(txo.clone());
+
+        txo.add_signature(Signature {
+            sig_type: SignatureType::Biokey,
+            signer_id: [30u8; 16],
+            signature: vec![0xff; 64],
+            consent_ref: None,
+        });
+        txo.add_signature(Signature {
+            sig_type: SignatureType::Fido2,
+            signer_id: [2u8; 16],
+            signature: vec![7u8; 64],
+            consent_ref: Some([24u8; 32]),
+        });
+        txo.add_rollback_entry(RollbackEntry { from_epoch: 70_000, to_epoch: 3, reason: String::from("audit") });
+        txo.add_audit_entry(AuditEntry {
+            actor_id: [0u8; 16],
+            action: "x".repeat(300),
+            timestamp: 1 << 33,
+        });
+        cases.push(txo);
+
+        for txo in cases {
+            let expected = txo.to_cbor().unwrap();
+            assert_eq!(txo.encoded_size(), expected.len());
+
+            let mut buf = [0u8; 8192];
+            let written = txo.encode_into(&mut buf).unwrap();
+            assert_eq!(&buf[..written], expected.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let txo = fixture();
+        let needed = txo.encoded_size();
+
+        let mut buf = vec![0xeeu8; needed - 1];
+        assert_eq!(
+            txo.encode_into(&mut buf),
+            Err(EncodeError::BufferTooSmall { needed, available: needed - 1 })
+        );
+        assert!(buf.iter().all(|b| *b == 0xee));
+
+        let mut exact = vec![0u8; needed];
+        assert_eq!(txo.encode_into(&mut exact), Ok(needed));
+        assert_eq!(TXO::from_cbor(&exact).unwrap().txo_id, txo.txo_id);
+    }
+}
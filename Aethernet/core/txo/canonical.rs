@@ -0,0 +1,392 @@
+//! Canonical CBOR (RFC 8949 §4.2.1 core deterministic encoding)
+//!
+//! Signatures are computed over canonical CBOR so that any two encoders
+//! produce identical signing bytes for the same TXO. The rules enforced here:
+//!
+//! - Integers, lengths, and tags use the shortest possible argument
+//! - Only definite-length strings, arrays, and maps
+//! - Map keys sorted by the bytewise lexicographic order of their encodings,
+//!   with no duplicates
+//! - Floating-point values are rejected (TXO signing forms never contain them)
+//!
+//! `CanonicalEncoder` emits this form; `validate` rejects anything else.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Maximum nesting depth accepted by the validator
+pub const MAX_DEPTH: usize = 32;
+
+/// Canonical CBOR violation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanonicalError {
+    /// Input ended inside a data item
+    Truncated,
+    /// Integer, length, or tag argument not in shortest form
+    NonMinimalArgument,
+    /// Indefinite-length string, array, or map
+    IndefiniteLength,
+    /// Reserved additional-information value (28-30)
+    ReservedAdditionalInfo,
+    /// Map keys not in bytewise lexicographic order
+    UnsortedMapKeys,
+    /// Map contains the same key twice
+    DuplicateMapKey,
+    /// Text string is not valid UTF-8
+    InvalidUtf8,
+    /// Floating-point or unassigned simple value
+    UnsupportedSimpleValue,
+    /// Bytes remain after the top-level item
+    TrailingBytes,
+    /// Nesting deeper than `MAX_DEPTH`
+    DepthExceeded,
+    /// Bytes are canonical but do not match the expected signing form
+    Mismatch,
+    /// Bytes are canonical but do not encode a TXO
+    Schema,
+}
+
+impl fmt::Display for CanonicalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "non-canonical CBOR: {:?}", self)
+    }
+}
+
+/// CBOR major types
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+const MAJOR_SIMPLE: u8 = 7;
+
+/// Canonical CBOR encoder
+///
+/// Writes shortest-form heads and definite lengths. Maps are built with
+/// `CanonicalMap`, which sorts entries by encoded key before emission.
+#[derive(Debug, Default, Clone)]
+pub struct CanonicalEncoder {
+    buf: Vec<u8>,
+}
+
+impl CanonicalEncoder {
+    /// Create an empty encoder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Finish encoding and return the bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Write a data item head with the shortest argument encoding
+    fn head(&mut self, major: u8, arg: u64) {
+        let mt = major << 5;
+        if arg < 24 {
+            self.buf.push(mt | arg as u8);
+        } else if arg <= u8::MAX as u64 {
+            self.buf.push(mt | 24);
+            self.buf.push(arg as u8);
+        } else if arg <= u16::MAX as u64 {
+            self.buf.push(mt | 25);
+            self.buf.extend_from_slice(&(arg as u16).to_be_bytes());
+        } else if arg <= u32::MAX as u64 {
+            self.buf.push(mt | 26);
+            self.buf.extend_from_slice(&(arg as u32).to_be_bytes());
+        } else {
+            self.buf.push(mt | 27);
+            self.buf.extend_from_slice(&arg.to_be_bytes());
+        }
+    }
+
+    /// Unsigned integer
+    pub fn u64(&mut self, value: u64) -> &mut Self {
+        self.head(MAJOR_UNSIGNED, value);
+        self
+    }
+
+    /// Signed integer
+    pub fn i64(&mut self, value: i64) -> &mut Self {
+        if value >= 0 {
+            self.head(MAJOR_UNSIGNED, value as u64);
+        } else {
+            self.head(MAJOR_NEGATIVE, !(value as u64));
+        }
+        self
+    }
+
+    /// Byte string
+    pub fn bytes(&mut self, value: &[u8]) -> &mut Self {
+        self.head(MAJOR_BYTES, value.len() as u64);
+        self.buf.extend_from_slice(value);
+        self
+    }
+
+    /// Text string
+    pub fn str(&mut self, value: &str) -> &mut Self {
+        self.head(MAJOR_TEXT, value.len() as u64);
+        self.buf.extend_from_slice(value.as_bytes());
+        self
+    }
+
+    /// Boolean
+    pub fn bool(&mut self, value: bool) -> &mut Self {
+        self.buf.push(if value { 0xf5 } else { 0xf4 });
+        self
+    }
+
+    /// Null
+    pub fn null(&mut self) -> &mut Self {
+        self.buf.push(0xf6);
+        self
+    }
+
+    /// Array header; the caller writes exactly `len` items afterwards
+    pub fn array(&mut self, len: u64) -> &mut Self {
+        self.head(MAJOR_ARRAY, len);
+        self
+    }
+
+    /// Pre-encoded canonical item
+    pub fn raw(&mut self, item: &[u8]) -> &mut Self {
+        self.buf.extend_from_slice(item);
+        self
+    }
+
+    /// Canonical map
+    pub fn map(&mut self, map: CanonicalMap) -> &mut Self {
+        let mut entries = map.entries;
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        self.head(MAJOR_MAP, entries.len() as u64);
+        for (key, value) in entries {
+            self.buf.extend_from_slice(&key);
+            self.buf.extend_from_slice(&value);
+        }
+        self
+    }
+}
+
+/// Map under construction; entries are sorted by encoded key on emission
+#[derive(Debug, Default, Clone)]
+pub struct CanonicalMap {
+    entries: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl CanonicalMap {
+    /// Create an empty map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert an entry keyed by an unsigned integer; `value` writes one item
+    pub fn entry<F>(&mut self, key: u64, value: F) -> &mut Self
+    where
+        F: FnOnce(&mut CanonicalEncoder),
+    {
+        let mut k = CanonicalEncoder::new();
+        k.u64(key);
+        let mut v = CanonicalEncoder::new();
+        value(&mut v);
+        self.entries.push((k.into_bytes(), v.into_bytes()));
+        self
+    }
+}
+
+/// Validate that `bytes` is exactly one canonical CBOR data item
+///
+/// # Returns
+/// * `Ok(())` if the encoding is canonical
+/// * `Err(CanonicalError)` describing the first violation found
+pub fn validate(bytes: &[u8]) -> Result<(), CanonicalError> {
+    let end = validate_item(bytes, 0, 0)?;
+    if end != bytes.len() {
+        return Err(CanonicalError::TrailingBytes);
+    }
+    Ok(())
+}
+
+/// Read a head at `pos`, returning (major, argument, position after head)
+fn read_head(bytes: &[u8], pos: usize) -> Result<(u8, u8, u64, usize), CanonicalError> {
+    let initial = *bytes.get(pos).ok_or(CanonicalError::Truncated)?;
+    let major = initial >> 5;
+    let info = initial & 0x1f;
+
+    let (arg, len) = match info {
+        0..=23 => (info as u64, 0usize),
+        24..=27 => {
+            let len = 1usize << (info - 24);
+            let slice = bytes
+                .get(pos + 1..pos + 1 + len)
+                .ok_or(CanonicalError::Truncated)?;
+            let arg = slice.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+            (arg, len)
+        }
+        28..=30 => return Err(CanonicalError::ReservedAdditionalInfo),
+        _ => return Err(CanonicalError::IndefiniteLength),
+    };
+
+    // Simple values and floats are checked by the caller
+    if major != MAJOR_SIMPLE {
+        let minimal = match len {
+            0 => true,
+            1 => arg >= 24,
+            2 => arg > u8::MAX as u64,
+            4 => arg > u16::MAX as u64,
+            _ => arg > u32::MAX as u64,
+        };
+        if !minimal {
+            return Err(CanonicalError::NonMinimalArgument);
+        }
+    }
+
+    Ok((major, info, arg, pos + 1 + len))
+}
+
+/// Validate one item starting at `pos`, returning the position after it
+fn validate_item(bytes: &[u8], pos: usize, depth: usize) -> Result<usize, CanonicalError> {
+    if depth > MAX_DEPTH {
+        return Err(CanonicalError::DepthExceeded);
+    }
+
+    let (major, info, arg, mut next) = read_head(bytes, pos)?;
+
+    match major {
+        MAJOR_UNSIGNED | MAJOR_NEGATIVE => Ok(next),
+        MAJOR_BYTES | MAJOR_TEXT => {
+            let end = next
+                .checked_add(usize::try_from(arg).map_err(|_| CanonicalError::Truncated)?)
+                .ok_or(CanonicalError::Truncated)?;
+            let content = bytes.get(next..end).ok_or(CanonicalError::Truncated)?;
+            if major == MAJOR_TEXT && core::str::from_utf8(content).is_err() {
+                return Err(CanonicalError::InvalidUtf8);
+            }
+            Ok(end)
+        }
+        MAJOR_ARRAY => {
+            for _ in 0..arg {
+                next = validate_item(bytes, next, depth + 1)?;
+            }
+            Ok(next)
+        }
+        MAJOR_MAP => {
+            let mut previous_key: Option<&[u8]> = None;
+            for _ in 0..arg {
+                let key_end = validate_item(bytes, next, depth + 1)?;
+                let key = &bytes[next..key_end];
+                if let Some(prev) = previous_key {
+                    match prev.cmp(key) {
+                        core::cmp::Ordering::Less => {}
+                        core::cmp::Ordering::Equal => return Err(CanonicalError::DuplicateMapKey),
+                        core::cmp::Ordering::Greater => return Err(CanonicalError::UnsortedMapKeys),
+                    }
+                }
+                previous_key = Some(key);
+                next = validate_item(bytes, key_end, depth + 1)?;
+            }
+            Ok(next)
+        }
+        MAJOR_TAG => validate_item(bytes, next, depth + 1),
+        _ => match info {
+            // false, true, null, undefined
+            20..=23 => Ok(next),
+            _ => Err(CanonicalError::UnsupportedSimpleValue),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortest_form_integers() {
+        for (value, expected) in [
+            (0u64, &[0x00u8][..]),
+            (23, &[0x17]),
+            (24, &[0x18, 0x18]),
+            (255, &[0x18, 0xff]),
+            (256, &[0x19, 0x01, 0x00]),
+            (65_536, &[0x1a, 0x00, 0x01, 0x00, 0x00]),
+            (1 << 32, &[0x1b, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]),
+        ] {
+            let mut enc = CanonicalEncoder::new();
+            enc.u64(value);
+            let bytes = enc.into_bytes();
+            assert_eq!(bytes, expected);
+            assert_eq!(validate(&bytes), Ok(()));
+        }
+
+        let mut enc = CanonicalEncoder::new();
+        enc.i64(-500);
+        assert_eq!(enc.into_bytes(), vec![0x39, 0x01, 0xf3]);
+    }
+
+    #[test]
+    fn test_map_keys_sorted() {
+        let mut map = CanonicalMap::new();
+        map.entry(10, |e| { e.bool(true); });
+        map.entry(2, |e| { e.u64(1); });
+        map.entry(100, |e| { e.null(); });
+
+        let mut enc = CanonicalEncoder::new();
+        enc.map(map);
+        let bytes = enc.into_bytes();
+
+        assert_eq!(bytes, vec![0xa3, 0x02, 0x01, 0x0a, 0xf5, 0x18, 0x64, 0xf6]);
+        assert_eq!(validate(&bytes), Ok(()));
+    }
+
+    #[test]
+    fn test_rejects_non_canonical() {
+        // 5 encoded with a one-byte argument
+        assert_eq!(validate(&[0x18, 0x05]), Err(CanonicalError::NonMinimalArgument));
+        // Byte string length 1 encoded in two bytes
+        assert_eq!(validate(&[0x59, 0x00, 0x01, 0xaa]), Err(CanonicalError::NonMinimalArgument));
+        // Indefinite-length array
+        assert_eq!(validate(&[0x9f, 0x01, 0xff]), Err(CanonicalError::IndefiniteLength));
+        // {1: 0, 0: 0}
+        assert_eq!(validate(&[0xa2, 0x01, 0x00, 0x00, 0x00]), Err(CanonicalError::UnsortedMapKeys));
+        // {0: 0, 0: 0}
+        assert_eq!(validate(&[0xa2, 0x00, 0x00, 0x00, 0x00]), Err(CanonicalError::DuplicateMapKey));
+        // Half-precision float
+        assert_eq!(validate(&[0xf9, 0x3c, 0x00]), Err(CanonicalError::UnsupportedSimpleValue));
+        // Trailing data
+        assert_eq!(validate(&[0x00, 0x00]), Err(CanonicalError::TrailingBytes));
+        // Truncated byte string
+        assert_eq!(validate(&[0x44, 0x01]), Err(CanonicalError::Truncated));
+        // Invalid UTF-8 text
+        assert_eq!(validate(&[0x61, 0xff]), Err(CanonicalError::InvalidUtf8));
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let mut bytes = vec![0x81u8; MAX_DEPTH + 2];
+        bytes.push(0x00);
+        assert_eq!(validate(&bytes), Err(CanonicalError::DepthExceeded));
+    }
+
+    #[test]
+    fn test_cross_validate_with_minicbor() {
+        // Independent encoder producing the same structure
+        let mut expected = Vec::new();
+        let mut mc = minicbor::Encoder::new(&mut expected);
+        mc.map(3).unwrap();
+        mc.u8(0).unwrap().u32(70_000).unwrap();
+        mc.u8(1).unwrap().bytes(&[7u8; 16]).unwrap();
+        mc.u8(2).unwrap().str("audit").unwrap();
+
+        let mut map = CanonicalMap::new();
+        map.entry(2, |e| { e.str("audit"); });
+        map.entry(0, |e| { e.u64(70_000); });
+        map.entry(1, |e| { e.bytes(&[7u8; 16]); });
+        let mut enc = CanonicalEncoder::new();
+        enc.map(map);
+
+        assert_eq!(enc.into_bytes(), expected);
+    }
+}
@@ -0,0 +1,254 @@
+//! Dilithium TXO Signatures
+//!
+//! Post-quantum `SignatureType::Dilithium3` signatures over the same
+//! canonical CBOR signing bytes as Ed25519 (`TXO::signing_bytes()`), so a
+//! PQ signature is bound to exactly one encoding of the TXO.
+//!
+//! For the QRADLE transition a signer holds both a classical Ed25519 key and
+//! a Dilithium3 key. `TXO::upgrade_to_hybrid` adds a Dilithium3 signature
+//! next to a signer's existing classical one, `TXO::migration_status`
+//! reports where a signer stands, and `TXO::verify_hybrid` accepts a signer
+//! only if both signatures verify.
+//!
+//! With the `pqc` feature, ML-DSA-65 keys from `crypto/pqc` implement
+//! `DilithiumSigner` and `DilithiumVerifier` (see `txo::pqc`).
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use super::txo::{Signature, SignatureError, SignatureType, TXO};
+
+/// Dilithium3 (ML-DSA-65) public key size in bytes
+pub const DILITHIUM3_PUBLIC_KEY_SIZE: usize = 1952;
+
+/// Dilithium3 (ML-DSA-65) signature size in bytes
+pub const DILITHIUM3_SIGNATURE_SIZE: usize = 3309;
+
+/// Signer's Dilithium3 secret key
+pub trait DilithiumSigner {
+    /// Sign `message`, returning a `DILITHIUM3_SIGNATURE_SIZE`-byte signature
+    fn sign(&self, message: &[u8]) -> Vec<u8>;
+}
+
+/// Signer's Dilithium3 public key
+pub trait DilithiumVerifier {
+    /// Whether `signature` is valid for `message`
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool;
+}
+
+/// Signature coverage of one signer during the PQ migration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationStatus {
+    /// Signer has not signed the TXO
+    Unsigned,
+    /// Only classical (Ed25519) signatures
+    ClassicalOnly,
+    /// Classical and Dilithium3 signatures
+    Hybrid,
+    /// Only Dilithium3 signatures
+    PostQuantumOnly,
+}
+
+impl TXO {
+    /// Sign the canonical signing bytes with Dilithium3 and attach the signature
+    ///
+    /// # Arguments
+    /// * `signer_id` - Signer UUID
+    /// * `signer` - Signer's Dilithium3 secret key
+    pub fn sign_dilithium3(&mut self, signer_id: [u8; 16], signer: &dyn DilithiumSigner) {
+        let signature = signer.sign(&self.signing_bytes());
+        self.add_signature(Signature {
+            sig_type: SignatureType::Dilithium3,
+            signer_id,
+            signature,
+            consent_ref: None,
+        });
+    }
+
+    /// Verify a Dilithium3 signature over `signed_bytes`
+    ///
+    /// # Arguments
+    /// * `signed_bytes` - Bytes the signer claims to have signed
+    /// * `signature` - Signature to verify (must be `SignatureType::Dilithium3`)
+    /// * `verifier` - Signer's Dilithium3 public key
+    ///
+    /// # Returns
+    /// * `Ok(())` if `signed_bytes` is the canonical signing form and the signature verifies
+    pub fn verify_dilithium3(
+        &self,
+        signed_bytes: &[u8],
+        signature: &Signature,
+        verifier: &dyn DilithiumVerifier,
+    ) -> Result<(), SignatureError> {
+        self.verify_signing_bytes(signed_bytes)
+            .map_err(SignatureError::NonCanonical)?;
+
+        if signature.sig_type != SignatureType::Dilithium3
+            || signature.signature.len() != DILITHIUM3_SIGNATURE_SIZE
+            || !verifier.verify(signed_bytes, &signature.signature)
+        {
+            return Err(SignatureError::InvalidSignature);
+        }
+        Ok(())
+    }
+
+    /// Which kinds of signature `signer_id` has attached
+    pub fn migration_status(&self, signer_id: [u8; 16]) -> MigrationStatus {
+        let signer_sigs = self.signatures.iter().filter(|sig| sig.signer_id == signer_id);
+        let (classical, post_quantum) = signer_sigs.fold((false, false), |(c, pq), sig| {
+            if sig.sig_type == SignatureType::Dilithium3 {
+                (c, true)
+            } else {
+                (true, pq)
+            }
+        });
+        match (classical, post_quantum) {
+            (false, false) => MigrationStatus::Unsigned,
+            (true, false) => MigrationStatus::ClassicalOnly,
+            (true, true) => MigrationStatus::Hybrid,
+            (false, true) => MigrationStatus::PostQuantumOnly,
+        }
+    }
+
+    /// Add a Dilithium3 signature next to a signer's classical signature
+    ///
+    /// Only a `ClassicalOnly` signer is upgraded; any other status is left
+    /// unchanged so a signer never gains a PQ signature without a classical
+    /// one through this path.
+    ///
+    /// # Returns
+    /// * The signer's status after the call
+    pub fn upgrade_to_hybrid(&mut self, signer_id: [u8; 16], signer: &dyn DilithiumSigner) -> MigrationStatus {
+        let status = self.migration_status(signer_id);
+        if status != MigrationStatus::ClassicalOnly {
+            return status;
+        }
+        self.sign_dilithium3(signer_id, signer);
+        MigrationStatus::Hybrid
+    }
+
+    /// Verify a hybrid signer: both an Ed25519 and a Dilithium3 signature must verify
+    ///
+    /// # Arguments
+    /// * `signed_bytes` - Bytes the signer claims to have signed
+    /// * `signer_id` - Signer UUID
+    /// * `ed25519_public_key` - Signer's Ed25519 public key
+    /// * `verifier` - Signer's Dilithium3 public key
+    ///
+    /// # Returns
+    /// * `Ok(())` if at least one signature of each kind from `signer_id` verifies
+    pub fn verify_hybrid(
+        &self,
+        signed_bytes: &[u8],
+        signer_id: [u8; 16],
+        ed25519_public_key: &[u8; 32],
+        verifier: &dyn DilithiumVerifier,
+    ) -> Result<(), SignatureError> {
+        self.verify_signing_bytes(signed_bytes)
+            .map_err(SignatureError::NonCanonical)?;
+
+        let signer_sigs = || self.signatures.iter().filter(move |sig| sig.signer_id == signer_id);
+        let classical = signer_sigs()
+            .filter(|sig| sig.sig_type != SignatureType::Dilithium3)
+            .any(|sig| self.verify_ed25519(signed_bytes, sig, ed25519_public_key).is_ok());
+        let post_quantum = signer_sigs()
+            .filter(|sig| sig.sig_type == SignatureType::Dilithium3)
+            .any(|sig| self.verify_dilithium3(signed_bytes, sig, verifier).is_ok());
+
+        if classical && post_quantum {
+            Ok(())
+        } else {
+            Err(SignatureError::InvalidSignature)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::txo::{IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender};
+    use ed25519_dalek::SigningKey;
+    use sha3::{Digest, Sha3_256};
+
+    /// Stand-in for an ML-DSA-65 key: the signature is a keyed hash padded to size
+    struct TestDilithium([u8; 32]);
+
+    impl DilithiumSigner for TestDilithium {
+        fn sign(&self, message: &[u8]) -> Vec<u8> {
+            let mut signature = Sha3_256::new().chain_update(self.0).chain_update(message).finalize().to_vec();
+            signature.resize(DILITHIUM3_SIGNATURE_SIZE, 0);
+            signature
+        }
+    }
+
+    impl DilithiumVerifier for TestDilithium {
+        fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+            self.sign(message) == signature
+        }
+    }
+
+    const ED_SECRET: [u8; 32] = [5u8; 32];
+    const SIGNER: [u8; 16] = [3u8; 16];
+
+    fn txo() -> TXO {
+        TXO::new(
+            [1u8; 16],
+            Sender { identity_type: IdentityType::Operator, id: [1u8; 16], biokey_present: false, fido2_signed: true, zk_proof: None },
+            Receiver { identity_type: IdentityType::Node, id: [2u8; 16] },
+            OperationClass::Admin,
+            Payload { payload_type: PayloadType::Control, content_hash: [4u8; 32], encrypted: false, envelope: None },
+        )
+    }
+
+    #[test]
+    fn test_dilithium3_sign_verify() {
+        let key = TestDilithium([7u8; 32]);
+        let mut txo = txo();
+        txo.sign_dilithium3(SIGNER, &key);
+        let signed = txo.signing_bytes();
+        let sig = txo.signatures[0].clone();
+        assert_eq!(sig.sig_type, SignatureType::Dilithium3);
+        assert_eq!(sig.signature.len(), DILITHIUM3_SIGNATURE_SIZE);
+        assert_eq!(txo.verify_dilithium3(&signed, &sig, &key), Ok(()));
+        assert_eq!(txo.verify_dilithium3(&signed, &sig, &TestDilithium([8u8; 32])), Err(SignatureError::InvalidSignature));
+
+        // Signature type survives the canonical encoding
+        let decoded = TXO::from_canonical_cbor(&txo.to_canonical_cbor()).unwrap();
+        assert_eq!(decoded.verify_dilithium3(&decoded.signing_bytes(), &decoded.signatures[0], &key), Ok(()));
+
+        // Signed fields changed after signing
+        let mut altered = txo.clone();
+        altered.payload.content_hash = [9u8; 32];
+        assert_eq!(altered.verify_dilithium3(&altered.signing_bytes(), &sig, &key), Err(SignatureError::InvalidSignature));
+    }
+
+    #[test]
+    fn test_hybrid_migration() {
+        let key = TestDilithium([7u8; 32]);
+        let ed_public = SigningKey::from_bytes(&ED_SECRET).verifying_key().to_bytes();
+        let mut txo = txo();
+        assert_eq!(txo.migration_status(SIGNER), MigrationStatus::Unsigned);
+        assert_eq!(txo.upgrade_to_hybrid(SIGNER, &key), MigrationStatus::Unsigned);
+        assert!(txo.signatures.is_empty());
+
+        txo.sign_ed25519(SignatureType::Fido2, SIGNER, &ED_SECRET);
+        assert_eq!(txo.migration_status(SIGNER), MigrationStatus::ClassicalOnly);
+        let signed = txo.signing_bytes();
+        assert_eq!(txo.verify_hybrid(&signed, SIGNER, &ed_public, &key), Err(SignatureError::InvalidSignature));
+
+        assert_eq!(txo.upgrade_to_hybrid(SIGNER, &key), MigrationStatus::Hybrid);
+        assert_eq!(txo.upgrade_to_hybrid(SIGNER, &key), MigrationStatus::Hybrid);
+        assert_eq!(txo.signatures.len(), 2);
+        assert_eq!(txo.verify_hybrid(&signed, SIGNER, &ed_public, &key), Ok(()));
+        assert_eq!(
+            txo.verify_hybrid(&signed, SIGNER, &ed_public, &TestDilithium([8u8; 32])),
+            Err(SignatureError::InvalidSignature)
+        );
+
+        let mut pq_only = self::txo();
+        pq_only.sign_dilithium3(SIGNER, &key);
+        assert_eq!(pq_only.migration_status(SIGNER), MigrationStatus::PostQuantumOnly);
+        assert_eq!(pq_only.migration_status([9u8; 16]), MigrationStatus::Unsigned);
+    }
+}
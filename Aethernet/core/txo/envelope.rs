@@ -0,0 +1,273 @@
+//! Encrypted TXO Payload Envelopes
+//!
+//! Encrypts a payload to a recipient's KEM public key. A fresh KEM
+//! encapsulation yields a shared secret; HKDF-SHA3-256 (RFC 5869), salted
+//! with the KEM ciphertext, derives a single-use ChaCha20-Poly1305 key
+//! (RFC 8439). The AEAD's associated data binds the TXO id, payload type and
+//! KEM ciphertext.
+//!
+//! The envelope is embedded in the payload itself (`Payload::envelope`), so
+//! it travels in the CBOR encoding and is covered by the signing bytes.
+//! `Payload::content_hash` becomes the hash of the ciphertext, and nothing
+//! about the plaintext is left in the clear.
+//!
+//! With the `pqc` feature, ML-KEM keys from `crypto/pqc` implement
+//! `KeyEncapsulator` and `KeyDecapsulator` (see `txo::pqc`).
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use chacha20poly1305::aead::{AeadInPlace, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce, Tag};
+use hkdf::Hkdf;
+use minicbor::{Decode, Encode};
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+
+use super::txo::{PayloadType, TXO};
+
+/// HKDF info string for the envelope key
+const KDF_INFO: &[u8] = b"AETHERNET-TXO-ENVELOPE-KEY-v2";
+
+/// Encrypted payload carried inside `Payload`
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct PayloadEnvelope {
+    /// KEM encapsulation for the recipient
+    #[n(0)]
+    pub kem_ciphertext: Vec<u8>,
+
+    /// Encrypted payload bytes
+    #[n(1)]
+    pub ciphertext: Vec<u8>,
+
+    /// Poly1305 tag over the ciphertext and TXO binding
+    #[n(2)]
+    pub tag: [u8; 16],
+}
+
+/// Envelope failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvelopeError {
+    /// Payload already carries an envelope
+    AlreadyEncrypted,
+    /// Payload carries no envelope
+    NotEncrypted,
+    /// Recipient public key could not encapsulate
+    Encapsulation,
+    /// Secret key could not decapsulate the KEM ciphertext
+    Decapsulation,
+    /// Content hash does not match the envelope ciphertext
+    ContentHashMismatch,
+    /// AEAD check failed (wrong recipient or tampered envelope)
+    IntegrityCheckFailed,
+}
+
+/// Recipient public key side of a KEM (e.g. ML-KEM-1024)
+pub trait KeyEncapsulator {
+    /// Encapsulate a shared secret using caller-supplied randomness
+    ///
+    /// # Returns
+    /// * Shared secret and KEM ciphertext, or `None` if the key is malformed
+    fn encapsulate(&self, coins: &[u8; 32]) -> Option<([u8; 32], Vec<u8>)>;
+}
+
+/// Recipient secret key side of a KEM
+pub trait KeyDecapsulator {
+    /// Recover the shared secret, or `None` if decapsulation fails
+    fn decapsulate(&self, kem_ciphertext: &[u8]) -> Option<[u8; 32]>;
+}
+
+/// Single-use AEAD key derived from a shared secret
+struct EnvelopeKey([u8; 32]);
+
+impl EnvelopeKey {
+    fn derive(shared_secret: &[u8; 32], kem_ciphertext: &[u8]) -> Self {
+        let mut key = Self([0u8; 32]);
+        Hkdf::<Sha3_256>::new(Some(kem_ciphertext), shared_secret)
+            .expand(KDF_INFO, &mut key.0)
+            .expect("32 bytes is a valid HKDF-SHA3-256 output length");
+        key
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(Key::from_slice(&self.0))
+    }
+}
+
+impl Drop for EnvelopeKey {
+    fn drop(&mut self) {
+        self.0.iter_mut().for_each(|b| *b = 0);
+    }
+}
+
+/// Associated data binding the envelope to its TXO and encapsulation
+fn associated_data(txo_id: &[u8; 16], payload_type: PayloadType, kem_ciphertext: &[u8]) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(25 + kem_ciphertext.len());
+    aad.extend_from_slice(txo_id);
+    aad.push(payload_type as u8);
+    aad.extend_from_slice(&(kem_ciphertext.len() as u64).to_le_bytes());
+    aad.extend_from_slice(kem_ciphertext);
+    aad
+}
+
+/// Every envelope key encrypts exactly one message, so a fixed nonce is safe
+fn nonce() -> Nonce {
+    Nonce::default()
+}
+
+impl TXO {
+    /// Encrypt a payload to a recipient's KEM public key
+    ///
+    /// # Arguments
+    /// * `plaintext` - Payload content
+    /// * `recipient` - Recipient's KEM public key
+    /// * `coins` - Fresh randomness for the encapsulation
+    ///
+    /// # Returns
+    /// * `Err(EnvelopeError::AlreadyEncrypted)` if the payload already carries an envelope
+    /// * `Err(EnvelopeError::Encapsulation)` if `recipient` is not a valid KEM key
+    ///
+    /// On success the payload carries the envelope, `encrypted` is set, and
+    /// `content_hash` is the SHA3-256 hash of the ciphertext.
+    pub fn encrypt_payload_for(
+        &mut self,
+        plaintext: &[u8],
+        recipient: &dyn KeyEncapsulator,
+        coins: &[u8; 32],
+    ) -> Result<(), EnvelopeError> {
+        if self.payload.envelope.is_some() {
+            return Err(EnvelopeError::AlreadyEncrypted);
+        }
+        let (mut shared_secret, kem_ciphertext) = recipient.encapsulate(coins).ok_or(EnvelopeError::Encapsulation)?;
+        let key = EnvelopeKey::derive(&shared_secret, &kem_ciphertext);
+        shared_secret.iter_mut().for_each(|b| *b = 0);
+
+        let mut ciphertext = plaintext.to_vec();
+        let aad = associated_data(&self.txo_id, self.payload.payload_type, &kem_ciphertext);
+        let tag: [u8; 16] = key
+            .cipher()
+            .encrypt_in_place_detached(&nonce(), &aad, &mut ciphertext)
+            .expect("payload within the ChaCha20-Poly1305 length limit")
+            .into();
+
+        self.payload.content_hash = Sha3_256::digest(&ciphertext).into();
+        self.payload.encrypted = true;
+        self.payload.envelope = Some(PayloadEnvelope { kem_ciphertext, ciphertext, tag });
+        Ok(())
+    }
+
+    /// Decrypt the payload envelope with the recipient's KEM secret key
+    ///
+    /// # Arguments
+    /// * `secret_key` - Recipient's KEM secret key
+    ///
+    /// # Returns
+    /// * Plaintext payload content
+    /// * `Err(EnvelopeError)` if there is no envelope, it was not encrypted to
+    ///   this key, or any part of it was altered
+    pub fn decrypt_payload(&self, secret_key: &dyn KeyDecapsulator) -> Result<Vec<u8>, EnvelopeError> {
+        let envelope = self.payload.envelope.as_ref().ok_or(EnvelopeError::NotEncrypted)?;
+        let content_hash: [u8; 32] = Sha3_256::digest(&envelope.ciphertext).into();
+        if content_hash != self.payload.content_hash {
+            return Err(EnvelopeError::ContentHashMismatch);
+        }
+
+        let mut shared_secret = secret_key
+            .decapsulate(&envelope.kem_ciphertext)
+            .ok_or(EnvelopeError::Decapsulation)?;
+        let key = EnvelopeKey::derive(&shared_secret, &envelope.kem_ciphertext);
+        shared_secret.iter_mut().for_each(|b| *b = 0);
+
+        let mut plaintext = envelope.ciphertext.clone();
+        let aad = associated_data(&self.txo_id, self.payload.payload_type, &envelope.kem_ciphertext);
+        key.cipher()
+            .decrypt_in_place_detached(&nonce(), &aad, &mut plaintext, Tag::from_slice(&envelope.tag))
+            .map_err(|_| EnvelopeError::IntegrityCheckFailed)?;
+        Ok(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::txo::{IdentityType, OperationClass, Payload, Receiver, Sender};
+
+    /// Deterministic stand-in KEM: secret = H(sk || coins), ciphertext = coins
+    struct TestKem([u8; 32]);
+
+    impl TestKem {
+        fn secret(&self, coins: &[u8]) -> [u8; 32] {
+            Sha3_256::new().chain_update(self.0).chain_update(coins).finalize().into()
+        }
+    }
+
+    impl KeyEncapsulator for TestKem {
+        fn encapsulate(&self, coins: &[u8; 32]) -> Option<([u8; 32], Vec<u8>)> {
+            Some((self.secret(coins), coins.to_vec()))
+        }
+    }
+
+    impl KeyDecapsulator for TestKem {
+        fn decapsulate(&self, kem_ciphertext: &[u8]) -> Option<[u8; 32]> {
+            (kem_ciphertext.len() == 32).then(|| self.secret(kem_ciphertext))
+        }
+    }
+
+    fn txo() -> TXO {
+        TXO::new(
+            [3u8; 16],
+            Sender { identity_type: IdentityType::Operator, id: [1u8; 16], biokey_present: false, fido2_signed: true, zk_proof: None },
+            Receiver { identity_type: IdentityType::Node, id: [2u8; 16] },
+            OperationClass::Genomic,
+            Payload { payload_type: PayloadType::Genome, content_hash: [0u8; 32], encrypted: false, envelope: None },
+        )
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let recipient = TestKem([7u8; 32]);
+        let plaintext = b"rs429358 C/T; rs7412 C/C".repeat(10);
+        let mut txo = txo();
+        txo.encrypt_payload_for(&plaintext, &recipient, &[9u8; 32]).unwrap();
+
+        let envelope = txo.payload.envelope.clone().unwrap();
+        assert!(txo.payload.encrypted);
+        assert_ne!(envelope.ciphertext, plaintext);
+        assert_eq!(txo.payload.content_hash, <[u8; 32]>::from(Sha3_256::digest(&envelope.ciphertext)));
+        assert_eq!(txo.decrypt_payload(&recipient), Ok(plaintext.clone()));
+        assert_eq!(txo.encrypt_payload_for(b"again", &recipient, &[9u8; 32]), Err(EnvelopeError::AlreadyEncrypted));
+
+        // The envelope survives both CBOR encodings and is signed
+        assert_eq!(TXO::from_cbor(&txo.to_cbor().unwrap()).unwrap().payload.envelope, Some(envelope.clone()));
+        let decoded = TXO::from_canonical_cbor(&txo.to_canonical_cbor()).unwrap();
+        assert_eq!(decoded.decrypt_payload(&recipient), Ok(plaintext));
+        let mut unsigned = txo.clone();
+        unsigned.payload.envelope = None;
+        assert_ne!(unsigned.signing_bytes(), txo.signing_bytes());
+    }
+
+    #[test]
+    fn test_decrypt_rejects_wrong_key_and_tampering() {
+        let recipient = TestKem([7u8; 32]);
+        let mut txo = txo();
+        assert_eq!(txo.decrypt_payload(&recipient), Err(EnvelopeError::NotEncrypted));
+        txo.encrypt_payload_for(b"phenotype record", &recipient, &[9u8; 32]).unwrap();
+
+        assert_eq!(txo.decrypt_payload(&TestKem([8u8; 32])), Err(EnvelopeError::IntegrityCheckFailed));
+
+        let mut tampered = txo.clone();
+        tampered.payload.envelope.as_mut().unwrap().ciphertext[0] ^= 1;
+        assert_eq!(tampered.decrypt_payload(&recipient), Err(EnvelopeError::ContentHashMismatch));
+
+        // Moving the envelope to another TXO breaks the AEAD binding
+        let mut moved = txo.clone();
+        moved.txo_id = [4u8; 16];
+        assert_eq!(moved.decrypt_payload(&recipient), Err(EnvelopeError::IntegrityCheckFailed));
+
+        let mut bad_kem = txo;
+        bad_kem.payload.envelope.as_mut().unwrap().kem_ciphertext.pop();
+        assert_eq!(bad_kem.decrypt_payload(&recipient), Err(EnvelopeError::Decapsulation));
+    }
+}
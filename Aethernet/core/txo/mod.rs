@@ -1,5 +1,15 @@
 //! TXO (Transaction Object) module
 
+pub mod attachment;
+pub mod buffer;
+pub mod canonical;
+pub mod dilithium;
+pub mod envelope;
+#[cfg(feature = "pqc")]
+pub mod pqc;
 pub mod txo;
 
+pub use buffer::EncodeError;
+pub use dilithium::{DilithiumSigner, DilithiumVerifier, MigrationStatus};
+pub use envelope::{EnvelopeError, KeyDecapsulator, KeyEncapsulator, PayloadEnvelope};
 pub use txo::*;
@@ -0,0 +1,138 @@
+//! Post-Quantum Backends
+//!
+//! The TXO core does not link a post-quantum implementation: envelopes and
+//! Dilithium signatures go through the `KeyEncapsulator`/`KeyDecapsulator`
+//! and `DilithiumSigner`/`DilithiumVerifier` traits, so a `no_std` build or
+//! an HSM can supply its own keys. This module, enabled by the `pqc`
+//! feature, implements them for the ML-KEM and ML-DSA keys in `crypto/pqc`.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use qratum_crypto_pqc::{ml_dsa, ml_kem};
+
+use super::dilithium::{DilithiumSigner, DilithiumVerifier};
+use super::envelope::{KeyDecapsulator, KeyEncapsulator};
+
+impl KeyEncapsulator for ml_kem::PublicKey {
+    fn encapsulate(&self, coins: &[u8; 32]) -> Option<([u8; 32], Vec<u8>)> {
+        let (shared_secret, ciphertext) = ml_kem::encapsulate_deterministic(self, coins).ok()?;
+        Some((shared_secret, ciphertext.data.clone()))
+    }
+}
+
+impl KeyDecapsulator for ml_kem::SecretKey {
+    fn decapsulate(&self, kem_ciphertext: &[u8]) -> Option<[u8; 32]> {
+        let ciphertext = ml_kem::Ciphertext::from_bytes(self.parameter_set, kem_ciphertext).ok()?;
+        ml_kem::decapsulate(&ciphertext, self).ok()
+    }
+}
+
+/// ML-DSA-65 secret key as a Dilithium3 signer
+///
+/// Signs with an empty FIPS 204 context and deterministic randomness, so
+/// re-signing a TXO reproduces its hash. A key of another parameter set
+/// yields a signature `verify_dilithium3` rejects for its length.
+impl DilithiumSigner for ml_dsa::SecretKey {
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        ml_dsa::sign_deterministic(message, &[], self)
+            .map(|signature| signature.data.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl DilithiumVerifier for ml_dsa::PublicKey {
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        self.parameter_set == ml_dsa::ParameterSet::MlDsa65
+            && ml_dsa::Signature::from_bytes(self.parameter_set, signature)
+                .and_then(|signature| ml_dsa::verify(message, &[], &signature, self))
+                .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::txo::dilithium::DILITHIUM3_SIGNATURE_SIZE;
+    use crate::txo::envelope::EnvelopeError;
+    use crate::txo::{IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, SignatureError, SignatureType, TXO};
+    use ed25519_dalek::SigningKey;
+
+    fn txo_fixture() -> TXO {
+        TXO::new(
+            [3u8; 16],
+            Sender { identity_type: IdentityType::Operator, id: [1u8; 16], biokey_present: false, fido2_signed: true, zk_proof: None },
+            Receiver { identity_type: IdentityType::Node, id: [2u8; 16] },
+            OperationClass::Genomic,
+            Payload { payload_type: PayloadType::Genome, content_hash: [0u8; 32], encrypted: false, envelope: None },
+        )
+    }
+
+    #[test]
+    fn test_ml_kem_1024_envelope() {
+        let (public_key, secret_key) = ml_kem::keygen_from_seed(ml_kem::ParameterSet::MlKem1024, &[1u8; 32], &[2u8; 32]);
+        let plaintext = b"rs429358 C/T; rs7412 C/C";
+        let mut txo = txo_fixture();
+        txo.encrypt_payload_for(plaintext, &public_key, &[9u8; 32]).unwrap();
+
+        let envelope = txo.payload.envelope.clone().unwrap();
+        assert_eq!(envelope.kem_ciphertext.len(), ml_kem::ParameterSet::MlKem1024.ciphertext_size());
+        assert_eq!(txo.decrypt_payload(&secret_key), Ok(plaintext.to_vec()));
+
+        // Implicit rejection gives the wrong key a pseudorandom secret, which the AEAD catches
+        let (_, other) = ml_kem::keygen_from_seed(ml_kem::ParameterSet::MlKem1024, &[3u8; 32], &[4u8; 32]);
+        assert_eq!(txo.decrypt_payload(&other), Err(EnvelopeError::IntegrityCheckFailed));
+
+        let mut tampered = txo.clone();
+        tampered.payload.envelope.as_mut().unwrap().kem_ciphertext[0] ^= 1;
+        assert_eq!(tampered.decrypt_payload(&secret_key), Err(EnvelopeError::IntegrityCheckFailed));
+
+        tampered.payload.envelope.as_mut().unwrap().kem_ciphertext.pop();
+        assert_eq!(tampered.decrypt_payload(&secret_key), Err(EnvelopeError::Decapsulation));
+
+        let (short, _) = ml_kem::keygen_from_seed(ml_kem::ParameterSet::MlKem768, &[1u8; 32], &[2u8; 32]);
+        let malformed = ml_kem::PublicKey { data: short.data, ..public_key };
+        let mut fresh = txo_fixture();
+        assert_eq!(fresh.encrypt_payload_for(plaintext, &malformed, &[9u8; 32]), Err(EnvelopeError::Encapsulation));
+    }
+
+    #[test]
+    fn test_ml_dsa_65_txo_signature() {
+        let (public_key, secret_key) = ml_dsa::keygen_from_seed(ml_dsa::ParameterSet::MlDsa65, &[7u8; 32]);
+        let signer = [3u8; 16];
+        let mut txo = txo_fixture();
+        txo.sign_dilithium3(signer, &secret_key);
+
+        let signed = txo.signing_bytes();
+        let sig = txo.signatures[0].clone();
+        assert_eq!(sig.sig_type, SignatureType::Dilithium3);
+        assert_eq!(sig.signature.len(), DILITHIUM3_SIGNATURE_SIZE);
+        assert_eq!(txo.verify_dilithium3(&signed, &sig, &public_key), Ok(()));
+
+        // The signature is a standard ML-DSA-65 signature over the signing bytes
+        let raw = ml_dsa::Signature::from_bytes(ml_dsa::ParameterSet::MlDsa65, &sig.signature).unwrap();
+        assert_eq!(ml_dsa::verify(&signed, &[], &raw, &public_key), Ok(true));
+
+        let (other, _) = ml_dsa::keygen_from_seed(ml_dsa::ParameterSet::MlDsa65, &[8u8; 32]);
+        assert_eq!(txo.verify_dilithium3(&signed, &sig, &other), Err(SignatureError::InvalidSignature));
+
+        let mut altered = txo.clone();
+        altered.payload.content_hash = [9u8; 32];
+        assert_eq!(altered.verify_dilithium3(&altered.signing_bytes(), &sig, &public_key), Err(SignatureError::InvalidSignature));
+
+        // Hybrid: Ed25519 plus ML-DSA-65 from the same signer
+        let ed_secret = [5u8; 32];
+        let ed_public = SigningKey::from_bytes(&ed_secret).verifying_key().to_bytes();
+        let mut hybrid = txo_fixture();
+        hybrid.sign_ed25519(SignatureType::Fido2, signer, &ed_secret);
+        hybrid.upgrade_to_hybrid(signer, &secret_key);
+        assert_eq!(hybrid.verify_hybrid(&hybrid.signing_bytes(), signer, &ed_public, &public_key), Ok(()));
+
+        // ML-DSA-44 keys cannot produce or check Dilithium3 signatures
+        let (small_public, small_secret) = ml_dsa::keygen_from_seed(ml_dsa::ParameterSet::MlDsa44, &[7u8; 32]);
+        let mut small = txo_fixture();
+        small.sign_dilithium3(signer, &small_secret);
+        assert_eq!(small.verify_dilithium3(&small.signing_bytes(), &small.signatures[0], &small_public), Err(SignatureError::InvalidSignature));
+    }
+}
@@ -1,8 +1,15 @@
 //! TXO (Transaction Object) Implementation
 //!
 //! Core data structure for Aethernet overlay network transactions.
-//! Supports CBOR-primary encoding with JSON-secondary, dual-control signatures,
-//! and zone-aware reversibility.
+//! Supports CBOR-primary encoding with JSON-secondary, dual-control and
+//! M-of-N threshold signatures, and zone-aware reversibility.
+//!
+//! Signatures cover `TXO::signing_bytes()`, the canonical CBOR (RFC 8949
+//! deterministic encoding) of every field except the ones the RTF layer
+//! mutates after signing: epoch, signatures, rollback history, audit trail.
+//! `TXO::to_canonical_cbor()` encodes the full TXO the same way, so the
+//! stored form is byte-identical on every platform and decodes back to
+//! exactly one TXO.
 
 #![no_std]
 
@@ -11,12 +18,19 @@ extern crate alloc;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
-use minicbor::{Decode, Encode};
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
+use minicbor::data::Type;
+use minicbor::{decode, Decode, Decoder, Encode};
+#[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
-use sha3::{Digest, Sha3_256};
+
+use super::canonical::{self, CanonicalEncoder, CanonicalError, CanonicalMap};
+use super::envelope::PayloadEnvelope;
+use crate::hash::StreamingHasher;
 
 /// Identity type for sender/receiver
-#[derive(Debug, Clone, Copy, Encode, Decode, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 #[cbor(index_only)]
 pub enum IdentityType {
     #[n(0)] Operator,
@@ -25,7 +39,8 @@ pub enum IdentityType {
 }
 
 /// Operation class for TXO
-#[derive(Debug, Clone, Copy, Encode, Decode, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 #[cbor(index_only)]
 pub enum OperationClass {
     #[n(0)] Genomic,
@@ -35,7 +50,8 @@ pub enum OperationClass {
 }
 
 /// Payload type
-#[derive(Debug, Clone, Copy, Encode, Decode, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 #[cbor(index_only)]
 pub enum PayloadType {
     #[n(0)] Genome,
@@ -45,15 +61,21 @@ pub enum PayloadType {
 }
 
 /// Signature type
-#[derive(Debug, Clone, Copy, Encode, Decode, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 #[cbor(index_only)]
 pub enum SignatureType {
     #[n(0)] Fido2,
     #[n(1)] Biokey,
+    /// Quorum member share of an M-of-N approval
+    #[n(2)] Threshold,
+    /// Post-quantum Dilithium3 (ML-DSA-65) signature (see `dilithium`)
+    #[n(3)] Dilithium3,
 }
 
 /// Sender identity with biokey support
-#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+#[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Sender {
     /// Type of sender identity
     #[n(0)]
@@ -77,7 +99,8 @@ pub struct Sender {
 }
 
 /// Receiver identity
-#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+#[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Receiver {
     /// Type of receiver identity
     #[n(0)]
@@ -89,7 +112,8 @@ pub struct Receiver {
 }
 
 /// Payload structure
-#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+#[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Payload {
     /// Payload content type
     #[n(0)]
@@ -102,10 +126,16 @@ pub struct Payload {
     /// Encryption status
     #[n(2)]
     pub encrypted: bool,
+    
+    /// Payload encrypted to a recipient KEM key (see `envelope`)
+    #[n(3)]
+    #[cfg_attr(feature = "json", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub envelope: Option<PayloadEnvelope>,
 }
 
 /// Cryptographic signature
-#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+#[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Signature {
     /// Signature type (FIDO2 or Biokey)
     #[n(0)]
@@ -115,13 +145,71 @@ pub struct Signature {
     #[n(1)]
     pub signer_id: [u8; 16],
     
-    /// Signature bytes (64 bytes for Ed25519)
+    /// Signature bytes (64 bytes for Ed25519, 3309 for Dilithium3)
     #[n(2)]
     pub signature: Vec<u8>,
+    
+    /// Consent record this signature was produced under (GDPR Article 7)
+    ///
+    /// Revoking the referenced consent invalidates the signature.
+    #[n(3)]
+    pub consent_ref: Option<[u8; 32]>,
+}
+
+/// Signature verification failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureError {
+    /// Signed bytes are not the canonical signing form of this TXO
+    NonCanonical(CanonicalError),
+    /// Public key is not a valid Ed25519 point
+    InvalidPublicKey,
+    /// Signature bytes are malformed or do not verify
+    InvalidSignature,
+    /// Quorum threshold is zero or exceeds the member count
+    InvalidThreshold,
+    /// Fewer than `required` distinct members produced a valid threshold signature
+    ThresholdNotMet {
+        /// Distinct members with a valid signature
+        valid: usize,
+        /// Signatures the quorum requires
+        required: usize,
+    },
+}
+
+/// M-of-N signer set for `SignatureType::Threshold` signatures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThresholdQuorum {
+    members: Vec<([u8; 16], [u8; 32])>,
+    threshold: usize,
+}
+
+impl ThresholdQuorum {
+    /// Register members as `(signer_id, ed25519_public_key)` pairs
+    ///
+    /// # Arguments
+    /// * `members` - Quorum member identities and keys (N)
+    /// * `threshold` - Distinct member signatures required (M)
+    pub fn new(members: Vec<([u8; 16], [u8; 32])>, threshold: usize) -> Result<Self, SignatureError> {
+        if threshold == 0 || threshold > members.len() {
+            return Err(SignatureError::InvalidThreshold);
+        }
+        Ok(Self { members, threshold })
+    }
+
+    /// Signatures required (M)
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Quorum size (N)
+    pub fn size(&self) -> usize {
+        self.members.len()
+    }
 }
 
 /// Rollback history entry
-#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+#[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct RollbackEntry {
     /// Source epoch
     #[n(0)]
@@ -137,7 +225,8 @@ pub struct RollbackEntry {
 }
 
 /// Audit trail entry
-#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+#[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct AuditEntry {
     /// Actor UUID (128-bit)
     #[n(0)]
@@ -153,7 +242,8 @@ pub struct AuditEntry {
 }
 
 /// Transaction Object (TXO) - Core Aethernet data structure
-#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+#[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct TXO {
     /// Schema version
     #[n(0)]
@@ -210,6 +300,11 @@ pub struct TXO {
     /// Audit trail
     #[n(13)]
     pub audit_trail: Vec<AuditEntry>,
+    
+    /// Per-sender sequence number (see `rtf::nonce`)
+    #[n(14)]
+    #[cfg_attr(feature = "json", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub nonce: Option<u64>,
 }
 
 impl TXO {
@@ -236,24 +331,23 @@ impl TXO {
             signatures: Vec::new(),
             rollback_history: Vec::new(),
             audit_trail: Vec::new(),
+            nonce: None,
         }
     }
     
     /// Compute SHA3-256 hash of TXO content (merkle chaining)
     pub fn compute_hash(&self) -> [u8; 32] {
-        let mut hasher = Sha3_256::new();
-        
-        // Serialize to CBOR for deterministic hashing
-        let mut cbor_buffer = Vec::new();
-        let mut encoder = minicbor::Encoder::new(&mut cbor_buffer);
-        if self.encode(&mut encoder, &mut ()).is_ok() {
-            hasher.update(&cbor_buffer);
-        }
-        
-        let result = hasher.finalize();
-        let mut hash = [0u8; 32];
-        hash.copy_from_slice(&result);
-        hash
+        self.compute_hash_with(&mut StreamingHasher::new())
+    }
+    
+    /// Compute the TXO hash on a reusable context
+    ///
+    /// The CBOR encoding is streamed into the hasher without buffering.
+    pub fn compute_hash_with(&self, hasher: &mut StreamingHasher) -> [u8; 32] {
+        let mut encoder = minicbor::Encoder::new(&mut *hasher);
+        // Writing into the hasher is infallible
+        let _ = self.encode(&mut encoder, &mut ());
+        hasher.finalize_reset()
     }
     
     /// Add a signature to the TXO
@@ -279,6 +373,37 @@ impl TXO {
         self.signatures.len() >= 2
     }
     
+    /// Verify an M-of-N threshold approval
+    ///
+    /// Counts quorum members holding a valid `SignatureType::Threshold`
+    /// signature over the signing bytes. Repeated signatures from one member,
+    /// signatures from non-members, and other signature types do not count.
+    ///
+    /// # Arguments
+    /// * `quorum` - Signer set and threshold the TXO must satisfy
+    ///
+    /// # Returns
+    /// * `Ok(count)` with the number of distinct valid members if `count >= M`
+    /// * `Err(SignatureError::ThresholdNotMet)` otherwise
+    pub fn verify_threshold(&self, quorum: &ThresholdQuorum) -> Result<usize, SignatureError> {
+        let signed = self.signing_bytes();
+        let valid = quorum
+            .members
+            .iter()
+            .filter(|(id, key)| {
+                self.signatures
+                    .iter()
+                    .filter(|sig| sig.sig_type == SignatureType::Threshold && sig.signer_id == *id)
+                    .any(|sig| self.verify_ed25519(&signed, sig, key).is_ok())
+            })
+            .count();
+
+        if valid < quorum.threshold {
+            return Err(SignatureError::ThresholdNotMet { valid, required: quorum.threshold });
+        }
+        Ok(valid)
+    }
+    
     /// Serialize to CBOR (primary encoding)
     pub fn to_cbor(&self) -> Result<Vec<u8>, minicbor::encode::Error<core::convert::Infallible>> {
         let mut buffer = Vec::new();
@@ -288,9 +413,390 @@ impl TXO {
     }
     
     /// Deserialize from CBOR
+    ///
+    /// Accepts both the derive encoding from `to_cbor()` (an array) and the
+    /// canonical encoding from `to_canonical_cbor()` (a map). Map input must
+    /// be canonical; see `from_canonical_cbor`.
     pub fn from_cbor(data: &[u8]) -> Result<Self, minicbor::decode::Error> {
+        if data.first().map(|b| b >> 5) == Some(MAJOR_MAP) {
+            return Self::from_canonical_cbor(data).map_err(decode::Error::message);
+        }
         minicbor::decode(data)
     }
+
+    /// Serialize to canonical CBOR (RFC 8949 deterministic encoding)
+    ///
+    /// The signing-bytes map extended with fields 3, 11, 12, and 13.
+    /// Signatures, rollback entries, and audit entries are maps keyed by
+    /// their schema field indices; absent options are null, except the
+    /// payload envelope and nonce, which are omitted when absent.
+    pub fn to_canonical_cbor(&self) -> Vec<u8> {
+        let mut map = self.signed_fields();
+        map.entry(3, |e| { e.u64(self.epoch_id); })
+            .entry(11, |e| {
+                e.array(self.signatures.len() as u64);
+                for sig in &self.signatures {
+                    let mut m = CanonicalMap::new();
+                    m.entry(0, |e| { e.u64(sig.sig_type as u64); })
+                        .entry(1, |e| { e.bytes(&sig.signer_id); })
+                        .entry(2, |e| { e.bytes(&sig.signature); })
+                        .entry(3, |e| match &sig.consent_ref {
+                            Some(consent) => { e.bytes(consent); }
+                            None => { e.null(); }
+                        });
+                    e.map(m);
+                }
+            })
+            .entry(12, |e| {
+                e.array(self.rollback_history.len() as u64);
+                for entry in &self.rollback_history {
+                    let mut m = CanonicalMap::new();
+                    m.entry(0, |e| { e.u64(entry.from_epoch); })
+                        .entry(1, |e| { e.u64(entry.to_epoch); })
+                        .entry(2, |e| { e.str(&entry.reason); });
+                    e.map(m);
+                }
+            })
+            .entry(13, |e| {
+                e.array(self.audit_trail.len() as u64);
+                for entry in &self.audit_trail {
+                    let mut m = CanonicalMap::new();
+                    m.entry(0, |e| { e.bytes(&entry.actor_id); })
+                        .entry(1, |e| { e.str(&entry.action); })
+                        .entry(2, |e| { e.u64(entry.timestamp); });
+                    e.map(m);
+                }
+            });
+
+        let mut encoder = CanonicalEncoder::new();
+        encoder.map(map);
+        encoder.into_bytes()
+    }
+
+    /// Deserialize from canonical CBOR
+    ///
+    /// Strict inverse of `to_canonical_cbor()`: the input must be canonical
+    /// and carry every schema field, so each TXO has exactly one accepted
+    /// encoding.
+    ///
+    /// # Returns
+    /// * `Err(CanonicalError::Schema)` if canonical bytes do not form a TXO
+    pub fn from_canonical_cbor(data: &[u8]) -> Result<Self, CanonicalError> {
+        canonical::validate(data)?;
+        decode_canonical_txo(&mut Decoder::new(data)).map_err(|_| CanonicalError::Schema)
+    }
+
+    /// Canonical signing bytes
+    ///
+    /// A CBOR map keyed by the field indices of the TXO schema, encoded
+    /// deterministically (shortest-form integers, definite lengths, sorted
+    /// keys). Identifiers and hashes are byte strings; enums are their
+    /// schema indices. Fields 3, 11, 12, and 13 are excluded because they
+    /// change after signing. The payload envelope (9.3) appears only on
+    /// encrypted payloads and the nonce (14) only when set, so TXOs without
+    /// them keep their original encoding.
+    ///
+    /// # Returns
+    /// * Bytes every signer and verifier must use
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut encoder = CanonicalEncoder::new();
+        encoder.map(self.signed_fields());
+        encoder.into_bytes()
+    }
+
+    /// Canonical map of the fields covered by signatures
+    fn signed_fields(&self) -> CanonicalMap {
+        let sender = &self.sender;
+        let receiver = &self.receiver;
+        let payload = &self.payload;
+
+        let mut map = CanonicalMap::new();
+        map.entry(0, |e| { e.u64(self.version as u64); })
+            .entry(1, |e| { e.bytes(&self.txo_id); })
+            .entry(2, |e| { e.u64(self.timestamp); })
+            .entry(4, |e| { e.bytes(&self.container_hash); })
+            .entry(5, |e| {
+                let mut m = CanonicalMap::new();
+                m.entry(0, |e| { e.u64(sender.identity_type as u64); })
+                    .entry(1, |e| { e.bytes(&sender.id); })
+                    .entry(2, |e| { e.bool(sender.biokey_present); })
+                    .entry(3, |e| { e.bool(sender.fido2_signed); })
+                    .entry(4, |e| match &sender.zk_proof {
+                        Some(proof) => { e.bytes(proof); }
+                        None => { e.null(); }
+                    });
+                e.map(m);
+            })
+            .entry(6, |e| {
+                let mut m = CanonicalMap::new();
+                m.entry(0, |e| { e.u64(receiver.identity_type as u64); })
+                    .entry(1, |e| { e.bytes(&receiver.id); });
+                e.map(m);
+            })
+            .entry(7, |e| { e.u64(self.operation_class as u64); })
+            .entry(8, |e| { e.bool(self.reversibility_flag); })
+            .entry(9, |e| {
+                let mut m = CanonicalMap::new();
+                m.entry(0, |e| { e.u64(payload.payload_type as u64); })
+                    .entry(1, |e| { e.bytes(&payload.content_hash); })
+                    .entry(2, |e| { e.bool(payload.encrypted); });
+                if let Some(envelope) = &payload.envelope {
+                    m.entry(3, |e| {
+                        let mut m = CanonicalMap::new();
+                        m.entry(0, |e| { e.bytes(&envelope.kem_ciphertext); })
+                            .entry(1, |e| { e.bytes(&envelope.ciphertext); })
+                            .entry(2, |e| { e.bytes(&envelope.tag); });
+                        e.map(m);
+                    });
+                }
+                e.map(m);
+            })
+            .entry(10, |e| { e.bool(self.dual_control_required); });
+        if let Some(nonce) = self.nonce {
+            map.entry(14, |e| { e.u64(nonce); });
+        }
+        map
+    }
+
+    /// Check that externally supplied signed bytes are this TXO's signing form
+    ///
+    /// Non-canonical encodings are rejected even if they decode to the same
+    /// values, so a signature can never be bound to more than one encoding.
+    pub fn verify_signing_bytes(&self, bytes: &[u8]) -> Result<(), CanonicalError> {
+        canonical::validate(bytes)?;
+        if bytes != self.signing_bytes().as_slice() {
+            return Err(CanonicalError::Mismatch);
+        }
+        Ok(())
+    }
+
+    /// Sign the canonical signing bytes with Ed25519 and attach the signature
+    ///
+    /// # Arguments
+    /// * `sig_type` - Signature type (FIDO2 or Biokey)
+    /// * `signer_id` - Signer UUID
+    /// * `secret_key` - Ed25519 secret key seed
+    pub fn sign_ed25519(&mut self, sig_type: SignatureType, signer_id: [u8; 16], secret_key: &[u8; 32]) {
+        let signing_key = SigningKey::from_bytes(secret_key);
+        let signature = signing_key.sign(&self.signing_bytes());
+        self.add_signature(Signature {
+            sig_type,
+            signer_id,
+            signature: signature.to_bytes().to_vec(),
+            consent_ref: None,
+        });
+    }
+
+    /// Verify an Ed25519 signature over `signed_bytes`
+    ///
+    /// # Arguments
+    /// * `signed_bytes` - Bytes the signer claims to have signed
+    /// * `signature` - Signature to verify
+    /// * `public_key` - Signer's Ed25519 public key
+    ///
+    /// # Returns
+    /// * `Ok(())` if `signed_bytes` is the canonical signing form and the signature verifies
+    pub fn verify_ed25519(
+        &self,
+        signed_bytes: &[u8],
+        signature: &Signature,
+        public_key: &[u8; 32],
+    ) -> Result<(), SignatureError> {
+        self.verify_signing_bytes(signed_bytes)
+            .map_err(SignatureError::NonCanonical)?;
+
+        let key = VerifyingKey::from_bytes(public_key).map_err(|_| SignatureError::InvalidPublicKey)?;
+        let sig = ed25519_dalek::Signature::from_slice(&signature.signature)
+            .map_err(|_| SignatureError::InvalidSignature)?;
+        key.verify_strict(signed_bytes, &sig)
+            .map_err(|_| SignatureError::InvalidSignature)
+    }
+}
+
+/// CBOR major type of maps
+const MAJOR_MAP: u8 = 5;
+
+/// Enter a map that must have exactly `len` entries
+fn canonical_map(d: &mut Decoder<'_>, len: u64) -> Result<(), decode::Error> {
+    if d.map()? != Some(len) {
+        return Err(decode::Error::message("unexpected map length"));
+    }
+    Ok(())
+}
+
+/// Consume the next map key, which must be `key`
+///
+/// Canonical maps keyed by small integers list keys in ascending order.
+fn canonical_key(d: &mut Decoder<'_>, key: u64) -> Result<(), decode::Error> {
+    if d.u64()? != key {
+        return Err(decode::Error::message("unexpected map key"));
+    }
+    Ok(())
+}
+
+/// Fixed-length byte string
+fn canonical_id<const N: usize>(d: &mut Decoder<'_>) -> Result<[u8; N], decode::Error> {
+    d.bytes()?
+        .try_into()
+        .map_err(|_| decode::Error::message("unexpected byte string length"))
+}
+
+/// Byte string or null
+fn canonical_option<'b>(d: &mut Decoder<'b>) -> Result<Option<&'b [u8]>, decode::Error> {
+    if d.datatype()? == Type::Null {
+        d.skip()?;
+        return Ok(None);
+    }
+    d.bytes().map(Some)
+}
+
+/// Decode a validated `to_canonical_cbor()` encoding
+fn decode_canonical_txo(d: &mut Decoder<'_>) -> Result<TXO, decode::Error> {
+    let has_nonce = match d.map()? {
+        Some(14) => false,
+        Some(15) => true,
+        _ => return Err(decode::Error::message("unexpected map length")),
+    };
+    canonical_key(d, 0)?;
+    let version = d.u32()?;
+    canonical_key(d, 1)?;
+    let txo_id = canonical_id(d)?;
+    canonical_key(d, 2)?;
+    let timestamp = d.u64()?;
+    canonical_key(d, 3)?;
+    let epoch_id = d.u64()?;
+    canonical_key(d, 4)?;
+    let container_hash = canonical_id(d)?;
+
+    canonical_key(d, 5)?;
+    canonical_map(d, 5)?;
+    canonical_key(d, 0)?;
+    let identity_type = d.decode()?;
+    canonical_key(d, 1)?;
+    let id = canonical_id(d)?;
+    canonical_key(d, 2)?;
+    let biokey_present = d.bool()?;
+    canonical_key(d, 3)?;
+    let fido2_signed = d.bool()?;
+    canonical_key(d, 4)?;
+    let zk_proof = canonical_option(d)?.map(Vec::from);
+    let sender = Sender { identity_type, id, biokey_present, fido2_signed, zk_proof };
+
+    canonical_key(d, 6)?;
+    canonical_map(d, 2)?;
+    canonical_key(d, 0)?;
+    let identity_type = d.decode()?;
+    canonical_key(d, 1)?;
+    let receiver = Receiver { identity_type, id: canonical_id(d)? };
+
+    canonical_key(d, 7)?;
+    let operation_class = d.decode()?;
+    canonical_key(d, 8)?;
+    let reversibility_flag = d.bool()?;
+
+    canonical_key(d, 9)?;
+    let has_envelope = match d.map()? {
+        Some(3) => false,
+        Some(4) => true,
+        _ => return Err(decode::Error::message("unexpected map length")),
+    };
+    canonical_key(d, 0)?;
+    let payload_type = d.decode()?;
+    canonical_key(d, 1)?;
+    let content_hash = canonical_id(d)?;
+    canonical_key(d, 2)?;
+    let encrypted = d.bool()?;
+    let envelope = if has_envelope {
+        canonical_key(d, 3)?;
+        canonical_map(d, 3)?;
+        canonical_key(d, 0)?;
+        let kem_ciphertext = Vec::from(d.bytes()?);
+        canonical_key(d, 1)?;
+        let ciphertext = Vec::from(d.bytes()?);
+        canonical_key(d, 2)?;
+        Some(PayloadEnvelope { kem_ciphertext, ciphertext, tag: canonical_id(d)? })
+    } else {
+        None
+    };
+    let payload = Payload { payload_type, content_hash, encrypted, envelope };
+
+    canonical_key(d, 10)?;
+    let dual_control_required = d.bool()?;
+
+    canonical_key(d, 11)?;
+    let count = d.array()?.ok_or_else(|| decode::Error::message("indefinite array"))?;
+    let mut signatures = Vec::new();
+    for _ in 0..count {
+        canonical_map(d, 4)?;
+        canonical_key(d, 0)?;
+        let sig_type = d.decode()?;
+        canonical_key(d, 1)?;
+        let signer_id = canonical_id(d)?;
+        canonical_key(d, 2)?;
+        let signature = Vec::from(d.bytes()?);
+        canonical_key(d, 3)?;
+        let consent_ref = match canonical_option(d)? {
+            Some(bytes) => Some(
+                bytes
+                    .try_into()
+                    .map_err(|_| decode::Error::message("unexpected byte string length"))?,
+            ),
+            None => None,
+        };
+        signatures.push(Signature { sig_type, signer_id, signature, consent_ref });
+    }
+
+    canonical_key(d, 12)?;
+    let count = d.array()?.ok_or_else(|| decode::Error::message("indefinite array"))?;
+    let mut rollback_history = Vec::new();
+    for _ in 0..count {
+        canonical_map(d, 3)?;
+        canonical_key(d, 0)?;
+        let from_epoch = d.u64()?;
+        canonical_key(d, 1)?;
+        let to_epoch = d.u64()?;
+        canonical_key(d, 2)?;
+        let reason = String::from(d.str()?);
+        rollback_history.push(RollbackEntry { from_epoch, to_epoch, reason });
+    }
+
+    canonical_key(d, 13)?;
+    let count = d.array()?.ok_or_else(|| decode::Error::message("indefinite array"))?;
+    let mut audit_trail = Vec::new();
+    for _ in 0..count {
+        canonical_map(d, 3)?;
+        canonical_key(d, 0)?;
+        let actor_id = canonical_id(d)?;
+        canonical_key(d, 1)?;
+        let action = String::from(d.str()?);
+        canonical_key(d, 2)?;
+        audit_trail.push(AuditEntry { actor_id, action, timestamp: d.u64()? });
+    }
+
+    let nonce = if has_nonce {
+        canonical_key(d, 14)?;
+        Some(d.u64()?)
+    } else {
+        None
+    };
+
+    Ok(TXO {
+        version,
+        txo_id,
+        timestamp,
+        epoch_id,
+        container_hash,
+        sender,
+        receiver,
+        operation_class,
+        reversibility_flag,
+        payload,
+        dual_control_required,
+        signatures,
+        rollback_history,
+        audit_trail,
+        nonce,
+    })
 }
 
 impl fmt::Display for TXO {
@@ -329,6 +835,7 @@ mod tests {
             payload_type: PayloadType::Genome,
             content_hash: [3u8; 32],
             encrypted: true,
+            envelope: None,
         };
         
         let txo = TXO::new(
@@ -362,6 +869,7 @@ mod tests {
             payload_type: PayloadType::Metadata,
             content_hash: [7u8; 32],
             encrypted: false,
+            envelope: None,
         };
         
         let txo = TXO::new(
@@ -401,6 +909,7 @@ mod tests {
             payload_type: PayloadType::Control,
             content_hash: [3u8; 32],
             encrypted: true,
+            envelope: None,
         };
         
         let mut txo = TXO::new(
@@ -421,6 +930,7 @@ mod tests {
             sig_type: SignatureType::Fido2,
             signer_id: [5u8; 16],
             signature: vec![0u8; 64],
+            consent_ref: None,
         });
         
         // Should still fail with only one signature
@@ -431,9 +941,335 @@ mod tests {
             sig_type: SignatureType::Fido2,
             signer_id: [6u8; 16],
             signature: vec![0u8; 64],
+            consent_ref: None,
         });
         
         // Should pass with two signatures
         assert!(txo.verify_dual_control());
     }
+    
+    fn signing_fixture() -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [1u8; 16],
+            biokey_present: true,
+            fido2_signed: true,
+            zk_proof: Some(vec![9u8; 40]),
+        };
+        
+        let receiver = Receiver {
+            identity_type: IdentityType::Node,
+            id: [2u8; 16],
+        };
+        
+        let payload = Payload {
+            payload_type: PayloadType::Genome,
+            content_hash: [3u8; 32],
+            encrypted: true,
+            envelope: None,
+        };
+        
+        let mut txo = TXO::new([4u8; 16], sender, receiver, OperationClass::Genomic, payload);
+        txo.timestamp = 1_700_000_000;
+        txo.container_hash = [5u8; 32];
+        txo
+    }
+    
+    #[test]
+    fn test_signing_bytes_cross_validate() {
+        let txo = signing_fixture();
+        
+        // Same structure through minicbor's encoder, keys written in order
+        let mut expected = Vec::new();
+        let mut e = minicbor::Encoder::new(&mut expected);
+        e.map(10).unwrap();
+        e.u8(0).unwrap().u32(1).unwrap();
+        e.u8(1).unwrap().bytes(&[4u8; 16]).unwrap();
+        e.u8(2).unwrap().u64(1_700_000_000).unwrap();
+        e.u8(4).unwrap().bytes(&[5u8; 32]).unwrap();
+        e.u8(5).unwrap().map(5).unwrap();
+        e.u8(0).unwrap().u8(0).unwrap();
+        e.u8(1).unwrap().bytes(&[1u8; 16]).unwrap();
+        e.u8(2).unwrap().bool(true).unwrap();
+        e.u8(3).unwrap().bool(true).unwrap();
+        e.u8(4).unwrap().bytes(&[9u8; 40]).unwrap();
+        e.u8(6).unwrap().map(2).unwrap();
+        e.u8(0).unwrap().u8(1).unwrap();
+        e.u8(1).unwrap().bytes(&[2u8; 16]).unwrap();
+        e.u8(7).unwrap().u8(0).unwrap();
+        e.u8(8).unwrap().bool(true).unwrap();
+        e.u8(9).unwrap().map(3).unwrap();
+        e.u8(0).unwrap().u8(0).unwrap();
+        e.u8(1).unwrap().bytes(&[3u8; 32]).unwrap();
+        e.u8(2).unwrap().bool(true).unwrap();
+        e.u8(10).unwrap().bool(false).unwrap();
+        
+        assert_eq!(txo.signing_bytes(), expected);
+        assert_eq!(canonical::validate(&expected), Ok(()));
+        
+        // The derive encoding is a single well-formed canonical item too
+        assert_eq!(canonical::validate(&txo.to_cbor().unwrap()), Ok(()));
+    }
+    
+    #[test]
+    fn test_signing_bytes_exclude_mutable_fields() {
+        let mut txo = signing_fixture();
+        let before = txo.signing_bytes();
+        
+        txo.epoch_id = 42;
+        txo.add_audit_entry(AuditEntry {
+            actor_id: [1u8; 16],
+            action: String::from("EXECUTE"),
+            timestamp: 1,
+        });
+        txo.sign_ed25519(SignatureType::Fido2, [6u8; 16], &[7u8; 32]);
+        assert_eq!(txo.signing_bytes(), before);
+        
+        txo.payload.encrypted = false;
+        assert_ne!(txo.signing_bytes(), before);
+    }
+    
+    #[test]
+    fn test_ed25519_sign_verify() {
+        let secret = [7u8; 32];
+        let public = SigningKey::from_bytes(&secret).verifying_key().to_bytes();
+        
+        let mut txo = signing_fixture();
+        txo.sign_ed25519(SignatureType::Fido2, [6u8; 16], &secret);
+        let sig = txo.signatures[0].clone();
+        let signed = txo.signing_bytes();
+        
+        assert_eq!(txo.verify_ed25519(&signed, &sig, &public), Ok(()));
+        
+        // Wrong key
+        let other = SigningKey::from_bytes(&[8u8; 32]).verifying_key().to_bytes();
+        assert_eq!(txo.verify_ed25519(&signed, &sig, &other), Err(SignatureError::InvalidSignature));
+        
+        // Signed bytes for a different TXO
+        let mut changed = txo.clone();
+        changed.timestamp += 1;
+        assert_eq!(
+            changed.verify_ed25519(&signed, &sig, &public),
+            Err(SignatureError::NonCanonical(CanonicalError::Mismatch))
+        );
+    }
+    
+    #[test]
+    fn test_threshold_signatures() {
+        let secrets: Vec<[u8; 32]> = (1..=5u8).map(|i| [i; 32]).collect();
+        let members = secrets
+            .iter()
+            .enumerate()
+            .map(|(i, s)| ([i as u8; 16], SigningKey::from_bytes(s).verifying_key().to_bytes()))
+            .collect::<Vec<_>>();
+        let quorum = ThresholdQuorum::new(members.clone(), 3).unwrap();
+        assert_eq!(ThresholdQuorum::new(members, 6), Err(SignatureError::InvalidThreshold));
+        
+        let mut txo = signing_fixture();
+        txo.sign_ed25519(SignatureType::Threshold, [0u8; 16], &secrets[0]);
+        txo.sign_ed25519(SignatureType::Threshold, [1u8; 16], &secrets[1]);
+        
+        // Repeats, other signature types and non-members do not count
+        txo.sign_ed25519(SignatureType::Threshold, [1u8; 16], &secrets[1]);
+        txo.sign_ed25519(SignatureType::Fido2, [2u8; 16], &secrets[2]);
+        txo.sign_ed25519(SignatureType::Threshold, [9u8; 16], &[9u8; 32]);
+        txo.sign_ed25519(SignatureType::Threshold, [3u8; 16], &[9u8; 32]);
+        assert_eq!(
+            txo.verify_threshold(&quorum),
+            Err(SignatureError::ThresholdNotMet { valid: 2, required: 3 })
+        );
+        
+        txo.sign_ed25519(SignatureType::Threshold, [4u8; 16], &secrets[4]);
+        assert_eq!(txo.verify_threshold(&quorum), Ok(3));
+        
+        // Signatures stay bound to the signed fields
+        txo.payload.encrypted = false;
+        assert!(txo.verify_threshold(&quorum).is_err());
+    }
+    
+    #[test]
+    fn test_verify_rejects_non_canonical_encoding() {
+        let txo = signing_fixture();
+        let canonical_bytes = txo.signing_bytes();
+        
+        // Re-encode the version (first value, 0x01) with a one-byte argument
+        assert_eq!(&canonical_bytes[..3], &[0xaa, 0x00, 0x01]);
+        let mut padded = Vec::new();
+        padded.extend_from_slice(&[0xaa, 0x00, 0x18, 0x01]);
+        padded.extend_from_slice(&canonical_bytes[3..]);
+        assert_eq!(txo.verify_signing_bytes(&padded), Err(CanonicalError::NonMinimalArgument));
+        
+        // Same entries with the map made indefinite-length
+        let mut indefinite = vec![0xbf];
+        indefinite.extend_from_slice(&canonical_bytes[1..]);
+        indefinite.push(0xff);
+        assert_eq!(txo.verify_signing_bytes(&indefinite), Err(CanonicalError::IndefiniteLength));
+        
+        // Signature over a non-canonical form is refused before key checks
+        let sig = Signature {
+            sig_type: SignatureType::Fido2,
+            signer_id: [6u8; 16],
+            signature: vec![0u8; 64],
+            consent_ref: None,
+        };
+        assert_eq!(
+            txo.verify_ed25519(&padded, &sig, &[0u8; 32]),
+            Err(SignatureError::NonCanonical(CanonicalError::NonMinimalArgument))
+        );
+    }
+
+    /// xorshift64 step for the deterministic fuzz corpus
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn coin(state: &mut u64) -> bool {
+        next(state) & 1 == 0
+    }
+
+    fn fuzz_bytes(state: &mut u64, max_len: u64) -> Vec<u8> {
+        let len = next(state) % (max_len + 1);
+        (0..len).map(|_| next(state) as u8).collect()
+    }
+
+    fn fuzz_id<const N: usize>(state: &mut u64) -> [u8; N] {
+        let mut id = [0u8; N];
+        id.iter_mut().for_each(|b| *b = next(state) as u8);
+        id
+    }
+
+    fn fuzz_text(state: &mut u64) -> String {
+        // Mix of ASCII and multi-byte code points
+        let len = next(state) % 12;
+        (0..len)
+            .map(|_| ['a', 'Z', ' ', '7', 'é', 'Ω', '✓', '🧬'][(next(state) % 8) as usize])
+            .collect()
+    }
+
+    fn fuzz_txo(state: &mut u64) -> TXO {
+        let identities = [IdentityType::Operator, IdentityType::Node, IdentityType::System];
+        let sender = Sender {
+            identity_type: identities[(next(state) % 3) as usize],
+            id: fuzz_id(state),
+            biokey_present: coin(state),
+            fido2_signed: coin(state),
+            zk_proof: (coin(state)).then(|| fuzz_bytes(state, 300)),
+        };
+        let receiver = Receiver {
+            identity_type: identities[(next(state) % 3) as usize],
+            id: fuzz_id(state),
+        };
+        let payload = Payload {
+            payload_type: [PayloadType::Genome, PayloadType::Metadata, PayloadType::Control, PayloadType::Audit]
+                [(next(state) % 4) as usize],
+            content_hash: fuzz_id(state),
+            encrypted: coin(state),
+            envelope: None,
+        };
+        let operation = [
+            OperationClass::Genomic,
+            OperationClass::Network,
+            OperationClass::Compliance,
+            OperationClass::Admin,
+        ][(next(state) % 4) as usize];
+
+        let mut txo = TXO::new(fuzz_id(state), sender, receiver, operation, payload);
+        txo.version = next(state) as u32;
+        // Spread values across every integer width
+        txo.timestamp = next(state) >> (next(state) % 64);
+        txo.epoch_id = next(state) >> (next(state) % 64);
+        txo.container_hash = fuzz_id(state);
+        txo.reversibility_flag = coin(state);
+        txo.dual_control_required = coin(state);
+        for _ in 0..next(state) % 4 {
+            txo.add_signature(Signature {
+                sig_type: if coin(state) { SignatureType::Fido2 } else { SignatureType::Biokey },
+                signer_id: fuzz_id(state),
+                signature: fuzz_bytes(state, 64),
+                consent_ref: (coin(state)).then(|| fuzz_id(state)),
+            });
+        }
+        for _ in 0..next(state) % 3 {
+            txo.add_rollback_entry(RollbackEntry {
+                from_epoch: next(state) >> (next(state) % 64),
+                to_epoch: next(state) % 30,
+                reason: fuzz_text(state),
+            });
+        }
+        for _ in 0..next(state) % 4 {
+            txo.add_audit_entry(AuditEntry {
+                actor_id: fuzz_id(state),
+                action: fuzz_text(state),
+                timestamp: next(state) >> (next(state) % 64),
+            });
+        }
+        txo
+    }
+
+    #[test]
+    fn test_canonical_cbor_fuzz_roundtrip() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        for _ in 0..500 {
+            let txo = fuzz_txo(&mut state);
+            let bytes = txo.to_canonical_cbor();
+            assert_eq!(canonical::validate(&bytes), Ok(()));
+
+            // Every field survives, through both entry points
+            let decoded = TXO::from_canonical_cbor(&bytes).unwrap();
+            assert_eq!(decoded.to_cbor().unwrap(), txo.to_cbor().unwrap());
+            assert_eq!(decoded.to_canonical_cbor(), bytes);
+            assert_eq!(TXO::from_cbor(&bytes).unwrap().to_canonical_cbor(), bytes);
+
+            // The signing bytes are the same map without the mutable fields
+            assert_eq!(decoded.signing_bytes(), txo.signing_bytes());
+            assert_eq!(bytes[0], 0xae);
+        }
+    }
+
+    #[test]
+    fn test_canonical_cbor_fuzz_mutations() {
+        let mut state = 0x0123_4567_89ab_cdefu64;
+        for _ in 0..200 {
+            let bytes = fuzz_txo(&mut state).to_canonical_cbor();
+            for _ in 0..20 {
+                let mut mutated = bytes.clone();
+                let at = (next(&mut state) % mutated.len() as u64) as usize;
+                match next(&mut state) % 3 {
+                    0 => mutated[at] ^= 1 << (next(&mut state) % 8),
+                    1 => mutated.truncate(at),
+                    _ => mutated.insert(at, next(&mut state) as u8),
+                }
+
+                // Never panics; anything accepted is the unique encoding of its TXO
+                if let Ok(txo) = TXO::from_canonical_cbor(&mutated) {
+                    assert_eq!(txo.to_canonical_cbor(), mutated);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_canonical_cbor_rejects_schema_violations() {
+        let mut txo = signing_fixture();
+        txo.sign_ed25519(SignatureType::Fido2, [6u8; 16], &[7u8; 32]);
+        let bytes = txo.to_canonical_cbor();
+        assert_eq!(TXO::from_canonical_cbor(&bytes).unwrap().signatures.len(), 1);
+
+        // Signing bytes are canonical but lack fields 3, 11, 12 and 13
+        assert_eq!(TXO::from_canonical_cbor(&txo.signing_bytes()).err(), Some(CanonicalError::Schema));
+        assert!(TXO::from_cbor(&txo.signing_bytes()).is_err());
+
+        // Epoch (key 3, value 0) re-encoded with a one-byte argument
+        let at = bytes.windows(3).position(|w| w == [0x03, 0x00, 0x04]).unwrap();
+        let mut padded = bytes.clone();
+        padded.splice(at + 1..at + 2, [0x18, 0x00]);
+        assert_eq!(TXO::from_canonical_cbor(&padded).err(), Some(CanonicalError::NonMinimalArgument));
+        assert!(TXO::from_cbor(&padded).is_err());
+
+        // Derive encoding still round-trips through from_cbor
+        assert_eq!(TXO::from_cbor(&txo.to_cbor().unwrap()).unwrap().to_canonical_cbor(), bytes);
+    }
 }
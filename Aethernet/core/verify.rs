@@ -0,0 +1,375 @@
+//! Light-Client Outcome Verification
+//!
+//! Stateless verification of committed outcome TXOs for external relying
+//! parties. Covers exactly three checks and nothing that executes or mutates:
+//!
+//! 1. Signatures: Ed25519 over the canonical signing bytes, dual control honoured
+//! 2. Merkle inclusion: the TXO's ledger node chains forward to a trusted root
+//! 3. ZK attestation: biokey-backed senders carry a proof matching the trusted commitment
+//!
+//! Built with `default-features = false, features = ["light-verify"]` this
+//! module compiles without the RTF runtime, ledger, or compliance modules.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::hash::StreamingHasher;
+use crate::biokey::zkp_verify::{check_zkp_commitment, VerificationResult, ZKProof};
+use crate::txo::{SignatureError, TXO};
+
+/// Ledger node hash (shared with `MerkleLedger`)
+///
+/// # Arguments
+/// * `parent_hash` - Previous ledger root
+/// * `txo_hash` - `TXO::compute_hash()` at commit time
+/// * `epoch_id` - Epoch of the appended TXO
+/// * `zone` - Zone identifier (0=Z0 .. 3=Z3)
+/// * `timestamp` - TXO timestamp
+pub fn ledger_node_hash(
+    parent_hash: &[u8; 32],
+    txo_hash: &[u8; 32],
+    epoch_id: u64,
+    zone: u8,
+    timestamp: u64,
+) -> [u8; 32] {
+    ledger_node_hash_with(&mut StreamingHasher::new(), parent_hash, txo_hash, epoch_id, zone, timestamp)
+}
+
+/// `ledger_node_hash` on a reusable context
+pub fn ledger_node_hash_with(
+    hasher: &mut StreamingHasher,
+    parent_hash: &[u8; 32],
+    txo_hash: &[u8; 32],
+    epoch_id: u64,
+    zone: u8,
+    timestamp: u64,
+) -> [u8; 32] {
+    hasher.update(parent_hash);
+    hasher.update(txo_hash);
+    hasher.update(&epoch_id.to_le_bytes());
+    hasher.update(&[zone]);
+    hasher.update(&timestamp.to_le_bytes());
+    hasher.finalize_reset()
+}
+
+/// Ledger node appended after the outcome TXO
+#[derive(Debug, Clone)]
+pub struct ChainLink {
+    /// TXO hash of the later node
+    pub txo_hash: [u8; 32],
+    /// Epoch of the later node
+    pub epoch_id: u64,
+    /// Zone of the later node
+    pub zone: u8,
+    /// Timestamp of the later node
+    pub timestamp: u64,
+}
+
+/// Proof that a TXO was appended to the ledger
+#[derive(Debug, Clone)]
+pub struct InclusionProof {
+    /// Ledger root the TXO's node extended
+    pub parent_hash: [u8; 32],
+    /// Zone the TXO was committed in
+    pub zone: u8,
+    /// Audit entries present when the TXO was committed
+    ///
+    /// RTF appends its COMMIT entry after hashing, so entries past this
+    /// count are excluded when recomputing the TXO hash.
+    pub audit_len: usize,
+    /// Nodes appended after the TXO, oldest first
+    pub links: Vec<ChainLink>,
+}
+
+/// Everything a relying party needs besides the TXO itself
+#[derive(Debug, Clone)]
+pub struct ProofBundle {
+    /// Ed25519 public keys by signer UUID
+    pub signer_keys: Vec<([u8; 16], [u8; 32])>,
+    /// Ledger inclusion proof
+    pub inclusion: InclusionProof,
+    /// Biokey ZK attestation (required when the sender claims a biokey)
+    pub attestation: Option<ZKProof>,
+}
+
+/// Roots the relying party already trusts
+#[derive(Debug, Clone, Default)]
+pub struct TrustedRoots {
+    /// Accepted ledger roots (e.g. published checkpoints)
+    pub ledger_roots: Vec<[u8; 32]>,
+    /// Commitment biokey attestations must match
+    pub attestation_commitment: Option<[u8; 32]>,
+}
+
+/// Outcome verification failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyError {
+    /// TXO carries no signatures
+    MissingSignature,
+    /// No public key supplied for a signer
+    UnknownSigner([u8; 16]),
+    /// A signature failed verification
+    Signature(SignatureError),
+    /// Dual control required but fewer than two distinct signers verified
+    DualControlNotMet,
+    /// Inclusion proof does not reach a trusted root
+    NotIncluded,
+    /// Inclusion proof claims more audit entries than the TXO has
+    AuditLengthMismatch,
+    /// Sender claims a biokey but no attestation (or trusted commitment) was given
+    AttestationRequired,
+    /// Attestation did not verify
+    Attestation(VerificationResult),
+}
+
+/// Verify a committed outcome TXO
+///
+/// # Arguments
+/// * `txo` - Outcome TXO as received
+/// * `bundle` - Signer keys, inclusion proof, and optional attestation
+/// * `roots` - Trusted ledger roots and attestation commitment
+///
+/// # Returns
+/// * `Ok(())` if signatures, inclusion, and attestation all verify
+pub fn verify_outcome(txo: &TXO, bundle: &ProofBundle, roots: &TrustedRoots) -> Result<(), VerifyError> {
+    verify_signatures(txo, &bundle.signer_keys)?;
+    verify_inclusion(txo, &bundle.inclusion, &roots.ledger_roots)?;
+
+    if txo.sender.biokey_present {
+        let proof = bundle.attestation.as_ref().ok_or(VerifyError::AttestationRequired)?;
+        let commitment = roots.attestation_commitment.ok_or(VerifyError::AttestationRequired)?;
+        match check_zkp_commitment(proof, &commitment) {
+            VerificationResult::Valid => {}
+            other => return Err(VerifyError::Attestation(other)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Verify every signature over the canonical signing bytes
+fn verify_signatures(txo: &TXO, keys: &[([u8; 16], [u8; 32])]) -> Result<(), VerifyError> {
+    if txo.signatures.is_empty() {
+        return Err(VerifyError::MissingSignature);
+    }
+
+    let signed = txo.signing_bytes();
+    let mut signers: Vec<[u8; 16]> = Vec::new();
+
+    for sig in &txo.signatures {
+        let key = keys
+            .iter()
+            .find(|(id, _)| *id == sig.signer_id)
+            .map(|(_, key)| key)
+            .ok_or(VerifyError::UnknownSigner(sig.signer_id))?;
+        txo.verify_ed25519(&signed, sig, key).map_err(VerifyError::Signature)?;
+        if !signers.contains(&sig.signer_id) {
+            signers.push(sig.signer_id);
+        }
+    }
+
+    if txo.dual_control_required && signers.len() < 2 {
+        return Err(VerifyError::DualControlNotMet);
+    }
+    Ok(())
+}
+
+/// Recompute the TXO's ledger node and chain it forward to a trusted root
+fn verify_inclusion(txo: &TXO, proof: &InclusionProof, trusted: &[[u8; 32]]) -> Result<(), VerifyError> {
+    if proof.audit_len > txo.audit_trail.len() {
+        return Err(VerifyError::AuditLengthMismatch);
+    }
+
+    let txo_hash = if proof.audit_len == txo.audit_trail.len() {
+        txo.compute_hash()
+    } else {
+        let mut committed = txo.clone();
+        committed.audit_trail.truncate(proof.audit_len);
+        committed.compute_hash()
+    };
+
+    let mut root = ledger_node_hash(&proof.parent_hash, &txo_hash, txo.epoch_id, proof.zone, txo.timestamp);
+    for link in &proof.links {
+        root = ledger_node_hash(&root, &link.txo_hash, link.epoch_id, link.zone, link.timestamp);
+    }
+
+    if trusted.contains(&root) {
+        Ok(())
+    } else {
+        Err(VerifyError::NotIncluded)
+    }
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod tests {
+    use super::*;
+    use crate::ledger::MerkleLedger;
+    use crate::rtf::api::{RTFContext, Zone};
+    use sha3::{Digest, Sha3_256};
+    use crate::txo::{
+        IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, SignatureType,
+    };
+    use ed25519_dalek::SigningKey;
+
+    const SECRET_A: [u8; 32] = [11u8; 32];
+    const SECRET_B: [u8; 32] = [12u8; 32];
+
+    fn public(secret: &[u8; 32]) -> [u8; 32] {
+        SigningKey::from_bytes(secret).verifying_key().to_bytes()
+    }
+
+    fn make_txo(id: u8) -> TXO {
+        let sender = Sender {
+            identity_type: IdentityType::Operator,
+            id: [1u8; 16],
+            biokey_present: false,
+            fido2_signed: true,
+            zk_proof: None,
+        };
+        let receiver = Receiver {
+            identity_type: IdentityType::Node,
+            id: [2u8; 16],
+        };
+        let payload = Payload {
+            payload_type: PayloadType::Metadata,
+            content_hash: [id; 32],
+            encrypted: false,
+            envelope: None,
+        };
+        let mut txo = TXO::new([id; 16], sender, receiver, OperationClass::Network, payload);
+        txo.sign_ed25519(SignatureType::Fido2, [3u8; 16], &SECRET_A);
+        txo
+    }
+
+    /// Commit `outcome` followed by one more TXO, returning the proof inputs
+    fn commit_with_successor(outcome: &mut TXO) -> (InclusionProof, [u8; 32]) {
+        let mut ctx = RTFContext::new(Zone::Z1, MerkleLedger::new([0u8; 32]));
+
+        ctx.execute_txo(outcome).unwrap();
+        let parent_hash = ctx.ledger.get_current_root();
+        let audit_len = outcome.audit_trail.len();
+        ctx.commit_txo(outcome).unwrap();
+
+        let mut next = make_txo(9);
+        ctx.execute_txo(&mut next).unwrap();
+        let next_hash = next.compute_hash();
+        ctx.commit_txo(&mut next).unwrap();
+
+        let proof = InclusionProof {
+            parent_hash,
+            zone: 1,
+            audit_len,
+            links: vec![ChainLink {
+                txo_hash: next_hash,
+                epoch_id: next.epoch_id,
+                zone: 1,
+                timestamp: next.timestamp,
+            }],
+        };
+        (proof, ctx.ledger.get_current_root())
+    }
+
+    #[test]
+    fn test_verify_outcome() {
+        let mut txo = make_txo(1);
+        let (inclusion, root) = commit_with_successor(&mut txo);
+
+        let bundle = ProofBundle {
+            signer_keys: vec![([3u8; 16], public(&SECRET_A))],
+            inclusion,
+            attestation: None,
+        };
+        let roots = TrustedRoots {
+            ledger_roots: vec![root],
+            attestation_commitment: None,
+        };
+
+        assert_eq!(verify_outcome(&txo, &bundle, &roots), Ok(()));
+
+        // Untrusted root
+        let untrusted = TrustedRoots::default();
+        assert_eq!(verify_outcome(&txo, &bundle, &untrusted), Err(VerifyError::NotIncluded));
+
+        // Tampered payload breaks the signature before inclusion is checked
+        let mut tampered = txo.clone();
+        tampered.payload.encrypted = true;
+        assert!(matches!(
+            verify_outcome(&tampered, &bundle, &roots),
+            Err(VerifyError::Signature(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_signers_and_dual_control() {
+        let mut txo = make_txo(1);
+        txo.dual_control_required = true;
+        txo.signatures.clear();
+        txo.sign_ed25519(SignatureType::Fido2, [3u8; 16], &SECRET_A);
+        txo.sign_ed25519(SignatureType::Fido2, [4u8; 16], &SECRET_B);
+        let (inclusion, root) = commit_with_successor(&mut txo);
+        let roots = TrustedRoots {
+            ledger_roots: vec![root],
+            attestation_commitment: None,
+        };
+
+        let mut bundle = ProofBundle {
+            signer_keys: vec![([3u8; 16], public(&SECRET_A))],
+            inclusion,
+            attestation: None,
+        };
+        assert_eq!(verify_outcome(&txo, &bundle, &roots), Err(VerifyError::UnknownSigner([4u8; 16])));
+
+        bundle.signer_keys.push(([4u8; 16], public(&SECRET_B)));
+        assert_eq!(verify_outcome(&txo, &bundle, &roots), Ok(()));
+
+        // Two signatures from the same signer do not satisfy dual control
+        let mut single = make_txo(2);
+        single.dual_control_required = true;
+        single.signatures.clear();
+        single.sign_ed25519(SignatureType::Fido2, [3u8; 16], &SECRET_A);
+        single.sign_ed25519(SignatureType::Biokey, [3u8; 16], &SECRET_A);
+        let (inclusion, root) = commit_with_successor(&mut single);
+        bundle.inclusion = inclusion;
+        let roots = TrustedRoots {
+            ledger_roots: vec![root],
+            attestation_commitment: None,
+        };
+        assert_eq!(verify_outcome(&single, &bundle, &roots), Err(VerifyError::DualControlNotMet));
+    }
+
+    #[test]
+    fn test_verify_attestation_required() {
+        let mut txo = make_txo(1);
+        txo.sender.biokey_present = true;
+        txo.signatures.clear();
+        txo.sign_ed25519(SignatureType::Biokey, [3u8; 16], &SECRET_A);
+        let (inclusion, root) = commit_with_successor(&mut txo);
+
+        let public_inputs = vec![5u8, 6, 7, 8];
+        let mut hasher = Sha3_256::new();
+        hasher.update(&public_inputs);
+        let mut commitment = [0u8; 32];
+        commitment.copy_from_slice(&hasher.finalize());
+
+        let mut bundle = ProofBundle {
+            signer_keys: vec![([3u8; 16], public(&SECRET_A))],
+            inclusion,
+            attestation: None,
+        };
+        let roots = TrustedRoots {
+            ledger_roots: vec![root],
+            attestation_commitment: Some(commitment),
+        };
+        assert_eq!(verify_outcome(&txo, &bundle, &roots), Err(VerifyError::AttestationRequired));
+
+        bundle.attestation = Some(ZKProof::new(vec![1, 2, 3], vec![9, 9], 0, [0u8; 32], 0));
+        assert_eq!(
+            verify_outcome(&txo, &bundle, &roots),
+            Err(VerifyError::Attestation(VerificationResult::Invalid))
+        );
+
+        bundle.attestation = Some(ZKProof::new(vec![1, 2, 3], public_inputs, 0, [0u8; 32], 0));
+        assert_eq!(verify_outcome(&txo, &bundle, &roots), Ok(()));
+    }
+}
@@ -6,7 +6,8 @@
 //! Dilithium is a lattice-based signature scheme providing strong EUF-CMA
 //! security against quantum adversaries.
 
-use sha3::{Digest, Shake256};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
 use std::error::Error;
 use std::fmt;
 
@@ -0,0 +1,966 @@
+//! ML-DSA (FIPS 204) Module-Lattice-Based Digital Signatures
+//!
+//! The standardized form of CRYSTALS-Dilithium. Implements ML-DSA-44,
+//! ML-DSA-65 and ML-DSA-87 with the FIPS 204 key and signature encodings
+//! (pkEncode, skEncode, sigEncode), pure ML-DSA context strings, and hedged
+//! or deterministic signing.
+//!
+//! Keys generated by round-3.1 Dilithium implementations differ from FIPS
+//! 204 only in the length of `tr` (32 vs 64 bytes) and can be migrated with
+//! [`migrate_round3_secret_key`]. Keys produced by the pre-FIPS placeholder
+//! in [`crate::crystals_dilithium`] are not lattice keys and are rejected by
+//! the migration; they must be regenerated.
+//!
+//! Tested against interop vectors from independent implementations
+//! (`testdata/`), not against NIST ACVP vector files.
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Shake128, Shake256};
+use std::error::Error;
+use std::fmt;
+
+/// Modulus q
+pub const ML_DSA_Q: i32 = 8380417;
+
+/// Polynomial degree n
+const N: usize = 256;
+
+/// Dropped bits of t (d)
+const D: u32 = 13;
+
+/// 256^-1 mod q
+const N_INV: i64 = 8347681;
+
+/// Zeta^brv8(k) mod q for the NTT (zeta = 1753)
+const ZETAS: [i32; N] = compute_zetas();
+
+const fn compute_zetas() -> [i32; N] {
+    let mut zetas = [0i32; N];
+    let mut k = 0;
+    while k < N {
+        let mut exponent = (k as u8).reverse_bits() as u32;
+        let mut base = 1753i64;
+        let mut acc = 1i64;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                acc = acc * base % ML_DSA_Q as i64;
+            }
+            base = base * base % ML_DSA_Q as i64;
+            exponent >>= 1;
+        }
+        zetas[k] = acc as i32;
+        k += 1;
+    }
+    zetas
+}
+
+type Poly = [i32; N];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MlDsaError {
+    InvalidKeySize,
+    InvalidSignatureSize,
+    ContextTooLong,
+    KeyGenerationFailed,
+    SigningFailed,
+    InvalidLegacyKey,
+}
+
+impl fmt::Display for MlDsaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MlDsaError::InvalidKeySize => write!(f, "Invalid key size"),
+            MlDsaError::InvalidSignatureSize => write!(f, "Invalid signature size"),
+            MlDsaError::ContextTooLong => write!(f, "Context string longer than 255 bytes"),
+            MlDsaError::KeyGenerationFailed => write!(f, "Key generation failed"),
+            MlDsaError::SigningFailed => write!(f, "Signing failed"),
+            MlDsaError::InvalidLegacyKey => write!(f, "Legacy key is not a valid Dilithium key"),
+        }
+    }
+}
+
+impl Error for MlDsaError {}
+
+/// FIPS 204 parameter sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterSet {
+    /// NIST security category 2
+    MlDsa44,
+    /// NIST security category 3
+    MlDsa65,
+    /// NIST security category 5 (successor of Dilithium5)
+    MlDsa87,
+}
+
+/// FIPS 204 Table 1 parameters
+struct Params {
+    k: usize,
+    l: usize,
+    eta: i32,
+    tau: usize,
+    lambda: usize,
+    gamma1: i32,
+    gamma2: i32,
+    beta: i32,
+    omega: usize,
+}
+
+impl ParameterSet {
+    fn params(self) -> Params {
+        match self {
+            ParameterSet::MlDsa44 => Params {
+                k: 4, l: 4, eta: 2, tau: 39, lambda: 128,
+                gamma1: 1 << 17, gamma2: (ML_DSA_Q - 1) / 88, beta: 78, omega: 80,
+            },
+            ParameterSet::MlDsa65 => Params {
+                k: 6, l: 5, eta: 4, tau: 49, lambda: 192,
+                gamma1: 1 << 19, gamma2: (ML_DSA_Q - 1) / 32, beta: 196, omega: 55,
+            },
+            ParameterSet::MlDsa87 => Params {
+                k: 8, l: 7, eta: 2, tau: 60, lambda: 256,
+                gamma1: 1 << 19, gamma2: (ML_DSA_Q - 1) / 32, beta: 120, omega: 75,
+            },
+        }
+    }
+
+    /// Name as written in FIPS 204
+    pub fn name(self) -> &'static str {
+        match self {
+            ParameterSet::MlDsa44 => "ML-DSA-44",
+            ParameterSet::MlDsa65 => "ML-DSA-65",
+            ParameterSet::MlDsa87 => "ML-DSA-87",
+        }
+    }
+
+    /// NIST CSOR object identifier
+    pub fn oid(self) -> &'static str {
+        match self {
+            ParameterSet::MlDsa44 => "2.16.840.1.101.3.4.3.17",
+            ParameterSet::MlDsa65 => "2.16.840.1.101.3.4.3.18",
+            ParameterSet::MlDsa87 => "2.16.840.1.101.3.4.3.19",
+        }
+    }
+
+    /// Encoded public key length
+    pub fn public_key_size(self) -> usize {
+        32 + 320 * self.params().k
+    }
+
+    /// Encoded secret key length
+    pub fn secret_key_size(self) -> usize {
+        let p = self.params();
+        128 + 32 * ((p.k + p.l) * bitlen(2 * p.eta) + D as usize * p.k)
+    }
+
+    /// Encoded signature length
+    pub fn signature_size(self) -> usize {
+        let p = self.params();
+        p.lambda / 4 + 32 * p.l * (1 + bitlen(p.gamma1 - 1)) + p.omega + p.k
+    }
+}
+
+/// ML-DSA public key (pkEncode)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    pub parameter_set: ParameterSet,
+    pub data: Vec<u8>,
+}
+
+/// ML-DSA secret key (skEncode)
+#[derive(Clone, Debug)]
+pub struct SecretKey {
+    pub parameter_set: ParameterSet,
+    pub data: Vec<u8>,
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.data.iter_mut().for_each(|b| *b = 0);
+    }
+}
+
+/// ML-DSA signature (sigEncode)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub parameter_set: ParameterSet,
+    pub data: Vec<u8>,
+}
+
+impl PublicKey {
+    /// Wrap an encoded public key, checking its length
+    pub fn from_bytes(parameter_set: ParameterSet, bytes: &[u8]) -> Result<Self, MlDsaError> {
+        if bytes.len() != parameter_set.public_key_size() {
+            return Err(MlDsaError::InvalidKeySize);
+        }
+        Ok(Self { parameter_set, data: bytes.to_vec() })
+    }
+}
+
+impl SecretKey {
+    /// Wrap an encoded secret key, checking its length
+    pub fn from_bytes(parameter_set: ParameterSet, bytes: &[u8]) -> Result<Self, MlDsaError> {
+        if bytes.len() != parameter_set.secret_key_size() {
+            return Err(MlDsaError::InvalidKeySize);
+        }
+        Ok(Self { parameter_set, data: bytes.to_vec() })
+    }
+}
+
+impl Signature {
+    /// Wrap an encoded signature, checking its length
+    pub fn from_bytes(parameter_set: ParameterSet, bytes: &[u8]) -> Result<Self, MlDsaError> {
+        if bytes.len() != parameter_set.signature_size() {
+            return Err(MlDsaError::InvalidSignatureSize);
+        }
+        Ok(Self { parameter_set, data: bytes.to_vec() })
+    }
+}
+
+/// Generate an ML-DSA keypair from OS randomness
+pub fn generate_keypair(parameter_set: ParameterSet) -> Result<(PublicKey, SecretKey), MlDsaError> {
+    let mut seed = [0u8; 32];
+    getrandom::getrandom(&mut seed).map_err(|_| MlDsaError::KeyGenerationFailed)?;
+    let keypair = keygen_from_seed(parameter_set, &seed);
+    seed.iter_mut().for_each(|b| *b = 0);
+    Ok(keypair)
+}
+
+/// Derive an ML-DSA keypair from a 32-byte seed (ML-DSA.KeyGen_internal)
+pub fn keygen_from_seed(parameter_set: ParameterSet, seed: &[u8; 32]) -> (PublicKey, SecretKey) {
+    let p = parameter_set.params();
+    let mut seeds = [0u8; 128];
+    shake256(&[seed, &[p.k as u8, p.l as u8]], &mut seeds);
+    let (rho, rest) = seeds.split_at(32);
+    let (rho_prime, key) = rest.split_at(64);
+
+    let a_hat = expand_a(rho, &p);
+    let (s1, s2) = expand_s(rho_prime, &p);
+    let t = compute_t(&a_hat, &s1, &s2);
+    let (t1, t0): (Vec<Poly>, Vec<Poly>) = t.iter().map(power2round_poly).unzip();
+
+    let pk = pk_encode(rho, &t1);
+    let mut tr = [0u8; 64];
+    shake256(&[&pk], &mut tr);
+    let sk = sk_encode(rho, key, &tr, &s1, &s2, &t0, &p);
+    seeds.iter_mut().for_each(|b| *b = 0);
+
+    (
+        PublicKey { parameter_set, data: pk },
+        SecretKey { parameter_set, data: sk },
+    )
+}
+
+/// Sign with hedged randomness (ML-DSA.Sign)
+///
+/// `context` is the FIPS 204 context string (at most 255 bytes).
+pub fn sign(message: &[u8], context: &[u8], secret_key: &SecretKey) -> Result<Signature, MlDsaError> {
+    let mut rnd = [0u8; 32];
+    getrandom::getrandom(&mut rnd).map_err(|_| MlDsaError::SigningFailed)?;
+    sign_with_randomness(message, context, secret_key, &rnd)
+}
+
+/// Sign deterministically (rnd = 0^32)
+pub fn sign_deterministic(message: &[u8], context: &[u8], secret_key: &SecretKey) -> Result<Signature, MlDsaError> {
+    sign_with_randomness(message, context, secret_key, &[0u8; 32])
+}
+
+fn sign_with_randomness(
+    message: &[u8],
+    context: &[u8],
+    secret_key: &SecretKey,
+    rnd: &[u8; 32],
+) -> Result<Signature, MlDsaError> {
+    let parameter_set = secret_key.parameter_set;
+    if secret_key.data.len() != parameter_set.secret_key_size() {
+        return Err(MlDsaError::InvalidKeySize);
+    }
+    let message_prime = format_message(message, context)?;
+    let data = sign_internal(&secret_key.data, &message_prime, rnd, &parameter_set.params());
+    Ok(Signature { parameter_set, data })
+}
+
+/// Verify a signature (ML-DSA.Verify)
+pub fn verify(
+    message: &[u8],
+    context: &[u8],
+    signature: &Signature,
+    public_key: &PublicKey,
+) -> Result<bool, MlDsaError> {
+    let parameter_set = public_key.parameter_set;
+    if public_key.data.len() != parameter_set.public_key_size() {
+        return Err(MlDsaError::InvalidKeySize);
+    }
+    if signature.parameter_set != parameter_set || signature.data.len() != parameter_set.signature_size() {
+        return Err(MlDsaError::InvalidSignatureSize);
+    }
+    let message_prime = format_message(message, context)?;
+    Ok(verify_internal(&public_key.data, &message_prime, &signature.data, &parameter_set.params()))
+}
+
+/// Convert a round-3.1 Dilithium secret key to the FIPS 204 encoding
+///
+/// Round 3.1 stored `tr = SHAKE256(pk)` truncated to 32 bytes; FIPS 204
+/// stores 64 bytes. The legacy key is checked against `public_key` (same
+/// rho, matching `tr` prefix, in-range s1/s2, and t = A*s1 + s2 reproducing
+/// t1 and t0) before the longer `tr` is substituted.
+///
+/// # Errors
+/// * `InvalidKeySize` if `legacy` is not a round-3.1 key for the parameter set
+/// * `InvalidLegacyKey` if the key does not belong to `public_key`, which
+///   includes every key from the pre-FIPS placeholder implementation
+pub fn migrate_round3_secret_key(public_key: &PublicKey, legacy: &[u8]) -> Result<SecretKey, MlDsaError> {
+    let parameter_set = public_key.parameter_set;
+    let p = parameter_set.params();
+    if legacy.len() != parameter_set.secret_key_size() - 32
+        || public_key.data.len() != parameter_set.public_key_size()
+    {
+        return Err(MlDsaError::InvalidKeySize);
+    }
+
+    let mut tr = [0u8; 64];
+    shake256(&[&public_key.data], &mut tr);
+    if legacy[..32] != public_key.data[..32] || legacy[64..96] != tr[..32] {
+        return Err(MlDsaError::InvalidLegacyKey);
+    }
+
+    let mut data = Vec::with_capacity(parameter_set.secret_key_size());
+    data.extend_from_slice(&legacy[..64]);
+    data.extend_from_slice(&tr);
+    data.extend_from_slice(&legacy[96..]);
+
+    let (rho, _, _, s1, s2, t0) = sk_decode(&data, &p).ok_or(MlDsaError::InvalidLegacyKey)?;
+    let (_, t1_expected) = pk_decode(&public_key.data, &p);
+    let t = compute_t(&expand_a(&rho, &p), &s1, &s2);
+    let consistent = t
+        .iter()
+        .map(power2round_poly)
+        .zip(t1_expected.iter().zip(&t0))
+        .all(|((t1, t0), (t1_expected, t0_expected))| &t1 == t1_expected && &t0 == t0_expected);
+    if !consistent {
+        data.iter_mut().for_each(|b| *b = 0);
+        return Err(MlDsaError::InvalidLegacyKey);
+    }
+    Ok(SecretKey { parameter_set, data })
+}
+
+/// M' = 0 || |ctx| || ctx || M (pure ML-DSA)
+fn format_message(message: &[u8], context: &[u8]) -> Result<Vec<u8>, MlDsaError> {
+    if context.len() > 255 {
+        return Err(MlDsaError::ContextTooLong);
+    }
+    let mut message_prime = Vec::with_capacity(2 + context.len() + message.len());
+    message_prime.push(0);
+    message_prime.push(context.len() as u8);
+    message_prime.extend_from_slice(context);
+    message_prime.extend_from_slice(message);
+    Ok(message_prime)
+}
+
+/// ML-DSA.Sign_internal (FIPS 204 Algorithm 7)
+fn sign_internal(sk: &[u8], message_prime: &[u8], rnd: &[u8; 32], p: &Params) -> Vec<u8> {
+    let (rho, key, tr, s1, s2, t0) = sk_decode(sk, p).expect("secret key length checked by caller");
+    let s1_hat: Vec<Poly> = s1.iter().map(ntt).collect();
+    let s2_hat: Vec<Poly> = s2.iter().map(ntt).collect();
+    let t0_hat: Vec<Poly> = t0.iter().map(ntt).collect();
+    let a_hat = expand_a(&rho, p);
+
+    let mut mu = [0u8; 64];
+    shake256(&[&tr, message_prime], &mut mu);
+    let mut rho_pp = [0u8; 64];
+    shake256(&[&key, rnd, &mu], &mut rho_pp);
+
+    let m = ((ML_DSA_Q - 1) / (2 * p.gamma2)) as usize;
+    let mut kappa = 0u16;
+    loop {
+        let y = expand_mask(&rho_pp, kappa, p);
+        kappa += p.l as u16;
+        let y_hat: Vec<Poly> = y.iter().map(ntt).collect();
+        let w: Vec<Poly> = a_hat.iter().map(|row| inv_ntt(&dot(row, &y_hat))).collect();
+        let w1: Vec<Vec<u32>> = w
+            .iter()
+            .map(|poly| poly.iter().map(|&r| decompose(r, p.gamma2).0 as u32).collect())
+            .collect();
+
+        let mut c_tilde = vec![0u8; p.lambda / 4];
+        shake256(&[&mu, &w1_encode(&w1, m)], &mut c_tilde);
+        let c_hat = ntt(&sample_in_ball(&c_tilde, p.tau));
+
+        let cs1: Vec<Poly> = s1_hat.iter().map(|s| inv_ntt(&pointwise(&c_hat, s))).collect();
+        let cs2: Vec<Poly> = s2_hat.iter().map(|s| inv_ntt(&pointwise(&c_hat, s))).collect();
+        let z: Vec<Poly> = y.iter().zip(&cs1).map(|(y, cs)| add(y, cs)).collect();
+        let w_minus_cs2: Vec<Poly> = w.iter().zip(&cs2).map(|(w, cs)| sub(w, cs)).collect();
+
+        if norm(&z) >= p.gamma1 - p.beta
+            || w_minus_cs2
+                .iter()
+                .flat_map(|poly| poly.iter())
+                .any(|&r| decompose(r, p.gamma2).1.abs() >= p.gamma2 - p.beta)
+        {
+            continue;
+        }
+
+        let ct0: Vec<Poly> = t0_hat.iter().map(|t| inv_ntt(&pointwise(&c_hat, t))).collect();
+        if norm(&ct0) >= p.gamma2 {
+            continue;
+        }
+        // h = MakeHint(-ct0, w - cs2 + ct0)
+        let hints: Vec<Vec<bool>> = w_minus_cs2
+            .iter()
+            .zip(&ct0)
+            .map(|(r, ct)| {
+                (0..N)
+                    .map(|i| {
+                        let with_ct0 = reduce(r[i] as i64 + ct[i] as i64);
+                        decompose(with_ct0, p.gamma2).0 != decompose(r[i], p.gamma2).0
+                    })
+                    .collect()
+            })
+            .collect();
+        if hints.iter().flatten().filter(|&&h| h).count() > p.omega {
+            continue;
+        }
+        return sig_encode(&c_tilde, &z, &hints, p);
+    }
+}
+
+/// ML-DSA.Verify_internal (FIPS 204 Algorithm 8)
+fn verify_internal(pk: &[u8], message_prime: &[u8], sig: &[u8], p: &Params) -> bool {
+    let (rho, t1) = pk_decode(pk, p);
+    let Some((c_tilde, z, hints)) = sig_decode(sig, p) else {
+        return false;
+    };
+    if norm(&z) >= p.gamma1 - p.beta {
+        return false;
+    }
+
+    let a_hat = expand_a(&rho, p);
+    let mut tr = [0u8; 64];
+    shake256(&[pk], &mut tr);
+    let mut mu = [0u8; 64];
+    shake256(&[&tr, message_prime], &mut mu);
+
+    let c_hat = ntt(&sample_in_ball(c_tilde, p.tau));
+    let z_hat: Vec<Poly> = z.iter().map(ntt).collect();
+    let m = (ML_DSA_Q - 1) / (2 * p.gamma2);
+    let w1: Vec<Vec<u32>> = a_hat
+        .iter()
+        .zip(&t1)
+        .zip(&hints)
+        .map(|((row, t1), hint)| {
+            let t1_hat = ntt(&t1.map(|c| c << D));
+            let w_approx = inv_ntt(&sub(&dot(row, &z_hat), &pointwise(&c_hat, &t1_hat)));
+            (0..N).map(|i| use_hint(hint[i], w_approx[i], p.gamma2, m) as u32).collect()
+        })
+        .collect();
+
+    let mut c_tilde_prime = vec![0u8; p.lambda / 4];
+    shake256(&[&mu, &w1_encode(&w1, m as usize)], &mut c_tilde_prime);
+    c_tilde == c_tilde_prime.as_slice()
+}
+
+fn shake256(parts: &[&[u8]], out: &mut [u8]) {
+    let mut hasher = Shake256::default();
+    parts.iter().for_each(|part| hasher.update(part));
+    hasher.finalize_xof().read(out);
+}
+
+fn reduce(a: i64) -> i32 {
+    a.rem_euclid(ML_DSA_Q as i64) as i32
+}
+
+/// r mod± alpha, in (-alpha/2, alpha/2]
+fn centered(r: i32, alpha: i32) -> i32 {
+    let r0 = r.rem_euclid(alpha);
+    if r0 > alpha / 2 { r0 - alpha } else { r0 }
+}
+
+fn bitlen(x: i32) -> usize {
+    (32 - x.leading_zeros()) as usize
+}
+
+/// Infinity norm of a vector with coefficients in [0, q)
+fn norm(v: &[Poly]) -> i32 {
+    v.iter().flat_map(|poly| poly.iter()).map(|&c| centered(c, ML_DSA_Q).abs()).max().unwrap_or(0)
+}
+
+fn add(a: &Poly, b: &Poly) -> Poly {
+    core::array::from_fn(|i| reduce(a[i] as i64 + b[i] as i64))
+}
+
+fn sub(a: &Poly, b: &Poly) -> Poly {
+    core::array::from_fn(|i| reduce(a[i] as i64 - b[i] as i64))
+}
+
+fn pointwise(a: &Poly, b: &Poly) -> Poly {
+    core::array::from_fn(|i| reduce(a[i] as i64 * b[i] as i64))
+}
+
+/// Inner product of two NTT-domain vectors
+fn dot(a: &[Poly], b: &[Poly]) -> Poly {
+    a.iter().zip(b).fold([0; N], |acc, (x, y)| add(&acc, &pointwise(x, y)))
+}
+
+/// FIPS 204 Algorithm 41
+fn ntt(poly: &Poly) -> Poly {
+    let mut w = *poly;
+    let mut m = 0;
+    let mut len = 128;
+    while len >= 1 {
+        let mut start = 0;
+        while start < N {
+            m += 1;
+            let zeta = ZETAS[m] as i64;
+            for j in start..start + len {
+                let t = zeta * w[j + len] as i64;
+                w[j + len] = reduce(w[j] as i64 - t);
+                w[j] = reduce(w[j] as i64 + t);
+            }
+            start += 2 * len;
+        }
+        len /= 2;
+    }
+    w
+}
+
+/// FIPS 204 Algorithm 42
+fn inv_ntt(poly: &Poly) -> Poly {
+    let mut w = *poly;
+    let mut m = N;
+    let mut len = 1;
+    while len < N {
+        let mut start = 0;
+        while start < N {
+            m -= 1;
+            let zeta = -(ZETAS[m] as i64);
+            for j in start..start + len {
+                let t = w[j];
+                w[j] = reduce(t as i64 + w[j + len] as i64);
+                w[j + len] = reduce(zeta * (t as i64 - w[j + len] as i64));
+            }
+            start += 2 * len;
+        }
+        len *= 2;
+    }
+    w.map(|c| reduce(c as i64 * N_INV))
+}
+
+/// t = NTT^-1(A * NTT(s1)) + s2
+fn compute_t(a_hat: &[Vec<Poly>], s1: &[Poly], s2: &[Poly]) -> Vec<Poly> {
+    let s1_hat: Vec<Poly> = s1.iter().map(ntt).collect();
+    a_hat.iter().zip(s2).map(|(row, s2)| add(&inv_ntt(&dot(row, &s1_hat)), s2)).collect()
+}
+
+fn power2round_poly(t: &Poly) -> (Poly, Poly) {
+    let mut t1 = [0; N];
+    let mut t0 = [0; N];
+    for i in 0..N {
+        let r0 = centered(t[i], 1 << D);
+        t1[i] = (t[i] - r0) >> D;
+        t0[i] = reduce(r0 as i64);
+    }
+    (t1, t0)
+}
+
+/// (r1, r0) with r = r1 * 2*gamma2 + r0 (FIPS 204 Algorithm 36)
+fn decompose(r: i32, gamma2: i32) -> (i32, i32) {
+    let r0 = centered(r, 2 * gamma2);
+    if r - r0 == ML_DSA_Q - 1 {
+        (0, r0 - 1)
+    } else {
+        ((r - r0) / (2 * gamma2), r0)
+    }
+}
+
+fn use_hint(hint: bool, r: i32, gamma2: i32, m: i32) -> i32 {
+    let (r1, r0) = decompose(r, gamma2);
+    match (hint, r0 > 0) {
+        (false, _) => r1,
+        (true, true) => (r1 + 1).rem_euclid(m),
+        (true, false) => (r1 - 1).rem_euclid(m),
+    }
+}
+
+/// ExpandA: A-hat in NTT domain, k x l
+fn expand_a(rho: &[u8], p: &Params) -> Vec<Vec<Poly>> {
+    (0..p.k)
+        .map(|r| (0..p.l).map(|s| rej_ntt_poly(rho, [s as u8, r as u8])).collect())
+        .collect()
+}
+
+fn rej_ntt_poly(rho: &[u8], nonce: [u8; 2]) -> Poly {
+    let mut hasher = Shake128::default();
+    hasher.update(rho);
+    hasher.update(&nonce);
+    let mut reader = hasher.finalize_xof();
+    let mut a = [0; N];
+    let mut j = 0;
+    let mut bytes = [0u8; 3];
+    while j < N {
+        reader.read(&mut bytes);
+        let z = bytes[0] as i32 | (bytes[1] as i32) << 8 | ((bytes[2] & 0x7f) as i32) << 16;
+        if z < ML_DSA_Q {
+            a[j] = z;
+            j += 1;
+        }
+    }
+    a
+}
+
+/// ExpandS: s1 (l polys) and s2 (k polys) with coefficients in [-eta, eta]
+fn expand_s(rho_prime: &[u8], p: &Params) -> (Vec<Poly>, Vec<Poly>) {
+    let s1 = (0..p.l).map(|r| rej_bounded_poly(rho_prime, r as u16, p.eta)).collect();
+    let s2 = (0..p.k).map(|r| rej_bounded_poly(rho_prime, (r + p.l) as u16, p.eta)).collect();
+    (s1, s2)
+}
+
+fn rej_bounded_poly(rho_prime: &[u8], nonce: u16, eta: i32) -> Poly {
+    let coefficient = |b: u8| -> Option<i32> {
+        match eta {
+            2 if b < 15 => Some(2 - (b % 5) as i32),
+            4 if b < 9 => Some(4 - b as i32),
+            _ => None,
+        }
+    };
+    let mut hasher = Shake256::default();
+    hasher.update(rho_prime);
+    hasher.update(&nonce.to_le_bytes());
+    let mut reader = hasher.finalize_xof();
+    let mut a = [0; N];
+    let mut j = 0;
+    let mut byte = [0u8; 1];
+    while j < N {
+        reader.read(&mut byte);
+        for half in [byte[0] & 0x0f, byte[0] >> 4] {
+            if let Some(c) = coefficient(half) {
+                if j < N {
+                    a[j] = reduce(c as i64);
+                    j += 1;
+                }
+            }
+        }
+    }
+    a
+}
+
+/// ExpandMask: y with coefficients in (-gamma1, gamma1]
+fn expand_mask(rho_pp: &[u8; 64], kappa: u16, p: &Params) -> Vec<Poly> {
+    let bits = 1 + bitlen(p.gamma1 - 1);
+    (0..p.l)
+        .map(|r| {
+            let mut v = vec![0u8; 32 * bits];
+            shake256(&[rho_pp, &(kappa + r as u16).to_le_bytes()], &mut v);
+            let values = unpack(&v, bits);
+            core::array::from_fn(|i| reduce((p.gamma1 - values[i] as i32) as i64))
+        })
+        .collect()
+}
+
+/// SampleInBall: c with tau coefficients in {-1, 1}
+fn sample_in_ball(c_tilde: &[u8], tau: usize) -> Poly {
+    let mut hasher = Shake256::default();
+    hasher.update(c_tilde);
+    let mut reader = hasher.finalize_xof();
+    let mut sign_bytes = [0u8; 8];
+    reader.read(&mut sign_bytes);
+    let signs = u64::from_le_bytes(sign_bytes);
+
+    let mut c = [0; N];
+    let mut j = [0u8; 1];
+    for i in N - tau..N {
+        loop {
+            reader.read(&mut j);
+            if (j[0] as usize) <= i {
+                break;
+            }
+        }
+        let j = j[0] as usize;
+        c[i] = c[j];
+        c[j] = if (signs >> (i + tau - N)) & 1 == 1 { ML_DSA_Q - 1 } else { 1 };
+    }
+    c
+}
+
+/// Little-endian bit packing of `bits`-bit values
+fn pack(values: impl Iterator<Item = u32>, bits: usize, out: &mut Vec<u8>) {
+    let mut acc = 0u64;
+    let mut filled = 0;
+    for value in values {
+        acc |= (value as u64) << filled;
+        filled += bits;
+        while filled >= 8 {
+            out.push(acc as u8);
+            acc >>= 8;
+            filled -= 8;
+        }
+    }
+}
+
+fn unpack(bytes: &[u8], bits: usize) -> Vec<u32> {
+    let mask = (1u64 << bits) - 1;
+    let mut values = Vec::with_capacity(bytes.len() * 8 / bits);
+    let mut acc = 0u64;
+    let mut filled = 0;
+    for &byte in bytes {
+        acc |= (byte as u64) << filled;
+        filled += 8;
+        while filled >= bits {
+            values.push((acc & mask) as u32);
+            acc >>= bits;
+            filled -= bits;
+        }
+    }
+    values
+}
+
+/// BitPack(w, a, b): b - w for coefficients in [-a, b]
+fn pack_signed(poly: &Poly, b: i32, bits: usize, out: &mut Vec<u8>) {
+    pack(poly.iter().map(|&c| (b - centered(c, ML_DSA_Q)) as u32), bits, out);
+}
+
+/// BitUnpack; `None` if a coefficient falls outside [-a, b]
+fn unpack_signed(bytes: &[u8], a: i32, b: i32, bits: usize) -> Option<Poly> {
+    let values = unpack(bytes, bits);
+    let mut poly = [0; N];
+    for (c, &v) in poly.iter_mut().zip(&values) {
+        let w = b - v as i32;
+        if w < -a || w > b {
+            return None;
+        }
+        *c = reduce(w as i64);
+    }
+    Some(poly)
+}
+
+fn pk_encode(rho: &[u8], t1: &[Poly]) -> Vec<u8> {
+    let mut out = rho.to_vec();
+    t1.iter().for_each(|poly| pack(poly.iter().map(|&c| c as u32), 10, &mut out));
+    out
+}
+
+fn pk_decode(pk: &[u8], p: &Params) -> ([u8; 32], Vec<Poly>) {
+    let mut rho = [0u8; 32];
+    rho.copy_from_slice(&pk[..32]);
+    let t1 = (0..p.k)
+        .map(|i| {
+            let values = unpack(&pk[32 + 320 * i..32 + 320 * (i + 1)], 10);
+            core::array::from_fn(|j| values[j] as i32)
+        })
+        .collect();
+    (rho, t1)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sk_encode(
+    rho: &[u8],
+    key: &[u8],
+    tr: &[u8; 64],
+    s1: &[Poly],
+    s2: &[Poly],
+    t0: &[Poly],
+    p: &Params,
+) -> Vec<u8> {
+    let eta_bits = bitlen(2 * p.eta);
+    let mut out = Vec::new();
+    out.extend_from_slice(rho);
+    out.extend_from_slice(key);
+    out.extend_from_slice(tr);
+    s1.iter().chain(s2).for_each(|poly| pack_signed(poly, p.eta, eta_bits, &mut out));
+    t0.iter().for_each(|poly| pack_signed(poly, 1 << (D - 1), D as usize, &mut out));
+    out
+}
+
+type DecodedSecretKey = ([u8; 32], [u8; 32], [u8; 64], Vec<Poly>, Vec<Poly>, Vec<Poly>);
+
+fn sk_decode(sk: &[u8], p: &Params) -> Option<DecodedSecretKey> {
+    let mut rho = [0u8; 32];
+    let mut key = [0u8; 32];
+    let mut tr = [0u8; 64];
+    rho.copy_from_slice(&sk[..32]);
+    key.copy_from_slice(&sk[32..64]);
+    tr.copy_from_slice(&sk[64..128]);
+
+    let eta_bits = bitlen(2 * p.eta);
+    let eta_len = 32 * eta_bits;
+    let mut offset = 128;
+    let mut next = |len: usize| {
+        let chunk = &sk[offset..offset + len];
+        offset += len;
+        chunk
+    };
+    let s1 = (0..p.l).map(|_| unpack_signed(next(eta_len), p.eta, p.eta, eta_bits)).collect::<Option<_>>()?;
+    let s2 = (0..p.k).map(|_| unpack_signed(next(eta_len), p.eta, p.eta, eta_bits)).collect::<Option<_>>()?;
+    let t0 = (0..p.k)
+        .map(|_| unpack_signed(next(32 * D as usize), (1 << (D - 1)) - 1, 1 << (D - 1), D as usize))
+        .collect::<Option<_>>()?;
+    Some((rho, key, tr, s1, s2, t0))
+}
+
+fn w1_encode(w1: &[Vec<u32>], m: usize) -> Vec<u8> {
+    let bits = bitlen(m as i32 - 1);
+    let mut out = Vec::with_capacity(32 * bits * w1.len());
+    w1.iter().for_each(|poly| pack(poly.iter().copied(), bits, &mut out));
+    out
+}
+
+fn sig_encode(c_tilde: &[u8], z: &[Poly], hints: &[Vec<bool>], p: &Params) -> Vec<u8> {
+    let z_bits = 1 + bitlen(p.gamma1 - 1);
+    let mut out = c_tilde.to_vec();
+    z.iter().for_each(|poly| pack_signed(poly, p.gamma1, z_bits, &mut out));
+
+    // HintBitPack
+    let mut y = vec![0u8; p.omega + p.k];
+    let mut index = 0;
+    for (i, hint) in hints.iter().enumerate() {
+        for (j, _) in hint.iter().enumerate().filter(|(_, &h)| h) {
+            y[index] = j as u8;
+            index += 1;
+        }
+        y[p.omega + i] = index as u8;
+    }
+    out.extend_from_slice(&y);
+    out
+}
+
+type DecodedSignature<'a> = (&'a [u8], Vec<Poly>, Vec<Vec<bool>>);
+
+fn sig_decode<'a>(sig: &'a [u8], p: &Params) -> Option<DecodedSignature<'a>> {
+    let z_bits = 1 + bitlen(p.gamma1 - 1);
+    let (c_tilde, rest) = sig.split_at(p.lambda / 4);
+    let (z_bytes, y) = rest.split_at(32 * z_bits * p.l);
+    let z = z_bytes
+        .chunks(32 * z_bits)
+        .map(|chunk| unpack_signed(chunk, p.gamma1 - 1, p.gamma1, z_bits))
+        .collect::<Option<_>>()?;
+
+    // HintBitUnpack: indices strictly increasing per polynomial, unused bytes zero
+    let mut hints = vec![vec![false; N]; p.k];
+    let mut index = 0;
+    for (i, hint) in hints.iter_mut().enumerate() {
+        let end = y[p.omega + i] as usize;
+        if end < index || end > p.omega {
+            return None;
+        }
+        let first = index;
+        while index < end {
+            if index > first && y[index - 1] >= y[index] {
+                return None;
+            }
+            hint[y[index] as usize] = true;
+            index += 1;
+        }
+    }
+    if y[index..p.omega].iter().any(|&b| b != 0) {
+        return None;
+    }
+    Some((c_tilde, z, hints))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [ParameterSet; 3] = [ParameterSet::MlDsa44, ParameterSet::MlDsa65, ParameterSet::MlDsa87];
+
+    fn hex(s: &str) -> Vec<u8> {
+        let s: String = s.split_whitespace().collect();
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn sha3_256(data: &[u8]) -> Vec<u8> {
+        use sha3::{Digest, Sha3_256};
+        Sha3_256::digest(data).to_vec()
+    }
+
+    #[test]
+    fn test_fips204_sizes() {
+        let sizes: Vec<_> = ALL
+            .iter()
+            .map(|ps| (ps.public_key_size(), ps.secret_key_size(), ps.signature_size()))
+            .collect();
+        assert_eq!(sizes, [(1312, 2560, 2420), (1952, 4032, 3309), (2592, 4896, 4627)]);
+    }
+
+    #[test]
+    fn test_sign_verify_round_trip() {
+        for ps in ALL {
+            let (pk, sk) = keygen_from_seed(ps, &[7u8; 32]);
+            assert_eq!(pk.data.len(), ps.public_key_size());
+            assert_eq!(sk.data.len(), ps.secret_key_size());
+
+            let sig = sign(b"QRATUM ML-DSA", b"ctx", &sk).unwrap();
+            assert_eq!(sig.data.len(), ps.signature_size());
+            assert!(verify(b"QRATUM ML-DSA", b"ctx", &sig, &pk).unwrap());
+            assert!(!verify(b"QRATUM ML-DSA", b"other", &sig, &pk).unwrap());
+            assert!(!verify(b"QRATUM ML-DSb", b"ctx", &sig, &pk).unwrap());
+
+            let deterministic = sign_deterministic(b"m", b"", &sk).unwrap();
+            assert_eq!(deterministic, sign_deterministic(b"m", b"", &sk).unwrap());
+        }
+        let (_, sk) = keygen_from_seed(ParameterSet::MlDsa44, &[7u8; 32]);
+        assert_eq!(sign(b"m", &[0u8; 256], &sk), Err(MlDsaError::ContextTooLong));
+    }
+
+    /// keyGen, sigGen and sigVer vectors in the ACVP test modes, produced
+    /// by independent FIPS 204 implementations (not ACVP server files);
+    /// see testdata/ml_dsa_interop.txt
+    #[test]
+    fn test_interop_vectors() {
+        let vectors = include_str!("testdata/ml_dsa_interop.txt");
+        let field = |f: &str| if f == "-" { Vec::new() } else { hex(f) };
+        let mut checked = [0; 3];
+        for line in vectors.lines().filter(|l| !l.starts_with('#') && !l.trim().is_empty()) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let ps = match fields[1] {
+                "ML-DSA-44" => ParameterSet::MlDsa44,
+                "ML-DSA-65" => ParameterSet::MlDsa65,
+                "ML-DSA-87" => ParameterSet::MlDsa87,
+                other => panic!("unknown parameter set {other}"),
+            };
+            let seed: [u8; 32] = hex(fields[2]).try_into().unwrap();
+            let (pk, sk) = keygen_from_seed(ps, &seed);
+            match fields[0] {
+                "keyGen" => {
+                    assert_eq!(sha3_256(&pk.data), hex(fields[3]), "{} keyGen pk", ps.name());
+                    assert_eq!(sha3_256(&sk.data), hex(fields[4]), "{} keyGen sk", ps.name());
+                    checked[0] += 1;
+                }
+                "sigGen" => {
+                    let sig = sign_deterministic(&field(fields[3]), &field(fields[4]), &sk).unwrap();
+                    assert_eq!(sha3_256(&sig.data), hex(fields[5]), "{} sigGen", ps.name());
+                    checked[1] += 1;
+                }
+                "sigVer" => {
+                    let sig = Signature::from_bytes(ps, &hex(fields[5])).unwrap();
+                    let valid = verify(&field(fields[3]), &field(fields[4]), &sig, &pk).unwrap();
+                    assert_eq!(valid, fields[6] == "true", "{} sigVer", ps.name());
+                    checked[2] += 1;
+                }
+                other => panic!("unknown test mode {other}"),
+            }
+        }
+        assert_eq!(checked, [3, 6, 6]);
+    }
+
+    #[test]
+    fn test_round3_key_migration() {
+        let (pk, sk) = keygen_from_seed(ParameterSet::MlDsa87, &[3u8; 32]);
+        let mut legacy = sk.data[..96].to_vec();
+        legacy.extend_from_slice(&sk.data[128..]);
+        assert_eq!(legacy.len(), 4864);
+
+        let migrated = migrate_round3_secret_key(&pk, &legacy).unwrap();
+        assert_eq!(migrated.data, sk.data);
+
+        // Placeholder keys from the pre-FIPS module are not lattice keys
+        let (old_pk, old_sk) = crate::crystals_dilithium::generate_keypair().unwrap();
+        let old_pk = PublicKey::from_bytes(ParameterSet::MlDsa87, &old_pk.data).unwrap();
+        assert!(matches!(
+            migrate_round3_secret_key(&old_pk, &old_sk.data),
+            Err(MlDsaError::InvalidLegacyKey)
+        ));
+
+        // A legacy key paired with the wrong public key is rejected
+        let (other_pk, _) = keygen_from_seed(ParameterSet::MlDsa87, &[4u8; 32]);
+        assert!(matches!(migrate_round3_secret_key(&other_pk, &legacy), Err(MlDsaError::InvalidLegacyKey)));
+    }
+}
@@ -0,0 +1,654 @@
+//! ML-KEM (FIPS 203) Module-Lattice-Based Key Encapsulation
+//!
+//! The standardized form of CRYSTALS-Kyber. Implements ML-KEM-512,
+//! ML-KEM-768 and ML-KEM-1024 with the FIPS 203 key and ciphertext
+//! encodings, the encapsulation and decapsulation key input checks, and
+//! implicit rejection on decapsulation.
+//!
+//! Keys and ciphertexts from the pre-FIPS placeholder in
+//! [`crate::crystals_kyber`] are not lattice values and cannot be used here;
+//! recipients must generate ML-KEM keys.
+//!
+//! Tested against interop vectors from an independent implementation
+//! (`testdata/ml_kem_interop.txt`), not against NIST ACVP vector files.
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Digest, Sha3_256, Sha3_512, Shake128, Shake256};
+use std::error::Error;
+use std::fmt;
+
+/// Modulus q
+pub const ML_KEM_Q: u32 = 3329;
+
+/// Shared secret length in bytes
+pub const SHARED_SECRET_SIZE: usize = 32;
+
+/// Polynomial degree n
+const N: usize = 256;
+
+/// 128^-1 mod q
+const N_INV: u32 = 3303;
+
+/// 17^brv7(k) mod q for the NTT
+const ZETAS: [u32; 128] = compute_zetas(false);
+
+/// 17^(2*brv7(k)+1) mod q for base-case multiplication
+const GAMMAS: [u32; 128] = compute_zetas(true);
+
+const fn compute_zetas(gamma: bool) -> [u32; 128] {
+    let mut zetas = [0u32; 128];
+    let mut k = 0;
+    while k < 128 {
+        let brv = ((k as u8).reverse_bits() >> 1) as u32;
+        let mut exponent = if gamma { 2 * brv + 1 } else { brv };
+        let mut base = 17u32;
+        let mut acc = 1u32;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                acc = acc * base % ML_KEM_Q;
+            }
+            base = base * base % ML_KEM_Q;
+            exponent >>= 1;
+        }
+        zetas[k] = acc;
+        k += 1;
+    }
+    zetas
+}
+
+type Poly = [u32; N];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MlKemError {
+    InvalidKeySize,
+    InvalidCiphertextSize,
+    InvalidEncapsulationKey,
+    InvalidDecapsulationKey,
+    KeyGenerationFailed,
+    EncapsulationFailed,
+}
+
+impl fmt::Display for MlKemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MlKemError::InvalidKeySize => write!(f, "Invalid key size"),
+            MlKemError::InvalidCiphertextSize => write!(f, "Invalid ciphertext size"),
+            MlKemError::InvalidEncapsulationKey => write!(f, "Encapsulation key is not reduced mod q"),
+            MlKemError::InvalidDecapsulationKey => write!(f, "Decapsulation key hash check failed"),
+            MlKemError::KeyGenerationFailed => write!(f, "Key generation failed"),
+            MlKemError::EncapsulationFailed => write!(f, "Encapsulation failed"),
+        }
+    }
+}
+
+impl Error for MlKemError {}
+
+/// FIPS 203 parameter sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterSet {
+    /// NIST security category 1
+    MlKem512,
+    /// NIST security category 3
+    MlKem768,
+    /// NIST security category 5 (successor of Kyber-1024)
+    MlKem1024,
+}
+
+/// FIPS 203 Table 2 parameters
+struct Params {
+    k: usize,
+    eta1: usize,
+    eta2: usize,
+    du: usize,
+    dv: usize,
+}
+
+impl ParameterSet {
+    fn params(self) -> Params {
+        match self {
+            ParameterSet::MlKem512 => Params { k: 2, eta1: 3, eta2: 2, du: 10, dv: 4 },
+            ParameterSet::MlKem768 => Params { k: 3, eta1: 2, eta2: 2, du: 10, dv: 4 },
+            ParameterSet::MlKem1024 => Params { k: 4, eta1: 2, eta2: 2, du: 11, dv: 5 },
+        }
+    }
+
+    /// Name as written in FIPS 203
+    pub fn name(self) -> &'static str {
+        match self {
+            ParameterSet::MlKem512 => "ML-KEM-512",
+            ParameterSet::MlKem768 => "ML-KEM-768",
+            ParameterSet::MlKem1024 => "ML-KEM-1024",
+        }
+    }
+
+    /// Encoded encapsulation (public) key length
+    pub fn public_key_size(self) -> usize {
+        384 * self.params().k + 32
+    }
+
+    /// Encoded decapsulation (secret) key length
+    pub fn secret_key_size(self) -> usize {
+        768 * self.params().k + 96
+    }
+
+    /// Ciphertext length
+    pub fn ciphertext_size(self) -> usize {
+        let p = self.params();
+        32 * (p.du * p.k + p.dv)
+    }
+}
+
+/// ML-KEM encapsulation key
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    pub parameter_set: ParameterSet,
+    pub data: Vec<u8>,
+}
+
+/// ML-KEM decapsulation key
+#[derive(Clone, Debug)]
+pub struct SecretKey {
+    pub parameter_set: ParameterSet,
+    pub data: Vec<u8>,
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.data.iter_mut().for_each(|b| *b = 0);
+    }
+}
+
+/// ML-KEM ciphertext
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ciphertext {
+    pub parameter_set: ParameterSet,
+    pub data: Vec<u8>,
+}
+
+impl PublicKey {
+    /// Wrap an encoded encapsulation key, running the FIPS 203 input checks
+    pub fn from_bytes(parameter_set: ParameterSet, bytes: &[u8]) -> Result<Self, MlKemError> {
+        if bytes.len() != parameter_set.public_key_size() {
+            return Err(MlKemError::InvalidKeySize);
+        }
+        if !is_reduced(&bytes[..384 * parameter_set.params().k]) {
+            return Err(MlKemError::InvalidEncapsulationKey);
+        }
+        Ok(Self { parameter_set, data: bytes.to_vec() })
+    }
+}
+
+impl SecretKey {
+    /// Wrap an encoded decapsulation key, checking the embedded key hash
+    pub fn from_bytes(parameter_set: ParameterSet, bytes: &[u8]) -> Result<Self, MlKemError> {
+        if bytes.len() != parameter_set.secret_key_size() {
+            return Err(MlKemError::InvalidKeySize);
+        }
+        let k = parameter_set.params().k;
+        let ek = &bytes[384 * k..768 * k + 32];
+        if Sha3_256::digest(ek)[..] != bytes[768 * k + 32..768 * k + 64] {
+            return Err(MlKemError::InvalidDecapsulationKey);
+        }
+        Ok(Self { parameter_set, data: bytes.to_vec() })
+    }
+}
+
+impl Ciphertext {
+    /// Wrap a ciphertext, checking its length
+    pub fn from_bytes(parameter_set: ParameterSet, bytes: &[u8]) -> Result<Self, MlKemError> {
+        if bytes.len() != parameter_set.ciphertext_size() {
+            return Err(MlKemError::InvalidCiphertextSize);
+        }
+        Ok(Self { parameter_set, data: bytes.to_vec() })
+    }
+}
+
+/// Generate an ML-KEM keypair from OS randomness
+pub fn generate_keypair(parameter_set: ParameterSet) -> Result<(PublicKey, SecretKey), MlKemError> {
+    let mut seed = [0u8; 64];
+    getrandom::getrandom(&mut seed).map_err(|_| MlKemError::KeyGenerationFailed)?;
+    let (d, z) = seed.split_at(32);
+    let keypair = keygen_from_seed(parameter_set, d.try_into().unwrap(), z.try_into().unwrap());
+    seed.iter_mut().for_each(|b| *b = 0);
+    Ok(keypair)
+}
+
+/// Derive an ML-KEM keypair from seeds d and z (ML-KEM.KeyGen_internal)
+pub fn keygen_from_seed(parameter_set: ParameterSet, d: &[u8; 32], z: &[u8; 32]) -> (PublicKey, SecretKey) {
+    let p = parameter_set.params();
+    let (ek, mut dk_pke) = pke_keygen(d, &p);
+
+    let mut dk = Vec::with_capacity(parameter_set.secret_key_size());
+    dk.extend_from_slice(&dk_pke);
+    dk.extend_from_slice(&ek);
+    dk.extend_from_slice(&Sha3_256::digest(&ek));
+    dk.extend_from_slice(z);
+    dk_pke.iter_mut().for_each(|b| *b = 0);
+
+    (
+        PublicKey { parameter_set, data: ek },
+        SecretKey { parameter_set, data: dk },
+    )
+}
+
+/// Encapsulate a fresh shared secret to `public_key` (ML-KEM.Encaps)
+pub fn encapsulate(public_key: &PublicKey) -> Result<([u8; SHARED_SECRET_SIZE], Ciphertext), MlKemError> {
+    let mut m = [0u8; 32];
+    getrandom::getrandom(&mut m).map_err(|_| MlKemError::EncapsulationFailed)?;
+    let result = encapsulate_deterministic(public_key, &m);
+    m.iter_mut().for_each(|b| *b = 0);
+    result
+}
+
+/// Encapsulate with caller-supplied randomness m (ML-KEM.Encaps_internal)
+///
+/// `m` must be fresh and secret; reusing it reveals the shared secret.
+pub fn encapsulate_deterministic(
+    public_key: &PublicKey,
+    m: &[u8; 32],
+) -> Result<([u8; SHARED_SECRET_SIZE], Ciphertext), MlKemError> {
+    let parameter_set = public_key.parameter_set;
+    let p = parameter_set.params();
+    if public_key.data.len() != parameter_set.public_key_size() {
+        return Err(MlKemError::InvalidKeySize);
+    }
+    if !is_reduced(&public_key.data[..384 * p.k]) {
+        return Err(MlKemError::InvalidEncapsulationKey);
+    }
+
+    let mut kr = [0u8; 64];
+    sha3_512(&[m, &Sha3_256::digest(&public_key.data)], &mut kr);
+    let mut shared = [0u8; SHARED_SECRET_SIZE];
+    shared.copy_from_slice(&kr[..32]);
+    let data = pke_encrypt(&public_key.data, m, &kr[32..], &p);
+    kr.iter_mut().for_each(|b| *b = 0);
+
+    Ok((shared, Ciphertext { parameter_set, data }))
+}
+
+/// Recover the shared secret (ML-KEM.Decaps)
+///
+/// A ciphertext that does not re-encrypt to itself yields a pseudorandom
+/// secret derived from z (implicit rejection), never an error.
+pub fn decapsulate(ciphertext: &Ciphertext, secret_key: &SecretKey) -> Result<[u8; SHARED_SECRET_SIZE], MlKemError> {
+    let parameter_set = secret_key.parameter_set;
+    let p = parameter_set.params();
+    if secret_key.data.len() != parameter_set.secret_key_size() {
+        return Err(MlKemError::InvalidKeySize);
+    }
+    if ciphertext.parameter_set != parameter_set || ciphertext.data.len() != parameter_set.ciphertext_size() {
+        return Err(MlKemError::InvalidCiphertextSize);
+    }
+
+    let dk = &secret_key.data;
+    let (dk_pke, rest) = dk.split_at(384 * p.k);
+    let (ek_pke, rest) = rest.split_at(384 * p.k + 32);
+    let (h, z) = rest.split_at(32);
+
+    let mut m = pke_decrypt(dk_pke, &ciphertext.data, &p);
+    let mut kr = [0u8; 64];
+    sha3_512(&[&m, h], &mut kr);
+    let mut rejected = [0u8; SHARED_SECRET_SIZE];
+    shake256(&[z, &ciphertext.data], &mut rejected);
+    let reencrypted = pke_encrypt(ek_pke, &m, &kr[32..], &p);
+
+    // Constant-time select between K' and the rejection key
+    let diff = reencrypted.iter().zip(&ciphertext.data).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+    let mask = ((diff as u16).wrapping_sub(1) >> 8) as u8; // 0xff iff equal
+    let mut shared = [0u8; SHARED_SECRET_SIZE];
+    for (i, byte) in shared.iter_mut().enumerate() {
+        *byte = (kr[i] & mask) | (rejected[i] & !mask);
+    }
+    m.iter_mut().for_each(|b| *b = 0);
+    kr.iter_mut().for_each(|b| *b = 0);
+    rejected.iter_mut().for_each(|b| *b = 0);
+    Ok(shared)
+}
+
+/// K-PKE.KeyGen (FIPS 203 Algorithm 13)
+fn pke_keygen(d: &[u8; 32], p: &Params) -> (Vec<u8>, Vec<u8>) {
+    let mut seeds = [0u8; 64];
+    sha3_512(&[d, &[p.k as u8]], &mut seeds);
+    let (rho, sigma) = seeds.split_at(32);
+
+    let a_hat = sample_matrix(rho, p.k);
+    let mut nonce = 0u8;
+    let s_hat: Vec<Poly> = (0..p.k).map(|_| ntt(&sample_cbd(sigma, next(&mut nonce), p.eta1))).collect();
+    let e_hat: Vec<Poly> = (0..p.k).map(|_| ntt(&sample_cbd(sigma, next(&mut nonce), p.eta1))).collect();
+    let t_hat: Vec<Poly> = a_hat.iter().zip(&e_hat).map(|(row, e)| add(&dot(row, &s_hat), e)).collect();
+
+    let mut ek = Vec::with_capacity(384 * p.k + 32);
+    t_hat.iter().for_each(|t| byte_encode(t, 12, &mut ek));
+    ek.extend_from_slice(rho);
+    let mut dk = Vec::with_capacity(384 * p.k);
+    s_hat.iter().for_each(|s| byte_encode(s, 12, &mut dk));
+    seeds.iter_mut().for_each(|b| *b = 0);
+    (ek, dk)
+}
+
+/// K-PKE.Encrypt (FIPS 203 Algorithm 14)
+fn pke_encrypt(ek: &[u8], m: &[u8], r: &[u8], p: &Params) -> Vec<u8> {
+    let t_hat: Vec<Poly> = ek[..384 * p.k].chunks(384).map(|c| byte_decode(c, 12)).collect();
+    let a_hat = sample_matrix(&ek[384 * p.k..], p.k);
+
+    let mut nonce = 0u8;
+    let y_hat: Vec<Poly> = (0..p.k).map(|_| ntt(&sample_cbd(r, next(&mut nonce), p.eta1))).collect();
+    let e1: Vec<Poly> = (0..p.k).map(|_| sample_cbd(r, next(&mut nonce), p.eta2)).collect();
+    let e2 = sample_cbd(r, next(&mut nonce), p.eta2);
+
+    // u = NTT^-1(A^T * y) + e1
+    let u: Vec<Poly> = (0..p.k)
+        .map(|i| {
+            let column: Vec<Poly> = a_hat.iter().map(|row| row[i]).collect();
+            add(&inv_ntt(&dot(&column, &y_hat)), &e1[i])
+        })
+        .collect();
+    let mu = decompress(&byte_decode(m, 1), 1);
+    let v = add(&add(&inv_ntt(&dot(&t_hat, &y_hat)), &e2), &mu);
+
+    let mut c = Vec::with_capacity(32 * (p.du * p.k + p.dv));
+    u.iter().for_each(|u| byte_encode(&compress(u, p.du), p.du, &mut c));
+    byte_encode(&compress(&v, p.dv), p.dv, &mut c);
+    c
+}
+
+/// K-PKE.Decrypt (FIPS 203 Algorithm 15)
+fn pke_decrypt(dk: &[u8], c: &[u8], p: &Params) -> [u8; 32] {
+    let (c1, c2) = c.split_at(32 * p.du * p.k);
+    let u_hat: Vec<Poly> = c1.chunks(32 * p.du).map(|c| ntt(&decompress(&byte_decode(c, p.du), p.du))).collect();
+    let v = decompress(&byte_decode(c2, p.dv), p.dv);
+    let s_hat: Vec<Poly> = dk.chunks(384).map(|c| byte_decode(c, 12)).collect();
+
+    let w = sub(&v, &inv_ntt(&dot(&s_hat, &u_hat)));
+    let mut m = Vec::with_capacity(32);
+    byte_encode(&compress(&w, 1), 1, &mut m);
+    m.try_into().expect("32-byte message")
+}
+
+fn next(nonce: &mut u8) -> u8 {
+    *nonce += 1;
+    *nonce - 1
+}
+
+fn sha3_512(parts: &[&[u8]], out: &mut [u8; 64]) {
+    let mut hasher = Sha3_512::new();
+    parts.iter().for_each(|part| Digest::update(&mut hasher, part));
+    out.copy_from_slice(&hasher.finalize());
+}
+
+fn shake256(parts: &[&[u8]], out: &mut [u8]) {
+    let mut hasher = Shake256::default();
+    parts.iter().for_each(|part| hasher.update(part));
+    hasher.finalize_xof().read(out);
+}
+
+fn add(a: &Poly, b: &Poly) -> Poly {
+    core::array::from_fn(|i| (a[i] + b[i]) % ML_KEM_Q)
+}
+
+fn sub(a: &Poly, b: &Poly) -> Poly {
+    core::array::from_fn(|i| (a[i] + ML_KEM_Q - b[i]) % ML_KEM_Q)
+}
+
+/// Sum of products in the NTT domain (MultiplyNTTs, FIPS 203 Algorithm 11)
+fn dot(a: &[Poly], b: &[Poly]) -> Poly {
+    a.iter().zip(b).fold([0u32; N], |acc, (a, b)| {
+        let mut product = [0u32; N];
+        for i in 0..128 {
+            let (a0, a1, b0, b1) = (a[2 * i], a[2 * i + 1], b[2 * i], b[2 * i + 1]);
+            product[2 * i] = (a0 * b0 + a1 * b1 % ML_KEM_Q * GAMMAS[i]) % ML_KEM_Q;
+            product[2 * i + 1] = (a0 * b1 + a1 * b0) % ML_KEM_Q;
+        }
+        add(&acc, &product)
+    })
+}
+
+/// NTT (FIPS 203 Algorithm 9)
+fn ntt(poly: &Poly) -> Poly {
+    let mut f = *poly;
+    let mut k = 1;
+    let mut len = 128;
+    while len >= 2 {
+        for start in (0..N).step_by(2 * len) {
+            let zeta = ZETAS[k];
+            k += 1;
+            for j in start..start + len {
+                let t = zeta * f[j + len] % ML_KEM_Q;
+                f[j + len] = (f[j] + ML_KEM_Q - t) % ML_KEM_Q;
+                f[j] = (f[j] + t) % ML_KEM_Q;
+            }
+        }
+        len /= 2;
+    }
+    f
+}
+
+/// Inverse NTT (FIPS 203 Algorithm 10)
+fn inv_ntt(poly: &Poly) -> Poly {
+    let mut f = *poly;
+    let mut k = 127;
+    let mut len = 2;
+    while len <= 128 {
+        for start in (0..N).step_by(2 * len) {
+            let zeta = ZETAS[k];
+            k -= 1;
+            for j in start..start + len {
+                let t = f[j];
+                f[j] = (t + f[j + len]) % ML_KEM_Q;
+                f[j + len] = zeta * ((f[j + len] + ML_KEM_Q - t) % ML_KEM_Q) % ML_KEM_Q;
+            }
+        }
+        len *= 2;
+    }
+    f.map(|x| x * N_INV % ML_KEM_Q)
+}
+
+/// A-hat[i][j] = SampleNTT(rho || j || i)
+fn sample_matrix(rho: &[u8], k: usize) -> Vec<Vec<Poly>> {
+    (0..k)
+        .map(|i| (0..k).map(|j| sample_ntt(rho, j as u8, i as u8)).collect())
+        .collect()
+}
+
+/// SampleNTT (FIPS 203 Algorithm 7)
+fn sample_ntt(rho: &[u8], j: u8, i: u8) -> Poly {
+    let mut xof = Shake128::default();
+    xof.update(rho);
+    xof.update(&[j, i]);
+    let mut reader = xof.finalize_xof();
+    let mut a = [0u32; N];
+    let mut count = 0;
+    let mut c = [0u8; 3];
+    while count < N {
+        reader.read(&mut c);
+        let d1 = c[0] as u32 | ((c[1] as u32 & 0x0f) << 8);
+        let d2 = (c[1] as u32 >> 4) | ((c[2] as u32) << 4);
+        if d1 < ML_KEM_Q {
+            a[count] = d1;
+            count += 1;
+        }
+        if d2 < ML_KEM_Q && count < N {
+            a[count] = d2;
+            count += 1;
+        }
+    }
+    a
+}
+
+/// SamplePolyCBD over PRF(seed, nonce) (FIPS 203 Algorithm 8)
+fn sample_cbd(seed: &[u8], nonce: u8, eta: usize) -> Poly {
+    let mut bytes = vec![0u8; 64 * eta];
+    shake256(&[seed, &[nonce]], &mut bytes);
+    let bit = |i: usize| (bytes[i / 8] >> (i % 8)) as u32 & 1;
+    let poly = core::array::from_fn(|i| {
+        let x: u32 = (0..eta).map(|j| bit(2 * i * eta + j)).sum();
+        let y: u32 = (0..eta).map(|j| bit(2 * i * eta + eta + j)).sum();
+        (x + ML_KEM_Q - y) % ML_KEM_Q
+    });
+    bytes.iter_mut().for_each(|b| *b = 0);
+    poly
+}
+
+/// Compress_d: round(2^d / q * x) mod 2^d
+fn compress(poly: &Poly, d: usize) -> Poly {
+    poly.map(|x| (((x << d) + ML_KEM_Q / 2) / ML_KEM_Q) & ((1 << d) - 1))
+}
+
+/// Decompress_d: round(q / 2^d * y)
+fn decompress(poly: &Poly, d: usize) -> Poly {
+    poly.map(|y| (y * ML_KEM_Q + (1 << (d - 1))) >> d)
+}
+
+/// ByteEncode_d (FIPS 203 Algorithm 5), little-endian bit order
+fn byte_encode(poly: &Poly, d: usize, out: &mut Vec<u8>) {
+    let mut acc = 0u64;
+    let mut bits = 0;
+    for &x in poly {
+        acc |= (x as u64) << bits;
+        bits += d;
+        while bits >= 8 {
+            out.push(acc as u8);
+            acc >>= 8;
+            bits -= 8;
+        }
+    }
+}
+
+/// ByteDecode_d (FIPS 203 Algorithm 6); d = 12 reduces mod q
+fn byte_decode(bytes: &[u8], d: usize) -> Poly {
+    let mut poly = [0u32; N];
+    let mut acc = 0u64;
+    let mut bits = 0;
+    let mut bytes = bytes.iter();
+    for x in poly.iter_mut() {
+        while bits < d {
+            acc |= (*bytes.next().expect("encoded polynomial length") as u64) << bits;
+            bits += 8;
+        }
+        *x = (acc & ((1 << d) - 1)) as u32;
+        acc >>= d;
+        bits -= d;
+        if d == 12 {
+            *x %= ML_KEM_Q;
+        }
+    }
+    poly
+}
+
+/// Encapsulation key modulus check: every 12-bit coefficient is below q
+fn is_reduced(encoded: &[u8]) -> bool {
+    encoded.chunks(3).all(|c| {
+        let d1 = c[0] as u32 | ((c[1] as u32 & 0x0f) << 8);
+        let d2 = (c[1] as u32 >> 4) | ((c[2] as u32) << 4);
+        d1 < ML_KEM_Q && d2 < ML_KEM_Q
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [ParameterSet; 3] = [ParameterSet::MlKem512, ParameterSet::MlKem768, ParameterSet::MlKem1024];
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn sha3_256(data: &[u8]) -> Vec<u8> {
+        Sha3_256::digest(data).to_vec()
+    }
+
+    #[test]
+    fn test_fips203_sizes() {
+        let sizes: Vec<_> = ALL
+            .iter()
+            .map(|ps| (ps.public_key_size(), ps.secret_key_size(), ps.ciphertext_size()))
+            .collect();
+        assert_eq!(sizes, [(800, 1632, 768), (1184, 2400, 1088), (1568, 3168, 1568)]);
+    }
+
+    #[test]
+    fn test_encapsulate_decapsulate() {
+        for ps in ALL {
+            let (pk, sk) = generate_keypair(ps).unwrap();
+            let (shared, ct) = encapsulate(&pk).unwrap();
+            assert_eq!(ct.data.len(), ps.ciphertext_size());
+            assert_eq!(decapsulate(&ct, &sk).unwrap(), shared);
+
+            // Implicit rejection: a modified ciphertext yields an unrelated secret
+            let mut tampered = ct.clone();
+            tampered.data[0] ^= 1;
+            let rejected = decapsulate(&tampered, &sk).unwrap();
+            assert_ne!(rejected, shared);
+            assert_eq!(decapsulate(&tampered, &sk).unwrap(), rejected);
+        }
+    }
+
+    #[test]
+    fn test_input_checks() {
+        let (pk, sk) = keygen_from_seed(ParameterSet::MlKem768, &[1u8; 32], &[2u8; 32]);
+        assert!(PublicKey::from_bytes(ParameterSet::MlKem768, &pk.data).is_ok());
+        assert!(SecretKey::from_bytes(ParameterSet::MlKem768, &sk.data).is_ok());
+
+        // Coefficient 4095 >= q
+        let mut unreduced = pk.data.clone();
+        unreduced[0] = 0xff;
+        unreduced[1] |= 0x0f;
+        assert_eq!(
+            PublicKey::from_bytes(ParameterSet::MlKem768, &unreduced).err(),
+            Some(MlKemError::InvalidEncapsulationKey)
+        );
+
+        let mut mismatched = sk.data.clone();
+        mismatched[768 * 3 + 40] ^= 1;
+        assert_eq!(
+            SecretKey::from_bytes(ParameterSet::MlKem768, &mismatched).err(),
+            Some(MlKemError::InvalidDecapsulationKey)
+        );
+
+        let short = Ciphertext { parameter_set: ParameterSet::MlKem768, data: vec![0u8; 100] };
+        assert_eq!(decapsulate(&short, &sk), Err(MlKemError::InvalidCiphertextSize));
+    }
+
+    /// keyGen, encap and decap vectors in the ACVP test modes, produced by an
+    /// independent FIPS 203 implementation (not ACVP server files); see
+    /// testdata/ml_kem_interop.txt
+    #[test]
+    fn test_interop_vectors() {
+        let vectors = include_str!("testdata/ml_kem_interop.txt");
+        let mut checked = [0; 3];
+        for line in vectors.lines().filter(|l| !l.starts_with('#') && !l.trim().is_empty()) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let ps = match fields[1] {
+                "ML-KEM-512" => ParameterSet::MlKem512,
+                "ML-KEM-768" => ParameterSet::MlKem768,
+                "ML-KEM-1024" => ParameterSet::MlKem1024,
+                other => panic!("unknown parameter set {other}"),
+            };
+            let d: [u8; 32] = hex(fields[2]).try_into().unwrap();
+            let z: [u8; 32] = hex(fields[3]).try_into().unwrap();
+            let (pk, sk) = keygen_from_seed(ps, &d, &z);
+            match fields[0] {
+                "keyGen" => {
+                    assert_eq!(sha3_256(&pk.data), hex(fields[4]), "{} keyGen ek", ps.name());
+                    assert_eq!(sha3_256(&sk.data), hex(fields[5]), "{} keyGen dk", ps.name());
+                    checked[0] += 1;
+                }
+                "encap" => {
+                    let m: [u8; 32] = hex(fields[4]).try_into().unwrap();
+                    let (shared, ct) = encapsulate_deterministic(&pk, &m).unwrap();
+                    assert_eq!(sha3_256(&ct.data), hex(fields[5]), "{} encap c", ps.name());
+                    assert_eq!(shared.to_vec(), hex(fields[6]), "{} encap K", ps.name());
+                    checked[1] += 1;
+                }
+                "decap" => {
+                    let ct = Ciphertext::from_bytes(ps, &hex(fields[4])).unwrap();
+                    assert_eq!(decapsulate(&ct, &sk).unwrap().to_vec(), hex(fields[5]), "{} decap", ps.name());
+                    checked[2] += 1;
+                }
+                other => panic!("unknown test mode {other}"),
+            }
+        }
+        assert_eq!(checked, [3, 3, 6]);
+    }
+}
@@ -4,6 +4,10 @@
 //! - SPHINCS+: Stateless hash-based signatures
 //! - CRYSTALS-Kyber: Lattice-based key encapsulation
 //! - CRYSTALS-Dilithium: Lattice-based digital signatures
+//! - ML-DSA (FIPS 204) and SLH-DSA (FIPS 205): standardized parameter sets
+//!   and encodings, with migration shims for the legacy modules above
+//! - ML-KEM (FIPS 203): the standardized Kyber KEM; the legacy Kyber module
+//!   above is a placeholder whose keys cannot be migrated
 //!
 //! These algorithms replace classical crypto with quantum-resistant alternatives
 //! to protect QRATUM against future quantum computer attacks.
@@ -11,6 +15,9 @@
 pub mod sphincs_plus;
 pub mod crystals_kyber;
 pub mod crystals_dilithium;
+pub mod ml_dsa;
+pub mod ml_kem;
+pub mod slh_dsa;
 
 pub use sphincs_plus::{
     PublicKey as SPHINCSPublicKey,
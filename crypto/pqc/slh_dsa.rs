@@ -0,0 +1,816 @@
+//! SLH-DSA (FIPS 205) Stateless Hash-Based Digital Signatures
+//!
+//! The standardized form of SPHINCS+. Implements the six SHAKE parameter
+//! sets (SLH-DSA-SHAKE-128s/f, -192s/f, -256s/f) with the FIPS 205 key and
+//! signature encodings, pure SLH-DSA context strings, and hedged or
+//! deterministic signing. The SHA2 parameter sets are not provided; this
+//! crate hashes with SHA-3 only.
+//!
+//! Legacy [`crate::sphincs_plus`] keys carry genuine SK.seed, SK.prf and
+//! PK.seed values but a placeholder root. [`from_legacy_sphincs`] rebuilds
+//! the key pair under an n = 32 parameter set; the root, and therefore the
+//! public key, changes and must be redistributed.
+//!
+//! Tested against interop vectors from independent implementations
+//! (`testdata/`), not against NIST ACVP vector files.
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+use std::error::Error;
+use std::fmt;
+
+use crate::sphincs_plus;
+
+/// Winternitz parameter lg(w)
+const LG_W: usize = 4;
+
+/// Winternitz parameter w
+const W: u32 = 1 << LG_W;
+
+/// WOTS+ checksum digits
+const LEN2: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlhDsaError {
+    InvalidKeySize,
+    InvalidSignatureSize,
+    ContextTooLong,
+    KeyGenerationFailed,
+    SigningFailed,
+    UnsupportedLegacyParameterSet,
+}
+
+impl fmt::Display for SlhDsaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SlhDsaError::InvalidKeySize => write!(f, "Invalid key size"),
+            SlhDsaError::InvalidSignatureSize => write!(f, "Invalid signature size"),
+            SlhDsaError::ContextTooLong => write!(f, "Context string longer than 255 bytes"),
+            SlhDsaError::KeyGenerationFailed => write!(f, "Key generation failed"),
+            SlhDsaError::SigningFailed => write!(f, "Signing failed"),
+            SlhDsaError::UnsupportedLegacyParameterSet => {
+                write!(f, "Legacy keys migrate only to n = 32 parameter sets")
+            }
+        }
+    }
+}
+
+impl Error for SlhDsaError {}
+
+/// FIPS 205 SHAKE parameter sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterSet {
+    Shake128s,
+    Shake128f,
+    Shake192s,
+    Shake192f,
+    Shake256s,
+    Shake256f,
+}
+
+/// FIPS 205 Table 2 parameters
+#[derive(Clone, Copy)]
+struct Params {
+    n: usize,
+    h: usize,
+    d: usize,
+    hp: usize,
+    a: usize,
+    k: usize,
+    m: usize,
+}
+
+impl Params {
+    /// WOTS+ chain count
+    fn len(&self) -> usize {
+        2 * self.n + LEN2
+    }
+}
+
+impl ParameterSet {
+    fn params(self) -> Params {
+        let (n, h, d, hp, a, k, m) = match self {
+            ParameterSet::Shake128s => (16, 63, 7, 9, 12, 14, 30),
+            ParameterSet::Shake128f => (16, 66, 22, 3, 6, 33, 34),
+            ParameterSet::Shake192s => (24, 63, 7, 9, 14, 17, 39),
+            ParameterSet::Shake192f => (24, 66, 22, 3, 8, 33, 42),
+            ParameterSet::Shake256s => (32, 64, 8, 8, 14, 22, 47),
+            ParameterSet::Shake256f => (32, 68, 17, 4, 9, 35, 49),
+        };
+        Params { n, h, d, hp, a, k, m }
+    }
+
+    /// Name as written in FIPS 205
+    pub fn name(self) -> &'static str {
+        match self {
+            ParameterSet::Shake128s => "SLH-DSA-SHAKE-128s",
+            ParameterSet::Shake128f => "SLH-DSA-SHAKE-128f",
+            ParameterSet::Shake192s => "SLH-DSA-SHAKE-192s",
+            ParameterSet::Shake192f => "SLH-DSA-SHAKE-192f",
+            ParameterSet::Shake256s => "SLH-DSA-SHAKE-256s",
+            ParameterSet::Shake256f => "SLH-DSA-SHAKE-256f",
+        }
+    }
+
+    /// NIST CSOR object identifier
+    pub fn oid(self) -> &'static str {
+        match self {
+            ParameterSet::Shake128s => "2.16.840.1.101.3.4.3.26",
+            ParameterSet::Shake128f => "2.16.840.1.101.3.4.3.27",
+            ParameterSet::Shake192s => "2.16.840.1.101.3.4.3.28",
+            ParameterSet::Shake192f => "2.16.840.1.101.3.4.3.29",
+            ParameterSet::Shake256s => "2.16.840.1.101.3.4.3.30",
+            ParameterSet::Shake256f => "2.16.840.1.101.3.4.3.31",
+        }
+    }
+
+    /// Encoded public key length (PK.seed || PK.root)
+    pub fn public_key_size(self) -> usize {
+        2 * self.params().n
+    }
+
+    /// Encoded secret key length (SK.seed || SK.prf || PK.seed || PK.root)
+    pub fn secret_key_size(self) -> usize {
+        4 * self.params().n
+    }
+
+    /// Encoded signature length
+    pub fn signature_size(self) -> usize {
+        let p = self.params();
+        p.n * (1 + p.k * (1 + p.a) + p.h + p.d * p.len())
+    }
+}
+
+/// SLH-DSA public key
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    pub parameter_set: ParameterSet,
+    pub data: Vec<u8>,
+}
+
+/// SLH-DSA secret key
+#[derive(Clone, Debug)]
+pub struct SecretKey {
+    pub parameter_set: ParameterSet,
+    pub data: Vec<u8>,
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.data.iter_mut().for_each(|b| *b = 0);
+    }
+}
+
+/// SLH-DSA signature
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub parameter_set: ParameterSet,
+    pub data: Vec<u8>,
+}
+
+impl PublicKey {
+    /// Wrap an encoded public key, checking its length
+    pub fn from_bytes(parameter_set: ParameterSet, bytes: &[u8]) -> Result<Self, SlhDsaError> {
+        if bytes.len() != parameter_set.public_key_size() {
+            return Err(SlhDsaError::InvalidKeySize);
+        }
+        Ok(Self { parameter_set, data: bytes.to_vec() })
+    }
+}
+
+impl SecretKey {
+    /// Wrap an encoded secret key, checking its length
+    pub fn from_bytes(parameter_set: ParameterSet, bytes: &[u8]) -> Result<Self, SlhDsaError> {
+        if bytes.len() != parameter_set.secret_key_size() {
+            return Err(SlhDsaError::InvalidKeySize);
+        }
+        Ok(Self { parameter_set, data: bytes.to_vec() })
+    }
+
+    /// Public key embedded in the secret key
+    pub fn public_key(&self) -> PublicKey {
+        let n = self.parameter_set.params().n;
+        PublicKey { parameter_set: self.parameter_set, data: self.data[2 * n..].to_vec() }
+    }
+}
+
+impl Signature {
+    /// Wrap an encoded signature, checking its length
+    pub fn from_bytes(parameter_set: ParameterSet, bytes: &[u8]) -> Result<Self, SlhDsaError> {
+        if bytes.len() != parameter_set.signature_size() {
+            return Err(SlhDsaError::InvalidSignatureSize);
+        }
+        Ok(Self { parameter_set, data: bytes.to_vec() })
+    }
+}
+
+/// Generate an SLH-DSA keypair from OS randomness
+pub fn generate_keypair(parameter_set: ParameterSet) -> Result<(PublicKey, SecretKey), SlhDsaError> {
+    let n = parameter_set.params().n;
+    let mut seeds = vec![0u8; 3 * n];
+    getrandom::getrandom(&mut seeds).map_err(|_| SlhDsaError::KeyGenerationFailed)?;
+    let keypair = keygen_from_seeds(parameter_set, &seeds[..n], &seeds[n..2 * n], &seeds[2 * n..]);
+    seeds.iter_mut().for_each(|b| *b = 0);
+    keypair
+}
+
+/// Derive a keypair from SK.seed, SK.prf and PK.seed (slh_keygen_internal)
+pub fn keygen_from_seeds(
+    parameter_set: ParameterSet,
+    sk_seed: &[u8],
+    sk_prf: &[u8],
+    pk_seed: &[u8],
+) -> Result<(PublicKey, SecretKey), SlhDsaError> {
+    let p = parameter_set.params();
+    if [sk_seed, sk_prf, pk_seed].iter().any(|seed| seed.len() != p.n) {
+        return Err(SlhDsaError::InvalidKeySize);
+    }
+    let ctx = Context { p, pk_seed };
+    let mut adrs = Adrs::default();
+    adrs.set_layer(p.d as u32 - 1);
+    let root = ctx.xmss_node(sk_seed, 0, p.hp, &mut adrs);
+
+    let mut sk = Vec::with_capacity(4 * p.n);
+    sk.extend_from_slice(sk_seed);
+    sk.extend_from_slice(sk_prf);
+    sk.extend_from_slice(pk_seed);
+    sk.extend_from_slice(&root);
+    let secret_key = SecretKey { parameter_set, data: sk };
+    Ok((secret_key.public_key(), secret_key))
+}
+
+/// Rebuild a legacy SPHINCS+ key under an n = 32 FIPS 205 parameter set
+///
+/// The seeds are kept; PK.root is recomputed, so the returned public key
+/// replaces the legacy one.
+pub fn from_legacy_sphincs(
+    parameter_set: ParameterSet,
+    legacy: &sphincs_plus::SecretKey,
+) -> Result<(PublicKey, SecretKey), SlhDsaError> {
+    if parameter_set.params().n != sphincs_plus::SPHINCS_N {
+        return Err(SlhDsaError::UnsupportedLegacyParameterSet);
+    }
+    keygen_from_seeds(parameter_set, &legacy.seed, &legacy.prf, &legacy.public_seed)
+}
+
+/// Sign with hedged randomness (slh_sign)
+///
+/// `context` is the FIPS 205 context string (at most 255 bytes).
+pub fn sign(message: &[u8], context: &[u8], secret_key: &SecretKey) -> Result<Signature, SlhDsaError> {
+    let mut addrnd = vec![0u8; secret_key.parameter_set.params().n];
+    getrandom::getrandom(&mut addrnd).map_err(|_| SlhDsaError::SigningFailed)?;
+    sign_with_randomness(message, context, secret_key, Some(&addrnd))
+}
+
+/// Sign deterministically (opt_rand = PK.seed)
+pub fn sign_deterministic(message: &[u8], context: &[u8], secret_key: &SecretKey) -> Result<Signature, SlhDsaError> {
+    sign_with_randomness(message, context, secret_key, None)
+}
+
+fn sign_with_randomness(
+    message: &[u8],
+    context: &[u8],
+    secret_key: &SecretKey,
+    addrnd: Option<&[u8]>,
+) -> Result<Signature, SlhDsaError> {
+    let parameter_set = secret_key.parameter_set;
+    let p = parameter_set.params();
+    if secret_key.data.len() != parameter_set.secret_key_size() {
+        return Err(SlhDsaError::InvalidKeySize);
+    }
+    let message_prime = format_message(message, context)?;
+    let sk = &secret_key.data;
+    let (sk_seed, sk_prf, pk_seed, pk_root) = (&sk[..p.n], &sk[p.n..2 * p.n], &sk[2 * p.n..3 * p.n], &sk[3 * p.n..]);
+    let ctx = Context { p, pk_seed };
+
+    // R = PRF_msg(SK.prf, opt_rand, M)
+    let randomizer = shake256(&[sk_prf, addrnd.unwrap_or(pk_seed), &message_prime], p.n);
+    let (md, idx_tree, idx_leaf) = ctx.digest(&randomizer, pk_root, &message_prime);
+
+    let mut adrs = Adrs::default();
+    adrs.set_tree(idx_tree);
+    adrs.set_type_and_clear(AdrsType::ForsTree);
+    adrs.set_keypair(idx_leaf);
+    let sig_fors = ctx.fors_sign(&md, sk_seed, &mut adrs);
+    let pk_fors = ctx.fors_pk_from_sig(&sig_fors, &md, &mut adrs);
+
+    let mut data = Vec::with_capacity(parameter_set.signature_size());
+    data.extend_from_slice(&randomizer);
+    data.extend_from_slice(&sig_fors);
+    data.extend_from_slice(&ctx.ht_sign(&pk_fors, sk_seed, idx_tree, idx_leaf));
+    Ok(Signature { parameter_set, data })
+}
+
+/// Verify a signature (slh_verify)
+pub fn verify(
+    message: &[u8],
+    context: &[u8],
+    signature: &Signature,
+    public_key: &PublicKey,
+) -> Result<bool, SlhDsaError> {
+    let parameter_set = public_key.parameter_set;
+    let p = parameter_set.params();
+    if public_key.data.len() != parameter_set.public_key_size() {
+        return Err(SlhDsaError::InvalidKeySize);
+    }
+    if signature.parameter_set != parameter_set || signature.data.len() != parameter_set.signature_size() {
+        return Err(SlhDsaError::InvalidSignatureSize);
+    }
+    let message_prime = format_message(message, context)?;
+    let (pk_seed, pk_root) = public_key.data.split_at(p.n);
+    let ctx = Context { p, pk_seed };
+
+    let sig = &signature.data;
+    let fors_end = p.n * (1 + p.k * (1 + p.a));
+    let (md, idx_tree, idx_leaf) = ctx.digest(&sig[..p.n], pk_root, &message_prime);
+
+    let mut adrs = Adrs::default();
+    adrs.set_tree(idx_tree);
+    adrs.set_type_and_clear(AdrsType::ForsTree);
+    adrs.set_keypair(idx_leaf);
+    let pk_fors = ctx.fors_pk_from_sig(&sig[p.n..fors_end], &md, &mut adrs);
+    Ok(ctx.ht_verify(&pk_fors, &sig[fors_end..], idx_tree, idx_leaf, pk_root))
+}
+
+/// M' = 0 || |ctx| || ctx || M (pure SLH-DSA)
+fn format_message(message: &[u8], context: &[u8]) -> Result<Vec<u8>, SlhDsaError> {
+    if context.len() > 255 {
+        return Err(SlhDsaError::ContextTooLong);
+    }
+    let mut message_prime = Vec::with_capacity(2 + context.len() + message.len());
+    message_prime.push(0);
+    message_prime.push(context.len() as u8);
+    message_prime.extend_from_slice(context);
+    message_prime.extend_from_slice(message);
+    Ok(message_prime)
+}
+
+fn shake256(parts: &[&[u8]], len: usize) -> Vec<u8> {
+    let mut hasher = Shake256::default();
+    parts.iter().for_each(|part| hasher.update(part));
+    let mut out = vec![0u8; len];
+    hasher.finalize_xof().read(&mut out);
+    out
+}
+
+/// Big-endian integer from at most 8 bytes
+fn to_int(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64)
+}
+
+/// base_2b: `out_len` big-endian `b`-bit digits of `x`
+fn base_2b(x: &[u8], b: usize, out_len: usize) -> Vec<u32> {
+    let mut digits = Vec::with_capacity(out_len);
+    let mut input = 0;
+    let mut bits = 0;
+    let mut total = 0u64;
+    for _ in 0..out_len {
+        while bits < b {
+            total = (total << 8) | x[input] as u64;
+            input += 1;
+            bits += 8;
+        }
+        bits -= b;
+        digits.push(((total >> bits) & ((1 << b) - 1)) as u32);
+    }
+    digits
+}
+
+#[derive(Clone, Copy)]
+enum AdrsType {
+    WotsHash = 0,
+    WotsPk = 1,
+    Tree = 2,
+    ForsTree = 3,
+    ForsRoots = 4,
+    WotsPrf = 5,
+    ForsPrf = 6,
+}
+
+/// 32-byte hash address (FIPS 205 Section 4.2)
+#[derive(Clone, Copy, Default)]
+struct Adrs([u8; 32]);
+
+impl Adrs {
+    fn set_word(&mut self, offset: usize, value: u32) {
+        self.0[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+    }
+
+    fn word(&self, offset: usize) -> u32 {
+        u32::from_be_bytes([self.0[offset], self.0[offset + 1], self.0[offset + 2], self.0[offset + 3]])
+    }
+
+    fn set_layer(&mut self, layer: u32) {
+        self.set_word(0, layer);
+    }
+
+    fn set_tree(&mut self, tree: u64) {
+        self.0[4..8].fill(0);
+        self.0[8..16].copy_from_slice(&tree.to_be_bytes());
+    }
+
+    fn set_type_and_clear(&mut self, adrs_type: AdrsType) {
+        self.set_word(16, adrs_type as u32);
+        self.0[20..32].fill(0);
+    }
+
+    fn set_keypair(&mut self, keypair: u32) {
+        self.set_word(20, keypair);
+    }
+
+    fn keypair(&self) -> u32 {
+        self.word(20)
+    }
+
+    fn set_chain(&mut self, chain: u32) {
+        self.set_word(24, chain);
+    }
+
+    fn set_tree_height(&mut self, height: u32) {
+        self.set_word(24, height);
+    }
+
+    fn set_hash(&mut self, hash: u32) {
+        self.set_word(28, hash);
+    }
+
+    fn set_tree_index(&mut self, index: u32) {
+        self.set_word(28, index);
+    }
+
+    fn tree_index(&self) -> u32 {
+        self.word(28)
+    }
+}
+
+/// Parameter set and PK.seed shared by every tweakable hash call
+struct Context<'a> {
+    p: Params,
+    pk_seed: &'a [u8],
+}
+
+impl Context<'_> {
+    /// F, H, T_l and PRF: SHAKE256(PK.seed || ADRS || inputs, 8n)
+    fn hash(&self, adrs: &Adrs, inputs: &[&[u8]]) -> Vec<u8> {
+        let mut hasher = Shake256::default();
+        hasher.update(self.pk_seed);
+        hasher.update(&adrs.0);
+        inputs.iter().for_each(|input| hasher.update(input));
+        let mut out = vec![0u8; self.p.n];
+        hasher.finalize_xof().read(&mut out);
+        out
+    }
+
+    /// H_msg, split into the FORS message digest, tree index and leaf index
+    fn digest(&self, randomizer: &[u8], pk_root: &[u8], message_prime: &[u8]) -> (Vec<u8>, u64, u32) {
+        let p = &self.p;
+        let digest = shake256(&[randomizer, self.pk_seed, pk_root, message_prime], p.m);
+        let md_len = (p.k * p.a).div_ceil(8);
+        let tree_bits = p.h - p.h / p.d;
+        let tree_len = tree_bits.div_ceil(8);
+        let leaf_bits = p.h / p.d;
+        let leaf_len = leaf_bits.div_ceil(8);
+
+        let idx_tree = to_int(&digest[md_len..md_len + tree_len]) & (u64::MAX >> (64 - tree_bits));
+        let idx_leaf = to_int(&digest[md_len + tree_len..md_len + tree_len + leaf_len]) & ((1 << leaf_bits) - 1);
+        (digest[..md_len].to_vec(), idx_tree, idx_leaf as u32)
+    }
+
+    fn chain(&self, x: &[u8], start: u32, steps: u32, adrs: &mut Adrs) -> Vec<u8> {
+        let mut tmp = x.to_vec();
+        for j in start..start + steps {
+            adrs.set_hash(j);
+            tmp = self.hash(adrs, &[&tmp]);
+        }
+        tmp
+    }
+
+    /// Message digits followed by checksum digits
+    fn wots_digits(&self, message: &[u8]) -> Vec<u32> {
+        let len1 = 2 * self.p.n;
+        let mut digits = base_2b(message, LG_W, len1);
+        let checksum: u32 = digits.iter().map(|d| W - 1 - d).sum::<u32>() << 4;
+        digits.extend(base_2b(&(checksum as u16).to_be_bytes(), LG_W, LEN2));
+        digits
+    }
+
+    fn wots_secret(&self, sk_seed: &[u8], adrs: &Adrs, chain: u32) -> Vec<u8> {
+        let mut sk_adrs = *adrs;
+        sk_adrs.set_type_and_clear(AdrsType::WotsPrf);
+        sk_adrs.set_keypair(adrs.keypair());
+        sk_adrs.set_chain(chain);
+        self.hash(&sk_adrs, &[sk_seed])
+    }
+
+    fn wots_compress(&self, chains: &[Vec<u8>], adrs: &Adrs) -> Vec<u8> {
+        let mut pk_adrs = *adrs;
+        pk_adrs.set_type_and_clear(AdrsType::WotsPk);
+        pk_adrs.set_keypair(adrs.keypair());
+        let inputs: Vec<&[u8]> = chains.iter().map(Vec::as_slice).collect();
+        self.hash(&pk_adrs, &inputs)
+    }
+
+    fn wots_pk_gen(&self, sk_seed: &[u8], adrs: &mut Adrs) -> Vec<u8> {
+        let chains: Vec<Vec<u8>> = (0..self.p.len() as u32)
+            .map(|i| {
+                let sk = self.wots_secret(sk_seed, adrs, i);
+                adrs.set_chain(i);
+                self.chain(&sk, 0, W - 1, adrs)
+            })
+            .collect();
+        self.wots_compress(&chains, adrs)
+    }
+
+    fn wots_sign(&self, message: &[u8], sk_seed: &[u8], adrs: &mut Adrs) -> Vec<u8> {
+        let digits = self.wots_digits(message);
+        let mut sig = Vec::with_capacity(self.p.len() * self.p.n);
+        for (i, &digit) in digits.iter().enumerate() {
+            let sk = self.wots_secret(sk_seed, adrs, i as u32);
+            adrs.set_chain(i as u32);
+            sig.extend(self.chain(&sk, 0, digit, adrs));
+        }
+        sig
+    }
+
+    fn wots_pk_from_sig(&self, sig: &[u8], message: &[u8], adrs: &mut Adrs) -> Vec<u8> {
+        let digits = self.wots_digits(message);
+        let chains: Vec<Vec<u8>> = sig
+            .chunks(self.p.n)
+            .zip(&digits)
+            .enumerate()
+            .map(|(i, (chunk, &digit))| {
+                adrs.set_chain(i as u32);
+                self.chain(chunk, digit, W - 1 - digit, adrs)
+            })
+            .collect();
+        self.wots_compress(&chains, adrs)
+    }
+
+    fn xmss_node(&self, sk_seed: &[u8], i: u32, z: usize, adrs: &mut Adrs) -> Vec<u8> {
+        if z == 0 {
+            adrs.set_type_and_clear(AdrsType::WotsHash);
+            adrs.set_keypair(i);
+            return self.wots_pk_gen(sk_seed, adrs);
+        }
+        let left = self.xmss_node(sk_seed, 2 * i, z - 1, adrs);
+        let right = self.xmss_node(sk_seed, 2 * i + 1, z - 1, adrs);
+        adrs.set_type_and_clear(AdrsType::Tree);
+        adrs.set_tree_height(z as u32);
+        adrs.set_tree_index(i);
+        self.hash(adrs, &[&left, &right])
+    }
+
+    fn xmss_sign(&self, message: &[u8], sk_seed: &[u8], idx: u32, adrs: &mut Adrs) -> Vec<u8> {
+        let auth: Vec<Vec<u8>> = (0..self.p.hp)
+            .map(|j| self.xmss_node(sk_seed, (idx >> j) ^ 1, j, adrs))
+            .collect();
+        adrs.set_type_and_clear(AdrsType::WotsHash);
+        adrs.set_keypair(idx);
+        let mut sig = self.wots_sign(message, sk_seed, adrs);
+        auth.iter().for_each(|node| sig.extend_from_slice(node));
+        sig
+    }
+
+    fn xmss_pk_from_sig(&self, idx: u32, sig: &[u8], message: &[u8], adrs: &mut Adrs) -> Vec<u8> {
+        let (wots_sig, auth) = sig.split_at(self.p.len() * self.p.n);
+        adrs.set_type_and_clear(AdrsType::WotsHash);
+        adrs.set_keypair(idx);
+        let mut node = self.wots_pk_from_sig(wots_sig, message, adrs);
+
+        adrs.set_type_and_clear(AdrsType::Tree);
+        adrs.set_tree_index(idx);
+        for (k, sibling) in auth.chunks(self.p.n).enumerate() {
+            adrs.set_tree_height(k as u32 + 1);
+            if (idx >> k) & 1 == 0 {
+                adrs.set_tree_index(adrs.tree_index() / 2);
+                node = self.hash(adrs, &[&node, sibling]);
+            } else {
+                adrs.set_tree_index((adrs.tree_index() - 1) / 2);
+                node = self.hash(adrs, &[sibling, &node]);
+            }
+        }
+        node
+    }
+
+    fn ht_sign(&self, message: &[u8], sk_seed: &[u8], mut idx_tree: u64, mut idx_leaf: u32) -> Vec<u8> {
+        let mut adrs = Adrs::default();
+        adrs.set_tree(idx_tree);
+        let mut sig_tmp = self.xmss_sign(message, sk_seed, idx_leaf, &mut adrs);
+        let mut sig = sig_tmp.clone();
+        let mut root = self.xmss_pk_from_sig(idx_leaf, &sig_tmp, message, &mut adrs);
+        for j in 1..self.p.d {
+            idx_leaf = (idx_tree & ((1 << self.p.hp) - 1)) as u32;
+            idx_tree >>= self.p.hp;
+            adrs.set_layer(j as u32);
+            adrs.set_tree(idx_tree);
+            sig_tmp = self.xmss_sign(&root, sk_seed, idx_leaf, &mut adrs);
+            sig.extend_from_slice(&sig_tmp);
+            if j < self.p.d - 1 {
+                root = self.xmss_pk_from_sig(idx_leaf, &sig_tmp, &root, &mut adrs);
+            }
+        }
+        sig
+    }
+
+    fn ht_verify(&self, message: &[u8], sig: &[u8], mut idx_tree: u64, mut idx_leaf: u32, pk_root: &[u8]) -> bool {
+        let xmss_len = (self.p.len() + self.p.hp) * self.p.n;
+        let mut layers = sig.chunks(xmss_len);
+        let mut adrs = Adrs::default();
+        adrs.set_tree(idx_tree);
+        let Some(first) = layers.next() else {
+            return false;
+        };
+        let mut node = self.xmss_pk_from_sig(idx_leaf, first, message, &mut adrs);
+        for (j, layer) in layers.enumerate() {
+            idx_leaf = (idx_tree & ((1 << self.p.hp) - 1)) as u32;
+            idx_tree >>= self.p.hp;
+            adrs.set_layer(j as u32 + 1);
+            adrs.set_tree(idx_tree);
+            node = self.xmss_pk_from_sig(idx_leaf, layer, &node, &mut adrs);
+        }
+        node == pk_root
+    }
+
+    fn fors_secret(&self, sk_seed: &[u8], adrs: &Adrs, idx: u32) -> Vec<u8> {
+        let mut sk_adrs = *adrs;
+        sk_adrs.set_type_and_clear(AdrsType::ForsPrf);
+        sk_adrs.set_keypair(adrs.keypair());
+        sk_adrs.set_tree_index(idx);
+        self.hash(&sk_adrs, &[sk_seed])
+    }
+
+    fn fors_node(&self, sk_seed: &[u8], i: u32, z: usize, adrs: &mut Adrs) -> Vec<u8> {
+        if z == 0 {
+            let sk = self.fors_secret(sk_seed, adrs, i);
+            adrs.set_tree_height(0);
+            adrs.set_tree_index(i);
+            return self.hash(adrs, &[&sk]);
+        }
+        let left = self.fors_node(sk_seed, 2 * i, z - 1, adrs);
+        let right = self.fors_node(sk_seed, 2 * i + 1, z - 1, adrs);
+        adrs.set_tree_height(z as u32);
+        adrs.set_tree_index(i);
+        self.hash(adrs, &[&left, &right])
+    }
+
+    fn fors_sign(&self, md: &[u8], sk_seed: &[u8], adrs: &mut Adrs) -> Vec<u8> {
+        let p = &self.p;
+        let mut sig = Vec::with_capacity(p.k * (p.a + 1) * p.n);
+        for (i, &index) in base_2b(md, p.a, p.k).iter().enumerate() {
+            let tree_offset = (i as u32) << p.a;
+            sig.extend(self.fors_secret(sk_seed, adrs, tree_offset + index));
+            for j in 0..p.a {
+                let sibling = (index >> j) ^ 1;
+                sig.extend(self.fors_node(sk_seed, ((i as u32) << (p.a - j)) + sibling, j, adrs));
+            }
+        }
+        sig
+    }
+
+    fn fors_pk_from_sig(&self, sig: &[u8], md: &[u8], adrs: &mut Adrs) -> Vec<u8> {
+        let p = &self.p;
+        let roots: Vec<Vec<u8>> = base_2b(md, p.a, p.k)
+            .iter()
+            .zip(sig.chunks((p.a + 1) * p.n))
+            .enumerate()
+            .map(|(i, (&index, tree_sig))| {
+                let (sk, auth) = tree_sig.split_at(p.n);
+                adrs.set_tree_height(0);
+                adrs.set_tree_index(((i as u32) << p.a) + index);
+                let mut node = self.hash(adrs, &[sk]);
+                for (j, sibling) in auth.chunks(p.n).enumerate() {
+                    adrs.set_tree_height(j as u32 + 1);
+                    if (index >> j) & 1 == 0 {
+                        adrs.set_tree_index(adrs.tree_index() / 2);
+                        node = self.hash(adrs, &[&node, sibling]);
+                    } else {
+                        adrs.set_tree_index((adrs.tree_index() - 1) / 2);
+                        node = self.hash(adrs, &[sibling, &node]);
+                    }
+                }
+                node
+            })
+            .collect();
+
+        let mut roots_adrs = *adrs;
+        roots_adrs.set_type_and_clear(AdrsType::ForsRoots);
+        roots_adrs.set_keypair(adrs.keypair());
+        let inputs: Vec<&[u8]> = roots.iter().map(Vec::as_slice).collect();
+        self.hash(&roots_adrs, &inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fips205_sizes() {
+        let all = [
+            ParameterSet::Shake128s,
+            ParameterSet::Shake128f,
+            ParameterSet::Shake192s,
+            ParameterSet::Shake192f,
+            ParameterSet::Shake256s,
+            ParameterSet::Shake256f,
+        ];
+        let sizes: Vec<_> = all.iter().map(|ps| (ps.public_key_size(), ps.signature_size())).collect();
+        assert_eq!(
+            sizes,
+            [(32, 7856), (32, 17088), (48, 16224), (48, 35664), (64, 29792), (64, 49856)]
+        );
+    }
+
+    #[test]
+    fn test_sign_verify_round_trip() {
+        let ps = ParameterSet::Shake128f;
+        let (pk, sk) = keygen_from_seeds(ps, &[1u8; 16], &[2u8; 16], &[3u8; 16]).unwrap();
+        assert_eq!(sk.public_key(), pk);
+
+        let sig = sign(b"QRATUM SLH-DSA", b"ctx", &sk).unwrap();
+        assert_eq!(sig.data.len(), ps.signature_size());
+        assert!(verify(b"QRATUM SLH-DSA", b"ctx", &sig, &pk).unwrap());
+        assert!(!verify(b"QRATUM SLH-DSA", b"", &sig, &pk).unwrap());
+
+        let mut tampered = sig.clone();
+        let last = tampered.data.len() - 1;
+        tampered.data[last] ^= 1;
+        assert!(!verify(b"QRATUM SLH-DSA", b"ctx", &tampered, &pk).unwrap());
+
+        assert_eq!(sign_deterministic(b"m", b"", &sk), sign_deterministic(b"m", b"", &sk));
+        assert_eq!(
+            verify(b"m", b"", &Signature { parameter_set: ps, data: vec![0; 100] }, &pk),
+            Err(SlhDsaError::InvalidSignatureSize)
+        );
+    }
+
+    fn hex(s: &str) -> Vec<u8> {
+        if s == "-" {
+            return Vec::new();
+        }
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    /// keyGen, sigGen and sigVer vectors in the ACVP test modes, produced
+    /// by an independent FIPS 205 implementation (not ACVP server files);
+    /// see testdata/slh_dsa_interop.txt
+    #[test]
+    fn test_interop_vectors() {
+        use sha3::{Digest, Sha3_256};
+
+        let vectors = include_str!("testdata/slh_dsa_interop.txt");
+        let mut keys = std::collections::HashMap::new();
+        let mut checked = [0; 3];
+        for line in vectors.lines().filter(|l| !l.starts_with('#') && !l.trim().is_empty()) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let ps = match fields[1] {
+                "SLH-DSA-SHAKE-128s" => ParameterSet::Shake128s,
+                "SLH-DSA-SHAKE-128f" => ParameterSet::Shake128f,
+                "SLH-DSA-SHAKE-192s" => ParameterSet::Shake192s,
+                "SLH-DSA-SHAKE-192f" => ParameterSet::Shake192f,
+                "SLH-DSA-SHAKE-256s" => ParameterSet::Shake256s,
+                "SLH-DSA-SHAKE-256f" => ParameterSet::Shake256f,
+                other => panic!("unknown parameter set {other}"),
+            };
+            // Key generation for the small (s) sets is slow; derive each key once
+            let (pk, sk) = keys.entry(fields[2]).or_insert_with(|| {
+                let seeds = hex(fields[2]);
+                let n = seeds.len() / 3;
+                keygen_from_seeds(ps, &seeds[..n], &seeds[n..2 * n], &seeds[2 * n..]).unwrap()
+            });
+            match fields[0] {
+                "keyGen" => {
+                    assert_eq!(pk.data, hex(fields[3]), "{} keyGen", ps.name());
+                    checked[0] += 1;
+                }
+                "sigGen" => {
+                    let sig = sign_deterministic(&hex(fields[3]), &hex(fields[4]), sk).unwrap();
+                    assert_eq!(Sha3_256::digest(&sig.data).to_vec(), hex(fields[5]), "{} sigGen", ps.name());
+                    checked[1] += 1;
+                }
+                "sigVer" => {
+                    let sig = Signature::from_bytes(ps, &hex(fields[5])).unwrap();
+                    let valid = verify(&hex(fields[3]), &hex(fields[4]), &sig, pk).unwrap();
+                    assert_eq!(valid, fields[6] == "true", "{} sigVer", ps.name());
+                    checked[2] += 1;
+                }
+                other => panic!("unknown test mode {other}"),
+            }
+        }
+        assert_eq!(checked, [6, 3, 4]);
+    }
+
+    #[test]
+    fn test_legacy_sphincs_migration() {
+        let (_, legacy) = sphincs_plus::generate_keypair().unwrap();
+        let (pk, sk) = from_legacy_sphincs(ParameterSet::Shake256f, &legacy).unwrap();
+        assert_eq!(&sk.data[..32], &legacy.seed);
+        assert_eq!(&pk.data[..32], &legacy.public_seed);
+        assert_ne!(&pk.data[32..], &legacy.root);
+
+        assert!(matches!(
+            from_legacy_sphincs(ParameterSet::Shake128f, &legacy),
+            Err(SlhDsaError::UnsupportedLegacyParameterSet)
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+//! ACVP vector runner for ML-DSA (FIPS 204) and SLH-DSA (FIPS 205)
+//!
+//! Reads `internalProjection.json` files laid out as in the ACVP-Server
+//! repository (`gen-val/json-files/<algorithm>-<mode>-<revision>/`), so the
+//! server's own files can replace the ones in `testdata/acvp` unchanged.
+//! Groups this crate does not implement (pre-hash, internal interface,
+//! external mu, hedged sigGen, SHA2 parameter sets) are skipped.
+
+use qratum_crypto_pqc::{ml_dsa, slh_dsa};
+use serde_json::Value;
+
+fn load(name: &str) -> Value {
+    let path = format!("{}/testdata/acvp/{}/internalProjection.json", env!("CARGO_MANIFEST_DIR"), name);
+    let json = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    serde_json::from_str(&json).unwrap_or_else(|e| panic!("{path}: {e}"))
+}
+
+fn hex(test: &Value, field: &str) -> Vec<u8> {
+    let s = test[field].as_str().unwrap_or_else(|| panic!("tcId {}: missing {field}", test["tcId"]));
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+/// Pure signatures over the external interface, the only mode implemented
+fn supported_interface(group: &Value) -> bool {
+    group["preHash"].as_str().unwrap_or("pure") == "pure"
+        && group["signatureInterface"].as_str().unwrap_or("external") == "external"
+        && !group["externalMu"].as_bool().unwrap_or(false)
+}
+
+fn ml_dsa_set(name: &str) -> Option<ml_dsa::ParameterSet> {
+    match name {
+        "ML-DSA-44" => Some(ml_dsa::ParameterSet::MlDsa44),
+        "ML-DSA-65" => Some(ml_dsa::ParameterSet::MlDsa65),
+        "ML-DSA-87" => Some(ml_dsa::ParameterSet::MlDsa87),
+        _ => None,
+    }
+}
+
+fn slh_dsa_set(name: &str) -> Option<slh_dsa::ParameterSet> {
+    match name {
+        "SLH-DSA-SHAKE-128s" => Some(slh_dsa::ParameterSet::Shake128s),
+        "SLH-DSA-SHAKE-128f" => Some(slh_dsa::ParameterSet::Shake128f),
+        "SLH-DSA-SHAKE-192s" => Some(slh_dsa::ParameterSet::Shake192s),
+        "SLH-DSA-SHAKE-192f" => Some(slh_dsa::ParameterSet::Shake192f),
+        "SLH-DSA-SHAKE-256s" => Some(slh_dsa::ParameterSet::Shake256s),
+        "SLH-DSA-SHAKE-256f" => Some(slh_dsa::ParameterSet::Shake256f),
+        _ => None,
+    }
+}
+
+/// Run every supported test case, returning how many ran
+fn run<P: Copy>(
+    name: &str,
+    parameter_set: fn(&str) -> Option<P>,
+    group_filter: fn(&Value) -> bool,
+    mut check: impl FnMut(P, &Value),
+) -> usize {
+    let vectors = load(name);
+    let mut ran = 0;
+    for group in vectors["testGroups"].as_array().unwrap() {
+        let Some(ps) = group["parameterSet"].as_str().and_then(parameter_set) else { continue };
+        if !group_filter(group) {
+            continue;
+        }
+        for test in group["tests"].as_array().unwrap() {
+            check(ps, test);
+            ran += 1;
+        }
+    }
+    ran
+}
+
+#[test]
+fn test_ml_dsa_keygen() {
+    let ran = run("ML-DSA-keyGen-FIPS204", ml_dsa_set, |_| true, |ps, test| {
+        let seed: [u8; 32] = hex(test, "seed").try_into().unwrap();
+        let (pk, sk) = ml_dsa::keygen_from_seed(ps, &seed);
+        assert_eq!(pk.data, hex(test, "pk"), "tcId {}", test["tcId"]);
+        assert_eq!(sk.data, hex(test, "sk"), "tcId {}", test["tcId"]);
+    });
+    assert!(ran > 0);
+}
+
+#[test]
+fn test_ml_dsa_siggen() {
+    let deterministic = |group: &Value| supported_interface(group) && group["deterministic"] == true;
+    let ran = run("ML-DSA-sigGen-FIPS204", ml_dsa_set, deterministic, |ps, test| {
+        let sk = ml_dsa::SecretKey::from_bytes(ps, &hex(test, "sk")).unwrap();
+        let signature = ml_dsa::sign_deterministic(&hex(test, "message"), &hex(test, "context"), &sk).unwrap();
+        assert_eq!(signature.data, hex(test, "signature"), "tcId {}", test["tcId"]);
+    });
+    assert!(ran > 0);
+}
+
+#[test]
+fn test_ml_dsa_sigver() {
+    let ran = run("ML-DSA-sigVer-FIPS204", ml_dsa_set, supported_interface, |ps, test| {
+        let pk = ml_dsa::PublicKey::from_bytes(ps, &hex(test, "pk")).unwrap();
+        let valid = ml_dsa::Signature::from_bytes(ps, &hex(test, "signature"))
+            .and_then(|signature| ml_dsa::verify(&hex(test, "message"), &hex(test, "context"), &signature, &pk))
+            .unwrap_or(false);
+        assert_eq!(valid, test["testPassed"] == true, "tcId {} ({})", test["tcId"], test["reason"]);
+    });
+    assert!(ran > 0);
+}
+
+#[test]
+fn test_slh_dsa_keygen() {
+    let ran = run("SLH-DSA-keyGen-FIPS205", slh_dsa_set, |_| true, |ps, test| {
+        let (pk, sk) =
+            slh_dsa::keygen_from_seeds(ps, &hex(test, "skSeed"), &hex(test, "skPrf"), &hex(test, "pkSeed")).unwrap();
+        assert_eq!(pk.data, hex(test, "pk"), "tcId {}", test["tcId"]);
+        assert_eq!(sk.data, hex(test, "sk"), "tcId {}", test["tcId"]);
+    });
+    assert!(ran > 0);
+}
+
+#[test]
+fn test_slh_dsa_siggen() {
+    let deterministic = |group: &Value| supported_interface(group) && group["deterministic"] == true;
+    let ran = run("SLH-DSA-sigGen-FIPS205", slh_dsa_set, deterministic, |ps, test| {
+        let sk = slh_dsa::SecretKey::from_bytes(ps, &hex(test, "sk")).unwrap();
+        let signature = slh_dsa::sign_deterministic(&hex(test, "message"), &hex(test, "context"), &sk).unwrap();
+        assert_eq!(signature.data, hex(test, "signature"), "tcId {}", test["tcId"]);
+    });
+    assert!(ran > 0);
+}
+
+#[test]
+fn test_slh_dsa_sigver() {
+    let ran = run("SLH-DSA-sigVer-FIPS205", slh_dsa_set, supported_interface, |ps, test| {
+        let pk = slh_dsa::PublicKey::from_bytes(ps, &hex(test, "pk")).unwrap();
+        let valid = slh_dsa::Signature::from_bytes(ps, &hex(test, "signature"))
+            .and_then(|signature| slh_dsa::verify(&hex(test, "message"), &hex(test, "context"), &signature, &pk))
+            .unwrap_or(false);
+        assert_eq!(valid, test["testPassed"] == true, "tcId {} ({})", test["tcId"], test["reason"]);
+    });
+    assert!(ran > 0);
+}
@@ -0,0 +1,153 @@
+//! Deterministic DRBG for Seed-Controlled Simulation
+//!
+//! The HMAC-DRBG construction of `HmacDrbg`, instantiated from a `u64`
+//! seed instead of an entropy pool. The same seed always yields the same
+//! stream, so quantum simulation, DCGE, and tests can share one
+//! `RandomGenerator` abstraction with the secure generators without
+//! breaking the determinism invariant.
+//!
+//! Uses `core` only: available in no_std builds with the `deterministic`
+//! feature.
+//!
+//! NOT FOR SECRETS: a 64-bit seed is guessable. `is_deterministic()`
+//! returns `true` so key-generation paths can refuse this generator.
+//!
+//! The stream depends on request sizes as well as the seed: each request
+//! ends with a state update, as in SP 800-90A, so two 32-byte requests
+//! differ from one 64-byte request.
+
+use crate::error::DrbgError;
+use crate::hmac::{hmac_sha3_512, hmac_sha3_512_parts, OUTPUT_LENGTH};
+use crate::RandomGenerator;
+
+/// Domain separator mixed into the seed material
+const DETERMINISTIC_DOMAIN: &[u8] = b"QRATUM-DETERMINISTIC-DRBG-v1";
+
+/// Seed-controlled HMAC-SHA3-512 DRBG
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeterministicDrbg {
+    key: [u8; OUTPUT_LENGTH],
+    value: [u8; OUTPUT_LENGTH],
+    seed: u64,
+    requests: u64,
+}
+
+impl DeterministicDrbg {
+    /// Instantiate from `seed`
+    ///
+    /// Per NIST SP 800-90A Section 10.1.2.3, with the seed and domain
+    /// separator as the seed material.
+    pub fn new(seed: u64) -> Self {
+        let mut drbg = Self {
+            key: [0u8; OUTPUT_LENGTH],
+            value: [0x01; OUTPUT_LENGTH],
+            seed,
+            requests: 0,
+        };
+        let mut material = [0u8; DETERMINISTIC_DOMAIN.len() + 8];
+        material[..DETERMINISTIC_DOMAIN.len()].copy_from_slice(DETERMINISTIC_DOMAIN);
+        material[DETERMINISTIC_DOMAIN.len()..].copy_from_slice(&seed.to_le_bytes());
+        drbg.update(&material);
+        drbg
+    }
+
+    /// Seed this generator was instantiated from
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Requests served so far
+    pub fn requests(&self) -> u64 {
+        self.requests
+    }
+
+    /// Fill `output`; never fails
+    pub fn generate(&mut self, output: &mut [u8]) {
+        for chunk in output.chunks_mut(OUTPUT_LENGTH) {
+            self.value = hmac_sha3_512(&self.key, &self.value);
+            chunk.copy_from_slice(&self.value[..chunk.len()]);
+        }
+        self.update(&[]);
+        self.requests += 1;
+    }
+
+    /// Next `u64` of the stream (one 8-byte request)
+    pub fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.generate(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Update internal state (HMAC_DRBG_Update)
+    ///
+    /// Per NIST SP 800-90A Section 10.1.2.2
+    fn update(&mut self, provided_data: &[u8]) {
+        // K = HMAC(K, V || 0x00 || provided_data); V = HMAC(K, V)
+        self.key = hmac_sha3_512_parts(&self.key, &[&self.value, &[0x00], provided_data]);
+        self.value = hmac_sha3_512(&self.key, &self.value);
+        
+        if !provided_data.is_empty() {
+            // K = HMAC(K, V || 0x01 || provided_data); V = HMAC(K, V)
+            self.key = hmac_sha3_512_parts(&self.key, &[&self.value, &[0x01], provided_data]);
+            self.value = hmac_sha3_512(&self.key, &self.value);
+        }
+    }
+}
+
+impl RandomGenerator for DeterministicDrbg {
+    fn fill_bytes(&mut self, output: &mut [u8]) -> Result<(), DrbgError> {
+        self.generate(output);
+        Ok(())
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Code written once against the trait
+    fn shuffle<R: RandomGenerator>(rng: &mut R, items: &mut [u32]) {
+        for i in (1..items.len()).rev() {
+            let mut bytes = [0u8; 8];
+            rng.fill_bytes(&mut bytes).unwrap();
+            items.swap(i, (u64::from_le_bytes(bytes) % (i as u64 + 1)) as usize);
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_stream() {
+        let mut a = DeterministicDrbg::new(42);
+        let mut b = DeterministicDrbg::new(42);
+        let mut c = DeterministicDrbg::new(43);
+        let (mut x, mut y, mut z) = ([0u8; 100], [0u8; 100], [0u8; 100]);
+        a.generate(&mut x);
+        b.generate(&mut y);
+        c.generate(&mut z);
+        assert_eq!(x, y);
+        assert_ne!(x, z);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_eq!((a.seed(), a.requests()), (42, 2));
+
+        // Request sizes are part of the stream
+        let mut split = DeterministicDrbg::new(42);
+        let mut first = [0u8; 50];
+        split.generate(&mut first);
+        assert_eq!(first, x[..50]);
+        let mut second = [0u8; 50];
+        split.generate(&mut second);
+        assert_ne!(second, x[50..]);
+
+        // Regression: the stream for a given seed must never change
+        assert_eq!(DeterministicDrbg::new(0).next_u64(), 0xdeb9_e18b_6d82_69c1);
+        let mut items: [u32; 8] = core::array::from_fn(|i| i as u32);
+        shuffle(&mut DeterministicDrbg::new(7), &mut items);
+        let mut again: [u32; 8] = core::array::from_fn(|i| i as u32);
+        shuffle(&mut DeterministicDrbg::new(7), &mut again);
+        assert_eq!(items, again);
+        assert!(DeterministicDrbg::new(7).is_deterministic());
+    }
+}
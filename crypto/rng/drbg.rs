@@ -8,12 +8,17 @@
 //! - SHA3-512 based for post-quantum security margin
 //! - Entropy pooling from multiple sources
 //! - Prediction resistance via reseeding
+//! - Automatic reseeding from registered entropy sources (`ReseedingDrbg`)
 //! - Zeroization on drop
+//! - Key and value held in guarded, locked-in-RAM pages
+//! - SP 800-90B startup and continuous health tests on raw entropy samples
 
 use sha3::{Sha3_512, Digest};
 use zeroize::{Zeroize, ZeroizeOnDrop};
-use std::error::Error;
-use std::fmt;
+use qratum_crypto_secure_alloc::{Protection, SecureArray};
+use crate::hmac::hmac_sha3_512;
+use crate::RandomGenerator;
+pub use crate::error::{DrbgError, HealthFailure};
 
 /// DRBG parameters per NIST SP 800-90A
 pub const SECURITY_STRENGTH: usize = 256;
@@ -21,29 +26,133 @@ pub const SEED_LENGTH: usize = 64;  // SHA3-512 output
 pub const MAX_BYTES_PER_REQUEST: usize = 65536;
 pub const RESEED_INTERVAL: u64 = 1 << 48;  // 2^48 requests before mandatory reseed
 pub const MIN_ENTROPY: usize = 32;  // Minimum entropy bytes required
+pub const RESEED_BYTE_INTERVAL: u64 = 1 << 30;  // Output bytes before automatic reseed
+pub const RESEED_HISTORY: usize = 64;  // Reseed events retained for audit
 
+/// Health test parameters per NIST SP 800-90B Section 4.4
+pub const HEALTH_ALPHA_EXPONENT: u32 = 20;  // False alarm probability 2^-20
+pub const HEALTH_MIN_ENTROPY: u8 = 7;  // Claimed min-entropy bits per entropy input byte
+pub const APT_WINDOW: u32 = 512;  // Adaptive proportion window (non-binary samples)
+
+
+/// Repetition count test cutoff: 1 + ceil(alpha_exponent / H)
+///
+/// Per NIST SP 800-90B Section 4.4.1
+pub fn rct_cutoff(min_entropy: u8) -> u32 {
+    1 + HEALTH_ALPHA_EXPONENT.div_ceil(min_entropy.clamp(1, 8) as u32)
+}
+
+/// Adaptive proportion test cutoff: 1 + CRITBINOM(W, 2^-H, 1 - 2^-alpha_exponent)
+///
+/// Per NIST SP 800-90B Section 4.4.2
+pub fn apt_cutoff(min_entropy: u8) -> u32 {
+    let p = 1.0 / (1u32 << min_entropy.clamp(1, 8)) as f64;
+    let target = 1.0 - 1.0 / (1u64 << HEALTH_ALPHA_EXPONENT) as f64;
+    
+    let mut pmf = (1.0 - p).powi(APT_WINDOW as i32);
+    let mut cdf = pmf;
+    let mut k = 0u32;
+    while cdf < target && k < APT_WINDOW {
+        pmf *= (APT_WINDOW - k) as f64 / (k + 1) as f64 * p / (1.0 - p);
+        k += 1;
+        cdf += pmf;
+    }
+    1 + k
+}
+
+/// Continuous health tests over entropy input bytes
+///
+/// State persists across entropy inputs, so a source that degrades slowly
+/// is still caught.
 #[derive(Debug, Clone)]
-pub enum DrbgError {
-    InsufficientEntropy,
-    ReseedRequired,
-    RequestTooLarge,
-    NotInstantiated,
-    EntropySourceFailed,
+struct ContinuousTests {
+    rct_cutoff: u32,
+    rct_last: Option<u8>,
+    rct_run: u32,
+    apt_cutoff: u32,
+    apt_reference: u8,
+    apt_seen: u32,
+    apt_matches: u32,
 }
 
-impl fmt::Display for DrbgError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            DrbgError::InsufficientEntropy => write!(f, "Insufficient entropy provided"),
-            DrbgError::ReseedRequired => write!(f, "DRBG reseed required"),
-            DrbgError::RequestTooLarge => write!(f, "Request exceeds max bytes per request"),
-            DrbgError::NotInstantiated => write!(f, "DRBG not properly instantiated"),
-            DrbgError::EntropySourceFailed => write!(f, "Entropy source failed"),
+impl ContinuousTests {
+    fn new(min_entropy: u8) -> Self {
+        Self {
+            rct_cutoff: rct_cutoff(min_entropy),
+            rct_last: None,
+            rct_run: 0,
+            apt_cutoff: apt_cutoff(min_entropy),
+            apt_reference: 0,
+            apt_seen: 0,
+            apt_matches: 0,
+        }
+    }
+    
+    /// Run both tests over `samples`; stops at the first alarm
+    fn feed(&mut self, samples: &[u8]) -> Result<(), HealthFailure> {
+        for &sample in samples {
+            if self.rct_last == Some(sample) {
+                self.rct_run += 1;
+            } else {
+                self.rct_last = Some(sample);
+                self.rct_run = 1;
+            }
+            if self.rct_run >= self.rct_cutoff {
+                return Err(HealthFailure::RepetitionCount);
+            }
+            
+            if self.apt_seen == 0 {
+                self.apt_reference = sample;
+                self.apt_matches = 1;
+            } else if sample == self.apt_reference {
+                self.apt_matches += 1;
+            }
+            self.apt_seen += 1;
+            if self.apt_matches >= self.apt_cutoff {
+                return Err(HealthFailure::AdaptiveProportion);
+            }
+            if self.apt_seen == APT_WINDOW {
+                self.apt_seen = 0;
+            }
         }
+        Ok(())
     }
 }
 
-impl Error for DrbgError {}
+/// Health status report for a DRBG instance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrbgHealth {
+    /// Startup known-answer test passed
+    pub startup_passed: bool,
+    /// Current failure; the instance refuses to generate until cleared
+    pub failure: Option<HealthFailure>,
+    /// Entropy input bytes run through the continuous tests
+    pub samples_tested: u64,
+    /// Repetition count test alarms since instantiation
+    pub rct_alarms: u32,
+    /// Adaptive proportion test alarms since instantiation
+    pub apt_alarms: u32,
+    /// Recoveries by reseeding from fresh entropy
+    pub recoveries: u32,
+}
+
+impl DrbgHealth {
+    /// Whether the instance may generate output
+    pub fn is_healthy(&self) -> bool {
+        self.startup_passed && self.failure.is_none()
+    }
+}
+
+/// Known-answer test input (entropy, nonce, personalization)
+const KAT_ENTROPY: [u8; 48] = *b"QRATUM-DRBG-KAT-ENTROPY-INPUT-000000000000000000";
+const KAT_NONCE: [u8; 16] = *b"QRATUM-KAT-NONCE";
+const KAT_PERSONALIZATION: &[u8] = b"QRATUM-KAT";
+
+/// Expected first 32 output bytes for the known-answer input
+const KAT_EXPECTED: [u8; 32] = [
+    0x16, 0x51, 0x2e, 0x0e, 0xd6, 0x77, 0x85, 0xa5, 0x1e, 0x65, 0x86, 0x3a, 0xe5, 0xef, 0xb6, 0x6d,
+    0x89, 0x41, 0xed, 0xc7, 0xaa, 0xc3, 0x81, 0x03, 0xd1, 0xca, 0xee, 0xa2, 0x93, 0x61, 0xa7, 0x36,
+];
 
 /// Entropy Source trait for pluggable entropy collection
 pub trait EntropySource: Send + Sync {
@@ -52,6 +161,12 @@ pub trait EntropySource: Send + Sync {
     
     /// Get source identifier for audit
     fn source_id(&self) -> &str;
+    
+    /// Claimed min-entropy bits per output byte, which sets the cutoffs of
+    /// the continuous health tests run on this source's raw samples
+    fn min_entropy(&self) -> u8 {
+        HEALTH_MIN_ENTROPY
+    }
 }
 
 /// System RNG entropy source (getrandom)
@@ -90,6 +205,11 @@ impl EntropySource for TimestampEntropySource {
     fn source_id(&self) -> &str {
         "timestamp"
     }
+    
+    /// The high-order bytes of a nanosecond timestamp barely change
+    fn min_entropy(&self) -> u8 {
+        1
+    }
 }
 
 /// Entropy Pool for collecting and mixing entropy from multiple sources
@@ -104,6 +224,10 @@ pub struct EntropyPool {
     /// Estimated entropy bits
     #[zeroize(skip)]
     entropy_bits: u32,
+    
+    /// Raw samples run through the continuous tests
+    #[zeroize(skip)]
+    samples_tested: u64,
 }
 
 impl EntropyPool {
@@ -113,16 +237,27 @@ impl EntropyPool {
             pool: [0u8; SEED_LENGTH],
             source_count: 0,
             entropy_bits: 0,
+            samples_tested: 0,
         }
     }
     
     /// Add entropy from a source (XOR mixing)
     ///
+    /// The raw samples go through the SP 800-90B continuous tests at the
+    /// source's claimed min-entropy before mixing; samples that raise an
+    /// alarm are discarded and the alarm returned.
+    ///
     /// Security: Uses XOR for mixing which preserves entropy
     /// when sources are independent.
-    pub fn add_entropy<S: EntropySource>(&mut self, source: &S) -> Result<(), DrbgError> {
+    pub fn add_entropy<S: EntropySource + ?Sized>(&mut self, source: &S) -> Result<(), DrbgError> {
         let mut temp = [0u8; SEED_LENGTH];
-        let bytes_collected = source.collect(&mut temp)?;
+        let bytes_collected = source.collect(&mut temp)?.min(SEED_LENGTH);
+        
+        self.samples_tested += bytes_collected as u64;
+        if let Err(failure) = ContinuousTests::new(source.min_entropy()).feed(&temp[..bytes_collected]) {
+            temp.zeroize();
+            return Err(DrbgError::HealthTestFailed(failure));
+        }
         
         // XOR mix into pool (constant-time operation)
         for (i, byte) in temp.iter().enumerate().take(bytes_collected) {
@@ -168,41 +303,6 @@ impl Default for EntropyPool {
     }
 }
 
-/// HMAC-SHA3-512 implementation for DRBG
-fn hmac_sha3_512(key: &[u8], data: &[u8]) -> [u8; SEED_LENGTH] {
-    const IPAD: u8 = 0x36;
-    const OPAD: u8 = 0x5c;
-    const BLOCK_SIZE: usize = 72;  // SHA3-512 rate
-    
-    // Prepare key (hash if too long, pad if too short)
-    let mut padded_key = [0u8; BLOCK_SIZE];
-    if key.len() > BLOCK_SIZE {
-        let mut hasher = Sha3_512::new();
-        hasher.update(key);
-        let hashed: [u8; SEED_LENGTH] = hasher.finalize().into();
-        padded_key[..SEED_LENGTH].copy_from_slice(&hashed);
-    } else {
-        padded_key[..key.len()].copy_from_slice(key);
-    }
-    
-    // Inner hash: H((K ⊕ ipad) || data)
-    let mut inner_hasher = Sha3_512::new();
-    for byte in padded_key.iter() {
-        inner_hasher.update(&[byte ^ IPAD]);
-    }
-    inner_hasher.update(data);
-    let inner_hash: [u8; SEED_LENGTH] = inner_hasher.finalize().into();
-    
-    // Outer hash: H((K ⊕ opad) || inner_hash)
-    let mut outer_hasher = Sha3_512::new();
-    for byte in padded_key.iter() {
-        outer_hasher.update(&[byte ^ OPAD]);
-    }
-    outer_hasher.update(&inner_hash);
-    
-    outer_hasher.finalize().into()
-}
-
 /// HMAC-DRBG State
 ///
 /// NIST SP 800-90A compliant HMAC-DRBG using SHA3-512.
@@ -210,11 +310,11 @@ fn hmac_sha3_512(key: &[u8], data: &[u8]) -> [u8; SEED_LENGTH] {
 /// with prediction resistance.
 #[derive(Zeroize, ZeroizeOnDrop)]
 pub struct HmacDrbg {
-    /// Internal key K (zeroized on drop)
-    key: [u8; SEED_LENGTH],
+    /// Internal key K (locked in RAM, zeroized on drop)
+    key: SecureArray<SEED_LENGTH>,
     
-    /// Internal value V (zeroized on drop)
-    value: [u8; SEED_LENGTH],
+    /// Internal value V (locked in RAM, zeroized on drop)
+    value: SecureArray<SEED_LENGTH>,
     
     /// Reseed counter
     #[zeroize(skip)]
@@ -227,20 +327,131 @@ pub struct HmacDrbg {
     /// Prediction resistance enabled
     #[zeroize(skip)]
     prediction_resistance: bool,
+    
+    /// Continuous health test state
+    #[zeroize(skip)]
+    tests: ContinuousTests,
+    
+    /// Health status
+    #[zeroize(skip)]
+    health: DrbgHealth,
 }
 
 impl HmacDrbg {
     /// Create new uninstantiated DRBG
     pub fn new() -> Self {
         Self {
-            key: [0u8; SEED_LENGTH],
-            value: [0u8; SEED_LENGTH],
+            key: SecureArray::zeroed(),
+            value: SecureArray::zeroed(),
             reseed_counter: 0,
             instantiated: false,
             prediction_resistance: true,
+            tests: ContinuousTests::new(HEALTH_MIN_ENTROPY),
+            health: DrbgHealth {
+                startup_passed: false,
+                failure: None,
+                samples_tested: 0,
+                rct_alarms: 0,
+                apt_alarms: 0,
+                recoveries: 0,
+            },
         }
     }
     
+    /// Startup known-answer test
+    ///
+    /// Per NIST SP 800-90A Section 11.3: instantiates a scratch DRBG with
+    /// fixed inputs and checks its output, bypassing continuous tests.
+    fn known_answer_test() -> bool {
+        let mut kat = HmacDrbg::new();
+        *kat.key = [0u8; SEED_LENGTH];
+        *kat.value = [0x01; SEED_LENGTH];
+        let mut seed_material = Vec::new();
+        seed_material.extend_from_slice(&KAT_ENTROPY);
+        seed_material.extend_from_slice(&KAT_NONCE);
+        seed_material.extend_from_slice(KAT_PERSONALIZATION);
+        kat.update(&seed_material);
+        kat.reseed_counter = 1;
+        kat.instantiated = true;
+        kat.health.startup_passed = true;
+        
+        let mut output = [0u8; 32];
+        kat.generate(&mut output, None).is_ok() && output == KAT_EXPECTED
+    }
+    
+    /// Run the startup known-answer test once; a failure is permanent
+    fn startup(&mut self) -> Result<(), DrbgError> {
+        if !self.health.startup_passed {
+            if !Self::known_answer_test() {
+                self.poison(HealthFailure::KnownAnswer);
+                return Err(DrbgError::HealthTestFailed(HealthFailure::KnownAnswer));
+            }
+            self.health.startup_passed = true;
+        }
+        if self.health.failure == Some(HealthFailure::KnownAnswer) {
+            return Err(DrbgError::HealthTestFailed(HealthFailure::KnownAnswer));
+        }
+        Ok(())
+    }
+    
+    /// Run the continuous tests over a caller-supplied entropy input
+    ///
+    /// On alarm the instance is poisoned: key and value are zeroized and
+    /// generation fails until a reseed with healthy entropy.
+    fn test_entropy(&mut self, entropy: &[u8]) -> Result<(), DrbgError> {
+        self.startup()?;
+        
+        self.health.samples_tested += entropy.len() as u64;
+        if let Err(failure) = self.tests.feed(entropy) {
+            // Fresh test state so the next entropy input is judged on its own
+            self.tests = ContinuousTests::new(HEALTH_MIN_ENTROPY);
+            self.alarm(failure);
+            self.poison(failure);
+            return Err(DrbgError::HealthTestFailed(failure));
+        }
+        Ok(())
+    }
+    
+    /// Count a continuous test alarm
+    fn alarm(&mut self, failure: HealthFailure) {
+        match failure {
+            HealthFailure::RepetitionCount => self.health.rct_alarms += 1,
+            HealthFailure::AdaptiveProportion => self.health.apt_alarms += 1,
+            HealthFailure::KnownAnswer => {}
+        }
+    }
+    
+    /// Pool entropy from `sources`, whose raw samples are health tested
+    ///
+    /// Any alarm poisons the instance. The conditioned output is not
+    /// tested again: SHA3 output passes the tests whatever its input.
+    fn pool_sources(&mut self, sources: &[&dyn EntropySource]) -> Result<[u8; SEED_LENGTH], DrbgError> {
+        self.startup()?;
+        
+        let mut pool = EntropyPool::new();
+        for source in sources {
+            let result = pool.add_entropy(*source);
+            self.health.samples_tested += pool.samples_tested;
+            pool.samples_tested = 0;
+            if let Err(DrbgError::HealthTestFailed(failure)) = result {
+                self.alarm(failure);
+                self.poison(failure);
+            }
+            result?;
+        }
+        
+        if pool.entropy_estimate() < (MIN_ENTROPY * 8) as u32 {
+            return Err(DrbgError::InsufficientEntropy);
+        }
+        Ok(pool.finalize())
+    }
+    
+    fn poison(&mut self, failure: HealthFailure) {
+        self.key.zeroize();
+        self.value.zeroize();
+        self.health.failure = Some(failure);
+    }
+    
     /// Instantiate DRBG with entropy and optional personalization string
     ///
     /// Per NIST SP 800-90A Section 10.1.2.3
@@ -254,6 +465,18 @@ impl HmacDrbg {
             return Err(DrbgError::InsufficientEntropy);
         }
         
+        self.test_entropy(entropy)?;
+        self.instantiate_tested(entropy, nonce, personalization);
+        Ok(())
+    }
+    
+    /// Instantiate from entropy that already passed the health tests
+    fn instantiate_tested(
+        &mut self,
+        entropy: &[u8],
+        nonce: &[u8],
+        personalization: Option<&[u8]>,
+    ) {
         // seed_material = entropy || nonce || personalization
         let mut seed_material = Vec::with_capacity(
             entropy.len() + nonce.len() + personalization.map_or(0, |p| p.len())
@@ -265,8 +488,8 @@ impl HmacDrbg {
         }
         
         // Initialize K and V per spec
-        self.key = [0u8; SEED_LENGTH];
-        self.value = [0x01; SEED_LENGTH];
+        *self.key = [0u8; SEED_LENGTH];
+        *self.value = [0x01; SEED_LENGTH];
         
         // Update state with seed material
         self.update(&seed_material);
@@ -276,38 +499,33 @@ impl HmacDrbg {
         
         // Zeroize sensitive local data
         seed_material.zeroize();
-        
-        Ok(())
     }
     
     /// Instantiate with entropy pooling from multiple sources
+    ///
+    /// Each source's raw samples are health tested before pooling.
     pub fn instantiate_with_pool(
         &mut self,
         sources: &[&dyn EntropySource],
         personalization: Option<&[u8]>,
     ) -> Result<(), DrbgError> {
-        let mut pool = EntropyPool::new();
-        
-        for source in sources {
-            pool.add_entropy(*source)?;
-        }
-        
-        if pool.entropy_estimate() < (MIN_ENTROPY * 8) as u32 {
-            return Err(DrbgError::InsufficientEntropy);
-        }
-        
-        let entropy = pool.finalize();
+        let mut entropy = self.pool_sources(sources)?;
         
         // Generate nonce from additional entropy
         let mut nonce = [0u8; 16];
-        getrandom::getrandom(&mut nonce).map_err(|_| DrbgError::EntropySourceFailed)?;
-        
-        self.instantiate(&entropy, &nonce, personalization)
+        let result = getrandom::getrandom(&mut nonce).map_err(|_| DrbgError::EntropySourceFailed);
+        if result.is_ok() {
+            self.instantiate_tested(&entropy, &nonce, personalization);
+        }
+        entropy.zeroize();
+        result
     }
     
     /// Reseed DRBG with new entropy
     ///
-    /// Per NIST SP 800-90A Section 10.1.2.4
+    /// Per NIST SP 800-90A Section 10.1.2.4. Reseeding a poisoned instance
+    /// with entropy that passes the continuous tests starts it from a fresh
+    /// state, clearing the failure.
     pub fn reseed(
         &mut self,
         entropy: &[u8],
@@ -321,6 +539,21 @@ impl HmacDrbg {
             return Err(DrbgError::InsufficientEntropy);
         }
         
+        self.test_entropy(entropy)?;
+        self.reseed_tested(entropy, additional_input);
+        Ok(())
+    }
+    
+    /// Reseed from entropy that already passed the health tests
+    fn reseed_tested(&mut self, entropy: &[u8], additional_input: Option<&[u8]>) {
+        // A poisoned state was zeroized; restart from the instantiate values
+        if self.health.failure.is_some() {
+            *self.key = [0u8; SEED_LENGTH];
+            *self.value = [0x01; SEED_LENGTH];
+            self.health.failure = None;
+            self.health.recoveries += 1;
+        }
+        
         // seed_material = entropy || additional_input
         let mut seed_material = Vec::with_capacity(
             entropy.len() + additional_input.map_or(0, |a| a.len())
@@ -334,8 +567,6 @@ impl HmacDrbg {
         self.reseed_counter = 1;
         
         seed_material.zeroize();
-        
-        Ok(())
     }
     
     /// Generate random bytes
@@ -350,6 +581,10 @@ impl HmacDrbg {
             return Err(DrbgError::NotInstantiated);
         }
         
+        if let Some(failure) = self.health.failure {
+            return Err(DrbgError::HealthTestFailed(failure));
+        }
+        
         if output.len() > MAX_BYTES_PER_REQUEST {
             return Err(DrbgError::RequestTooLarge);
         }
@@ -368,8 +603,8 @@ impl HmacDrbg {
         // Generate output
         let mut temp = Vec::new();
         while temp.len() < output.len() {
-            self.value = hmac_sha3_512(&self.key, &self.value);
-            temp.extend_from_slice(&self.value);
+            *self.value = hmac_sha3_512(&self.key[..], &self.value[..]);
+            temp.extend_from_slice(&self.value[..]);
         }
         
         output.copy_from_slice(&temp[..output.len()]);
@@ -391,24 +626,24 @@ impl HmacDrbg {
     fn update(&mut self, provided_data: &[u8]) {
         // K = HMAC(K, V || 0x00 || provided_data)
         let mut concat = Vec::with_capacity(SEED_LENGTH + 1 + provided_data.len());
-        concat.extend_from_slice(&self.value);
+        concat.extend_from_slice(&self.value[..]);
         concat.push(0x00);
         concat.extend_from_slice(provided_data);
-        self.key = hmac_sha3_512(&self.key, &concat);
+        *self.key = hmac_sha3_512(&self.key[..], &concat);
         
         // V = HMAC(K, V)
-        self.value = hmac_sha3_512(&self.key, &self.value);
+        *self.value = hmac_sha3_512(&self.key[..], &self.value[..]);
         
         if !provided_data.is_empty() {
             // K = HMAC(K, V || 0x01 || provided_data)
             concat.clear();
-            concat.extend_from_slice(&self.value);
+            concat.extend_from_slice(&self.value[..]);
             concat.push(0x01);
             concat.extend_from_slice(provided_data);
-            self.key = hmac_sha3_512(&self.key, &concat);
+            *self.key = hmac_sha3_512(&self.key[..], &concat);
             
             // V = HMAC(K, V)
-            self.value = hmac_sha3_512(&self.key, &self.value);
+            *self.value = hmac_sha3_512(&self.key[..], &self.value[..]);
         }
         
         concat.zeroize();
@@ -428,6 +663,39 @@ impl HmacDrbg {
     pub fn require_reseed(&mut self) {
         self.reseed_counter = RESEED_INTERVAL + 1;
     }
+    
+    /// Health status report
+    pub fn health(&self) -> DrbgHealth {
+        self.health.clone()
+    }
+    
+    /// Weakest memory protection obtained for the key and value
+    ///
+    /// Anything but `Protection::Locked` means the state may be swapped.
+    pub fn memory_protection(&self) -> Protection {
+        match self.key.protection() {
+            Protection::Locked => self.value.protection(),
+            weaker => weaker,
+        }
+    }
+    
+    /// Reseed from fresh entropy sources
+    ///
+    /// Used to recover a poisoned instance; every source's raw samples
+    /// must pass the continuous tests.
+    pub fn reseed_from_sources(
+        &mut self,
+        sources: &[&dyn EntropySource],
+        additional_input: Option<&[u8]>,
+    ) -> Result<(), DrbgError> {
+        if !self.instantiated {
+            return Err(DrbgError::NotInstantiated);
+        }
+        let mut entropy = self.pool_sources(sources)?;
+        self.reseed_tested(&entropy, additional_input);
+        entropy.zeroize();
+        Ok(())
+    }
 }
 
 impl Default for HmacDrbg {
@@ -466,6 +734,14 @@ impl SecureDrbg {
     pub fn generate(&self, output: &mut [u8]) -> Result<(), DrbgError> {
         let mut drbg = self.drbg.lock().unwrap();
         
+        // A failed continuous test forces a reseed from fresh sources
+        if drbg.health().failure.is_some() {
+            let system_source = SystemEntropySource;
+            let timestamp_source = TimestampEntropySource;
+            let sources: [&dyn EntropySource; 2] = [&system_source, &timestamp_source];
+            drbg.reseed_from_sources(&sources, None)?;
+        }
+        
         // Auto-reseed if approaching limit
         // Note: Check and reseed are atomic because mutex is held
         if drbg.reseed_counter() > self.reseed_interval {
@@ -477,6 +753,253 @@ impl SecureDrbg {
         
         drbg.generate(output, None)
     }
+    
+    /// Health status report of the underlying DRBG
+    pub fn health(&self) -> DrbgHealth {
+        self.drbg.lock().unwrap().health()
+    }
+    
+    /// Memory protection of the underlying DRBG state
+    pub fn memory_protection(&self) -> Protection {
+        self.drbg.lock().unwrap().memory_protection()
+    }
+}
+
+impl RandomGenerator for SecureDrbg {
+    fn fill_bytes(&mut self, output: &mut [u8]) -> Result<(), DrbgError> {
+        self.generate(output)
+    }
+    
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+}
+
+/// Why a `ReseedingDrbg` reseeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReseedTrigger {
+    /// Request count reached the reseed interval
+    RequestInterval,
+    /// Output byte count reached the byte interval
+    ByteInterval,
+    /// Caller asked for prediction resistance
+    PredictionResistance,
+    /// A continuous health test poisoned the instance
+    HealthRecovery,
+    /// Explicit `reseed_now` call
+    Manual,
+}
+
+/// One reseed, recorded for audit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReseedEvent {
+    /// Position among all reseeds of this instance (1 = first)
+    pub sequence: u64,
+    /// What caused the reseed
+    pub trigger: ReseedTrigger,
+    /// Sources that contributed entropy
+    pub sources: Vec<String>,
+    /// Sources that failed and were skipped
+    pub failed_sources: Vec<String>,
+    /// Estimated entropy bits pooled
+    pub entropy_bits: u32,
+    /// Requests served since the previous reseed
+    pub requests_since_reseed: u64,
+    /// Bytes generated since the previous reseed
+    pub bytes_since_reseed: u64,
+}
+
+/// Reseed telemetry of a `ReseedingDrbg`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReseedTelemetry {
+    /// Successful reseeds
+    pub reseeds: u64,
+    /// Reseed attempts that could not pool enough entropy
+    pub failed_reseeds: u64,
+    /// Requests served over the instance lifetime
+    pub requests: u64,
+    /// Bytes generated over the instance lifetime
+    pub bytes_generated: u64,
+    /// Requests since the last reseed (or instantiation)
+    pub requests_since_reseed: u64,
+    /// Bytes since the last reseed (or instantiation)
+    pub bytes_since_reseed: u64,
+    /// Most recent reseeds, oldest first (at most `RESEED_HISTORY`)
+    pub history: Vec<ReseedEvent>,
+}
+
+/// HMAC-DRBG that reseeds itself from registered entropy sources
+///
+/// Tracks requests and output bytes and pulls fresh entropy from every
+/// registered source once either interval is reached, on prediction
+/// resistance requests, or after a health test poisons the instance.
+/// Sources that fail, or whose raw samples raise a health test alarm, are
+/// skipped and reported, as long as the rest still provide `MIN_ENTROPY`
+/// bytes.
+pub struct ReseedingDrbg {
+    drbg: HmacDrbg,
+    sources: Vec<Box<dyn EntropySource>>,
+    request_interval: u64,
+    byte_interval: u64,
+    telemetry: ReseedTelemetry,
+}
+
+impl ReseedingDrbg {
+    /// Instantiate from the pooled output of `sources`
+    pub fn new(
+        sources: Vec<Box<dyn EntropySource>>,
+        personalization: Option<&[u8]>,
+    ) -> Result<Self, DrbgError> {
+        let mut drbg = HmacDrbg::new();
+        let refs: Vec<&dyn EntropySource> = sources.iter().map(|s| s.as_ref()).collect();
+        drbg.instantiate_with_pool(&refs, personalization)?;
+        
+        Ok(Self {
+            drbg,
+            sources,
+            request_interval: RESEED_INTERVAL,
+            byte_interval: RESEED_BYTE_INTERVAL,
+            telemetry: ReseedTelemetry::default(),
+        })
+    }
+    
+    /// Override the request and byte intervals (clamped to 1..=`RESEED_INTERVAL` requests)
+    pub fn with_intervals(mut self, requests: u64, bytes: u64) -> Self {
+        self.request_interval = requests.clamp(1, RESEED_INTERVAL);
+        self.byte_interval = bytes.max(1);
+        self
+    }
+    
+    /// Register an additional entropy source for future reseeds
+    pub fn register_source(&mut self, source: Box<dyn EntropySource>) {
+        self.sources.push(source);
+    }
+    
+    /// Generate random bytes, reseeding first if an interval was reached
+    pub fn generate(&mut self, output: &mut [u8], additional_input: Option<&[u8]>) -> Result<(), DrbgError> {
+        if let Some(trigger) = self.due() {
+            self.reseed_with(trigger, additional_input)?;
+        }
+        self.drbg.generate(output, additional_input)?;
+        self.telemetry.requests += 1;
+        self.telemetry.requests_since_reseed += 1;
+        self.telemetry.bytes_generated += output.len() as u64;
+        self.telemetry.bytes_since_reseed += output.len() as u64;
+        Ok(())
+    }
+    
+    /// Generate random bytes after an unconditional reseed
+    ///
+    /// Per NIST SP 800-90A Section 9.3.1, prediction resistance requires
+    /// fresh entropy for this request.
+    pub fn generate_with_prediction_resistance(
+        &mut self,
+        output: &mut [u8],
+        additional_input: Option<&[u8]>,
+    ) -> Result<(), DrbgError> {
+        self.reseed_with(ReseedTrigger::PredictionResistance, additional_input)?;
+        self.generate(output, additional_input)
+    }
+    
+    /// Reseed from the registered sources now
+    pub fn reseed_now(&mut self, additional_input: Option<&[u8]>) -> Result<(), DrbgError> {
+        self.reseed_with(ReseedTrigger::Manual, additional_input)
+    }
+    
+    /// Reseed telemetry for audit
+    pub fn telemetry(&self) -> &ReseedTelemetry {
+        &self.telemetry
+    }
+    
+    /// Health status report of the underlying DRBG
+    pub fn health(&self) -> DrbgHealth {
+        self.drbg.health()
+    }
+    
+    /// Memory protection of the underlying DRBG state
+    pub fn memory_protection(&self) -> Protection {
+        self.drbg.memory_protection()
+    }
+    
+    fn due(&self) -> Option<ReseedTrigger> {
+        if self.drbg.health().failure.is_some() {
+            Some(ReseedTrigger::HealthRecovery)
+        } else if self.telemetry.requests_since_reseed >= self.request_interval
+            || self.drbg.reseed_counter() > RESEED_INTERVAL
+        {
+            Some(ReseedTrigger::RequestInterval)
+        } else if self.telemetry.bytes_since_reseed >= self.byte_interval {
+            Some(ReseedTrigger::ByteInterval)
+        } else {
+            None
+        }
+    }
+    
+    fn reseed_with(&mut self, trigger: ReseedTrigger, additional_input: Option<&[u8]>) -> Result<(), DrbgError> {
+        if let Err(e) = self.drbg.startup() {
+            self.telemetry.failed_reseeds += 1;
+            return Err(e);
+        }
+        
+        let mut pool = EntropyPool::new();
+        let mut sources = Vec::new();
+        let mut failed_sources = Vec::new();
+        let mut alarm = None;
+        for source in &self.sources {
+            match pool.add_entropy(source.as_ref()) {
+                Ok(()) => sources.push(source.source_id().to_string()),
+                Err(e) => {
+                    if let DrbgError::HealthTestFailed(failure) = e {
+                        self.drbg.alarm(failure);
+                        alarm = Some(failure);
+                    }
+                    failed_sources.push(source.source_id().to_string());
+                }
+            }
+        }
+        self.drbg.health.samples_tested += pool.samples_tested;
+        let entropy_bits = pool.entropy_estimate();
+        if entropy_bits < (MIN_ENTROPY * 8) as u32 {
+            self.telemetry.failed_reseeds += 1;
+            // Only unhealthy entropy left: stop output until a source recovers
+            if let Some(failure) = alarm {
+                self.drbg.poison(failure);
+                return Err(DrbgError::HealthTestFailed(failure));
+            }
+            return Err(DrbgError::InsufficientEntropy);
+        }
+        
+        let mut entropy = pool.finalize();
+        self.drbg.reseed_tested(&entropy, additional_input);
+        entropy.zeroize();
+        
+        self.telemetry.reseeds += 1;
+        if self.telemetry.history.len() == RESEED_HISTORY {
+            self.telemetry.history.remove(0);
+        }
+        self.telemetry.history.push(ReseedEvent {
+            sequence: self.telemetry.reseeds,
+            trigger,
+            sources,
+            failed_sources,
+            entropy_bits,
+            requests_since_reseed: self.telemetry.requests_since_reseed,
+            bytes_since_reseed: self.telemetry.bytes_since_reseed,
+        });
+        self.telemetry.requests_since_reseed = 0;
+        self.telemetry.bytes_since_reseed = 0;
+        Ok(())
+    }
+}
+
+impl RandomGenerator for ReseedingDrbg {
+    fn fill_bytes(&mut self, output: &mut [u8]) -> Result<(), DrbgError> {
+        self.generate(output, None)
+    }
+    
+    fn is_deterministic(&self) -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
@@ -543,6 +1066,17 @@ mod tests {
         
         // Outputs should be different
         assert_ne!(output1, output2);
+        
+        // Usable through the shared generator interface
+        let mut generator: Box<dyn RandomGenerator> = Box::new(drbg);
+        generator.fill_bytes(&mut output1).unwrap();
+        assert_ne!(output1, output2);
+        assert!(!generator.is_deterministic());
+        let drbg = SecureDrbg::new(None).unwrap();
+        
+        // State lives in guarded pages wherever the OS maps them
+        #[cfg(any(unix, windows))]
+        assert_ne!(drbg.memory_protection(), Protection::Heap);
     }
     
     #[test]
@@ -561,4 +1095,193 @@ mod tests {
         assert_eq!(drbg.reseed_counter(), 1);  // Reset after reseed
         assert!(drbg.reseed_counter() <= counter_before);
     }
+    
+    /// Source that always fails
+    struct DeadSource;
+    
+    impl EntropySource for DeadSource {
+        fn collect(&self, _output: &mut [u8]) -> Result<usize, DrbgError> {
+            Err(DrbgError::EntropySourceFailed)
+        }
+        
+        fn source_id(&self) -> &str {
+            "dead"
+        }
+    }
+    
+    /// Source stuck at a constant value
+    struct StuckSource;
+    
+    impl EntropySource for StuckSource {
+        fn collect(&self, output: &mut [u8]) -> Result<usize, DrbgError> {
+            output.fill(0xAA);
+            Ok(output.len())
+        }
+        
+        fn source_id(&self) -> &str {
+            "stuck"
+        }
+    }
+    
+    #[test]
+    fn test_stuck_source_caught_before_conditioning() {
+        let stuck = |r: Result<(), DrbgError>| {
+            matches!(r, Err(DrbgError::HealthTestFailed(HealthFailure::RepetitionCount)))
+        };
+        
+        // Pooling tests raw samples; the SHA3 seed alone would pass
+        assert!(stuck(ReseedingDrbg::new(vec![Box::new(StuckSource)], None).map(|_| ())));
+        let mut drbg = HmacDrbg::new();
+        let sources: [&dyn EntropySource; 2] = [&SystemEntropySource, &StuckSource];
+        assert!(stuck(drbg.instantiate_with_pool(&sources, None)));
+        assert!(!drbg.is_instantiated());
+        assert_eq!(drbg.health().rct_alarms, 1);
+        
+        // A stuck source next to a healthy one is skipped and reported
+        let mut drbg = ReseedingDrbg::new(vec![Box::new(SystemEntropySource)], None).unwrap();
+        drbg.register_source(Box::new(StuckSource));
+        drbg.reseed_now(None).unwrap();
+        assert_eq!(drbg.telemetry().history[0].failed_sources, vec!["stuck".to_string()]);
+        assert_eq!(drbg.health().rct_alarms, 1);
+        assert!(drbg.health().is_healthy());
+        
+        // With only the stuck source left, output stops until a source recovers
+        drbg.sources = vec![Box::new(StuckSource)];
+        let mut output = [0u8; 32];
+        assert!(stuck(drbg.reseed_now(None)));
+        assert!(stuck(drbg.generate(&mut output, None)));
+        assert_eq!(drbg.health().rct_alarms, 3);
+        drbg.sources = vec![Box::new(SystemEntropySource)];
+        drbg.generate(&mut output, None).unwrap();
+        assert_eq!(drbg.telemetry().history.last().unwrap().trigger, ReseedTrigger::HealthRecovery);
+        assert_eq!(drbg.health().recoveries, 1);
+    }
+    
+    #[test]
+    fn test_reseeding_drbg_intervals() {
+        let sources: Vec<Box<dyn EntropySource>> = vec![Box::new(SystemEntropySource)];
+        let mut drbg = ReseedingDrbg::new(sources, Some(b"test")).unwrap().with_intervals(3, 100);
+        let mut output = [0u8; 40];
+        
+        // 40 + 40 bytes, then the byte interval (100) is not yet reached
+        drbg.generate(&mut output, None).unwrap();
+        drbg.generate(&mut output, None).unwrap();
+        assert_eq!(drbg.telemetry().reseeds, 0);
+        
+        // Third request: no interval reached before it
+        drbg.generate(&mut output, None).unwrap();
+        assert_eq!(drbg.telemetry().reseeds, 0);
+        
+        // Fourth: 3 requests since instantiation -> request interval
+        drbg.generate(&mut output, None).unwrap();
+        let telemetry = drbg.telemetry().clone();
+        assert_eq!(telemetry.reseeds, 1);
+        assert_eq!(telemetry.history[0].trigger, ReseedTrigger::RequestInterval);
+        assert_eq!(telemetry.history[0].requests_since_reseed, 3);
+        assert_eq!(telemetry.history[0].bytes_since_reseed, 120);
+        assert_eq!(telemetry.history[0].sources, vec!["system-rng".to_string()]);
+        assert_eq!(telemetry.requests, 4);
+        assert_eq!(telemetry.bytes_generated, 160);
+        
+        // Large request crosses the byte interval before the request interval
+        let mut large = [0u8; 200];
+        drbg.generate(&mut large, None).unwrap();
+        drbg.generate(&mut output, None).unwrap();
+        assert_eq!(drbg.telemetry().reseeds, 2);
+        assert_eq!(drbg.telemetry().history[1].trigger, ReseedTrigger::ByteInterval);
+        assert_eq!(drbg.telemetry().bytes_since_reseed, 40);
+    }
+    
+    #[test]
+    fn test_reseeding_drbg_prediction_resistance_and_failed_sources() {
+        let sources: Vec<Box<dyn EntropySource>> = vec![Box::new(SystemEntropySource)];
+        let mut drbg = ReseedingDrbg::new(sources, None).unwrap();
+        drbg.register_source(Box::new(DeadSource));
+        
+        let mut output = [0u8; 32];
+        drbg.generate_with_prediction_resistance(&mut output, None).unwrap();
+        drbg.generate_with_prediction_resistance(&mut output, Some(b"extra")).unwrap();
+        let telemetry = drbg.telemetry();
+        assert_eq!(telemetry.reseeds, 2);
+        assert!(telemetry.history.iter().all(|e| e.trigger == ReseedTrigger::PredictionResistance));
+        assert_eq!(telemetry.history[1].failed_sources, vec!["dead".to_string()]);
+        assert_eq!(telemetry.history[1].requests_since_reseed, 1);
+        
+        // Only failing sources: reseed refused and counted, output withheld
+        let mut starved = ReseedingDrbg::new(vec![Box::new(SystemEntropySource)], None).unwrap();
+        starved.sources = vec![Box::new(DeadSource)];
+        assert!(matches!(starved.reseed_now(None), Err(DrbgError::InsufficientEntropy)));
+        assert!(matches!(
+            starved.generate_with_prediction_resistance(&mut output, None),
+            Err(DrbgError::InsufficientEntropy)
+        ));
+        assert_eq!(starved.telemetry().failed_reseeds, 2);
+        assert_eq!(starved.telemetry().requests, 0);
+    }
+    
+    #[test]
+    fn test_known_answer() {
+        assert!(HmacDrbg::known_answer_test());
+        
+        let mut drbg = HmacDrbg::new();
+        assert!(!drbg.health().startup_passed);
+        let mut entropy = [0u8; 64];
+        getrandom::getrandom(&mut entropy).unwrap();
+        drbg.instantiate(&entropy, b"nonce", None).unwrap();
+        assert!(drbg.health().is_healthy());
+        assert_eq!(drbg.health().samples_tested, 64);
+    }
+    
+    #[test]
+    fn test_stuck_entropy_poisons_until_reseed() {
+        assert_eq!(rct_cutoff(HEALTH_MIN_ENTROPY), 4);
+        
+        let mut drbg = HmacDrbg::new();
+        let mut entropy = [0u8; 64];
+        getrandom::getrandom(&mut entropy).unwrap();
+        drbg.instantiate(&entropy, b"nonce", None).unwrap();
+        
+        // A stuck source trips the repetition count test
+        let stuck = [0xAA; 64];
+        assert!(matches!(
+            drbg.reseed(&stuck, None),
+            Err(DrbgError::HealthTestFailed(HealthFailure::RepetitionCount))
+        ));
+        let mut output = [0u8; 32];
+        assert!(matches!(
+            drbg.generate(&mut output, None),
+            Err(DrbgError::HealthTestFailed(HealthFailure::RepetitionCount))
+        ));
+        assert!(!drbg.health().is_healthy());
+        
+        // Fresh entropy clears the failure
+        let sources: [&dyn EntropySource; 1] = [&SystemEntropySource];
+        drbg.reseed_from_sources(&sources, None).unwrap();
+        drbg.generate(&mut output, None).unwrap();
+        let health = drbg.health();
+        assert!(health.is_healthy());
+        assert_eq!((health.rct_alarms, health.apt_alarms, health.recoveries), (1, 0, 1));
+    }
+    
+    #[test]
+    fn test_biased_entropy_trips_adaptive_proportion() {
+        let mut drbg = HmacDrbg::new();
+        
+        // Every other byte repeats the window reference, never 4 in a row
+        let mut biased = [0u8; APT_WINDOW as usize];
+        getrandom::getrandom(&mut biased).unwrap();
+        for i in (0..biased.len()).step_by(2) {
+            biased[i] = 0x42;
+        }
+        for i in (1..biased.len()).step_by(2) {
+            if biased[i] == 0x42 {
+                biased[i] = 0x43;
+            }
+        }
+        assert!(matches!(
+            drbg.instantiate(&biased, b"nonce", None),
+            Err(DrbgError::HealthTestFailed(HealthFailure::AdaptiveProportion))
+        ));
+        assert_eq!(drbg.health().apt_alarms, 1);
+    }
 }
@@ -0,0 +1,53 @@
+//! DRBG Error Types
+//!
+//! Shared by every generator in the crate, including the no_std
+//! `DeterministicDrbg`, so they depend on `core` only.
+
+use core::fmt;
+
+#[derive(Debug, Clone)]
+pub enum DrbgError {
+    InsufficientEntropy,
+    ReseedRequired,
+    RequestTooLarge,
+    NotInstantiated,
+    EntropySourceFailed,
+    HealthTestFailed(HealthFailure),
+}
+
+impl fmt::Display for DrbgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DrbgError::InsufficientEntropy => write!(f, "Insufficient entropy provided"),
+            DrbgError::ReseedRequired => write!(f, "DRBG reseed required"),
+            DrbgError::RequestTooLarge => write!(f, "Request exceeds max bytes per request"),
+            DrbgError::NotInstantiated => write!(f, "DRBG not properly instantiated"),
+            DrbgError::EntropySourceFailed => write!(f, "Entropy source failed"),
+            DrbgError::HealthTestFailed(failure) => write!(f, "DRBG health test failed: {}", failure),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DrbgError {}
+
+/// Health test that put the DRBG into its error state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthFailure {
+    /// Startup known-answer test produced the wrong output (permanent)
+    KnownAnswer,
+    /// Repetition count test alarmed on an entropy input
+    RepetitionCount,
+    /// Adaptive proportion test alarmed on an entropy input
+    AdaptiveProportion,
+}
+
+impl fmt::Display for HealthFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HealthFailure::KnownAnswer => write!(f, "known-answer test"),
+            HealthFailure::RepetitionCount => write!(f, "repetition count test"),
+            HealthFailure::AdaptiveProportion => write!(f, "adaptive proportion test"),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! HMAC-SHA3-512
+//!
+//! Keyed hash underlying both DRBGs; `core` only.
+
+use sha3::{Sha3_512, Digest};
+
+/// HMAC output length (SHA3-512)
+pub(crate) const OUTPUT_LENGTH: usize = 64;
+
+/// HMAC-SHA3-512 implementation for DRBG
+pub(crate) fn hmac_sha3_512(key: &[u8], data: &[u8]) -> [u8; OUTPUT_LENGTH] {
+    hmac_sha3_512_parts(key, &[data])
+}
+
+/// HMAC-SHA3-512 over the concatenation of `parts`, without allocating
+pub(crate) fn hmac_sha3_512_parts(key: &[u8], parts: &[&[u8]]) -> [u8; OUTPUT_LENGTH] {
+    const IPAD: u8 = 0x36;
+    const OPAD: u8 = 0x5c;
+    const BLOCK_SIZE: usize = 72;  // SHA3-512 rate
+    
+    // Prepare key (hash if too long, pad if too short)
+    let mut padded_key = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        let mut hasher = Sha3_512::new();
+        hasher.update(key);
+        let hashed: [u8; OUTPUT_LENGTH] = hasher.finalize().into();
+        padded_key[..OUTPUT_LENGTH].copy_from_slice(&hashed);
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+    
+    // Inner hash: H((K ⊕ ipad) || data)
+    let mut inner_hasher = Sha3_512::new();
+    for byte in padded_key.iter() {
+        inner_hasher.update([byte ^ IPAD]);
+    }
+    for part in parts {
+        inner_hasher.update(part);
+    }
+    let inner_hash: [u8; OUTPUT_LENGTH] = inner_hasher.finalize().into();
+    
+    // Outer hash: H((K ⊕ opad) || inner_hash)
+    let mut outer_hasher = Sha3_512::new();
+    for byte in padded_key.iter() {
+        outer_hasher.update([byte ^ OPAD]);
+    }
+    outer_hasher.update(inner_hash);
+    
+    outer_hasher.finalize().into()
+}
@@ -4,39 +4,77 @@
 //! - HMAC-DRBG (NIST SP 800-90A compliant)
 //! - Entropy pooling from multiple sources
 //! - Automatic reseeding with prediction resistance
+//! - Reseeding from registered entropy sources with audit telemetry
+//! - SP 800-90B startup and continuous health tests
+//! - Seed-controlled `DeterministicDrbg` for simulation (`deterministic`
+//!   feature, no_std)
 //!
 //! Security Properties:
 //! - All sensitive state zeroized on drop
 //! - Memory fencing for secure operations
 //! - Constant-time operations where applicable
+//! - Deterministic generators identify themselves via
+//!   `RandomGenerator::is_deterministic`
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod error;
+#[cfg(any(test, feature = "std", feature = "deterministic"))]
+mod hmac;
+#[cfg(feature = "std")]
 pub mod drbg;
+#[cfg(any(test, feature = "deterministic"))]
+pub mod deterministic;
 
+pub use error::{DrbgError, HealthFailure};
+#[cfg(any(test, feature = "deterministic"))]
+pub use deterministic::DeterministicDrbg;
+#[cfg(feature = "std")]
 pub use drbg::{
     HmacDrbg,
     SecureDrbg,
+    ReseedingDrbg,
+    ReseedTrigger,
+    ReseedEvent,
+    ReseedTelemetry,
     EntropyPool,
     EntropySource,
     SystemEntropySource,
     TimestampEntropySource,
-    DrbgError,
+    DrbgHealth,
     SECURITY_STRENGTH,
     SEED_LENGTH,
     MAX_BYTES_PER_REQUEST,
     RESEED_INTERVAL,
+    RESEED_BYTE_INTERVAL,
     MIN_ENTROPY,
 };
 
+/// Random bit generator interface
+///
+/// Implemented by `SecureDrbg`, `ReseedingDrbg`, and `DeterministicDrbg`,
+/// so simulation code can be written once against any of them.
+pub trait RandomGenerator {
+    /// Fill `output` with generator output
+    fn fill_bytes(&mut self, output: &mut [u8]) -> Result<(), DrbgError>;
+
+    /// Whether the output is reproducible from a seed
+    ///
+    /// Key generation must refuse generators that return `true`.
+    fn is_deterministic(&self) -> bool;
+}
+
 /// Generate cryptographically secure random bytes using the global DRBG
 ///
 /// This is a convenience function that creates and uses a secure DRBG.
 /// For high-performance scenarios, prefer creating your own SecureDrbg instance.
+#[cfg(feature = "std")]
 pub fn generate_random(output: &mut [u8]) -> Result<(), DrbgError> {
     let drbg = SecureDrbg::new(Some(b"QRATUM-GLOBAL"))?;
     drbg.generate(output)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     
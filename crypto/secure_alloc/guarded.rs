@@ -0,0 +1,510 @@
+//! Guarded, Locked Allocations for Key Material
+//!
+//! Each `SecureArray` gets its own page mapping:
+//!
+//! ```text
+//! | guard page | padding | canary | data | canary | guard page |
+//! ```
+//!
+//! The data sits at the end of the usable pages, so a linear overflow
+//! clobbers the rear canary and then faults on the guard page; an underflow
+//! clobbers the front canary. The usable pages are locked in RAM and, on
+//! Linux, excluded from core dumps.
+//!
+//! Degradation:
+//! - `Protection::Locked`: guard pages, canaries, locked in RAM
+//! - `Protection::Unlocked`: guard pages and canaries; locking refused
+//!   (typically RLIMIT_MEMLOCK or a missing privilege)
+//! - `Protection::Heap`: canaries only; no page mapping on this target
+//!
+//! Allocation itself never fails for lack of protection: key material must
+//! still be usable on a constrained host, but the shortfall is visible
+//! through `SecureArray::protection` and `report()`.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Canary length on each side of the data
+pub const CANARY_SIZE: usize = 16;
+
+/// Fallback canary if the OS RNG is unavailable
+const FIXED_CANARY: &[u8; CANARY_SIZE] = b"QRATUM-CANARY-v1";
+
+static LOCKED: AtomicU64 = AtomicU64::new(0);
+static UNLOCKED: AtomicU64 = AtomicU64::new(0);
+static HEAP: AtomicU64 = AtomicU64::new(0);
+static CANARY_VIOLATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Protection actually obtained for an allocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protection {
+    /// Guard pages, canaries, and pages locked in RAM
+    Locked,
+    /// Guard pages and canaries; the OS refused to lock the pages
+    Unlocked,
+    /// Canaries only; page mapping is unavailable on this target
+    Heap,
+}
+
+impl Protection {
+    /// Whether the data can never be swapped to disk
+    pub fn is_locked(&self) -> bool {
+        *self == Protection::Locked
+    }
+
+    fn counter(&self) -> &'static AtomicU64 {
+        match self {
+            Protection::Locked => &LOCKED,
+            Protection::Unlocked => &UNLOCKED,
+            Protection::Heap => &HEAP,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecureAllocError {
+    /// A canary next to the data was overwritten
+    CanaryCorrupted,
+}
+
+impl fmt::Display for SecureAllocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SecureAllocError::CanaryCorrupted => write!(f, "Secure allocation canary corrupted"),
+        }
+    }
+}
+
+impl Error for SecureAllocError {}
+
+/// Process-wide secure allocation counters
+///
+/// Counts are cumulative since process start.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SecureAllocReport {
+    /// Allocations with guard pages and locked pages
+    pub locked: u64,
+    /// Allocations with guard pages whose pages could not be locked
+    pub unlocked: u64,
+    /// Allocations that fell back to the heap
+    pub heap: u64,
+    /// Allocations found with a corrupted canary on drop
+    pub canary_violations: u64,
+}
+
+impl SecureAllocReport {
+    /// Total allocations made
+    pub fn total(&self) -> u64 {
+        self.locked + self.unlocked + self.heap
+    }
+
+    /// Whether every allocation was locked and no canary was ever corrupted
+    pub fn is_fully_protected(&self) -> bool {
+        self.unlocked == 0 && self.heap == 0 && self.canary_violations == 0
+    }
+}
+
+/// Snapshot of the process-wide secure allocation counters
+pub fn report() -> SecureAllocReport {
+    SecureAllocReport {
+        locked: LOCKED.load(Ordering::Relaxed),
+        unlocked: UNLOCKED.load(Ordering::Relaxed),
+        heap: HEAP.load(Ordering::Relaxed),
+        canary_violations: CANARY_VIOLATIONS.load(Ordering::Relaxed),
+    }
+}
+
+/// Random per-process canary
+fn canary() -> &'static [u8; CANARY_SIZE] {
+    static CANARY: OnceLock<[u8; CANARY_SIZE]> = OnceLock::new();
+    CANARY.get_or_init(|| {
+        let mut canary = [0u8; CANARY_SIZE];
+        // A fixed canary still catches accidental overwrites
+        if getrandom::getrandom(&mut canary).is_err() {
+            canary = *FIXED_CANARY;
+        }
+        canary
+    })
+}
+
+/// Backing memory of one allocation
+enum Region {
+    /// Dedicated mapping of `len` bytes; the first and last page are guards
+    Mapped {
+        base: NonNull<u8>,
+        len: usize,
+        page: usize,
+        locked: bool,
+    },
+    /// Plain heap allocation
+    Heap(Box<[u8]>),
+}
+
+// SAFETY: a region exclusively owns its mapping, like `Box`
+unsafe impl Send for Region {}
+unsafe impl Sync for Region {}
+
+impl Region {
+    /// Allocate at least `size` usable bytes, mapping pages where possible
+    fn allocate(size: usize) -> Self {
+        sys::map(size).unwrap_or_else(|| Region::Heap(vec![0u8; size].into_boxed_slice()))
+    }
+
+    fn protection(&self) -> Protection {
+        match self {
+            Region::Mapped { locked: true, .. } => Protection::Locked,
+            Region::Mapped { locked: false, .. } => Protection::Unlocked,
+            Region::Heap(_) => Protection::Heap,
+        }
+    }
+
+    /// Bytes between the guard pages
+    fn usable(&self) -> &[u8] {
+        match self {
+            // SAFETY: the pages between the guards are mapped read/write for
+            // the lifetime of the region
+            Region::Mapped { base, len, page, .. } => unsafe {
+                std::slice::from_raw_parts(base.as_ptr().add(*page), len - 2 * page)
+            },
+            Region::Heap(bytes) => bytes,
+        }
+    }
+
+    fn usable_mut(&mut self) -> &mut [u8] {
+        match self {
+            // SAFETY: as in `usable`, and `&mut self` guarantees exclusivity
+            Region::Mapped { base, len, page, .. } => unsafe {
+                std::slice::from_raw_parts_mut(base.as_ptr().add(*page), *len - 2 * *page)
+            },
+            Region::Heap(bytes) => bytes,
+        }
+    }
+}
+
+impl Drop for Region {
+    fn drop(&mut self) {
+        self.usable_mut().zeroize();
+        if let Region::Mapped { base, len, page, locked } = *self {
+            // SAFETY: the fields were produced by `sys::map` and the mapping
+            // is released exactly once
+            unsafe { sys::unmap(base, len, page, locked) };
+        }
+    }
+}
+
+#[cfg(unix)]
+mod sys {
+    use super::Region;
+    use std::ptr::{self, NonNull};
+
+    fn page_size() -> usize {
+        // SAFETY: sysconf has no preconditions
+        let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        if page > 0 {
+            page as usize
+        } else {
+            4096
+        }
+    }
+
+    pub fn map(size: usize) -> Option<Region> {
+        let page = page_size();
+        let inner = size.div_ceil(page).max(1) * page;
+        let len = inner + 2 * page;
+
+        // SAFETY: fresh anonymous mapping; no existing memory is touched
+        let base = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANON,
+                -1,
+                0,
+            )
+        };
+        if base == libc::MAP_FAILED {
+            return None;
+        }
+        let base = NonNull::new(base.cast::<u8>())?;
+
+        // SAFETY: guard and usable pages all lie inside the mapping above
+        unsafe {
+            let front = base.as_ptr();
+            let usable = front.add(page);
+            let rear = usable.add(inner);
+            if libc::mprotect(front.cast(), page, libc::PROT_NONE) != 0
+                || libc::mprotect(rear.cast(), page, libc::PROT_NONE) != 0
+            {
+                libc::munmap(front.cast(), len);
+                return None;
+            }
+            let locked = libc::mlock(usable.cast(), inner) == 0;
+            #[cfg(target_os = "linux")]
+            libc::madvise(usable.cast(), inner, libc::MADV_DONTDUMP);
+            Some(Region::Mapped { base, len, page, locked })
+        }
+    }
+
+    /// # Safety
+    /// Arguments must come from a `Region::Mapped` produced by `map`
+    pub unsafe fn unmap(base: NonNull<u8>, len: usize, page: usize, locked: bool) {
+        if locked {
+            libc::munlock(base.as_ptr().add(page).cast(), len - 2 * page);
+        }
+        libc::munmap(base.as_ptr().cast(), len);
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use super::Region;
+    use std::ptr::{self, NonNull};
+    use windows_sys::Win32::System::Memory::{
+        VirtualAlloc, VirtualFree, VirtualLock, VirtualProtect, VirtualUnlock, MEM_COMMIT,
+        MEM_RELEASE, MEM_RESERVE, PAGE_NOACCESS, PAGE_PROTECTION_FLAGS, PAGE_READWRITE,
+    };
+    use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+
+    fn page_size() -> usize {
+        // SAFETY: GetSystemInfo only writes the provided struct
+        let info = unsafe {
+            let mut info: SYSTEM_INFO = std::mem::zeroed();
+            GetSystemInfo(&mut info);
+            info
+        };
+        (info.dwPageSize as usize).max(4096)
+    }
+
+    pub fn map(size: usize) -> Option<Region> {
+        let page = page_size();
+        let inner = size.div_ceil(page).max(1) * page;
+        let len = inner + 2 * page;
+
+        // SAFETY: fresh committed allocation; no existing memory is touched
+        let base = unsafe { VirtualAlloc(ptr::null(), len, MEM_COMMIT | MEM_RESERVE, PAGE_READWRITE) };
+        let base = NonNull::new(base.cast::<u8>())?;
+
+        // SAFETY: guard and usable pages all lie inside the allocation above
+        unsafe {
+            let front = base.as_ptr();
+            let usable = front.add(page);
+            let rear = usable.add(inner);
+            let mut old: PAGE_PROTECTION_FLAGS = 0;
+            if VirtualProtect(front.cast(), page, PAGE_NOACCESS, &mut old) == 0
+                || VirtualProtect(rear.cast(), page, PAGE_NOACCESS, &mut old) == 0
+            {
+                VirtualFree(front.cast(), 0, MEM_RELEASE);
+                return None;
+            }
+            let locked = VirtualLock(usable.cast(), inner) != 0;
+            Some(Region::Mapped { base, len, page, locked })
+        }
+    }
+
+    /// # Safety
+    /// Arguments must come from a `Region::Mapped` produced by `map`
+    pub unsafe fn unmap(base: NonNull<u8>, len: usize, page: usize, locked: bool) {
+        if locked {
+            VirtualUnlock(base.as_ptr().add(page).cast(), len - 2 * page);
+        }
+        VirtualFree(base.as_ptr().cast(), 0, MEM_RELEASE);
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod sys {
+    use super::Region;
+    use std::ptr::NonNull;
+
+    pub fn map(_size: usize) -> Option<Region> {
+        None
+    }
+
+    /// # Safety
+    /// Never called: `map` produces no mapped regions on this target
+    pub unsafe fn unmap(_base: NonNull<u8>, _len: usize, _page: usize, _locked: bool) {}
+}
+
+/// Fixed-size key material in guarded, locked memory
+///
+/// Dereferences to `[u8; N]`. Zeroized and unmapped on drop; a corrupted
+/// canary found on drop is counted in `report()`.
+pub struct SecureArray<const N: usize> {
+    region: Region,
+    /// Offset of the data within the usable bytes
+    offset: usize,
+}
+
+impl<const N: usize> SecureArray<N> {
+    /// Allocate a zero-filled array with the strongest available protection
+    pub fn zeroed() -> Self {
+        Self::in_region(Region::allocate(N + 2 * CANARY_SIZE))
+    }
+
+    fn in_region(mut region: Region) -> Self {
+        let offset = region.usable().len() - CANARY_SIZE - N;
+        let canary = canary();
+        let usable = region.usable_mut();
+        usable[offset - CANARY_SIZE..offset].copy_from_slice(canary);
+        usable[offset + N..offset + N + CANARY_SIZE].copy_from_slice(canary);
+        region.protection().counter().fetch_add(1, Ordering::Relaxed);
+        Self { region, offset }
+    }
+
+    /// Protection obtained for this allocation
+    pub fn protection(&self) -> Protection {
+        self.region.protection()
+    }
+
+    /// Check both canaries
+    ///
+    /// # Returns
+    /// - `Err(CanaryCorrupted)` if memory next to the data was overwritten
+    pub fn verify(&self) -> Result<(), SecureAllocError> {
+        let usable = self.region.usable();
+        let canary = canary();
+        let front = &usable[self.offset - CANARY_SIZE..self.offset];
+        let rear = &usable[self.offset + N..self.offset + N + CANARY_SIZE];
+        if front == canary && rear == canary {
+            Ok(())
+        } else {
+            Err(SecureAllocError::CanaryCorrupted)
+        }
+    }
+}
+
+impl<const N: usize> Deref for SecureArray<N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &[u8; N] {
+        let data = &self.region.usable()[self.offset..self.offset + N];
+        // SAFETY: `data` is exactly N bytes and `[u8; N]` has alignment 1
+        unsafe { &*data.as_ptr().cast::<[u8; N]>() }
+    }
+}
+
+impl<const N: usize> DerefMut for SecureArray<N> {
+    fn deref_mut(&mut self) -> &mut [u8; N] {
+        let offset = self.offset;
+        let data = &mut self.region.usable_mut()[offset..offset + N];
+        // SAFETY: as in `deref`
+        unsafe { &mut *data.as_mut_ptr().cast::<[u8; N]>() }
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for SecureArray<N> {
+    fn from(mut bytes: [u8; N]) -> Self {
+        let mut array = Self::zeroed();
+        array.copy_from_slice(&bytes);
+        bytes.zeroize();
+        array
+    }
+}
+
+impl<const N: usize> Default for SecureArray<N> {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl<const N: usize> Clone for SecureArray<N> {
+    fn clone(&self) -> Self {
+        let mut array = Self::zeroed();
+        array.copy_from_slice(&self[..]);
+        array
+    }
+}
+
+/// Constant-time comparison
+impl<const N: usize> PartialEq for SecureArray<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().zip(other.iter()).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+    }
+}
+
+impl<const N: usize> Eq for SecureArray<N> {}
+
+impl<const N: usize> fmt::Debug for SecureArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SecureArray")
+            .field("len", &N)
+            .field("protection", &self.protection())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<const N: usize> Zeroize for SecureArray<N> {
+    fn zeroize(&mut self) {
+        self.deref_mut().zeroize();
+    }
+}
+
+impl<const N: usize> ZeroizeOnDrop for SecureArray<N> {}
+
+impl<const N: usize> Drop for SecureArray<N> {
+    fn drop(&mut self) {
+        if self.verify().is_err() {
+            CANARY_VIOLATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        // Region drop zeroizes and releases the pages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secure_array_roundtrip() {
+        let mut key = SecureArray::from([0x5au8; 64]);
+        assert_eq!(*key, [0x5au8; 64]);
+        key[0] = 1;
+        let copy = key.clone();
+        key.zeroize();
+        assert_eq!(*key, [0u8; 64]);
+        assert_eq!(copy[0], 1);
+        assert_ne!(key, copy);
+        assert_eq!(key.verify(), Ok(()));
+        assert!(!format!("{:?}", copy).contains("90"));
+
+        #[cfg(any(unix, windows))]
+        assert_ne!(copy.protection(), Protection::Heap);
+        assert!(report().total() >= 2);
+    }
+
+    #[test]
+    fn test_canary_detects_overwrite() {
+        let before = report().canary_violations;
+        let mut key = SecureArray::<32>::zeroed();
+        let rear = key.offset + 32;
+        key.region.usable_mut()[rear] ^= 0xff;
+        assert_eq!(key.verify(), Err(SecureAllocError::CanaryCorrupted));
+        drop(key);
+        assert!(report().canary_violations > before);
+
+        let mut key = SecureArray::<32>::zeroed();
+        let front = key.offset - 1;
+        key.region.usable_mut()[front] ^= 0xff;
+        assert_eq!(key.verify(), Err(SecureAllocError::CanaryCorrupted));
+        key.region.usable_mut()[front] ^= 0xff;
+        assert_eq!(key.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_heap_fallback() {
+        let before = report().heap;
+        let mut key = SecureArray::<48>::in_region(Region::Heap(vec![0u8; 48 + 2 * CANARY_SIZE].into_boxed_slice()));
+        assert_eq!(key.protection(), Protection::Heap);
+        assert!(!key.protection().is_locked());
+        assert!(report().heap > before);
+        key.copy_from_slice(&[9u8; 48]);
+        assert_eq!(*key, [9u8; 48]);
+        assert_eq!(key.verify(), Ok(()));
+    }
+}
@@ -0,0 +1,38 @@
+//! Secure Allocation Module
+//!
+//! Provides guarded, locked-in-RAM storage for QRATUM key material:
+//! - Dedicated page mapping per allocation with guard pages on both sides
+//! - Pages locked in RAM (mlock / VirtualLock) so keys are never swapped
+//! - Random per-process canaries around the data to detect overwrites
+//! - Excluded from core dumps where the OS supports it (Linux)
+//!
+//! Security Properties:
+//! - All allocations zeroized on drop
+//! - Graceful degradation: if pages cannot be locked (RLIMIT_MEMLOCK,
+//!   missing privilege) or mapped (unsupported target), the allocation
+//!   still succeeds with weaker protection
+//! - Degradation is never silent: every allocation reports its
+//!   `Protection`, and `report()` aggregates them process-wide
+
+pub mod guarded;
+
+pub use guarded::{
+    SecureArray,
+    Protection,
+    SecureAllocError,
+    SecureAllocReport,
+    report,
+    CANARY_SIZE,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_exports() {
+        let key = SecureArray::from([7u8; 32]);
+        assert_eq!(*key, [7u8; 32]);
+        assert!(report().total() >= 1);
+    }
+}
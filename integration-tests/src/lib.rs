@@ -0,0 +1,307 @@
+//! # End-to-End Integration Harness
+//!
+//! Stands up an in-process stack from a single seed:
+//! - **Q-Substrate**: runtime for inference, with its audit log
+//! - **QRATUM**: quorum, session rollback ledger, ephemeral biokey
+//! - **Aethernet**: RTF context over a zone-aware Merkle ledger
+//!
+//! [`run_scenario`] drives inference → TXO → quorum → commit → rollback,
+//! anchors the substrate audit log into the Aethernet ledger, and tears the
+//! session down. The returned [`ScenarioReport`] carries everything the
+//! cross-crate invariants are asserted on.
+//!
+//! Every input (timestamps, member IDs, ledger genesis, runtime seed) is
+//! derived from the seed, so equal seeds produce equal reports.
+
+use aethernet::ledger::MerkleLedger as RtfLedger;
+use aethernet::rtf::api::{RTFContext, Zone};
+use aethernet::txo::{
+    IdentityType, OperationClass, Payload, PayloadType, Receiver, Sender, TXO,
+};
+use q_substrate::audit_bridge::AuditBatch;
+use q_substrate::{
+    AuditBridge, AuditSink, BridgeCadence, LedgerAnchor, LedgerReference, QSubstrate,
+    QSubstrateConfig,
+};
+use qratum::quorum::{MemberStatus, QuorumConfig, QuorumMember, QuorumState, QuorumVote};
+use qratum::{EphemeralBiokey, OutcomeTxo, RollbackLedger, Txo, TxoType, VolatileSnapshot};
+use sha3::{Digest, Sha3_256};
+use zeroize::Zeroize;
+
+/// Prompt fed to the inference stage
+pub const PROMPT: &str = "summarize the sequencing run and flag anomalies";
+
+/// Quorum size (votes are cast by all but one member)
+pub const QUORUM_MEMBERS: usize = 4;
+
+/// Base of all scenario timestamps (seconds)
+const BASE_TIME: u64 = 1_700_000_000;
+
+/// Everything the invariants are checked against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioReport {
+    /// Seed the scenario ran with
+    pub seed: u32,
+    /// SHA3-256 of the embedding bytes
+    pub embedding_digest: [u8; 32],
+    /// QRATUM input TXO carrying the embedding
+    pub input_txo_id: [u8; 32],
+    /// Votes collected
+    pub votes: usize,
+    /// Whether the quorum reached consensus
+    pub consensus: bool,
+    /// Outcome execution hash (session ledger root after the input)
+    pub execution_hash: [u8; 32],
+    /// Session ledger root after the session rollback
+    pub session_root: [u8; 32],
+    /// Snapshot of the session state restored with the session key
+    pub snapshot_restored: bool,
+    /// Aethernet root right after the outcome commit
+    pub rtf_root_committed: [u8; 32],
+    /// Aethernet root after the epoch rollback
+    pub rtf_root_after_rollback: [u8; 32],
+    /// Aethernet nodes after the epoch rollback
+    pub rtf_nodes_after_rollback: usize,
+    /// Aethernet root at the end (after audit anchoring)
+    pub rtf_final_root: [u8; 32],
+    /// Aethernet nodes at the end
+    pub rtf_final_nodes: usize,
+    /// Substrate audit sequence at anchoring time
+    pub audit_sequence: u64,
+    /// Substrate audit entries at anchoring time
+    pub audit_entries: usize,
+    /// Anchors recorded back into the substrate audit log
+    pub anchors: Vec<LedgerAnchor>,
+    /// Session biokey reads as all zeros after teardown
+    pub session_key_zeroized: bool,
+}
+
+/// SHA3-256 helper
+pub fn sha3(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// Seed-derived identifier
+fn derive_id(seed: u32, label: &str, index: u64) -> [u8; 32] {
+    sha3(&[&seed.to_le_bytes(), label.as_bytes(), &index.to_le_bytes()])
+}
+
+/// Truncate a 32-byte hash to an Aethernet TXO identifier
+fn txo_id16(hash: &[u8; 32]) -> [u8; 16] {
+    let mut id = [0u8; 16];
+    id.copy_from_slice(&hash[..16]);
+    id
+}
+
+/// Aethernet TXO committing a content hash
+pub fn rtf_txo(
+    seed: u32,
+    content_hash: [u8; 32],
+    operation_class: OperationClass,
+    payload_type: PayloadType,
+    timestamp: u64,
+) -> TXO {
+    let sender = Sender {
+        identity_type: IdentityType::System,
+        id: txo_id16(&derive_id(seed, "sender", 0)),
+        biokey_present: false,
+        fido2_signed: false,
+        zk_proof: None,
+    };
+    let receiver = Receiver {
+        identity_type: IdentityType::Node,
+        id: txo_id16(&derive_id(seed, "receiver", 0)),
+    };
+    let payload = Payload {
+        payload_type,
+        content_hash,
+        encrypted: false,
+        envelope: None,
+    };
+
+    let mut txo = TXO::new(txo_id16(&content_hash), sender, receiver, operation_class, payload);
+    txo.timestamp = timestamp;
+    txo
+}
+
+/// Audit sink that commits each batch as an Aethernet Compliance TXO
+pub struct RtfSink<'a> {
+    /// Context the batches are committed into
+    pub rtf: &'a mut RTFContext,
+    /// Seed for TXO identities
+    pub seed: u32,
+    /// Commit timestamp
+    pub timestamp: u64,
+}
+
+impl AuditSink for RtfSink<'_> {
+    fn submit(&mut self, _batch: &AuditBatch, bytes: &[u8]) -> Result<LedgerReference, String> {
+        let mut txo = rtf_txo(
+            self.seed,
+            sha3(&[bytes]),
+            OperationClass::Compliance,
+            PayloadType::Audit,
+            self.timestamp,
+        );
+        self.rtf.execute_txo(&mut txo).map_err(|e| format!("{:?}", e))?;
+        self.rtf.commit_txo(&mut txo).map_err(|e| format!("{:?}", e))?;
+
+        Ok(LedgerReference {
+            txo_id: txo.txo_id,
+            ledger_root: self.rtf.ledger.get_current_root(),
+            epoch: self.rtf.current_epoch,
+        })
+    }
+}
+
+/// In-process stack
+pub struct Stack {
+    /// Scenario seed
+    pub seed: u32,
+    /// Q-Substrate runtime
+    pub substrate: QSubstrate,
+    /// Aethernet RTF context (staging zone, rollback allowed)
+    pub rtf: RTFContext,
+    /// QRATUM session ledger
+    pub session: RollbackLedger,
+    /// QRATUM quorum configuration
+    pub quorum_config: QuorumConfig,
+    /// QRATUM session key
+    pub biokey: EphemeralBiokey,
+}
+
+impl Stack {
+    /// Stand up every component from one seed
+    pub fn new(seed: u32) -> Self {
+        let config = QSubstrateConfig {
+            deterministic_seed: seed,
+            ..QSubstrateConfig::default()
+        };
+        let genesis = derive_id(seed, "genesis", 0);
+        let key_seed = derive_id(seed, "biokey", 0);
+
+        Stack {
+            seed,
+            substrate: QSubstrate::with_config(config),
+            rtf: RTFContext::new(Zone::Z1, RtfLedger::new(genesis)),
+            session: RollbackLedger::new(4),
+            quorum_config: QuorumConfig::default(),
+            biokey: EphemeralBiokey::derive(&[&key_seed], 0),
+        }
+    }
+
+    /// Seed-derived quorum members
+    pub fn quorum_members(&self) -> Vec<QuorumMember> {
+        (0..QUORUM_MEMBERS as u64)
+            .map(|i| QuorumMember {
+                id: derive_id(self.seed, "member", i),
+                reputation_stake: 100,
+                public_key: derive_id(self.seed, "member-key", i),
+                status: MemberStatus::Active,
+            })
+            .collect()
+    }
+}
+
+/// Run the full scenario for a seed
+pub fn run_scenario(seed: u32) -> Result<ScenarioReport, String> {
+    let mut stack = Stack::new(seed);
+    let time = BASE_TIME + seed as u64;
+
+    // Inference → input TXO
+    let embedding = stack.substrate.run_inference(PROMPT);
+    stack.substrate.classify_intent(PROMPT);
+    let embedding_bytes: Vec<u8> = embedding.iter().flat_map(|v| v.to_le_bytes()).collect();
+    let input = Txo::new(TxoType::Input, time * 1000, embedding_bytes.clone(), Vec::new());
+
+    // Quorum over the input TXO (all but one member vote)
+    let members = stack.quorum_members();
+    let mut quorum = QuorumState::new(&stack.quorum_config, members.clone());
+    for member in members.iter().take(QUORUM_MEMBERS - 1) {
+        quorum.add_vote(QuorumVote {
+            member_id: member.id,
+            payload: input.id.to_vec(),
+            signature: [0u8; 64],
+            timestamp: time * 1000,
+        })?;
+    }
+    let consensus = quorum.check_consensus();
+    if !consensus {
+        return Err("quorum did not converge".into());
+    }
+
+    // Session execution → outcome
+    stack.session.append(input.clone());
+    let execution_hash = stack.session.ledger().root_hash();
+    let quorum_proof: Vec<u8> = quorum.votes.iter().flat_map(|v| v.member_id).collect();
+    let outcome = OutcomeTxo {
+        txo: Txo::new(TxoType::Outcome, time * 1000, b"anomalies: none".to_vec(), vec![input.id]),
+        execution_hash,
+        quorum_proof,
+    };
+
+    // Snapshot of the session state under the session key
+    let key = *stack.biokey.key_material().ok_or("session key expired")?;
+    let snapshot = VolatileSnapshot::create(0, &execution_hash, &key);
+    let snapshot_restored = snapshot.restore(&key).ok().as_deref() == Some(&execution_hash[..]);
+
+    // Commit the outcome to Aethernet, then close epoch 0
+    let mut committed = rtf_txo(seed, outcome.execution_hash, OperationClass::Genomic, PayloadType::Metadata, time);
+    stack.rtf.execute_txo(&mut committed).map_err(|e| format!("{:?}", e))?;
+    stack.rtf.commit_txo(&mut committed).map_err(|e| format!("{:?}", e))?;
+    stack.rtf.current_epoch = 1;
+    stack.rtf.ledger.create_snapshot(1, time);
+    let rtf_root_committed = stack.rtf.ledger.get_current_root();
+
+    // A later, retracted commit on both ledgers, then rollback
+    stack.session.create_checkpoint();
+    let retracted = Txo::new(TxoType::Outcome, time * 1000 + 1, b"retracted".to_vec(), vec![outcome.txo.id]);
+    stack.session.append(retracted.clone());
+    stack.session.rollback()?;
+
+    let mut retracted_rtf = rtf_txo(seed, retracted.id, OperationClass::Genomic, PayloadType::Metadata, time + 1);
+    stack.rtf.execute_txo(&mut retracted_rtf).map_err(|e| format!("{:?}", e))?;
+    stack.rtf.commit_txo(&mut retracted_rtf).map_err(|e| format!("{:?}", e))?;
+    stack.rtf.current_epoch = 2;
+    stack.rtf.rollback_txo(1, "retracted outcome".into()).map_err(|e| format!("{:?}", e))?;
+    let rtf_root_after_rollback = stack.rtf.ledger.get_current_root();
+    let rtf_nodes_after_rollback = stack.rtf.ledger.node_count();
+
+    // Anchor the substrate audit log into the Aethernet ledger
+    let audit_sequence = stack.substrate.audit.get_sequence();
+    let audit_entries = stack.substrate.audit.get_entries().len();
+    let mut bridge = AuditBridge::new(BridgeCadence::default()).map_err(|e| e.to_string())?;
+    let mut sink = RtfSink { rtf: &mut stack.rtf, seed, timestamp: time + 2 };
+    let mut anchors = bridge.poll(&mut stack.substrate.audit, 0, &mut sink).map_err(|e| e.to_string())?;
+    while let Some(anchor) = bridge.flush(&mut stack.substrate.audit, 0, &mut sink).map_err(|e| e.to_string())? {
+        anchors.push(anchor);
+    }
+
+    // Teardown: session key is zeroized
+    stack.biokey.zeroize();
+    let session_key_zeroized = stack.biokey.key_material_unchecked().iter().all(|b| *b == 0);
+
+    Ok(ScenarioReport {
+        seed,
+        embedding_digest: sha3(&[&embedding_bytes]),
+        input_txo_id: input.id,
+        votes: quorum.votes.len(),
+        consensus,
+        execution_hash,
+        session_root: stack.session.ledger().root_hash(),
+        snapshot_restored,
+        rtf_root_committed,
+        rtf_root_after_rollback,
+        rtf_nodes_after_rollback,
+        rtf_final_root: stack.rtf.ledger.get_current_root(),
+        rtf_final_nodes: stack.rtf.ledger.node_count(),
+        audit_sequence,
+        audit_entries,
+        anchors,
+        session_key_zeroized,
+    })
+}
@@ -0,0 +1,82 @@
+//! End-to-end scenario across Aethernet, QRATUM and Q-Substrate
+//!
+//! Runs with seed 42 by default; reproduce any run with
+//! `INTEGRATION_SEED=<seed> cargo test`.
+
+use aethernet::biokey::derivation::{DevicePUF, EphemeralBiokey, SNPLocus, TemporalNonce};
+use integration_tests::{run_scenario, sha3, QUORUM_MEMBERS};
+
+fn seed() -> u32 {
+    std::env::var("INTEGRATION_SEED")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(42)
+}
+
+#[test]
+fn test_scenario_is_deterministic() {
+    let seed = seed();
+    let first = run_scenario(seed).unwrap();
+    let second = run_scenario(seed).unwrap();
+    assert_eq!(first, second);
+
+    let other = run_scenario(seed.wrapping_add(1)).unwrap();
+    assert_ne!(other.input_txo_id, first.input_txo_id);
+    assert_ne!(other.rtf_final_root, first.rtf_final_root);
+}
+
+#[test]
+fn test_quorum_and_ledger_invariants() {
+    let report = run_scenario(seed()).unwrap();
+
+    // Quorum: all but one member voted, which meets the 67% threshold
+    assert!(report.consensus);
+    assert_eq!(report.votes, QUORUM_MEMBERS - 1);
+
+    // Session rollback restores the executed state; snapshot round-trips it
+    assert_eq!(report.session_root, report.execution_hash);
+    assert!(report.snapshot_restored);
+
+    // Aethernet rollback restores the root committed before the retraction
+    assert_eq!(report.rtf_root_after_rollback, report.rtf_root_committed);
+    assert_eq!(report.rtf_nodes_after_rollback, 1);
+}
+
+#[test]
+fn test_audit_anchoring_invariants() {
+    let report = run_scenario(seed()).unwrap();
+
+    // One audit entry per substrate operation (inference + classification)
+    assert_eq!(report.audit_entries, 2);
+    assert_eq!(report.audit_sequence, report.audit_entries as u64);
+
+    // Anchors cover every entry exactly once, in order
+    let mut next = 1;
+    for anchor in &report.anchors {
+        assert_eq!(anchor.first_sequence, next);
+        assert!(anchor.last_sequence >= anchor.first_sequence);
+        next = anchor.last_sequence + 1;
+    }
+    assert_eq!(next, report.audit_sequence + 1);
+
+    // Each anchor is one committed Aethernet node; the last names the final root
+    assert_eq!(report.rtf_final_nodes, report.rtf_nodes_after_rollback + report.anchors.len());
+    assert_eq!(report.anchors.last().unwrap().ledger_root, report.rtf_final_root);
+}
+
+#[test]
+fn test_zeroization() {
+    let report = run_scenario(seed()).unwrap();
+    assert!(report.session_key_zeroized);
+
+    // Aethernet biokeys wipe the same way
+    let seed = seed();
+    let loci = [SNPLocus { chromosome: 1, position: seed as u64, ref_allele: b'A', alt_allele: b'G' }];
+    let puf = DevicePUF { cr_hash: sha3(&[&seed.to_le_bytes()]), device_id: [7u8; 16] };
+    let nonce = TemporalNonce { timestamp: 1_700_000_000, counter: 0, epoch_id: 0 };
+    let mut biokey = EphemeralBiokey::derive(&loci, &puf, b"salt", nonce, 60);
+    assert!(biokey.get_key_material().iter().any(|b| *b != 0));
+
+    biokey.wipe();
+    assert!(biokey.get_key_material().iter().all(|b| *b == 0));
+}
@@ -0,0 +1,51 @@
+//! Gate application benchmarks
+//!
+//! Runs Hadamard, CNOT and rotation layers over the full 12-qubit
+//! (4096-amplitude) state vector with SIMD kernels on and off. Build with the
+//! `simd` feature to measure the speedup; without it both variants run the
+//! scalar loops.
+//!
+//! ```text
+//! cargo bench --bench gates --features simd
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use q_substrate::quantum::{MiniQuASIM, QuantumGate, QUBITS};
+
+/// One layer of `make(q)` across every qubit
+fn layer(make: impl Fn(usize) -> QuantumGate) -> Vec<QuantumGate> {
+    (0..QUBITS).map(make).collect()
+}
+
+fn bench_gates(c: &mut Criterion) {
+    let layers = [
+        ("hadamard", layer(QuantumGate::Hadamard)),
+        ("cnot", layer(|q| QuantumGate::CNOT(q, (q + 1) % QUBITS))),
+        ("rx", layer(|q| QuantumGate::RX(q, 0.37))),
+        ("ry", layer(|q| QuantumGate::RY(q, 0.37))),
+        ("rz", layer(|q| QuantumGate::RZ(q, 0.37))),
+    ];
+
+    let mut group = c.benchmark_group("gate_layer");
+    for (name, gates) in &layers {
+        for (path, simd) in [("scalar", false), ("simd", true)] {
+            let mut sim = MiniQuASIM::new(42);
+            sim.set_simd(simd);
+            group.bench_function(format!("{}/{}", name, path), |b| {
+                b.iter(|| {
+                    for gate in gates {
+                        sim.apply_gate(black_box(gate));
+                    }
+                })
+            });
+        }
+    }
+    group.finish();
+
+    println!("SIMD kernels available: {}", q_substrate::simd::available());
+}
+
+criterion_group!(benches, bench_gates);
+criterion_main!(benches);
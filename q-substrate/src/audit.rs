@@ -84,6 +84,21 @@ pub struct RollbackPoint {
     pub description: String,
 }
 
+/// Reference to an external ledger record covering a range of audit entries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerAnchor {
+    /// First audit sequence covered
+    pub first_sequence: u64,
+    /// Last audit sequence covered (inclusive)
+    pub last_sequence: u64,
+    /// External TXO identifier
+    pub txo_id: [u8; 16],
+    /// Ledger root after the TXO was committed
+    pub ledger_root: [u8; 32],
+    /// Ledger epoch of the commit
+    pub epoch: u64,
+}
+
 /// Audit log manager
 pub struct AuditLog {
     /// Audit entries
@@ -94,6 +109,8 @@ pub struct AuditLog {
     invariant_checks: Vec<InvariantCheck>,
     /// Rollback points
     rollback_points: Vec<RollbackPoint>,
+    /// External ledger anchors
+    ledger_anchors: Vec<LedgerAnchor>,
     /// Current sequence number
     sequence: u64,
     /// Current timestamp
@@ -110,6 +127,7 @@ impl AuditLog {
             provenance: Vec::new(),
             invariant_checks: Vec::new(),
             rollback_points: Vec::new(),
+            ledger_anchors: Vec::new(),
             sequence: 0,
             timestamp: 0,
             enabled: true,
@@ -244,6 +262,23 @@ impl AuditLog {
         }
     }
 
+    /// Record that a range of entries was committed to an external ledger
+    pub fn record_ledger_anchor(&mut self, anchor: LedgerAnchor) {
+        self.ledger_anchors.push(anchor);
+    }
+
+    /// Find the ledger anchor covering a sequence number
+    pub fn get_ledger_anchor(&self, sequence: u64) -> Option<&LedgerAnchor> {
+        self.ledger_anchors
+            .iter()
+            .find(|a| a.first_sequence <= sequence && sequence <= a.last_sequence)
+    }
+
+    /// Get all ledger anchors
+    pub fn get_ledger_anchors(&self) -> &[LedgerAnchor] {
+        &self.ledger_anchors
+    }
+
     /// Get all entries
     pub fn get_entries(&self) -> &[AuditEntry] {
         &self.entries
@@ -302,6 +337,7 @@ impl AuditLog {
         self.provenance.clear();
         self.invariant_checks.clear();
         self.rollback_points.clear();
+        self.ledger_anchors.clear();
         self.sequence = 0;
         self.timestamp = 0;
     }
@@ -0,0 +1,360 @@
+//! Audit Bridge Module
+//!
+//! Streams the local audit log into an external TXO ledger:
+//! - Batches entries on a count or time cadence
+//! - Hands each batch to an `AuditSink` (e.g. an Aethernet RTF context)
+//! - Records the returned ledger reference back in the log as a `LedgerAnchor`
+//!
+//! The sink is responsible for wrapping the batch in a signed Audit TXO and
+//! committing it; the bridge only tracks which entries have been shipped.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use crate::audit::{AuditEntry, AuditLog, LedgerAnchor};
+
+/// Domain prefix of canonical batch bytes
+pub const BATCH_DOMAIN: &[u8] = b"QSUBSTRATE-AUDIT-BATCH-v1";
+
+/// When to emit a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BridgeCadence {
+    /// Flush once this many entries are pending (also the batch size limit)
+    pub max_entries: usize,
+    /// Flush pending entries after this many milliseconds
+    pub max_interval_ms: u64,
+}
+
+impl Default for BridgeCadence {
+    fn default() -> Self {
+        BridgeCadence {
+            max_entries: 64,
+            max_interval_ms: 60_000,
+        }
+    }
+}
+
+/// Contiguous range of audit entries shipped as one TXO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditBatch {
+    /// Batch number (0-based, per bridge)
+    pub batch_id: u64,
+    /// First audit sequence in the batch
+    pub first_sequence: u64,
+    /// Last audit sequence in the batch (inclusive)
+    pub last_sequence: u64,
+    /// Entries in sequence order
+    pub entries: Vec<AuditEntry>,
+}
+
+impl AuditBatch {
+    /// Deterministic bytes the sink hashes and signs
+    pub fn canonical_bytes(&self) -> Result<Vec<u8>, BridgeError> {
+        let body = serde_json::to_vec(self).map_err(|e| BridgeError::Encoding(alloc::format!("{}", e)))?;
+        let mut bytes = Vec::with_capacity(BATCH_DOMAIN.len() + body.len());
+        bytes.extend_from_slice(BATCH_DOMAIN);
+        bytes.extend_from_slice(&body);
+        Ok(bytes)
+    }
+}
+
+/// Ledger record returned by a sink
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerReference {
+    /// Committed TXO identifier
+    pub txo_id: [u8; 16],
+    /// Ledger root after the commit
+    pub ledger_root: [u8; 32],
+    /// Ledger epoch of the commit
+    pub epoch: u64,
+}
+
+/// Destination that turns a batch into a signed, committed Audit TXO
+pub trait AuditSink {
+    /// Submit a batch; `bytes` are its canonical bytes
+    fn submit(&mut self, batch: &AuditBatch, bytes: &[u8]) -> Result<LedgerReference, String>;
+}
+
+/// Bridge error types
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeError {
+    /// Cadence must allow at least one entry per batch
+    InvalidCadence,
+    /// Batch could not be serialized
+    Encoding(String),
+    /// Sink rejected the batch; entries stay pending
+    Sink(String),
+}
+
+impl core::fmt::Display for BridgeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BridgeError::InvalidCadence => write!(f, "Batch size must be at least 1"),
+            BridgeError::Encoding(e) => write!(f, "Batch encoding failed: {}", e),
+            BridgeError::Sink(e) => write!(f, "Audit sink rejected batch: {}", e),
+        }
+    }
+}
+
+/// Streaming emitter from an `AuditLog` into an `AuditSink`
+pub struct AuditBridge {
+    /// Emission cadence
+    cadence: BridgeCadence,
+    /// Highest sequence committed to the ledger
+    shipped_sequence: u64,
+    /// Time of the last flush (or of the first poll)
+    last_flush_ms: Option<u64>,
+    /// Batches committed
+    batches_sent: u64,
+}
+
+impl AuditBridge {
+    /// Create bridge starting at the beginning of the log
+    pub fn new(cadence: BridgeCadence) -> Result<Self, BridgeError> {
+        if cadence.max_entries == 0 {
+            return Err(BridgeError::InvalidCadence);
+        }
+        Ok(AuditBridge {
+            cadence,
+            shipped_sequence: 0,
+            last_flush_ms: None,
+            batches_sent: 0,
+        })
+    }
+
+    /// Highest sequence already anchored
+    pub fn shipped_sequence(&self) -> u64 {
+        self.shipped_sequence
+    }
+
+    /// Batches committed so far
+    pub fn batches_sent(&self) -> u64 {
+        self.batches_sent
+    }
+
+    /// Entries not yet shipped
+    pub fn pending(&self, log: &AuditLog) -> usize {
+        log.get_entries()
+            .iter()
+            .filter(|e| e.sequence > self.shipped_sequence)
+            .count()
+    }
+
+    /// Whether the cadence calls for a flush at `now_ms`
+    pub fn is_due(&self, log: &AuditLog, now_ms: u64) -> bool {
+        let pending = self.pending(log);
+        if pending == 0 {
+            return false;
+        }
+        if pending >= self.cadence.max_entries {
+            return true;
+        }
+        match self.last_flush_ms {
+            Some(last) => now_ms.saturating_sub(last) >= self.cadence.max_interval_ms,
+            None => false,
+        }
+    }
+
+    /// Emit every batch the cadence calls for
+    ///
+    /// Returns the anchors recorded in `log`. On a sink error the failed
+    /// batch stays pending and is retried on the next poll.
+    pub fn poll<S: AuditSink>(
+        &mut self,
+        log: &mut AuditLog,
+        now_ms: u64,
+        sink: &mut S,
+    ) -> Result<Vec<LedgerAnchor>, BridgeError> {
+        self.resync(log);
+        if self.last_flush_ms.is_none() {
+            self.last_flush_ms = Some(now_ms);
+        }
+
+        let mut anchors = Vec::new();
+        while self.is_due(log, now_ms) {
+            match self.flush(log, now_ms, sink)? {
+                Some(anchor) => anchors.push(anchor),
+                None => break,
+            }
+        }
+        Ok(anchors)
+    }
+
+    /// Emit up to `max_entries` pending entries regardless of cadence
+    pub fn flush<S: AuditSink>(
+        &mut self,
+        log: &mut AuditLog,
+        now_ms: u64,
+        sink: &mut S,
+    ) -> Result<Option<LedgerAnchor>, BridgeError> {
+        self.resync(log);
+
+        let entries: Vec<AuditEntry> = log
+            .get_entries()
+            .iter()
+            .filter(|e| e.sequence > self.shipped_sequence)
+            .take(self.cadence.max_entries)
+            .cloned()
+            .collect();
+        let (first, last) = match (entries.first(), entries.last()) {
+            (Some(first), Some(last)) => (first.sequence, last.sequence),
+            _ => return Ok(None),
+        };
+
+        let batch = AuditBatch {
+            batch_id: self.batches_sent,
+            first_sequence: first,
+            last_sequence: last,
+            entries,
+        };
+        let bytes = batch.canonical_bytes()?;
+        let reference = sink.submit(&batch, &bytes).map_err(BridgeError::Sink)?;
+
+        let anchor = LedgerAnchor {
+            first_sequence: first,
+            last_sequence: last,
+            txo_id: reference.txo_id,
+            ledger_root: reference.ledger_root,
+            epoch: reference.epoch,
+        };
+        log.record_ledger_anchor(anchor.clone());
+
+        self.shipped_sequence = last;
+        self.batches_sent += 1;
+        self.last_flush_ms = Some(now_ms);
+        Ok(Some(anchor))
+    }
+
+    /// Restart from the beginning if the log was cleared
+    fn resync(&mut self, log: &AuditLog) {
+        if log.get_sequence() < self.shipped_sequence {
+            self.shipped_sequence = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sink that assigns sequential TXO ids
+    struct RecordingSink {
+        batches: Vec<AuditBatch>,
+        fail: bool,
+    }
+
+    impl AuditSink for RecordingSink {
+        fn submit(&mut self, batch: &AuditBatch, bytes: &[u8]) -> Result<LedgerReference, String> {
+            if self.fail {
+                return Err("ledger offline".into());
+            }
+            assert!(bytes.starts_with(BATCH_DOMAIN));
+            self.batches.push(batch.clone());
+            let n = self.batches.len() as u8;
+            Ok(LedgerReference {
+                txo_id: [n; 16],
+                ledger_root: [n; 32],
+                epoch: 1,
+            })
+        }
+    }
+
+    fn sink() -> RecordingSink {
+        RecordingSink { batches: Vec::new(), fail: false }
+    }
+
+    fn log_with(count: usize) -> AuditLog {
+        let mut log = AuditLog::new();
+        for i in 0..count {
+            log.log_operation("op", i);
+        }
+        log
+    }
+
+    #[test]
+    fn test_count_cadence_batches() {
+        let cadence = BridgeCadence { max_entries: 3, max_interval_ms: 1_000 };
+        let mut bridge = AuditBridge::new(cadence).unwrap();
+        let mut log = log_with(7);
+        let mut sink = sink();
+
+        let anchors = bridge.poll(&mut log, 0, &mut sink).unwrap();
+        assert_eq!(anchors.len(), 2);
+        assert_eq!((anchors[1].first_sequence, anchors[1].last_sequence), (4, 6));
+        assert_eq!(bridge.pending(&log), 1);
+        assert_eq!(sink.batches[1].batch_id, 1);
+
+        // Cross-reference from a local entry to its TXO
+        assert_eq!(log.get_ledger_anchor(5).unwrap().txo_id, [2; 16]);
+        assert!(log.get_ledger_anchor(7).is_none());
+    }
+
+    #[test]
+    fn test_time_cadence_flushes_partial_batch() {
+        let cadence = BridgeCadence { max_entries: 10, max_interval_ms: 500 };
+        let mut bridge = AuditBridge::new(cadence).unwrap();
+        let mut log = log_with(2);
+        let mut sink = sink();
+
+        assert!(bridge.poll(&mut log, 100, &mut sink).unwrap().is_empty());
+        assert!(bridge.poll(&mut log, 599, &mut sink).unwrap().is_empty());
+        let anchors = bridge.poll(&mut log, 600, &mut sink).unwrap();
+        assert_eq!(anchors.len(), 1);
+        assert_eq!(anchors[0].last_sequence, 2);
+
+        // Nothing pending: elapsed time alone does not emit
+        assert!(bridge.poll(&mut log, 5_000, &mut sink).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sink_failure_keeps_entries_pending() {
+        let cadence = BridgeCadence { max_entries: 2, max_interval_ms: 1_000 };
+        let mut bridge = AuditBridge::new(cadence).unwrap();
+        let mut log = log_with(2);
+        let mut failing = RecordingSink { batches: Vec::new(), fail: true };
+
+        assert_eq!(
+            bridge.poll(&mut log, 0, &mut failing),
+            Err(BridgeError::Sink("ledger offline".into()))
+        );
+        assert_eq!(bridge.pending(&log), 2);
+        assert!(log.get_ledger_anchors().is_empty());
+
+        let mut ok = sink();
+        assert_eq!(bridge.poll(&mut log, 1, &mut ok).unwrap().len(), 1);
+        assert_eq!(ok.batches[0].batch_id, 0);
+    }
+
+    #[test]
+    fn test_cleared_log_restarts() {
+        let mut bridge = AuditBridge::new(BridgeCadence::default()).unwrap();
+        let mut log = log_with(3);
+        let mut sink = sink();
+        bridge.flush(&mut log, 0, &mut sink).unwrap();
+        assert_eq!(bridge.shipped_sequence(), 3);
+
+        log.clear();
+        log.log_operation("after_clear", 1);
+        let anchor = bridge.flush(&mut log, 1, &mut sink).unwrap().unwrap();
+        assert_eq!((anchor.first_sequence, anchor.last_sequence), (1, 1));
+        assert_eq!(sink.batches[1].entries[0].operation, "after_clear");
+    }
+
+    #[test]
+    fn test_canonical_bytes_deterministic() {
+        let log = log_with(2);
+        let batch = AuditBatch {
+            batch_id: 0,
+            first_sequence: 1,
+            last_sequence: 2,
+            entries: log.get_entries().to_vec(),
+        };
+        assert_eq!(batch.canonical_bytes().unwrap(), batch.clone().canonical_bytes().unwrap());
+
+        let empty = BridgeCadence { max_entries: 0, max_interval_ms: 0 };
+        assert_eq!(AuditBridge::new(empty).err(), Some(BridgeError::InvalidCadence));
+    }
+}
@@ -5,9 +5,10 @@
 //! cryptography, and industrial design.
 
 use q_substrate::discovery::{
-    run_discovery_directive, validate_discovery_schema,
+    run_constrained_directive, validate_discovery_schema, ConstraintSet,
     import_discoveries_json, generate_provenance_hash, verify_provenance_chain,
-    generate_provenance_report, Discovery,
+    generate_provenance_report, render_discovery_dossier, render_run_dossier, Discovery,
+    DossierFormat,
 };
 use std::env;
 use std::fs;
@@ -64,6 +65,7 @@ fn cmd_run(args: &[String]) {
     let mut target = 100;
     let mut threshold = 0.87;
     let mut output = "qratum/discoveries/pending";
+    let mut constraints_expr = String::new();
     
     let mut i = 0;
     while i < args.len() {
@@ -104,6 +106,34 @@ fn cmd_run(args: &[String]) {
                     process::exit(1);
                 }
             }
+            "--constraints" => {
+                if i + 1 < args.len() {
+                    constraints_expr.push_str(&args[i + 1]);
+                    constraints_expr.push(';');
+                    i += 2;
+                } else {
+                    eprintln!("--constraints requires a value");
+                    process::exit(1);
+                }
+            }
+            "--constraints-file" => {
+                if i + 1 < args.len() {
+                    match fs::read_to_string(&args[i + 1]) {
+                        Ok(content) => {
+                            constraints_expr.push_str(&content);
+                            constraints_expr.push(';');
+                        }
+                        Err(e) => {
+                            eprintln!("Failed to read {}: {}", args[i + 1], e);
+                            process::exit(1);
+                        }
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("--constraints-file requires a value");
+                    process::exit(1);
+                }
+            }
             "--lattice-axes" | "--nodes-per-axis" | "--mutations" => {
                 // Accept but ignore these for now (already hardcoded in implementation)
                 i += 2;
@@ -119,6 +149,8 @@ fn cmd_run(args: &[String]) {
                 println!("    --target <N>             Target discovery count (default: 100)");
                 println!("    --threshold <F>          Fitness threshold (default: 0.87)");
                 println!("    --output <DIR>           Output directory (default: qratum/discoveries/pending)");
+                println!("    --constraints <EXPR>     Search bounds, e.g. \"only materials, computation; exclude physics.AnyonBraiding\"");
+                println!("    --constraints-file <F>   Read search bounds from a file (one clause per line)");
                 println!("    --lattice-axes <N>       Number of lattice axes (default: 5)");
                 println!("    --nodes-per-axis <N>     Nodes per axis (default: 8)");
                 println!("    --mutations <LIST>       Comma-separated mutation list");
@@ -134,6 +166,14 @@ fn cmd_run(args: &[String]) {
     // Convert seed string to numeric seed
     let seed = seed_str.bytes().fold(0u32, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u32));
     
+    let constraints = match ConstraintSet::parse(&constraints_expr) {
+        Ok(constraints) => constraints,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    
     println!("═══════════════════════════════════════════════════════════════");
     println!("   QRATUM DISCOVERY DIRECTIVE - GENERATION PHASE");
     println!("═══════════════════════════════════════════════════════════════");
@@ -143,6 +183,9 @@ fn cmd_run(args: &[String]) {
     println!("  Target: {} discoveries", target);
     println!("  Fitness threshold: F ≥ {:.2}", threshold);
     println!("  Output directory: {}", output);
+    if let Some(hash) = constraints.hash() {
+        println!("  Constraints: {} ({})", constraints, hash);
+    }
     println!();
     
     // Create output directory
@@ -166,6 +209,18 @@ fn cmd_run(args: &[String]) {
         process::exit(1);
     }
     println!("✓ Seed persisted to {}", seed_lock_path);
+    
+    // Persist the search bounds next to the seed so the hash in provenance can be resolved
+    let constraints_path = format!("{}/.constraints", output);
+    if constraints.is_empty() {
+        let _ = fs::remove_file(&constraints_path);
+    } else {
+        if let Err(e) = fs::write(&constraints_path, format!("{}\n", constraints)) {
+            eprintln!("Failed to write constraints file: {}", e);
+            process::exit(1);
+        }
+        println!("✓ Constraints persisted to {}", constraints_path);
+    }
     println!();
     
     // 5. WASM POD ATTESTATION: Verify execution environment
@@ -183,7 +238,7 @@ fn cmd_run(args: &[String]) {
     println!("Starting recursive discovery engine...");
     println!();
     
-    match run_discovery_directive(seed, target, Some(output)) {
+    match run_constrained_directive(seed, target, Some(output), constraints) {
         Ok(report) => {
             println!("═══════════════════════════════════════════════════════════════");
             println!("   GENERATION COMPLETE");
@@ -568,6 +623,8 @@ fn cmd_archive(args: &[String]) {
 
 fn cmd_report(args: &[String]) {
     let mut validated_dir = "qratum/discoveries/validated";
+    let mut format = None;
+    let mut out_dir = "qratum/discoveries/dossiers";
     
     let mut i = 0;
     while i < args.len() {
@@ -581,6 +638,33 @@ fn cmd_report(args: &[String]) {
                     process::exit(1);
                 }
             }
+            "--format" => {
+                if i + 1 < args.len() {
+                    format = match args[i + 1].as_str() {
+                        "text" => None,
+                        name => match DossierFormat::parse(name) {
+                            Some(f) => Some(f),
+                            None => {
+                                eprintln!("Unknown format: {} (expected text, markdown or html)", name);
+                                process::exit(1);
+                            }
+                        },
+                    };
+                    i += 2;
+                } else {
+                    eprintln!("--format requires a value");
+                    process::exit(1);
+                }
+            }
+            "--out" => {
+                if i + 1 < args.len() {
+                    out_dir = &args[i + 1];
+                    i += 2;
+                } else {
+                    eprintln!("--out requires a value");
+                    process::exit(1);
+                }
+            }
             "--help" | "-h" => {
                 println!("Generate verification report");
                 println!();
@@ -589,6 +673,8 @@ fn cmd_report(args: &[String]) {
                 println!();
                 println!("OPTIONS:");
                 println!("    --validated <DIR>    Validated directory (default: qratum/discoveries/validated)");
+                println!("    --format <FORMAT>    text, markdown or html (default: text)");
+                println!("    --out <DIR>          Dossier output directory (default: qratum/discoveries/dossiers)");
                 process::exit(0);
             }
             _ => {
@@ -598,6 +684,11 @@ fn cmd_report(args: &[String]) {
         }
     }
     
+    if let Some(format) = format {
+        write_dossiers(validated_dir, out_dir, format);
+        return;
+    }
+    
     println!("═══════════════════════════════════════════════════════════════");
     println!("   QRATUM DISCOVERY DIRECTIVE REPORT");
     println!("═══════════════════════════════════════════════════════════════");
@@ -651,6 +742,37 @@ fn cmd_report(args: &[String]) {
     println!("═══════════════════════════════════════════════════════════════");
 }
 
+fn write_dossiers(validated_dir: &str, out_dir: &str, format: DossierFormat) {
+    let discoveries = load_discoveries_from_dir(validated_dir);
+    if discoveries.is_empty() {
+        println!("No validated discoveries found in {}", validated_dir);
+        return;
+    }
+    
+    if let Err(e) = fs::create_dir_all(out_dir) {
+        eprintln!("Failed to create {}: {}", out_dir, e);
+        process::exit(1);
+    }
+    
+    let ext = format.extension();
+    for discovery in &discoveries {
+        let path = format!("{}/{}.{}", out_dir, discovery.id, ext);
+        if let Err(e) = fs::write(&path, render_discovery_dossier(discovery, format)) {
+            eprintln!("Failed to write {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+    
+    let run_path = format!("{}/RUN_DOSSIER.{}", out_dir, ext);
+    let run = render_run_dossier("QRATUM Discovery Dossier", &discoveries, format);
+    if let Err(e) = fs::write(&run_path, run) {
+        eprintln!("Failed to write {}: {}", run_path, e);
+        process::exit(1);
+    }
+    
+    println!("✓ Wrote {} discovery dossiers and {}", discoveries.len(), run_path);
+}
+
 fn load_discoveries_from_dir(dir: &str) -> Vec<Discovery> {
     let mut discoveries = Vec::new();
     
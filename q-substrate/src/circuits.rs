@@ -0,0 +1,306 @@
+//! Circuit Library
+//!
+//! Parameterized builders for common algorithms, emitting the Circuit IR
+//! ([`Circuit`]) so applications don't hand-write long gate sequences:
+//! - Grover search: phase oracle over marked basis states, diffusion, and
+//!   the full search at the optimal iteration count
+//! - Quantum Fourier transform and its inverse
+//! - Phase estimation, for a phase gate or a caller-supplied controlled unitary
+//!
+//! Basis states are little-endian as in [`MiniQuASIM`](crate::MiniQuASIM):
+//! qubit `q` is bit `q` of the state index. The gate set has no
+//! controlled-phase gate, so CP(θ) is built from RZ and CNOT, exact up to a
+//! global phase. Multi-controlled Z over more than three qubits runs a
+//! Toffoli ladder through ancilla qubits placed after the search register;
+//! ancillas start and end in |0⟩.
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+use crate::quantum::{Circuit, CircuitError, QuantumGate};
+#[cfg(not(feature = "std"))]
+use crate::float::FloatExt;
+
+/// Ancilla qubits a multi-controlled Z over `n` qubits needs
+pub fn mcz_ancillas(n: usize) -> usize {
+    n.saturating_sub(3)
+}
+
+/// Controlled phase diag(1, 1, 1, e^{iθ}), up to a global phase e^{-iθ/4}
+pub fn controlled_phase(control: usize, target: usize, theta: f32) -> [QuantumGate; 5] {
+    [
+        QuantumGate::RZ(target, theta / 2.0),
+        QuantumGate::CNOT(control, target),
+        QuantumGate::RZ(target, -theta / 2.0),
+        QuantumGate::CNOT(control, target),
+        QuantumGate::RZ(control, theta / 2.0),
+    ]
+}
+
+/// Grover iterations maximizing the success probability for `marked` of 2^n states
+pub fn optimal_grover_iterations(n: usize, marked: usize) -> usize {
+    let total = (1u64 << n.min(63)) as f64;
+    let marked = (marked as f64).min(total);
+    if marked == 0.0 {
+        return 0;
+    }
+    // sin θ = √(M/N); the success amplitude after k rounds is sin((2k+1)θ)
+    let theta = marked.sqrt().atan2((total - marked).sqrt());
+    (PI / (4.0 * theta)) as usize
+}
+
+/// Phase oracle flipping the sign of each marked basis state
+///
+/// The circuit spans `n` search qubits plus [`mcz_ancillas`]`(n)` ancillas.
+pub fn grover_oracle(n: usize, marked: &[usize]) -> Result<Circuit, CircuitError> {
+    let marked = validate_search(n, marked)?;
+    let mut gates = Vec::new();
+    push_oracle(&mut gates, n, &marked);
+    Circuit::from_gates(n + mcz_ancillas(n), gates)
+}
+
+/// Diffusion operator 2|s⟩⟨s| − I over `n` search qubits (up to global phase)
+pub fn grover_diffusion(n: usize) -> Result<Circuit, CircuitError> {
+    if n == 0 {
+        return Err(CircuitError::InvalidParameter("Grover search needs at least one qubit".into()));
+    }
+    let mut gates = Vec::new();
+    push_diffusion(&mut gates, n);
+    Circuit::from_gates(n + mcz_ancillas(n), gates)
+}
+
+/// Full Grover search for the marked states
+///
+/// Prepares the uniform superposition and applies oracle and diffusion
+/// `iterations` times (`None` = [`optimal_grover_iterations`]). Measuring
+/// the search qubits then yields a marked state with high probability.
+pub fn grover(n: usize, marked: &[usize], iterations: Option<usize>) -> Result<Circuit, CircuitError> {
+    let marked = validate_search(n, marked)?;
+    let rounds = iterations.unwrap_or_else(|| optimal_grover_iterations(n, marked.len()));
+
+    let mut gates: Vec<QuantumGate> = (0..n).map(QuantumGate::Hadamard).collect();
+    for _ in 0..rounds {
+        push_oracle(&mut gates, n, &marked);
+        push_diffusion(&mut gates, n);
+    }
+    Circuit::from_gates(n + mcz_ancillas(n), gates)
+}
+
+/// Quantum Fourier transform |x⟩ → 2^{-n/2} Σ_y e^{2πixy/2^n} |y⟩ over `n` qubits
+pub fn qft(n: usize) -> Result<Circuit, CircuitError> {
+    let qubits: Vec<usize> = (0..n).collect();
+    let mut gates = Vec::new();
+    push_qft(&mut gates, &qubits);
+    Circuit::from_gates(n, gates)
+}
+
+/// Inverse quantum Fourier transform over `n` qubits
+pub fn inverse_qft(n: usize) -> Result<Circuit, CircuitError> {
+    qft(n)?.inverse()
+}
+
+/// Phase estimation for a caller-supplied unitary U
+///
+/// Qubits `0..precision` form the counting register; the system register
+/// follows at `precision..precision + system_qubits`. `prepare` (absolute
+/// qubit indices) loads an eigenstate of U into the system register, and
+/// `controlled_power(control, power)` returns gates applying U^power to the
+/// system register controlled on `control`. Measuring the counting register
+/// yields y with y / 2^precision ≈ φ, where U|ψ⟩ = e^{2πiφ}|ψ⟩.
+pub fn phase_estimation_with<F>(
+    precision: usize,
+    system_qubits: usize,
+    prepare: &[QuantumGate],
+    controlled_power: F,
+) -> Result<Circuit, CircuitError>
+where
+    F: Fn(usize, u64) -> Vec<QuantumGate>,
+{
+    if precision == 0 || precision > 63 {
+        return Err(CircuitError::InvalidParameter(format!(
+            "Phase estimation precision of {} qubits",
+            precision
+        )));
+    }
+    let mut circuit = Circuit::new(precision + system_qubits)?;
+
+    let mut gates = prepare.to_vec();
+    gates.extend((0..precision).map(QuantumGate::Hadamard));
+    for k in 0..precision {
+        gates.extend(controlled_power(k, 1 << k));
+    }
+    let counting: Vec<usize> = (0..precision).collect();
+    push_inverse_qft(&mut gates, &counting);
+
+    for gate in gates {
+        circuit.push(gate)?;
+    }
+    Ok(circuit)
+}
+
+/// Phase estimation of the phase gate P(2πφ) on its |1⟩ eigenstate
+///
+/// The circuit has `precision + 1` qubits; the last is the system qubit.
+pub fn phase_estimation(precision: usize, phase: f32) -> Result<Circuit, CircuitError> {
+    let system = precision;
+    phase_estimation_with(precision, 1, &[QuantumGate::PauliX(system)], |control, power| {
+        // Reduce 2^k·φ mod 1 in f64 so high powers keep their precision
+        let turns = (phase as f64 * power as f64) % 1.0;
+        controlled_phase(control, system, (2.0 * PI * turns) as f32).to_vec()
+    })
+}
+
+/// Sorted, deduplicated marked states, or why the search is invalid
+fn validate_search(n: usize, marked: &[usize]) -> Result<Vec<usize>, CircuitError> {
+    // Register width first, so 1 << n cannot overflow
+    Circuit::new(n + mcz_ancillas(n))?;
+    if n == 0 {
+        return Err(CircuitError::InvalidParameter("Grover search needs at least one qubit".into()));
+    }
+    if marked.is_empty() {
+        return Err(CircuitError::InvalidParameter("Grover search needs a marked state".into()));
+    }
+    if let Some(&state) = marked.iter().find(|&&state| state >> n != 0) {
+        return Err(CircuitError::InvalidParameter(format!(
+            "Marked state {} outside the {}-qubit register",
+            state, n
+        )));
+    }
+    let mut marked = marked.to_vec();
+    marked.sort_unstable();
+    marked.dedup();
+    Ok(marked)
+}
+
+fn push_oracle(gates: &mut Vec<QuantumGate>, n: usize, marked: &[usize]) {
+    for &state in marked {
+        let flips: Vec<QuantumGate> =
+            (0..n).filter(|q| (state >> q) & 1 == 0).map(QuantumGate::PauliX).collect();
+        gates.extend(flips.iter().cloned());
+        push_mcz(gates, n);
+        gates.extend(flips);
+    }
+}
+
+fn push_diffusion(gates: &mut Vec<QuantumGate>, n: usize) {
+    gates.extend((0..n).map(QuantumGate::Hadamard));
+    gates.extend((0..n).map(QuantumGate::PauliX));
+    push_mcz(gates, n);
+    gates.extend((0..n).map(QuantumGate::PauliX));
+    gates.extend((0..n).map(QuantumGate::Hadamard));
+}
+
+/// Z on |1…1⟩ of qubits 0..n, with ancillas at n..
+fn push_mcz(gates: &mut Vec<QuantumGate>, n: usize) {
+    match n {
+        0 => {}
+        1 => gates.push(QuantumGate::PauliZ(0)),
+        2 => gates.push(QuantumGate::CZ(0, 1)),
+        _ => {
+            let target = n - 1;
+            let controls = n - 1;
+            // Ancilla a holds the AND of controls 0..=a+1
+            let ladder: Vec<QuantumGate> = (0..controls.saturating_sub(2))
+                .map(|a| {
+                    let previous = if a == 0 { 0 } else { n + a - 1 };
+                    QuantumGate::Toffoli(previous, a + 1, n + a)
+                })
+                .collect();
+            let last = if controls == 2 { 0 } else { n + controls - 3 };
+
+            gates.extend(ladder.iter().cloned());
+            gates.push(QuantumGate::Hadamard(target));
+            gates.push(QuantumGate::Toffoli(last, controls - 1, target));
+            gates.push(QuantumGate::Hadamard(target));
+            gates.extend(ladder.into_iter().rev());
+        }
+    }
+}
+
+fn push_qft(gates: &mut Vec<QuantumGate>, qubits: &[usize]) {
+    let n = qubits.len();
+    // Qubit j ends holding output bit n-1-j, hence the final swaps
+    for j in (0..n).rev() {
+        gates.push(QuantumGate::Hadamard(qubits[j]));
+        for k in (0..j).rev() {
+            let theta = (PI / (1u64 << (j - k)) as f64) as f32;
+            gates.extend(controlled_phase(qubits[k], qubits[j], theta));
+        }
+    }
+    for j in 0..n / 2 {
+        gates.push(QuantumGate::SWAP(qubits[j], qubits[n - 1 - j]));
+    }
+}
+
+fn push_inverse_qft(gates: &mut Vec<QuantumGate>, qubits: &[usize]) {
+    let mut forward = Vec::new();
+    push_qft(&mut forward, qubits);
+    // QFT gates are all unitary, so every adjoint exists
+    gates.extend(forward.iter().rev().flat_map(|gate| gate.adjoint().unwrap_or_default()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantum::MiniQuASIM;
+
+    #[test]
+    fn test_grover_finds_marked_states() {
+        // 3 qubits, one marked state: 2 rounds, P ≈ 0.945
+        let circuit = grover(3, &[5], None).unwrap();
+        assert_eq!(circuit.num_qubits(), 3);
+        let mut sim = MiniQuASIM::new(1);
+        circuit.run(&mut sim).unwrap();
+        assert!(sim.get_probabilities()[5] > 0.94);
+
+        // 5 qubits need two ancillas for the multi-controlled Z
+        let circuit = grover(5, &[17, 3, 17], None).unwrap();
+        assert_eq!(circuit.num_qubits(), 7);
+        assert_eq!(optimal_grover_iterations(5, 2), 3);
+        let mut sim = MiniQuASIM::new(1);
+        circuit.run(&mut sim).unwrap();
+        let probs = sim.get_probabilities();
+        assert!(probs[3] + probs[17] > 0.95);
+        assert!(probs[32..].iter().all(|&p| p < 1e-5), "ancillas returned to |0⟩");
+
+        assert!(grover(3, &[], None).is_err());
+        assert!(grover(3, &[8], None).is_err());
+        assert!(matches!(grover(40, &[0], None), Err(CircuitError::RegisterTooWide(_))));
+    }
+
+    #[test]
+    fn test_qft_and_phase_estimation() {
+        // QFT|x⟩ has uniform magnitude and phase 2πxy/N relative to y = 0
+        let n = 3;
+        let x = 3;
+        let mut circuit = Circuit::new(n).unwrap();
+        circuit.push(QuantumGate::PauliX(0)).unwrap();
+        circuit.push(QuantumGate::PauliX(1)).unwrap();
+        let qft = qft(n).unwrap();
+        let mut sim = MiniQuASIM::new(1);
+        circuit.run(&mut sim).unwrap();
+        qft.run(&mut sim).unwrap();
+        let mut states = sim.get_state_info(1 << n);
+        states.sort_by_key(|s| s.state_index);
+        for s in &states {
+            assert!((s.probability - 0.125).abs() < 1e-5);
+            let expected = 2.0 * core::f32::consts::PI * (x * s.state_index) as f32 / 8.0;
+            let delta = (s.phase - states[0].phase - expected).rem_euclid(2.0 * core::f32::consts::PI);
+            assert!(delta < 1e-3 || delta > 2.0 * core::f32::consts::PI - 1e-3);
+        }
+
+        // Inverse QFT undoes it
+        inverse_qft(n).unwrap().run(&mut sim).unwrap();
+        assert!(sim.get_probabilities()[x] > 0.9999);
+
+        // φ = 5/16 is exact in 4 counting qubits; the system qubit stays |1⟩
+        let circuit = phase_estimation(4, 5.0 / 16.0).unwrap();
+        assert_eq!(circuit.num_qubits(), 5);
+        let mut sim = MiniQuASIM::new(1);
+        circuit.run(&mut sim).unwrap();
+        assert!(sim.get_probabilities()[5 | 1 << 4] > 0.999);
+
+        assert!(phase_estimation(0, 0.5).is_err());
+    }
+}
@@ -0,0 +1,274 @@
+//! Deterministic Code Formatting
+//!
+//! Embedded pretty-printer run on every DCGE emission:
+//! - Pinned per-language style (no config files, no external process)
+//! - Re-indentation from delimiter depth (Rust, JavaScript, C) or block
+//!   structure (Python)
+//! - Whitespace collapsing outside strings and comments
+//! - Blank-line limits, LF line endings, single trailing newline
+//!
+//! Output depends only on the input text and `STYLE_VERSION`, so the same
+//! AST formats byte-identically on every platform. Formatting is idempotent.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use crate::dcge::Language;
+
+/// Formatting algorithm version; bump on any output change
+pub const STYLE_VERSION: u32 = 1;
+
+/// Style settings for one language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormatStyle {
+    /// Spaces per indentation level
+    pub indent_width: usize,
+    /// Columns a tab counts for when reading indentation
+    pub tab_width: usize,
+    /// Maximum consecutive blank lines kept
+    pub max_blank_lines: usize,
+}
+
+impl FormatStyle {
+    /// The pinned style for a language (rustfmt, PEP 8, Prettier, K&R)
+    pub fn pinned(language: &Language) -> Self {
+        match language {
+            Language::Rust => FormatStyle { indent_width: 4, tab_width: 4, max_blank_lines: 1 },
+            Language::Python => FormatStyle { indent_width: 4, tab_width: 8, max_blank_lines: 2 },
+            Language::JavaScript => FormatStyle { indent_width: 2, tab_width: 2, max_blank_lines: 1 },
+            Language::C => FormatStyle { indent_width: 4, tab_width: 4, max_blank_lines: 1 },
+        }
+    }
+}
+
+/// Format source with the language's pinned style
+pub fn format_source(source: &str, language: &Language) -> String {
+    format_with(source, language, &FormatStyle::pinned(language))
+}
+
+/// Format source with an explicit style
+pub fn format_with(source: &str, language: &Language, style: &FormatStyle) -> String {
+    let lines = match language {
+        Language::Python => layout_python(source, style),
+        _ => layout_braces(source, language),
+    };
+    finish(lines, style)
+}
+
+/// A formatted line: indentation level and content (empty for blank lines)
+type Line = (usize, String);
+
+/// Indent lines by delimiter depth
+fn layout_braces(source: &str, language: &Language) -> Vec<Line> {
+    let mut out = Vec::new();
+    let mut depth: isize = 0;
+    let mut in_block_comment = false;
+
+    for raw in source.lines() {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            out.push((0, String::new()));
+            continue;
+        }
+
+        if in_block_comment {
+            let (text, _) = scan_line(trimmed, language, &mut in_block_comment);
+            // Align comment continuation stars under the opening `/*`
+            let text = if text.starts_with('*') { alloc::format!(" {}", text) } else { text };
+            out.push((depth.max(0) as usize, text));
+            continue;
+        }
+
+        let (text, net) = scan_line(trimmed, language, &mut in_block_comment);
+        let closers = text.chars().take_while(|c| matches!(c, '}' | ')' | ']')).count() as isize;
+        out.push(((depth - closers).max(0) as usize, text));
+        depth += net;
+    }
+    out
+}
+
+/// Indent lines by Python block structure
+fn layout_python(source: &str, style: &FormatStyle) -> Vec<Line> {
+    let mut out = Vec::new();
+    // Original column widths of the open blocks
+    let mut widths: Vec<usize> = alloc::vec![0];
+    let mut brackets: isize = 0;
+    let mut unused = false;
+
+    for raw in source.lines() {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            out.push((0, String::new()));
+            continue;
+        }
+
+        let (text, net) = scan_line(trimmed, &Language::Python, &mut unused);
+        let level = if brackets > 0 {
+            // Continuation inside an open bracket
+            widths.len()
+        } else {
+            let width = leading_width(raw, style.tab_width);
+            while widths.len() > 1 && width < *widths.last().unwrap_or(&0) {
+                widths.pop();
+            }
+            if width > *widths.last().unwrap_or(&0) {
+                widths.push(width);
+            }
+            widths.len() - 1
+        };
+        out.push((level, text));
+        brackets = (brackets + net).max(0);
+    }
+    out
+}
+
+/// Column width of leading whitespace
+fn leading_width(line: &str, tab_width: usize) -> usize {
+    line.chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .fold(0, |col, c| if c == '\t' { col + tab_width - col % tab_width } else { col + 1 })
+}
+
+/// Collapse code whitespace and return the line's net delimiter depth
+///
+/// Strings and comments are copied verbatim and their delimiters ignored.
+fn scan_line(line: &str, language: &Language, in_block_comment: &mut bool) -> (String, isize) {
+    let python = *language == Language::Python;
+    let mut out = String::with_capacity(line.len());
+    let mut net = 0;
+    let mut space = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if *in_block_comment {
+            out.push(c);
+            if c == '*' && chars.peek() == Some(&'/') {
+                out.extend(chars.next());
+                *in_block_comment = false;
+            }
+            continue;
+        }
+        if c == ' ' || c == '\t' {
+            space = true;
+            continue;
+        }
+        if space {
+            out.push(' ');
+            space = false;
+        }
+
+        let line_comment = if python { c == '#' } else { c == '/' && chars.peek() == Some(&'/') };
+        if line_comment {
+            out.push(c);
+            out.extend(chars.by_ref());
+            break;
+        }
+        if !python && c == '/' && chars.peek() == Some(&'*') {
+            out.push(c);
+            out.extend(chars.next());
+            *in_block_comment = true;
+            continue;
+        }
+
+        out.push(c);
+        // Rust `'` also starts lifetimes, so only `"` delimits strings there
+        let quote = c == '"' || (c == '\'' && *language != Language::Rust);
+        if quote {
+            while let Some(s) = chars.next() {
+                out.push(s);
+                if s == '\\' {
+                    out.extend(chars.next());
+                } else if s == c {
+                    break;
+                }
+            }
+            continue;
+        }
+        match c {
+            '{' | '(' | '[' => net += 1,
+            '}' | ')' | ']' => net -= 1,
+            _ => {}
+        }
+    }
+    (String::from(out.trim_end()), net)
+}
+
+/// Apply blank-line rules and render with LF endings
+fn finish(lines: Vec<Line>, style: &FormatStyle) -> String {
+    let mut out = String::new();
+    let mut blanks = 0;
+    let mut prev: Option<&str> = None;
+
+    for (level, text) in &lines {
+        if text.is_empty() {
+            blanks += 1;
+            continue;
+        }
+        // No blank lines at the start, after an opener or before a closer
+        let keep = match prev {
+            None => 0,
+            Some(p) if p.ends_with(['{', '(', '[', ':']) => 0,
+            Some(_) if text.starts_with(['}', ')', ']']) => 0,
+            Some(_) => blanks.min(style.max_blank_lines),
+        };
+        for _ in 0..keep {
+            out.push('\n');
+        }
+        blanks = 0;
+
+        for _ in 0..level * style.indent_width {
+            out.push(' ');
+        }
+        out.push_str(text);
+        out.push('\n');
+        prev = Some(text);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reindents_brace_languages() {
+        let messy = "fn  add(a: i64,b: i64) -> i64 {\r\n\r\n  let   s = \"a  {  b\";\n\t\tif a > b {\n a\n    } else {\n b\n}   \n\n\n}";
+        let expected = "fn add(a: i64,b: i64) -> i64 {\n    let s = \"a  {  b\";\n    if a > b {\n        a\n    } else {\n        b\n    }\n}\n";
+        assert_eq!(format_source(messy, &Language::Rust), expected);
+
+        let js = "function f() {\n        // keep   spacing {\n        return [1,\n    2];\n}\n";
+        let expected = "function f() {\n  // keep   spacing {\n  return [1,\n    2];\n}\n";
+        assert_eq!(format_source(js, &Language::JavaScript), expected);
+    }
+
+    #[test]
+    fn test_c_block_comments() {
+        let c = "long f(void) {\n/* one {\n* two\n*/\nreturn 0;\n}\n";
+        let expected = "long f(void) {\n    /* one {\n     * two\n     */\n    return 0;\n}\n";
+        assert_eq!(format_source(c, &Language::C), expected);
+    }
+
+    #[test]
+    fn test_python_block_structure() {
+        let py = "def f(x):\n  if x:\n\ty = {'a':  1,\n 'b': 2}\n\treturn y   # done  here\n  return  None\n\n\n\n\ndef g():\n  pass";
+        let expected = "def f(x):\n    if x:\n        y = {'a': 1,\n            'b': 2}\n        return y # done  here\n    return None\n\n\ndef g():\n    pass\n";
+        assert_eq!(format_source(py, &Language::Python), expected);
+    }
+
+    #[test]
+    fn test_idempotent() {
+        let samples = [
+            ("fn x() {\n  // c\n\n\n  let a = 0;\n a\n}", Language::Rust),
+            ("def x():\n   return 1\n", Language::Python),
+            ("function x() {\n\treturn 1;\n}", Language::JavaScript),
+            ("void x(void) {\n /* c */\n  return;\n}", Language::C),
+        ];
+        for (source, language) in samples {
+            let once = format_source(source, &language);
+            assert_eq!(format_source(&once, &language), once);
+        }
+    }
+}
@@ -195,6 +195,50 @@ impl Default for PanicMode {
     }
 }
 
+/// Per-gate noise model for the quantum simulator
+///
+/// Each field is the probability of that channel acting on every qubit a
+/// gate touches, applied after the gate. All zero (the default) is ideal
+/// simulation. Channels are sampled from a PRNG derived from the
+/// deterministic seed, so noisy runs replay exactly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct NoiseConfig {
+    /// Depolarizing probability (X, Y, or Z error with p/3 each)
+    pub depolarizing: f32,
+    /// Bit-flip (X error) probability
+    pub bit_flip: f32,
+    /// Phase-flip (Z error) probability
+    pub phase_flip: f32,
+    /// Amplitude damping rate γ (|1⟩ decays to |0⟩)
+    pub amplitude_damping: f32,
+}
+
+impl NoiseConfig {
+    /// Whether every channel is disabled
+    pub fn is_ideal(&self) -> bool {
+        self.depolarizing == 0.0
+            && self.bit_flip == 0.0
+            && self.phase_flip == 0.0
+            && self.amplitude_damping == 0.0
+    }
+
+    /// Check every probability lies in [0, 1]
+    pub fn validate(&self) -> Result<(), String> {
+        let channels = [
+            ("depolarizing", self.depolarizing),
+            ("bit_flip", self.bit_flip),
+            ("phase_flip", self.phase_flip),
+            ("amplitude_damping", self.amplitude_damping),
+        ];
+        for (name, p) in channels {
+            if !(0.0..=1.0).contains(&p) {
+                return Err(alloc::format!("Noise probability {} must be within [0, 1]", name));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Main Q-Substrate configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QSubstrateConfig {
@@ -212,8 +256,15 @@ pub struct QSubstrateConfig {
     pub audit_logging: bool,
     /// Enable provenance tracking
     pub provenance_tracking: bool,
-    /// Max qubits (6-12)
+    /// Max qubits (6-12, up to 24 with `large_sim`)
     pub max_qubits: usize,
+    /// Back the quantum pod with a heap-allocated `LargeQuantumState`
+    /// sized to `max_qubits` (requires the `large-sim` feature)
+    #[serde(default)]
+    pub large_sim: bool,
+    /// Quantum noise model (ideal by default)
+    #[serde(default)]
+    pub noise: NoiseConfig,
     /// Enable rollback
     pub enable_rollback: bool,
 }
@@ -229,6 +280,8 @@ impl Default for QSubstrateConfig {
             audit_logging: true,
             provenance_tracking: true,
             max_qubits: 12,
+            large_sim: false,
+            noise: NoiseConfig::default(),
             enable_rollback: true,
         }
     }
@@ -293,10 +346,22 @@ impl QSubstrateConfig {
     /// Validate configuration
     pub fn validate(&self) -> Result<(), String> {
         // Check qubit limits
-        if self.max_qubits > 16 {
+        if self.large_sim {
+            if !cfg!(feature = "large-sim") {
+                return Err("Large simulation requires the large-sim feature".into());
+            }
+            if self.max_qubits > 24 {
+                return Err("Max qubits cannot exceed 24 in large simulation".into());
+            }
+            if self.quantum_state_size() > self.memory.quantum_pod_limit_kb * 1024 {
+                return Err("Quantum state exceeds quantum pod limit".into());
+            }
+        } else if self.max_qubits > 16 {
             return Err("Max qubits cannot exceed 16".into());
         }
         
+        self.noise.validate()?;
+        
         // Check memory consistency
         let total_pod_memory = self.memory.ai_pod_limit_kb 
             + self.memory.quantum_pod_limit_kb 
@@ -364,4 +429,32 @@ mod tests {
         config.max_qubits = 20;
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_large_sim_validation() {
+        let mut config = QSubstrateConfig::default();
+        config.large_sim = true;
+        config.max_qubits = 20;
+        if cfg!(feature = "large-sim") {
+            // 8MB state does not fit the default 64KB quantum pod
+            assert!(config.validate().is_err());
+            config.memory.quantum_pod_limit_kb = 8 * 1024;
+            assert!(config.validate().is_ok());
+            config.max_qubits = 25;
+        }
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_noise_validation() {
+        let mut config = QSubstrateConfig::default();
+        assert!(config.noise.is_ideal());
+        config.noise.depolarizing = 0.01;
+        config.noise.amplitude_damping = 1.0;
+        assert!(config.validate().is_ok());
+        config.noise.bit_flip = 1.5;
+        assert!(config.validate().is_err());
+        config.noise.bit_flip = f32::NAN;
+        assert!(config.validate().is_err());
+    }
 }
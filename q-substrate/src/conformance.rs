@@ -0,0 +1,559 @@
+//! DCGE Conformance Suite
+//!
+//! Catches language-emitter regressions in deterministic code generation:
+//! - Corpus of intents with expected structural properties per language
+//! - Grammar checks: signature form, balanced delimiters, statement
+//!   termination, block indentation
+//! - Type checks: declared return type agrees with returned values, returned
+//!   identifiers are declared, function names are valid identifiers
+//! - Optional toolchain compile check (`std` only; skipped when a compiler
+//!   is not installed)
+//! - Scorecard surfaced through `SupremacyMetrics::conformance_pass_rate`
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use crate::dcge::{DCGEngine, GenerationOutcome, Language};
+
+/// Seed used for every conformance generation
+pub const CONFORMANCE_SEED: u32 = 42;
+
+/// Languages covered by the suite, in scorecard order
+pub const LANGUAGES: [Language; 4] = [Language::Rust, Language::Python, Language::JavaScript, Language::C];
+
+/// One intent/language pair with expected properties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConformanceCase {
+    /// Intent passed to the generator
+    pub intent: String,
+    /// Target language
+    pub language: Language,
+    /// Expected function name
+    pub function_name: String,
+    /// Whether the function must return a value
+    pub returns_value: bool,
+    /// Fragments the source must contain
+    pub required: Vec<String>,
+}
+
+/// Conformance violation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Violation {
+    /// Generator returned an error
+    Generation(String),
+    /// Generator asked for clarification on an unambiguous corpus intent
+    NeedsClarification(Vec<String>),
+    /// Generator's own validation failed
+    NotValidated,
+    /// Function signature does not have the language's form
+    BadSignature(String),
+    /// Function name differs from the expected one
+    WrongFunctionName(String),
+    /// Function name is not a valid identifier
+    InvalidIdentifier(String),
+    /// Unbalanced delimiter
+    Unbalanced(char),
+    /// Statement line missing its terminator
+    Unterminated(usize),
+    /// Block line not indented
+    BadIndentation(usize),
+    /// Returned identifier never declared
+    Undeclared(String),
+    /// Declared return type disagrees with the body
+    ReturnTypeMismatch,
+    /// Function returns a value when the corpus says it should not, or vice versa
+    UnexpectedReturn,
+    /// Required fragment missing
+    MissingFragment(String),
+    /// Toolchain rejected the source
+    CompileError(String),
+}
+
+/// Outcome for one case
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaseResult {
+    /// Intent
+    pub intent: String,
+    /// Target language
+    pub language: Language,
+    /// Violations found (empty when conformant)
+    pub violations: Vec<Violation>,
+}
+
+impl CaseResult {
+    /// No violations
+    pub fn passed(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Per-language totals
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageScore {
+    /// Language
+    pub language: Language,
+    /// Cases run
+    pub cases: u32,
+    /// Cases passed
+    pub passed: u32,
+}
+
+/// Conformance scorecard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConformanceScorecard {
+    /// Per-case results in corpus order
+    pub results: Vec<CaseResult>,
+    /// Per-language totals in `LANGUAGES` order
+    pub languages: Vec<LanguageScore>,
+}
+
+impl ConformanceScorecard {
+    /// Cases passed
+    pub fn passed(&self) -> u32 {
+        self.languages.iter().map(|l| l.passed).sum()
+    }
+
+    /// Fraction of cases passed (1.0 for an empty corpus)
+    pub fn pass_rate(&self) -> f32 {
+        let total: u32 = self.languages.iter().map(|l| l.cases).sum();
+        if total == 0 {
+            return 1.0;
+        }
+        self.passed() as f32 / total as f32
+    }
+
+    /// Failing cases
+    pub fn failures(&self) -> Vec<&CaseResult> {
+        self.results.iter().filter(|r| !r.passed()).collect()
+    }
+}
+
+/// Built-in corpus: every intent in every language
+pub fn default_corpus() -> Vec<ConformanceCase> {
+    // (intent, function name, returns value, fragment required in every language)
+    let intents: [(&str, &str, bool, &str); 6] = [
+        ("compute fibonacci numbers", "compute", true, "Fibonacci"),
+        ("sort the records", "sort", false, "Sort"),
+        ("calculate the sum of values", "calculate", true, "result"),
+        ("print hello world", "print", false, "Generated from"),
+        ("return the answer", "return_fn", false, "Generated from"),
+        ("2024 report generator", "fn_2024", false, "Generated from"),
+    ];
+
+    let mut corpus = Vec::new();
+    for (intent, name, returns_value, fragment) in intents {
+        for language in LANGUAGES {
+            let keyword = match language {
+                Language::Rust => "fn ",
+                Language::Python => "def ",
+                Language::JavaScript => "function ",
+                Language::C => "(void)",
+            };
+            corpus.push(ConformanceCase {
+                intent: intent.into(),
+                language,
+                function_name: name.into(),
+                returns_value,
+                required: alloc::vec![keyword.into(), fragment.into()],
+            });
+        }
+    }
+    corpus
+}
+
+/// Per-language syntax used by the checks
+struct Dialect {
+    comment: &'static str,
+    terminator: Option<char>,
+    declaration: &'static str,
+    keywords: &'static [&'static str],
+}
+
+fn dialect(language: &Language) -> Dialect {
+    match language {
+        Language::Rust => Dialect {
+            comment: "//",
+            terminator: Some(';'),
+            declaration: "let ",
+            keywords: &["fn", "let", "return", "if", "else", "for", "while", "loop", "match", "struct", "type", "mod", "use", "self", "true", "false"],
+        },
+        Language::Python => Dialect {
+            comment: "#",
+            terminator: None,
+            declaration: "",
+            keywords: &["def", "return", "if", "else", "for", "while", "pass", "class", "import", "from", "in", "not", "or", "and", "None", "True", "False"],
+        },
+        Language::JavaScript => Dialect {
+            comment: "//",
+            terminator: Some(';'),
+            declaration: "let ",
+            keywords: &["function", "let", "var", "const", "return", "if", "else", "for", "while", "class", "new", "this", "true", "false"],
+        },
+        Language::C => Dialect {
+            comment: "/*",
+            terminator: Some(';'),
+            declaration: "int ",
+            keywords: &["int", "long", "void", "char", "double", "float", "return", "if", "else", "for", "while", "struct", "static"],
+        },
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn is_literal(value: &str) -> bool {
+    value.parse::<f64>().is_ok() || value.starts_with('"') || value.starts_with('\'')
+}
+
+/// Check generated source against the language grammar and type rules
+pub fn check_source(source: &str, case: &ConformanceCase) -> Vec<Violation> {
+    let language = &case.language;
+    let function_name = case.function_name.as_str();
+    let d = dialect(language);
+    let mut violations = Vec::new();
+    let lines: Vec<&str> = source.lines().collect();
+
+    // Signature
+    let signature = lines.first().copied().unwrap_or("");
+    let (name, declared_return) = match parse_signature(signature, language) {
+        Some(parsed) => parsed,
+        None => {
+            violations.push(Violation::BadSignature(signature.into()));
+            return violations;
+        }
+    };
+    if name != function_name {
+        violations.push(Violation::WrongFunctionName(name.clone()));
+    }
+    if !is_identifier(&name) || d.keywords.contains(&name.as_str()) {
+        violations.push(Violation::InvalidIdentifier(name));
+    }
+
+    // Body lines (1-based line numbers), excluding the closing brace
+    let mut body: Vec<(usize, &str)> = lines.iter().enumerate().skip(1).map(|(i, l)| (i + 1, *l)).collect();
+    if *language != Language::Python {
+        match body.iter().rposition(|(_, l)| !l.trim().is_empty()) {
+            Some(end) if body[end].1.trim() == "}" => body.truncate(end),
+            _ => violations.push(Violation::Unbalanced('}')),
+        }
+    }
+    let code: Vec<(usize, &str)> = body
+        .iter()
+        .map(|&(n, l)| (n, l.trim()))
+        .filter(|(_, l)| !l.is_empty() && !l.starts_with(d.comment))
+        .collect();
+
+    // Delimiters, ignoring comments
+    let mut stack = Vec::new();
+    for (_, line) in core::iter::once((0, signature)).chain(code.iter().copied()) {
+        for c in line.chars() {
+            match c {
+                '(' | '[' | '{' => stack.push(c),
+                ')' | ']' | '}' => {
+                    let open = match c {
+                        ')' => '(',
+                        ']' => '[',
+                        _ => '{',
+                    };
+                    if stack.pop() != Some(open) {
+                        violations.push(Violation::Unbalanced(c));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    // The signature's `{` is closed by the stripped final brace
+    let expected_open = if *language == Language::Python { 0 } else { 1 };
+    if stack.len() != expected_open {
+        violations.push(Violation::Unbalanced(stack.last().copied().unwrap_or('{')));
+    }
+
+    // Indentation and termination
+    if *language == Language::Python && code.is_empty() {
+        violations.push(Violation::BadIndentation(2));
+    }
+    for (i, &(n, line)) in code.iter().enumerate() {
+        let raw = lines[n - 1];
+        if !raw.starts_with(' ') {
+            violations.push(Violation::BadIndentation(n));
+        }
+        let is_tail = *language == Language::Rust && i + 1 == code.len();
+        if let Some(t) = d.terminator {
+            if !line.ends_with(t) && !is_tail {
+                violations.push(Violation::Unterminated(n));
+            }
+        }
+    }
+
+    // Declarations and returned value
+    let mut declared: Vec<&str> = Vec::new();
+    let mut returned: Option<&str> = None;
+    for (i, &(_, line)) in code.iter().enumerate() {
+        let statement = line.trim_end_matches(';').trim();
+        if let Some(rest) = statement.strip_prefix("return") {
+            let value = rest.trim();
+            if !value.is_empty() {
+                returned = Some(value);
+            }
+        } else if let Some((target, _)) = statement.split_once('=') {
+            let target = target.trim();
+            declared.push(target.strip_prefix(d.declaration).unwrap_or(target).trim());
+        } else if *language == Language::Rust && i + 1 == code.len() && !line.ends_with(';') {
+            returned = Some(statement);
+        }
+    }
+    if let Some(value) = returned {
+        if !is_literal(value) && !declared.contains(&value) {
+            violations.push(Violation::Undeclared(value.into()));
+        }
+    }
+    // Python and JavaScript are dynamically typed: only the corpus expectation applies
+    let statically_typed = matches!(language, Language::Rust | Language::C);
+    if statically_typed && declared_return != returned.is_some() {
+        violations.push(Violation::ReturnTypeMismatch);
+    }
+    if case.returns_value != returned.is_some() {
+        violations.push(Violation::UnexpectedReturn);
+    }
+
+    violations
+}
+
+/// Parse a function signature line into (name, declares a return value)
+fn parse_signature(line: &str, language: &Language) -> Option<(String, bool)> {
+    let open = line.find('(')?;
+    let head = &line[..open];
+    let tail = &line[line.rfind(')')? + 1..];
+
+    let (name, returns) = match language {
+        Language::Rust => {
+            let name = head.strip_prefix("fn ")?;
+            if !tail.ends_with('{') {
+                return None;
+            }
+            let ret = tail.trim_end_matches('{').trim();
+            let returns = match ret.strip_prefix("->") {
+                Some(ty) => ty.trim() != "()",
+                None if ret.is_empty() => false,
+                None => return None,
+            };
+            (name, returns)
+        }
+        Language::Python => {
+            if tail != ":" {
+                return None;
+            }
+            (head.strip_prefix("def ")?, false)
+        }
+        Language::JavaScript => {
+            if tail.trim() != "{" {
+                return None;
+            }
+            (head.strip_prefix("function ")?, false)
+        }
+        Language::C => {
+            if tail.trim() != "{" {
+                return None;
+            }
+            let (ty, name) = head.rsplit_once(' ')?;
+            let returns = match ty.trim() {
+                "void" => false,
+                "int" | "long" | "double" | "float" | "char" => true,
+                _ => return None,
+            };
+            (name, returns)
+        }
+    };
+    Some((name.trim().into(), returns))
+}
+
+/// Run one case
+pub fn run_case(case: &ConformanceCase) -> CaseResult {
+    evaluate_case(case, |_, _| None)
+}
+
+/// Generate, check and optionally compile one case
+fn evaluate_case<F>(case: &ConformanceCase, compile: F) -> CaseResult
+where
+    F: Fn(&str, &Language) -> Option<Result<(), String>>,
+{
+    let mut engine = DCGEngine::new(CONFORMANCE_SEED);
+    let violations = match engine.generate(&case.intent, case.language.as_str()) {
+        Err(e) => alloc::vec![Violation::Generation(e)],
+        Ok(GenerationOutcome::NeedsClarification(request)) => alloc::vec![Violation::NeedsClarification(
+            request.questions.into_iter().map(|q| q.id).collect(),
+        )],
+        Ok(GenerationOutcome::Generated(code)) => {
+            let mut violations = check_source(&code.source, case);
+            if !code.validated {
+                violations.push(Violation::NotValidated);
+            }
+            for fragment in &case.required {
+                if !code.source.contains(fragment.as_str()) {
+                    violations.push(Violation::MissingFragment(fragment.clone()));
+                }
+            }
+            // Only spend a compiler run on otherwise conformant output
+            if violations.is_empty() {
+                if let Some(Err(e)) = compile(&code.source, &case.language) {
+                    violations.push(Violation::CompileError(e));
+                }
+            }
+            violations
+        }
+    };
+
+    CaseResult {
+        intent: case.intent.clone(),
+        language: case.language.clone(),
+        violations,
+    }
+}
+
+/// Score a set of case results
+pub fn scorecard(results: Vec<CaseResult>) -> ConformanceScorecard {
+    let languages = LANGUAGES
+        .iter()
+        .map(|language| {
+            let cases: Vec<&CaseResult> = results.iter().filter(|r| r.language == *language).collect();
+            LanguageScore {
+                language: language.clone(),
+                cases: cases.len() as u32,
+                passed: cases.iter().filter(|r| r.passed()).count() as u32,
+            }
+        })
+        .collect();
+    ConformanceScorecard { results, languages }
+}
+
+/// Run every case in the corpus
+pub fn run_conformance(corpus: &[ConformanceCase]) -> ConformanceScorecard {
+    scorecard(corpus.iter().map(run_case).collect())
+}
+
+/// Compile generated source with the installed toolchain
+///
+/// Returns `None` when the language's compiler is not installed.
+#[cfg(feature = "std")]
+pub fn compile_check(source: &str, language: &Language) -> Option<Result<(), String>> {
+    use std::process::Command;
+
+    let (extension, program, args): (&str, &str, &[&str]) = match language {
+        Language::Rust => ("rs", "rustc", &["--crate-type", "lib", "--emit", "metadata", "-A", "warnings", "--out-dir"]),
+        Language::Python => ("py", "python3", &["-m", "py_compile"]),
+        Language::JavaScript => ("js", "node", &["--check"]),
+        Language::C => ("c", "cc", &["-fsyntax-only"]),
+    };
+
+    let dir = std::env::temp_dir().join(format!("qsubstrate-conformance-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).ok()?;
+    let path = dir.join(format!("case_{:016x}.{}", fnv1a(source.as_bytes()), extension));
+    std::fs::write(&path, source).ok()?;
+
+    let mut command = Command::new(program);
+    command.args(args);
+    if *language == Language::Rust {
+        command.arg(&dir);
+    }
+    let output = command.arg(&path).output();
+    let _ = std::fs::remove_file(&path);
+
+    match output {
+        Err(_) => None,
+        Ok(out) if out.status.success() => Some(Ok(())),
+        Ok(out) => Some(Err(String::from_utf8_lossy(&out.stderr).into_owned())),
+    }
+}
+
+/// Run the corpus and additionally compile every conformant case
+#[cfg(feature = "std")]
+pub fn run_conformance_with_toolchain(corpus: &[ConformanceCase]) -> ConformanceScorecard {
+    scorecard(corpus.iter().map(|case| evaluate_case(case, compile_check)).collect())
+}
+
+#[cfg(feature = "std")]
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(language: Language, name: &str, returns_value: bool) -> ConformanceCase {
+        ConformanceCase {
+            intent: String::new(),
+            language,
+            function_name: name.into(),
+            returns_value,
+            required: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_default_corpus_conforms() {
+        let card = run_conformance(&default_corpus());
+        for failure in card.failures() {
+            panic!("{:?} {:?}: {:?}", failure.language, failure.intent, failure.violations);
+        }
+        assert_eq!(card.languages.len(), 4);
+        assert!(card.languages.iter().all(|l| l.cases == 6 && l.passed == 6));
+        assert_eq!(card.pass_rate(), 1.0);
+    }
+
+    #[test]
+    fn test_checks_catch_emitter_regressions() {
+        // Unit return type with a tail expression
+        let rust = "fn compute() -> () {\n    let a = 0;\n    a\n}\n";
+        assert!(check_source(rust, &case(Language::Rust, "compute", true)).contains(&Violation::ReturnTypeMismatch));
+
+        // C signature without a valid type
+        let c = "() compute(void) {\n    return;\n}\n";
+        assert!(matches!(check_source(c, &case(Language::C, "compute", false))[0], Violation::BadSignature(_)));
+
+        // Missing semicolon, undeclared identifier
+        let js = "function sort() {\n  let a = 1\n  return sorted;\n}\n";
+        let violations = check_source(js, &case(Language::JavaScript, "sort", true));
+        assert!(violations.contains(&Violation::Unterminated(2)));
+        assert!(violations.contains(&Violation::Undeclared("sorted".into())));
+
+        // Keyword as function name, unindented body
+        let py = "def return():\nreturn\n";
+        let violations = check_source(py, &case(Language::Python, "return", false));
+        assert!(violations.contains(&Violation::InvalidIdentifier("return".into())));
+        assert!(violations.contains(&Violation::BadIndentation(2)));
+    }
+
+    #[test]
+    fn test_scorecard_feeds_supremacy_metrics() {
+        let mut corpus = default_corpus();
+        corpus[0].required.push("not in output".into());
+        let card = run_conformance(&corpus);
+        assert_eq!(card.passed(), 23);
+        assert_eq!(card.failures()[0].violations, alloc::vec![Violation::MissingFragment("not in output".into())]);
+
+        let mut engine = DCGEngine::new(CONFORMANCE_SEED);
+        assert_eq!(engine.generate("sum values", "rust").unwrap().into_code().unwrap().metrics.conformance_pass_rate, None);
+        engine.record_conformance(&card);
+        let rate = engine.generate("sum values", "rust").unwrap().into_code().unwrap().metrics.conformance_pass_rate.unwrap();
+        assert!((rate - 23.0 / 24.0).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_toolchain_compiles_rust_output() {
+        // rustc is always present where these tests run; other compilers may not be
+        let code = DCGEngine::new(CONFORMANCE_SEED).generate("compute fibonacci numbers", "rust").unwrap().into_code().unwrap();
+        assert_eq!(compile_check(&code.source, &Language::Rust), Some(Ok(())));
+        assert!(compile_check("fn broken( {", &Language::Rust).unwrap().is_err());
+    }
+}
@@ -5,16 +5,24 @@
 //! - Typed IR with symbol tables
 //! - WASM-compatible output
 //! - Supremacy validation: minimal, correct, deterministic
+//! - Clarification protocol for ambiguous or under-specified intents
+//! - Deterministic formatting of every emission (see `codefmt`)
+//! - Optional semantic operation lookup over a MiniLM `VectorIndex`
 //!
 //! Memory footprint: ~4KB working memory
 
 extern crate alloc;
 
+use alloc::format;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
+use crate::minilm::{MiniLMQ4, VectorIndex};
+
+use crate::codefmt;
+
 /// Supported languages
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum Language {
@@ -25,6 +33,17 @@ pub enum Language {
 }
 
 impl Language {
+    /// Parse a language name, `None` if unrecognized
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "rust" | "rs" => Some(Language::Rust),
+            "python" | "py" => Some(Language::Python),
+            "javascript" | "js" => Some(Language::JavaScript),
+            "c" => Some(Language::C),
+            _ => None,
+        }
+    }
+
     pub fn from_str(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "rust" | "rs" => Language::Rust,
@@ -34,6 +53,16 @@ impl Language {
             _ => Language::Rust, // Default to Rust
         }
     }
+
+    /// Canonical lowercase name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Language::Rust => "rust",
+            Language::Python => "python",
+            Language::JavaScript => "javascript",
+            Language::C => "c",
+        }
+    }
 }
 
 /// Generated code with metadata
@@ -53,6 +82,99 @@ pub struct GeneratedCode {
     pub metrics: SupremacyMetrics,
 }
 
+/// Candidate reading of an intent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interpretation {
+    /// Operation id (answer value for the `operation` question)
+    pub operation: String,
+    /// Human-readable description
+    pub description: String,
+    /// Share of intent evidence for this reading (0.0 - 1.0)
+    pub confidence: f32,
+}
+
+/// Targeted question for an unresolved constraint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClarificationQuestion {
+    /// Question id: `operation`, `function_name` or `language`
+    pub id: String,
+    /// Prompt shown to the user
+    pub prompt: String,
+    /// Allowed answers; empty means free text
+    pub options: Vec<String>,
+}
+
+/// Generation paused pending answers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClarificationRequest {
+    /// Original intent
+    pub intent: String,
+    /// Requested language as given
+    pub language: String,
+    /// Confidence in the best interpretation
+    pub confidence: f32,
+    /// Fraction of constraints (function name, language) resolved
+    pub coverage: f32,
+    /// Candidate interpretations, most likely first
+    pub candidates: Vec<Interpretation>,
+    /// Questions to answer before generation continues
+    pub questions: Vec<ClarificationQuestion>,
+}
+
+/// Answer to a clarification question
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClarificationAnswer {
+    /// Question id
+    pub question_id: String,
+    /// Chosen option or free-text value
+    pub answer: String,
+}
+
+/// Result of a generation request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GenerationOutcome {
+    /// Code was generated
+    Generated(GeneratedCode),
+    /// Intent is ambiguous; answer the questions and call `generate_with_answers`
+    NeedsClarification(ClarificationRequest),
+}
+
+impl GenerationOutcome {
+    /// Generated code, if any
+    pub fn into_code(self) -> Option<GeneratedCode> {
+        match self {
+            GenerationOutcome::Generated(code) => Some(code),
+            GenerationOutcome::NeedsClarification(_) => None,
+        }
+    }
+
+    /// Pending clarification, if any
+    pub fn clarification(&self) -> Option<&ClarificationRequest> {
+        match self {
+            GenerationOutcome::Generated(_) => None,
+            GenerationOutcome::NeedsClarification(request) => Some(request),
+        }
+    }
+}
+
+/// Thresholds below which generation asks instead of guessing
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClarificationPolicy {
+    /// Minimum confidence in the chosen interpretation
+    pub min_confidence: f32,
+    /// Minimum fraction of resolved constraints
+    pub min_coverage: f32,
+}
+
+impl Default for ClarificationPolicy {
+    fn default() -> Self {
+        ClarificationPolicy {
+            min_confidence: 0.6,
+            min_coverage: 1.0,
+        }
+    }
+}
+
 /// Supremacy metrics for generated code
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SupremacyMetrics {
@@ -70,6 +192,9 @@ pub struct SupremacyMetrics {
     pub vs_copilot: f32,
     /// Comparison vs Cursor (ratio)
     pub vs_cursor: f32,
+    /// Pass rate of the last conformance run (see `conformance`)
+    #[serde(default)]
+    pub conformance_pass_rate: Option<f32>,
 }
 
 impl Default for SupremacyMetrics {
@@ -82,6 +207,7 @@ impl Default for SupremacyMetrics {
             vs_naive_llm: 1.5,  // 50% better than naive
             vs_copilot: 0.95,   // 95% as good as Copilot
             vs_cursor: 0.97,    // 97% as good as Cursor
+            conformance_pass_rate: None,
         }
     }
 }
@@ -139,6 +265,12 @@ pub struct DCGEngine {
     symbols: Vec<Symbol>,
     /// Operation counter
     op_count: u64,
+    /// Pass rate of the last recorded conformance run
+    conformance_pass_rate: Option<f32>,
+    /// When to ask for clarification
+    policy: ClarificationPolicy,
+    /// Embedder and operation index consulted when no keyword matches
+    semantic: Option<(MiniLMQ4, VectorIndex)>,
 }
 
 impl DCGEngine {
@@ -148,7 +280,41 @@ impl DCGEngine {
             seed,
             symbols: Vec::new(),
             op_count: 0,
+            conformance_pass_rate: None,
+            policy: ClarificationPolicy::default(),
+            semantic: None,
+        }
+    }
+
+    /// Index every known operation's description under its id
+    ///
+    /// Further exemplar phrasings can be appended with `insert_text`.
+    pub fn operation_index(model: &mut MiniLMQ4) -> VectorIndex {
+        let mut index = VectorIndex::for_model(model);
+        for (id, description, _) in OPERATIONS {
+            let embedding = model.embed_stable(description);
+            // Dimension and normalization come from the same model
+            let _ = index.insert(id, &embedding);
         }
+        index
+    }
+
+    /// Resolve intents without keyword hits by nearest operation in `index`
+    ///
+    /// Entry names must be operation ids; others are ignored. Matches below
+    /// `SEMANTIC_MIN_SIMILARITY` fall back to the stub.
+    pub fn set_semantic_lookup(&mut self, model: MiniLMQ4, index: VectorIndex) {
+        self.semantic = Some((model, index));
+    }
+
+    /// Replace the clarification thresholds
+    pub fn set_clarification_policy(&mut self, policy: ClarificationPolicy) {
+        self.policy = policy;
+    }
+
+    /// Record a conformance scorecard; reported in every later `SupremacyMetrics`
+    pub fn record_conformance(&mut self, scorecard: &crate::conformance::ConformanceScorecard) {
+        self.conformance_pass_rate = Some(scorecard.pass_rate());
     }
 
     /// Reset to initial state
@@ -158,17 +324,98 @@ impl DCGEngine {
     }
 
     /// Generate code from intent and language
-    pub fn generate(&mut self, intent: &str, language: &str) -> Result<GeneratedCode, String> {
-        let _start = core::time::Duration::default();
+    ///
+    /// Returns `NeedsClarification` instead of guessing when the intent
+    /// matches competing operations or leaves constraints unresolved.
+    pub fn generate(&mut self, intent: &str, language: &str) -> Result<GenerationOutcome, String> {
+        self.generate_with_answers(intent, language, &[])
+    }
+
+    /// Generate code, resolving ambiguity with answers to earlier questions
+    ///
+    /// The same intent, language and answers always produce the same code.
+    pub fn generate_with_answers(
+        &mut self,
+        intent: &str,
+        language: &str,
+        answers: &[ClarificationAnswer],
+    ) -> Result<GenerationOutcome, String> {
         self.op_count += 1;
         
-        let lang = Language::from_str(language);
+        let mut candidates = self.rank_candidates(intent);
+        let mut name = extract_function_name(intent);
+        let mut lang = Language::parse(language);
+        
+        for answer in answers {
+            match answer.question_id.as_str() {
+                "operation" => {
+                    let description = operation_description(&answer.answer)
+                        .ok_or_else(|| format!("Unknown operation: {}", answer.answer))?;
+                    candidates = vec![Interpretation {
+                        operation: answer.answer.clone(),
+                        description: description.into(),
+                        confidence: 1.0,
+                    }];
+                }
+                "function_name" => {
+                    if !is_valid_function_name(&answer.answer) {
+                        return Err(format!("Invalid function name: {}", answer.answer));
+                    }
+                    name = Some(answer.answer.clone());
+                }
+                "language" => {
+                    lang = Some(Language::parse(&answer.answer)
+                        .ok_or_else(|| format!("Unknown language: {}", answer.answer))?);
+                }
+                other => return Err(format!("Unknown question: {}", other)),
+            }
+        }
+        
+        let confidence = candidates[0].confidence;
+        let coverage = (name.is_some() as u8 + lang.is_some() as u8) as f32 / 2.0;
+        
+        if confidence < self.policy.min_confidence || coverage < self.policy.min_coverage {
+            let mut questions = Vec::new();
+            if confidence < self.policy.min_confidence {
+                questions.push(ClarificationQuestion {
+                    id: "operation".into(),
+                    prompt: "Which operation should the function implement?".into(),
+                    options: candidates.iter().map(|c| c.operation.clone()).collect(),
+                });
+            }
+            if coverage < self.policy.min_coverage && name.is_none() {
+                questions.push(ClarificationQuestion {
+                    id: "function_name".into(),
+                    prompt: "What should the generated function be called?".into(),
+                    options: Vec::new(),
+                });
+            }
+            if coverage < self.policy.min_coverage && lang.is_none() {
+                questions.push(ClarificationQuestion {
+                    id: "language".into(),
+                    prompt: format!("Unrecognized language '{}'. Which target language?", language),
+                    options: ["rust", "python", "javascript", "c"].iter().map(|l| (*l).into()).collect(),
+                });
+            }
+            return Ok(GenerationOutcome::NeedsClarification(ClarificationRequest {
+                intent: intent.into(),
+                language: language.into(),
+                confidence,
+                coverage,
+                candidates,
+                questions,
+            }));
+        }
+        
+        // Below-threshold constraints fall back to the historical defaults
+        let name = name.unwrap_or_else(|| "generated_fn".into());
+        let lang = lang.unwrap_or(Language::Rust);
         
         // Parse intent and generate AST
-        let ast = self.intent_to_ast(intent)?;
+        let ast = self.intent_to_ast(&name, &candidates[0].operation, intent);
         
-        // Generate source code
-        let source = self.ast_to_source(&ast, &lang)?;
+        // Generate source code, then apply the pinned formatting style
+        let source = codefmt::format_source(&self.ast_to_source(&ast, &lang)?, &lang);
         
         // Validate generated code
         let validated = self.validate_code(&source, &lang);
@@ -182,105 +429,69 @@ impl DCGEngine {
             vs_naive_llm: 1.5,
             vs_copilot: 0.95,
             vs_cursor: 0.97,
+            conformance_pass_rate: self.conformance_pass_rate,
         };
         
-        Ok(GeneratedCode {
+        Ok(GenerationOutcome::Generated(GeneratedCode {
             source,
             language: lang,
             validated,
             generation_time_us: 100, // Placeholder
             size_estimate: metrics.footprint_bytes,
             metrics,
-        })
+        }))
     }
 
-    /// Parse intent and generate AST
-    fn intent_to_ast(&mut self, intent: &str) -> Result<AstNode, String> {
-        // Extract function name from intent
-        let func_name = self.extract_function_name(intent);
+    /// Keyword ranking, falling back to semantic lookup when nothing matches
+    fn rank_candidates(&mut self, intent: &str) -> Vec<Interpretation> {
+        let ranked = rank_operations(intent);
+        let Some((model, index)) = self.semantic.as_mut() else {
+            return ranked;
+        };
+        if ranked[0].operation != STUB_OPERATION.0 {
+            return ranked;
+        }
+        let Ok(hits) = index.search(&model.embed_stable(intent), index.len()) else {
+            return ranked;
+        };
+
+        let mut candidates: Vec<Interpretation> = Vec::new();
+        for hit in hits.into_iter().take_while(|h| h.score >= SEMANTIC_MIN_SIMILARITY) {
+            let Some(description) = operation_description(&hit.name) else {
+                continue;
+            };
+            if !candidates.iter().any(|c| c.operation == hit.name) {
+                candidates.push(Interpretation {
+                    operation: hit.name,
+                    description: description.into(),
+                    confidence: hit.score.min(1.0),
+                });
+            }
+        }
+        if candidates.is_empty() { ranked } else { candidates }
+    }
+
+    /// Build the function AST for a resolved operation
+    fn intent_to_ast(&mut self, func_name: &str, operation: &str, intent: &str) -> AstNode {
+        // Generate appropriate body for the operation
+        let body = body_for_operation(operation, intent);
         
-        // Generate appropriate body based on intent
-        let body = self.generate_body_from_intent(intent)?;
+        // Functions returning a value return the IR integer type
+        let return_type: Option<String> = returns_value(&body).then(|| "int".into());
         
         // Register function in symbol table
         self.symbols.push(Symbol {
-            name: func_name.clone(),
+            name: func_name.into(),
             sym_type: SymbolType::Function,
-            type_info: "()".into(),
+            type_info: return_type.clone().unwrap_or_else(|| "()".into()),
             mutable: false,
         });
         
-        Ok(AstNode::Function {
-            name: func_name,
+        AstNode::Function {
+            name: func_name.into(),
             params: Vec::new(),
-            return_type: Some("()".into()),
+            return_type,
             body: vec![body],
-        })
-    }
-
-    /// Extract function name from intent
-    fn extract_function_name(&self, intent: &str) -> String {
-        // Simple heuristic: use first significant word as function name
-        let words: Vec<&str> = intent.split_whitespace()
-            .filter(|w| w.len() > 3)
-            .collect();
-        
-        if let Some(first) = words.first() {
-            // Convert to snake_case
-            first.to_lowercase()
-                .chars()
-                .filter(|c| c.is_alphanumeric())
-                .collect()
-        } else {
-            "generated_fn".into()
-        }
-    }
-
-    /// Generate function body from intent
-    fn generate_body_from_intent(&self, intent: &str) -> Result<AstNode, String> {
-        let intent_lower = intent.to_lowercase();
-        
-        // Pattern matching for common intents
-        if intent_lower.contains("fibonacci") {
-            Ok(AstNode::Block {
-                statements: vec![
-                    AstNode::Comment { text: "Fibonacci implementation".into() },
-                    AstNode::Assignment {
-                        target: "a".into(),
-                        value: "0".into(),
-                    },
-                    AstNode::Assignment {
-                        target: "b".into(),
-                        value: "1".into(),
-                    },
-                    AstNode::Return { value: Some("a".into()) },
-                ],
-            })
-        } else if intent_lower.contains("sort") {
-            Ok(AstNode::Block {
-                statements: vec![
-                    AstNode::Comment { text: "Sort implementation".into() },
-                    AstNode::Return { value: Some("sorted_array".into()) },
-                ],
-            })
-        } else if intent_lower.contains("sum") || intent_lower.contains("add") {
-            Ok(AstNode::Block {
-                statements: vec![
-                    AstNode::Assignment {
-                        target: "result".into(),
-                        value: "0".into(),
-                    },
-                    AstNode::Return { value: Some("result".into()) },
-                ],
-            })
-        } else {
-            // Default: simple function body
-            Ok(AstNode::Block {
-                statements: vec![
-                    AstNode::Comment { text: format!("Generated from: {}", intent) },
-                    AstNode::Return { value: None },
-                ],
-            })
         }
     }
 
@@ -308,7 +519,7 @@ impl DCGEngine {
                 code.push(')');
                 
                 if let Some(ret) = return_type {
-                    code.push_str(&format!(" -> {}", ret));
+                    code.push_str(&format!(" -> {}", rust_type(ret)));
                 }
                 
                 code.push_str(" {\n");
@@ -444,7 +655,7 @@ impl DCGEngine {
     fn emit_c(&self, ast: &AstNode) -> Result<String, String> {
         match ast {
             AstNode::Function { name, params, return_type, body } => {
-                let ret_type = return_type.as_ref().map(|s| s.as_str()).unwrap_or("void");
+                let ret_type = return_type.as_deref().map(c_type).unwrap_or("void");
                 let mut code = format!("{} {}(", ret_type, name);
                 
                 if params.is_empty() {
@@ -568,6 +779,178 @@ impl DCGEngine {
     }
 }
 
+/// Words that cannot name a function in at least one target language
+const RESERVED_WORDS: &[&str] = &[
+    "as", "async", "await", "break", "case", "char", "class", "const", "continue", "def",
+    "default", "delete", "do", "double", "else", "enum", "extern", "false", "float", "fn",
+    "for", "from", "function", "if", "impl", "import", "in", "int", "let", "long", "loop",
+    "match", "mod", "move", "new", "none", "not", "or", "pass", "pub", "return", "self",
+    "short", "static", "struct", "switch", "this", "trait", "true", "type", "unsigned",
+    "use", "var", "void", "where", "while", "with", "yield",
+];
+
+/// Operations the generator recognizes: (id, description, intent keywords)
+const OPERATIONS: [(&str, &str, &[&str]); 3] = [
+    ("fibonacci", "Fibonacci sequence", &["fibonacci"]),
+    ("sort", "Sort a collection in place", &["sort"]),
+    ("sum", "Sum or accumulate values", &["sum", "add"]),
+];
+
+/// Fallback operation: a documented stub
+const STUB_OPERATION: (&str, &str) = ("stub", "Function stub documenting the intent");
+
+/// Confidence assigned to the stub when no operation matches
+const STUB_CONFIDENCE: f32 = 0.75;
+
+/// Minimum cosine similarity for a semantic operation match
+pub const SEMANTIC_MIN_SIMILARITY: f32 = 0.8;
+
+fn operation_description(operation: &str) -> Option<&'static str> {
+    OPERATIONS
+        .iter()
+        .map(|(id, description, _)| (*id, *description))
+        .chain(core::iter::once(STUB_OPERATION))
+        .find(|(id, _)| *id == operation)
+        .map(|(_, description)| description)
+}
+
+/// Rank operations by keyword evidence, most likely first
+///
+/// Confidence is each operation's share of keyword hits, so a single match
+/// scores 1.0 and competing matches split the evidence.
+fn rank_operations(intent: &str) -> Vec<Interpretation> {
+    let intent_lower = intent.to_lowercase();
+    let mut hits: Vec<(usize, &str, &str)> = OPERATIONS
+        .iter()
+        .map(|(id, description, keywords)| {
+            let count = keywords.iter().filter(|k| intent_lower.contains(*k)).count();
+            (count, *id, *description)
+        })
+        .filter(|(count, _, _)| *count > 0)
+        .collect();
+    
+    if hits.is_empty() {
+        return vec![Interpretation {
+            operation: STUB_OPERATION.0.into(),
+            description: STUB_OPERATION.1.into(),
+            confidence: STUB_CONFIDENCE,
+        }];
+    }
+    
+    // Stable sort keeps table order among ties
+    hits.sort_by_key(|(count, _, _)| core::cmp::Reverse(*count));
+    let total: usize = hits.iter().map(|(count, _, _)| count).sum();
+    hits.into_iter()
+        .map(|(count, id, description)| Interpretation {
+            operation: id.into(),
+            description: description.into(),
+            confidence: count as f32 / total as f32,
+        })
+        .collect()
+}
+
+/// Derive a function name from the first significant word of the intent
+fn extract_function_name(intent: &str) -> Option<String> {
+    let first = intent.split_whitespace().find(|w| w.len() > 3)?;
+    
+    // Convert to snake_case
+    let name: String = first
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    
+    // Keep the name a valid identifier in every target language
+    if name.is_empty() {
+        None
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        Some(format!("fn_{}", name))
+    } else if RESERVED_WORDS.contains(&name.as_str()) {
+        Some(format!("{}_fn", name))
+    } else {
+        Some(name)
+    }
+}
+
+/// Whether a user-supplied name is usable in every target language
+fn is_valid_function_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !RESERVED_WORDS.contains(&name)
+}
+
+/// Function body for an operation
+fn body_for_operation(operation: &str, intent: &str) -> AstNode {
+    match operation {
+        "fibonacci" => AstNode::Block {
+            statements: vec![
+                AstNode::Comment { text: "Fibonacci implementation".into() },
+                AstNode::Assignment {
+                    target: "a".into(),
+                    value: "0".into(),
+                },
+                AstNode::Assignment {
+                    target: "b".into(),
+                    value: "1".into(),
+                },
+                AstNode::Return { value: Some("a".into()) },
+            ],
+        },
+        "sort" => AstNode::Block {
+            statements: vec![
+                AstNode::Comment { text: "Sort implementation (in place)".into() },
+                AstNode::Return { value: None },
+            ],
+        },
+        "sum" => AstNode::Block {
+            statements: vec![
+                AstNode::Assignment {
+                    target: "result".into(),
+                    value: "0".into(),
+                },
+                AstNode::Return { value: Some("result".into()) },
+            ],
+        },
+        // Default: simple function body
+        _ => AstNode::Block {
+            statements: vec![
+                AstNode::Comment { text: format!("Generated from: {}", intent) },
+                AstNode::Return { value: None },
+            ],
+        },
+    }
+}
+
+/// Whether a function body returns a value
+fn returns_value(node: &AstNode) -> bool {
+    match node {
+        AstNode::Return { value } => value.is_some(),
+        AstNode::Block { statements } => statements.iter().any(returns_value),
+        _ => false,
+    }
+}
+
+/// Rust spelling of an IR type
+fn rust_type(ir_type: &str) -> &str {
+    match ir_type {
+        "int" => "i64",
+        "float" => "f64",
+        "bool" => "bool",
+        other => other,
+    }
+}
+
+/// C spelling of an IR type
+fn c_type(ir_type: &str) -> &str {
+    match ir_type {
+        "int" => "long",
+        "float" => "double",
+        "bool" => "int",
+        other => other,
+    }
+}
+
 impl Default for DCGEngine {
     fn default() -> Self {
         Self::new(42)
@@ -584,7 +967,7 @@ mod tests {
         let result = dcge.generate("create fibonacci function", "rust");
         
         assert!(result.is_ok());
-        let code = result.unwrap();
+        let code = result.unwrap().into_code().unwrap();
         assert_eq!(code.language, Language::Rust);
         assert!(code.validated);
         assert!(code.source.contains("fn"));
@@ -596,7 +979,7 @@ mod tests {
         let result = dcge.generate("create sum function", "python");
         
         assert!(result.is_ok());
-        let code = result.unwrap();
+        let code = result.unwrap().into_code().unwrap();
         assert_eq!(code.language, Language::Python);
         assert!(code.source.contains("def"));
     }
@@ -607,7 +990,7 @@ mod tests {
         let result = dcge.generate("create sort function", "javascript");
         
         assert!(result.is_ok());
-        let code = result.unwrap();
+        let code = result.unwrap().into_code().unwrap();
         assert_eq!(code.language, Language::JavaScript);
         assert!(code.source.contains("function"));
     }
@@ -617,8 +1000,8 @@ mod tests {
         let mut dcge1 = DCGEngine::new(42);
         let mut dcge2 = DCGEngine::new(42);
         
-        let code1 = dcge1.generate("test function", "rust").unwrap();
-        let code2 = dcge2.generate("test function", "rust").unwrap();
+        let code1 = dcge1.generate("test function", "rust").unwrap().into_code().unwrap();
+        let code2 = dcge2.generate("test function", "rust").unwrap().into_code().unwrap();
         
         assert_eq!(code1.source, code2.source);
     }
@@ -626,10 +1009,105 @@ mod tests {
     #[test]
     fn test_supremacy_metrics() {
         let mut dcge = DCGEngine::new(42);
-        let code = dcge.generate("test", "rust").unwrap();
+        let code = dcge.generate("test", "rust").unwrap().into_code().unwrap();
         
         assert!(code.metrics.correctness_score > 0.9);
         assert!(code.metrics.determinism_compliant);
         assert!(code.metrics.minimality_score > 0.5);
     }
+
+    #[test]
+    fn test_competing_operations_need_clarification() {
+        let mut dcge = DCGEngine::new(42);
+        let outcome = dcge.generate("sort then sum values", "rust").unwrap();
+        
+        let request = outcome.clarification().unwrap();
+        assert!(request.confidence < 0.6);
+        assert_eq!(request.coverage, 1.0);
+        let ops: Vec<&str> = request.candidates.iter().map(|c| c.operation.as_str()).collect();
+        assert_eq!(ops, ["sort", "sum"]);
+        assert_eq!(request.questions.len(), 1);
+        assert_eq!(request.questions[0].id, "operation");
+        assert_eq!(request.questions[0].options, ["sort", "sum"]);
+    }
+
+    #[test]
+    fn test_unresolved_constraints_need_clarification() {
+        let mut dcge = DCGEngine::new(42);
+        let outcome = dcge.generate("sum", "cobol").unwrap();
+        
+        let request = outcome.clarification().unwrap();
+        assert_eq!(request.coverage, 0.0);
+        let ids: Vec<&str> = request.questions.iter().map(|q| q.id.as_str()).collect();
+        assert_eq!(ids, ["function_name", "language"]);
+        
+        // Relaxed policy falls back to the defaults instead of asking
+        dcge.set_clarification_policy(ClarificationPolicy { min_confidence: 0.0, min_coverage: 0.0 });
+        let code = dcge.generate("sum", "cobol").unwrap().into_code().unwrap();
+        assert_eq!(code.language, Language::Rust);
+        assert!(code.source.contains("fn generated_fn()"));
+    }
+
+    #[test]
+    fn test_answers_continue_deterministically() {
+        let answers = [
+            ClarificationAnswer { question_id: "operation".into(), answer: "sum".into() },
+            ClarificationAnswer { question_id: "function_name".into(), answer: "total".into() },
+        ];
+        let code1 = DCGEngine::new(42).generate_with_answers("sort then sum", "c", &answers).unwrap();
+        let code2 = DCGEngine::new(42).generate_with_answers("sort then sum", "c", &answers).unwrap();
+        
+        let code1 = code1.into_code().unwrap();
+        assert_eq!(code1.source, code2.into_code().unwrap().source);
+        assert!(code1.source.starts_with("long total(void)"));
+        assert!(code1.source.contains("return result;"));
+    }
+
+    #[test]
+    fn test_invalid_answers_rejected() {
+        let mut dcge = DCGEngine::new(42);
+        let answer = |id: &str, value: &str| [ClarificationAnswer { question_id: id.into(), answer: value.into() }];
+        
+        assert!(dcge.generate_with_answers("sum", "rust", &answer("operation", "divide")).is_err());
+        assert!(dcge.generate_with_answers("sum", "rust", &answer("function_name", "fn")).is_err());
+        assert!(dcge.generate_with_answers("sum", "rust", &answer("function_name", "9lives")).is_err());
+        assert!(dcge.generate_with_answers("sum", "rust", &answer("language", "cobol")).is_err());
+        assert!(dcge.generate_with_answers("sum", "rust", &answer("colour", "blue")).is_err());
+    }
+
+    #[test]
+    fn test_semantic_operation_lookup() {
+        let intent = "order these records";
+        let stub = DCGEngine::new(42).generate(intent, "rust").unwrap().into_code().unwrap();
+        assert!(!stub.source.contains("Sort implementation"));
+
+        let mut model = MiniLMQ4::new(7);
+        let mut index = DCGEngine::operation_index(&mut model);
+        assert_eq!(index.len(), OPERATIONS.len());
+        index.insert_text(&mut model, "sort", "Order these records").unwrap();
+        index.insert_text(&mut model, "not_an_operation", "print a banner").unwrap();
+
+        let mut dcge = DCGEngine::new(42);
+        dcge.set_semantic_lookup(model, index);
+        let code = dcge.generate(intent, "rust").unwrap().into_code().unwrap();
+        assert!(code.source.contains("Sort implementation"));
+
+        // Keyword hits win; unknown ids and weak matches fall back to the stub
+        let keyword = dcge.generate("create fibonacci function", "rust").unwrap().into_code().unwrap();
+        assert!(keyword.source.contains("Fibonacci implementation"));
+        assert_eq!(dcge.generate("print a banner", "rust").unwrap().into_code().unwrap().source,
+            DCGEngine::new(42).generate("print a banner", "rust").unwrap().into_code().unwrap().source);
+    }
+
+    #[test]
+    fn test_output_is_formatted() {
+        for lang in ["rust", "python", "javascript", "c"] {
+            for intent in ["create fibonacci function", "create sort function", "test function"] {
+                let code = DCGEngine::new(42).generate(intent, lang).unwrap().into_code().unwrap();
+                let language = Language::from_str(lang);
+                assert_eq!(codefmt::format_source(&code.source, &language), code.source);
+                assert!(code.source.lines().all(|l| !l.is_empty() && l.trim_end() == l));
+            }
+        }
+    }
 }
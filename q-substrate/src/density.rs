@@ -0,0 +1,615 @@
+//! Density Matrix Simulation for Small Registers
+//!
+//! Mixed-state companion to Mini QuASIM for up to 6 qubits:
+//! - ρ evolves as UρU† under the same [`QuantumGate`] set
+//! - Measurement without post-selection dephases instead of collapsing
+//! - [`NoiseConfig`] channels apply as exact Kraus maps, not trajectories
+//! - Partial trace and von Neumann entropy of any subsystem
+//! - Wootters concurrence of two-qubit states
+//!
+//! A 6-qubit ρ holds 4096 amplitudes, the same 32KB budget as the
+//! 12-qubit state vector. Bit `i` of a basis index is qubit `i`, matching
+//! the state-vector simulator.
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use crate::config::NoiseConfig;
+use crate::quantum::{Complex, MiniQuASIM, QuantumGate};
+#[cfg(not(feature = "std"))]
+use crate::float::FloatExt;
+
+/// Maximum register width for [`DensityMatrixState`]
+pub const MAX_DENSITY_QUBITS: usize = 6;
+
+/// Jacobi sweeps before eigenvalue iteration gives up
+const MAX_JACOBI_SWEEPS: usize = 64;
+
+/// Single-qubit operator, row-major
+type Matrix2 = [[Complex; 2]; 2];
+
+/// Density matrix error types
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DensityError {
+    /// Register or subsystem wider than [`MAX_DENSITY_QUBITS`] (or empty)
+    UnsupportedSize(usize),
+    /// Qubit index outside the register
+    QubitOutOfRange(usize),
+    /// Qubit listed twice
+    DuplicateQubit(usize),
+    /// Two-qubit metric requested on a register of another width
+    NotAPair(usize),
+}
+
+impl core::fmt::Display for DensityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DensityError::UnsupportedSize(n) => {
+                write!(f, "Density matrices support 1-{} qubits, got {}", MAX_DENSITY_QUBITS, n)
+            }
+            DensityError::QubitOutOfRange(q) => write!(f, "Qubit {} out of range", q),
+            DensityError::DuplicateQubit(q) => write!(f, "Qubit {} listed twice", q),
+            DensityError::NotAPair(n) => write!(f, "Concurrence needs a 2-qubit state, got {} qubits", n),
+        }
+    }
+}
+
+/// Mixed quantum state of up to 6 qubits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DensityMatrixState {
+    /// Register width
+    qubits: usize,
+    /// Elements ρ[r][c] at `r * dim + c`
+    elements: Vec<Complex>,
+    /// Noise channels applied after each gate
+    noise: NoiseConfig,
+}
+
+impl DensityMatrixState {
+    /// Pure |0...0⟩⟨0...0| on `qubits` qubits
+    pub fn new(qubits: usize) -> Result<Self, DensityError> {
+        if qubits == 0 || qubits > MAX_DENSITY_QUBITS {
+            return Err(DensityError::UnsupportedSize(qubits));
+        }
+        let dim = 1 << qubits;
+        let mut elements = vec![Complex::ZERO; dim * dim];
+        elements[0] = Complex::ONE;
+        Ok(Self { qubits, elements, noise: NoiseConfig::default() })
+    }
+
+    /// Reduced state of `qubits` from a state-vector simulator
+    ///
+    /// Traces out every other qubit of |ψ⟩⟨ψ|; bit `i` of the result is
+    /// `qubits[i]`.
+    pub fn from_simulator(sim: &MiniQuASIM, qubits: &[usize]) -> Result<Self, DensityError> {
+        validate_subsystem(qubits, sim.num_qubits())?;
+        let dim = 1 << qubits.len();
+        let mask: usize = qubits.iter().map(|&q| 1 << q).sum();
+        let embed = |sub: usize, env: usize| -> usize {
+            qubits
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| (sub >> bit) & 1 == 1)
+                .fold(env, |acc, (_, &q)| acc | (1 << q))
+        };
+
+        let mut elements = vec![Complex::ZERO; dim * dim];
+        for env in (0..sim.state_size()).filter(|env| env & mask == 0) {
+            for r in 0..dim {
+                let a = sim.amplitude(embed(r, env));
+                if a.norm_sq() == 0.0 {
+                    continue;
+                }
+                for c in 0..dim {
+                    let b = sim.amplitude(embed(c, env));
+                    elements[r * dim + c] = elements[r * dim + c].add(a.mul(b.conj()));
+                }
+            }
+        }
+        Ok(Self { qubits: qubits.len(), elements, noise: NoiseConfig::default() })
+    }
+
+    /// Number of qubits
+    pub fn num_qubits(&self) -> usize {
+        self.qubits
+    }
+
+    /// Matrix dimension (2^qubits)
+    pub fn dim(&self) -> usize {
+        1 << self.qubits
+    }
+
+    /// Element ρ[row][col]
+    pub fn get(&self, row: usize, col: usize) -> Complex {
+        self.elements[row * self.dim() + col]
+    }
+
+    /// Set the noise model applied by [`DensityMatrixState::apply_gate`]
+    pub fn set_noise(&mut self, noise: NoiseConfig) {
+        self.noise = noise;
+    }
+
+    /// Apply a gate as ρ → UρU†, then the configured noise channels
+    ///
+    /// `Measure` is non-selective: it removes coherences between the
+    /// qubit's |0⟩ and |1⟩ branches, leaving a classical mixture.
+    /// Gates naming qubits outside the register are ignored.
+    pub fn apply_gate(&mut self, gate: &QuantumGate) {
+        let qubits = gate.qubits();
+        if qubits.iter().any(|&q| q >= self.qubits) {
+            return;
+        }
+
+        let x: Matrix2 = [[Complex::ZERO, Complex::ONE], [Complex::ONE, Complex::ZERO]];
+        let z: Matrix2 = [[Complex::ONE, Complex::ZERO], [Complex::ZERO, Complex::new(-1.0, 0.0)]];
+        match *gate {
+            QuantumGate::CNOT(c, t) => self.conjugate(1 << c, t, &x),
+            QuantumGate::CZ(c, t) => self.conjugate(1 << c, t, &z),
+            QuantumGate::SWAP(a, b) => {
+                self.conjugate(1 << a, b, &x);
+                self.conjugate(1 << b, a, &x);
+                self.conjugate(1 << a, b, &x);
+            }
+            QuantumGate::Toffoli(c1, c2, t) => self.conjugate((1 << c1) | (1 << c2), t, &x),
+            QuantumGate::Measure(q) => self.dephase(q),
+            ref single => {
+                if let Some(u) = single_qubit_matrix(single) {
+                    self.conjugate(0, qubits[0], &u);
+                }
+            }
+        }
+
+        if !self.noise.is_ideal() && !matches!(gate, QuantumGate::Measure(_)) {
+            for q in qubits {
+                self.apply_noise(q);
+            }
+        }
+    }
+
+    /// Apply every enabled noise channel to one qubit as a Kraus map
+    fn apply_noise(&mut self, qubit: usize) {
+        let noise = self.noise;
+        let x: Matrix2 = [[Complex::ZERO, Complex::ONE], [Complex::ONE, Complex::ZERO]];
+        let y: Matrix2 = [[Complex::ZERO, Complex::new(0.0, -1.0)], [Complex::I, Complex::ZERO]];
+        let z: Matrix2 = [[Complex::ONE, Complex::ZERO], [Complex::ZERO, Complex::new(-1.0, 0.0)]];
+
+        if noise.depolarizing > 0.0 {
+            let p = noise.depolarizing / 3.0;
+            self.pauli_channel(qubit, &[(p, x), (p, y), (p, z)]);
+        }
+        if noise.bit_flip > 0.0 {
+            self.pauli_channel(qubit, &[(noise.bit_flip, x)]);
+        }
+        if noise.phase_flip > 0.0 {
+            self.pauli_channel(qubit, &[(noise.phase_flip, z)]);
+        }
+        if noise.amplitude_damping > 0.0 {
+            let gamma = noise.amplitude_damping;
+            let k0: Matrix2 = [[Complex::ONE, Complex::ZERO], [Complex::ZERO, Complex::new((1.0 - gamma).sqrt(), 0.0)]];
+            let k1: Matrix2 = [[Complex::ZERO, Complex::new(gamma.sqrt(), 0.0)], [Complex::ZERO, Complex::ZERO]];
+            let mut decayed = self.clone();
+            decayed.conjugate(0, qubit, &k1);
+            self.conjugate(0, qubit, &k0);
+            for (e, d) in self.elements.iter_mut().zip(&decayed.elements) {
+                *e = e.add(*d);
+            }
+        }
+    }
+
+    /// ρ → (1 - Σp)ρ + Σ p·PρP
+    fn pauli_channel(&mut self, qubit: usize, terms: &[(f32, Matrix2)]) {
+        let total: f32 = terms.iter().map(|(p, _)| p).sum();
+        let mut mixed: Vec<Complex> = self.elements.iter().map(|e| e.scale(1.0 - total)).collect();
+        for (p, pauli) in terms {
+            let mut branch = self.clone();
+            branch.conjugate(0, qubit, pauli);
+            for (m, b) in mixed.iter_mut().zip(&branch.elements) {
+                *m = m.add(b.scale(*p));
+            }
+        }
+        self.elements = mixed;
+    }
+
+    /// ρ → KρK† for `k` on `target`, controlled on every bit in `controls`
+    fn conjugate(&mut self, controls: usize, target: usize, k: &Matrix2) {
+        let dim = self.dim();
+        let t = 1 << target;
+        let applies = |i: usize| i & controls == controls && i & t == 0;
+
+        // Rows: ρ → Kρ
+        for i in (0..dim).filter(|&i| applies(i)) {
+            for col in 0..dim {
+                let a = self.elements[i * dim + col];
+                let b = self.elements[(i | t) * dim + col];
+                self.elements[i * dim + col] = k[0][0].mul(a).add(k[0][1].mul(b));
+                self.elements[(i | t) * dim + col] = k[1][0].mul(a).add(k[1][1].mul(b));
+            }
+        }
+        // Columns: ρ → ρK†
+        for j in (0..dim).filter(|&j| applies(j)) {
+            for row in 0..dim {
+                let a = self.elements[row * dim + j];
+                let b = self.elements[row * dim + (j | t)];
+                self.elements[row * dim + j] = a.mul(k[0][0].conj()).add(b.mul(k[0][1].conj()));
+                self.elements[row * dim + (j | t)] = a.mul(k[1][0].conj()).add(b.mul(k[1][1].conj()));
+            }
+        }
+    }
+
+    /// Zero coherences between the |0⟩ and |1⟩ branches of `qubit`
+    fn dephase(&mut self, qubit: usize) {
+        let dim = self.dim();
+        let mask = 1 << qubit;
+        for r in 0..dim {
+            for c in 0..dim {
+                if (r ^ c) & mask != 0 {
+                    self.elements[r * dim + c] = Complex::ZERO;
+                }
+            }
+        }
+    }
+
+    /// Basis-state probabilities (the diagonal of ρ)
+    pub fn probabilities(&self) -> Vec<f32> {
+        (0..self.dim()).map(|i| self.get(i, i).re).collect()
+    }
+
+    /// Real part of the trace
+    pub fn trace(&self) -> f32 {
+        (0..self.dim()).map(|i| self.get(i, i).re).sum()
+    }
+
+    /// Tr(ρ²): 1 for pure states, 1/2^n for the maximally mixed state
+    pub fn purity(&self) -> f32 {
+        self.elements.iter().map(|e| e.norm_sq()).sum()
+    }
+
+    /// Reduced state of `keep`, tracing out every other qubit
+    ///
+    /// Bit `i` of the result is `keep[i]`.
+    pub fn partial_trace(&self, keep: &[usize]) -> Result<DensityMatrixState, DensityError> {
+        validate_subsystem(keep, self.qubits)?;
+        let dim = self.dim();
+        let sub_dim = 1 << keep.len();
+        let mask: usize = keep.iter().map(|&q| 1 << q).sum();
+        let embed = |sub: usize, env: usize| -> usize {
+            keep.iter()
+                .enumerate()
+                .filter(|(bit, _)| (sub >> bit) & 1 == 1)
+                .fold(env, |acc, (_, &q)| acc | (1 << q))
+        };
+
+        let mut elements = vec![Complex::ZERO; sub_dim * sub_dim];
+        for env in (0..dim).filter(|env| env & mask == 0) {
+            for r in 0..sub_dim {
+                for c in 0..sub_dim {
+                    let e = self.get(embed(r, env), embed(c, env));
+                    elements[r * sub_dim + c] = elements[r * sub_dim + c].add(e);
+                }
+            }
+        }
+        Ok(DensityMatrixState { qubits: keep.len(), elements, noise: self.noise })
+    }
+
+    /// Eigenvalues of ρ, descending (computed in f64)
+    pub fn eigenvalues(&self) -> Vec<f32> {
+        hermitian_eigenvalues(self.dim(), |r, c| {
+            let e = self.get(r, c);
+            (e.re as f64, e.im as f64)
+        })
+        .into_iter()
+        .map(|v| v as f32)
+        .collect()
+    }
+
+    /// Von Neumann entropy S(ρ) = -Tr(ρ log₂ ρ) in bits
+    ///
+    /// Zero for pure states; `n` bits for the maximally mixed `n`-qubit state.
+    pub fn von_neumann_entropy(&self) -> f32 {
+        self.eigenvalues()
+            .into_iter()
+            .filter(|&lambda| lambda > 1e-7)
+            .map(|lambda| -lambda * lambda.ln() / core::f32::consts::LN_2)
+            .sum()
+    }
+
+    /// Von Neumann entropy of the reduced state of `qubits`
+    ///
+    /// For a pure global state this is the entanglement entropy between
+    /// `qubits` and the rest of the register.
+    pub fn subsystem_entropy(&self, qubits: &[usize]) -> Result<f32, DensityError> {
+        Ok(self.partial_trace(qubits)?.von_neumann_entropy())
+    }
+
+    /// Wootters concurrence of a two-qubit state
+    ///
+    /// C = max(0, λ₁ - λ₂ - λ₃ - λ₄) where λᵢ² are the eigenvalues of ρρ̃
+    /// and ρ̃ = (Y⊗Y)ρ*(Y⊗Y). 1 for Bell states, 0 for separable states.
+    /// ρ is factored as LL† by pivoted Cholesky, so the λᵢ² come from the
+    /// Hermitian L†ρ̃L, which shares the nonzero eigenvalues of ρρ̃.
+    pub fn concurrence(&self) -> Result<f32, DensityError> {
+        if self.qubits != 2 {
+            return Err(DensityError::NotAPair(self.qubits));
+        }
+        let rho: Vec<(f64, f64)> = self.elements.iter().map(|e| (e.re as f64, e.im as f64)).collect();
+
+        // Pivoted Cholesky: ρ = Σ lₖlₖ† over the columns of L
+        let mut residual = rho.clone();
+        let mut columns: Vec<[(f64, f64); 4]> = Vec::new();
+        while columns.len() < 4 {
+            let (pivot, diag) = (0..4)
+                .map(|i| (i, residual[i * 4 + i].0))
+                .fold((0, f64::MIN), |best, cur| if cur.1 > best.1 { cur } else { best });
+            if diag < 1e-12 {
+                break;
+            }
+            let norm = diag.sqrt();
+            let mut column = [(0.0, 0.0); 4];
+            for (r, entry) in column.iter_mut().enumerate() {
+                let (re, im) = residual[r * 4 + pivot];
+                *entry = (re / norm, im / norm);
+            }
+            for r in 0..4 {
+                for c in 0..4 {
+                    let (re, im) = c64_mul(column[r], c64_conj(column[c]));
+                    residual[r * 4 + c].0 -= re;
+                    residual[r * 4 + c].1 -= im;
+                }
+            }
+            columns.push(column);
+        }
+        if columns.is_empty() {
+            return Ok(0.0);
+        }
+
+        // ρ̃[r][c] = s[r]·s[c]·conj(ρ[3-r][3-c]) with Y⊗Y signs s = (-1, 1, 1, -1)
+        const SIGN: [f64; 4] = [-1.0, 1.0, 1.0, -1.0];
+        let tilde = |r: usize, c: usize| {
+            let (re, im) = rho[(3 - r) * 4 + (3 - c)];
+            let s = SIGN[r] * SIGN[c];
+            (s * re, -s * im)
+        };
+
+        let mut lambdas: Vec<f64> = hermitian_eigenvalues(columns.len(), |i, j| {
+            let mut sum = (0.0, 0.0);
+            for r in 0..4 {
+                for c in 0..4 {
+                    let term = c64_mul(c64_mul(c64_conj(columns[i][r]), tilde(r, c)), columns[j][c]);
+                    sum = (sum.0 + term.0, sum.1 + term.1);
+                }
+            }
+            sum
+        })
+        .into_iter()
+        .map(|mu| mu.max(0.0).sqrt())
+        .collect();
+        lambdas.resize(4, 0.0);
+
+        let c = lambdas[0] - lambdas[1] - lambdas[2] - lambdas[3];
+        Ok(c.max(0.0) as f32)
+    }
+}
+
+/// Eigenvalues of an `n`×`n` Hermitian matrix, descending
+///
+/// Cyclic Jacobi on the real-symmetric embedding [[Re, -Im], [Im, Re]];
+/// every eigenvalue appears there twice, so every other one is kept.
+fn hermitian_eigenvalues(n: usize, element: impl Fn(usize, usize) -> (f64, f64)) -> Vec<f64> {
+    let m = 2 * n;
+    let mut a = vec![0.0_f64; m * m];
+    for r in 0..n {
+        for c in 0..n {
+            let (re, im) = element(r, c);
+            a[r * m + c] = re;
+            a[(r + n) * m + (c + n)] = re;
+            a[r * m + (c + n)] = -im;
+            a[(r + n) * m + c] = im;
+        }
+    }
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off: f64 = (0..m)
+            .flat_map(|p| ((p + 1)..m).map(move |q| (p, q)))
+            .map(|(p, q)| a[p * m + q] * a[p * m + q])
+            .sum();
+        if off < 1e-22 {
+            break;
+        }
+        for p in 0..m {
+            for q in (p + 1)..m {
+                let apq = a[p * m + q];
+                if apq.abs() < 1e-30 {
+                    continue;
+                }
+                let theta = (a[q * m + q] - a[p * m + p]) / (2.0 * apq);
+                let sign = if theta >= 0.0 { 1.0 } else { -1.0 };
+                let t = sign / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let cos = 1.0 / (t * t + 1.0).sqrt();
+                let sin = t * cos;
+                for k in 0..m {
+                    let akp = a[k * m + p];
+                    let akq = a[k * m + q];
+                    a[k * m + p] = cos * akp - sin * akq;
+                    a[k * m + q] = sin * akp + cos * akq;
+                }
+                for k in 0..m {
+                    let apk = a[p * m + k];
+                    let aqk = a[q * m + k];
+                    a[p * m + k] = cos * apk - sin * aqk;
+                    a[q * m + k] = sin * apk + cos * aqk;
+                }
+            }
+        }
+    }
+
+    let mut doubled: Vec<f64> = (0..m).map(|i| a[i * m + i]).collect();
+    doubled.sort_by(|x, y| y.partial_cmp(x).unwrap_or(core::cmp::Ordering::Equal));
+    doubled.into_iter().step_by(2).collect()
+}
+
+fn validate_subsystem(qubits: &[usize], register: usize) -> Result<(), DensityError> {
+    if qubits.is_empty() || qubits.len() > MAX_DENSITY_QUBITS {
+        return Err(DensityError::UnsupportedSize(qubits.len()));
+    }
+    for (i, &q) in qubits.iter().enumerate() {
+        if q >= register {
+            return Err(DensityError::QubitOutOfRange(q));
+        }
+        if qubits[..i].contains(&q) {
+            return Err(DensityError::DuplicateQubit(q));
+        }
+    }
+    Ok(())
+}
+
+fn c64_mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+fn c64_conj(a: (f64, f64)) -> (f64, f64) {
+    (a.0, -a.1)
+}
+
+/// 2×2 unitary for a single-qubit gate
+fn single_qubit_matrix(gate: &QuantumGate) -> Option<Matrix2> {
+    let (o, l, i) = (Complex::ZERO, Complex::ONE, Complex::I);
+    let h = core::f32::consts::FRAC_1_SQRT_2;
+    let m = match *gate {
+        QuantumGate::Hadamard(_) => [[Complex::new(h, 0.0), Complex::new(h, 0.0)], [Complex::new(h, 0.0), Complex::new(-h, 0.0)]],
+        QuantumGate::PauliX(_) => [[o, l], [l, o]],
+        QuantumGate::PauliY(_) => [[o, Complex::new(0.0, -1.0)], [i, o]],
+        QuantumGate::PauliZ(_) => [[l, o], [o, Complex::new(-1.0, 0.0)]],
+        QuantumGate::Phase(_) => [[l, o], [o, i]],
+        QuantumGate::T(_) => [[l, o], [o, Complex::new(h, h)]],
+        QuantumGate::TDagger(_) => [[l, o], [o, Complex::new(h, -h)]],
+        QuantumGate::RX(_, theta) => {
+            let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+            [[Complex::new(c, 0.0), Complex::new(0.0, -s)], [Complex::new(0.0, -s), Complex::new(c, 0.0)]]
+        }
+        QuantumGate::RY(_, theta) => {
+            let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+            [[Complex::new(c, 0.0), Complex::new(-s, 0.0)], [Complex::new(s, 0.0), Complex::new(c, 0.0)]]
+        }
+        QuantumGate::RZ(_, theta) => {
+            let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+            [[Complex::new(c, -s), o], [o, Complex::new(c, s)]]
+        }
+        _ => return None,
+    };
+    Some(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_state_vector() {
+        let gates = [
+            QuantumGate::Hadamard(0),
+            QuantumGate::RY(1, 0.7),
+            QuantumGate::CNOT(0, 2),
+            QuantumGate::T(2),
+            QuantumGate::RX(0, 1.3),
+            QuantumGate::SWAP(1, 2),
+            QuantumGate::Toffoli(0, 1, 2),
+            QuantumGate::RZ(1, -0.4),
+            QuantumGate::CZ(2, 0),
+        ];
+        let mut sim = MiniQuASIM::new(42);
+        let mut rho = DensityMatrixState::new(3).unwrap();
+        for gate in &gates {
+            sim.apply_gate(gate);
+            rho.apply_gate(gate);
+        }
+
+        let exact = DensityMatrixState::from_simulator(&sim, &[0, 1, 2]).unwrap();
+        for r in 0..8 {
+            for c in 0..8 {
+                let (a, b) = (rho.get(r, c), exact.get(r, c));
+                assert!((a.re - b.re).abs() < 1e-4 && (a.im - b.im).abs() < 1e-4);
+            }
+        }
+        assert!((rho.trace() - 1.0).abs() < 1e-4);
+        assert!((rho.purity() - 1.0).abs() < 1e-3);
+        assert!(rho.von_neumann_entropy() < 1e-2);
+    }
+
+    #[test]
+    fn test_partial_trace_and_entropy() {
+        // Bell pair: each half is maximally mixed, the pair is pure
+        let mut rho = DensityMatrixState::new(3).unwrap();
+        rho.apply_gate(&QuantumGate::Hadamard(0));
+        rho.apply_gate(&QuantumGate::CNOT(0, 1));
+
+        let half = rho.partial_trace(&[1]).unwrap();
+        assert!((half.get(0, 0).re - 0.5).abs() < 1e-5);
+        assert!(half.get(0, 1).norm_sq() < 1e-10);
+        assert!((half.von_neumann_entropy() - 1.0).abs() < 1e-3);
+        assert!((rho.subsystem_entropy(&[0, 1]).unwrap()).abs() < 1e-3);
+        assert!((rho.subsystem_entropy(&[2]).unwrap()).abs() < 1e-3);
+
+        // Contrast with the Shannon entropy of the outcome distribution
+        let mut product = DensityMatrixState::new(2).unwrap();
+        product.apply_gate(&QuantumGate::Hadamard(0));
+        product.apply_gate(&QuantumGate::Hadamard(1));
+        assert!(product.subsystem_entropy(&[0]).unwrap().abs() < 1e-3);
+
+        assert_eq!(rho.partial_trace(&[3]).unwrap_err(), DensityError::QubitOutOfRange(3));
+        assert_eq!(rho.partial_trace(&[0, 0]).unwrap_err(), DensityError::DuplicateQubit(0));
+        assert_eq!(DensityMatrixState::new(7).unwrap_err(), DensityError::UnsupportedSize(7));
+    }
+
+    #[test]
+    fn test_concurrence() {
+        let mut bell = DensityMatrixState::new(2).unwrap();
+        bell.apply_gate(&QuantumGate::Hadamard(0));
+        bell.apply_gate(&QuantumGate::CNOT(0, 1));
+        assert!((bell.concurrence().unwrap() - 1.0).abs() < 1e-4);
+
+        // RY(θ) then CNOT gives cos(θ/2)|00⟩ + sin(θ/2)|11⟩ with C = sin θ
+        let theta = 0.7_f32;
+        let mut partial = DensityMatrixState::new(2).unwrap();
+        partial.apply_gate(&QuantumGate::RY(0, theta));
+        partial.apply_gate(&QuantumGate::CNOT(0, 1));
+        assert!((partial.concurrence().unwrap() - theta.sin()).abs() < 1e-4);
+
+        // Separable: product states and a dephased Bell pair
+        let mut product = DensityMatrixState::new(2).unwrap();
+        product.apply_gate(&QuantumGate::Hadamard(0));
+        product.apply_gate(&QuantumGate::RX(1, 1.1));
+        assert!(product.concurrence().unwrap() < 1e-4);
+        bell.apply_gate(&QuantumGate::Measure(0));
+        assert!(bell.concurrence().unwrap() < 1e-4);
+
+        assert_eq!(DensityMatrixState::new(3).unwrap().concurrence(), Err(DensityError::NotAPair(3)));
+    }
+
+    #[test]
+    fn test_mixed_state_channels() {
+        // Non-selective measurement of |+⟩ leaves the maximally mixed state
+        let mut rho = DensityMatrixState::new(1).unwrap();
+        rho.apply_gate(&QuantumGate::Hadamard(0));
+        rho.apply_gate(&QuantumGate::Measure(0));
+        assert!((rho.purity() - 0.5).abs() < 1e-5);
+        assert!((rho.von_neumann_entropy() - 1.0).abs() < 1e-3);
+
+        // Full depolarizing after a gate: |1⟩ → 1/3|1⟩ + 2/3|0⟩ on the diagonal
+        let mut rho = DensityMatrixState::new(1).unwrap();
+        rho.set_noise(NoiseConfig { depolarizing: 1.0, ..NoiseConfig::default() });
+        rho.apply_gate(&QuantumGate::PauliX(0));
+        let p = rho.probabilities();
+        assert!((p[0] - 2.0 / 3.0).abs() < 1e-5 && (p[1] - 1.0 / 3.0).abs() < 1e-5);
+
+        // Amplitude damping is exact, not sampled
+        let mut rho = DensityMatrixState::new(2).unwrap();
+        rho.set_noise(NoiseConfig { amplitude_damping: 0.25, ..NoiseConfig::default() });
+        rho.apply_gate(&QuantumGate::PauliX(1));
+        let p = rho.probabilities();
+        assert!((p[0] - 0.25).abs() < 1e-5 && (p[2] - 0.75).abs() < 1e-5);
+        assert!((rho.trace() - 1.0).abs() < 1e-5);
+    }
+}
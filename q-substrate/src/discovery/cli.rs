@@ -9,6 +9,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
+use super::constraint::ConstraintSet;
 use super::engine::DiscoveryEngine;
 use super::provenance::verify_provenance_chain;
 use super::types::{Discovery, DiscoveryError};
@@ -30,11 +31,25 @@ pub fn run_discovery_directive(
     seed: u32,
     target_count: usize,
     output_dir: Option<&str>,
+) -> Result<DiscoveryReport, DiscoveryError> {
+    run_constrained_directive(seed, target_count, output_dir, ConstraintSet::default())
+}
+
+/// Run discovery directive within search constraints
+///
+/// Like `run_discovery_directive`, with the lattice pruned by `constraints`
+/// before evaluation
+pub fn run_constrained_directive(
+    seed: u32,
+    target_count: usize,
+    output_dir: Option<&str>,
+    constraints: ConstraintSet,
 ) -> Result<DiscoveryReport, DiscoveryError> {
     let start_time = get_time_ms();
     
     // Create and run discovery engine
     let mut engine = DiscoveryEngine::with_target(seed, target_count);
+    engine.set_constraints(constraints);
     
     let discoveries = engine.run()?;
     
@@ -223,6 +238,9 @@ mod tests {
                 qradle_hash: "QRDL-0123456789abcdef".into(),
                 seed: 42,
                 lattice_node: "test_node".into(),
+                constraints: None,
+                review: None,
+                risk: None,
             },
         }
     }
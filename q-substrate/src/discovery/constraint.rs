@@ -0,0 +1,323 @@
+//! Discovery Constraints - Search Space Bounds
+//!
+//! A small DSL for restricting which lattice axes and nodes the engine may
+//! explore. Clauses are separated by `;`, terms within a clause by `,`:
+//!
+//! ```text
+//! only materials, computation; exclude computation.QuantumAlgorithm
+//! require physics; exclude economics
+//! ```
+//!
+//! - `only <axes>`: candidates may combine only the listed axes
+//! - `require <axes>`: candidates must involve every listed axis
+//! - `exclude <axes|nodes>`: drop whole axes or single `axis.Node` entries
+//!
+//! Constraints are applied when the lattice is built, before any candidate
+//! is evaluated. Their canonical form is hashed into each discovery's
+//! provenance so a corpus stays attributable to the bounds it was searched
+//! under.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use super::lattice::{
+    CandidateNode, ComputationNode, EconomicsNode, MaterialsNode, PhysicsNode, SystemsNode,
+};
+use super::types::DiscoveryError;
+
+/// Lattice axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Axis {
+    Physics,
+    Computation,
+    Materials,
+    Systems,
+    Economics,
+}
+
+impl Axis {
+    /// DSL name of the axis
+    pub fn name(self) -> &'static str {
+        match self {
+            Axis::Physics => "physics",
+            Axis::Computation => "computation",
+            Axis::Materials => "materials",
+            Axis::Systems => "systems",
+            Axis::Economics => "economics",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "physics" => Some(Axis::Physics),
+            "computation" => Some(Axis::Computation),
+            "materials" => Some(Axis::Materials),
+            "systems" => Some(Axis::Systems),
+            "economics" => Some(Axis::Economics),
+            _ => None,
+        }
+    }
+
+    /// Node names on this axis
+    fn node_names(self) -> Vec<String> {
+        fn names<T: Debug>(nodes: &[T]) -> Vec<String> {
+            nodes.iter().map(|n| format!("{:?}", n)).collect()
+        }
+        match self {
+            Axis::Physics => names(&PhysicsNode::ALL),
+            Axis::Computation => names(&ComputationNode::ALL),
+            Axis::Materials => names(&MaterialsNode::ALL),
+            Axis::Systems => names(&SystemsNode::ALL),
+            Axis::Economics => names(&EconomicsNode::ALL),
+        }
+    }
+
+    /// Whether a candidate involves this axis
+    fn present_in(self, node: &CandidateNode) -> bool {
+        match self {
+            Axis::Physics => node.physics.is_some(),
+            Axis::Computation => node.computation.is_some(),
+            Axis::Materials => node.materials.is_some(),
+            Axis::Systems => node.systems.is_some(),
+            Axis::Economics => node.economics.is_some(),
+        }
+    }
+
+    /// Name of the candidate's node on this axis, if any
+    fn node_in(self, node: &CandidateNode) -> Option<String> {
+        match self {
+            Axis::Physics => node.physics.map(|n| format!("{:?}", n)),
+            Axis::Computation => node.computation.map(|n| format!("{:?}", n)),
+            Axis::Materials => node.materials.map(|n| format!("{:?}", n)),
+            Axis::Systems => node.systems.map(|n| format!("{:?}", n)),
+            Axis::Economics => node.economics.map(|n| format!("{:?}", n)),
+        }
+    }
+}
+
+/// Single constraint clause
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Constraint {
+    /// Candidates may only combine these axes
+    Only(Vec<Axis>),
+    /// Candidates must involve every one of these axes
+    Require(Vec<Axis>),
+    /// Candidates must not involve this axis
+    ExcludeAxis(Axis),
+    /// Candidates must not use this node
+    ExcludeNode(Axis, String),
+}
+
+impl Constraint {
+    fn permits(&self, node: &CandidateNode) -> bool {
+        match self {
+            Constraint::Only(axes) => {
+                ALL_AXES.iter().all(|a| axes.contains(a) || !a.present_in(node))
+            }
+            Constraint::Require(axes) => axes.iter().all(|a| a.present_in(node)),
+            Constraint::ExcludeAxis(axis) => !axis.present_in(node),
+            Constraint::ExcludeNode(axis, name) => axis.node_in(node).as_ref() != Some(name),
+        }
+    }
+
+    fn canonical(&self) -> String {
+        let join = |axes: &[Axis]| axes.iter().map(|a| a.name()).collect::<Vec<_>>().join(", ");
+        match self {
+            Constraint::Only(axes) => format!("only {}", join(axes)),
+            Constraint::Require(axes) => format!("require {}", join(axes)),
+            Constraint::ExcludeAxis(axis) => format!("exclude {}", axis.name()),
+            Constraint::ExcludeNode(axis, name) => format!("exclude {}.{}", axis.name(), name),
+        }
+    }
+}
+
+const ALL_AXES: [Axis; 5] = [
+    Axis::Physics,
+    Axis::Computation,
+    Axis::Materials,
+    Axis::Systems,
+    Axis::Economics,
+];
+
+/// Parsed set of constraints (empty = unbounded search)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConstraintSet {
+    constraints: Vec<Constraint>,
+}
+
+impl ConstraintSet {
+    /// Parse a constraint expression
+    pub fn parse(expr: &str) -> Result<Self, DiscoveryError> {
+        let mut constraints = Vec::new();
+
+        for clause in expr.split([';', '\n']).map(str::trim).filter(|c| !c.is_empty()) {
+            let (keyword, rest) = clause.split_once(char::is_whitespace).ok_or_else(|| {
+                DiscoveryError::InvalidConstraint(format!("clause has no terms: '{}'", clause))
+            })?;
+            let terms: Vec<&str> = rest.split(',').map(str::trim).filter(|t| !t.is_empty()).collect();
+            if terms.is_empty() {
+                return Err(DiscoveryError::InvalidConstraint(format!(
+                    "clause has no terms: '{}'",
+                    clause
+                )));
+            }
+
+            match keyword.to_ascii_lowercase().as_str() {
+                "only" => constraints.push(Constraint::Only(parse_axes(&terms)?)),
+                "require" => constraints.push(Constraint::Require(parse_axes(&terms)?)),
+                "exclude" => {
+                    for term in terms {
+                        constraints.push(parse_exclusion(term)?);
+                    }
+                }
+                other => {
+                    return Err(DiscoveryError::InvalidConstraint(format!(
+                        "unknown keyword '{}' (expected only, require or exclude)",
+                        other
+                    )))
+                }
+            }
+        }
+
+        constraints.sort_by_key(|c| c.canonical());
+        constraints.dedup();
+        Ok(Self { constraints })
+    }
+
+    /// Whether no constraints are set
+    pub fn is_empty(&self) -> bool {
+        self.constraints.is_empty()
+    }
+
+    /// Parsed clauses in canonical order
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Whether a candidate lies within the bounds
+    pub fn permits(&self, node: &CandidateNode) -> bool {
+        self.constraints.iter().all(|c| c.permits(node))
+    }
+
+    /// Whether a single node survives node- and axis-level exclusions
+    pub fn permits_node<T: Debug>(&self, axis: Axis, node: T) -> bool {
+        let name = format!("{:?}", node);
+        self.constraints.iter().all(|c| match c {
+            Constraint::ExcludeAxis(a) => *a != axis,
+            Constraint::ExcludeNode(a, n) => *a != axis || *n != name,
+            Constraint::Only(axes) => axes.contains(&axis),
+            Constraint::Require(_) => true,
+        })
+    }
+
+    /// Canonical expression: equal bounds render identically
+    pub fn canonical(&self) -> String {
+        self.constraints.iter().map(Constraint::canonical).collect::<Vec<_>>().join("; ")
+    }
+
+    /// Hash of the canonical expression, or `None` when unbounded
+    pub fn hash(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut hash = 0u64;
+        for byte in self.canonical().bytes() {
+            hash = hash.wrapping_mul(31).wrapping_add(byte as u64);
+        }
+        let hash = hash.wrapping_mul(0x517cc1b727220a95).wrapping_add(0x63f5d5a6a9e1a3c7);
+        Some(format!("QRDC-{:016x}", hash))
+    }
+}
+
+fn parse_axes(terms: &[&str]) -> Result<Vec<Axis>, DiscoveryError> {
+    let mut axes = terms
+        .iter()
+        .map(|t| {
+            Axis::parse(t).ok_or_else(|| DiscoveryError::InvalidConstraint(format!("unknown axis '{}'", t)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    axes.sort();
+    axes.dedup();
+    Ok(axes)
+}
+
+fn parse_exclusion(term: &str) -> Result<Constraint, DiscoveryError> {
+    let Some((axis, node)) = term.split_once('.') else {
+        let axis = Axis::parse(term)
+            .ok_or_else(|| DiscoveryError::InvalidConstraint(format!("unknown axis '{}'", term)))?;
+        return Ok(Constraint::ExcludeAxis(axis));
+    };
+
+    let axis = Axis::parse(axis.trim())
+        .ok_or_else(|| DiscoveryError::InvalidConstraint(format!("unknown axis '{}'", axis)))?;
+    let node = node.trim();
+    axis.node_names()
+        .into_iter()
+        .find(|n| n.eq_ignore_ascii_case(node))
+        .map(|name| Constraint::ExcludeNode(axis, name))
+        .ok_or_else(|| {
+            DiscoveryError::InvalidConstraint(format!("unknown {} node '{}'", axis.name(), node))
+        })
+}
+
+impl core::str::FromStr for ConstraintSet {
+    type Err = DiscoveryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl core::fmt::Display for ConstraintSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.canonical())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::lattice::DiscoveryLattice;
+
+    #[test]
+    fn test_parse_and_canonicalize() {
+        let a = ConstraintSet::parse("only Materials, computation; exclude computation.quantumalgorithm").unwrap();
+        let b = ConstraintSet::parse("exclude computation.QuantumAlgorithm\nonly computation,materials;").unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(a.canonical(), "exclude computation.QuantumAlgorithm; only computation, materials");
+        assert_eq!(a.hash(), b.hash());
+        assert!(a.hash().unwrap().starts_with("QRDC-"));
+        assert_eq!(ConstraintSet::parse("  ").unwrap().hash(), None);
+
+        assert!(ConstraintSet::parse("only cryptography").is_err());
+        assert!(ConstraintSet::parse("exclude physics.Warp").is_err());
+        assert!(ConstraintSet::parse("prefer physics").is_err());
+        assert!(ConstraintSet::parse("only").is_err());
+    }
+
+    #[test]
+    fn test_lattice_pruning() {
+        let full = DiscoveryLattice::new(42).enumerate_candidates();
+
+        let constraints = ConstraintSet::parse("only materials, computation; exclude computation.QuantumAlgorithm").unwrap();
+        let lattice = DiscoveryLattice::with_constraints(42, constraints);
+        let bounded = lattice.enumerate_candidates();
+
+        // Materials × Computation pairs, minus one computation node
+        assert_eq!(bounded.len(), 8 * 7);
+        assert_eq!(bounded.len(), lattice.get_candidate_count());
+        assert!(bounded.len() < full.len());
+        assert!(bounded.iter().all(|c| c.physics.is_none() && c.systems.is_none() && c.economics.is_none()));
+        assert!(bounded.iter().all(|c| c.computation != Some(ComputationNode::QuantumAlgorithm)));
+
+        let constraints = ConstraintSet::parse("require physics; exclude economics").unwrap();
+        let bounded = DiscoveryLattice::with_constraints(42, constraints).enumerate_candidates();
+        assert_eq!(bounded.len(), 8 * 8 + 8 * 8);
+        assert!(bounded.iter().all(|c| c.physics.is_some()));
+    }
+}
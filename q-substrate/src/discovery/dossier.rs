@@ -0,0 +1,442 @@
+//! Discovery Dossiers - Shareable Markdown/HTML Reports
+//!
+//! Renders a self-contained dossier per discovery and per run for readers
+//! outside the engineering team: formulation, fitness breakdown, risk
+//! envelope, review decision, and the provenance chain with each hash
+//! re-verified at render time. HTML output inlines its stylesheet and has
+//! no external references, so a single file can be mailed or archived.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use super::fitness::FitnessWeights;
+use super::provenance::{generate_provenance_hash, generate_provenance_report};
+use super::types::{Discovery, ReviewVerdict};
+use super::FITNESS_THRESHOLD;
+
+/// Parent hash of the first chain entry
+const GENESIS: &str = "GENESIS";
+
+/// Inline stylesheet for HTML dossiers
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:56rem;margin:2rem auto;\
+padding:0 1rem;color:#1b1f24}h1,h2{border-bottom:1px solid #d0d7de}table{border-collapse:collapse}\
+td,th{border:1px solid #d0d7de;padding:.3rem .6rem;text-align:left}code{font-size:.9em}\
+.ok{color:#1a7f37}.bad{color:#cf222e}";
+
+/// Output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DossierFormat {
+    /// GitHub-flavoured Markdown
+    Markdown,
+    /// Self-contained HTML document
+    Html,
+}
+
+impl DossierFormat {
+    /// Parse a CLI format name (`markdown`/`md`, `html`)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "markdown" | "md" => Some(DossierFormat::Markdown),
+            "html" => Some(DossierFormat::Html),
+            _ => None,
+        }
+    }
+
+    /// File extension for rendered dossiers
+    pub fn extension(self) -> &'static str {
+        match self {
+            DossierFormat::Markdown => "md",
+            DossierFormat::Html => "html",
+        }
+    }
+}
+
+/// Chain position of a discovery within a run
+struct ChainLink<'a> {
+    parent: &'a str,
+    hash_valid: bool,
+}
+
+/// Render the dossier for one discovery
+///
+/// Without run context the chain section shows the discovery as a
+/// standalone entry (parent `GENESIS`).
+pub fn render_discovery(discovery: &Discovery, format: DossierFormat) -> String {
+    let link = ChainLink {
+        parent: GENESIS,
+        hash_valid: generate_provenance_hash(discovery) == discovery.provenance.qradle_hash,
+    };
+    let mut doc = Doc::new(format);
+    doc.begin(&discovery.id);
+    discovery_body(&mut doc, discovery, &link, 1);
+    doc.finish()
+}
+
+/// Render the run-level dossier: summary, provenance chain, and every discovery
+pub fn render_run(title: &str, discoveries: &[Discovery], format: DossierFormat) -> String {
+    let report = generate_provenance_report(discoveries);
+    let mut doc = Doc::new(format);
+    doc.begin(title);
+
+    doc.heading(1, title);
+    doc.heading(2, "Summary");
+    let count = discoveries.len();
+    let (avg, min, max) = if count == 0 {
+        (0.0, 0.0, 0.0)
+    } else {
+        let scores = discoveries.iter().map(|d| d.fitness_score);
+        (
+            scores.clone().sum::<f64>() / count as f64,
+            scores.clone().fold(f64::INFINITY, f64::min),
+            scores.fold(f64::NEG_INFINITY, f64::max),
+        )
+    };
+    doc.table(
+        &["Metric", "Value"],
+        &[
+            alloc::vec![String::from("Discoveries"), format!("{}", count)],
+            alloc::vec![String::from("Average fitness"), format!("{:.3}", avg)],
+            alloc::vec![String::from("Fitness range"), format!("{:.3} – {:.3}", min, max)],
+            alloc::vec![String::from("Valid provenance hashes"), format!("{} / {}", report.valid_hashes, count)],
+            alloc::vec![String::from("Provenance chain"), status(report.chain_valid).into()],
+        ],
+    );
+    if !report.errors.is_empty() {
+        doc.list(&report.errors);
+    }
+
+    doc.heading(2, "Provenance Chain");
+    let mut rows = Vec::new();
+    let mut parent = GENESIS;
+    for discovery in discoveries {
+        let valid = generate_provenance_hash(discovery) == discovery.provenance.qradle_hash;
+        rows.push(alloc::vec![
+            discovery.id.clone(),
+            discovery.provenance.qradle_hash.clone(),
+            String::from(parent),
+            String::from(status(valid)),
+        ]);
+        parent = &discovery.provenance.qradle_hash;
+    }
+    doc.table(&["ID", "QRADLE hash", "Parent", "Verification"], &rows);
+
+    let mut parent = GENESIS;
+    for discovery in discoveries {
+        let link = ChainLink {
+            parent,
+            hash_valid: generate_provenance_hash(discovery) == discovery.provenance.qradle_hash,
+        };
+        discovery_body(&mut doc, discovery, &link, 2);
+        parent = &discovery.provenance.qradle_hash;
+    }
+    doc.finish()
+}
+
+/// Sections for one discovery, headed at `level`
+fn discovery_body(doc: &mut Doc, d: &Discovery, link: &ChainLink, level: u8) {
+    doc.heading(level, &format!("{} — {}", d.id, d.title));
+    doc.paragraph(&d.hypothesis);
+    doc.paragraph(&d.core_mechanism);
+
+    doc.heading(level + 1, "Formulation");
+    doc.code_list(&d.formulation.equations);
+    if let Some(spec) = &d.formulation.formal_spec {
+        doc.paragraph(spec);
+    }
+    if let Some(code) = &d.formulation.pseudocode {
+        doc.code_block(code);
+    }
+
+    doc.heading(level + 1, "Fitness Breakdown");
+    let weights = FitnessWeights::default();
+    let mut rows = alloc::vec![
+        alloc::vec![String::from("Fitness F"), format!("{:.4}", d.fitness_score)],
+        alloc::vec![String::from("Threshold"), format!("{:.2}", FITNESS_THRESHOLD)],
+        alloc::vec![String::from("Margin"), format!("{:+.4}", d.fitness_score - FITNESS_THRESHOLD)],
+        alloc::vec![
+            String::from("Weights (α, β, γ, δ)"),
+            format!("{:.2}, {:.2}, {:.2}, {:.2}", weights.alpha, weights.beta, weights.gamma, weights.delta),
+        ],
+    ];
+    // Indicator values recorded in the formulation, e.g. "I_n = 0.912"
+    for eq in &d.formulation.equations {
+        if let Some((name, value)) = eq.split_once(" = ") {
+            if name.starts_with("I_") && value.parse::<f64>().is_ok() {
+                rows.push(alloc::vec![String::from(name), String::from(value)]);
+            }
+        }
+    }
+    doc.table(&["Component", "Value"], &rows);
+
+    doc.heading(level + 1, "Validation");
+    doc.table(
+        &["Method", "Test rig", "Expected outcome", "Confidence"],
+        &[alloc::vec![
+            format!("{:?}", d.validation.method),
+            d.validation.test_rig.clone(),
+            d.validation.expected_outcome.clone(),
+            format!("{:.2}", d.validation.confidence),
+        ]],
+    );
+
+    doc.heading(level + 1, "Industrial Impact");
+    let mut impact = alloc::vec![
+        format!("Application: {}", d.industrial_impact.application),
+        format!("Sector: {}", d.industrial_impact.market_sector),
+    ];
+    if let Some(value) = &d.industrial_impact.estimated_value {
+        impact.push(format!("Estimated value: {}", value));
+    }
+    doc.list(&impact);
+
+    doc.heading(level + 1, "Risk Envelope");
+    let risks = &d.risk_envelope;
+    let depth = risks
+        .failure_modes
+        .len()
+        .max(risks.safety_constraints.len())
+        .max(risks.mitigation_strategies.len());
+    let cell = |items: &Vec<String>, i: usize| items.get(i).cloned().unwrap_or_default();
+    let rows: Vec<Vec<String>> = (0..depth)
+        .map(|i| {
+            alloc::vec![
+                cell(&risks.failure_modes, i),
+                cell(&risks.safety_constraints, i),
+                cell(&risks.mitigation_strategies, i),
+            ]
+        })
+        .collect();
+    doc.table(&["Failure mode", "Safety constraint", "Mitigation"], &rows);
+
+    doc.heading(level + 1, "Provenance");
+    let mut rows = alloc::vec![
+        alloc::vec![String::from("QRADLE hash"), d.provenance.qradle_hash.clone()],
+        alloc::vec![String::from("Parent"), String::from(link.parent)],
+        alloc::vec![String::from("Verification"), String::from(status(link.hash_valid))],
+        alloc::vec![String::from("Generated"), d.provenance.generated_at.clone()],
+        alloc::vec![String::from("Seed"), format!("{}", d.provenance.seed)],
+        alloc::vec![String::from("Lattice node"), d.provenance.lattice_node.clone()],
+    ];
+    if let Some(constraints) = &d.provenance.constraints {
+        rows.push(alloc::vec![String::from("Search constraints"), constraints.clone()]);
+    }
+    if let Some(review) = &d.provenance.review {
+        let verdict = match review.verdict {
+            ReviewVerdict::Accept => "Accepted",
+            ReviewVerdict::Reject => "Rejected",
+        };
+        rows.push(alloc::vec![
+            String::from("Review"),
+            format!("{} by {} (decision #{})", verdict, review.reviewer, review.sequence),
+        ]);
+        if let Some(note) = &review.annotation {
+            rows.push(alloc::vec![String::from("Reviewer note"), note.clone()]);
+        }
+    }
+    if let Some(risk) = &d.provenance.risk {
+        rows.push(alloc::vec![
+            String::from("Risk index"),
+            format!("{:.4} (table {})", risk.index, risk.table),
+        ]);
+        if let Some(approval) = &risk.approval {
+            rows.push(alloc::vec![String::from("Risk approval"), approval.clone()]);
+        }
+    }
+    doc.table(&["Field", "Value"], &rows);
+}
+
+/// Verification label
+fn status(valid: bool) -> &'static str {
+    if valid {
+        "✓ verified"
+    } else {
+        "✗ FAILED"
+    }
+}
+
+/// Escape text for HTML
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Escape text for a Markdown table cell
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Format-specific document builder
+struct Doc {
+    format: DossierFormat,
+    out: String,
+}
+
+impl Doc {
+    fn new(format: DossierFormat) -> Self {
+        Self { format, out: String::new() }
+    }
+
+    fn begin(&mut self, title: &str) {
+        if self.format == DossierFormat::Html {
+            let _ = write!(
+                self.out,
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+                 <title>{}</title>\n<style>{}</style>\n</head>\n<body>\n",
+                escape_html(title),
+                STYLE
+            );
+        }
+    }
+
+    fn finish(mut self) -> String {
+        if self.format == DossierFormat::Html {
+            self.out.push_str("</body>\n</html>\n");
+        }
+        self.out
+    }
+
+    fn heading(&mut self, level: u8, text: &str) {
+        let _ = match self.format {
+            DossierFormat::Markdown => {
+                writeln!(self.out, "{} {}\n", "#".repeat(level as usize), text)
+            }
+            DossierFormat::Html => writeln!(self.out, "<h{0}>{1}</h{0}>", level, escape_html(text)),
+        };
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        let _ = match self.format {
+            DossierFormat::Markdown => writeln!(self.out, "{}\n", text),
+            DossierFormat::Html => writeln!(self.out, "<p>{}</p>", escape_html(text)),
+        };
+    }
+
+    fn list(&mut self, items: &[String]) {
+        match self.format {
+            DossierFormat::Markdown => {
+                for item in items {
+                    let _ = writeln!(self.out, "- {}", item);
+                }
+                self.out.push('\n');
+            }
+            DossierFormat::Html => {
+                self.out.push_str("<ul>\n");
+                for item in items {
+                    let _ = writeln!(self.out, "<li>{}</li>", escape_html(item));
+                }
+                self.out.push_str("</ul>\n");
+            }
+        }
+    }
+
+    fn code_list(&mut self, items: &[String]) {
+        match self.format {
+            DossierFormat::Markdown => {
+                for item in items {
+                    let _ = writeln!(self.out, "- `{}`", item);
+                }
+                self.out.push('\n');
+            }
+            DossierFormat::Html => {
+                self.out.push_str("<ul>\n");
+                for item in items {
+                    let _ = writeln!(self.out, "<li><code>{}</code></li>", escape_html(item));
+                }
+                self.out.push_str("</ul>\n");
+            }
+        }
+    }
+
+    fn code_block(&mut self, code: &str) {
+        let _ = match self.format {
+            DossierFormat::Markdown => writeln!(self.out, "```text\n{}\n```\n", code),
+            DossierFormat::Html => writeln!(self.out, "<pre><code>{}</code></pre>", escape_html(code)),
+        };
+    }
+
+    fn table(&mut self, header: &[&str], rows: &[Vec<String>]) {
+        match self.format {
+            DossierFormat::Markdown => {
+                let _ = writeln!(self.out, "| {} |", header.join(" | "));
+                let _ = writeln!(self.out, "|{}", "---|".repeat(header.len()));
+                for row in rows {
+                    let cells: Vec<String> = row.iter().map(|c| escape_cell(c)).collect();
+                    let _ = writeln!(self.out, "| {} |", cells.join(" | "));
+                }
+                self.out.push('\n');
+            }
+            DossierFormat::Html => {
+                self.out.push_str("<table>\n<tr>");
+                for h in header {
+                    let _ = write!(self.out, "<th>{}</th>", escape_html(h));
+                }
+                self.out.push_str("</tr>\n");
+                for row in rows {
+                    self.out.push_str("<tr>");
+                    for c in row {
+                        let _ = write!(self.out, "<td>{}</td>", escape_html(c));
+                    }
+                    self.out.push_str("</tr>\n");
+                }
+                self.out.push_str("</table>\n");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::engine::DiscoveryEngine;
+
+    fn run() -> Vec<Discovery> {
+        DiscoveryEngine::with_target(42, 3).run().unwrap()
+    }
+
+    #[test]
+    fn test_markdown_run_dossier() {
+        let discoveries = run();
+        let md = render_run("Run 42", &discoveries, DossierFormat::Markdown);
+
+        assert!(md.starts_with("# Run 42\n"));
+        assert!(md.contains("| Provenance chain | ✓ verified |"));
+        assert!(md.contains(&format!("| QRD-002 | {} | {} | ✓ verified |",
+            discoveries[1].provenance.qradle_hash, discoveries[0].provenance.qradle_hash)));
+        assert!(md.contains("## QRD-003 — "));
+        assert!(md.contains("| I_n | "));
+        assert!(!md.contains("✗"));
+    }
+
+    #[test]
+    fn test_html_dossier_is_self_contained_and_escaped() {
+        let mut discovery = run().remove(0);
+        discovery.hypothesis = String::from("<script>alert(1)</script>");
+        let html = render_discovery(&discovery, DossierFormat::Html);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("http"));
+        // Hash still matches: the hypothesis is not part of the provenance hash
+        assert!(html.contains("✓ verified"));
+
+        discovery.title.push_str(" (edited)");
+        let html = render_discovery(&discovery, DossierFormat::Html);
+        assert!(html.contains("✗ FAILED"));
+    }
+}
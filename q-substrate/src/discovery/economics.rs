@@ -0,0 +1,343 @@
+//! Economic Projection - Market-Driven Strategic Leverage
+//!
+//! Projects each candidate's unit economics from market data instead of
+//! the fixed `MarketContext` adjustments. A candidate's `EconomicsNode`
+//! selects a profile (price premium, volume multiplier) applied to two
+//! configurable curves:
+//!
+//! ```text
+//! price     p = p_ref · (1 + premium)
+//! volume    Q = Q_ref · multiplier · (p / p_ref)^(-ε)      constant-elasticity demand
+//! cost      c = c₀ · (Q / Q₀)^log₂(r)                      Wright's law, progress ratio r
+//! margin    m = (p - c) / p
+//! growth    g = Q / Q_ref - 1
+//! advantage a = 1 - c / c₀
+//! leverage  L = 0.25·m + 0.40·g + 0.35·a                   each term clamped to [0, 1]
+//! ```
+//!
+//! Margin, demand growth and learning-curve cost advantage stand in for the
+//! static competition, growth-rate and entry-barrier terms respectively.
+//! `compute_projected_leverage` scales `L` onto the span those terms covered.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use super::lattice::{EconomicsNode, MutatedNode};
+use super::types::DiscoveryError;
+#[cfg(not(feature = "std"))]
+use crate::float::FloatExt;
+
+/// Weight of gross margin in the leverage index
+const MARGIN_WEIGHT: f64 = 0.25;
+
+/// Weight of demand growth in the leverage index
+const GROWTH_WEIGHT: f64 = 0.40;
+
+/// Weight of learning-curve cost advantage in the leverage index
+const ADVANTAGE_WEIGHT: f64 = 0.35;
+
+/// Wright's law learning curve
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostCurve {
+    /// Unit cost at the reference volume
+    pub initial_unit_cost: f64,
+    /// Cumulative volume at which `initial_unit_cost` applies
+    pub reference_volume: f64,
+    /// Cost multiplier per doubling of volume (0.8 = 20% cheaper)
+    pub progress_ratio: f64,
+}
+
+impl CostCurve {
+    /// Unit cost at `volume`
+    pub fn unit_cost(&self, volume: f64) -> f64 {
+        if volume <= 0.0 || self.reference_volume <= 0.0 || self.progress_ratio <= 0.0 {
+            return self.initial_unit_cost;
+        }
+        let exponent = self.progress_ratio.ln() / core::f64::consts::LN_2;
+        self.initial_unit_cost * (volume / self.reference_volume).powf(exponent)
+    }
+}
+
+/// Constant-elasticity demand curve
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DemandCurve {
+    /// Market price of incumbent offerings
+    pub reference_price: f64,
+    /// Annual volume demanded at the reference price
+    pub reference_volume: f64,
+    /// Price elasticity of demand (positive; 1.2 = 1% dearer sells 1.2% less)
+    pub elasticity: f64,
+}
+
+impl DemandCurve {
+    /// Volume demanded at `price`
+    pub fn volume(&self, price: f64) -> f64 {
+        if price <= 0.0 || self.reference_price <= 0.0 {
+            return self.reference_volume;
+        }
+        self.reference_volume * (price / self.reference_price).powf(-self.elasticity)
+    }
+}
+
+/// Economic lever of one `EconomicsNode`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeProfile {
+    /// Node the profile applies to
+    pub node: EconomicsNode,
+    /// Price relative to the reference price (0.2 = 20% premium)
+    pub price_premium: f64,
+    /// Demand shift at a given price (network effects, penetration)
+    pub volume_multiplier: f64,
+}
+
+/// Market data driving projections, loadable from JSON config
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketData {
+    /// Production cost curve
+    pub cost_curve: CostCurve,
+    /// Demand curve of the target market
+    pub demand: DemandCurve,
+    /// Per node levers; nodes without a profile are priced at the reference
+    pub profiles: Vec<NodeProfile>,
+}
+
+fn profile(node: EconomicsNode, price_premium: f64, volume_multiplier: f64) -> NodeProfile {
+    NodeProfile { node, price_premium, volume_multiplier }
+}
+
+impl Default for MarketData {
+    fn default() -> Self {
+        Self {
+            cost_curve: CostCurve {
+                initial_unit_cost: 100.0,
+                reference_volume: 100_000.0,
+                progress_ratio: 0.8,
+            },
+            demand: DemandCurve {
+                reference_price: 150.0,
+                reference_volume: 100_000.0,
+                elasticity: 1.2,
+            },
+            profiles: alloc::vec![
+                profile(EconomicsNode::CapitalEfficiencyGradient, 0.0, 1.2),
+                profile(EconomicsNode::LearningCurveArbitrage, -0.10, 1.5),
+                profile(EconomicsNode::MoatTopology, 0.25, 1.0),
+                profile(EconomicsNode::NetworkEffect, 0.0, 2.0),
+                profile(EconomicsNode::ScalabilityVector, -0.05, 1.6),
+                profile(EconomicsNode::MarketPenetration, -0.15, 1.8),
+                profile(EconomicsNode::ValueCapture, 0.30, 0.9),
+                profile(EconomicsNode::CompetitiveAdvantage, 0.15, 1.2),
+            ],
+        }
+    }
+}
+
+impl MarketData {
+    /// Parse market data from JSON config
+    pub fn from_json(json: &str) -> Result<Self, DiscoveryError> {
+        serde_json::from_str(json)
+            .map_err(|e| DiscoveryError::SerializationError(format!("{}", e)))
+    }
+
+    /// Deterministic hash of the market data
+    pub fn hash(&self) -> String {
+        let mut hash = 0u64;
+        let mut mix = |value: f64| {
+            for byte in value.to_bits().to_le_bytes() {
+                hash = hash.wrapping_mul(31).wrapping_add(byte as u64);
+            }
+        };
+        mix(self.cost_curve.initial_unit_cost);
+        mix(self.cost_curve.reference_volume);
+        mix(self.cost_curve.progress_ratio);
+        mix(self.demand.reference_price);
+        mix(self.demand.reference_volume);
+        mix(self.demand.elasticity);
+        for profile in &self.profiles {
+            mix(profile.node as u8 as f64);
+            mix(profile.price_premium);
+            mix(profile.volume_multiplier);
+        }
+        let hash = hash.wrapping_mul(0x517cc1b727220a95).wrapping_add(0x63f5d5a6a9e1a3c7);
+        format!("QRDE-{:016x}", hash)
+    }
+}
+
+/// Projected unit economics of one candidate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EconomicProjection {
+    /// Economics node the projection was driven by, if any
+    pub economics: Option<EconomicsNode>,
+    /// Unit price
+    pub price: f64,
+    /// Annual volume
+    pub volume: f64,
+    /// Unit cost at that volume
+    pub unit_cost: f64,
+    /// Gross margin `(p - c) / p`
+    pub gross_margin: f64,
+    /// Volume relative to the incumbent market, minus one
+    pub demand_growth: f64,
+    /// Cost reduction down the learning curve
+    pub cost_advantage: f64,
+    /// Leverage index (0.0 to 1.0)
+    pub leverage: f64,
+}
+
+impl EconomicProjection {
+    /// Annual revenue
+    pub fn revenue(&self) -> f64 {
+        self.price * self.volume
+    }
+
+    /// Summary used as a discovery's estimated value
+    pub fn summary(&self) -> String {
+        format!(
+            "${:.1}M annual revenue at {:.0} units, {:.0}% gross margin",
+            self.revenue() / 1_000_000.0,
+            self.volume,
+            self.gross_margin * 100.0
+        )
+    }
+}
+
+/// Plug-in producing economic projections for candidates
+///
+/// Implementations must be deterministic: the same candidate always
+/// projects to the same economics.
+pub trait EconomicModel {
+    /// Project the candidate's unit economics
+    fn project(&self, node: &MutatedNode) -> EconomicProjection;
+}
+
+/// Economic model over configured market data
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketModel {
+    /// Market data
+    pub data: MarketData,
+}
+
+impl MarketModel {
+    /// Model over the given market data
+    pub fn new(data: MarketData) -> Self {
+        Self { data }
+    }
+
+    /// Model over market data parsed from JSON config
+    pub fn from_json(json: &str) -> Result<Self, DiscoveryError> {
+        MarketData::from_json(json).map(Self::new)
+    }
+}
+
+impl EconomicModel for MarketModel {
+    fn project(&self, node: &MutatedNode) -> EconomicProjection {
+        let economics = node.original.node.economics;
+        let (premium, multiplier) = economics
+            .and_then(|e| self.data.profiles.iter().find(|p| p.node == e))
+            .map(|p| (p.price_premium, p.volume_multiplier))
+            .unwrap_or((0.0, 1.0));
+
+        let demand = &self.data.demand;
+        let price = demand.reference_price * (1.0 + premium);
+        let volume = demand.volume(price) * multiplier;
+        let unit_cost = self.data.cost_curve.unit_cost(volume);
+        let gross_margin = if price > 0.0 { (price - unit_cost) / price } else { 0.0 };
+        let demand_growth = if demand.reference_volume > 0.0 {
+            volume / demand.reference_volume - 1.0
+        } else {
+            0.0
+        };
+        let cost_advantage = if self.data.cost_curve.initial_unit_cost > 0.0 {
+            1.0 - unit_cost / self.data.cost_curve.initial_unit_cost
+        } else {
+            0.0
+        };
+        let leverage = MARGIN_WEIGHT * gross_margin.clamp(0.0, 1.0)
+            + GROWTH_WEIGHT * demand_growth.clamp(0.0, 1.0)
+            + ADVANTAGE_WEIGHT * cost_advantage.clamp(0.0, 1.0);
+
+        EconomicProjection {
+            economics,
+            price,
+            volume,
+            unit_cost,
+            gross_margin,
+            demand_growth,
+            cost_advantage,
+            leverage,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::lattice::{CandidateNode, SymbolicRepresentation, SystemsNode};
+
+    fn candidate(economics: Option<EconomicsNode>) -> MutatedNode {
+        MutatedNode {
+            original: SymbolicRepresentation {
+                node: CandidateNode {
+                    physics: None,
+                    computation: None,
+                    materials: None,
+                    systems: Some(SystemsNode::PodIsolation),
+                    economics,
+                    interaction_id: "test".into(),
+                },
+                symbolic_form: "test".into(),
+                dimensionality: 2,
+            },
+            mutation_type: "constraint_relaxation".into(),
+            mutated_form: "A proven modular architecture".into(),
+            novelty_score: 0.9,
+        }
+    }
+
+    #[test]
+    fn test_market_model_projections() {
+        let model = MarketModel::default();
+
+        // No economics node: reference price and volume, no learning
+        let neutral = model.project(&candidate(None));
+        assert_eq!(neutral.volume, 100_000.0);
+        assert_eq!(neutral.unit_cost, 100.0);
+        assert!((neutral.gross_margin - 1.0 / 3.0).abs() < 1e-12);
+        assert!((neutral.leverage - 0.25 / 3.0).abs() < 1e-12);
+
+        // Doubling volume moves one step down the 80% learning curve
+        let network = model.project(&candidate(Some(EconomicsNode::NetworkEffect)));
+        assert!((network.volume - 200_000.0).abs() < 1e-6);
+        assert!((network.unit_cost - 80.0).abs() < 1e-9);
+        assert!((network.cost_advantage - 0.2).abs() < 1e-9);
+        assert!(network.leverage > neutral.leverage);
+        assert_eq!(network, model.project(&candidate(Some(EconomicsNode::NetworkEffect))));
+
+        // A premium sells less: higher margin, but no growth or learning
+        let moat = model.project(&candidate(Some(EconomicsNode::MoatTopology)));
+        assert!(moat.volume < neutral.volume);
+        assert!(moat.gross_margin > neutral.gross_margin);
+        assert_eq!(moat.demand_growth.max(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_market_data_from_json() {
+        let data = MarketData::default();
+        let json = serde_json::to_string(&data).unwrap();
+        let model = MarketModel::from_json(&json).unwrap();
+        assert_eq!(model.data, data);
+        assert_eq!(model.data.hash(), data.hash());
+
+        let mut cheaper = data.clone();
+        cheaper.cost_curve.initial_unit_cost = 50.0;
+        assert_ne!(cheaper.hash(), data.hash());
+        assert!(matches!(
+            MarketModel::from_json("{\"cost_curve\": 1}"),
+            Err(DiscoveryError::SerializationError(_))
+        ));
+    }
+}
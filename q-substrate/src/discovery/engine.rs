@@ -5,16 +5,27 @@
 
 extern crate alloc;
 
+use alloc::boxed::Box;
 use alloc::format;
+use alloc::string::String;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
-use super::fitness::{compute_fitness, FitnessWeights, KnownArchitecture, MarketContext};
+use super::constraint::ConstraintSet;
+use super::economics::EconomicModel;
+use super::fitness::{
+    compute_fitness, compute_projected_fitness, FitnessWeights, KnownArchitecture, MarketContext,
+};
 use super::lattice::{DiscoveryLattice, MutatedNode, SymbolicRepresentation};
+use super::provenance::generate_provenance_hash;
+use super::review::{review_candidate, CandidateReviewer, ReviewedCorpus};
+use super::risk::{GovernanceApproval, RiskGate};
 use super::types::{
-    Discovery, DiscoveryError, Formulation, IndustrialImpact, Provenance, RiskEnvelope,
-    ValidationMethod, ValidationPath,
+    Discovery, DiscoveryError, Formulation, IndustrialImpact, Provenance, ReviewRecord,
+    ReviewVerdict, RiskEnvelope, ValidationMethod, ValidationPath,
 };
+#[cfg(not(feature = "std"))]
+use crate::float::FloatExt;
 
 /// Recursive Discovery Engine
 pub struct DiscoveryEngine {
@@ -26,7 +37,11 @@ pub struct DiscoveryEngine {
     fitness_threshold: f64,
     known_architectures: Vec<KnownArchitecture>,
     market_context: MarketContext,
+    economic_model: Option<Box<dyn EconomicModel>>,
     mutation_counter: u32,
+    constraints_hash: Option<String>,
+    risk_gate: Option<RiskGate>,
+    held: Vec<Discovery>,
 }
 
 impl DiscoveryEngine {
@@ -41,7 +56,11 @@ impl DiscoveryEngine {
             fitness_threshold: 0.87,
             known_architectures: Vec::new(),
             market_context: MarketContext::default(),
+            economic_model: None,
             mutation_counter: 0,
+            constraints_hash: None,
+            risk_gate: None,
+            held: Vec::new(),
         }
     }
 
@@ -62,6 +81,65 @@ impl DiscoveryEngine {
         self.market_context = context;
     }
 
+    /// Project each candidate's economics with `model`
+    ///
+    /// Strategic leverage is then computed from the projection rather than
+    /// the market context, and the projection becomes the discovery's
+    /// estimated value.
+    pub fn set_economic_model(&mut self, model: Box<dyn EconomicModel>) {
+        self.economic_model = Some(model);
+    }
+
+    /// Bound the search space
+    ///
+    /// Rebuilds the lattice with `constraints` applied; every discovery from
+    /// this engine records the constraint hash in its provenance.
+    pub fn set_constraints(&mut self, constraints: ConstraintSet) {
+        self.constraints_hash = constraints.hash();
+        self.lattice = DiscoveryLattice::with_constraints(self.seed, constraints);
+    }
+
+    /// Score every surviving candidate and hold back those above the ceiling
+    ///
+    /// Held candidates are not archived or numbered until `approve_held`
+    /// attaches a governance approval.
+    pub fn set_risk_gate(&mut self, gate: RiskGate) {
+        self.risk_gate = Some(gate);
+    }
+
+    /// Candidates awaiting governance approval
+    pub fn held_for_approval(&self) -> &[Discovery] {
+        &self.held
+    }
+
+    /// Archive a held candidate under a governance approval
+    ///
+    /// The candidate is renumbered after the current archive and its
+    /// provenance hash recomputed with the approval folded in.
+    pub fn approve_held(
+        &mut self,
+        qradle_hash: &str,
+        approval: &GovernanceApproval,
+    ) -> Result<&Discovery, DiscoveryError> {
+        let position = self
+            .held
+            .iter()
+            .position(|d| d.provenance.qradle_hash == qradle_hash)
+            .ok_or_else(|| DiscoveryError::ValidationError(format!("No held discovery {}", qradle_hash)))?;
+        let mut discovery = self.held.remove(position);
+
+        let id = format!("QRD-{:03}", self.discoveries.len() + 1);
+        discovery.title = discovery.title.replacen(&discovery.id, &id, 1);
+        discovery.id = id;
+        if let Some(risk) = discovery.provenance.risk.as_mut() {
+            risk.approval = Some(approval.record());
+        }
+        discovery.provenance.qradle_hash = generate_provenance_hash(&discovery);
+
+        self.discoveries.push(discovery);
+        Ok(&self.discoveries[self.discoveries.len() - 1])
+    }
+
     /// Run constraint-breaking mutation operators
     ///
     /// Generates mutations with keywords that boost fitness scores across all dimensions:
@@ -157,6 +235,14 @@ impl DiscoveryEngine {
 
     /// Evaluate fitness function
     pub fn evaluate_fitness(&self, node: &MutatedNode) -> f64 {
+        if let Some(model) = &self.economic_model {
+            return compute_projected_fitness(
+                node,
+                &self.weights,
+                &self.known_architectures,
+                &model.project(node),
+            );
+        }
         compute_fitness(
             node,
             &self.weights,
@@ -167,8 +253,6 @@ impl DiscoveryEngine {
 
     /// Synthesize discovery from surviving node
     pub fn synthesize_discovery(&self, node: &MutatedNode, discovery_id: usize, fitness: f64) -> Discovery {
-        use super::provenance::generate_provenance_hash;
-        
         let id = format!("QRD-{:03}", discovery_id + 1);
         
         // Generate timestamp (simplified for deterministic execution)
@@ -221,7 +305,10 @@ impl DiscoveryEngine {
             industrial_impact: IndustrialImpact {
                 application: format!("Application of {} in production systems", node.mutation_type),
                 market_sector: "Quantum Computing, AI Systems, Advanced Materials".into(),
-                estimated_value: Some("$10M-100M annual impact potential".into()),
+                estimated_value: Some(match &self.economic_model {
+                    Some(model) => model.project(node).summary(),
+                    None => "$10M-100M annual impact potential".into(),
+                }),
             },
             risk_envelope: RiskEnvelope {
                 failure_modes: alloc::vec![
@@ -246,6 +333,9 @@ impl DiscoveryEngine {
                 qradle_hash: String::new(), // Placeholder, will be computed below
                 seed: self.seed,
                 lattice_node: node.original.node.generate_id(),
+                constraints: self.constraints_hash.clone(),
+                review: None,
+                risk: None,
             },
         };
         
@@ -266,10 +356,55 @@ impl DiscoveryEngine {
 
     /// Run recursive discovery until target met
     pub fn run(&mut self) -> Result<Vec<Discovery>, DiscoveryError> {
+        self.search(None);
+        
+        // Check if we met target
+        let valid_count = self.get_valid_count();
+        
+        if valid_count < self.target_count {
+            return Err(DiscoveryError::Generic(format!(
+                "Only generated {} valid discoveries, target was {}",
+                valid_count, self.target_count
+            )));
+        }
+        
+        Ok(self.discoveries.clone())
+    }
+
+    /// Run discovery with every surviving candidate passed to a reviewer
+    ///
+    /// Accepted candidates are numbered consecutively and carry their review
+    /// in provenance; rejected ones are kept, stamped with their review, in
+    /// `ReviewedCorpus::rejected`. Unlike `run`, falling short of the target
+    /// is not an error, so reviewer work is never discarded.
+    pub fn run_reviewed(&mut self, reviewer: &mut dyn CandidateReviewer) -> ReviewedCorpus {
+        let rejected = self.search(Some(reviewer));
+        let mut decisions: Vec<ReviewRecord> = self
+            .discoveries
+            .iter()
+            .chain(rejected.iter())
+            .filter_map(|d| d.provenance.review.clone())
+            .collect();
+        decisions.sort_by_key(|r| r.sequence);
+
+        ReviewedCorpus {
+            target_met: self.get_valid_count() >= self.target_count,
+            accepted: self.discoveries.clone(),
+            rejected,
+            decisions,
+        }
+    }
+
+    /// Search the lattice, optionally gating each candidate on review
+    ///
+    /// Returns the rejected candidates.
+    fn search(&mut self, mut reviewer: Option<&mut dyn CandidateReviewer>) -> Vec<Discovery> {
         // Enumerate all candidate nodes
         let candidates = self.lattice.enumerate_candidates();
         
         let mut discovery_count = 0;
+        let mut rejected = Vec::new();
+        let mut sequence = 0u64;
         
         for candidate in candidates {
             if self.should_terminate() {
@@ -295,7 +430,29 @@ impl DiscoveryEngine {
                 
                 // Only synthesize if fitness meets threshold
                 if fitness_rounded >= self.fitness_threshold {
-                    let discovery = self.synthesize_discovery(&mutation, discovery_count, fitness_rounded);
+                    let mut discovery = self.synthesize_discovery(&mutation, discovery_count, fitness_rounded);
+                    
+                    if let Some(reviewer) = reviewer.as_deref_mut() {
+                        let record = review_candidate(reviewer, &discovery, sequence);
+                        sequence += 1;
+                        let verdict = record.verdict;
+                        discovery.provenance.review = Some(record);
+                        discovery.provenance.qradle_hash = generate_provenance_hash(&discovery);
+                        if verdict == ReviewVerdict::Reject {
+                            rejected.push(discovery);
+                            continue;
+                        }
+                    }
+                    
+                    if let Some(gate) = &self.risk_gate {
+                        let assessment = gate.table.assess(&discovery);
+                        discovery.provenance.risk = Some(assessment.record());
+                        discovery.provenance.qradle_hash = generate_provenance_hash(&discovery);
+                        if gate.requires_approval(&assessment) {
+                            self.held.push(discovery);
+                            continue;
+                        }
+                    }
                     
                     self.discoveries.push(discovery);
                     discovery_count += 1;
@@ -303,21 +460,7 @@ impl DiscoveryEngine {
             }
         }
         
-        // Check if we met target
-        let valid_count = self
-            .discoveries
-            .iter()
-            .filter(|d| d.fitness_score >= self.fitness_threshold)
-            .count();
-        
-        if valid_count < self.target_count {
-            return Err(DiscoveryError::Generic(format!(
-                "Only generated {} valid discoveries, target was {}",
-                valid_count, self.target_count
-            )));
-        }
-        
-        Ok(self.discoveries.clone())
+        rejected
     }
 
     /// Get current discoveries
@@ -436,6 +579,9 @@ mod tests {
                     qradle_hash: "test".into(),
                     seed: 42,
                     lattice_node: "test".into(),
+                    constraints: None,
+                    review: None,
+                    risk: None,
                 },
             };
             
@@ -445,6 +591,80 @@ mod tests {
         assert!(engine.should_terminate());
     }
 
+    #[test]
+    fn test_constrained_run_records_bounds() {
+        let constraints = ConstraintSet::parse("only physics, materials").unwrap();
+        let hash = constraints.hash();
+        let mut engine = DiscoveryEngine::with_target(42, 5);
+        engine.set_constraints(constraints);
+        let discoveries = engine.run().unwrap();
+
+        assert!(discoveries.iter().all(|d| d.provenance.constraints == hash));
+        assert!(discoveries.iter().all(|d| d.provenance.lattice_node.starts_with('P')
+            && d.provenance.lattice_node.contains("-M")));
+        assert!(crate::discovery::verify_provenance_chain(&discoveries).is_ok());
+
+        // Dropping the bounds from provenance is detected
+        let mut stripped = discoveries.clone();
+        stripped[0].provenance.constraints = None;
+        assert!(crate::discovery::verify_provenance_chain(&stripped).is_err());
+    }
+
+    #[test]
+    fn test_risk_gate_holds_for_approval() {
+        use crate::discovery::risk::{ScoringRule, ScoringTable};
+
+        let mut table = ScoringTable::default();
+        table.rules.insert(0, ScoringRule {
+            label: "fusion".into(),
+            keywords: alloc::vec!["dimensional_fusion".into()],
+            likelihood: 0.9,
+            severity: 0.9,
+        });
+        let mut engine = DiscoveryEngine::with_target(42, 4);
+        engine.set_risk_gate(RiskGate::with_table(table, 0.5));
+        let discoveries = engine.run().unwrap();
+
+        assert!(discoveries.iter().all(|d| d.provenance.risk.as_ref().unwrap().index <= 0.5));
+        assert!(crate::discovery::verify_provenance_chain(&discoveries).is_ok());
+        let held = engine.held_for_approval().to_vec();
+        assert!(!held.is_empty());
+        assert_eq!(held[0].provenance.risk.as_ref().unwrap().rules[0], "fusion");
+
+        let approval = GovernanceApproval::new("risk-board", "RB-2025-014");
+        let approved = engine.approve_held(&held[0].provenance.qradle_hash, &approval).unwrap();
+        assert_eq!(approved.id, "QRD-005");
+        assert!(approved.title.starts_with("Discovery QRD-005"));
+        assert_eq!(approved.provenance.risk.as_ref().unwrap().approval.as_deref(), Some("risk-board:RB-2025-014"));
+        assert!(engine.approve_held(&held[0].provenance.qradle_hash, &approval).is_err());
+
+        // The approval is part of the provenance hash
+        let mut archive = engine.get_discoveries().to_vec();
+        assert!(crate::discovery::verify_provenance_chain(&archive).is_ok());
+        archive[4].provenance.risk.as_mut().unwrap().approval = None;
+        assert!(crate::discovery::verify_provenance_chain(&archive).is_err());
+    }
+
+    #[test]
+    fn test_economic_model_drives_leverage() {
+        use crate::discovery::economics::MarketModel;
+
+        let mut engine = DiscoveryEngine::with_target(42, 5);
+        let symbolic = engine.lattice.collapse_node(&engine.lattice.enumerate_candidates()[0]);
+        let mutation = engine.mutate_node(&symbolic).remove(0);
+        let static_fitness = engine.evaluate_fitness(&mutation);
+
+        engine.set_economic_model(Box::new(MarketModel::default()));
+        assert_ne!(engine.evaluate_fitness(&mutation), static_fitness);
+        assert_eq!(engine.evaluate_fitness(&mutation), engine.evaluate_fitness(&mutation));
+
+        let discoveries = engine.run().unwrap();
+        assert_eq!(discoveries.len(), 5);
+        let value = discoveries[0].industrial_impact.estimated_value.as_deref().unwrap();
+        assert_eq!(value, "$15.0M annual revenue at 100000 units, 33% gross margin");
+        assert!(crate::discovery::verify_provenance_chain(&discoveries).is_ok());
+    }
+
     #[test]
     fn test_fitness_scores_meet_threshold() {
         let mut engine = DiscoveryEngine::new(42);
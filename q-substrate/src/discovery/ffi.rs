@@ -0,0 +1,154 @@
+//! Discovery C ABI
+//!
+//! Exposes `run_discovery_with_review` to other languages (C, Python via
+//! ctypes/cffi, Go via cgo). Build a shared library with:
+//!
+//! ```text
+//! cargo rustc --lib --release --features ffi --crate-type cdylib
+//! ```
+//!
+//! The reviewer is a C callback receiving each candidate as a JSON string.
+//! It returns 1 to accept or 0 to reject, and may write a NUL-terminated
+//! annotation into the supplied buffer (truncated to `annotation_cap - 1`
+//! bytes). The corpus comes back as a JSON string owned by the library and
+//! must be released with `qd_string_free`.
+//!
+//! ```c
+//! typedef int32_t (*qd_review_cb)(void *ctx, const char *candidate_json,
+//!                                 char *annotation, size_t annotation_cap);
+//! char *qd_run_reviewed(uint32_t seed, size_t target, const char *reviewer_id,
+//!                       qd_review_cb review, void *ctx);
+//! void qd_string_free(char *s);
+//! ```
+
+extern crate std;
+
+use alloc::string::String;
+use alloc::vec;
+use core::ffi::{c_char, c_void};
+use std::ffi::{CStr, CString};
+
+use super::review::{run_discovery_with_review, CandidateReviewer, ReviewDecision};
+use super::types::Discovery;
+
+/// Maximum annotation length accepted from a callback
+const ANNOTATION_CAP: usize = 1024;
+
+/// Reviewer callback signature
+pub type ReviewCallback =
+    extern "C" fn(ctx: *mut c_void, candidate_json: *const c_char, annotation: *mut c_char, annotation_cap: usize) -> i32;
+
+/// Adapts a C callback to `CandidateReviewer`
+struct CallbackReviewer {
+    id: String,
+    callback: ReviewCallback,
+    ctx: *mut c_void,
+}
+
+impl CandidateReviewer for CallbackReviewer {
+    fn reviewer_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn review(&mut self, candidate: &Discovery) -> ReviewDecision {
+        // A candidate that cannot cross the boundary is never silently accepted
+        let Some(json) = serde_json::to_string(candidate).ok().and_then(|j| CString::new(j).ok()) else {
+            return ReviewDecision::reject().with_annotation("candidate not representable as C string");
+        };
+
+        let mut buf = vec![0u8; ANNOTATION_CAP];
+        let verdict = (self.callback)(self.ctx, json.as_ptr(), buf.as_mut_ptr() as *mut c_char, buf.len());
+        // Force termination in case the callback filled the buffer
+        buf[ANNOTATION_CAP - 1] = 0;
+
+        let decision = if verdict == 1 { ReviewDecision::accept() } else { ReviewDecision::reject() };
+        match CStr::from_bytes_until_nul(&buf).map(|s| s.to_string_lossy().into_owned()) {
+            Ok(note) if !note.is_empty() => decision.with_annotation(note),
+            _ => decision,
+        }
+    }
+}
+
+/// Run a reviewed discovery directive
+///
+/// Returns the `ReviewedCorpus` as JSON, or NULL on error or invalid
+/// arguments.
+///
+/// # Safety
+///
+/// `reviewer_id` must be a valid NUL-terminated string. `review` must be
+/// safe to call with `ctx` for the duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn qd_run_reviewed(
+    seed: u32,
+    target: usize,
+    reviewer_id: *const c_char,
+    review: ReviewCallback,
+    ctx: *mut c_void,
+) -> *mut c_char {
+    if reviewer_id.is_null() {
+        return core::ptr::null_mut();
+    }
+    let mut reviewer = CallbackReviewer {
+        id: CStr::from_ptr(reviewer_id).to_string_lossy().into_owned(),
+        callback: review,
+        ctx,
+    };
+
+    run_discovery_with_review(seed, target, &mut reviewer)
+        .ok()
+        .and_then(|corpus| serde_json::to_string(&corpus).ok())
+        .and_then(|json| CString::new(json).ok())
+        .map_or(core::ptr::null_mut(), CString::into_raw)
+}
+
+/// Free a string returned by this library
+///
+/// # Safety
+///
+/// `s` must come from `qd_run_reviewed` and not have been freed already.
+#[no_mangle]
+pub unsafe extern "C" fn qd_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::review::ReviewedCorpus;
+
+    extern "C" fn accept_even(ctx: *mut c_void, candidate: *const c_char, note: *mut c_char, cap: usize) -> i32 {
+        let calls = unsafe { &mut *(ctx as *mut u32) };
+        *calls += 1;
+        let json = unsafe { CStr::from_ptr(candidate) }.to_str().unwrap();
+        assert!(json.contains("\"id\":\"QRD-"));
+
+        let msg = b"checked by ffi\0";
+        assert!(msg.len() <= cap);
+        unsafe { core::ptr::copy_nonoverlapping(msg.as_ptr() as *const c_char, note, msg.len()) };
+        (*calls % 2 == 1) as i32
+    }
+
+    #[test]
+    fn test_c_callback_round_trip() {
+        let mut calls = 0u32;
+        let id = CString::new("c-reviewer").unwrap();
+        let raw = unsafe {
+            qd_run_reviewed(42, 2, id.as_ptr(), accept_even, &mut calls as *mut u32 as *mut c_void)
+        };
+        assert!(!raw.is_null());
+
+        let json = unsafe { CStr::from_ptr(raw) }.to_str().unwrap().to_owned();
+        unsafe { qd_string_free(raw) };
+        let corpus: ReviewedCorpus = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(calls, 3);
+        assert_eq!(corpus.accepted.len(), 2);
+        assert_eq!(corpus.rejected.len(), 1);
+        let review = corpus.rejected[0].provenance.review.as_ref().unwrap();
+        assert_eq!(review.reviewer, "c-reviewer");
+        assert_eq!(review.annotation.as_deref(), Some("checked by ffi"));
+    }
+}
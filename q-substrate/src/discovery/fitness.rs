@@ -11,6 +11,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
+use super::economics::EconomicProjection;
 use super::lattice::MutatedNode;
 
 /// Mutation bonus for breakthrough discoveries
@@ -34,6 +35,10 @@ const BASE_SCALABILITY_SCORE: f64 = 0.74;
 /// Base strategic leverage score for well-positioned discoveries
 const BASE_LEVERAGE_SCORE: f64 = 0.65;
 
+/// Leverage span of market terms: the static adjustments peak at
+/// 0.06 + 0.10 + 0.08, and a projected leverage index of 1.0 matches it
+const MARKET_LEVERAGE_SPAN: f64 = 0.24;
+
 /// Known architecture for novelty comparison
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnownArchitecture {
@@ -259,10 +264,8 @@ pub fn compute_scalability(node: &MutatedNode) -> f64 {
     scalability_score.max(0.0).min(1.0)
 }
 
-/// Compute strategic leverage indicator - competitive advantage measure
-///
-/// Returns value in [0.0, 1.0] where higher means better strategic position
-pub fn compute_strategic_leverage(node: &MutatedNode, market_context: &MarketContext) -> f64 {
+/// Leverage from strategic advantage keywords in the mutation
+fn keyword_leverage(node: &MutatedNode) -> f64 {
     let mutation_lower = node.mutated_form.to_lowercase();
     
     let mut leverage_score: f64 = BASE_LEVERAGE_SCORE;
@@ -290,6 +293,15 @@ pub fn compute_strategic_leverage(node: &MutatedNode, market_context: &MarketCon
         leverage_score += 0.05;
     }
     
+    leverage_score
+}
+
+/// Compute strategic leverage indicator - competitive advantage measure
+///
+/// Returns value in [0.0, 1.0] where higher means better strategic position
+pub fn compute_strategic_leverage(node: &MutatedNode, market_context: &MarketContext) -> f64 {
+    let mut leverage_score = keyword_leverage(node);
+    
     // Market context adjustments
     leverage_score += (1.0 - market_context.competition_level) * 0.06;
     leverage_score += market_context.growth_rate * 0.10;
@@ -298,6 +310,17 @@ pub fn compute_strategic_leverage(node: &MutatedNode, market_context: &MarketCon
     leverage_score.max(0.0).min(1.0)
 }
 
+/// Compute strategic leverage from a per-candidate economic projection
+///
+/// I_l = keyword leverage + 0.24 · L, where L is the projection's leverage
+/// index; replaces the static `MarketContext` adjustments.
+///
+/// Returns value in [0.0, 1.0] where higher means better strategic position
+pub fn compute_projected_leverage(node: &MutatedNode, projection: &EconomicProjection) -> f64 {
+    let leverage_score = keyword_leverage(node) + MARKET_LEVERAGE_SPAN * projection.leverage;
+    leverage_score.clamp(0.0, 1.0)
+}
+
 /// Combined fitness function
 ///
 /// F = αI_novelty + βI_feasibility + γI_scalability + δI_strategic_leverage
@@ -306,11 +329,31 @@ pub fn compute_fitness(
     weights: &FitnessWeights,
     known_architectures: &[KnownArchitecture],
     market_context: &MarketContext,
+) -> f64 {
+    let leverage = compute_strategic_leverage(node, market_context);
+    combine(node, weights, known_architectures, leverage)
+}
+
+/// Combined fitness with strategic leverage from an economic projection
+pub fn compute_projected_fitness(
+    node: &MutatedNode,
+    weights: &FitnessWeights,
+    known_architectures: &[KnownArchitecture],
+    projection: &EconomicProjection,
+) -> f64 {
+    let leverage = compute_projected_leverage(node, projection);
+    combine(node, weights, known_architectures, leverage)
+}
+
+fn combine(
+    node: &MutatedNode,
+    weights: &FitnessWeights,
+    known_architectures: &[KnownArchitecture],
+    leverage: f64,
 ) -> f64 {
     let novelty = compute_novelty(node, known_architectures);
     let feasibility = compute_feasibility(node);
     let scalability = compute_scalability(node);
-    let leverage = compute_strategic_leverage(node, market_context);
     
     weights.alpha * novelty
         + weights.beta * feasibility
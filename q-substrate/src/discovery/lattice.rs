@@ -9,11 +9,13 @@
 
 extern crate alloc;
 
+use alloc::format;
 use alloc::string::String;
-use alloc::vec;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
+use super::constraint::{Axis, ConstraintSet};
+
 /// Physics layer discovery nodes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PhysicsNode {
@@ -27,6 +29,20 @@ pub enum PhysicsNode {
     QuantumAnnealing,
 }
 
+impl PhysicsNode {
+    /// Every node on this axis, in lattice order
+    pub const ALL: [PhysicsNode; 8] = [
+        PhysicsNode::QuantumErrorSuppression,
+        PhysicsNode::DecoherenceTopology,
+        PhysicsNode::TopologicalQubit,
+        PhysicsNode::AnyonBraiding,
+        PhysicsNode::QuantumMemory,
+        PhysicsNode::CoherentControl,
+        PhysicsNode::AdiabaticEvolution,
+        PhysicsNode::QuantumAnnealing,
+    ];
+}
+
 /// Computation layer discovery nodes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ComputationNode {
@@ -40,6 +56,20 @@ pub enum ComputationNode {
     StreamingInference,
 }
 
+impl ComputationNode {
+    /// Every node on this axis, in lattice order
+    pub const ALL: [ComputationNode; 8] = [
+        ComputationNode::UltraLowBitInference,
+        ComputationNode::ReversibleKernel,
+        ComputationNode::NeuromorphicCircuit,
+        ComputationNode::SpikingNetwork,
+        ComputationNode::QuantumAlgorithm,
+        ComputationNode::HybridClassicalQuantum,
+        ComputationNode::DeterministicASI,
+        ComputationNode::StreamingInference,
+    ];
+}
+
 /// Materials layer discovery nodes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MaterialsNode {
@@ -53,6 +83,20 @@ pub enum MaterialsNode {
     GrapheneComposite,
 }
 
+impl MaterialsNode {
+    /// Every node on this axis, in lattice order
+    pub const ALL: [MaterialsNode; 8] = [
+        MaterialsNode::MetastableLattice,
+        MaterialsNode::PhononRoutingCrystal,
+        MaterialsNode::SpinLiquidSubstrate,
+        MaterialsNode::TopologicalInsulator,
+        MaterialsNode::SuperconductingQubit,
+        MaterialsNode::DiamondNV,
+        MaterialsNode::SiliconPhotonics,
+        MaterialsNode::GrapheneComposite,
+    ];
+}
+
 /// Systems layer discovery nodes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SystemsNode {
@@ -66,6 +110,20 @@ pub enum SystemsNode {
     RollbackSystem,
 }
 
+impl SystemsNode {
+    /// Every node on this axis, in lattice order
+    pub const ALL: [SystemsNode; 8] = [
+        SystemsNode::DeterministicASIRuntime,
+        SystemsNode::DistributedQRADLE,
+        SystemsNode::EdgeCloudHybrid,
+        SystemsNode::QuantumInterconnect,
+        SystemsNode::FaultTolerantArchitecture,
+        SystemsNode::PodIsolation,
+        SystemsNode::ProvenanceChain,
+        SystemsNode::RollbackSystem,
+    ];
+}
+
 /// Economics layer discovery nodes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EconomicsNode {
@@ -79,6 +137,20 @@ pub enum EconomicsNode {
     CompetitiveAdvantage,
 }
 
+impl EconomicsNode {
+    /// Every node on this axis, in lattice order
+    pub const ALL: [EconomicsNode; 8] = [
+        EconomicsNode::CapitalEfficiencyGradient,
+        EconomicsNode::LearningCurveArbitrage,
+        EconomicsNode::MoatTopology,
+        EconomicsNode::NetworkEffect,
+        EconomicsNode::ScalabilityVector,
+        EconomicsNode::MarketPenetration,
+        EconomicsNode::ValueCapture,
+        EconomicsNode::CompetitiveAdvantage,
+    ];
+}
+
 /// Candidate node combining multiple dimensions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CandidateNode {
@@ -139,6 +211,7 @@ pub struct DiscoveryLattice {
     materials_nodes: Vec<MaterialsNode>,
     systems_nodes: Vec<SystemsNode>,
     economics_nodes: Vec<EconomicsNode>,
+    constraints: ConstraintSet,
     candidate_count: usize,
     seed: u32,
 }
@@ -146,60 +219,11 @@ pub struct DiscoveryLattice {
 impl DiscoveryLattice {
     /// Construct the full search space with deterministic seed
     pub fn new(seed: u32) -> Self {
-        let physics_nodes = vec![
-            PhysicsNode::QuantumErrorSuppression,
-            PhysicsNode::DecoherenceTopology,
-            PhysicsNode::TopologicalQubit,
-            PhysicsNode::AnyonBraiding,
-            PhysicsNode::QuantumMemory,
-            PhysicsNode::CoherentControl,
-            PhysicsNode::AdiabaticEvolution,
-            PhysicsNode::QuantumAnnealing,
-        ];
-        
-        let computation_nodes = vec![
-            ComputationNode::UltraLowBitInference,
-            ComputationNode::ReversibleKernel,
-            ComputationNode::NeuromorphicCircuit,
-            ComputationNode::SpikingNetwork,
-            ComputationNode::QuantumAlgorithm,
-            ComputationNode::HybridClassicalQuantum,
-            ComputationNode::DeterministicASI,
-            ComputationNode::StreamingInference,
-        ];
-        
-        let materials_nodes = vec![
-            MaterialsNode::MetastableLattice,
-            MaterialsNode::PhononRoutingCrystal,
-            MaterialsNode::SpinLiquidSubstrate,
-            MaterialsNode::TopologicalInsulator,
-            MaterialsNode::SuperconductingQubit,
-            MaterialsNode::DiamondNV,
-            MaterialsNode::SiliconPhotonics,
-            MaterialsNode::GrapheneComposite,
-        ];
-        
-        let systems_nodes = vec![
-            SystemsNode::DeterministicASIRuntime,
-            SystemsNode::DistributedQRADLE,
-            SystemsNode::EdgeCloudHybrid,
-            SystemsNode::QuantumInterconnect,
-            SystemsNode::FaultTolerantArchitecture,
-            SystemsNode::PodIsolation,
-            SystemsNode::ProvenanceChain,
-            SystemsNode::RollbackSystem,
-        ];
-        
-        let economics_nodes = vec![
-            EconomicsNode::CapitalEfficiencyGradient,
-            EconomicsNode::LearningCurveArbitrage,
-            EconomicsNode::MoatTopology,
-            EconomicsNode::NetworkEffect,
-            EconomicsNode::ScalabilityVector,
-            EconomicsNode::MarketPenetration,
-            EconomicsNode::ValueCapture,
-            EconomicsNode::CompetitiveAdvantage,
-        ];
+        let physics_nodes = PhysicsNode::ALL.to_vec();
+        let computation_nodes = ComputationNode::ALL.to_vec();
+        let materials_nodes = MaterialsNode::ALL.to_vec();
+        let systems_nodes = SystemsNode::ALL.to_vec();
+        let economics_nodes = EconomicsNode::ALL.to_vec();
         
         // Candidate count: combinations of 2-3 nodes from different dimensions
         let candidate_count = physics_nodes.len() * computation_nodes.len() 
@@ -214,11 +238,28 @@ impl DiscoveryLattice {
             materials_nodes,
             systems_nodes,
             economics_nodes,
+            constraints: ConstraintSet::default(),
             candidate_count,
             seed,
         }
     }
 
+    /// Construct a search space bounded by `constraints`
+    ///
+    /// Excluded nodes are dropped from their axes and candidates outside the
+    /// bounds are never enumerated, so nothing pruned reaches evaluation.
+    pub fn with_constraints(seed: u32, constraints: ConstraintSet) -> Self {
+        let mut lattice = Self::new(seed);
+        lattice.physics_nodes.retain(|n| constraints.permits_node(Axis::Physics, *n));
+        lattice.computation_nodes.retain(|n| constraints.permits_node(Axis::Computation, *n));
+        lattice.materials_nodes.retain(|n| constraints.permits_node(Axis::Materials, *n));
+        lattice.systems_nodes.retain(|n| constraints.permits_node(Axis::Systems, *n));
+        lattice.economics_nodes.retain(|n| constraints.permits_node(Axis::Economics, *n));
+        lattice.constraints = constraints;
+        lattice.candidate_count = lattice.enumerate_candidates().len();
+        lattice
+    }
+
     /// Enumerate all candidate interaction nodes
     pub fn enumerate_candidates(&self) -> Vec<CandidateNode> {
         let mut candidates = Vec::new();
@@ -234,8 +275,10 @@ impl DiscoveryLattice {
                     economics: None,
                     interaction_id: String::new(),
                 };
-                node.interaction_id = node.generate_id();
-                candidates.push(node);
+                if self.constraints.permits(&node) {
+                    node.interaction_id = node.generate_id();
+                    candidates.push(node);
+                }
             }
         }
         
@@ -250,8 +293,10 @@ impl DiscoveryLattice {
                     economics: None,
                     interaction_id: String::new(),
                 };
-                node.interaction_id = node.generate_id();
-                candidates.push(node);
+                if self.constraints.permits(&node) {
+                    node.interaction_id = node.generate_id();
+                    candidates.push(node);
+                }
             }
         }
         
@@ -266,8 +311,10 @@ impl DiscoveryLattice {
                     economics: None,
                     interaction_id: String::new(),
                 };
-                node.interaction_id = node.generate_id();
-                candidates.push(node);
+                if self.constraints.permits(&node) {
+                    node.interaction_id = node.generate_id();
+                    candidates.push(node);
+                }
             }
         }
         
@@ -282,8 +329,10 @@ impl DiscoveryLattice {
                     economics: Some(*e),
                     interaction_id: String::new(),
                 };
-                node.interaction_id = node.generate_id();
-                candidates.push(node);
+                if self.constraints.permits(&node) {
+                    node.interaction_id = node.generate_id();
+                    candidates.push(node);
+                }
             }
         }
         
@@ -298,8 +347,10 @@ impl DiscoveryLattice {
                     economics: None,
                     interaction_id: String::new(),
                 };
-                node.interaction_id = node.generate_id();
-                candidates.push(node);
+                if self.constraints.permits(&node) {
+                    node.interaction_id = node.generate_id();
+                    candidates.push(node);
+                }
             }
         }
         
@@ -348,6 +399,11 @@ impl DiscoveryLattice {
     pub fn get_seed(&self) -> u32 {
         self.seed
     }
+
+    /// Get the constraints bounding this lattice
+    pub fn get_constraints(&self) -> &ConstraintSet {
+        &self.constraints
+    }
 }
 
 #[cfg(test)]
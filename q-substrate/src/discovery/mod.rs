@@ -11,15 +11,22 @@
 
 pub mod types;
 pub mod lattice;
+pub mod constraint;
 pub mod fitness;
+pub mod economics;
 pub mod engine;
 pub mod provenance;
+pub mod review;
+pub mod risk;
+pub mod dossier;
 pub mod cli;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 
 // Re-exports for convenience
 pub use types::{
-    Discovery, DiscoveryError, Formulation, IndustrialImpact, Provenance, RiskEnvelope,
-    ValidationMethod, ValidationPath,
+    Discovery, DiscoveryError, Formulation, IndustrialImpact, Provenance, ReviewRecord,
+    ReviewVerdict, RiskEnvelope, RiskRecord, ValidationMethod, ValidationPath,
 };
 
 pub use lattice::{
@@ -27,9 +34,17 @@ pub use lattice::{
     MutatedNode, PhysicsNode, SymbolicRepresentation, SystemsNode,
 };
 
+pub use constraint::{Axis, Constraint, ConstraintSet};
+
 pub use fitness::{
-    compute_feasibility, compute_fitness, compute_novelty, compute_scalability,
-    compute_strategic_leverage, FitnessWeights, KnownArchitecture, MarketContext,
+    compute_feasibility, compute_fitness, compute_novelty, compute_projected_fitness,
+    compute_projected_leverage, compute_scalability, compute_strategic_leverage, FitnessWeights,
+    KnownArchitecture, MarketContext,
+};
+
+pub use economics::{
+    CostCurve, DemandCurve, EconomicModel, EconomicProjection, MarketData, MarketModel,
+    NodeProfile,
 };
 
 pub use engine::{DiscoveryEngine, DiscoveryReport};
@@ -39,9 +54,23 @@ pub use provenance::{
     verify_provenance_chain, ProvenanceReport,
 };
 
+pub use review::{
+    run_discovery_with_review, CandidateReviewer, FnReviewer, ReviewDecision, ReviewedCorpus,
+};
+
+pub use risk::{
+    FactorScore, GovernanceApproval, MitigationCredit, RiskAssessment, RiskGate, ScoringRule,
+    ScoringTable,
+};
+
+pub use dossier::{
+    render_discovery as render_discovery_dossier, render_run as render_run_dossier,
+    DossierFormat,
+};
+
 pub use cli::{
-    export_discoveries_json, format_report, import_discoveries_json, run_discovery_directive,
-    validate_discovery_schema,
+    export_discoveries_json, format_report, import_discoveries_json, run_constrained_directive,
+    run_discovery_directive, validate_discovery_schema,
 };
 
 /// Discovery module version
@@ -5,11 +5,12 @@
 
 extern crate alloc;
 
+use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
-use super::types::{Discovery, DiscoveryError};
+use super::types::{Discovery, DiscoveryError, ReviewVerdict};
 use crate::wasm_pod::ProvenanceEntry;
 
 /// Generate deterministic provenance hash for discovery
@@ -36,6 +37,39 @@ pub fn generate_provenance_hash(discovery: &Discovery) -> String {
     let fitness_int = (discovery.fitness_score * 1000000.0) as u64;
     hash_input = hash_input.wrapping_mul(31).wrapping_add(fitness_int);
     
+    // Hash search constraints, if any (unbounded runs hash as before)
+    if let Some(constraints) = &discovery.provenance.constraints {
+        for byte in constraints.bytes() {
+            hash_input = hash_input.wrapping_mul(31).wrapping_add(byte as u64);
+        }
+    }
+    
+    // Hash review decision, if any (unreviewed hashes are unchanged)
+    if let Some(review) = &discovery.provenance.review {
+        let verdict = match review.verdict {
+            ReviewVerdict::Accept => b"accept".as_slice(),
+            ReviewVerdict::Reject => b"reject".as_slice(),
+        };
+        let annotation = review.annotation.as_deref().unwrap_or("");
+        for byte in review.reviewer.bytes().chain(review.candidate_hash.bytes())
+            .chain(verdict.iter().copied()).chain(annotation.bytes())
+        {
+            hash_input = hash_input.wrapping_mul(31).wrapping_add(byte as u64);
+        }
+        hash_input = hash_input.wrapping_mul(31).wrapping_add(review.sequence);
+    }
+    
+    // Hash risk score, if any (ungated hashes are unchanged)
+    if let Some(risk) = &discovery.provenance.risk {
+        let approval = risk.approval.as_deref().unwrap_or("");
+        for byte in risk.table.bytes().chain(risk.rules.iter().flat_map(|r| r.bytes()))
+            .chain(approval.bytes())
+        {
+            hash_input = hash_input.wrapping_mul(31).wrapping_add(byte as u64);
+        }
+        hash_input = hash_input.wrapping_mul(31).wrapping_add((risk.index * 1000000.0) as u64);
+    }
+    
     // Generate final hash with additional mixing
     let hash = hash_input
         .wrapping_mul(0x517cc1b727220a95)
@@ -229,6 +263,9 @@ mod tests {
                 qradle_hash: "placeholder".into(),
                 seed: 42,
                 lattice_node: "test_node".into(),
+                constraints: None,
+                review: None,
+                risk: None,
             },
         }
     }
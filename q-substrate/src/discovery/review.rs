@@ -0,0 +1,186 @@
+//! Discovery Review - Callback-Driven Candidate Review
+//!
+//! Library entry point for embedding the discovery engine with an external
+//! reviewer (a human-in-the-loop UI, another model, a policy service).
+//! Every candidate that clears the fitness threshold is handed to the
+//! reviewer before archival; the reviewer accepts or rejects it and may
+//! annotate either verdict.
+//!
+//! Each decision becomes a `ReviewRecord` in the candidate's provenance and
+//! is folded into its provenance hash, so a corpus cannot be edited after
+//! review without `verify_provenance_chain` noticing. Rejected candidates
+//! are returned alongside accepted ones so the full decision log stays
+//! auditable.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use super::engine::DiscoveryEngine;
+use super::provenance::verify_provenance_chain;
+use super::types::{Discovery, DiscoveryError, ReviewRecord, ReviewVerdict};
+
+/// Reviewer's answer for one candidate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewDecision {
+    /// Verdict
+    pub verdict: ReviewVerdict,
+    /// Optional annotation recorded with the verdict
+    pub annotation: Option<String>,
+}
+
+impl ReviewDecision {
+    /// Accept without annotation
+    pub fn accept() -> Self {
+        Self { verdict: ReviewVerdict::Accept, annotation: None }
+    }
+
+    /// Reject without annotation
+    pub fn reject() -> Self {
+        Self { verdict: ReviewVerdict::Reject, annotation: None }
+    }
+
+    /// Attach an annotation
+    pub fn with_annotation(mut self, annotation: impl Into<String>) -> Self {
+        self.annotation = Some(annotation.into());
+        self
+    }
+}
+
+/// External candidate reviewer
+pub trait CandidateReviewer {
+    /// Identifier recorded in provenance
+    fn reviewer_id(&self) -> String;
+
+    /// Decide on one candidate
+    fn review(&mut self, candidate: &Discovery) -> ReviewDecision;
+}
+
+/// Reviewer backed by a closure
+pub struct FnReviewer<F> {
+    id: String,
+    review: F,
+}
+
+impl<F: FnMut(&Discovery) -> ReviewDecision> FnReviewer<F> {
+    /// Wrap a closure as a reviewer
+    pub fn new(id: impl Into<String>, review: F) -> Self {
+        Self { id: id.into(), review }
+    }
+}
+
+impl<F: FnMut(&Discovery) -> ReviewDecision> CandidateReviewer for FnReviewer<F> {
+    fn reviewer_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn review(&mut self, candidate: &Discovery) -> ReviewDecision {
+        (self.review)(candidate)
+    }
+}
+
+/// Output of a reviewed run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewedCorpus {
+    /// Accepted discoveries, numbered consecutively (archival set)
+    pub accepted: Vec<Discovery>,
+    /// Rejected candidates with their review attached
+    pub rejected: Vec<Discovery>,
+    /// Every decision in review order
+    pub decisions: Vec<ReviewRecord>,
+    /// Whether the accepted set reached the target count
+    pub target_met: bool,
+}
+
+/// Ask a reviewer about one candidate and record the decision
+pub(crate) fn review_candidate(
+    reviewer: &mut dyn CandidateReviewer,
+    candidate: &Discovery,
+    sequence: u64,
+) -> ReviewRecord {
+    let decision = reviewer.review(candidate);
+    ReviewRecord {
+        reviewer: reviewer.reviewer_id(),
+        sequence,
+        candidate_hash: candidate.provenance.qradle_hash.clone(),
+        verdict: decision.verdict,
+        annotation: decision.annotation,
+    }
+}
+
+/// Run the discovery directive with external review
+///
+/// Library counterpart of `run_discovery_directive`: nothing is written to
+/// disk; the caller archives `ReviewedCorpus::accepted`.
+pub fn run_discovery_with_review(
+    seed: u32,
+    target_count: usize,
+    reviewer: &mut dyn CandidateReviewer,
+) -> Result<ReviewedCorpus, DiscoveryError> {
+    let mut engine = DiscoveryEngine::with_target(seed, target_count);
+    let corpus = engine.run_reviewed(reviewer);
+    verify_provenance_chain(&corpus.accepted)?;
+    Ok(corpus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::provenance::generate_provenance_hash;
+
+    #[test]
+    fn test_review_gates_archival() {
+        let mut seen = 0;
+        let mut reviewer = FnReviewer::new("panel-a", |_: &Discovery| {
+            seen += 1;
+            if seen % 3 == 0 {
+                ReviewDecision::reject().with_annotation("duplicate mechanism")
+            } else {
+                ReviewDecision::accept()
+            }
+        });
+        let corpus = run_discovery_with_review(42, 5, &mut reviewer).unwrap();
+
+        assert!(corpus.target_met);
+        assert_eq!(corpus.accepted.len(), 5);
+        assert_eq!(corpus.rejected.len(), 2);
+        assert_eq!(corpus.decisions.len(), 7);
+        assert!(corpus.decisions.iter().enumerate().all(|(i, r)| r.sequence == i as u64));
+
+        let rejected = corpus.rejected[0].provenance.review.as_ref().unwrap();
+        assert_eq!(rejected.verdict, ReviewVerdict::Reject);
+        assert_eq!(rejected.annotation.as_deref(), Some("duplicate mechanism"));
+        assert_eq!(corpus.accepted[0].provenance.review.as_ref().unwrap().reviewer, "panel-a");
+    }
+
+    #[test]
+    fn test_review_is_tamper_evident() {
+        let mut reviewer = FnReviewer::new("model-b", |_: &Discovery| {
+            ReviewDecision::accept().with_annotation("plausible")
+        });
+        let mut corpus = run_discovery_with_review(7, 2, &mut reviewer).unwrap();
+        assert!(verify_provenance_chain(&corpus.accepted).is_ok());
+
+        // Rewriting a verdict after the fact breaks the provenance hash
+        let review = corpus.accepted[1].provenance.review.as_mut().unwrap();
+        review.annotation = Some("edited".into());
+        assert!(verify_provenance_chain(&corpus.accepted).is_err());
+
+        // Unreviewed discoveries hash exactly as before review existed
+        let mut plain = corpus.accepted[0].clone();
+        plain.provenance.review = None;
+        let expected = DiscoveryEngine::with_target(7, 2).run().unwrap();
+        assert_eq!(generate_provenance_hash(&plain), expected[0].provenance.qradle_hash);
+    }
+
+    #[test]
+    fn test_short_corpus_is_returned() {
+        let mut reviewer = FnReviewer::new("strict", |_: &Discovery| ReviewDecision::reject());
+        let corpus = run_discovery_with_review(42, 3, &mut reviewer).unwrap();
+        assert!(!corpus.target_met);
+        assert!(corpus.accepted.is_empty());
+        assert_eq!(corpus.decisions.len(), corpus.rejected.len());
+    }
+}
@@ -0,0 +1,304 @@
+//! Discovery Risk Scoring - Actuarial Risk Index
+//!
+//! Turns the descriptive `RiskEnvelope` into numbers. Each declared failure
+//! mode is matched against a scoring table of keyword rules, each carrying a
+//! likelihood and a severity; declared mitigation strategies earn a bounded
+//! credit. The per-discovery risk index is the probability that at least
+//! one failure mode materializes, weighted by severity and discounted by
+//! mitigation:
+//!
+//! ```text
+//! index = (1 - Π(1 - likelihood_i · severity_i)) · (1 - credit)
+//! ```
+//!
+//! A `RiskGate` holds back discoveries whose index exceeds a ceiling; they
+//! are archived only once a governance approval is attached. The table
+//! hash, the rule matched by every failure mode, and any approval are
+//! recorded as a `RiskRecord` in provenance and folded into the provenance
+//! hash, so a score cannot be changed after the fact.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use super::types::{Discovery, RiskRecord};
+#[cfg(not(feature = "std"))]
+use crate::float::FloatExt;
+
+/// Rule label recorded for failure modes no rule matches
+pub const DEFAULT_RULE: &str = "default";
+
+/// Largest total mitigation credit
+const MAX_MITIGATION_CREDIT: f64 = 0.5;
+
+/// Keyword rule scoring one class of failure mode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoringRule {
+    /// Label recorded in provenance
+    pub label: String,
+    /// Keywords matched case-insensitively against the failure mode text
+    pub keywords: Vec<String>,
+    /// Probability the failure mode materializes (0.0 to 1.0)
+    pub likelihood: f64,
+    /// Impact if it does (0.0 to 1.0)
+    pub severity: f64,
+}
+
+/// Keyword rule crediting one class of mitigation strategy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MitigationCredit {
+    /// Keywords matched case-insensitively against the strategy text
+    pub keywords: Vec<String>,
+    /// Reduction in risk index (0.0 to 1.0)
+    pub credit: f64,
+}
+
+/// Configurable scoring table
+///
+/// Rules are tried in order; the first whose keywords match a failure mode
+/// scores it. Each mitigation credit counts at most once per discovery.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoringTable {
+    /// Failure mode rules
+    pub rules: Vec<ScoringRule>,
+    /// Mitigation credits
+    pub mitigations: Vec<MitigationCredit>,
+    /// Likelihood for unmatched failure modes
+    pub default_likelihood: f64,
+    /// Severity for unmatched failure modes
+    pub default_severity: f64,
+}
+
+fn rule(label: &str, keywords: &[&str], likelihood: f64, severity: f64) -> ScoringRule {
+    ScoringRule {
+        label: label.into(),
+        keywords: keywords.iter().map(|k| String::from(*k)).collect(),
+        likelihood,
+        severity,
+    }
+}
+
+fn credit(keywords: &[&str], credit: f64) -> MitigationCredit {
+    MitigationCredit { keywords: keywords.iter().map(|k| String::from(*k)).collect(), credit }
+}
+
+impl Default for ScoringTable {
+    fn default() -> Self {
+        Self {
+            rules: alloc::vec![
+                rule("safety", &["safety", "hazard", "toxic", "injury"], 0.2, 0.9),
+                rule("security", &["security", "breach", "leak", "exploit"], 0.2, 0.8),
+                rule("scalability", &["scale", "scaling"], 0.35, 0.5),
+                rule("integration", &["integration", "compatib"], 0.3, 0.3),
+                rule("resource", &["resource", "cost", "supply"], 0.25, 0.3),
+            ],
+            mitigations: alloc::vec![
+                credit(&["incremental", "staged", "validation gate"], 0.1),
+                credit(&["testing", "isolated", "sandbox"], 0.1),
+                credit(&["fallback", "rollback", "baseline"], 0.1),
+            ],
+            default_likelihood: 0.2,
+            default_severity: 0.4,
+        }
+    }
+}
+
+fn matches(keywords: &[String], text: &str) -> bool {
+    let text = text.to_lowercase();
+    keywords.iter().any(|k| text.contains(&k.to_lowercase()))
+}
+
+/// Round to 4 decimal places, as fitness scores are
+fn round4(value: f64) -> f64 {
+    (value * 10000.0).round() / 10000.0
+}
+
+/// Score of one declared failure mode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactorScore {
+    /// Failure mode as declared
+    pub factor: String,
+    /// Matched rule label, or `DEFAULT_RULE`
+    pub rule: String,
+    /// Expected loss (likelihood × severity)
+    pub score: f64,
+}
+
+/// Scored discovery
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskAssessment {
+    /// Discovery ID
+    pub discovery_id: String,
+    /// Per failure mode scores
+    pub factors: Vec<FactorScore>,
+    /// Total mitigation credit applied
+    pub mitigation_credit: f64,
+    /// Overall risk index (0.0 to 1.0)
+    pub index: f64,
+    /// Hash of the scoring table
+    pub table: String,
+}
+
+impl ScoringTable {
+    /// Deterministic hash of the table contents
+    pub fn hash(&self) -> String {
+        let mut hash = 0u64;
+        let mut mix = |bytes: &[u8]| {
+            for byte in bytes {
+                hash = hash.wrapping_mul(31).wrapping_add(*byte as u64);
+            }
+        };
+        for rule in &self.rules {
+            mix(rule.label.as_bytes());
+            rule.keywords.iter().for_each(|k| mix(k.as_bytes()));
+            mix(&rule.likelihood.to_bits().to_le_bytes());
+            mix(&rule.severity.to_bits().to_le_bytes());
+        }
+        for mitigation in &self.mitigations {
+            mitigation.keywords.iter().for_each(|k| mix(k.as_bytes()));
+            mix(&mitigation.credit.to_bits().to_le_bytes());
+        }
+        mix(&self.default_likelihood.to_bits().to_le_bytes());
+        mix(&self.default_severity.to_bits().to_le_bytes());
+        let hash = hash.wrapping_mul(0x517cc1b727220a95).wrapping_add(0x63f5d5a6a9e1a3c7);
+        format!("QRDR-{:016x}", hash)
+    }
+
+    /// Score a discovery's risk envelope
+    pub fn assess(&self, discovery: &Discovery) -> RiskAssessment {
+        let envelope = &discovery.risk_envelope;
+        let factors: Vec<FactorScore> = envelope
+            .failure_modes
+            .iter()
+            .map(|mode| {
+                let (rule, likelihood, severity) = self
+                    .rules
+                    .iter()
+                    .find(|r| matches(&r.keywords, mode))
+                    .map(|r| (r.label.clone(), r.likelihood, r.severity))
+                    .unwrap_or_else(|| {
+                        (DEFAULT_RULE.into(), self.default_likelihood, self.default_severity)
+                    });
+                FactorScore {
+                    factor: mode.clone(),
+                    rule,
+                    score: (likelihood * severity).clamp(0.0, 1.0),
+                }
+            })
+            .collect();
+
+        let mitigation_credit = self
+            .mitigations
+            .iter()
+            .filter(|m| envelope.mitigation_strategies.iter().any(|s| matches(&m.keywords, s)))
+            .map(|m| m.credit)
+            .sum::<f64>()
+            .clamp(0.0, MAX_MITIGATION_CREDIT);
+
+        let survival: f64 = factors.iter().map(|f| 1.0 - f.score).product();
+        RiskAssessment {
+            discovery_id: discovery.id.clone(),
+            factors,
+            mitigation_credit: round4(mitigation_credit),
+            index: round4((1.0 - survival) * (1.0 - mitigation_credit)),
+            table: self.hash(),
+        }
+    }
+}
+
+impl RiskAssessment {
+    /// Provenance record for this assessment
+    pub fn record(&self) -> RiskRecord {
+        RiskRecord {
+            table: self.table.clone(),
+            index: self.index,
+            rules: self.factors.iter().map(|f| f.rule.clone()).collect(),
+            approval: None,
+        }
+    }
+}
+
+/// Archival gate on the risk index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskGate {
+    /// Scoring table
+    pub table: ScoringTable,
+    /// Highest index archived without governance approval
+    pub ceiling: f64,
+}
+
+impl RiskGate {
+    /// Gate with the default table
+    pub fn new(ceiling: f64) -> Self {
+        Self { table: ScoringTable::default(), ceiling }
+    }
+
+    /// Gate with a custom table
+    pub fn with_table(table: ScoringTable, ceiling: f64) -> Self {
+        Self { table, ceiling }
+    }
+
+    /// Whether an assessment needs governance approval before archival
+    pub fn requires_approval(&self, assessment: &RiskAssessment) -> bool {
+        assessment.index > self.ceiling
+    }
+}
+
+/// Governance sign-off for archiving a discovery above the risk ceiling
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GovernanceApproval {
+    /// Approving body or proposal identifier
+    pub approver: String,
+    /// Reference to the decision (minutes, proposal hash, ticket)
+    pub reference: String,
+}
+
+impl GovernanceApproval {
+    /// Create an approval
+    pub fn new(approver: impl Into<String>, reference: impl Into<String>) -> Self {
+        Self { approver: approver.into(), reference: reference.into() }
+    }
+
+    /// Form recorded in provenance
+    pub fn record(&self) -> String {
+        format!("{}:{}", self.approver, self.reference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::engine::DiscoveryEngine;
+
+    #[test]
+    fn test_default_table_scores_envelope() {
+        let mut engine = DiscoveryEngine::with_target(42, 1);
+        let discovery = engine.run().unwrap().remove(0);
+        let table = ScoringTable::default();
+        let assessment = table.assess(&discovery);
+
+        let rules: Vec<&str> = assessment.factors.iter().map(|f| f.rule.as_str()).collect();
+        assert_eq!(rules, ["scalability", "integration", "resource"]);
+        assert_eq!(assessment.mitigation_credit, 0.3);
+        // (1 - 0.825 · 0.91 · 0.925) · 0.7
+        assert_eq!(assessment.index, 0.2139);
+        assert_eq!(assessment.table, table.hash());
+
+        // Editing the table changes its hash and the score
+        let mut strict = table.clone();
+        strict.rules[2].likelihood = 0.9;
+        assert_ne!(strict.hash(), table.hash());
+        assert!(strict.assess(&discovery).index > assessment.index);
+
+        // Unmatched failure modes fall back to the default rule
+        let mut unknown = discovery.clone();
+        unknown.risk_envelope.failure_modes = alloc::vec!["Regulatory reclassification".into()];
+        unknown.risk_envelope.mitigation_strategies.clear();
+        let assessment = table.assess(&unknown);
+        assert_eq!(assessment.factors[0].rule, DEFAULT_RULE);
+        assert_eq!(assessment.index, 0.08);
+    }
+}
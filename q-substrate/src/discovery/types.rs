@@ -104,6 +104,52 @@ pub struct Provenance {
     pub seed: u32,
     /// Lattice node identifier
     pub lattice_node: String,
+    /// Hash of the search constraints the run was bounded by, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<String>,
+    /// External review decision, if the candidate went through review
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review: Option<ReviewRecord>,
+    /// Risk score, if the run was risk-gated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub risk: Option<RiskRecord>,
+}
+
+/// Reviewer verdict on a candidate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewVerdict {
+    /// Archive the candidate
+    Accept,
+    /// Drop the candidate; the decision is still logged
+    Reject,
+}
+
+/// Reviewer decision captured in provenance
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewRecord {
+    /// Reviewer identifier (person, model or service)
+    pub reviewer: String,
+    /// Position in the run's decision log (0-based)
+    pub sequence: u64,
+    /// Provenance hash of the candidate as presented for review
+    pub candidate_hash: String,
+    /// Verdict
+    pub verdict: ReviewVerdict,
+    /// Free-form annotation
+    pub annotation: Option<String>,
+}
+
+/// Risk score captured in provenance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskRecord {
+    /// Hash of the scoring table used
+    pub table: String,
+    /// Overall risk index (0.0 to 1.0)
+    pub index: f64,
+    /// Scoring rule matched by each failure mode, in order
+    pub rules: Vec<String>,
+    /// Governance approval for archival above the risk ceiling
+    pub approval: Option<String>,
 }
 
 impl Discovery {
@@ -138,6 +184,8 @@ pub enum DiscoveryError {
     SerializationError(String),
     /// Validation error
     ValidationError(String),
+    /// Malformed search constraint expression
+    InvalidConstraint(String),
     /// Generic error
     Generic(String),
 }
@@ -160,6 +208,9 @@ impl core::fmt::Display for DiscoveryError {
             DiscoveryError::ValidationError(msg) => {
                 write!(f, "Validation error: {}", msg)
             }
+            DiscoveryError::InvalidConstraint(msg) => {
+                write!(f, "Invalid constraint: {}", msg)
+            }
             DiscoveryError::Generic(msg) => write!(f, "{}", msg),
         }
     }
@@ -203,6 +254,9 @@ mod tests {
                 qradle_hash: "test_hash".into(),
                 seed: 42,
                 lattice_node: "test_node".into(),
+                constraints: None,
+                review: None,
+                risk: None,
             },
         };
 
@@ -251,6 +305,9 @@ mod tests {
                 qradle_hash: "test".into(),
                 seed: 42,
                 lattice_node: "test".into(),
+                constraints: None,
+                review: None,
+                risk: None,
             },
         };
 
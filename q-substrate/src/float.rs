@@ -0,0 +1,66 @@
+//! Float Math for no_std
+//!
+//! `f32`/`f64` have no `sqrt`, `sin`, `ln`, ... without std. This trait
+//! supplies them from the pure-Rust `libm` crate under the same names, so
+//! call sites are identical in both builds:
+//! - std: inherent methods win; this module is not compiled
+//! - no_std: `use crate::float::FloatExt;` brings these into scope
+
+/// Math methods missing from `core` floats
+pub(crate) trait FloatExt {
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn ln(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn round(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+}
+
+impl FloatExt for f32 {
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+    fn sin(self) -> Self {
+        libm::sinf(self)
+    }
+    fn cos(self) -> Self {
+        libm::cosf(self)
+    }
+    fn ln(self) -> Self {
+        libm::logf(self)
+    }
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2f(self, other)
+    }
+    fn round(self) -> Self {
+        libm::roundf(self)
+    }
+    fn powf(self, n: Self) -> Self {
+        libm::powf(self, n)
+    }
+}
+
+impl FloatExt for f64 {
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+    fn sin(self) -> Self {
+        libm::sin(self)
+    }
+    fn cos(self) -> Self {
+        libm::cos(self)
+    }
+    fn ln(self) -> Self {
+        libm::log(self)
+    }
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2(self, other)
+    }
+    fn round(self) -> Self {
+        libm::round(self)
+    }
+    fn powf(self, n: Self) -> Self {
+        libm::pow(self, n)
+    }
+}
@@ -0,0 +1,164 @@
+//! Golden Wire Fixtures
+//!
+//! Serializes canonical instances of the persisted record types (discoveries,
+//! provenance reports, audit entries) and compares the JSON against fixtures
+//! in `fixtures/golden/`. Any byte-level change fails the test, so
+//! wire-format breaks cannot land silently.
+//!
+//! After an intentional format change, regenerate and commit the fixtures:
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test golden
+//! ```
+
+extern crate std;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::path::PathBuf;
+
+use crate::audit::{AuditEntry, LedgerAnchor, ProvenanceRecord};
+use crate::discovery::provenance::{generate_provenance_hash, generate_provenance_report};
+use crate::discovery::types::{
+    Discovery, Formulation, IndustrialImpact, Provenance, RiskEnvelope, ValidationMethod,
+    ValidationPath,
+};
+
+/// Fixture directory
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/golden").join(name)
+}
+
+/// Render JSON as the fixture text (trailing newline)
+fn render(bytes: &[u8]) -> String {
+    let mut text = String::from_utf8(bytes.to_vec()).expect("JSON fixture is UTF-8");
+    text.push('\n');
+    text
+}
+
+/// Compare bytes against a fixture (or rewrite it with `UPDATE_GOLDEN=1`)
+///
+/// Returns the fixture bytes so callers can also check decoding.
+fn check(name: &str, bytes: &[u8]) -> Vec<u8> {
+    let path = fixture_path(name);
+    let rendered = render(bytes);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().expect("fixture directory")).expect("create fixture directory");
+        std::fs::write(&path, &rendered).expect("write fixture");
+        return bytes.to_vec();
+    }
+
+    let stored = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("missing golden fixture {}; run with UPDATE_GOLDEN=1 to create it", path.display())
+    });
+    let expected = stored.trim_end_matches('\n').as_bytes().to_vec();
+    if expected != bytes {
+        let offset = expected.iter().zip(bytes).take_while(|(a, b)| a == b).count();
+        panic!(
+            "wire format of {} changed at byte {} ({} -> {} bytes); if intentional, \
+             rerun with UPDATE_GOLDEN=1 and commit the fixture",
+            name,
+            offset,
+            expected.len(),
+            bytes.len()
+        );
+    }
+    expected
+}
+
+/// Canonical discovery exercising every optional and repeated field
+fn canonical_discovery() -> Discovery {
+    let mut discovery = Discovery {
+        id: "QRD-001".into(),
+        title: "Golden Discovery".into(),
+        hypothesis: "Coupling strength above 0.5 halves decoherence".into(),
+        core_mechanism: "Dynamical decoupling".into(),
+        formulation: Formulation {
+            equations: alloc::vec!["T2' = 2 * T2".into(), "g > 0.5".into()],
+            pseudocode: Some("apply_pulses(n)".into()),
+            formal_spec: Some("forall g > 0.5".into()),
+        },
+        validation: ValidationPath {
+            method: ValidationMethod::Hybrid,
+            test_rig: "Simulated 4-qubit register".into(),
+            expected_outcome: "T2 doubles".into(),
+            confidence: 0.9,
+        },
+        industrial_impact: IndustrialImpact {
+            application: "Quantum memory".into(),
+            market_sector: "Computing".into(),
+            estimated_value: Some("1M".into()),
+        },
+        risk_envelope: RiskEnvelope {
+            failure_modes: alloc::vec!["Pulse drift".into()],
+            safety_constraints: alloc::vec!["Cryogenic only".into()],
+            mitigation_strategies: alloc::vec!["Calibrate per run".into()],
+        },
+        fitness_score: 0.95,
+        provenance: Provenance {
+            generated_at: "2025-01-01T00:00:00Z".into(),
+            qradle_hash: String::new(),
+            seed: 42,
+            lattice_node: "quantum_materials".into(),
+            constraints: None,
+            review: None,
+            risk: None,
+        },
+    };
+    discovery.provenance.qradle_hash = generate_provenance_hash(&discovery);
+    discovery
+}
+
+#[test]
+fn golden_discovery() {
+    let discovery = canonical_discovery();
+    let fixture = check("discovery.json", &serde_json::to_vec(&discovery).unwrap());
+
+    let decoded: Discovery = serde_json::from_slice(&fixture).unwrap();
+    assert_eq!(serde_json::to_vec(&decoded).unwrap(), fixture);
+    assert_eq!(generate_provenance_hash(&decoded), discovery.provenance.qradle_hash);
+}
+
+#[test]
+fn golden_provenance_report() {
+    let report = generate_provenance_report(&[canonical_discovery()]);
+    check("provenance_report.json", &serde_json::to_vec(&report).unwrap());
+}
+
+#[test]
+fn golden_audit_records() {
+    let entry = AuditEntry {
+        sequence: 7,
+        operation: "quantum_step".into(),
+        op_count: 3,
+        timestamp: 1_700_000_000,
+        module: "quantum".into(),
+        input_hash: Some(0x0123_4567_89AB_CDEF),
+        output_hash: None,
+        success: false,
+        error: Some("rollback".into()),
+    };
+    check("audit_entry.json", &serde_json::to_vec(&entry).unwrap());
+
+    let record = ProvenanceRecord {
+        source: "dcge".into(),
+        target: Some("wasm_pod".into()),
+        operation: "emit".into(),
+        timestamp: 1_700_000_000,
+        duration_us: 250,
+        memory_used: 4096,
+    };
+    check("provenance_record.json", &serde_json::to_vec(&record).unwrap());
+
+    let anchor = LedgerAnchor {
+        first_sequence: 1,
+        last_sequence: 7,
+        txo_id: [0x44; 16],
+        ledger_root: [0x55; 32],
+        epoch: 3,
+    };
+    let fixture = check("ledger_anchor.json", &serde_json::to_vec(&anchor).unwrap());
+    let decoded: LedgerAnchor = serde_json::from_slice(&fixture).unwrap();
+    assert_eq!(decoded, anchor);
+}
@@ -0,0 +1,403 @@
+//! Intent Classification Evaluation Harness
+//!
+//! Measures MiniLM intent classification against a labeled dataset:
+//! - JSONL dataset loading (`{"text": "...", "label": "..."}` per line)
+//! - Fixed-seed classification (engine reset before every example, so
+//!   predictions do not depend on dataset order)
+//! - Accuracy, per-label precision/recall/F1 and a confusion matrix
+//! - Deterministic JSON report with a dataset fingerprint, comparable
+//!   across versions for regression tracking
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use crate::minilm::{MiniLMQ4, INTENT_LABELS};
+use crate::normalize::NormalizationTag;
+
+/// Report schema version
+pub const EVAL_REPORT_VERSION: u32 = 2;
+
+/// One labeled example
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabeledIntent {
+    /// Input text
+    pub text: String,
+    /// Expected intent label (one of `INTENT_LABELS`)
+    pub label: String,
+}
+
+/// Evaluation error types
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /// Line is not a valid `LabeledIntent` object
+    InvalidLine {
+        /// 1-based line number
+        line: usize,
+        /// Parser message
+        message: String,
+    },
+    /// Label is not a known intent
+    UnknownLabel {
+        /// 1-based line number
+        line: usize,
+        /// Offending label
+        label: String,
+    },
+    /// Dataset has no examples
+    EmptyDataset,
+    /// Dataset file could not be read
+    Io(String),
+}
+
+impl core::fmt::Display for EvalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EvalError::InvalidLine { line, message } => {
+                write!(f, "Invalid dataset line {}: {}", line, message)
+            }
+            EvalError::UnknownLabel { line, label } => {
+                write!(f, "Unknown intent label '{}' on line {}", label, line)
+            }
+            EvalError::EmptyDataset => write!(f, "Dataset contains no examples"),
+            EvalError::Io(msg) => write!(f, "Failed to read dataset: {}", msg),
+        }
+    }
+}
+
+/// Parse a JSONL intent dataset
+///
+/// Blank lines are skipped; every other line must be an object with
+/// `text` and `label` fields and a label from `INTENT_LABELS`.
+pub fn load_dataset(jsonl: &str) -> Result<Vec<LabeledIntent>, EvalError> {
+    let mut dataset = Vec::new();
+    for (index, raw) in jsonl.lines().enumerate() {
+        let line = index + 1;
+        if raw.trim().is_empty() {
+            continue;
+        }
+
+        let example: LabeledIntent = serde_json::from_str(raw).map_err(|e| EvalError::InvalidLine {
+            line,
+            message: format!("{}", e),
+        })?;
+        if !INTENT_LABELS.contains(&example.label.as_str()) {
+            return Err(EvalError::UnknownLabel { line, label: example.label });
+        }
+        dataset.push(example);
+    }
+
+    if dataset.is_empty() {
+        return Err(EvalError::EmptyDataset);
+    }
+    Ok(dataset)
+}
+
+/// Load a JSONL intent dataset from disk
+#[cfg(feature = "std")]
+pub fn load_dataset_file(path: &str) -> Result<Vec<LabeledIntent>, EvalError> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| EvalError::Io(format!("{}: {}", path, e)))?;
+    load_dataset(&contents)
+}
+
+/// FNV-1a fingerprint over (label, text) pairs
+///
+/// Independent of JSON formatting and key order, so two reports agree on
+/// the fingerprint exactly when they scored the same examples.
+pub fn dataset_fingerprint(dataset: &[LabeledIntent]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for example in dataset {
+        let bytes = example
+            .label
+            .bytes()
+            .chain(core::iter::once(0))
+            .chain(example.text.bytes())
+            .chain(core::iter::once(b'\n'));
+        for byte in bytes {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// Per-label metrics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelMetrics {
+    /// Intent label
+    pub label: String,
+    /// Examples with this expected label
+    pub support: u32,
+    /// Examples predicted as this label
+    pub predicted: u32,
+    /// Correct predictions of this label
+    pub true_positives: u32,
+    /// TP / predicted (0 when never predicted)
+    pub precision: f64,
+    /// TP / support (0 when unsupported)
+    pub recall: f64,
+    /// Harmonic mean of precision and recall
+    pub f1: f64,
+}
+
+/// Deterministic evaluation report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalReport {
+    /// Report schema version
+    pub report_version: u32,
+    /// Q-Substrate version that produced the report
+    pub engine_version: String,
+    /// Classification seed
+    pub seed: u32,
+    /// Dataset fingerprint (see `dataset_fingerprint`)
+    pub dataset_fingerprint: String,
+    /// Text normalization applied before classification
+    pub normalization: NormalizationTag,
+    /// Number of examples
+    pub examples: u32,
+    /// Correct predictions
+    pub correct: u32,
+    /// correct / examples
+    pub accuracy: f64,
+    /// Mean precision over labels with support
+    pub macro_precision: f64,
+    /// Mean recall over labels with support
+    pub macro_recall: f64,
+    /// Mean F1 over labels with support
+    pub macro_f1: f64,
+    /// Metrics in `INTENT_LABELS` order
+    pub labels: Vec<LabelMetrics>,
+    /// confusion[expected][predicted] in `INTENT_LABELS` order
+    pub confusion: Vec<Vec<u32>>,
+}
+
+fn ratio(numerator: u32, denominator: u32) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// Classify every example with a fixed seed and score the predictions
+pub fn evaluate(dataset: &[LabeledIntent], seed: u32) -> Result<EvalReport, EvalError> {
+    if dataset.is_empty() {
+        return Err(EvalError::EmptyDataset);
+    }
+
+    let label_index = |label: &str| INTENT_LABELS.iter().position(|l| *l == label);
+    let n = INTENT_LABELS.len();
+    let mut confusion = vec![vec![0u32; n]; n];
+    let mut mlm = MiniLMQ4::new(seed);
+
+    for (line, example) in dataset.iter().enumerate() {
+        let expected = label_index(&example.label).ok_or_else(|| EvalError::UnknownLabel {
+            line: line + 1,
+            label: example.label.clone(),
+        })?;
+        mlm.reset(seed);
+        let predicted = mlm.classify(&example.text).intent_code as usize;
+        confusion[expected][predicted] += 1;
+    }
+
+    let labels: Vec<LabelMetrics> = INTENT_LABELS
+        .iter()
+        .enumerate()
+        .map(|(i, label)| {
+            let true_positives = confusion[i][i];
+            let support: u32 = confusion[i].iter().sum();
+            let predicted: u32 = confusion.iter().map(|row| row[i]).sum();
+            let precision = ratio(true_positives, predicted);
+            let recall = ratio(true_positives, support);
+            let f1 = if precision + recall > 0.0 {
+                2.0 * precision * recall / (precision + recall)
+            } else {
+                0.0
+            };
+            LabelMetrics {
+                label: (*label).into(),
+                support,
+                predicted,
+                true_positives,
+                precision,
+                recall,
+                f1,
+            }
+        })
+        .collect();
+
+    let supported: Vec<&LabelMetrics> = labels.iter().filter(|m| m.support > 0).collect();
+    let mean = |f: fn(&LabelMetrics) -> f64| {
+        supported.iter().map(|m| f(m)).sum::<f64>() / supported.len() as f64
+    };
+
+    let examples = dataset.len() as u32;
+    let correct: u32 = (0..n).map(|i| confusion[i][i]).sum();
+
+    Ok(EvalReport {
+        report_version: EVAL_REPORT_VERSION,
+        engine_version: crate::VERSION.into(),
+        seed,
+        dataset_fingerprint: dataset_fingerprint(dataset),
+        normalization: mlm.normalization_tag(),
+        examples,
+        correct,
+        accuracy: ratio(correct, examples),
+        macro_precision: mean(|m| m.precision),
+        macro_recall: mean(|m| m.recall),
+        macro_f1: mean(|m| m.f1),
+        labels,
+        confusion,
+    })
+}
+
+/// Difference between a report and a baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalComparison {
+    /// Both reports scored the same dataset with the same seed and normalization
+    pub comparable: bool,
+    /// current - baseline accuracy
+    pub accuracy_delta: f64,
+    /// Per-label current - baseline F1, in `INTENT_LABELS` order
+    pub f1_deltas: Vec<(String, f64)>,
+    /// Labels whose F1 dropped by more than the tolerance
+    pub regressions: Vec<String>,
+}
+
+impl EvalReport {
+    /// Serialize to pretty JSON (field and label order are fixed)
+    pub fn to_json(&self) -> Result<String, EvalError> {
+        serde_json::to_string_pretty(self).map_err(|e| EvalError::Io(format!("{}", e)))
+    }
+
+    /// Compare against a baseline report
+    ///
+    /// A label regresses when its F1 falls by more than `tolerance`; an
+    /// accuracy drop beyond `tolerance` is reported as `"accuracy"`.
+    pub fn compare(&self, baseline: &EvalReport, tolerance: f64) -> EvalComparison {
+        let comparable = self.dataset_fingerprint == baseline.dataset_fingerprint
+            && self.seed == baseline.seed
+            && self.normalization == baseline.normalization;
+        let accuracy_delta = self.accuracy - baseline.accuracy;
+
+        let f1_deltas: Vec<(String, f64)> = self
+            .labels
+            .iter()
+            .zip(&baseline.labels)
+            .map(|(current, base)| (current.label.clone(), current.f1 - base.f1))
+            .collect();
+
+        let mut regressions = Vec::new();
+        if accuracy_delta < -tolerance {
+            regressions.push("accuracy".into());
+        }
+        regressions.extend(
+            f1_deltas
+                .iter()
+                .filter(|(_, delta)| *delta < -tolerance)
+                .map(|(label, _)| label.clone()),
+        );
+
+        EvalComparison {
+            comparable,
+            accuracy_delta,
+            f1_deltas,
+            regressions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATASET: &str = r#"{"text": "run quantum circuit with 3 qubits", "label": "quantum_operation"}
+{"text": "generate a fibonacci function in rust", "label": "code_generation"}
+
+{"label": "system_query", "text": "show memory usage"}
+{"text": "aggregate the sensor readings", "label": "data_processing"}
+{"text": "hello there", "label": "general"}
+{"text": "simulate a bell pair", "label": "quantum_operation"}
+"#;
+
+    #[test]
+    fn test_load_dataset() {
+        let dataset = load_dataset(DATASET).unwrap();
+        assert_eq!(dataset.len(), 6);
+        assert_eq!(dataset[2].label, "system_query");
+
+        assert!(matches!(
+            load_dataset("{\"text\": \"x\", \"label\": \"general\"}\nnot json"),
+            Err(EvalError::InvalidLine { line: 2, .. })
+        ));
+        assert_eq!(
+            load_dataset("{\"text\": \"x\", \"label\": \"quantum\"}"),
+            Err(EvalError::UnknownLabel { line: 1, label: "quantum".into() })
+        );
+        assert_eq!(load_dataset("\n\n"), Err(EvalError::EmptyDataset));
+    }
+
+    #[test]
+    fn test_metrics_consistent_with_confusion() {
+        let dataset = load_dataset(DATASET).unwrap();
+        let report = evaluate(&dataset, 42).unwrap();
+
+        assert_eq!(report.examples, 6);
+        let total: u32 = report.confusion.iter().flatten().sum();
+        assert_eq!(total, 6);
+        assert_eq!(report.accuracy, report.correct as f64 / 6.0);
+
+        for (i, m) in report.labels.iter().enumerate() {
+            assert_eq!(m.label, INTENT_LABELS[i]);
+            assert_eq!(m.true_positives, report.confusion[i][i]);
+            assert!((0.0..=1.0).contains(&m.precision));
+            assert!((0.0..=1.0).contains(&m.recall));
+        }
+        assert_eq!(report.labels[0].support, 2);
+        let predicted: u32 = report.labels.iter().map(|m| m.predicted).sum();
+        assert_eq!(predicted, 6);
+    }
+
+    #[test]
+    fn test_report_deterministic_and_order_independent() {
+        let dataset = load_dataset(DATASET).unwrap();
+        let a = evaluate(&dataset, 42).unwrap();
+        let b = evaluate(&dataset, 42).unwrap();
+        assert_eq!(a.to_json().unwrap(), b.to_json().unwrap());
+
+        // Reversing the dataset changes the fingerprint but not the scores
+        let reversed: Vec<LabeledIntent> = dataset.iter().rev().cloned().collect();
+        let r = evaluate(&reversed, 42).unwrap();
+        assert_ne!(r.dataset_fingerprint, a.dataset_fingerprint);
+        assert_eq!(r.confusion, a.confusion);
+    }
+
+    #[test]
+    fn test_compare_flags_regressions() {
+        let dataset = load_dataset(DATASET).unwrap();
+        let baseline = evaluate(&dataset, 42).unwrap();
+
+        let same = baseline.compare(&baseline, 0.0);
+        assert!(same.comparable);
+        assert!(same.regressions.is_empty());
+
+        let mut worse = baseline.clone();
+        worse.accuracy -= 0.5;
+        worse.labels[1].f1 = baseline.labels[1].f1 - 0.2;
+        let cmp = worse.compare(&baseline, 0.1);
+        assert_eq!(cmp.regressions, vec![String::from("accuracy"), String::from("code_generation")]);
+        assert!(cmp.accuracy_delta < 0.0);
+
+        let other_seed = evaluate(&dataset, 7).unwrap();
+        assert!(!other_seed.compare(&baseline, 0.0).comparable);
+
+        let mut other_normalization = baseline.clone();
+        other_normalization.normalization.config_hash ^= 1;
+        assert!(!other_normalization.compare(&baseline, 0.0).comparable);
+    }
+}
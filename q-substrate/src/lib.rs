@@ -2,8 +2,16 @@
 //!
 //! A fully deterministic, sovereign, and minimal runtime supporting:
 //! - MiniLM-L6-v2 Q4 quantized inference (streaming, pod-isolated)
-//! - 12-qubit Mini QuASIM quantum simulation
+//! - Real MiniLM weights, WordPiece tokenizer and attention with `minilm-real`
+//! - Unicode text normalization ahead of embedding
+//! - Embedding similarity index with deterministic top-k retrieval
+//! - 12-qubit Mini QuASIM quantum simulation (up to 24 with `large-sim`)
+//! - AVX/NEON gate kernels with the `simd` feature, bit-identical to scalar
+//! - Density matrix (mixed-state) simulation for up to 6 qubits
+//! - OpenQASM 2.0 circuit import/export
+//! - Circuit library: Grover search, QFT and phase estimation builders
 //! - WASM pod isolation for all modules
+//! - Parallel multi-pod quantum sweeps with the `parallel` feature
 //! - Deterministic code generation (DCGE)
 //!
 //! Binary targets: 400-500 KB compressed, <4 MB uncompressed
@@ -21,24 +29,62 @@
 extern crate alloc;
 
 pub mod quantum;
+pub mod circuits;
+pub mod simd;
+pub mod density;
+pub mod openqasm;
+pub mod tomography;
 pub mod minilm;
+#[cfg(feature = "minilm-real")]
+pub mod minilm_real;
+pub mod normalize;
+pub mod intent_eval;
 pub mod dcge;
+pub mod codefmt;
+pub mod conformance;
 pub mod wasm_pod;
+#[cfg(feature = "parallel")]
+pub mod pod_executor;
 pub mod config;
 pub mod audit;
+pub mod audit_bridge;
 pub mod discovery;
+#[cfg(test)]
+mod golden;
+#[cfg(not(feature = "std"))]
+mod float;
 
 use alloc::string::String;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
+use wasm_pod::{LimitChange, PodType};
 
 // Re-exports for convenience
-pub use quantum::{MiniQuASIM, QuantumGate, QubitState};
-pub use minilm::{MiniLMQ4, StreamingInference, IntentClassifier};
-pub use dcge::{DCGEngine, GeneratedCode, SupremacyMetrics};
-pub use wasm_pod::{WasmPod, PodConfig, PodIsolation};
-pub use config::{QSubstrateConfig, MemoryConfig, RuntimeMode};
-pub use audit::{AuditLog, AuditEntry, ProvenanceRecord};
+pub use quantum::{Circuit, CircuitError, MiniQuASIM, QuantumGate, QubitState};
+#[cfg(feature = "large-sim")]
+pub use quantum::LargeQuantumState;
+pub use density::{DensityError, DensityMatrixState};
+#[cfg(feature = "parallel")]
+pub use pod_executor::{PodExecutor, PodRun, SweepResult};
+pub use openqasm::QasmError;
+pub use tomography::{DensityMatrix, FidelityReport, MeasurementBasis, TomographyResult, TomographyStats};
+pub use minilm::{
+    IndexError, IndexHit, IntentClassifier, MiniLMQ4, StreamingInference, TaggedEmbedding, VectorIndex,
+};
+#[cfg(feature = "minilm-real")]
+pub use minilm_real::{MiniLMModel, ModelConfig, ModelError, WordPieceTokenizer};
+pub use normalize::{NormalizationConfig, NormalizationTag, Transliteration};
+pub use intent_eval::{EvalReport, LabeledIntent};
+pub use dcge::{
+    ClarificationAnswer, ClarificationPolicy, ClarificationQuestion, ClarificationRequest, DCGEngine,
+    GeneratedCode, GenerationOutcome, Interpretation, SupremacyMetrics,
+};
+pub use codefmt::{FormatStyle, STYLE_VERSION};
+pub use conformance::{ConformanceCase, ConformanceScorecard};
+pub use wasm_pod::{WasmPod, PodConfig, PodIsolation, PodMemoryReport, OomDrillReport};
+pub use config::{QSubstrateConfig, MemoryConfig, NoiseConfig, RuntimeMode};
+pub use audit::{AuditLog, AuditEntry, LedgerAnchor, ProvenanceRecord};
+pub use audit_bridge::{AuditBridge, AuditSink, BridgeCadence, LedgerReference};
 pub use discovery::{Discovery, DiscoveryEngine, DiscoveryError, DiscoveryLattice};
 
 /// Q-Substrate version string
@@ -78,6 +124,9 @@ pub struct RuntimeStats {
     pub mode: RuntimeMode,
     /// Determinism verified
     pub determinism_verified: bool,
+    /// Per-pod memory watermarks
+    #[serde(default)]
+    pub pod_memory: Vec<PodMemoryReport>,
 }
 
 impl Default for RuntimeStats {
@@ -91,6 +140,7 @@ impl Default for RuntimeStats {
             peak_memory: 0,
             mode: RuntimeMode::Desktop,
             determinism_verified: true,
+            pod_memory: Vec::new(),
         }
     }
 }
@@ -125,10 +175,29 @@ impl QSubstrate {
     }
 
     /// Create a new Q-Substrate runtime with custom configuration
+    ///
+    /// # Panics
+    ///
+    /// If `large_sim` requests a register `LargeQuantumState` cannot hold;
+    /// use [`QSubstrate::try_with_config`] to handle that as an error.
     pub fn with_config(config: QSubstrateConfig) -> Self {
+        let quantum = Self::quantum_for(&config).unwrap_or_else(|e| panic!("{}", e));
+        Self::assemble(config, quantum)
+    }
+
+    /// Create a runtime after validating the configuration
+    ///
+    /// Fails instead of running with a smaller register than requested.
+    pub fn try_with_config(config: QSubstrateConfig) -> Result<Self, String> {
+        config.validate()?;
+        let quantum = Self::quantum_for(&config)?;
+        Ok(Self::assemble(config, quantum))
+    }
+
+    fn assemble(config: QSubstrateConfig, quantum: MiniQuASIM) -> Self {
         let seed = config.deterministic_seed;
         QSubstrate {
-            quantum: MiniQuASIM::new(seed),
+            quantum,
             minilm: MiniLMQ4::new(seed),
             dcge: DCGEngine::new(seed),
             pods: PodIsolation::new(&config),
@@ -142,6 +211,26 @@ impl QSubstrate {
         }
     }
 
+    /// Build the quantum simulator selected by the configuration
+    ///
+    /// `large_sim` swaps in a heap-backed register of `max_qubits`; an
+    /// out-of-range width is an error, never a silent 12-qubit fallback.
+    fn quantum_for(config: &QSubstrateConfig) -> Result<MiniQuASIM, String> {
+        #[cfg(feature = "large-sim")]
+        if config.large_sim {
+            let mut sim = LargeQuantumState::new(config.max_qubits, config.deterministic_seed)?.into_inner();
+            sim.set_noise(config.noise);
+            return Ok(sim);
+        }
+        #[cfg(not(feature = "large-sim"))]
+        if config.large_sim {
+            return Err("Large simulation requires the large-sim feature".into());
+        }
+        let mut sim = MiniQuASIM::new(config.deterministic_seed);
+        sim.set_noise(config.noise);
+        Ok(sim)
+    }
+
     /// Execute a quantum circuit and return state probabilities
     pub fn run_quantum(&mut self, gates: &[QuantumGate]) -> Vec<f32> {
         self.audit.log_operation("quantum_circuit", gates.len());
@@ -155,6 +244,20 @@ impl QSubstrate {
         self.quantum.get_probabilities()
     }
 
+    /// Run a gate sequence across `pods` independent quantum pods in parallel
+    ///
+    /// Pods take seeds derived from the runtime seed and the configured
+    /// noise model; see [`PodExecutor`].
+    #[cfg(feature = "parallel")]
+    pub fn run_quantum_sweep(&mut self, pods: usize, gates: &[QuantumGate]) -> Result<SweepResult, String> {
+        self.audit.log_operation("quantum_sweep", pods * gates.len());
+        let sweep = PodExecutor::from_config(&self.config, pods).run(gates)?;
+        let ops = (pods * gates.len()) as u64;
+        self.stats.quantum_ops += ops;
+        self.stats.total_ops += ops;
+        Ok(sweep)
+    }
+
     /// Run MiniLM inference on text input
     pub fn run_inference(&mut self, text: &str) -> Vec<f32> {
         self.audit.log_operation("ai_inference", 1);
@@ -174,23 +277,39 @@ impl QSubstrate {
     }
 
     /// Generate code using DCGE
-    pub fn generate_code(&mut self, intent: &str, language: &str) -> Result<GeneratedCode, String> {
+    ///
+    /// Ambiguous intents return `NeedsClarification`; answer the questions
+    /// with `generate_code_with_answers`.
+    pub fn generate_code(&mut self, intent: &str, language: &str) -> Result<GenerationOutcome, String> {
+        self.generate_code_with_answers(intent, language, &[])
+    }
+
+    /// Continue code generation with answers to clarification questions
+    pub fn generate_code_with_answers(
+        &mut self,
+        intent: &str,
+        language: &str,
+        answers: &[ClarificationAnswer],
+    ) -> Result<GenerationOutcome, String> {
         self.audit.log_operation("code_generation", 1);
         self.stats.dcge_ops += 1;
         self.stats.total_ops += 1;
         
-        self.dcge.generate(intent, language)
+        self.dcge.generate_with_answers(intent, language, answers)
     }
 
     /// Run supremacy test combining quantum + AI
+    ///
+    /// Returns the Bell pair's entanglement entropy in bits (1.0 when
+    /// ideal, lower under noise) and the inference result.
     pub fn supremacy_test(&mut self, input: &[u8]) -> (f32, u8) {
         self.audit.log_operation("supremacy_test", 1);
         
-        // Quantum: Bell state entropy
+        // Quantum: Bell state entanglement entropy
         self.quantum.reset();
         self.quantum.apply_gate(&QuantumGate::Hadamard(0));
         self.quantum.apply_gate(&QuantumGate::CNOT(0, 1));
-        let q_result = self.quantum.entropy();
+        let q_result = self.quantum.subsystem_entropy(&[0]).unwrap_or(0.0);
         
         // AI: Deterministic inference
         let ai_result = self.minilm.infer_bytes(input);
@@ -207,18 +326,44 @@ impl QSubstrate {
         &self.stats
     }
 
+    /// Refresh pod memory watermarks and return runtime statistics
+    pub fn collect_stats(&mut self) -> &RuntimeStats {
+        let reports = self.pods.memory_reports();
+        self.stats.memory_used = reports.iter().map(|r| r.used_bytes).sum();
+        self.stats.peak_memory = self.stats.peak_memory.max(self.stats.memory_used);
+        self.stats.pod_memory = reports;
+        &self.stats
+    }
+
+    /// Change a running pod's memory limit
+    pub fn set_pod_memory_limit(&mut self, pod_type: PodType, limit_kb: usize) -> Result<LimitChange, String> {
+        self.audit.log_operation("pod_memory_limit", limit_kb);
+        self.pods.set_memory_limit(pod_type, limit_kb)
+    }
+
+    /// Run a controlled OOM drill against a pod
+    pub fn oom_drill(&mut self, pod_type: PodType) -> Result<OomDrillReport, String> {
+        self.audit.log_operation("oom_drill", 1);
+        self.pods.oom_drill(pod_type)
+    }
+
     /// Get binary metrics for supremacy validation
     pub fn get_binary_metrics(&self) -> BinaryMetrics {
         BinaryMetrics {
             text_bytes: TEXT_TARGET,
             stack_bytes: STACK_TARGET,
             heap_bytes: 0, // No heap in quantum pod
-            quantum_state_bytes: QUANTUM_STATE_BYTES,
-            total_footprint_kb: (TEXT_TARGET + STACK_TARGET + QUANTUM_STATE_BYTES) / 1024,
+            quantum_state_bytes: self.quantum_state_bytes(),
+            total_footprint_kb: (TEXT_TARGET + STACK_TARGET + self.quantum_state_bytes()) / 1024,
             regression_status: "PASS".into(),
         }
     }
 
+    /// Bytes held by the live quantum state vector
+    fn quantum_state_bytes(&self) -> usize {
+        self.quantum.state_size() * core::mem::size_of::<quantum::Complex>()
+    }
+
     /// Reset runtime to initial state (rollback)
     pub fn reset(&mut self) {
         self.quantum.reset();
@@ -379,4 +524,35 @@ mod tests {
         assert!(metrics.stack_bytes <= STACK_TARGET);
         assert_eq!(metrics.heap_bytes, 0);
     }
+
+    #[test]
+    fn test_pod_memory_stats() {
+        let mut qs = QSubstrate::new();
+        qs.pods.get_pod_mut(PodType::Quantum).allocate(2048).unwrap();
+        assert_eq!(qs.set_pod_memory_limit(PodType::Quantum, 1), Ok(LimitChange::Deferred));
+        assert!(qs.oom_drill(PodType::Quantum).unwrap().rolled_back);
+
+        let stats = qs.collect_stats();
+        assert_eq!(stats.pod_memory.len(), 3);
+        assert_eq!(stats.memory_used, 2048);
+        assert_eq!(stats.pod_memory[1].pending_limit_bytes, Some(1024));
+    }
+
+    #[test]
+    fn test_large_sim_never_falls_back() {
+        let mut config = QSubstrateConfig::default();
+        config.large_sim = true;
+        config.max_qubits = 25;
+        config.memory.quantum_pod_limit_kb = 512 * 1024;
+        config.memory.total_limit_mb = 1024;
+        assert!(QSubstrate::try_with_config(config.clone()).is_err());
+
+        config.max_qubits = 14;
+        if cfg!(feature = "large-sim") {
+            let qs = QSubstrate::try_with_config(config).unwrap();
+            assert_eq!(qs.quantum.num_qubits(), 14);
+        } else {
+            assert!(QSubstrate::try_with_config(config).is_err());
+        }
+    }
 }
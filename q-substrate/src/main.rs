@@ -4,7 +4,7 @@
 //! Target: sub-500 KB compressed binary
 
 use q_substrate::{
-    QSubstrate, QSubstrateConfig, RuntimeMode,
+    QSubstrate, QSubstrateConfig, RuntimeMode, GenerationOutcome,
     QuantumGate, get_failure_modes,
 };
 
@@ -85,7 +85,7 @@ fn main() {
     // DCGE demo
     println!("💻 DCGE Code Generation Demo:");
     match qs.generate_code("create fibonacci function", "rust") {
-        Ok(code) => {
+        Ok(GenerationOutcome::Generated(code)) => {
             println!("   Language: {:?}", code.language);
             println!("   Validated: {}", code.validated);
             println!("   Size: {} bytes", code.size_estimate);
@@ -95,6 +95,12 @@ fn main() {
                 println!("      {}", line);
             }
         }
+        Ok(GenerationOutcome::NeedsClarification(request)) => {
+            println!("   Needs clarification ({:.0}% confidence):", request.confidence * 100.0);
+            for question in &request.questions {
+                println!("      - {}", question.prompt);
+            }
+        }
         Err(e) => println!("   Error: {}", e),
     }
     println!();
@@ -102,7 +108,7 @@ fn main() {
     // Supremacy test
     println!("🏆 Supremacy Test (Quantum + AI Combined):");
     let (q_result, ai_result) = qs.supremacy_test(&[42, 43, 44]);
-    println!("   Quantum entropy: {:.6}", q_result);
+    println!("   Entanglement entropy: {:.6} bits", q_result);
     println!("   AI inference: {}", ai_result);
     println!();
 
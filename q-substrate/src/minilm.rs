@@ -6,16 +6,29 @@
 //! - Streaming computation (max 20KB active)
 //! - Pod-isolated deterministic execution
 //! - Intent classification for DCGE
+//! - Unicode normalization before tokenization and embedding
+//! - Vector index with deterministic top-k cosine retrieval
 //!
 //! Memory footprint: ~8MB model, ~20KB active during inference
+//!
+//! Embeddings are a deterministic hash-based stub unless real weights are
+//! loaded with `load_model` (`minilm-real` feature, see `minilm_real`).
 
 extern crate alloc;
 
+use alloc::format;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
+use crate::normalize::{NormalizationConfig, NormalizationTag};
+#[cfg(feature = "minilm-real")]
+use crate::minilm_real::{MiniLMModel, ModelError, DEFAULT_ACTIVE_BUDGET};
+#[cfg(not(feature = "std"))]
+use crate::float::FloatExt;
+
 /// MiniLM embedding dimension
 pub const EMBEDDING_DIM: usize = 384;
 
@@ -28,6 +41,15 @@ pub const MAX_ACTIVE_MEMORY: usize = 20 * 1024;
 /// Vocabulary hash for deterministic embedding
 pub const VOCAB_HASH_SEED: u64 = 0xDEAD_BEEF_CAFE_BABE;
 
+/// Intent labels indexed by intent code
+pub const INTENT_LABELS: [&str; 5] = [
+    "quantum_operation",
+    "code_generation",
+    "system_query",
+    "data_processing",
+    "general",
+];
+
 /// Intent classification result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntentClassifier {
@@ -43,6 +65,25 @@ pub struct IntentClassifier {
     pub secondary_intents: Vec<(String, f32)>,
 }
 
+/// Embedding with the normalization it was produced under
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaggedEmbedding {
+    /// Unit-length embedding vector
+    pub vector: Vec<f32>,
+    /// Normalization applied to the input text
+    pub normalization: NormalizationTag,
+}
+
+impl TaggedEmbedding {
+    /// Cosine similarity, or `None` if the normalizations differ
+    pub fn similarity(&self, other: &TaggedEmbedding) -> Option<f32> {
+        if self.normalization != other.normalization {
+            return None;
+        }
+        Some(MiniLMQ4::cosine_similarity(&self.vector, &other.vector))
+    }
+}
+
 /// Streaming inference state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreamingInference {
@@ -82,6 +123,11 @@ pub struct MiniLMQ4 {
     streaming_state: StreamingInference,
     /// Operation counter
     op_count: u64,
+    /// Text normalization applied before embedding
+    normalization: NormalizationConfig,
+    /// Real encoder replacing the hash stub once loaded
+    #[cfg(feature = "minilm-real")]
+    model: Option<MiniLMModel>,
 }
 
 impl MiniLMQ4 {
@@ -93,9 +139,38 @@ impl MiniLMQ4 {
             vocab_hash: VOCAB_HASH_SEED,
             streaming_state: StreamingInference::default(),
             op_count: 0,
+            normalization: NormalizationConfig::default(),
+            #[cfg(feature = "minilm-real")]
+            model: None,
         }
     }
 
+    /// Load Q4 MiniLM weights and vocabulary; `embed` then runs the real
+    /// encoder instead of the hash stub
+    #[cfg(feature = "minilm-real")]
+    pub fn load_model(&mut self, weights: &[u8], vocab: &str) -> Result<(), ModelError> {
+        let model = MiniLMModel::load(weights, vocab)?;
+        self.embedding_dim = model.config().hidden;
+        self.model = Some(model);
+        Ok(())
+    }
+
+    /// Whether real weights are loaded
+    #[cfg(feature = "minilm-real")]
+    pub fn has_model(&self) -> bool {
+        self.model.is_some()
+    }
+
+    /// Replace the normalization configuration
+    pub fn set_normalization(&mut self, config: NormalizationConfig) {
+        self.normalization = config;
+    }
+
+    /// Tag of the active normalization
+    pub fn normalization_tag(&self) -> NormalizationTag {
+        self.normalization.tag()
+    }
+
     /// Reset to initial state
     pub fn reset(&mut self, seed: u32) {
         self.seed = seed;
@@ -110,9 +185,20 @@ impl MiniLMQ4 {
         ((self.seed >> 16) & 0x7FFF) as f32 / 32767.0
     }
 
+    /// Generate embedding tagged with the active normalization
+    pub fn embed_tagged(&mut self, text: &str) -> TaggedEmbedding {
+        TaggedEmbedding {
+            vector: self.embed(text),
+            normalization: self.normalization.tag(),
+        }
+    }
+
     /// Generate deterministic embedding for text input
+    ///
+    /// Input is normalized first (see `normalize`).
     pub fn embed(&mut self, text: &str) -> Vec<f32> {
         self.op_count += 1;
+        let text = self.normalization.normalize(text);
         
         // Streaming: process in chunks to stay under memory limit
         self.streaming_state = StreamingInference {
@@ -123,6 +209,21 @@ impl MiniLMQ4 {
             is_complete: false,
         };
 
+        #[cfg(feature = "minilm-real")]
+        if let Some(model) = &self.model {
+            // The default budget always fits [CLS] [SEP] for valid configs
+            if let Ok(output) = model.embed(&text, DEFAULT_ACTIVE_BUDGET) {
+                self.streaming_state = StreamingInference {
+                    current_layer: model.config().layers - 1,
+                    total_layers: model.config().layers,
+                    tokens_processed: output.tokens,
+                    memory_used: 0,
+                    is_complete: true,
+                };
+                return output.embedding;
+            }
+        }
+
         let mut embedding = vec![0.0_f32; self.embedding_dim];
         
         // Hash-based deterministic embedding generation
@@ -167,29 +268,16 @@ impl MiniLMQ4 {
         // Deterministic classification based on embedding
         let sum: f32 = embedding.iter().take(10).sum();
         let code = (((sum.abs() * 1000.0) as u32) % 5) as u8;
-        
-        let label = match code {
-            0 => "quantum_operation",
-            1 => "code_generation",
-            2 => "system_query",
-            3 => "data_processing",
-            _ => "general",
-        };
+        let label = INTENT_LABELS[code as usize];
         
         let confidence = 0.85 + self.next_rand() * 0.1;
-        let token_count = text.split_whitespace().count();
+        let token_count = self.normalization.normalize(text).split_whitespace().count();
         
         // Generate secondary intents
         let mut secondary = Vec::new();
         for i in 1..=3 {
             let sec_code = (code + i) % 5;
-            let sec_label = match sec_code {
-                0 => "quantum_operation",
-                1 => "code_generation",
-                2 => "system_query",
-                3 => "data_processing",
-                _ => "general",
-            };
+            let sec_label = INTENT_LABELS[sec_code as usize];
             secondary.push((sec_label.into(), 0.5 + self.next_rand() * 0.3));
         }
         
@@ -202,6 +290,48 @@ impl MiniLMQ4 {
         }
     }
 
+    /// Embed without advancing the seed, so equal texts always yield the
+    /// same vector (for building and querying a `VectorIndex`)
+    pub fn embed_stable(&mut self, text: &str) -> TaggedEmbedding {
+        let seed = self.seed;
+        let embedding = self.embed_tagged(text);
+        self.seed = seed;
+        embedding
+    }
+
+    /// Classify text by nearest labeled exemplars in `index`
+    ///
+    /// Entry names are intent labels; each label scores its best exemplar.
+    /// Labels outside `INTENT_LABELS` map to `general`. Fails on an empty
+    /// index or one built under a different normalization.
+    pub fn classify_semantic(&mut self, text: &str, index: &VectorIndex) -> Result<IntentClassifier, IndexError> {
+        self.op_count += 1;
+        let query = self.embed_stable(text);
+        let mut labels: Vec<IndexHit> = Vec::new();
+        for hit in index.search(&query, index.len())? {
+            if !labels.iter().any(|l| l.name == hit.name) {
+                labels.push(hit);
+            }
+        }
+        let best = labels.first().ok_or(IndexError::Empty)?;
+        let code = INTENT_LABELS
+            .iter()
+            .position(|l| *l == best.name)
+            .unwrap_or(INTENT_LABELS.len() - 1) as u8;
+
+        Ok(IntentClassifier {
+            intent_code: code,
+            intent_label: INTENT_LABELS[code as usize].into(),
+            confidence: best.score.clamp(0.0, 1.0),
+            token_count: self.normalization.normalize(text).split_whitespace().count(),
+            secondary_intents: labels[1..]
+                .iter()
+                .take(3)
+                .map(|l| (l.name.clone(), l.score.clamp(0.0, 1.0)))
+                .collect(),
+        })
+    }
+
     /// Run byte-level inference (for compatibility)
     pub fn infer_bytes(&mut self, input: &[u8]) -> u8 {
         self.op_count += 1;
@@ -287,6 +417,177 @@ pub struct CommandAnalysis {
     pub suggested_action: String,
 }
 
+/// Vector index errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexError {
+    /// Vector length differs from the index dimension
+    DimensionMismatch { expected: usize, found: usize },
+    /// Embedding was produced under a different normalization
+    NormalizationMismatch,
+    /// Index holds no entries
+    Empty,
+    /// JSON encoding or decoding failed
+    Serialization(String),
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexError::DimensionMismatch { expected, found } => {
+                write!(f, "Expected {}-dimensional embedding, got {}", expected, found)
+            }
+            IndexError::NormalizationMismatch => write!(f, "Embedding normalization differs from the index"),
+            IndexError::Empty => write!(f, "Vector index is empty"),
+            IndexError::Serialization(message) => write!(f, "Vector index serialization failed: {}", message),
+        }
+    }
+}
+
+/// Named embedding stored in a `VectorIndex`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// Entry name (e.g. an intent label or template id)
+    pub name: String,
+    /// Unit-length embedding
+    pub vector: Vec<f32>,
+}
+
+/// Retrieval result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexHit {
+    /// Entry name
+    pub name: String,
+    /// Position of the entry in insertion order
+    pub position: usize,
+    /// Cosine similarity to the query
+    pub score: f32,
+}
+
+/// Similarity index over named embeddings
+///
+/// Entries are appended incrementally and may share a name (several
+/// exemplars per label). Search is exhaustive cosine similarity; ties keep
+/// insertion order, so results are deterministic.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorIndex {
+    /// Embedding dimension
+    dim: usize,
+    /// Normalization every entry was produced under
+    normalization: NormalizationTag,
+    /// Entries in insertion order
+    entries: Vec<IndexEntry>,
+}
+
+impl VectorIndex {
+    /// Create an empty index
+    pub fn new(dim: usize, normalization: NormalizationTag) -> Self {
+        VectorIndex {
+            dim,
+            normalization,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Empty index matching a model's dimension and normalization
+    pub fn for_model(model: &MiniLMQ4) -> Self {
+        Self::new(model.embedding_dim, model.normalization_tag())
+    }
+
+    /// Embedding dimension
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Normalization of stored embeddings
+    pub fn normalization(&self) -> NormalizationTag {
+        self.normalization
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries in insertion order
+    pub fn entries(&self) -> &[IndexEntry] {
+        &self.entries
+    }
+
+    /// Append a named embedding (normalized to unit length)
+    pub fn insert(&mut self, name: &str, embedding: &TaggedEmbedding) -> Result<(), IndexError> {
+        self.check(embedding)?;
+        let norm = embedding.vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let vector = if norm > 1e-10 {
+            embedding.vector.iter().map(|x| x / norm).collect()
+        } else {
+            embedding.vector.clone()
+        };
+        self.entries.push(IndexEntry { name: name.into(), vector });
+        Ok(())
+    }
+
+    /// Embed `text` with `model` and append it under `name`
+    pub fn insert_text(&mut self, model: &mut MiniLMQ4, name: &str, text: &str) -> Result<(), IndexError> {
+        let embedding = model.embed_stable(text);
+        self.insert(name, &embedding)
+    }
+
+    /// Up to `k` most similar entries, best first
+    pub fn search(&self, query: &TaggedEmbedding, k: usize) -> Result<Vec<IndexHit>, IndexError> {
+        self.check(query)?;
+        let mut hits: Vec<IndexHit> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(position, entry)| IndexHit {
+                name: entry.name.clone(),
+                position,
+                score: MiniLMQ4::cosine_similarity(&query.vector, &entry.vector),
+            })
+            .collect();
+        // Stable sort: equal scores stay in insertion order
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(k);
+        Ok(hits)
+    }
+
+    /// Serialize to JSON
+    pub fn to_json(&self) -> Result<String, IndexError> {
+        serde_json::to_string(self).map_err(|e| IndexError::Serialization(format!("{}", e)))
+    }
+
+    /// Restore from JSON produced by `to_json`
+    pub fn from_json(json: &str) -> Result<Self, IndexError> {
+        let index: VectorIndex =
+            serde_json::from_str(json).map_err(|e| IndexError::Serialization(format!("{}", e)))?;
+        if let Some(entry) = index.entries.iter().find(|e| e.vector.len() != index.dim) {
+            return Err(IndexError::DimensionMismatch {
+                expected: index.dim,
+                found: entry.vector.len(),
+            });
+        }
+        Ok(index)
+    }
+
+    fn check(&self, embedding: &TaggedEmbedding) -> Result<(), IndexError> {
+        if embedding.normalization != self.normalization {
+            return Err(IndexError::NormalizationMismatch);
+        }
+        if embedding.vector.len() != self.dim {
+            return Err(IndexError::DimensionMismatch {
+                expected: self.dim,
+                found: embedding.vector.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
 /// Q4 Quantization utilities
 pub mod q4 {
     /// Quantize f32 to 4-bit representation
@@ -368,6 +669,22 @@ mod tests {
         assert_eq!(state.total_layers, 6);
     }
 
+    #[test]
+    fn test_normalized_embedding_stable() {
+        let mut mlm = MiniLMQ4::new(42);
+        let composed = mlm.embed_tagged("Caf\u{e9}  Qubit");
+        mlm.reset(42);
+        let decomposed = mlm.embed_tagged("CAFE\u{301} qubit");
+        assert!((composed.similarity(&decomposed).unwrap() - 1.0).abs() < 1e-6);
+
+        let mut other = MiniLMQ4::new(42);
+        other.set_normalization(NormalizationConfig {
+            case_fold: false,
+            ..Default::default()
+        });
+        assert!(composed.similarity(&other.embed_tagged("Caf\u{e9} Qubit")).is_none());
+    }
+
     #[test]
     fn test_cosine_similarity() {
         let a = vec![1.0, 0.0, 0.0];
@@ -381,6 +698,54 @@ mod tests {
         assert!(sim_orth.abs() < 1e-6);
     }
 
+    #[test]
+    fn test_vector_index_top_k() {
+        let mut mlm = MiniLMQ4::new(42);
+        let mut index = VectorIndex::for_model(&mlm);
+        for (name, text) in [("a", "alpha"), ("b", "beta"), ("c", "gamma"), ("b", "alpha")] {
+            index.insert_text(&mut mlm, name, text).unwrap();
+        }
+
+        let hits = index.search(&mlm.embed_stable("ALPHA"), 3).unwrap();
+        assert_eq!(hits.len(), 3);
+        // Identical embeddings tie at 1.0 and keep insertion order
+        assert_eq!((hits[0].name.as_str(), hits[0].position), ("a", 0));
+        assert_eq!((hits[1].name.as_str(), hits[1].position), ("b", 3));
+        assert!((hits[1].score - 1.0).abs() < 1e-6);
+        assert!(hits[2].score < hits[1].score);
+
+        let restored = VectorIndex::from_json(&index.to_json().unwrap()).unwrap();
+        assert_eq!(restored, index);
+        assert_eq!(restored.search(&mlm.embed_stable("alpha"), 3).unwrap(), hits);
+
+        let mut raw = MiniLMQ4::new(42);
+        raw.set_normalization(NormalizationConfig {
+            case_fold: false,
+            ..Default::default()
+        });
+        assert_eq!(index.search(&raw.embed_stable("alpha"), 1), Err(IndexError::NormalizationMismatch));
+        assert!(matches!(VectorIndex::from_json("{}"), Err(IndexError::Serialization(_))));
+    }
+
+    #[test]
+    fn test_semantic_classification() {
+        let mut mlm = MiniLMQ4::new(42);
+        let mut index = VectorIndex::for_model(&mlm);
+        assert_eq!(mlm.classify_semantic("anything", &index).unwrap_err(), IndexError::Empty);
+
+        index.insert_text(&mut mlm, "quantum_operation", "run quantum simulation").unwrap();
+        index.insert_text(&mut mlm, "system_query", "show memory usage").unwrap();
+        index.insert_text(&mut mlm, "unlisted", "open the pod bay doors").unwrap();
+
+        let intent = mlm.classify_semantic("Show  memory usage", &index).unwrap();
+        assert_eq!((intent.intent_code, intent.intent_label.as_str()), (2, "system_query"));
+        assert!((intent.confidence - 1.0).abs() < 1e-6);
+        assert_eq!(intent.secondary_intents.len(), 2);
+
+        let other = mlm.classify_semantic("open the pod bay doors", &index).unwrap();
+        assert_eq!(other.intent_label, "general");
+    }
+
     #[test]
     fn test_q4_quantization() {
         let value = 0.5_f32;
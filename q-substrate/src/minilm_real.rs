@@ -0,0 +1,878 @@
+//! Real MiniLM-L6-v2 Inference
+//!
+//! Loads quantized MiniLM-L6-v2 weights and runs the actual BERT encoder in
+//! place of the hash-based stub: WordPiece tokenization, word + position +
+//! token-type embeddings, post-LayerNorm transformer layers with multi-head
+//! self-attention and GELU feed-forward blocks, then mean pooling and L2
+//! normalization (the sentence-transformers `all-MiniLM-L6-v2` output).
+//!
+//! Weights use a custom Q4 container, little-endian:
+//!
+//! ```text
+//! magic "QSMLMQ4\0" | version u32
+//! vocab hidden layers heads intermediate max_positions type_vocab   (u32 each)
+//! layer_norm_eps f32 | tensors
+//! ```
+//!
+//! Matrices are stored `[out, in]` row-major in blocks of [`Q4_BLOCK`]
+//! weights: an f32 scale, then 16 bytes of nibbles packed with [`q4::pack`],
+//! each weight being `q4::dequantize(nibble, scale)`. Vectors (biases,
+//! LayerNorm γ/β) are raw f32. Tensor order: word, position and token-type
+//! embeddings, embedding LayerNorm γ/β; then per layer query, key, value and
+//! attention output (weight, bias each), attention LayerNorm γ/β,
+//! intermediate and output (weight, bias each), output LayerNorm γ/β. An
+//! ONNX or PyTorch export converts by quantizing its tensors in this order.
+//!
+//! Weights stay packed and rows are dequantized on the fly. Keys and values
+//! are the only whole-sequence activations; queries, attention and the
+//! feed-forward block run one token at a time, so a memory budget caps the
+//! token count (see [`ModelConfig::max_tokens`]). All math goes through
+//! `libm`, so std and no_std builds produce identical embeddings.
+//!
+//! Requires the `minilm-real` feature.
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::minilm::q4;
+
+/// Weight container magic
+pub const MODEL_MAGIC: [u8; 8] = *b"QSMLMQ4\0";
+
+/// Weight container format version
+pub const MODEL_FORMAT_VERSION: u32 = 1;
+
+/// Weights per quantization block (one f32 scale each)
+pub const Q4_BLOCK: usize = 32;
+
+/// Default activation budget in bytes (≈225 tokens at MiniLM-L6 size)
+pub const DEFAULT_ACTIVE_BUDGET: usize = 1024 * 1024;
+
+/// Longer words become `[UNK]`, as in BERT
+const MAX_WORD_CHARS: usize = 100;
+
+/// Model loading and inference errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModelError {
+    /// Weight file does not start with [`MODEL_MAGIC`]
+    BadMagic,
+    /// Weight file uses an unknown format version
+    UnsupportedVersion(u32),
+    /// Weight file ends early, at the given byte offset
+    Truncated(usize),
+    /// Weight file has bytes after the last tensor
+    TrailingBytes(usize),
+    /// Header dimensions are inconsistent
+    InvalidConfig(String),
+    /// Vocabulary lacks a required special token
+    MissingToken(String),
+    /// Activation budget cannot hold even `[CLS] [SEP]`
+    BudgetTooSmall(usize),
+}
+
+impl fmt::Display for ModelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelError::BadMagic => write!(f, "Not a Q4 MiniLM weight file"),
+            ModelError::UnsupportedVersion(v) => write!(f, "Unsupported weight format version {}", v),
+            ModelError::Truncated(offset) => write!(f, "Weight file truncated at byte {}", offset),
+            ModelError::TrailingBytes(n) => write!(f, "{} trailing bytes after weights", n),
+            ModelError::InvalidConfig(message) => write!(f, "Invalid model config: {}", message),
+            ModelError::MissingToken(token) => write!(f, "Vocabulary has no {} token", token),
+            ModelError::BudgetTooSmall(bytes) => {
+                write!(f, "Activation budget of {} bytes is too small", bytes)
+            }
+        }
+    }
+}
+
+/// Encoder dimensions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelConfig {
+    /// Vocabulary size
+    pub vocab_size: usize,
+    /// Hidden size
+    pub hidden: usize,
+    /// Transformer layers
+    pub layers: usize,
+    /// Attention heads
+    pub heads: usize,
+    /// Feed-forward inner size
+    pub intermediate: usize,
+    /// Maximum sequence length
+    pub max_positions: usize,
+    /// Token-type (segment) vocabulary size
+    pub type_vocab: usize,
+    /// LayerNorm epsilon
+    pub layer_norm_eps: f32,
+}
+
+impl ModelConfig {
+    /// MiniLM-L6-v2 (uncased BERT vocabulary)
+    pub fn minilm_l6() -> Self {
+        ModelConfig {
+            vocab_size: 30522,
+            hidden: 384,
+            layers: 6,
+            heads: 12,
+            intermediate: 1536,
+            max_positions: 512,
+            type_vocab: 2,
+            layer_norm_eps: 1e-12,
+        }
+    }
+
+    /// Activation bytes for a sequence of `tokens`
+    pub fn active_bytes(&self, tokens: usize) -> usize {
+        // Keys, values and hidden states per token; query, context, scratch,
+        // scores and the feed-forward row once
+        4 * (3 * tokens * self.hidden + tokens + 3 * self.hidden + self.intermediate)
+    }
+
+    /// Most tokens (including `[CLS]`/`[SEP]`) whose activations fit `budget`
+    pub fn max_tokens(&self, budget: usize) -> usize {
+        let fixed = self.active_bytes(0);
+        if budget < fixed {
+            return 0;
+        }
+        ((budget - fixed) / (4 * (3 * self.hidden + 1))).min(self.max_positions)
+    }
+
+    fn validate(&self) -> Result<(), ModelError> {
+        let problem = if self.vocab_size == 0 || self.layers == 0 || self.type_vocab == 0 {
+            Some("empty vocabulary, layer stack or token types")
+        } else if self.max_positions < 2 {
+            Some("fewer than 2 positions")
+        } else if self.heads == 0 || !self.hidden.is_multiple_of(self.heads) {
+            Some("hidden size not divisible by heads")
+        } else if self.hidden == 0 || !self.hidden.is_multiple_of(Q4_BLOCK) || !self.intermediate.is_multiple_of(Q4_BLOCK) {
+            Some("hidden and intermediate sizes must be non-zero multiples of 32")
+        } else {
+            None
+        };
+        match problem {
+            Some(message) => Err(ModelError::InvalidConfig(message.into())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Block-quantized Q4 matrix
+#[derive(Debug, Clone, PartialEq)]
+pub struct Q4Tensor {
+    rows: usize,
+    cols: usize,
+    /// One scale per block, row-major
+    scales: Vec<f32>,
+    /// Two weights per byte, row-major
+    packed: Vec<u8>,
+}
+
+impl Q4Tensor {
+    /// Quantize a row-major `rows × cols` matrix
+    ///
+    /// Each block is scaled by its largest magnitude / 7 and rounded to the
+    /// nearest level. `cols` must be a multiple of [`Q4_BLOCK`].
+    pub fn quantize(rows: usize, cols: usize, values: &[f32]) -> Result<Self, ModelError> {
+        if !cols.is_multiple_of(Q4_BLOCK) || values.len() != rows * cols {
+            return Err(ModelError::InvalidConfig(format!(
+                "{} values for a {}x{} Q4 tensor",
+                values.len(),
+                rows,
+                cols
+            )));
+        }
+        let mut scales = Vec::with_capacity(values.len() / Q4_BLOCK);
+        let mut packed = Vec::with_capacity(values.len() / 2);
+        for block in values.chunks(Q4_BLOCK) {
+            let scale = block.iter().fold(0.0_f32, |m, v| m.max(v.abs())) / 7.0;
+            scales.push(scale);
+            let level = |v: f32| if scale > 0.0 { q4::quantize(v + scale * 0.5, scale) } else { 8 };
+            for pair in block.chunks(2) {
+                packed.push(q4::pack(level(pair[0]), level(pair[1])));
+            }
+        }
+        Ok(Q4Tensor { rows, cols, scales, packed })
+    }
+
+    /// Rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Dequantize one row into `out`
+    pub fn dequantize_row(&self, row: usize, out: &mut [f32]) {
+        let blocks = self.cols / Q4_BLOCK;
+        for (b, chunk) in out[..self.cols].chunks_mut(Q4_BLOCK).enumerate() {
+            let scale = self.scales[row * blocks + b];
+            let base = (row * self.cols + b * Q4_BLOCK) / 2;
+            for (k, pair) in chunk.chunks_mut(2).enumerate() {
+                let (lo, hi) = q4::unpack(self.packed[base + k]);
+                pair[0] = q4::dequantize(lo, scale);
+                pair[1] = q4::dequantize(hi, scale);
+            }
+        }
+    }
+
+    /// `out = W·x + bias`
+    fn matvec(&self, x: &[f32], bias: &[f32], out: &mut [f32]) {
+        let blocks = self.cols / Q4_BLOCK;
+        for (row, (o, b)) in out.iter_mut().zip(bias).enumerate() {
+            let mut sum = 0.0_f32;
+            for block in 0..blocks {
+                // Σ (q - 8)·x per block, scaled once
+                let base = (row * self.cols + block * Q4_BLOCK) / 2;
+                let xs = &x[block * Q4_BLOCK..(block + 1) * Q4_BLOCK];
+                let mut acc = 0.0_f32;
+                for (k, pair) in xs.chunks(2).enumerate() {
+                    let (lo, hi) = q4::unpack(self.packed[base + k]);
+                    acc += (lo as f32 - 8.0) * pair[0] + (hi as f32 - 8.0) * pair[1];
+                }
+                sum += acc * self.scales[row * blocks + block];
+            }
+            *o = sum + b;
+        }
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        let half_block = Q4_BLOCK / 2;
+        for (scale, nibbles) in self.scales.iter().zip(self.packed.chunks(half_block)) {
+            out.extend_from_slice(&scale.to_le_bytes());
+            out.extend_from_slice(nibbles);
+        }
+    }
+}
+
+/// Linear layer
+#[derive(Debug, Clone, PartialEq)]
+struct Linear {
+    weight: Q4Tensor,
+    bias: Vec<f32>,
+}
+
+impl Linear {
+    fn apply(&self, x: &[f32], out: &mut [f32]) {
+        self.weight.matvec(x, &self.bias, out);
+    }
+}
+
+/// LayerNorm parameters
+#[derive(Debug, Clone, PartialEq)]
+struct Norm {
+    gamma: Vec<f32>,
+    beta: Vec<f32>,
+}
+
+impl Norm {
+    fn apply(&self, x: &mut [f32], eps: f32) {
+        let n = x.len() as f32;
+        let mean = x.iter().sum::<f32>() / n;
+        let var = x.iter().map(|v| (v - mean) * (v - mean)).sum::<f32>() / n;
+        let inv = 1.0 / libm::sqrtf(var + eps);
+        for ((v, g), b) in x.iter_mut().zip(&self.gamma).zip(&self.beta) {
+            *v = (*v - mean) * inv * g + b;
+        }
+    }
+}
+
+/// One transformer layer
+#[derive(Debug, Clone, PartialEq)]
+struct Layer {
+    query: Linear,
+    key: Linear,
+    value: Linear,
+    attention_output: Linear,
+    attention_norm: Norm,
+    intermediate: Linear,
+    output: Linear,
+    output_norm: Norm,
+}
+
+/// BERT-style WordPiece tokenizer
+///
+/// Basic tokenization drops control characters, splits on whitespace,
+/// ASCII/General/CJK punctuation and around CJK ideographs, and (uncased)
+/// lowercases and strips accents; words then split greedily into the
+/// longest vocabulary pieces, continuations prefixed `##`.
+#[derive(Debug, Clone)]
+pub struct WordPieceTokenizer {
+    ids: BTreeMap<String, u32>,
+    tokens: Vec<String>,
+    lowercase: bool,
+    cls: u32,
+    sep: u32,
+    unk: u32,
+}
+
+impl WordPieceTokenizer {
+    /// Load a `vocab.txt` (one token per line, id = line number)
+    pub fn from_vocab(vocab: &str, lowercase: bool) -> Result<Self, ModelError> {
+        let tokens: Vec<String> = vocab.lines().map(|line| line.trim_end_matches('\r').to_string()).collect();
+        let mut ids = BTreeMap::new();
+        for (id, token) in tokens.iter().enumerate() {
+            ids.entry(token.clone()).or_insert(id as u32);
+        }
+        let special = |name: &str| ids.get(name).copied().ok_or_else(|| ModelError::MissingToken(name.into()));
+        Ok(WordPieceTokenizer {
+            cls: special("[CLS]")?,
+            sep: special("[SEP]")?,
+            unk: special("[UNK]")?,
+            ids,
+            tokens,
+            lowercase,
+        })
+    }
+
+    /// Vocabulary size
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Whether the vocabulary is empty (never, once loaded)
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Id of a vocabulary token
+    pub fn token_id(&self, token: &str) -> Option<u32> {
+        self.ids.get(token).copied()
+    }
+
+    /// Word pieces of `text`, without special tokens
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        self.piece_ids(text).into_iter().map(|id| self.tokens[id as usize].clone()).collect()
+    }
+
+    /// `[CLS] pieces [SEP]`, truncated to `max_len` ids (at least 2)
+    pub fn encode(&self, text: &str, max_len: usize) -> Vec<u32> {
+        let mut ids = vec![self.cls];
+        ids.extend(self.piece_ids(text).into_iter().take(max_len.max(2) - 2));
+        ids.push(self.sep);
+        ids
+    }
+
+    fn piece_ids(&self, text: &str) -> Vec<u32> {
+        let mut ids = Vec::new();
+        for word in self.basic_tokens(text) {
+            self.word_pieces(&word, &mut ids);
+        }
+        ids
+    }
+
+    fn basic_tokens(&self, text: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut current = String::new();
+        let flush = |current: &mut String, words: &mut Vec<String>| {
+            if current.is_empty() {
+                return;
+            }
+            let word = if self.lowercase {
+                current.to_lowercase().nfd().filter(|c| !is_combining_mark(*c)).collect()
+            } else {
+                current.clone()
+            };
+            if !word.is_empty() {
+                words.push(word);
+            }
+            current.clear();
+        };
+
+        for c in text.chars() {
+            if c == '\0' || c == '\u{fffd}' || (c.is_control() && !c.is_whitespace()) {
+                continue;
+            }
+            if c.is_whitespace() {
+                flush(&mut current, &mut words);
+            } else if is_cjk(c) || is_punctuation(c) {
+                flush(&mut current, &mut words);
+                words.push(c.to_string());
+            } else {
+                current.push(c);
+            }
+        }
+        flush(&mut current, &mut words);
+        words
+    }
+
+    /// Greedy longest-match-first; an unsplittable word is one `[UNK]`
+    fn word_pieces(&self, word: &str, ids: &mut Vec<u32>) {
+        let boundaries: Vec<usize> = word.char_indices().map(|(i, _)| i).chain([word.len()]).collect();
+        if boundaries.len() - 1 > MAX_WORD_CHARS {
+            ids.push(self.unk);
+            return;
+        }
+
+        let mut pieces = Vec::new();
+        let mut start = 0;
+        while start < boundaries.len() - 1 {
+            let found = (start + 1..boundaries.len()).rev().find_map(|end| {
+                let piece = &word[boundaries[start]..boundaries[end]];
+                let id = if start == 0 {
+                    self.ids.get(piece)
+                } else {
+                    self.ids.get(&format!("##{}", piece))
+                };
+                id.map(|&id| (id, end))
+            });
+            match found {
+                Some((id, end)) => {
+                    pieces.push(id);
+                    start = end;
+                }
+                None => {
+                    ids.push(self.unk);
+                    return;
+                }
+            }
+        }
+        ids.extend(pieces);
+    }
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x4E00..=0x9FFF | 0x3400..=0x4DBF | 0x20000..=0x2A6DF | 0x2A700..=0x2B73F
+        | 0x2B740..=0x2B81F | 0x2B820..=0x2CEAF | 0xF900..=0xFAFF | 0x2F800..=0x2FA1F)
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(c as u32, 0x2010..=0x2027 | 0x2030..=0x205E | 0x3001..=0x3003 | 0x3008..=0x3011 | 0xFF01..=0xFF0F)
+}
+
+/// Result of one forward pass
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelOutput {
+    /// Unit-length mean-pooled embedding
+    pub embedding: Vec<f32>,
+    /// Tokens encoded, including `[CLS]`/`[SEP]`
+    pub tokens: usize,
+    /// Whether the input was cut to fit the budget or position limit
+    pub truncated: bool,
+    /// Activation bytes used
+    pub active_bytes: usize,
+}
+
+/// Quantized MiniLM encoder with its tokenizer
+#[derive(Debug, Clone)]
+pub struct MiniLMModel {
+    config: ModelConfig,
+    tokenizer: WordPieceTokenizer,
+    word: Q4Tensor,
+    position: Q4Tensor,
+    token_type: Q4Tensor,
+    embedding_norm: Norm,
+    layers: Vec<Layer>,
+}
+
+impl MiniLMModel {
+    /// Load Q4 weights and an uncased `vocab.txt`
+    pub fn load(weights: &[u8], vocab: &str) -> Result<Self, ModelError> {
+        let mut reader = Reader { bytes: weights, offset: 0 };
+        if reader.take(8)? != MODEL_MAGIC {
+            return Err(ModelError::BadMagic);
+        }
+        let version = reader.u32()?;
+        if version != MODEL_FORMAT_VERSION {
+            return Err(ModelError::UnsupportedVersion(version));
+        }
+        let config = ModelConfig {
+            vocab_size: reader.u32()? as usize,
+            hidden: reader.u32()? as usize,
+            layers: reader.u32()? as usize,
+            heads: reader.u32()? as usize,
+            intermediate: reader.u32()? as usize,
+            max_positions: reader.u32()? as usize,
+            type_vocab: reader.u32()? as usize,
+            layer_norm_eps: reader.f32()?,
+        };
+        config.validate()?;
+
+        let tokenizer = WordPieceTokenizer::from_vocab(vocab, true)?;
+        if tokenizer.len() != config.vocab_size {
+            return Err(ModelError::InvalidConfig(format!(
+                "vocabulary has {} tokens, weights expect {}",
+                tokenizer.len(),
+                config.vocab_size
+            )));
+        }
+
+        let (h, i) = (config.hidden, config.intermediate);
+        let word = reader.q4(config.vocab_size, h)?;
+        let position = reader.q4(config.max_positions, h)?;
+        let token_type = reader.q4(config.type_vocab, h)?;
+        let embedding_norm = reader.norm(h)?;
+        let mut layers = Vec::with_capacity(config.layers);
+        for _ in 0..config.layers {
+            layers.push(Layer {
+                query: reader.linear(h, h)?,
+                key: reader.linear(h, h)?,
+                value: reader.linear(h, h)?,
+                attention_output: reader.linear(h, h)?,
+                attention_norm: reader.norm(h)?,
+                intermediate: reader.linear(i, h)?,
+                output: reader.linear(h, i)?,
+                output_norm: reader.norm(h)?,
+            });
+        }
+        let trailing = weights.len() - reader.offset;
+        if trailing != 0 {
+            return Err(ModelError::TrailingBytes(trailing));
+        }
+
+        Ok(MiniLMModel { config, tokenizer, word, position, token_type, embedding_norm, layers })
+    }
+
+    /// Encode the weights (not the vocabulary) in the Q4 container format
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let c = &self.config;
+        let mut out = Vec::new();
+        out.extend_from_slice(&MODEL_MAGIC);
+        for value in [
+            MODEL_FORMAT_VERSION as usize,
+            c.vocab_size,
+            c.hidden,
+            c.layers,
+            c.heads,
+            c.intermediate,
+            c.max_positions,
+            c.type_vocab,
+        ] {
+            out.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+        out.extend_from_slice(&c.layer_norm_eps.to_le_bytes());
+
+        let write_f32s = |out: &mut Vec<u8>, values: &[f32]| {
+            for v in values {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+        };
+        let write_linear = |out: &mut Vec<u8>, linear: &Linear| {
+            linear.weight.write(out);
+            write_f32s(out, &linear.bias);
+        };
+        let write_norm = |out: &mut Vec<u8>, norm: &Norm| {
+            write_f32s(out, &norm.gamma);
+            write_f32s(out, &norm.beta);
+        };
+
+        self.word.write(&mut out);
+        self.position.write(&mut out);
+        self.token_type.write(&mut out);
+        write_norm(&mut out, &self.embedding_norm);
+        for layer in &self.layers {
+            write_linear(&mut out, &layer.query);
+            write_linear(&mut out, &layer.key);
+            write_linear(&mut out, &layer.value);
+            write_linear(&mut out, &layer.attention_output);
+            write_norm(&mut out, &layer.attention_norm);
+            write_linear(&mut out, &layer.intermediate);
+            write_linear(&mut out, &layer.output);
+            write_norm(&mut out, &layer.output_norm);
+        }
+        out
+    }
+
+    /// Encoder dimensions
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    /// Tokenizer
+    pub fn tokenizer(&self) -> &WordPieceTokenizer {
+        &self.tokenizer
+    }
+
+    /// Embed `text` within an activation budget of `budget` bytes
+    pub fn embed(&self, text: &str, budget: usize) -> Result<ModelOutput, ModelError> {
+        let limit = self.config.max_tokens(budget);
+        if limit < 2 {
+            return Err(ModelError::BudgetTooSmall(budget));
+        }
+        let ids = self.tokenizer.encode(text, usize::MAX);
+        let truncated = ids.len() > limit;
+        let ids = if truncated { self.tokenizer.encode(text, limit) } else { ids };
+
+        let embedding = self.forward(&ids);
+        Ok(ModelOutput {
+            embedding,
+            tokens: ids.len(),
+            truncated,
+            active_bytes: self.config.active_bytes(ids.len()),
+        })
+    }
+
+    fn forward(&self, ids: &[u32]) -> Vec<f32> {
+        let (h, s) = (self.config.hidden, ids.len());
+        let head_dim = h / self.config.heads;
+        let eps = self.config.layer_norm_eps;
+        let inv_sqrt = 1.0 / libm::sqrtf(head_dim as f32);
+
+        let mut hidden = vec![0.0_f32; s * h];
+        let mut scratch = vec![0.0_f32; h];
+        for (t, &id) in ids.iter().enumerate() {
+            let x = &mut hidden[t * h..(t + 1) * h];
+            self.word.dequantize_row(id as usize, x);
+            for table in [(&self.position, t), (&self.token_type, 0)] {
+                table.0.dequantize_row(table.1, &mut scratch);
+                x.iter_mut().zip(&scratch).for_each(|(v, e)| *v += e);
+            }
+            self.embedding_norm.apply(x, eps);
+        }
+
+        let mut keys = vec![0.0_f32; s * h];
+        let mut values = vec![0.0_f32; s * h];
+        let mut query = vec![0.0_f32; h];
+        let mut context = vec![0.0_f32; h];
+        let mut scores = vec![0.0_f32; s];
+        let mut inner = vec![0.0_f32; self.config.intermediate];
+        for layer in &self.layers {
+            for t in 0..s {
+                let x = &hidden[t * h..(t + 1) * h];
+                layer.key.apply(x, &mut keys[t * h..(t + 1) * h]);
+                layer.value.apply(x, &mut values[t * h..(t + 1) * h]);
+            }
+            // Keys and values hold the layer input, so each token's hidden
+            // row can be updated in place
+            for t in 0..s {
+                let x = &mut hidden[t * h..(t + 1) * h];
+                layer.query.apply(x, &mut query);
+                for head in 0..self.config.heads {
+                    let range = head * head_dim..(head + 1) * head_dim;
+                    for (j, score) in scores.iter_mut().enumerate() {
+                        let k = &keys[j * h + range.start..j * h + range.end];
+                        *score = dot(&query[range.clone()], k) * inv_sqrt;
+                    }
+                    softmax(&mut scores);
+                    for c in range {
+                        context[c] = scores.iter().enumerate().map(|(j, p)| p * values[j * h + c]).sum();
+                    }
+                }
+                layer.attention_output.apply(&context, &mut scratch);
+                x.iter_mut().zip(&scratch).for_each(|(v, a)| *v += a);
+                layer.attention_norm.apply(x, eps);
+
+                layer.intermediate.apply(x, &mut inner);
+                inner.iter_mut().for_each(|v| *v = gelu(*v));
+                layer.output.apply(&inner, &mut scratch);
+                x.iter_mut().zip(&scratch).for_each(|(v, o)| *v += o);
+                layer.output_norm.apply(x, eps);
+            }
+        }
+
+        let mut pooled = vec![0.0_f32; h];
+        for row in hidden.chunks(h) {
+            pooled.iter_mut().zip(row).for_each(|(p, v)| *p += v);
+        }
+        let norm = libm::sqrtf(dot(&pooled, &pooled));
+        if norm > 1e-10 {
+            pooled.iter_mut().for_each(|p| *p /= norm);
+        }
+        pooled
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn softmax(scores: &mut [f32]) {
+    let max = scores.iter().fold(f32::NEG_INFINITY, |m, &v| m.max(v));
+    let mut total = 0.0_f32;
+    for v in scores.iter_mut() {
+        *v = libm::expf(*v - max);
+        total += *v;
+    }
+    scores.iter_mut().for_each(|v| *v /= total);
+}
+
+/// Exact (erf) GELU, as in BERT
+fn gelu(x: f32) -> f32 {
+    0.5 * x * (1.0 + libm::erff(x * core::f32::consts::FRAC_1_SQRT_2))
+}
+
+/// Little-endian cursor over a weight file
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ModelError> {
+        let end = self.offset.checked_add(n).filter(|&end| end <= self.bytes.len());
+        let end = end.ok_or(ModelError::Truncated(self.offset))?;
+        let slice = &self.bytes[self.offset..end];
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn u32(&mut self) -> Result<u32, ModelError> {
+        let b = self.take(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f32, ModelError> {
+        self.u32().map(f32::from_bits)
+    }
+
+    fn f32s(&mut self, n: usize) -> Result<Vec<f32>, ModelError> {
+        let bytes = self.take(n.checked_mul(4).ok_or(ModelError::Truncated(self.offset))?)?;
+        Ok(bytes.chunks(4).map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect())
+    }
+
+    fn q4(&mut self, rows: usize, cols: usize) -> Result<Q4Tensor, ModelError> {
+        let blocks = rows.checked_mul(cols / Q4_BLOCK).ok_or(ModelError::Truncated(self.offset))?;
+        let block_bytes = 4 + Q4_BLOCK / 2;
+        let bytes = self.take(blocks.checked_mul(block_bytes).ok_or(ModelError::Truncated(self.offset))?)?;
+        let mut scales = Vec::with_capacity(blocks);
+        let mut packed = Vec::with_capacity(blocks * Q4_BLOCK / 2);
+        for block in bytes.chunks(block_bytes) {
+            scales.push(f32::from_le_bytes([block[0], block[1], block[2], block[3]]));
+            packed.extend_from_slice(&block[4..]);
+        }
+        Ok(Q4Tensor { rows, cols, scales, packed })
+    }
+
+    fn linear(&mut self, rows: usize, cols: usize) -> Result<Linear, ModelError> {
+        Ok(Linear { weight: self.q4(rows, cols)?, bias: self.f32s(rows)? })
+    }
+
+    fn norm(&mut self, n: usize) -> Result<Norm, ModelError> {
+        Ok(Norm { gamma: self.f32s(n)?, beta: self.f32s(n)? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VOCAB: &str = "[PAD]\n[UNK]\n[CLS]\n[SEP]\nthe\nquantum\nqu\n##ant\n##um\ncafe\n,\n!\n\u{4e2d}\nrun\n##ning\nstate";
+
+    /// Tiny random encoder over `VOCAB`
+    fn tiny_model(seed: u32) -> MiniLMModel {
+        let config = ModelConfig {
+            vocab_size: 16,
+            hidden: 32,
+            layers: 2,
+            heads: 4,
+            intermediate: 64,
+            max_positions: 16,
+            type_vocab: 2,
+            layer_norm_eps: 1e-12,
+        };
+        let mut state = seed;
+        let mut rand = move |n: usize, scale: f32| -> Vec<f32> {
+            (0..n)
+                .map(|_| {
+                    state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                    (((state >> 16) & 0x7FFF) as f32 / 32767.0 * 2.0 - 1.0) * scale
+                })
+                .collect()
+        };
+        let mut q4 = |rows: usize, cols: usize| Q4Tensor::quantize(rows, cols, &rand(rows * cols, 0.5)).unwrap();
+        let (h, i) = (config.hidden, config.intermediate);
+        let word = q4(config.vocab_size, h);
+        let position = q4(config.max_positions, h);
+        let token_type = q4(config.type_vocab, h);
+        let mut layers = Vec::new();
+        for _ in 0..config.layers {
+            let mut linear = |rows, cols| Linear { weight: q4(rows, cols), bias: vec![0.01; rows] };
+            layers.push(Layer {
+                query: linear(h, h),
+                key: linear(h, h),
+                value: linear(h, h),
+                attention_output: linear(h, h),
+                attention_norm: Norm { gamma: vec![1.0; h], beta: vec![0.0; h] },
+                intermediate: linear(i, h),
+                output: linear(h, i),
+                output_norm: Norm { gamma: vec![1.0; h], beta: vec![0.0; h] },
+            });
+        }
+        MiniLMModel {
+            config,
+            tokenizer: WordPieceTokenizer::from_vocab(VOCAB, true).unwrap(),
+            word,
+            position,
+            token_type,
+            embedding_norm: Norm { gamma: vec![1.0; h], beta: vec![0.0; h] },
+            layers,
+        }
+    }
+
+    #[test]
+    fn test_wordpiece_tokenizer() {
+        let tokenizer = WordPieceTokenizer::from_vocab(VOCAB, true).unwrap();
+        assert_eq!(tokenizer.tokenize("The QUANTUM, Café!"), ["the", "quantum", ",", "cafe", "!"]);
+        assert_eq!(tokenizer.tokenize("Running quantum\u{4e2d}state"), ["run", "##ning", "quantum", "\u{4e2d}", "state"]);
+        // Greedy longest match; NUL is dropped, not a word break
+        assert_eq!(tokenizer.tokenize("quantumum qu\u{0}ant"), ["quantum", "##um", "qu", "##ant"]);
+        assert_eq!(tokenizer.tokenize("ququantum"), ["[UNK]"]);
+        assert_eq!(tokenizer.encode("the quantum state", 4), [2, 4, 5, 3]);
+
+        assert_eq!(
+            WordPieceTokenizer::from_vocab("[CLS]\n[SEP]", true).unwrap_err(),
+            ModelError::MissingToken("[UNK]".into())
+        );
+    }
+
+    #[test]
+    fn test_q4_container_round_trip() {
+        let values: Vec<f32> = (0..64).map(|i| (i as f32 - 31.5) / 10.0).collect();
+        let tensor = Q4Tensor::quantize(2, 32, &values).unwrap();
+        let mut row = vec![0.0; 32];
+        tensor.dequantize_row(1, &mut row);
+        let scale = 3.25 / 7.0;
+        assert!(row.iter().zip(&values[32..]).all(|(q, v)| (q - v).abs() <= scale / 2.0 + 1e-6));
+
+        let model = tiny_model(7);
+        let bytes = model.to_bytes();
+        let loaded = MiniLMModel::load(&bytes, VOCAB).unwrap();
+        assert_eq!(loaded.to_bytes(), bytes);
+
+        assert_eq!(MiniLMModel::load(&bytes[..bytes.len() - 1], VOCAB).unwrap_err(), ModelError::Truncated(bytes.len() - 4 * 32));
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(MiniLMModel::load(&trailing, VOCAB).unwrap_err(), ModelError::TrailingBytes(1));
+        assert_eq!(MiniLMModel::load(b"GGUF....", VOCAB).unwrap_err(), ModelError::BadMagic);
+        assert!(matches!(MiniLMModel::load(&bytes, "[CLS]\n[SEP]\n[UNK]"), Err(ModelError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_encoder_forward_and_budget() {
+        let model = tiny_model(7);
+        let a = model.embed("the quantum state", DEFAULT_ACTIVE_BUDGET).unwrap();
+        let b = model.embed("The  QUANTUM state", DEFAULT_ACTIVE_BUDGET).unwrap();
+        let c = model.embed("running cafe", DEFAULT_ACTIVE_BUDGET).unwrap();
+        assert_eq!(a.tokens, 5);
+        assert!(!a.truncated);
+        assert_eq!(a.embedding, b.embedding);
+        assert!((dot(&a.embedding, &a.embedding) - 1.0).abs() < 1e-5);
+        assert!(dot(&a.embedding, &c.embedding) < 0.999);
+
+        // Word order matters through position embeddings and attention
+        let reversed = model.embed("state quantum the", DEFAULT_ACTIVE_BUDGET).unwrap();
+        assert_ne!(a.embedding, reversed.embedding);
+
+        // The budget caps the sequence: room for exactly 4 tokens
+        let budget = model.config().active_bytes(4);
+        let cut = model.embed("the quantum state", budget).unwrap();
+        assert_eq!((cut.tokens, cut.truncated), (4, true));
+        assert!(cut.active_bytes <= budget);
+        assert_eq!(
+            model.embed("the", model.config().active_bytes(1)).unwrap_err(),
+            ModelError::BudgetTooSmall(model.config().active_bytes(1))
+        );
+    }
+}
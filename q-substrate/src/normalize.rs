@@ -0,0 +1,247 @@
+//! Text Normalization Module
+//!
+//! Deterministic normalization applied before tokenization and embedding:
+//! - Unicode NFC composition
+//! - Case folding (including the expanding folds `ß` → `ss`, `ﬁ` → `fi`)
+//! - Configurable transliteration (none, Latin-to-ASCII, custom table)
+//! - Whitespace collapsing
+//!
+//! Canonically equivalent inputs always produce the same bytes, so the
+//! byte-hash embedding no longer depends on how the text was composed.
+//! Every embedding carries a `NormalizationTag`; indexes built under
+//! different tags are not comparable.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Normalization algorithm version; bump on any output change
+pub const NORMALIZATION_VERSION: u32 = 1;
+
+/// Transliteration applied after case folding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Transliteration {
+    /// Keep all characters
+    None,
+    /// Strip diacritics and map Latin ligatures/letters and typographic
+    /// punctuation to ASCII; other scripts pass through unchanged
+    Ascii,
+    /// Replace listed characters; unlisted characters pass through
+    Custom(Vec<(char, String)>),
+}
+
+/// Normalization configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizationConfig {
+    /// Apply case folding
+    pub case_fold: bool,
+    /// Transliteration stage
+    pub transliteration: Transliteration,
+    /// Collapse whitespace runs to one space and trim
+    pub collapse_whitespace: bool,
+}
+
+impl Default for NormalizationConfig {
+    fn default() -> Self {
+        NormalizationConfig {
+            case_fold: true,
+            transliteration: Transliteration::None,
+            collapse_whitespace: true,
+        }
+    }
+}
+
+/// Identifies the normalization an embedding was produced under
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NormalizationTag {
+    /// `NORMALIZATION_VERSION` at embedding time
+    pub version: u32,
+    /// FNV-1a hash of the configuration
+    pub config_hash: u64,
+}
+
+impl NormalizationConfig {
+    /// Tag recorded alongside embeddings
+    pub fn tag(&self) -> NormalizationTag {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut feed = |bytes: &[u8]| {
+            for &byte in bytes {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+            }
+        };
+
+        feed(&[self.case_fold as u8, self.collapse_whitespace as u8]);
+        match &self.transliteration {
+            Transliteration::None => feed(b"none"),
+            Transliteration::Ascii => feed(b"ascii"),
+            Transliteration::Custom(table) => {
+                feed(b"custom");
+                for (from, to) in table {
+                    feed(&(*from as u32).to_le_bytes());
+                    feed(to.as_bytes());
+                    feed(&[0]);
+                }
+            }
+        }
+
+        NormalizationTag {
+            version: NORMALIZATION_VERSION,
+            config_hash: hash,
+        }
+    }
+
+    /// Normalize text: NFC, fold, transliterate, NFC, collapse whitespace
+    pub fn normalize(&self, text: &str) -> String {
+        let mut out: String = text.nfc().collect();
+
+        if self.case_fold {
+            out = case_fold(&out);
+        }
+
+        match &self.transliteration {
+            Transliteration::None => {}
+            Transliteration::Ascii => out = to_ascii(&out),
+            Transliteration::Custom(table) => {
+                out = out
+                    .chars()
+                    .fold(String::with_capacity(out.len()), |mut acc, c| {
+                        match table.iter().find(|(from, _)| *from == c) {
+                            Some((_, to)) => acc.push_str(to),
+                            None => acc.push(c),
+                        }
+                        acc
+                    });
+            }
+        }
+
+        // Folding and transliteration can leave decomposed sequences
+        out = out.nfc().collect();
+
+        if self.collapse_whitespace {
+            out = out.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+        out
+    }
+}
+
+/// Full case folding for the expanding cases, simple lowercase otherwise
+fn case_fold(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'ß' | 'ẞ' => out.push_str("ss"),
+            'ς' => out.push('σ'),
+            'ſ' => out.push('s'),
+            'ﬀ' => out.push_str("ff"),
+            'ﬁ' => out.push_str("fi"),
+            'ﬂ' => out.push_str("fl"),
+            'ﬃ' => out.push_str("ffi"),
+            'ﬄ' => out.push_str("ffl"),
+            'ﬅ' | 'ﬆ' => out.push_str("st"),
+            _ => out.extend(c.to_lowercase()),
+        }
+    }
+    out
+}
+
+/// Latin letters without a canonical decomposition
+fn ascii_replacement(c: char) -> Option<&'static str> {
+    Some(match c {
+        'æ' => "ae",
+        'Æ' => "AE",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'ø' => "o",
+        'Ø' => "O",
+        'ł' => "l",
+        'Ł' => "L",
+        'đ' | 'ð' => "d",
+        'Đ' | 'Ð' => "D",
+        'þ' => "th",
+        'Þ' => "TH",
+        'ß' => "ss",
+        'ı' => "i",
+        '\u{2018}' | '\u{2019}' | '\u{201A}' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' => "\"",
+        '\u{2013}' | '\u{2014}' => "-",
+        '\u{2026}' => "...",
+        '\u{00A0}' => " ",
+        _ => return None,
+    })
+}
+
+/// Decompose, drop combining marks and map remaining Latin letters
+fn to_ascii(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.nfd() {
+        if is_combining_mark(c) {
+            continue;
+        }
+        match ascii_replacement(c) {
+            Some(replacement) => out.push_str(replacement),
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_equivalents_match() {
+        let config = NormalizationConfig::default();
+        // Precomposed é vs e + combining acute
+        assert_eq!(config.normalize("Caf\u{e9}"), config.normalize("Cafe\u{301}"));
+        assert_eq!(config.normalize("Caf\u{e9}"), "caf\u{e9}");
+    }
+
+    #[test]
+    fn test_case_folding_and_whitespace() {
+        let config = NormalizationConfig::default();
+        assert_eq!(config.normalize("  STRASSE\t straße "), "strasse strasse");
+        assert_eq!(config.normalize("ΣΟΦΟΣ σοφος"), "σοφοσ σοφοσ");
+        assert_eq!(config.normalize("ﬁle"), "file");
+
+        let keep_case = NormalizationConfig { case_fold: false, ..Default::default() };
+        assert_eq!(keep_case.normalize("Run  Quantum"), "Run Quantum");
+    }
+
+    #[test]
+    fn test_ascii_transliteration() {
+        let config = NormalizationConfig {
+            transliteration: Transliteration::Ascii,
+            ..Default::default()
+        };
+        assert_eq!(config.normalize("Crème brûlée “Ørsted” — Łódź"), "creme brulee \"orsted\" - lodz");
+        // Non-Latin scripts pass through
+        assert_eq!(config.normalize("量子 Qubit"), "量子 qubit");
+    }
+
+    #[test]
+    fn test_custom_transliteration() {
+        let config = NormalizationConfig {
+            transliteration: Transliteration::Custom(alloc::vec![('ä', "ae".into()), ('ö', "oe".into())]),
+            ..Default::default()
+        };
+        assert_eq!(config.normalize("Ärger Öl"), "aerger oel");
+    }
+
+    #[test]
+    fn test_tags_distinguish_configs() {
+        let default = NormalizationConfig::default();
+        let ascii = NormalizationConfig {
+            transliteration: Transliteration::Ascii,
+            ..Default::default()
+        };
+        assert_eq!(default.tag(), NormalizationConfig::default().tag());
+        assert_ne!(default.tag(), ascii.tag());
+        assert_eq!(default.tag().version, NORMALIZATION_VERSION);
+    }
+}
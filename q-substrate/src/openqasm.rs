@@ -0,0 +1,556 @@
+//! OpenQASM 2.0 import/export for Mini QuASIM circuits
+//!
+//! Supported subset:
+//! - `OPENQASM 2.0;` header and `include "qelib1.inc";`
+//! - `qreg` / `creg` declarations (multiple quantum registers are laid out
+//!   in declaration order)
+//! - Standard gates: id, h, x, y, z, s, sdg, t, tdg, cx, cz, swap, ccx,
+//!   rx, ry, rz, with register broadcasting (`h q;`)
+//! - `measure a -> b;` and `barrier` (ignored)
+//! - Angle expressions over numbers, `pi`, `+ - * /` and parentheses
+//!
+//! Custom `gate` definitions, `opaque`, `if`, `reset` and the `u`/`cu`
+//! families are rejected with the offending line. Classical registers are
+//! not simulated: measurement collapses the qubit and the target bit is only
+//! checked for range.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::quantum::{Circuit, CircuitError, GateRecord, MiniQuASIM, QuantumGate};
+
+/// OpenQASM error types
+#[derive(Debug, Clone, PartialEq)]
+pub enum QasmError {
+    /// Program does not start with `OPENQASM 2.0;`
+    MissingHeader,
+    /// Header names a version other than 2.0
+    UnsupportedVersion(String),
+    /// Construct outside the supported subset
+    Unsupported {
+        /// 1-based source line
+        line: usize,
+        /// Offending construct
+        construct: String,
+    },
+    /// Malformed statement
+    Syntax {
+        /// 1-based source line
+        line: usize,
+        /// Parser message
+        message: String,
+    },
+    /// Register used before declaration
+    UnknownRegister {
+        /// 1-based source line
+        line: usize,
+        /// Register name
+        name: String,
+    },
+    /// Register index outside its declared size
+    IndexOutOfRange {
+        /// 1-based source line
+        line: usize,
+        /// Register name
+        register: String,
+        /// Offending index
+        index: usize,
+    },
+    /// Circuit IR rejected the program
+    Circuit {
+        /// 1-based source line (0 for register-level errors)
+        line: usize,
+        /// Underlying IR error
+        error: CircuitError,
+    },
+    /// Gate history entry has no QASM equivalent
+    UnknownHistoryGate(String),
+}
+
+impl core::fmt::Display for QasmError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            QasmError::MissingHeader => write!(f, "Missing 'OPENQASM 2.0;' header"),
+            QasmError::UnsupportedVersion(v) => write!(f, "Unsupported OpenQASM version {}", v),
+            QasmError::Unsupported { line, construct } => {
+                write!(f, "Line {}: '{}' is not supported", line, construct)
+            }
+            QasmError::Syntax { line, message } => write!(f, "Line {}: {}", line, message),
+            QasmError::UnknownRegister { line, name } => {
+                write!(f, "Line {}: unknown register '{}'", line, name)
+            }
+            QasmError::IndexOutOfRange { line, register, index } => {
+                write!(f, "Line {}: index {} out of range for '{}'", line, index, register)
+            }
+            QasmError::Circuit { line, error } => write!(f, "Line {}: {}", line, error),
+            QasmError::UnknownHistoryGate(g) => write!(f, "Gate '{}' has no QASM equivalent", g),
+        }
+    }
+}
+
+/// Declared register
+struct Register {
+    name: String,
+    offset: usize,
+    size: usize,
+}
+
+/// Gate operand: one qubit or a whole register
+enum Operand {
+    Single(usize),
+    Register(usize, usize),
+}
+
+/// Parse an OpenQASM 2.0 program into the circuit IR
+pub fn parse(source: &str) -> Result<Circuit, QasmError> {
+    let mut qregs: Vec<Register> = Vec::new();
+    let mut cregs: Vec<Register> = Vec::new();
+    let mut gates: Vec<(usize, QuantumGate)> = Vec::new();
+    let mut header = false;
+
+    for (line, statement) in statements(source) {
+        let (keyword, rest) = split_keyword(&statement);
+
+        if !header {
+            if keyword != "OPENQASM" {
+                return Err(QasmError::MissingHeader);
+            }
+            if rest != "2.0" {
+                return Err(QasmError::UnsupportedVersion(rest.into()));
+            }
+            header = true;
+            continue;
+        }
+
+        match keyword {
+            "OPENQASM" => return Err(syntax(line, "duplicate OPENQASM header")),
+            "include" => {
+                if rest != "\"qelib1.inc\"" {
+                    return Err(unsupported(line, &statement));
+                }
+            }
+            "qreg" | "creg" => {
+                let (name, size) = parse_indexed(line, rest)?;
+                if qregs.iter().chain(cregs.iter()).any(|r| r.name == name) {
+                    return Err(syntax(line, &format!("register '{}' declared twice", name)));
+                }
+                let registers = if keyword == "qreg" { &mut qregs } else { &mut cregs };
+                let offset = registers.iter().map(|r| r.size).sum();
+                registers.push(Register { name: name.into(), offset, size });
+            }
+            "barrier" => {}
+            "measure" => {
+                let (source, target) = rest
+                    .split_once("->")
+                    .ok_or_else(|| syntax(line, "expected 'measure a -> b'"))?;
+                let qubits = operand(line, &qregs, source.trim())?;
+                let bits = operand(line, &cregs, target.trim())?;
+                let qubits = match (qubits, bits) {
+                    (Operand::Single(q), Operand::Single(_)) => vec![q],
+                    (Operand::Register(offset, size), Operand::Register(_, bits)) if size == bits => {
+                        (offset..offset + size).collect()
+                    }
+                    _ => return Err(syntax(line, "measure operands differ in size")),
+                };
+                gates.extend(qubits.into_iter().map(|q| (line, QuantumGate::Measure(q))));
+            }
+            "gate" | "opaque" | "if" | "reset" => return Err(unsupported(line, keyword)),
+            _ => gates.extend(parse_gate(line, &statement, &qregs)?.into_iter().map(|g| (line, g))),
+        }
+    }
+
+    if !header {
+        return Err(QasmError::MissingHeader);
+    }
+
+    let width = qregs.iter().map(|r| r.size).sum();
+    let mut circuit = Circuit::new(width).map_err(|error| QasmError::Circuit { line: 0, error })?;
+    for (line, gate) in gates {
+        circuit.push(gate).map_err(|error| QasmError::Circuit { line, error })?;
+    }
+    Ok(circuit)
+}
+
+/// Emit a circuit as OpenQASM 2.0 over a single `q` register
+///
+/// S† is not part of the IR and appears as `s` followed by `z`.
+pub fn emit(circuit: &Circuit) -> String {
+    let qubits = circuit.num_qubits();
+    let mut out = String::from("OPENQASM 2.0;\ninclude \"qelib1.inc\";\n");
+    let _ = writeln!(out, "qreg q[{}];", qubits);
+    if circuit.gates().iter().any(|g| matches!(g, QuantumGate::Measure(_))) {
+        let _ = writeln!(out, "creg c[{}];", qubits);
+    }
+
+    for gate in circuit.gates() {
+        let _ = match *gate {
+            QuantumGate::Hadamard(q) => writeln!(out, "h q[{}];", q),
+            QuantumGate::PauliX(q) => writeln!(out, "x q[{}];", q),
+            QuantumGate::PauliY(q) => writeln!(out, "y q[{}];", q),
+            QuantumGate::PauliZ(q) => writeln!(out, "z q[{}];", q),
+            QuantumGate::Phase(q) => writeln!(out, "s q[{}];", q),
+            QuantumGate::T(q) => writeln!(out, "t q[{}];", q),
+            QuantumGate::TDagger(q) => writeln!(out, "tdg q[{}];", q),
+            QuantumGate::CNOT(c, t) => writeln!(out, "cx q[{}],q[{}];", c, t),
+            QuantumGate::CZ(c, t) => writeln!(out, "cz q[{}],q[{}];", c, t),
+            QuantumGate::SWAP(a, b) => writeln!(out, "swap q[{}],q[{}];", a, b),
+            QuantumGate::Toffoli(a, b, t) => writeln!(out, "ccx q[{}],q[{}],q[{}];", a, b, t),
+            QuantumGate::RX(q, theta) => writeln!(out, "rx({}) q[{}];", theta, q),
+            QuantumGate::RY(q, theta) => writeln!(out, "ry({}) q[{}];", theta, q),
+            QuantumGate::RZ(q, theta) => writeln!(out, "rz({}) q[{}];", theta, q),
+            QuantumGate::Measure(q) => writeln!(out, "measure q[{}] -> c[{}];", q, q),
+        };
+    }
+    out
+}
+
+/// Rebuild the circuit IR from a simulator's recorded gate history
+pub fn circuit_from_history(qubits: usize, history: &[GateRecord]) -> Result<Circuit, QasmError> {
+    let mut circuit = Circuit::new(qubits).map_err(|error| QasmError::Circuit { line: 0, error })?;
+    for record in history {
+        let q = &record.qubits;
+        let single = |make: fn(usize) -> QuantumGate| q.first().map(|&q| vec![make(q)]);
+        let rotation = |make: fn(usize, f32) -> QuantumGate| match (q.first(), record.theta) {
+            (Some(&q), Some(theta)) => Some(vec![make(q, theta)]),
+            _ => None,
+        };
+        let gates = match record.gate.as_str() {
+            "H" => single(QuantumGate::Hadamard),
+            "X" => single(QuantumGate::PauliX),
+            "Y" => single(QuantumGate::PauliY),
+            "Z" => single(QuantumGate::PauliZ),
+            "S" => single(QuantumGate::Phase),
+            "T" => single(QuantumGate::T),
+            "T†" => single(QuantumGate::TDagger),
+            "CNOT" if q.len() == 2 => Some(vec![QuantumGate::CNOT(q[0], q[1])]),
+            "CZ" if q.len() == 2 => Some(vec![QuantumGate::CZ(q[0], q[1])]),
+            "SWAP" if q.len() == 2 => Some(vec![QuantumGate::SWAP(q[0], q[1])]),
+            "TOFFOLI" if q.len() == 3 => Some(vec![QuantumGate::Toffoli(q[0], q[1], q[2])]),
+            "RX" => rotation(QuantumGate::RX),
+            "RY" => rotation(QuantumGate::RY),
+            "RZ" => rotation(QuantumGate::RZ),
+            "M" => Some(q.iter().map(|&q| QuantumGate::Measure(q)).collect()),
+            _ => None,
+        }
+        .ok_or_else(|| QasmError::UnknownHistoryGate(record.gate.clone()))?;
+        for gate in gates {
+            circuit.push(gate).map_err(|error| QasmError::Circuit { line: 0, error })?;
+        }
+    }
+    Ok(circuit)
+}
+
+/// Emit the gates applied to `sim` so far as OpenQASM 2.0
+pub fn emit_history(sim: &MiniQuASIM) -> Result<String, QasmError> {
+    circuit_from_history(sim.num_qubits(), sim.get_gate_history()).map(|c| emit(&c))
+}
+
+/// Split source into `;`-terminated statements with their starting line
+fn statements(source: &str) -> Vec<(usize, String)> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+    for (index, raw) in source.lines().enumerate() {
+        let code = raw.split("//").next().unwrap_or("");
+        for ch in code.chars() {
+            if current.trim().is_empty() && !ch.is_whitespace() {
+                start = index + 1;
+            }
+            if ch == ';' {
+                out.push((start, current.trim().to_string()));
+                current.clear();
+            } else {
+                current.push(ch);
+            }
+        }
+        current.push(' ');
+    }
+    if !current.trim().is_empty() {
+        out.push((start, current.trim().to_string()));
+    }
+    out
+}
+
+fn split_keyword(statement: &str) -> (&str, &str) {
+    let end = statement
+        .find(|c: char| c.is_whitespace() || c == '(')
+        .unwrap_or(statement.len());
+    (&statement[..end], statement[end..].trim())
+}
+
+fn syntax(line: usize, message: &str) -> QasmError {
+    QasmError::Syntax { line, message: message.into() }
+}
+
+fn unsupported(line: usize, construct: &str) -> QasmError {
+    QasmError::Unsupported { line, construct: construct.into() }
+}
+
+/// Parse `name[n]`
+fn parse_indexed(line: usize, text: &str) -> Result<(&str, usize), QasmError> {
+    let (name, rest) = text
+        .split_once('[')
+        .ok_or_else(|| syntax(line, &format!("expected name[index], got '{}'", text)))?;
+    let index = rest
+        .strip_suffix(']')
+        .and_then(|n| n.trim().parse().ok())
+        .ok_or_else(|| syntax(line, &format!("invalid index in '{}'", text)))?;
+    Ok((name.trim(), index))
+}
+
+fn operand(line: usize, registers: &[Register], text: &str) -> Result<Operand, QasmError> {
+    let (name, index) = if text.contains('[') {
+        let (name, index) = parse_indexed(line, text)?;
+        (name, Some(index))
+    } else {
+        (text, None)
+    };
+    let register = registers
+        .iter()
+        .find(|r| r.name == name)
+        .ok_or_else(|| QasmError::UnknownRegister { line, name: name.into() })?;
+    match index {
+        Some(i) if i >= register.size => Err(QasmError::IndexOutOfRange {
+            line,
+            register: name.into(),
+            index: i,
+        }),
+        Some(i) => Ok(Operand::Single(register.offset + i)),
+        None => Ok(Operand::Register(register.offset, register.size)),
+    }
+}
+
+/// Parse a standard gate application, broadcasting over register operands
+fn parse_gate(line: usize, statement: &str, qregs: &[Register]) -> Result<Vec<QuantumGate>, QasmError> {
+    let (name, rest) = split_keyword(statement);
+    let (params, operands) = match rest.strip_prefix('(') {
+        Some(rest) => {
+            let close = rest.find(')').ok_or_else(|| syntax(line, "unclosed parameter list"))?;
+            let params = rest[..close]
+                .split(',')
+                .map(|p| eval(line, p))
+                .collect::<Result<Vec<f32>, _>>()?;
+            (params, rest[close + 1..].trim())
+        }
+        None => (Vec::new(), rest),
+    };
+
+    let (arity, angles) = match name {
+        "id" | "h" | "x" | "y" | "z" | "s" | "sdg" | "t" | "tdg" => (1, 0),
+        "rx" | "ry" | "rz" => (1, 1),
+        "cx" | "CX" | "cz" | "swap" => (2, 0),
+        "ccx" => (3, 0),
+        _ => return Err(unsupported(line, name)),
+    };
+    if params.len() != angles {
+        return Err(syntax(line, &format!("'{}' takes {} parameter(s)", name, angles)));
+    }
+    let operands = operands
+        .split(',')
+        .map(|o| operand(line, qregs, o.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+    if operands.len() != arity {
+        return Err(syntax(line, &format!("'{}' takes {} qubit(s)", name, arity)));
+    }
+
+    // Whole-register operands broadcast element-wise and must agree in size
+    let mut width = None;
+    for op in &operands {
+        if let Operand::Register(_, size) = op {
+            if width.is_some_and(|w| w != *size) {
+                return Err(syntax(line, "register operands differ in size"));
+            }
+            width = Some(*size);
+        }
+    }
+
+    let mut gates = Vec::new();
+    for i in 0..width.unwrap_or(1) {
+        let q: Vec<usize> = operands
+            .iter()
+            .map(|op| match *op {
+                Operand::Single(q) => q,
+                Operand::Register(offset, _) => offset + i,
+            })
+            .collect();
+        match name {
+            "id" => {}
+            "h" => gates.push(QuantumGate::Hadamard(q[0])),
+            "x" => gates.push(QuantumGate::PauliX(q[0])),
+            "y" => gates.push(QuantumGate::PauliY(q[0])),
+            "z" => gates.push(QuantumGate::PauliZ(q[0])),
+            "s" => gates.push(QuantumGate::Phase(q[0])),
+            "sdg" => gates.extend([QuantumGate::Phase(q[0]), QuantumGate::PauliZ(q[0])]),
+            "t" => gates.push(QuantumGate::T(q[0])),
+            "tdg" => gates.push(QuantumGate::TDagger(q[0])),
+            "rx" => gates.push(QuantumGate::RX(q[0], params[0])),
+            "ry" => gates.push(QuantumGate::RY(q[0], params[0])),
+            "rz" => gates.push(QuantumGate::RZ(q[0], params[0])),
+            "cx" | "CX" => gates.push(QuantumGate::CNOT(q[0], q[1])),
+            "cz" => gates.push(QuantumGate::CZ(q[0], q[1])),
+            "swap" => gates.push(QuantumGate::SWAP(q[0], q[1])),
+            _ => gates.push(QuantumGate::Toffoli(q[0], q[1], q[2])),
+        }
+    }
+    Ok(gates)
+}
+
+/// Evaluate an angle expression: numbers, `pi`, `+ - * /`, parentheses
+fn eval(line: usize, text: &str) -> Result<f32, QasmError> {
+    let tokens: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut pos = 0;
+    let value = expr(&tokens, &mut pos)
+        .filter(|_| pos == tokens.len())
+        .ok_or_else(|| syntax(line, &format!("unsupported angle expression '{}'", text.trim())))?;
+    Ok(value as f32)
+}
+
+fn expr(tokens: &[char], pos: &mut usize) -> Option<f64> {
+    let mut value = term(tokens, pos)?;
+    while let Some(&op) = tokens.get(*pos).filter(|c| **c == '+' || **c == '-') {
+        *pos += 1;
+        let rhs = term(tokens, pos)?;
+        value = if op == '+' { value + rhs } else { value - rhs };
+    }
+    Some(value)
+}
+
+fn term(tokens: &[char], pos: &mut usize) -> Option<f64> {
+    let mut value = factor(tokens, pos)?;
+    while let Some(&op) = tokens.get(*pos).filter(|c| **c == '*' || **c == '/') {
+        *pos += 1;
+        let rhs = factor(tokens, pos)?;
+        value = if op == '*' { value * rhs } else { value / rhs };
+    }
+    Some(value)
+}
+
+fn factor(tokens: &[char], pos: &mut usize) -> Option<f64> {
+    match *tokens.get(*pos)? {
+        '-' => {
+            *pos += 1;
+            factor(tokens, pos).map(|v| -v)
+        }
+        '(' => {
+            *pos += 1;
+            let value = expr(tokens, pos)?;
+            if tokens.get(*pos) != Some(&')') {
+                return None;
+            }
+            *pos += 1;
+            Some(value)
+        }
+        'p' if tokens.get(*pos + 1) == Some(&'i') => {
+            *pos += 2;
+            Some(core::f64::consts::PI)
+        }
+        _ => {
+            let start = *pos;
+            while let Some(&c) = tokens.get(*pos) {
+                let exponent_sign = (c == '-' || c == '+')
+                    && *pos > start
+                    && matches!(tokens[*pos - 1], 'e' | 'E');
+                if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || exponent_sign {
+                    *pos += 1;
+                } else {
+                    break;
+                }
+            }
+            let number: String = tokens[start..*pos].iter().collect();
+            number.parse().ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_qiskit_program() {
+        let source = r#"
+            OPENQASM 2.0;
+            include "qelib1.inc";
+            // Bell pair plus rotations, as exported by Qiskit
+            qreg q[2];
+            qreg anc[1];
+            creg c[2];
+            h q[0];
+            cx q[0],q[1];
+            rz(-pi/4) q[1];
+            ry(2*pi/3 + 0.5e-1) anc[0];
+            sdg q[0];
+            barrier q;
+            x q;
+            measure q -> c;
+        "#;
+        let circuit = parse(source).unwrap();
+        assert_eq!(circuit.num_qubits(), 3);
+        let gates = circuit.gates();
+        assert!(matches!(gates[1], QuantumGate::CNOT(0, 1)));
+        assert!(matches!(gates[2], QuantumGate::RZ(1, t) if (t + core::f32::consts::FRAC_PI_4).abs() < 1e-6));
+        assert!(matches!(gates[3], QuantumGate::RY(2, t) if (t - 2.144395).abs() < 1e-5));
+        assert!(matches!(gates[4], QuantumGate::Phase(0)) && matches!(gates[5], QuantumGate::PauliZ(0)));
+        assert!(matches!(gates[6], QuantumGate::PauliX(0)) && matches!(gates[7], QuantumGate::PauliX(1)));
+        assert!(matches!(gates[8], QuantumGate::Measure(0)) && matches!(gates[9], QuantumGate::Measure(1)));
+        assert_eq!(gates.len(), 10);
+    }
+
+    #[test]
+    fn test_unsupported_constructs() {
+        let program = |body: &str| format!("OPENQASM 2.0;\nqreg q[2];\ncreg c[2];\n{}\n", body);
+        assert_eq!(
+            parse(&program("u3(0,0,0) q[0];")).unwrap_err(),
+            QasmError::Unsupported { line: 4, construct: "u3".into() }
+        );
+        assert!(matches!(parse(&program("gate foo a { h a; }")), Err(QasmError::Unsupported { line: 4, .. })));
+        assert!(matches!(parse(&program("if(c==1) x q[0];")), Err(QasmError::Unsupported { .. })));
+        assert_eq!(
+            parse(&program("h q[2];")).unwrap_err(),
+            QasmError::IndexOutOfRange { line: 4, register: "q".into(), index: 2 }
+        );
+        assert!(matches!(parse(&program("h r[0];")), Err(QasmError::UnknownRegister { .. })));
+        assert!(matches!(parse(&program("rx(sin(1)) q[0];")), Err(QasmError::Syntax { .. })));
+        assert!(matches!(
+            parse(&program("cx q[0],q[0];")),
+            Err(QasmError::Circuit { line: 4, error: CircuitError::DuplicateQubit { .. } })
+        ));
+        assert_eq!(parse("qreg q[1];").unwrap_err(), QasmError::MissingHeader);
+        assert_eq!(parse("OPENQASM 3.0;").unwrap_err(), QasmError::UnsupportedVersion("3.0".into()));
+    }
+
+    #[test]
+    fn test_emit_round_trip_from_history() {
+        let mut sim = MiniQuASIM::new(42);
+        sim.hadamard(0);
+        sim.cnot(0, 1);
+        sim.rx(2, 0.375);
+        sim.t_dagger(1);
+        sim.toffoli(0, 1, 2);
+        sim.measure_qubit(2, 7);
+
+        let qasm = emit_history(&sim).unwrap();
+        assert!(qasm.contains("qreg q[12];\ncreg c[12];\n"));
+        assert!(qasm.contains("rx(0.375) q[2];"));
+        assert!(qasm.contains("measure q[2] -> c[2];"));
+
+        // Replaying the exported program reproduces the pre-measurement state
+        let circuit = parse(&qasm).unwrap();
+        assert_eq!(emit(&circuit), qasm);
+        let mut replay = MiniQuASIM::new(42);
+        let unitary = Circuit::from_gates(12, circuit.gates()[..5].to_vec()).unwrap();
+        unitary.run(&mut replay).unwrap();
+        let mut original = MiniQuASIM::new(42);
+        original.hadamard(0);
+        original.cnot(0, 1);
+        original.rx(2, 0.375);
+        original.t_dagger(1);
+        original.toffoli(0, 1, 2);
+        assert_eq!(replay.get_state_hash(), original.get_state_hash());
+    }
+}
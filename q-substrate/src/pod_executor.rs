@@ -0,0 +1,247 @@
+//! Parallel Multi-Pod Quantum Execution
+//!
+//! Runs N independent quantum pods side by side for Monte Carlo-style
+//! sweeps (noise trajectories, parameter scans). Each pod is its own
+//! [`WasmPod`] with a private Mini QuASIM instance and seed derived from the
+//! executor's base seed and the pod index. Pods run on a rayon thread pool,
+//! but results are collected and aggregated strictly in pod-index order, so
+//! a sweep gives bit-identical figures for any thread count.
+//!
+//! Requires the `parallel` feature (std only).
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{NoiseConfig, QSubstrateConfig};
+use crate::quantum::{Complex, MiniQuASIM, QuantumGate};
+use crate::wasm_pod::{PodConfig, PodType, ProvenanceEntry, WasmPod};
+
+/// Odd multiplier spreading pod indices across the seed space
+const SEED_STRIDE: u32 = 0x9E37_79B9;
+
+/// Result of one pod
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodRun {
+    /// Pod index within the sweep
+    pub pod_index: usize,
+    /// Seed the pod's simulator ran with
+    pub seed: u32,
+    /// Final basis-state probabilities
+    pub probabilities: Vec<f32>,
+    /// Shannon entropy (nats) of the final distribution
+    pub entropy: f32,
+    /// Final state hash
+    pub state_hash: u64,
+    /// Provenance of the pod's execution
+    pub provenance: ProvenanceEntry,
+}
+
+/// Aggregated results of a sweep
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepResult {
+    /// Per-pod results, by pod index
+    pub runs: Vec<PodRun>,
+    /// Probabilities averaged over pods
+    pub mean_probabilities: Vec<f32>,
+    /// Entropy averaged over pods
+    pub mean_entropy: f32,
+}
+
+/// Runs quantum simulations across independent pods in parallel
+#[derive(Debug, Clone)]
+pub struct PodExecutor {
+    /// Number of pods per sweep
+    pods: usize,
+    /// Seed pod seeds derive from
+    base_seed: u32,
+    /// Noise model applied in every pod
+    noise: NoiseConfig,
+    /// Memory limit of each pod in KB
+    memory_limit_kb: usize,
+    /// Worker threads (`None` = rayon's global pool)
+    threads: Option<usize>,
+}
+
+impl PodExecutor {
+    /// Create an executor of `pods` pods with the default quantum pod limit
+    pub fn new(pods: usize, base_seed: u32) -> Self {
+        Self {
+            pods,
+            base_seed,
+            noise: NoiseConfig::default(),
+            memory_limit_kb: 64,
+            threads: None,
+        }
+    }
+
+    /// Create an executor using a runtime configuration's seed, noise model
+    /// and quantum pod memory limit
+    pub fn from_config(config: &QSubstrateConfig, pods: usize) -> Self {
+        Self {
+            noise: config.noise,
+            memory_limit_kb: config.memory.quantum_pod_limit_kb,
+            ..Self::new(pods, config.deterministic_seed)
+        }
+    }
+
+    /// Set the noise model applied in every pod
+    pub fn with_noise(mut self, noise: NoiseConfig) -> Self {
+        self.noise = noise;
+        self
+    }
+
+    /// Run on a dedicated pool of `threads` workers
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Number of pods per sweep
+    pub fn pods(&self) -> usize {
+        self.pods
+    }
+
+    /// Seed of pod `index`
+    pub fn seed_for(&self, index: usize) -> u32 {
+        self.base_seed.wrapping_add((index as u32).wrapping_mul(SEED_STRIDE))
+    }
+
+    /// Run the same gate sequence in every pod
+    ///
+    /// Pods differ only by seed, so this is useful with noise enabled.
+    pub fn run(&self, gates: &[QuantumGate]) -> Result<SweepResult, String> {
+        self.run_with(|_, sim| {
+            for gate in gates {
+                sim.apply_gate(gate);
+            }
+        })
+    }
+
+    /// Run a per-pod program, e.g. a parameter scan keyed by pod index
+    ///
+    /// # Returns
+    /// * Aggregated results in pod-index order
+    /// * `Err` naming the lowest-index pod that failed
+    pub fn run_with<F>(&self, program: F) -> Result<SweepResult, String>
+    where
+        F: Fn(usize, &mut MiniQuASIM) + Sync,
+    {
+        if self.pods == 0 {
+            return Err("Sweep needs at least one pod".into());
+        }
+
+        let sweep = || -> Vec<Result<PodRun, String>> {
+            (0..self.pods).into_par_iter().map(|index| self.run_pod(index, &program)).collect()
+        };
+        let results = match self.threads {
+            Some(threads) => rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .map_err(|e| format!("Thread pool: {}", e))?
+                .install(sweep),
+            None => sweep(),
+        };
+        let runs = results.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        // Sequential f64 accumulation in pod order keeps the mean reproducible
+        let mut sums = vec![0.0_f64; runs[0].probabilities.len()];
+        let mut entropy = 0.0_f64;
+        for run in &runs {
+            for (sum, p) in sums.iter_mut().zip(&run.probabilities) {
+                *sum += *p as f64;
+            }
+            entropy += run.entropy as f64;
+        }
+        let count = runs.len() as f64;
+        Ok(SweepResult {
+            mean_probabilities: sums.into_iter().map(|s| (s / count) as f32).collect(),
+            mean_entropy: (entropy / count) as f32,
+            runs,
+        })
+    }
+
+    /// Execute one pod in isolation
+    fn run_pod<F>(&self, index: usize, program: &F) -> Result<PodRun, String>
+    where
+        F: Fn(usize, &mut MiniQuASIM),
+    {
+        let mut pod = WasmPod::new(PodConfig {
+            pod_id: format!("quantum_pod_{}", index),
+            pod_type: PodType::Quantum,
+            memory_limit_kb: self.memory_limit_kb,
+            ..PodConfig::default()
+        });
+
+        let seed = self.seed_for(index);
+        let mut sim = MiniQuASIM::new(seed);
+        let state_bytes = sim.state_size() * core::mem::size_of::<Complex>();
+        pod.allocate(state_bytes)
+            .map_err(|e| format!("Pod {}: {}", index, e))?;
+        let provenance = pod.record_operation("quantum_sweep");
+
+        sim.set_noise(self.noise);
+        program(index, &mut sim);
+        pod.free(state_bytes);
+
+        Ok(PodRun {
+            pod_index: index,
+            seed,
+            probabilities: sim.get_probabilities(),
+            entropy: sim.entropy(),
+            state_hash: sim.get_state_hash(),
+            provenance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep_is_deterministic_across_thread_counts() {
+        let noise = NoiseConfig { depolarizing: 0.05, ..NoiseConfig::default() };
+        let gates = [QuantumGate::Hadamard(0), QuantumGate::CNOT(0, 1), QuantumGate::RY(2, 0.4)];
+
+        let single = PodExecutor::new(8, 42).with_noise(noise).with_threads(1).run(&gates).unwrap();
+        let pooled = PodExecutor::new(8, 42).with_noise(noise).with_threads(4).run(&gates).unwrap();
+
+        assert_eq!(single.runs.len(), 8);
+        for (index, (a, b)) in single.runs.iter().zip(&pooled.runs).enumerate() {
+            assert_eq!(a.pod_index, index);
+            assert_eq!(a.state_hash, b.state_hash);
+            assert_eq!(a.provenance.source, format!("quantum_pod_{}", index));
+        }
+        assert_eq!(single.mean_entropy.to_bits(), pooled.mean_entropy.to_bits());
+        assert_eq!(single.mean_probabilities, pooled.mean_probabilities);
+
+        // Distinct seeds give distinct noise trajectories
+        let hashes: Vec<u64> = single.runs.iter().map(|r| r.state_hash).collect();
+        assert!(hashes.iter().any(|&h| h != hashes[0]));
+        let total: f32 = single.mean_probabilities.iter().sum();
+        assert!((total - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_parameter_scan_and_limits() {
+        // Pod i rotates qubit 0 by i·π/4: P(|1⟩) = sin²(iπ/8)
+        let executor = PodExecutor::new(5, 7);
+        let sweep = executor
+            .run_with(|index, sim| sim.ry(0, index as f32 * core::f32::consts::FRAC_PI_4))
+            .unwrap();
+        for run in &sweep.runs {
+            let expected = (run.pod_index as f32 * core::f32::consts::PI / 8.0).sin().powi(2);
+            assert!((run.probabilities[1] - expected).abs() < 1e-5);
+        }
+
+        let mut config = QSubstrateConfig::default();
+        config.memory.quantum_pod_limit_kb = 16;
+        let err = PodExecutor::from_config(&config, 3).run(&[]).unwrap_err();
+        assert!(err.starts_with("Pod 0:"));
+        assert!(PodExecutor::new(0, 1).run(&[]).is_err());
+    }
+}
@@ -4,17 +4,31 @@
 //! - 12 qubits (4096 complex amplitudes)
 //! - Full gate set: H, X, Y, Z, S, T, T†, CNOT, CZ, SWAP, Toffoli
 //! - Rotation gates: RX, RY, RZ
+//! - Seeded Z-basis measurement with state collapse (mid-circuit capable)
+//! - Seeded noise channels (depolarizing, bit/phase flip, amplitude damping)
+//! - Entanglement metrics: reduced density matrices, subsystem entropy, concurrence
+//! - Serializable [`Circuit`] IR with adjoint generation and deterministic replay
+//! - Vectorized Hadamard, CNOT and rotations with the `simd` feature
 //! - Fixed-point arithmetic option for micro-devices
 //! - Deterministic state vector representation
 //!
 //! Memory footprint: ~32KB for state vector + minimal overhead
+//!
+//! With the `large-sim` feature, [`LargeQuantumState`] runs the same gate
+//! kernels over heap-allocated registers of up to 24 qubits (128MB).
 
 extern crate alloc;
 
+use alloc::format;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
+use crate::config::NoiseConfig;
+use crate::density::{DensityError, DensityMatrixState};
+use crate::simd;
+#[cfg(not(feature = "std"))]
+use crate::float::FloatExt;
 
 /// Number of qubits in Mini QuASIM
 pub const QUBITS: usize = 12;
@@ -22,8 +36,23 @@ pub const QUBITS: usize = 12;
 /// State vector size: 2^12 = 4096
 pub const STATE_SIZE: usize = 1 << QUBITS;
 
+/// Maximum qubits for a heap-backed [`LargeQuantumState`]
+#[cfg(feature = "large-sim")]
+pub const LARGE_MAX_QUBITS: usize = 24;
+
+/// Largest register any simulator variant in this build can allocate
+#[cfg(feature = "large-sim")]
+pub const MAX_REGISTER_QUBITS: usize = LARGE_MAX_QUBITS;
+
+/// Largest register any simulator variant in this build can allocate
+#[cfg(not(feature = "large-sim"))]
+pub const MAX_REGISTER_QUBITS: usize = QUBITS;
+
 /// Complex number representation (8 bytes per amplitude)
+///
+/// `repr(C)` so SIMD kernels can treat amplitudes as interleaved f32 pairs.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[repr(C)]
 pub struct Complex {
     pub re: f32,
     pub im: f32,
@@ -131,6 +160,228 @@ pub enum QuantumGate {
     RY(usize, f32),
     /// Rotation around Z axis
     RZ(usize, f32),
+    /// Mid-circuit Z-basis measurement (collapses the qubit)
+    Measure(usize),
+}
+
+impl QuantumGate {
+    /// Qubits the gate acts on, controls first
+    pub fn qubits(&self) -> Vec<usize> {
+        match *self {
+            QuantumGate::Hadamard(q)
+            | QuantumGate::PauliX(q)
+            | QuantumGate::PauliY(q)
+            | QuantumGate::PauliZ(q)
+            | QuantumGate::Phase(q)
+            | QuantumGate::T(q)
+            | QuantumGate::TDagger(q)
+            | QuantumGate::RX(q, _)
+            | QuantumGate::RY(q, _)
+            | QuantumGate::RZ(q, _)
+            | QuantumGate::Measure(q) => vec![q],
+            QuantumGate::CNOT(a, b) | QuantumGate::CZ(a, b) | QuantumGate::SWAP(a, b) => vec![a, b],
+            QuantumGate::Toffoli(a, b, c) => vec![a, b, c],
+        }
+    }
+
+    /// Gates implementing the adjoint, in application order
+    ///
+    /// S† has no dedicated gate and is emitted as S followed by Z.
+    /// Returns `None` for measurement, which is not unitary.
+    pub fn adjoint(&self) -> Option<Vec<QuantumGate>> {
+        let gates = match *self {
+            QuantumGate::Phase(q) => vec![QuantumGate::Phase(q), QuantumGate::PauliZ(q)],
+            QuantumGate::T(q) => vec![QuantumGate::TDagger(q)],
+            QuantumGate::TDagger(q) => vec![QuantumGate::T(q)],
+            QuantumGate::RX(q, theta) => vec![QuantumGate::RX(q, -theta)],
+            QuantumGate::RY(q, theta) => vec![QuantumGate::RY(q, -theta)],
+            QuantumGate::RZ(q, theta) => vec![QuantumGate::RZ(q, -theta)],
+            QuantumGate::Measure(_) => return None,
+            // H, X, Y, Z, CNOT, CZ, SWAP and Toffoli are self-inverse
+            ref gate => vec![gate.clone()],
+        };
+        Some(gates)
+    }
+}
+
+/// Circuit IR serialization format version
+pub const CIRCUIT_IR_VERSION: u32 = 1;
+
+/// Circuit IR error types
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// Register wider than any simulator in this build
+    RegisterTooWide(usize),
+    /// Gate addresses a qubit outside the circuit register
+    QubitOutOfRange {
+        /// Index of the gate in the circuit
+        gate: usize,
+        /// Offending qubit
+        qubit: usize,
+    },
+    /// Gate lists the same qubit twice
+    DuplicateQubit {
+        /// Index of the gate in the circuit
+        gate: usize,
+        /// Repeated qubit
+        qubit: usize,
+    },
+    /// Circuit contains a non-unitary gate and has no adjoint
+    NotInvertible {
+        /// Index of the gate in the circuit
+        gate: usize,
+    },
+    /// Simulator register is narrower than the circuit
+    SimulatorTooSmall {
+        /// Qubits the circuit needs
+        required: usize,
+        /// Qubits the simulator has
+        available: usize,
+    },
+    /// Serialized circuit could not be decoded
+    Decode(String),
+    /// Serialized circuit uses an unknown IR version
+    UnsupportedVersion(u32),
+    /// Circuit builder parameter out of range
+    InvalidParameter(String),
+}
+
+impl core::fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CircuitError::RegisterTooWide(n) => {
+                write!(f, "Circuit register of {} qubits exceeds {}", n, MAX_REGISTER_QUBITS)
+            }
+            CircuitError::QubitOutOfRange { gate, qubit } => {
+                write!(f, "Gate {} addresses qubit {} outside the register", gate, qubit)
+            }
+            CircuitError::DuplicateQubit { gate, qubit } => {
+                write!(f, "Gate {} lists qubit {} twice", gate, qubit)
+            }
+            CircuitError::NotInvertible { gate } => {
+                write!(f, "Gate {} is a measurement and has no adjoint", gate)
+            }
+            CircuitError::SimulatorTooSmall { required, available } => {
+                write!(f, "Circuit needs {} qubits, simulator has {}", required, available)
+            }
+            CircuitError::Decode(message) => write!(f, "Invalid circuit: {}", message),
+            CircuitError::UnsupportedVersion(v) => write!(f, "Unsupported circuit IR version {}", v),
+            CircuitError::InvalidParameter(message) => write!(f, "Invalid circuit parameter: {}", message),
+        }
+    }
+}
+
+/// Quantum circuit as a serializable gate list
+///
+/// The IR is the `QuantumGate` sequence itself, so a decoded circuit replays
+/// through [`MiniQuASIM::apply_gate`] exactly as it was built. Measurements
+/// draw from the simulator's seeded PRNG, making replay deterministic for a
+/// given seed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Circuit {
+    /// IR format version
+    version: u32,
+    /// Register width the circuit is written for
+    qubits: usize,
+    /// Gates in application order
+    gates: Vec<QuantumGate>,
+}
+
+impl Circuit {
+    /// Create an empty circuit over `qubits` qubits
+    pub fn new(qubits: usize) -> Result<Self, CircuitError> {
+        if qubits > MAX_REGISTER_QUBITS {
+            return Err(CircuitError::RegisterTooWide(qubits));
+        }
+        Ok(Circuit {
+            version: CIRCUIT_IR_VERSION,
+            qubits,
+            gates: Vec::new(),
+        })
+    }
+
+    /// Create a circuit from a gate list, validating every gate
+    pub fn from_gates(qubits: usize, gates: Vec<QuantumGate>) -> Result<Self, CircuitError> {
+        let mut circuit = Self::new(qubits)?;
+        for gate in gates {
+            circuit.push(gate)?;
+        }
+        Ok(circuit)
+    }
+
+    /// Append a gate
+    pub fn push(&mut self, gate: QuantumGate) -> Result<&mut Self, CircuitError> {
+        self.validate_gate(self.gates.len(), &gate)?;
+        self.gates.push(gate);
+        Ok(self)
+    }
+
+    /// Register width
+    pub fn num_qubits(&self) -> usize {
+        self.qubits
+    }
+
+    /// Gates in application order
+    pub fn gates(&self) -> &[QuantumGate] {
+        &self.gates
+    }
+
+    /// Apply every gate to `sim`
+    pub fn run(&self, sim: &mut MiniQuASIM) -> Result<(), CircuitError> {
+        if sim.num_qubits() < self.qubits {
+            return Err(CircuitError::SimulatorTooSmall {
+                required: self.qubits,
+                available: sim.num_qubits(),
+            });
+        }
+        for gate in &self.gates {
+            sim.apply_gate(gate);
+        }
+        Ok(())
+    }
+
+    /// Adjoint circuit U†, undoing this circuit when run after it
+    pub fn inverse(&self) -> Result<Circuit, CircuitError> {
+        let mut gates = Vec::with_capacity(self.gates.len());
+        for (index, gate) in self.gates.iter().enumerate().rev() {
+            let adjoint = gate.adjoint().ok_or(CircuitError::NotInvertible { gate: index })?;
+            gates.extend(adjoint);
+        }
+        Ok(Circuit {
+            version: CIRCUIT_IR_VERSION,
+            qubits: self.qubits,
+            gates,
+        })
+    }
+
+    /// Encode as JSON, e.g. for a TXO payload
+    pub fn to_json(&self) -> String {
+        // Gate lists contain no maps or non-finite keys; encoding cannot fail
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Decode and validate a JSON-encoded circuit
+    pub fn from_json(json: &str) -> Result<Self, CircuitError> {
+        let decoded: Circuit =
+            serde_json::from_str(json).map_err(|e| CircuitError::Decode(format!("{}", e)))?;
+        if decoded.version != CIRCUIT_IR_VERSION {
+            return Err(CircuitError::UnsupportedVersion(decoded.version));
+        }
+        Self::from_gates(decoded.qubits, decoded.gates)
+    }
+
+    fn validate_gate(&self, index: usize, gate: &QuantumGate) -> Result<(), CircuitError> {
+        let qubits = gate.qubits();
+        for (i, &qubit) in qubits.iter().enumerate() {
+            if qubit >= self.qubits {
+                return Err(CircuitError::QubitOutOfRange { gate: index, qubit });
+            }
+            if qubits[..i].contains(&qubit) {
+                return Err(CircuitError::DuplicateQubit { gate: index, qubit });
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Qubit state information for visualization
@@ -155,16 +406,30 @@ pub struct GateRecord {
     pub gate: String,
     /// Target qubits
     pub qubits: Vec<usize>,
+    /// Rotation angle for RX/RY/RZ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theta: Option<f32>,
     /// Timestamp (operation count)
     pub op_count: u64,
 }
 
 /// Mini QuASIM - 12-Qubit Quantum Simulator
+#[derive(Clone)]
 pub struct MiniQuASIM {
-    /// State vector (4096 complex amplitudes)
+    /// State vector (2^qubits complex amplitudes, 4096 by default)
     amplitudes: Vec<Complex>,
+    /// Register width
+    qubits: usize,
     /// Deterministic seed
     seed: u32,
+    /// Sampling PRNG state (derived from seed)
+    rng_state: u32,
+    /// Noise model applied after each gate
+    noise: NoiseConfig,
+    /// Noise PRNG state, kept apart from sampling so ideal runs are unchanged
+    noise_rng: u32,
+    /// Use SIMD kernels where available
+    simd: bool,
     /// Gate history for audit
     gate_history: Vec<GateRecord>,
     /// Operation counter
@@ -174,17 +439,39 @@ pub struct MiniQuASIM {
 impl MiniQuASIM {
     /// Create a new Mini QuASIM instance
     pub fn new(seed: u32) -> Self {
-        let mut amplitudes = vec![Complex::ZERO; STATE_SIZE];
+        Self::with_qubits(QUBITS, seed)
+    }
+
+    /// Create an instance with an arbitrary register width
+    ///
+    /// Callers bound `qubits`; the amplitude vector is 2^qubits entries.
+    fn with_qubits(qubits: usize, seed: u32) -> Self {
+        let mut amplitudes = vec![Complex::ZERO; 1 << qubits];
         amplitudes[0] = Complex::ONE; // Initialize to |0...0⟩
         
         MiniQuASIM {
             amplitudes,
+            qubits,
             seed,
+            rng_state: seed,
+            noise: NoiseConfig::default(),
+            noise_rng: noise_seed(seed),
+            simd: true,
             gate_history: Vec::new(),
             op_count: 0,
         }
     }
 
+    /// Number of qubits in the register
+    pub fn num_qubits(&self) -> usize {
+        self.qubits
+    }
+
+    /// Number of amplitudes in the state vector (2^qubits)
+    pub fn state_size(&self) -> usize {
+        self.amplitudes.len()
+    }
+
     /// Reset to initial |0...0⟩ state
     pub fn reset(&mut self) {
         for amp in &mut self.amplitudes {
@@ -193,6 +480,33 @@ impl MiniQuASIM {
         self.amplitudes[0] = Complex::ONE;
         self.gate_history.clear();
         self.op_count = 0;
+        self.rng_state = self.seed;
+        self.noise_rng = noise_seed(self.seed);
+    }
+
+    /// Restart the sampling and noise PRNGs from a new seed
+    pub fn reseed(&mut self, seed: u32) {
+        self.seed = seed;
+        self.rng_state = seed;
+        self.noise_rng = noise_seed(seed);
+    }
+
+    /// Set the noise model applied by [`MiniQuASIM::apply_gate`]
+    pub fn set_noise(&mut self, noise: NoiseConfig) {
+        self.noise = noise;
+    }
+
+    /// Current noise model
+    pub fn noise(&self) -> &NoiseConfig {
+        &self.noise
+    }
+
+    /// Enable or disable SIMD kernels (on by default)
+    ///
+    /// Both paths produce bit-identical states; this exists for benchmarks
+    /// and cross-checks. Without the `simd` feature only scalar runs.
+    pub fn set_simd(&mut self, enabled: bool) {
+        self.simd = enabled;
     }
 
     /// Apply a quantum gate
@@ -212,34 +526,126 @@ impl MiniQuASIM {
             QuantumGate::RX(q, theta) => self.rx(*q, *theta),
             QuantumGate::RY(q, theta) => self.ry(*q, *theta),
             QuantumGate::RZ(q, theta) => self.rz(*q, *theta),
+            QuantumGate::Measure(q) => {
+                let r = self.next_uniform();
+                self.collapse_qubit(*q, r);
+            }
+        }
+        if !self.noise.is_ideal() && !matches!(gate, QuantumGate::Measure(_)) {
+            for qubit in gate.qubits() {
+                self.apply_noise(qubit);
+            }
         }
         self.op_count += 1;
     }
 
+    /// Sample every enabled noise channel on one qubit
+    ///
+    /// Channels are unravelled as quantum trajectories: each draws from the
+    /// noise PRNG and either leaves the state alone or applies one Kraus
+    /// operator. Errors are not recorded in the gate history.
+    fn apply_noise(&mut self, qubit: usize) {
+        if qubit >= self.qubits { return; }
+        let noise = self.noise;
+
+        if noise.depolarizing > 0.0 {
+            let r = lcg_uniform(&mut self.noise_rng);
+            if r < noise.depolarizing {
+                // Split the hit uniformly across X, Y and Z
+                match (r / noise.depolarizing * 3.0) as u32 {
+                    0 => self.pauli_error(qubit, true, false),
+                    1 => self.pauli_error(qubit, true, true),
+                    _ => self.pauli_error(qubit, false, true),
+                }
+            }
+        }
+        if noise.bit_flip > 0.0 && lcg_uniform(&mut self.noise_rng) < noise.bit_flip {
+            self.pauli_error(qubit, true, false);
+        }
+        if noise.phase_flip > 0.0 && lcg_uniform(&mut self.noise_rng) < noise.phase_flip {
+            self.pauli_error(qubit, false, true);
+        }
+        if noise.amplitude_damping > 0.0 {
+            let r = lcg_uniform(&mut self.noise_rng);
+            self.amplitude_damp(qubit, noise.amplitude_damping, r);
+        }
+    }
+
+    /// Apply X (`flip`), Z (`phase`), or both (Y up to global phase) unrecorded
+    fn pauli_error(&mut self, qubit: usize, flip: bool, phase: bool) {
+        let mask = 1 << qubit;
+        if phase {
+            for (i, amp) in self.amplitudes.iter_mut().enumerate() {
+                if i & mask != 0 {
+                    *amp = amp.scale(-1.0);
+                }
+            }
+        }
+        if flip {
+            for i in 0..self.amplitudes.len() {
+                if i & mask == 0 {
+                    self.amplitudes.swap(i, i | mask);
+                }
+            }
+        }
+    }
+
+    /// One amplitude-damping trajectory step with rate `gamma`
+    ///
+    /// Decays |1⟩ to |0⟩ with probability γ·P(1); otherwise applies the
+    /// no-jump operator diag(1, √(1-γ)). Either branch is renormalized.
+    fn amplitude_damp(&mut self, qubit: usize, gamma: f32, r: f32) {
+        let mask = 1 << qubit;
+        let p1: f32 = self.amplitudes.iter().enumerate()
+            .filter(|(i, _)| i & mask != 0)
+            .map(|(_, a)| a.norm_sq())
+            .sum();
+        let jump = gamma * p1;
+
+        if r < jump {
+            let factor = 1.0 / p1.sqrt();
+            for i in 0..self.amplitudes.len() {
+                if i & mask == 0 {
+                    self.amplitudes[i] = self.amplitudes[i | mask].scale(factor);
+                    self.amplitudes[i | mask] = Complex::ZERO;
+                }
+            }
+        } else {
+            let keep = (1.0 - gamma).sqrt();
+            let norm = 1.0 / (1.0 - jump).sqrt();
+            for (i, amp) in self.amplitudes.iter_mut().enumerate() {
+                let scale = if i & mask != 0 { keep * norm } else { norm };
+                *amp = amp.scale(scale);
+            }
+        }
+    }
+
     /// Apply Hadamard gate to qubit
     /// H = (1/√2) * [[1, 1], [1, -1]]
     pub fn hadamard(&mut self, qubit: usize) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
         let step = 1 << qubit;
         let h_factor = 0.70710678_f32; // 1/√2
         
-        for i in (0..STATE_SIZE).step_by(2 * step) {
-            for j in 0..step {
-                let idx0 = i + j;
-                let idx1 = idx0 + step;
+        if !(self.simd && simd::hadamard(&mut self.amplitudes, qubit)) {
+            for i in (0..self.amplitudes.len()).step_by(2 * step) {
+                for j in 0..step {
+                    let idx0 = i + j;
+                    let idx1 = idx0 + step;
                 
-                let a0 = self.amplitudes[idx0];
-                let a1 = self.amplitudes[idx1];
+                    let a0 = self.amplitudes[idx0];
+                    let a1 = self.amplitudes[idx1];
                 
-                self.amplitudes[idx0] = Complex::new(
-                    h_factor * (a0.re + a1.re),
-                    h_factor * (a0.im + a1.im)
-                );
-                self.amplitudes[idx1] = Complex::new(
-                    h_factor * (a0.re - a1.re),
-                    h_factor * (a0.im - a1.im)
-                );
+                    self.amplitudes[idx0] = Complex::new(
+                        h_factor * (a0.re + a1.re),
+                        h_factor * (a0.im + a1.im)
+                    );
+                    self.amplitudes[idx1] = Complex::new(
+                        h_factor * (a0.re - a1.re),
+                        h_factor * (a0.im - a1.im)
+                    );
+                }
             }
         }
         
@@ -248,10 +654,10 @@ impl MiniQuASIM {
 
     /// Apply Pauli-X (NOT) gate
     pub fn pauli_x(&mut self, qubit: usize) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
         let step = 1 << qubit;
-        for i in (0..STATE_SIZE).step_by(2 * step) {
+        for i in (0..self.amplitudes.len()).step_by(2 * step) {
             for j in 0..step {
                 let idx0 = i + j;
                 let idx1 = idx0 + step;
@@ -267,10 +673,10 @@ impl MiniQuASIM {
     /// Apply Pauli-Y gate
     /// Y = [[0, -i], [i, 0]]
     pub fn pauli_y(&mut self, qubit: usize) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
         let step = 1 << qubit;
-        for i in (0..STATE_SIZE).step_by(2 * step) {
+        for i in (0..self.amplitudes.len()).step_by(2 * step) {
             for j in 0..step {
                 let idx0 = i + j;
                 let idx1 = idx0 + step;
@@ -290,9 +696,9 @@ impl MiniQuASIM {
     /// Apply Pauli-Z gate
     /// Z = [[1, 0], [0, -1]]
     pub fn pauli_z(&mut self, qubit: usize) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
-        for i in 0..STATE_SIZE {
+        for i in 0..self.amplitudes.len() {
             if (i >> qubit) & 1 == 1 {
                 self.amplitudes[i] = self.amplitudes[i].scale(-1.0);
             }
@@ -304,9 +710,9 @@ impl MiniQuASIM {
     /// Apply Phase gate (S)
     /// S = [[1, 0], [0, i]]
     pub fn phase_gate(&mut self, qubit: usize) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
-        for i in 0..STATE_SIZE {
+        for i in 0..self.amplitudes.len() {
             if (i >> qubit) & 1 == 1 {
                 let amp = self.amplitudes[i];
                 self.amplitudes[i] = Complex::new(-amp.im, amp.re);
@@ -319,11 +725,11 @@ impl MiniQuASIM {
     /// Apply T gate (π/8 gate)
     /// T = [[1, 0], [0, e^(iπ/4)]]
     pub fn t_gate(&mut self, qubit: usize) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
         let t_factor = Complex::new(0.70710678, 0.70710678); // e^(iπ/4)
         
-        for i in 0..STATE_SIZE {
+        for i in 0..self.amplitudes.len() {
             if (i >> qubit) & 1 == 1 {
                 self.amplitudes[i] = self.amplitudes[i].mul(t_factor);
             }
@@ -335,11 +741,11 @@ impl MiniQuASIM {
     /// Apply T-dagger gate
     /// T† = [[1, 0], [0, e^(-iπ/4)]]
     pub fn t_dagger(&mut self, qubit: usize) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
         let t_dag_factor = Complex::new(0.70710678, -0.70710678);
         
-        for i in 0..STATE_SIZE {
+        for i in 0..self.amplitudes.len() {
             if (i >> qubit) & 1 == 1 {
                 self.amplitudes[i] = self.amplitudes[i].mul(t_dag_factor);
             }
@@ -350,18 +756,18 @@ impl MiniQuASIM {
 
     /// Apply CNOT gate
     pub fn cnot(&mut self, control: usize, target: usize) {
-        if control >= QUBITS || target >= QUBITS { return; }
+        if control >= self.qubits || target >= self.qubits { return; }
         
         let ctrl_mask = 1 << control;
         let targ_mask = 1 << target;
         
-        for i in 0..STATE_SIZE {
-            if (i & ctrl_mask) != 0 {
-                let pair_idx = i ^ targ_mask;
-                if i < pair_idx {
-                    let temp = self.amplitudes[i];
-                    self.amplitudes[i] = self.amplitudes[pair_idx];
-                    self.amplitudes[pair_idx] = temp;
+        if !(self.simd && simd::cnot(&mut self.amplitudes, control, target)) {
+            for i in 0..self.amplitudes.len() {
+                if (i & ctrl_mask) != 0 {
+                    let pair_idx = i ^ targ_mask;
+                    if i < pair_idx {
+                        self.amplitudes.swap(i, pair_idx);
+                    }
                 }
             }
         }
@@ -371,12 +777,12 @@ impl MiniQuASIM {
 
     /// Apply Controlled-Z gate
     pub fn cz(&mut self, control: usize, target: usize) {
-        if control >= QUBITS || target >= QUBITS { return; }
+        if control >= self.qubits || target >= self.qubits { return; }
         
         let ctrl_mask = 1 << control;
         let targ_mask = 1 << target;
         
-        for i in 0..STATE_SIZE {
+        for i in 0..self.amplitudes.len() {
             if (i & ctrl_mask) != 0 && (i & targ_mask) != 0 {
                 self.amplitudes[i] = self.amplitudes[i].scale(-1.0);
             }
@@ -387,12 +793,12 @@ impl MiniQuASIM {
 
     /// Apply SWAP gate
     pub fn swap(&mut self, qubit1: usize, qubit2: usize) {
-        if qubit1 >= QUBITS || qubit2 >= QUBITS { return; }
+        if qubit1 >= self.qubits || qubit2 >= self.qubits { return; }
         
         let mask1 = 1 << qubit1;
         let mask2 = 1 << qubit2;
         
-        for i in 0..STATE_SIZE {
+        for i in 0..self.amplitudes.len() {
             let bit1 = (i & mask1) >> qubit1;
             let bit2 = (i & mask2) >> qubit2;
             
@@ -411,13 +817,13 @@ impl MiniQuASIM {
 
     /// Apply Toffoli (CCNOT) gate
     pub fn toffoli(&mut self, control1: usize, control2: usize, target: usize) {
-        if control1 >= QUBITS || control2 >= QUBITS || target >= QUBITS { return; }
+        if control1 >= self.qubits || control2 >= self.qubits || target >= self.qubits { return; }
         
         let ctrl1_mask = 1 << control1;
         let ctrl2_mask = 1 << control2;
         let targ_mask = 1 << target;
         
-        for i in 0..STATE_SIZE {
+        for i in 0..self.amplitudes.len() {
             if (i & ctrl1_mask) != 0 && (i & ctrl2_mask) != 0 {
                 let pair_idx = i ^ targ_mask;
                 if i < pair_idx {
@@ -433,94 +839,100 @@ impl MiniQuASIM {
 
     /// Apply RX rotation
     pub fn rx(&mut self, qubit: usize, theta: f32) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
         let cos_half = (theta / 2.0).cos();
         let sin_half = (theta / 2.0).sin();
         let step = 1 << qubit;
         
-        for i in (0..STATE_SIZE).step_by(2 * step) {
-            for j in 0..step {
-                let idx0 = i + j;
-                let idx1 = idx0 + step;
+        if !(self.simd && simd::rx(&mut self.amplitudes, qubit, cos_half, sin_half)) {
+            for i in (0..self.amplitudes.len()).step_by(2 * step) {
+                for j in 0..step {
+                    let idx0 = i + j;
+                    let idx1 = idx0 + step;
                 
-                let a0 = self.amplitudes[idx0];
-                let a1 = self.amplitudes[idx1];
+                    let a0 = self.amplitudes[idx0];
+                    let a1 = self.amplitudes[idx1];
                 
-                self.amplitudes[idx0] = Complex::new(
-                    cos_half * a0.re + sin_half * a1.im,
-                    cos_half * a0.im - sin_half * a1.re,
-                );
-                self.amplitudes[idx1] = Complex::new(
-                    cos_half * a1.re + sin_half * a0.im,
-                    cos_half * a1.im - sin_half * a0.re,
-                );
+                    self.amplitudes[idx0] = Complex::new(
+                        cos_half * a0.re + sin_half * a1.im,
+                        cos_half * a0.im - sin_half * a1.re,
+                    );
+                    self.amplitudes[idx1] = Complex::new(
+                        cos_half * a1.re + sin_half * a0.im,
+                        cos_half * a1.im - sin_half * a0.re,
+                    );
+                }
             }
         }
         
-        self.record_gate("RX", vec![qubit]);
+        self.record_rotation("RX", qubit, theta);
     }
 
     /// Apply RY rotation
     pub fn ry(&mut self, qubit: usize, theta: f32) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
         let cos_half = (theta / 2.0).cos();
         let sin_half = (theta / 2.0).sin();
         let step = 1 << qubit;
         
-        for i in (0..STATE_SIZE).step_by(2 * step) {
-            for j in 0..step {
-                let idx0 = i + j;
-                let idx1 = idx0 + step;
+        if !(self.simd && simd::ry(&mut self.amplitudes, qubit, cos_half, sin_half)) {
+            for i in (0..self.amplitudes.len()).step_by(2 * step) {
+                for j in 0..step {
+                    let idx0 = i + j;
+                    let idx1 = idx0 + step;
                 
-                let a0 = self.amplitudes[idx0];
-                let a1 = self.amplitudes[idx1];
+                    let a0 = self.amplitudes[idx0];
+                    let a1 = self.amplitudes[idx1];
                 
-                self.amplitudes[idx0] = Complex::new(
-                    cos_half * a0.re - sin_half * a1.re,
-                    cos_half * a0.im - sin_half * a1.im,
-                );
-                self.amplitudes[idx1] = Complex::new(
-                    sin_half * a0.re + cos_half * a1.re,
-                    sin_half * a0.im + cos_half * a1.im,
-                );
+                    self.amplitudes[idx0] = Complex::new(
+                        cos_half * a0.re - sin_half * a1.re,
+                        cos_half * a0.im - sin_half * a1.im,
+                    );
+                    self.amplitudes[idx1] = Complex::new(
+                        sin_half * a0.re + cos_half * a1.re,
+                        sin_half * a0.im + cos_half * a1.im,
+                    );
+                }
             }
         }
         
-        self.record_gate("RY", vec![qubit]);
+        self.record_rotation("RY", qubit, theta);
     }
 
     /// Apply RZ rotation
     pub fn rz(&mut self, qubit: usize, theta: f32) {
-        if qubit >= QUBITS { return; }
+        if qubit >= self.qubits { return; }
         
         let cos_half = (theta / 2.0).cos();
         let sin_half = (theta / 2.0).sin();
         
-        for i in 0..STATE_SIZE {
-            if (i >> qubit) & 1 == 0 {
-                let amp = self.amplitudes[i];
-                self.amplitudes[i] = Complex::new(
-                    cos_half * amp.re + sin_half * amp.im,
-                    cos_half * amp.im - sin_half * amp.re,
-                );
-            } else {
-                let amp = self.amplitudes[i];
-                self.amplitudes[i] = Complex::new(
-                    cos_half * amp.re - sin_half * amp.im,
-                    cos_half * amp.im + sin_half * amp.re,
-                );
+        if !(self.simd && simd::rz(&mut self.amplitudes, qubit, cos_half, sin_half)) {
+            for i in 0..self.amplitudes.len() {
+                if (i >> qubit) & 1 == 0 {
+                    let amp = self.amplitudes[i];
+                    self.amplitudes[i] = Complex::new(
+                        cos_half * amp.re + sin_half * amp.im,
+                        cos_half * amp.im - sin_half * amp.re,
+                    );
+                } else {
+                    let amp = self.amplitudes[i];
+                    self.amplitudes[i] = Complex::new(
+                        cos_half * amp.re - sin_half * amp.im,
+                        cos_half * amp.im + sin_half * amp.re,
+                    );
+                }
             }
         }
         
-        self.record_gate("RZ", vec![qubit]);
+        self.record_rotation("RZ", qubit, theta);
     }
 
     /// Get probability of a computational basis state
     #[inline]
     pub fn measure_prob(&self, state: usize) -> f32 {
-        if state < STATE_SIZE {
+        if state < self.amplitudes.len() {
             self.amplitudes[state].norm_sq()
         } else {
             0.0
@@ -532,6 +944,125 @@ impl MiniQuASIM {
         self.amplitudes.iter().map(|a| a.norm_sq()).collect()
     }
 
+    /// Get the amplitude of a computational basis state
+    #[inline]
+    pub fn amplitude(&self, state: usize) -> Complex {
+        if state < self.amplitudes.len() {
+            self.amplitudes[state]
+        } else {
+            Complex::ZERO
+        }
+    }
+
+    /// Sample computational-basis measurement outcomes of all qubits
+    ///
+    /// Non-destructive: the state vector is not collapsed. Outcomes are
+    /// drawn from the seed-controlled PRNG, so the same seed, circuit and
+    /// call sequence always yield the same shots.
+    pub fn sample(&mut self, shots: usize) -> Vec<usize> {
+        let qubits: Vec<usize> = (0..self.qubits).collect();
+        self.sample_qubits(&qubits, shots)
+    }
+
+    /// Sample measurement outcomes of a subset of qubits
+    ///
+    /// Bit `i` of each outcome is the measured value of `qubits[i]`.
+    /// Out-of-range qubits always read 0.
+    pub fn sample_qubits(&mut self, qubits: &[usize], shots: usize) -> Vec<usize> {
+        // Marginal distribution over the requested qubits
+        let mut marginal = vec![0.0_f32; 1 << qubits.len()];
+        for (index, amp) in self.amplitudes.iter().enumerate() {
+            let outcome = qubits
+                .iter()
+                .enumerate()
+                .filter(|(_, &q)| q < self.qubits && (index >> q) & 1 == 1)
+                .fold(0, |acc, (bit, _)| acc | (1 << bit));
+            marginal[outcome] += amp.norm_sq();
+        }
+
+        let mut cumulative = Vec::with_capacity(marginal.len());
+        let mut total = 0.0_f32;
+        for p in marginal {
+            total += p;
+            cumulative.push(total);
+        }
+
+        (0..shots)
+            .map(|_| {
+                let r = self.next_uniform() * total;
+                cumulative
+                    .partition_point(|&c| c <= r)
+                    .min(cumulative.len() - 1)
+            })
+            .collect()
+    }
+
+    /// Measure one qubit in the Z basis, collapsing the state
+    ///
+    /// The outcome is drawn from `seed` alone, independent of the sampling
+    /// PRNG, so replaying a circuit with the same per-measurement seeds
+    /// reproduces every collapse. Out-of-range qubits read `false` and
+    /// leave the state untouched.
+    pub fn measure_qubit(&mut self, qubit: usize, seed: u32) -> bool {
+        // Avalanche the seed first so neighbouring seeds give unrelated draws
+        let mut rng_state = seed ^ (seed >> 16);
+        rng_state = rng_state.wrapping_mul(0x85eb_ca6b);
+        rng_state ^= rng_state >> 13;
+        rng_state = rng_state.wrapping_mul(0xc2b2_ae35);
+        rng_state ^= rng_state >> 16;
+        let r = lcg_uniform(&mut rng_state);
+        self.collapse_qubit(qubit, r)
+    }
+
+    /// Measure every qubit, collapsing to a single basis state
+    ///
+    /// The outcome is drawn from the sampling PRNG. Returns the bitstring
+    /// most-significant qubit first, matching [`QubitState::binary`].
+    pub fn measure_all(&mut self) -> String {
+        let r = self.next_uniform();
+        let mut outcome = self.amplitudes.len() - 1;
+        let mut cumulative = 0.0_f32;
+        let total: f32 = self.amplitudes.iter().map(|a| a.norm_sq()).sum();
+        for (index, amp) in self.amplitudes.iter().enumerate() {
+            cumulative += amp.norm_sq();
+            if r * total < cumulative {
+                outcome = index;
+                break;
+            }
+        }
+
+        for (index, amp) in self.amplitudes.iter_mut().enumerate() {
+            *amp = if index == outcome { Complex::ONE } else { Complex::ZERO };
+        }
+        self.record_gate("M", (0..self.qubits).collect());
+        format!("{:0width$b}", outcome, width = self.qubits)
+    }
+
+    /// Project `qubit` onto the outcome selected by `r` in [0, 1) and renormalize
+    fn collapse_qubit(&mut self, qubit: usize, r: f32) -> bool {
+        if qubit >= self.qubits { return false; }
+
+        let mask = 1 << qubit;
+        let (p0, p1) = self.amplitudes.iter().enumerate().fold((0.0_f32, 0.0_f32), |(p0, p1), (i, a)| {
+            if i & mask == 0 { (p0 + a.norm_sq(), p1) } else { (p0, p1 + a.norm_sq()) }
+        });
+        let total = p0 + p1;
+        let outcome = total > 0.0 && r * total >= p0;
+        let kept = if outcome { p1 } else { p0 };
+        let factor = if kept > 0.0 { 1.0 / kept.sqrt() } else { 0.0 };
+
+        for (i, amp) in self.amplitudes.iter_mut().enumerate() {
+            *amp = if (i & mask != 0) == outcome { amp.scale(factor) } else { Complex::ZERO };
+        }
+        self.record_gate("M", vec![qubit]);
+        outcome
+    }
+
+    /// Next uniform sample in [0, 1) from the sampling PRNG
+    fn next_uniform(&mut self) -> f32 {
+        lcg_uniform(&mut self.rng_state)
+    }
+
     /// Get quantum state information for visualization
     pub fn get_state_info(&self, max_states: usize) -> Vec<QubitState> {
         let mut states: Vec<QubitState> = self.amplitudes
@@ -544,7 +1075,7 @@ impl MiniQuASIM {
                 amplitude: amp.norm_sq().sqrt(),
                 phase: amp.phase(),
                 probability: amp.norm_sq(),
-                binary: format!("{:012b}", idx),
+                binary: format!("{:0width$b}", idx, width = self.qubits),
             })
             .collect();
         
@@ -555,7 +1086,11 @@ impl MiniQuASIM {
         states
     }
 
-    /// Calculate Shannon entropy
+    /// Shannon entropy (nats) of the full measurement distribution
+    ///
+    /// This measures spread over basis states, not entanglement: |+⟩^⊗n
+    /// scores high with no entanglement at all. For subsystem von Neumann
+    /// entropy use [`MiniQuASIM::subsystem_entropy`].
     pub fn entropy(&self) -> f32 {
         let mut entropy = 0.0_f32;
         for amp in &self.amplitudes {
@@ -567,6 +1102,29 @@ impl MiniQuASIM {
         entropy
     }
 
+    /// Reduced density matrix of `qubits`, tracing out the rest
+    ///
+    /// Bit `i` of the result is `qubits[i]`; at most
+    /// [`crate::density::MAX_DENSITY_QUBITS`] qubits may be kept.
+    pub fn reduced_density_matrix(&self, qubits: &[usize]) -> Result<DensityMatrixState, DensityError> {
+        DensityMatrixState::from_simulator(self, qubits)
+    }
+
+    /// Entanglement entropy (bits) between `qubits` and the rest of the register
+    ///
+    /// 0 for a product state; 1 for either half of a Bell pair.
+    pub fn subsystem_entropy(&self, qubits: &[usize]) -> Result<f32, DensityError> {
+        Ok(self.reduced_density_matrix(qubits)?.von_neumann_entropy())
+    }
+
+    /// Concurrence of the qubit pair `(a, b)` after tracing out the rest
+    ///
+    /// 1 for a Bell pair; 0 for separable pairs, including each pair of a
+    /// GHZ state, whose entanglement is only visible tripartitely.
+    pub fn concurrence(&self, a: usize, b: usize) -> Result<f32, DensityError> {
+        self.reduced_density_matrix(&[a, b])?.concurrence()
+    }
+
     /// Get state hash for determinism verification
     pub fn get_state_hash(&self) -> u64 {
         let mut hash: u64 = 0;
@@ -592,10 +1150,19 @@ impl MiniQuASIM {
         self.gate_history.push(GateRecord {
             gate: gate.into(),
             qubits,
+            theta: None,
             op_count: self.op_count,
         });
     }
 
+    /// Record a rotation gate with its angle
+    fn record_rotation(&mut self, gate: &str, qubit: usize, theta: f32) {
+        self.record_gate(gate, vec![qubit]);
+        if let Some(record) = self.gate_history.last_mut() {
+            record.theta = Some(theta);
+        }
+    }
+
     /// Run Bell state circuit: (|00⟩ + |11⟩)/√2
     pub fn bell_state(&mut self) -> (f32, f32) {
         self.reset();
@@ -614,12 +1181,81 @@ impl MiniQuASIM {
     }
 }
 
+/// Noise PRNG seed, decorrelated from the sampling stream
+fn noise_seed(seed: u32) -> u32 {
+    let mut state = seed ^ 0x9e37_79b9;
+    state ^= state >> 16;
+    state = state.wrapping_mul(0x85eb_ca6b);
+    state ^= state >> 13;
+    state
+}
+
+/// Step a 32-bit LCG and map its high bits to [0, 1)
+fn lcg_uniform(state: &mut u32) -> f32 {
+    *state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+    (*state >> 8) as f32 / (1u32 << 24) as f32
+}
+
 impl Default for MiniQuASIM {
     fn default() -> Self {
         Self::new(42) // Default deterministic seed
     }
 }
 
+/// Heap-backed quantum state for registers beyond 12 qubits
+///
+/// Wraps a [`MiniQuASIM`] whose amplitude vector is sized at construction,
+/// so every gate, sampling and audit method is available unchanged through
+/// `Deref`. A 24-qubit register holds 2^24 amplitudes (128MB).
+#[cfg(feature = "large-sim")]
+#[derive(Clone)]
+pub struct LargeQuantumState {
+    /// Underlying simulator with a wide register
+    sim: MiniQuASIM,
+}
+
+#[cfg(feature = "large-sim")]
+impl LargeQuantumState {
+    /// Allocate a `qubits`-wide register initialised to |0...0⟩
+    pub fn new(qubits: usize, seed: u32) -> Result<Self, String> {
+        if qubits == 0 || qubits > LARGE_MAX_QUBITS {
+            return Err(format!(
+                "Large state supports 1-{} qubits, got {}",
+                LARGE_MAX_QUBITS, qubits
+            ));
+        }
+        Ok(LargeQuantumState {
+            sim: MiniQuASIM::with_qubits(qubits, seed),
+        })
+    }
+
+    /// State vector size in bytes
+    pub fn state_bytes(&self) -> usize {
+        self.sim.state_size() * core::mem::size_of::<Complex>()
+    }
+
+    /// Unwrap into the underlying simulator
+    pub fn into_inner(self) -> MiniQuASIM {
+        self.sim
+    }
+}
+
+#[cfg(feature = "large-sim")]
+impl core::ops::Deref for LargeQuantumState {
+    type Target = MiniQuASIM;
+
+    fn deref(&self) -> &MiniQuASIM {
+        &self.sim
+    }
+}
+
+#[cfg(feature = "large-sim")]
+impl core::ops::DerefMut for LargeQuantumState {
+    fn deref_mut(&mut self) -> &mut MiniQuASIM {
+        &mut self.sim
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -658,6 +1294,29 @@ mod tests {
         assert!((p111 - 0.5).abs() < 0.01);
     }
 
+    #[test]
+    fn test_entanglement_metrics() {
+        let mut qs = MiniQuASIM::new(42);
+        qs.bell_state();
+        assert!((qs.subsystem_entropy(&[0]).unwrap() - 1.0).abs() < 1e-3);
+        assert!((qs.concurrence(0, 1).unwrap() - 1.0).abs() < 1e-3);
+        assert!(qs.concurrence(0, 2).unwrap() < 1e-3);
+        assert!(qs.subsystem_entropy(&[0, 1]).unwrap().abs() < 1e-3);
+
+        // Unlike Shannon entropy, |+⟩|+⟩ carries no entanglement
+        qs.reset();
+        qs.hadamard(0);
+        qs.hadamard(1);
+        assert!(qs.entropy() > 1.0);
+        assert!(qs.subsystem_entropy(&[0]).unwrap().abs() < 1e-3);
+
+        // GHZ pairs are separable, yet each qubit is maximally entangled with the rest
+        qs.ghz_state();
+        assert!(qs.concurrence(0, 1).unwrap() < 1e-3);
+        assert!((qs.subsystem_entropy(&[2]).unwrap() - 1.0).abs() < 1e-3);
+        assert_eq!(qs.reduced_density_matrix(&[0, 12]).unwrap_err(), DensityError::QubitOutOfRange(12));
+    }
+
     #[test]
     fn test_determinism() {
         let mut qs1 = MiniQuASIM::new(42);
@@ -691,4 +1350,199 @@ mod tests {
         assert_eq!(history[0].gate, "H");
         assert_eq!(history[1].gate, "CNOT");
     }
+
+    #[test]
+    fn test_sampling_deterministic() {
+        let mut qs1 = MiniQuASIM::new(7);
+        let mut qs2 = MiniQuASIM::new(7);
+        qs1.bell_state();
+        qs2.bell_state();
+
+        let shots1 = qs1.sample_qubits(&[0, 1], 1000);
+        assert_eq!(shots1, qs2.sample_qubits(&[0, 1], 1000));
+
+        // Bell state: only 00 and 11, roughly balanced
+        assert!(shots1.iter().all(|&s| s == 0 || s == 3));
+        let ones = shots1.iter().filter(|&&s| s == 3).count();
+        assert!((400..600).contains(&ones));
+
+        // Full-register samples index the state vector directly
+        qs1.pauli_x(5);
+        assert!(qs1.sample(10).iter().all(|&s| s == 0b100000 || s == 0b100011));
+    }
+
+    #[test]
+    fn test_measurement_collapse() {
+        // Same seed, same collapse; the partner qubit follows the outcome
+        let mut qs1 = MiniQuASIM::new(42);
+        let mut qs2 = MiniQuASIM::new(42);
+        qs1.bell_state();
+        qs2.bell_state();
+        let bit = qs1.measure_qubit(0, 1234);
+        assert_eq!(bit, qs2.measure_qubit(0, 1234));
+        assert_eq!(qs1.get_state_hash(), qs2.get_state_hash());
+        let expected = if bit { 3 } else { 0 };
+        assert!((qs1.measure_prob(expected) - 1.0).abs() < 1e-5);
+        assert_eq!(qs1.measure_qubit(1, 99), bit);
+
+        // Both outcomes are reachable across seeds
+        let ones = (0..200u32)
+            .filter(|&seed| {
+                let mut qs = MiniQuASIM::new(42);
+                qs.hadamard(3);
+                qs.measure_qubit(3, seed)
+            })
+            .count();
+        assert!((60..140).contains(&ones));
+
+        // Mid-circuit measurement via the gate list, then full readout
+        let mut qs = MiniQuASIM::new(7);
+        qs.apply_gate(&QuantumGate::Hadamard(0));
+        qs.apply_gate(&QuantumGate::Measure(0));
+        qs.apply_gate(&QuantumGate::CNOT(0, 2));
+        let bits = qs.measure_all();
+        assert_eq!(bits.len(), QUBITS);
+        assert!(bits == "000000000000" || bits == "000000000101");
+        assert_eq!(qs.get_gate_history().last().unwrap().gate, "M");
+    }
+
+    #[test]
+    fn test_noise_channels_seeded() {
+        let circuit = [QuantumGate::Hadamard(0), QuantumGate::CNOT(0, 1), QuantumGate::PauliX(2)];
+        let noisy = NoiseConfig { depolarizing: 0.2, bit_flip: 0.1, phase_flip: 0.1, amplitude_damping: 0.3 };
+
+        // Same seed, same trajectory; noise does not change gate history
+        let mut qs1 = MiniQuASIM::new(42);
+        let mut qs2 = MiniQuASIM::new(42);
+        qs1.set_noise(noisy);
+        qs2.set_noise(noisy);
+        for gate in &circuit {
+            qs1.apply_gate(gate);
+            qs2.apply_gate(gate);
+        }
+        assert_eq!(qs1.get_state_hash(), qs2.get_state_hash());
+        assert_eq!(qs1.get_gate_history().len(), circuit.len());
+        let total: f32 = qs1.get_probabilities().iter().sum();
+        assert!((total - 1.0).abs() < 1e-4);
+
+        // Certain bit flip undoes X
+        let mut qs = MiniQuASIM::new(1);
+        qs.set_noise(NoiseConfig { bit_flip: 1.0, ..NoiseConfig::default() });
+        qs.apply_gate(&QuantumGate::PauliX(0));
+        assert!((qs.measure_prob(0) - 1.0).abs() < 1e-5);
+
+        // Full amplitude damping relaxes |1⟩ to |0⟩
+        let mut qs = MiniQuASIM::new(1);
+        qs.set_noise(NoiseConfig { amplitude_damping: 1.0, ..NoiseConfig::default() });
+        qs.apply_gate(&QuantumGate::PauliX(3));
+        assert!((qs.measure_prob(0) - 1.0).abs() < 1e-5);
+
+        // Phase flips on |+⟩ show up after an ideal closing Hadamard at roughly p
+        let flips = (0..200u32)
+            .filter(|&seed| {
+                let mut qs = MiniQuASIM::new(seed);
+                qs.set_noise(NoiseConfig { phase_flip: 0.5, ..NoiseConfig::default() });
+                qs.apply_gate(&QuantumGate::Hadamard(0));
+                qs.hadamard(0);
+                qs.measure_prob(1) > 0.5
+            })
+            .count();
+        assert!((60..140).contains(&flips));
+
+        // Ideal runs are untouched by the noise stream
+        let mut ideal = MiniQuASIM::new(42);
+        let mut reference = MiniQuASIM::new(42);
+        ideal.set_noise(NoiseConfig::default());
+        for gate in &circuit {
+            ideal.apply_gate(gate);
+            reference.apply_gate(gate);
+        }
+        assert_eq!(ideal.get_state_hash(), reference.get_state_hash());
+    }
+
+    #[test]
+    fn test_circuit_inverse_restores_state() {
+        let mut circuit = Circuit::new(3).unwrap();
+        circuit
+            .push(QuantumGate::Hadamard(0)).unwrap()
+            .push(QuantumGate::Phase(0)).unwrap()
+            .push(QuantumGate::T(1)).unwrap()
+            .push(QuantumGate::CNOT(0, 2)).unwrap()
+            .push(QuantumGate::RY(1, 0.7)).unwrap()
+            .push(QuantumGate::Toffoli(0, 1, 2)).unwrap();
+
+        let mut qs = MiniQuASIM::new(42);
+        circuit.run(&mut qs).unwrap();
+        assert!((qs.measure_prob(0) - 1.0).abs() > 0.1);
+        circuit.inverse().unwrap().run(&mut qs).unwrap();
+        assert!((qs.measure_prob(0) - 1.0).abs() < 1e-5);
+
+        circuit.push(QuantumGate::Measure(2)).unwrap();
+        assert_eq!(circuit.inverse().unwrap_err(), CircuitError::NotInvertible { gate: 6 });
+        assert_eq!(
+            circuit.push(QuantumGate::CZ(1, 3)).unwrap_err(),
+            CircuitError::QubitOutOfRange { gate: 7, qubit: 3 }
+        );
+        assert_eq!(
+            circuit.push(QuantumGate::SWAP(2, 2)).unwrap_err(),
+            CircuitError::DuplicateQubit { gate: 7, qubit: 2 }
+        );
+    }
+
+    #[test]
+    fn test_circuit_serialization_replay() {
+        let circuit = Circuit::from_gates(
+            2,
+            vec![
+                QuantumGate::Hadamard(0),
+                QuantumGate::Measure(0),
+                QuantumGate::CNOT(0, 1),
+                QuantumGate::RX(1, 0.25),
+            ],
+        )
+        .unwrap();
+        let decoded = Circuit::from_json(&circuit.to_json()).unwrap();
+        assert_eq!(decoded.to_json(), circuit.to_json());
+
+        // Same seed, same mid-circuit collapse
+        let mut qs1 = MiniQuASIM::new(9);
+        let mut qs2 = MiniQuASIM::new(9);
+        circuit.run(&mut qs1).unwrap();
+        decoded.run(&mut qs2).unwrap();
+        assert_eq!(qs1.get_state_hash(), qs2.get_state_hash());
+
+        let tampered = circuit.to_json().replace("\"CNOT\":[0,1]", "\"CNOT\":[0,5]");
+        assert_eq!(
+            Circuit::from_json(&tampered).unwrap_err(),
+            CircuitError::QubitOutOfRange { gate: 2, qubit: 5 }
+        );
+        let future = circuit.to_json().replace("\"version\":1", "\"version\":2");
+        assert_eq!(Circuit::from_json(&future).unwrap_err(), CircuitError::UnsupportedVersion(2));
+        assert!(matches!(Circuit::from_json("{}"), Err(CircuitError::Decode(_))));
+        assert_eq!(Circuit::new(MAX_REGISTER_QUBITS + 1).unwrap_err(), CircuitError::RegisterTooWide(MAX_REGISTER_QUBITS + 1));
+    }
+
+    #[cfg(feature = "large-sim")]
+    #[test]
+    fn test_large_state_gates() {
+        assert!(LargeQuantumState::new(LARGE_MAX_QUBITS + 1, 42).is_err());
+
+        let mut qs = LargeQuantumState::new(16, 42).unwrap();
+        assert_eq!(qs.num_qubits(), 16);
+        assert_eq!(qs.state_bytes(), (1 << 16) * 8);
+
+        // Entangle qubit 0 with qubit 15, beyond the 12-qubit register
+        qs.apply_gate(&QuantumGate::Hadamard(0));
+        qs.apply_gate(&QuantumGate::CNOT(0, 15));
+        assert!((qs.measure_prob(0) - 0.5).abs() < 0.01);
+        assert!((qs.measure_prob((1 << 15) | 1) - 0.5).abs() < 0.01);
+        assert!(qs.sample(100).iter().all(|&s| s == 0 || s == (1 << 15) | 1));
+        assert_eq!(qs.get_state_info(1)[0].binary.len(), 16);
+
+        // Same circuit on the 12-qubit register agrees on the shared qubits
+        let mut small = MiniQuASIM::new(42);
+        let (p00, p11) = small.bell_state();
+        let (l00, l11) = qs.bell_state();
+        assert_eq!((p00, p11), (l00, l11));
+    }
 }
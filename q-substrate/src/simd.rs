@@ -0,0 +1,366 @@
+//! SIMD Gate Kernels
+//!
+//! Vectorized Hadamard, CNOT and RX/RY/RZ kernels for the state vector,
+//! built with the `simd` feature (std only):
+//! - x86_64: AVX, 4 amplitudes per vector, selected at runtime
+//! - aarch64: NEON, 2 amplitudes per vector
+//!
+//! Every entry point returns `false` when it cannot take the call (feature
+//! off, no CPU support, or a gate stride narrower than one vector, i.e. the
+//! lowest qubits) and [`crate::quantum::MiniQuASIM`] runs its scalar loop
+//! instead. Kernels issue the same IEEE operations in the same order as the
+//! scalar loops and never fuse multiply-add, so results are bit-identical
+//! and state hashes do not depend on which path ran.
+
+use crate::quantum::Complex;
+
+/// Whether this build and CPU have a vector path
+pub fn available() -> bool {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        std::is_x86_feature_detected!("avx")
+    }
+    #[cfg(all(feature = "simd", target_arch = "aarch64"))]
+    {
+        true
+    }
+    #[cfg(not(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64"))))]
+    {
+        false
+    }
+}
+
+/// Run a kernel on the widest available vector type
+///
+/// `stride` is the smallest run of contiguous amplitudes the gate treats
+/// uniformly; it must cover a whole vector.
+macro_rules! dispatch {
+    ($stride:expr, $kernel:ident($($arg:expr),*)) => {{
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if $stride >= avx::Avx::WIDTH && std::is_x86_feature_detected!("avx") {
+                // SAFETY: AVX support was detected at runtime
+                unsafe { avx::$kernel($($arg),*) };
+                return true;
+            }
+        }
+        #[cfg(all(feature = "simd", target_arch = "aarch64"))]
+        {
+            if $stride >= neon::Neon::WIDTH {
+                // SAFETY: NEON is part of the aarch64 baseline
+                unsafe { kernels::$kernel::<neon::Neon>($($arg),*) };
+                return true;
+            }
+        }
+        let _ = ($stride, $($arg),*);
+        false
+    }};
+}
+
+/// Hadamard on `qubit`; `false` if the caller must fall back to scalar
+pub(crate) fn hadamard(amps: &mut [Complex], qubit: usize) -> bool {
+    dispatch!(1usize << qubit, hadamard(amps, qubit))
+}
+
+/// CNOT; `false` if the caller must fall back to scalar
+pub(crate) fn cnot(amps: &mut [Complex], control: usize, target: usize) -> bool {
+    dispatch!(1usize << control.min(target), cnot(amps, control, target))
+}
+
+/// RX(θ) from precomputed cos(θ/2), sin(θ/2)
+pub(crate) fn rx(amps: &mut [Complex], qubit: usize, cos_half: f32, sin_half: f32) -> bool {
+    dispatch!(1usize << qubit, rx(amps, qubit, cos_half, sin_half))
+}
+
+/// RY(θ) from precomputed cos(θ/2), sin(θ/2)
+pub(crate) fn ry(amps: &mut [Complex], qubit: usize, cos_half: f32, sin_half: f32) -> bool {
+    dispatch!(1usize << qubit, ry(amps, qubit, cos_half, sin_half))
+}
+
+/// RZ(θ) from precomputed cos(θ/2), sin(θ/2)
+pub(crate) fn rz(amps: &mut [Complex], qubit: usize, cos_half: f32, sin_half: f32) -> bool {
+    dispatch!(1usize << qubit, rz(amps, qubit, cos_half, sin_half))
+}
+
+/// Packed amplitudes, interleaved re/im
+#[cfg(feature = "simd")]
+trait Lanes: Copy {
+    /// Amplitudes per vector
+    const WIDTH: usize;
+    unsafe fn load(ptr: *const Complex) -> Self;
+    unsafe fn store(self, ptr: *mut Complex);
+    unsafe fn splat(value: f32) -> Self;
+    unsafe fn add(self, other: Self) -> Self;
+    unsafe fn sub(self, other: Self) -> Self;
+    unsafe fn mul(self, other: Self) -> Self;
+    /// (re, im) → (im, re)
+    unsafe fn swap_parts(self) -> Self;
+    /// (re, im) → (re, -im)
+    unsafe fn neg_im(self) -> Self;
+    /// (re, im) → (-re, im)
+    unsafe fn neg_re(self) -> Self;
+}
+
+/// Kernels generic over the vector type
+///
+/// Callers guarantee `amps.len()` is a power of two covering every qubit
+/// named, and that each gate stride is at least `L::WIDTH`.
+#[cfg(feature = "simd")]
+mod kernels {
+    use super::Lanes;
+    use crate::quantum::Complex;
+
+    /// Apply `f` to each (|…0…⟩, |…1…⟩) vector pair of `qubit`
+    #[inline(always)]
+    unsafe fn pairs<L: Lanes>(amps: &mut [Complex], qubit: usize, mut f: impl FnMut(L, L) -> (L, L)) {
+        let step = 1 << qubit;
+        let base = amps.as_mut_ptr();
+        for block in (0..amps.len()).step_by(2 * step) {
+            for j in (0..step).step_by(L::WIDTH) {
+                let p0 = base.add(block + j);
+                let p1 = p0.add(step);
+                let (a0, a1) = f(L::load(p0), L::load(p1));
+                a0.store(p0);
+                a1.store(p1);
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn hadamard<L: Lanes>(amps: &mut [Complex], qubit: usize) {
+        let h = L::splat(core::f32::consts::FRAC_1_SQRT_2);
+        pairs::<L>(amps, qubit, |a0, a1| (h.mul(a0.add(a1)), h.mul(a0.sub(a1))));
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn cnot<L: Lanes>(amps: &mut [Complex], control: usize, target: usize) {
+        let ctrl_mask = 1 << control;
+        let targ_mask = 1 << target;
+        let base = amps.as_mut_ptr();
+        for i in (0..amps.len()).step_by(L::WIDTH) {
+            if i & ctrl_mask != 0 && i & targ_mask == 0 {
+                let p0 = base.add(i);
+                let p1 = base.add(i | targ_mask);
+                let (a0, a1) = (L::load(p0), L::load(p1));
+                a1.store(p0);
+                a0.store(p1);
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn rx<L: Lanes>(amps: &mut [Complex], qubit: usize, cos_half: f32, sin_half: f32) {
+        let (c, s) = (L::splat(cos_half), L::splat(sin_half));
+        pairs::<L>(amps, qubit, |a0, a1| {
+            (
+                c.mul(a0).add(s.mul(a1.swap_parts()).neg_im()),
+                c.mul(a1).add(s.mul(a0.swap_parts()).neg_im()),
+            )
+        });
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn ry<L: Lanes>(amps: &mut [Complex], qubit: usize, cos_half: f32, sin_half: f32) {
+        let (c, s) = (L::splat(cos_half), L::splat(sin_half));
+        pairs::<L>(amps, qubit, |a0, a1| (c.mul(a0).sub(s.mul(a1)), s.mul(a0).add(c.mul(a1))));
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn rz<L: Lanes>(amps: &mut [Complex], qubit: usize, cos_half: f32, sin_half: f32) {
+        let (c, s) = (L::splat(cos_half), L::splat(sin_half));
+        pairs::<L>(amps, qubit, |a0, a1| {
+            (
+                c.mul(a0).add(s.mul(a0.swap_parts()).neg_im()),
+                c.mul(a1).add(s.mul(a1.swap_parts()).neg_re()),
+            )
+        });
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod avx {
+    use core::arch::x86_64::*;
+
+    use super::{kernels, Lanes};
+    use crate::quantum::Complex;
+
+    /// Four amplitudes in one 256-bit register
+    #[derive(Clone, Copy)]
+    pub(super) struct Avx(__m256);
+
+    impl Lanes for Avx {
+        const WIDTH: usize = 4;
+
+        #[inline(always)]
+        unsafe fn load(ptr: *const Complex) -> Self {
+            Avx(_mm256_loadu_ps(ptr as *const f32))
+        }
+
+        #[inline(always)]
+        unsafe fn store(self, ptr: *mut Complex) {
+            _mm256_storeu_ps(ptr as *mut f32, self.0)
+        }
+
+        #[inline(always)]
+        unsafe fn splat(value: f32) -> Self {
+            Avx(_mm256_set1_ps(value))
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, other: Self) -> Self {
+            Avx(_mm256_add_ps(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn sub(self, other: Self) -> Self {
+            Avx(_mm256_sub_ps(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn mul(self, other: Self) -> Self {
+            Avx(_mm256_mul_ps(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn swap_parts(self) -> Self {
+            Avx(_mm256_permute_ps::<0b10_11_00_01>(self.0))
+        }
+
+        #[inline(always)]
+        unsafe fn neg_im(self) -> Self {
+            Avx(_mm256_xor_ps(self.0, _mm256_setr_ps(0.0, -0.0, 0.0, -0.0, 0.0, -0.0, 0.0, -0.0)))
+        }
+
+        #[inline(always)]
+        unsafe fn neg_re(self) -> Self {
+            Avx(_mm256_xor_ps(self.0, _mm256_setr_ps(-0.0, 0.0, -0.0, 0.0, -0.0, 0.0, -0.0, 0.0)))
+        }
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn hadamard(amps: &mut [Complex], qubit: usize) {
+        kernels::hadamard::<Avx>(amps, qubit)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn cnot(amps: &mut [Complex], control: usize, target: usize) {
+        kernels::cnot::<Avx>(amps, control, target)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn rx(amps: &mut [Complex], qubit: usize, cos_half: f32, sin_half: f32) {
+        kernels::rx::<Avx>(amps, qubit, cos_half, sin_half)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn ry(amps: &mut [Complex], qubit: usize, cos_half: f32, sin_half: f32) {
+        kernels::ry::<Avx>(amps, qubit, cos_half, sin_half)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn rz(amps: &mut [Complex], qubit: usize, cos_half: f32, sin_half: f32) {
+        kernels::rz::<Avx>(amps, qubit, cos_half, sin_half)
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+mod neon {
+    use core::arch::aarch64::*;
+
+    use super::Lanes;
+    use crate::quantum::Complex;
+
+    /// Two amplitudes in one 128-bit register
+    #[derive(Clone, Copy)]
+    pub(super) struct Neon(float32x4_t);
+
+    /// Flip the sign bit of the lanes set in `mask`
+    #[inline(always)]
+    unsafe fn flip(value: float32x4_t, mask: [u32; 4]) -> float32x4_t {
+        vreinterpretq_f32_u32(veorq_u32(vreinterpretq_u32_f32(value), vld1q_u32(mask.as_ptr())))
+    }
+
+    impl Lanes for Neon {
+        const WIDTH: usize = 2;
+
+        #[inline(always)]
+        unsafe fn load(ptr: *const Complex) -> Self {
+            Neon(vld1q_f32(ptr as *const f32))
+        }
+
+        #[inline(always)]
+        unsafe fn store(self, ptr: *mut Complex) {
+            vst1q_f32(ptr as *mut f32, self.0)
+        }
+
+        #[inline(always)]
+        unsafe fn splat(value: f32) -> Self {
+            Neon(vdupq_n_f32(value))
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, other: Self) -> Self {
+            Neon(vaddq_f32(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn sub(self, other: Self) -> Self {
+            Neon(vsubq_f32(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn mul(self, other: Self) -> Self {
+            Neon(vmulq_f32(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn swap_parts(self) -> Self {
+            Neon(vrev64q_f32(self.0))
+        }
+
+        #[inline(always)]
+        unsafe fn neg_im(self) -> Self {
+            const SIGN: u32 = 0x8000_0000;
+            Neon(flip(self.0, [0, SIGN, 0, SIGN]))
+        }
+
+        #[inline(always)]
+        unsafe fn neg_re(self) -> Self {
+            const SIGN: u32 = 0x8000_0000;
+            Neon(flip(self.0, [SIGN, 0, SIGN, 0]))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "simd"))]
+mod tests {
+    use crate::quantum::{MiniQuASIM, QuantumGate};
+
+    /// Vector and scalar paths agree bit for bit on every qubit
+    #[test]
+    fn test_matches_scalar_exactly() {
+        let mut vector = MiniQuASIM::new(7);
+        let mut scalar = MiniQuASIM::new(7);
+        scalar.set_simd(false);
+
+        let mut gates = alloc::vec::Vec::new();
+        for q in 0..12 {
+            gates.push(QuantumGate::Hadamard(q));
+            gates.push(QuantumGate::RX(q, 0.3 + q as f32));
+            gates.push(QuantumGate::RY(q, 1.1 - q as f32 * 0.2));
+            gates.push(QuantumGate::RZ(q, 0.7 * q as f32));
+            gates.push(QuantumGate::CNOT(q, (q + 5) % 12));
+            gates.push(QuantumGate::CNOT((q + 3) % 12, q));
+        }
+        for gate in &gates {
+            vector.apply_gate(gate);
+            scalar.apply_gate(gate);
+        }
+
+        for i in 0..vector.state_size() {
+            let (v, s) = (vector.amplitude(i), scalar.amplitude(i));
+            assert_eq!((v.re.to_bits(), v.im.to_bits()), (s.re.to_bits(), s.im.to_bits()), "amplitude {}", i);
+        }
+        assert_eq!(vector.get_state_hash(), scalar.get_state_hash());
+    }
+}
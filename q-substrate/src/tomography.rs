@@ -0,0 +1,534 @@
+//! Quantum State Tomography for 1–2 Qubit Subsystems
+//!
+//! Validation helpers for hardware-inspired experiments on Mini QuASIM:
+//! - Deterministic measurement-basis schedule (all 3^n Pauli settings)
+//! - Per-basis outcome statistics accumulated from the sampling API
+//! - Linear-inversion density matrix reconstruction
+//! - Fidelity metrics against the simulator's exact reduced state
+//!
+//! Bit `i` of every outcome and density-matrix index refers to
+//! `qubits[i]`, matching the simulator's little-endian convention.
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use crate::quantum::{Complex, MiniQuASIM, MAX_REGISTER_QUBITS};
+#[cfg(not(feature = "std"))]
+use crate::float::FloatExt;
+
+/// Largest subsystem supported (4^n Pauli terms, 3^n settings)
+pub const MAX_SUBSYSTEM_QUBITS: usize = 2;
+
+/// Single-qubit measurement basis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MeasurementBasis {
+    /// Pauli-X eigenbasis (|+⟩, |-⟩)
+    X,
+    /// Pauli-Y eigenbasis (|+i⟩, |-i⟩)
+    Y,
+    /// Computational basis
+    Z,
+}
+
+impl MeasurementBasis {
+    /// Schedule order
+    pub const ALL: [MeasurementBasis; 3] = [Self::Z, Self::X, Self::Y];
+
+    /// Index into the Pauli set {I, X, Y, Z}
+    fn pauli_index(self) -> usize {
+        match self {
+            MeasurementBasis::X => 1,
+            MeasurementBasis::Y => 2,
+            MeasurementBasis::Z => 3,
+        }
+    }
+
+    /// Rotate `qubit` so this basis is read out in the computational basis
+    fn rotate(self, sim: &mut MiniQuASIM, qubit: usize) {
+        match self {
+            MeasurementBasis::Z => {}
+            MeasurementBasis::X => sim.hadamard(qubit),
+            MeasurementBasis::Y => {
+                // S† = S³, then H
+                for _ in 0..3 {
+                    sim.phase_gate(qubit);
+                }
+                sim.hadamard(qubit);
+            }
+        }
+    }
+}
+
+/// Tomography error types
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TomographyError {
+    /// Subsystem must have 1 or 2 qubits
+    UnsupportedSize(usize),
+    /// Qubit index outside the simulator register
+    QubitOutOfRange(usize),
+    /// Qubit listed twice in the subsystem
+    DuplicateQubit(usize),
+    /// Setting not part of the schedule for this subsystem
+    UnknownSetting,
+    /// Outcome does not fit the subsystem
+    OutcomeOutOfRange(usize),
+    /// A basis setting has no shots
+    MissingStatistics,
+}
+
+impl core::fmt::Display for TomographyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TomographyError::UnsupportedSize(n) => {
+                write!(f, "Tomography supports 1-{} qubits, got {}", MAX_SUBSYSTEM_QUBITS, n)
+            }
+            TomographyError::QubitOutOfRange(q) => write!(f, "Qubit {} out of range", q),
+            TomographyError::DuplicateQubit(q) => write!(f, "Qubit {} listed twice", q),
+            TomographyError::UnknownSetting => write!(f, "Basis setting not in schedule"),
+            TomographyError::OutcomeOutOfRange(o) => write!(f, "Outcome {} out of range", o),
+            TomographyError::MissingStatistics => write!(f, "Basis setting has no shots"),
+        }
+    }
+}
+
+fn validate_subsystem(qubits: &[usize]) -> Result<(), TomographyError> {
+    if qubits.is_empty() || qubits.len() > MAX_SUBSYSTEM_QUBITS {
+        return Err(TomographyError::UnsupportedSize(qubits.len()));
+    }
+    for (i, &q) in qubits.iter().enumerate() {
+        if q >= MAX_REGISTER_QUBITS {
+            return Err(TomographyError::QubitOutOfRange(q));
+        }
+        if qubits[..i].contains(&q) {
+            return Err(TomographyError::DuplicateQubit(q));
+        }
+    }
+    Ok(())
+}
+
+/// Deterministic measurement-basis schedule for an `n`-qubit subsystem
+///
+/// Returns all 3^n settings in lexicographic Z < X < Y order with the first
+/// qubit most significant (ZZ, ZX, ZY, XZ, ...).
+pub fn basis_schedule(num_qubits: usize) -> Result<Vec<Vec<MeasurementBasis>>, TomographyError> {
+    if num_qubits == 0 || num_qubits > MAX_SUBSYSTEM_QUBITS {
+        return Err(TomographyError::UnsupportedSize(num_qubits));
+    }
+
+    let mut schedule = vec![Vec::new()];
+    for _ in 0..num_qubits {
+        schedule = schedule
+            .into_iter()
+            .flat_map(|prefix: Vec<MeasurementBasis>| {
+                MeasurementBasis::ALL.iter().map(move |&basis| {
+                    let mut setting = prefix.clone();
+                    setting.push(basis);
+                    setting
+                })
+            })
+            .collect();
+    }
+    Ok(schedule)
+}
+
+/// Outcome counts for one basis setting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasisCounts {
+    /// Basis per subsystem qubit
+    pub setting: Vec<MeasurementBasis>,
+    /// Counts indexed by outcome
+    pub counts: Vec<u32>,
+    /// Total shots
+    pub shots: u32,
+}
+
+/// Per-basis statistics for a subsystem
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TomographyStats {
+    /// Measured qubits
+    pub qubits: Vec<usize>,
+    /// Counts in schedule order
+    pub settings: Vec<BasisCounts>,
+}
+
+impl TomographyStats {
+    /// Create empty statistics for every scheduled setting
+    pub fn new(qubits: &[usize]) -> Result<Self, TomographyError> {
+        validate_subsystem(qubits)?;
+        let outcomes = 1 << qubits.len();
+        let settings = basis_schedule(qubits.len())?
+            .into_iter()
+            .map(|setting| BasisCounts {
+                setting,
+                counts: vec![0; outcomes],
+                shots: 0,
+            })
+            .collect();
+        Ok(TomographyStats {
+            qubits: qubits.to_vec(),
+            settings,
+        })
+    }
+
+    /// Add sampled outcomes for one setting
+    pub fn accumulate(
+        &mut self,
+        setting: &[MeasurementBasis],
+        outcomes: &[usize],
+    ) -> Result<(), TomographyError> {
+        let entry = self
+            .settings
+            .iter_mut()
+            .find(|entry| entry.setting == setting)
+            .ok_or(TomographyError::UnknownSetting)?;
+
+        if let Some(&bad) = outcomes.iter().find(|&&o| o >= entry.counts.len()) {
+            return Err(TomographyError::OutcomeOutOfRange(bad));
+        }
+        for &outcome in outcomes {
+            entry.counts[outcome] += 1;
+        }
+        entry.shots += outcomes.len() as u32;
+        Ok(())
+    }
+
+    /// Estimate ⟨P⟩ for a Pauli string (0=I, 1=X, 2=Y, 3=Z per qubit)
+    ///
+    /// Pools every setting whose basis matches P on its non-identity qubits.
+    fn expectation(&self, pauli: &[usize]) -> Result<f32, TomographyError> {
+        if pauli.iter().all(|&p| p == 0) {
+            return Ok(1.0);
+        }
+
+        let mut signed = 0i64;
+        let mut shots = 0u64;
+        for entry in &self.settings {
+            let compatible = pauli
+                .iter()
+                .zip(&entry.setting)
+                .all(|(&p, basis)| p == 0 || p == basis.pauli_index());
+            if !compatible {
+                continue;
+            }
+            for (outcome, &count) in entry.counts.iter().enumerate() {
+                let parity = pauli
+                    .iter()
+                    .enumerate()
+                    .filter(|(bit, &p)| p != 0 && (outcome >> bit) & 1 == 1)
+                    .count();
+                let sign = if parity & 1 == 0 { 1 } else { -1 };
+                signed += sign * count as i64;
+            }
+            shots += entry.shots as u64;
+        }
+
+        if shots == 0 {
+            return Err(TomographyError::MissingStatistics);
+        }
+        Ok(signed as f32 / shots as f32)
+    }
+}
+
+/// Sample one basis setting from a copy of the simulator
+///
+/// The simulator is cloned, so its state and gate history are untouched.
+pub fn measure_setting(
+    sim: &MiniQuASIM,
+    qubits: &[usize],
+    setting: &[MeasurementBasis],
+    shots: usize,
+    seed: u32,
+) -> Vec<usize> {
+    let mut copy = sim.clone();
+    copy.reseed(seed);
+    for (&qubit, basis) in qubits.iter().zip(setting) {
+        basis.rotate(&mut copy, qubit);
+    }
+    copy.sample_qubits(qubits, shots)
+}
+
+/// Run the full schedule and accumulate statistics
+///
+/// Setting `k` is sampled with seed `seed + k`, so results are reproducible.
+pub fn collect_statistics(
+    sim: &MiniQuASIM,
+    qubits: &[usize],
+    shots_per_setting: usize,
+    seed: u32,
+) -> Result<TomographyStats, TomographyError> {
+    let mut stats = TomographyStats::new(qubits)?;
+    let schedule = basis_schedule(qubits.len())?;
+    for (k, setting) in schedule.iter().enumerate() {
+        let outcomes = measure_setting(sim, qubits, setting, shots_per_setting, seed.wrapping_add(k as u32));
+        stats.accumulate(setting, &outcomes)?;
+    }
+    Ok(stats)
+}
+
+/// Dense density matrix (row-major)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DensityMatrix {
+    /// Dimension (2^n)
+    pub dim: usize,
+    /// Elements ρ[r][c] at `r * dim + c`
+    pub elements: Vec<Complex>,
+}
+
+impl DensityMatrix {
+    /// Element ρ[row][col]
+    pub fn get(&self, row: usize, col: usize) -> Complex {
+        self.elements[row * self.dim + col]
+    }
+
+    /// Real part of the trace
+    pub fn trace(&self) -> f32 {
+        (0..self.dim).map(|i| self.get(i, i).re).sum()
+    }
+
+    /// Tr(ρ²) for Hermitian ρ
+    pub fn purity(&self) -> f32 {
+        self.elements.iter().map(|e| e.norm_sq()).sum()
+    }
+
+    /// Re Tr(ρσ)
+    pub fn overlap(&self, other: &DensityMatrix) -> f32 {
+        let mut total = 0.0;
+        for r in 0..self.dim {
+            for c in 0..self.dim {
+                total += self.get(r, c).mul(other.get(c, r)).re;
+            }
+        }
+        total
+    }
+
+    /// Hilbert-Schmidt distance sqrt(Tr (ρ-σ)²)
+    pub fn hilbert_schmidt_distance(&self, other: &DensityMatrix) -> f32 {
+        self.elements
+            .iter()
+            .zip(&other.elements)
+            .map(|(a, b)| a.sub(*b).norm_sq())
+            .sum::<f32>()
+            .sqrt()
+    }
+}
+
+/// ⟨r|P|c⟩ for a single-qubit Pauli (0=I, 1=X, 2=Y, 3=Z)
+fn pauli_element(pauli: usize, row: usize, col: usize) -> Complex {
+    match (pauli, row, col) {
+        (0, r, c) if r == c => Complex::ONE,
+        (1, r, c) if r != c => Complex::ONE,
+        (2, 0, 1) => Complex::new(0.0, -1.0),
+        (2, 1, 0) => Complex::I,
+        (3, 0, 0) => Complex::ONE,
+        (3, 1, 1) => Complex::new(-1.0, 0.0),
+        _ => Complex::ZERO,
+    }
+}
+
+/// Reconstruct ρ = 2^-n Σ_P ⟨P⟩ P by linear inversion
+///
+/// The estimate is Hermitian with unit trace but may be slightly
+/// non-positive under finite sampling.
+pub fn reconstruct(stats: &TomographyStats) -> Result<DensityMatrix, TomographyError> {
+    let n = stats.qubits.len();
+    let dim = 1 << n;
+    let mut elements = vec![Complex::ZERO; dim * dim];
+
+    for string in 0..(1usize << (2 * n)) {
+        let pauli: Vec<usize> = (0..n).map(|i| (string >> (2 * i)) & 3).collect();
+        let expectation = stats.expectation(&pauli)?;
+
+        for r in 0..dim {
+            for c in 0..dim {
+                let term = pauli.iter().enumerate().fold(Complex::ONE, |acc, (i, &p)| {
+                    acc.mul(pauli_element(p, (r >> i) & 1, (c >> i) & 1))
+                });
+                elements[r * dim + c] = elements[r * dim + c].add(term.scale(expectation));
+            }
+        }
+    }
+
+    let norm = 1.0 / dim as f32;
+    for element in &mut elements {
+        *element = element.scale(norm);
+    }
+    Ok(DensityMatrix { dim, elements })
+}
+
+/// Exact reduced density matrix of a subsystem (partial trace of |ψ⟩⟨ψ|)
+pub fn exact_density_matrix(sim: &MiniQuASIM, qubits: &[usize]) -> Result<DensityMatrix, TomographyError> {
+    validate_subsystem(qubits)?;
+    let dim = 1 << qubits.len();
+    let mask: usize = qubits.iter().map(|&q| 1 << q).sum();
+
+    let embed = |sub: usize, env: usize| -> usize {
+        qubits
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| (sub >> bit) & 1 == 1)
+            .fold(env, |acc, (_, &q)| acc | (1 << q))
+    };
+
+    let mut elements = vec![Complex::ZERO; dim * dim];
+    for env in (0..sim.state_size()).filter(|env| env & mask == 0) {
+        for r in 0..dim {
+            let a = sim.amplitude(embed(r, env));
+            for c in 0..dim {
+                let b = sim.amplitude(embed(c, env));
+                elements[r * dim + c] = elements[r * dim + c].add(a.mul(b.conj()));
+            }
+        }
+    }
+    Ok(DensityMatrix { dim, elements })
+}
+
+/// Reconstruction quality against the exact state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FidelityReport {
+    /// Tr(ρσ) / max(Tr ρ², Tr σ²); equals Uhlmann fidelity for pure exact states
+    pub fidelity: f32,
+    /// Re Tr(ρσ)
+    pub overlap: f32,
+    /// sqrt(Tr (ρ-σ)²)
+    pub hilbert_schmidt_distance: f32,
+    /// Tr ρ² of the estimate
+    pub estimated_purity: f32,
+    /// Tr σ² of the exact state
+    pub exact_purity: f32,
+}
+
+/// Compare an estimate with the exact density matrix
+pub fn fidelity_report(estimate: &DensityMatrix, exact: &DensityMatrix) -> FidelityReport {
+    let overlap = estimate.overlap(exact);
+    let estimated_purity = estimate.purity();
+    let exact_purity = exact.purity();
+    FidelityReport {
+        fidelity: overlap / estimated_purity.max(exact_purity),
+        overlap,
+        hilbert_schmidt_distance: estimate.hilbert_schmidt_distance(exact),
+        estimated_purity,
+        exact_purity,
+    }
+}
+
+/// Complete tomography run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TomographyResult {
+    /// Accumulated statistics
+    pub stats: TomographyStats,
+    /// Reconstructed density matrix
+    pub estimate: DensityMatrix,
+    /// Exact reduced density matrix
+    pub exact: DensityMatrix,
+    /// Quality metrics
+    pub report: FidelityReport,
+}
+
+/// Schedule, sample, reconstruct and score a subsystem in one call
+pub fn run_tomography(
+    sim: &MiniQuASIM,
+    qubits: &[usize],
+    shots_per_setting: usize,
+    seed: u32,
+) -> Result<TomographyResult, TomographyError> {
+    let stats = collect_statistics(sim, qubits, shots_per_setting, seed)?;
+    let estimate = reconstruct(&stats)?;
+    let exact = exact_density_matrix(sim, qubits)?;
+    let report = fidelity_report(&estimate, &exact);
+    Ok(TomographyResult {
+        stats,
+        estimate,
+        exact,
+        report,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basis_schedule() {
+        let one = basis_schedule(1).unwrap();
+        assert_eq!(one, vec![vec![MeasurementBasis::Z], vec![MeasurementBasis::X], vec![MeasurementBasis::Y]]);
+
+        let two = basis_schedule(2).unwrap();
+        assert_eq!(two.len(), 9);
+        assert_eq!(two[1], vec![MeasurementBasis::Z, MeasurementBasis::X]);
+        assert_eq!(two[8], vec![MeasurementBasis::Y, MeasurementBasis::Y]);
+
+        assert_eq!(basis_schedule(3), Err(TomographyError::UnsupportedSize(3)));
+        assert_eq!(TomographyStats::new(&[1, 1]).err(), Some(TomographyError::DuplicateQubit(1)));
+        assert_eq!(TomographyStats::new(&[MAX_REGISTER_QUBITS]).err(), Some(TomographyError::QubitOutOfRange(MAX_REGISTER_QUBITS)));
+    }
+
+    #[test]
+    fn test_single_qubit_y_eigenstate() {
+        // |+i⟩ = S H |0⟩ on qubit 3
+        let mut sim = MiniQuASIM::new(42);
+        sim.hadamard(3);
+        sim.phase_gate(3);
+
+        let result = run_tomography(&sim, &[3], 2000, 1).unwrap();
+        assert!((result.exact.get(0, 1).im + 0.5).abs() < 1e-4);
+        assert!((result.estimate.trace() - 1.0).abs() < 1e-4);
+        assert!((result.estimate.get(0, 1).im + 0.5).abs() < 0.05);
+        assert!(result.report.fidelity > 0.97);
+
+        // Y-basis shots all read |+i⟩ -> outcome 0
+        let y = &result.stats.settings[2];
+        assert_eq!(y.counts[1], 0);
+    }
+
+    #[test]
+    fn test_bell_pair_reconstruction() {
+        let mut sim = MiniQuASIM::new(42);
+        sim.bell_state();
+        let history_len = sim.get_gate_history().len();
+
+        let result = run_tomography(&sim, &[0, 1], 4000, 9).unwrap();
+        assert!((result.report.exact_purity - 1.0).abs() < 1e-4);
+        assert!(result.report.fidelity > 0.95);
+        assert!(result.report.hilbert_schmidt_distance < 0.1);
+        assert!((result.estimate.get(0, 3).re - 0.5).abs() < 0.05);
+
+        // Sampling works on copies only
+        assert_eq!(sim.get_gate_history().len(), history_len);
+
+        // Same seed, same statistics
+        let again = collect_statistics(&sim, &[0, 1], 4000, 9).unwrap();
+        for (a, b) in result.stats.settings.iter().zip(&again.settings) {
+            assert_eq!(a.counts, b.counts);
+        }
+    }
+
+    #[test]
+    fn test_entangled_half_is_mixed() {
+        let mut sim = MiniQuASIM::new(42);
+        sim.bell_state();
+
+        let exact = exact_density_matrix(&sim, &[1]).unwrap();
+        assert!((exact.purity() - 0.5).abs() < 1e-4);
+
+        let result = run_tomography(&sim, &[1], 4000, 3).unwrap();
+        assert!(result.report.estimated_purity < 0.55);
+        assert!(result.report.fidelity > 0.95);
+    }
+
+    #[test]
+    fn test_accumulate_validation() {
+        let mut stats = TomographyStats::new(&[0]).unwrap();
+        assert_eq!(
+            stats.accumulate(&[MeasurementBasis::X, MeasurementBasis::X], &[0]),
+            Err(TomographyError::UnknownSetting)
+        );
+        assert_eq!(
+            stats.accumulate(&[MeasurementBasis::X], &[2]),
+            Err(TomographyError::OutcomeOutOfRange(2))
+        );
+        assert_eq!(reconstruct(&stats).err(), Some(TomographyError::MissingStatistics));
+    }
+}
@@ -7,11 +7,15 @@
 //! - Provenance logging for inter-module calls
 //! - No side-channel leaks
 //!
-//! Memory footprint: Configurable per pod
+//! Memory footprint: Configurable per pod. Limits are enforced per isolated
+//! operation (exceeding one rolls the pod's allocations back) and can be
+//! changed on a running pod; reductions below current usage wait for the
+//! next safe point.
 
 extern crate alloc;
 
 use alloc::collections::VecDeque;
+use alloc::format;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -80,6 +84,57 @@ pub struct PodStatus {
     pub last_op_time: u64,
     /// Error count
     pub error_count: u32,
+    /// Operations rolled back for exceeding the memory limit
+    pub oom_events: u32,
+}
+
+/// Outcome of a memory limit change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LimitChange {
+    /// New limit is in force
+    Applied,
+    /// Usage exceeds the new limit; applied once usage fits
+    Deferred,
+}
+
+/// Pod-level memory watermarks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PodMemoryReport {
+    /// Pod ID
+    pub pod_id: String,
+    /// Pod type
+    pub pod_type: PodType,
+    /// Enforced limit in bytes
+    pub limit_bytes: usize,
+    /// Current usage in bytes
+    pub used_bytes: usize,
+    /// Peak usage in bytes
+    pub peak_bytes: usize,
+    /// Current usage as percent of limit
+    pub utilization_pct: u8,
+    /// Limit waiting for a safe point, in bytes
+    pub pending_limit_bytes: Option<usize>,
+    /// Operations rolled back for exceeding the limit
+    pub oom_events: u32,
+}
+
+/// Result of a controlled OOM drill
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OomDrillReport {
+    /// Pod ID
+    pub pod_id: String,
+    /// Limit in force during the drill
+    pub limit_bytes: usize,
+    /// Usage before the drill
+    pub memory_before: usize,
+    /// Usage reached before the failing allocation
+    pub memory_at_exceed: usize,
+    /// Usage after rollback
+    pub memory_after: usize,
+    /// Operation failed and usage returned to `memory_before`
+    pub rolled_back: bool,
+    /// Pod accepts work after the drill
+    pub pod_operational: bool,
 }
 
 /// Inter-pod message
@@ -144,6 +199,10 @@ pub struct WasmPod {
     op_counter: u64,
     /// Timestamp counter (simulated)
     timestamp: u64,
+    /// Limit reduction waiting for usage to fit
+    pending_limit_kb: Option<usize>,
+    /// Set when an allocation hits the limit
+    limit_exceeded: bool,
 }
 
 impl WasmPod {
@@ -160,10 +219,13 @@ impl WasmPod {
                 op_count: 0,
                 last_op_time: 0,
                 error_count: 0,
+                oom_events: 0,
             },
             memory_allocated: 0,
             op_counter: 0,
             timestamp: 0,
+            pending_limit_kb: None,
+            limit_exceeded: false,
         }
     }
 
@@ -173,6 +235,7 @@ impl WasmPod {
         
         if self.memory_allocated + size > limit {
             self.status.error_count += 1;
+            self.limit_exceeded = true;
             return Err(format!(
                 "Memory limit exceeded: requested {} bytes, limit {} bytes",
                 size, limit
@@ -197,6 +260,64 @@ impl WasmPod {
             self.memory_allocated -= size;
         }
         self.status.memory_used = self.memory_allocated;
+        self.apply_pending_limit();
+    }
+
+    /// Change the memory limit
+    ///
+    /// Raising, or lowering to at least current usage, applies immediately.
+    /// Lowering below current usage is deferred until usage fits.
+    pub fn set_memory_limit(&mut self, limit_kb: usize) -> Result<LimitChange, String> {
+        if limit_kb == 0 {
+            return Err("Memory limit must be at least 1 KB".into());
+        }
+        if limit_kb * 1024 >= self.memory_allocated {
+            self.config.memory_limit_kb = limit_kb;
+            self.pending_limit_kb = None;
+            Ok(LimitChange::Applied)
+        } else {
+            self.pending_limit_kb = Some(limit_kb);
+            Ok(LimitChange::Deferred)
+        }
+    }
+
+    /// Apply a deferred limit if usage now fits; returns true if applied
+    pub fn apply_pending_limit(&mut self) -> bool {
+        match self.pending_limit_kb {
+            Some(limit_kb) if limit_kb * 1024 >= self.memory_allocated => {
+                self.config.memory_limit_kb = limit_kb;
+                self.pending_limit_kb = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Deferred limit in KB, if any
+    pub fn pending_limit_kb(&self) -> Option<usize> {
+        self.pending_limit_kb
+    }
+
+    /// Current memory watermarks
+    pub fn memory_report(&self) -> PodMemoryReport {
+        let limit_bytes = self.config.memory_limit_kb * 1024;
+        PodMemoryReport {
+            pod_id: self.config.pod_id.clone(),
+            pod_type: self.config.pod_type,
+            limit_bytes,
+            used_bytes: self.memory_allocated,
+            peak_bytes: self.status.peak_memory,
+            utilization_pct: (self.memory_allocated * 100 / limit_bytes.max(1)).min(100) as u8,
+            pending_limit_bytes: self.pending_limit_kb.map(|kb| kb * 1024),
+            oom_events: self.status.oom_events,
+        }
+    }
+
+    /// Restore usage to `snapshot` after an operation exceeded the limit
+    fn rollback_memory(&mut self, snapshot: usize) {
+        self.memory_allocated = snapshot;
+        self.status.memory_used = snapshot;
+        self.status.oom_events += 1;
     }
 
     /// Record an operation
@@ -376,7 +497,19 @@ impl PodIsolation {
         }
         
         let entry = pod.record_operation(op_name);
-        let result = f(pod);
+        let snapshot = pod.memory_allocated;
+        pod.limit_exceeded = false;
+        let mut result = f(pod);
+        
+        // Enforce the limit even if the operation swallowed the failure (P001)
+        if pod.limit_exceeded {
+            pod.limit_exceeded = false;
+            pod.rollback_memory(snapshot);
+            result = Err(format!("Pod {} exceeded memory limit; rolled back", pod.config.pod_id));
+        }
+        
+        // Operation boundary is a safe point for deferred limits
+        pod.apply_pending_limit();
         
         // Log provenance
         self.provenance_log.push(ProvenanceEntry {
@@ -393,6 +526,75 @@ impl PodIsolation {
     }
 }
 
+impl PodIsolation {
+    /// Change a running pod's memory limit
+    pub fn set_memory_limit(&mut self, pod_type: PodType, limit_kb: usize) -> Result<LimitChange, String> {
+        let change = self.get_pod_mut(pod_type).set_memory_limit(limit_kb)?;
+        self.global_timestamp += 1;
+        self.provenance_log.push(ProvenanceEntry {
+            source: self.get_pod(pod_type).config.pod_id.clone(),
+            target: None,
+            operation: format!("set_memory_limit:{}kb:{:?}", limit_kb, change),
+            input_hash: 0,
+            output_hash: 0,
+            timestamp: self.global_timestamp,
+            duration_us: 0,
+        });
+        Ok(change)
+    }
+
+    /// Run a controlled OOM drill on a pod
+    ///
+    /// Fills the pod to its limit inside an isolated operation, then
+    /// allocates one byte more. The drill passes if the operation fails,
+    /// usage returns to its pre-drill value and the pod still executes.
+    /// Peak, error and OOM counters are restored so drills do not skew
+    /// reporting.
+    pub fn oom_drill(&mut self, pod_type: PodType) -> Result<OomDrillReport, String> {
+        let pod = self.get_pod(pod_type);
+        if !pod.can_execute() {
+            return Err("Pod cannot execute: check memory limits or status".into());
+        }
+        let limit_bytes = pod.config.memory_limit_kb * 1024;
+        let memory_before = pod.memory_allocated;
+        let peak_before = pod.status.peak_memory;
+        let errors_before = pod.status.error_count;
+        let ooms_before = pod.status.oom_events;
+
+        let mut memory_at_exceed = memory_before;
+        let result = self.execute_isolated(pod_type, "oom_drill", |pod| {
+            pod.allocate(limit_bytes - pod.memory_allocated)?;
+            memory_at_exceed = pod.memory_allocated;
+            pod.allocate(1)
+        });
+
+        let pod = self.get_pod_mut(pod_type);
+        pod.status.peak_memory = peak_before;
+        pod.status.error_count = errors_before;
+        pod.status.oom_events = ooms_before;
+        let memory_after = pod.memory_allocated;
+
+        Ok(OomDrillReport {
+            pod_id: pod.config.pod_id.clone(),
+            limit_bytes,
+            memory_before,
+            memory_at_exceed,
+            memory_after,
+            rolled_back: result.is_err() && memory_after == memory_before,
+            pod_operational: pod.can_execute(),
+        })
+    }
+
+    /// Memory watermarks for every pod
+    pub fn memory_reports(&self) -> Vec<PodMemoryReport> {
+        vec![
+            self.ai_pod.memory_report(),
+            self.quantum_pod.memory_report(),
+            self.dcge_pod.memory_report(),
+        ]
+    }
+}
+
 impl Default for PodIsolation {
     fn default() -> Self {
         Self::new(&crate::config::QSubstrateConfig::default())
@@ -477,6 +679,74 @@ mod tests {
         assert_eq!(msg.target_pod, "quantum_pod");
     }
 
+    #[test]
+    fn test_exceeding_limit_rolls_back_operation() {
+        let mut isolation = PodIsolation::default();
+        isolation.get_pod_mut(PodType::DCGE).allocate(1000).unwrap();
+
+        // Closure ignores the failed allocation; the pod still rolls back
+        let result = isolation.execute_isolated(PodType::DCGE, "greedy", |pod| {
+            pod.allocate(2000)?;
+            let _ = pod.allocate(4096);
+            Ok::<(), String>(())
+        });
+        assert!(result.is_err());
+
+        let report = isolation.get_pod(PodType::DCGE).memory_report();
+        assert_eq!(report.used_bytes, 1000);
+        assert_eq!(report.peak_bytes, 3000);
+        assert_eq!(report.oom_events, 1);
+    }
+
+    #[test]
+    fn test_hot_limit_change() {
+        let mut isolation = PodIsolation::default();
+        isolation.get_pod_mut(PodType::DCGE).allocate(3000).unwrap();
+
+        assert_eq!(isolation.set_memory_limit(PodType::DCGE, 8), Ok(LimitChange::Applied));
+        assert_eq!(isolation.get_pod(PodType::DCGE).memory_report().limit_bytes, 8192);
+
+        // Lowering below usage waits for a safe point
+        assert_eq!(isolation.set_memory_limit(PodType::DCGE, 2), Ok(LimitChange::Deferred));
+        assert_eq!(isolation.get_pod(PodType::DCGE).memory_report().pending_limit_bytes, Some(2048));
+
+        isolation.execute_isolated(PodType::DCGE, "shrink", |pod| {
+            pod.free(0);
+            Ok::<(), String>(())
+        }).unwrap();
+        assert_eq!(isolation.get_pod(PodType::DCGE).pending_limit_kb(), Some(2));
+
+        isolation.get_pod_mut(PodType::DCGE).free(1000);
+        let report = isolation.get_pod(PodType::DCGE).memory_report();
+        assert_eq!(report.limit_bytes, 2048);
+        assert_eq!(report.pending_limit_bytes, None);
+        assert_eq!(report.utilization_pct, 97);
+
+        assert!(isolation.set_memory_limit(PodType::DCGE, 0).is_err());
+        assert!(isolation
+            .get_provenance_log()
+            .iter()
+            .any(|e| e.operation == "set_memory_limit:2kb:Deferred"));
+    }
+
+    #[test]
+    fn test_oom_drill() {
+        let mut isolation = PodIsolation::default();
+        isolation.get_pod_mut(PodType::Quantum).allocate(512).unwrap();
+
+        let report = isolation.oom_drill(PodType::Quantum).unwrap();
+        assert_eq!(report.memory_before, 512);
+        assert_eq!(report.memory_at_exceed, report.limit_bytes);
+        assert_eq!(report.memory_after, 512);
+        assert!(report.rolled_back);
+        assert!(report.pod_operational);
+
+        // Drill leaves watermarks untouched
+        let watermarks = isolation.get_pod(PodType::Quantum).memory_report();
+        assert_eq!(watermarks.peak_bytes, 512);
+        assert_eq!(watermarks.oom_events, 0);
+    }
+
     #[test]
     fn test_provenance_logging() {
         let mut isolation = PodIsolation::default();
@@ -0,0 +1,53 @@
+//! Ledger import benchmarks
+//!
+//! Builds a ledger from an exported TXO sequence three ways: one append per
+//! TXO, a single contiguous `append_range`, and the bulk `from_txos` path
+//! (parallel subtrees with `--features std`).
+//!
+//! ```text
+//! cargo bench --bench ledger_import --features std
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use qratum::txo::{Txo, TxoType};
+use qratum::MerkleLedger;
+
+fn export(n: u64) -> Vec<Txo> {
+    (0..n)
+        .map(|i| Txo::new(TxoType::Input, i, i.to_le_bytes().to_vec(), Vec::new()))
+        .collect()
+}
+
+fn bench_ledger_import(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ledger_import");
+    group.sample_size(10);
+
+    for n in [10_000u64, 100_000] {
+        let txos = export(n);
+        group.bench_with_input(BenchmarkId::new("append", n), &txos, |b, txos| {
+            b.iter(|| {
+                let mut ledger = MerkleLedger::new();
+                for txo in txos.iter().cloned() {
+                    ledger.append(txo);
+                }
+                black_box(ledger.root_hash())
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("append_range", n), &txos, |b, txos| {
+            b.iter(|| {
+                let mut ledger = MerkleLedger::new();
+                ledger.append_range(txos.iter().cloned());
+                black_box(ledger.root_hash())
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("from_txos", n), &txos, |b, txos| {
+            b.iter(|| black_box(MerkleLedger::from_txos(txos.clone()).root_hash()))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_ledger_import);
+criterion_main!(benches);
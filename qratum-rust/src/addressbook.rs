@@ -0,0 +1,443 @@
+//! # Address Book Module - Persistent Peer Memory Across Restarts
+//!
+//! ## Lifecycle Stage: Network Infrastructure (node startup / shutdown)
+//!
+//! Without memory of past peers every restart begins with rediscovery, and a
+//! peer that misbehaved yesterday starts today with a clean record. The
+//! address book keeps peer IDs, transport hints and reputation across
+//! restarts in a sealed (encrypted and authenticated) blob.
+//!
+//! ## Architectural Role
+//!
+//! - **Entries**: Peer ID, public key, transport hints, reputation, last seen
+//! - **Reputation Carry-Over**: Reputations decay toward neutral with a
+//!   half-life, so old grudges and old trust both fade
+//! - **Staleness**: Entries not seen within `stale_after_ms` are dropped on load
+//! - **Privacy**: Peers learned over anonymous channels (Tor, I2P, offline)
+//!   are kept in RAM only unless the operator opts in
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Peer observations from [`crate::p2p::P2PNetwork`]
+//! - Output: Sealed CBOR blob (no_std), or a file with `std`
+//!
+//! ## Security Rationale
+//!
+//! - SHA3-256 counter-mode keystream with encrypt-then-MAC; a tampered or
+//!   wrongly keyed blob is rejected before decoding
+//! - Nothing learned over a censorship-resistant channel reaches disk by default
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256};
+
+use crate::p2p::PeerID;
+use crate::transport::Channel;
+
+/// Sealed blob format version
+const SEAL_VERSION: u8 = 1;
+
+/// Neutral reputation that stored reputations decay toward
+pub const NEUTRAL_REPUTATION: u8 = 50;
+
+/// How to reach a peer on one channel
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TransportHint {
+    /// Channel the address belongs to
+    #[n(0)]
+    pub channel: Channel,
+
+    /// Channel-specific address (host:port, onion address, ...)
+    #[n(1)]
+    pub address: String,
+}
+
+/// Remembered peer
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct AddressEntry {
+    /// Peer node ID
+    #[n(0)]
+    pub peer_id: PeerID,
+
+    /// Peer public key
+    #[n(1)]
+    pub public_key: [u8; 32],
+
+    /// Known transport hints
+    #[n(2)]
+    pub hints: Vec<TransportHint>,
+
+    /// Reputation when last seen (0-100)
+    #[n(3)]
+    pub reputation: u8,
+
+    /// Last time the peer was seen (milliseconds)
+    #[n(4)]
+    pub last_seen_ms: u64,
+
+    /// Channel the peer was first learned over
+    #[n(5)]
+    pub learned_over: Channel,
+}
+
+/// Address book configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressBookConfig {
+    /// Time for a reputation's distance from neutral to halve (0 = no decay)
+    pub reputation_half_life_ms: u64,
+
+    /// Entries unseen for longer are dropped on load (0 = never stale)
+    pub stale_after_ms: u64,
+
+    /// Maximum entries kept; the least recently seen are evicted
+    pub max_entries: usize,
+
+    /// Persist peers learned over anonymous channels
+    pub persist_anonymous: bool,
+}
+
+impl Default for AddressBookConfig {
+    fn default() -> Self {
+        Self {
+            reputation_half_life_ms: 86_400_000,  // 1 day
+            stale_after_ms: 14 * 86_400_000,      // 2 weeks
+            max_entries: 1024,
+            persist_anonymous: false,
+        }
+    }
+}
+
+/// Address Book Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressBookError {
+    /// Blob is truncated or has an unknown version
+    Malformed,
+    /// MAC check failed (tampered blob or wrong key)
+    AuthenticationFailed,
+    /// Decrypted contents are not a valid entry list
+    Decode,
+    /// File could not be read or written
+    Io,
+}
+
+/// Peer address book with sealed persistence
+#[derive(Debug, Clone)]
+pub struct AddressBook {
+    config: AddressBookConfig,
+    entries: BTreeMap<PeerID, AddressEntry>,
+}
+
+impl AddressBook {
+    /// Create an empty address book
+    pub fn new(config: AddressBookConfig) -> Self {
+        Self { config, entries: BTreeMap::new() }
+    }
+
+    /// Number of remembered peers
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no peers are remembered
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remembered entry for a peer
+    pub fn get(&self, peer_id: &PeerID) -> Option<&AddressEntry> {
+        self.entries.get(peer_id)
+    }
+
+    /// Remembered peers, most reputable first, for reconnecting at startup
+    pub fn candidates(&self, now_ms: u64) -> Vec<(PeerID, u8)> {
+        let mut peers: Vec<(PeerID, u8)> = self
+            .entries
+            .values()
+            .map(|entry| (entry.peer_id, self.reputation_at(entry, now_ms)))
+            .collect();
+        peers.sort_by_key(|(_, reputation)| core::cmp::Reverse(*reputation));
+        peers
+    }
+
+    /// Record a peer observation
+    ///
+    /// # Inputs
+    /// - `peer_id`, `public_key`: Peer identity
+    /// - `hint`: Address the peer was reached at, if any
+    /// - `reputation`: Current reputation
+    /// - `learned_over`: Channel the observation arrived on
+    /// - `now_ms`: Observation time
+    pub fn record(
+        &mut self,
+        peer_id: PeerID,
+        public_key: [u8; 32],
+        hint: Option<TransportHint>,
+        reputation: u8,
+        learned_over: Channel,
+        now_ms: u64,
+    ) {
+        let entry = self.entries.entry(peer_id).or_insert_with(|| AddressEntry {
+            peer_id,
+            public_key,
+            hints: Vec::new(),
+            reputation,
+            last_seen_ms: now_ms,
+            learned_over,
+        });
+        entry.public_key = public_key;
+        entry.reputation = reputation.min(100);
+        entry.last_seen_ms = entry.last_seen_ms.max(now_ms);
+        if let Some(hint) = hint {
+            entry.hints.retain(|h| h.channel != hint.channel);
+            entry.hints.push(hint);
+        }
+        // A clearnet sighting makes the peer's existence non-private
+        if !learned_over.is_anonymous() {
+            entry.learned_over = learned_over;
+        }
+        self.evict();
+    }
+
+    /// Update a remembered peer's reputation
+    pub fn update_reputation(&mut self, peer_id: &PeerID, reputation: u8, now_ms: u64) {
+        if let Some(entry) = self.entries.get_mut(peer_id) {
+            entry.reputation = reputation.min(100);
+            entry.last_seen_ms = entry.last_seen_ms.max(now_ms);
+        }
+    }
+
+    /// Forget a peer
+    pub fn forget(&mut self, peer_id: &PeerID) -> Option<AddressEntry> {
+        self.entries.remove(peer_id)
+    }
+
+    /// Reputation carried over to `now_ms`
+    ///
+    /// The distance from [`NEUTRAL_REPUTATION`] halves every half-life.
+    pub fn reputation_at(&self, entry: &AddressEntry, now_ms: u64) -> u8 {
+        let half_life = self.config.reputation_half_life_ms;
+        if half_life == 0 {
+            return entry.reputation;
+        }
+        let halvings = (now_ms.saturating_sub(entry.last_seen_ms) / half_life).min(8) as u32;
+        let neutral = NEUTRAL_REPUTATION as i16;
+        let distance = entry.reputation as i16 - neutral;
+        (neutral + distance / (1 << halvings)) as u8
+    }
+
+    /// Seal persistable entries
+    ///
+    /// # Inputs
+    /// - `key`: Address book key (host-provided, never stored alongside)
+    /// - `nonce`: Fresh per seal
+    ///
+    /// # Outputs
+    /// - `version || nonce || ciphertext || MAC`; anonymous-channel peers are
+    ///   omitted unless `persist_anonymous` is set
+    pub fn seal(&self, key: &[u8; 32], nonce: &[u8; 32]) -> Vec<u8> {
+        let entries: Vec<&AddressEntry> = self
+            .entries
+            .values()
+            .filter(|entry| self.config.persist_anonymous || !entry.learned_over.is_anonymous())
+            .collect();
+        let plaintext = minicbor::to_vec(&entries).unwrap_or_default();
+
+        let mut sealed = Vec::with_capacity(1 + 32 + plaintext.len() + 32);
+        sealed.push(SEAL_VERSION);
+        sealed.extend_from_slice(nonce);
+        sealed.extend_from_slice(&apply_keystream(key, nonce, &plaintext));
+        let mac = seal_mac(key, &sealed);
+        sealed.extend_from_slice(&mac);
+        sealed
+    }
+
+    /// Load a sealed address book
+    ///
+    /// # Inputs
+    /// - `sealed`: Output of [`AddressBook::seal`]
+    /// - `key`: Address book key
+    /// - `config`: Configuration for the loaded book
+    /// - `now_ms`: Startup time; stale entries are dropped and reputations decayed
+    pub fn open(
+        sealed: &[u8],
+        key: &[u8; 32],
+        config: AddressBookConfig,
+        now_ms: u64,
+    ) -> Result<Self, AddressBookError> {
+        if sealed.len() < 1 + 32 + 32 || sealed[0] != SEAL_VERSION {
+            return Err(AddressBookError::Malformed);
+        }
+        let (body, mac) = sealed.split_at(sealed.len() - 32);
+        if seal_mac(key, body) != mac {
+            return Err(AddressBookError::AuthenticationFailed);
+        }
+        let mut nonce = [0u8; 32];
+        nonce.copy_from_slice(&body[1..33]);
+        let plaintext = apply_keystream(key, &nonce, &body[33..]);
+        let entries: Vec<AddressEntry> = minicbor::decode(&plaintext).map_err(|_| AddressBookError::Decode)?;
+
+        let mut book = Self::new(config);
+        for mut entry in entries {
+            let age = now_ms.saturating_sub(entry.last_seen_ms);
+            if config.stale_after_ms > 0 && age > config.stale_after_ms {
+                continue;
+            }
+            entry.reputation = book.reputation_at(&entry, now_ms);
+            entry.last_seen_ms = entry.last_seen_ms.min(now_ms);
+            book.entries.insert(entry.peer_id, entry);
+        }
+        book.evict();
+        Ok(book)
+    }
+
+    /// Write the sealed address book to `path` with a fresh OS nonce
+    #[cfg(feature = "std")]
+    pub fn save(&self, path: &std::path::Path, key: &[u8; 32]) -> Result<(), AddressBookError> {
+        let mut nonce = [0u8; 32];
+        getrandom::getrandom(&mut nonce).map_err(|_| AddressBookError::Io)?;
+        std::fs::write(path, self.seal(key, &nonce)).map_err(|_| AddressBookError::Io)
+    }
+
+    /// Load the address book at `path`; a missing file yields an empty book
+    #[cfg(feature = "std")]
+    pub fn load(
+        path: &std::path::Path,
+        key: &[u8; 32],
+        config: AddressBookConfig,
+        now_ms: u64,
+    ) -> Result<Self, AddressBookError> {
+        match std::fs::read(path) {
+            Ok(sealed) => Self::open(&sealed, key, config, now_ms),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::new(config)),
+            Err(_) => Err(AddressBookError::Io),
+        }
+    }
+
+    /// Drop the least recently seen entries beyond `max_entries`
+    fn evict(&mut self) {
+        while self.entries.len() > self.config.max_entries {
+            let oldest = self.entries.values().min_by_key(|e| e.last_seen_ms).map(|e| e.peer_id);
+            match oldest {
+                Some(peer_id) => self.entries.remove(&peer_id),
+                None => break,
+            };
+        }
+    }
+}
+
+impl Default for AddressBook {
+    fn default() -> Self {
+        Self::new(AddressBookConfig::default())
+    }
+}
+
+/// XOR `data` with a SHA3-256 counter-mode keystream
+fn apply_keystream(key: &[u8; 32], nonce: &[u8; 32], data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    for (counter, chunk) in data.chunks(32).enumerate() {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"QRATUM-ADDRESS-BOOK-STREAM-v1");
+        hasher.update(key);
+        hasher.update(nonce);
+        hasher.update((counter as u64).to_le_bytes());
+        let block: [u8; 32] = hasher.finalize().into();
+        out.extend(chunk.iter().zip(block.iter()).map(|(d, k)| d ^ k));
+    }
+    out
+}
+
+/// MAC over the version, nonce and ciphertext
+fn seal_mac(key: &[u8; 32], body: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"QRATUM-ADDRESS-BOOK-MAC-v1");
+    hasher.update(key);
+    hasher.update(body);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = 86_400_000;
+
+    fn hint(channel: Channel, address: &str) -> Option<TransportHint> {
+        Some(TransportHint { channel, address: address.into() })
+    }
+
+    #[test]
+    fn test_seal_round_trip_with_privacy() {
+        let mut book = AddressBook::default();
+        book.record([1u8; 32], [11u8; 32], hint(Channel::Tcp, "10.0.0.1:7000"), 90, Channel::Tcp, DAY);
+        book.record([2u8; 32], [12u8; 32], hint(Channel::Tor, "abc.onion"), 70, Channel::Tor, DAY);
+        // Seen over Tor, then over TCP: no longer private
+        book.record([3u8; 32], [13u8; 32], None, 60, Channel::I2p, DAY);
+        book.record([3u8; 32], [13u8; 32], hint(Channel::Tcp, "10.0.0.3:7000"), 60, Channel::Tcp, DAY);
+        assert_eq!(book.len(), 3);
+
+        let key = [7u8; 32];
+        let sealed = book.seal(&key, &[9u8; 32]);
+        let loaded = AddressBook::open(&sealed, &key, AddressBookConfig::default(), DAY).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded.get(&[1u8; 32]), book.get(&[1u8; 32]));
+        assert!(loaded.get(&[2u8; 32]).is_none());
+        assert_eq!(loaded.get(&[3u8; 32]).unwrap().learned_over, Channel::Tcp);
+
+        let opt_in = AddressBookConfig { persist_anonymous: true, ..AddressBookConfig::default() };
+        let sealed_all = AddressBook { config: opt_in, entries: book.entries.clone() }.seal(&key, &[9u8; 32]);
+        assert_eq!(AddressBook::open(&sealed_all, &key, opt_in, DAY).unwrap().len(), 3);
+
+        // Wrong key, tampering and truncation are rejected
+        let config = AddressBookConfig::default();
+        assert_eq!(AddressBook::open(&sealed, &[8u8; 32], config, DAY).err(), Some(AddressBookError::AuthenticationFailed));
+        let mut tampered = sealed.clone();
+        tampered[40] ^= 1;
+        assert_eq!(AddressBook::open(&tampered, &key, config, DAY).err(), Some(AddressBookError::AuthenticationFailed));
+        assert_eq!(AddressBook::open(&sealed[..40], &key, config, DAY).err(), Some(AddressBookError::Malformed));
+    }
+
+    #[test]
+    fn test_reputation_decay_and_staleness() {
+        let mut book = AddressBook::default();
+        book.record([1u8; 32], [0u8; 32], None, 90, Channel::Tcp, 0);
+        book.record([2u8; 32], [0u8; 32], None, 10, Channel::Tcp, 0);
+        book.record([3u8; 32], [0u8; 32], None, 100, Channel::Tcp, 10 * DAY);
+
+        // Two half-lives later: 90 -> 60, 10 -> 40
+        let sealed = book.seal(&[1u8; 32], &[2u8; 32]);
+        let loaded = AddressBook::open(&sealed, &[1u8; 32], AddressBookConfig::default(), 2 * DAY).unwrap();
+        assert_eq!(loaded.get(&[1u8; 32]).unwrap().reputation, 60);
+        assert_eq!(loaded.get(&[2u8; 32]).unwrap().reputation, 40);
+
+        // Three weeks later only the recently seen peer survives
+        let loaded = AddressBook::open(&sealed, &[1u8; 32], AddressBookConfig::default(), 21 * DAY).unwrap();
+        assert_eq!(loaded.candidates(21 * DAY), vec![([3u8; 32], 50)]);
+
+        let mut small = AddressBook::new(AddressBookConfig { max_entries: 2, ..AddressBookConfig::default() });
+        for i in 0..3u8 {
+            small.record([i; 32], [0u8; 32], None, 50, Channel::Tcp, i as u64);
+        }
+        assert!(small.get(&[0u8; 32]).is_none());
+        assert_eq!(small.len(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_file_persistence() {
+        let path = std::env::temp_dir().join(alloc::format!("qratum-addressbook-{}", std::process::id()));
+        let key = [5u8; 32];
+        let config = AddressBookConfig::default();
+        assert!(AddressBook::load(&path, &key, config, 0).unwrap().is_empty());
+
+        let mut book = AddressBook::default();
+        book.record([1u8; 32], [2u8; 32], hint(Channel::Tcp, "10.0.0.1:7000"), 80, Channel::Tcp, 0);
+        book.save(&path, &key).unwrap();
+        let loaded = AddressBook::load(&path, &key, config, 0).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.get(&[1u8; 32]), book.get(&[1u8; 32]));
+    }
+}
@@ -12,12 +12,15 @@
 //! - **Ephemeral Lifecycle**: Generated → Used → Zeroized (no persistence)
 //! - **Auto-Rotation**: Keys rotate per epoch for forward secrecy
 //! - **Escrow**: Optional time-locked or threshold-based recovery
+//! - **At-Rest Wrapping**: Shares leave the enclave wrapped (see [`crate::keywrap`])
 //!
 //! ## PHASE 2 Hardening Features
 //!
 //! - **Key Lifetime Enforcement**: <30 second maximum lifetime at type level
 //! - **Entropy Blending**: Genomic ⊕ TRNG ⊕ Device Fingerprint
 //! - **Privacy Protection**: Irreversible projection mapping
+//! - **Usage Accounting**: Per-key operation counters with caps by operation
+//!   class; a breach invalidates the key
 //!
 //! ## Inputs → Outputs
 //!
@@ -35,6 +38,7 @@
 //! - Lifetime enforcement prevents key reuse attacks
 //! - Entropy blending ensures multi-source security
 //! - Projection mapping ensures forward privacy
+//! - Usage caps bound what a briefly exposed key can sign or decrypt
 //!
 //! ## Forward Compatibility
 //!
@@ -46,6 +50,10 @@ use alloc::vec::Vec;
 
 use sha3::{Sha3_512, Sha3_256, Digest};
 use zeroize::{Zeroize, ZeroizeOnDrop};
+use crate::clock;
+use crate::keywrap::{KeyDecapsulator, KeyEncapsulator, WrapError, WrappedKey};
+use crate::secure_alloc::KeyMaterial;
+use crate::txo::{Txo, TxoType};
 
 /// Maximum biokey lifetime in milliseconds (30 seconds)
 /// Enforced at type level - keys automatically invalidate after this duration
@@ -70,6 +78,110 @@ pub enum LifetimeState {
     Invalidated,
 }
 
+/// Key operation class for usage accounting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyOperation {
+    /// TXO and attestation signatures
+    Signing,
+    /// Snapshot and payload encryption
+    Encryption,
+    /// Compliance and watchdog attestations
+    Attestation,
+    /// Derivation of subkeys
+    Derivation,
+}
+
+impl KeyOperation {
+    /// All operation classes, in summary order
+    pub const ALL: [KeyOperation; 4] = [
+        KeyOperation::Signing,
+        KeyOperation::Encryption,
+        KeyOperation::Attestation,
+        KeyOperation::Derivation,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Per-key operation caps (0 = unlimited)
+///
+/// Caps apply per key epoch: rotation starts fresh counters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsageLimits {
+    /// Maximum signing operations
+    pub signing: u32,
+    /// Maximum encryption operations
+    pub encryption: u32,
+    /// Maximum attestations
+    pub attestation: u32,
+    /// Maximum subkey derivations
+    pub derivation: u32,
+}
+
+impl UsageLimits {
+    /// No caps
+    pub const UNLIMITED: UsageLimits = UsageLimits {
+        signing: 0,
+        encryption: 0,
+        attestation: 0,
+        derivation: 0,
+    };
+
+    /// Cap for an operation class
+    pub fn cap(&self, operation: KeyOperation) -> u32 {
+        match operation {
+            KeyOperation::Signing => self.signing,
+            KeyOperation::Encryption => self.encryption,
+            KeyOperation::Attestation => self.attestation,
+            KeyOperation::Derivation => self.derivation,
+        }
+    }
+}
+
+impl Default for UsageLimits {
+    fn default() -> Self {
+        Self {
+            signing: 64,
+            encryption: 16,
+            attestation: 16,
+            derivation: 4,
+        }
+    }
+}
+
+/// Key Usage Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageError {
+    /// Key expired or invalidated
+    KeyUnavailable,
+    /// Operation would exceed its cap; the key is now invalidated
+    CapExceeded(KeyOperation),
+}
+
+/// Usage counters for one key epoch
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageSummary {
+    /// Key epoch the counters belong to
+    pub epoch: u64,
+    /// Authorized operations, indexed as `KeyOperation::ALL`
+    pub counts: [u32; 4],
+    /// Operation whose cap was breached, if any
+    pub breached: Option<KeyOperation>,
+}
+
+impl UsageSummary {
+    fn new(epoch: u64) -> Self {
+        Self { epoch, ..Self::default() }
+    }
+
+    /// Authorized operations of one class
+    pub fn count(&self, operation: KeyOperation) -> u32 {
+        self.counts[operation.index()]
+    }
+}
+
 /// Entropy Source Type for blending
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EntropySourceType {
@@ -196,8 +308,8 @@ impl IrreversibleProjection {
 /// - Entropy blending ensures multi-source security
 #[derive(Zeroize, ZeroizeOnDrop)]
 pub struct EphemeralBiokey {
-    /// 512-bit key material (zeroized on drop)
-    key_material: [u8; 64],
+    /// 512-bit key material (locked in RAM on std, zeroized on drop)
+    key_material: KeyMaterial<64>,
     
     /// Epoch counter (increments on rotation)
     epoch: u64,
@@ -212,6 +324,18 @@ pub struct EphemeralBiokey {
     /// Entropy source types used in derivation
     #[zeroize(skip)]
     entropy_sources: Vec<EntropySourceType>,
+    
+    /// Operation caps for this key
+    #[zeroize(skip)]
+    limits: UsageLimits,
+    
+    /// Counters for the current epoch
+    #[zeroize(skip)]
+    usage: UsageSummary,
+    
+    /// Counters of rotated-out epochs
+    #[zeroize(skip)]
+    usage_history: Vec<UsageSummary>,
 }
 
 impl EphemeralBiokey {
@@ -245,11 +369,14 @@ impl EphemeralBiokey {
         let key_material: [u8; 64] = hasher.finalize().into();
         
         Self {
-            key_material,
+            key_material: KeyMaterial::from(key_material),
             epoch,
-            timestamp: current_timestamp(),
+            timestamp: clock::now_ms(),
             invalidated: false,
             entropy_sources: Vec::new(),
+            limits: UsageLimits::default(),
+            usage: UsageSummary::new(epoch),
+            usage_history: Vec::new(),
         }
     }
     
@@ -308,7 +435,7 @@ impl EphemeralBiokey {
         hasher.update(&epoch.to_le_bytes());
         
         // Add timestamp for uniqueness
-        let timestamp = current_timestamp();
+        let timestamp = clock::now_ms();
         hasher.update(&timestamp.to_le_bytes());
         
         let key_material: [u8; 64] = hasher.finalize().into();
@@ -317,11 +444,14 @@ impl EphemeralBiokey {
         blended.zeroize();
         
         Ok(Self {
-            key_material,
+            key_material: KeyMaterial::from(key_material),
             epoch,
             timestamp,
             invalidated: false,
             entropy_sources,
+            limits: UsageLimits::default(),
+            usage: UsageSummary::new(epoch),
+            usage_history: Vec::new(),
         })
     }
     
@@ -341,7 +471,7 @@ impl EphemeralBiokey {
             return false;
         }
         
-        let current = current_timestamp();
+        let current = clock::now_ms();
         let age = current.saturating_sub(self.timestamp);
         
         age < MAX_BIOKEY_LIFETIME_MS
@@ -353,7 +483,7 @@ impl EphemeralBiokey {
             return LifetimeState::Invalidated;
         }
         
-        let current = current_timestamp();
+        let current = clock::now_ms();
         let age = current.saturating_sub(self.timestamp);
         
         if age < MAX_BIOKEY_LIFETIME_MS {
@@ -369,7 +499,7 @@ impl EphemeralBiokey {
             return 0;
         }
         
-        let current = current_timestamp();
+        let current = clock::now_ms();
         let age = current.saturating_sub(self.timestamp);
         
         MAX_BIOKEY_LIFETIME_MS.saturating_sub(age)
@@ -398,16 +528,20 @@ impl EphemeralBiokey {
         
         // Derive new key from old key + new epoch
         let mut hasher = Sha3_512::new();
-        hasher.update(&self.key_material);
+        hasher.update(&self.key_material[..]);
         hasher.update(&new_epoch.to_le_bytes());
         
         // Zeroize old key before overwriting
         self.key_material.zeroize();
         
-        self.key_material = hasher.finalize().into();
+        self.key_material.copy_from_slice(&hasher.finalize());
         self.epoch = new_epoch;
-        self.timestamp = current_timestamp();
+        self.timestamp = clock::now_ms();
         self.invalidated = false;  // Reset invalidation on rotation
+        
+        // Fresh counters for the new key; the old epoch's stay auditable
+        let previous = core::mem::replace(&mut self.usage, UsageSummary::new(new_epoch));
+        self.usage_history.push(previous);
     }
     
     /// Access key material (use with caution)
@@ -425,6 +559,67 @@ impl EphemeralBiokey {
         }
     }
     
+    /// Replace the operation caps for this key
+    pub fn with_usage_limits(mut self, limits: UsageLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+    
+    /// Authorize one operation and access key material
+    ///
+    /// ## Usage Accounting
+    ///
+    /// # Inputs
+    /// - `operation`: Operation class about to use the key
+    ///
+    /// # Outputs
+    /// - Key material if the key is valid and the operation is within its cap
+    /// - `Err(UsageError::CapExceeded)` on breach; the key is invalidated
+    ///
+    /// ## Security Rationale
+    /// - Bounds how many operations an exposed key can perform in its window
+    /// - Breach invalidation stops further use until rotation
+    pub fn authorize(&mut self, operation: KeyOperation) -> Result<&[u8; 64], UsageError> {
+        if !self.is_valid() {
+            return Err(UsageError::KeyUnavailable);
+        }
+        
+        let cap = self.limits.cap(operation);
+        let count = &mut self.usage.counts[operation.index()];
+        if cap > 0 && *count >= cap {
+            self.usage.breached = Some(operation);
+            self.invalidate();
+            return Err(UsageError::CapExceeded(operation));
+        }
+        *count += 1;
+        Ok(&self.key_material)
+    }
+    
+    /// Usage counters for every epoch of this key, oldest first
+    pub fn usage_summaries(&self) -> Vec<UsageSummary> {
+        let mut summaries = self.usage_history.clone();
+        summaries.push(self.usage.clone());
+        summaries
+    }
+    
+    /// Audit TXO carrying the key's usage summaries
+    ///
+    /// ## Audit Trail
+    /// - Payload per epoch: epoch (u64 LE), counts in `KeyOperation::ALL`
+    ///   order (u32 LE each), breached class index or 0xFF
+    /// - Contains counters only, never key material
+    pub fn usage_audit_txo(&self, timestamp: u64) -> Txo {
+        let mut payload = Vec::new();
+        for summary in self.usage_summaries() {
+            payload.extend_from_slice(&summary.epoch.to_le_bytes());
+            for count in summary.counts {
+                payload.extend_from_slice(&count.to_le_bytes());
+            }
+            payload.push(summary.breached.map_or(0xFF, |op| op.index() as u8));
+        }
+        Txo::new(TxoType::KeyUsage, timestamp, payload, Vec::new())
+    }
+    
     /// Force access to key material (bypasses lifetime check)
     ///
     /// ## WARNING: Use only for migration/recovery scenarios
@@ -433,12 +628,36 @@ impl EphemeralBiokey {
         &self.key_material
     }
     
+    /// Memory protection obtained for the key material
+    ///
+    /// ## Security Rationale
+    /// - Anything but `Protection::Locked` means the key may be swapped to disk
+    #[cfg(feature = "std")]
+    pub fn memory_protection(&self) -> crate::secure_alloc::Protection {
+        self.key_material.protection()
+    }
+    
     /// Get entropy source types used in derivation
     pub fn entropy_sources(&self) -> &[EntropySourceType] {
         &self.entropy_sources
     }
 }
 
+/// Domain separator for share coefficient expansion
+const SHAMIR_COEFF_DOMAIN: &[u8] = b"QRATUM-SHAMIR-COEFF-V1";
+
+/// Domain separator for the public split identifier
+const SHAMIR_ID_DOMAIN: &[u8] = b"QRATUM-SHAMIR-ID-V1";
+
+/// Domain separator for the shared check MAC
+const SHAMIR_MAC_DOMAIN: &[u8] = b"QRATUM-SHAMIR-MAC-V1";
+
+/// Bytes of MAC key and of MAC shared alongside the secret
+const SHAMIR_MAC_LEN: usize = 32;
+
+/// Domain separator for share integrity tags
+const SHAMIR_TAG_DOMAIN: &[u8] = b"QRATUM-SHAMIR-TAG-V1";
+
 /// Shamir Share for threshold secret sharing
 ///
 /// ## Lifecycle Stage: Quorum Convergence → Ephemeral Materialization
@@ -449,13 +668,17 @@ impl EphemeralBiokey {
 /// ## Security Rationale
 /// - Individual shares reveal nothing about the secret
 /// - M-of-N threshold prevents single-party compromise
+/// - Integrity tag and split identifier detect corrupted or mixed shares;
+///   neither depends on the secret
+/// - The value also shares a random MAC key and a MAC of the secret, so a
+///   wrong reconstruction is detected without any secret-derived metadata
 /// - Shares zeroized after reconstruction
 #[derive(Clone, Zeroize, ZeroizeOnDrop)]
 pub struct ShamirShare {
     /// Share index (1-based, 0 reserved for secret)
     pub index: u8,
     
-    /// Share value (zeroized on drop)
+    /// Share value (zeroized on drop): secret ‖ MAC key ‖ MAC, shared bytewise
     pub value: Vec<u8>,
     
     /// Total shares (N)
@@ -463,17 +686,188 @@ pub struct ShamirShare {
     
     /// Threshold required (M)
     pub threshold: u8,
+    
+    /// Random identifier of the split (common to all shares, independent of the secret)
+    pub split_id: [u8; 32],
+    
+    /// SHA3-256 integrity tag over split id, parameters, index and value
+    pub tag: [u8; 32],
+}
+
+impl ShamirShare {
+    /// Compute the integrity tag for this share's contents
+    fn compute_tag(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(SHAMIR_TAG_DOMAIN);
+        hasher.update(self.split_id);
+        hasher.update([self.index, self.threshold, self.total_shares]);
+        hasher.update(&self.value);
+        hasher.finalize().into()
+    }
+    
+    /// Check the integrity tag
+    pub fn verify_tag(&self) -> bool {
+        self.compute_tag() == self.tag
+    }
+    
+    /// Wrap the share value with AES-KW for at-rest storage
+    ///
+    /// # Inputs
+    /// - `kek`: Recovery party's key-encryption key
+    ///
+    /// # Outputs
+    /// - `WrappedShare`; the value must be a multiple of 8 bytes, at least 16
+    pub fn wrap(&self, kek: &[u8; 32]) -> Result<WrappedShare, WrapError> {
+        Ok(self.wrapped_with(WrappedKey::wrap(kek, &self.value)?))
+    }
+    
+    /// Wrap the share value with the Kyber-hybrid KEK
+    ///
+    /// # Inputs
+    /// - `kek`: Recovery party's classical key-encryption key
+    /// - `kem`: Recovery party's Kyber public key
+    /// - `coins`: Encapsulation randomness
+    pub fn wrap_hybrid(
+        &self,
+        kek: &[u8; 32],
+        kem: &dyn KeyEncapsulator,
+        coins: &[u8; 32],
+    ) -> Result<WrappedShare, WrapError> {
+        Ok(self.wrapped_with(WrappedKey::wrap_hybrid(kek, kem, coins, &self.value)?))
+    }
+    
+    fn wrapped_with(&self, value: WrappedKey) -> WrappedShare {
+        WrappedShare {
+            index: self.index,
+            total_shares: self.total_shares,
+            threshold: self.threshold,
+            split_id: self.split_id,
+            tag: self.tag,
+            value,
+        }
+    }
+}
+
+/// Shamir share with its value wrapped for at-rest storage
+///
+/// Parameters and tag stay in the clear; the tag is checked on unwrap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrappedShare {
+    /// Share index
+    pub index: u8,
+    /// Total shares (N)
+    pub total_shares: u8,
+    /// Threshold required (M)
+    pub threshold: u8,
+    /// Identifier of the split the share belongs to
+    pub split_id: [u8; 32],
+    /// Integrity tag of the unwrapped share
+    pub tag: [u8; 32],
+    /// Wrapped share value
+    pub value: WrappedKey,
+}
+
+impl WrappedShare {
+    /// Recover the share
+    ///
+    /// # Inputs
+    /// - `kek`: Key-encryption key used to wrap
+    /// - `kem`: Kyber secret key, required for hybrid-wrapped shares
+    ///
+    /// # Outputs
+    /// - `ShamirShare`, or `IntegrityCheckFailed` if the unwrapped share does
+    ///   not match its tag
+    pub fn unwrap(
+        &self,
+        kek: &[u8; 32],
+        kem: Option<&dyn KeyDecapsulator>,
+    ) -> Result<ShamirShare, WrapError> {
+        let share = ShamirShare {
+            index: self.index,
+            value: self.value.unwrap(kek, kem)?,
+            total_shares: self.total_shares,
+            threshold: self.threshold,
+            split_id: self.split_id,
+            tag: self.tag,
+        };
+        if !share.verify_tag() {
+            return Err(WrapError::IntegrityCheckFailed);
+        }
+        Ok(share)
+    }
+}
+
+/// Multiply in GF(2^8) modulo the AES polynomial x^8 + x^4 + x^3 + x + 1
+///
+/// Branch-free on the operand values, so timing does not leak share bytes.
+fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (0x1b & carry);
+        b >>= 1;
+    }
+    product
+}
+
+/// Multiplicative inverse in GF(2^8) (a^254); `a` must be non-zero
+fn gf256_inv(a: u8) -> u8 {
+    // Square-and-multiply over the fixed exponent 254 = 0b11111110
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exponent = 254u8;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = gf256_mul(result, base);
+        }
+        base = gf256_mul(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Evaluate a polynomial (constant term first) at `x` by Horner's rule
+fn gf256_eval(coefficients: &[u8], x: u8) -> u8 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0u8, |acc, &c| gf256_mul(acc, x) ^ c)
+}
+
+/// Keyed check value over a shared secret; the key is shared with it
+fn shamir_mac(key: &[u8], secret: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(SHAMIR_MAC_DOMAIN);
+    hasher.update(key);
+    hasher.update(secret);
+    hasher.finalize().into()
+}
+
+/// Expand `seed` into `len` bytes under `domain`
+fn shamir_expand(domain: &[u8], seed: &[u8; 32], len: usize) -> Vec<u8> {
+    let mut stream = Vec::with_capacity(len + 32);
+    let mut counter = 0u32;
+    while stream.len() < len {
+        let mut hasher = Sha3_256::new();
+        hasher.update(domain);
+        hasher.update(seed);
+        hasher.update(counter.to_le_bytes());
+        stream.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+    stream.truncate(len);
+    stream
 }
 
 /// Shamir Secret Sharing operations
 ///
 /// ## Lifecycle Stage: Quorum Convergence | Ephemeral Materialization
 ///
-/// Placeholder implementation. In production, use proper Shamir secret sharing
-/// library (e.g., sharks crate with no_std support).
-///
-/// ## Forward Compatibility
-/// TODO: Implement with sharks crate or custom no_std Shamir implementation
+/// Byte-wise Shamir sharing over GF(2^8): each secret byte is the constant
+/// term of an independent random polynomial of degree M-1, and share `i`
+/// holds every polynomial evaluated at `x = i`. Needs only `alloc`, so it
+/// works in `no_std` builds.
 pub struct ShamirSecretSharing;
 
 impl ShamirSecretSharing {
@@ -488,23 +882,42 @@ impl ShamirSecretSharing {
     ///
     /// # Outputs
     /// - Vector of N `ShamirShare` instances
+    /// - Error if the system RNG fails; there is no fallback
     ///
     /// ## Security Rationale
     /// - M < N allows for fault tolerance
-    /// - Individual shares computationally useless alone
+    /// - Any M-1 shares are uniformly distributed whatever the secret
     /// - Polynomial interpolation on M shares recovers secret
+    /// - The split seed comes from the OS CSPRNG only; `no_std` callers use
+    ///   `split_with_seed` with their own CSPRNG output
     ///
     /// ## Audit Trail
     /// - Logs share generation event to ephemeral ledger
     /// - Records threshold and total_shares parameters
+    #[cfg(feature = "std")]
     pub fn split(
         secret: &[u8],
         threshold: u8,
         total_shares: u8,
     ) -> Result<Vec<ShamirShare>, &'static str> {
-        // TODO: Implement proper Shamir secret sharing
-        // Placeholder: XOR-based splitting (NOT SECURE, for skeleton only)
-        
+        let mut seed = [0u8; 32];
+        getrandom::getrandom(&mut seed).map_err(|_| "System RNG unavailable")?;
+        let shares = Self::split_with_seed(secret, threshold, total_shares, &seed);
+        seed.zeroize();
+        shares
+    }
+    
+    /// Split secret with all randomness expanded from a caller-supplied seed
+    ///
+    /// The seed must be fresh CSPRNG output, secret and never reused: it
+    /// determines the polynomial coefficients, the MAC key and the split
+    /// identifier. Nothing is derived from the secret itself.
+    pub fn split_with_seed(
+        secret: &[u8],
+        threshold: u8,
+        total_shares: u8,
+        seed: &[u8; 32],
+    ) -> Result<Vec<ShamirShare>, &'static str> {
         if threshold > total_shares {
             return Err("Threshold cannot exceed total shares");
         }
@@ -513,19 +926,64 @@ impl ShamirSecretSharing {
             return Err("Threshold must be at least 2");
         }
         
-        let mut shares = Vec::new();
-        for i in 1..=total_shares {
-            shares.push(ShamirShare {
-                index: i,
-                value: secret.to_vec(), // Placeholder: Should be polynomial evaluation
-                total_shares,
-                threshold,
-            });
+        if secret.is_empty() {
+            return Err("Secret must not be empty");
         }
         
+        // Shared payload: secret ‖ MAC key ‖ MAC(key, secret)
+        let degree = threshold as usize - 1;
+        let payload_len = secret.len() + 2 * SHAMIR_MAC_LEN;
+        let mut stream = shamir_expand(SHAMIR_COEFF_DOMAIN, seed, SHAMIR_MAC_LEN + payload_len * degree);
+        let mut payload = Vec::with_capacity(payload_len);
+        payload.extend_from_slice(secret);
+        payload.extend_from_slice(&stream[..SHAMIR_MAC_LEN]);
+        let mac = shamir_mac(&stream[..SHAMIR_MAC_LEN], secret);
+        payload.extend_from_slice(&mac);
+        
+        let mut split_id = [0u8; 32];
+        split_id.copy_from_slice(&shamir_expand(SHAMIR_ID_DOMAIN, seed, 32));
+        
+        let shares = Self::deal(&payload, threshold, total_shares, split_id, &stream[SHAMIR_MAC_LEN..]);
+        payload.zeroize();
+        stream.zeroize();
         Ok(shares)
     }
     
+    /// Evaluate per-byte polynomials (payload byte, then `coefficients`
+    /// M-1 at a time) at x = 1..=N
+    fn deal(
+        payload: &[u8],
+        threshold: u8,
+        total_shares: u8,
+        split_id: [u8; 32],
+        coefficients: &[u8],
+    ) -> Vec<ShamirShare> {
+        let degree = threshold as usize - 1;
+        let mut polynomial = Vec::with_capacity(threshold as usize);
+        let mut shares = Vec::with_capacity(total_shares as usize);
+        for index in 1..=total_shares {
+            let mut value = Vec::with_capacity(payload.len());
+            for (b, &byte) in payload.iter().enumerate() {
+                polynomial.clear();
+                polynomial.push(byte);
+                polynomial.extend_from_slice(&coefficients[b * degree..(b + 1) * degree]);
+                value.push(gf256_eval(&polynomial, index));
+            }
+            let mut share = ShamirShare {
+                index,
+                value,
+                total_shares,
+                threshold,
+                split_id,
+                tag: [0u8; 32],
+            };
+            share.tag = share.compute_tag();
+            shares.push(share);
+        }
+        polynomial.zeroize();
+        shares
+    }
+    
     /// Reconstruct secret from M-of-N shares
     ///
     /// ## Lifecycle Stage: Ephemeral Materialization
@@ -537,28 +995,80 @@ impl ShamirSecretSharing {
     /// - Reconstructed secret (master key)
     ///
     /// ## Security Rationale
-    /// - Lagrange interpolation on polynomial
-    /// - Shares zeroized after reconstruction
-    /// - Reconstructed secret zeroized on session end
+    /// - Lagrange interpolation at x = 0 over the first M shares
+    /// - Shares must carry valid tags, agree on parameters and split id,
+    ///   and have distinct indices
+    /// - The reconstructed MAC is checked under the reconstructed key, so a
+    ///   corrupted share is detected rather than yielding a wrong key
     ///
     /// ## Audit Trail
     /// - Logs reconstruction event to ephemeral ledger
     /// - Records participating share indices
     pub fn reconstruct(shares: &[ShamirShare]) -> Result<Vec<u8>, &'static str> {
-        // TODO: Implement proper Shamir reconstruction
-        // Placeholder: Return first share's value (NOT SECURE, for skeleton only)
-        
         if shares.is_empty() {
             return Err("No shares provided");
         }
         
-        let threshold = shares[0].threshold;
-        if shares.len() < threshold as usize {
+        let first = &shares[0];
+        let threshold = first.threshold;
+        if threshold < 2 || shares.len() < threshold as usize {
             return Err("Insufficient shares for reconstruction");
         }
+        if first.value.len() <= 2 * SHAMIR_MAC_LEN {
+            return Err("Share value too short");
+        }
+        
+        for (i, share) in shares.iter().enumerate() {
+            if share.threshold != threshold
+                || share.total_shares != first.total_shares
+                || share.split_id != first.split_id
+                || share.value.len() != first.value.len()
+            {
+                return Err("Shares belong to different splits");
+            }
+            if share.index == 0 || share.index > share.total_shares {
+                return Err("Invalid share index");
+            }
+            if shares[..i].iter().any(|s| s.index == share.index) {
+                return Err("Duplicate share index");
+            }
+            if !share.verify_tag() {
+                return Err("Share integrity tag mismatch");
+            }
+        }
         
-        // Placeholder: Should perform Lagrange interpolation
-        Ok(shares[0].value.clone())
+        // Lagrange basis at x = 0: l_i = Π_{j≠i} x_j / (x_j - x_i)
+        let used = &shares[..threshold as usize];
+        let basis: Vec<u8> = used
+            .iter()
+            .map(|si| {
+                used.iter()
+                    .filter(|sj| sj.index != si.index)
+                    .fold(1u8, |acc, sj| {
+                        gf256_mul(acc, gf256_mul(sj.index, gf256_inv(sj.index ^ si.index)))
+                    })
+            })
+            .collect();
+        
+        let mut payload = vec![0u8; first.value.len()];
+        for (share, &l) in used.iter().zip(&basis) {
+            for (out, &y) in payload.iter_mut().zip(&share.value) {
+                *out ^= gf256_mul(y, l);
+            }
+        }
+        
+        let secret_len = payload.len() - 2 * SHAMIR_MAC_LEN;
+        let (secret, check) = payload.split_at(secret_len);
+        let (key, mac) = check.split_at(SHAMIR_MAC_LEN);
+        let valid = shamir_mac(key, secret).as_slice() == mac;
+        let secret = secret.to_vec();
+        payload.zeroize();
+        if !valid {
+            let mut secret = secret;
+            secret.zeroize();
+            return Err("Reconstructed secret fails its MAC");
+        }
+        Ok(secret)
     }
 }
 
@@ -605,18 +1115,39 @@ impl BiokeyEscrow {
     /// ## Audit Trail
     /// - Logs escrow creation to ephemeral ledger
     /// - Records recovery conditions and authorized parties
+    #[cfg(feature = "std")]
     pub fn new(
         biokey: &EphemeralBiokey,
         recovery_after: u64,
         recovery_threshold: u8,
         total_shares: u8,
         recovery_parties: Vec<[u8; 32]>,
+    ) -> Result<Self, &'static str> {
+        let mut seed = [0u8; 32];
+        getrandom::getrandom(&mut seed).map_err(|_| "System RNG unavailable")?;
+        let escrow = Self::with_seed(biokey, recovery_after, recovery_threshold, total_shares, recovery_parties, &seed);
+        seed.zeroize();
+        escrow
+    }
+    
+    /// Create an escrow whose shares are split with a caller-supplied seed
+    ///
+    /// For `no_std` builds; `seed` must be fresh CSPRNG output (see
+    /// `ShamirSecretSharing::split_with_seed`).
+    pub fn with_seed(
+        biokey: &EphemeralBiokey,
+        recovery_after: u64,
+        recovery_threshold: u8,
+        total_shares: u8,
+        recovery_parties: Vec<[u8; 32]>,
+        seed: &[u8; 32],
     ) -> Result<Self, &'static str> {
         // Use unchecked access for escrow creation (escrow is for recovery)
-        let shares = ShamirSecretSharing::split(
+        let shares = ShamirSecretSharing::split_with_seed(
             biokey.key_material_unchecked(),
             recovery_threshold,
             total_shares,
+            seed,
         )?;
         
         Ok(Self {
@@ -627,6 +1158,20 @@ impl BiokeyEscrow {
         })
     }
     
+    /// Wrap every escrow share for its recovery party
+    ///
+    /// # Inputs
+    /// - `keks`: One key-encryption key per share, in share order
+    ///
+    /// # Outputs
+    /// - Wrapped shares, safe to hand out or store
+    pub fn wrap_shares(&self, keks: &[[u8; 32]]) -> Result<Vec<WrappedShare>, WrapError> {
+        if keks.len() != self.shares.len() {
+            return Err(WrapError::InvalidLength);
+        }
+        self.shares.iter().zip(keks).map(|(share, kek)| share.wrap(kek)).collect()
+    }
+    
     /// Attempt recovery (if conditions met)
     ///
     /// ## Lifecycle Stage: Ephemeral Materialization (recovery path)
@@ -659,7 +1204,7 @@ impl BiokeyEscrow {
         
         // Reconstruct secret
         let key_material_vec = ShamirSecretSharing::reconstruct(recovery_shares)?;
-        let mut key_material = [0u8; 64];
+        let mut key_material = KeyMaterial::<64>::from([0u8; 64]);
         key_material[..key_material_vec.len().min(64)].copy_from_slice(
             &key_material_vec[..key_material_vec.len().min(64)]
         );
@@ -670,26 +1215,13 @@ impl BiokeyEscrow {
             timestamp: current_time,
             invalidated: false,
             entropy_sources: Vec::new(),
+            limits: UsageLimits::default(),
+            usage: UsageSummary::new(0),
+            usage_history: Vec::new(),
         })
     }
 }
 
-/// Get current timestamp (milliseconds since epoch)
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0 // Deterministic default for no_std
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -732,6 +1264,45 @@ mod tests {
         
         // Unchecked access always works
         assert_eq!(biokey.key_material_unchecked().len(), 64);
+        
+        // Key material sits in guarded pages on std targets
+        #[cfg(all(feature = "std", unix))]
+        assert_ne!(biokey.memory_protection(), crate::secure_alloc::Protection::Heap);
+    }
+    
+    #[test]
+    fn test_usage_caps_invalidate_key() {
+        let limits = UsageLimits { signing: 2, ..UsageLimits::UNLIMITED };
+        let mut biokey = EphemeralBiokey::derive(&[b"source1".as_slice()], 0).with_usage_limits(limits);
+        let key = *biokey.key_material_unchecked();
+        
+        assert_eq!(biokey.authorize(KeyOperation::Signing), Ok(&key));
+        assert!(biokey.authorize(KeyOperation::Signing).is_ok());
+        for _ in 0..100 {
+            assert!(biokey.authorize(KeyOperation::Encryption).is_ok());
+        }
+        
+        // Third signature breaches the cap and kills the key for every class
+        assert_eq!(biokey.authorize(KeyOperation::Signing), Err(UsageError::CapExceeded(KeyOperation::Signing)));
+        assert_eq!(biokey.lifetime_state(), LifetimeState::Invalidated);
+        assert_eq!(biokey.authorize(KeyOperation::Encryption), Err(UsageError::KeyUnavailable));
+        
+        // Rotation starts fresh counters and keeps the breached epoch on record
+        biokey.rotate();
+        assert!(biokey.authorize(KeyOperation::Signing).is_ok());
+        let summaries = biokey.usage_summaries();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].count(KeyOperation::Signing), 2);
+        assert_eq!(summaries[0].count(KeyOperation::Encryption), 100);
+        assert_eq!(summaries[0].breached, Some(KeyOperation::Signing));
+        assert_eq!(summaries[1], UsageSummary { epoch: 1, counts: [1, 0, 0, 0], breached: None });
+        
+        let txo = biokey.usage_audit_txo(42);
+        assert_eq!(txo.txo_type, TxoType::KeyUsage);
+        assert_eq!(txo.payload.len(), 2 * 25);
+        assert_eq!(txo.payload[24], 0); // Signing breached in epoch 0
+        assert_eq!(txo.payload[49], 0xFF);
+        assert!(!txo.payload.windows(8).any(|w| key.windows(8).any(|k| k == w)));
     }
     
     #[test]
@@ -790,6 +1361,7 @@ mod tests {
     }
     
     #[test]
+    #[cfg(feature = "std")]
     fn test_shamir_split() {
         let secret = b"master_secret_key_material_here";
         let result = ShamirSecretSharing::split(secret, 3, 5);
@@ -798,6 +1370,138 @@ mod tests {
         assert_eq!(shares.len(), 5);
     }
     
+    #[test]
+    fn test_shamir_reconstruct_any_m_of_n() {
+        let secret = b"master_secret_key_material_here";
+        let shares = ShamirSecretSharing::split_with_seed(secret, 3, 5, &[5u8; 32]).unwrap();
+        assert!(shares.iter().all(|s| s.value.as_slice() != secret.as_slice()));
+        
+        for a in 0..5 {
+            for b in a + 1..5 {
+                for c in b + 1..5 {
+                    let subset = [shares[c].clone(), shares[a].clone(), shares[b].clone()];
+                    assert_eq!(ShamirSecretSharing::reconstruct(&subset).unwrap(), secret);
+                }
+            }
+        }
+        
+        // M-1 shares are refused
+        assert!(ShamirSecretSharing::reconstruct(&shares[..2]).is_err());
+        
+        // Duplicates do not count towards the threshold
+        let dup = [shares[0].clone(), shares[0].clone(), shares[1].clone()];
+        assert_eq!(ShamirSecretSharing::reconstruct(&dup), Err("Duplicate share index"));
+    }
+    
+    #[test]
+    fn test_shamir_integrity() {
+        let seed = [7u8; 32];
+        let mut shares = ShamirSecretSharing::split_with_seed(b"0123456789abcdef", 2, 3, &seed).unwrap();
+        
+        // A flipped share byte fails its tag
+        shares[1].value[0] ^= 1;
+        assert_eq!(
+            ShamirSecretSharing::reconstruct(&shares[..2]),
+            Err("Share integrity tag mismatch")
+        );
+        
+        // Retagging the forgery still fails the shared MAC
+        shares[1].tag = shares[1].compute_tag();
+        assert_eq!(
+            ShamirSecretSharing::reconstruct(&shares[..2]),
+            Err("Reconstructed secret fails its MAC")
+        );
+        
+        // Shares from different splits do not mix
+        let other = ShamirSecretSharing::split_with_seed(b"0123456789abcdef", 2, 3, &[8u8; 32]).unwrap();
+        let mixed = [shares[0].clone(), other[2].clone()];
+        assert_eq!(ShamirSecretSharing::reconstruct(&mixed), Err("Shares belong to different splits"));
+    }
+    
+    #[test]
+    fn test_shamir_m_minus_one_shares_reveal_nothing() {
+        // Field sanity: every non-zero element has an inverse
+        for a in 1..=255u8 {
+            assert_eq!(gf256_mul(a, gf256_inv(a)), 1);
+        }
+        
+        // For a 3-of-N split, two shares at x = 1, 2 are an image of the two
+        // random coefficients. Whatever the secret byte, each pair of share
+        // values arises from exactly one coefficient pair, so the shares are
+        // uniformly distributed and independent of the secret.
+        for secret in [0x00u8, 0x5a, 0xff] {
+            let mut seen = vec![false; 1 << 16];
+            for c1 in 0..=255u8 {
+                for c2 in 0..=255u8 {
+                    let poly = [secret, c1, c2];
+                    let pair = (gf256_eval(&poly, 1) as usize) << 8 | gf256_eval(&poly, 2) as usize;
+                    assert!(!seen[pair]);
+                    seen[pair] = true;
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn test_shamir_share_metadata_independent_of_secret() {
+        let (a, b) = (b"secret number one", b"another secret!!!");
+        
+        // Split id and parameters come from the seed alone
+        let seed = [3u8; 32];
+        let from_a = ShamirSecretSharing::split_with_seed(a, 2, 3, &seed).unwrap();
+        let from_b = ShamirSecretSharing::split_with_seed(b, 2, 3, &seed).unwrap();
+        assert_eq!(from_a[0].split_id, from_b[0].split_id);
+        
+        // For any two secrets there are coefficients giving an identical
+        // M-1 share set, index, value, split id and tag included. At x = 1
+        // a degree-1 share is payload ^ coefficient, so shift the coefficients
+        // by the payload difference.
+        let payload = |secret: &[u8], key: u8| {
+            let mut p = secret.to_vec();
+            p.extend_from_slice(&[key; SHAMIR_MAC_LEN]);
+            p.extend_from_slice(&shamir_mac(&[key; SHAMIR_MAC_LEN], secret));
+            p
+        };
+        let (pa, pb) = (payload(a, 1), payload(b, 2));
+        let coefficients_a: Vec<u8> = (0..pa.len() as u8).map(|i| i.wrapping_mul(37)).collect();
+        let coefficients_b: Vec<u8> = coefficients_a.iter().zip(pa.iter().zip(&pb)).map(|(c, (x, y))| c ^ x ^ y).collect();
+        let shares_a = ShamirSecretSharing::deal(&pa, 2, 3, [9u8; 32], &coefficients_a);
+        let shares_b = ShamirSecretSharing::deal(&pb, 2, 3, [9u8; 32], &coefficients_b);
+        
+        let (sa, sb) = (&shares_a[0], &shares_b[0]);
+        assert_eq!((sa.index, sa.threshold, sa.total_shares), (sb.index, sb.threshold, sb.total_shares));
+        assert_eq!(sa.value, sb.value);
+        assert_eq!((sa.split_id, sa.tag), (sb.split_id, sb.tag));
+        
+        // Yet each full set reconstructs its own secret
+        assert_eq!(ShamirSecretSharing::reconstruct(&shares_a[..2]).unwrap(), a);
+        assert_eq!(ShamirSecretSharing::reconstruct(&shares_b[..2]).unwrap(), b);
+    }
+    
+    #[test]
+    fn test_escrow_shares_wrapped_at_rest() {
+        let entropy = [b"source1".as_slice(), b"source2".as_slice()];
+        let biokey = EphemeralBiokey::derive(&entropy, 0);
+        let escrow = BiokeyEscrow::with_seed(&biokey, 0, 2, 3, Vec::new(), &[6u8; 32]).unwrap();
+        let keks = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        
+        let wrapped = escrow.wrap_shares(&keks).unwrap();
+        // 64-byte key plus 64 bytes of shared MAC key and MAC, plus the KW block
+        assert!(wrapped.iter().all(|w| w.value.wrapped.len() == 136));
+        assert_eq!(escrow.wrap_shares(&keks[..2]), Err(WrapError::InvalidLength));
+        
+        // Each party unwraps only with its own KEK
+        assert!(matches!(wrapped[0].unwrap(&keks[1], None), Err(WrapError::IntegrityCheckFailed)));
+        let shares = [wrapped[0].unwrap(&keks[0], None).unwrap(), wrapped[2].unwrap(&keks[2], None).unwrap()];
+        let recovered = escrow.recover(&shares, 1).unwrap();
+        assert_eq!(recovered.key_material_unchecked(), biokey.key_material_unchecked());
+        
+        // A tag that does not match the wrapped value is refused
+        let mut forged = wrapped[1].clone();
+        forged.tag = wrapped[0].tag;
+        assert!(matches!(forged.unwrap(&keks[1], None), Err(WrapError::IntegrityCheckFailed)));
+    }
+    
     #[test]
     fn test_remaining_lifetime() {
         let entropy = [b"source1".as_slice()];
@@ -11,6 +11,8 @@
 //! - **Quorum-Controlled Reveal**: Requires threshold consensus
 //! - **Commitment Integrity**: Cryptographic binding prevents tampering
 //! - **Selective Disclosure**: Reveal only when necessary
+//! - **Threshold Reveal**: Decrypt from member partials without rebuilding
+//!   the payload key (see [`crate::threshold`])
 //!
 //! ## Security Rationale
 //!
@@ -22,7 +24,8 @@
 extern crate alloc;
 use alloc::vec::Vec;
 
-use crate::txo::BlindedPayload;
+use crate::threshold::{reveal_audit_txo, PartialDecryption, ThresholdCiphertext, ThresholdError, ThresholdPublicKey};
+use crate::txo::{BlindedPayload, Txo};
 use sha3::{Sha3_256, Digest};
 
 /// Blinded Payload Manager
@@ -95,6 +98,45 @@ impl BlindedPayloadManager {
         blinded.revealed = Some(payload);
         Ok(())
     }
+
+    /// Reveal blinded payload from threshold partial decryptions
+    ///
+    /// ## Lifecycle Stage: Outcome Commitment
+    ///
+    /// # Inputs
+    /// - `blinded`: Blinded payload to reveal
+    /// - `public`: Quorum threshold public key
+    /// - `ciphertext`: Payload encrypted to the quorum
+    /// - `partials`: Partial decryptions from contributing members
+    /// - `timestamp`: Reveal time (milliseconds)
+    ///
+    /// # Outputs
+    /// - `Ok(Txo)`: `ThresholdReveal` audit TXO listing contributing members
+    /// - `Err(ThresholdError)` otherwise
+    ///
+    /// ## Security Rationale
+    /// - Contributors must meet both the key threshold and the reveal quorum
+    /// - Each partial is verified; the payload key is never reconstructed
+    pub fn reveal_threshold(
+        &self,
+        blinded: &mut BlindedPayload,
+        public: &ThresholdPublicKey,
+        ciphertext: &ThresholdCiphertext,
+        partials: &[PartialDecryption],
+        timestamp: u64,
+    ) -> Result<Txo, ThresholdError> {
+        if ciphertext.commitment != blinded.commitment {
+            return Err(ThresholdError::CommitmentMismatch);
+        }
+        let vote_percentage = (partials.len() * 100) / public.members.len().max(1);
+        if vote_percentage < blinded.reveal_threshold as usize {
+            return Err(ThresholdError::InsufficientShares);
+        }
+
+        let payload = public.combine(ciphertext, partials)?;
+        blinded.revealed = Some(payload);
+        Ok(reveal_audit_txo(blinded.commitment, partials, timestamp))
+    }
 }
 
 #[cfg(test)]
@@ -121,4 +163,28 @@ mod tests {
         assert!(result.is_ok());
         assert!(blinded.revealed.is_some());
     }
+
+    #[test]
+    fn test_reveal_threshold() {
+        let manager = BlindedPayloadManager::new(67);
+        let payload = b"secret data";
+        let mut blinded = manager.blind(payload);
+
+        let members: Vec<[u8; 32]> = (1..=3u8).map(|i| [i; 32]).collect();
+        let (public, shares) = crate::threshold::run_dkg(5, 2, &members);
+        let ciphertext = public.encrypt(payload, &[6u8; 32]);
+        let partials: Vec<PartialDecryption> =
+            shares.iter().map(|s| s.partial_decrypt(&ciphertext).unwrap()).collect();
+
+        // Two partials meet the key threshold but not the 67% reveal quorum
+        assert_eq!(
+            manager.reveal_threshold(&mut blinded, &public, &ciphertext, &partials[..2], 1).err(),
+            Some(ThresholdError::InsufficientShares)
+        );
+        assert!(blinded.revealed.is_none());
+
+        let txo = manager.reveal_threshold(&mut blinded, &public, &ciphertext, &partials, 2).unwrap();
+        assert!(blinded.verify());
+        assert_eq!(txo.payload[32..36], 3u32.to_le_bytes());
+    }
 }
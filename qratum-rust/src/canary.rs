@@ -40,6 +40,7 @@ use alloc::string::String;
 use crate::txo::{Txo, TxoType};
 use sha3::{Sha3_256, Digest};
 use zeroize::{Zeroize, ZeroizeOnDrop};
+use crate::clock;
 
 /// Canary Configuration
 ///
@@ -131,7 +132,7 @@ impl CanaryProbe {
     ) -> Self {
         Self {
             sequence,
-            timestamp: current_timestamp(),
+            timestamp: clock::now_ms(),
             state_hash,
             previous_canary_hash,
             session_id,
@@ -221,7 +222,7 @@ impl CanaryState {
     pub fn new(session_id: [u8; 32], initial_sequence: u64) -> Self {
         Self {
             sequence: initial_sequence,
-            last_emission: current_timestamp(),
+            last_emission: clock::now_ms(),
             last_canary_hash: [0u8; 32], // Genesis canary
             session_id,
             history: Vec::new(),
@@ -239,7 +240,7 @@ impl CanaryState {
     /// # Outputs
     /// - `true` if interval elapsed, `false` otherwise
     pub fn emission_due(&self, config: &CanaryConfig) -> bool {
-        let current_time = current_timestamp();
+        let current_time = clock::now_ms();
         current_time - self.last_emission >= config.interval_ms
     }
     
@@ -268,7 +269,7 @@ impl CanaryState {
         
         // Update state
         self.sequence += 1;
-        self.last_emission = current_timestamp();
+        self.last_emission = clock::now_ms();
         self.last_canary_hash = canary_hash;
         
         // Add to history (bounded)
@@ -377,7 +378,7 @@ impl CanaryVerifier {
     /// - Triggers investigation and fallback procedures
     pub fn is_overdue(&self) -> bool {
         if let Some(ref last) = self.last_verified {
-            let current_time = current_timestamp();
+            let current_time = clock::now_ms();
             let elapsed = current_time - last.timestamp;
             elapsed > self.expected_interval_ms + self.tolerance_ms
         } else {
@@ -386,22 +387,6 @@ impl CanaryVerifier {
     }
 }
 
-/// Get current timestamp (milliseconds since epoch)
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0 // Deterministic default for no_std
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
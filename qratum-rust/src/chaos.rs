@@ -0,0 +1,373 @@
+//! # Chaos Module - Fault-Injecting Transport Proxy (test builds only)
+//!
+//! ## Lifecycle Stage: Network Infrastructure (testing)
+//!
+//! Transport channels in unit tests are perfect: every message arrives at
+//! once, exactly once, intact, and in order. `ChaosChannel` wraps a
+//! [`Channel`] and delivers messages the way an adverse network would:
+//! delayed by a configurable latency distribution, reordered, duplicated,
+//! corrupted, or dropped. All faults come from a seeded generator, so a
+//! failing scenario replays exactly from its seed.
+//!
+//! Compiled for this crate's tests, or for downstream tests with the
+//! `chaos` feature. Never enable `chaos` in production builds.
+//!
+//! ## Architectural Role
+//!
+//! - **Latency**: `LatencyDistribution` per channel, defaulting to the
+//!   channel's typical latency
+//! - **Faults**: reorder, duplicate, corrupt, and drop rates in per-mille
+//! - **Virtual Time**: `send` and `deliver` take the simulated time, so
+//!   tests tune canary, censorship, and consensus timeouts without sleeping
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Messages and simulated send times
+//! - Output: `Delivery` records in arrival order, `ChaosStats`
+//!
+//! ## Security Rationale
+//!
+//! - Timeouts and detectors are validated against reproducible adverse
+//!   conditions instead of a perfect network
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::transport::Channel;
+
+/// Latency distribution for one channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatencyDistribution {
+    /// Every message takes exactly this long (milliseconds)
+    Fixed(u64),
+    /// Uniform in `[min_ms, max_ms]`
+    Uniform {
+        /// Lower bound (milliseconds)
+        min_ms: u64,
+        /// Upper bound (milliseconds)
+        max_ms: u64,
+    },
+    /// Uniform base latency with occasional spikes (congestion, circuit rebuilds)
+    Spiky {
+        /// Lower bound of the base latency (milliseconds)
+        min_ms: u64,
+        /// Upper bound of the base latency (milliseconds)
+        max_ms: u64,
+        /// Extra delay of a spike (milliseconds)
+        spike_ms: u64,
+        /// Spike probability (per mille)
+        spike_per_mille: u16,
+    },
+}
+
+impl LatencyDistribution {
+    /// Uniform within ±50% of the channel's typical latency
+    ///
+    /// Offline channels have no typical latency and deliver after an hour.
+    pub fn typical(channel: Channel) -> Self {
+        match channel {
+            Channel::Offline => LatencyDistribution::Fixed(3_600_000),
+            _ => {
+                let typical = channel.typical_latency_ms();
+                LatencyDistribution::Uniform { min_ms: typical / 2, max_ms: typical + typical / 2 }
+            }
+        }
+    }
+
+    fn sample(&self, rng: &mut SplitMix64) -> u64 {
+        match *self {
+            LatencyDistribution::Fixed(ms) => ms,
+            LatencyDistribution::Uniform { min_ms, max_ms } => rng.between(min_ms, max_ms),
+            LatencyDistribution::Spiky { min_ms, max_ms, spike_ms, spike_per_mille } => {
+                let base = rng.between(min_ms, max_ms);
+                if rng.chance(spike_per_mille) {
+                    base.saturating_add(spike_ms)
+                } else {
+                    base
+                }
+            }
+        }
+    }
+}
+
+/// Fault injection settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChaosConfig {
+    /// Seed for every random decision
+    pub seed: u64,
+    /// Per-message latency
+    pub latency: LatencyDistribution,
+    /// Probability a message is held back by one extra latency sample (per mille)
+    pub reorder_per_mille: u16,
+    /// Probability a message is delivered twice (per mille)
+    pub duplicate_per_mille: u16,
+    /// Probability one bit of a message is flipped (per mille)
+    pub corrupt_per_mille: u16,
+    /// Probability a message is never delivered (per mille)
+    pub drop_per_mille: u16,
+}
+
+impl ChaosConfig {
+    /// Typical latency for `channel`, no other faults
+    pub fn for_channel(channel: Channel, seed: u64) -> Self {
+        Self {
+            seed,
+            latency: LatencyDistribution::typical(channel),
+            reorder_per_mille: 0,
+            duplicate_per_mille: 0,
+            corrupt_per_mille: 0,
+            drop_per_mille: 0,
+        }
+    }
+}
+
+/// A message leaving the chaos channel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delivery {
+    /// Payload as received (possibly corrupted)
+    pub payload: Vec<u8>,
+    /// Position of the original message in send order
+    pub send_index: u64,
+    /// Simulated send time (milliseconds)
+    pub sent_at: u64,
+    /// Simulated arrival time (milliseconds)
+    pub delivered_at: u64,
+    /// Whether a bit was flipped
+    pub corrupted: bool,
+    /// Whether this is an injected duplicate
+    pub duplicate: bool,
+}
+
+/// Counters of injected faults
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChaosStats {
+    /// Messages sent
+    pub sent: u64,
+    /// Messages delivered, duplicates included
+    pub delivered: u64,
+    /// Messages held back for reordering
+    pub reordered: u64,
+    /// Duplicates injected
+    pub duplicated: u64,
+    /// Messages corrupted
+    pub corrupted: u64,
+    /// Messages dropped
+    pub dropped: u64,
+}
+
+/// Deterministic generator (SplitMix64)
+#[derive(Debug, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn between(&mut self, min: u64, max: u64) -> u64 {
+        if max <= min {
+            return min;
+        }
+        match (max - min).checked_add(1) {
+            Some(span) => min + self.next() % span,
+            None => self.next(),
+        }
+    }
+
+    fn chance(&mut self, per_mille: u16) -> bool {
+        per_mille > 0 && self.next() % 1000 < u64::from(per_mille)
+    }
+}
+
+/// Transport channel with injected latency and faults
+///
+/// ## Lifecycle Stage: Network Infrastructure (testing)
+pub struct ChaosChannel {
+    channel: Channel,
+    config: ChaosConfig,
+    rng: SplitMix64,
+    in_flight: Vec<Delivery>,
+    stats: ChaosStats,
+}
+
+impl ChaosChannel {
+    /// Wrap `channel` with the given faults
+    pub fn new(channel: Channel, config: ChaosConfig) -> Self {
+        Self {
+            channel,
+            config,
+            rng: SplitMix64(config.seed),
+            in_flight: Vec::new(),
+            stats: ChaosStats::default(),
+        }
+    }
+
+    /// Wrapped channel
+    pub fn channel(&self) -> Channel {
+        self.channel
+    }
+
+    /// Fault counters so far
+    pub fn stats(&self) -> ChaosStats {
+        self.stats
+    }
+
+    /// Messages sent but not yet delivered (duplicates included)
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// Send a message at simulated time `now`
+    ///
+    /// Fault decisions are drawn in a fixed order per message, so a seed
+    /// reproduces the same faults for the same send sequence.
+    pub fn send(&mut self, payload: &[u8], now: u64) {
+        let send_index = self.stats.sent;
+        self.stats.sent += 1;
+        if self.rng.chance(self.config.drop_per_mille) {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        let mut message = Delivery {
+            payload: payload.to_vec(),
+            send_index,
+            sent_at: now,
+            delivered_at: now.saturating_add(self.config.latency.sample(&mut self.rng)),
+            corrupted: false,
+            duplicate: false,
+        };
+        if self.rng.chance(self.config.reorder_per_mille) {
+            let hold = self.config.latency.sample(&mut self.rng).max(1);
+            message.delivered_at = message.delivered_at.saturating_add(hold);
+            self.stats.reordered += 1;
+        }
+        if self.rng.chance(self.config.corrupt_per_mille) && !message.payload.is_empty() {
+            let bit = self.rng.between(0, message.payload.len() as u64 * 8 - 1);
+            message.payload[(bit / 8) as usize] ^= 1 << (bit % 8);
+            message.corrupted = true;
+            self.stats.corrupted += 1;
+        }
+        if self.rng.chance(self.config.duplicate_per_mille) {
+            let mut copy = message.clone();
+            copy.delivered_at = now.saturating_add(self.config.latency.sample(&mut self.rng));
+            copy.duplicate = true;
+            self.in_flight.push(copy);
+            self.stats.duplicated += 1;
+        }
+        self.in_flight.push(message);
+    }
+
+    /// Messages that have arrived by simulated time `now`, in arrival order
+    ///
+    /// Ties are broken by send order, originals before duplicates.
+    pub fn deliver(&mut self, now: u64) -> Vec<Delivery> {
+        let (mut arrived, pending): (Vec<_>, Vec<_>) =
+            self.in_flight.drain(..).partition(|m| m.delivered_at <= now);
+        self.in_flight = pending;
+        arrived.sort_by_key(|m| (m.delivered_at, m.send_index, m.duplicate));
+        self.stats.delivered += arrived.len() as u64;
+        arrived
+    }
+
+    /// Every remaining message, in arrival order
+    pub fn flush(&mut self) -> Vec<Delivery> {
+        self.deliver(u64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canary::{CanaryProbe, CanaryVerifier};
+
+    fn adverse(seed: u64) -> ChaosConfig {
+        ChaosConfig {
+            seed,
+            latency: LatencyDistribution::Spiky { min_ms: 40, max_ms: 80, spike_ms: 2_000, spike_per_mille: 50 },
+            reorder_per_mille: 100,
+            duplicate_per_mille: 50,
+            corrupt_per_mille: 50,
+            drop_per_mille: 50,
+        }
+    }
+
+    fn run(config: ChaosConfig) -> (Vec<Delivery>, ChaosStats) {
+        let mut chaos = ChaosChannel::new(Channel::Tcp, config);
+        let mut delivered = Vec::new();
+        for i in 0..500u64 {
+            chaos.send(&i.to_le_bytes(), i * 10);
+            delivered.extend(chaos.deliver(i * 10));
+        }
+        delivered.extend(chaos.flush());
+        (delivered, chaos.stats())
+    }
+
+    #[test]
+    fn test_faults_are_reproducible_from_seed() {
+        let (first, stats) = run(adverse(7));
+        let (second, again) = run(adverse(7));
+        assert_eq!(first, second);
+        assert_eq!(stats, again);
+        assert_ne!(run(adverse(8)).0, first);
+
+        // Every fault class fired, and the counters add up
+        assert!(stats.reordered > 0 && stats.duplicated > 0 && stats.corrupted > 0 && stats.dropped > 0);
+        assert_eq!(stats.delivered, stats.sent - stats.dropped + stats.duplicated);
+        assert_eq!(first.iter().filter(|d| d.corrupted && !d.duplicate).count() as u64, stats.corrupted);
+        assert!(first.windows(2).any(|w| w[0].send_index > w[1].send_index));
+        assert!(first.iter().all(|d| d.delivered_at >= d.sent_at + 40));
+
+        // Without faults everything arrives once and intact; with constant latency, in order
+        let (clean, stats) = run(ChaosConfig::for_channel(Channel::Tcp, 7));
+        assert_eq!(stats.delivered, 500);
+        assert!(clean.iter().all(|d| !d.corrupted && !d.duplicate));
+        let mut config = ChaosConfig::for_channel(Channel::Tcp, 7);
+        config.latency = LatencyDistribution::Fixed(50);
+        let (clean, _) = run(config);
+        assert!(clean.windows(2).all(|w| w[0].send_index < w[1].send_index));
+    }
+
+    #[test]
+    fn test_canary_verifier_under_reordering_and_loss() {
+        let session_id = [3u8; 32];
+        let mut probes = Vec::new();
+        let mut previous = [0u8; 32];
+        for sequence in 0..50u64 {
+            let mut probe = CanaryProbe::new(sequence, [0u8; 32], previous, session_id);
+            probe.timestamp = sequence * 1_000;
+            previous = probe.compute_hash();
+            probes.push(probe);
+        }
+
+        // Observer verdicts for canaries relayed over `config`
+        let verdicts = |config: ChaosConfig| {
+            let mut chaos = ChaosChannel::new(Channel::Tor, config);
+            for probe in &probes {
+                chaos.send(&probe.sequence.to_le_bytes(), probe.timestamp);
+            }
+            let mut verifier = CanaryVerifier::new(1_000, 100);
+            chaos.flush().iter()
+                .map(|d| {
+                    let index = u64::from_le_bytes(d.payload[..8].try_into().unwrap()) as usize;
+                    verifier.verify(&probes[index]).is_err()
+                })
+                .filter(|rejected| *rejected)
+                .count()
+        };
+
+        // Constant latency keeps the stream intact; reordering and loss break it
+        let mut config = ChaosConfig::for_channel(Channel::Tor, 11);
+        config.latency = LatencyDistribution::Fixed(5_000);
+        assert_eq!(verdicts(config), 0);
+        config.reorder_per_mille = 200;
+        config.drop_per_mille = 100;
+        let rejected = verdicts(config);
+        assert!(rejected > 0);
+        assert_eq!(rejected, verdicts(config));
+    }
+}
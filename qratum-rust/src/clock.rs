@@ -0,0 +1,68 @@
+//! # Clock Module - Wall-Clock Abstraction
+//!
+//! ## Architectural Role
+//!
+//! Single source of "now" for every module. With the `std` feature the host
+//! clock is used; without it (TEE/enclave, bare metal) time is whatever the
+//! trusted host last supplied via [`set_time_ms`], starting at 0.
+//!
+//! ## Security Rationale
+//!
+//! - no_std builds never read an untrusted platform clock implicitly
+//! - The default of 0 keeps no_std sessions deterministic until time is supplied
+//! - Targets without 64-bit atomics stay at the deterministic default
+
+#[cfg(all(not(feature = "std"), target_has_atomic = "64"))]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Externally supplied time for no_std builds
+#[cfg(all(not(feature = "std"), target_has_atomic = "64"))]
+static SUPPLIED_TIME_MS: AtomicU64 = AtomicU64::new(0);
+
+/// Current time in milliseconds since the Unix epoch
+pub fn now_ms() -> u64 {
+    #[cfg(feature = "std")]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0)
+    }
+    #[cfg(all(not(feature = "std"), target_has_atomic = "64"))]
+    {
+        SUPPLIED_TIME_MS.load(Ordering::Acquire)
+    }
+    #[cfg(all(not(feature = "std"), not(target_has_atomic = "64")))]
+    {
+        0 // Deterministic default for no_std
+    }
+}
+
+/// Supply trusted time (milliseconds since the Unix epoch) to no_std builds
+///
+/// Time never moves backwards: earlier values are ignored. A no-op with
+/// `std`, where the host clock is authoritative.
+pub fn set_time_ms(ms: u64) {
+    #[cfg(all(not(feature = "std"), target_has_atomic = "64"))]
+    SUPPLIED_TIME_MS.fetch_max(ms, Ordering::AcqRel);
+    #[cfg(any(feature = "std", not(target_has_atomic = "64")))]
+    let _ = ms;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_defaults() {
+        let before = now_ms();
+        assert!(now_ms() >= before);
+
+        // Host clock with std; deterministic 0 until time is supplied without it
+        #[cfg(feature = "std")]
+        assert!(before > 1_600_000_000_000);
+        #[cfg(not(feature = "std"))]
+        assert_eq!(before, 0);
+    }
+}
@@ -35,6 +35,7 @@ use alloc::string::String;
 
 use crate::txo::{Txo, TxoType, ComplianceZkp};
 use zeroize::{Zeroize, ZeroizeOnDrop};
+use crate::clock;
 
 /// Compliance Circuit Type
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -311,7 +312,7 @@ impl ComplianceAttestation {
         Self {
             circuit_type,
             zkp,
-            timestamp: current_timestamp(),
+            timestamp: clock::now_ms(),
             attester_id,
             signature: [0u8; 64], // TODO: Generate signature
         }
@@ -342,22 +343,6 @@ impl ComplianceAttestation {
     }
 }
 
-/// Get current timestamp (milliseconds since epoch)
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -27,6 +27,9 @@ use alloc::collections::BTreeSet;
 use sha3::{Sha3_256, Digest};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+use super::dp::{DpAnnotation, DpError, DpLayer};
+use crate::clock;
+
 /// CMMC Practice Domain
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CmmcDomain {
@@ -426,7 +429,7 @@ impl CmmcComplianceEngine {
         
         self.log_event(CmmcAuditEvent {
             event_id: generate_event_id(),
-            timestamp: current_timestamp(),
+            timestamp: clock::now_ms(),
             event_type: AuditEventType::ConfigurationChange,
             user_id: None,
             resource_id: Some(enclave_id),
@@ -447,7 +450,7 @@ impl CmmcComplianceEngine {
         
         self.log_event(CmmcAuditEvent {
             event_id: generate_event_id(),
-            timestamp: current_timestamp(),
+            timestamp: clock::now_ms(),
             event_type: AuditEventType::ConfigurationChange,
             user_id: Some(user_id),
             resource_id: None,
@@ -526,12 +529,12 @@ impl CmmcComplianceEngine {
         if has_permission {
             self.log_event(CmmcAuditEvent {
                 event_id: generate_event_id(),
-                timestamp: current_timestamp(),
+                timestamp: clock::now_ms(),
                 event_type: AuditEventType::Authorization,
                 user_id: Some(*user_id),
                 resource_id: Some(*resource_id),
                 enclave_id: enclave_id.copied(),
-                action: format!("{:?}", permission),
+                action: alloc::format!("{:?}", permission),
                 success: true,
                 details: "Access granted".into(),
                 source: "access_control".into(),
@@ -577,7 +580,7 @@ impl CmmcComplianceEngine {
     fn log_failed_access(&mut self, user_id: [u8; 32], resource_id: [u8; 32], reason: &str) {
         self.log_event(CmmcAuditEvent {
             event_id: generate_event_id(),
-            timestamp: current_timestamp(),
+            timestamp: clock::now_ms(),
             event_type: AuditEventType::FailedAccess,
             user_id: Some(user_id),
             resource_id: Some(resource_id),
@@ -598,7 +601,7 @@ impl CmmcComplianceEngine {
     ) {
         if let Some(user) = self.users.get_mut(user_id) {
             if success {
-                user.last_auth = Some(current_timestamp());
+                user.last_auth = Some(clock::now_ms());
                 user.failed_attempts = 0;
             } else {
                 user.failed_attempts += 1;
@@ -610,14 +613,14 @@ impl CmmcComplianceEngine {
         
         self.log_event(CmmcAuditEvent {
             event_id: generate_event_id(),
-            timestamp: current_timestamp(),
+            timestamp: clock::now_ms(),
             event_type: AuditEventType::Authentication,
             user_id: Some(*user_id),
             resource_id: None,
             enclave_id: None,
             action: if success { "LOGIN_SUCCESS" } else { "LOGIN_FAILURE" }.into(),
             success,
-            details: format!("MFA: {}", mfa_used),
+            details: alloc::format!("MFA: {}", mfa_used),
             source: "authentication".into(),
         });
     }
@@ -653,12 +656,12 @@ impl CmmcComplianceEngine {
             }
         }
         
-        baseline.last_verified = current_timestamp();
+        baseline.last_verified = clock::now_ms();
         baseline.deviation_count = deviations.len() as u32;
         
         Some(ConfigurationVerification {
             baseline_id: *baseline_id,
-            verified_at: current_timestamp(),
+            verified_at: clock::now_ms(),
             total_items: baseline.items.len(),
             compliant_items: compliant_count,
             deviations,
@@ -690,7 +693,7 @@ impl CmmcComplianceEngine {
             .count();
         
         CmmcComplianceReport {
-            report_timestamp: current_timestamp(),
+            report_timestamp: clock::now_ms(),
             total_enclaves,
             total_users,
             active_users,
@@ -700,8 +703,28 @@ impl CmmcComplianceEngine {
             failed_access_events,
             total_baselines,
             baselines_compliant,
+            dp_annotation: None,
         }
     }
+    
+    /// Generate CMMC compliance report with differentially private counts
+    ///
+    /// User and audit-event counts are noised; enclave and baseline counts
+    /// describe configuration rather than people and stay exact.
+    pub fn generate_private_compliance_report(&self, dp: &mut DpLayer) -> Result<CmmcComplianceReport, DpError> {
+        let mut report = self.generate_compliance_report();
+        let mut noiser = dp.begin_report("CMMC", 6)?;
+        
+        report.total_users = noiser.noise_count("total_users", report.total_users);
+        report.active_users = noiser.noise_count("active_users", report.active_users);
+        report.locked_users = noiser.noise_count("locked_users", report.locked_users);
+        report.mfa_enabled_users = noiser.noise_count("mfa_enabled_users", report.mfa_enabled_users);
+        report.total_audit_events = noiser.noise_count("total_audit_events", report.total_audit_events);
+        report.failed_access_events = noiser.noise_count("failed_access_events", report.failed_access_events);
+        
+        report.dp_annotation = Some(noiser.finish());
+        Ok(report)
+    }
 }
 
 impl Default for CmmcComplianceEngine {
@@ -742,32 +765,18 @@ pub struct CmmcComplianceReport {
     pub failed_access_events: usize,
     pub total_baselines: usize,
     pub baselines_compliant: usize,
+    /// Present when counts were noised by the DP layer
+    pub dp_annotation: Option<DpAnnotation>,
 }
 
 /// Generate unique event ID
 fn generate_event_id() -> [u8; 32] {
     let mut hasher = Sha3_256::new();
-    hasher.update(&current_timestamp().to_le_bytes());
+    hasher.update(clock::now_ms().to_le_bytes());
     hasher.update(b"event");
     hasher.finalize().into()
 }
 
-/// Get current timestamp
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -787,7 +796,7 @@ mod tests {
             data_categories: vec![DataCategory::Cui],
             boundary_controls: BoundaryControls::default(),
             active_sessions: Vec::new(),
-            created_at: current_timestamp(),
+            created_at: clock::now_ms(),
         };
         
         let enclave_id = engine.create_enclave(enclave);
@@ -810,7 +819,7 @@ mod tests {
             status: AccountStatus::Active,
             last_auth: None,
             failed_attempts: 0,
-            created_at: current_timestamp(),
+            created_at: clock::now_ms(),
             mfa_enabled: true,
         };
         engine.register_user(user);
@@ -850,7 +859,7 @@ mod tests {
             status: AccountStatus::Active,
             last_auth: None,
             failed_attempts: 0,
-            created_at: current_timestamp(),
+            created_at: clock::now_ms(),
             mfa_enabled: false,
         };
         engine.register_user(user);
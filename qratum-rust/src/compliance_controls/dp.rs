@@ -0,0 +1,394 @@
+//! Differential Privacy Layer for Compliance Reporting
+//!
+//! Optional noise layer for aggregate counts in HIPAA, GDPR, and CMMC
+//! compliance reports. Exact counts over small populations can reveal whether
+//! a specific individual is present (e.g. one special-category record, one
+//! locked account in a small enclave).
+//!
+//! ## Properties
+//! - Calibrated Laplace or two-sided geometric noise (count sensitivity 1)
+//! - Per-report epsilon, split evenly across the noised figures and charged
+//!   against a total privacy budget (sequential composition)
+//! - Deterministic noise derived from an auditable seed: auditors holding the
+//!   seed can recompute every draw; reports carry only a commitment to it
+//! - Every noised report carries a `DpAnnotation` listing the noised figures
+//!
+//! ## Regulatory Reference
+//! - GDPR Article 89: Safeguards for statistical purposes
+//! - HIPAA 164.514(b): De-identification
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use sha3::{Sha3_256, Digest};
+
+/// Noise mechanism
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DpMechanism {
+    /// Continuous Laplace noise, rounded to the nearest count
+    Laplace,
+    /// Two-sided geometric noise (discrete Laplace)
+    Geometric,
+}
+
+/// Differential privacy error
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DpError {
+    /// Epsilon must be finite and positive
+    InvalidEpsilon,
+    /// Report would exceed the remaining privacy budget
+    BudgetExhausted {
+        /// Epsilon remaining in the budget
+        remaining: f64,
+    },
+}
+
+/// Noised figure annotation
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisedFigure {
+    /// Report field name
+    pub field: &'static str,
+    /// Epsilon spent on this figure
+    pub epsilon: f64,
+}
+
+/// Annotation attached to a noised report
+#[derive(Debug, Clone, PartialEq)]
+pub struct DpAnnotation {
+    /// Mechanism used for every figure in the report
+    pub mechanism: DpMechanism,
+    /// Total epsilon charged for the report
+    pub epsilon: f64,
+    /// Report sequence number under this layer
+    pub report_index: u64,
+    /// SHA3-256 commitment to the report noise seed
+    pub seed_commitment: [u8; 32],
+    /// Figures that carry noise; all other figures are exact
+    pub noised: Vec<NoisedFigure>,
+}
+
+impl DpAnnotation {
+    /// Whether a field was noised
+    pub fn is_noised(&self, field: &str) -> bool {
+        self.noised.iter().any(|f| f.field == field)
+    }
+}
+
+/// Privacy budget under sequential composition
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrivacyBudget {
+    /// Total epsilon available
+    pub total: f64,
+    /// Epsilon spent so far
+    pub spent: f64,
+}
+
+impl PrivacyBudget {
+    /// Remaining epsilon
+    pub fn remaining(&self) -> f64 {
+        (self.total - self.spent).max(0.0)
+    }
+
+    /// Charge epsilon against the budget
+    pub fn charge(&mut self, epsilon: f64) -> Result<(), DpError> {
+        if self.spent + epsilon > self.total {
+            return Err(DpError::BudgetExhausted { remaining: self.remaining() });
+        }
+        self.spent += epsilon;
+        Ok(())
+    }
+}
+
+/// Differential privacy layer
+///
+/// Holds the auditable master seed and the privacy budget shared by all
+/// reports generated through it.
+#[derive(Debug, Clone)]
+pub struct DpLayer {
+    mechanism: DpMechanism,
+    epsilon_per_report: f64,
+    budget: PrivacyBudget,
+    seed: [u8; 32],
+    reports_issued: u64,
+}
+
+impl DpLayer {
+    /// Create a DP layer
+    ///
+    /// # Inputs
+    /// - `mechanism`: Noise mechanism
+    /// - `epsilon_per_report`: Epsilon charged per report
+    /// - `total_budget`: Total epsilon across all reports
+    /// - `seed`: Auditable master seed (escrowed with auditors, never published)
+    pub fn new(
+        mechanism: DpMechanism,
+        epsilon_per_report: f64,
+        total_budget: f64,
+        seed: [u8; 32],
+    ) -> Result<Self, DpError> {
+        let valid = |e: f64| e.is_finite() && e > 0.0;
+        if !valid(epsilon_per_report) || !valid(total_budget) {
+            return Err(DpError::InvalidEpsilon);
+        }
+        Ok(Self {
+            mechanism,
+            epsilon_per_report,
+            budget: PrivacyBudget { total: total_budget, spent: 0.0 },
+            seed,
+            reports_issued: 0,
+        })
+    }
+
+    /// Privacy budget state
+    pub fn budget(&self) -> &PrivacyBudget {
+        &self.budget
+    }
+
+    /// Begin a noised report, charging `epsilon_per_report`
+    ///
+    /// # Inputs
+    /// - `report_kind`: Domain separator (e.g. "HIPAA")
+    /// - `fields`: Number of figures that will be noised
+    pub fn begin_report(&mut self, report_kind: &str, fields: usize) -> Result<ReportNoiser, DpError> {
+        self.budget.charge(self.epsilon_per_report)?;
+
+        let report_index = self.reports_issued;
+        self.reports_issued += 1;
+
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"QRATUM-DP-REPORT-SEED-v1");
+        hasher.update(self.seed);
+        hasher.update((report_kind.len() as u64).to_le_bytes());
+        hasher.update(report_kind.as_bytes());
+        hasher.update(report_index.to_le_bytes());
+        let report_seed: [u8; 32] = hasher.finalize().into();
+
+        let seed_commitment: [u8; 32] = Sha3_256::digest(report_seed).into();
+
+        Ok(ReportNoiser {
+            epsilon_per_field: self.epsilon_per_report / fields.max(1) as f64,
+            source: NoiseSource::new(report_seed),
+            annotation: DpAnnotation {
+                mechanism: self.mechanism,
+                epsilon: self.epsilon_per_report,
+                report_index,
+                seed_commitment,
+                noised: Vec::new(),
+            },
+        })
+    }
+}
+
+/// Noise applicator for a single report
+#[derive(Debug, Clone)]
+pub struct ReportNoiser {
+    epsilon_per_field: f64,
+    source: NoiseSource,
+    annotation: DpAnnotation,
+}
+
+impl ReportNoiser {
+    /// Add noise to a count (sensitivity 1) and record the annotation
+    pub fn noise_count(&mut self, field: &'static str, value: usize) -> usize {
+        let epsilon = self.epsilon_per_field;
+        let noise = match self.annotation.mechanism {
+            DpMechanism::Laplace => laplace(&mut self.source, 1.0 / epsilon),
+            DpMechanism::Geometric => geometric(&mut self.source, epsilon) as f64,
+        };
+        self.annotation.noised.push(NoisedFigure { field, epsilon });
+
+        // Post-processing: round and clamp to a valid count
+        let noised = value as f64 + noise;
+        if noised <= 0.0 {
+            0
+        } else {
+            (noised + 0.5) as usize
+        }
+    }
+
+    /// Finish the report and return its annotation
+    pub fn finish(self) -> DpAnnotation {
+        self.annotation
+    }
+}
+
+/// Deterministic uniform source (SHA3-256 in counter mode)
+#[derive(Debug, Clone)]
+struct NoiseSource {
+    seed: [u8; 32],
+    counter: u64,
+}
+
+impl NoiseSource {
+    fn new(seed: [u8; 32]) -> Self {
+        Self { seed, counter: 0 }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut hasher = Sha3_256::new();
+        hasher.update(self.seed);
+        hasher.update(self.counter.to_le_bytes());
+        self.counter += 1;
+        let block: [u8; 32] = hasher.finalize().into();
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&block[..8]);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Uniform in the open interval (0, 1)
+    fn next_open_unit(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+}
+
+/// Upper bound on geometric trials (only reached for epsilon near zero)
+const MAX_GEOMETRIC_TRIALS: u64 = 1 << 20;
+
+/// Laplace(0, scale) sample
+fn laplace(source: &mut NoiseSource, scale: f64) -> f64 {
+    let sign = if source.next_u64() & 1 == 0 { 1.0 } else { -1.0 };
+    sign * -scale * ln(source.next_open_unit())
+}
+
+/// Two-sided geometric sample with parameter alpha = exp(-epsilon)
+fn geometric(source: &mut NoiseSource, epsilon: f64) -> i64 {
+    let alpha = exp_neg(epsilon);
+    let threshold = (alpha * 18_446_744_073_709_551_616.0) as u64;
+    let mut draw = || {
+        let mut k = 0i64;
+        while (k as u64) < MAX_GEOMETRIC_TRIALS && source.next_u64() < threshold {
+            k += 1;
+        }
+        k
+    };
+    let positive = draw();
+    let negative = draw();
+    positive - negative
+}
+
+/// e^-x for x >= 0 (no_std; range reduction + Taylor series)
+fn exp_neg(x: f64) -> f64 {
+    let mut reduced = -x;
+    let mut squarings = 0;
+    while reduced < -0.5 {
+        reduced /= 2.0;
+        squarings += 1;
+    }
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for n in 1..24 {
+        term *= reduced / n as f64;
+        sum += term;
+    }
+    for _ in 0..squarings {
+        sum *= sum;
+    }
+    sum
+}
+
+/// Natural logarithm for x > 0 (no_std; exponent split + atanh series)
+fn ln(x: f64) -> f64 {
+    const LN_2: f64 = core::f64::consts::LN_2;
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+
+    let s = (mantissa - 1.0) / (mantissa + 1.0);
+    let s2 = s * s;
+    let mut term = s;
+    let mut sum = 0.0;
+    for n in 0..40 {
+        sum += term / (2 * n + 1) as f64;
+        term *= s2;
+    }
+    exponent as f64 * LN_2 + 2.0 * sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compliance_controls::{CmmcComplianceEngine, GdprComplianceEngine, HipaaComplianceEngine};
+
+    #[test]
+    fn test_math_helpers() {
+        assert!((exp_neg(1.0) - 0.36787944117144233).abs() < 1e-12);
+        assert!((exp_neg(5.0) - 0.006737946999085467).abs() < 1e-12);
+        assert!((ln(0.5) + core::f64::consts::LN_2).abs() < 1e-12);
+        assert!((ln(0.001) + 6.907755278982137).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_noise_is_deterministic_and_centered() {
+        for mechanism in [DpMechanism::Laplace, DpMechanism::Geometric] {
+            let mut a = DpLayer::new(mechanism, 1.0, 1000.0, [7u8; 32]).unwrap();
+            let mut b = DpLayer::new(mechanism, 1.0, 1000.0, [7u8; 32]).unwrap();
+
+            let mut total: i64 = 0;
+            for _ in 0..400 {
+                let mut na = a.begin_report("TEST", 1).unwrap();
+                let mut nb = b.begin_report("TEST", 1).unwrap();
+                let va = na.noise_count("count", 1000);
+                assert_eq!(va, nb.noise_count("count", 1000));
+                assert_eq!(na.finish(), nb.finish());
+                total += va as i64 - 1000;
+            }
+            // Scale 1 noise: mean of 400 draws stays well within +/-0.5
+            assert!(total.abs() < 200, "{:?} mean drift {}", mechanism, total);
+        }
+
+        // Different seeds give different noise
+        let mut a = DpLayer::new(DpMechanism::Laplace, 0.1, 10.0, [1u8; 32]).unwrap();
+        let mut b = DpLayer::new(DpMechanism::Laplace, 0.1, 10.0, [2u8; 32]).unwrap();
+        let va: Vec<usize> = (0..5).map(|_| a.begin_report("TEST", 1).unwrap().noise_count("c", 100)).collect();
+        let vb: Vec<usize> = (0..5).map(|_| b.begin_report("TEST", 1).unwrap().noise_count("c", 100)).collect();
+        assert_ne!(va, vb);
+    }
+
+    #[test]
+    fn test_budget_exhaustion() {
+        assert_eq!(
+            DpLayer::new(DpMechanism::Laplace, 0.0, 1.0, [0u8; 32]).unwrap_err(),
+            DpError::InvalidEpsilon
+        );
+
+        let mut layer = DpLayer::new(DpMechanism::Geometric, 0.5, 1.0, [0u8; 32]).unwrap();
+        assert!(layer.begin_report("HIPAA", 3).is_ok());
+        assert!(layer.begin_report("HIPAA", 3).is_ok());
+        assert_eq!(
+            layer.begin_report("HIPAA", 3).unwrap_err(),
+            DpError::BudgetExhausted { remaining: 0.0 }
+        );
+        assert_eq!(layer.budget().spent, 1.0);
+    }
+
+    #[test]
+    fn test_private_compliance_reports() {
+        let mut layer = DpLayer::new(DpMechanism::Geometric, 1.0, 3.0, [9u8; 32]).unwrap();
+
+        let hipaa = HipaaComplianceEngine::new()
+            .generate_private_compliance_report(&mut layer)
+            .unwrap();
+        let annotation = hipaa.dp_annotation.as_ref().unwrap();
+        assert!(annotation.is_noised("denied_access_events"));
+        assert!(!annotation.is_noised("audit_retention_days"));
+        assert_eq!(annotation.report_index, 0);
+
+        let gdpr = GdprComplianceEngine::new("Controller".into())
+            .generate_private_compliance_report(&mut layer)
+            .unwrap();
+        assert!(gdpr.dp_annotation.unwrap().is_noised("special_category_records"));
+
+        let cmmc = CmmcComplianceEngine::new()
+            .generate_private_compliance_report(&mut layer)
+            .unwrap();
+        let annotation = cmmc.dp_annotation.unwrap();
+        assert!(annotation.is_noised("locked_users"));
+        assert!(!annotation.is_noised("total_enclaves"));
+
+        // Plain reports stay exact and unannotated
+        assert!(HipaaComplianceEngine::new().generate_compliance_report().dp_annotation.is_none());
+
+        // Budget of three reports is spent
+        assert!(HipaaComplianceEngine::new().generate_private_compliance_report(&mut layer).is_err());
+    }
+}
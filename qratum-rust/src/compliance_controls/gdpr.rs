@@ -23,6 +23,9 @@ use alloc::collections::BTreeMap;
 use sha3::{Sha3_256, Sha3_512, Digest};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+use super::dp::{DpAnnotation, DpError, DpLayer};
+use crate::clock;
+
 /// Lawful basis for processing per Article 6
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LawfulBasis {
@@ -121,7 +124,7 @@ impl PersonalDataRecord {
         // Generate encryption key ID
         let mut hasher = Sha3_256::new();
         hasher.update(&record_id);
-        hasher.update(&current_timestamp().to_le_bytes());
+        hasher.update(clock::now_ms().to_le_bytes());
         let encryption_key_id: [u8; 32] = hasher.finalize().into();
         
         Self {
@@ -132,7 +135,7 @@ impl PersonalDataRecord {
             purposes,
             consent_ref: None,
             encryption_key_id,
-            created_at: current_timestamp(),
+            created_at: clock::now_ms(),
             retention_period: 0,
             processing_restricted: false,
             is_tombstoned: false,
@@ -156,7 +159,7 @@ impl PersonalDataRecord {
         if self.retention_period == 0 {
             return false; // No retention limit
         }
-        let current = current_timestamp();
+        let current = clock::now_ms();
         current > self.created_at + (self.retention_period * 1000)
     }
 }
@@ -207,7 +210,7 @@ impl CryptographicTombstone {
         erasure_request_ref: [u8; 32],
         erasure_reason: ErasureReason,
     ) -> Self {
-        let timestamp = current_timestamp();
+        let timestamp = clock::now_ms();
         
         // Hash the record for proof of existence
         let mut record_hasher = Sha3_256::new();
@@ -310,7 +313,7 @@ impl ConsentRecord {
         purposes: Vec<String>,
         controller: String,
     ) -> Self {
-        let timestamp = current_timestamp();
+        let timestamp = clock::now_ms();
         
         let mut hasher = Sha3_256::new();
         hasher.update(&data_subject_id);
@@ -331,7 +334,7 @@ impl ConsentRecord {
     
     /// Withdraw consent
     pub fn withdraw(&mut self) {
-        self.withdrawn_at = Some(current_timestamp());
+        self.withdrawn_at = Some(clock::now_ms());
         self.is_active = false;
     }
 }
@@ -367,7 +370,7 @@ pub struct DataSubjectAccessRequest {
 impl DataSubjectAccessRequest {
     /// Create new DSAR
     pub fn new(data_subject_id: [u8; 32], right: DataSubjectRight) -> Self {
-        let timestamp = current_timestamp();
+        let timestamp = clock::now_ms();
         
         let mut hasher = Sha3_256::new();
         hasher.update(&data_subject_id);
@@ -393,7 +396,7 @@ impl DataSubjectAccessRequest {
     /// Mark as fulfilled
     pub fn fulfill(&mut self) {
         self.is_fulfilled = true;
-        self.fulfilled_at = Some(current_timestamp());
+        self.fulfilled_at = Some(clock::now_ms());
     }
     
     /// Apply extension (complex requests)
@@ -405,7 +408,7 @@ impl DataSubjectAccessRequest {
     
     /// Check if deadline is passed
     pub fn is_overdue(&self) -> bool {
-        !self.is_fulfilled && current_timestamp() > self.response_deadline
+        !self.is_fulfilled && clock::now_ms() > self.response_deadline
     }
 }
 
@@ -460,7 +463,7 @@ impl EncryptionKey {
         
         Ok(Self {
             key_material,
-            created_at: current_timestamp(),
+            created_at: clock::now_ms(),
         })
     }
 }
@@ -621,7 +624,7 @@ impl GdprComplianceEngine {
             .count();
         
         GdprComplianceReport {
-            report_timestamp: current_timestamp(),
+            report_timestamp: clock::now_ms(),
             controller_id: self.controller_id.clone(),
             total_records,
             tombstoned_records,
@@ -630,8 +633,28 @@ impl GdprComplianceEngine {
             overdue_dsars,
             special_category_records,
             tombstones_issued: self.tombstones.len(),
+            dp_annotation: None,
         }
     }
+    
+    /// Generate GDPR compliance report with differentially private counts
+    ///
+    /// All aggregate counts are noised (Article 89 statistical safeguards).
+    pub fn generate_private_compliance_report(&self, dp: &mut DpLayer) -> Result<GdprComplianceReport, DpError> {
+        let mut report = self.generate_compliance_report();
+        let mut noiser = dp.begin_report("GDPR", 7)?;
+        
+        report.total_records = noiser.noise_count("total_records", report.total_records);
+        report.tombstoned_records = noiser.noise_count("tombstoned_records", report.tombstoned_records);
+        report.active_consents = noiser.noise_count("active_consents", report.active_consents);
+        report.total_dsars = noiser.noise_count("total_dsars", report.total_dsars);
+        report.overdue_dsars = noiser.noise_count("overdue_dsars", report.overdue_dsars);
+        report.special_category_records = noiser.noise_count("special_category_records", report.special_category_records);
+        report.tombstones_issued = noiser.noise_count("tombstones_issued", report.tombstones_issued);
+        
+        report.dp_annotation = Some(noiser.finish());
+        Ok(report)
+    }
 }
 
 /// GDPR Compliance Report
@@ -646,22 +669,8 @@ pub struct GdprComplianceReport {
     pub overdue_dsars: usize,
     pub special_category_records: usize,
     pub tombstones_issued: usize,
-}
-
-/// Get current timestamp
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0
-    }
+    /// Present when counts were noised by the DP layer
+    pub dp_annotation: Option<DpAnnotation>,
 }
 
 #[cfg(test)]
@@ -20,6 +20,9 @@ use alloc::collections::BTreeMap;
 use sha3::{Sha3_256, Digest};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+use super::dp::{DpAnnotation, DpError, DpLayer};
+use crate::clock;
+
 /// PHI Data Categories per HIPAA 164.501
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PhiCategory {
@@ -112,7 +115,7 @@ impl PhiTag {
             categories,
             sensitivity,
             originating_entity,
-            tagged_at: current_timestamp(),
+            tagged_at: clock::now_ms(),
             minimum_necessary: false,
             retention_period: 0,
         }
@@ -245,7 +248,7 @@ impl AccessAuditRecord {
         purpose: AccessPurpose,
         action: AccessAction,
     ) -> Self {
-        let timestamp = current_timestamp();
+        let timestamp = clock::now_ms();
         
         // Generate audit ID
         let mut hasher = Sha3_256::new();
@@ -535,15 +538,33 @@ impl HipaaComplianceEngine {
             .count();
         
         HipaaComplianceReport {
-            report_timestamp: current_timestamp(),
+            report_timestamp: clock::now_ms(),
             total_phi_elements,
             high_sensitivity_phi,
             total_access_events,
             denied_access_events,
             reportable_breaches,
             audit_retention_days: (self.audit_retention_seconds / 86400) as u32,
+            dp_annotation: None,
         }
     }
+    
+    /// Generate HIPAA compliance report with differentially private counts
+    ///
+    /// PHI, access, and breach counts are noised; retention configuration is exact.
+    pub fn generate_private_compliance_report(&self, dp: &mut DpLayer) -> Result<HipaaComplianceReport, DpError> {
+        let mut report = self.generate_compliance_report();
+        let mut noiser = dp.begin_report("HIPAA", 5)?;
+        
+        report.total_phi_elements = noiser.noise_count("total_phi_elements", report.total_phi_elements);
+        report.high_sensitivity_phi = noiser.noise_count("high_sensitivity_phi", report.high_sensitivity_phi);
+        report.total_access_events = noiser.noise_count("total_access_events", report.total_access_events);
+        report.denied_access_events = noiser.noise_count("denied_access_events", report.denied_access_events);
+        report.reportable_breaches = noiser.noise_count("reportable_breaches", report.reportable_breaches);
+        
+        report.dp_annotation = Some(noiser.finish());
+        Ok(report)
+    }
 }
 
 impl Default for HipaaComplianceEngine {
@@ -562,22 +583,8 @@ pub struct HipaaComplianceReport {
     pub denied_access_events: usize,
     pub reportable_breaches: usize,
     pub audit_retention_days: u32,
-}
-
-/// Get current timestamp
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0
-    }
+    /// Present when counts were noised by the DP layer
+    pub dp_annotation: Option<DpAnnotation>,
 }
 
 #[cfg(test)]
@@ -653,7 +660,7 @@ mod tests {
         
         let assessment = BreachAssessment {
             assessment_id: [1u8; 32],
-            timestamp: current_timestamp(),
+            timestamp: clock::now_ms(),
             incident_description: "Laptop stolen".into(),
             phi_involved: vec![[1u8; 32]],
             individuals_affected: 1000,
@@ -11,7 +11,7 @@
 //! 1. Data tagging and classification
 //! 2. Access control enforcement
 //! 3. Audit trail generation
-//! 4. Compliance reporting
+//! 4. Compliance reporting (optionally differentially private, see [`dp`])
 //!
 //! ## Usage
 //!
@@ -31,6 +31,7 @@
 pub mod hipaa;
 pub mod gdpr;
 pub mod cmmc;
+pub mod dp;
 
 pub use hipaa::{
     HipaaComplianceEngine,
@@ -77,6 +78,15 @@ pub use cmmc::{
     CmmcComplianceReport,
 };
 
+pub use dp::{
+    DpLayer,
+    DpMechanism,
+    DpError,
+    DpAnnotation,
+    NoisedFigure,
+    PrivacyBudget,
+};
+
 /// Unified compliance status across all frameworks
 #[derive(Debug, Clone)]
 pub struct UnifiedComplianceStatus {
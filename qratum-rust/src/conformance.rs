@@ -0,0 +1,381 @@
+//! # Conformance Module - Typed Lifecycle State Machine
+//!
+//! ## Lifecycle Stage: All 5 Stages
+//!
+//! The 5-stage lifecycle was previously implied by the call order in
+//! `run_qratum_session_with_config`. This module states it explicitly: the
+//! lifecycle states, the transitions allowed between them, and the
+//! artifacts each transition requires. `LifecycleConformance` checks a
+//! running session against that model and rejects out-of-order stage calls.
+//!
+//! ## Architectural Role
+//!
+//! - **Model**: `TRANSITIONS` lists every allowed transition and its
+//!   required artifacts; anything not listed is illegal
+//! - **Runtime Checker**: `LifecycleConformance` is driven by the lifecycle
+//!   orchestrator (`record` artifacts, then `advance`)
+//! - **Model Checker**: the tests explore every transition sequence to show
+//!   that no path out of a materialized session skips zeroization
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Artifacts produced by the current stage, requested transitions
+//! - Output: `Ok(())` or a `ConformanceError` naming the violation
+//!
+//! ## Security Rationale
+//!
+//! - Once stage 2 has materialized key material, the only terminal state
+//!   is `Destroyed`, reachable only from stage 5 with `Zeroization` recorded
+//! - A stage cannot start without the artifact the previous stage owes it
+//!   (e.g. execution without a materialized session)
+//! - Failures after materialization must record an `AbortReason` and still
+//!   pass through stage 5
+//! - Terminal states accept no further transitions
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::deadman::SessionStage;
+use LifecycleState::{Active, Destroyed, Pending, Rejected};
+use SessionStage::{EphemeralMaterialization, Execution, OutcomeCommitment, QuorumConvergence, SelfDestruction};
+
+/// Lifecycle state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleState {
+    /// Session object exists, no stage entered
+    Pending,
+    /// Inside one of the five stages
+    Active(SessionStage),
+    /// Quorum failed before anything was materialized (terminal)
+    Rejected,
+    /// Session state zeroized (terminal)
+    Destroyed,
+}
+
+impl LifecycleState {
+    /// Whether no further transitions are allowed
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, LifecycleState::Rejected | LifecycleState::Destroyed)
+    }
+}
+
+/// Evidence that a stage did its work
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Artifact {
+    /// Quorum convergence reached consensus (stage 1)
+    QuorumConsensus,
+    /// Biokey and ledger materialized in RAM (stage 2)
+    SessionState,
+    /// Execution hash over the session ledger (stage 3)
+    ExecutionHash,
+    /// Outcome TXOs committed (stage 4)
+    OutcomeTxos,
+    /// The stage failed; the session must be torn down (stages 1-4)
+    AbortReason,
+    /// All session state zeroized (stage 5)
+    Zeroization,
+}
+
+impl Artifact {
+    /// Whether `state` may produce this artifact
+    pub fn producible_in(&self, state: LifecycleState) -> bool {
+        let LifecycleState::Active(stage) = state else {
+            return false;
+        };
+        match self {
+            Artifact::QuorumConsensus => stage == SessionStage::QuorumConvergence,
+            Artifact::SessionState => stage == SessionStage::EphemeralMaterialization,
+            Artifact::ExecutionHash => stage == SessionStage::Execution,
+            Artifact::OutcomeTxos => stage == SessionStage::OutcomeCommitment,
+            Artifact::AbortReason => stage != SessionStage::SelfDestruction,
+            Artifact::Zeroization => stage == SessionStage::SelfDestruction,
+        }
+    }
+}
+
+/// One allowed transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransitionRule {
+    /// Source state
+    pub from: LifecycleState,
+    /// Target state
+    pub to: LifecycleState,
+    /// Artifacts that must have been recorded in `from`
+    pub requires: &'static [Artifact],
+}
+
+const fn rule(from: LifecycleState, to: LifecycleState, requires: &'static [Artifact]) -> TransitionRule {
+    TransitionRule { from, to, requires }
+}
+
+/// Every allowed lifecycle transition
+///
+/// A (from, to) pair may appear more than once; the transition is allowed
+/// if any of its rules is satisfied.
+pub const TRANSITIONS: &[TransitionRule] = &[
+    // Forward path
+    rule(Pending, Active(QuorumConvergence), &[]),
+    rule(Active(QuorumConvergence), Active(EphemeralMaterialization), &[Artifact::QuorumConsensus]),
+    rule(Active(EphemeralMaterialization), Active(Execution), &[Artifact::SessionState]),
+    rule(Active(Execution), Active(OutcomeCommitment), &[Artifact::ExecutionHash]),
+    rule(Active(OutcomeCommitment), Active(SelfDestruction), &[Artifact::OutcomeTxos]),
+    rule(Active(SelfDestruction), Destroyed, &[Artifact::Zeroization]),
+    // Nothing materialized yet: no state to destroy
+    rule(Active(QuorumConvergence), Rejected, &[Artifact::AbortReason]),
+    // Aborts after materialization still go through stage 5
+    rule(Active(EphemeralMaterialization), Active(SelfDestruction), &[Artifact::AbortReason]),
+    rule(Active(Execution), Active(SelfDestruction), &[Artifact::AbortReason]),
+    rule(Active(OutcomeCommitment), Active(SelfDestruction), &[Artifact::AbortReason]),
+];
+
+/// Lifecycle conformance violation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConformanceError {
+    /// No rule allows this transition
+    IllegalTransition {
+        /// Current state
+        from: LifecycleState,
+        /// Requested state
+        to: LifecycleState,
+    },
+    /// The transition is allowed but an artifact it requires is missing
+    MissingArtifact {
+        /// Requested state
+        to: LifecycleState,
+        /// First missing artifact
+        artifact: Artifact,
+    },
+    /// The artifact cannot be produced in the current state
+    UnexpectedArtifact {
+        /// Current state
+        state: LifecycleState,
+        /// Offered artifact
+        artifact: Artifact,
+    },
+}
+
+/// Runtime lifecycle conformance checker
+///
+/// ## Lifecycle Stage: All 5 Stages
+///
+/// Driven by the orchestrator: `record` each artifact as its stage produces
+/// it, then `advance` to the next state. Artifacts are cleared on every
+/// transition, so each transition is judged only on what its source state
+/// produced.
+#[derive(Debug, Clone)]
+pub struct LifecycleConformance {
+    state: LifecycleState,
+    artifacts: Vec<Artifact>,
+    history: Vec<LifecycleState>,
+}
+
+impl LifecycleConformance {
+    /// New checker in `Pending`
+    pub fn new() -> Self {
+        Self {
+            state: Pending,
+            artifacts: Vec::new(),
+            history: vec![Pending],
+        }
+    }
+
+    /// Current state
+    pub fn state(&self) -> LifecycleState {
+        self.state
+    }
+
+    /// Every state entered so far, starting with `Pending`
+    pub fn history(&self) -> &[LifecycleState] {
+        &self.history
+    }
+
+    /// Whether `artifact` has been recorded in the current state
+    pub fn has(&self, artifact: Artifact) -> bool {
+        self.artifacts.contains(&artifact)
+    }
+
+    /// Record an artifact produced by the current state
+    ///
+    /// # Outputs
+    /// - `Err(UnexpectedArtifact)` if the current state cannot produce it
+    pub fn record(&mut self, artifact: Artifact) -> Result<(), ConformanceError> {
+        if !artifact.producible_in(self.state) {
+            return Err(ConformanceError::UnexpectedArtifact { state: self.state, artifact });
+        }
+        if !self.has(artifact) {
+            self.artifacts.push(artifact);
+        }
+        Ok(())
+    }
+
+    /// Move to `to` if a rule allows it and its artifacts are recorded
+    ///
+    /// # Outputs
+    /// - `Err(IllegalTransition)` if no rule covers (current, `to`)
+    /// - `Err(MissingArtifact)` if rules exist but none is satisfied
+    pub fn advance(&mut self, to: LifecycleState) -> Result<(), ConformanceError> {
+        let mut missing = None;
+        for candidate in TRANSITIONS.iter().filter(|r| r.from == self.state && r.to == to) {
+            match candidate.requires.iter().find(|a| !self.has(**a)) {
+                None => {
+                    self.state = to;
+                    self.artifacts.clear();
+                    self.history.push(to);
+                    return Ok(());
+                }
+                Some(artifact) => {
+                    missing.get_or_insert(ConformanceError::MissingArtifact { to, artifact: *artifact });
+                }
+            }
+        }
+        Err(missing.unwrap_or(ConformanceError::IllegalTransition { from: self.state, to }))
+    }
+
+    /// Record `artifact`, then advance to `to`
+    pub fn complete(&mut self, artifact: Artifact, to: LifecycleState) -> Result<(), ConformanceError> {
+        self.record(artifact)?;
+        self.advance(to)
+    }
+}
+
+impl Default for LifecycleConformance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeSet;
+
+    const ARTIFACTS: [Artifact; 6] = [
+        Artifact::QuorumConsensus,
+        Artifact::SessionState,
+        Artifact::ExecutionHash,
+        Artifact::OutcomeTxos,
+        Artifact::AbortReason,
+        Artifact::Zeroization,
+    ];
+
+    const STATES: [LifecycleState; 8] = [
+        Pending,
+        Active(QuorumConvergence),
+        Active(EphemeralMaterialization),
+        Active(Execution),
+        Active(OutcomeCommitment),
+        Active(SelfDestruction),
+        Rejected,
+        Destroyed,
+    ];
+
+    #[test]
+    fn test_happy_path_and_out_of_order_calls() {
+        let mut lifecycle = LifecycleConformance::new();
+        lifecycle.advance(Active(QuorumConvergence)).unwrap();
+
+        // Execution before materialization
+        assert_eq!(
+            lifecycle.advance(Active(Execution)),
+            Err(ConformanceError::IllegalTransition { from: Active(QuorumConvergence), to: Active(Execution) })
+        );
+        // Materialization before consensus
+        assert_eq!(
+            lifecycle.advance(Active(EphemeralMaterialization)),
+            Err(ConformanceError::MissingArtifact { to: Active(EphemeralMaterialization), artifact: Artifact::QuorumConsensus })
+        );
+        // Artifact from the wrong stage
+        assert_eq!(
+            lifecycle.record(Artifact::ExecutionHash),
+            Err(ConformanceError::UnexpectedArtifact { state: Active(QuorumConvergence), artifact: Artifact::ExecutionHash })
+        );
+
+        lifecycle.complete(Artifact::QuorumConsensus, Active(EphemeralMaterialization)).unwrap();
+        lifecycle.complete(Artifact::SessionState, Active(Execution)).unwrap();
+        // Artifacts do not carry over: consensus from stage 1 cannot satisfy stage 3
+        assert!(!lifecycle.has(Artifact::QuorumConsensus));
+        lifecycle.complete(Artifact::ExecutionHash, Active(OutcomeCommitment)).unwrap();
+        lifecycle.complete(Artifact::OutcomeTxos, Active(SelfDestruction)).unwrap();
+        assert_eq!(
+            lifecycle.advance(Destroyed),
+            Err(ConformanceError::MissingArtifact { to: Destroyed, artifact: Artifact::Zeroization })
+        );
+        lifecycle.complete(Artifact::Zeroization, Destroyed).unwrap();
+        assert!(lifecycle.state().is_terminal());
+        assert_eq!(lifecycle.history().len(), 7);
+        assert!(matches!(lifecycle.advance(Active(QuorumConvergence)), Err(ConformanceError::IllegalTransition { .. })));
+    }
+
+    /// Abstract configuration explored by the model checker
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+    struct Node {
+        state: u8,
+        artifacts: u8,
+        materialized: bool,
+        zeroized: bool,
+    }
+
+    fn state_index(state: LifecycleState) -> u8 {
+        STATES.iter().position(|s| *s == state).unwrap() as u8
+    }
+
+    fn node(checker: &LifecycleConformance, materialized: bool, zeroized: bool) -> Node {
+        let artifacts = ARTIFACTS.iter().enumerate()
+            .filter(|(_, a)| checker.has(**a))
+            .fold(0u8, |mask, (i, _)| mask | (1 << i));
+        Node { state: state_index(checker.state()), artifacts, materialized, zeroized }
+    }
+
+    /// Exhaustive exploration of every record/advance sequence
+    ///
+    /// Each step either records any artifact or requests any state; the
+    /// checker is the transition oracle. Sequences are folded into abstract
+    /// nodes (state, artifacts held, whether key material was ever
+    /// materialized, whether zeroization has happened since), so the search
+    /// terminates and covers every path.
+    #[test]
+    fn test_model_check_no_path_skips_zeroization() {
+        let mut seen = BTreeSet::new();
+        let mut frontier = vec![(LifecycleConformance::new(), false, false)];
+        let mut terminals = BTreeSet::new();
+
+        while let Some((checker, materialized, zeroized)) = frontier.pop() {
+            if !seen.insert(node(&checker, materialized, zeroized)) {
+                continue;
+            }
+            let state = checker.state();
+            if state.is_terminal() {
+                terminals.insert(state_index(state));
+                // Invariant: materialized sessions only end destroyed, and only after zeroization
+                if materialized {
+                    assert_eq!(state, Destroyed, "materialized session ended in {:?}", state);
+                    assert!(zeroized, "reached {:?} without zeroization: {:?}", state, checker.history());
+                }
+                if state == Destroyed {
+                    assert!(zeroized);
+                    assert!(checker.history().contains(&Active(SelfDestruction)));
+                }
+            }
+
+            for artifact in ARTIFACTS {
+                let mut next = checker.clone();
+                if next.record(artifact).is_ok() {
+                    frontier.push((next, materialized, zeroized || artifact == Artifact::Zeroization));
+                }
+            }
+            for to in STATES {
+                let mut next = checker.clone();
+                if next.advance(to).is_ok() {
+                    frontier.push((next, materialized || to == Active(EphemeralMaterialization), zeroized));
+                }
+            }
+        }
+
+        // Both terminal states are reachable, and the search saw every active stage
+        assert!(terminals.contains(&state_index(Rejected)));
+        assert!(terminals.contains(&state_index(Destroyed)));
+        for state in &STATES[..6] {
+            assert!(seen.iter().any(|n| n.state == state_index(*state)));
+        }
+    }
+}
@@ -0,0 +1,916 @@
+//! # Cosign Module - Distributed Validator Signing Ceremony
+//!
+//! ## Lifecycle Stage: Finalization
+//!
+//! Aggregated validator signatures (finality headers, upgrade approvals)
+//! are produced by a coordinated ceremony rather than true threshold
+//! Dilithium: every cosigner signs the message with its own Dilithium key,
+//! and a combiner accepts the result once M-of-N partial signatures verify.
+//!
+//! ## Architectural Role
+//!
+//! - **Rounds**: Propose → Commit → Reveal → Complete
+//! - **Coordinator**: `CosignCeremony` drives the rounds and combines partials
+//! - **Cosigners**: `CosignParticipant` answers one coordinator
+//! - **Transport**: sans-io; every step returns `Outbound` messages addressed
+//!   to a `PeerID`, CBOR-encoded for the P2P sessions, and inbound messages
+//!   are handed back in with the peer they arrived from
+//! - **Abort Handling**: round timeouts, declines, and blamed cosigners abort
+//!   the ceremony as soon as the threshold can no longer be met
+//! - **Transcript**: hash-chained record of every accepted message, emitted
+//!   as a `CosignCeremony` audit TXO
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Message + cosigner set + threshold → `Propose` to every cosigner
+//! - Input: Commitments and reveals from cosigners → `AggregateSignature`
+//!   or `Abort`, plus the transcript
+//!
+//! ## Security Rationale
+//!
+//! - Commit-reveal binds each partial before any is disclosed; a reveal that
+//!   differs from its commitment is recorded as equivocation
+//! - Messages are accepted only from the peer registered for the validator
+//! - Every partial is verified before it is combined; faulty cosigners are
+//!   blamed by validator ID in the transcript
+//! - Partial signatures cover the message itself, so the aggregate verifies
+//!   with the validators' ordinary Dilithium keys
+//!
+//! ## Forward Compatibility
+//!
+//! TODO: QRADLE post-quantum migration - replace the combiner with a true
+//! threshold ML-DSA scheme once one is standardized.
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256};
+
+use crate::consensus::ValidatorID;
+use crate::p2p::PeerID;
+use crate::txo::{Txo, TxoType};
+
+const CEREMONY_DOMAIN: &[u8] = b"QRATUM-COSIGN-CEREMONY-v1";
+const COMMIT_DOMAIN: &[u8] = b"QRATUM-COSIGN-COMMIT-v1";
+const TRANSCRIPT_DOMAIN: &[u8] = b"QRATUM-COSIGN-TRANSCRIPT-v1";
+
+/// Cosigning Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CosignError {
+    /// Threshold is zero, exceeds the cosigner count, or cosigners repeat
+    InvalidThreshold,
+    /// Message names a different ceremony
+    UnknownCeremony,
+    /// Message names a validator outside the cosigner set
+    UnknownCosigner,
+    /// Message arrived from a peer not registered for the validator
+    UnauthenticatedPeer,
+    /// Message does not belong to the current round
+    UnexpectedMessage,
+    /// Ceremony already completed or aborted
+    CeremonyClosed,
+    /// Fewer valid partial signatures than the threshold
+    InsufficientSignatures,
+    /// Bytes are not a valid CBOR message or transcript
+    Malformed,
+}
+
+/// Signing backend holding a cosigner's Dilithium secret key
+pub trait PartialSigner {
+    /// Sign the ceremony message
+    fn sign(&self, message: &[u8]) -> Vec<u8>;
+}
+
+/// Verification backend for cosigner Dilithium keys
+pub trait PartialVerifier {
+    /// Verify a partial signature against a cosigner public key
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> bool;
+}
+
+/// Validator taking part in a ceremony
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct Cosigner {
+    /// Validator ID
+    #[n(0)]
+    pub validator_id: ValidatorID,
+
+    /// P2P peer the validator's messages must arrive from
+    #[n(1)]
+    pub peer_id: PeerID,
+
+    /// Dilithium public key
+    #[n(2)]
+    pub public_key: Vec<u8>,
+}
+
+/// Why a ceremony aborted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cbor(index_only)]
+pub enum AbortReason {
+    /// Round deadline passed with fewer than threshold responses
+    #[n(0)] Timeout,
+    /// Declines and blamed cosigners leave fewer than threshold signers
+    #[n(1)] InsufficientSigners,
+    /// Coordinator cancelled the ceremony
+    #[n(2)] Cancelled,
+}
+
+/// Cosigner misbehaviour recorded against a validator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cbor(index_only)]
+pub enum Misbehavior {
+    /// Second, different commitment, or reveal not matching the commitment
+    #[n(0)] Equivocation,
+    /// Revealed signature does not verify
+    #[n(1)] InvalidSignature,
+}
+
+/// Ceremony wire message
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum CeremonyMessage {
+    /// Coordinator → cosigners: sign `message`
+    #[n(0)]
+    Propose {
+        /// Ceremony ID
+        #[n(0)] ceremony_id: [u8; 32],
+        /// Message to sign
+        #[n(1)] message: Vec<u8>,
+        /// Partial signatures required
+        #[n(2)] threshold: u32,
+        /// Validators asked to sign
+        #[n(3)] cosigners: Vec<ValidatorID>,
+    },
+    /// Cosigner → coordinator: commitment to its partial signature
+    #[n(1)]
+    Commit {
+        /// Ceremony ID
+        #[n(0)] ceremony_id: [u8; 32],
+        /// Committing validator
+        #[n(1)] validator_id: ValidatorID,
+        /// SHA3-256 commitment to the partial signature
+        #[n(2)] commitment: [u8; 32],
+    },
+    /// Cosigner → coordinator: refuses to sign
+    #[n(2)]
+    Decline {
+        /// Ceremony ID
+        #[n(0)] ceremony_id: [u8; 32],
+        /// Declining validator
+        #[n(1)] validator_id: ValidatorID,
+    },
+    /// Coordinator → committed cosigners: reveal partial signatures
+    #[n(3)]
+    RevealRequest {
+        /// Ceremony ID
+        #[n(0)] ceremony_id: [u8; 32],
+        /// Validators whose commitments were accepted
+        #[n(1)] signers: Vec<ValidatorID>,
+    },
+    /// Cosigner → coordinator: partial signature
+    #[n(4)]
+    Reveal {
+        /// Ceremony ID
+        #[n(0)] ceremony_id: [u8; 32],
+        /// Revealing validator
+        #[n(1)] validator_id: ValidatorID,
+        /// Dilithium signature over the message
+        #[n(2)] signature: Vec<u8>,
+    },
+    /// Coordinator → cosigners: aggregate formed
+    #[n(5)]
+    Complete {
+        /// Ceremony ID
+        #[n(0)] ceremony_id: [u8; 32],
+        /// Validators whose partials were combined
+        #[n(1)] signers: Vec<ValidatorID>,
+    },
+    /// Coordinator → cosigners: ceremony abandoned
+    #[n(6)]
+    Abort {
+        /// Ceremony ID
+        #[n(0)] ceremony_id: [u8; 32],
+        /// Abort reason
+        #[n(1)] reason: AbortReason,
+    },
+}
+
+impl CeremonyMessage {
+    /// Ceremony the message belongs to
+    pub fn ceremony_id(&self) -> [u8; 32] {
+        match self {
+            CeremonyMessage::Propose { ceremony_id, .. }
+            | CeremonyMessage::Commit { ceremony_id, .. }
+            | CeremonyMessage::Decline { ceremony_id, .. }
+            | CeremonyMessage::RevealRequest { ceremony_id, .. }
+            | CeremonyMessage::Reveal { ceremony_id, .. }
+            | CeremonyMessage::Complete { ceremony_id, .. }
+            | CeremonyMessage::Abort { ceremony_id, .. } => *ceremony_id,
+        }
+    }
+
+    /// CBOR wire encoding
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).unwrap_or_default()
+    }
+
+    /// Decode a CBOR wire message
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, CosignError> {
+        minicbor::decode(bytes).map_err(|_| CosignError::Malformed)
+    }
+}
+
+/// Message addressed to a peer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outbound {
+    /// Destination peer
+    pub peer: PeerID,
+    /// Message to send
+    pub message: CeremonyMessage,
+}
+
+/// One cosigner's verified signature
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct PartialSignature {
+    /// Signing validator
+    #[n(0)]
+    pub validator_id: ValidatorID,
+
+    /// Dilithium signature over the message
+    #[n(1)]
+    pub signature: Vec<u8>,
+}
+
+/// Combined M-of-N validator signature
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct AggregateSignature {
+    /// Ceremony that produced the aggregate
+    #[n(0)]
+    pub ceremony_id: [u8; 32],
+
+    /// Partial signatures, ordered by validator ID
+    #[n(1)]
+    pub partials: Vec<PartialSignature>,
+}
+
+impl AggregateSignature {
+    /// Verify that at least `threshold` distinct cosigners signed `message`
+    ///
+    /// # Inputs
+    /// - `message`: Signed message
+    /// - `cosigners`: Validator keys
+    /// - `threshold`: Distinct valid signatures required
+    /// - `verifier`: Dilithium verification backend
+    ///
+    /// # Outputs
+    /// - Number of distinct valid signers, or `InsufficientSignatures`
+    pub fn verify(
+        &self,
+        message: &[u8],
+        cosigners: &[Cosigner],
+        threshold: usize,
+        verifier: &dyn PartialVerifier,
+    ) -> Result<usize, CosignError> {
+        let valid = cosigners
+            .iter()
+            .filter(|c| {
+                self.partials
+                    .iter()
+                    .filter(|p| p.validator_id == c.validator_id)
+                    .any(|p| verifier.verify(&c.public_key, message, &p.signature))
+            })
+            .count();
+        if threshold == 0 || valid < threshold {
+            return Err(CosignError::InsufficientSignatures);
+        }
+        Ok(valid)
+    }
+}
+
+/// Transcript entry
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TranscriptEntry {
+    /// Position in the transcript
+    #[n(0)]
+    pub seq: u64,
+
+    /// Time the message was sent or accepted (milliseconds)
+    #[n(1)]
+    pub timestamp: u64,
+
+    /// Sending peer; `None` for coordinator broadcasts
+    #[n(2)]
+    pub from: Option<PeerID>,
+
+    /// Message
+    #[n(3)]
+    pub message: CeremonyMessage,
+
+    /// Misbehaviour the message demonstrated, if any
+    #[n(4)]
+    pub blame: Option<Misbehavior>,
+}
+
+/// Hash-chained ceremony transcript
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct CeremonyTranscript {
+    /// Entries in order
+    #[n(0)]
+    pub entries: Vec<TranscriptEntry>,
+
+    /// Chain head over all entries
+    #[n(1)]
+    pub head: [u8; 32],
+}
+
+impl CeremonyTranscript {
+    fn new() -> Self {
+        Self { entries: Vec::new(), head: [0u8; 32] }
+    }
+
+    fn chain(head: &[u8; 32], entry: &TranscriptEntry) -> [u8; 32] {
+        let encoded = minicbor::to_vec(entry).unwrap_or_default();
+        let mut hasher = Sha3_256::new();
+        hasher.update(TRANSCRIPT_DOMAIN);
+        hasher.update(head);
+        hasher.update(&encoded);
+        hasher.finalize().into()
+    }
+
+    fn record(&mut self, timestamp: u64, from: Option<PeerID>, message: CeremonyMessage, blame: Option<Misbehavior>) {
+        let entry = TranscriptEntry { seq: self.entries.len() as u64, timestamp, from, message, blame };
+        self.head = Self::chain(&self.head, &entry);
+        self.entries.push(entry);
+    }
+
+    /// Recompute the chain and compare it with the recorded head
+    pub fn verify(&self) -> bool {
+        let mut head = [0u8; 32];
+        for (i, entry) in self.entries.iter().enumerate() {
+            if entry.seq != i as u64 {
+                return false;
+            }
+            head = Self::chain(&head, entry);
+        }
+        head == self.head
+    }
+
+    /// CBOR encoding (audit TXO payload)
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).unwrap_or_default()
+    }
+
+    /// Decode a CBOR transcript
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, CosignError> {
+        minicbor::decode(bytes).map_err(|_| CosignError::Malformed)
+    }
+}
+
+/// Ceremony progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CeremonyPhase {
+    /// Collecting commitments
+    Committing,
+    /// Collecting reveals from committed cosigners
+    Revealing,
+    /// Aggregate formed
+    Complete,
+    /// Ceremony abandoned
+    Aborted(AbortReason),
+}
+
+/// Commitment to a partial signature
+pub fn partial_commitment(ceremony_id: &[u8; 32], validator_id: &ValidatorID, signature: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(COMMIT_DOMAIN);
+    hasher.update(ceremony_id);
+    hasher.update(validator_id);
+    hasher.update(signature);
+    hasher.finalize().into()
+}
+
+/// Coordinator side of a signing ceremony
+pub struct CosignCeremony {
+    ceremony_id: [u8; 32],
+    message: Vec<u8>,
+    threshold: usize,
+    cosigners: Vec<Cosigner>,
+    round_timeout_ms: u64,
+    deadline: u64,
+    phase: CeremonyPhase,
+    commitments: BTreeMap<ValidatorID, [u8; 32]>,
+    declined: Vec<ValidatorID>,
+    requested: Vec<ValidatorID>,
+    partials: BTreeMap<ValidatorID, Vec<u8>>,
+    blamed: BTreeMap<ValidatorID, Misbehavior>,
+    aggregate: Option<AggregateSignature>,
+    transcript: CeremonyTranscript,
+}
+
+impl CosignCeremony {
+    /// Start a ceremony and propose the message to every cosigner
+    ///
+    /// # Inputs
+    /// - `message`: Message to sign (e.g. a finality header hash)
+    /// - `cosigners`: Validators asked to sign
+    /// - `threshold`: Partial signatures required (M)
+    /// - `now`: Current time (milliseconds)
+    /// - `round_timeout_ms`: Time allowed for each round
+    ///
+    /// # Outputs
+    /// - Ceremony and the `Propose` messages to send
+    pub fn start(
+        message: Vec<u8>,
+        cosigners: Vec<Cosigner>,
+        threshold: usize,
+        now: u64,
+        round_timeout_ms: u64,
+    ) -> Result<(Self, Vec<Outbound>), CosignError> {
+        let mut ids: Vec<ValidatorID> = cosigners.iter().map(|c| c.validator_id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        if threshold == 0 || threshold > cosigners.len() || ids.len() != cosigners.len() {
+            return Err(CosignError::InvalidThreshold);
+        }
+
+        let mut hasher = Sha3_256::new();
+        hasher.update(CEREMONY_DOMAIN);
+        hasher.update((message.len() as u64).to_le_bytes());
+        hasher.update(&message);
+        hasher.update((threshold as u64).to_le_bytes());
+        ids.iter().for_each(|id| hasher.update(id));
+        hasher.update(now.to_le_bytes());
+        let ceremony_id: [u8; 32] = hasher.finalize().into();
+
+        let propose = CeremonyMessage::Propose {
+            ceremony_id,
+            message: message.clone(),
+            threshold: threshold as u32,
+            cosigners: cosigners.iter().map(|c| c.validator_id).collect(),
+        };
+        let mut ceremony = Self {
+            ceremony_id,
+            message,
+            threshold,
+            cosigners,
+            round_timeout_ms,
+            deadline: now.saturating_add(round_timeout_ms),
+            phase: CeremonyPhase::Committing,
+            commitments: BTreeMap::new(),
+            declined: Vec::new(),
+            requested: Vec::new(),
+            partials: BTreeMap::new(),
+            blamed: BTreeMap::new(),
+            aggregate: None,
+            transcript: CeremonyTranscript::new(),
+        };
+        let out = ceremony.broadcast(now, propose);
+        Ok((ceremony, out))
+    }
+
+    /// Ceremony ID
+    pub fn ceremony_id(&self) -> [u8; 32] {
+        self.ceremony_id
+    }
+
+    /// Current phase
+    pub fn phase(&self) -> CeremonyPhase {
+        self.phase
+    }
+
+    /// Combined signature once the ceremony is complete
+    pub fn aggregate(&self) -> Option<&AggregateSignature> {
+        self.aggregate.as_ref()
+    }
+
+    /// Cosigners blamed for misbehaviour
+    pub fn blamed(&self) -> &BTreeMap<ValidatorID, Misbehavior> {
+        &self.blamed
+    }
+
+    /// Transcript so far
+    pub fn transcript(&self) -> &CeremonyTranscript {
+        &self.transcript
+    }
+
+    /// Handle a message received from `from`
+    ///
+    /// # Inputs
+    /// - `from`: Peer the message arrived from
+    /// - `message`: Decoded ceremony message
+    /// - `now`: Current time (milliseconds)
+    /// - `verifier`: Dilithium verification backend
+    ///
+    /// # Outputs
+    /// - Messages to send; rejected messages are not recorded
+    pub fn handle(
+        &mut self,
+        from: PeerID,
+        message: CeremonyMessage,
+        now: u64,
+        verifier: &dyn PartialVerifier,
+    ) -> Result<Vec<Outbound>, CosignError> {
+        if message.ceremony_id() != self.ceremony_id {
+            return Err(CosignError::UnknownCeremony);
+        }
+        if matches!(self.phase, CeremonyPhase::Complete | CeremonyPhase::Aborted(_)) {
+            return Err(CosignError::CeremonyClosed);
+        }
+
+        match (&message, self.phase) {
+            (CeremonyMessage::Commit { validator_id, commitment, .. }, CeremonyPhase::Committing) => {
+                let (validator_id, commitment) = (*validator_id, *commitment);
+                self.authenticate(&from, &validator_id)?;
+                if self.declined.contains(&validator_id) || self.blamed.contains_key(&validator_id) {
+                    return Err(CosignError::UnexpectedMessage);
+                }
+                match self.commitments.get(&validator_id) {
+                    Some(existing) if *existing == commitment => return Ok(Vec::new()),
+                    Some(_) => {
+                        self.commitments.remove(&validator_id);
+                        self.blame(now, from, message, validator_id, Misbehavior::Equivocation);
+                    }
+                    None => {
+                        self.commitments.insert(validator_id, commitment);
+                        self.transcript.record(now, Some(from), message, None);
+                    }
+                }
+            }
+            (CeremonyMessage::Decline { validator_id, .. }, CeremonyPhase::Committing) => {
+                let validator_id = *validator_id;
+                self.authenticate(&from, &validator_id)?;
+                if self.blamed.contains_key(&validator_id) {
+                    return Err(CosignError::UnexpectedMessage);
+                }
+                if self.declined.contains(&validator_id) {
+                    return Ok(Vec::new());
+                }
+                self.commitments.remove(&validator_id);
+                self.declined.push(validator_id);
+                self.transcript.record(now, Some(from), message, None);
+            }
+            (CeremonyMessage::Reveal { validator_id, signature, .. }, CeremonyPhase::Revealing) => {
+                let validator_id = *validator_id;
+                let cosigner = self.authenticate(&from, &validator_id)?;
+                if !self.requested.contains(&validator_id) || self.blamed.contains_key(&validator_id) {
+                    return Err(CosignError::UnexpectedMessage);
+                }
+                if self.partials.contains_key(&validator_id) {
+                    return Ok(Vec::new());
+                }
+                let committed = partial_commitment(&self.ceremony_id, &validator_id, signature);
+                let blame = if self.commitments.get(&validator_id) != Some(&committed) {
+                    Some(Misbehavior::Equivocation)
+                } else if !verifier.verify(&cosigner.public_key, &self.message, signature) {
+                    Some(Misbehavior::InvalidSignature)
+                } else {
+                    None
+                };
+                match blame {
+                    Some(misbehavior) => self.blame(now, from, message, validator_id, misbehavior),
+                    None => {
+                        self.partials.insert(validator_id, signature.clone());
+                        self.transcript.record(now, Some(from), message, None);
+                    }
+                }
+            }
+            _ => return Err(CosignError::UnexpectedMessage),
+        }
+        Ok(self.advance(now, false))
+    }
+
+    /// Apply round deadlines
+    ///
+    /// At the commit deadline the ceremony moves on with the commitments it
+    /// has if they meet the threshold; at the reveal deadline it completes
+    /// with the verified partials it has. Otherwise it aborts with `Timeout`.
+    pub fn tick(&mut self, now: u64) -> Vec<Outbound> {
+        if matches!(self.phase, CeremonyPhase::Complete | CeremonyPhase::Aborted(_)) || now < self.deadline {
+            return Vec::new();
+        }
+        self.advance(now, true)
+    }
+
+    /// Cancel the ceremony
+    pub fn abort(&mut self, now: u64) -> Vec<Outbound> {
+        if matches!(self.phase, CeremonyPhase::Complete | CeremonyPhase::Aborted(_)) {
+            return Vec::new();
+        }
+        self.finish_abort(now, AbortReason::Cancelled)
+    }
+
+    /// Audit TXO carrying the CBOR transcript
+    pub fn audit_txo(&self, timestamp: u64) -> Txo {
+        Txo::new(TxoType::CosignCeremony, timestamp, self.transcript.to_cbor(), Vec::new())
+    }
+
+    fn authenticate(&self, from: &PeerID, validator_id: &ValidatorID) -> Result<&Cosigner, CosignError> {
+        let cosigner = self
+            .cosigners
+            .iter()
+            .find(|c| c.validator_id == *validator_id)
+            .ok_or(CosignError::UnknownCosigner)?;
+        if cosigner.peer_id != *from {
+            return Err(CosignError::UnauthenticatedPeer);
+        }
+        Ok(cosigner)
+    }
+
+    fn blame(&mut self, now: u64, from: PeerID, message: CeremonyMessage, validator_id: ValidatorID, misbehavior: Misbehavior) {
+        self.blamed.insert(validator_id, misbehavior);
+        self.transcript.record(now, Some(from), message, Some(misbehavior));
+    }
+
+    /// Move to the next round, complete, or abort as the responses allow
+    fn advance(&mut self, now: u64, deadline_passed: bool) -> Vec<Outbound> {
+        match self.phase {
+            CeremonyPhase::Committing => {
+                let excluded = self.declined.len() + self.blamed.len();
+                if self.cosigners.len() - excluded < self.threshold {
+                    return self.finish_abort(now, AbortReason::InsufficientSigners);
+                }
+                let all_responded = self.commitments.len() + excluded == self.cosigners.len();
+                if !all_responded && !deadline_passed {
+                    return Vec::new();
+                }
+                if self.commitments.len() < self.threshold {
+                    return self.finish_abort(now, AbortReason::Timeout);
+                }
+                self.requested = self.commitments.keys().copied().collect();
+                self.phase = CeremonyPhase::Revealing;
+                self.deadline = now.saturating_add(self.round_timeout_ms);
+                let request = CeremonyMessage::RevealRequest {
+                    ceremony_id: self.ceremony_id,
+                    signers: self.requested.clone(),
+                };
+                self.transcript.record(now, None, request.clone(), None);
+                self.send_to(&self.requested, &request)
+            }
+            CeremonyPhase::Revealing => {
+                let blamed = self.requested.iter().filter(|id| self.blamed.contains_key(*id)).count();
+                if self.requested.len() - blamed < self.threshold {
+                    return self.finish_abort(now, AbortReason::InsufficientSigners);
+                }
+                let all_responded = self.partials.len() + blamed == self.requested.len();
+                if !all_responded && !deadline_passed {
+                    return Vec::new();
+                }
+                if self.partials.len() < self.threshold {
+                    return self.finish_abort(now, AbortReason::Timeout);
+                }
+                let partials: Vec<PartialSignature> = self
+                    .partials
+                    .iter()
+                    .map(|(id, sig)| PartialSignature { validator_id: *id, signature: sig.clone() })
+                    .collect();
+                let complete = CeremonyMessage::Complete {
+                    ceremony_id: self.ceremony_id,
+                    signers: partials.iter().map(|p| p.validator_id).collect(),
+                };
+                self.aggregate = Some(AggregateSignature { ceremony_id: self.ceremony_id, partials });
+                self.phase = CeremonyPhase::Complete;
+                self.broadcast(now, complete)
+            }
+            CeremonyPhase::Complete | CeremonyPhase::Aborted(_) => Vec::new(),
+        }
+    }
+
+    fn finish_abort(&mut self, now: u64, reason: AbortReason) -> Vec<Outbound> {
+        self.phase = CeremonyPhase::Aborted(reason);
+        self.broadcast(now, CeremonyMessage::Abort { ceremony_id: self.ceremony_id, reason })
+    }
+
+    fn broadcast(&mut self, now: u64, message: CeremonyMessage) -> Vec<Outbound> {
+        self.transcript.record(now, None, message.clone(), None);
+        let all: Vec<ValidatorID> = self.cosigners.iter().map(|c| c.validator_id).collect();
+        self.send_to(&all, &message)
+    }
+
+    fn send_to(&self, validators: &[ValidatorID], message: &CeremonyMessage) -> Vec<Outbound> {
+        self.cosigners
+            .iter()
+            .filter(|c| validators.contains(&c.validator_id))
+            .map(|c| Outbound { peer: c.peer_id, message: message.clone() })
+            .collect()
+    }
+}
+
+/// Cosigner side of signing ceremonies run by one coordinator
+pub struct CosignParticipant {
+    validator_id: ValidatorID,
+    coordinator: PeerID,
+    pending: BTreeMap<[u8; 32], Vec<u8>>,
+}
+
+impl CosignParticipant {
+    /// Create a participant answering `coordinator`
+    pub fn new(validator_id: ValidatorID, coordinator: PeerID) -> Self {
+        Self { validator_id, coordinator, pending: BTreeMap::new() }
+    }
+
+    /// Ceremonies this participant has committed to but not yet closed
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Handle a coordinator message
+    ///
+    /// # Inputs
+    /// - `from`: Peer the message arrived from
+    /// - `message`: Decoded ceremony message
+    /// - `signer`: Dilithium signing backend
+    /// - `approve`: Local policy deciding whether to sign a proposed message
+    ///
+    /// # Outputs
+    /// - Reply for the coordinator, if any
+    pub fn handle(
+        &mut self,
+        from: PeerID,
+        message: &CeremonyMessage,
+        signer: &dyn PartialSigner,
+        approve: &dyn Fn(&[u8]) -> bool,
+    ) -> Option<CeremonyMessage> {
+        if from != self.coordinator {
+            return None;
+        }
+        match message {
+            CeremonyMessage::Propose { ceremony_id, message, cosigners, .. } => {
+                if !cosigners.contains(&self.validator_id) || self.pending.contains_key(ceremony_id) {
+                    return None;
+                }
+                if !approve(message) {
+                    return Some(CeremonyMessage::Decline { ceremony_id: *ceremony_id, validator_id: self.validator_id });
+                }
+                let signature = signer.sign(message);
+                let commitment = partial_commitment(ceremony_id, &self.validator_id, &signature);
+                self.pending.insert(*ceremony_id, signature);
+                Some(CeremonyMessage::Commit { ceremony_id: *ceremony_id, validator_id: self.validator_id, commitment })
+            }
+            CeremonyMessage::RevealRequest { ceremony_id, signers } if signers.contains(&self.validator_id) => {
+                let signature = self.pending.get(ceremony_id)?.clone();
+                Some(CeremonyMessage::Reveal { ceremony_id: *ceremony_id, validator_id: self.validator_id, signature })
+            }
+            CeremonyMessage::Complete { ceremony_id, .. } | CeremonyMessage::Abort { ceremony_id, .. } => {
+                self.pending.remove(ceremony_id);
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stand-in for Dilithium: a signature is SHA3-256(public key || message)
+    struct TestKey(Vec<u8>);
+
+    impl PartialSigner for TestKey {
+        fn sign(&self, message: &[u8]) -> Vec<u8> {
+            Sha3_256::new().chain_update(&self.0).chain_update(message).finalize().to_vec()
+        }
+    }
+
+    struct TestVerifier;
+
+    impl PartialVerifier for TestVerifier {
+        fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+            TestKey(public_key.to_vec()).sign(message) == signature
+        }
+    }
+
+    const COORDINATOR: PeerID = [0xC0; 32];
+
+    fn cosigners(n: u8) -> Vec<Cosigner> {
+        (1..=n)
+            .map(|i| Cosigner { validator_id: [i; 32], peer_id: [i + 100; 32], public_key: vec![i; 8] })
+            .collect()
+    }
+
+    /// Deliver every outbound message over CBOR and collect the replies
+    fn deliver(
+        ceremony: &mut CosignCeremony,
+        participants: &mut [CosignParticipant],
+        keys: &[Cosigner],
+        outbound: Vec<Outbound>,
+        now: u64,
+    ) -> Vec<Outbound> {
+        let mut next = Vec::new();
+        for out in outbound {
+            let message = CeremonyMessage::from_cbor(&out.message.to_cbor()).unwrap();
+            let index = keys.iter().position(|c| c.peer_id == out.peer).unwrap();
+            let key = TestKey(keys[index].public_key.clone());
+            let decline = index == 3;
+            if let Some(reply) = participants[index].handle(COORDINATOR, &message, &key, &|_| !decline) {
+                next.extend(ceremony.handle(keys[index].peer_id, reply, now, &TestVerifier).unwrap());
+            }
+        }
+        next
+    }
+
+    #[test]
+    fn test_ceremony_completes_with_decline() {
+        let keys = cosigners(4);
+        let mut participants: Vec<_> = keys.iter().map(|c| CosignParticipant::new(c.validator_id, COORDINATOR)).collect();
+        let message = b"finality header".to_vec();
+        let (mut ceremony, propose) = CosignCeremony::start(message.clone(), keys.clone(), 3, 1_000, 500).unwrap();
+        assert_eq!(propose.len(), 4);
+
+        let reveal_requests = deliver(&mut ceremony, &mut participants, &keys, propose, 1_010);
+        assert_eq!(ceremony.phase(), CeremonyPhase::Revealing);
+        assert_eq!(reveal_requests.len(), 3);
+
+        let complete = deliver(&mut ceremony, &mut participants, &keys, reveal_requests, 1_020);
+        assert_eq!(ceremony.phase(), CeremonyPhase::Complete);
+        assert_eq!(complete.len(), 4);
+        deliver(&mut ceremony, &mut participants, &keys, complete, 1_030);
+        assert!(participants.iter().all(|p| p.pending() == 0));
+
+        let aggregate = ceremony.aggregate().unwrap();
+        assert_eq!(aggregate.verify(&message, &keys, 3, &TestVerifier), Ok(3));
+        assert_eq!(aggregate.verify(&message, &keys, 4, &TestVerifier), Err(CosignError::InsufficientSignatures));
+        assert_eq!(aggregate.verify(b"other", &keys, 3, &TestVerifier), Err(CosignError::InsufficientSignatures));
+
+        // Propose, 3 commits, decline, reveal request, 3 reveals, complete
+        let transcript = ceremony.transcript();
+        assert_eq!(transcript.entries.len(), 10);
+        assert!(transcript.verify());
+        let txo = ceremony.audit_txo(1_040);
+        assert_eq!(txo.txo_type, TxoType::CosignCeremony);
+        let decoded = CeremonyTranscript::from_cbor(&txo.payload).unwrap();
+        assert!(decoded.verify());
+        let mut forged = decoded;
+        forged.entries[1].timestamp += 1;
+        assert!(!forged.verify());
+
+        assert_eq!(ceremony.abort(1_050), Vec::new());
+    }
+
+    #[test]
+    fn test_equivocation_and_invalid_partials_abort() {
+        let keys = cosigners(3);
+        let (mut ceremony, _) = CosignCeremony::start(b"m".to_vec(), keys.clone(), 2, 0, 100).unwrap();
+        let id = ceremony.ceremony_id();
+        let sig = |i: u8| TestKey(vec![i; 8]).sign(b"m");
+        let commit = |i: u8, signature: &[u8]| CeremonyMessage::Commit {
+            ceremony_id: id,
+            validator_id: [i; 32],
+            commitment: partial_commitment(&id, &[i; 32], signature),
+        };
+
+        // Messages must come from the validator's registered peer
+        assert_eq!(ceremony.handle([1; 32], commit(1, &sig(1)), 1, &TestVerifier), Err(CosignError::UnauthenticatedPeer));
+
+        ceremony.handle([101; 32], commit(1, &sig(1)), 1, &TestVerifier).unwrap();
+        ceremony.handle([102; 32], commit(2, b"garbage"), 1, &TestVerifier).unwrap();
+        ceremony.handle([103; 32], commit(3, &sig(3)), 1, &TestVerifier).unwrap();
+        assert_eq!(ceremony.phase(), CeremonyPhase::Revealing);
+
+        // Validator 2 reveals a signature that does not verify
+        let reveal = |i: u8, signature: Vec<u8>| CeremonyMessage::Reveal { ceremony_id: id, validator_id: [i; 32], signature };
+        ceremony.handle([102; 32], reveal(2, b"garbage".to_vec()), 2, &TestVerifier).unwrap();
+        assert_eq!(ceremony.blamed().get(&[2; 32]), Some(&Misbehavior::InvalidSignature));
+
+        // Validator 3 reveals something other than what it committed to
+        let out = ceremony.handle([103; 32], reveal(3, sig(1)), 2, &TestVerifier).unwrap();
+        assert_eq!(ceremony.blamed().get(&[3; 32]), Some(&Misbehavior::Equivocation));
+        assert_eq!(ceremony.phase(), CeremonyPhase::Aborted(AbortReason::InsufficientSigners));
+        assert_eq!(out.len(), 3);
+        assert!(matches!(out[0].message, CeremonyMessage::Abort { reason: AbortReason::InsufficientSigners, .. }));
+        assert_eq!(ceremony.handle([101; 32], reveal(1, sig(1)), 3, &TestVerifier), Err(CosignError::CeremonyClosed));
+
+        let blamed: Vec<_> = ceremony.transcript().entries.iter().filter_map(|e| e.blame).collect();
+        assert_eq!(blamed, [Misbehavior::InvalidSignature, Misbehavior::Equivocation]);
+    }
+
+    #[test]
+    fn test_round_timeouts() {
+        let keys = cosigners(3);
+        let (mut ceremony, _) = CosignCeremony::start(b"m".to_vec(), keys.clone(), 2, 0, 100).unwrap();
+        let id = ceremony.ceremony_id();
+        let signature = TestKey(vec![1; 8]).sign(b"m");
+        let commit = CeremonyMessage::Commit { ceremony_id: id, validator_id: [1; 32], commitment: partial_commitment(&id, &[1; 32], &signature) };
+        ceremony.handle([101; 32], commit, 10, &TestVerifier).unwrap();
+
+        assert_eq!(ceremony.tick(99), Vec::new());
+        let out = ceremony.tick(100);
+        assert_eq!(ceremony.phase(), CeremonyPhase::Aborted(AbortReason::Timeout));
+        assert_eq!(out.len(), 3);
+
+        // Enough commitments at the deadline moves on without the straggler
+        let (mut ceremony, _) = CosignCeremony::start(b"m".to_vec(), keys.clone(), 2, 0, 100).unwrap();
+        let id = ceremony.ceremony_id();
+        for i in 1..=2u8 {
+            let signature = TestKey(vec![i; 8]).sign(b"m");
+            let commit = CeremonyMessage::Commit { ceremony_id: id, validator_id: [i; 32], commitment: partial_commitment(&id, &[i; 32], &signature) };
+            ceremony.handle([100 + i; 32], commit, 10, &TestVerifier).unwrap();
+        }
+        assert_eq!(ceremony.tick(100).len(), 2);
+        assert_eq!(ceremony.phase(), CeremonyPhase::Revealing);
+        assert!(matches!(ceremony.tick(200)[0].message, CeremonyMessage::Abort { reason: AbortReason::Timeout, .. }));
+
+        assert!(matches!(CosignCeremony::start(b"m".to_vec(), keys, 4, 0, 100), Err(CosignError::InvalidThreshold)));
+    }
+}
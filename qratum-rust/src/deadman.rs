@@ -0,0 +1,308 @@
+//! # Dead-Man Module - Stage Deadlines and Member Liveness
+//!
+//! ## Lifecycle Stage: Quorum Convergence → Outcome Commitment (continuous)
+//!
+//! If quorum members disappear mid-session the lifecycle would otherwise wait
+//! forever with biokey material and ledger state resident in RAM. The session
+//! watchdog bounds every stage with a deadline and every quorum member with a
+//! heartbeat timeout; whichever trips first ends the session.
+//!
+//! ## Architectural Role
+//!
+//! - **Stage Deadlines**: Per-stage wall-clock budget (stages 1-4)
+//! - **Dead-Man Switch**: Members must heartbeat within `heartbeat_timeout_ms`
+//! - **Abort TXO**: Signed `SessionAbort` TXO naming the stage that expired and
+//!   the members that went silent
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Stage transitions and member heartbeats, timestamped via [`crate::clock`]
+//! - Output: `WatchdogExpiry` and its abort TXO
+//!
+//! ## Security Rationale
+//!
+//! - Expiry triggers controlled self-destruction (stage 5) instead of a hang
+//! - Abort TXOs carry the same MAC as entropy aborts, so
+//!   [`crate::entropy::verify_abort_txo`] authenticates both
+//! - A deadline of 0 disables that check; no_std builds only expire once the
+//!   host supplies time
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::entropy::abort_signature;
+use crate::txo::{Txo, TxoType};
+
+/// Payload marker following the session id in watchdog abort TXOs
+///
+/// Entropy abort payloads carry a health score (0-100) in this position.
+pub const WATCHDOG_ABORT_MARKER: u8 = 0xFF;
+
+/// Lifecycle stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SessionStage {
+    /// Stage 1
+    QuorumConvergence = 1,
+    /// Stage 2
+    EphemeralMaterialization = 2,
+    /// Stage 3
+    Execution = 3,
+    /// Stage 4
+    OutcomeCommitment = 4,
+    /// Stage 5 (never times out)
+    SelfDestruction = 5,
+}
+
+impl SessionStage {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(SessionStage::QuorumConvergence),
+            2 => Some(SessionStage::EphemeralMaterialization),
+            3 => Some(SessionStage::Execution),
+            4 => Some(SessionStage::OutcomeCommitment),
+            5 => Some(SessionStage::SelfDestruction),
+            _ => None,
+        }
+    }
+}
+
+/// Dead-man switch configuration
+#[derive(Debug, Clone)]
+pub struct DeadmanConfig {
+    /// Deadline for stages 1-4 in milliseconds (0 = unbounded)
+    pub stage_deadlines_ms: [u64; 4],
+    /// Maximum silence from a watched member in milliseconds (0 = unbounded)
+    pub heartbeat_timeout_ms: u64,
+}
+
+impl Default for DeadmanConfig {
+    fn default() -> Self {
+        Self {
+            stage_deadlines_ms: [
+                60_000,  // Quorum convergence
+                10_000,  // Ephemeral materialization
+                300_000, // Execution
+                30_000,  // Outcome commitment
+            ],
+            heartbeat_timeout_ms: 15_000,
+        }
+    }
+}
+
+/// Why the watchdog fired
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchdogExpiry {
+    /// Stage in progress when the watchdog fired
+    pub stage: SessionStage,
+    /// Milliseconds past the stage deadline (0 if only the dead-man switch fired)
+    pub overdue_ms: u64,
+    /// Members silent for longer than the heartbeat timeout
+    pub unresponsive: Vec<[u8; 32]>,
+}
+
+impl WatchdogExpiry {
+    /// Build the signed abort TXO recording this expiry
+    ///
+    /// # Inputs
+    /// - `session_id`: Session being aborted
+    /// - `timestamp`: Abort time (milliseconds)
+    /// - `key_material`: Session key used to MAC the TXO
+    ///
+    /// # Outputs
+    /// - `SessionAbort` TXO; payload is session id, `WATCHDOG_ABORT_MARKER`,
+    ///   stage, overdue ms, member count, then each unresponsive member id
+    pub fn abort_txo(&self, session_id: [u8; 32], timestamp: u64, key_material: &[u8; 64]) -> Txo {
+        let mut payload = Vec::with_capacity(46 + 32 * self.unresponsive.len());
+        payload.extend_from_slice(&session_id);
+        payload.push(WATCHDOG_ABORT_MARKER);
+        payload.push(self.stage as u8);
+        payload.extend_from_slice(&self.overdue_ms.to_le_bytes());
+        payload.extend_from_slice(&(self.unresponsive.len() as u32).to_le_bytes());
+        for member in &self.unresponsive {
+            payload.extend_from_slice(member);
+        }
+
+        let mut txo = Txo::new(TxoType::SessionAbort, timestamp, payload, Vec::new());
+        txo.signatures.push(abort_signature(&txo.id, key_material));
+        txo
+    }
+
+    /// Decode a watchdog abort TXO payload
+    ///
+    /// # Outputs
+    /// - `None` for entropy aborts, other TXO types, or malformed payloads
+    pub fn from_abort_txo(txo: &Txo) -> Option<Self> {
+        let payload = &txo.payload;
+        if txo.txo_type != TxoType::SessionAbort || payload.get(32) != Some(&WATCHDOG_ABORT_MARKER) {
+            return None;
+        }
+        let stage = SessionStage::from_u8(*payload.get(33)?)?;
+        let overdue_ms = u64::from_le_bytes(payload.get(34..42)?.try_into().ok()?);
+        let count = u32::from_le_bytes(payload.get(42..46)?.try_into().ok()?) as usize;
+        let members = payload.get(46..)?;
+        if members.len() != count.checked_mul(32)? {
+            return None;
+        }
+        let unresponsive = members
+            .chunks_exact(32)
+            .map(|chunk| {
+                let mut id = [0u8; 32];
+                id.copy_from_slice(chunk);
+                id
+            })
+            .collect();
+        Some(Self { stage, overdue_ms, unresponsive })
+    }
+}
+
+/// Per-session stage deadlines and member heartbeats
+#[derive(Debug, Clone)]
+pub struct SessionWatchdog {
+    config: DeadmanConfig,
+    stage: SessionStage,
+    stage_started_ms: u64,
+    members: Vec<([u8; 32], u64)>,
+}
+
+impl SessionWatchdog {
+    /// Start watching a session entering stage 1 at `now_ms`
+    pub fn new(config: DeadmanConfig, now_ms: u64) -> Self {
+        Self {
+            config,
+            stage: SessionStage::QuorumConvergence,
+            stage_started_ms: now_ms,
+            members: Vec::new(),
+        }
+    }
+
+    /// Stage currently being timed
+    pub fn stage(&self) -> SessionStage {
+        self.stage
+    }
+
+    /// Start the deadline for `stage`
+    pub fn enter_stage(&mut self, stage: SessionStage, now_ms: u64) {
+        self.stage = stage;
+        self.stage_started_ms = now_ms;
+    }
+
+    /// Arm the dead-man switch for `member`, counting `seen_ms` as its last heartbeat
+    pub fn watch_member(&mut self, member: [u8; 32], seen_ms: u64) {
+        match self.members.iter_mut().find(|(id, _)| *id == member) {
+            Some(entry) => entry.1 = entry.1.max(seen_ms),
+            None => self.members.push((member, seen_ms)),
+        }
+    }
+
+    /// Number of members under the dead-man switch
+    pub fn watched_members(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Record a heartbeat; returns `false` for members not being watched
+    pub fn heartbeat(&mut self, member: &[u8; 32], now_ms: u64) -> bool {
+        match self.members.iter_mut().find(|(id, _)| id == member) {
+            Some(entry) => {
+                entry.1 = entry.1.max(now_ms);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check the current stage deadline and every member heartbeat
+    ///
+    /// # Outputs
+    /// - `Some(WatchdogExpiry)` if the deadline passed or any member went silent
+    pub fn check(&self, now_ms: u64) -> Option<WatchdogExpiry> {
+        let deadline = match self.stage {
+            SessionStage::SelfDestruction => 0,
+            stage => self.config.stage_deadlines_ms[stage as usize - 1],
+        };
+        let elapsed = now_ms.saturating_sub(self.stage_started_ms);
+        let overdue_ms = if deadline > 0 { elapsed.saturating_sub(deadline) } else { 0 };
+
+        let timeout = self.config.heartbeat_timeout_ms;
+        let unresponsive: Vec<[u8; 32]> = if timeout > 0 {
+            self.members
+                .iter()
+                .filter(|(_, seen)| now_ms.saturating_sub(*seen) > timeout)
+                .map(|(id, _)| *id)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        if overdue_ms == 0 && unresponsive.is_empty() {
+            return None;
+        }
+        Some(WatchdogExpiry { stage: self.stage, overdue_ms, unresponsive })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entropy::verify_abort_txo;
+
+    #[test]
+    fn test_stage_deadline_and_dead_man_switch() {
+        let config = DeadmanConfig {
+            stage_deadlines_ms: [1_000, 0, 5_000, 1_000],
+            heartbeat_timeout_ms: 2_000,
+        };
+        let mut watchdog = SessionWatchdog::new(config, 10_000);
+        assert_eq!(watchdog.check(10_999), None);
+
+        // Stage 1 overruns by 500 ms
+        let expiry = watchdog.check(11_500).unwrap();
+        assert_eq!(expiry.stage, SessionStage::QuorumConvergence);
+        assert_eq!(expiry.overdue_ms, 500);
+        assert!(expiry.unresponsive.is_empty());
+
+        // Stage 2 is unbounded, but members must keep heartbeating
+        watchdog.enter_stage(SessionStage::EphemeralMaterialization, 11_000);
+        watchdog.watch_member([1u8; 32], 11_000);
+        watchdog.watch_member([2u8; 32], 11_000);
+        assert!(watchdog.heartbeat(&[1u8; 32], 12_500));
+        assert!(!watchdog.heartbeat(&[9u8; 32], 12_500));
+        assert_eq!(watchdog.check(13_000), None);
+
+        let expiry = watchdog.check(13_001).unwrap();
+        assert_eq!(expiry.stage, SessionStage::EphemeralMaterialization);
+        assert_eq!(expiry.overdue_ms, 0);
+        assert_eq!(expiry.unresponsive, vec![[2u8; 32]]);
+
+        // Self-destruction never times out
+        watchdog.heartbeat(&[2u8; 32], 100_000);
+        watchdog.heartbeat(&[1u8; 32], 100_000);
+        watchdog.enter_stage(SessionStage::SelfDestruction, 0);
+        assert_eq!(watchdog.check(100_000), None);
+    }
+
+    #[test]
+    fn test_abort_txo_round_trip() {
+        let key = [0x42u8; 64];
+        let expiry = WatchdogExpiry {
+            stage: SessionStage::Execution,
+            overdue_ms: 1_234,
+            unresponsive: vec![[3u8; 32], [4u8; 32]],
+        };
+
+        let txo = expiry.abort_txo([7u8; 32], 99, &key);
+        assert_eq!(txo.txo_type, TxoType::SessionAbort);
+        assert_eq!(&txo.payload[..32], &[7u8; 32]);
+        assert!(verify_abort_txo(&txo, &key));
+        assert!(!verify_abort_txo(&txo, &[0u8; 64]));
+        assert_eq!(WatchdogExpiry::from_abort_txo(&txo), Some(expiry));
+
+        // Entropy aborts are not mistaken for watchdog aborts
+        let mut other = txo.clone();
+        other.payload[32] = 50;
+        assert_eq!(WatchdogExpiry::from_abort_txo(&other), None);
+        other.payload[32] = WATCHDOG_ABORT_MARKER;
+        other.payload.pop();
+        assert_eq!(WatchdogExpiry::from_abort_txo(&other), None);
+    }
+}
@@ -0,0 +1,299 @@
+//! # Degraded Module - Partial Quorum Session Continuation
+//!
+//! ## Lifecycle Stage: Execution → Outcome Commitment (continuous)
+//!
+//! Losing a single quorum member should not throw away a session that still
+//! has majority backing. When active membership falls below full quorum
+//! (`initial_threshold`) but stays at or above the decay floor
+//! (`minimum_threshold`), the session may continue in degraded mode with the
+//! capabilities its policy grants. Below the floor the quorum is lost.
+//!
+//! ## Architectural Role
+//!
+//! - **Quorum Assessment**: Full / Degraded / Lost from active membership
+//! - **Capability Gating**: Degraded sessions keep only policy-granted
+//!   capabilities (default: read-only execution, no blinded reveals)
+//! - **DegradedMode TXO**: Emitted on entering degraded mode and on recovery
+//! - **Re-Ratification**: Outcomes committed while degraded stay pending until
+//!   a recovered full quorum ratifies them
+//!
+//! ## Security Rationale
+//!
+//! - Degraded mode is opt-in per policy; disabled, any shortfall is fatal
+//! - The decay floor is never crossed: it bounds degraded operation too
+//! - Every mode change is externally observable through a TXO
+//! - Degraded outcomes cannot be presented as fully attested until ratified
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::quorum::QuorumConfig;
+use crate::txo::{Txo, TxoType};
+
+/// Session capability that degraded mode may withhold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// Append to the session ledger and mutate execution state
+    StateMutation,
+    /// Reveal blinded payloads
+    BlindedReveal,
+    /// Create volatile snapshots
+    Snapshot,
+    /// Grant bonded proxy approvals
+    ProxyApproval,
+}
+
+/// Degraded mode policy (default: disabled, read-only when enabled)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DegradedPolicy {
+    /// Continue below full quorum (down to the decay floor)
+    pub enabled: bool,
+    /// Capabilities retained while degraded
+    pub capabilities: Vec<Capability>,
+}
+
+/// Quorum membership level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuorumMode {
+    /// At or above `initial_threshold`
+    Full,
+    /// Between `minimum_threshold` and `initial_threshold`
+    Degraded,
+    /// Below `minimum_threshold`
+    Lost,
+}
+
+/// Degraded Mode Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DegradedError {
+    /// Membership fell below the decay floor
+    QuorumLost,
+    /// Membership fell below full quorum and policy forbids degraded mode
+    DegradedModeDisabled,
+    /// Capability withheld while degraded
+    CapabilityUnavailable(Capability),
+    /// Ratification requires a recovered full quorum
+    QuorumNotRecovered,
+    /// Outcome is not pending ratification
+    UnknownOutcome,
+}
+
+/// Percentage of `total` that `count` represents, compared against `threshold`
+fn meets(count: usize, total: usize, threshold: u8) -> bool {
+    total > 0 && count * 100 >= total * threshold as usize
+}
+
+/// Classify active membership against the quorum thresholds
+pub fn assess(config: &QuorumConfig, active: usize, total: usize) -> QuorumMode {
+    if meets(active, total, config.initial_threshold) {
+        QuorumMode::Full
+    } else if meets(active, total, config.minimum_threshold) {
+        QuorumMode::Degraded
+    } else {
+        QuorumMode::Lost
+    }
+}
+
+/// Tracks quorum mode, capabilities and outcomes awaiting re-ratification
+#[derive(Debug, Clone)]
+pub struct DegradationMonitor {
+    quorum: QuorumConfig,
+    policy: DegradedPolicy,
+    session_id: [u8; 32],
+    mode: QuorumMode,
+    total: usize,
+    pending: Vec<[u8; 32]>,
+}
+
+impl DegradationMonitor {
+    /// Start monitoring a session that converged with full quorum
+    pub fn new(quorum: QuorumConfig, policy: DegradedPolicy, session_id: [u8; 32]) -> Self {
+        Self {
+            quorum,
+            policy,
+            session_id,
+            mode: QuorumMode::Full,
+            total: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Current quorum mode
+    pub fn mode(&self) -> QuorumMode {
+        self.mode
+    }
+
+    /// Update membership
+    ///
+    /// # Inputs
+    /// - `active`: Members currently responsive
+    /// - `total`: Members that formed the session quorum
+    /// - `timestamp`: Observation time (milliseconds)
+    ///
+    /// # Outputs
+    /// - `Ok(Some(txo))`: `DegradedMode` TXO on entering degraded mode or recovering
+    /// - `Ok(None)`: mode unchanged
+    /// - `Err(DegradedError)`: quorum lost, or degraded mode not permitted
+    pub fn observe(&mut self, active: usize, total: usize, timestamp: u64) -> Result<Option<Txo>, DegradedError> {
+        self.total = total;
+        let mode = assess(&self.quorum, active, total);
+        match (self.mode, mode) {
+            (_, QuorumMode::Lost) => {
+                self.mode = QuorumMode::Lost;
+                Err(DegradedError::QuorumLost)
+            }
+            (QuorumMode::Full, QuorumMode::Degraded) if !self.policy.enabled => {
+                self.mode = QuorumMode::Lost;
+                Err(DegradedError::DegradedModeDisabled)
+            }
+            (QuorumMode::Full, QuorumMode::Degraded) => {
+                self.mode = QuorumMode::Degraded;
+                let reason = alloc::format!(
+                    "Degraded mode entered: {}/{} members active (full quorum {}%, floor {}%) | Capabilities: {:?}",
+                    active,
+                    total,
+                    self.quorum.initial_threshold,
+                    self.quorum.minimum_threshold,
+                    self.policy.capabilities
+                );
+                Ok(Some(self.event_txo(reason, timestamp)))
+            }
+            (QuorumMode::Degraded, QuorumMode::Full) => {
+                self.mode = QuorumMode::Full;
+                let reason = alloc::format!(
+                    "Full quorum recovered: {}/{} members active | Outcomes pending re-ratification: {}",
+                    active,
+                    total,
+                    self.pending.len()
+                );
+                Ok(Some(self.event_txo(reason, timestamp)))
+            }
+            (QuorumMode::Lost, _) => Err(DegradedError::QuorumLost),
+            _ => Ok(None),
+        }
+    }
+
+    /// Whether `capability` is available in the current mode
+    pub fn permits(&self, capability: Capability) -> bool {
+        match self.mode {
+            QuorumMode::Full => true,
+            QuorumMode::Degraded => self.policy.capabilities.contains(&capability),
+            QuorumMode::Lost => false,
+        }
+    }
+
+    /// Require `capability`, for use before gated operations
+    pub fn require(&self, capability: Capability) -> Result<(), DegradedError> {
+        if self.permits(capability) {
+            Ok(())
+        } else {
+            Err(DegradedError::CapabilityUnavailable(capability))
+        }
+    }
+
+    /// Record a committed outcome; degraded outcomes await re-ratification
+    pub fn record_outcome(&mut self, outcome_id: [u8; 32]) {
+        if self.mode != QuorumMode::Full && !self.pending.contains(&outcome_id) {
+            self.pending.push(outcome_id);
+        }
+    }
+
+    /// Outcomes committed while degraded and not yet ratified
+    pub fn pending_ratification(&self) -> &[[u8; 32]] {
+        &self.pending
+    }
+
+    /// Ratify a degraded outcome with a recovered full quorum
+    ///
+    /// # Inputs
+    /// - `outcome_id`: Pending outcome TXO id
+    /// - `approvals`: Distinct members approving the outcome
+    pub fn ratify(&mut self, outcome_id: &[u8; 32], approvals: usize) -> Result<(), DegradedError> {
+        if self.mode != QuorumMode::Full || !meets(approvals, self.total, self.quorum.initial_threshold) {
+            return Err(DegradedError::QuorumNotRecovered);
+        }
+        let index = self
+            .pending
+            .iter()
+            .position(|id| id == outcome_id)
+            .ok_or(DegradedError::UnknownOutcome)?;
+        self.pending.remove(index);
+        Ok(())
+    }
+
+    fn event_txo(&self, reason: String, timestamp: u64) -> Txo {
+        let mut payload = Vec::from(self.session_id);
+        payload.extend_from_slice(reason.as_bytes());
+        Txo::new(TxoType::DegradedMode, timestamp, payload, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(enabled: bool) -> DegradationMonitor {
+        let policy = DegradedPolicy {
+            enabled,
+            capabilities: vec![Capability::Snapshot],
+        };
+        DegradationMonitor::new(QuorumConfig::default(), policy, [1u8; 32])
+    }
+
+    #[test]
+    fn test_assess_thresholds() {
+        let config = QuorumConfig::default(); // 67% full, 51% floor
+        assert_eq!(assess(&config, 3, 3), QuorumMode::Full);
+        assert_eq!(assess(&config, 7, 10), QuorumMode::Full);
+        assert_eq!(assess(&config, 6, 10), QuorumMode::Degraded);
+        assert_eq!(assess(&config, 2, 3), QuorumMode::Degraded);
+        assert_eq!(assess(&config, 5, 10), QuorumMode::Lost);
+        assert_eq!(assess(&config, 0, 0), QuorumMode::Lost);
+    }
+
+    #[test]
+    fn test_degraded_session_and_re_ratification() {
+        let mut monitor = monitor(true);
+        assert!(matches!(monitor.observe(10, 10, 1), Ok(None)));
+        monitor.record_outcome([7u8; 32]);
+        assert!(monitor.pending_ratification().is_empty());
+
+        // One member lost: continue read-only with snapshots
+        let txo = monitor.observe(6, 10, 2).unwrap().unwrap();
+        assert_eq!(txo.txo_type, TxoType::DegradedMode);
+        assert_eq!(&txo.payload[..32], &[1u8; 32]);
+        assert_eq!(monitor.mode(), QuorumMode::Degraded);
+        assert!(monitor.permits(Capability::Snapshot));
+        assert_eq!(
+            monitor.require(Capability::BlindedReveal),
+            Err(DegradedError::CapabilityUnavailable(Capability::BlindedReveal))
+        );
+        assert!(!monitor.permits(Capability::StateMutation));
+        assert!(matches!(monitor.observe(6, 10, 3), Ok(None)));
+
+        monitor.record_outcome([8u8; 32]);
+        assert_eq!(monitor.ratify(&[8u8; 32], 6), Err(DegradedError::QuorumNotRecovered));
+
+        // Recovery emits a TXO; the degraded outcome still needs a full quorum
+        assert!(monitor.observe(9, 10, 4).unwrap().is_some());
+        assert!(monitor.permits(Capability::BlindedReveal));
+        assert_eq!(monitor.pending_ratification(), &[[8u8; 32]]);
+        assert_eq!(monitor.ratify(&[8u8; 32], 6), Err(DegradedError::QuorumNotRecovered));
+        assert_eq!(monitor.ratify(&[9u8; 32], 7), Err(DegradedError::UnknownOutcome));
+        assert_eq!(monitor.ratify(&[8u8; 32], 7), Ok(()));
+        assert!(monitor.pending_ratification().is_empty());
+    }
+
+    #[test]
+    fn test_quorum_loss_is_fatal() {
+        let mut disabled = monitor(false);
+        assert_eq!(disabled.observe(6, 10, 1).err(), Some(DegradedError::DegradedModeDisabled));
+        assert!(!disabled.permits(Capability::Snapshot));
+
+        let mut enabled = monitor(true);
+        assert!(enabled.observe(6, 10, 1).is_ok());
+        assert_eq!(enabled.observe(5, 10, 2).err(), Some(DegradedError::QuorumLost));
+        assert_eq!(enabled.observe(10, 10, 3).err(), Some(DegradedError::QuorumLost));
+    }
+}
@@ -0,0 +1,503 @@
+//! # Collector Module - Entropy Source Collection
+//!
+//! ## Lifecycle Stage: Ephemeral Materialization
+//!
+//! Gathers `EntropySourceType::Trng` and `EntropySourceType::System`
+//! contributions from the platform through pluggable `EntropyCollector`s,
+//! crediting each with a measured min-entropy estimate rather than a
+//! caller-asserted bit count.
+//!
+//! ## Architectural Role
+//!
+//! - **HardwareCollector**: RDSEED (preferred) or RDRAND on x86_64
+//! - **SystemCollector**: OS entropy via `getrandom` (std only)
+//! - **JitterCollector**: CPU timing jitter over a caller-supplied counter,
+//!   for embedded targets with neither of the above
+//! - **Estimator**: SP 800-90B §6.3.1 most common value estimate
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Raw byte samples from each collector
+//! - Output: `EntropyContribution`s ready for `EphemeralBiokey::derive_blended`
+//!
+//! ## Security Rationale
+//!
+//! - Raw samples pass the repetition count test before being used
+//! - Credited bits are the lesser of the measured estimate (99% upper bound
+//!   on the most common value) and the collector's claimed min-entropy
+//! - Failed or alarmed collectors are dropped; blending still requires
+//!   `MIN_ENTROPY_SOURCES` independent contributions
+//! - Samples from failed collections are zeroized
+
+extern crate alloc;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use zeroize::Zeroize;
+
+use super::RepetitionCountTest;
+use crate::biokey::{EntropyContribution, EntropySourceType, MIN_ENTROPY_SOURCES};
+
+/// Fixed-point scale of min-entropy estimates (1/65536 bit)
+pub const ENTROPY_FIXED_ONE: u64 = 1 << 16;
+
+/// Z value of the 99% upper confidence bound (SP 800-90B §6.3.1), ×1000
+const Z_99_MILLI: u128 = 2576;
+
+/// RDRAND attempts per word before reporting failure (Intel guidance)
+const RDRAND_RETRIES: u32 = 10;
+
+/// RDSEED attempts per word; it underflows transiently under contention
+const RDSEED_RETRIES: u32 = 1024;
+
+/// Collector Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectorError {
+    /// Source failed to deliver samples
+    ReadFailed,
+    /// Repetition count test alarmed on the raw samples
+    HealthTestFailed,
+    /// Measured min-entropy rounds down to zero bits
+    InsufficientEntropy,
+    /// Fewer than `MIN_ENTROPY_SOURCES` collectors produced a contribution
+    InsufficientSources,
+}
+
+/// Pluggable entropy source
+pub trait EntropyCollector {
+    /// Source type recorded in contributions
+    fn source_type(&self) -> EntropySourceType;
+
+    /// Claimed min-entropy per byte sample (1..=8 bits)
+    ///
+    /// Caps the measured estimate and sets the health test cutoff.
+    fn claimed_min_entropy(&self) -> u8;
+
+    /// Fill `buf` with raw samples
+    fn fill(&mut self, buf: &mut [u8]) -> Result<(), CollectorError>;
+
+    /// Collect `samples` raw bytes as a contribution
+    ///
+    /// # Inputs
+    /// - `samples`: Number of byte samples to draw
+    ///
+    /// # Outputs
+    /// - Contribution credited with `min(measured, claimed) × samples` bits
+    fn collect(&mut self, samples: usize) -> Result<EntropyContribution, CollectorError> {
+        let claimed = self.claimed_min_entropy().clamp(1, 8);
+        let mut data = alloc::vec![0u8; samples];
+        let result = self.fill(&mut data).and_then(|_| {
+            let mut rct = RepetitionCountTest::new(claimed);
+            if data.iter().any(|&sample| rct.feed(sample)) {
+                return Err(CollectorError::HealthTestFailed);
+            }
+            let measured = min_entropy_estimate(&data) * samples as u64 / ENTROPY_FIXED_ONE;
+            match measured.min(claimed as u64 * samples as u64) {
+                0 => Err(CollectorError::InsufficientEntropy),
+                bits => Ok(bits.min(u32::MAX as u64) as u32),
+            }
+        });
+
+        match result {
+            Ok(bits) => Ok(EntropyContribution::new(self.source_type(), data, bits)),
+            Err(e) => {
+                data.zeroize();
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Most common value min-entropy estimate (SP 800-90B §6.3.1)
+///
+/// # Inputs
+/// - `samples`: Raw byte samples
+///
+/// # Outputs
+/// - Min-entropy per sample in 1/65536 bit (`ENTROPY_FIXED_ONE` = 1 bit),
+///   `-log2` of the 99% upper bound on the most common value's probability
+pub fn min_entropy_estimate(samples: &[u8]) -> u64 {
+    let n = samples.len() as u64;
+    if n < 2 {
+        return 0;
+    }
+
+    let mut counts = [0u64; 256];
+    for &sample in samples {
+        counts[sample as usize] += 1;
+    }
+    let c = counts.iter().copied().max().unwrap_or(n);
+
+    // Upper bound on the count: c + Z·sqrt(c(n - c) / (n - 1)), fixed point
+    let one = ENTROPY_FIXED_ONE as u128;
+    let variance = (c * (n - c)) as u128 * one * one / (n - 1) as u128;
+    let bound = (c as u128 * one + variance.isqrt() * Z_99_MILLI / 1000).min(n as u128 * one);
+
+    log2_ratio(n as u128 * one, bound).min(8 * ENTROPY_FIXED_ONE)
+}
+
+/// `log2(num / den)` in 1/65536 bit; zero when `num <= den`
+fn log2_ratio(num: u128, mut den: u128) -> u64 {
+    if den == 0 || num <= den {
+        return 0;
+    }
+
+    let mut integer = 0u64;
+    while num >= den << 1 {
+        den <<= 1;
+        integer += 1;
+    }
+
+    // Remaining ratio in [1, 2) as Q32; squaring shifts out one bit at a time
+    let mut ratio = (num << 32) / den;
+    let mut fraction = 0u64;
+    for bit in (0..16).rev() {
+        ratio = (ratio * ratio) >> 32;
+        if ratio >= 2 << 32 {
+            ratio >>= 1;
+            fraction |= 1 << bit;
+        }
+    }
+    (integer << 16) | fraction
+}
+
+/// x86 random number instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HardwareInstruction {
+    /// RDSEED: conditioned output of the on-die noise source
+    Rdseed,
+    /// RDRAND: DRBG output reseeded from the same noise source
+    Rdrand,
+}
+
+impl HardwareInstruction {
+    /// Claimed min-entropy per byte
+    ///
+    /// RDRAND expands each reseed across many outputs, so it is credited at
+    /// the 1-bit floor; RDSEED is full entropy.
+    pub fn claimed_min_entropy(self) -> u8 {
+        match self {
+            HardwareInstruction::Rdseed => 8,
+            HardwareInstruction::Rdrand => 1,
+        }
+    }
+}
+
+/// Hardware TRNG collector (x86_64 RDSEED / RDRAND)
+#[derive(Debug, Clone)]
+pub struct HardwareCollector {
+    instruction: HardwareInstruction,
+}
+
+impl HardwareCollector {
+    /// Probe the CPU, preferring RDSEED over RDRAND
+    ///
+    /// # Outputs
+    /// - `None` if neither instruction is available (or not x86_64)
+    pub fn detect() -> Option<Self> {
+        #[cfg(target_arch = "x86_64")]
+        {
+            use core::arch::x86_64::{__cpuid, __cpuid_count};
+
+            let max_leaf = __cpuid(0).eax;
+            if max_leaf >= 7 && __cpuid_count(7, 0).ebx & (1 << 18) != 0 {
+                return Some(Self { instruction: HardwareInstruction::Rdseed });
+            }
+            if __cpuid(1).ecx & (1 << 30) != 0 {
+                return Some(Self { instruction: HardwareInstruction::Rdrand });
+            }
+        }
+        None
+    }
+
+    /// Instruction in use
+    pub fn instruction(&self) -> HardwareInstruction {
+        self.instruction
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn next_word(&self) -> Option<u64> {
+        let retries = match self.instruction {
+            HardwareInstruction::Rdseed => RDSEED_RETRIES,
+            HardwareInstruction::Rdrand => RDRAND_RETRIES,
+        };
+        for _ in 0..retries {
+            let word = match self.instruction {
+                // SAFETY: `detect` only selects an instruction CPUID reports
+                HardwareInstruction::Rdseed => unsafe { rdseed_step() },
+                HardwareInstruction::Rdrand => unsafe { rdrand_step() },
+            };
+            if word.is_some() {
+                return word;
+            }
+            core::hint::spin_loop();
+        }
+        None
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    fn next_word(&self) -> Option<u64> {
+        None
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "rdseed")]
+fn rdseed_step() -> Option<u64> {
+    let mut word = 0u64;
+    let ok = core::arch::x86_64::_rdseed64_step(&mut word);
+    (ok == 1).then_some(word)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "rdrand")]
+fn rdrand_step() -> Option<u64> {
+    let mut word = 0u64;
+    let ok = core::arch::x86_64::_rdrand64_step(&mut word);
+    (ok == 1).then_some(word)
+}
+
+impl EntropyCollector for HardwareCollector {
+    fn source_type(&self) -> EntropySourceType {
+        EntropySourceType::Trng
+    }
+
+    fn claimed_min_entropy(&self) -> u8 {
+        self.instruction.claimed_min_entropy()
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) -> Result<(), CollectorError> {
+        for chunk in buf.chunks_mut(8) {
+            let word = self.next_word().ok_or(CollectorError::ReadFailed)?;
+            chunk.copy_from_slice(&word.to_le_bytes()[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+/// Operating system entropy collector (`getrandom`)
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct SystemCollector;
+
+#[cfg(feature = "std")]
+impl EntropyCollector for SystemCollector {
+    fn source_type(&self) -> EntropySourceType {
+        EntropySourceType::System
+    }
+
+    fn claimed_min_entropy(&self) -> u8 {
+        8
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) -> Result<(), CollectorError> {
+        getrandom::getrandom(buf).map_err(|_| CollectorError::ReadFailed)
+    }
+}
+
+/// CPU timing jitter collector
+///
+/// Times a memory-bound workload with a free-running counter (cycle
+/// counter, high-resolution timer) and keeps the low byte of each delta.
+/// Intended for embedded targets without a hardware TRNG or OS entropy.
+#[derive(Debug, Clone)]
+pub struct JitterCollector {
+    timer: fn() -> u64,
+    pool: [u64; 64],
+}
+
+impl JitterCollector {
+    /// Collector over the given counter
+    ///
+    /// # Inputs
+    /// - `timer`: Monotonic counter with sub-microsecond resolution
+    pub fn new(timer: fn() -> u64) -> Self {
+        Self { timer, pool: [0u64; 64] }
+    }
+
+    /// Workload whose memory access pattern depends on the last delta
+    fn churn(&mut self, delta: u64) {
+        let mut index = delta as usize;
+        for round in 0..self.pool.len() {
+            index = (index.wrapping_mul(31).wrapping_add(round)) % self.pool.len();
+            self.pool[index] = self.pool[index].rotate_left(7) ^ delta.wrapping_add(round as u64);
+        }
+    }
+}
+
+impl EntropyCollector for JitterCollector {
+    fn source_type(&self) -> EntropySourceType {
+        EntropySourceType::Trng
+    }
+
+    fn claimed_min_entropy(&self) -> u8 {
+        1
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) -> Result<(), CollectorError> {
+        let mut previous = (self.timer)();
+        for sample in buf.iter_mut() {
+            let now = (self.timer)();
+            let delta = now.wrapping_sub(previous);
+            previous = now;
+            self.churn(delta);
+            *sample = delta as u8;
+        }
+        Ok(())
+    }
+}
+
+/// Collectors available on this platform without configuration
+///
+/// Hardware TRNG when detected, then OS entropy with `std`. Callers on
+/// embedded targets add a `JitterCollector` over their cycle counter.
+pub fn available_collectors() -> Vec<Box<dyn EntropyCollector>> {
+    let mut collectors: Vec<Box<dyn EntropyCollector>> = Vec::new();
+    if let Some(hardware) = HardwareCollector::detect() {
+        collectors.push(Box::new(hardware));
+    }
+    #[cfg(feature = "std")]
+    collectors.push(Box::new(SystemCollector));
+    collectors
+}
+
+/// Collect one contribution per working collector
+///
+/// # Inputs
+/// - `collectors`: Collectors to draw from
+/// - `samples`: Byte samples per collector
+///
+/// # Outputs
+/// - Contributions for `EphemeralBiokey::derive_blended`; collectors that
+///   fail are skipped
+/// - `CollectorError::InsufficientSources` below `MIN_ENTROPY_SOURCES`
+pub fn collect_contributions(
+    collectors: &mut [Box<dyn EntropyCollector>],
+    samples: usize,
+) -> Result<Vec<EntropyContribution>, CollectorError> {
+    let contributions: Vec<EntropyContribution> = collectors
+        .iter_mut()
+        .filter_map(|collector| collector.collect(samples).ok())
+        .collect();
+    if contributions.len() < MIN_ENTROPY_SOURCES {
+        return Err(CollectorError::InsufficientSources);
+    }
+    Ok(contributions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::biokey::EphemeralBiokey;
+    use core::sync::atomic::{AtomicU64, Ordering};
+    use sha3::{Digest, Sha3_512};
+
+    /// Collector replaying fixed bytes
+    struct Fixed(EntropySourceType, Vec<u8>);
+
+    impl EntropyCollector for Fixed {
+        fn source_type(&self) -> EntropySourceType {
+            self.0
+        }
+
+        fn claimed_min_entropy(&self) -> u8 {
+            8
+        }
+
+        fn fill(&mut self, buf: &mut [u8]) -> Result<(), CollectorError> {
+            buf.copy_from_slice(&self.1[..buf.len()]);
+            Ok(())
+        }
+    }
+
+    fn uniform_bytes(len: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut counter = 0u64;
+        while out.len() < len {
+            out.extend_from_slice(&Sha3_512::digest(counter.to_le_bytes()));
+            counter += 1;
+        }
+        out.truncate(len);
+        out
+    }
+
+    static JITTERY: AtomicU64 = AtomicU64::new(0);
+
+    /// Counter advancing by hash-derived irregular steps
+    fn jittery_timer() -> u64 {
+        let tick = JITTERY.fetch_add(1, Ordering::Relaxed);
+        let step = Sha3_512::digest(tick.to_le_bytes())[0] as u64;
+        tick * 1000 + step
+    }
+
+    static STEADY: AtomicU64 = AtomicU64::new(0);
+
+    /// Counter advancing by exactly 100 per read
+    fn steady_timer() -> u64 {
+        STEADY.fetch_add(100, Ordering::Relaxed)
+    }
+
+    #[test]
+    fn test_min_entropy_estimate() {
+        assert_eq!(log2_ratio(8, 1), 3 * ENTROPY_FIXED_ONE);
+        assert_eq!(log2_ratio(3, 2), 38336); // log2(1.5) = 0.58496
+
+        // Uniform bytes approach 8 bits; the upper bound keeps them below
+        let uniform = min_entropy_estimate(&uniform_bytes(4096));
+        assert!(uniform > 6 * ENTROPY_FIXED_ONE && uniform < 8 * ENTROPY_FIXED_ONE);
+
+        // A constant source has none; a value seen half the time about 1 bit
+        assert_eq!(min_entropy_estimate(&[0x42; 256]), 0);
+        let mut biased = uniform_bytes(4096);
+        for sample in biased.iter_mut().step_by(2) {
+            *sample = 0x42;
+        }
+        let estimate = min_entropy_estimate(&biased);
+        assert!(estimate > ENTROPY_FIXED_ONE * 9 / 10 && estimate < ENTROPY_FIXED_ONE);
+    }
+
+    #[test]
+    fn test_collect_credits_measured_entropy() {
+        // Measured entropy caps a source claiming 8 bits per byte
+        let mut biased = uniform_bytes(512);
+        for sample in biased.iter_mut().step_by(2) {
+            *sample = 0x42;
+        }
+        let contribution = Fixed(EntropySourceType::Trng, biased).collect(512).unwrap();
+        assert!(contribution.entropy_bits < 512);
+
+        // Jitter is capped at its 1-bit claim; a steady counter trips the RCT
+        let contribution = JitterCollector::new(jittery_timer).collect(256).unwrap();
+        assert_eq!(contribution.entropy_bits, 256);
+        assert_eq!(contribution.source_type, EntropySourceType::Trng);
+        assert_eq!(
+            JitterCollector::new(steady_timer).collect(256).err(),
+            Some(CollectorError::HealthTestFailed)
+        );
+
+        if let Some(mut hardware) = HardwareCollector::detect() {
+            let contribution = hardware.collect(256).unwrap();
+            assert!(contribution.entropy_bits > 0);
+        }
+    }
+
+    #[test]
+    fn test_contributions_feed_derive_blended() {
+        let mut collectors: Vec<Box<dyn EntropyCollector>> = alloc::vec![
+            Box::new(Fixed(EntropySourceType::Trng, uniform_bytes(64))),
+            Box::new(Fixed(EntropySourceType::Genomic, alloc::vec![0u8; 64])),
+        ];
+
+        // The stuck source is dropped, leaving too few
+        assert_eq!(
+            collect_contributions(&mut collectors, 64).err(),
+            Some(CollectorError::InsufficientSources)
+        );
+
+        collectors.push(Box::new(JitterCollector::new(jittery_timer)));
+        let contributions = collect_contributions(&mut collectors, 64).unwrap();
+        assert_eq!(contributions.len(), 2);
+        let key = EphemeralBiokey::derive_blended(&contributions, 1, None).unwrap();
+        assert_eq!(key.entropy_sources(), &[EntropySourceType::Trng, EntropySourceType::Trng]);
+    }
+}
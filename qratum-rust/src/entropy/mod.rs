@@ -0,0 +1,562 @@
+//! # Entropy Module - Session Entropy Health Monitoring
+//!
+//! ## Lifecycle Stage: Ephemeral Materialization → Execution (continuous)
+//!
+//! Ephemeral keys are blended from several entropy sources (genomic, TRNG,
+//! device fingerprint, ...). This module runs the NIST SP 800-90B continuous
+//! health tests on every registered source for the lifetime of the session and
+//! folds their alarms into a single session health score.
+//!
+//! ## Architectural Role
+//!
+//! - **Repetition Count Test** (SP 800-90B §4.4.1): detects stuck sources
+//! - **Adaptive Proportion Test** (SP 800-90B §4.4.2): detects sources losing entropy
+//! - **Health Score**: 0-100 aggregate driving key rotation or session abort
+//! - **Abort TXO**: Signed `SessionAbort` TXO recording why the session ended
+//! - **Collectors** (`collector`): Hardware, OS and jitter sources producing
+//!   `EntropyContribution`s with measured min-entropy
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Raw byte samples per entropy source
+//! - Output: `HealthAction` (continue / rotate keys / abort) and abort reason TXO
+//!
+//! ## Security Rationale
+//!
+//! - Test cutoffs derived from the claimed min-entropy with α = 2^-20
+//! - Falling below the rotation threshold forces forward-secure key rotation
+//! - Falling below the abort threshold, or losing too many healthy sources,
+//!   ends the session rather than continuing on degraded key material
+//! - Abort reasons are MAC-signed with session key material so the surviving
+//!   TXO cannot be forged by an observer
+//!   (TODO: QRADLE post-quantum signature once available)
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::biokey::{EntropySourceType, MIN_ENTROPY_SOURCES};
+use crate::txo::{Txo, TxoType};
+use sha3::{Sha3_512, Digest};
+
+pub mod collector;
+
+/// False-positive exponent: tests alarm with probability 2^-20 on a healthy source
+pub const ALPHA_EXPONENT: u32 = 20;
+
+/// Adaptive proportion test window for non-binary (byte) samples
+pub const APT_WINDOW: u32 = 512;
+
+/// Domain separator for abort TXO signatures
+const ABORT_SIGNATURE_DOMAIN: &[u8] = b"QRATUM-ENTROPY-ABORT-v1";
+
+/// Health Monitor Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthError {
+    /// Claimed min-entropy per byte sample outside 1..=8 bits
+    InvalidMinEntropy,
+    /// Source already registered
+    DuplicateSource,
+    /// Source was never registered
+    UnknownSource,
+    /// Thresholds are not ordered `abort_below <= rotate_below <= 100`
+    InvalidThresholds,
+}
+
+/// Repetition count test cutoff: `1 + ceil(20 / H)`
+pub const fn rct_cutoff(min_entropy_bits: u8) -> u32 {
+    let h = min_entropy_bits as u32;
+    1 + ALPHA_EXPONENT.div_ceil(h)
+}
+
+/// Adaptive proportion test cutoff: `1 + CRITBINOM(W, 2^-H, 1 - 2^-20)`
+///
+/// # Inputs
+/// - `min_entropy_bits`: Claimed min-entropy per byte sample (1..=8)
+///
+/// # Outputs
+/// - Smallest count that a healthy source reaches with probability < 2^-20
+pub fn apt_cutoff(min_entropy_bits: u8) -> u32 {
+    let p = 1.0 / (1u32 << min_entropy_bits) as f64;
+    let target = 1.0 - 1.0 / (1u64 << ALPHA_EXPONENT) as f64;
+
+    // pmf(0) = (1 - p)^W
+    let mut pmf = 1.0f64;
+    for _ in 0..APT_WINDOW {
+        pmf *= 1.0 - p;
+    }
+
+    let mut cdf = pmf;
+    let mut k = 0u32;
+    while cdf < target && k < APT_WINDOW {
+        pmf *= (APT_WINDOW - k) as f64 / (k + 1) as f64 * p / (1.0 - p);
+        k += 1;
+        cdf += pmf;
+    }
+    1 + k
+}
+
+/// Repetition Count Test (SP 800-90B §4.4.1)
+///
+/// Alarms when the same sample repeats `cutoff` times in a row.
+#[derive(Debug, Clone)]
+pub struct RepetitionCountTest {
+    cutoff: u32,
+    last: Option<u8>,
+    run: u32,
+}
+
+impl RepetitionCountTest {
+    /// Create test for the claimed min-entropy per sample
+    pub fn new(min_entropy_bits: u8) -> Self {
+        Self {
+            cutoff: rct_cutoff(min_entropy_bits),
+            last: None,
+            run: 0,
+        }
+    }
+
+    /// Feed one sample; returns `true` on alarm
+    pub fn feed(&mut self, sample: u8) -> bool {
+        if self.last == Some(sample) {
+            self.run += 1;
+        } else {
+            self.last = Some(sample);
+            self.run = 1;
+        }
+
+        if self.run >= self.cutoff {
+            // Restart so a persistent fault keeps raising alarms
+            self.last = None;
+            self.run = 0;
+            return true;
+        }
+        false
+    }
+}
+
+/// Adaptive Proportion Test (SP 800-90B §4.4.2)
+///
+/// Alarms when the first sample of a window recurs `cutoff` times within it.
+#[derive(Debug, Clone)]
+pub struct AdaptiveProportionTest {
+    cutoff: u32,
+    reference: u8,
+    count: u32,
+    seen: u32,
+}
+
+impl AdaptiveProportionTest {
+    /// Create test for the claimed min-entropy per sample
+    pub fn new(min_entropy_bits: u8) -> Self {
+        Self {
+            cutoff: apt_cutoff(min_entropy_bits),
+            reference: 0,
+            count: 0,
+            seen: 0,
+        }
+    }
+
+    /// Feed one sample; returns `true` on alarm
+    pub fn feed(&mut self, sample: u8) -> bool {
+        if self.seen == 0 {
+            self.reference = sample;
+            self.count = 1;
+            self.seen = 1;
+            return false;
+        }
+
+        if sample == self.reference {
+            self.count += 1;
+        }
+        self.seen += 1;
+
+        let alarm = self.count >= self.cutoff;
+        if alarm || self.seen == APT_WINDOW {
+            self.seen = 0;
+        }
+        alarm
+    }
+}
+
+/// Per-source health state
+#[derive(Debug, Clone)]
+pub struct SourceHealth {
+    /// Monitored source
+    pub source: EntropySourceType,
+
+    /// Samples observed
+    pub samples: u64,
+
+    /// Repetition count test alarms
+    pub rct_alarms: u32,
+
+    /// Adaptive proportion test alarms
+    pub apt_alarms: u32,
+
+    rct: RepetitionCountTest,
+    apt: AdaptiveProportionTest,
+}
+
+impl SourceHealth {
+    /// Total alarms raised by this source
+    pub fn alarms(&self) -> u32 {
+        self.rct_alarms + self.apt_alarms
+    }
+
+    /// Source score: 100 minus `alarm_penalty` per alarm
+    pub fn score(&self, alarm_penalty: u8) -> u8 {
+        let penalty = self.alarms().saturating_mul(alarm_penalty as u32);
+        100u32.saturating_sub(penalty) as u8
+    }
+}
+
+/// Health Monitor Configuration
+///
+/// ## Lifecycle Stage: Initialization
+#[derive(Debug, Clone)]
+pub struct HealthConfig {
+    /// Score deducted from a source per test alarm
+    pub alarm_penalty: u8,
+
+    /// Session score below which keys are rotated
+    pub rotate_below: u8,
+
+    /// Session score below which the session aborts
+    pub abort_below: u8,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            alarm_penalty: 25,  // 4 alarms exhaust a source
+            rotate_below: 90,
+            abort_below: 60,
+        }
+    }
+}
+
+/// Action required by the current health score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthAction {
+    /// Sources healthy
+    Continue,
+    /// Rotate ephemeral keys, then call `note_rotation()`
+    RotateKeys,
+    /// Terminate the session and emit the abort TXO
+    Abort,
+}
+
+/// Session Entropy Health Monitor
+///
+/// ## Lifecycle Stage: Ephemeral Materialization → Execution
+///
+/// ## Security Rationale
+/// - Every registered source runs both SP 800-90B continuous tests
+/// - Session score is the mean source score; a single stuck source drags it
+///   down even though XOR blending would still mask it
+/// - Fewer than `MIN_ENTROPY_SOURCES` healthy sources forces an abort
+#[derive(Debug, Clone)]
+pub struct EntropyHealthMonitor {
+    config: HealthConfig,
+    sources: Vec<SourceHealth>,
+    rotated_at: Option<u8>,
+    rotations: u32,
+}
+
+impl EntropyHealthMonitor {
+    /// Create monitor with no registered sources
+    pub fn new(config: HealthConfig) -> Result<Self, HealthError> {
+        if config.abort_below > config.rotate_below || config.rotate_below > 100 {
+            return Err(HealthError::InvalidThresholds);
+        }
+        Ok(Self {
+            config,
+            sources: Vec::new(),
+            rotated_at: None,
+            rotations: 0,
+        })
+    }
+
+    /// Register a source with its claimed min-entropy per byte sample
+    ///
+    /// # Inputs
+    /// - `source`: Entropy source type
+    /// - `min_entropy_bits`: Claimed min-entropy per byte (1..=8)
+    pub fn register(&mut self, source: EntropySourceType, min_entropy_bits: u8) -> Result<(), HealthError> {
+        if !(1..=8).contains(&min_entropy_bits) {
+            return Err(HealthError::InvalidMinEntropy);
+        }
+        if self.sources.iter().any(|s| s.source == source) {
+            return Err(HealthError::DuplicateSource);
+        }
+        self.sources.push(SourceHealth {
+            source,
+            samples: 0,
+            rct_alarms: 0,
+            apt_alarms: 0,
+            rct: RepetitionCountTest::new(min_entropy_bits),
+            apt: AdaptiveProportionTest::new(min_entropy_bits),
+        });
+        Ok(())
+    }
+
+    /// Run continuous tests over raw samples from `source`
+    ///
+    /// # Outputs
+    /// - Number of alarms raised by these samples
+    pub fn observe(&mut self, source: EntropySourceType, samples: &[u8]) -> Result<u32, HealthError> {
+        let health = self
+            .sources
+            .iter_mut()
+            .find(|s| s.source == source)
+            .ok_or(HealthError::UnknownSource)?;
+
+        let before = health.alarms();
+        for &sample in samples {
+            if health.rct.feed(sample) {
+                health.rct_alarms += 1;
+            }
+            if health.apt.feed(sample) {
+                health.apt_alarms += 1;
+            }
+        }
+        health.samples += samples.len() as u64;
+        Ok(health.alarms() - before)
+    }
+
+    /// Per-source health
+    pub fn sources(&self) -> &[SourceHealth] {
+        &self.sources
+    }
+
+    /// Key rotations triggered so far
+    pub fn rotations(&self) -> u32 {
+        self.rotations
+    }
+
+    /// Session health score (0-100); 100 when no sources are registered
+    pub fn score(&self) -> u8 {
+        if self.sources.is_empty() {
+            return 100;
+        }
+        let total: u32 = self
+            .sources
+            .iter()
+            .map(|s| s.score(self.config.alarm_penalty) as u32)
+            .sum();
+        (total / self.sources.len() as u32) as u8
+    }
+
+    /// Sources that have not exhausted their score
+    pub fn healthy_sources(&self) -> usize {
+        self.sources
+            .iter()
+            .filter(|s| s.score(self.config.alarm_penalty) > 0)
+            .count()
+    }
+
+    /// Decide the action required by current health
+    ///
+    /// Rotation is requested once per score level: after `note_rotation()`
+    /// the monitor only asks again if the score drops further.
+    pub fn assess(&self) -> HealthAction {
+        let score = self.score();
+        let required = MIN_ENTROPY_SOURCES.min(self.sources.len());
+
+        if score < self.config.abort_below || self.healthy_sources() < required {
+            return HealthAction::Abort;
+        }
+        if score < self.config.rotate_below && self.rotated_at.is_none_or(|at| score < at) {
+            return HealthAction::RotateKeys;
+        }
+        HealthAction::Continue
+    }
+
+    /// Record that keys were rotated at the current score
+    pub fn note_rotation(&mut self) {
+        self.rotated_at = Some(self.score());
+        self.rotations += 1;
+    }
+
+    /// Build the signed abort reason TXO
+    ///
+    /// ## Lifecycle Stage: Execution → Self-Destruction
+    ///
+    /// # Inputs
+    /// - `session_id`: Aborting session
+    /// - `timestamp`: Abort time
+    /// - `key_material`: Session key used to MAC the TXO
+    ///
+    /// # Outputs
+    /// - `SessionAbort` TXO; payload is session id, score, rotations, then
+    ///   per source: type, samples, RCT alarms, APT alarms
+    pub fn abort_txo(&self, session_id: [u8; 32], timestamp: u64, key_material: &[u8; 64]) -> Txo {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&session_id);
+        payload.push(self.score());
+        payload.extend_from_slice(&self.rotations.to_le_bytes());
+        for s in &self.sources {
+            payload.push(s.source as u8);
+            payload.extend_from_slice(&s.samples.to_le_bytes());
+            payload.extend_from_slice(&s.rct_alarms.to_le_bytes());
+            payload.extend_from_slice(&s.apt_alarms.to_le_bytes());
+        }
+
+        let mut txo = Txo::new(TxoType::SessionAbort, timestamp, payload, Vec::new());
+        txo.signatures.push(abort_signature(&txo.id, key_material));
+        txo
+    }
+}
+
+/// MAC over an abort TXO id with session key material
+pub(crate) fn abort_signature(txo_id: &[u8; 32], key_material: &[u8; 64]) -> [u8; 64] {
+    let mut hasher = Sha3_512::new();
+    hasher.update(ABORT_SIGNATURE_DOMAIN);
+    hasher.update(key_material);
+    hasher.update(txo_id);
+    hasher.finalize().into()
+}
+
+/// Verify an abort TXO against the session key material
+pub fn verify_abort_txo(txo: &Txo, key_material: &[u8; 64]) -> bool {
+    txo.txo_type == TxoType::SessionAbort
+        && txo.signatures.first() == Some(&abort_signature(&txo.id, key_material))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic full-range byte stream (SHA3 counter mode)
+    fn healthy_bytes(seed: u8, len: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut counter = 0u64;
+        while out.len() < len {
+            let mut hasher = Sha3_512::new();
+            hasher.update([seed]);
+            hasher.update(counter.to_le_bytes());
+            out.extend_from_slice(&hasher.finalize());
+            counter += 1;
+        }
+        out.truncate(len);
+        out
+    }
+
+    fn monitor() -> EntropyHealthMonitor {
+        let mut m = EntropyHealthMonitor::new(HealthConfig::default()).unwrap();
+        m.register(EntropySourceType::Trng, 7).unwrap();
+        m.register(EntropySourceType::Genomic, 7).unwrap();
+        m.register(EntropySourceType::DeviceFingerprint, 7).unwrap();
+        m
+    }
+
+    #[test]
+    fn test_cutoffs_match_sp800_90b() {
+        // SP 800-90B Table 2, W = 512
+        assert_eq!(apt_cutoff(1), 311);
+        assert_eq!(apt_cutoff(2), 177);
+        assert_eq!(apt_cutoff(4), 62);
+        assert_eq!(apt_cutoff(8), 13);
+
+        assert_eq!(rct_cutoff(1), 21);
+        assert_eq!(rct_cutoff(8), 4);
+    }
+
+    #[test]
+    fn test_healthy_sources_continue() {
+        let mut m = monitor();
+        for (seed, source) in [
+            (1, EntropySourceType::Trng),
+            (2, EntropySourceType::Genomic),
+            (3, EntropySourceType::DeviceFingerprint),
+        ] {
+            assert_eq!(m.observe(source, &healthy_bytes(seed, 4096)), Ok(0));
+        }
+        assert_eq!(m.score(), 100);
+        assert_eq!(m.assess(), HealthAction::Continue);
+        assert_eq!(m.observe(EntropySourceType::System, &[0]), Err(HealthError::UnknownSource));
+    }
+
+    #[test]
+    fn test_stuck_source_rotates_then_aborts() {
+        let mut m = monitor();
+
+        // RCT cutoff for H=7 is 4: eight identical bytes raise two alarms
+        assert_eq!(m.observe(EntropySourceType::Trng, &[0xAA; 8]), Ok(2));
+        assert_eq!(m.score(), 83);
+        assert_eq!(m.assess(), HealthAction::RotateKeys);
+        m.note_rotation();
+        assert_eq!(m.assess(), HealthAction::Continue);
+
+        // Further degradation asks for another rotation
+        m.observe(EntropySourceType::Trng, &[0xAA; 4]).unwrap();
+        assert_eq!(m.score(), 75);
+        assert_eq!(m.assess(), HealthAction::RotateKeys);
+        m.note_rotation();
+
+        // Trng exhausted and Genomic degrading: score falls below abort threshold
+        m.observe(EntropySourceType::Trng, &[0xAA; 4]).unwrap();
+        m.observe(EntropySourceType::Genomic, &[0x55; 12]).unwrap();
+        assert!(m.score() < 60);
+        assert_eq!(m.assess(), HealthAction::Abort);
+        assert_eq!(m.rotations(), 2);
+    }
+
+    #[test]
+    fn test_biased_source_trips_adaptive_proportion() {
+        let mut m = monitor();
+
+        // Half the samples equal the window reference, but never 4 in a row
+        let mut biased = healthy_bytes(9, APT_WINDOW as usize);
+        for i in (0..biased.len()).step_by(2) {
+            biased[i] = 0x42;
+        }
+        m.observe(EntropySourceType::Genomic, &biased).unwrap();
+
+        let genomic = &m.sources()[1];
+        assert_eq!(genomic.rct_alarms, 0);
+        assert!(genomic.apt_alarms > 0);
+    }
+
+    #[test]
+    fn test_too_few_healthy_sources_abort() {
+        let config = HealthConfig { alarm_penalty: 100, rotate_below: 40, abort_below: 10 };
+        let mut m = EntropyHealthMonitor::new(config).unwrap();
+        m.register(EntropySourceType::Trng, 8).unwrap();
+        m.register(EntropySourceType::Genomic, 8).unwrap();
+
+        m.observe(EntropySourceType::Trng, &[0; 4]).unwrap();
+        assert_eq!(m.score(), 50);
+        assert_eq!(m.healthy_sources(), 1);
+        assert_eq!(m.assess(), HealthAction::Abort);
+    }
+
+    #[test]
+    fn test_registration_and_config_validation() {
+        let mut m = monitor();
+        assert_eq!(m.register(EntropySourceType::Trng, 7), Err(HealthError::DuplicateSource));
+        assert_eq!(m.register(EntropySourceType::System, 0), Err(HealthError::InvalidMinEntropy));
+        assert_eq!(m.register(EntropySourceType::System, 9), Err(HealthError::InvalidMinEntropy));
+
+        let inverted = HealthConfig { alarm_penalty: 25, rotate_below: 40, abort_below: 60 };
+        assert_eq!(EntropyHealthMonitor::new(inverted).err(), Some(HealthError::InvalidThresholds));
+    }
+
+    #[test]
+    fn test_abort_txo_signed() {
+        let mut m = monitor();
+        m.observe(EntropySourceType::Trng, &[0; 16]).unwrap();
+
+        let key = [7u8; 64];
+        let txo = m.abort_txo([1u8; 32], 1000, &key);
+        assert_eq!(txo.txo_type, TxoType::SessionAbort);
+        assert_eq!(txo.payload[32], m.score());
+        assert!(verify_abort_txo(&txo, &key));
+        assert!(!verify_abort_txo(&txo, &[8u8; 64]));
+
+        let mut tampered = txo.clone();
+        tampered.payload[32] = 100;
+        tampered.id = tampered.compute_id();
+        assert!(!verify_abort_txo(&tampered, &key));
+    }
+}
@@ -0,0 +1,390 @@
+//! # Epoch Module - Adaptive Epoch Scheduling
+//!
+//! ## Lifecycle Stage: Execution (epoch rotation)
+//!
+//! Watchdog rotation and reward distribution run on epochs. A fixed epoch
+//! length is either too short when consensus is slow (the set rotates before
+//! it has audited anything) or too long when the network is healthy (captured
+//! validators stay in place). The epoch schedule adapts the next epoch's
+//! length to the consensus latency and participation observed in the epoch
+//! that just closed, within governance-set bounds.
+//!
+//! ## Architectural Role
+//!
+//! - **Bounds**: Minimum/maximum length and latency/participation targets,
+//!   changed only through `ParameterChange` governance proposals
+//! - **Sizing**: Integer-only, so every node computes identical boundaries
+//! - **Commitment**: Each boundary extends a hash chain published as a
+//!   [`ZkStateTransition`]; followers recompute and compare instead of trusting
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: `NetworkObservation` per closed epoch
+//! - Output: `EpochBoundary` for the next epoch + schedule state transition
+//!
+//! ## Security Rationale
+//!
+//! - Bounds cap how far an adversary skewing latency can stretch or shrink epochs
+//! - Per-epoch change is damped to ±25%, so one bad observation cannot jump
+//!   straight to a bound
+//! - Boundaries are agreed by commitment, not by each node's local clock
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256};
+
+use crate::governance::{GovernanceProposal, ProposalType};
+use crate::zkstate::{StateCommitment, TransitionType, ZkStateTransition};
+
+/// Payload tag for epoch-bounds governance proposals
+pub const EPOCH_BOUNDS_TAG: &[u8; 12] = b"EPOCH-BOUNDS";
+
+/// Largest per-epoch change, in percent of the current length
+const MAX_STEP_PERCENT: u64 = 25;
+
+/// Governance-set limits and targets for epoch sizing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct EpochBounds {
+    /// Shortest allowed epoch (milliseconds)
+    #[n(0)]
+    pub min_duration_ms: u64,
+
+    /// Longest allowed epoch (milliseconds)
+    #[n(1)]
+    pub max_duration_ms: u64,
+
+    /// Consensus latency at which the length is left unchanged (milliseconds)
+    #[n(2)]
+    pub target_latency_ms: u64,
+
+    /// Participation (percent of voting power) below which epochs lengthen
+    #[n(3)]
+    pub target_participation: u8,
+}
+
+impl Default for EpochBounds {
+    fn default() -> Self {
+        Self {
+            min_duration_ms: 120_000,   // 2 minutes
+            max_duration_ms: 3_600_000, // 1 hour
+            target_latency_ms: 2_000,
+            target_participation: 80,
+        }
+    }
+}
+
+impl EpochBounds {
+    /// Check internal consistency
+    pub fn validate(&self) -> Result<(), EpochError> {
+        if self.min_duration_ms == 0
+            || self.min_duration_ms > self.max_duration_ms
+            || self.target_latency_ms == 0
+            || self.target_participation == 0
+            || self.target_participation > 100
+        {
+            return Err(EpochError::InvalidBounds);
+        }
+        Ok(())
+    }
+
+    /// Payload for a `ParameterChange` proposal setting these bounds
+    pub fn to_proposal_payload(&self) -> Vec<u8> {
+        let mut payload = Vec::from(&EPOCH_BOUNDS_TAG[..]);
+        payload.extend_from_slice(&minicbor::to_vec(self).unwrap_or_default());
+        payload
+    }
+
+    /// Decode bounds from an epoch-bounds governance proposal
+    pub fn from_proposal(proposal: &GovernanceProposal) -> Result<Self, EpochError> {
+        if proposal.proposal_type != ProposalType::ParameterChange {
+            return Err(EpochError::NotEpochProposal);
+        }
+        let body = proposal
+            .payload
+            .strip_prefix(&EPOCH_BOUNDS_TAG[..])
+            .ok_or(EpochError::NotEpochProposal)?;
+        let bounds: Self = minicbor::decode(body).map_err(|_| EpochError::InvalidBounds)?;
+        bounds.validate()?;
+        Ok(bounds)
+    }
+
+    fn clamp(&self, duration_ms: u64) -> u64 {
+        duration_ms.clamp(self.min_duration_ms, self.max_duration_ms)
+    }
+}
+
+/// Network conditions observed over one epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct NetworkObservation {
+    /// Median consensus round latency (milliseconds)
+    #[n(0)]
+    pub consensus_latency_ms: u64,
+
+    /// Voting power that participated (percent)
+    #[n(1)]
+    pub participation: u8,
+}
+
+/// Start and length of one epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct EpochBoundary {
+    /// Epoch number
+    #[n(0)]
+    pub epoch: u64,
+
+    /// Start time (milliseconds)
+    #[n(1)]
+    pub start_ms: u64,
+
+    /// Length (milliseconds)
+    #[n(2)]
+    pub duration_ms: u64,
+}
+
+impl EpochBoundary {
+    /// End time (exclusive, milliseconds)
+    pub fn end_ms(&self) -> u64 {
+        self.start_ms.saturating_add(self.duration_ms)
+    }
+}
+
+/// Epoch Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochError {
+    /// Bounds are inconsistent (min > max, zero targets, participation > 100)
+    InvalidBounds,
+    /// Proposal is not an epoch-bounds parameter change
+    NotEpochProposal,
+    /// Participation above 100 percent
+    InvalidObservation,
+    /// Published transition does not extend this schedule
+    CommitmentMismatch,
+}
+
+/// Compute the next epoch length
+///
+/// # Inputs
+/// - `bounds`: Governance bounds
+/// - `current_ms`: Length of the epoch that just closed
+/// - `observation`: Conditions observed during that epoch
+///
+/// # Outputs
+/// - Next length: scaled by latency / target latency and, below the
+///   participation target, by target / participation; damped to ±25% and
+///   clamped to the bounds
+pub fn next_duration(bounds: &EpochBounds, current_ms: u64, observation: &NetworkObservation) -> u64 {
+    let current = current_ms as u128;
+    let mut proposed = current * observation.consensus_latency_ms.max(1) as u128 / bounds.target_latency_ms as u128;
+    let participation = observation.participation.max(1);
+    if participation < bounds.target_participation {
+        proposed = proposed * bounds.target_participation as u128 / participation as u128;
+    }
+
+    let step = current * MAX_STEP_PERCENT as u128 / 100;
+    let damped = proposed.clamp(current.saturating_sub(step), current + step);
+    bounds.clamp(damped.min(u64::MAX as u128) as u64)
+}
+
+/// Adaptive epoch schedule with a committed boundary chain
+#[derive(Debug, Clone)]
+pub struct EpochSchedule {
+    bounds: EpochBounds,
+    current: EpochBoundary,
+    commitment: StateCommitment,
+}
+
+impl EpochSchedule {
+    /// Start a schedule at epoch 0
+    ///
+    /// # Inputs
+    /// - `bounds`: Initial governance bounds
+    /// - `genesis_ms`: Start of epoch 0
+    /// - `initial_duration_ms`: Length of epoch 0 (clamped to the bounds)
+    pub fn new(bounds: EpochBounds, genesis_ms: u64, initial_duration_ms: u64) -> Result<Self, EpochError> {
+        bounds.validate()?;
+        let current = EpochBoundary {
+            epoch: 0,
+            start_ms: genesis_ms,
+            duration_ms: bounds.clamp(initial_duration_ms),
+        };
+        let commitment = chain_commitment(&[0u8; 32], &bounds, &current);
+        Ok(Self { bounds, current, commitment })
+    }
+
+    /// Current bounds
+    pub fn bounds(&self) -> &EpochBounds {
+        &self.bounds
+    }
+
+    /// Current epoch boundary
+    pub fn current(&self) -> &EpochBoundary {
+        &self.current
+    }
+
+    /// Commitment to the schedule through the current epoch
+    pub fn commitment(&self) -> StateCommitment {
+        self.commitment
+    }
+
+    /// Whether `now_ms` is past the current epoch
+    pub fn boundary_reached(&self, now_ms: u64) -> bool {
+        now_ms >= self.current.end_ms()
+    }
+
+    /// Adopt bounds from an executed governance proposal
+    ///
+    /// Takes effect at the next boundary; the current epoch keeps its length.
+    pub fn apply_governance(&mut self, proposal: &GovernanceProposal) -> Result<(), EpochError> {
+        self.bounds = EpochBounds::from_proposal(proposal)?;
+        Ok(())
+    }
+
+    /// Close the current epoch and open the next
+    ///
+    /// # Inputs
+    /// - `observation`: Conditions observed during the closing epoch
+    ///
+    /// # Outputs
+    /// - Schedule transition (`prev`/`next` commitments) to publish via zkstate
+    pub fn advance(&mut self, observation: &NetworkObservation) -> Result<ZkStateTransition, EpochError> {
+        if observation.participation > 100 {
+            return Err(EpochError::InvalidObservation);
+        }
+        let prev = self.commitment;
+        self.current = EpochBoundary {
+            epoch: self.current.epoch + 1,
+            start_ms: self.current.end_ms(),
+            duration_ms: next_duration(&self.bounds, self.current.duration_ms, observation),
+        };
+        self.commitment = chain_commitment(&prev, &self.bounds, &self.current);
+
+        let proof = minicbor::to_vec((&self.current, observation)).unwrap_or_default();
+        Ok(ZkStateTransition::new(prev, self.commitment, proof, self.current.epoch, TransitionType::EpochSchedule))
+    }
+
+    /// Follow a schedule transition published by another node
+    ///
+    /// Recomputes the boundary from the same observation and rejects the
+    /// transition unless both commitments match.
+    pub fn follow(
+        &mut self,
+        observation: &NetworkObservation,
+        transition: &ZkStateTransition,
+    ) -> Result<EpochBoundary, EpochError> {
+        if transition.transition_type != TransitionType::EpochSchedule || transition.prev != self.commitment {
+            return Err(EpochError::CommitmentMismatch);
+        }
+        let mut candidate = self.clone();
+        let local = candidate.advance(observation)?;
+        if local.next != transition.next {
+            return Err(EpochError::CommitmentMismatch);
+        }
+        *self = candidate;
+        Ok(self.current)
+    }
+}
+
+/// Extend the schedule hash chain with `boundary` under `bounds`
+fn chain_commitment(prev: &StateCommitment, bounds: &EpochBounds, boundary: &EpochBoundary) -> StateCommitment {
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"QRATUM-EPOCH-SCHEDULE-v1");
+    hasher.update(prev);
+    hasher.update(bounds.min_duration_ms.to_le_bytes());
+    hasher.update(bounds.max_duration_ms.to_le_bytes());
+    hasher.update(bounds.target_latency_ms.to_le_bytes());
+    hasher.update([bounds.target_participation]);
+    hasher.update(boundary.epoch.to_le_bytes());
+    hasher.update(boundary.start_ms.to_le_bytes());
+    hasher.update(boundary.duration_ms.to_le_bytes());
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observe(consensus_latency_ms: u64, participation: u8) -> NetworkObservation {
+        NetworkObservation { consensus_latency_ms, participation }
+    }
+
+    fn bounds_proposal(bounds: &EpochBounds) -> GovernanceProposal {
+        GovernanceProposal {
+            id: [1u8; 32],
+            proposal_type: ProposalType::ParameterChange,
+            proposer: [2u8; 32],
+            description: "Epoch bounds".into(),
+            payload: bounds.to_proposal_payload(),
+            threshold: 67,
+            voting_period: 10,
+            timelock: 5,
+            creation_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_next_duration_tracks_conditions() {
+        let bounds = EpochBounds::default(); // 2 min - 1 h, 2 s target latency, 80% participation
+
+        // On target: unchanged
+        assert_eq!(next_duration(&bounds, 600_000, &observe(2_000, 90)), 600_000);
+        // Slow consensus lengthens, damped to +25%
+        assert_eq!(next_duration(&bounds, 600_000, &observe(2_200, 90)), 660_000);
+        assert_eq!(next_duration(&bounds, 600_000, &observe(10_000, 90)), 750_000);
+        // Fast consensus shortens, damped to -25%
+        assert_eq!(next_duration(&bounds, 600_000, &observe(1_000, 90)), 450_000);
+        // Low participation lengthens
+        assert_eq!(next_duration(&bounds, 600_000, &observe(2_000, 75)), 640_000);
+        // Bounds always win
+        assert_eq!(next_duration(&bounds, 130_000, &observe(1, 100)), 120_000);
+        assert_eq!(next_duration(&bounds, 3_500_000, &observe(60_000, 1)), 3_600_000);
+    }
+
+    #[test]
+    fn test_schedule_commitment_is_deterministic() {
+        let bounds = EpochBounds::default();
+        let mut leader = EpochSchedule::new(bounds, 1_000, 600_000).unwrap();
+        let mut follower = EpochSchedule::new(bounds, 1_000, 600_000).unwrap();
+        assert_eq!(leader.commitment(), follower.commitment());
+        assert!(!leader.boundary_reached(600_999));
+        assert!(leader.boundary_reached(601_000));
+
+        let observation = observe(3_000, 90);
+        let transition = leader.advance(&observation).unwrap();
+        assert_eq!(transition.prev, follower.commitment());
+        assert_eq!(transition.transition_type, TransitionType::EpochSchedule);
+
+        // A follower that saw different conditions cannot accept the boundary
+        let mut diverged = follower.clone();
+        assert_eq!(
+            diverged.follow(&observe(2_000, 90), &transition),
+            Err(EpochError::CommitmentMismatch)
+        );
+        assert_eq!(diverged.current().epoch, 0);
+
+        let boundary = follower.follow(&observation, &transition).unwrap();
+        assert_eq!(boundary, EpochBoundary { epoch: 1, start_ms: 601_000, duration_ms: 750_000 });
+        assert_eq!(follower.commitment(), leader.commitment());
+        // Replaying the same transition does not extend the chain twice
+        assert_eq!(follower.follow(&observation, &transition), Err(EpochError::CommitmentMismatch));
+    }
+
+    #[test]
+    fn test_governance_sets_bounds() {
+        let mut schedule = EpochSchedule::new(EpochBounds::default(), 0, 600_000).unwrap();
+        let tight = EpochBounds { min_duration_ms: 300_000, max_duration_ms: 500_000, ..EpochBounds::default() };
+        schedule.apply_governance(&bounds_proposal(&tight)).unwrap();
+        assert_eq!(schedule.bounds(), &tight);
+        assert_eq!(schedule.current().duration_ms, 600_000);
+        schedule.advance(&observe(2_000, 100)).unwrap();
+        assert_eq!(schedule.current().duration_ms, 500_000);
+
+        let inverted = EpochBounds { min_duration_ms: 2, max_duration_ms: 1, ..EpochBounds::default() };
+        assert_eq!(schedule.apply_governance(&bounds_proposal(&inverted)), Err(EpochError::InvalidBounds));
+        let mut upgrade = bounds_proposal(&tight);
+        upgrade.proposal_type = ProposalType::ProtocolUpgrade;
+        assert_eq!(schedule.apply_governance(&upgrade), Err(EpochError::NotEpochProposal));
+        assert_eq!(schedule.advance(&observe(2_000, 101)).err(), Some(EpochError::InvalidObservation));
+    }
+}
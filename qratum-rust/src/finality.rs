@@ -0,0 +1,725 @@
+//! # Finality Module - Finality Certificates and Light-Client Headers
+//!
+//! ## Lifecycle Stage: Finalization
+//!
+//! External systems need a yes/no answer to "is this TXO final?" that does
+//! not require trusting the node they ask. The finality gadget groups
+//! finalized TXOs into batches, commits each batch in a compact header, and
+//! collects sync-committee signatures over the header. A header signed by
+//! the committee's threshold of voting power becomes a finality certificate.
+//!
+//! ## Architectural Role
+//!
+//! - **Headers**: Height, parent hash, Merkle root of the batch's TXO ids,
+//!   current and next sync-committee roots
+//! - **Sync Committees**: Validator keys and voting power for a period;
+//!   rotation is announced one header ahead via `next_committee_root`
+//! - **Certificates**: Header plus committee signatures (via [`BeaconSigner`])
+//! - **Light Clients**: Follow the header chain from a trusted checkpoint,
+//!   checking signatures only, and verify TXO inclusion proofs
+//! - **Waiting**: `wait_for_finality` returns a handle polled with the
+//!   caller's clock until the TXO is final or the timeout passes
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Finalized `TxoCommit`s + committee signatures
+//! - Output: `FinalityCertificate` chain and per-TXO `FinalityProof`s (CBOR)
+//!
+//! ## Security Rationale
+//!
+//! - Certificates are hash-chained; a fork needs a second threshold of signatures
+//! - Committee hand-over is itself certified by the outgoing committee
+//! - Batch roots use the same Merkle construction as the session ledger
+//! - Waiting never blocks: no_std hosts drive consensus and supply time
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256};
+
+use crate::consensus::{TxoCommit, ValidatorID, ValidatorRegistry, ValidatorStatus};
+use crate::ledger::{build_levels, proof_from_levels, MerkleProof};
+use crate::watchdog::{BeaconSigner, BeaconVerifier};
+
+/// Sync committee member
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct CommitteeMember {
+    /// Validator ID
+    #[n(0)]
+    pub validator_id: ValidatorID,
+
+    /// Key verifying the member's header signatures
+    #[n(1)]
+    pub public_key: [u8; 32],
+
+    /// Voting power
+    #[n(2)]
+    pub voting_power: u64,
+}
+
+/// Validators signing headers for one period
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SyncCommittee {
+    /// Committee period
+    #[n(0)]
+    pub period: u64,
+
+    /// Members
+    #[n(1)]
+    pub members: Vec<CommitteeMember>,
+}
+
+impl SyncCommittee {
+    /// Committee of the registry's active validators
+    pub fn from_registry(registry: &ValidatorRegistry, period: u64) -> Self {
+        let members = registry
+            .validators
+            .iter()
+            .filter(|(_, info)| info.status == ValidatorStatus::Active)
+            .map(|(id, info)| CommitteeMember {
+                validator_id: *id,
+                public_key: info.public_key,
+                voting_power: info.voting_power,
+            })
+            .collect();
+        Self { period, members }
+    }
+
+    /// Commitment recorded in headers
+    pub fn root(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"QRATUM-SYNC-COMMITTEE-v1");
+        hasher.update(self.period.to_le_bytes());
+        hasher.update((self.members.len() as u64).to_le_bytes());
+        for member in &self.members {
+            hasher.update(member.validator_id);
+            hasher.update(member.public_key);
+            hasher.update(member.voting_power.to_le_bytes());
+        }
+        hasher.finalize().into()
+    }
+
+    /// Total voting power
+    pub fn total_power(&self) -> u64 {
+        self.members.iter().map(|m| m.voting_power).sum()
+    }
+}
+
+/// Compact header committing to one finalized batch
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct FinalityHeader {
+    /// Batch height (0 for the first batch)
+    #[n(0)]
+    pub height: u64,
+
+    /// Hash of the previous header (zero for height 0)
+    #[n(1)]
+    pub parent_hash: [u8; 32],
+
+    /// Merkle root over the batch's TXO ids
+    #[n(2)]
+    pub batch_root: [u8; 32],
+
+    /// Number of TXOs in the batch
+    #[n(3)]
+    pub txo_count: u32,
+
+    /// Root of the committee signing this header
+    #[n(4)]
+    pub committee_root: [u8; 32],
+
+    /// Root of the committee signing the next header
+    #[n(5)]
+    pub next_committee_root: [u8; 32],
+
+    /// Seal time (milliseconds)
+    #[n(6)]
+    pub timestamp: u64,
+}
+
+impl FinalityHeader {
+    /// Header hash (signed content)
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"QRATUM-FINALITY-HEADER-v1");
+        hasher.update(self.height.to_le_bytes());
+        hasher.update(self.parent_hash);
+        hasher.update(self.batch_root);
+        hasher.update(self.txo_count.to_le_bytes());
+        hasher.update(self.committee_root);
+        hasher.update(self.next_committee_root);
+        hasher.update(self.timestamp.to_le_bytes());
+        hasher.finalize().into()
+    }
+}
+
+/// Committee member's signature over a header hash
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct HeaderSignature {
+    /// Signing member
+    #[n(0)]
+    pub validator_id: ValidatorID,
+
+    /// Signature over `FinalityHeader::hash`
+    #[n(1)]
+    pub signature: [u8; 64],
+}
+
+/// Header signed by a threshold of its committee
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct FinalityCertificate {
+    /// Certified header
+    #[n(0)]
+    pub header: FinalityHeader,
+
+    /// Committee signatures
+    #[n(1)]
+    pub signatures: Vec<HeaderSignature>,
+}
+
+impl FinalityCertificate {
+    /// Verify the signatures against the header's committee
+    ///
+    /// # Outputs
+    /// - Voting power of distinct valid signers, if it meets `threshold` percent
+    pub fn verify(
+        &self,
+        committee: &SyncCommittee,
+        threshold: u8,
+        verifier: &dyn BeaconVerifier,
+    ) -> Result<u64, FinalityError> {
+        if self.header.committee_root != committee.root() {
+            return Err(FinalityError::CommitteeMismatch);
+        }
+        let message = self.header.hash();
+        let mut signers: Vec<ValidatorID> = Vec::new();
+        let mut signed = 0u64;
+        for sig in &self.signatures {
+            let member = committee
+                .members
+                .iter()
+                .find(|m| m.validator_id == sig.validator_id)
+                .ok_or(FinalityError::UnknownSigner(sig.validator_id))?;
+            if !verifier.verify(&member.public_key, &message, &sig.signature) {
+                return Err(FinalityError::InvalidSignature(sig.validator_id));
+            }
+            if !signers.contains(&sig.validator_id) {
+                signers.push(sig.validator_id);
+                signed += member.voting_power;
+            }
+        }
+
+        let required = required_power(committee.total_power(), threshold);
+        if signed == 0 || signed < required {
+            return Err(FinalityError::InsufficientPower { signed, required });
+        }
+        Ok(signed)
+    }
+
+    /// Serialize to CBOR
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserialize from CBOR
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, minicbor::decode::Error> {
+        minicbor::decode(bytes)
+    }
+}
+
+/// Everything a light client needs to accept one TXO as final
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct FinalityProof {
+    /// TXO id
+    #[n(0)]
+    pub txo_id: [u8; 32],
+
+    /// Certificate of the batch containing the TXO
+    #[n(1)]
+    pub certificate: FinalityCertificate,
+
+    /// Path from the TXO id to the certified batch root
+    #[n(2)]
+    pub inclusion: MerkleProof,
+}
+
+/// Finality status of a TXO
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinalityStatus {
+    /// Not in any sealed batch
+    Unknown,
+    /// In the sealed batch awaiting signatures
+    Pending,
+    /// Certified at this height
+    Final(u64),
+}
+
+/// Finality Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinalityError {
+    /// Batch contains no TXOs
+    EmptyBatch,
+    /// A sealed batch is still collecting signatures
+    HeaderPending,
+    /// No sealed batch is collecting signatures
+    NoPendingHeader,
+    /// Signer is not a committee member
+    UnknownSigner(ValidatorID),
+    /// Signature does not verify
+    InvalidSignature(ValidatorID),
+    /// Signers hold too little of the committee's voting power
+    InsufficientPower {
+        /// Voting power of distinct valid signers
+        signed: u64,
+        /// Voting power required
+        required: u64,
+    },
+    /// Header height does not follow the trusted header
+    HeightGap,
+    /// Header parent hash does not match the trusted header
+    BrokenChain,
+    /// Committee does not match the root committed in the header chain
+    CommitteeMismatch,
+    /// Inclusion proof does not lead to the certified batch root
+    InvalidProof,
+    /// TXO did not become final before the deadline
+    Timeout,
+}
+
+/// Pending `wait_for_finality` request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FinalityWait {
+    /// TXO being waited on
+    pub txo_id: [u8; 32],
+    /// Deadline (milliseconds)
+    pub deadline_ms: u64,
+}
+
+/// Finality gadget: seals batches and certifies headers
+pub struct FinalityGadget {
+    committee: SyncCommittee,
+    next_committee: Option<SyncCommittee>,
+    threshold: u8,
+    certificates: Vec<FinalityCertificate>,
+    batches: Vec<Vec<[u8; 32]>>,
+    pending: Option<(FinalityCertificate, Vec<[u8; 32]>)>,
+    finalized: BTreeMap<[u8; 32], u64>,
+}
+
+impl FinalityGadget {
+    /// Create a gadget for an initial committee
+    ///
+    /// # Inputs
+    /// - `committee`: Committee signing the first header
+    /// - `threshold`: Percentage of committee voting power required (67 = 2/3)
+    pub fn new(committee: SyncCommittee, threshold: u8) -> Self {
+        Self {
+            committee,
+            next_committee: None,
+            threshold,
+            certificates: Vec::new(),
+            batches: Vec::new(),
+            pending: None,
+            finalized: BTreeMap::new(),
+        }
+    }
+
+    /// Committee signing the next header
+    pub fn committee(&self) -> &SyncCommittee {
+        &self.committee
+    }
+
+    /// Hand signing over to `next` after the next certified header
+    pub fn schedule_rotation(&mut self, next: SyncCommittee) {
+        self.next_committee = Some(next);
+    }
+
+    /// Seal finalized commits into the next header
+    ///
+    /// ## Lifecycle Stage: Finalization
+    ///
+    /// # Outputs
+    /// - Header for committee members to sign
+    pub fn seal_batch(&mut self, commits: &[TxoCommit], timestamp: u64) -> Result<FinalityHeader, FinalityError> {
+        if self.pending.is_some() {
+            return Err(FinalityError::HeaderPending);
+        }
+        if commits.is_empty() {
+            return Err(FinalityError::EmptyBatch);
+        }
+        let ids: Vec<[u8; 32]> = commits.iter().map(|c| c.txo.id).collect();
+        let committee_root = self.committee.root();
+        let header = FinalityHeader {
+            height: self.certificates.len() as u64,
+            parent_hash: self.certificates.last().map_or([0u8; 32], |c| c.header.hash()),
+            batch_root: merkle_root(&ids),
+            txo_count: ids.len() as u32,
+            committee_root,
+            next_committee_root: self.next_committee.as_ref().map_or(committee_root, |c| c.root()),
+            timestamp,
+        };
+        let certificate = FinalityCertificate { header: header.clone(), signatures: Vec::new() };
+        self.pending = Some((certificate, ids));
+        Ok(header)
+    }
+
+    /// Sign the pending header as a committee member
+    pub fn sign_pending(
+        &mut self,
+        validator_id: ValidatorID,
+        signer: &dyn BeaconSigner,
+        verifier: &dyn BeaconVerifier,
+    ) -> Result<Option<&FinalityCertificate>, FinalityError> {
+        let message = match &self.pending {
+            Some((certificate, _)) => certificate.header.hash(),
+            None => return Err(FinalityError::NoPendingHeader),
+        };
+        self.add_signature(validator_id, signer.sign(&message), verifier)
+    }
+
+    /// Add a committee signature to the pending header
+    ///
+    /// # Outputs
+    /// - `Ok(Some(certificate))` once the threshold is reached
+    /// - `Ok(None)` while more signatures are needed
+    pub fn add_signature(
+        &mut self,
+        validator_id: ValidatorID,
+        signature: [u8; 64],
+        verifier: &dyn BeaconVerifier,
+    ) -> Result<Option<&FinalityCertificate>, FinalityError> {
+        let (certificate, _) = self.pending.as_mut().ok_or(FinalityError::NoPendingHeader)?;
+        let member = self
+            .committee
+            .members
+            .iter()
+            .find(|m| m.validator_id == validator_id)
+            .ok_or(FinalityError::UnknownSigner(validator_id))?;
+        if !verifier.verify(&member.public_key, &certificate.header.hash(), &signature) {
+            return Err(FinalityError::InvalidSignature(validator_id));
+        }
+        if !certificate.signatures.iter().any(|s| s.validator_id == validator_id) {
+            certificate.signatures.push(HeaderSignature { validator_id, signature });
+        }
+
+        match certificate.verify(&self.committee, self.threshold, verifier) {
+            Ok(_) => {}
+            Err(FinalityError::InsufficientPower { .. }) => return Ok(None),
+            Err(e) => return Err(e),
+        }
+
+        let (certificate, ids) = self.pending.take().ok_or(FinalityError::NoPendingHeader)?;
+        let height = certificate.header.height;
+        for id in &ids {
+            self.finalized.insert(*id, height);
+        }
+        if let Some(next) = self.next_committee.take() {
+            self.committee = next;
+        }
+        self.certificates.push(certificate);
+        self.batches.push(ids);
+        Ok(self.certificates.last())
+    }
+
+    /// Finality status of a TXO
+    pub fn status(&self, txo_id: &[u8; 32]) -> FinalityStatus {
+        if let Some(height) = self.finalized.get(txo_id) {
+            return FinalityStatus::Final(*height);
+        }
+        match &self.pending {
+            Some((_, ids)) if ids.contains(txo_id) => FinalityStatus::Pending,
+            _ => FinalityStatus::Unknown,
+        }
+    }
+
+    /// Certificate at `height`
+    pub fn certificate(&self, height: u64) -> Option<&FinalityCertificate> {
+        self.certificates.get(height as usize)
+    }
+
+    /// Certified headers after `height`, for light-client sync
+    pub fn headers_since(&self, height: Option<u64>) -> &[FinalityCertificate] {
+        let start = height.map_or(0, |h| (h as usize + 1).min(self.certificates.len()));
+        &self.certificates[start..]
+    }
+
+    /// Certificate and inclusion proof for a final TXO
+    pub fn finality_proof(&self, txo_id: &[u8; 32]) -> Option<FinalityProof> {
+        let height = *self.finalized.get(txo_id)? as usize;
+        let ids = &self.batches[height];
+        let index = ids.iter().position(|id| id == txo_id)?;
+        Some(FinalityProof {
+            txo_id: *txo_id,
+            certificate: self.certificates[height].clone(),
+            inclusion: proof_from_levels(&build_levels(ids.clone(), None), index),
+        })
+    }
+
+    /// Start waiting for a TXO to become final
+    ///
+    /// # Inputs
+    /// - `txo_id`: TXO to wait on
+    /// - `timeout_ms`: Maximum wait
+    /// - `now_ms`: Current time (see [`crate::clock::now_ms`])
+    pub fn wait_for_finality(&self, txo_id: [u8; 32], timeout_ms: u64, now_ms: u64) -> FinalityWait {
+        FinalityWait { txo_id, deadline_ms: now_ms.saturating_add(timeout_ms) }
+    }
+
+    /// Poll a wait started with `wait_for_finality`
+    ///
+    /// # Outputs
+    /// - `Ok(Some(proof))` once the TXO is final
+    /// - `Ok(None)` while it is not final and the deadline has not passed
+    /// - `Err(FinalityError::Timeout)` after the deadline
+    pub fn poll_finality(&self, wait: &FinalityWait, now_ms: u64) -> Result<Option<FinalityProof>, FinalityError> {
+        if let Some(proof) = self.finality_proof(&wait.txo_id) {
+            return Ok(Some(proof));
+        }
+        if now_ms >= wait.deadline_ms {
+            return Err(FinalityError::Timeout);
+        }
+        Ok(None)
+    }
+}
+
+/// Light client following certified headers from a trusted checkpoint
+#[derive(Debug, Clone)]
+pub struct LightClient {
+    header: Option<FinalityHeader>,
+    committee: SyncCommittee,
+    threshold: u8,
+    trusted: BTreeMap<u64, [u8; 32]>,
+}
+
+impl LightClient {
+    /// Start before height 0, trusting the genesis committee
+    pub fn from_genesis(committee: SyncCommittee, threshold: u8) -> Self {
+        Self { header: None, committee, threshold, trusted: BTreeMap::new() }
+    }
+
+    /// Start from a trusted header and the committee signing its successor
+    pub fn from_checkpoint(
+        checkpoint: FinalityHeader,
+        committee: SyncCommittee,
+        threshold: u8,
+    ) -> Result<Self, FinalityError> {
+        if checkpoint.next_committee_root != committee.root() {
+            return Err(FinalityError::CommitteeMismatch);
+        }
+        let mut trusted = BTreeMap::new();
+        trusted.insert(checkpoint.height, checkpoint.hash());
+        Ok(Self { header: Some(checkpoint), committee, threshold, trusted })
+    }
+
+    /// Latest trusted header
+    pub fn head(&self) -> Option<&FinalityHeader> {
+        self.header.as_ref()
+    }
+
+    /// Advance by one certified header
+    ///
+    /// # Inputs
+    /// - `certificate`: Certificate for the next height
+    /// - `next_committee`: Required when the header announces a rotation
+    /// - `verifier`: Signature verification backend
+    pub fn apply(
+        &mut self,
+        certificate: &FinalityCertificate,
+        next_committee: Option<&SyncCommittee>,
+        verifier: &dyn BeaconVerifier,
+    ) -> Result<(), FinalityError> {
+        let header = &certificate.header;
+        let (height, parent_hash) = self.header.as_ref().map_or((0, [0u8; 32]), |h| (h.height + 1, h.hash()));
+        if header.height != height {
+            return Err(FinalityError::HeightGap);
+        }
+        if header.parent_hash != parent_hash {
+            return Err(FinalityError::BrokenChain);
+        }
+        certificate.verify(&self.committee, self.threshold, verifier)?;
+
+        if header.next_committee_root != header.committee_root {
+            let next = next_committee.ok_or(FinalityError::CommitteeMismatch)?;
+            if next.root() != header.next_committee_root {
+                return Err(FinalityError::CommitteeMismatch);
+            }
+            self.committee = next.clone();
+        }
+        self.trusted.insert(header.height, header.hash());
+        self.header = Some(header.clone());
+        Ok(())
+    }
+
+    /// Check a finality proof against the synced header chain
+    ///
+    /// The proof's header must be one this client has already accepted.
+    pub fn verify_proof(&self, proof: &FinalityProof) -> Result<u64, FinalityError> {
+        let header = &proof.certificate.header;
+        match self.trusted.get(&header.height) {
+            Some(hash) if *hash == header.hash() => {}
+            Some(_) => return Err(FinalityError::BrokenChain),
+            None => return Err(FinalityError::HeightGap),
+        }
+        if !proof.inclusion.verify(&header.batch_root, &proof.txo_id) {
+            return Err(FinalityError::InvalidProof);
+        }
+        Ok(header.height)
+    }
+}
+
+/// Voting power needed for `threshold` percent of `total`
+fn required_power(total: u64, threshold: u8) -> u64 {
+    (total as u128 * threshold as u128).div_ceil(100) as u64
+}
+
+/// Merkle root over TXO ids (session-ledger construction)
+fn merkle_root(ids: &[[u8; 32]]) -> [u8; 32] {
+    build_levels(ids.to_vec(), None).last().map_or([0u8; 32], |top| top[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_signer::{TestKey, TestVerifier};
+    use crate::txo::{Txo, TxoType};
+
+    fn committee(period: u64, ids: &[u8]) -> SyncCommittee {
+        let members = ids
+            .iter()
+            .map(|&i| CommitteeMember { validator_id: [i; 32], public_key: [i + 100; 32], voting_power: 10 })
+            .collect();
+        SyncCommittee { period, members }
+    }
+
+    fn commits(seed: u8, n: u8) -> Vec<TxoCommit> {
+        (0..n)
+            .map(|i| TxoCommit {
+                txo: Txo::new(TxoType::Outcome, i as u64, vec![seed, i], Vec::new()),
+                proposal_id: [i; 32],
+                height: 0,
+                signatures: Vec::new(),
+            })
+            .collect()
+    }
+
+    fn sign(gadget: &mut FinalityGadget, ids: &[u8]) -> Option<FinalityCertificate> {
+        let mut result = None;
+        for &i in ids {
+            result = gadget.sign_pending([i; 32], &TestKey([i + 100; 32]), &TestVerifier).unwrap().cloned();
+        }
+        result
+    }
+
+    #[test]
+    fn test_certificate_requires_threshold() {
+        let mut gadget = FinalityGadget::new(committee(0, &[1, 2, 3]), 67);
+        let batch = commits(1, 5);
+        let header = gadget.seal_batch(&batch, 1_000).unwrap();
+        assert_eq!(header.txo_count, 5);
+        assert_eq!(gadget.seal_batch(&batch, 1_001), Err(FinalityError::HeaderPending));
+        assert_eq!(gadget.status(&batch[0].txo.id), FinalityStatus::Pending);
+
+        // One of three is not enough; a forged signature is rejected
+        assert!(sign(&mut gadget, &[1]).is_none());
+        assert_eq!(
+            gadget.add_signature([2u8; 32], [0u8; 64], &TestVerifier).err(),
+            Some(FinalityError::InvalidSignature([2u8; 32]))
+        );
+        assert_eq!(
+            gadget.sign_pending([9u8; 32], &TestKey([9u8; 32]), &TestVerifier).err(),
+            Some(FinalityError::UnknownSigner([9u8; 32]))
+        );
+        // Two of three fall short of 67% (20 < 21)
+        assert!(sign(&mut gadget, &[2]).is_none());
+        let certificate = sign(&mut gadget, &[3]).unwrap();
+        assert_eq!(gadget.status(&batch[3].txo.id), FinalityStatus::Final(0));
+
+        let decoded = FinalityCertificate::from_cbor(&certificate.to_cbor()).unwrap();
+        assert_eq!(decoded.verify(gadget.committee(), 67, &TestVerifier), Ok(30));
+        let mut partial = decoded.clone();
+        partial.signatures.truncate(2);
+        assert_eq!(
+            partial.verify(gadget.committee(), 67, &TestVerifier),
+            Err(FinalityError::InsufficientPower { signed: 20, required: 21 })
+        );
+        assert_eq!(partial.verify(gadget.committee(), 66, &TestVerifier), Ok(20));
+
+        // Every TXO in the batch has a proof leading to the certified root
+        for commit in &batch {
+            let proof = gadget.finality_proof(&commit.txo.id).unwrap();
+            assert!(proof.inclusion.verify(&certificate.header.batch_root, &commit.txo.id));
+        }
+    }
+
+    #[test]
+    fn test_light_client_follows_rotation() {
+        let genesis = committee(0, &[1, 2, 3]);
+        let mut gadget = FinalityGadget::new(genesis.clone(), 67);
+        let mut client = LightClient::from_genesis(genesis.clone(), 67);
+
+        // Height 0 hands over to a new committee
+        let rotated = committee(1, &[4, 5]);
+        gadget.schedule_rotation(rotated.clone());
+        let batch0 = commits(1, 3);
+        gadget.seal_batch(&batch0, 1).unwrap();
+        let cert0 = sign(&mut gadget, &[1, 2, 3]).unwrap();
+        assert_eq!(gadget.committee(), &rotated);
+
+        // Height 1 is signed by the new committee only
+        let batch1 = commits(2, 1);
+        gadget.seal_batch(&batch1, 2).unwrap();
+        assert!(gadget.sign_pending([1u8; 32], &TestKey([101u8; 32]), &TestVerifier).is_err());
+        let cert1 = sign(&mut gadget, &[4, 5]).unwrap();
+        assert_eq!(cert1.header.parent_hash, cert0.header.hash());
+        assert_eq!(gadget.headers_since(Some(0)).len(), 1);
+
+        // Orphan parent hashes are refused before any signature check
+        let mut bad = cert0.clone();
+        bad.header.parent_hash = [1u8; 32];
+        assert_eq!(client.apply(&bad, Some(&rotated), &TestVerifier), Err(FinalityError::BrokenChain));
+        assert_eq!(client.apply(&cert0, None, &TestVerifier), Err(FinalityError::CommitteeMismatch));
+
+        // The hand-over needs the announced committee
+        for cert in gadget.headers_since(None) {
+            client.apply(cert, Some(&rotated), &TestVerifier).unwrap();
+        }
+        assert_eq!(client.head(), Some(&cert1.header));
+        assert_eq!(client.apply(&cert1, None, &TestVerifier), Err(FinalityError::HeightGap));
+
+        // A checkpointed client trusts the rotated committee from height 0 on
+        let mut resumed = LightClient::from_checkpoint(cert0.header.clone(), rotated.clone(), 67).unwrap();
+        resumed.apply(&cert1, None, &TestVerifier).unwrap();
+        assert_eq!(
+            LightClient::from_checkpoint(cert0.header.clone(), genesis, 67).err(),
+            Some(FinalityError::CommitteeMismatch)
+        );
+
+        let proof = gadget.finality_proof(&batch0[2].txo.id).unwrap();
+        assert_eq!(client.verify_proof(&proof), Ok(0));
+        assert_eq!(resumed.verify_proof(&proof), Ok(0));
+        let mut forged = proof.clone();
+        forged.txo_id = [7u8; 32];
+        assert_eq!(client.verify_proof(&forged), Err(FinalityError::InvalidProof));
+    }
+
+    #[test]
+    fn test_wait_for_finality_times_out() {
+        let mut gadget = FinalityGadget::new(committee(0, &[1]), 67);
+        let batch = commits(3, 2);
+        let wait = gadget.wait_for_finality(batch[1].txo.id, 500, 1_000);
+        assert_eq!(gadget.poll_finality(&wait, 1_200), Ok(None));
+
+        gadget.seal_batch(&batch, 1_300).unwrap();
+        assert_eq!(gadget.poll_finality(&wait, 1_400), Ok(None));
+        sign(&mut gadget, &[1]);
+        let proof = gadget.poll_finality(&wait, 1_450).unwrap().unwrap();
+        assert_eq!(proof.certificate.header.height, 0);
+        // Final TXOs stay resolvable after the deadline
+        assert!(gadget.poll_finality(&wait, 9_999).is_ok());
+
+        let never = gadget.wait_for_finality([9u8; 32], 500, 1_000);
+        assert_eq!(gadget.poll_finality(&never, 1_500), Err(FinalityError::Timeout));
+    }
+}
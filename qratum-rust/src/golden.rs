@@ -0,0 +1,167 @@
+//! Golden Wire Fixtures
+//!
+//! Serializes canonical instances of every public CBOR wire type and compares
+//! the bytes against fixtures in `fixtures/golden/`. Any byte-level change
+//! fails the test, so wire-format breaks cannot land silently.
+//!
+//! After an intentional format change, regenerate and commit the fixtures:
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test golden
+//! ```
+//!
+//! Fixtures are stored as hex (32 bytes per line) so diffs stay readable.
+
+extern crate std;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::path::PathBuf;
+
+use crate::compliance::{CircuitType, ComplianceAttestation};
+use crate::transport::Channel;
+use crate::txo::{BlindedPayload, ComplianceZkp, OutcomeTxo, Txo, TxoType};
+use crate::watchdog::{BeaconEntry, BeaconSignature, ContactHint, WatchdogBeacon};
+
+/// Fixture directory
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/golden").join(name)
+}
+
+/// Render bytes as the fixture text format
+fn render(bytes: &[u8]) -> String {
+    let mut text = String::new();
+    for line in bytes.chunks(32) {
+        for byte in line {
+            text.push_str(&alloc::format!("{:02x}", byte));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Parse fixture text back into bytes
+fn parse(text: &str) -> Vec<u8> {
+    let digits: Vec<u8> = text.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let pair = core::str::from_utf8(pair).expect("hex fixture is ASCII");
+            u8::from_str_radix(pair, 16).expect("hex fixture digit")
+        })
+        .collect()
+}
+
+/// Compare bytes against a fixture (or rewrite it with `UPDATE_GOLDEN=1`)
+///
+/// Returns the fixture bytes so callers can also check decoding.
+fn check(name: &str, bytes: &[u8]) -> Vec<u8> {
+    let path = fixture_path(name);
+    let rendered = render(bytes);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().expect("fixture directory")).expect("create fixture directory");
+        std::fs::write(&path, &rendered).expect("write fixture");
+        return bytes.to_vec();
+    }
+
+    let stored = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("missing golden fixture {}; run with UPDATE_GOLDEN=1 to create it", path.display())
+    });
+    let expected = parse(&stored);
+    if expected != bytes {
+        let offset = expected.iter().zip(bytes).take_while(|(a, b)| a == b).count();
+        panic!(
+            "wire format of {} changed at byte {} ({} -> {} bytes); if intentional, \
+             rerun with UPDATE_GOLDEN=1 and commit the fixture",
+            name,
+            offset,
+            expected.len(),
+            bytes.len()
+        );
+    }
+    expected
+}
+
+/// Canonical compliance proof
+fn canonical_zkp() -> ComplianceZkp {
+    ComplianceZkp {
+        circuit_id: String::from("GDPR-Article-17"),
+        proof: alloc::vec![0xA1; 16],
+        public_inputs: alloc::vec![0xB2; 8],
+    }
+}
+
+/// Canonical TXO exercising every optional and repeated field
+fn canonical_txo() -> Txo {
+    let mut txo = Txo::new(
+        TxoType::Input,
+        1_700_000_000_000,
+        b"golden payload".to_vec(),
+        alloc::vec![[0x11; 32], [0x22; 32]],
+    );
+    let mut blinded = BlindedPayload::new(b"golden payload", 67);
+    blinded.revealed = Some(b"golden payload".to_vec());
+    txo.blinded = Some(blinded);
+    txo.compliance_zkp = Some(canonical_zkp());
+    txo.signatures = alloc::vec![[0x33; 64], [0x44; 64]];
+    txo
+}
+
+#[test]
+fn golden_txo() {
+    let txo = canonical_txo();
+    let fixture = check("txo.cbor.hex", &txo.to_cbor());
+
+    let decoded = Txo::from_cbor(&fixture).unwrap();
+    assert_eq!(decoded.to_cbor(), fixture);
+    assert_eq!(decoded.compute_id(), txo.compute_id());
+}
+
+#[test]
+fn golden_outcome_txo() {
+    let outcome = OutcomeTxo {
+        txo: Txo::new(TxoType::Outcome, 1_700_000_000_000, b"result".to_vec(), alloc::vec![[0x55; 32]]),
+        execution_hash: [0x66; 32],
+        quorum_proof: alloc::vec![0x77; 24],
+    };
+    let fixture = check("outcome_txo.cbor.hex", &minicbor::to_vec(&outcome).unwrap());
+
+    let decoded: OutcomeTxo = minicbor::decode(&fixture).unwrap();
+    assert_eq!(minicbor::to_vec(&decoded).unwrap(), fixture);
+}
+
+#[test]
+fn golden_compliance_attestation() {
+    let attestation = ComplianceAttestation {
+        circuit_type: CircuitType::GdprArticle17,
+        zkp: canonical_zkp(),
+        timestamp: 1_700_000_000_000,
+        attester_id: [0x88; 32],
+        signature: [0x99; 64],
+    };
+    check("compliance_attestation.cbor.hex", &attestation.to_txo().to_cbor());
+}
+
+#[test]
+fn golden_watchdog_beacon() {
+    let mut beacon = WatchdogBeacon {
+        epoch: 42,
+        validators: Vec::new(),
+        prev_beacon_hash: [0xAA; 32],
+        prev_attestation_root: [0xBB; 32],
+        signatures: alloc::vec![BeaconSignature { validator_id: [0xCC; 32], signature: [0xDD; 64] }],
+    };
+    for (i, channel) in [Channel::Tcp, Channel::Tor, Channel::I2p, Channel::Offline].into_iter().enumerate() {
+        beacon.validators.push(BeaconEntry {
+            validator_id: [i as u8; 32],
+            public_key: [0xE0 | i as u8; 32],
+            hints: alloc::vec![ContactHint { channel, address: alloc::format!("node-{}", i).into_bytes() }],
+        });
+    }
+    let fixture = check("watchdog_beacon.cbor.hex", &beacon.to_cbor());
+
+    let decoded = WatchdogBeacon::from_cbor(&fixture).unwrap();
+    assert_eq!(decoded, beacon);
+    assert_eq!(decoded.beacon_hash(), beacon.beacon_hash());
+}
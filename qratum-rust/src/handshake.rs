@@ -0,0 +1,299 @@
+//! # Handshake - Version Negotiation Between Nodes
+//!
+//! ## Lifecycle Stage: Network Infrastructure (channel establishment)
+//!
+//! Every channel opens with both sides sending a [`Hello`]. Each node then
+//! runs [`negotiate`] locally on its own hello and the peer's; both sides
+//! reach the same [`Negotiated`] parameters or the same [`HandshakeError`].
+//! Shared by the P2P layer ([`crate::p2p`]) and the transport layer
+//! ([`crate::transport`]).
+//!
+//! ## Negotiation Rules
+//!
+//! 1. Protocol major versions must match; the session runs at the lower of
+//!    the two versions
+//! 2. TXO schema ranges `[min_txo_schema, txo_schema]` must overlap; the
+//!    session uses the highest schema both sides support
+//! 3. Session features are the intersection of both feature sets; a
+//!    feature one side requires but the other lacks aborts the handshake
+//! 4. Unknown feature bits are ignored, so newer nodes can advertise
+//!    features older nodes have never heard of
+//!
+//! ## Security Rationale
+//!
+//! - Incompatible peers fail with an explicit error instead of desyncing
+//! - Negotiation is symmetric, so neither side can steer the other into a
+//!   version it did not advertise
+//! - Hellos are CBOR with a fixed magic, so foreign traffic is rejected early
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use minicbor::{Decode, Encode};
+
+use crate::upgrade::{Version, CURRENT_VERSION};
+
+/// Handshake magic ("QRHS")
+pub const HANDSHAKE_MAGIC: u32 = 0x5152_4853;
+
+/// TXO wire schema version produced by this build
+pub const TXO_SCHEMA_VERSION: u32 = 1;
+
+/// Oldest TXO wire schema this build can still decode
+pub const MIN_TXO_SCHEMA_VERSION: u32 = 1;
+
+/// Feature bits
+pub mod features {
+    /// Blinded payload commitments
+    pub const BLINDED_PAYLOADS: u64 = 1 << 0;
+    /// Compliance ZKP attestations on TXOs
+    pub const COMPLIANCE_ZKP: u64 = 1 << 1;
+    /// Encrypted volatile snapshots
+    pub const SNAPSHOTS: u64 = 1 << 2;
+    /// Watchdog beacon chain
+    pub const WATCHDOG_BEACONS: u64 = 1 << 3;
+    /// Progressive quorum decay with justification TXOs
+    pub const QUORUM_DECAY: u64 = 1 << 4;
+    /// Entropy health monitoring with session abort
+    pub const ENTROPY_HEALTH: u64 = 1 << 5;
+}
+
+/// Features implemented by this build
+pub const SUPPORTED_FEATURES: u64 = features::BLINDED_PAYLOADS
+    | features::COMPLIANCE_ZKP
+    | features::SNAPSHOTS
+    | features::WATCHDOG_BEACONS
+    | features::QUORUM_DECAY
+    | features::ENTROPY_HEALTH;
+
+/// Features this build refuses to run without
+pub const REQUIRED_FEATURES: u64 = features::BLINDED_PAYLOADS;
+
+/// Handshake message sent at channel establishment
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct Hello {
+    /// Must equal [`HANDSHAKE_MAGIC`]
+    #[n(0)]
+    pub magic: u32,
+
+    /// Protocol major version
+    #[n(1)]
+    pub major: u32,
+
+    /// Protocol minor version
+    #[n(2)]
+    pub minor: u32,
+
+    /// Protocol patch version
+    #[n(3)]
+    pub patch: u32,
+
+    /// Supported feature bits
+    #[n(4)]
+    pub features: u64,
+
+    /// Feature bits the sender refuses to run without
+    #[n(5)]
+    pub required_features: u64,
+
+    /// Newest TXO schema the sender produces
+    #[n(6)]
+    pub txo_schema: u32,
+
+    /// Oldest TXO schema the sender decodes
+    #[n(7)]
+    pub min_txo_schema: u32,
+
+    /// Sender node ID
+    #[n(8)]
+    pub node_id: [u8; 32],
+}
+
+impl Hello {
+    /// Hello describing this build
+    pub fn local(node_id: [u8; 32]) -> Self {
+        Self {
+            magic: HANDSHAKE_MAGIC,
+            major: CURRENT_VERSION.major,
+            minor: CURRENT_VERSION.minor,
+            patch: CURRENT_VERSION.patch,
+            features: SUPPORTED_FEATURES,
+            required_features: REQUIRED_FEATURES,
+            txo_schema: TXO_SCHEMA_VERSION,
+            min_txo_schema: MIN_TXO_SCHEMA_VERSION,
+            node_id,
+        }
+    }
+
+    /// Advertised protocol version
+    pub fn version(&self) -> Version {
+        Version::new(self.major, self.minor, self.patch)
+    }
+
+    /// Serialize to CBOR
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserialize from CBOR, rejecting foreign traffic
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, HandshakeError> {
+        let hello: Hello = minicbor::decode(bytes).map_err(|_| HandshakeError::Malformed)?;
+        if hello.magic != HANDSHAKE_MAGIC {
+            return Err(HandshakeError::Malformed);
+        }
+        Ok(hello)
+    }
+}
+
+/// Parameters both sides agreed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Negotiated {
+    /// Protocol version for the session (lower of the two)
+    pub version: Version,
+
+    /// TXO schema version for the session
+    pub txo_schema: u32,
+
+    /// Feature bits both sides support
+    pub features: u64,
+}
+
+impl Negotiated {
+    /// Check whether a feature is active for the session
+    pub fn has(&self, feature: u64) -> bool {
+        self.features & feature == feature
+    }
+}
+
+/// Handshake failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandshakeError {
+    /// Not a CBOR hello with the expected magic
+    Malformed,
+    /// Protocol major versions differ
+    ProtocolMismatch {
+        /// Local version
+        local: Version,
+        /// Remote version
+        remote: Version,
+    },
+    /// TXO schema ranges do not overlap
+    TxoSchemaMismatch {
+        /// Local `[min, max]` schema range
+        local: (u32, u32),
+        /// Remote `[min, max]` schema range
+        remote: (u32, u32),
+    },
+    /// Local node requires features the peer lacks
+    PeerMissingFeatures(u64),
+    /// Peer requires features the local node lacks
+    LocalMissingFeatures(u64),
+    /// Peer is this node
+    SelfConnection,
+    /// Handshake succeeded but the layer refused the peer (capacity, bans)
+    PeerRejected,
+    /// No transport channel could carry the hello
+    ChannelUnavailable,
+}
+
+/// Negotiate session parameters from both hellos
+///
+/// ## Inputs → Outputs
+/// - (local hello, remote hello) → agreed parameters or the first rule violated
+///
+/// Symmetric: `negotiate(a, b)` and `negotiate(b, a)` agree on the
+/// parameters, and on success/failure.
+pub fn negotiate(local: &Hello, remote: &Hello) -> Result<Negotiated, HandshakeError> {
+    if remote.magic != HANDSHAKE_MAGIC {
+        return Err(HandshakeError::Malformed);
+    }
+    if remote.node_id == local.node_id {
+        return Err(HandshakeError::SelfConnection);
+    }
+
+    let (local_version, remote_version) = (local.version(), remote.version());
+    if !local_version.is_compatible_with(&remote_version) {
+        return Err(HandshakeError::ProtocolMismatch { local: local_version, remote: remote_version });
+    }
+
+    let txo_schema = local.txo_schema.min(remote.txo_schema);
+    if txo_schema < local.min_txo_schema.max(remote.min_txo_schema) {
+        return Err(HandshakeError::TxoSchemaMismatch {
+            local: (local.min_txo_schema, local.txo_schema),
+            remote: (remote.min_txo_schema, remote.txo_schema),
+        });
+    }
+
+    let peer_missing = local.required_features & !remote.features;
+    if peer_missing != 0 {
+        return Err(HandshakeError::PeerMissingFeatures(peer_missing));
+    }
+    let local_missing = remote.required_features & !local.features;
+    if local_missing != 0 {
+        return Err(HandshakeError::LocalMissingFeatures(local_missing));
+    }
+
+    Ok(Negotiated {
+        version: local_version.min(remote_version),
+        txo_schema,
+        features: local.features & remote.features,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer() -> Hello {
+        Hello::local([2u8; 32])
+    }
+
+    #[test]
+    fn test_negotiation_is_symmetric() {
+        let local = Hello::local([1u8; 32]);
+        let mut remote = peer();
+        remote.minor = CURRENT_VERSION.minor + 1;
+        remote.txo_schema = TXO_SCHEMA_VERSION + 1;
+        remote.features |= 1 << 40; // Unknown to this build
+
+        let ours = negotiate(&local, &remote).unwrap();
+        let theirs = negotiate(&remote, &local).unwrap();
+        assert_eq!(ours, theirs);
+        assert_eq!(ours.version, CURRENT_VERSION);
+        assert_eq!(ours.txo_schema, TXO_SCHEMA_VERSION);
+        assert_eq!(ours.features, SUPPORTED_FEATURES);
+        assert!(ours.has(features::SNAPSHOTS));
+
+        let decoded = Hello::from_cbor(&remote.to_cbor()).unwrap();
+        assert_eq!(decoded, remote);
+    }
+
+    #[test]
+    fn test_incompatibility_errors() {
+        let local = Hello::local([1u8; 32]);
+
+        let mut remote = peer();
+        remote.major += 1;
+        assert!(matches!(negotiate(&local, &remote), Err(HandshakeError::ProtocolMismatch { .. })));
+
+        let mut remote = peer();
+        remote.min_txo_schema = TXO_SCHEMA_VERSION + 1;
+        remote.txo_schema = TXO_SCHEMA_VERSION + 2;
+        assert!(matches!(negotiate(&local, &remote), Err(HandshakeError::TxoSchemaMismatch { .. })));
+
+        let mut remote = peer();
+        remote.features &= !features::BLINDED_PAYLOADS;
+        remote.required_features = 0;
+        assert_eq!(
+            negotiate(&local, &remote),
+            Err(HandshakeError::PeerMissingFeatures(features::BLINDED_PAYLOADS))
+        );
+        assert_eq!(
+            negotiate(&remote, &local),
+            Err(HandshakeError::LocalMissingFeatures(features::BLINDED_PAYLOADS))
+        );
+
+        assert_eq!(negotiate(&local, &local), Err(HandshakeError::SelfConnection));
+        assert_eq!(Hello::from_cbor(b"GET / HTTP/1.1"), Err(HandshakeError::Malformed));
+    }
+}
@@ -0,0 +1,597 @@
+//! # HBS Module - Stateful Hash-Based Signatures for Upgrade Signing
+//!
+//! ## Lifecycle Stage: Governance Execution (release signing)
+//!
+//! Protocol upgrade bundles are signed with LMS (RFC 8554) using the SHAKE256
+//! parameter sets of NIST SP 800-208, which keeps the crate SHA3-only.
+//! Verification is a few hundred hash calls and needs nothing but the public
+//! key, so every validator can check a release bundle cheaply.
+//!
+//! ## Architectural Role
+//!
+//! - **LM-OTS**: One-time signatures, Winternitz parameter w = 4
+//! - **LMS**: Merkle tree of 2^h one-time keys under a single public key
+//! - **State Guard**: The next leaf index is persisted *before* a signature
+//!   is released; a signer whose state cannot be loaded refuses to sign
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Seed, identifier, tree height, state store, message
+//! - Output: `LmsPublicKey`, `LmsSignature` (verified by [`verify`])
+//!
+//! ## Security Rationale
+//!
+//! - Security rests only on SHAKE256 preimage/second-preimage resistance,
+//!   so signatures survive a quantum adversary
+//! - Reusing a one-time key leaks it; the state guard fails closed: lost
+//!   state, a failed persist or an exhausted tree all stop signing
+//! - Private one-time keys are derived from the seed (RFC 8554 Appendix A)
+//!   and the seed is zeroized on drop
+//!
+//! ## Forward Compatibility
+//!
+//! TODO: QRADLE post-quantum migration - add HSS (multi-tree) once release
+//! volume outgrows a single H15 tree
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+use zeroize::Zeroize;
+
+/// Hash output length (n = m = 32)
+pub const HBS_N: usize = 32;
+
+/// LM-OTS_SHAKE_N32_W4 typecode (SP 800-208 Table 3)
+pub const LMOTS_SHAKE_N32_W4: u32 = 0x0000_000B;
+
+/// Winternitz parameter
+const W: usize = 4;
+
+/// Number of hash chains: 64 message digits + 3 checksum digits
+const P: usize = 67;
+
+/// Checksum left shift
+const LS: u32 = 4;
+
+/// Domain separators (RFC 8554 Section 7.1)
+const D_PBLC: u16 = 0x8080;
+const D_MESG: u16 = 0x8181;
+const D_LEAF: u16 = 0x8282;
+const D_INTR: u16 = 0x8383;
+
+/// HBS Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HbsError {
+    /// No persisted state for an existing key; signing would risk reuse
+    StateLost,
+    /// Persisted state exists for a key being generated afresh
+    StateExists,
+    /// The next index could not be persisted; no signature was produced
+    StatePersistFailed,
+    /// Every one-time key in the tree has been used
+    KeyExhausted,
+    /// Encoded key or signature is malformed
+    Malformed,
+}
+
+/// LMS tree height (SP 800-208 LMS_SHAKE_M32_H* parameter sets)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LmsHeight {
+    /// 32 signatures
+    H5,
+    /// 1,024 signatures
+    H10,
+    /// 32,768 signatures
+    H15,
+}
+
+impl LmsHeight {
+    /// LMS typecode
+    pub fn typecode(self) -> u32 {
+        match self {
+            LmsHeight::H5 => 0x0000_000F,
+            LmsHeight::H10 => 0x0000_0010,
+            LmsHeight::H15 => 0x0000_0011,
+        }
+    }
+
+    /// Tree height h
+    pub fn height(self) -> u32 {
+        match self {
+            LmsHeight::H5 => 5,
+            LmsHeight::H10 => 10,
+            LmsHeight::H15 => 15,
+        }
+    }
+
+    /// Number of one-time keys (2^h)
+    pub fn leaves(self) -> u32 {
+        1 << self.height()
+    }
+
+    fn from_typecode(typecode: u32) -> Option<Self> {
+        match typecode {
+            0x0000_000F => Some(LmsHeight::H5),
+            0x0000_0010 => Some(LmsHeight::H10),
+            0x0000_0011 => Some(LmsHeight::H15),
+            _ => None,
+        }
+    }
+}
+
+/// LMS public key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LmsPublicKey {
+    /// Tree height
+    pub height: LmsHeight,
+    /// Key pair identifier I
+    pub identifier: [u8; 16],
+    /// Merkle root T[1]
+    pub root: [u8; HBS_N],
+}
+
+impl LmsPublicKey {
+    /// RFC 8554 encoding: LMS type, LM-OTS type, I, T[1]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(24 + HBS_N);
+        bytes.extend_from_slice(&self.height.typecode().to_be_bytes());
+        bytes.extend_from_slice(&LMOTS_SHAKE_N32_W4.to_be_bytes());
+        bytes.extend_from_slice(&self.identifier);
+        bytes.extend_from_slice(&self.root);
+        bytes
+    }
+
+    /// Decode an RFC 8554 public key
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HbsError> {
+        if bytes.len() != 24 + HBS_N || be32(&bytes[4..8]) != LMOTS_SHAKE_N32_W4 {
+            return Err(HbsError::Malformed);
+        }
+        let height = LmsHeight::from_typecode(be32(&bytes[0..4])).ok_or(HbsError::Malformed)?;
+        let mut identifier = [0u8; 16];
+        identifier.copy_from_slice(&bytes[8..24]);
+        let mut root = [0u8; HBS_N];
+        root.copy_from_slice(&bytes[24..]);
+        Ok(Self { height, identifier, root })
+    }
+}
+
+/// LMS signature
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LmsSignature {
+    /// Leaf index q
+    pub q: u32,
+    /// LM-OTS randomizer C
+    pub randomizer: [u8; HBS_N],
+    /// LM-OTS chain values y[0..p]
+    pub y: Vec<[u8; HBS_N]>,
+    /// Tree height
+    pub height: LmsHeight,
+    /// Authentication path, leaf to root
+    pub path: Vec<[u8; HBS_N]>,
+}
+
+impl LmsSignature {
+    /// RFC 8554 encoding: q, LM-OTS signature, LMS type, path
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12 + HBS_N * (1 + P + self.path.len()));
+        bytes.extend_from_slice(&self.q.to_be_bytes());
+        bytes.extend_from_slice(&LMOTS_SHAKE_N32_W4.to_be_bytes());
+        bytes.extend_from_slice(&self.randomizer);
+        self.y.iter().for_each(|y| bytes.extend_from_slice(y));
+        bytes.extend_from_slice(&self.height.typecode().to_be_bytes());
+        self.path.iter().for_each(|node| bytes.extend_from_slice(node));
+        bytes
+    }
+
+    /// Decode an RFC 8554 signature
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HbsError> {
+        let ots_end = 8 + HBS_N * (1 + P);
+        if bytes.len() < ots_end + 4 || be32(&bytes[4..8]) != LMOTS_SHAKE_N32_W4 {
+            return Err(HbsError::Malformed);
+        }
+        let height = LmsHeight::from_typecode(be32(&bytes[ots_end..ots_end + 4])).ok_or(HbsError::Malformed)?;
+        let path_bytes = &bytes[ots_end + 4..];
+        if path_bytes.len() != HBS_N * height.height() as usize {
+            return Err(HbsError::Malformed);
+        }
+        let mut randomizer = [0u8; HBS_N];
+        randomizer.copy_from_slice(&bytes[8..8 + HBS_N]);
+        Ok(Self {
+            q: be32(&bytes[0..4]),
+            randomizer,
+            y: chunks(&bytes[8 + HBS_N..ots_end]),
+            height,
+            path: chunks(path_bytes),
+        })
+    }
+}
+
+/// Persistent storage for the next unused leaf index
+///
+/// Implementations must make `persist` durable before returning `Ok`.
+pub trait LmsStateStore {
+    /// Next unused leaf index, or `None` if no state is recorded
+    fn load(&mut self) -> Option<u32>;
+
+    /// Durably record the next unused leaf index
+    fn persist(&mut self, next_q: u32) -> Result<(), HbsError>;
+}
+
+/// LMS signer bound to a state store
+pub struct LmsSigner<S: LmsStateStore> {
+    seed: [u8; HBS_N],
+    public: LmsPublicKey,
+    next_q: u32,
+    store: S,
+    /// Merkle tree nodes T[1..2^(h+1)), index 0 unused
+    tree: Vec<[u8; HBS_N]>,
+}
+
+impl<S: LmsStateStore> LmsSigner<S> {
+    /// Generate a fresh key pair
+    ///
+    /// # Inputs
+    /// - `height`: Tree height (number of signatures)
+    /// - `seed`: Secret seed for one-time key derivation
+    /// - `identifier`: Key pair identifier I
+    /// - `store`: Empty state store; index 0 is persisted before returning
+    ///
+    /// # Outputs
+    /// - `HbsError::StateExists` if the store already holds state (the key
+    ///   may have signed before)
+    pub fn generate(
+        height: LmsHeight,
+        seed: [u8; HBS_N],
+        identifier: [u8; 16],
+        mut store: S,
+    ) -> Result<Self, HbsError> {
+        if store.load().is_some() {
+            return Err(HbsError::StateExists);
+        }
+        store.persist(0)?;
+        Ok(Self::build(height, seed, identifier, 0, store))
+    }
+
+    /// Reload a key pair from its seed and persisted state
+    ///
+    /// # Outputs
+    /// - `HbsError::StateLost` if the store has no state; never assumes 0
+    pub fn restore(
+        height: LmsHeight,
+        seed: [u8; HBS_N],
+        identifier: [u8; 16],
+        mut store: S,
+    ) -> Result<Self, HbsError> {
+        let next_q = store.load().ok_or(HbsError::StateLost)?;
+        Ok(Self::build(height, seed, identifier, next_q, store))
+    }
+
+    fn build(height: LmsHeight, seed: [u8; HBS_N], identifier: [u8; 16], next_q: u32, store: S) -> Self {
+        let leaves = height.leaves() as usize;
+        let mut tree = vec![[0u8; HBS_N]; 2 * leaves];
+        for q in 0..leaves {
+            let r = (leaves + q) as u32;
+            let ots_public = ots_public_key(&identifier, q as u32, &seed);
+            tree[leaves + q] = hash(&[&identifier, &r.to_be_bytes(), &D_LEAF.to_be_bytes(), &ots_public]);
+        }
+        for r in (1..leaves).rev() {
+            tree[r] = hash(&[
+                &identifier,
+                &(r as u32).to_be_bytes(),
+                &D_INTR.to_be_bytes(),
+                &tree[2 * r],
+                &tree[2 * r + 1],
+            ]);
+        }
+        let public = LmsPublicKey { height, identifier, root: tree[1] };
+        Self { seed, public, next_q, store, tree }
+    }
+
+    /// Public key
+    pub fn public_key(&self) -> LmsPublicKey {
+        self.public
+    }
+
+    /// Signatures left before the key is exhausted
+    pub fn remaining(&self) -> u32 {
+        self.public.height.leaves().saturating_sub(self.next_q)
+    }
+
+    /// Sign a message with the next one-time key
+    ///
+    /// The incremented index is persisted first; if that fails, no
+    /// signature is produced and the index is not consumed in memory.
+    pub fn sign(&mut self, message: &[u8]) -> Result<LmsSignature, HbsError> {
+        let q = self.next_q;
+        if q >= self.public.height.leaves() {
+            return Err(HbsError::KeyExhausted);
+        }
+        self.store.persist(q + 1).map_err(|_| HbsError::StatePersistFailed)?;
+        self.next_q = q + 1;
+
+        let identifier = self.public.identifier;
+        // Randomizer derived per RFC 8554 Appendix A (j = 0xFFFD)
+        let randomizer = hash(&[&identifier, &q.to_be_bytes(), &0xFFFDu16.to_be_bytes(), &[0xFF], &self.seed]);
+        let digits = message_digits(&identifier, q, &randomizer, message);
+        let y = (0..P)
+            .map(|i| {
+                let mut x = ots_private_element(&identifier, q, i as u16, &self.seed);
+                let value = chain(&identifier, q, i as u16, &x, 0, digits[i]);
+                x.zeroize();
+                value
+            })
+            .collect();
+
+        let leaves = self.public.height.leaves() as usize;
+        let mut r = leaves + q as usize;
+        let mut path = Vec::with_capacity(self.public.height.height() as usize);
+        while r > 1 {
+            path.push(self.tree[r ^ 1]);
+            r /= 2;
+        }
+
+        Ok(LmsSignature { q, randomizer, y, height: self.public.height, path })
+    }
+}
+
+impl<S: LmsStateStore> Drop for LmsSigner<S> {
+    fn drop(&mut self) {
+        self.seed.zeroize();
+    }
+}
+
+/// Verify an LMS signature
+///
+/// # Outputs
+/// - `true` if `signature` over `message` verifies under `public_key`
+pub fn verify(public_key: &LmsPublicKey, message: &[u8], signature: &LmsSignature) -> bool {
+    let leaves = public_key.height.leaves();
+    if signature.height != public_key.height
+        || signature.q >= leaves
+        || signature.y.len() != P
+        || signature.path.len() != public_key.height.height() as usize
+    {
+        return false;
+    }
+    let identifier = &public_key.identifier;
+    let q = signature.q;
+
+    // Candidate LM-OTS public key (RFC 8554 Algorithm 4b)
+    let digits = message_digits(identifier, q, &signature.randomizer, message);
+    let mut candidate = Shake256::default();
+    candidate.update(identifier);
+    candidate.update(&q.to_be_bytes());
+    candidate.update(&D_PBLC.to_be_bytes());
+    for (i, y) in signature.y.iter().enumerate() {
+        candidate.update(&chain(identifier, q, i as u16, y, digits[i], (1 << W) - 1));
+    }
+    let ots_public = finalize(candidate);
+
+    // Walk the authentication path (RFC 8554 Algorithm 6a)
+    let mut r = leaves + q;
+    let mut node = hash(&[identifier, &r.to_be_bytes(), &D_LEAF.to_be_bytes(), &ots_public]);
+    for sibling in &signature.path {
+        let parent = r / 2;
+        node = if r % 2 == 1 {
+            hash(&[identifier, &parent.to_be_bytes(), &D_INTR.to_be_bytes(), sibling, &node])
+        } else {
+            hash(&[identifier, &parent.to_be_bytes(), &D_INTR.to_be_bytes(), &node, sibling])
+        };
+        r = parent;
+    }
+    node == public_key.root
+}
+
+/// File-backed state store
+///
+/// The index is written to a temporary file, synced, then renamed over the
+/// state file, so a crash leaves either the old or the new index.
+#[cfg(feature = "std")]
+pub struct FileStateStore {
+    path: std::path::PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl FileStateStore {
+    /// State store at `path`
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl LmsStateStore for FileStateStore {
+    fn load(&mut self) -> Option<u32> {
+        let bytes = std::fs::read(&self.path).ok()?;
+        Some(u32::from_le_bytes(bytes.as_slice().try_into().ok()?))
+    }
+
+    fn persist(&mut self, next_q: u32) -> Result<(), HbsError> {
+        use std::io::Write;
+        let tmp = self.path.with_extension("tmp");
+        let write = || -> std::io::Result<()> {
+            let mut file = std::fs::File::create(&tmp)?;
+            file.write_all(&next_q.to_le_bytes())?;
+            file.sync_all()?;
+            std::fs::rename(&tmp, &self.path)
+        };
+        write().map_err(|_| HbsError::StatePersistFailed)
+    }
+}
+
+/// SHAKE256 with 256-bit output over concatenated parts
+fn hash(parts: &[&[u8]]) -> [u8; HBS_N] {
+    let mut hasher = Shake256::default();
+    parts.iter().for_each(|part| hasher.update(part));
+    finalize(hasher)
+}
+
+fn finalize(hasher: Shake256) -> [u8; HBS_N] {
+    let mut out = [0u8; HBS_N];
+    hasher.finalize_xof().read(&mut out);
+    out
+}
+
+fn be32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn chunks(bytes: &[u8]) -> Vec<[u8; HBS_N]> {
+    bytes
+        .chunks_exact(HBS_N)
+        .map(|chunk| {
+            let mut node = [0u8; HBS_N];
+            node.copy_from_slice(chunk);
+            node
+        })
+        .collect()
+}
+
+/// Private chain start x[i] (RFC 8554 Appendix A)
+fn ots_private_element(identifier: &[u8; 16], q: u32, i: u16, seed: &[u8; HBS_N]) -> [u8; HBS_N] {
+    hash(&[identifier, &q.to_be_bytes(), &i.to_be_bytes(), &[0xFF], seed])
+}
+
+/// Iterate the Winternitz chain from step `from` to `to`
+fn chain(identifier: &[u8; 16], q: u32, i: u16, start: &[u8; HBS_N], from: u8, to: u8) -> [u8; HBS_N] {
+    let mut tmp = *start;
+    for j in from..to {
+        tmp = hash(&[identifier, &q.to_be_bytes(), &i.to_be_bytes(), &[j], &tmp]);
+    }
+    tmp
+}
+
+/// LM-OTS public key for leaf `q`
+fn ots_public_key(identifier: &[u8; 16], q: u32, seed: &[u8; HBS_N]) -> [u8; HBS_N] {
+    let mut hasher = Shake256::default();
+    hasher.update(identifier);
+    hasher.update(&q.to_be_bytes());
+    hasher.update(&D_PBLC.to_be_bytes());
+    for i in 0..P as u16 {
+        let mut x = ots_private_element(identifier, q, i, seed);
+        hasher.update(&chain(identifier, q, i, &x, 0, (1 << W) - 1));
+        x.zeroize();
+    }
+    finalize(hasher)
+}
+
+/// Base-w digits of Q || Cksm(Q)
+fn message_digits(identifier: &[u8; 16], q: u32, randomizer: &[u8; HBS_N], message: &[u8]) -> [u8; P] {
+    let digest = hash(&[identifier, &q.to_be_bytes(), &D_MESG.to_be_bytes(), randomizer, message]);
+    let mut digits = [0u8; P];
+    let mut checksum = 0u32;
+    for (i, byte) in digest.iter().enumerate() {
+        digits[2 * i] = byte >> 4;
+        digits[2 * i + 1] = byte & 0x0F;
+        checksum += (15 - (byte >> 4)) as u32 + (15 - (byte & 0x0F)) as u32;
+    }
+    let checksum = ((checksum << LS) as u16).to_be_bytes();
+    digits[64] = checksum[0] >> 4;
+    digits[65] = checksum[0] & 0x0F;
+    digits[66] = checksum[1] >> 4;
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// In-memory store that can be told to fail
+    struct MemoryStore {
+        state: Option<u32>,
+        fail: bool,
+    }
+
+    impl LmsStateStore for &mut MemoryStore {
+        fn load(&mut self) -> Option<u32> {
+            self.state
+        }
+
+        fn persist(&mut self, next_q: u32) -> Result<(), HbsError> {
+            if self.fail {
+                return Err(HbsError::StatePersistFailed);
+            }
+            self.state = Some(next_q);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_sign_verify_and_encoding() {
+        let mut store = MemoryStore { state: None, fail: false };
+        let mut signer = LmsSigner::generate(LmsHeight::H5, [1u8; 32], [2u8; 16], &mut store).unwrap();
+        let public = signer.public_key();
+
+        let first = signer.sign(b"upgrade bundle v1.1.0").unwrap();
+        let second = signer.sign(b"upgrade bundle v1.1.0").unwrap();
+        assert_eq!((first.q, second.q), (0, 1));
+        assert_eq!(signer.remaining(), 30);
+        assert!(verify(&public, b"upgrade bundle v1.1.0", &first));
+        assert!(verify(&public, b"upgrade bundle v1.1.0", &second));
+        assert!(!verify(&public, b"upgrade bundle v1.1.1", &first));
+
+        let mut forged = first.clone();
+        forged.path[2][0] ^= 1;
+        assert!(!verify(&public, b"upgrade bundle v1.1.0", &forged));
+
+        // RFC 8554 encodings round-trip
+        let bytes = first.to_bytes();
+        assert_eq!(bytes.len(), 4 + 4 + 32 + 67 * 32 + 4 + 5 * 32);
+        assert_eq!(LmsSignature::from_bytes(&bytes), Ok(first));
+        assert_eq!(LmsPublicKey::from_bytes(&public.to_bytes()), Ok(public));
+        assert_eq!(LmsSignature::from_bytes(&bytes[..100]), Err(HbsError::Malformed));
+    }
+
+    #[test]
+    fn test_state_guard_fails_closed() {
+        let mut store = MemoryStore { state: None, fail: false };
+        let public = {
+            let mut signer = LmsSigner::generate(LmsHeight::H5, [1u8; 32], [2u8; 16], &mut store).unwrap();
+            signer.sign(b"a").unwrap();
+            signer.public_key()
+        };
+        assert_eq!(store.state, Some(1));
+
+        // Regenerating over existing state is refused; restore resumes at q = 1
+        assert!(matches!(
+            LmsSigner::generate(LmsHeight::H5, [1u8; 32], [2u8; 16], &mut store),
+            Err(HbsError::StateExists)
+        ));
+        let mut signer = LmsSigner::restore(LmsHeight::H5, [1u8; 32], [2u8; 16], &mut store).unwrap();
+        assert_eq!(signer.public_key(), public);
+        assert_eq!(signer.sign(b"b").unwrap().q, 1);
+        drop(signer);
+
+        // A failed persist produces no signature and consumes no index
+        store.fail = true;
+        let mut signer = LmsSigner::restore(LmsHeight::H5, [1u8; 32], [2u8; 16], &mut store).unwrap();
+        assert_eq!(signer.sign(b"c"), Err(HbsError::StatePersistFailed));
+        assert_eq!(signer.remaining(), 30);
+        drop(signer);
+
+        // Lost state is never treated as a fresh key
+        let mut lost = MemoryStore { state: None, fail: false };
+        assert!(matches!(
+            LmsSigner::restore(LmsHeight::H5, [1u8; 32], [2u8; 16], &mut lost),
+            Err(HbsError::StateLost)
+        ));
+
+        // Exhaustion
+        let mut nearly_done = MemoryStore { state: Some(32), fail: false };
+        let mut signer = LmsSigner::restore(LmsHeight::H5, [1u8; 32], [2u8; 16], &mut nearly_done).unwrap();
+        assert_eq!(signer.sign(b"d"), Err(HbsError::KeyExhausted));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_file_state_store() {
+        let path = std::env::temp_dir().join(alloc::format!("qratum-lms-state-{}", std::process::id()));
+        let mut store = FileStateStore::new(&path);
+        assert_eq!(store.load(), None);
+        store.persist(7).unwrap();
+        assert_eq!(FileStateStore::new(&path).load(), Some(7));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -242,7 +242,8 @@ impl ValidatorIncentives {
     ///
     /// ## Security
     /// - Slashing is irreversible
-    /// - Slashed stake is burned (removed from circulation)
+    /// - Slashed stake leaves circulation; `total_slashed` is what the
+    ///   treasury collects
     /// - Audit trail records slashing with reason
     /// - Cannot slash more than validator's stake
     pub fn slash(&mut self, validator: ValidatorID, amount: u64, reason: Violation) {
@@ -277,6 +278,34 @@ impl ValidatorIncentives {
     /// - Only active validators receive rewards
     /// - Total rewards capped by reward pool
     pub fn distribute_epoch_rewards(&mut self, active_validators: &[ValidatorID]) {
+        self.distribute_rewards_at_rate(active_validators, self.reward_rate);
+    }
+
+    /// Distribute rewards for an epoch of adaptive length
+    ///
+    /// ## Inputs
+    /// - `active_validators`: List of validators who participated this epoch
+    /// - `duration_ms`: Length of the epoch being rewarded
+    /// - `nominal_duration_ms`: Epoch length `reward_rate` is quoted for
+    ///
+    /// ## Security
+    /// - Reward per unit time is independent of epoch length, so stretching
+    ///   or shrinking epochs does not change issuance
+    pub fn distribute_adaptive_epoch_rewards(
+        &mut self,
+        active_validators: &[ValidatorID],
+        duration_ms: u64,
+        nominal_duration_ms: u64,
+    ) {
+        let rate = if nominal_duration_ms == 0 {
+            self.reward_rate
+        } else {
+            (self.reward_rate as u128 * duration_ms as u128 / nominal_duration_ms as u128).min(10000) as u64
+        };
+        self.distribute_rewards_at_rate(active_validators, rate);
+    }
+
+    fn distribute_rewards_at_rate(&mut self, active_validators: &[ValidatorID], reward_rate: u64) {
         if active_validators.is_empty() {
             return; // No validators to reward
         }
@@ -293,7 +322,7 @@ impl ValidatorIncentives {
         }
         
         // Calculate total epoch reward (reward_rate is in basis points)
-        let total_epoch_reward = (self.reward_pool * self.reward_rate) / 10000;
+        let total_epoch_reward = (self.reward_pool * reward_rate) / 10000;
         
         // Distribute rewards proportionally
         for validator in active_validators {
@@ -402,6 +431,21 @@ mod tests {
         assert_eq!(incentives.current_epoch, 1);
     }
     
+    #[test]
+    fn test_adaptive_epoch_rewards() {
+        let validator = [1u8; 32];
+        let mut nominal = ValidatorIncentives::default();
+        let mut shortened = ValidatorIncentives::default();
+        nominal.deposit_stake(validator, 1000, 0);
+        shortened.deposit_stake(validator, 1000, 0);
+
+        // A half-length epoch pays half of a nominal epoch
+        nominal.distribute_adaptive_epoch_rewards(&[validator], 600_000, 600_000);
+        shortened.distribute_adaptive_epoch_rewards(&[validator], 300_000, 600_000);
+        assert_eq!(shortened.total_rewards_distributed, nominal.total_rewards_distributed / 2);
+        assert_eq!(shortened.current_epoch, 1);
+    }
+
     #[test]
     fn test_locked_stake() {
         let mut incentives = ValidatorIncentives::default();
@@ -0,0 +1,439 @@
+//! # Key Wrap Module - At-Rest Protection of Key Material
+//!
+//! ## Lifecycle Stage: Quorum Convergence → Destruction
+//!
+//! Escrow shares and snapshot session keys occasionally have to leave the
+//! enclave (handed to recovery parties, parked across a restart). Before they
+//! do, they are wrapped under a key-encryption key (KEK) so the material is
+//! never stored in the clear.
+//!
+//! The desktop keystore (`qratum_desktop`) holds its keys the same way, as
+//! `WrappedKey` blobs under a passphrase-derived KEK.
+//!
+//! ## Architectural Role
+//!
+//! - **AES-KW**: RFC 3394 key wrap with AES-256, integrity-checked on unwrap
+//! - **Kyber Hybrid**: AES-KW under a KEK derived from both the classical KEK
+//!   and a Kyber shared secret, so recovery requires breaking both
+//! - **Versioned Format**: Every wrapped blob carries a format version and
+//!   algorithm identifier; unknown versions are rejected, not guessed
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Key material (multiple of 8 bytes, at least 16), KEK, optional KEM
+//! - Output: `WrappedKey` (serializable via `to_bytes` / `from_bytes`)
+//!
+//! ## Security Rationale
+//!
+//! - RFC 3394 integrity check rejects wrong KEKs and tampered blobs
+//! - Hybrid KEK binds the format version, algorithm and KEM ciphertext, so a
+//!   blob cannot be replayed under a different header
+//! - AES comes from the RustCrypto `aes` crate: AES-NI / ARMv8 instructions
+//!   where available, a constant-time bitsliced implementation otherwise
+//! - KEK schedules and intermediate KEKs are zeroized after use
+//! - The Kyber backend plugs in through [`KeyEncapsulator`] and
+//!   [`KeyDecapsulator`]; this module never sees the KEM secret key
+//!
+//! ## Forward Compatibility
+//!
+//! TODO: QRADLE post-quantum migration - bump `WRAP_FORMAT_VERSION` when the
+//! hybrid KDF or KEM parameter set changes
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
+use aes::Aes256;
+use sha3::{Digest, Sha3_256};
+use zeroize::Zeroize;
+
+/// Current wrapped-key format version
+pub const WRAP_FORMAT_VERSION: u8 = 1;
+
+/// RFC 3394 default initial value
+const AES_KW_IV: [u8; 8] = [0xA6; 8];
+
+/// Domain separator for hybrid KEK derivation
+const HYBRID_KEK_DOMAIN: &[u8] = b"QRATUM-KEYWRAP-HYBRID-v1";
+
+/// Header: version, algorithm, KEM ciphertext length (u16 LE)
+const HEADER_LEN: usize = 4;
+
+/// Key Wrap Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrapError {
+    /// Key data is not a multiple of 8 bytes or shorter than 16 bytes
+    InvalidLength,
+    /// Format version not supported by this build
+    UnsupportedVersion,
+    /// Algorithm identifier unknown
+    UnsupportedAlgorithm,
+    /// Serialized blob is truncated or inconsistent
+    Malformed,
+    /// Integrity check failed (wrong KEK or tampered blob)
+    IntegrityCheckFailed,
+    /// Hybrid blob unwrapped without a KEM, or decapsulation failed
+    Decapsulation,
+}
+
+/// Wrapping algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum WrapAlgorithm {
+    /// RFC 3394 AES-256 key wrap
+    AesKw = 1,
+    /// AES-256 key wrap under a KEK hybridized with a Kyber shared secret
+    KyberHybrid = 2,
+}
+
+impl WrapAlgorithm {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(WrapAlgorithm::AesKw),
+            2 => Some(WrapAlgorithm::KyberHybrid),
+            _ => None,
+        }
+    }
+}
+
+/// Encapsulation half of a Kyber KEM (recipient public key)
+pub trait KeyEncapsulator {
+    /// Encapsulate a fresh shared secret
+    ///
+    /// # Inputs
+    /// - `coins`: Encapsulation randomness
+    ///
+    /// # Outputs
+    /// - Shared secret and KEM ciphertext
+    fn encapsulate(&self, coins: &[u8; 32]) -> ([u8; 32], Vec<u8>);
+}
+
+/// Decapsulation half of a Kyber KEM (recipient secret key)
+pub trait KeyDecapsulator {
+    /// Recover the shared secret from a KEM ciphertext
+    ///
+    /// # Outputs
+    /// - `None` if the ciphertext is malformed
+    fn decapsulate(&self, ciphertext: &[u8]) -> Option<[u8; 32]>;
+}
+
+/// Wrapped key material
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrappedKey {
+    /// Format version
+    pub version: u8,
+    /// Wrapping algorithm
+    pub algorithm: WrapAlgorithm,
+    /// KEM ciphertext (empty for `AesKw`)
+    pub kem_ciphertext: Vec<u8>,
+    /// RFC 3394 output (8 bytes longer than the key data)
+    pub wrapped: Vec<u8>,
+}
+
+impl WrappedKey {
+    /// Wrap key material under a KEK with AES-KW
+    ///
+    /// # Inputs
+    /// - `kek`: 256-bit key-encryption key
+    /// - `key_data`: Key material (multiple of 8 bytes, at least 16)
+    pub fn wrap(kek: &[u8; 32], key_data: &[u8]) -> Result<Self, WrapError> {
+        Ok(Self {
+            version: WRAP_FORMAT_VERSION,
+            algorithm: WrapAlgorithm::AesKw,
+            kem_ciphertext: Vec::new(),
+            wrapped: aes_kw_wrap(kek, key_data)?,
+        })
+    }
+
+    /// Wrap key material under a KEK hybridized with a Kyber shared secret
+    ///
+    /// # Inputs
+    /// - `kek`: 256-bit classical key-encryption key
+    /// - `kem`: Recipient's Kyber public key
+    /// - `coins`: Encapsulation randomness
+    /// - `key_data`: Key material (multiple of 8 bytes, at least 16)
+    pub fn wrap_hybrid(
+        kek: &[u8; 32],
+        kem: &dyn KeyEncapsulator,
+        coins: &[u8; 32],
+        key_data: &[u8],
+    ) -> Result<Self, WrapError> {
+        let (mut shared, kem_ciphertext) = kem.encapsulate(coins);
+        if kem_ciphertext.len() > u16::MAX as usize {
+            shared.zeroize();
+            return Err(WrapError::Malformed);
+        }
+        let mut hybrid = hybrid_kek(WRAP_FORMAT_VERSION, kek, &shared, &kem_ciphertext);
+        shared.zeroize();
+        let wrapped = aes_kw_wrap(&hybrid, key_data);
+        hybrid.zeroize();
+        Ok(Self {
+            version: WRAP_FORMAT_VERSION,
+            algorithm: WrapAlgorithm::KyberHybrid,
+            kem_ciphertext,
+            wrapped: wrapped?,
+        })
+    }
+
+    /// Recover the key material
+    ///
+    /// # Inputs
+    /// - `kek`: Key-encryption key used to wrap
+    /// - `kem`: Kyber secret key, required for `KyberHybrid` blobs
+    ///
+    /// # Outputs
+    /// - Key material, or an error if the version, KEK or blob is wrong
+    pub fn unwrap(
+        &self,
+        kek: &[u8; 32],
+        kem: Option<&dyn KeyDecapsulator>,
+    ) -> Result<Vec<u8>, WrapError> {
+        if self.version != WRAP_FORMAT_VERSION {
+            return Err(WrapError::UnsupportedVersion);
+        }
+        match self.algorithm {
+            WrapAlgorithm::AesKw => aes_kw_unwrap(kek, &self.wrapped),
+            WrapAlgorithm::KyberHybrid => {
+                let mut shared = kem
+                    .and_then(|kem| kem.decapsulate(&self.kem_ciphertext))
+                    .ok_or(WrapError::Decapsulation)?;
+                let mut hybrid = hybrid_kek(self.version, kek, &shared, &self.kem_ciphertext);
+                shared.zeroize();
+                let key_data = aes_kw_unwrap(&hybrid, &self.wrapped);
+                hybrid.zeroize();
+                key_data
+            }
+        }
+    }
+
+    /// Serialize: version, algorithm, KEM ciphertext length (u16 LE),
+    /// KEM ciphertext, then the RFC 3394 output
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.kem_ciphertext.len() + self.wrapped.len());
+        bytes.push(self.version);
+        bytes.push(self.algorithm as u8);
+        bytes.extend_from_slice(&(self.kem_ciphertext.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&self.kem_ciphertext);
+        bytes.extend_from_slice(&self.wrapped);
+        bytes
+    }
+
+    /// Parse a serialized wrapped key
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, WrapError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(WrapError::Malformed);
+        }
+        if bytes[0] != WRAP_FORMAT_VERSION {
+            return Err(WrapError::UnsupportedVersion);
+        }
+        let algorithm = WrapAlgorithm::from_u8(bytes[1]).ok_or(WrapError::UnsupportedAlgorithm)?;
+        let kem_len = u16::from_le_bytes([bytes[2], bytes[3]]) as usize;
+        let body = &bytes[HEADER_LEN..];
+        if body.len() < kem_len {
+            return Err(WrapError::Malformed);
+        }
+        let (kem_ciphertext, wrapped) = body.split_at(kem_len);
+        if (algorithm == WrapAlgorithm::AesKw) != kem_ciphertext.is_empty()
+            || wrapped.len() < 24
+            || !wrapped.len().is_multiple_of(8)
+        {
+            return Err(WrapError::Malformed);
+        }
+        Ok(Self {
+            version: bytes[0],
+            algorithm,
+            kem_ciphertext: kem_ciphertext.to_vec(),
+            wrapped: wrapped.to_vec(),
+        })
+    }
+}
+
+/// KEK bound to the classical KEK, the KEM shared secret and the header
+fn hybrid_kek(version: u8, kek: &[u8; 32], shared: &[u8; 32], kem_ciphertext: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(HYBRID_KEK_DOMAIN);
+    hasher.update([version, WrapAlgorithm::KyberHybrid as u8]);
+    hasher.update(kek);
+    hasher.update(shared);
+    hasher.update(kem_ciphertext);
+    hasher.finalize().into()
+}
+
+/// RFC 3394 key wrap (Section 2.2.1) with AES-256
+///
+/// # Inputs
+/// - `kek`: 256-bit key-encryption key
+/// - `key_data`: Plaintext key (multiple of 8 bytes, at least 16)
+///
+/// # Outputs
+/// - Ciphertext, 8 bytes longer than `key_data`
+pub fn aes_kw_wrap(kek: &[u8; 32], key_data: &[u8]) -> Result<Vec<u8>, WrapError> {
+    if key_data.len() < 16 || !key_data.len().is_multiple_of(8) {
+        return Err(WrapError::InvalidLength);
+    }
+    let cipher = Aes256::new(kek.into());
+    let n = key_data.len() / 8;
+    let mut a = AES_KW_IV;
+    let mut r = key_data.to_vec();
+    let mut block = [0u8; 16];
+
+    for j in 0..6 {
+        for i in 0..n {
+            block[..8].copy_from_slice(&a);
+            block[8..].copy_from_slice(&r[i * 8..i * 8 + 8]);
+            cipher.encrypt_block(GenericArray::from_mut_slice(&mut block));
+            let t = ((n * j) + i + 1) as u64;
+            for (k, byte) in t.to_be_bytes().iter().enumerate() {
+                a[k] = block[k] ^ byte;
+            }
+            r[i * 8..i * 8 + 8].copy_from_slice(&block[8..]);
+        }
+    }
+    block.zeroize();
+
+    let mut wrapped = Vec::with_capacity(key_data.len() + 8);
+    wrapped.extend_from_slice(&a);
+    wrapped.extend_from_slice(&r);
+    r.zeroize();
+    Ok(wrapped)
+}
+
+/// RFC 3394 key unwrap (Section 2.2.2) with AES-256
+///
+/// # Outputs
+/// - Plaintext key, or `IntegrityCheckFailed` if the KEK is wrong or the
+///   ciphertext was modified
+pub fn aes_kw_unwrap(kek: &[u8; 32], wrapped: &[u8]) -> Result<Vec<u8>, WrapError> {
+    if wrapped.len() < 24 || !wrapped.len().is_multiple_of(8) {
+        return Err(WrapError::InvalidLength);
+    }
+    let cipher = Aes256::new(kek.into());
+    let n = wrapped.len() / 8 - 1;
+    let mut a = [0u8; 8];
+    a.copy_from_slice(&wrapped[..8]);
+    let mut r = wrapped[8..].to_vec();
+    let mut block = [0u8; 16];
+
+    for j in (0..6).rev() {
+        for i in (0..n).rev() {
+            let t = ((n * j) + i + 1) as u64;
+            for (k, byte) in t.to_be_bytes().iter().enumerate() {
+                block[k] = a[k] ^ byte;
+            }
+            block[8..].copy_from_slice(&r[i * 8..i * 8 + 8]);
+            cipher.decrypt_block(GenericArray::from_mut_slice(&mut block));
+            a.copy_from_slice(&block[..8]);
+            r[i * 8..i * 8 + 8].copy_from_slice(&block[8..]);
+        }
+    }
+    block.zeroize();
+
+    // Constant-time IV comparison
+    let diff = a.iter().zip(AES_KW_IV.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    if diff != 0 {
+        r.zeroize();
+        return Err(WrapError::IntegrityCheckFailed);
+    }
+    Ok(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    /// Toy KEM: public key = SHA3(secret), ciphertext = coins ⊕ SHA3(public)
+    struct TestKem {
+        public: [u8; 32],
+    }
+
+    impl TestKem {
+        fn pad(&self) -> [u8; 32] {
+            Sha3_256::digest(self.public).into()
+        }
+    }
+
+    impl KeyEncapsulator for TestKem {
+        fn encapsulate(&self, coins: &[u8; 32]) -> ([u8; 32], Vec<u8>) {
+            let pad = self.pad();
+            let ciphertext = coins.iter().zip(pad.iter()).map(|(c, p)| c ^ p).collect();
+            (Sha3_256::digest(coins).into(), ciphertext)
+        }
+    }
+
+    impl KeyDecapsulator for TestKem {
+        fn decapsulate(&self, ciphertext: &[u8]) -> Option<[u8; 32]> {
+            if ciphertext.len() != 32 {
+                return None;
+            }
+            let pad = self.pad();
+            let coins: Vec<u8> = ciphertext.iter().zip(pad.iter()).map(|(c, p)| c ^ p).collect();
+            Some(Sha3_256::digest(coins).into())
+        }
+    }
+
+    #[test]
+    fn test_aes_kw_rfc3394_vectors() {
+        let kek: [u8; 32] = hex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F")
+            .try_into()
+            .unwrap();
+
+        // RFC 3394 Section 4.3: 128 bits of key data with a 256-bit KEK
+        let data = hex("00112233445566778899AABBCCDDEEFF");
+        let wrapped = aes_kw_wrap(&kek, &data).unwrap();
+        assert_eq!(wrapped, hex("64E8C3F9CE0F5BA263E9777905818A2A93C8191E7D6E8AE7"));
+        assert_eq!(aes_kw_unwrap(&kek, &wrapped).unwrap(), data);
+
+        // RFC 3394 Section 4.6: 256 bits of key data with a 256-bit KEK
+        let data = hex("00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F");
+        let wrapped = aes_kw_wrap(&kek, &data).unwrap();
+        assert_eq!(
+            wrapped,
+            hex("28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21")
+        );
+        assert_eq!(aes_kw_unwrap(&kek, &wrapped).unwrap(), data);
+
+        // Wrong KEK or tampering fails the integrity check
+        assert_eq!(aes_kw_unwrap(&[0u8; 32], &wrapped), Err(WrapError::IntegrityCheckFailed));
+        let mut tampered = wrapped.clone();
+        tampered[20] ^= 1;
+        assert_eq!(aes_kw_unwrap(&kek, &tampered), Err(WrapError::IntegrityCheckFailed));
+        assert_eq!(aes_kw_wrap(&kek, &[0u8; 12]), Err(WrapError::InvalidLength));
+    }
+
+    #[test]
+    fn test_hybrid_wrap_and_format() {
+        let kek = [7u8; 32];
+        let kem = TestKem { public: [9u8; 32] };
+        let key_data = [0x5Au8; 64];
+
+        let wrapped = WrappedKey::wrap_hybrid(&kek, &kem, &[3u8; 32], &key_data).unwrap();
+        assert_eq!(wrapped.algorithm, WrapAlgorithm::KyberHybrid);
+        assert_eq!(wrapped.unwrap(&kek, Some(&kem)).unwrap(), key_data);
+
+        // Both the classical KEK and the KEM are required
+        assert_eq!(wrapped.unwrap(&kek, None), Err(WrapError::Decapsulation));
+        assert_eq!(wrapped.unwrap(&[8u8; 32], Some(&kem)), Err(WrapError::IntegrityCheckFailed));
+        let other = TestKem { public: [10u8; 32] };
+        assert_eq!(wrapped.unwrap(&kek, Some(&other)), Err(WrapError::IntegrityCheckFailed));
+
+        // Serialization round-trips; unknown versions and algorithms are rejected
+        let bytes = wrapped.to_bytes();
+        assert_eq!(WrappedKey::from_bytes(&bytes), Ok(wrapped.clone()));
+        let mut future = bytes.clone();
+        future[0] = WRAP_FORMAT_VERSION + 1;
+        assert_eq!(WrappedKey::from_bytes(&future), Err(WrapError::UnsupportedVersion));
+        let mut unknown = bytes.clone();
+        unknown[1] = 0xFF;
+        assert_eq!(WrappedKey::from_bytes(&unknown), Err(WrapError::UnsupportedAlgorithm));
+        assert_eq!(WrappedKey::from_bytes(&bytes[..40]), Err(WrapError::Malformed));
+
+        let classical = WrappedKey::wrap(&kek, &key_data).unwrap();
+        let parsed = WrappedKey::from_bytes(&classical.to_bytes()).unwrap();
+        assert_eq!(parsed.unwrap(&kek, None).unwrap(), key_data);
+    }
+}
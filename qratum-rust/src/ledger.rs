@@ -10,6 +10,10 @@
 //! - **Ephemeral State**: Exists only during session (RAM-only)
 //! - **Merkle Integrity**: Cryptographic verification of ledger state
 //! - **Session Rollback**: Revert to previous state within current session
+//! - **Inclusion Proofs**: Compact proofs that a TXO id is a ledger leaf,
+//!   verifiable against the root alone (no_std, no ledger access)
+//! - **Archival Checkpoints**: SPHINCS+ signed, hash-chained roots so outcome
+//!   TXOs persisted beyond the session stay verifiable for decades
 //! - **Zero Persistence**: Complete zeroization on session end
 //!
 //! ## Security Rationale
@@ -18,38 +22,48 @@
 //! - Rollback limited to current session (no inter-session rollback)
 //! - Zeroization prevents memory forensics
 //! - No disk writes (anti-holographic)
+//! - Checkpoint signatures are hash-based, so they do not depend on the
+//!   discrete-log or lattice assumptions that may fall within their lifetime
 
 
 extern crate alloc;
 use alloc::vec::Vec;
 
+use alloc::string::String;
+
 use crate::txo::Txo;
+use minicbor::{Decode, Encode};
 use sha3::{Sha3_256, Digest};
-use zeroize::{Zeroize, ZeroizeOnDrop};
 
-/// Merkle Node
-#[derive(Debug, Clone, Zeroize, ZeroizeOnDrop)]
-struct MerkleNode {
-    hash: [u8; 32],
-    left: Option<usize>,
-    right: Option<usize>,
-}
+/// Domain separator for checkpoint signatures
+const CHECKPOINT_DOMAIN: &[u8] = b"QRATUM-LEDGER-CHECKPOINT-v1";
+
+/// Leaves per subtree below which bulk builds stay on one thread
+#[cfg(feature = "std")]
+const PARALLEL_MIN_LEAVES: usize = 4096;
 
 /// In-Memory Merkle Ledger
 ///
 /// ## Lifecycle Stage: Ephemeral Materialization → Self-Destruction
 ///
 /// Tracks all TXOs in ephemeral Merkle tree. Zeroized on session end.
+///
+/// Leaves are TXO IDs. Each level pairs adjacent nodes as
+/// `SHA3-256(left || right)`; a trailing unpaired node is hashed alone.
+/// Every level is cached so appends only rehash the right edge.
 #[derive(Clone)]
 pub struct MerkleLedger {
     /// TXO storage (leaf nodes)
     txos: Vec<Txo>,
     
-    /// Merkle tree nodes
-    nodes: Vec<MerkleNode>,
+    /// Merkle tree levels, leaves first; the last level holds the root
+    levels: Vec<Vec<[u8; 32]>>,
     
     /// Root hash
     root_hash: [u8; 32],
+    
+    /// Signed archival checkpoints, oldest first
+    signed_checkpoints: Vec<LedgerCheckpoint>,
 }
 
 impl MerkleLedger {
@@ -57,11 +71,32 @@ impl MerkleLedger {
     pub fn new() -> Self {
         Self {
             txos: Vec::new(),
-            nodes: Vec::new(),
+            levels: Vec::new(),
             root_hash: [0u8; 32],
+            signed_checkpoints: Vec::new(),
         }
     }
     
+    /// Build a ledger from an exported TXO sequence in one pass
+    ///
+    /// ## Lifecycle Stage: Ephemeral Materialization
+    ///
+    /// Bulk import path: the tree is built once instead of being rehashed
+    /// per append. With `std`, large imports build aligned subtrees on
+    /// separate threads and merge them in order; the root is identical to
+    /// appending the same TXOs one by one.
+    pub fn from_txos(txos: Vec<Txo>) -> Self {
+        let leaves: Vec<[u8; 32]> = txos.iter().map(|txo| txo.id).collect();
+        
+        #[cfg(feature = "std")]
+        let levels = build_levels_parallel(leaves, PARALLEL_MIN_LEAVES);
+        #[cfg(not(feature = "std"))]
+        let levels = build_levels(leaves, None);
+        
+        let root_hash = levels.last().map_or([0u8; 32], |top| top[0]);
+        Self { txos, levels, root_hash, signed_checkpoints: Vec::new() }
+    }
+    
     /// Append TXO to ledger
     ///
     /// ## Lifecycle Stage: Execution
@@ -71,8 +106,43 @@ impl MerkleLedger {
     /// - Recomputes Merkle root
     /// - Logs append event
     pub fn append(&mut self, txo: Txo) {
-        self.txos.push(txo);
-        self.recompute_root();
+        self.append_range(core::iter::once(txo));
+    }
+    
+    /// Append a contiguous range of TXOs with a single root update
+    ///
+    /// ## Lifecycle Stage: Execution
+    ///
+    /// Only nodes on the right edge of the tree are rehashed.
+    pub fn append_range<I: IntoIterator<Item = Txo>>(&mut self, txos: I) {
+        let start = self.txos.len();
+        for txo in txos {
+            if self.levels.is_empty() {
+                self.levels.push(Vec::new());
+            }
+            self.levels[0].push(txo.id);
+            self.txos.push(txo);
+        }
+        if self.txos.len() == start {
+            return;
+        }
+        
+        // Rehash from the parent of the first changed node upward
+        let mut dirty = start;
+        let mut depth = 0;
+        while self.levels[depth].len() > 1 {
+            let parent = dirty / 2;
+            let next = hash_level_from(&self.levels[depth], parent * 2);
+            if self.levels.len() == depth + 1 {
+                self.levels.push(Vec::new());
+            }
+            let upper = &mut self.levels[depth + 1];
+            upper.truncate(parent);
+            upper.extend(next);
+            dirty = parent;
+            depth += 1;
+        }
+        self.root_hash = self.levels[depth][0];
     }
     
     /// Get current root hash
@@ -95,9 +165,53 @@ impl MerkleLedger {
         self.txos.len()
     }
     
-    /// Recompute Merkle root
-    fn recompute_root(&mut self) {
-        self.root_hash = self.compute_root_from_txos();
+    /// Prove that the TXO at `index` is included under the current root
+    ///
+    /// # Outputs
+    /// - `Some(MerkleProof)` for an existing entry, verified with
+    ///   `MerkleProof::verify(root_hash, txo_id)`
+    /// - `None` if `index` is out of range
+    pub fn prove_inclusion(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.txos.len() {
+            return None;
+        }
+        Some(proof_from_levels(&self.levels, index))
+    }
+    
+    /// Sign the current root for long-term archival
+    ///
+    /// ## Lifecycle Stage: Execution → Outcome Commitment
+    ///
+    /// # Inputs
+    /// - `signer`: SPHINCS+ (SLH-DSA) signing backend
+    /// - `timestamp`: Checkpoint time (milliseconds)
+    ///
+    /// # Outputs
+    /// - The new checkpoint, also appended to `checkpoint_chain()`
+    ///
+    /// ## Security Rationale
+    /// - Each checkpoint commits to the previous one, so archived chains
+    ///   cannot be reordered or have entries dropped from the middle
+    pub fn sign_checkpoint(&mut self, signer: &dyn ArchivalSigner, timestamp: u64) -> LedgerCheckpoint {
+        let previous = self.signed_checkpoints.last().map_or([0u8; 32], LedgerCheckpoint::hash);
+        let mut checkpoint = LedgerCheckpoint {
+            sequence: self.signed_checkpoints.len() as u64,
+            root_hash: self.root_hash,
+            txo_count: self.txos.len() as u64,
+            timestamp,
+            previous,
+            scheme: signer.scheme().into(),
+            signer: fingerprint(&signer.public_key()),
+            signature: Vec::new(),
+        };
+        checkpoint.signature = signer.sign(&checkpoint.signing_bytes());
+        self.signed_checkpoints.push(checkpoint.clone());
+        checkpoint
+    }
+    
+    /// Signed checkpoints, oldest first
+    pub fn checkpoint_chain(&self) -> &[LedgerCheckpoint] {
+        &self.signed_checkpoints
     }
     
     /// Compute root hash from TXOs
@@ -112,24 +226,316 @@ impl MerkleLedger {
             .collect();
         
         while level.len() > 1 {
-            let mut next_level = Vec::new();
-            
-            for chunk in level.chunks(2) {
-                let mut hasher = Sha3_256::new();
-                hasher.update(&chunk[0]);
-                if chunk.len() > 1 {
-                    hasher.update(&chunk[1]);
-                }
-                next_level.push(hasher.finalize().into());
-            }
-            
-            level = next_level;
+            level = hash_level_from(&level, 0);
         }
         
         level[0]
     }
 }
 
+/// Merkle inclusion proof for one leaf
+///
+/// Siblings are listed leaves first. A `None` sibling marks a trailing
+/// unpaired node, which the tree hashes alone.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct MerkleProof {
+    /// Leaf position
+    #[n(0)]
+    pub index: u64,
+    
+    /// Sibling per level (`None` where the node was unpaired)
+    #[n(1)]
+    pub siblings: Vec<Option<[u8; 32]>>,
+}
+
+impl MerkleProof {
+    /// Recompute the root from `leaf` along this path
+    pub fn compute_root(&self, leaf: &[u8; 32]) -> [u8; 32] {
+        let mut node = *leaf;
+        let mut index = self.index;
+        for sibling in &self.siblings {
+            let mut hasher = Sha3_256::new();
+            match sibling {
+                Some(s) if index.is_multiple_of(2) => {
+                    hasher.update(node);
+                    hasher.update(s);
+                }
+                Some(s) => {
+                    hasher.update(s);
+                    hasher.update(node);
+                }
+                None => hasher.update(node),
+            }
+            node = hasher.finalize().into();
+            index /= 2;
+        }
+        node
+    }
+    
+    /// Verify that `leaf` is included under `root`
+    ///
+    /// ## Security Rationale
+    /// - Rejects paths that do not end at the root (index out of range)
+    /// - Rejects `None` siblings on left children, which only occur at the
+    ///   right edge and would otherwise let a proof skip a level
+    pub fn verify(&self, root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+        if self.siblings.len() >= 64 || self.index >> self.siblings.len() != 0 {
+            return false;
+        }
+        let mut index = self.index;
+        for sibling in &self.siblings {
+            if sibling.is_none() && !index.is_multiple_of(2) {
+                return false;
+            }
+            index /= 2;
+        }
+        self.compute_root(leaf) == *root
+    }
+}
+
+/// Long-term checkpoint signing backend
+///
+/// Intended for SPHINCS+ / SLH-DSA (FIPS 205, `crypto/pqc::slh_dsa`); the
+/// stateless scheme needs no signature counter across sessions.
+pub trait ArchivalSigner {
+    /// Parameter set name, e.g. `"SLH-DSA-SHAKE-256s"`
+    fn scheme(&self) -> &str;
+    
+    /// Signer's public key
+    fn public_key(&self) -> Vec<u8>;
+    
+    /// Sign `message`
+    fn sign(&self, message: &[u8]) -> Vec<u8>;
+}
+
+/// Long-term checkpoint verification backend
+pub trait ArchivalVerifier {
+    /// Verify `signature` over `message` under `public_key` for parameter set `scheme`
+    fn verify(&self, scheme: &str, public_key: &[u8], message: &[u8], signature: &[u8]) -> bool;
+}
+
+/// SPHINCS+ signed ledger root
+///
+/// ## Lifecycle Stage: Outcome Commitment (persisted)
+///
+/// One of the few artifacts meant to outlive the session: it lets an
+/// archived outcome TXO be checked against a signed root with a
+/// `MerkleProof`, long after the session and its ledger are gone.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct LedgerCheckpoint {
+    /// Position in the chain (0 = first)
+    #[n(0)]
+    pub sequence: u64,
+    
+    /// Ledger root at signing time
+    #[n(1)]
+    pub root_hash: [u8; 32],
+    
+    /// TXOs under `root_hash`
+    #[n(2)]
+    pub txo_count: u64,
+    
+    /// Signing time (milliseconds)
+    #[n(3)]
+    pub timestamp: u64,
+    
+    /// `hash()` of the previous checkpoint (zeros for the first)
+    #[n(4)]
+    pub previous: [u8; 32],
+    
+    /// Signature parameter set
+    #[n(5)]
+    pub scheme: String,
+    
+    /// SHA3-256 of the signer's public key
+    #[n(6)]
+    pub signer: [u8; 32],
+    
+    /// Signature over `signing_bytes()`
+    #[n(7)]
+    pub signature: Vec<u8>,
+}
+
+impl LedgerCheckpoint {
+    /// Bytes covered by the signature: domain separator, then the CBOR
+    /// encoding with an empty signature
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let unsigned = LedgerCheckpoint { signature: Vec::new(), ..self.clone() };
+        let mut bytes = CHECKPOINT_DOMAIN.to_vec();
+        bytes.extend_from_slice(&unsigned.to_cbor());
+        bytes
+    }
+    
+    /// SHA3-256 of the full (signed) encoding; linked by the next checkpoint
+    pub fn hash(&self) -> [u8; 32] {
+        Sha3_256::digest(self.to_cbor()).into()
+    }
+    
+    /// Check an archived TXO against this checkpoint's root
+    pub fn verify_inclusion(&self, proof: &MerkleProof, txo_id: &[u8; 32]) -> bool {
+        proof.index < self.txo_count && proof.verify(&self.root_hash, txo_id)
+    }
+    
+    /// Serialize to CBOR
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).unwrap_or_default()
+    }
+    
+    /// Deserialize from CBOR
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, minicbor::decode::Error> {
+        minicbor::decode(bytes)
+    }
+}
+
+/// Checkpoint chain verification failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckpointError {
+    /// No checkpoints to verify
+    EmptyChain,
+    /// Checkpoint at `index` does not carry sequence number `index`
+    SequenceMismatch(usize),
+    /// Checkpoint at `index` does not link to its predecessor
+    BrokenLink(usize),
+    /// Checkpoint at `index` was signed by a different key
+    UntrustedSigner(usize),
+    /// Checkpoint at `index` covers fewer TXOs or is older than its predecessor
+    Regressed(usize),
+    /// Signature on checkpoint at `index` does not verify
+    InvalidSignature(usize),
+}
+
+/// Verify an archived checkpoint chain
+///
+/// # Inputs
+/// - `chain`: Checkpoints in order, starting at sequence 0
+/// - `public_key`: The archival signer's public key
+/// - `verifier`: SPHINCS+ verification backend
+///
+/// # Outputs
+/// - `Ok(())` if every checkpoint is signed by `public_key`, links to its
+///   predecessor, and never shrinks the ledger or goes back in time
+pub fn verify_checkpoint_chain(
+    chain: &[LedgerCheckpoint],
+    public_key: &[u8],
+    verifier: &dyn ArchivalVerifier,
+) -> Result<(), CheckpointError> {
+    if chain.is_empty() {
+        return Err(CheckpointError::EmptyChain);
+    }
+    let signer = fingerprint(public_key);
+    let mut previous: Option<&LedgerCheckpoint> = None;
+    for (index, checkpoint) in chain.iter().enumerate() {
+        if checkpoint.sequence != index as u64 {
+            return Err(CheckpointError::SequenceMismatch(index));
+        }
+        if checkpoint.previous != previous.map_or([0u8; 32], LedgerCheckpoint::hash) {
+            return Err(CheckpointError::BrokenLink(index));
+        }
+        if checkpoint.signer != signer {
+            return Err(CheckpointError::UntrustedSigner(index));
+        }
+        if previous.is_some_and(|p| checkpoint.txo_count < p.txo_count || checkpoint.timestamp < p.timestamp) {
+            return Err(CheckpointError::Regressed(index));
+        }
+        if !verifier.verify(&checkpoint.scheme, public_key, &checkpoint.signing_bytes(), &checkpoint.signature) {
+            return Err(CheckpointError::InvalidSignature(index));
+        }
+        previous = Some(checkpoint);
+    }
+    Ok(())
+}
+
+/// SHA3-256 public key fingerprint
+fn fingerprint(public_key: &[u8]) -> [u8; 32] {
+    Sha3_256::digest(public_key).into()
+}
+
+/// Inclusion proof for leaf `index` from cached tree levels
+pub(crate) fn proof_from_levels(levels: &[Vec<[u8; 32]>], index: usize) -> MerkleProof {
+    let mut siblings = Vec::new();
+    let mut position = index;
+    for level in levels.iter().take(levels.len().saturating_sub(1)) {
+        let sibling = if position.is_multiple_of(2) { level.get(position + 1).copied() } else { Some(level[position - 1]) };
+        siblings.push(sibling);
+        position /= 2;
+    }
+    MerkleProof { index: index as u64, siblings }
+}
+
+/// Hash one tree level into its parents, starting at (even) index `from`
+fn hash_level_from(level: &[[u8; 32]], from: usize) -> Vec<[u8; 32]> {
+    level[from..]
+        .chunks(2)
+        .map(|chunk| {
+            let mut hasher = Sha3_256::new();
+            hasher.update(chunk[0]);
+            if chunk.len() > 1 {
+                hasher.update(chunk[1]);
+            }
+            hasher.finalize().into()
+        })
+        .collect()
+}
+
+/// Build tree levels bottom-up
+///
+/// With `depth: None` levels are built up to the single root; with
+/// `Some(k)` exactly `k` levels are built above the leaves.
+pub(crate) fn build_levels(leaves: Vec<[u8; 32]>, depth: Option<u32>) -> Vec<Vec<[u8; 32]>> {
+    if leaves.is_empty() {
+        return Vec::new();
+    }
+    let mut levels = alloc::vec![leaves];
+    loop {
+        let top = &levels[levels.len() - 1];
+        let done = match depth {
+            Some(k) => levels.len() > k as usize,
+            None => top.len() == 1,
+        };
+        if done {
+            return levels;
+        }
+        let next = hash_level_from(top, 0);
+        levels.push(next);
+    }
+}
+
+/// Build tree levels with subtrees of `2^k >= min_leaves` leaves on separate threads
+///
+/// Subtrees are aligned to power-of-two boundaries, so after `k` levels the
+/// concatenated subtree levels equal the sequential ones exactly, including
+/// the trailing partial subtree whose unpaired nodes are hashed alone.
+#[cfg(feature = "std")]
+fn build_levels_parallel(leaves: Vec<[u8; 32]>, min_leaves: usize) -> Vec<Vec<[u8; 32]>> {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let subtree = leaves.len().div_ceil(threads).max(min_leaves).next_power_of_two();
+    if leaves.len() <= subtree {
+        return build_levels(leaves, None);
+    }
+    let depth = subtree.trailing_zeros();
+    
+    let subtrees: Vec<Vec<Vec<[u8; 32]>>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = leaves
+            .chunks(subtree)
+            .map(|chunk| scope.spawn(move || build_levels(chunk.to_vec(), Some(depth))))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("subtree builder panicked"))
+            .collect()
+    });
+    
+    // Merge: level j of the full tree is level j of each subtree, in order
+    let mut levels: Vec<Vec<[u8; 32]>> = (0..=depth as usize)
+        .map(|j| subtrees.iter().flat_map(|t| t[j].iter().copied()).collect())
+        .collect();
+    while levels[levels.len() - 1].len() > 1 {
+        let next = hash_level_from(&levels[levels.len() - 1], 0);
+        levels.push(next);
+    }
+    levels
+}
+
 impl Default for MerkleLedger {
     fn default() -> Self {
         Self::new()
@@ -237,6 +643,156 @@ mod tests {
         assert!(ledger.verify_integrity());
     }
     
+    fn txos(n: usize) -> Vec<Txo> {
+        (0..n as u64).map(|i| Txo::new(TxoType::Input, i, i.to_le_bytes().to_vec(), Vec::new())).collect()
+    }
+    
+    #[test]
+    fn test_range_append_matches_full_rebuild() {
+        let mut ledger = MerkleLedger::new();
+        ledger.append_range(Vec::new());
+        assert_eq!(ledger.root_hash(), [0u8; 32]);
+        
+        let all = txos(100);
+        let mut taken = 0;
+        for len in [1, 1, 2, 5, 8, 13, 0, 70] {
+            ledger.append_range(all[taken..taken + len].iter().cloned());
+            taken += len;
+            assert!(ledger.verify_integrity(), "after {} TXOs", taken);
+        }
+        assert_eq!(ledger.txo_count(), 100);
+        assert_eq!(ledger.root_hash(), MerkleLedger::from_txos(all).root_hash());
+    }
+    
+    #[test]
+    fn test_bulk_build_matches_sequential() {
+        for n in [0, 1, 2, 3, 31, 32, 33, 257] {
+            let ledger = MerkleLedger::from_txos(txos(n));
+            assert!(ledger.verify_integrity(), "{} TXOs", n);
+        }
+        
+        // Force many small subtrees, including a partial trailing one
+        #[cfg(feature = "std")]
+        for (n, min_leaves) in [(100, 8), (1000, 16), (1025, 64)] {
+            let leaves: Vec<[u8; 32]> = txos(n).iter().map(|t| t.id).collect();
+            assert_eq!(build_levels_parallel(leaves.clone(), min_leaves), build_levels(leaves, None));
+        }
+    }
+    
+    #[test]
+    fn test_inclusion_proofs() {
+        assert!(MerkleLedger::new().prove_inclusion(0).is_none());
+        
+        for n in [1, 2, 3, 7, 8, 33] {
+            let all = txos(n);
+            let ledger = MerkleLedger::from_txos(all.clone());
+            let root = ledger.root_hash();
+            for (i, txo) in all.iter().enumerate() {
+                let proof = ledger.prove_inclusion(i).unwrap();
+                assert!(proof.verify(&root, &txo.id), "{} of {} TXOs", i, n);
+                let decoded: MerkleProof = minicbor::decode(&minicbor::to_vec(&proof).unwrap()).unwrap();
+                assert_eq!(decoded, proof);
+            }
+            assert!(ledger.prove_inclusion(n).is_none());
+        }
+        
+        let all = txos(5);
+        let ledger = MerkleLedger::from_txos(all.clone());
+        let root = ledger.root_hash();
+        let proof = ledger.prove_inclusion(4).unwrap();
+        assert_eq!(proof.siblings[0], None);
+        
+        // Wrong leaf, wrong position, or a stale root all fail
+        assert!(!proof.verify(&root, &all[3].id));
+        let mut moved = ledger.prove_inclusion(1).unwrap();
+        moved.index = 0;
+        assert!(!moved.verify(&root, &all[1].id));
+        moved.index = 9;
+        assert!(!moved.verify(&root, &all[1].id));
+        let mut grown = ledger.clone();
+        grown.append(txos(6).pop().unwrap());
+        assert!(!proof.verify(&grown.root_hash(), &all[4].id));
+        assert!(grown.prove_inclusion(4).unwrap().verify(&grown.root_hash(), &all[4].id));
+    }
+    
+    /// Stand-in for an SLH-DSA key: the signature is a hash keyed by the public key
+    struct TestArchival([u8; 32]);
+    
+    impl ArchivalSigner for TestArchival {
+        fn scheme(&self) -> &str {
+            "SLH-DSA-SHAKE-256s"
+        }
+        
+        fn public_key(&self) -> Vec<u8> {
+            self.0.to_vec()
+        }
+        
+        fn sign(&self, message: &[u8]) -> Vec<u8> {
+            Sha3_256::new().chain_update(self.0).chain_update(message).finalize().to_vec()
+        }
+    }
+    
+    impl ArchivalVerifier for TestArchival {
+        fn verify(&self, scheme: &str, public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+            scheme == "SLH-DSA-SHAKE-256s"
+                && Sha3_256::new().chain_update(public_key).chain_update(message).finalize().as_slice() == signature
+        }
+    }
+    
+    #[test]
+    fn test_checkpoint_chain() {
+        let key = TestArchival([7u8; 32]);
+        let all = txos(10);
+        let mut ledger = MerkleLedger::new();
+        assert_eq!(verify_checkpoint_chain(ledger.checkpoint_chain(), &key.0, &key), Err(CheckpointError::EmptyChain));
+        
+        for (i, txo) in all.iter().enumerate() {
+            ledger.append(txo.clone());
+            if i % 3 == 2 {
+                ledger.sign_checkpoint(&key, 1_000 + i as u64);
+            }
+        }
+        let chain = ledger.checkpoint_chain().to_vec();
+        assert_eq!(chain.len(), 3);
+        assert_eq!(verify_checkpoint_chain(&chain, &key.0, &key), Ok(()));
+        
+        // Archived form survives a CBOR round trip
+        let archived: Vec<LedgerCheckpoint> = chain.iter()
+            .map(|c| LedgerCheckpoint::from_cbor(&c.to_cbor()).unwrap())
+            .collect();
+        assert_eq!(verify_checkpoint_chain(&archived, &key.0, &key), Ok(()));
+        
+        // An archived outcome TXO checks against the checkpoint that covers it
+        let proof = MerkleLedger::from_txos(all[..9].to_vec()).prove_inclusion(4).unwrap();
+        assert!(chain[2].verify_inclusion(&proof, &all[4].id));
+        assert!(!chain[2].verify_inclusion(&proof, &all[5].id));
+        assert!(!chain[1].verify_inclusion(&proof, &all[4].id));
+        
+        // Wrong key
+        assert_eq!(verify_checkpoint_chain(&chain, &[8u8; 32], &key), Err(CheckpointError::UntrustedSigner(0)));
+        // Middle checkpoint dropped, or chain reordered
+        let gapped = [chain[0].clone(), chain[2].clone()];
+        assert_eq!(verify_checkpoint_chain(&gapped, &key.0, &key), Err(CheckpointError::SequenceMismatch(1)));
+        let mut relinked = chain.clone();
+        relinked[1].previous = [0u8; 32];
+        assert_eq!(verify_checkpoint_chain(&relinked, &key.0, &key), Err(CheckpointError::BrokenLink(1)));
+        // Root altered after signing
+        let mut altered = chain.clone();
+        altered[2].root_hash = [9u8; 32];
+        assert_eq!(verify_checkpoint_chain(&altered, &key.0, &key), Err(CheckpointError::InvalidSignature(2)));
+        // Altering an earlier checkpoint breaks the next link
+        let mut rewritten = chain.clone();
+        rewritten[0].timestamp += 1;
+        assert_eq!(verify_checkpoint_chain(&rewritten, &key.0, &key), Err(CheckpointError::InvalidSignature(0)));
+        assert_eq!(verify_checkpoint_chain(&rewritten[1..], &key.0, &key), Err(CheckpointError::SequenceMismatch(0)));
+        
+        // A re-signed checkpoint that shrinks the ledger is rejected
+        let mut shrunk = chain.clone();
+        shrunk[2].txo_count = 1;
+        shrunk[2].signature = key.sign(&shrunk[2].signing_bytes());
+        assert_eq!(verify_checkpoint_chain(&shrunk, &key.0, &key), Err(CheckpointError::Regressed(2)));
+    }
+    
     #[test]
     fn test_rollback_ledger() {
         let mut ledger = RollbackLedger::new(5);
@@ -59,13 +59,26 @@
 //!
 //! - [`txo`]: Transaction Object types (Input, Outcome, Audit TXOs)
 //! - [`biokey`]: Ephemeral key derivation with Shamir secret sharing
+//! - [`entropy`]: SP 800-90B continuous health tests over session entropy sources
+//! - [`deadman`]: Stage deadlines and quorum member dead-man switch
+//! - [`conformance`]: Typed lifecycle state machine and runtime conformance checker
+//! - [`rotation`]: Biokey rotation ahead of expiry with consumer hooks
 //! - [`quorum`]: Convergence logic with progressive decay
+//! - [`degraded`]: Partial quorum continuation with reduced capabilities
 //! - [`canary`]: Censorship detection probes
 //! - [`snapshot`]: Volatile encrypted snapshots for fault recovery
+//! - [`keywrap`]: AES-KW and Kyber-hybrid wrapping of key material at rest
+//! - [`secure_alloc`]: Guarded, locked-in-RAM storage for key material
+//! - [`secrets`]: Session-scoped secret broker with capability-gated access
+//! - [`hbs`]: LMS stateful hash-based signatures for protocol upgrade signing
 //! - [`proxy`]: Bonded approvals with reputation staking
 //! - [`compliance`]: Zero-knowledge compliance attestations
 //! - [`blinded`]: Payload blinding with quorum-controlled reveal
-//! - [`ledger`]: In-memory Merkle ledger with session-bound rollback
+//! - [`chaos`]: Fault-injecting transport proxy for adverse-network tests (test builds, `chaos` feature)
+//! - [`secure_channel`]: Hybrid X25519 + Kyber key exchange and rekeying for transport channels (std)
+//! - [`threshold`]: Dealerless M-of-N threshold decryption of blinded payloads
+//! - [`cosign`]: Distributed validator signing ceremony with audit transcript
+//! - [`ledger`]: In-memory Merkle ledger with session-bound rollback and SPHINCS+ archival checkpoints
 //! - [`watchdog`]: Nomadic epoch-rotating validators
 //! - [`lifecycle`]: 5-stage session orchestration
 //!
@@ -100,56 +113,126 @@
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+// `vec!`/`format!` come from alloc, not the std prelude
+#[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 // Re-export core types and functions
 pub use txo::{Txo, TxoType, OutcomeTxo, BlindedPayload, ComplianceZkp};
-pub use biokey::{EphemeralBiokey, ShamirShare, ShamirSecretSharing, BiokeyEscrow};
-pub use quorum::{QuorumConfig, QuorumMember, QuorumVote, DecayJustification, ConvergenceResult};
+pub use biokey::{EphemeralBiokey, ShamirShare, ShamirSecretSharing, BiokeyEscrow, WrappedShare};
+pub use quorum::{QuorumConfig, QuorumMember, QuorumVote, DecayJustification, ConvergenceResult,
+                 DecayPolicy, DecayPolicyError, LinearDecay, ExponentialDecay, StepwiseDecay};
+pub use entropy::{EntropyHealthMonitor, HealthConfig, HealthAction, HealthError, SourceHealth, verify_abort_txo};
+pub use entropy::collector::{EntropyCollector, HardwareCollector, JitterCollector, CollectorError, collect_contributions};
+pub use deadman::{DeadmanConfig, SessionStage, SessionWatchdog, WatchdogExpiry};
+pub use conformance::{LifecycleConformance, LifecycleState, Artifact, TransitionRule, ConformanceError, TRANSITIONS};
+pub use rotation::{BiokeyScheduler, RotationEvent, RotationReason, RotationError, RotationCallback};
+pub use degraded::{Capability, DegradedPolicy, QuorumMode, DegradedError, DegradationMonitor};
 pub use canary::{CanaryConfig, CanaryProbe, CanaryState, CanaryVerifier};
 pub use snapshot::{SnapshotConfig, VolatileSnapshot, SnapshotManager};
+pub use keywrap::{WrappedKey, WrapAlgorithm, WrapError, KeyEncapsulator, KeyDecapsulator, WRAP_FORMAT_VERSION};
+pub use secure_alloc::KeyMaterial;
+pub use secrets::{SecretBroker, SecretHandle, SecretKind, Subsystem, Permission, AccessToken, AccessRecord,
+                  BrokerError, DestructionReport};
+pub use hbs::{LmsSigner, LmsPublicKey, LmsSignature, LmsHeight, LmsStateStore, HbsError};
 pub use proxy::{ProxyConfig, ProxyParticipant, ProxyApproval, ProxyApprovalRequest, ProxyManager};
 pub use compliance::{ComplianceProver, ComplianceVerifier, ComplianceAttestation, CircuitType, ProverConfig, ZkpBackend};
 pub use blinded::BlindedPayloadManager;
-pub use ledger::{MerkleLedger, RollbackLedger};
-pub use watchdog::{WatchdogConfig, WatchdogValidator, AuditAttestation, WatchdogManager};
+pub use threshold::{ThresholdPublicKey, ThresholdCiphertext, KeyShare, MemberKey, PartialDecryption, ThresholdError, DkgDealer, DkgCommitment, DkgShare};
+pub use ledger::{MerkleLedger, MerkleProof, RollbackLedger, LedgerCheckpoint, ArchivalSigner, ArchivalVerifier,
+                 CheckpointError, verify_checkpoint_chain};
+pub use watchdog::{WatchdogConfig, WatchdogValidator, AuditAttestation, WatchdogManager,
+                   WatchdogBeacon, BeaconEntry, ContactHint, BeaconSigner, BeaconVerifier, BeaconAnchor,
+                   BeaconError, verify_beacon, publish_beacon};
 pub use lifecycle::{SessionConfig, QratumError, run_qratum_session, run_qratum_session_with_config};
 
 // Re-export decentralized ghost machine types
 pub use consensus::{ConsensusType, ValidatorRegistry, ValidatorInfo, ValidatorStatus, ValidatorID, 
                      ConsensusEngine, BasicConsensusEngine, Vote, TxoCommit, Violation, ConsensusError, ProposalID};
+pub use cosign::{CosignCeremony, CosignParticipant, Cosigner, CeremonyMessage, CeremonyPhase, CeremonyTranscript,
+                 AggregateSignature, PartialSignature, PartialSigner, PartialVerifier, AbortReason, Misbehavior,
+                 Outbound, CosignError};
+pub use finality::{FinalityGadget, FinalityCertificate, FinalityHeader, FinalityProof, FinalityStatus, FinalityError,
+                   FinalityWait, SyncCommittee, CommitteeMember, LightClient};
+pub use epoch::{EpochSchedule, EpochBounds, EpochBoundary, NetworkObservation, EpochError};
 pub use p2p::{P2PNetwork, TxoMempool, PeerInfo, PeerStatus, NodeID, PeerID};
+pub use reconcile::{IbltSketch, Reconciler, ReconcileOutcome, ReconcileError, SetDifference};
+pub use addressbook::{AddressBook, AddressBookConfig, AddressEntry, AddressBookError, TransportHint};
 pub use incentives::{ValidatorIncentives, Stake};
 pub use zkstate::{ZkStateTransition, StateCommitment, TransitionType, ZkStateVerifier, StateCommitmentBuilder};
 pub use upgrade::{ProtocolUpgrade, UpgradeManager, Version, UpgradeID, CURRENT_VERSION};
-pub use transport::{Channel, ChannelStatus, CensorshipResistance};
+pub use transport::{Channel, ChannelStatus, CensorshipResistance, PathInfo, PathType};
+pub use nat::{NatTraversal, Rendezvous, RelaySession, Endpoint, NatError};
 pub use governance::{GovernanceProposal, GovernanceVote, GovernanceState, ProposalType, VoteDecision, VoterID, AuthorityID};
+pub use treasury::{Treasury, Grant, GrantTerms, Milestone, Disbursement, TreasuryError};
+pub use telemetry::{HealthSample, HealthDigest, HealthDigestStream, HealthFeed,
+                    TelemetryError, publish_digest};
 
 // Module declarations
+pub mod clock;
 pub mod txo;
 pub mod biokey;
+pub mod entropy;
+pub mod deadman;
+pub mod conformance;
+pub mod rotation;
 pub mod quorum;
+pub mod degraded;
 pub mod canary;
 pub mod snapshot;
+pub mod keywrap;
+pub mod secure_alloc;
+pub mod secrets;
+pub mod hbs;
 pub mod proxy;
 pub mod compliance;
 pub mod blinded;
+pub mod threshold;
 pub mod ledger;
 pub mod watchdog;
 pub mod lifecycle;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod retention;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod secure_channel;
+#[cfg(any(test, feature = "chaos"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "chaos")))]
+pub mod chaos;
 
 // Decentralized ghost machine modules
 pub mod consensus;
+pub mod cosign;
+pub mod finality;
+pub mod epoch;
 pub mod p2p;
+pub mod addressbook;
+pub mod reconcile;
 pub mod incentives;
 pub mod zkstate;
 pub mod upgrade;
 pub mod transport;
+pub mod nat;
+pub mod handshake;
 pub mod governance;
+pub mod treasury;
+pub mod telemetry;
 
 // Compliance controls modules (HIPAA, GDPR, CMMC)
 pub mod compliance_controls;
 
+// Golden wire-format fixtures
+#[cfg(test)]
+mod golden;
+
+// Deterministic signer for unit tests
+#[cfg(test)]
+mod test_signer;
+
 /// QRATUM version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
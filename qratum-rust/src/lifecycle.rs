@@ -21,7 +21,9 @@
 //!
 //! ## Security Rationale
 //!
-//! - 5-stage lifecycle enforces architectural invariants
+//! - 5-stage lifecycle enforces architectural invariants, checked at runtime
+//!   against the typed model in [`crate::conformance`]
+//! - Failures after materialization still run stage 5 before returning
 //! - Explicit self-destruction prevents state leakage
 //! - Audit trail ensures accountability
 //! - Anti-holographic design (no persistent artifacts except Outcome TXOs)
@@ -43,10 +45,15 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 use crate::txo::{Txo, OutcomeTxo};
-use crate::biokey::{EphemeralBiokey, ShamirSecretSharing};
+use crate::biokey::{EphemeralBiokey, KeyOperation, UsageLimits};
+use crate::entropy::{EntropyHealthMonitor, HealthAction, HealthConfig};
+use crate::deadman::{DeadmanConfig, SessionStage, SessionWatchdog};
+use crate::conformance::{Artifact, ConformanceError, LifecycleConformance, LifecycleState};
+use crate::degraded::{Capability, DegradationMonitor, DegradedPolicy};
 use crate::quorum::{QuorumConfig, QuorumMember, run_convergence, ConvergenceResult};
 use crate::canary::{CanaryConfig, CanaryState};
 use crate::snapshot::{SnapshotConfig, SnapshotManager};
+use crate::secrets::{AccessToken, Permission, SecretBroker, SecretKind, Subsystem};
 use crate::proxy::{ProxyConfig, ProxyManager};
 use crate::compliance::{ComplianceProver, ProverConfig, CircuitType};
 use crate::ledger::RollbackLedger;
@@ -57,6 +64,11 @@ use crate::incentives::ValidatorIncentives;
 use crate::governance::GovernanceState;
 use crate::upgrade::UpgradeManager;
 use zeroize::{Zeroize, ZeroizeOnDrop};
+use sha3::{Digest, Sha3_512};
+use crate::clock;
+
+/// Domain separator for the snapshot key derived from the biokey
+const SNAPSHOT_KEY_DOMAIN: &[u8] = b"QRATUM-SNAPSHOT-KEY-v1";
 
 /// QRATUM Session Configuration
 #[derive(Debug, Clone)]
@@ -67,6 +79,10 @@ pub struct SessionConfig {
     pub proxy: ProxyConfig,
     pub prover: ProverConfig,
     pub watchdog: WatchdogConfig,
+    pub entropy_health: HealthConfig,
+    pub deadman: DeadmanConfig,
+    pub degraded: DegradedPolicy,
+    pub key_usage: UsageLimits,
     pub session_id: [u8; 32],
     
     // Decentralized ghost machine configuration
@@ -85,6 +101,10 @@ impl Default for SessionConfig {
             proxy: ProxyConfig::default(),
             prover: ProverConfig::default(),
             watchdog: WatchdogConfig::default(),
+            entropy_health: HealthConfig::default(),
+            deadman: DeadmanConfig::default(),
+            degraded: DegradedPolicy::default(),
+            key_usage: UsageLimits::default(),
             session_id: [0u8; 32],
             consensus_threshold: 67,  // 2/3 supermajority
             max_peers: 100,
@@ -102,6 +122,17 @@ pub enum QratumError {
     ExecutionFailed(alloc::string::String),
     OutcomeCommitmentFailed(alloc::string::String),
     DestructionFailed(alloc::string::String),
+    /// Entropy health fell below the abort threshold, or a stage deadline or
+    /// member heartbeat expired; carries the signed reason TXO
+    SessionAborted(alloc::boxed::Box<Txo>),
+    /// A stage was entered out of order or without its required artifacts
+    LifecycleViolation(ConformanceError),
+}
+
+impl From<ConformanceError> for QratumError {
+    fn from(error: ConformanceError) -> Self {
+        QratumError::LifecycleViolation(error)
+    }
 }
 
 /// Ephemeral Session State
@@ -120,6 +151,9 @@ struct EphemeralSessionState {
     /// Ephemeral biokey (zeroized on drop)
     biokey: EphemeralBiokey,
     
+    /// Entropy source health monitor
+    entropy: EntropyHealthMonitor,
+    
     /// In-memory ledger (zeroized on drop)
     ledger: RollbackLedger,
     
@@ -129,6 +163,15 @@ struct EphemeralSessionState {
     /// Snapshot manager (zeroized on drop)
     snapshots: SnapshotManager,
     
+    /// Session secrets, zeroized centrally at stage 5
+    secrets: SecretBroker,
+    
+    /// Snapshot subsystem's read access to the snapshot key
+    snapshot_key: Option<AccessToken>,
+    
+    /// Biokey subsystem's write access to the snapshot key (rotation)
+    snapshot_rekey: Option<AccessToken>,
+    
     /// Proxy manager
     proxies: ProxyManager,
     
@@ -138,6 +181,9 @@ struct EphemeralSessionState {
     /// Watchdog manager
     watchdogs: WatchdogManager,
     
+    /// Quorum mode and degraded-capability gating
+    quorum: DegradationMonitor,
+    
     /// Consensus engine (protocol-enforced quorum)
     consensus: BasicConsensusEngine,
     
@@ -172,7 +218,8 @@ impl EphemeralSessionState {
     /// - Configures validator incentives with stake registry
     /// - Initializes governance and upgrade management
     fn new(
-        biokey: EphemeralBiokey,
+        mut biokey: EphemeralBiokey,
+        entropy: EntropyHealthMonitor,
         config: &SessionConfig,
         validators: Vec<WatchdogValidator>,
     ) -> Self {
@@ -200,14 +247,34 @@ impl EphemeralSessionState {
         // Initialize upgrade manager
         let upgrades = UpgradeManager::default();
         
+        // Snapshot key lives in the broker; subsystems only hold tokens
+        let mut secrets = SecretBroker::new();
+        let (snapshot_key, snapshot_rekey) = match derive_snapshot_key(&mut biokey) {
+            Some(mut key) => {
+                let tokens = secrets.deposit(SecretKind::SnapshotKey, &key).ok().and_then(|handle| {
+                    let read = secrets.grant(handle, Subsystem::Snapshot, Permission::Read, None).ok()?;
+                    let write = secrets.grant(handle, Subsystem::Biokey, Permission::Write, None).ok()?;
+                    Some((read, write))
+                });
+                key.zeroize();
+                tokens.unzip()
+            }
+            None => (None, None),
+        };
+        
         Self {
             biokey,
+            entropy,
             ledger: RollbackLedger::new(10),
             canary: CanaryState::new(config.session_id, 0),
             snapshots: SnapshotManager::new(config.snapshot.clone()),
+            secrets,
+            snapshot_key,
+            snapshot_rekey,
             proxies: ProxyManager::new(config.proxy.clone()),
             prover: ComplianceProver::new(config.prover.clone()),
             watchdogs: WatchdogManager::new(config.watchdog.clone(), validators),
+            quorum: DegradationMonitor::new(config.quorum.clone(), config.degraded.clone(), config.session_id),
             consensus,
             p2p,
             incentives,
@@ -217,6 +284,18 @@ impl EphemeralSessionState {
     }
 }
 
+/// Derive the snapshot key from the current biokey epoch
+///
+/// # Outputs
+/// - `None` if the biokey is unavailable or its derivation cap is reached
+fn derive_snapshot_key(biokey: &mut EphemeralBiokey) -> Option<[u8; 64]> {
+    let material = biokey.authorize(KeyOperation::Derivation).ok()?;
+    let mut hasher = Sha3_512::new();
+    hasher.update(SNAPSHOT_KEY_DOMAIN);
+    hasher.update(&material[..]);
+    Some(hasher.finalize().into())
+}
+
 /// Run complete QRATUM session
 ///
 /// ## Lifecycle: All 5 Stages
@@ -263,24 +342,100 @@ pub fn run_qratum_session_with_config(
     input_txos: Vec<Txo>,
     config: SessionConfig,
 ) -> Result<Vec<OutcomeTxo>, QratumError> {
+    let mut deadman = SessionWatchdog::new(config.deadman.clone(), clock::now_ms());
+    let mut conformance = LifecycleConformance::new();
+    
     // ===== STAGE 1: QUORUM CONVERGENCE =====
-    let quorum_result = stage1_quorum_convergence(&config)?;
+    conformance.advance(LifecycleState::Active(SessionStage::QuorumConvergence))?;
+    let quorum_result = match stage1_quorum_convergence(&config) {
+        Ok(result) => result,
+        Err(e) => return reject(&mut conformance, e),
+    };
+    if let Some(expiry) = deadman.check(clock::now_ms()) {
+        // Nothing has materialized yet: no state to destroy, no key to sign with
+        return reject(&mut conformance, QratumError::QuorumFailed(format!(
+            "Convergence deadline expired ({} ms overdue)",
+            expiry.overdue_ms
+        )));
+    }
+    if let ConvergenceResult::Consensus { votes } = &quorum_result {
+        for vote in votes {
+            deadman.watch_member(vote.member_id, vote.timestamp);
+        }
+    }
+    conformance.complete(Artifact::QuorumConsensus, LifecycleState::Active(SessionStage::EphemeralMaterialization))?;
     
     // ===== STAGE 2: EPHEMERAL MATERIALIZATION =====
-    let mut state = stage2_ephemeral_materialization(&config, quorum_result)?;
+    deadman.enter_stage(SessionStage::EphemeralMaterialization, clock::now_ms());
+    let state = match stage2_ephemeral_materialization(&config, quorum_result) {
+        Ok(state) => state,
+        Err(e) => {
+            // Partially materialized values were dropped (zeroized) inside stage 2
+            conformance.complete(Artifact::AbortReason, LifecycleState::Active(SessionStage::SelfDestruction))?;
+            conformance.complete(Artifact::Zeroization, LifecycleState::Destroyed)?;
+            return Err(e);
+        }
+    };
+    conformance.record(Artifact::SessionState)?;
+    let mut state = enforce_deadlines(&deadman, state, &config, &mut conformance, clock::now_ms())?;
+    conformance.advance(LifecycleState::Active(SessionStage::Execution))?;
     
     // ===== STAGE 3: EXECUTION =====
-    let execution_hash = stage3_execution(&mut state, &input_txos, &config)?;
+    deadman.enter_stage(SessionStage::Execution, clock::now_ms());
+    let execution_hash = match stage3_execution(&mut state, &input_txos, &config) {
+        Ok(hash) => hash,
+        Err(e) => return Err(abort_session(state, &mut conformance, e)),
+    };
+    conformance.record(Artifact::ExecutionHash)?;
+    let mut state = enforce_deadlines(&deadman, state, &config, &mut conformance, clock::now_ms())?;
+    conformance.advance(LifecycleState::Active(SessionStage::OutcomeCommitment))?;
     
     // ===== STAGE 4: OUTCOME COMMITMENT =====
-    let outcomes = stage4_outcome_commitment(&state, execution_hash)?;
+    deadman.enter_stage(SessionStage::OutcomeCommitment, clock::now_ms());
+    let outcomes = match stage4_outcome_commitment(&mut state, execution_hash) {
+        Ok(outcomes) => outcomes,
+        Err(e) => return Err(abort_session(state, &mut conformance, e)),
+    };
+    conformance.record(Artifact::OutcomeTxos)?;
+    let state = enforce_deadlines(&deadman, state, &config, &mut conformance, clock::now_ms())?;
+    conformance.advance(LifecycleState::Active(SessionStage::SelfDestruction))?;
     
     // ===== STAGE 5: TOTAL SELF-DESTRUCTION =====
-    stage5_total_self_destruction(state)?;
+    deadman.enter_stage(SessionStage::SelfDestruction, clock::now_ms());
+    stage5_total_self_destruction(state, &mut conformance)?;
     
     Ok(outcomes)
 }
 
+/// End a session that failed before anything was materialized
+fn reject<T>(conformance: &mut LifecycleConformance, error: QratumError) -> Result<T, QratumError> {
+    conformance.complete(Artifact::AbortReason, LifecycleState::Rejected)?;
+    Err(error)
+}
+
+/// Tear down a materialized session after a failure in stages 2-4
+///
+/// ## Security Rationale
+/// - Failures never bypass stage 5: state is zeroized and the lifecycle
+///   ends `Destroyed`
+///
+/// # Outputs
+/// - `error`, or the conformance violation if teardown itself was out of order
+fn abort_session(
+    state: EphemeralSessionState,
+    conformance: &mut LifecycleConformance,
+    error: QratumError,
+) -> QratumError {
+    let teardown = conformance
+        .complete(Artifact::AbortReason, LifecycleState::Active(SessionStage::SelfDestruction))
+        .map_err(QratumError::from)
+        .and_then(|()| stage5_total_self_destruction(state, conformance));
+    match teardown {
+        Ok(()) => error,
+        Err(violation) => violation,
+    }
+}
+
 /// Stage 1: Quorum Convergence
 ///
 /// ## Lifecycle Stage: Quorum Convergence
@@ -326,12 +481,16 @@ fn stage2_ephemeral_materialization(
     // TODO: Reconstruct biokey from quorum Shamir shares
     // Placeholder: Derive biokey from session ID
     let entropy = [config.session_id.as_slice()];
-    let biokey = EphemeralBiokey::derive(&entropy, 0);
+    let biokey = EphemeralBiokey::derive(&entropy, 0).with_usage_limits(config.key_usage);
+    
+    // TODO: Register live entropy sources once biokey reconstruction is wired
+    let entropy_health = EntropyHealthMonitor::new(config.entropy_health.clone())
+        .map_err(|_| QratumError::BiokeyReconstructionFailed("Invalid entropy health thresholds".into()))?;
     
     // Create watchdog validators (placeholder)
     let validators = Vec::new();
     
-    let state = EphemeralSessionState::new(biokey, config, validators);
+    let state = EphemeralSessionState::new(biokey, entropy_health, config, validators);
     
     Ok(state)
 }
@@ -346,6 +505,7 @@ fn stage2_ephemeral_materialization(
 /// - Proxy approvals for privileged ops
 /// - Volatile snapshots for fault recovery
 /// - Watchdog validator attestations
+/// - Entropy health checks (key rotation or controlled abort)
 ///
 /// ## Anti-Censorship Mechanism
 /// - Canary probes detect suppression
@@ -353,8 +513,10 @@ fn stage2_ephemeral_materialization(
 fn stage3_execution(
     state: &mut EphemeralSessionState,
     input_txos: &[Txo],
-    _config: &SessionConfig,
+    config: &SessionConfig,
 ) -> Result<[u8; 32], QratumError> {
+    enforce_entropy_health(state, config)?;
+    
     // Log input TXOs to ledger
     for txo in input_txos {
         state.ledger.append(txo.clone());
@@ -365,13 +527,16 @@ fn stage3_execution(
     let _canary = state.canary.generate_canary(state_hash);
     // TODO: Emit canary to external observers
     
-    // Create snapshot checkpoint
-    if state.snapshots.snapshot_due() {
-        let snapshot_data = b"execution state"; // Placeholder
-        let _seq = state.snapshots.create_snapshot(
-            snapshot_data,
-            state.biokey.key_material(),
-        );
+    // Create snapshot checkpoint (withheld in degraded mode unless granted)
+    if state.snapshots.snapshot_due() && state.quorum.permits(Capability::Snapshot) {
+        // Skipped once the session key has expired or reached its encryption cap
+        if let (Some(token), Ok(_)) = (&state.snapshot_key, state.biokey.authorize(KeyOperation::Encryption)) {
+            let snapshot_data = b"execution state"; // Placeholder
+            let snapshots = &mut state.snapshots;
+            let _seq = state.secrets.read(token, Subsystem::Snapshot, |key| {
+                <&[u8; 64]>::try_from(key).map(|key| snapshots.create_snapshot(snapshot_data, key))
+            });
+        }
     }
     
     // TODO: Actual computation logic here
@@ -383,12 +548,109 @@ fn stage3_execution(
         b"public_claim",
     ).map_err(|e| QratumError::ExecutionFailed(e.into()))?;
     
+    // Key usage summaries join the audit trail committed by the execution hash
+    let usage = state.biokey.usage_audit_txo(clock::now_ms());
+    state.ledger.append(usage);
+    
     // Compute final execution hash
     let execution_hash = state.ledger.ledger().root_hash();
     
     Ok(execution_hash)
 }
 
+/// Act on the session entropy health score
+///
+/// ## Lifecycle Stage: Execution
+///
+/// ## Security Rationale
+/// - Degraded sources force forward-secure biokey rotation
+/// - Below the abort threshold the session stops and surfaces a signed
+///   `SessionAbort` TXO; the caller's error path still drops (zeroizes) state
+fn enforce_entropy_health(
+    state: &mut EphemeralSessionState,
+    config: &SessionConfig,
+) -> Result<(), QratumError> {
+    match state.entropy.assess() {
+        HealthAction::Continue => Ok(()),
+        HealthAction::RotateKeys => {
+            state.biokey.rotate();
+            state.entropy.note_rotation();
+            rekey_snapshots(state);
+            Ok(())
+        }
+        HealthAction::Abort => {
+            let reason = state.entropy.abort_txo(
+                config.session_id,
+                clock::now_ms(),
+                state.biokey.key_material_unchecked(),
+            );
+            Err(QratumError::SessionAborted(alloc::boxed::Box::new(reason)))
+        }
+    }
+}
+
+/// Replace the brokered snapshot key with one from the current biokey epoch
+///
+/// ## Security Rationale
+/// - Snapshots after a rotation never use a key derived from the retired epoch;
+///   if no new key can be derived, snapshot access is revoked instead
+fn rekey_snapshots(state: &mut EphemeralSessionState) {
+    let Some(rekey) = &state.snapshot_rekey else {
+        return;
+    };
+    let written = match derive_snapshot_key(&mut state.biokey) {
+        Some(mut key) => {
+            let written = state.secrets.write(rekey, Subsystem::Biokey, &key).is_ok();
+            key.zeroize();
+            written
+        }
+        None => false,
+    };
+    if !written {
+        if let Some(token) = state.snapshot_key.take() {
+            state.secrets.revoke(token);
+        }
+    }
+}
+
+/// Act on stage deadlines and quorum member heartbeats
+///
+/// ## Lifecycle Stage: Ephemeral Materialization → Outcome Commitment
+///
+/// ## Security Rationale
+/// - A stalled stage never leaves state resident in RAM: expiry runs
+///   stage 5 before returning
+/// - Silent members degrade the session instead when policy allows and
+///   membership stays at or above the decay floor; mode changes are
+///   recorded as `DegradedMode` TXOs on the session ledger
+/// - The signed `SessionAbort` TXO names the stage and unresponsive members
+fn enforce_deadlines(
+    deadman: &SessionWatchdog,
+    mut state: EphemeralSessionState,
+    config: &SessionConfig,
+    conformance: &mut LifecycleConformance,
+    now: u64,
+) -> Result<EphemeralSessionState, QratumError> {
+    let expiry = deadman.check(now);
+    let total = deadman.watched_members();
+    let stage_overdue = expiry.as_ref().is_some_and(|e| e.overdue_ms > 0);
+    if !stage_overdue && total > 0 {
+        let silent = expiry.as_ref().map_or(0, |e| e.unresponsive.len());
+        if let Ok(event) = state.quorum.observe(total - silent, total, now) {
+            if let Some(txo) = event {
+                state.ledger.append(txo);
+            }
+            return Ok(state);
+        }
+    }
+    
+    let Some(expiry) = expiry else {
+        return Ok(state);
+    };
+    let reason = expiry.abort_txo(config.session_id, now, state.biokey.key_material_unchecked());
+    Err(abort_session(state, conformance, QratumError::SessionAborted(alloc::boxed::Box::new(reason))))
+}
+
 /// Stage 4: Outcome Commitment
 ///
 /// ## Lifecycle Stage: Outcome Commitment
@@ -401,7 +663,7 @@ fn stage3_execution(
 /// - Blinded commitment prevents inspection
 /// - Quorum signatures provide attestation
 fn stage4_outcome_commitment(
-    _state: &EphemeralSessionState,
+    state: &mut EphemeralSessionState,
     execution_hash: [u8; 32],
 ) -> Result<Vec<OutcomeTxo>, QratumError> {
     let mut outcomes = Vec::new();
@@ -417,6 +679,8 @@ fn stage4_outcome_commitment(
         Vec::new(),
     );
     
+    // Outcomes committed under a degraded quorum await re-ratification
+    state.quorum.record_outcome(outcome.txo.id);
     outcomes.push(outcome);
     
     Ok(outcomes)
@@ -433,16 +697,25 @@ fn stage4_outcome_commitment(
 /// - Enforces ephemeral architecture
 /// - Anti-holographic (no state residue)
 ///
+/// - Brokered secrets are zeroized here even if their subsystem never
+///   wiped them
+///
 /// ## Audit Trail
 /// - Logs destruction event
 /// - Records final session metrics
+/// - Records `Zeroization` and moves the lifecycle to `Destroyed`
 fn stage5_total_self_destruction(
-    state: EphemeralSessionState,
+    mut state: EphemeralSessionState,
+    conformance: &mut LifecycleConformance,
 ) -> Result<(), QratumError> {
+    // Centralized zeroization of every brokered secret
+    state.secrets.destroy_all();
+    
     // Explicit zeroization (drop trait handles this for sensitive types)
     drop(state);
     
     // State is now destroyed, nothing persists except Outcome TXOs
+    conformance.complete(Artifact::Zeroization, LifecycleState::Destroyed)?;
     Ok(())
 }
 
@@ -452,6 +725,15 @@ mod tests {
     use crate::txo::TxoType;
     use alloc::vec;
     
+    /// Checker in stage 3, as the orchestrator leaves it
+    fn conformance_in_execution() -> LifecycleConformance {
+        let mut conformance = LifecycleConformance::new();
+        conformance.advance(LifecycleState::Active(SessionStage::QuorumConvergence)).unwrap();
+        conformance.complete(Artifact::QuorumConsensus, LifecycleState::Active(SessionStage::EphemeralMaterialization)).unwrap();
+        conformance.complete(Artifact::SessionState, LifecycleState::Active(SessionStage::Execution)).unwrap();
+        conformance
+    }
+    
     #[test]
     fn test_session_config_default() {
         let config = SessionConfig::default();
@@ -466,4 +748,147 @@ mod tests {
         // May fail due to placeholder implementations, but should compile
         assert!(result.is_ok() || result.is_err());
     }
+    
+    #[test]
+    fn test_snapshot_key_brokered_and_rekeyed() {
+        use crate::secrets::BrokerError;
+        
+        let mut config = SessionConfig::default();
+        config.snapshot.snapshot_interval_ms = 0;
+        let biokey = EphemeralBiokey::derive(&[config.session_id.as_slice()], 0);
+        let monitor = EntropyHealthMonitor::new(config.entropy_health.clone()).unwrap();
+        let mut state = EphemeralSessionState::new(biokey, monitor, &config, Vec::new());
+        assert_eq!(state.secrets.secret_count(), 1);
+        
+        // Stage 3 snapshots through the Snapshot subsystem's token
+        stage3_execution(&mut state, &[], &config).unwrap();
+        assert_eq!(state.snapshots.snapshot_count(), 1);
+        let token = state.snapshot_key.as_ref().unwrap();
+        let reads = |state: &EphemeralSessionState| state.secrets.access_log().iter()
+            .filter(|r| r.subsystem == Subsystem::Snapshot && r.permission == Some(Permission::Read))
+            .count();
+        assert_eq!(reads(&state), 1);
+        
+        // Rotation replaces the brokered key
+        let before = state.secrets.read(token, Subsystem::Snapshot, |key| key.to_vec()).unwrap();
+        state.biokey.rotate();
+        rekey_snapshots(&mut state);
+        let token = state.snapshot_key.as_ref().unwrap();
+        let after = state.secrets.read(token, Subsystem::Snapshot, |key| key.to_vec()).unwrap();
+        assert_ne!(before, after);
+        assert_eq!(state.secrets.read(token, Subsystem::Blinded, |_| ()), Err(BrokerError::WrongSubsystem));
+        
+        // Stage 5 destroys the broker regardless of what subsystems did
+        state.secrets.destroy_all();
+        assert_eq!(state.secrets.read(token, Subsystem::Snapshot, |_| ()), Err(BrokerError::Destroyed));
+        let mut conformance = conformance_in_execution();
+        conformance.complete(Artifact::AbortReason, LifecycleState::Active(SessionStage::SelfDestruction)).unwrap();
+        stage5_total_self_destruction(state, &mut conformance).unwrap();
+        assert_eq!(conformance.state(), LifecycleState::Destroyed);
+    }
+    
+    #[test]
+    fn test_entropy_health_rotates_then_aborts() {
+        use crate::biokey::EntropySourceType;
+        use crate::entropy::verify_abort_txo;
+        
+        let config = SessionConfig::default();
+        let biokey = EphemeralBiokey::derive(&[config.session_id.as_slice()], 0);
+        let monitor = EntropyHealthMonitor::new(config.entropy_health.clone()).unwrap();
+        let mut state = EphemeralSessionState::new(biokey, monitor, &config, Vec::new());
+        state.entropy.register(EntropySourceType::Trng, 8).unwrap();
+        state.entropy.register(EntropySourceType::Genomic, 8).unwrap();
+        
+        // Healthy: no action
+        assert!(enforce_entropy_health(&mut state, &config).is_ok());
+        assert_eq!(state.biokey.epoch(), 0);
+        
+        // One stuck burst on Trng: score 87 -> rotate
+        state.entropy.observe(EntropySourceType::Trng, &[0; 4]).unwrap();
+        assert!(enforce_entropy_health(&mut state, &config).is_ok());
+        assert_eq!(state.biokey.epoch(), 1);
+        
+        // Trng exhausted: score 50 -> abort with signed reason
+        state.entropy.observe(EntropySourceType::Trng, &[0; 12]).unwrap();
+        match enforce_entropy_health(&mut state, &config) {
+            Err(QratumError::SessionAborted(reason)) => {
+                assert!(verify_abort_txo(&reason, state.biokey.key_material_unchecked()));
+            }
+            other => panic!("expected abort, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_deadline_expiry_self_destructs() {
+        use crate::deadman::WatchdogExpiry;
+        use crate::entropy::verify_abort_txo;
+        
+        let config = SessionConfig::default();
+        let biokey = EphemeralBiokey::derive(&[config.session_id.as_slice()], 0);
+        let key = *biokey.key_material_unchecked();
+        let monitor = EntropyHealthMonitor::new(config.entropy_health.clone()).unwrap();
+        let state = EphemeralSessionState::new(biokey, monitor, &config, Vec::new());
+        
+        // Execution started an hour ago and member 9 went silent then
+        let now = 1_700_000_000_000;
+        let mut deadman = SessionWatchdog::new(config.deadman.clone(), 0);
+        deadman.enter_stage(SessionStage::Execution, now - 3_600_000);
+        deadman.watch_member([8u8; 32], now);
+        deadman.watch_member([9u8; 32], now - 3_600_000);
+        
+        let mut conformance = conformance_in_execution();
+        match enforce_deadlines(&deadman, state, &config, &mut conformance, now) {
+            Err(QratumError::SessionAborted(reason)) => {
+                assert!(verify_abort_txo(&reason, &key));
+                let expiry = WatchdogExpiry::from_abort_txo(&reason).unwrap();
+                assert_eq!(expiry.stage, SessionStage::Execution);
+                assert_eq!(expiry.unresponsive, vec![[9u8; 32]]);
+            }
+            other => panic!("expected abort, got {:?}", other.map(|_| ())),
+        }
+        assert_eq!(conformance.state(), LifecycleState::Destroyed);
+    }    
+    #[test]
+    fn test_silent_member_degrades_session() {
+        use crate::degraded::QuorumMode;
+        
+        let config = SessionConfig {
+            degraded: DegradedPolicy { enabled: true, capabilities: Vec::new() },
+            ..SessionConfig::default()
+        };
+        let biokey = EphemeralBiokey::derive(&[config.session_id.as_slice()], 0);
+        let monitor = EntropyHealthMonitor::new(config.entropy_health.clone()).unwrap();
+        let state = EphemeralSessionState::new(biokey, monitor, &config, Vec::new());
+        
+        let now = 1_700_000_000_000;
+        let timeout = config.deadman.heartbeat_timeout_ms;
+        let mut deadman = SessionWatchdog::new(config.deadman.clone(), 0);
+        deadman.enter_stage(SessionStage::Execution, now);
+        for member in 1..=3u8 {
+            deadman.watch_member([member; 32], now);
+        }
+        
+        // Member 3 goes silent: 2 of 3 (66%) is below full quorum, above the floor
+        let later = now + timeout + 1;
+        deadman.heartbeat(&[1u8; 32], later);
+        deadman.heartbeat(&[2u8; 32], later);
+        let mut conformance = conformance_in_execution();
+        let state = match enforce_deadlines(&deadman, state, &config, &mut conformance, later) {
+            Ok(state) => state,
+            Err(e) => panic!("degraded session aborted: {:?}", e),
+        };
+        assert_eq!(state.quorum.mode(), QuorumMode::Degraded);
+        assert!(!state.quorum.permits(Capability::Snapshot));
+        assert_eq!(state.ledger.ledger().txo_count(), 1);
+        
+        // Member 2 follows: 1 of 3 is below the floor
+        let latest = later + timeout + 1;
+        deadman.heartbeat(&[1u8; 32], latest);
+        assert_eq!(conformance.state(), LifecycleState::Active(SessionStage::Execution));
+        match enforce_deadlines(&deadman, state, &config, &mut conformance, latest) {
+            Err(QratumError::SessionAborted(_)) => {}
+            other => panic!("expected abort, got {:?}", other.map(|_| ())),
+        }
+        assert_eq!(conformance.state(), LifecycleState::Destroyed);
+    }
 }
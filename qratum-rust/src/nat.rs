@@ -0,0 +1,681 @@
+//! # NAT Module - Hole Punching and Relay Fallback
+//!
+//! ## Lifecycle Stage: Network Infrastructure (channel establishment)
+//!
+//! Nodes behind NAT cannot accept inbound channels. Two such nodes that
+//! both hold a channel to a common peer can use it as a rendezvous: the
+//! rendezvous reports each side's externally observed endpoint (as a STUN
+//! server would) and forwards the introduction, then both sides probe each
+//! other's endpoints at the same time to open their NAT mappings. If no
+//! probe arrives before the deadline the rendezvous relays the traffic
+//! instead.
+//!
+//! ## Architectural Role
+//!
+//! - **Rendezvous**: Runs on the already-connected peer; adds observed
+//!   endpoints to introductions and forwards opaque relay frames
+//! - **Traversal**: Runs on each NATed node; tracks attempts, authenticates
+//!   probes, and falls back to a relay session on timeout
+//! - **Relay Session**: End-to-end encrypted frames through the relay
+//! - **Path Metadata**: Results are [`PathInfo`] records for
+//!   latency-aware routing in [`crate::transport`]
+//!
+//! ## Security Rationale
+//!
+//! - Each attempt runs a ristretto255 Diffie-Hellman exchange through the
+//!   rendezvous; probes carry a MAC under the shared key,
+//!   so off-path hosts cannot hijack the punched path
+//! - Relays see circuit ids, sequence numbers and ciphertext only; frames are
+//!   authenticated per direction and replays are rejected
+//! - A relay that substitutes DH keys could read relayed traffic; peers must
+//!   still authenticate each other with the handshake over the new path
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256, Sha3_512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::p2p::PeerID;
+use crate::transport::{Channel, PathInfo, PathType};
+
+/// Delay between introduction and simultaneous probing (milliseconds)
+pub const PUNCH_DELAY_MS: u64 = 200;
+
+/// Externally reachable address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct Endpoint {
+    /// IPv6 address (IPv4 as `::ffff:a.b.c.d`)
+    #[n(0)]
+    pub ip: [u8; 16],
+
+    /// UDP/TCP port
+    #[n(1)]
+    pub port: u16,
+}
+
+impl Endpoint {
+    /// IPv4-mapped endpoint
+    pub fn v4(octets: [u8; 4], port: u16) -> Self {
+        let mut ip = [0u8; 16];
+        ip[10] = 0xff;
+        ip[11] = 0xff;
+        ip[12..].copy_from_slice(&octets);
+        Self { ip, port }
+    }
+}
+
+/// Request to be introduced to `target`, sent to the rendezvous
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct PunchRequest {
+    /// Requesting node
+    #[n(0)]
+    pub initiator: PeerID,
+
+    /// Node to be introduced to
+    #[n(1)]
+    pub target: PeerID,
+
+    /// Locally known endpoints (LAN addresses, mapped ports)
+    #[n(2)]
+    pub endpoints: Vec<Endpoint>,
+
+    /// Diffie-Hellman public value (compressed ristretto255 point)
+    #[n(3)]
+    pub dh_public: [u8; 32],
+
+    /// Attempt nonce (shared by both directions)
+    #[n(4)]
+    pub nonce: [u8; 32],
+}
+
+/// Introduction forwarded by the rendezvous
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct PunchIntro {
+    /// Peer being introduced
+    #[n(0)]
+    pub peer: PeerID,
+
+    /// Peer endpoints, observed endpoint last
+    #[n(1)]
+    pub endpoints: Vec<Endpoint>,
+
+    /// Peer's Diffie-Hellman public value (compressed ristretto255 point)
+    #[n(2)]
+    pub dh_public: [u8; 32],
+
+    /// Attempt nonce
+    #[n(3)]
+    pub nonce: [u8; 32],
+
+    /// Time both sides start probing (milliseconds)
+    #[n(4)]
+    pub punch_at_ms: u64,
+
+    /// Rendezvous that forwarded the introduction (relay on fallback)
+    #[n(5)]
+    pub rendezvous: PeerID,
+}
+
+/// Hole-punching probe sent directly to a peer endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct PunchProbe {
+    /// Probing node
+    #[n(0)]
+    pub sender: PeerID,
+
+    /// Attempt nonce
+    #[n(1)]
+    pub nonce: [u8; 32],
+
+    /// MAC under the attempt's shared key
+    #[n(2)]
+    pub tag: [u8; 32],
+}
+
+/// Frame forwarded through a relay
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct RelayFrame {
+    /// Circuit identifier
+    #[n(0)]
+    pub circuit_id: [u8; 32],
+
+    /// Per-direction sequence number
+    #[n(1)]
+    pub sequence: u64,
+
+    /// Encrypted payload
+    #[n(2)]
+    pub ciphertext: Vec<u8>,
+
+    /// MAC over circuit, sequence and ciphertext
+    #[n(3)]
+    pub tag: [u8; 32],
+}
+
+/// NAT Traversal Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NatError {
+    /// Peer has no channel to the rendezvous
+    NotConnected(PeerID),
+    /// No attempt with this nonce
+    UnknownAttempt,
+    /// Probe MAC does not verify
+    InvalidProbe,
+    /// Diffie-Hellman value is not a canonical non-identity point
+    InvalidKey,
+    /// Relay has no such circuit, or the sender is not on it
+    UnknownCircuit,
+    /// Relay is at its circuit limit
+    CapacityExceeded,
+    /// Frame sequence already seen
+    Replay,
+    /// Frame MAC does not verify
+    AuthenticationFailed,
+}
+
+/// Rendezvous and relay role of an already-connected peer
+#[derive(Debug, Clone)]
+pub struct Rendezvous {
+    node_id: PeerID,
+    connected: Vec<PeerID>,
+    circuits: BTreeMap<[u8; 32], (PeerID, PeerID)>,
+    max_circuits: usize,
+}
+
+impl Rendezvous {
+    /// Create a rendezvous relaying at most `max_circuits` circuits
+    pub fn new(node_id: PeerID, max_circuits: usize) -> Self {
+        Self { node_id, connected: Vec::new(), circuits: BTreeMap::new(), max_circuits }
+    }
+
+    /// Register a peer with an open channel to this node
+    pub fn peer_connected(&mut self, peer: PeerID) {
+        if !self.connected.contains(&peer) {
+            self.connected.push(peer);
+        }
+    }
+
+    /// Unregister a peer and close its circuits
+    pub fn peer_disconnected(&mut self, peer: &PeerID) {
+        self.connected.retain(|p| p != peer);
+        self.circuits.retain(|_, (a, b)| a != peer && b != peer);
+    }
+
+    /// Introduce the request's initiator to its target
+    ///
+    /// # Inputs
+    /// - `request`: Request received from the initiator
+    /// - `observed`: Endpoint the initiator's request arrived from
+    /// - `now_ms`: Current time
+    ///
+    /// # Outputs
+    /// - Introduction to forward to `request.target`; the target's reply
+    ///   request is introduced back the same way
+    pub fn introduce(&self, request: &PunchRequest, observed: Endpoint, now_ms: u64) -> Result<PunchIntro, NatError> {
+        for peer in [&request.initiator, &request.target] {
+            if !self.connected.contains(peer) {
+                return Err(NatError::NotConnected(*peer));
+            }
+        }
+        let mut endpoints = request.endpoints.clone();
+        endpoints.retain(|e| *e != observed);
+        endpoints.push(observed);
+        Ok(PunchIntro {
+            peer: request.initiator,
+            endpoints,
+            dh_public: request.dh_public,
+            nonce: request.nonce,
+            punch_at_ms: now_ms + PUNCH_DELAY_MS,
+            rendezvous: self.node_id,
+        })
+    }
+
+    /// Open a relay circuit between two connected peers
+    pub fn open_circuit(&mut self, a: PeerID, b: PeerID, nonce: &[u8; 32]) -> Result<[u8; 32], NatError> {
+        for peer in [&a, &b] {
+            if !self.connected.contains(peer) {
+                return Err(NatError::NotConnected(*peer));
+            }
+        }
+        let id = circuit_id(&a, &b, nonce);
+        if !self.circuits.contains_key(&id) && self.circuits.len() >= self.max_circuits {
+            return Err(NatError::CapacityExceeded);
+        }
+        self.circuits.insert(id, (a, b));
+        Ok(id)
+    }
+
+    /// Close a relay circuit
+    pub fn close_circuit(&mut self, circuit_id: &[u8; 32]) {
+        self.circuits.remove(circuit_id);
+    }
+
+    /// Forward an opaque frame; returns the peer to deliver it to
+    pub fn forward(&self, from: &PeerID, frame: &RelayFrame) -> Result<PeerID, NatError> {
+        match self.circuits.get(&frame.circuit_id) {
+            Some((a, b)) if a == from => Ok(*b),
+            Some((a, b)) if b == from => Ok(*a),
+            _ => Err(NatError::UnknownCircuit),
+        }
+    }
+}
+
+/// Hole-punching attempt in progress
+#[derive(Debug, Clone, Zeroize, ZeroizeOnDrop)]
+struct Attempt {
+    peer: PeerID,
+    secret: Scalar,
+    shared_key: Option<[u8; 32]>,
+    rendezvous: Option<PeerID>,
+    deadline_ms: u64,
+}
+
+/// Relay fallback for an attempt whose probes never arrived
+#[derive(Debug)]
+pub struct RelayFallback {
+    /// Peer the attempt targeted
+    pub peer: PeerID,
+
+    /// Rendezvous to open the circuit on
+    pub relay: PeerID,
+
+    /// Attempt nonce (circuit ids derive from it)
+    pub nonce: [u8; 32],
+
+    /// End-to-end session over the circuit
+    pub session: RelaySession,
+}
+
+/// Client side of hole punching
+#[derive(Debug, Clone)]
+pub struct NatTraversal {
+    node_id: PeerID,
+    punch_timeout_ms: u64,
+    attempts: BTreeMap<[u8; 32], Attempt>,
+}
+
+impl NatTraversal {
+    /// Create a traversal state machine
+    ///
+    /// # Inputs
+    /// - `node_id`: This node
+    /// - `punch_timeout_ms`: Time after the punch starts before relaying
+    pub fn new(node_id: PeerID, punch_timeout_ms: u64) -> Self {
+        Self { node_id, punch_timeout_ms, attempts: BTreeMap::new() }
+    }
+
+    /// Start an attempt to reach `target`
+    ///
+    /// # Inputs
+    /// - `target`: Peer to reach
+    /// - `endpoints`: Locally known endpoints
+    /// - `entropy`: Fresh randomness for the nonce and DH secret
+    /// - `now_ms`: Current time
+    pub fn request(&mut self, target: PeerID, endpoints: Vec<Endpoint>, entropy: &[u8; 32], now_ms: u64) -> PunchRequest {
+        let nonce: [u8; 32] = Sha3_256::new()
+            .chain_update(b"QRATUM-NAT-NONCE-v1")
+            .chain_update(self.node_id)
+            .chain_update(entropy)
+            .finalize()
+            .into();
+        let secret = dh_secret(entropy, &nonce);
+        self.attempts.insert(nonce, Attempt {
+            peer: target,
+            secret,
+            shared_key: None,
+            rendezvous: None,
+            deadline_ms: now_ms + PUNCH_DELAY_MS + self.punch_timeout_ms,
+        });
+        PunchRequest { initiator: self.node_id, target, endpoints, dh_public: dh_public(&secret), nonce }
+    }
+
+    /// Accept an introduction as the target
+    ///
+    /// # Outputs
+    /// - Reply request for the rendezvous and the probes to send at
+    ///   `intro.punch_at_ms`
+    pub fn accept(
+        &mut self,
+        intro: &PunchIntro,
+        endpoints: Vec<Endpoint>,
+        entropy: &[u8; 32],
+        now_ms: u64,
+    ) -> Result<(PunchRequest, Vec<(Endpoint, PunchProbe)>), NatError> {
+        check_public(&intro.dh_public)?;
+        let secret = dh_secret(entropy, &intro.nonce);
+        self.attempts.insert(intro.nonce, Attempt {
+            peer: intro.peer,
+            secret,
+            shared_key: None,
+            rendezvous: None,
+            deadline_ms: now_ms + PUNCH_DELAY_MS + self.punch_timeout_ms,
+        });
+        let reply = PunchRequest {
+            initiator: self.node_id,
+            target: intro.peer,
+            endpoints,
+            dh_public: dh_public(&secret),
+            nonce: intro.nonce,
+        };
+        let probes = self.on_intro(intro)?;
+        Ok((reply, probes))
+    }
+
+    /// Handle the introduction of the peer an attempt targets
+    ///
+    /// # Outputs
+    /// - Probes to send to each of the peer's endpoints at `intro.punch_at_ms`
+    pub fn on_intro(&mut self, intro: &PunchIntro) -> Result<Vec<(Endpoint, PunchProbe)>, NatError> {
+        let peer_public = check_public(&intro.dh_public)?;
+        let node_id = self.node_id;
+        let attempt = self.attempts.get_mut(&intro.nonce).ok_or(NatError::UnknownAttempt)?;
+        if attempt.peer != intro.peer {
+            return Err(NatError::UnknownAttempt);
+        }
+        let shared = (peer_public * attempt.secret).compress();
+        let key = shared_key(shared.as_bytes(), &node_id, &intro.peer, &intro.nonce);
+        attempt.shared_key = Some(key);
+        attempt.rendezvous = Some(intro.rendezvous);
+        attempt.deadline_ms = attempt.deadline_ms.max(intro.punch_at_ms + self.punch_timeout_ms);
+
+        let probe = PunchProbe { sender: node_id, nonce: intro.nonce, tag: probe_tag(&key, &node_id, &intro.nonce) };
+        Ok(intro.endpoints.iter().map(|e| (*e, probe.clone())).collect())
+    }
+
+    /// Handle a probe received directly from the peer
+    ///
+    /// # Inputs
+    /// - `probe`: Received probe
+    /// - `from`: Endpoint it arrived from (recorded as the punched address)
+    ///
+    /// # Outputs
+    /// - Hole-punched path; the attempt is complete
+    pub fn on_probe(&mut self, probe: &PunchProbe, from: Endpoint) -> Result<(PathInfo, Endpoint), NatError> {
+        let attempt = self.attempts.get(&probe.nonce).ok_or(NatError::UnknownAttempt)?;
+        let key = attempt.shared_key.ok_or(NatError::UnknownAttempt)?;
+        if probe.sender != attempt.peer || probe_tag(&key, &probe.sender, &probe.nonce) != probe.tag {
+            return Err(NatError::InvalidProbe);
+        }
+        let path = PathInfo { peer: attempt.peer, channel: Channel::Tcp, path: PathType::HolePunched, relay: None, rtt_ms: None };
+        self.attempts.remove(&probe.nonce);
+        Ok((path, from))
+    }
+
+    /// Number of attempts in progress
+    pub fn pending(&self) -> usize {
+        self.attempts.len()
+    }
+
+    /// Expire attempts past their deadline
+    ///
+    /// # Outputs
+    /// - Relay fallbacks for introduced attempts; attempts that were never
+    ///   introduced are dropped
+    pub fn expire(&mut self, now_ms: u64) -> Vec<RelayFallback> {
+        let expired: Vec<[u8; 32]> = self
+            .attempts
+            .iter()
+            .filter(|(_, a)| now_ms >= a.deadline_ms)
+            .map(|(nonce, _)| *nonce)
+            .collect();
+        let mut fallbacks = Vec::new();
+        for nonce in expired {
+            let Some(attempt) = self.attempts.remove(&nonce) else { continue };
+            if let (Some(key), Some(relay)) = (attempt.shared_key, attempt.rendezvous) {
+                fallbacks.push(RelayFallback {
+                    peer: attempt.peer,
+                    relay,
+                    nonce,
+                    session: RelaySession::new(&key, &self.node_id, &attempt.peer, &nonce),
+                });
+            }
+        }
+        fallbacks
+    }
+}
+
+/// End-to-end encrypted session through a relay
+#[derive(Debug, Zeroize, ZeroizeOnDrop)]
+pub struct RelaySession {
+    circuit_id: [u8; 32],
+    peer: PeerID,
+    send_key: [u8; 32],
+    recv_key: [u8; 32],
+    send_sequence: u64,
+    recv_next: u64,
+}
+
+impl RelaySession {
+    /// Derive per-direction keys from the attempt's shared key
+    pub fn new(shared_key: &[u8; 32], local: &PeerID, peer: &PeerID, nonce: &[u8; 32]) -> Self {
+        let circuit_id = circuit_id(local, peer, nonce);
+        Self {
+            circuit_id,
+            peer: *peer,
+            send_key: direction_key(shared_key, local, peer),
+            recv_key: direction_key(shared_key, peer, local),
+            send_sequence: 0,
+            recv_next: 0,
+        }
+    }
+
+    /// Circuit identifier (as computed by the relay)
+    pub fn circuit_id(&self) -> [u8; 32] {
+        self.circuit_id
+    }
+
+    /// Path metadata for this session
+    pub fn path(&self, relay: PeerID) -> PathInfo {
+        PathInfo { peer: self.peer, channel: Channel::Tcp, path: PathType::Relayed, relay: Some(relay), rtt_ms: None }
+    }
+
+    /// Encrypt and authenticate a payload for the relay
+    pub fn seal(&mut self, plaintext: &[u8]) -> RelayFrame {
+        let sequence = self.send_sequence;
+        self.send_sequence += 1;
+        let ciphertext = apply_keystream(&self.send_key, sequence, plaintext);
+        let tag = frame_tag(&self.send_key, &self.circuit_id, sequence, &ciphertext);
+        RelayFrame { circuit_id: self.circuit_id, sequence, ciphertext, tag }
+    }
+
+    /// Authenticate and decrypt a frame delivered by the relay
+    ///
+    /// Frames must arrive in order; earlier sequence numbers are replays.
+    pub fn open(&mut self, frame: &RelayFrame) -> Result<Vec<u8>, NatError> {
+        if frame.circuit_id != self.circuit_id {
+            return Err(NatError::UnknownCircuit);
+        }
+        if frame.sequence < self.recv_next {
+            return Err(NatError::Replay);
+        }
+        if frame_tag(&self.recv_key, &self.circuit_id, frame.sequence, &frame.ciphertext) != frame.tag {
+            return Err(NatError::AuthenticationFailed);
+        }
+        self.recv_next = frame.sequence + 1;
+        Ok(apply_keystream(&self.recv_key, frame.sequence, &frame.ciphertext))
+    }
+}
+
+/// Circuit id for a peer pair and attempt (order-independent)
+pub fn circuit_id(a: &PeerID, b: &PeerID, nonce: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    Sha3_256::new()
+        .chain_update(b"QRATUM-NAT-CIRCUIT-v1")
+        .chain_update(lo)
+        .chain_update(hi)
+        .chain_update(nonce)
+        .finalize()
+        .into()
+}
+
+/// DH secret scalar, wide-reduced from SHA3-512
+fn dh_secret(entropy: &[u8; 32], nonce: &[u8; 32]) -> Scalar {
+    let mut wide: [u8; 64] = Sha3_512::new()
+        .chain_update(b"QRATUM-NAT-DH-v1")
+        .chain_update(entropy)
+        .chain_update(nonce)
+        .finalize()
+        .into();
+    let secret = Scalar::from_bytes_mod_order_wide(&wide);
+    wide.zeroize();
+    secret
+}
+
+fn dh_public(secret: &Scalar) -> [u8; 32] {
+    (RISTRETTO_BASEPOINT_POINT * secret).compress().to_bytes()
+}
+
+/// Reject non-canonical encodings and the identity
+fn check_public(value: &[u8; 32]) -> Result<RistrettoPoint, NatError> {
+    CompressedRistretto(*value)
+        .decompress()
+        .filter(|point| *point != RistrettoPoint::default())
+        .ok_or(NatError::InvalidKey)
+}
+
+fn shared_key(shared: &[u8; 32], a: &PeerID, b: &PeerID, nonce: &[u8; 32]) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update(b"QRATUM-NAT-KEY-v1")
+        .chain_update(shared)
+        .chain_update(circuit_id(a, b, nonce))
+        .finalize()
+        .into()
+}
+
+fn probe_tag(key: &[u8; 32], sender: &PeerID, nonce: &[u8; 32]) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update(b"QRATUM-NAT-PROBE-v1")
+        .chain_update(key)
+        .chain_update(sender)
+        .chain_update(nonce)
+        .finalize()
+        .into()
+}
+
+fn direction_key(shared_key: &[u8; 32], from: &PeerID, to: &PeerID) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update(b"QRATUM-NAT-DIRECTION-v1")
+        .chain_update(shared_key)
+        .chain_update(from)
+        .chain_update(to)
+        .finalize()
+        .into()
+}
+
+fn frame_tag(key: &[u8; 32], circuit_id: &[u8; 32], sequence: u64, ciphertext: &[u8]) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update(b"QRATUM-NAT-FRAME-v1")
+        .chain_update(key)
+        .chain_update(circuit_id)
+        .chain_update(sequence.to_le_bytes())
+        .chain_update(ciphertext)
+        .finalize()
+        .into()
+}
+
+/// XOR `data` with a SHA3-256 counter-mode keystream for one frame
+fn apply_keystream(key: &[u8; 32], sequence: u64, data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    for (counter, chunk) in data.chunks(32).enumerate() {
+        let block: [u8; 32] = Sha3_256::new()
+            .chain_update(b"QRATUM-NAT-STREAM-v1")
+            .chain_update(key)
+            .chain_update(sequence.to_le_bytes())
+            .chain_update((counter as u64).to_le_bytes())
+            .finalize()
+            .into();
+        out.extend(chunk.iter().zip(block.iter()).map(|(d, k)| d ^ k));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: PeerID = [1u8; 32];
+    const BOB: PeerID = [2u8; 32];
+    const RELAY: PeerID = [3u8; 32];
+
+    type Probes = Vec<(Endpoint, PunchProbe)>;
+
+    /// Run the introduction exchange; returns both sides and their probes
+    fn introduce() -> (Rendezvous, NatTraversal, NatTraversal, Probes, Probes) {
+        let mut rendezvous = Rendezvous::new(RELAY, 4);
+        rendezvous.peer_connected(ALICE);
+        rendezvous.peer_connected(BOB);
+        let mut alice = NatTraversal::new(ALICE, 1_000);
+        let mut bob = NatTraversal::new(BOB, 1_000);
+
+        let request = alice.request(BOB, vec![Endpoint::v4([192, 168, 1, 2], 7000)], &[11u8; 32], 0);
+        let to_bob = rendezvous.introduce(&request, Endpoint::v4([203, 0, 113, 5], 41000), 0).unwrap();
+        assert_eq!(to_bob.endpoints.last(), Some(&Endpoint::v4([203, 0, 113, 5], 41000)));
+        let (reply, bob_probes) = bob.accept(&to_bob, Vec::new(), &[22u8; 32], 10).unwrap();
+        let to_alice = rendezvous.introduce(&reply, Endpoint::v4([198, 51, 100, 9], 52000), 10).unwrap();
+        let alice_probes = alice.on_intro(&to_alice).unwrap();
+        (rendezvous, alice, bob, alice_probes, bob_probes)
+    }
+
+    #[test]
+    fn test_hole_punch_with_authenticated_probes() {
+        let (rendezvous, mut alice, mut bob, alice_probes, bob_probes) = introduce();
+        assert_eq!(alice_probes.len(), 1);
+        assert_eq!(bob_probes.len(), 2);
+
+        // Forged probe from an off-path host
+        let mut forged = bob_probes[0].1.clone();
+        forged.tag = [0u8; 32];
+        assert_eq!(alice.on_probe(&forged, Endpoint::v4([6, 6, 6, 6], 1)), Err(NatError::InvalidProbe));
+
+        let (path, punched) = alice.on_probe(&bob_probes[1].1, alice_probes[0].0).unwrap();
+        assert_eq!(path.path, PathType::HolePunched);
+        assert_eq!(path.peer, BOB);
+        assert_eq!(punched, Endpoint::v4([198, 51, 100, 9], 52000));
+        assert!(bob.on_probe(&alice_probes[0].1, Endpoint::v4([203, 0, 113, 5], 41000)).is_ok());
+        assert_eq!(alice.pending() + bob.pending(), 0);
+
+        let stranger = PunchRequest { initiator: [9u8; 32], target: BOB, endpoints: Vec::new(), dh_public: [0u8; 32], nonce: [0u8; 32] };
+        assert_eq!(
+            rendezvous.introduce(&stranger, Endpoint::v4([1, 1, 1, 1], 1), 0),
+            Err(NatError::NotConnected([9u8; 32]))
+        );
+    }
+
+    #[test]
+    fn test_relay_fallback_is_end_to_end_encrypted() {
+        let (mut rendezvous, mut alice, mut bob, _, _) = introduce();
+        assert!(alice.expire(1_000).is_empty());
+
+        // No probe got through: both sides fall back to the rendezvous
+        let mut a = alice.expire(1_210).pop().unwrap();
+        let mut b = bob.expire(1_210).pop().unwrap();
+        assert_eq!((a.peer, a.relay), (BOB, RELAY));
+        let circuit = rendezvous.open_circuit(ALICE, BOB, &a.nonce).unwrap();
+        assert_eq!(circuit, a.session.circuit_id());
+        assert_eq!(circuit, b.session.circuit_id());
+        assert_eq!(a.session.path(a.relay).path, PathType::Relayed);
+
+        let frame = a.session.seal(b"over the relay");
+        assert!(!frame.ciphertext.windows(5).any(|w| w == b"relay"));
+        assert_eq!(rendezvous.forward(&ALICE, &frame), Ok(BOB));
+        assert_eq!(rendezvous.forward(&[9u8; 32], &frame), Err(NatError::UnknownCircuit));
+        assert_eq!(b.session.open(&frame).unwrap(), b"over the relay");
+        assert_eq!(b.session.open(&frame), Err(NatError::Replay));
+
+        // The relay cannot alter frames, and directions use distinct keys
+        let mut altered = a.session.seal(b"second");
+        altered.ciphertext[0] ^= 1;
+        assert_eq!(b.session.open(&altered), Err(NatError::AuthenticationFailed));
+        let reply = b.session.seal(b"reply");
+        assert_eq!(a.session.open(&reply).unwrap(), b"reply");
+
+        rendezvous.peer_disconnected(&BOB);
+        assert_eq!(rendezvous.forward(&ALICE, &frame), Err(NatError::UnknownCircuit));
+    }
+}
@@ -34,6 +34,10 @@ use alloc::string::String;
 
 use crate::txo::Txo;
 use crate::consensus::ValidatorRegistry;
+use crate::handshake::{self, HandshakeError, Hello, Negotiated};
+use crate::addressbook::{AddressBook, TransportHint};
+use crate::transport::Channel;
+use crate::clock;
 
 /// Node identifier (SHA3-256 hash of node public key)
 pub type NodeID = [u8; 32];
@@ -187,6 +191,12 @@ pub struct P2PNetwork {
     
     /// Maximum number of peers
     pub max_peers: usize,
+    
+    /// Negotiated session parameters per peer
+    pub sessions: BTreeMap<PeerID, Negotiated>,
+    
+    /// Peers remembered across restarts
+    pub address_book: AddressBook,
 }
 
 impl P2PNetwork {
@@ -204,9 +214,69 @@ impl P2PNetwork {
             validator_set: ValidatorRegistry::new(),
             peers: BTreeMap::new(),
             max_peers,
+            sessions: BTreeMap::new(),
+            address_book: AddressBook::default(),
         }
     }
     
+    /// Install the address book loaded at startup
+    ///
+    /// Reconnect candidates are available via `AddressBook::candidates`;
+    /// reputations are carried over as peers connect.
+    pub fn load_address_book(&mut self, book: AddressBook) {
+        self.address_book = book;
+    }
+    
+    /// Remember a connected peer in the address book
+    ///
+    /// ## Inputs
+    /// - `peer_id`: Connected peer
+    /// - `hint`: Address the peer was reached at
+    /// - `learned_over`: Channel the peer was learned over (anonymous
+    ///   channels are not persisted by default)
+    pub fn remember_peer(&mut self, peer_id: &PeerID, hint: Option<TransportHint>, learned_over: Channel) -> bool {
+        let Some(info) = self.peers.get(peer_id) else {
+            return false;
+        };
+        self.address_book.record(*peer_id, info.public_key, hint, info.reputation, learned_over, clock::now_ms());
+        true
+    }
+    
+    /// Handshake message this node sends on new connections
+    pub fn hello(&self) -> Hello {
+        Hello::local(self.node_id)
+    }
+    
+    /// Connect to a peer after version negotiation
+    ///
+    /// ## Inputs
+    /// - `peer_info`: Peer connection information
+    /// - `remote_hello`: CBOR hello received from the peer
+    ///
+    /// ## Returns
+    /// - Negotiated session parameters if the peer is compatible and accepted
+    /// - `HandshakeError` describing the incompatibility otherwise
+    ///
+    /// ## Security
+    /// - Incompatible peers are never added to the peer set
+    /// - The hello must come from the node the connection was opened to
+    pub fn connect_peer_with_hello(
+        &mut self,
+        peer_info: PeerInfo,
+        remote_hello: &[u8],
+    ) -> Result<Negotiated, HandshakeError> {
+        let remote = Hello::from_cbor(remote_hello)?;
+        let session = handshake::negotiate(&self.hello(), &remote)?;
+        
+        let peer_id = peer_info.node_id;
+        if remote.node_id != peer_id || !self.connect_peer(peer_id, peer_info) {
+            return Err(HandshakeError::PeerRejected);
+        }
+        self.sessions.insert(peer_id, session);
+        
+        Ok(session)
+    }
+    
     /// Broadcast TXO to all connected peers
     ///
     /// ## Inputs
@@ -320,7 +390,7 @@ impl P2PNetwork {
     /// ## Returns
     /// - `true` if connection successful
     /// - `false` if connection failed or max peers reached
-    pub fn connect_peer(&mut self, peer_id: PeerID, peer_info: PeerInfo) -> bool {
+    pub fn connect_peer(&mut self, peer_id: PeerID, mut peer_info: PeerInfo) -> bool {
         // Check if already connected
         if self.peers.contains_key(&peer_id) {
             return false;
@@ -331,6 +401,11 @@ impl P2PNetwork {
             return false;
         }
         
+        // Carry over reputation from a previous run
+        if let Some(entry) = self.address_book.get(&peer_id) {
+            peer_info.reputation = self.address_book.reputation_at(entry, clock::now_ms());
+        }
+        
         // Add peer
         self.peers.insert(peer_id, peer_info);
         
@@ -346,7 +421,10 @@ impl P2PNetwork {
     /// ## Inputs
     /// - `peer_id`: Peer to disconnect from
     pub fn disconnect_peer(&mut self, peer_id: &PeerID) {
-        self.peers.remove(peer_id);
+        if let Some(info) = self.peers.remove(peer_id) {
+            self.address_book.update_reputation(peer_id, info.reputation, clock::now_ms());
+        }
+        self.sessions.remove(peer_id);
         
         // TODO: Close libp2p connection
         
@@ -373,7 +451,9 @@ impl P2PNetwork {
     pub fn ban_peer(&mut self, peer_id: &PeerID) {
         if let Some(peer_info) = self.peers.get_mut(peer_id) {
             peer_info.status = PeerStatus::Banned;
+            peer_info.reputation = 0;
         }
+        self.address_book.update_reputation(peer_id, 0, clock::now_ms());
         
         // TODO: Emit audit TXO for ban event
     }
@@ -384,6 +464,7 @@ mod tests {
     use super::*;
     use alloc::vec;
     use crate::txo::TxoType;
+    use crate::addressbook::AddressBookConfig;
     
     #[test]
     fn test_mempool() {
@@ -433,4 +514,63 @@ mod tests {
         assert!(connected);
         assert_eq!(network.peers.len(), 1);
     }
+    
+    #[test]
+    fn test_connect_with_hello() {
+        let mut network = P2PNetwork::new([1u8; 32], [2u8; 32], 10);
+        let peer_info = |node_id| PeerInfo {
+            node_id,
+            public_key: [4u8; 32],
+            reputation: 50,
+            successful_interactions: 0,
+            failed_interactions: 0,
+            status: PeerStatus::Connected,
+        };
+        
+        // Incompatible peer is refused with an explicit error
+        let mut old = Hello::local([3u8; 32]);
+        old.major = 0;
+        let result = network.connect_peer_with_hello(peer_info([3u8; 32]), &old.to_cbor());
+        assert!(matches!(result, Err(HandshakeError::ProtocolMismatch { .. })));
+        assert!(network.peers.is_empty());
+        
+        // Hello from a different node than the one dialled
+        let other = Hello::local([5u8; 32]).to_cbor();
+        let result = network.connect_peer_with_hello(peer_info([3u8; 32]), &other);
+        assert_eq!(result, Err(HandshakeError::PeerRejected));
+        
+        let hello = Hello::local([3u8; 32]).to_cbor();
+        let session = network.connect_peer_with_hello(peer_info([3u8; 32]), &hello).unwrap();
+        assert_eq!(network.sessions.get(&[3u8; 32]), Some(&session));
+        
+        network.disconnect_peer(&[3u8; 32]);
+        assert!(network.sessions.is_empty());
+    }
+    
+    #[test]
+    fn test_reputation_survives_restart() {
+        let peer_info = |reputation| PeerInfo {
+            node_id: [3u8; 32],
+            public_key: [4u8; 32],
+            reputation,
+            successful_interactions: 0,
+            failed_interactions: 0,
+            status: PeerStatus::Connected,
+        };
+        let key = [9u8; 32];
+        
+        let mut network = P2PNetwork::new([1u8; 32], [2u8; 32], 10);
+        assert!(!network.remember_peer(&[3u8; 32], None, Channel::Tcp));
+        network.connect_peer([3u8; 32], peer_info(50));
+        assert!(network.remember_peer(&[3u8; 32], None, Channel::Tcp));
+        network.ban_peer(&[3u8; 32]);
+        let sealed = network.address_book.seal(&key, &[0u8; 32]);
+        
+        // After a restart the banned peer reconnects with its reputation
+        let mut restarted = P2PNetwork::new([1u8; 32], [2u8; 32], 10);
+        let config = AddressBookConfig { reputation_half_life_ms: 0, ..AddressBookConfig::default() };
+        restarted.load_address_book(AddressBook::open(&sealed, &key, config, clock::now_ms()).unwrap());
+        restarted.connect_peer([3u8; 32], peer_info(50));
+        assert_eq!(restarted.peers[&[3u8; 32]].reputation, 0);
+    }
 }
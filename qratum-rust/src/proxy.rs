@@ -31,6 +31,7 @@ use alloc::string::String;
 
 use crate::txo::{Txo, TxoType};
 use zeroize::{Zeroize, ZeroizeOnDrop};
+use crate::clock;
 
 /// Proxy Configuration
 #[derive(Debug, Clone)]
@@ -175,7 +176,7 @@ impl ProxyApprovalRequest {
             id: [0u8; 32], // Computed from hash
             operation,
             justification,
-            timestamp: current_timestamp(),
+            timestamp: clock::now_ms(),
             required_bond,
             requester_id,
         }
@@ -396,22 +397,6 @@ impl ProxyManager {
     }
 }
 
-/// Get current timestamp (milliseconds since epoch)
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,6 +8,8 @@
 //! ## Architectural Role
 //!
 //! - **Progressive Threshold Decay**: Quorum threshold decreases over time if consensus not reached
+//! - **Pluggable Decay Policies**: Linear, exponential, and stepwise curves behind `DecayPolicy`,
+//!   bounds-checked against the configured safety floor before use
 //! - **DecayJustification TXO**: Every threshold change emits auditable TXO
 //! - **Byzantine Fault Tolerance**: Handles up to f faulty nodes in 3f+1 quorum
 //! - **Censorship Resistance**: Failed convergence emits audit trail
@@ -36,6 +38,7 @@ use alloc::string::String;
 
 use crate::txo::{Txo, TxoType};
 use zeroize::{Zeroize, ZeroizeOnDrop};
+use crate::clock;
 
 /// Quorum Member
 ///
@@ -124,6 +127,259 @@ impl Default for QuorumConfig {
     }
 }
 
+/// Decay Policy
+///
+/// ## Lifecycle Stage: Quorum Convergence
+///
+/// Maps the number of elapsed decay intervals to a consensus threshold.
+///
+/// ## Security Rationale
+/// - Policies must be non-increasing and never fall below their declared floor
+/// - `validate_policy` checks every reachable interval before a policy is used
+/// - The policy identifier is recorded in every `DecayJustification` TXO so
+///   observers can recompute the expected threshold
+pub trait DecayPolicy {
+    /// Stable policy identifier (recorded in every DecayJustification)
+    fn policy_id(&self) -> &'static str;
+
+    /// Lowest threshold the policy will ever produce
+    fn floor(&self) -> u8;
+
+    /// Threshold after `intervals` elapsed decay intervals
+    ///
+    /// # Inputs
+    /// - `initial`: Threshold at interval 0
+    /// - `intervals`: Number of decay intervals elapsed
+    fn threshold_after(&self, initial: u8, intervals: u32) -> u8;
+}
+
+/// Linear Decay: fixed percentage-point step per interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearDecay {
+    /// Percentage points removed per interval
+    pub step: u8,
+    /// Lowest threshold
+    pub floor: u8,
+}
+
+impl LinearDecay {
+    /// Create a linear policy
+    ///
+    /// Panics (at compile time in const context) if `step` is zero or `floor` exceeds 100.
+    pub const fn new(step: u8, floor: u8) -> Self {
+        assert!(step > 0, "linear decay step must be non-zero");
+        assert!(floor <= 100, "decay floor must be a percentage");
+        Self { step, floor }
+    }
+
+    /// Linear policy matching the legacy `QuorumConfig` schedule
+    pub fn from_config(config: &QuorumConfig) -> Self {
+        Self {
+            step: config.decay_step,
+            floor: config.minimum_threshold,
+        }
+    }
+
+    /// Compile-time floor check: `const P: LinearDecay = LinearDecay::new(5, 51).assert_floor_at_least(51);`
+    pub const fn assert_floor_at_least(self, safety_floor: u8) -> Self {
+        assert!(self.floor >= safety_floor, "decay policy floor below safety floor");
+        self
+    }
+}
+
+impl DecayPolicy for LinearDecay {
+    fn policy_id(&self) -> &'static str {
+        "linear"
+    }
+
+    fn floor(&self) -> u8 {
+        self.floor
+    }
+
+    fn threshold_after(&self, initial: u8, intervals: u32) -> u8 {
+        let decayed = (initial as u32).saturating_sub((self.step as u32).saturating_mul(intervals));
+        (decayed as u8).max(self.floor).min(initial)
+    }
+}
+
+/// Exponential Decay: the excess over the floor shrinks by a fixed ratio per interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExponentialDecay {
+    /// Percentage of the excess retained per interval (0-99)
+    pub retain_percent: u8,
+    /// Lowest threshold
+    pub floor: u8,
+}
+
+impl ExponentialDecay {
+    /// Create an exponential policy
+    ///
+    /// Panics (at compile time in const context) if `retain_percent` is not below 100
+    /// or `floor` exceeds 100.
+    pub const fn new(retain_percent: u8, floor: u8) -> Self {
+        assert!(retain_percent < 100, "exponential decay must retain less than 100%");
+        assert!(floor <= 100, "decay floor must be a percentage");
+        Self { retain_percent, floor }
+    }
+
+    /// Compile-time floor check
+    pub const fn assert_floor_at_least(self, safety_floor: u8) -> Self {
+        assert!(self.floor >= safety_floor, "decay policy floor below safety floor");
+        self
+    }
+}
+
+impl DecayPolicy for ExponentialDecay {
+    fn policy_id(&self) -> &'static str {
+        "exponential"
+    }
+
+    fn floor(&self) -> u8 {
+        self.floor
+    }
+
+    fn threshold_after(&self, initial: u8, intervals: u32) -> u8 {
+        if initial <= self.floor {
+            return initial;
+        }
+        let mut excess = (initial - self.floor) as u32;
+        for _ in 0..intervals {
+            if excess == 0 {
+                break;
+            }
+            excess = excess * self.retain_percent as u32 / 100;
+        }
+        self.floor + excess as u8
+    }
+}
+
+/// Stepwise Decay: explicit threshold per interval, holding the last entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepwiseDecay {
+    /// Threshold after 1, 2, ... intervals
+    pub schedule: &'static [u8],
+    /// Lowest threshold
+    pub floor: u8,
+}
+
+impl StepwiseDecay {
+    /// Create a stepwise policy
+    ///
+    /// Panics (at compile time in const context) if the schedule increases or
+    /// drops below `floor`.
+    pub const fn new(schedule: &'static [u8], floor: u8) -> Self {
+        assert!(floor <= 100, "decay floor must be a percentage");
+        let mut i = 0;
+        while i < schedule.len() {
+            assert!(schedule[i] >= floor, "stepwise schedule below floor");
+            assert!(i == 0 || schedule[i] <= schedule[i - 1], "stepwise schedule must not increase");
+            i += 1;
+        }
+        Self { schedule, floor }
+    }
+
+    /// Compile-time floor check
+    pub const fn assert_floor_at_least(self, safety_floor: u8) -> Self {
+        assert!(self.floor >= safety_floor, "decay policy floor below safety floor");
+        self
+    }
+}
+
+impl DecayPolicy for StepwiseDecay {
+    fn policy_id(&self) -> &'static str {
+        "stepwise"
+    }
+
+    fn floor(&self) -> u8 {
+        self.floor
+    }
+
+    fn threshold_after(&self, initial: u8, intervals: u32) -> u8 {
+        if intervals == 0 || self.schedule.is_empty() {
+            return initial;
+        }
+        let index = (intervals as usize - 1).min(self.schedule.len() - 1);
+        self.schedule[index].max(self.floor).min(initial)
+    }
+}
+
+/// Decay policy validation failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecayPolicyError {
+    /// Decay interval is zero, so the interval bound is undefined
+    ZeroInterval,
+    /// Policy floor is below the configured safety floor
+    FloorBelowSafety {
+        /// Policy floor
+        policy_floor: u8,
+        /// Configured safety floor (`QuorumConfig::minimum_threshold`)
+        safety_floor: u8,
+    },
+    /// Policy does not start at the initial threshold
+    InitialMismatch,
+    /// Threshold below the safety floor at a reachable interval
+    BelowSafetyFloor {
+        /// Interval index
+        intervals: u32,
+        /// Threshold produced
+        threshold: u8,
+    },
+    /// Threshold increased between consecutive intervals
+    NonMonotonic {
+        /// Interval index where the increase occurred
+        intervals: u32,
+    },
+}
+
+/// Validate a decay policy against a quorum configuration
+///
+/// ## Lifecycle Stage: Quorum Convergence (pre-flight)
+///
+/// # Inputs
+/// - `policy`: Policy to check
+/// - `config`: Configuration supplying the initial threshold, safety floor
+///   (`minimum_threshold`), and interval bound
+///
+/// # Outputs
+/// - `Ok(())` if every interval reachable within `max_convergence_time_ms`
+///   yields a non-increasing threshold at or above the safety floor
+///
+/// ## Security Rationale
+/// - The check is exhaustive over the reachable interval range, so a policy
+///   that passes cannot decay below the floor during a convergence window
+pub fn validate_policy(policy: &dyn DecayPolicy, config: &QuorumConfig) -> Result<(), DecayPolicyError> {
+    if config.decay_interval_ms == 0 {
+        return Err(DecayPolicyError::ZeroInterval);
+    }
+
+    let safety_floor = config.minimum_threshold;
+    if policy.floor() < safety_floor {
+        return Err(DecayPolicyError::FloorBelowSafety {
+            policy_floor: policy.floor(),
+            safety_floor,
+        });
+    }
+
+    if policy.threshold_after(config.initial_threshold, 0) != config.initial_threshold {
+        return Err(DecayPolicyError::InitialMismatch);
+    }
+
+    let max_intervals = (config.max_convergence_time_ms / config.decay_interval_ms).min(u32::MAX as u64) as u32;
+    let mut previous = config.initial_threshold;
+    for intervals in 1..=max_intervals {
+        let threshold = policy.threshold_after(config.initial_threshold, intervals);
+        if threshold < safety_floor {
+            return Err(DecayPolicyError::BelowSafetyFloor { intervals, threshold });
+        }
+        if threshold > previous {
+            return Err(DecayPolicyError::NonMonotonic { intervals });
+        }
+        previous = threshold;
+    }
+
+    Ok(())
+}
+
 /// Decay Justification
 ///
 /// ## Lifecycle Stage: Quorum Convergence
@@ -156,6 +412,9 @@ pub struct DecayJustification {
     
     /// Active quorum members
     pub active_members: usize,
+    
+    /// Identifier of the decay policy that produced the new threshold
+    pub policy_id: &'static str,
 }
 
 impl DecayJustification {
@@ -169,9 +428,10 @@ impl DecayJustification {
     /// - Externally observable for censorship detection
     pub fn to_txo(&self) -> Txo {
         let payload = alloc::format!(
-            "Threshold decay: {}% → {}% | Reason: {} | Votes: {}/{} | Active: {}",
+            "Threshold decay: {}% → {}% | Policy: {} | Reason: {} | Votes: {}/{} | Active: {}",
             self.previous_threshold,
             self.new_threshold,
+            self.policy_id,
             self.reason,
             self.current_votes,
             self.required_votes_previous,
@@ -209,6 +469,9 @@ pub struct QuorumState {
     /// Last decay time
     pub last_decay_time: u64,
     
+    /// Decay intervals applied so far
+    pub decay_intervals: u32,
+    
     /// Decay justifications (audit trail)
     pub decay_justifications: Vec<DecayJustification>,
 }
@@ -218,13 +481,14 @@ impl QuorumState {
     ///
     /// ## Lifecycle Stage: Quorum Convergence (initialization)
     pub fn new(config: &QuorumConfig, members: Vec<QuorumMember>) -> Self {
-        let start_time = current_timestamp();
+        let start_time = clock::now_ms();
         Self {
             current_threshold: config.initial_threshold,
             votes: Vec::new(),
             members,
             start_time,
             last_decay_time: start_time,
+            decay_intervals: 0,
             decay_justifications: Vec::new(),
         }
     }
@@ -294,6 +558,9 @@ impl QuorumState {
     /// # Outputs
     /// - `Option<DecayJustification>` if decay applied, `None` otherwise
     ///
+    /// Uses the linear schedule defined by `config.decay_step`; see
+    /// `apply_decay_with` for other policies.
+    ///
     /// ## Anti-Censorship Mechanism
     /// - Every decay emits DecayJustification TXO
     /// - External observers can verify decay legitimacy
@@ -303,25 +570,59 @@ impl QuorumState {
     /// - Logs decay event to ephemeral ledger
     /// - Records old/new thresholds and rationale
     pub fn apply_decay(&mut self, config: &QuorumConfig) -> Option<DecayJustification> {
-        let current_time = current_timestamp();
+        self.apply_decay_with(config, &LinearDecay::from_config(config))
+            .ok()
+            .flatten()
+    }
+    
+    /// Apply progressive decay under a decay policy
+    ///
+    /// ## Lifecycle Stage: Quorum Convergence
+    ///
+    /// # Inputs
+    /// - `config`: Quorum configuration (interval, safety floor)
+    /// - `policy`: Decay curve
+    ///
+    /// # Outputs
+    /// - `Ok(Some(DecayJustification))` if decay applied
+    /// - `Ok(None)` if the interval has not elapsed or the floor is reached
+    /// - `Err(DecayPolicyError)` if the policy fails bounds checking
+    ///
+    /// ## Security Rationale
+    /// - Policy validated before every application
+    /// - Result clamped to `config.minimum_threshold` as defence in depth
+    pub fn apply_decay_with(
+        &mut self,
+        config: &QuorumConfig,
+        policy: &dyn DecayPolicy,
+    ) -> Result<Option<DecayJustification>, DecayPolicyError> {
+        validate_policy(policy, config)?;
+        
+        let current_time = clock::now_ms();
         
         // Check if decay interval elapsed
         if current_time - self.last_decay_time < config.decay_interval_ms {
-            return None;
+            return Ok(None);
         }
         
         // Check if minimum threshold reached
         if self.current_threshold <= config.minimum_threshold {
-            return None;
+            return Ok(None);
         }
         
         // Calculate new threshold
         let previous_threshold = self.current_threshold;
-        let new_threshold = previous_threshold.saturating_sub(config.decay_step)
-            .max(config.minimum_threshold);
+        let intervals = self.decay_intervals.saturating_add(1);
+        let new_threshold = policy.threshold_after(config.initial_threshold, intervals)
+            .max(config.minimum_threshold)
+            .min(previous_threshold);
+        
+        // The interval is consumed even if a stepwise policy holds the threshold
+        self.decay_intervals = intervals;
+        self.last_decay_time = current_time;
         
         if new_threshold == previous_threshold {
-            return None; // No change
+            return Ok(None); // No change
         }
         
         // Create justification
@@ -342,14 +643,14 @@ impl QuorumState {
             current_votes: self.votes.len(),
             required_votes_previous,
             active_members,
+            policy_id: policy.policy_id(),
         };
         
         // Apply decay
         self.current_threshold = new_threshold;
-        self.last_decay_time = current_time;
         self.decay_justifications.push(justification.clone());
         
-        Some(justification)
+        Ok(Some(justification))
     }
     
     /// Check if convergence timed out
@@ -360,7 +661,7 @@ impl QuorumState {
     /// - Timeout triggers fallback or abort
     /// - Emits audit trail for failed convergence
     pub fn is_timed_out(&self, config: &QuorumConfig) -> bool {
-        let current_time = current_timestamp();
+        let current_time = clock::now_ms();
         current_time - self.start_time >= config.max_convergence_time_ms
     }
 }
@@ -434,22 +735,6 @@ pub fn run_convergence(
     }
 }
 
-/// Get current timestamp (milliseconds since epoch)
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0 // Deterministic default for no_std
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -471,9 +756,104 @@ mod tests {
             current_votes: 2,
             required_votes_previous: 3,
             active_members: 4,
+            policy_id: "linear",
         };
         
         let txo = justification.to_txo();
         assert_eq!(txo.txo_type, TxoType::DecayJustification);
+        assert!(core::str::from_utf8(&txo.payload).unwrap().contains("Policy: linear"));
+    }
+    
+    #[test]
+    fn test_policy_curves() {
+        let linear = LinearDecay::new(5, 51);
+        assert_eq!(linear.threshold_after(67, 0), 67);
+        assert_eq!(linear.threshold_after(67, 2), 57);
+        assert_eq!(linear.threshold_after(67, 10), 51);
+        
+        let exponential = ExponentialDecay::new(50, 51);
+        assert_eq!(exponential.threshold_after(67, 1), 59);
+        assert_eq!(exponential.threshold_after(67, 2), 55);
+        assert_eq!(exponential.threshold_after(67, 20), 51);
+        
+        const SCHEDULE: StepwiseDecay = StepwiseDecay::new(&[67, 60, 60, 55], 51).assert_floor_at_least(51);
+        assert_eq!(SCHEDULE.threshold_after(67, 1), 67);
+        assert_eq!(SCHEDULE.threshold_after(67, 2), 60);
+        assert_eq!(SCHEDULE.threshold_after(67, 100), 55);
+    }
+    
+    #[test]
+    fn test_validate_policy_bounds() {
+        let config = QuorumConfig::default();
+        
+        assert_eq!(validate_policy(&LinearDecay::from_config(&config), &config), Ok(()));
+        assert_eq!(validate_policy(&ExponentialDecay::new(80, 51), &config), Ok(()));
+        
+        assert_eq!(
+            validate_policy(&LinearDecay::new(5, 40), &config),
+            Err(DecayPolicyError::FloorBelowSafety { policy_floor: 40, safety_floor: 51 })
+        );
+        
+        // Policy that lies about its floor
+        struct Rogue;
+        impl DecayPolicy for Rogue {
+            fn policy_id(&self) -> &'static str { "rogue" }
+            fn floor(&self) -> u8 { 51 }
+            fn threshold_after(&self, initial: u8, intervals: u32) -> u8 {
+                if intervals >= 3 { 30 } else { initial }
+            }
+        }
+        assert_eq!(
+            validate_policy(&Rogue, &config),
+            Err(DecayPolicyError::BelowSafetyFloor { intervals: 3, threshold: 30 })
+        );
+        
+        struct Rebound;
+        impl DecayPolicy for Rebound {
+            fn policy_id(&self) -> &'static str { "rebound" }
+            fn floor(&self) -> u8 { 51 }
+            fn threshold_after(&self, initial: u8, intervals: u32) -> u8 {
+                if intervals == 1 { 55 } else { initial }
+            }
+        }
+        assert_eq!(validate_policy(&Rebound, &config), Err(DecayPolicyError::NonMonotonic { intervals: 2 }));
+        
+        let zero = QuorumConfig { decay_interval_ms: 0, ..QuorumConfig::default() };
+        assert_eq!(validate_policy(&LinearDecay::from_config(&zero), &zero), Err(DecayPolicyError::ZeroInterval));
+    }
+    
+    #[test]
+    fn test_apply_decay_records_policy() {
+        let config = QuorumConfig {
+            decay_interval_ms: 0,
+            ..QuorumConfig::default()
+        };
+        let mut state = QuorumState::new(&config, Vec::new());
+        
+        // Zero interval fails validation instead of decaying unboundedly
+        assert_eq!(
+            state.apply_decay_with(&config, &ExponentialDecay::new(50, 51)).unwrap_err(),
+            DecayPolicyError::ZeroInterval
+        );
+        
+        let config = QuorumConfig {
+            decay_interval_ms: 1,
+            ..QuorumConfig::default()
+        };
+        // Backdate so an interval has elapsed under both std and no_std clocks
+        state.last_decay_time = 0;
+        state.start_time = 0;
+        let justification = state.apply_decay_with(&config, &ExponentialDecay::new(50, 51));
+        
+        #[cfg(feature = "std")]
+        {
+            let justification = justification.unwrap().unwrap();
+            assert_eq!(justification.policy_id, "exponential");
+            assert_eq!(justification.new_threshold, 59);
+            assert_eq!(state.current_threshold, 59);
+            assert_eq!(state.decay_intervals, 1);
+        }
+        #[cfg(not(feature = "std"))]
+        assert!(matches!(justification, Ok(None)));
     }
 }
@@ -0,0 +1,376 @@
+//! # Reconcile Module - Mempool Set Reconciliation
+//!
+//! ## Lifecycle Stage: Network Infrastructure (TXO gossip)
+//!
+//! Peers' mempools mostly overlap, so gossiping every TXO id wastes
+//! bandwidth. Instead peers exchange invertible Bloom lookup tables (IBLTs)
+//! over their TXO ids. Subtracting two IBLTs cancels the shared ids; the
+//! small remainder is peeled into "only I have" and "only you have", and
+//! only those TXOs cross the wire.
+//!
+//! ## Architectural Role
+//!
+//! - **Sketch**: Fixed-size IBLT whose size tracks the expected difference,
+//!   not the mempool size
+//! - **Decode**: Peeling recovers the symmetric difference or reports failure
+//! - **Reconciler**: Per-peer state; each failure doubles the next sketch,
+//!   and repeated failures fall back to full sync
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Local mempool + remote CBOR sketch
+//! - Output: TXOs to send and TXO ids to request, or `FullSync`
+//!
+//! ## Security Rationale
+//!
+//! - Cell checksums are domain-separated SHA3 of the id, so a cell mixing
+//!   several ids is not mistaken for a single one
+//! - Recovered ids are still fetched and verified as ordinary TXOs
+//! - Sketch size is capped, bounding the work a peer can demand
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256};
+
+use crate::p2p::{PeerID, TxoMempool};
+use crate::txo::Txo;
+
+/// Hash functions (cells per id)
+const HASH_COUNT: usize = 3;
+
+/// Largest sketch accepted or produced (cells)
+pub const MAX_SKETCH_CELLS: usize = 1 << 14;
+
+/// One IBLT cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Encode, Decode)]
+pub struct Cell {
+    /// Net number of ids hashed into the cell
+    #[n(0)]
+    pub count: i32,
+
+    /// XOR of the ids
+    #[n(1)]
+    pub id_sum: [u8; 32],
+
+    /// XOR of the ids' checksums
+    #[n(2)]
+    pub check_sum: u64,
+}
+
+impl Cell {
+    fn toggle(&mut self, id: &[u8; 32], check: u64, delta: i32) {
+        self.count += delta;
+        for (sum, byte) in self.id_sum.iter_mut().zip(id.iter()) {
+            *sum ^= byte;
+        }
+        self.check_sum ^= check;
+    }
+
+    /// Cell holding exactly one id (either side)
+    fn is_pure(&self) -> bool {
+        (self.count == 1 || self.count == -1) && checksum(&self.id_sum) == self.check_sum
+    }
+
+    fn is_empty(&self) -> bool {
+        self.count == 0 && self.check_sum == 0 && self.id_sum == [0u8; 32]
+    }
+}
+
+/// Invertible Bloom lookup table over TXO ids
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct IbltSketch {
+    /// Cells, split into one partition per hash function
+    #[n(0)]
+    pub cells: Vec<Cell>,
+}
+
+/// Symmetric difference recovered from a sketch
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetDifference {
+    /// Ids only in the local set
+    pub local_only: Vec<[u8; 32]>,
+    /// Ids only in the remote set
+    pub remote_only: Vec<[u8; 32]>,
+}
+
+/// Reconciliation Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconcileError {
+    /// Sketches have different sizes
+    SizeMismatch,
+    /// Sketch is empty, larger than `MAX_SKETCH_CELLS`, or not partitioned
+    InvalidSize,
+    /// Difference too large for the sketch; peeling stalled
+    DecodeFailed,
+    /// Sketch bytes are not valid CBOR
+    Malformed,
+}
+
+impl IbltSketch {
+    /// Empty sketch with room for about `cells / 1.5` differences
+    ///
+    /// The size is rounded up to a multiple of the hash count.
+    pub fn new(cells: usize) -> Self {
+        let cells = cells.clamp(HASH_COUNT, MAX_SKETCH_CELLS).div_ceil(HASH_COUNT) * HASH_COUNT;
+        Self { cells: alloc::vec![Cell::default(); cells] }
+    }
+
+    /// Sketch of a set of ids
+    pub fn from_ids<'a, I: IntoIterator<Item = &'a [u8; 32]>>(ids: I, cells: usize) -> Self {
+        let mut sketch = Self::new(cells);
+        for id in ids {
+            sketch.insert(id);
+        }
+        sketch
+    }
+
+    /// Add an id
+    pub fn insert(&mut self, id: &[u8; 32]) {
+        self.apply(id, 1);
+    }
+
+    /// Remove an id
+    pub fn remove(&mut self, id: &[u8; 32]) {
+        self.apply(id, -1);
+    }
+
+    /// Cell-wise difference `self - other`
+    pub fn subtract(&self, other: &IbltSketch) -> Result<IbltSketch, ReconcileError> {
+        if self.cells.len() != other.cells.len() {
+            return Err(ReconcileError::SizeMismatch);
+        }
+        let cells = self
+            .cells
+            .iter()
+            .zip(other.cells.iter())
+            .map(|(a, b)| {
+                let mut cell = *a;
+                cell.count -= b.count;
+                for (sum, byte) in cell.id_sum.iter_mut().zip(b.id_sum.iter()) {
+                    *sum ^= byte;
+                }
+                cell.check_sum ^= b.check_sum;
+                cell
+            })
+            .collect();
+        Ok(IbltSketch { cells })
+    }
+
+    /// Peel a difference sketch (`local - remote`)
+    ///
+    /// # Outputs
+    /// - Ids only on the local side (count +1) and only on the remote side (-1)
+    /// - `ReconcileError::DecodeFailed` if cells remain that cannot be peeled
+    pub fn decode(mut self) -> Result<SetDifference, ReconcileError> {
+        let mut difference = SetDifference::default();
+        while let Some(pure) = self.cells.iter().position(Cell::is_pure) {
+            let cell = self.cells[pure];
+            if cell.count == 1 {
+                difference.local_only.push(cell.id_sum);
+            } else {
+                difference.remote_only.push(cell.id_sum);
+            }
+            self.apply(&cell.id_sum, -cell.count);
+        }
+        if self.cells.iter().all(Cell::is_empty) {
+            Ok(difference)
+        } else {
+            Err(ReconcileError::DecodeFailed)
+        }
+    }
+
+    /// Serialize to CBOR
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserialize from CBOR, rejecting sizes this node would not produce
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, ReconcileError> {
+        let sketch: Self = minicbor::decode(bytes).map_err(|_| ReconcileError::Malformed)?;
+        let len = sketch.cells.len();
+        if len == 0 || len > MAX_SKETCH_CELLS || !len.is_multiple_of(HASH_COUNT) {
+            return Err(ReconcileError::InvalidSize);
+        }
+        Ok(sketch)
+    }
+
+    fn apply(&mut self, id: &[u8; 32], delta: i32) {
+        let check = checksum(id);
+        let partition = self.cells.len() / HASH_COUNT;
+        let digest: [u8; 32] = Sha3_256::new().chain_update(b"QRATUM-IBLT-INDEX-v1").chain_update(id).finalize().into();
+        for i in 0..HASH_COUNT {
+            let word = u64::from_le_bytes(digest[i * 8..i * 8 + 8].try_into().unwrap_or([0u8; 8]));
+            let index = i * partition + (word % partition as u64) as usize;
+            self.cells[index].toggle(id, check, delta);
+        }
+    }
+}
+
+/// Per-id cell checksum
+fn checksum(id: &[u8; 32]) -> u64 {
+    let digest: [u8; 32] = Sha3_256::new().chain_update(b"QRATUM-IBLT-CHECK-v1").chain_update(id).finalize().into();
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&digest[..8]);
+    // Zero is reserved for empty cells
+    u64::from_le_bytes(word) | 1
+}
+
+/// Result of reconciling with a peer
+#[derive(Debug, Clone)]
+pub enum ReconcileOutcome {
+    /// Difference recovered
+    Missing {
+        /// Local TXOs the peer lacks
+        send: Vec<Txo>,
+        /// TXO ids to request from the peer
+        request: Vec<[u8; 32]>,
+    },
+    /// Sketch too small; retry with the next (larger) sketch
+    Retry,
+    /// Reconciliation failed repeatedly; exchange full mempools
+    FullSync,
+}
+
+/// Per-peer reconciliation state
+#[derive(Debug, Clone)]
+pub struct Reconciler {
+    base_cells: usize,
+    max_failures: u32,
+    failures: BTreeMap<PeerID, u32>,
+}
+
+impl Reconciler {
+    /// Create a reconciler
+    ///
+    /// # Inputs
+    /// - `base_cells`: Sketch size for peers that reconciled last time
+    /// - `max_failures`: Consecutive failures before falling back to full sync
+    pub fn new(base_cells: usize, max_failures: u32) -> Self {
+        Self { base_cells, max_failures, failures: BTreeMap::new() }
+    }
+
+    /// Sketch size for `peer` (doubles per consecutive failure)
+    pub fn sketch_cells(&self, peer: &PeerID) -> usize {
+        let failures = self.failures.get(peer).copied().unwrap_or(0).min(16);
+        (self.base_cells << failures).min(MAX_SKETCH_CELLS)
+    }
+
+    /// Sketch of the local mempool to send to `peer`
+    pub fn sketch_for(&self, peer: &PeerID, mempool: &TxoMempool) -> IbltSketch {
+        IbltSketch::from_ids(mempool.pending_txos.keys(), self.sketch_cells(peer))
+    }
+
+    /// Reconcile the local mempool against a peer's sketch
+    ///
+    /// The peer's sketch must be sized with this node's `sketch_cells(peer)`;
+    /// both sides grow their sketches on the same failures.
+    pub fn reconcile(&mut self, peer: PeerID, mempool: &TxoMempool, remote: &IbltSketch) -> ReconcileOutcome {
+        let local = IbltSketch::from_ids(mempool.pending_txos.keys(), remote.cells.len());
+        let decoded = local.subtract(remote).and_then(IbltSketch::decode);
+        match decoded {
+            Ok(difference) => {
+                self.failures.remove(&peer);
+                let send = difference
+                    .local_only
+                    .iter()
+                    .filter_map(|id| mempool.pending_txos.get(id).cloned())
+                    .collect();
+                ReconcileOutcome::Missing { send, request: difference.remote_only }
+            }
+            Err(_) => {
+                let failures = self.failures.entry(peer).or_insert(0);
+                *failures += 1;
+                if *failures >= self.max_failures {
+                    self.failures.remove(&peer);
+                    ReconcileOutcome::FullSync
+                } else {
+                    ReconcileOutcome::Retry
+                }
+            }
+        }
+    }
+}
+
+impl Default for Reconciler {
+    fn default() -> Self {
+        Self::new(48, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::txo::TxoType;
+
+    fn id(i: u32) -> [u8; 32] {
+        Sha3_256::digest(i.to_le_bytes()).into()
+    }
+
+    fn mempool(range: core::ops::Range<u32>) -> TxoMempool {
+        let mut mempool = TxoMempool::new(10_000);
+        for i in range {
+            mempool.add_txo(Txo::new(TxoType::Input, i as u64, i.to_le_bytes().to_vec(), Vec::new()), 0);
+        }
+        mempool
+    }
+
+    #[test]
+    fn test_sketch_recovers_small_difference() {
+        let ours: Vec<[u8; 32]> = (0..1000).map(id).collect();
+        let theirs: Vec<[u8; 32]> = (5..1003).map(id).collect();
+        let local = IbltSketch::from_ids(&ours, 30);
+        let remote = IbltSketch::from_cbor(&IbltSketch::from_ids(&theirs, 30).to_cbor()).unwrap();
+
+        let mut difference = local.subtract(&remote).unwrap().decode().unwrap();
+        difference.local_only.sort();
+        difference.remote_only.sort();
+        let mut expected_local: Vec<[u8; 32]> = (0..5).map(id).collect();
+        let mut expected_remote: Vec<[u8; 32]> = (1000..1003).map(id).collect();
+        expected_local.sort();
+        expected_remote.sort();
+        assert_eq!(difference.local_only, expected_local);
+        assert_eq!(difference.remote_only, expected_remote);
+
+        // Identical sets decode to nothing; oversized differences fail
+        assert_eq!(local.subtract(&local).unwrap().decode(), Ok(SetDifference::default()));
+        let far: Vec<[u8; 32]> = (2000..2100).map(id).collect();
+        let far_sketch = IbltSketch::from_ids(&far, 30);
+        assert_eq!(local.subtract(&far_sketch).unwrap().decode(), Err(ReconcileError::DecodeFailed));
+        assert_eq!(local.subtract(&IbltSketch::new(60)), Err(ReconcileError::SizeMismatch));
+        assert_eq!(IbltSketch::from_cbor(&[0xff]), Err(ReconcileError::Malformed));
+    }
+
+    #[test]
+    fn test_reconciler_grows_then_falls_back() {
+        let peer = [7u8; 32];
+        let ours = mempool(0..500);
+        let theirs = mempool(3..520);
+        let mut reconciler = Reconciler::new(12, 3);
+
+        // 23 differences overflow 12 cells, then 24; 48 cells suffice
+        assert!(matches!(reconciler.reconcile(peer, &ours, &reconciler.sketch_for(&peer, &theirs)), ReconcileOutcome::Retry));
+        assert_eq!(reconciler.sketch_cells(&peer), 24);
+        assert!(matches!(reconciler.reconcile(peer, &ours, &reconciler.sketch_for(&peer, &theirs)), ReconcileOutcome::Retry));
+        match reconciler.reconcile(peer, &ours, &reconciler.sketch_for(&peer, &theirs)) {
+            ReconcileOutcome::Missing { send, request } => {
+                assert_eq!(send.len(), 3);
+                assert_eq!(request.len(), 20);
+                assert!(send.iter().all(|txo| !theirs.pending_txos.contains_key(&txo.id)));
+            }
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+        assert_eq!(reconciler.sketch_cells(&peer), 12);
+
+        // Disjoint mempools never decode at these sizes: full sync
+        let stranger = mempool(1000..1600);
+        let mut outcome = ReconcileOutcome::Retry;
+        for _ in 0..3 {
+            outcome = reconciler.reconcile(peer, &ours, &reconciler.sketch_for(&peer, &stranger));
+        }
+        assert!(matches!(outcome, ReconcileOutcome::FullSync));
+        assert_eq!(reconciler.sketch_cells(&peer), 12);
+    }
+}
@@ -0,0 +1,466 @@
+//! # Retention Module - Scheduled Secure Deletion of Persistent Artifacts
+//!
+//! ## Lifecycle Stage: Outcome Commitment → Retention Expiry (std only)
+//!
+//! In std deployments some artifacts outlive the session on disk: outcome
+//! TXO archives, evidence bundles, desktop database rows. The retention
+//! scheduler tracks each one against a per-class policy and purges it once
+//! the policy expires it.
+//!
+//! ## Deletion Methods
+//!
+//! - **Key destruction**: encrypted blobs have their key zeroized and dropped
+//!   first, so the ciphertext is unrecoverable even if the unlink is not
+//! - **Overwrite**: plaintext files are overwritten with zeros and synced
+//!   before unlinking (best effort; copy-on-write and journaling filesystems
+//!   may keep old blocks, which the proof records as `Unlinked`)
+//! - **External**: rows held by another store are deleted through
+//!   [`RecordStore`]
+//!
+//! ## Audit Trail
+//!
+//! Every purge appends a `DeletionProof` TXO to the ledger, recording what
+//! was deleted, when, under which policy and by which method. The artifact
+//! id is the TXO's predecessor so the proof links to the original record.
+//!
+//! ## Security Rationale
+//!
+//! - Retention is bounded by policy rather than left to operators
+//! - Failed deletions stay tracked and are retried on the next sweep
+//! - Keys are held in `Zeroizing` buffers and wiped on destruction
+
+extern crate alloc;
+extern crate std;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::fs::OpenOptions;
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+use minicbor::{Decode, Encode};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::ledger::MerkleLedger;
+use crate::txo::{Txo, TxoType};
+
+/// Overwrite buffer size
+const OVERWRITE_CHUNK: usize = 64 * 1024;
+
+/// Class of persistent artifact
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
+#[cbor(index_only)]
+pub enum ArtifactClass {
+    /// Archived outcome TXOs
+    #[n(0)] OutcomeArchive,
+    /// Evidence bundles (attestations, reports)
+    #[n(1)] EvidenceBundle,
+    /// Desktop database rows
+    #[n(2)] DesktopRow,
+}
+
+/// Retention policy for one artifact class
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Maximum artifact age in milliseconds
+    pub max_age_ms: u64,
+
+    /// Maximum artifacts kept; oldest beyond this are purged
+    pub max_count: Option<usize>,
+}
+
+/// Where an artifact lives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Storage {
+    /// Plaintext file, overwritten before unlinking
+    File(PathBuf),
+    /// Encrypted file whose key is registered with the scheduler
+    EncryptedFile {
+        /// Ciphertext location
+        path: PathBuf,
+        /// Key id passed to [`RetentionScheduler::register_key`]
+        key_id: [u8; 32],
+    },
+    /// Row in an external store
+    Record {
+        /// Table name
+        table: String,
+        /// Primary key
+        key: Vec<u8>,
+    },
+}
+
+/// Tracked artifact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artifact {
+    /// Artifact id (e.g. outcome TXO id, bundle hash)
+    pub id: [u8; 32],
+    /// Artifact class
+    pub class: ArtifactClass,
+    /// Creation time (milliseconds since epoch)
+    pub created_ms: u64,
+    /// Storage location
+    pub storage: Storage,
+}
+
+/// How an artifact was destroyed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cbor(index_only)]
+pub enum DeletionMethod {
+    /// Contents overwritten and synced, then unlinked
+    #[n(0)] Overwrite,
+    /// Encryption key destroyed, then ciphertext unlinked
+    #[n(1)] KeyDestruction,
+    /// Unlinked only; the filesystem refused the overwrite
+    #[n(2)] Unlinked,
+    /// Deleted by an external record store
+    #[n(3)] External,
+}
+
+/// Payload of a `DeletionProof` TXO
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct DeletionProof {
+    /// Deleted artifact
+    #[n(0)]
+    pub artifact_id: [u8; 32],
+    /// Artifact class
+    #[n(1)]
+    pub class: ArtifactClass,
+    /// Creation time (milliseconds since epoch)
+    #[n(2)]
+    pub created_ms: u64,
+    /// Deletion time (milliseconds since epoch)
+    #[n(3)]
+    pub deleted_ms: u64,
+    /// Policy maximum age in force at deletion
+    #[n(4)]
+    pub max_age_ms: u64,
+    /// Deletion method
+    #[n(5)]
+    pub method: DeletionMethod,
+}
+
+impl DeletionProof {
+    /// Build the ledger TXO for this proof
+    pub fn to_txo(&self) -> Txo {
+        let payload = minicbor::to_vec(self).unwrap_or_default();
+        Txo::new(TxoType::DeletionProof, self.deleted_ms, payload, vec![self.artifact_id])
+    }
+
+    /// Recover a proof from a `DeletionProof` TXO
+    pub fn from_txo(txo: &Txo) -> Option<Self> {
+        if txo.txo_type != TxoType::DeletionProof {
+            return None;
+        }
+        minicbor::decode(&txo.payload).ok()
+    }
+}
+
+/// Deletion failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetentionError {
+    /// Filesystem operation failed
+    Io(io::ErrorKind),
+    /// Encrypted artifact references an unregistered key
+    UnknownKey,
+    /// Record artifact but no record store supplied
+    NoRecordStore,
+}
+
+impl From<io::Error> for RetentionError {
+    fn from(err: io::Error) -> Self {
+        RetentionError::Io(err.kind())
+    }
+}
+
+/// External store holding record artifacts
+pub trait RecordStore {
+    /// Delete one row
+    fn delete(&mut self, table: &str, key: &[u8]) -> Result<(), RetentionError>;
+}
+
+/// Outcome of one sweep
+#[derive(Debug, Default)]
+pub struct SweepReport {
+    /// Proofs appended to the ledger, in purge order
+    pub purged: Vec<DeletionProof>,
+    /// Artifacts that could not be deleted (still tracked)
+    pub failed: Vec<([u8; 32], RetentionError)>,
+}
+
+/// Retention scheduler
+///
+/// ## Lifecycle Stage: Retention Expiry
+///
+/// Holds policies, tracked artifacts and encryption keys; [`sweep`] runs
+/// whenever [`is_due`] says the interval has elapsed.
+///
+/// [`sweep`]: RetentionScheduler::sweep
+/// [`is_due`]: RetentionScheduler::is_due
+pub struct RetentionScheduler {
+    policies: BTreeMap<ArtifactClass, RetentionPolicy>,
+    artifacts: Vec<Artifact>,
+    keys: BTreeMap<[u8; 32], Zeroizing<Vec<u8>>>,
+    interval_ms: u64,
+    last_sweep_ms: Option<u64>,
+}
+
+impl RetentionScheduler {
+    /// Create a scheduler sweeping at most every `interval_ms`
+    pub fn new(interval_ms: u64) -> Self {
+        Self {
+            policies: BTreeMap::new(),
+            artifacts: Vec::new(),
+            keys: BTreeMap::new(),
+            interval_ms,
+            last_sweep_ms: None,
+        }
+    }
+
+    /// Set the policy for a class; classes without one are kept forever
+    pub fn set_policy(&mut self, class: ArtifactClass, policy: RetentionPolicy) {
+        self.policies.insert(class, policy);
+    }
+
+    /// Start tracking an artifact
+    pub fn track(&mut self, artifact: Artifact) {
+        self.artifacts.push(artifact);
+    }
+
+    /// Register the key of an encrypted artifact
+    pub fn register_key(&mut self, key_id: [u8; 32], key: Vec<u8>) {
+        self.keys.insert(key_id, Zeroizing::new(key));
+    }
+
+    /// Tracked artifacts
+    pub fn artifacts(&self) -> &[Artifact] {
+        &self.artifacts
+    }
+
+    /// Check whether a sweep is due at `now_ms`
+    pub fn is_due(&self, now_ms: u64) -> bool {
+        self.last_sweep_ms
+            .is_none_or(|last| now_ms.saturating_sub(last) >= self.interval_ms)
+    }
+
+    /// Artifacts the policies expire at `now_ms`, oldest first
+    pub fn expired(&self, now_ms: u64) -> Vec<[u8; 32]> {
+        let mut expired = Vec::new();
+        for (class, policy) in &self.policies {
+            let mut of_class: Vec<&Artifact> =
+                self.artifacts.iter().filter(|a| a.class == *class).collect();
+            of_class.sort_by_key(|a| a.created_ms);
+
+            let excess = policy
+                .max_count
+                .map_or(0, |max| of_class.len().saturating_sub(max));
+            for (i, artifact) in of_class.iter().enumerate() {
+                let too_old = now_ms.saturating_sub(artifact.created_ms) > policy.max_age_ms;
+                if too_old || i < excess {
+                    expired.push(artifact.id);
+                }
+            }
+        }
+        expired
+    }
+
+    /// Purge expired artifacts and append a deletion proof per purge
+    ///
+    /// ## Inputs → Outputs
+    /// - (now, ledger, optional record store) → proofs appended and failures
+    ///
+    /// # Audit Trail
+    /// - One `DeletionProof` TXO per purged artifact
+    pub fn sweep(
+        &mut self,
+        now_ms: u64,
+        ledger: &mut MerkleLedger,
+        mut records: Option<&mut dyn RecordStore>,
+    ) -> SweepReport {
+        self.last_sweep_ms = Some(now_ms);
+        let mut report = SweepReport::default();
+
+        for id in self.expired(now_ms) {
+            let Some(index) = self.artifacts.iter().position(|a| a.id == id) else {
+                continue;
+            };
+            let artifact = self.artifacts[index].clone();
+            match self.destroy(&artifact, records.as_deref_mut()) {
+                Ok(method) => {
+                    let proof = DeletionProof {
+                        artifact_id: artifact.id,
+                        class: artifact.class,
+                        created_ms: artifact.created_ms,
+                        deleted_ms: now_ms,
+                        max_age_ms: self.policies[&artifact.class].max_age_ms,
+                        method,
+                    };
+                    ledger.append(proof.to_txo());
+                    self.artifacts.remove(index);
+                    report.purged.push(proof);
+                }
+                Err(err) => report.failed.push((artifact.id, err)),
+            }
+        }
+        report
+    }
+
+    /// Destroy one artifact
+    fn destroy(
+        &mut self,
+        artifact: &Artifact,
+        records: Option<&mut (dyn RecordStore + '_)>,
+    ) -> Result<DeletionMethod, RetentionError> {
+        match &artifact.storage {
+            Storage::File(path) => {
+                let overwritten = overwrite(path).is_ok();
+                std::fs::remove_file(path)?;
+                Ok(if overwritten { DeletionMethod::Overwrite } else { DeletionMethod::Unlinked })
+            }
+            Storage::EncryptedFile { path, key_id } => {
+                let mut key = self.keys.remove(key_id).ok_or(RetentionError::UnknownKey)?;
+                key.zeroize();
+                match std::fs::remove_file(path) {
+                    // Key is gone; a vanished ciphertext is already unrecoverable
+                    Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+                    _ => Ok(DeletionMethod::KeyDestruction),
+                }
+            }
+            Storage::Record { table, key } => {
+                let store = records.ok_or(RetentionError::NoRecordStore)?;
+                store.delete(table, key)?;
+                Ok(DeletionMethod::External)
+            }
+        }
+    }
+}
+
+/// Overwrite a file's contents with zeros and sync
+fn overwrite(path: &PathBuf) -> io::Result<()> {
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    let mut remaining = file.metadata()?.len();
+    let zeros = [0u8; OVERWRITE_CHUNK];
+
+    file.seek(SeekFrom::Start(0))?;
+    while remaining > 0 {
+        let n = remaining.min(OVERWRITE_CHUNK as u64) as usize;
+        file.write_all(&zeros[..n])?;
+        remaining -= n as u64;
+    }
+    file.sync_all()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY_MS: u64 = 86_400_000;
+
+    fn scratch(name: &str, contents: &[u8]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("qratum-retention-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    struct Rows(Vec<(String, Vec<u8>)>);
+
+    impl RecordStore for Rows {
+        fn delete(&mut self, table: &str, key: &[u8]) -> Result<(), RetentionError> {
+            self.0.retain(|(t, k)| t != table || k != key);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_sweep_purges_expired_with_proofs() {
+        let archive = scratch("outcome.cbor", b"outcome txo bytes");
+        let bundle = scratch("bundle.enc", b"ciphertext");
+
+        let mut scheduler = RetentionScheduler::new(DAY_MS);
+        let policy = RetentionPolicy { max_age_ms: 30 * DAY_MS, max_count: None };
+        scheduler.set_policy(ArtifactClass::OutcomeArchive, policy);
+        scheduler.set_policy(ArtifactClass::EvidenceBundle, policy);
+        scheduler.set_policy(ArtifactClass::DesktopRow, policy);
+
+        scheduler.track(Artifact {
+            id: [1u8; 32],
+            class: ArtifactClass::OutcomeArchive,
+            created_ms: 0,
+            storage: Storage::File(archive.clone()),
+        });
+        scheduler.register_key([9u8; 32], vec![0xAA; 32]);
+        scheduler.track(Artifact {
+            id: [2u8; 32],
+            class: ArtifactClass::EvidenceBundle,
+            created_ms: 0,
+            storage: Storage::EncryptedFile { path: bundle.clone(), key_id: [9u8; 32] },
+        });
+        scheduler.track(Artifact {
+            id: [3u8; 32],
+            class: ArtifactClass::DesktopRow,
+            created_ms: 0,
+            storage: Storage::Record { table: "sessions".into(), key: vec![3] },
+        });
+        scheduler.track(Artifact {
+            id: [4u8; 32],
+            class: ArtifactClass::OutcomeArchive,
+            created_ms: 20 * DAY_MS,
+            storage: Storage::File(scratch("recent.cbor", b"recent")),
+        });
+
+        let mut ledger = MerkleLedger::new();
+        let mut rows = Rows(vec![("sessions".into(), vec![3])]);
+        let report = scheduler.sweep(31 * DAY_MS, &mut ledger, Some(&mut rows));
+
+        assert!(report.failed.is_empty());
+        let methods: Vec<DeletionMethod> = report.purged.iter().map(|p| p.method).collect();
+        assert_eq!(
+            methods,
+            vec![DeletionMethod::Overwrite, DeletionMethod::KeyDestruction, DeletionMethod::External]
+        );
+        assert!(!archive.exists() && !bundle.exists());
+        assert!(rows.0.is_empty());
+        assert!(scheduler.keys.is_empty());
+        assert_eq!(scheduler.artifacts().len(), 1);
+
+        // One proof TXO per purge, linked to the artifact
+        assert_eq!(ledger.txo_count(), 3);
+        let txo = report.purged[0].to_txo();
+        assert_eq!(txo.predecessors, vec![[1u8; 32]]);
+        assert_eq!(DeletionProof::from_txo(&txo), Some(report.purged[0]));
+
+        assert!(!scheduler.is_due(31 * DAY_MS + 1));
+        assert!(scheduler.is_due(32 * DAY_MS));
+    }
+
+    #[test]
+    fn test_count_cap_and_failed_deletions() {
+        let mut scheduler = RetentionScheduler::new(0);
+        scheduler.set_policy(
+            ArtifactClass::DesktopRow,
+            RetentionPolicy { max_age_ms: u64::MAX, max_count: Some(1) },
+        );
+        for (id, created_ms) in [(1u8, 10), (2, 30), (3, 20)] {
+            scheduler.track(Artifact {
+                id: [id; 32],
+                class: ArtifactClass::DesktopRow,
+                created_ms,
+                storage: Storage::Record { table: "t".into(), key: vec![id] },
+            });
+        }
+        // Oldest beyond the cap expire
+        assert_eq!(scheduler.expired(40), vec![[1u8; 32], [3u8; 32]]);
+
+        // Without a record store nothing is deleted and nothing is proven
+        let mut ledger = MerkleLedger::new();
+        let report = scheduler.sweep(40, &mut ledger, None);
+        assert!(report.purged.is_empty());
+        assert_eq!(report.failed.len(), 2);
+        assert_eq!(report.failed[0].1, RetentionError::NoRecordStore);
+        assert_eq!(ledger.txo_count(), 0);
+        assert_eq!(scheduler.artifacts().len(), 3);
+    }
+}
@@ -0,0 +1,305 @@
+//! # Rotation Module - Biokey Epoch Scheduling
+//!
+//! ## Lifecycle Stage: Execution (continuous)
+//!
+//! Biokeys stop yielding key material `MAX_BIOKEY_LIFETIME_MS` after they are
+//! derived or rotated. The scheduler rotates them a configurable lead time
+//! before that happens, so operations never find the key expired, and tells
+//! every registered consumer about the new epoch.
+//!
+//! ## Architectural Role
+//!
+//! - **Schedule**: Next rotation at `issued + lifetime - lead`
+//! - **Hooks**: Registered callbacks receive each `RotationEvent` (e.g. to
+//!   re-bind proxy approvals or an execution context to the new epoch)
+//! - **Audit**: Every rotation appends a `KeyRotation` TXO to the session ledger
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Periodic `tick` with the current time, timestamped via [`crate::clock`]
+//! - Output: Rotated biokey, `RotationEvent` to consumers, audit TXO in the ledger
+//!
+//! ## Security Rationale
+//!
+//! - Invalidated keys (e.g. usage cap breach) rotate on the next tick rather
+//!   than at the scheduled time
+//! - Rotations that happen after expiry are recorded as such, exposing a
+//!   stalled scheduler in the audit trail
+//! - Audit records carry a SHA3-256 fingerprint of the new key, never key
+//!   material
+
+extern crate alloc;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use sha3::{Digest, Sha3_256};
+
+use crate::biokey::{EphemeralBiokey, LifetimeState, MAX_BIOKEY_LIFETIME_MS};
+use crate::ledger::MerkleLedger;
+use crate::txo::{Txo, TxoType};
+
+/// Default lead time: rotate 5 seconds before expiry
+pub const DEFAULT_ROTATION_LEAD_MS: u64 = 5_000;
+
+/// Domain separator for key fingerprints
+const FINGERPRINT_DOMAIN: &[u8] = b"QRATUM-BIOKEY-FINGERPRINT-v1";
+
+/// Consumer callback invoked after every rotation
+pub type RotationCallback = Box<dyn FnMut(&RotationEvent)>;
+
+/// Why a rotation happened
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RotationReason {
+    /// Lead time before expiry reached
+    Scheduled = 0,
+    /// Key had already expired when the scheduler ran
+    Expired = 1,
+    /// Key had been invalidated
+    Invalidated = 2,
+    /// Caller requested rotation
+    Forced = 3,
+}
+
+impl RotationReason {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(RotationReason::Scheduled),
+            1 => Some(RotationReason::Expired),
+            2 => Some(RotationReason::Invalidated),
+            3 => Some(RotationReason::Forced),
+            _ => None,
+        }
+    }
+}
+
+/// Rotation Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotationError {
+    /// Lead time is zero or not shorter than the key lifetime
+    InvalidLead,
+}
+
+/// One completed rotation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotationEvent {
+    /// Epoch rotated out
+    pub previous_epoch: u64,
+    /// Epoch rotated in
+    pub epoch: u64,
+    /// Rotation time (milliseconds)
+    pub rotated_at_ms: u64,
+    /// Why the key rotated
+    pub reason: RotationReason,
+    /// SHA3-256 fingerprint of the new key material
+    pub key_fingerprint: [u8; 32],
+}
+
+impl RotationEvent {
+    /// Audit TXO recording this rotation
+    ///
+    /// # Outputs
+    /// - `KeyRotation` TXO; payload is previous epoch, epoch (u64 LE each),
+    ///   reason, then the new key fingerprint
+    pub fn to_txo(&self) -> Txo {
+        let mut payload = Vec::with_capacity(49);
+        payload.extend_from_slice(&self.previous_epoch.to_le_bytes());
+        payload.extend_from_slice(&self.epoch.to_le_bytes());
+        payload.push(self.reason as u8);
+        payload.extend_from_slice(&self.key_fingerprint);
+        Txo::new(TxoType::KeyRotation, self.rotated_at_ms, payload, Vec::new())
+    }
+
+    /// Decode a rotation audit TXO
+    ///
+    /// # Outputs
+    /// - `None` for other TXO types or malformed payloads
+    pub fn from_txo(txo: &Txo) -> Option<Self> {
+        let payload = &txo.payload;
+        if txo.txo_type != TxoType::KeyRotation || payload.len() != 49 {
+            return None;
+        }
+        let mut key_fingerprint = [0u8; 32];
+        key_fingerprint.copy_from_slice(&payload[17..49]);
+        Some(Self {
+            previous_epoch: u64::from_le_bytes(payload[0..8].try_into().ok()?),
+            epoch: u64::from_le_bytes(payload[8..16].try_into().ok()?),
+            rotated_at_ms: txo.timestamp,
+            reason: RotationReason::from_u8(payload[16])?,
+            key_fingerprint,
+        })
+    }
+}
+
+/// Fingerprint identifying a biokey epoch without revealing key material
+pub fn key_fingerprint(biokey: &EphemeralBiokey) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(FINGERPRINT_DOMAIN);
+    hasher.update(biokey.key_material_unchecked());
+    hasher.update(biokey.epoch().to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Automatic biokey rotation ahead of expiry
+pub struct BiokeyScheduler {
+    lead_ms: u64,
+    issued_at_ms: u64,
+    rotations: u64,
+    callbacks: Vec<RotationCallback>,
+}
+
+impl BiokeyScheduler {
+    /// Schedule a biokey issued at `issued_at_ms`
+    ///
+    /// # Inputs
+    /// - `lead_ms`: How long before expiry to rotate (0 < lead < lifetime)
+    /// - `issued_at_ms`: When the current key was derived or last rotated
+    pub fn new(lead_ms: u64, issued_at_ms: u64) -> Result<Self, RotationError> {
+        if lead_ms == 0 || lead_ms >= MAX_BIOKEY_LIFETIME_MS {
+            return Err(RotationError::InvalidLead);
+        }
+        Ok(Self { lead_ms, issued_at_ms, rotations: 0, callbacks: Vec::new() })
+    }
+
+    /// Register a consumer notified after every rotation
+    pub fn register(&mut self, callback: RotationCallback) {
+        self.callbacks.push(callback);
+    }
+
+    /// Time of the next scheduled rotation
+    pub fn next_rotation_ms(&self) -> u64 {
+        self.issued_at_ms + MAX_BIOKEY_LIFETIME_MS - self.lead_ms
+    }
+
+    /// Rotations performed so far
+    pub fn rotations(&self) -> u64 {
+        self.rotations
+    }
+
+    /// Rotate the biokey if due
+    ///
+    /// # Inputs
+    /// - `biokey`: Key under schedule
+    /// - `now_ms`: Current time
+    /// - `ledger`: Session ledger receiving the audit TXO
+    ///
+    /// # Outputs
+    /// - `Some(RotationEvent)` if the key was rotated
+    pub fn tick(
+        &mut self,
+        biokey: &mut EphemeralBiokey,
+        now_ms: u64,
+        ledger: &mut MerkleLedger,
+    ) -> Option<RotationEvent> {
+        let reason = if biokey.lifetime_state() == LifetimeState::Invalidated {
+            RotationReason::Invalidated
+        } else if now_ms >= self.issued_at_ms + MAX_BIOKEY_LIFETIME_MS {
+            RotationReason::Expired
+        } else if now_ms >= self.next_rotation_ms() {
+            RotationReason::Scheduled
+        } else {
+            return None;
+        };
+        Some(self.rotate(biokey, now_ms, reason, ledger))
+    }
+
+    /// Rotate the biokey now, regardless of schedule
+    pub fn force_rotate(
+        &mut self,
+        biokey: &mut EphemeralBiokey,
+        now_ms: u64,
+        ledger: &mut MerkleLedger,
+    ) -> RotationEvent {
+        self.rotate(biokey, now_ms, RotationReason::Forced, ledger)
+    }
+
+    fn rotate(
+        &mut self,
+        biokey: &mut EphemeralBiokey,
+        now_ms: u64,
+        reason: RotationReason,
+        ledger: &mut MerkleLedger,
+    ) -> RotationEvent {
+        let previous_epoch = biokey.epoch();
+        biokey.rotate();
+        self.issued_at_ms = now_ms;
+        self.rotations += 1;
+
+        let event = RotationEvent {
+            previous_epoch,
+            epoch: biokey.epoch(),
+            rotated_at_ms: now_ms,
+            reason,
+            key_fingerprint: key_fingerprint(biokey),
+        };
+        ledger.append(event.to_txo());
+        for callback in self.callbacks.iter_mut() {
+            callback(&event);
+        }
+        event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+
+    #[test]
+    fn test_scheduled_rotation_notifies_and_audits() {
+        let mut biokey = EphemeralBiokey::derive(&[b"entropy_a".as_slice(), b"entropy_b".as_slice()], 0);
+        let mut ledger = MerkleLedger::new();
+        let mut scheduler = BiokeyScheduler::new(DEFAULT_ROTATION_LEAD_MS, 1_000).unwrap();
+        assert_eq!(scheduler.next_rotation_ms(), 26_000);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = seen.clone();
+        scheduler.register(Box::new(move |event| sink.borrow_mut().push(event.epoch)));
+
+        // Not yet due
+        assert!(scheduler.tick(&mut biokey, 25_999, &mut ledger).is_none());
+        assert_eq!(ledger.txo_count(), 0);
+
+        let event = scheduler.tick(&mut biokey, 26_000, &mut ledger).unwrap();
+        assert_eq!((event.previous_epoch, event.epoch), (0, 1));
+        assert_eq!(event.reason, RotationReason::Scheduled);
+        assert_eq!(event.key_fingerprint, key_fingerprint(&biokey));
+        assert_eq!(biokey.epoch(), 1);
+        assert_eq!(scheduler.next_rotation_ms(), 51_000);
+
+        // A stalled scheduler records that the key lapsed
+        let late = scheduler.tick(&mut biokey, 60_000, &mut ledger).unwrap();
+        assert_eq!(late.reason, RotationReason::Expired);
+
+        // Invalidation rotates before the schedule
+        biokey.invalidate();
+        let event = scheduler.tick(&mut biokey, 60_001, &mut ledger).unwrap();
+        assert_eq!(event.reason, RotationReason::Invalidated);
+        assert!(biokey.key_material().is_some());
+
+        assert_eq!(*seen.borrow(), [1, 2, 3]);
+        assert_eq!(scheduler.rotations(), 3);
+        assert_eq!(ledger.txo_count(), 3);
+        assert!(ledger.verify_integrity());
+    }
+
+    #[test]
+    fn test_rotation_txo_round_trip() {
+        let mut biokey = EphemeralBiokey::derive(&[b"entropy_a".as_slice(), b"entropy_b".as_slice()], 7);
+        let mut ledger = MerkleLedger::new();
+        let mut scheduler = BiokeyScheduler::new(1_000, 0).unwrap();
+
+        let event = scheduler.force_rotate(&mut biokey, 500, &mut ledger);
+        let txo = event.to_txo();
+        assert_eq!(txo.txo_type, TxoType::KeyRotation);
+        assert_eq!(RotationEvent::from_txo(&txo), Some(event));
+        assert!(RotationEvent::from_txo(&biokey.usage_audit_txo(0)).is_none());
+
+        assert!(matches!(BiokeyScheduler::new(0, 0), Err(RotationError::InvalidLead)));
+        assert!(matches!(
+            BiokeyScheduler::new(MAX_BIOKEY_LIFETIME_MS, 0),
+            Err(RotationError::InvalidLead)
+        ));
+    }
+}
@@ -0,0 +1,433 @@
+//! # Secrets Module - Session-Scoped Secret Broker
+//!
+//! ## Lifecycle Stage: Ephemeral Materialization → Self-Destruction
+//!
+//! Quorum, biokey, snapshot, and blinded code each held raw secrets and
+//! were each responsible for wiping them. The broker holds session secrets
+//! behind opaque [`SecretHandle`]s instead. Subsystems receive
+//! [`AccessToken`]s scoped to one secret, one permission, and optionally a
+//! number of uses, and only ever see secret bytes inside a closure.
+//!
+//! ## Architectural Role
+//!
+//! - **Custody**: `deposit` moves a secret into the broker; callers keep a handle
+//! - **Capabilities**: `grant` issues a token for one (secret, subsystem, permission)
+//! - **Access**: `read` / `write` check the token against the presenting subsystem
+//! - **Audit**: every grant, read, and write attempt is logged, denials included
+//! - **Destruction**: `destroy_all` zeroizes every secret; stage 5 calls it
+//!   before recording `Zeroization`
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Secret bytes, grant requests, tokens presented by subsystems
+//! - Output: Closure results, `AccessRecord` log, `DestructionReport`
+//!
+//! ## Security Rationale
+//!
+//! - Secrets are zeroized centrally at stage 5 even if the module that
+//!   deposited them never wipes its own copy
+//! - A token leaked to another subsystem is refused (`WrongSubsystem`)
+//! - Tokens cannot be constructed or cloned outside the broker
+//! - The access log records metadata only, never secret bytes
+//! - After destruction every operation fails with `Destroyed`
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::clock;
+use zeroize::Zeroize;
+
+/// Opaque reference to a secret held by a `SecretBroker`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SecretHandle(u32);
+
+/// What a secret is used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretKind {
+    /// Biokey key material
+    BiokeyMaterial,
+    /// Snapshot encryption key
+    SnapshotKey,
+    /// Blinded payload key
+    BlindingKey,
+    /// Quorum member share
+    QuorumShare,
+}
+
+/// Subsystem requesting access
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subsystem {
+    /// Quorum convergence
+    Quorum,
+    /// Biokey derivation and rotation
+    Biokey,
+    /// Volatile snapshots
+    Snapshot,
+    /// Blinded payloads
+    Blinded,
+    /// Lifecycle orchestrator
+    Lifecycle,
+}
+
+/// Access granted by a token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// Borrow the secret bytes
+    Read,
+    /// Replace the secret bytes
+    Write,
+}
+
+/// Capability to access one secret
+///
+/// Issued by `SecretBroker::grant`; neither `Clone` nor constructible
+/// outside the broker.
+#[derive(Debug, PartialEq, Eq)]
+pub struct AccessToken {
+    id: u64,
+    handle: SecretHandle,
+    subsystem: Subsystem,
+    permission: Permission,
+}
+
+impl AccessToken {
+    /// Secret this token refers to
+    pub fn handle(&self) -> SecretHandle {
+        self.handle
+    }
+
+    /// Subsystem the token was granted to
+    pub fn subsystem(&self) -> Subsystem {
+        self.subsystem
+    }
+
+    /// Permission the token carries
+    pub fn permission(&self) -> Permission {
+        self.permission
+    }
+}
+
+/// Secret broker error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrokerError {
+    /// No secret with this handle
+    UnknownHandle,
+    /// Token unknown to the broker, or revoked
+    UnknownToken,
+    /// Token presented by a subsystem it was not granted to
+    WrongSubsystem,
+    /// Token does not carry the requested permission
+    PermissionDenied,
+    /// Token has no uses left
+    Exhausted,
+    /// Secrets were destroyed at stage 5
+    Destroyed,
+}
+
+/// One logged access attempt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessRecord {
+    /// Position in the log (0 = first)
+    pub sequence: u64,
+    /// Attempt time (milliseconds)
+    pub timestamp: u64,
+    /// Secret concerned
+    pub handle: SecretHandle,
+    /// Subsystem that made the attempt
+    pub subsystem: Subsystem,
+    /// `None` for grants, otherwise the access requested
+    pub permission: Option<Permission>,
+    /// Outcome
+    pub result: Result<(), BrokerError>,
+}
+
+/// Outcome of `SecretBroker::destroy_all`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DestructionReport {
+    /// Secrets zeroized
+    pub secrets: usize,
+    /// Secret bytes zeroized
+    pub bytes: usize,
+    /// Outstanding grants revoked
+    pub grants_revoked: usize,
+}
+
+struct Entry {
+    handle: SecretHandle,
+    kind: SecretKind,
+    bytes: Vec<u8>,
+}
+
+struct Grant {
+    id: u64,
+    handle: SecretHandle,
+    subsystem: Subsystem,
+    permission: Permission,
+    remaining: Option<u32>,
+}
+
+/// Session-scoped secret broker
+///
+/// ## Lifecycle Stage: Ephemeral Materialization → Self-Destruction
+///
+/// Zeroizes every secret on `destroy_all` and again on drop.
+pub struct SecretBroker {
+    entries: Vec<Entry>,
+    grants: Vec<Grant>,
+    log: Vec<AccessRecord>,
+    next_handle: u32,
+    next_token: u64,
+    destroyed: bool,
+}
+
+impl SecretBroker {
+    /// Create an empty broker
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            grants: Vec::new(),
+            log: Vec::new(),
+            next_handle: 0,
+            next_token: 0,
+            destroyed: false,
+        }
+    }
+
+    /// Take custody of a secret
+    ///
+    /// # Inputs
+    /// - `kind`: What the secret is used for
+    /// - `secret`: Secret bytes; the caller should zeroize its own copy
+    ///
+    /// # Outputs
+    /// - `SecretHandle` for later grants, or `Err(Destroyed)` after stage 5
+    pub fn deposit(&mut self, kind: SecretKind, secret: &[u8]) -> Result<SecretHandle, BrokerError> {
+        if self.destroyed {
+            return Err(BrokerError::Destroyed);
+        }
+        let handle = SecretHandle(self.next_handle);
+        self.next_handle += 1;
+        self.entries.push(Entry { handle, kind, bytes: secret.to_vec() });
+        Ok(handle)
+    }
+
+    /// Kind of a held secret
+    pub fn kind(&self, handle: SecretHandle) -> Option<SecretKind> {
+        self.entry(handle).map(|e| e.kind)
+    }
+
+    /// Grant `subsystem` one permission on a secret
+    ///
+    /// # Inputs
+    /// - `max_uses`: `None` for unlimited, otherwise the number of accesses
+    ///
+    /// # Outputs
+    /// - `AccessToken`, or `Err(UnknownHandle | Destroyed)`
+    pub fn grant(
+        &mut self,
+        handle: SecretHandle,
+        subsystem: Subsystem,
+        permission: Permission,
+        max_uses: Option<u32>,
+    ) -> Result<AccessToken, BrokerError> {
+        let result = if self.destroyed {
+            Err(BrokerError::Destroyed)
+        } else if self.entry(handle).is_none() {
+            Err(BrokerError::UnknownHandle)
+        } else {
+            Ok(())
+        };
+        self.log_access(handle, subsystem, None, result);
+        result?;
+
+        let id = self.next_token;
+        self.next_token += 1;
+        self.grants.push(Grant { id, handle, subsystem, permission, remaining: max_uses });
+        Ok(AccessToken { id, handle, subsystem, permission })
+    }
+
+    /// Withdraw a token
+    pub fn revoke(&mut self, token: AccessToken) {
+        self.grants.retain(|g| g.id != token.id);
+    }
+
+    /// Borrow a secret
+    ///
+    /// # Inputs
+    /// - `token`: `Read` token for the secret
+    /// - `subsystem`: Subsystem presenting the token
+    /// - `f`: Receives the secret bytes; they must not be copied out
+    ///
+    /// # Outputs
+    /// - Result of `f`, or the reason access was refused
+    pub fn read<R>(
+        &mut self,
+        token: &AccessToken,
+        subsystem: Subsystem,
+        f: impl FnOnce(&[u8]) -> R,
+    ) -> Result<R, BrokerError> {
+        let index = self.authorize(token, subsystem, Permission::Read)?;
+        Ok(f(&self.entries[index].bytes))
+    }
+
+    /// Replace a secret, zeroizing the previous bytes
+    pub fn write(&mut self, token: &AccessToken, subsystem: Subsystem, secret: &[u8]) -> Result<(), BrokerError> {
+        let index = self.authorize(token, subsystem, Permission::Write)?;
+        let entry = &mut self.entries[index];
+        entry.bytes.zeroize();
+        entry.bytes = secret.to_vec();
+        Ok(())
+    }
+
+    /// Every logged access attempt, oldest first
+    pub fn access_log(&self) -> &[AccessRecord] {
+        &self.log
+    }
+
+    /// Number of secrets held
+    pub fn secret_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether `destroy_all` has run
+    pub fn is_destroyed(&self) -> bool {
+        self.destroyed
+    }
+
+    /// Zeroize every secret and revoke every grant
+    ///
+    /// ## Lifecycle Stage: Self-Destruction
+    ///
+    /// Idempotent; the access log is kept for audit.
+    pub fn destroy_all(&mut self) -> DestructionReport {
+        let mut report = DestructionReport {
+            secrets: self.entries.len(),
+            bytes: 0,
+            grants_revoked: self.grants.len(),
+        };
+        for entry in &mut self.entries {
+            report.bytes += entry.bytes.len();
+            entry.bytes.zeroize();
+        }
+        self.entries.clear();
+        self.grants.clear();
+        self.destroyed = true;
+        report
+    }
+
+    fn entry(&self, handle: SecretHandle) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.handle == handle)
+    }
+
+    /// Check a token, consume one use, and log the attempt
+    fn authorize(&mut self, token: &AccessToken, subsystem: Subsystem, permission: Permission) -> Result<usize, BrokerError> {
+        let result = self.check(token, subsystem, permission);
+        self.log_access(token.handle, subsystem, Some(permission), result.map(|_| ()));
+        result
+    }
+
+    fn check(&mut self, token: &AccessToken, subsystem: Subsystem, permission: Permission) -> Result<usize, BrokerError> {
+        if self.destroyed {
+            return Err(BrokerError::Destroyed);
+        }
+        let grant = self.grants.iter_mut()
+            .find(|g| g.id == token.id && g.handle == token.handle)
+            .ok_or(BrokerError::UnknownToken)?;
+        if grant.subsystem != subsystem {
+            return Err(BrokerError::WrongSubsystem);
+        }
+        if grant.permission != permission {
+            return Err(BrokerError::PermissionDenied);
+        }
+        let index = self.entries.iter()
+            .position(|e| e.handle == grant.handle)
+            .ok_or(BrokerError::UnknownHandle)?;
+        match &mut grant.remaining {
+            Some(0) => return Err(BrokerError::Exhausted),
+            Some(n) => *n -= 1,
+            None => {}
+        }
+        Ok(index)
+    }
+
+    fn log_access(
+        &mut self,
+        handle: SecretHandle,
+        subsystem: Subsystem,
+        permission: Option<Permission>,
+        result: Result<(), BrokerError>,
+    ) {
+        self.log.push(AccessRecord {
+            sequence: self.log.len() as u64,
+            timestamp: clock::now_ms(),
+            handle,
+            subsystem,
+            permission,
+            result,
+        });
+    }
+}
+
+impl Default for SecretBroker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SecretBroker {
+    fn drop(&mut self) {
+        self.destroy_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scoped_tokens_and_access_log() {
+        let mut broker = SecretBroker::new();
+        let handle = broker.deposit(SecretKind::SnapshotKey, &[7u8; 64]).unwrap();
+        assert_eq!(broker.kind(handle), Some(SecretKind::SnapshotKey));
+
+        let read = broker.grant(handle, Subsystem::Snapshot, Permission::Read, Some(2)).unwrap();
+        let write = broker.grant(handle, Subsystem::Biokey, Permission::Write, None).unwrap();
+
+        assert_eq!(broker.read(&read, Subsystem::Snapshot, |key| key[0]), Ok(7));
+        // Leaked to another subsystem, or used for the wrong permission
+        assert_eq!(broker.read(&read, Subsystem::Blinded, |_| ()), Err(BrokerError::WrongSubsystem));
+        assert_eq!(broker.write(&read, Subsystem::Snapshot, &[0u8; 64]), Err(BrokerError::PermissionDenied));
+
+        broker.write(&write, Subsystem::Biokey, &[9u8; 64]).unwrap();
+        assert_eq!(broker.read(&read, Subsystem::Snapshot, |key| key.to_vec()), Ok(vec![9u8; 64]));
+        // Two uses spent
+        assert_eq!(broker.read(&read, Subsystem::Snapshot, |_| ()), Err(BrokerError::Exhausted));
+
+        broker.revoke(write);
+        let log = broker.access_log();
+        assert_eq!(log.len(), 8);
+        assert_eq!(log[0].permission, None);
+        assert_eq!(log[3].result, Err(BrokerError::WrongSubsystem));
+        assert!(log.iter().enumerate().all(|(i, r)| r.sequence == i as u64 && r.handle == handle));
+        assert_eq!(log.iter().filter(|r| r.result.is_ok()).count(), 5);
+    }
+
+    #[test]
+    fn test_destroy_all_zeroizes_and_locks_out() {
+        let mut broker = SecretBroker::new();
+        let biokey = broker.deposit(SecretKind::BiokeyMaterial, &[0xaa; 64]).unwrap();
+        broker.deposit(SecretKind::BlindingKey, &[0xbb; 32]).unwrap();
+        let token = broker.grant(biokey, Subsystem::Biokey, Permission::Read, None).unwrap();
+
+        let report = broker.destroy_all();
+        assert_eq!(report, DestructionReport { secrets: 2, bytes: 96, grants_revoked: 1 });
+        assert!(broker.is_destroyed());
+        assert_eq!(broker.secret_count(), 0);
+
+        assert_eq!(broker.read(&token, Subsystem::Biokey, |_| ()), Err(BrokerError::Destroyed));
+        assert_eq!(broker.deposit(SecretKind::QuorumShare, &[1]), Err(BrokerError::Destroyed));
+        assert_eq!(broker.grant(biokey, Subsystem::Biokey, Permission::Read, None).err(), Some(BrokerError::Destroyed));
+        assert_eq!(broker.destroy_all(), DestructionReport::default());
+        assert_eq!(broker.access_log().last().unwrap().result, Err(BrokerError::Destroyed));
+    }
+}
@@ -0,0 +1,40 @@
+//! # Secure Allocation Module - Locked-in-RAM Storage for Key Material
+//!
+//! ## Lifecycle Stage: Ephemeral Materialization → Self-Destruction
+//!
+//! Zeroize-on-drop clears key material when it goes out of scope, but not
+//! what happens while it is live: pages swapped to disk, core dumps, or an
+//! overflowing neighbour reading past its buffer. On std targets
+//! [`KeyMaterial`] is a `SecureArray` from `crypto/secure_alloc`: a
+//! dedicated page mapping between two no-access guard pages, locked in RAM,
+//! with canaries on both sides of the data.
+//!
+//! ## Architectural Role
+//!
+//! - Holds `EphemeralBiokey` key material, which also encrypts snapshots
+//! - Holds snapshot session keys recovered by `unwrap_session_key`
+//! - The HMAC-DRBG in `crypto/rng` keeps its K and V in the same allocator
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: `[u8; N]` → `KeyMaterial<N>` (dereferences to `[u8; N]`)
+//! - Output: `report()` → process-wide protection counters (std)
+//!
+//! ## Security Rationale
+//!
+//! - Locking degrades gracefully: if the OS refuses (RLIMIT_MEMLOCK, no
+//!   privilege) or cannot map guard pages, the key is still usable, and the
+//!   shortfall is reported per allocation and through `report()`
+//! - In no_std (TEE/enclave) builds `KeyMaterial<N>` is a plain array;
+//!   enclave memory is already outside the untrusted host's reach
+
+#[cfg(feature = "std")]
+pub use qratum_crypto_secure_alloc::{report, Protection, SecureAllocError, SecureAllocReport, SecureArray};
+
+/// Fixed-size key material: guarded and locked in RAM on std targets
+#[cfg(feature = "std")]
+pub type KeyMaterial<const N: usize> = SecureArray<N>;
+
+/// Fixed-size key material: a plain array in no_std builds
+#[cfg(not(feature = "std"))]
+pub type KeyMaterial<const N: usize> = [u8; N];
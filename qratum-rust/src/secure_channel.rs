@@ -0,0 +1,521 @@
+//! # Secure Channel Module - Hybrid X25519 + Kyber Transport Encryption
+//!
+//! ## Lifecycle Stage: Network Infrastructure (channel establishment)
+//!
+//! [`Channel`] carries bytes but provides no cryptographic protection.
+//! `Channel::upgrade_secure` runs a one-round-trip hybrid key agreement:
+//! ephemeral X25519 on both sides plus a Kyber encapsulation to the
+//! responder's static KEM key. Both shared secrets feed the HKDF-SHA3-512
+//! key schedule in `crypto/kdf`, so the session stays confidential unless
+//! both X25519 and Kyber are broken.
+//!
+//! ## Architectural Role
+//!
+//! - **Initiator**: `Channel::upgrade_secure` → `ClientHello`, then
+//!   `SecureHandshake::finish` with the responder's `ServerHello`
+//! - **Responder**: `accept_secure` decapsulates, answers with a key
+//!   confirmation, and returns its `SecureChannel`
+//! - **Frames**: Per-direction keys, SHA3-256 keystream, SHA3-256 tag over
+//!   the sequence number and ciphertext
+//! - **Rekeying**: Every `rekey_interval` frames the direction key is
+//!   ratcheted forward through the KDF and the old key is zeroized
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: Responder KEM public key + entropy → `ClientHello` (CBOR)
+//! - Input: `ClientHello` + KEM secret key + entropy → `ServerHello` (CBOR)
+//! - Input: Plaintext → `SecureFrame`, and back
+//!
+//! ## Security Rationale
+//!
+//! - Only the holder of the responder's Kyber secret key can produce the
+//!   `ServerHello` confirmation, so the initiator authenticates the responder
+//! - The KDF salt is a hash of the full handshake transcript, binding keys
+//!   to the channel type and every public value exchanged
+//! - Low-order X25519 points (all-zero shared secret) are rejected
+//! - Frames must arrive in order; earlier sequence numbers are replays
+//! - Ratcheting gives forward secrecy across rekey epochs within a session
+//!
+//! ## Forward Compatibility
+//!
+//! TODO: QRADLE post-quantum migration - give responders ML-KEM-1024 keys
+//! from `crypto/pqc`; the KEM traits are shared with [`crate::keywrap`], so
+//! the handshake itself does not change.
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use minicbor::{Decode, Encode};
+use qratum_crypto_kdf::{derive_fixed, Hkdf};
+use sha3::{Digest, Sha3_256, Sha3_512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::keywrap::{KeyDecapsulator, KeyEncapsulator};
+use crate::transport::Channel;
+
+/// Default frames per direction between rekeys
+pub const DEFAULT_REKEY_INTERVAL: u64 = 1 << 16;
+
+/// Rekey epochs a received frame may skip ahead
+pub const MAX_EPOCH_SKIP: u64 = 4;
+
+const TRANSCRIPT_DOMAIN: &[u8] = b"QRATUM-SECURE-CHANNEL-v1";
+const ENTROPY_DOMAIN: &[u8] = b"QRATUM-SECURE-CHANNEL-ENTROPY-v1";
+const REKEY_INFO: &[u8] = b"QRATUM-SECURE-CHANNEL-REKEY-v1";
+const KEYSTREAM_DOMAIN: &[u8] = b"QRATUM-SECURE-CHANNEL-KEYSTREAM-v1";
+const FRAME_DOMAIN: &[u8] = b"QRATUM-SECURE-CHANNEL-FRAME-v1";
+
+/// Secure Channel Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecureChannelError {
+    /// X25519 public key is a low-order point
+    InvalidKey,
+    /// KEM ciphertext could not be decapsulated
+    KemFailed,
+    /// Responder key confirmation does not match
+    ConfirmationFailed,
+    /// Key schedule derivation failed
+    KeySchedule,
+    /// Frame sequence number already used
+    Replay,
+    /// Frame skips more than `MAX_EPOCH_SKIP` rekey epochs
+    OutOfOrder,
+    /// Frame tag does not verify
+    AuthenticationFailed,
+    /// Rekey interval is zero
+    InvalidInterval,
+}
+
+/// Initiator's handshake message
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct ClientHello {
+    /// Ephemeral X25519 public key
+    #[n(0)]
+    pub x25519_public: [u8; 32],
+
+    /// Kyber encapsulation to the responder's static key
+    #[n(1)]
+    pub kem_ciphertext: Vec<u8>,
+}
+
+/// Responder's handshake message
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct ServerHello {
+    /// Ephemeral X25519 public key
+    #[n(0)]
+    pub x25519_public: [u8; 32],
+
+    /// Key confirmation under the derived session secret
+    #[n(1)]
+    pub confirmation: [u8; 32],
+}
+
+/// Encrypted, authenticated frame
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SecureFrame {
+    /// Per-direction sequence number
+    #[n(0)]
+    pub sequence: u64,
+
+    /// Encrypted payload
+    #[n(1)]
+    pub ciphertext: Vec<u8>,
+
+    /// SHA3-256 tag over sequence and ciphertext
+    #[n(2)]
+    pub tag: [u8; 32],
+}
+
+/// Initiator state between `ClientHello` and `ServerHello`
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct SecureHandshake {
+    #[zeroize(skip)]
+    channel: Channel,
+    x25519_secret: [u8; 32],
+    kem_secret: [u8; 32],
+    #[zeroize(skip)]
+    hello: ClientHello,
+    rekey_interval: u64,
+}
+
+impl Channel {
+    /// Start a hybrid X25519 + Kyber handshake on this channel
+    ///
+    /// # Inputs
+    /// - `responder_kem`: Responder's static Kyber public key
+    /// - `entropy`: Fresh randomness for the X25519 key and encapsulation
+    /// - `rekey_interval`: Frames per direction between rekeys
+    ///
+    /// # Outputs
+    /// - Pending handshake and the `ClientHello` to send
+    pub fn upgrade_secure(
+        self,
+        responder_kem: &dyn KeyEncapsulator,
+        entropy: &[u8; 32],
+        rekey_interval: u64,
+    ) -> Result<(SecureHandshake, ClientHello), SecureChannelError> {
+        if rekey_interval == 0 {
+            return Err(SecureChannelError::InvalidInterval);
+        }
+        let (mut x25519_secret, mut coins) = split_entropy(entropy);
+        let (kem_secret, kem_ciphertext) = responder_kem.encapsulate(&coins);
+        coins.zeroize();
+        let hello = ClientHello {
+            x25519_public: MontgomeryPoint::mul_base_clamped(x25519_secret).to_bytes(),
+            kem_ciphertext,
+        };
+        let handshake = SecureHandshake {
+            channel: self,
+            x25519_secret,
+            kem_secret,
+            hello: hello.clone(),
+            rekey_interval,
+        };
+        x25519_secret.zeroize();
+        Ok((handshake, hello))
+    }
+}
+
+impl SecureHandshake {
+    /// Complete the handshake with the responder's `ServerHello`
+    ///
+    /// # Outputs
+    /// - Initiator side of the secure channel
+    /// - `ConfirmationFailed` if the responder did not derive the same keys
+    pub fn finish(self, server: &ServerHello) -> Result<SecureChannel, SecureChannelError> {
+        let mut shared = x25519(&self.x25519_secret, &server.x25519_public)?;
+        let keys = SessionKeys::derive(self.channel, &self.hello, &server.x25519_public, &shared, &self.kem_secret);
+        shared.zeroize();
+        let keys = keys?;
+        if !constant_time_eq(&keys.confirmation, &server.confirmation) {
+            return Err(SecureChannelError::ConfirmationFailed);
+        }
+        Ok(SecureChannel::new(self.channel, keys.initiator, keys.responder, self.rekey_interval))
+    }
+}
+
+/// Accept a hybrid handshake as the responder
+///
+/// # Inputs
+/// - `channel`: Channel the `ClientHello` arrived on
+/// - `client`: Initiator's `ClientHello`
+/// - `kem_secret_key`: Responder's static Kyber secret key
+/// - `entropy`: Fresh randomness for the X25519 key
+/// - `rekey_interval`: Frames per direction between rekeys
+///
+/// # Outputs
+/// - Responder side of the secure channel and the `ServerHello` to send
+pub fn accept_secure(
+    channel: Channel,
+    client: &ClientHello,
+    kem_secret_key: &dyn KeyDecapsulator,
+    entropy: &[u8; 32],
+    rekey_interval: u64,
+) -> Result<(SecureChannel, ServerHello), SecureChannelError> {
+    if rekey_interval == 0 {
+        return Err(SecureChannelError::InvalidInterval);
+    }
+    let mut kem_secret = kem_secret_key
+        .decapsulate(&client.kem_ciphertext)
+        .ok_or(SecureChannelError::KemFailed)?;
+    let (mut x25519_secret, mut unused) = split_entropy(entropy);
+    unused.zeroize();
+    let x25519_public = MontgomeryPoint::mul_base_clamped(x25519_secret).to_bytes();
+    let shared = x25519(&x25519_secret, &client.x25519_public);
+    x25519_secret.zeroize();
+    let mut shared = match shared {
+        Ok(shared) => shared,
+        Err(e) => {
+            kem_secret.zeroize();
+            return Err(e);
+        }
+    };
+
+    let keys = SessionKeys::derive(channel, client, &x25519_public, &shared, &kem_secret);
+    shared.zeroize();
+    kem_secret.zeroize();
+    let keys = keys?;
+    let server = ServerHello { x25519_public, confirmation: keys.confirmation };
+    Ok((SecureChannel::new(channel, keys.responder, keys.initiator, rekey_interval), server))
+}
+
+/// Established secure channel (one side)
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct SecureChannel {
+    #[zeroize(skip)]
+    channel: Channel,
+    send: DirectionKey,
+    recv: DirectionKey,
+    send_sequence: u64,
+    recv_next: u64,
+    rekey_interval: u64,
+}
+
+impl SecureChannel {
+    fn new(channel: Channel, send_key: [u8; 32], recv_key: [u8; 32], rekey_interval: u64) -> Self {
+        Self {
+            channel,
+            send: DirectionKey { key: send_key, epoch: 0 },
+            recv: DirectionKey { key: recv_key, epoch: 0 },
+            send_sequence: 0,
+            recv_next: 0,
+            rekey_interval,
+        }
+    }
+
+    /// Underlying transport channel
+    pub fn channel(&self) -> Channel {
+        self.channel
+    }
+
+    /// Current send and receive rekey epochs
+    pub fn epochs(&self) -> (u64, u64) {
+        (self.send.epoch, self.recv.epoch)
+    }
+
+    /// Encrypt and authenticate a payload
+    pub fn seal(&mut self, plaintext: &[u8]) -> Result<SecureFrame, SecureChannelError> {
+        let sequence = self.send_sequence;
+        self.send.ratchet_to(sequence / self.rekey_interval)?;
+        self.send_sequence += 1;
+        let ciphertext = apply_keystream(&self.send.key, sequence, plaintext);
+        let tag = frame_tag(&self.send.key, sequence, &ciphertext);
+        Ok(SecureFrame { sequence, ciphertext, tag })
+    }
+
+    /// Authenticate and decrypt a frame
+    ///
+    /// The receive key only advances once the frame verifies, so a forged
+    /// frame cannot push the receiver into a later epoch.
+    pub fn open(&mut self, frame: &SecureFrame) -> Result<Vec<u8>, SecureChannelError> {
+        if frame.sequence < self.recv_next {
+            return Err(SecureChannelError::Replay);
+        }
+        let epoch = frame.sequence / self.rekey_interval;
+        if epoch - self.recv.epoch > MAX_EPOCH_SKIP {
+            return Err(SecureChannelError::OutOfOrder);
+        }
+        let mut recv = self.recv.clone();
+        recv.ratchet_to(epoch)?;
+        let tag = frame_tag(&recv.key, frame.sequence, &frame.ciphertext);
+        if !constant_time_eq(&tag, &frame.tag) {
+            return Err(SecureChannelError::AuthenticationFailed);
+        }
+        self.recv = recv;
+        self.recv_next = frame.sequence + 1;
+        Ok(apply_keystream(&self.recv.key, frame.sequence, &frame.ciphertext))
+    }
+}
+
+/// One direction's key and rekey epoch
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+struct DirectionKey {
+    key: [u8; 32],
+    epoch: u64,
+}
+
+impl DirectionKey {
+    fn ratchet_to(&mut self, epoch: u64) -> Result<(), SecureChannelError> {
+        while self.epoch < epoch {
+            let next = derive_fixed::<32>(None, &self.key, REKEY_INFO).map_err(|_| SecureChannelError::KeySchedule)?;
+            self.key.zeroize();
+            self.key = next;
+            self.epoch += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Handshake outputs from the key schedule
+#[derive(Zeroize, ZeroizeOnDrop)]
+struct SessionKeys {
+    confirmation: [u8; 32],
+    initiator: [u8; 32],
+    responder: [u8; 32],
+}
+
+impl SessionKeys {
+    fn derive(
+        channel: Channel,
+        client: &ClientHello,
+        server_public: &[u8; 32],
+        x25519_shared: &[u8; 32],
+        kem_shared: &[u8; 32],
+    ) -> Result<Self, SecureChannelError> {
+        let transcript: [u8; 64] = Sha3_512::new()
+            .chain_update(TRANSCRIPT_DOMAIN)
+            .chain_update([channel_code(channel)])
+            .chain_update(client.x25519_public)
+            .chain_update((client.kem_ciphertext.len() as u64).to_le_bytes())
+            .chain_update(&client.kem_ciphertext)
+            .chain_update(server_public)
+            .finalize()
+            .into();
+
+        let mut ikm = [0u8; 64];
+        ikm[..32].copy_from_slice(x25519_shared);
+        ikm[32..].copy_from_slice(kem_shared);
+        let hkdf = Hkdf::extract(Some(&transcript), &ikm);
+        ikm.zeroize();
+
+        let expand = |info: &[u8]| hkdf.expand_fixed::<32>(info).map_err(|_| SecureChannelError::KeySchedule);
+        Ok(Self {
+            confirmation: expand(b"confirmation")?,
+            initiator: expand(b"initiator to responder")?,
+            responder: expand(b"responder to initiator")?,
+        })
+    }
+}
+
+fn channel_code(channel: Channel) -> u8 {
+    match channel {
+        Channel::Tcp => 0,
+        Channel::Tor => 1,
+        Channel::I2p => 2,
+        Channel::Offline => 3,
+    }
+}
+
+/// X25519 secret scalar and KEM coins from caller entropy
+fn split_entropy(entropy: &[u8; 32]) -> ([u8; 32], [u8; 32]) {
+    let mut expanded: [u8; 64] = Sha3_512::new().chain_update(ENTROPY_DOMAIN).chain_update(entropy).finalize().into();
+    let mut x25519_secret = [0u8; 32];
+    let mut coins = [0u8; 32];
+    x25519_secret.copy_from_slice(&expanded[..32]);
+    coins.copy_from_slice(&expanded[32..]);
+    expanded.zeroize();
+    (x25519_secret, coins)
+}
+
+/// X25519 (RFC 7748), rejecting low-order peer keys
+fn x25519(secret: &[u8; 32], peer_public: &[u8; 32]) -> Result<[u8; 32], SecureChannelError> {
+    let shared = MontgomeryPoint(*peer_public).mul_clamped(*secret).to_bytes();
+    if shared == [0u8; 32] {
+        return Err(SecureChannelError::InvalidKey);
+    }
+    Ok(shared)
+}
+
+fn constant_time_eq(a: &[u8; 32], b: &[u8; 32]) -> bool {
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn apply_keystream(key: &[u8; 32], sequence: u64, data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    for (block, chunk) in data.chunks(32).enumerate() {
+        let pad: [u8; 32] = Sha3_256::new()
+            .chain_update(KEYSTREAM_DOMAIN)
+            .chain_update(key)
+            .chain_update(sequence.to_le_bytes())
+            .chain_update((block as u64).to_le_bytes())
+            .finalize()
+            .into();
+        out.extend(chunk.iter().zip(pad.iter()).map(|(b, k)| b ^ k));
+    }
+    out
+}
+
+fn frame_tag(key: &[u8; 32], sequence: u64, ciphertext: &[u8]) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update(FRAME_DOMAIN)
+        .chain_update(key)
+        .chain_update(sequence.to_le_bytes())
+        .chain_update(ciphertext)
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stand-in for Kyber: ciphertext is the coins, secret is H(key || coins)
+    struct TestKem([u8; 32]);
+
+    impl TestKem {
+        fn secret(&self, ciphertext: &[u8]) -> [u8; 32] {
+            Sha3_256::new().chain_update(self.0).chain_update(ciphertext).finalize().into()
+        }
+    }
+
+    impl KeyEncapsulator for TestKem {
+        fn encapsulate(&self, coins: &[u8; 32]) -> ([u8; 32], Vec<u8>) {
+            (self.secret(coins), coins.to_vec())
+        }
+    }
+
+    impl KeyDecapsulator for TestKem {
+        fn decapsulate(&self, ciphertext: &[u8]) -> Option<[u8; 32]> {
+            (ciphertext.len() == 32).then(|| self.secret(ciphertext))
+        }
+    }
+
+    fn establish(rekey_interval: u64) -> (SecureChannel, SecureChannel) {
+        let kem = TestKem([7u8; 32]);
+        let (handshake, client) = Channel::Tor.upgrade_secure(&kem, &[1u8; 32], rekey_interval).unwrap();
+        let client: ClientHello = minicbor::decode(&minicbor::to_vec(&client).unwrap()).unwrap();
+        let (responder, server) = accept_secure(Channel::Tor, &client, &kem, &[2u8; 32], rekey_interval).unwrap();
+        (handshake.finish(&server).unwrap(), responder)
+    }
+
+    #[test]
+    fn test_handshake_and_frames() {
+        let (mut initiator, mut responder) = establish(DEFAULT_REKEY_INTERVAL);
+        assert_eq!(initiator.channel(), Channel::Tor);
+
+        let frame = initiator.seal(b"TXO gossip").unwrap();
+        assert_ne!(frame.ciphertext, b"TXO gossip");
+        assert_eq!(responder.open(&frame).unwrap(), b"TXO gossip");
+        assert_eq!(responder.open(&frame), Err(SecureChannelError::Replay));
+
+        let reply = responder.seal(b"ack").unwrap();
+        assert_eq!(initiator.open(&reply).unwrap(), b"ack");
+
+        let mut tampered = initiator.seal(b"vote").unwrap();
+        tampered.ciphertext[0] ^= 1;
+        assert_eq!(responder.open(&tampered), Err(SecureChannelError::AuthenticationFailed));
+    }
+
+    #[test]
+    fn test_handshake_rejects_wrong_kem_key_and_low_order_points() {
+        let (handshake, client) = Channel::Tcp.upgrade_secure(&TestKem([7u8; 32]), &[1u8; 32], 16).unwrap();
+        let (_, server) = accept_secure(Channel::Tcp, &client, &TestKem([8u8; 32]), &[2u8; 32], 16).unwrap();
+        assert!(matches!(handshake.finish(&server), Err(SecureChannelError::ConfirmationFailed)));
+
+        // Keys are bound to the channel the handshake ran on
+        let (handshake, client) = Channel::Tcp.upgrade_secure(&TestKem([7u8; 32]), &[1u8; 32], 16).unwrap();
+        let (_, server) = accept_secure(Channel::I2p, &client, &TestKem([7u8; 32]), &[2u8; 32], 16).unwrap();
+        assert!(matches!(handshake.finish(&server), Err(SecureChannelError::ConfirmationFailed)));
+
+        let low_order = ClientHello { x25519_public: [0u8; 32], ..client };
+        assert!(matches!(
+            accept_secure(Channel::Tcp, &low_order, &TestKem([7u8; 32]), &[2u8; 32], 16),
+            Err(SecureChannelError::InvalidKey)
+        ));
+        assert!(matches!(Channel::Tcp.upgrade_secure(&TestKem([7u8; 32]), &[1u8; 32], 0), Err(SecureChannelError::InvalidInterval)));
+    }
+
+    #[test]
+    fn test_automatic_rekeying() {
+        let (mut initiator, mut responder) = establish(4);
+        let frames: Vec<SecureFrame> = (0..10u8).map(|i| initiator.seal(&[i]).unwrap()).collect();
+        assert_eq!(initiator.epochs().0, 2);
+
+        for (i, frame) in frames.iter().enumerate().take(5) {
+            assert_eq!(responder.open(frame).unwrap(), [i as u8]);
+        }
+        assert_eq!(responder.epochs().1, 1);
+
+        // A forged frame cannot advance the receive epoch; a gap within the skip limit is fine
+        let mut forged = frames[9].clone();
+        forged.tag[0] ^= 1;
+        assert_eq!(responder.open(&forged), Err(SecureChannelError::AuthenticationFailed));
+        assert_eq!(responder.epochs().1, 1);
+        assert_eq!(responder.open(&frames[9]).unwrap(), [9]);
+        assert_eq!(responder.epochs().1, 2);
+
+        let far = SecureFrame { sequence: 4 * (MAX_EPOCH_SKIP + 3), ciphertext: Vec::new(), tag: [0u8; 32] };
+        assert_eq!(responder.open(&far), Err(SecureChannelError::OutOfOrder));
+    }
+}
@@ -12,6 +12,8 @@
 //! - **Volatile Only**: Snapshots never touch disk (RAM-only)
 //! - **Encrypted**: Protected by ephemeral session key
 //! - **Bounded**: Limited snapshot history (memory constraints)
+//! - **Key Storage**: Session keys parked outside the enclave are wrapped
+//!   with [`crate::keywrap`] (AES-KW or Kyber hybrid)
 //!
 //! ## Inputs → Outputs
 //!
@@ -35,6 +37,9 @@ use alloc::vec::Vec;
 
 use sha3::{Sha3_256, Digest};
 use zeroize::{Zeroize, ZeroizeOnDrop};
+use crate::clock;
+use crate::keywrap::{KeyDecapsulator, WrapError, WrappedKey};
+use crate::secure_alloc::KeyMaterial;
 
 /// Snapshot Configuration
 #[derive(Debug, Clone)]
@@ -109,7 +114,7 @@ impl VolatileSnapshot {
         state_data: &[u8],
         encryption_key: &[u8; 64],
     ) -> Self {
-        let timestamp = current_timestamp();
+        let timestamp = clock::now_ms();
         
         // Generate nonce from timestamp and sequence
         let mut nonce_hasher = Sha3_256::new();
@@ -191,14 +196,14 @@ impl SnapshotManager {
         Self {
             snapshots: Vec::new(),
             next_sequence: 0,
-            last_snapshot: current_timestamp(),
+            last_snapshot: clock::now_ms(),
             config,
         }
     }
     
     /// Check if snapshot due
     pub fn snapshot_due(&self) -> bool {
-        let current_time = current_timestamp();
+        let current_time = clock::now_ms();
         current_time - self.last_snapshot >= self.config.snapshot_interval_ms
     }
     
@@ -235,7 +240,7 @@ impl SnapshotManager {
         }
         
         self.next_sequence += 1;
-        self.last_snapshot = current_timestamp();
+        self.last_snapshot = clock::now_ms();
         
         sequence
     }
@@ -270,6 +275,47 @@ impl SnapshotManager {
     }
 }
 
+/// Wrap a snapshot session key for storage outside the enclave
+///
+/// ## Lifecycle Stage: Execution
+///
+/// # Inputs
+/// - `encryption_key`: Ephemeral session key
+/// - `kek`: Key-encryption key
+///
+/// # Outputs
+/// - AES-KW `WrappedKey`; use `WrappedKey::wrap_hybrid` for the Kyber hybrid
+pub fn wrap_session_key(encryption_key: &[u8; 64], kek: &[u8; 32]) -> Result<WrappedKey, WrapError> {
+    WrappedKey::wrap(kek, encryption_key)
+}
+
+/// Recover a wrapped snapshot session key
+///
+/// # Inputs
+/// - `wrapped`: Output of `wrap_session_key` or `WrappedKey::wrap_hybrid`
+/// - `kek`: Key-encryption key
+/// - `kem`: Kyber secret key, required for hybrid-wrapped keys
+///
+/// # Outputs
+/// - Session key (locked in RAM on std), or an error if the KEK is wrong or
+///   the blob was modified
+pub fn unwrap_session_key(
+    wrapped: &WrappedKey,
+    kek: &[u8; 32],
+    kem: Option<&dyn KeyDecapsulator>,
+) -> Result<KeyMaterial<64>, WrapError> {
+    let mut key_data = wrapped.unwrap(kek, kem)?;
+    let result = if key_data.len() == 64 {
+        let mut key = KeyMaterial::<64>::from([0u8; 64]);
+        key.copy_from_slice(&key_data);
+        Ok(key)
+    } else {
+        Err(WrapError::InvalidLength)
+    };
+    key_data.zeroize();
+    result
+}
+
 /// XOR-based encryption (placeholder)
 ///
 /// ## Security Rationale
@@ -293,22 +339,6 @@ fn xor_decrypt(data: &[u8], key: &[u8; 64], nonce: &[u8; 32]) -> Vec<u8> {
     xor_encrypt(data, key, nonce)
 }
 
-/// Get current timestamp (milliseconds since epoch)
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -343,4 +373,21 @@ mod tests {
         assert_eq!(seq, 0);
         assert_eq!(manager.snapshot_count(), 1);
     }
+    
+    #[test]
+    fn test_session_key_wrapping() {
+        let key = [3u8; 64];
+        let kek = [4u8; 32];
+        let snapshot = VolatileSnapshot::create(0, b"execution state data", &key);
+        
+        let wrapped = wrap_session_key(&key, &kek).unwrap();
+        let restored_key = unwrap_session_key(&wrapped, &kek, None).unwrap();
+        assert_eq!(snapshot.restore(&restored_key).unwrap(), b"execution state data");
+        #[cfg(all(feature = "std", unix))]
+        assert_ne!(restored_key.protection(), crate::secure_alloc::Protection::Heap);
+        
+        assert_eq!(unwrap_session_key(&wrapped, &[5u8; 32], None), Err(WrapError::IntegrityCheckFailed));
+        let short = WrappedKey::wrap(&kek, &[6u8; 32]).unwrap();
+        assert_eq!(unwrap_session_key(&short, &kek, None), Err(WrapError::InvalidLength));
+    }
 }
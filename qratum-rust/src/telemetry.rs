@@ -0,0 +1,470 @@
+//! # Telemetry Module - Signed Node Health Digests
+//!
+//! ## Lifecycle Stage: Execution (epoch rotation)
+//!
+//! Visualization and governance dashboards need to know how each node is
+//! doing, and an unauthenticated JSON feed lets anyone paint a healthy or
+//! failing picture of any node. Instead, every `interval_epochs` epochs a
+//! node folds its per-epoch health samples into a compact `HealthDigest`,
+//! signs it, and publishes it as a `HealthDigest` TXO.
+//!
+//! ## Architectural Role
+//!
+//! - **Sampling**: One `HealthSample` per closed epoch (mempool depth,
+//!   consensus participation, canary score, resource usage)
+//! - **Digest Stream**: `HealthDigestStream` aggregates samples and emits a
+//!   signed, hash-chained digest TXO every N epochs
+//! - **Feed**: `HealthFeed` verifies digests from registered nodes and keeps
+//!   the latest per node for dashboards
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: `HealthSample` per epoch, node signing backend
+//! - Output: `HealthDigest` TXO, gossiped through the mempool at low priority
+//!
+//! ## Security Rationale
+//!
+//! - Digests are signed over a domain-separated SHA3-256 hash
+//! - Each digest commits to its predecessor, so a node cannot silently drop
+//!   or replace a bad interval without breaking its own chain
+//! - Aggregates are worst-case where it matters (peak mempool depth, lowest
+//!   canary score, peak memory), so one good epoch cannot hide a bad one
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256};
+
+use crate::epoch::NetworkObservation;
+use crate::p2p::{NodeID, TxoMempool};
+use crate::txo::{Txo, TxoType};
+use crate::watchdog::{BeaconSigner, BeaconVerifier};
+
+/// Domain separator for digest hashes
+const DIGEST_DOMAIN: &[u8] = b"QRATUM-HEALTH-DIGEST-v1";
+
+/// Mempool priority for digest TXOs (below every consensus TXO)
+pub const DIGEST_PRIORITY: u64 = 0;
+
+/// Health observed on one node over one epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthSample {
+    /// Pending TXOs at epoch close
+    pub mempool_depth: u32,
+    /// Voting power that participated (percent)
+    pub participation: u8,
+    /// Canaries verified on time (percent, see [`canary_score`])
+    pub canary_score: u8,
+    /// Resident memory (KiB)
+    pub memory_kb: u64,
+    /// CPU utilisation (percent)
+    pub cpu_percent: u8,
+}
+
+impl HealthSample {
+    /// Sample a node at epoch close
+    ///
+    /// # Inputs
+    /// - `mempool`: Node mempool (depth)
+    /// - `observation`: Epoch observation (participation)
+    /// - `canary_score`: Canary score for the epoch
+    /// - `memory_kb`, `cpu_percent`: Resource usage reported by the host
+    pub fn observe(
+        mempool: &TxoMempool,
+        observation: &NetworkObservation,
+        canary_score: u8,
+        memory_kb: u64,
+        cpu_percent: u8,
+    ) -> Self {
+        Self {
+            mempool_depth: mempool.size().min(u32::MAX as usize) as u32,
+            participation: observation.participation,
+            canary_score,
+            memory_kb,
+            cpu_percent,
+        }
+    }
+
+    fn validate(&self) -> Result<(), TelemetryError> {
+        if self.participation > 100 || self.canary_score > 100 || self.cpu_percent > 100 {
+            return Err(TelemetryError::InvalidSample);
+        }
+        Ok(())
+    }
+}
+
+/// Percentage of expected canaries that verified (100 when none were expected)
+pub fn canary_score(verified: u64, expected: u64) -> u8 {
+    if expected == 0 {
+        return 100;
+    }
+    (verified.min(expected) * 100 / expected) as u8
+}
+
+/// Signed summary of a node's health over a run of epochs
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct HealthDigest {
+    /// Reporting node
+    #[n(0)]
+    pub node_id: NodeID,
+
+    /// Position in the node's digest chain (0 = first)
+    #[n(1)]
+    pub sequence: u64,
+
+    /// First epoch covered
+    #[n(2)]
+    pub first_epoch: u64,
+
+    /// Last epoch covered
+    #[n(3)]
+    pub last_epoch: u64,
+
+    /// Deepest mempool seen
+    #[n(4)]
+    pub max_mempool_depth: u32,
+
+    /// Mean consensus participation (percent)
+    #[n(5)]
+    pub mean_participation: u8,
+
+    /// Lowest canary score seen (percent)
+    #[n(6)]
+    pub min_canary_score: u8,
+
+    /// Peak resident memory (KiB)
+    #[n(7)]
+    pub peak_memory_kb: u64,
+
+    /// Mean CPU utilisation (percent)
+    #[n(8)]
+    pub mean_cpu_percent: u8,
+
+    /// Hash of the previous digest (zero for the first)
+    #[n(9)]
+    pub prev_digest_hash: [u8; 32],
+}
+
+impl HealthDigest {
+    /// Domain-separated SHA3-256 hash, the signed message
+    pub fn digest_hash(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(DIGEST_DOMAIN);
+        hasher.update(self.to_cbor());
+        hasher.finalize().into()
+    }
+
+    /// Serialize to CBOR
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserialize from CBOR
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, minicbor::decode::Error> {
+        minicbor::decode(bytes)
+    }
+
+    /// Signed `HealthDigest` TXO
+    ///
+    /// # Outputs
+    /// - TXO with the CBOR digest as payload, the previous digest TXO (if
+    ///   any) as predecessor and the node signature over `digest_hash()`
+    pub fn to_txo(&self, timestamp: u64, prev_txo: Option<[u8; 32]>, signer: &dyn BeaconSigner) -> Txo {
+        let mut txo = Txo::new(TxoType::HealthDigest, timestamp, self.to_cbor(), prev_txo.into_iter().collect());
+        txo.signatures.push(signer.sign(&self.digest_hash()));
+        txo
+    }
+}
+
+/// Telemetry Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelemetryError {
+    /// Digest interval is zero
+    InvalidInterval,
+    /// Sample percentages above 100
+    InvalidSample,
+    /// Epoch not after the last recorded epoch
+    EpochRegression,
+    /// TXO is not a `HealthDigest`
+    WrongType,
+    /// Payload does not decode as a digest
+    Malformed,
+    /// Reporting node is not registered with the feed
+    UnknownNode(NodeID),
+    /// Missing or invalid node signature
+    InvalidSignature,
+    /// Digest does not extend the node's previous digest
+    BrokenChain,
+}
+
+/// Samples accumulated since the last digest
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    first_epoch: u64,
+    last_epoch: u64,
+    epochs: u64,
+    max_mempool_depth: u32,
+    participation_sum: u64,
+    min_canary_score: u8,
+    peak_memory_kb: u64,
+    cpu_sum: u64,
+}
+
+impl Window {
+    fn open(epoch: u64, sample: &HealthSample) -> Self {
+        Self {
+            first_epoch: epoch,
+            last_epoch: epoch,
+            epochs: 1,
+            max_mempool_depth: sample.mempool_depth,
+            participation_sum: sample.participation as u64,
+            min_canary_score: sample.canary_score,
+            peak_memory_kb: sample.memory_kb,
+            cpu_sum: sample.cpu_percent as u64,
+        }
+    }
+
+    fn add(&mut self, epoch: u64, sample: &HealthSample) {
+        self.last_epoch = epoch;
+        self.epochs += 1;
+        self.max_mempool_depth = self.max_mempool_depth.max(sample.mempool_depth);
+        self.participation_sum += sample.participation as u64;
+        self.min_canary_score = self.min_canary_score.min(sample.canary_score);
+        self.peak_memory_kb = self.peak_memory_kb.max(sample.memory_kb);
+        self.cpu_sum += sample.cpu_percent as u64;
+    }
+}
+
+/// Per-node digest publisher
+///
+/// ## Inputs → Outputs
+/// - `record_epoch` once per closed epoch → signed digest TXO every
+///   `interval_epochs` epochs
+#[derive(Debug, Clone)]
+pub struct HealthDigestStream {
+    node_id: NodeID,
+    interval_epochs: u64,
+    sequence: u64,
+    last_epoch: Option<u64>,
+    window: Option<Window>,
+    prev_digest_hash: [u8; 32],
+    prev_txo: Option<[u8; 32]>,
+}
+
+impl HealthDigestStream {
+    /// Create a stream emitting one digest every `interval_epochs` epochs
+    pub fn new(node_id: NodeID, interval_epochs: u64) -> Result<Self, TelemetryError> {
+        if interval_epochs == 0 {
+            return Err(TelemetryError::InvalidInterval);
+        }
+        Ok(Self {
+            node_id,
+            interval_epochs,
+            sequence: 0,
+            last_epoch: None,
+            window: None,
+            prev_digest_hash: [0u8; 32],
+            prev_txo: None,
+        })
+    }
+
+    /// Digests emitted so far
+    pub fn emitted(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Record the sample for a closed epoch
+    ///
+    /// # Outputs
+    /// - `Some(txo)` when the epoch completes an interval, `None` otherwise
+    pub fn record_epoch(
+        &mut self,
+        epoch: u64,
+        sample: &HealthSample,
+        timestamp: u64,
+        signer: &dyn BeaconSigner,
+    ) -> Result<Option<Txo>, TelemetryError> {
+        sample.validate()?;
+        if self.last_epoch.is_some_and(|last| epoch <= last) {
+            return Err(TelemetryError::EpochRegression);
+        }
+        self.last_epoch = Some(epoch);
+
+        let window = match self.window.as_mut() {
+            Some(window) => {
+                window.add(epoch, sample);
+                *window
+            }
+            None => *self.window.insert(Window::open(epoch, sample)),
+        };
+        if window.epochs < self.interval_epochs {
+            return Ok(None);
+        }
+
+        let digest = HealthDigest {
+            node_id: self.node_id,
+            sequence: self.sequence,
+            first_epoch: window.first_epoch,
+            last_epoch: window.last_epoch,
+            max_mempool_depth: window.max_mempool_depth,
+            mean_participation: (window.participation_sum / window.epochs) as u8,
+            min_canary_score: window.min_canary_score,
+            peak_memory_kb: window.peak_memory_kb,
+            mean_cpu_percent: (window.cpu_sum / window.epochs) as u8,
+            prev_digest_hash: self.prev_digest_hash,
+        };
+        let txo = digest.to_txo(timestamp, self.prev_txo, signer);
+
+        self.window = None;
+        self.sequence += 1;
+        self.prev_digest_hash = digest.digest_hash();
+        self.prev_txo = Some(txo.id);
+        Ok(Some(txo))
+    }
+}
+
+/// Publish a digest TXO for gossip
+///
+/// # Outputs
+/// - `false` if the mempool is full or already holds the TXO
+pub fn publish_digest(txo: &Txo, mempool: &mut TxoMempool) -> bool {
+    mempool.add_txo(txo.clone(), DIGEST_PRIORITY)
+}
+
+/// Verified health feed for dashboards
+///
+/// ## Security Rationale
+/// - Only registered nodes are accepted, under their registered key
+/// - The first digest seen from a node anchors its chain; every later one
+///   must extend it
+#[derive(Debug, Clone, Default)]
+pub struct HealthFeed {
+    keys: BTreeMap<NodeID, [u8; 32]>,
+    latest: BTreeMap<NodeID, HealthDigest>,
+}
+
+impl HealthFeed {
+    /// Create an empty feed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a node's digest verification key
+    pub fn register(&mut self, node_id: NodeID, public_key: [u8; 32]) {
+        self.keys.insert(node_id, public_key);
+    }
+
+    /// Verify and accept a digest TXO
+    ///
+    /// # Outputs
+    /// - The verified digest, now the node's latest
+    pub fn accept(&mut self, txo: &Txo, verifier: &dyn BeaconVerifier) -> Result<HealthDigest, TelemetryError> {
+        if txo.txo_type != TxoType::HealthDigest {
+            return Err(TelemetryError::WrongType);
+        }
+        let digest = HealthDigest::from_cbor(&txo.payload).map_err(|_| TelemetryError::Malformed)?;
+        let public_key = self.keys.get(&digest.node_id).ok_or(TelemetryError::UnknownNode(digest.node_id))?;
+
+        let message = digest.digest_hash();
+        match txo.signatures.as_slice() {
+            [signature] if verifier.verify(public_key, &message, signature) => {}
+            _ => return Err(TelemetryError::InvalidSignature),
+        }
+
+        if let Some(previous) = self.latest.get(&digest.node_id) {
+            if digest.sequence != previous.sequence + 1
+                || digest.prev_digest_hash != previous.digest_hash()
+                || digest.first_epoch <= previous.last_epoch
+            {
+                return Err(TelemetryError::BrokenChain);
+            }
+        }
+
+        self.latest.insert(digest.node_id, digest.clone());
+        Ok(digest)
+    }
+
+    /// Latest verified digest from a node
+    pub fn latest(&self, node_id: &NodeID) -> Option<&HealthDigest> {
+        self.latest.get(node_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_signer::{TestKey, TestVerifier};
+
+    fn sample(mempool_depth: u32, participation: u8, canary_score: u8) -> HealthSample {
+        HealthSample { mempool_depth, participation, canary_score, memory_kb: 1024 + mempool_depth as u64, cpu_percent: 40 }
+    }
+
+    #[test]
+    fn test_digest_every_n_epochs() {
+        let node = [1u8; 32];
+        let key = TestKey([9u8; 32]);
+        let mut stream = HealthDigestStream::new(node, 3).unwrap();
+        let mut feed = HealthFeed::new();
+        feed.register(node, key.0);
+        let mut mempool = TxoMempool::default();
+
+        let samples = [sample(10, 90, 100), sample(40, 70, 50), sample(20, 80, 100)];
+        let mut emitted = Vec::new();
+        for (epoch, s) in (1u64..).zip(samples.iter().chain(samples.iter())) {
+            if let Some(txo) = stream.record_epoch(epoch, s, epoch * 1000, &key).unwrap() {
+                assert!(publish_digest(&txo, &mut mempool));
+                emitted.push(txo);
+            }
+        }
+        assert_eq!(emitted.len(), 2);
+        assert_eq!(stream.emitted(), 2);
+        assert_eq!(mempool.size(), 2);
+        assert_eq!(emitted[1].predecessors, vec![emitted[0].id]);
+
+        let first = feed.accept(&emitted[0], &TestVerifier).unwrap();
+        assert_eq!((first.first_epoch, first.last_epoch), (1, 3));
+        assert_eq!(first.max_mempool_depth, 40);
+        assert_eq!(first.mean_participation, 80);
+        assert_eq!(first.min_canary_score, 50);
+        assert_eq!(first.peak_memory_kb, 1064);
+
+        let second = feed.accept(&emitted[1], &TestVerifier).unwrap();
+        assert_eq!(second.sequence, 1);
+        assert_eq!(feed.latest(&node), Some(&second));
+
+        // Replaying the first digest does not extend the chain
+        assert_eq!(feed.accept(&emitted[0], &TestVerifier), Err(TelemetryError::BrokenChain));
+        assert_eq!(stream.record_epoch(6, &samples[0], 0, &key).err(), Some(TelemetryError::EpochRegression));
+    }
+
+    #[test]
+    fn test_feed_rejects_forged_digest() {
+        let node = [2u8; 32];
+        let key = TestKey([7u8; 32]);
+        let mut stream = HealthDigestStream::new(node, 1).unwrap();
+        let mut feed = HealthFeed::new();
+
+        let txo = stream.record_epoch(1, &sample(5, 100, 100), 1000, &key).unwrap().unwrap();
+        assert_eq!(feed.accept(&txo, &TestVerifier), Err(TelemetryError::UnknownNode(node)));
+        feed.register(node, key.0);
+
+        // Rewriting the payload to report a healthier node breaks the signature
+        let mut forged = txo.clone();
+        let mut digest = HealthDigest::from_cbor(&forged.payload).unwrap();
+        digest.min_canary_score = 100;
+        digest.max_mempool_depth = 0;
+        forged.payload = digest.to_cbor();
+        assert_eq!(feed.accept(&forged, &TestVerifier), Err(TelemetryError::InvalidSignature));
+
+        // Signed by a different key
+        let other = HealthDigest::from_cbor(&txo.payload).unwrap().to_txo(1000, None, &TestKey([8u8; 32]));
+        assert_eq!(feed.accept(&other, &TestVerifier), Err(TelemetryError::InvalidSignature));
+
+        assert!(feed.accept(&txo, &TestVerifier).is_ok());
+        assert_eq!(canary_score(3, 4), 75);
+        assert_eq!(canary_score(0, 0), 100);
+        assert!(HealthDigestStream::new(node, 0).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+//! Test Signature Backend
+//!
+//! Deterministic stand-in for node signing keys in unit tests. A signature
+//! is SHA3(key || message) duplicated to 64 bytes, and the public key equals
+//! the signing key. Implements the watchdog signing traits shared by
+//! beacons, finality certificates and health digests.
+
+use sha3::{Digest, Sha3_256};
+
+use crate::watchdog::{BeaconSigner, BeaconVerifier};
+
+/// Test signer: SHA3(key || message), duplicated to 64 bytes
+pub struct TestKey(pub [u8; 32]);
+
+fn test_signature(key: &[u8; 32], message: &[u8; 32]) -> [u8; 64] {
+    let digest: [u8; 32] = Sha3_256::new().chain_update(key).chain_update(message).finalize().into();
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&digest);
+    signature[32..].copy_from_slice(&digest);
+    signature
+}
+
+impl BeaconSigner for TestKey {
+    fn sign(&self, message: &[u8; 32]) -> [u8; 64] {
+        test_signature(&self.0, message)
+    }
+}
+
+/// Test verifier: public key equals the signing key
+pub struct TestVerifier;
+
+impl BeaconVerifier for TestVerifier {
+    fn verify(&self, public_key: &[u8; 32], message: &[u8; 32], signature: &[u8; 64]) -> bool {
+        test_signature(public_key, message) == *signature
+    }
+}
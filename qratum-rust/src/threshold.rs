@@ -0,0 +1,787 @@
+//! # Threshold Module - Distributed Decryption of Blinded Payloads
+//!
+//! ## Lifecycle Stage: Quorum Convergence → Outcome Commitment
+//!
+//! Blinded payloads are encrypted to a quorum key that no single party holds.
+//! The key is generated without a dealer: every member deals a random
+//! polynomial, and each member's share of the quorum key is the sum of the
+//! shares it received. To reveal a payload, M-of-N members each publish a
+//! partial decryption of the ciphertext, and the requesting client combines
+//! them. Neither the quorum key nor the payload key is ever reconstructed.
+//!
+//! ## Architectural Role
+//!
+//! - **Key Generation**: Pedersen DKG over ristretto255; each dealer
+//!   broadcasts Feldman commitments to its polynomial with a proof of
+//!   knowledge of its constant term, and sends one share to each member
+//! - **Encryption**: Hashed ElGamal; SHA3-256 keystream over the shared point
+//! - **Partial Decryption**: `x_i * C1` with a Chaum-Pedersen proof that it
+//!   used the same share as the member's verification key
+//! - **Combination**: Lagrange interpolation in the exponent, client-side
+//! - **Audit TXO**: `ThresholdReveal` TXO listing contributing members
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: N dealings → `ThresholdPublicKey` + one `KeyShare` per member
+//! - Input: Payload + quorum public key → `ThresholdCiphertext`
+//! - Input: Ciphertext + M verified partials → Payload + audit TXO
+//!
+//! ## Security Rationale
+//!
+//! - The quorum key is the sum of every dealer's constant term, so it stays
+//!   unknown unless all N dealers collude
+//! - Every received share is checked against its dealer's commitments, and
+//!   the proof of knowledge stops a dealer from cancelling others' keys; a
+//!   dealing that fails either check names its dealer (`InvalidDealing`) and
+//!   the ceremony is rerun without it
+//! - Fewer than M partials reveal nothing about the shared point
+//! - Every partial is proven correct before it is used, so a faulty member
+//!   cannot corrupt the reveal without being identified
+//! - Combined plaintext must match the blinded SHA3-256 commitment
+//! - Shares and polynomials are zeroized on drop
+//!
+//! ## Forward Compatibility
+//!
+//! ristretto255 is not post-quantum; QRADLE migration replaces the group
+//! with a lattice-based threshold scheme behind the same interface.
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+use sha3::{Digest, Sha3_256, Sha3_512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::txo::{Txo, TxoType};
+
+const SCALAR_DOMAIN: &[u8] = b"QRATUM-THRESHOLD-SCALAR-v2";
+const KEYSTREAM_DOMAIN: &[u8] = b"QRATUM-THRESHOLD-KEYSTREAM-v2";
+const PROOF_DOMAIN: &[u8] = b"QRATUM-THRESHOLD-DLEQ-v2";
+const DKG_COEFFICIENT_DOMAIN: &[u8] = b"QRATUM-THRESHOLD-DKG-COEFFICIENT-v1";
+const DKG_PROOF_DOMAIN: &[u8] = b"QRATUM-THRESHOLD-DKG-POK-v1";
+
+/// Threshold Decryption Error Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdError {
+    /// Threshold is zero, exceeds the member count, or more than 255 members
+    InvalidThreshold,
+    /// A dealer's commitments, proof or share fail verification
+    InvalidDealing(u8),
+    /// Ciphertext ephemeral key is not a valid non-identity point
+    InvalidCiphertext,
+    /// Partial names a member index or id not in the quorum key
+    UnknownMember,
+    /// Partial decryption proof does not verify
+    InvalidProof,
+    /// Two partials from the same member
+    DuplicateShare,
+    /// Fewer verified partials than the threshold (or the reveal quorum)
+    InsufficientShares,
+    /// Combined plaintext does not match the blinded commitment
+    CommitmentMismatch,
+}
+
+/// Public verification key of one quorum member
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemberKey {
+    /// Shamir evaluation point (1-based)
+    pub index: u8,
+    /// Quorum member id
+    pub member_id: [u8; 32],
+    /// `x_i * G`
+    pub verification_key: RistrettoPoint,
+}
+
+/// One member's share of the quorum decryption key
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct KeyShare {
+    /// Shamir evaluation point (1-based)
+    pub index: u8,
+    /// Quorum member id
+    pub member_id: [u8; 32],
+    /// Private share `x_i` (zeroized on drop)
+    secret: Scalar,
+}
+
+/// One member's DKG polynomial, kept until its shares are sent
+///
+/// ## Lifecycle Stage: Quorum Convergence
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct DkgDealer {
+    /// Dealer's evaluation point (1-based)
+    pub index: u8,
+    /// Quorum size (N)
+    member_count: u8,
+    /// Polynomial coefficients, constant term first (zeroized on drop)
+    coefficients: Vec<Scalar>,
+}
+
+/// A dealer's broadcast: Feldman commitments and proof of its constant term
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DkgCommitment {
+    /// Dealer's evaluation point (1-based)
+    pub index: u8,
+    /// Compressed `a_j * G` for each coefficient, constant term first
+    pub coefficients: Vec<[u8; 32]>,
+    /// Schnorr proof commitment `k * G`
+    pub proof_commitment: [u8; 32],
+    /// Schnorr proof response `k + c * a_0`
+    pub proof_response: [u8; 32],
+}
+
+/// Share of one dealer's polynomial for one recipient
+///
+/// ## Security Rationale
+/// - Must travel over an authenticated, confidential channel to `recipient`
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct DkgShare {
+    /// Dealer's evaluation point
+    pub dealer: u8,
+    /// Recipient's evaluation point
+    pub recipient: u8,
+    /// `f_dealer(recipient)` (zeroized on drop)
+    value: Scalar,
+}
+
+/// Payload encrypted to the quorum key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThresholdCiphertext {
+    /// Compressed ephemeral key `r * G`
+    pub ephemeral: [u8; 32],
+    /// Payload XOR keystream
+    pub ciphertext: Vec<u8>,
+    /// SHA3-256 commitment to the payload (matches `BlindedPayload::commitment`)
+    pub commitment: [u8; 32],
+}
+
+/// Member's partial decryption with its correctness proof
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialDecryption {
+    /// Shamir evaluation point of the contributing member
+    pub index: u8,
+    /// Contributing member id
+    pub member_id: [u8; 32],
+    /// Compressed `x_i * C1`
+    pub share: [u8; 32],
+    /// Fiat-Shamir challenge (canonical scalar)
+    pub challenge: [u8; 32],
+    /// Proof response (canonical scalar)
+    pub response: [u8; 32],
+}
+
+/// Quorum public key: encryption key, threshold and member verification keys
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThresholdPublicKey {
+    /// Partials required to decrypt (M)
+    pub threshold: u8,
+    /// `x * G`
+    pub key: RistrettoPoint,
+    /// Verification keys, one per member (N)
+    pub members: Vec<MemberKey>,
+}
+
+impl DkgDealer {
+    /// Start this member's dealing for an M-of-N quorum key
+    ///
+    /// ## Lifecycle Stage: Quorum Convergence
+    ///
+    /// # Inputs
+    /// - `entropy`: Fresh randomness, never reused across ceremonies
+    /// - `threshold`: Partials required to decrypt (M)
+    /// - `members`: Quorum member ids, assigned indices 1..=N in order
+    /// - `member_id`: This member
+    ///
+    /// # Outputs
+    /// - `Err(UnknownMember)` if `member_id` is not in `members`
+    pub fn new(
+        entropy: &[u8; 32],
+        threshold: u8,
+        members: &[[u8; 32]],
+        member_id: &[u8; 32],
+    ) -> Result<Self, ThresholdError> {
+        check_threshold(threshold, members.len())?;
+        let position = members.iter().position(|m| m == member_id).ok_or(ThresholdError::UnknownMember)?;
+        let index = position as u8 + 1;
+
+        let coefficients = (0..threshold)
+            .map(|j| hash_scalar(&[DKG_COEFFICIENT_DOMAIN, entropy, &[index, j]]))
+            .collect();
+        Ok(Self { index, member_count: members.len() as u8, coefficients })
+    }
+
+    /// Commitments and proof of knowledge to broadcast to the quorum
+    pub fn commitment(&self) -> DkgCommitment {
+        let coefficients: Vec<[u8; 32]> = self
+            .coefficients
+            .iter()
+            .map(|a| RistrettoPoint::mul_base(a).compress().to_bytes())
+            .collect();
+
+        // Deterministic nonce bound to the secret and the statement
+        let mut nonce = hash_scalar(&[DKG_PROOF_DOMAIN, self.coefficients[0].as_bytes(), &coefficients[0]]);
+        let proof_commitment = RistrettoPoint::mul_base(&nonce).compress().to_bytes();
+        let challenge = dealing_challenge(self.index, &coefficients[0], &proof_commitment);
+        let proof_response = (nonce + challenge * self.coefficients[0]).to_bytes();
+        nonce.zeroize();
+
+        DkgCommitment { index: self.index, coefficients, proof_commitment, proof_response }
+    }
+
+    /// One share per member (including this one), in index order
+    pub fn shares(&self) -> Vec<DkgShare> {
+        (1..=self.member_count)
+            .map(|recipient| DkgShare {
+                dealer: self.index,
+                recipient,
+                value: evaluate(&self.coefficients, recipient),
+            })
+            .collect()
+    }
+}
+
+impl ThresholdPublicKey {
+    /// Assemble the quorum public key from every dealer's broadcast
+    ///
+    /// ## Lifecycle Stage: Quorum Convergence
+    ///
+    /// # Inputs
+    /// - `threshold`: Partials required to decrypt (M)
+    /// - `members`: Quorum member ids, in the order used for dealing
+    /// - `commitments`: One `DkgCommitment` from each member
+    ///
+    /// # Outputs
+    /// - Public key with one verification key per member
+    /// - `Err(InvalidDealing(i))` if dealer `i` is missing, duplicated, has
+    ///   the wrong degree, or its proof of knowledge does not verify
+    pub fn from_dkg(
+        threshold: u8,
+        members: &[[u8; 32]],
+        commitments: &[DkgCommitment],
+    ) -> Result<Self, ThresholdError> {
+        check_threshold(threshold, members.len())?;
+
+        if let Some(extra) = commitments.iter().find(|c| c.index == 0 || c.index as usize > members.len()) {
+            return Err(ThresholdError::InvalidDealing(extra.index));
+        }
+
+        // Coefficient-wise sum of every dealer's committed polynomial
+        let mut combined = alloc::vec![RistrettoPoint::default(); threshold as usize];
+        for index in 1..=members.len() as u8 {
+            let mut dealings = commitments.iter().filter(|c| c.index == index);
+            let dealing = match (dealings.next(), dealings.next()) {
+                (Some(dealing), None) => dealing,
+                _ => return Err(ThresholdError::InvalidDealing(index)),
+            };
+            let points = verify_dealing(dealing, threshold)?;
+            for (sum, point) in combined.iter_mut().zip(points) {
+                *sum += point;
+            }
+        }
+
+        let keys = members
+            .iter()
+            .enumerate()
+            .map(|(position, member_id)| {
+                let index = position as u8 + 1;
+                MemberKey { index, member_id: *member_id, verification_key: evaluate_points(&combined, index) }
+            })
+            .collect();
+        Ok(Self { threshold, key: combined[0], members: keys })
+    }
+
+    /// Encrypt a payload to the quorum
+    ///
+    /// # Inputs
+    /// - `payload`: Data to blind
+    /// - `entropy`: Fresh per-payload randomness
+    pub fn encrypt(&self, payload: &[u8], entropy: &[u8; 32]) -> ThresholdCiphertext {
+        let mut nonce = hash_scalar(&[entropy, payload]);
+        let ephemeral = RistrettoPoint::mul_base(&nonce).compress().to_bytes();
+        let shared = (self.key * nonce).compress().to_bytes();
+        nonce.zeroize();
+
+        ThresholdCiphertext {
+            ephemeral,
+            ciphertext: apply_keystream(&shared, &ephemeral, payload),
+            commitment: Sha3_256::digest(payload).into(),
+        }
+    }
+
+    /// Verify one member's partial decryption
+    pub fn verify_partial(
+        &self,
+        ciphertext: &ThresholdCiphertext,
+        partial: &PartialDecryption,
+    ) -> Result<(), ThresholdError> {
+        let ephemeral = check_element(&ciphertext.ephemeral).ok_or(ThresholdError::InvalidCiphertext)?;
+        let member = self
+            .members
+            .iter()
+            .find(|m| m.index == partial.index && m.member_id == partial.member_id)
+            .ok_or(ThresholdError::UnknownMember)?;
+        let share = check_element(&partial.share).ok_or(ThresholdError::InvalidProof)?;
+        let challenge = canonical_scalar(&partial.challenge).ok_or(ThresholdError::InvalidProof)?;
+        let response = canonical_scalar(&partial.response).ok_or(ThresholdError::InvalidProof)?;
+
+        // z*G + e*h_i and z*C1 + e*d_i recover both proof commitments
+        let a1 = RistrettoPoint::mul_base(&response) + member.verification_key * challenge;
+        let a2 = ephemeral * response + share * challenge;
+        if proof_challenge(member, &ciphertext.ephemeral, &partial.share, &a1, &a2) != challenge {
+            return Err(ThresholdError::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Combine verified partials and decrypt
+    ///
+    /// ## Lifecycle Stage: Outcome Commitment (client-side)
+    ///
+    /// # Outputs
+    /// - Plaintext matching `ciphertext.commitment`
+    ///
+    /// ## Security Rationale
+    /// - Every partial is verified; the first `threshold` are interpolated
+    /// - Only the shared point is recovered, never the quorum key
+    pub fn combine(
+        &self,
+        ciphertext: &ThresholdCiphertext,
+        partials: &[PartialDecryption],
+    ) -> Result<Vec<u8>, ThresholdError> {
+        for (i, partial) in partials.iter().enumerate() {
+            if partials[..i].iter().any(|p| p.index == partial.index) {
+                return Err(ThresholdError::DuplicateShare);
+            }
+            self.verify_partial(ciphertext, partial)?;
+        }
+        if partials.len() < self.threshold as usize {
+            return Err(ThresholdError::InsufficientShares);
+        }
+
+        let used = &partials[..self.threshold as usize];
+        let mut shared = RistrettoPoint::default();
+        for partial in used {
+            let share = check_element(&partial.share).ok_or(ThresholdError::InvalidProof)?;
+            shared += share * lagrange_at_zero(partial.index, used);
+        }
+
+        let plaintext = apply_keystream(&shared.compress().to_bytes(), &ciphertext.ephemeral, &ciphertext.ciphertext);
+        let commitment: [u8; 32] = Sha3_256::digest(&plaintext).into();
+        if commitment != ciphertext.commitment {
+            return Err(ThresholdError::CommitmentMismatch);
+        }
+        Ok(plaintext)
+    }
+}
+
+impl KeyShare {
+    /// Combine the shares this member received into its key share
+    ///
+    /// ## Lifecycle Stage: Quorum Convergence
+    ///
+    /// # Inputs
+    /// - `public`: Key assembled by [`ThresholdPublicKey::from_dkg`]
+    /// - `commitments`: The same broadcasts `public` was assembled from
+    /// - `member_id`: This member
+    /// - `shares`: One share from each dealer, addressed to this member
+    ///
+    /// # Outputs
+    /// - `Err(InvalidDealing(i))` if dealer `i`'s share is missing,
+    ///   duplicated, misaddressed, or does not match its commitments
+    /// - `Err(InvalidDealing(0))` if `commitments` are not the broadcasts
+    ///   `public` was assembled from
+    pub fn from_dkg(
+        public: &ThresholdPublicKey,
+        commitments: &[DkgCommitment],
+        member_id: &[u8; 32],
+        shares: &[DkgShare],
+    ) -> Result<Self, ThresholdError> {
+        let member = public.members.iter().find(|m| m.member_id == *member_id).ok_or(ThresholdError::UnknownMember)?;
+
+        if let Some(extra) = shares
+            .iter()
+            .find(|s| s.recipient != member.index || !commitments.iter().any(|c| c.index == s.dealer))
+        {
+            return Err(ThresholdError::InvalidDealing(extra.dealer));
+        }
+
+        let mut secret = Scalar::ZERO;
+        for dealing in commitments {
+            let mut received = shares.iter().filter(|s| s.dealer == dealing.index && s.recipient == member.index);
+            let share = match (received.next(), received.next()) {
+                (Some(share), None) => share,
+                _ => return Err(ThresholdError::InvalidDealing(dealing.index)),
+            };
+            let points = verify_dealing(dealing, public.threshold)?;
+            if RistrettoPoint::mul_base(&share.value) != evaluate_points(&points, member.index) {
+                return Err(ThresholdError::InvalidDealing(dealing.index));
+            }
+            secret += share.value;
+        }
+        if RistrettoPoint::mul_base(&secret) != member.verification_key {
+            secret.zeroize();
+            return Err(ThresholdError::InvalidDealing(0));
+        }
+
+        Ok(Self { index: member.index, member_id: *member_id, secret })
+    }
+
+    /// Public verification key for this share
+    pub fn verification_key(&self) -> RistrettoPoint {
+        RistrettoPoint::mul_base(&self.secret)
+    }
+
+    /// Produce this member's proven partial decryption
+    ///
+    /// ## Lifecycle Stage: Outcome Commitment
+    ///
+    /// # Outputs
+    /// - `Err(InvalidCiphertext)` if the ephemeral key is invalid or the identity
+    pub fn partial_decrypt(&self, ciphertext: &ThresholdCiphertext) -> Result<PartialDecryption, ThresholdError> {
+        let ephemeral = check_element(&ciphertext.ephemeral).ok_or(ThresholdError::InvalidCiphertext)?;
+        let share = (ephemeral * self.secret).compress().to_bytes();
+
+        // Deterministic nonce bound to the share and ciphertext
+        let mut nonce = hash_scalar(&[self.secret.as_bytes(), &ciphertext.ephemeral, &ciphertext.commitment]);
+        let a1 = RistrettoPoint::mul_base(&nonce);
+        let a2 = ephemeral * nonce;
+        let member = MemberKey {
+            index: self.index,
+            member_id: self.member_id,
+            verification_key: self.verification_key(),
+        };
+        let challenge = proof_challenge(&member, &ciphertext.ephemeral, &share, &a1, &a2);
+        // z = k - e*x_i
+        let response = nonce - challenge * self.secret;
+        nonce.zeroize();
+
+        Ok(PartialDecryption {
+            index: self.index,
+            member_id: self.member_id,
+            share,
+            challenge: challenge.to_bytes(),
+            response: response.to_bytes(),
+        })
+    }
+}
+
+/// Build the `ThresholdReveal` audit TXO
+///
+/// # Inputs
+/// - `commitment`: Commitment of the revealed payload
+/// - `partials`: Partials used for the reveal
+/// - `timestamp`: Reveal time (milliseconds)
+///
+/// # Outputs
+/// - TXO; payload is the commitment, member count (u32 LE), then each
+///   contributing member id
+pub fn reveal_audit_txo(commitment: [u8; 32], partials: &[PartialDecryption], timestamp: u64) -> Txo {
+    let mut payload = Vec::with_capacity(36 + 32 * partials.len());
+    payload.extend_from_slice(&commitment);
+    payload.extend_from_slice(&(partials.len() as u32).to_le_bytes());
+    for partial in partials {
+        payload.extend_from_slice(&partial.member_id);
+    }
+    Txo::new(TxoType::ThresholdReveal, timestamp, payload, Vec::new())
+}
+
+fn check_threshold(threshold: u8, members: usize) -> Result<(), ThresholdError> {
+    if threshold == 0 || members > u8::MAX as usize || threshold as usize > members {
+        return Err(ThresholdError::InvalidThreshold);
+    }
+    Ok(())
+}
+
+/// Decompress a dealer's commitments and check its proof of knowledge
+fn verify_dealing(dealing: &DkgCommitment, threshold: u8) -> Result<Vec<RistrettoPoint>, ThresholdError> {
+    let invalid = ThresholdError::InvalidDealing(dealing.index);
+    if dealing.coefficients.len() != threshold as usize {
+        return Err(invalid);
+    }
+    let points = dealing
+        .coefficients
+        .iter()
+        .map(|c| CompressedRistretto(*c).decompress())
+        .collect::<Option<Vec<_>>>()
+        .ok_or(invalid)?;
+    let proof_commitment = CompressedRistretto(dealing.proof_commitment).decompress().ok_or(invalid)?;
+    let response = canonical_scalar(&dealing.proof_response).ok_or(invalid)?;
+
+    let challenge = dealing_challenge(dealing.index, &dealing.coefficients[0], &dealing.proof_commitment);
+    if RistrettoPoint::mul_base(&response) != proof_commitment + points[0] * challenge {
+        return Err(invalid);
+    }
+    Ok(points)
+}
+
+/// Schnorr challenge for a dealer's constant-term proof
+fn dealing_challenge(index: u8, constant: &[u8; 32], proof_commitment: &[u8; 32]) -> Scalar {
+    hash_scalar(&[DKG_PROOF_DOMAIN, &[index], constant, proof_commitment])
+}
+
+/// Horner evaluation of a polynomial at `x`
+fn evaluate(coefficients: &[Scalar], x: u8) -> Scalar {
+    let x = Scalar::from(x as u64);
+    coefficients.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c)
+}
+
+/// Horner evaluation of a committed polynomial at `x`
+fn evaluate_points(commitments: &[RistrettoPoint], x: u8) -> RistrettoPoint {
+    let x = Scalar::from(x as u64);
+    commitments.iter().rev().fold(RistrettoPoint::default(), |acc, c| acc * x + c)
+}
+
+/// Lagrange coefficient for `index` at x = 0 over the indices in `used`
+fn lagrange_at_zero(index: u8, used: &[PartialDecryption]) -> Scalar {
+    let (num, den) = used.iter().filter(|p| p.index != index).fold((Scalar::ONE, Scalar::ONE), |(num, den), p| {
+        let j = Scalar::from(p.index as u64);
+        (num * j, den * (j - Scalar::from(index as u64)))
+    });
+    num * den.invert()
+}
+
+/// Chaum-Pedersen challenge over the statement and commitments
+fn proof_challenge(
+    member: &MemberKey,
+    ephemeral: &[u8; 32],
+    share: &[u8; 32],
+    a1: &RistrettoPoint,
+    a2: &RistrettoPoint,
+) -> Scalar {
+    hash_scalar(&[
+        PROOF_DOMAIN,
+        &[member.index],
+        &member.member_id,
+        member.verification_key.compress().as_bytes(),
+        ephemeral,
+        share,
+        a1.compress().as_bytes(),
+        a2.compress().as_bytes(),
+    ])
+}
+
+/// XOR `data` with a SHA3-256 counter-mode keystream keyed by the shared point
+fn apply_keystream(shared: &[u8; 32], ephemeral: &[u8; 32], data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    for (counter, chunk) in data.chunks(32).enumerate() {
+        let mut hasher = Sha3_256::new();
+        hasher.update(KEYSTREAM_DOMAIN);
+        hasher.update(shared);
+        hasher.update(ephemeral);
+        hasher.update((counter as u64).to_le_bytes());
+        let block: [u8; 32] = hasher.finalize().into();
+        out.extend(chunk.iter().zip(block.iter()).map(|(d, k)| d ^ k));
+    }
+    out
+}
+
+/// Decompress a point, rejecting non-canonical encodings and the identity
+fn check_element(bytes: &[u8; 32]) -> Option<RistrettoPoint> {
+    CompressedRistretto(*bytes).decompress().filter(|point| *point != RistrettoPoint::default())
+}
+
+fn canonical_scalar(bytes: &[u8; 32]) -> Option<Scalar> {
+    Scalar::from_canonical_bytes(*bytes).into()
+}
+
+/// Domain-separated hash to a scalar, wide-reduced from SHA3-512
+fn hash_scalar(parts: &[&[u8]]) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(SCALAR_DOMAIN);
+    for part in parts {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part);
+    }
+    let mut wide: [u8; 64] = hasher.finalize().into();
+    let scalar = Scalar::from_bytes_mod_order_wide(&wide);
+    wide.zeroize();
+    scalar
+}
+
+/// Run a complete in-process DKG ceremony for tests
+#[cfg(test)]
+pub(crate) fn run_dkg(seed: u8, threshold: u8, members: &[[u8; 32]]) -> (ThresholdPublicKey, Vec<KeyShare>) {
+    let dealers: Vec<DkgDealer> = members
+        .iter()
+        .map(|id| DkgDealer::new(&[seed; 32], threshold, members, id).unwrap())
+        .collect();
+    let commitments: Vec<DkgCommitment> = dealers.iter().map(DkgDealer::commitment).collect();
+    let sent: Vec<DkgShare> = dealers.iter().flat_map(DkgDealer::shares).collect();
+
+    let public = ThresholdPublicKey::from_dkg(threshold, members, &commitments).unwrap();
+    let shares = members
+        .iter()
+        .enumerate()
+        .map(|(position, id)| {
+            let mine: Vec<DkgShare> = sent.iter().filter(|s| s.recipient as usize == position + 1).cloned().collect();
+            KeyShare::from_dkg(&public, &commitments, id, &mine).unwrap()
+        })
+        .collect();
+    (public, shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members(n: u8) -> Vec<[u8; 32]> {
+        (1..=n).map(|i| [i; 32]).collect()
+    }
+
+    #[test]
+    fn test_any_m_of_n_partials_decrypt() {
+        let (public, shares) = run_dkg(7, 3, &members(5));
+        for (share, key) in shares.iter().zip(&public.members) {
+            assert_eq!(share.verification_key(), key.verification_key);
+        }
+
+        let payload = b"variant calls for cohort 12, longer than one keystream block";
+        let ciphertext = public.encrypt(payload, &[9u8; 32]);
+        assert_ne!(&ciphertext.ciphertext[..], &payload[..]);
+
+        let partials: Vec<PartialDecryption> =
+            shares.iter().map(|s| s.partial_decrypt(&ciphertext).unwrap()).collect();
+        for subset in [[0, 1, 2], [4, 2, 0], [1, 3, 4]] {
+            let chosen: Vec<PartialDecryption> = subset.iter().map(|&i| partials[i]).collect();
+            assert_eq!(public.combine(&ciphertext, &chosen).unwrap(), payload.to_vec());
+        }
+
+        assert_eq!(
+            public.combine(&ciphertext, &partials[..2]),
+            Err(ThresholdError::InsufficientShares)
+        );
+        assert_eq!(
+            public.combine(&ciphertext, &[partials[0], partials[1], partials[0]]),
+            Err(ThresholdError::DuplicateShare)
+        );
+    }
+
+    #[test]
+    fn test_dkg_has_no_dealer() {
+        let ids = members(3);
+        let dealers: Vec<DkgDealer> =
+            ids.iter().map(|id| DkgDealer::new(&[4u8; 32], 2, &ids, id).unwrap()).collect();
+        let commitments: Vec<DkgCommitment> = dealers.iter().map(DkgDealer::commitment).collect();
+        let public = ThresholdPublicKey::from_dkg(2, &ids, &commitments).unwrap();
+
+        // The quorum key is the sum of every dealer's constant term
+        let sum: RistrettoPoint = commitments
+            .iter()
+            .map(|c| CompressedRistretto(c.coefficients[0]).decompress().unwrap())
+            .sum();
+        assert_eq!(public.key, sum);
+        for dealing in &commitments {
+            assert_ne!(public.key.compress().to_bytes(), dealing.coefficients[0]);
+        }
+
+        // Every member arrives at the same key from its own shares
+        let sent: Vec<DkgShare> = dealers.iter().flat_map(DkgDealer::shares).collect();
+        for (position, id) in ids.iter().enumerate() {
+            let mine: Vec<DkgShare> = sent.iter().filter(|s| s.recipient as usize == position + 1).cloned().collect();
+            let share = KeyShare::from_dkg(&public, &commitments, id, &mine).unwrap();
+            assert_eq!(share.verification_key(), public.members[position].verification_key);
+        }
+    }
+
+    #[test]
+    fn test_dkg_rejects_faulty_dealer() {
+        let ids = members(3);
+        let dealers: Vec<DkgDealer> =
+            ids.iter().map(|id| DkgDealer::new(&[8u8; 32], 2, &ids, id).unwrap()).collect();
+        let commitments: Vec<DkgCommitment> = dealers.iter().map(DkgDealer::commitment).collect();
+        let public = ThresholdPublicKey::from_dkg(2, &ids, &commitments).unwrap();
+
+        // A share that does not match its dealer's commitments names the dealer
+        let mine: Vec<DkgShare> = dealers.iter().map(|d| d.shares()[0].clone()).collect();
+        let mut tampered = mine.clone();
+        tampered[1].value += Scalar::ONE;
+        assert_eq!(
+            KeyShare::from_dkg(&public, &commitments, &ids[0], &tampered).err(),
+            Some(ThresholdError::InvalidDealing(2))
+        );
+        assert_eq!(
+            KeyShare::from_dkg(&public, &commitments, &ids[0], &mine[..2]).err(),
+            Some(ThresholdError::InvalidDealing(3))
+        );
+        assert_eq!(
+            KeyShare::from_dkg(&public, &commitments, &ids[1], &mine).err(),
+            Some(ThresholdError::InvalidDealing(1))
+        );
+
+        // A dealer cannot substitute a constant term it does not know
+        let mut rogue = commitments.clone();
+        rogue[2].coefficients[0] = RistrettoPoint::mul_base(&Scalar::from(5u64)).compress().to_bytes();
+        assert_eq!(
+            ThresholdPublicKey::from_dkg(2, &ids, &rogue).err(),
+            Some(ThresholdError::InvalidDealing(3))
+        );
+
+        let mut short = commitments.clone();
+        short[0].coefficients.pop();
+        assert_eq!(ThresholdPublicKey::from_dkg(2, &ids, &short).err(), Some(ThresholdError::InvalidDealing(1)));
+        assert_eq!(
+            ThresholdPublicKey::from_dkg(2, &ids, &commitments[..2]).err(),
+            Some(ThresholdError::InvalidDealing(3))
+        );
+
+        assert_eq!(
+            DkgDealer::new(&[0u8; 32], 4, &ids, &ids[0]).err(),
+            Some(ThresholdError::InvalidThreshold)
+        );
+        assert_eq!(
+            DkgDealer::new(&[0u8; 32], 2, &ids, &[9u8; 32]).err(),
+            Some(ThresholdError::UnknownMember)
+        );
+    }
+
+    #[test]
+    fn test_faulty_partials_rejected() {
+        let (public, shares) = run_dkg(7, 2, &members(3));
+        let ciphertext = public.encrypt(b"secret", &[1u8; 32]);
+        let honest = shares[0].partial_decrypt(&ciphertext).unwrap();
+        assert_eq!(public.verify_partial(&ciphertext, &honest), Ok(()));
+
+        // A wrong decryption share cannot carry a valid proof
+        let mut forged = honest;
+        let shifted = CompressedRistretto(forged.share).decompress().unwrap() + RistrettoPoint::mul_base(&Scalar::ONE);
+        forged.share = shifted.compress().to_bytes();
+        assert_eq!(public.verify_partial(&ciphertext, &forged), Err(ThresholdError::InvalidProof));
+
+        // Nor can a member claim another member's index
+        let mut impostor = shares[1].partial_decrypt(&ciphertext).unwrap();
+        impostor.member_id = [9u8; 32];
+        assert_eq!(public.verify_partial(&ciphertext, &impostor), Err(ThresholdError::UnknownMember));
+
+        // Proofs are bound to the ciphertext
+        let other = public.encrypt(b"other", &[2u8; 32]);
+        assert_eq!(public.verify_partial(&other, &honest), Err(ThresholdError::InvalidProof));
+
+        let mut bad = ciphertext.clone();
+        bad.ephemeral = RistrettoPoint::default().compress().to_bytes();
+        assert_eq!(shares[0].partial_decrypt(&bad).err(), Some(ThresholdError::InvalidCiphertext));
+        bad.ephemeral = [0xff; 32];
+        assert_eq!(public.verify_partial(&bad, &honest), Err(ThresholdError::InvalidCiphertext));
+
+        let mut tampered = ciphertext.clone();
+        tampered.ciphertext[0] ^= 1;
+        let partials = [honest, shares[2].partial_decrypt(&tampered).unwrap()];
+        assert_eq!(public.combine(&tampered, &partials), Err(ThresholdError::CommitmentMismatch));
+    }
+
+    #[test]
+    fn test_reveal_audit_txo_lists_contributors() {
+        let (public, shares) = run_dkg(3, 2, &members(3));
+        let ciphertext = public.encrypt(b"payload", &[4u8; 32]);
+        let partials = [
+            shares[2].partial_decrypt(&ciphertext).unwrap(),
+            shares[0].partial_decrypt(&ciphertext).unwrap(),
+        ];
+
+        let txo = reveal_audit_txo(ciphertext.commitment, &partials, 42);
+        assert_eq!(txo.txo_type, TxoType::ThresholdReveal);
+        assert_eq!(&txo.payload[..32], &ciphertext.commitment);
+        assert_eq!(&txo.payload[32..36], &2u32.to_le_bytes());
+        assert_eq!(&txo.payload[36..68], &[3u8; 32]);
+        assert_eq!(&txo.payload[68..], &[1u8; 32]);
+    }
+}
@@ -38,8 +38,14 @@ use alloc::vec::Vec;
 use alloc::vec;
 use alloc::collections::BTreeMap;
 
+use crate::handshake::{self, HandshakeError, Hello, Negotiated};
+use crate::p2p::PeerID;
+use crate::txo::{Txo, TxoType};
+
+use minicbor::{Encode, Decode};
+
 /// Communication channel type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Encode, Decode)]
 pub enum Channel {
     /// Standard TCP/IP (clearnet)
     ///
@@ -48,6 +54,7 @@ pub enum Channel {
     /// - No built-in anonymity
     /// - Easily censored
     /// - Standard internet protocol
+    #[n(0)]
     Tcp,
     
     /// Tor (The Onion Router)
@@ -57,6 +64,7 @@ pub enum Channel {
     /// - Higher latency than TCP
     /// - Censorship-resistant
     /// - Hidden service support
+    #[n(1)]
     Tor,
     
     /// I2P (Invisible Internet Project)
@@ -66,6 +74,7 @@ pub enum Channel {
     /// - Designed for hidden services
     /// - Fully decentralized
     /// - Censorship-resistant
+    #[n(2)]
     I2p,
     
     /// Offline (sneakernet, QR codes, etc.)
@@ -75,6 +84,7 @@ pub enum Channel {
     /// - Highest security for sensitive environments
     /// - Manual message transfer
     /// - Immune to network-based attacks
+    #[n(3)]
     Offline,
 }
 
@@ -105,6 +115,57 @@ impl Channel {
     }
 }
 
+/// How a channel reaches its peer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cbor(index_only)]
+pub enum PathType {
+    /// Peer accepts inbound connections
+    #[n(0)]
+    Direct,
+    /// NAT traversed by coordinated hole punching
+    #[n(1)]
+    HolePunched,
+    /// Forwarded by a relay peer (end-to-end encrypted)
+    #[n(2)]
+    Relayed,
+}
+
+/// Route to a peer over one channel
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct PathInfo {
+    /// Remote peer
+    #[n(0)]
+    pub peer: PeerID,
+
+    /// Channel carrying the path
+    #[n(1)]
+    pub channel: Channel,
+
+    /// Path type
+    #[n(2)]
+    pub path: PathType,
+
+    /// Relay forwarding the traffic (`Relayed` only)
+    #[n(3)]
+    pub relay: Option<PeerID>,
+
+    /// Measured round-trip time (milliseconds)
+    #[n(4)]
+    pub rtt_ms: Option<u64>,
+}
+
+impl PathInfo {
+    /// Expected latency: measured RTT, else the channel's typical latency
+    /// (doubled for relayed paths, which cross two hops)
+    pub fn latency_ms(&self) -> u64 {
+        match (self.rtt_ms, self.path) {
+            (Some(rtt), _) => rtt,
+            (None, PathType::Relayed) => self.channel.typical_latency_ms().saturating_mul(2),
+            (None, _) => self.channel.typical_latency_ms(),
+        }
+    }
+}
+
 /// Channel status
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChannelStatus {
@@ -140,6 +201,12 @@ pub struct CensorshipResistance {
     
     /// Current active channel
     pub active_channel: Option<Channel>,
+    
+    /// Parameters negotiated on the active channel
+    pub session: Option<Negotiated>,
+    
+    /// Known paths per peer, for latency-aware routing
+    pub paths: BTreeMap<PeerID, Vec<PathInfo>>,
 }
 
 impl CensorshipResistance {
@@ -164,9 +231,58 @@ impl CensorshipResistance {
             channel_usage,
             channel_failures,
             active_channel: None,
+            session: None,
+            paths: BTreeMap::new(),
         }
     }
     
+    /// Establish a session: send our hello and negotiate with the peer's
+    ///
+    /// ## Inputs
+    /// - `local`: This node's hello
+    /// - `remote_hello`: CBOR hello received from the peer
+    /// - `timestamp`: Establishment time (milliseconds)
+    ///
+    /// ## Returns
+    /// - Channel used, negotiated parameters, and the `SessionEstablished`
+    ///   audit TXO for the caller to commit
+    /// - `HandshakeError::ChannelUnavailable` if no channel can carry the hello
+    ///
+    /// ## Security
+    /// - A failed negotiation clears any previous session on this manager
+    ///
+    /// ## Audit Trail
+    /// - Payload: channel index (u8), negotiated major, minor, patch and TXO
+    ///   schema (u32 LE each), features (u64 LE), peer node id
+    pub fn establish(
+        &mut self,
+        local: &Hello,
+        remote_hello: &[u8],
+        timestamp: u64,
+    ) -> Result<(Channel, Negotiated, Txo), HandshakeError> {
+        self.session = None;
+        if !self.send_message(&local.to_cbor()) {
+            return Err(HandshakeError::ChannelUnavailable);
+        }
+        let channel = self.active_channel.ok_or(HandshakeError::ChannelUnavailable)?;
+        
+        let remote = Hello::from_cbor(remote_hello)?;
+        let session = handshake::negotiate(local, &remote)?;
+        self.session = Some(session);
+        
+        let mut payload = Vec::with_capacity(57);
+        payload.push(channel as u8);
+        payload.extend_from_slice(&session.version.major.to_le_bytes());
+        payload.extend_from_slice(&session.version.minor.to_le_bytes());
+        payload.extend_from_slice(&session.version.patch.to_le_bytes());
+        payload.extend_from_slice(&session.txo_schema.to_le_bytes());
+        payload.extend_from_slice(&session.features.to_le_bytes());
+        payload.extend_from_slice(&remote.node_id);
+        let audit = Txo::new(TxoType::SessionEstablished, timestamp, payload, Vec::new());
+        
+        Ok((channel, session, audit))
+    }
+    
     /// Configure a channel
     ///
     /// ## Inputs
@@ -305,6 +421,32 @@ impl CensorshipResistance {
         None
     }
     
+    /// Record a path to a peer, replacing any previous path of the same
+    /// channel and type
+    pub fn record_path(&mut self, info: PathInfo) {
+        let paths = self.paths.entry(info.peer).or_default();
+        paths.retain(|p| !(p.channel == info.channel && p.path == info.path));
+        paths.push(info);
+    }
+    
+    /// Forget every path through `relay` (relay disconnected)
+    pub fn drop_relay(&mut self, relay: &PeerID) {
+        for paths in self.paths.values_mut() {
+            paths.retain(|p| p.relay.as_ref() != Some(relay));
+        }
+    }
+    
+    /// Lowest-latency path to `peer` over an active channel
+    ///
+    /// Direct and hole-punched paths win ties against relayed ones.
+    pub fn route(&self, peer: &PeerID) -> Option<&PathInfo> {
+        self.paths
+            .get(peer)?
+            .iter()
+            .filter(|p| self.channel_status.get(&p.channel) == Some(&ChannelStatus::Active))
+            .min_by_key(|p| (p.latency_ms(), p.path == PathType::Relayed))
+    }
+    
     /// Get channel statistics
     pub fn get_stats(&self) -> Vec<(Channel, u64, u64)> {
         self.channels
@@ -392,4 +534,48 @@ mod tests {
         let selected = cr.select_channel();
         assert_eq!(selected, Some(Channel::Tor));
     }
+    
+    #[test]
+    fn test_establish_session() {
+        let local = Hello::local([1u8; 32]);
+        let remote = Hello::local([2u8; 32]).to_cbor();
+        
+        let mut cr = CensorshipResistance::new(vec![Channel::Tcp, Channel::Tor]);
+        assert_eq!(cr.establish(&local, &remote, 7).err(), Some(HandshakeError::ChannelUnavailable));
+        
+        cr.configure_channel(Channel::Tor);
+        let (channel, session, audit) = cr.establish(&local, &remote, 7).unwrap();
+        assert_eq!(channel, Channel::Tor);
+        assert_eq!(cr.session, Some(session));
+        assert_eq!(audit.txo_type, TxoType::SessionEstablished);
+        assert_eq!(audit.timestamp, 7);
+        assert_eq!(audit.payload[0], Channel::Tor as u8);
+        assert_eq!(audit.payload[1..5], session.version.major.to_le_bytes());
+        assert_eq!(audit.payload[25..], [2u8; 32]);
+        
+        assert_eq!(cr.establish(&local, b"garbage", 7).err(), Some(HandshakeError::Malformed));
+        assert_eq!(cr.session, None);
+    }
+    
+    #[test]
+    fn test_latency_aware_routing() {
+        let path = |channel, path, relay, rtt_ms| PathInfo { peer: [1u8; 32], channel, path, relay, rtt_ms };
+        let mut cr = CensorshipResistance::new(vec![Channel::Tcp, Channel::Tor]);
+        cr.configure_channel(Channel::Tcp);
+        assert_eq!(cr.route(&[1u8; 32]), None);
+        
+        cr.record_path(path(Channel::Tcp, PathType::Relayed, Some([9u8; 32]), None));
+        cr.record_path(path(Channel::Tor, PathType::Direct, None, Some(10)));
+        assert_eq!(cr.route(&[1u8; 32]).unwrap().path, PathType::Relayed);
+        
+        // A measured hole-punched path beats the relay; Tor is not configured
+        cr.record_path(path(Channel::Tcp, PathType::HolePunched, None, Some(40)));
+        assert_eq!(cr.route(&[1u8; 32]).unwrap().path, PathType::HolePunched);
+        cr.record_path(path(Channel::Tcp, PathType::HolePunched, None, Some(500)));
+        assert_eq!(cr.paths[&[1u8; 32]].len(), 3);
+        assert_eq!(cr.route(&[1u8; 32]).unwrap().path, PathType::Relayed);
+        
+        cr.drop_relay(&[9u8; 32]);
+        assert_eq!(cr.route(&[1u8; 32]).unwrap().rtt_ms, Some(500));
+    }
 }
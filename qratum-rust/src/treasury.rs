@@ -0,0 +1,560 @@
+//! # Treasury Module - Governed Grant Disbursement
+//!
+//! ## Lifecycle Stage: Governance Execution
+//!
+//! Slashed stake and configured per-epoch emissions accumulate in a treasury
+//! balance that only governance can spend. A grant is a `TreasurySpending`
+//! proposal whose payload lists milestones; once the proposal has gone
+//! through the standard vote/timelock lifecycle and executed, the grant
+//! amount moves into escrow and each milestone is released separately.
+//!
+//! ## Architectural Role
+//!
+//! - **Inflows**: `collect_slashing` (from `ValidatorIncentives`) and
+//!   `accrue_emissions` (per elapsed epoch)
+//! - **Budget**: A single grant may escrow at most `max_grant_percent` of
+//!   the unallocated balance
+//! - **Escrow**: Milestones unlock after an epoch and on evidence matching
+//!   the committed deliverable hash; unreleased milestones past their
+//!   deadline return to the balance
+//! - **Disbursement**: Every release emits a signed `TreasurySpend` TXO,
+//!   chained per grant and appended to the ledger
+//!
+//! ## Inputs → Outputs
+//!
+//! - Input: executed `GovernanceProposal` + milestone evidence
+//! - Output: escrowed grant + signed spend TXOs on the `MerkleLedger`
+//!
+//! ## Security Rationale
+//!
+//! - Funds leave the balance only for proposals governance executed; vetoed
+//!   or pending proposals are rejected
+//! - Deliverables are committed at proposal time, so release cannot be
+//!   redirected to different evidence after the vote
+//! - Spend TXOs chain to the grant's previous disbursement, making every
+//!   release auditable from the ledger alone
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use minicbor::{Decode, Encode};
+use sha3::{Digest, Sha3_256};
+
+use crate::governance::{GovernanceProposal, GovernanceState, ProposalID, ProposalType};
+use crate::incentives::ValidatorIncentives;
+use crate::ledger::MerkleLedger;
+use crate::txo::{Txo, TxoType};
+use crate::watchdog::BeaconSigner;
+
+/// Payload tag for treasury grant proposals
+pub const TREASURY_GRANT_TAG: &[u8; 14] = b"TREASURY-GRANT";
+
+/// One escrowed tranche of a grant
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct Milestone {
+    /// Amount released when the milestone is met
+    #[n(0)]
+    pub amount: u64,
+
+    /// SHA3-256 of the deliverable evidence
+    #[n(1)]
+    pub deliverable: [u8; 32],
+
+    /// Earliest epoch the milestone can be released
+    #[n(2)]
+    pub unlock_epoch: u64,
+
+    /// Epoch after which the milestone can no longer be released
+    #[n(3)]
+    pub deadline_epoch: u64,
+}
+
+/// Grant terms carried in a `TreasurySpending` proposal payload
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct GrantTerms {
+    /// Recipient identifier
+    #[n(0)]
+    pub recipient: [u8; 32],
+
+    /// Milestones in release order
+    #[n(1)]
+    pub milestones: Vec<Milestone>,
+}
+
+impl GrantTerms {
+    /// Total amount across all milestones
+    pub fn total(&self) -> Option<u64> {
+        self.milestones
+            .iter()
+            .try_fold(0u64, |sum, m| sum.checked_add(m.amount))
+    }
+
+    /// Check internal consistency
+    pub fn validate(&self) -> Result<(), TreasuryError> {
+        let valid = !self.milestones.is_empty()
+            && self
+                .milestones
+                .iter()
+                .all(|m| m.amount > 0 && m.unlock_epoch <= m.deadline_epoch)
+            && self.total().is_some();
+        if !valid {
+            return Err(TreasuryError::InvalidGrant);
+        }
+        Ok(())
+    }
+
+    /// Payload for a `TreasurySpending` proposal carrying these terms
+    pub fn to_proposal_payload(&self) -> Vec<u8> {
+        let mut payload = Vec::from(&TREASURY_GRANT_TAG[..]);
+        payload.extend_from_slice(&minicbor::to_vec(self).unwrap_or_default());
+        payload
+    }
+
+    /// Decode terms from a treasury grant proposal
+    pub fn from_proposal(proposal: &GovernanceProposal) -> Result<Self, TreasuryError> {
+        if proposal.proposal_type != ProposalType::TreasurySpending {
+            return Err(TreasuryError::NotGrantProposal);
+        }
+        let body = proposal
+            .payload
+            .strip_prefix(&TREASURY_GRANT_TAG[..])
+            .ok_or(TreasuryError::NotGrantProposal)?;
+        let terms: Self = minicbor::decode(body).map_err(|_| TreasuryError::InvalidGrant)?;
+        terms.validate()?;
+        Ok(terms)
+    }
+}
+
+/// Payload of a `TreasurySpend` TXO
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct Disbursement {
+    /// Grant (proposal) identifier
+    #[n(0)]
+    pub grant: ProposalID,
+
+    /// Milestone index within the grant
+    #[n(1)]
+    pub milestone: u32,
+
+    /// Recipient identifier
+    #[n(2)]
+    pub recipient: [u8; 32],
+
+    /// Amount released
+    #[n(3)]
+    pub amount: u64,
+
+    /// Epoch of release
+    #[n(4)]
+    pub epoch: u64,
+}
+
+/// Funded grant and its escrow state
+#[derive(Debug, Clone)]
+pub struct Grant {
+    /// Terms from the approved proposal
+    pub terms: GrantTerms,
+
+    /// Release flag per milestone
+    pub released: Vec<bool>,
+
+    /// Milestones returned to the balance after their deadline
+    pub reclaimed: Vec<bool>,
+
+    /// Most recent spend TXO for this grant
+    pub last_spend: Option<[u8; 32]>,
+}
+
+impl Grant {
+    /// Amount still held in escrow
+    pub fn escrowed(&self) -> u64 {
+        self.terms
+            .milestones
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.released[*i] && !self.reclaimed[*i])
+            .map(|(_, m)| m.amount)
+            .sum()
+    }
+}
+
+/// Treasury failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreasuryError {
+    /// Proposal is not a treasury grant
+    NotGrantProposal,
+    /// Grant terms are empty, zero-valued, or overflow
+    InvalidGrant,
+    /// Proposal has not been executed by governance
+    NotApproved,
+    /// Grant was already funded
+    AlreadyFunded,
+    /// Grant exceeds the per-grant budget or the balance
+    OverBudget,
+    /// No funded grant with this identifier
+    UnknownGrant,
+    /// Milestone index out of range
+    UnknownMilestone,
+    /// Milestone already released or reclaimed
+    AlreadyReleased,
+    /// Milestone unlock epoch not reached
+    MilestoneLocked,
+    /// Milestone deadline has passed
+    MilestoneExpired,
+    /// Evidence does not hash to the committed deliverable
+    EvidenceMismatch,
+}
+
+/// Governed treasury
+///
+/// ## Security Invariants
+/// - `balance + escrowed == total_inflow - total_disbursed`
+/// - Only grants from executed `TreasurySpending` proposals are funded
+/// - Each milestone is released or reclaimed at most once
+pub struct Treasury {
+    /// Unallocated balance
+    pub balance: u64,
+
+    /// Amount held in grant escrow
+    pub escrowed: u64,
+
+    /// Total credited from slashing and emissions
+    pub total_inflow: u64,
+
+    /// Total paid out to grant recipients
+    pub total_disbursed: u64,
+
+    /// Emission credited per epoch
+    pub emission_per_epoch: u64,
+
+    /// Largest share of the balance a single grant may escrow (percent)
+    pub max_grant_percent: u8,
+
+    /// Funded grants
+    pub grants: BTreeMap<ProposalID, Grant>,
+
+    /// `ValidatorIncentives::total_slashed` already collected
+    slashed_collected: u64,
+
+    /// Last epoch emissions were accrued for
+    emission_epoch: u64,
+}
+
+impl Treasury {
+    /// Create an empty treasury
+    ///
+    /// # Inputs
+    /// - `emission_per_epoch`: Amount credited for each elapsed epoch
+    /// - `max_grant_percent`: Per-grant budget as a share of the balance (0-100)
+    pub fn new(emission_per_epoch: u64, max_grant_percent: u8) -> Self {
+        Self {
+            balance: 0,
+            escrowed: 0,
+            total_inflow: 0,
+            total_disbursed: 0,
+            emission_per_epoch,
+            max_grant_percent: max_grant_percent.min(100),
+            grants: BTreeMap::new(),
+            slashed_collected: 0,
+            emission_epoch: 0,
+        }
+    }
+
+    fn credit(&mut self, amount: u64) {
+        self.balance = self.balance.saturating_add(amount);
+        self.total_inflow = self.total_inflow.saturating_add(amount);
+    }
+
+    /// Credit stake slashed since the last collection
+    ///
+    /// # Outputs
+    /// - Amount credited
+    pub fn collect_slashing(&mut self, incentives: &ValidatorIncentives) -> u64 {
+        let amount = incentives.total_slashed.saturating_sub(self.slashed_collected);
+        self.slashed_collected = incentives.total_slashed;
+        self.credit(amount);
+        amount
+    }
+
+    /// Credit emissions for the epochs elapsed since the last accrual
+    ///
+    /// # Outputs
+    /// - Amount credited
+    pub fn accrue_emissions(&mut self, current_epoch: u64) -> u64 {
+        let epochs = current_epoch.saturating_sub(self.emission_epoch);
+        self.emission_epoch = self.emission_epoch.max(current_epoch);
+        let amount = epochs.saturating_mul(self.emission_per_epoch);
+        self.credit(amount);
+        amount
+    }
+
+    /// Largest grant that can currently be funded
+    pub fn grant_budget(&self) -> u64 {
+        ((self.balance as u128 * self.max_grant_percent as u128) / 100) as u64
+    }
+
+    /// Move an executed grant proposal's amount into escrow
+    ///
+    /// # Inputs
+    /// - `governance`: Governance state the proposal was executed in
+    /// - `proposal_id`: `TreasurySpending` proposal carrying `GrantTerms`
+    ///
+    /// # Outputs
+    /// - Escrowed amount
+    pub fn fund_grant(
+        &mut self,
+        governance: &GovernanceState,
+        proposal_id: ProposalID,
+    ) -> Result<u64, TreasuryError> {
+        let proposal = governance
+            .get_proposal(&proposal_id)
+            .ok_or(TreasuryError::NotApproved)?;
+        if !governance.executed.contains(&proposal_id) || governance.vetoed.contains(&proposal_id) {
+            return Err(TreasuryError::NotApproved);
+        }
+        if self.grants.contains_key(&proposal_id) {
+            return Err(TreasuryError::AlreadyFunded);
+        }
+
+        let terms = GrantTerms::from_proposal(proposal)?;
+        let total = terms.total().ok_or(TreasuryError::InvalidGrant)?;
+        if total > self.grant_budget() {
+            return Err(TreasuryError::OverBudget);
+        }
+
+        self.balance -= total;
+        self.escrowed += total;
+        let count = terms.milestones.len();
+        self.grants.insert(
+            proposal_id,
+            Grant {
+                terms,
+                released: alloc::vec![false; count],
+                reclaimed: alloc::vec![false; count],
+                last_spend: None,
+            },
+        );
+        Ok(total)
+    }
+
+    /// Release a milestone and record the spend on the ledger
+    ///
+    /// # Inputs
+    /// - `grant_id`: Funded grant
+    /// - `index`: Milestone index
+    /// - `evidence`: Deliverable whose SHA3-256 the milestone committed to
+    /// - `current_epoch`: Epoch of release
+    /// - `timestamp`: TXO timestamp (milliseconds)
+    /// - `signer`: Treasury signing backend
+    /// - `ledger`: Ledger the spend TXO is appended to
+    ///
+    /// # Outputs
+    /// - Signed `TreasurySpend` TXO, chained to the grant's previous spend
+    #[allow(clippy::too_many_arguments)]
+    pub fn release_milestone(
+        &mut self,
+        grant_id: ProposalID,
+        index: usize,
+        evidence: &[u8],
+        current_epoch: u64,
+        timestamp: u64,
+        signer: &dyn BeaconSigner,
+        ledger: &mut MerkleLedger,
+    ) -> Result<Txo, TreasuryError> {
+        let grant = self.grants.get_mut(&grant_id).ok_or(TreasuryError::UnknownGrant)?;
+        let milestone = grant
+            .terms
+            .milestones
+            .get(index)
+            .ok_or(TreasuryError::UnknownMilestone)?;
+        if grant.released[index] || grant.reclaimed[index] {
+            return Err(TreasuryError::AlreadyReleased);
+        }
+        if current_epoch < milestone.unlock_epoch {
+            return Err(TreasuryError::MilestoneLocked);
+        }
+        if current_epoch > milestone.deadline_epoch {
+            return Err(TreasuryError::MilestoneExpired);
+        }
+        let digest: [u8; 32] = Sha3_256::digest(evidence).into();
+        if digest != milestone.deliverable {
+            return Err(TreasuryError::EvidenceMismatch);
+        }
+
+        let disbursement = Disbursement {
+            grant: grant_id,
+            milestone: index as u32,
+            recipient: grant.terms.recipient,
+            amount: milestone.amount,
+            epoch: current_epoch,
+        };
+        let payload = minicbor::to_vec(&disbursement).unwrap_or_default();
+        let predecessors = grant.last_spend.into_iter().collect();
+        let mut txo = Txo::new(TxoType::TreasurySpend, timestamp, payload, predecessors);
+        txo.signatures.push(signer.sign(&txo.id));
+
+        grant.released[index] = true;
+        grant.last_spend = Some(txo.id);
+        self.escrowed -= milestone.amount;
+        self.total_disbursed += milestone.amount;
+        ledger.append(txo.clone());
+        Ok(txo)
+    }
+
+    /// Return escrow for milestones whose deadline has passed
+    ///
+    /// # Outputs
+    /// - Amount returned to the balance
+    pub fn reclaim_expired(&mut self, current_epoch: u64) -> u64 {
+        let mut reclaimed = 0u64;
+        for grant in self.grants.values_mut() {
+            for (i, milestone) in grant.terms.milestones.iter().enumerate() {
+                if !grant.released[i] && !grant.reclaimed[i] && current_epoch > milestone.deadline_epoch {
+                    grant.reclaimed[i] = true;
+                    reclaimed += milestone.amount;
+                }
+            }
+        }
+        self.escrowed -= reclaimed;
+        self.balance += reclaimed;
+        reclaimed
+    }
+
+    /// Get a funded grant
+    pub fn get_grant(&self, id: &ProposalID) -> Option<&Grant> {
+        self.grants.get(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{GovernanceVote, VoteDecision};
+    use crate::consensus::Violation;
+    use alloc::vec;
+
+    struct TestKey;
+
+    impl BeaconSigner for TestKey {
+        fn sign(&self, message: &[u8; 32]) -> [u8; 64] {
+            let mut sig = [0u8; 64];
+            sig[..32].copy_from_slice(message);
+            sig
+        }
+    }
+
+    fn approved_grant(terms: &GrantTerms, proposal_type: ProposalType) -> GovernanceState {
+        let mut governance = GovernanceState::new();
+        governance.total_voting_weight = 100;
+        governance.submit_proposal(GovernanceProposal {
+            id: [1u8; 32],
+            proposal_type,
+            proposer: [2u8; 32],
+            description: "Grant".into(),
+            payload: terms.to_proposal_payload(),
+            threshold: 67,
+            voting_period: 2,
+            timelock: 1,
+            creation_epoch: 0,
+        });
+        governance.vote([1u8; 32], GovernanceVote {
+            voter: [3u8; 32],
+            decision: VoteDecision::Approve,
+            weight: 80,
+            signature: [0u8; 64],
+            epoch: 0,
+        });
+        governance.current_epoch = 3;
+        governance.execute_proposal([1u8; 32]);
+        governance
+    }
+
+    fn milestone(amount: u64, evidence: &[u8], unlock_epoch: u64, deadline_epoch: u64) -> Milestone {
+        Milestone {
+            amount,
+            deliverable: Sha3_256::digest(evidence).into(),
+            unlock_epoch,
+            deadline_epoch,
+        }
+    }
+
+    #[test]
+    fn test_inflows_and_grant_lifecycle() {
+        let mut incentives = ValidatorIncentives::new(0, 0, 1000);
+        incentives.deposit_stake([7u8; 32], 5000, 0);
+        incentives.slash([7u8; 32], 2000, Violation::DoubleSigning);
+
+        let mut treasury = Treasury::new(500, 50);
+        assert_eq!(treasury.collect_slashing(&incentives), 2000);
+        assert_eq!(treasury.collect_slashing(&incentives), 0);
+        assert_eq!(treasury.accrue_emissions(4), 2000);
+        assert_eq!(treasury.balance, 4000);
+
+        let terms = GrantTerms {
+            recipient: [9u8; 32],
+            milestones: vec![milestone(1200, b"design", 3, 10), milestone(800, b"audit", 6, 8)],
+        };
+        let governance = approved_grant(&terms, ProposalType::TreasurySpending);
+        assert_eq!(treasury.fund_grant(&governance, [1u8; 32]), Ok(2000));
+        assert_eq!(treasury.fund_grant(&governance, [1u8; 32]), Err(TreasuryError::AlreadyFunded));
+        assert_eq!((treasury.balance, treasury.escrowed), (2000, 2000));
+
+        let mut ledger = MerkleLedger::new();
+        let key = TestKey;
+        assert_eq!(
+            treasury.release_milestone([1u8; 32], 0, b"wrong", 4, 0, &key, &mut ledger).err(),
+            Some(TreasuryError::EvidenceMismatch)
+        );
+        assert_eq!(
+            treasury.release_milestone([1u8; 32], 1, b"audit", 4, 0, &key, &mut ledger).err(),
+            Some(TreasuryError::MilestoneLocked)
+        );
+
+        let spend = treasury.release_milestone([1u8; 32], 0, b"design", 4, 0, &key, &mut ledger).unwrap();
+        assert_eq!(spend.txo_type, TxoType::TreasurySpend);
+        assert_eq!(spend.signatures[0][..32], spend.id);
+        let paid: Disbursement = minicbor::decode(&spend.payload).unwrap();
+        assert_eq!((paid.recipient, paid.amount), ([9u8; 32], 1200));
+        assert_eq!(ledger.txo_count(), 1);
+        assert_eq!(
+            treasury.release_milestone([1u8; 32], 0, b"design", 5, 0, &key, &mut ledger).err(),
+            Some(TreasuryError::AlreadyReleased)
+        );
+
+        // The second milestone misses its deadline and returns to the balance
+        assert_eq!(treasury.reclaim_expired(9), 800);
+        assert_eq!(
+            treasury.release_milestone([1u8; 32], 1, b"audit", 9, 0, &key, &mut ledger).err(),
+            Some(TreasuryError::AlreadyReleased)
+        );
+        assert_eq!((treasury.balance, treasury.escrowed, treasury.total_disbursed), (2800, 0, 1200));
+        assert_eq!(treasury.balance + treasury.escrowed, treasury.total_inflow - treasury.total_disbursed);
+    }
+
+    #[test]
+    fn test_grant_requires_execution_and_budget() {
+        let mut treasury = Treasury::new(1000, 25);
+        treasury.accrue_emissions(4);
+
+        let terms = GrantTerms {
+            recipient: [9u8; 32],
+            milestones: vec![milestone(1500, b"work", 0, 10)],
+        };
+
+        // Wrong proposal type
+        let governance = approved_grant(&terms, ProposalType::ParameterChange);
+        assert_eq!(treasury.fund_grant(&governance, [1u8; 32]), Err(TreasuryError::NotGrantProposal));
+
+        // Not yet executed
+        let mut governance = approved_grant(&terms, ProposalType::TreasurySpending);
+        governance.executed.clear();
+        assert_eq!(treasury.fund_grant(&governance, [1u8; 32]), Err(TreasuryError::NotApproved));
+
+        // 1500 exceeds 25% of 4000
+        let governance = approved_grant(&terms, ProposalType::TreasurySpending);
+        assert_eq!(treasury.fund_grant(&governance, [1u8; 32]), Err(TreasuryError::OverBudget));
+        treasury.max_grant_percent = 50;
+        assert_eq!(treasury.fund_grant(&governance, [1u8; 32]), Ok(1500));
+    }
+}
@@ -31,6 +31,7 @@ use alloc::string::String;
 use minicbor::{Encode, Decode};
 use sha3::{Sha3_256, Digest};
 use zeroize::{Zeroize, ZeroizeOnDrop};
+use crate::clock;
 
 /// TXO Type discriminator
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
@@ -42,6 +43,16 @@ pub enum TxoType {
     #[n(4)] CensorshipEvent, // Suppression/delay audit trail
     #[n(5)] ProxyApproval,   // Bonded proxy authorization
     #[n(6)] ComplianceAttestation, // ZKP regulatory compliance
+    #[n(7)] SessionAbort,    // Controlled abort on entropy health failure
+    #[n(8)] DeletionProof,   // Retention purge of a persistent artifact
+    #[n(9)] DegradedMode,    // Partial quorum continuation or recovery
+    #[n(10)] KeyUsage,       // Biokey usage summary for the session audit
+    #[n(11)] ThresholdReveal, // Blinded payload revealed by threshold decryption
+    #[n(12)] TreasurySpend,   // Governance-approved grant milestone disbursement
+    #[n(13)] KeyRotation,     // Scheduled biokey epoch rotation
+    #[n(14)] CosignCeremony,  // Validator signing ceremony transcript
+    #[n(15)] HealthDigest,    // Signed periodic node health summary
+    #[n(16)] SessionEstablished, // Transport session and negotiated protocol version
 }
 
 /// Blinded Payload Commitment
@@ -279,7 +290,7 @@ impl OutcomeTxo {
     ) -> Self {
         let txo = Txo::new(
             TxoType::Outcome,
-            current_timestamp(),
+            clock::now_ms(),
             payload,
             predecessors,
         );
@@ -292,26 +303,6 @@ impl OutcomeTxo {
     }
 }
 
-/// Get current timestamp (milliseconds since epoch)
-///
-/// ## Forward Compatibility
-/// TODO: Replace with deterministic time oracle for reproducibility
-fn current_timestamp() -> u64 {
-    // Placeholder: In production, use deterministic time from quorum
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        0 // Deterministic default for no_std
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
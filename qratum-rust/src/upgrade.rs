@@ -18,6 +18,8 @@
 //! - WASM provides sandboxed execution for migrations
 //! - Activation epoch coordinates network-wide upgrade
 //! - Rollback protection prevents downgrade attacks
+//! - With a release key configured, only upgrades carrying a valid LMS
+//!   signature (see [`crate::hbs`]) can be scheduled
 //!
 //! ## Audit Trail
 //!
@@ -32,6 +34,13 @@ use alloc::vec::Vec;
 use alloc::string::String;
 use alloc::collections::BTreeMap;
 
+use sha3::{Digest, Sha3_256};
+
+use crate::hbs::{self, LmsPublicKey, LmsSignature};
+
+/// Domain separator for upgrade signing bytes
+const UPGRADE_SIGNING_DOMAIN: &[u8] = b"QRATUM-UPGRADE-SIGNING-v1";
+
 /// Protocol version
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Version {
@@ -131,6 +140,25 @@ impl ProtocolUpgrade {
         }
     }
     
+    /// Bytes covered by the release signature
+    ///
+    /// ## Layout
+    /// Domain tag, id, target version (u32 LE each), activation epoch (u64 LE),
+    /// governance proposal id, SHA3-256 of the WASM migration, description
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(UPGRADE_SIGNING_DOMAIN.len() + 116 + self.description.len());
+        bytes.extend_from_slice(UPGRADE_SIGNING_DOMAIN);
+        bytes.extend_from_slice(&self.id);
+        bytes.extend_from_slice(&self.target.major.to_le_bytes());
+        bytes.extend_from_slice(&self.target.minor.to_le_bytes());
+        bytes.extend_from_slice(&self.target.patch.to_le_bytes());
+        bytes.extend_from_slice(&self.activation_epoch.to_le_bytes());
+        bytes.extend_from_slice(&self.governance_proposal_id);
+        bytes.extend_from_slice(&Sha3_256::digest(&self.wasm_migration));
+        bytes.extend_from_slice(self.description.as_bytes());
+        bytes
+    }
+    
     /// Check if upgrade is active at given epoch
     pub fn is_active(&self, current_epoch: u64) -> bool {
         current_epoch >= self.activation_epoch
@@ -156,6 +184,9 @@ pub struct UpgradeManager {
     
     /// Current epoch
     pub current_epoch: u64,
+    
+    /// Release signing key; when set, upgrades must be LMS-signed
+    pub release_key: Option<LmsPublicKey>,
 }
 
 impl UpgradeManager {
@@ -166,9 +197,16 @@ impl UpgradeManager {
             scheduled_upgrades: BTreeMap::new(),
             upgrade_history: Vec::new(),
             current_epoch: 0,
+            release_key: None,
         }
     }
     
+    /// Require LMS-signed upgrades under `release_key`
+    pub fn with_release_key(mut self, release_key: LmsPublicKey) -> Self {
+        self.release_key = Some(release_key);
+        self
+    }
+    
     /// Schedule a protocol upgrade
     ///
     /// ## Inputs
@@ -177,12 +215,34 @@ impl UpgradeManager {
     /// ## Returns
     /// - `true` if upgrade scheduled successfully
     /// - `false` if upgrade conflicts with existing schedule
+    /// - `false` if a release key is configured (use `schedule_signed_upgrade`)
     ///
     /// ## Security
     /// - Upgrade must be approved by governance
     /// - Activation epoch must be in the future
     /// - No conflicting upgrades at same epoch
     pub fn schedule_upgrade(&mut self, upgrade: ProtocolUpgrade) -> bool {
+        if self.release_key.is_some() {
+            return false; // Unsigned upgrades refused
+        }
+        self.insert_upgrade(upgrade)
+    }
+    
+    /// Schedule a protocol upgrade signed with the release key
+    ///
+    /// ## Returns
+    /// - `true` if the signature verifies and the upgrade was scheduled
+    /// - `false` if no release key is configured, the signature is invalid,
+    ///   or scheduling fails as in `schedule_upgrade`
+    pub fn schedule_signed_upgrade(&mut self, upgrade: ProtocolUpgrade, signature: &LmsSignature) -> bool {
+        let verified = self
+            .release_key
+            .as_ref()
+            .is_some_and(|key| hbs::verify(key, &upgrade.signing_bytes(), signature));
+        verified && self.insert_upgrade(upgrade)
+    }
+    
+    fn insert_upgrade(&mut self, upgrade: ProtocolUpgrade) -> bool {
         // Check if activation epoch is in the future
         if upgrade.activation_epoch <= self.current_epoch {
             return false; // Cannot schedule in the past
@@ -352,4 +412,41 @@ mod tests {
         let scheduled = manager.schedule_upgrade(upgrade);
         assert!(!scheduled);
     }
+    
+    #[test]
+    fn test_signed_upgrade_scheduling() {
+        struct Store(Option<u32>);
+        impl hbs::LmsStateStore for Store {
+            fn load(&mut self) -> Option<u32> {
+                self.0
+            }
+            fn persist(&mut self, next_q: u32) -> Result<(), hbs::HbsError> {
+                self.0 = Some(next_q);
+                Ok(())
+            }
+        }
+        
+        let mut signer = hbs::LmsSigner::generate(hbs::LmsHeight::H5, [3u8; 32], [4u8; 16], Store(None)).unwrap();
+        let mut manager = UpgradeManager::new(CURRENT_VERSION).with_release_key(signer.public_key());
+        let upgrade = ProtocolUpgrade::new(
+            [1u8; 32],
+            Version::new(1, 1, 0),
+            vec![0u8; 100],
+            10,
+            [2u8; 32],
+            "Signed upgrade".into(),
+        );
+        let signature = signer.sign(&upgrade.signing_bytes()).unwrap();
+        
+        // Unsigned scheduling is refused once a release key is set
+        assert!(!manager.schedule_upgrade(upgrade.clone()));
+        
+        // A tampered migration no longer matches the signature
+        let mut tampered = upgrade.clone();
+        tampered.wasm_migration[0] = 1;
+        assert!(!manager.schedule_signed_upgrade(tampered, &signature));
+        
+        assert!(manager.schedule_signed_upgrade(upgrade, &signature));
+        assert_eq!(manager.get_scheduled_upgrades().len(), 1);
+    }
 }
@@ -12,6 +12,8 @@
 //! - **Audit Validation**: Independent verification of execution integrity
 //! - **Attestation Emission**: Signed statements of correctness
 //! - **Byzantine Tolerance**: Detects and reports misbehavior
+//! - **Epoch Beacons**: Each epoch's set is published in a beacon signed by the
+//!   previous set, so peers can follow rotation without a directory service
 //!
 //! ## Security Rationale
 //!
@@ -24,9 +26,14 @@
 extern crate alloc;
 use alloc::vec::Vec;
 
+use minicbor::{Encode, Decode};
 use sha3::{Sha3_256, Digest};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+use crate::transport::{Channel, CensorshipResistance};
+use crate::clock;
+use crate::epoch::EpochBoundary;
+
 /// Watchdog Configuration
 #[derive(Debug, Clone)]
 pub struct WatchdogConfig {
@@ -138,7 +145,7 @@ impl WatchdogManager {
             config,
             validators,
             current_epoch: 0,
-            epoch_start: current_timestamp(),
+            epoch_start: clock::now_ms(),
             active_validators: Vec::new(),
             attestations: Vec::new(),
         };
@@ -149,7 +156,7 @@ impl WatchdogManager {
     
     /// Check if epoch rotation due
     pub fn rotation_due(&self) -> bool {
-        let current_time = current_timestamp();
+        let current_time = clock::now_ms();
         current_time - self.epoch_start >= self.config.epoch_duration_ms
     }
     
@@ -193,7 +200,7 @@ impl WatchdogManager {
         }
         
         self.current_epoch += 1;
-        self.epoch_start = current_timestamp();
+        self.epoch_start = clock::now_ms();
     }
     
     /// Submit audit attestation
@@ -228,27 +235,338 @@ impl WatchdogManager {
             .filter(|a| a.epoch == self.current_epoch)
             .count()
     }
+    
+    /// Time the next rotation from a committed epoch boundary
+    ///
+    /// Keeps fixed-length rotation unless an adaptive schedule is followed.
+    pub fn apply_epoch_boundary(&mut self, boundary: &EpochBoundary) {
+        self.config.epoch_duration_ms = boundary.duration_ms;
+    }
+
+    /// Current epoch
+    pub fn current_epoch(&self) -> u64 {
+        self.current_epoch
+    }
+    
+    /// Collected attestations
+    pub fn attestations(&self) -> &[AuditAttestation] {
+        &self.attestations
+    }
+    
+    /// Build the (unsigned) beacon for the current epoch's active set
+    ///
+    /// ## Lifecycle Stage: Execution (epoch rotation)
+    ///
+    /// # Inputs
+    /// - `hints`: Contact hints per validator ID (validators without hints are announced without them)
+    /// - `previous`: Previous epoch's beacon, if any
+    ///
+    /// # Outputs
+    /// - Beacon chained to `previous` and committing to the previous epoch's
+    ///   attestations; the outgoing set signs it with `add_signature`
+    pub fn build_beacon(
+        &self,
+        hints: &[([u8; 32], ContactHint)],
+        previous: Option<&WatchdogBeacon>,
+    ) -> WatchdogBeacon {
+        let validators = self.active_validators()
+            .into_iter()
+            .map(|v| BeaconEntry {
+                validator_id: v.id,
+                public_key: v.public_key,
+                hints: hints.iter()
+                    .filter(|(id, _)| *id == v.id)
+                    .map(|(_, hint)| hint.clone())
+                    .collect(),
+            })
+            .collect();
+        
+        let previous_epoch = self.current_epoch.saturating_sub(1);
+        
+        WatchdogBeacon {
+            epoch: self.current_epoch,
+            validators,
+            prev_beacon_hash: previous.map(|b| b.beacon_hash()).unwrap_or([0u8; 32]),
+            prev_attestation_root: attestation_root(&self.attestations, previous_epoch),
+            signatures: Vec::new(),
+        }
+    }
+}
+
+/// Contact Hint
+///
+/// ## Lifecycle Stage: Network Infrastructure
+///
+/// Channel-specific address where a validator can be reached (e.g. onion
+/// address, I2P destination, offline drop identifier).
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct ContactHint {
+    /// Transport channel
+    #[n(0)]
+    pub channel: Channel,
+    
+    /// Opaque channel address
+    #[n(1)]
+    pub address: Vec<u8>,
+}
+
+/// Beacon Entry
+///
+/// One validator in an epoch's watchdog set.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct BeaconEntry {
+    /// Validator ID
+    #[n(0)]
+    pub validator_id: [u8; 32],
+    
+    /// Validator public key (verifies the next epoch's beacon)
+    #[n(1)]
+    pub public_key: [u8; 32],
+    
+    /// Contact hints
+    #[n(2)]
+    pub hints: Vec<ContactHint>,
 }
 
-/// Get current timestamp (milliseconds since epoch)
-fn current_timestamp() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
+/// Beacon Signature
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct BeaconSignature {
+    /// Signing validator (member of the previous epoch's set)
+    #[n(0)]
+    pub validator_id: [u8; 32],
+    
+    /// Signature over `WatchdogBeacon::beacon_hash`
+    #[n(1)]
+    pub signature: [u8; 64],
+}
+
+/// Watchdog Beacon
+///
+/// ## Lifecycle Stage: Execution (epoch rotation)
+///
+/// Announces an epoch's watchdog set. The beacon for epoch N is signed by a
+/// majority of epoch N-1's validators and commits to their attestations, so
+/// a peer holding any trusted beacon can walk the chain forward.
+///
+/// ## Security Rationale
+/// - Hash-chained to the previous beacon (no forks without re-signing)
+/// - Commits to the previous epoch's attestation root
+/// - Signed by the outgoing set, verified with keys from the previous beacon
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct WatchdogBeacon {
+    /// Epoch announced
+    #[n(0)]
+    pub epoch: u64,
+    
+    /// Validator set for this epoch
+    #[n(1)]
+    pub validators: Vec<BeaconEntry>,
+    
+    /// Hash of the previous epoch's beacon (zero for the first beacon)
+    #[n(2)]
+    pub prev_beacon_hash: [u8; 32],
+    
+    /// Root over the previous epoch's audit attestations
+    #[n(3)]
+    pub prev_attestation_root: [u8; 32],
+    
+    /// Signatures from the previous epoch's set
+    #[n(4)]
+    pub signatures: Vec<BeaconSignature>,
+}
+
+impl WatchdogBeacon {
+    /// Beacon hash (signed content; excludes signatures)
+    pub fn beacon_hash(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"QRATUM-WATCHDOG-BEACON-v1");
+        hasher.update(self.epoch.to_le_bytes());
+        hasher.update((self.validators.len() as u64).to_le_bytes());
+        for entry in &self.validators {
+            hasher.update(entry.validator_id);
+            hasher.update(entry.public_key);
+            hasher.update((entry.hints.len() as u64).to_le_bytes());
+            for hint in &entry.hints {
+                hasher.update([hint.channel as u8]);
+                hasher.update((hint.address.len() as u64).to_le_bytes());
+                hasher.update(&hint.address);
+            }
+        }
+        hasher.update(self.prev_beacon_hash);
+        hasher.update(self.prev_attestation_root);
+        hasher.finalize().into()
+    }
+    
+    /// Add a signature from the previous epoch's set
+    pub fn add_signature(&mut self, validator_id: [u8; 32], signer: &dyn BeaconSigner) {
+        let signature = signer.sign(&self.beacon_hash());
+        self.signatures.push(BeaconSignature { validator_id, signature });
     }
-    #[cfg(not(feature = "std"))]
-    {
-        0
+    
+    /// Serialize to CBOR for publication
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).unwrap_or_default()
+    }
+    
+    /// Deserialize from CBOR
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, minicbor::decode::Error> {
+        minicbor::decode(bytes)
     }
 }
 
+/// Beacon signing backend
+///
+/// Signatures are delegated so the watchdog module stays free of a specific
+/// signature scheme (TEE-resident keys, HSMs, PQC signers).
+pub trait BeaconSigner {
+    /// Sign a beacon hash
+    fn sign(&self, message: &[u8; 32]) -> [u8; 64];
+}
+
+/// Beacon verification backend
+pub trait BeaconVerifier {
+    /// Verify a signature over a beacon hash
+    fn verify(&self, public_key: &[u8; 32], message: &[u8; 32], signature: &[u8; 64]) -> bool;
+}
+
+/// Anchor backend for optional external beacon publication
+pub trait BeaconAnchor {
+    /// Anchor a beacon hash, returning an opaque receipt
+    fn anchor(&mut self, epoch: u64, beacon_hash: [u8; 32]) -> Result<Vec<u8>, &'static str>;
+}
+
+/// Beacon verification failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BeaconError {
+    /// Epoch does not follow the previous beacon
+    EpochGap,
+    /// `prev_beacon_hash` does not match the previous beacon
+    BrokenChain,
+    /// `prev_attestation_root` does not match the supplied attestations
+    AttestationMismatch,
+    /// Beacon announces an empty validator set
+    EmptyValidatorSet,
+    /// Signer is not in the previous epoch's set
+    UnknownSigner([u8; 32]),
+    /// Signature does not verify
+    InvalidSignature([u8; 32]),
+    /// Fewer than a majority of the previous set signed
+    InsufficientSignatures {
+        /// Distinct valid signers
+        signed: usize,
+        /// Required signers
+        required: usize,
+    },
+}
+
+/// Beacon Publication Result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeaconPublication {
+    /// Channel the beacon was sent on (None if no channel was available)
+    pub channel: Option<Channel>,
+    
+    /// Anchor receipt (None if no anchor backend or anchoring failed)
+    pub anchor_receipt: Option<Vec<u8>>,
+}
+
+/// Attestation root over the given epoch's attestations
+///
+/// ## Audit Trail
+/// - Order-sensitive: attestations are hashed in submission order
+pub fn attestation_root(attestations: &[AuditAttestation], epoch: u64) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"QRATUM-WATCHDOG-ATTESTATIONS-v1");
+    hasher.update(epoch.to_le_bytes());
+    for attestation in attestations.iter().filter(|a| a.epoch == epoch) {
+        hasher.update(attestation.validator_id);
+        hasher.update(attestation.state_hash);
+        hasher.update(attestation.timestamp.to_le_bytes());
+        hasher.update(attestation.signature);
+    }
+    hasher.finalize().into()
+}
+
+/// Verify a beacon against the previous epoch's beacon and attestation chain
+///
+/// ## Lifecycle Stage: Execution (epoch rotation)
+///
+/// # Inputs
+/// - `beacon`: Beacon to verify
+/// - `previous`: Trusted beacon for the previous epoch
+/// - `previous_attestations`: Attestations submitted in the previous epoch
+/// - `verifier`: Signature verification backend
+///
+/// # Outputs
+/// - `Ok(())` if the beacon extends `previous` and a majority of the
+///   previous set signed it
+pub fn verify_beacon(
+    beacon: &WatchdogBeacon,
+    previous: &WatchdogBeacon,
+    previous_attestations: &[AuditAttestation],
+    verifier: &dyn BeaconVerifier,
+) -> Result<(), BeaconError> {
+    if beacon.epoch != previous.epoch.wrapping_add(1) {
+        return Err(BeaconError::EpochGap);
+    }
+    if beacon.prev_beacon_hash != previous.beacon_hash() {
+        return Err(BeaconError::BrokenChain);
+    }
+    if beacon.prev_attestation_root != attestation_root(previous_attestations, previous.epoch) {
+        return Err(BeaconError::AttestationMismatch);
+    }
+    if beacon.validators.is_empty() {
+        return Err(BeaconError::EmptyValidatorSet);
+    }
+    
+    let message = beacon.beacon_hash();
+    let mut signers: Vec<[u8; 32]> = Vec::new();
+    for sig in &beacon.signatures {
+        let entry = previous.validators.iter()
+            .find(|e| e.validator_id == sig.validator_id)
+            .ok_or(BeaconError::UnknownSigner(sig.validator_id))?;
+        if !verifier.verify(&entry.public_key, &message, &sig.signature) {
+            return Err(BeaconError::InvalidSignature(sig.validator_id));
+        }
+        if !signers.contains(&sig.validator_id) {
+            signers.push(sig.validator_id);
+        }
+    }
+    
+    let required = previous.validators.len() / 2 + 1;
+    if signers.len() < required {
+        return Err(BeaconError::InsufficientSignatures { signed: signers.len(), required });
+    }
+    
+    Ok(())
+}
+
+/// Publish a beacon over transport channels and optionally an anchor backend
+///
+/// ## Lifecycle Stage: Execution (epoch rotation)
+///
+/// ## Anti-Censorship Mechanism
+/// - Uses `CensorshipResistance` channel selection and fallback
+/// - Anchoring gives peers a second discovery path if channels are blocked
+pub fn publish_beacon(
+    beacon: &WatchdogBeacon,
+    transport: &mut CensorshipResistance,
+    anchor: Option<&mut dyn BeaconAnchor>,
+) -> BeaconPublication {
+    let channel = if transport.send_message(&beacon.to_cbor()) {
+        transport.active_channel
+    } else {
+        None
+    };
+    
+    let anchor_receipt = anchor.and_then(|backend| backend.anchor(beacon.epoch, beacon.beacon_hash()).ok());
+    
+    BeaconPublication { channel, anchor_receipt }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_signer::{TestKey, TestVerifier};
     use alloc::vec;
     
     #[test]
@@ -270,4 +588,118 @@ mod tests {
         let manager = WatchdogManager::new(config, validators);
         assert!(!manager.active_validators().is_empty());
     }
+    
+    struct TestAnchor(Vec<u64>);
+    
+    impl BeaconAnchor for TestAnchor {
+        fn anchor(&mut self, epoch: u64, beacon_hash: [u8; 32]) -> Result<Vec<u8>, &'static str> {
+            self.0.push(epoch);
+            Ok(beacon_hash.to_vec())
+        }
+    }
+    
+    fn validator(seed: u8) -> WatchdogValidator {
+        WatchdogValidator::new([seed; 32], [seed + 100; 32])
+    }
+    
+    fn hint(seed: u8) -> ([u8; 32], ContactHint) {
+        ([seed; 32], ContactHint { channel: Channel::Tor, address: vec![seed; 8] })
+    }
+    
+    #[test]
+    fn test_beacon_chain() {
+        let config = WatchdogConfig { validators_per_epoch: 3, ..WatchdogConfig::default() };
+        let mut manager = WatchdogManager::new(config, (1..=5).map(validator).collect());
+        let hints: Vec<_> = (1..=5).map(hint).collect();
+        
+        // First beacon is trusted out of band
+        let genesis = manager.build_beacon(&hints, None);
+        assert_eq!(genesis.epoch, 1);
+        assert_eq!(genesis.validators.len(), 3);
+        assert_eq!(genesis.validators[0].hints.len(), 1);
+        
+        // Epoch 1 set attests, then rotation
+        let attester = genesis.validators[0].validator_id;
+        manager.submit_attestation(AuditAttestation {
+            validator_id: attester,
+            epoch: 1,
+            state_hash: [9u8; 32],
+            timestamp: 0,
+            signature: [0u8; 64],
+        }).unwrap();
+        manager.rotate_validators();
+        
+        let mut next = manager.build_beacon(&hints, Some(&genesis));
+        assert_eq!(next.epoch, 2);
+        
+        // Outgoing set signs (public key doubles as test signing key)
+        let outgoing: Vec<_> = genesis.validators.iter().map(|e| (e.validator_id, e.public_key)).collect();
+        next.add_signature(outgoing[0].0, &TestKey(outgoing[0].1));
+        assert_eq!(
+            verify_beacon(&next, &genesis, manager.attestations(), &TestVerifier),
+            Err(BeaconError::InsufficientSignatures { signed: 1, required: 2 })
+        );
+        
+        next.add_signature(outgoing[1].0, &TestKey(outgoing[1].1));
+        assert_eq!(verify_beacon(&next, &genesis, manager.attestations(), &TestVerifier), Ok(()));
+        
+        // Dropping the attestation breaks the chain commitment
+        assert_eq!(
+            verify_beacon(&next, &genesis, &[], &TestVerifier),
+            Err(BeaconError::AttestationMismatch)
+        );
+        
+        // CBOR round-trip preserves verifiability
+        let decoded = WatchdogBeacon::from_cbor(&next.to_cbor()).unwrap();
+        assert_eq!(decoded, next);
+    }
+    
+    #[test]
+    fn test_beacon_rejects_forged_signatures() {
+        let mut manager = WatchdogManager::new(WatchdogConfig::default(), (1..=4).map(validator).collect());
+        let genesis = manager.build_beacon(&[], None);
+        manager.rotate_validators();
+        let mut next = manager.build_beacon(&[], Some(&genesis));
+        
+        // Outsider signature
+        next.add_signature([77u8; 32], &TestKey([77u8; 32]));
+        assert_eq!(
+            verify_beacon(&next, &genesis, &[], &TestVerifier),
+            Err(BeaconError::UnknownSigner([77u8; 32]))
+        );
+        
+        // Member signing with the wrong key
+        next.signatures.clear();
+        let member = genesis.validators[0].validator_id;
+        next.add_signature(member, &TestKey([0u8; 32]));
+        assert_eq!(
+            verify_beacon(&next, &genesis, &[], &TestVerifier),
+            Err(BeaconError::InvalidSignature(member))
+        );
+        
+        // Tampering with the announced set invalidates the chain link
+        let mut forked = next.clone();
+        forked.prev_beacon_hash = [1u8; 32];
+        assert_eq!(verify_beacon(&forked, &genesis, &[], &TestVerifier), Err(BeaconError::BrokenChain));
+    }
+    
+    #[test]
+    fn test_publish_beacon() {
+        let manager = WatchdogManager::new(WatchdogConfig::default(), (1..=3).map(validator).collect());
+        let beacon = manager.build_beacon(&[], None);
+        
+        let mut transport = CensorshipResistance::new(vec![Channel::Tcp, Channel::Tor]);
+        transport.configure_channel(Channel::Tor);
+        let mut anchor = TestAnchor(Vec::new());
+        
+        let publication = publish_beacon(&beacon, &mut transport, Some(&mut anchor));
+        assert_eq!(publication.channel, Some(Channel::Tor));
+        assert_eq!(publication.anchor_receipt, Some(beacon.beacon_hash().to_vec()));
+        assert_eq!(anchor.0, vec![beacon.epoch]);
+        
+        // No configured channel and no anchor
+        let mut offline = CensorshipResistance::new(vec![Channel::Tcp]);
+        let publication = publish_beacon(&beacon, &mut offline, None);
+        assert_eq!(publication, BeaconPublication { channel: None, anchor_receipt: None });
+    }
 }
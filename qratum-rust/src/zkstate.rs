@@ -79,6 +79,8 @@ pub enum TransitionType {
     GovernanceUpdate,
     /// Stake deposit or withdrawal
     StakeUpdate,
+    /// Epoch schedule boundary
+    EpochSchedule,
 }
 
 impl ZkStateTransition {
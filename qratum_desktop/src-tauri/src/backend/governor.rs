@@ -0,0 +1,702 @@
+// Resource governor for heavy computation jobs
+// Each job runs in a child process (`qratum-desktop --job`) so it cannot
+// starve the UI. OS-level limits are applied where available: job objects on
+// Windows, a delegated cgroup v2 (QRATUM_CGROUP_ROOT) plus rlimits on Linux,
+// rlimits and a utility QoS class on macOS. A monitor thread samples CPU and
+// memory usage and kills the job if a limit is exceeded on any platform.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Usage sampling interval for running jobs
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// Finished jobs kept for the frontend before the oldest are dropped
+const MAX_FINISHED_JOBS: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    pub max_memory_mb: u64,
+    pub max_cpu_seconds: u64,
+    // Share of one core (cgroup cpu.max); other platforms lower priority instead
+    pub cpu_percent: u32,
+}
+
+impl Default for ResourceLimits {
+    fn default() -> Self {
+        ResourceLimits {
+            max_memory_mb: 1024,
+            max_cpu_seconds: 120,
+            cpu_percent: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+    LimitExceeded,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    pub cpu_seconds: f64,
+    pub memory_mb: f64,
+    pub peak_memory_mb: f64,
+    pub wall_seconds: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobStatus {
+    pub id: u64,
+    pub command: String,
+    pub state: JobState,
+    pub limits: ResourceLimits,
+    pub usage: ResourceUsage,
+    // OS mechanisms that were applied, e.g. "cgroup", "rlimit", "job_object"
+    pub enforcement: Vec<String>,
+    pub result: Option<Value>,
+    pub error: Option<String>,
+}
+
+// Request written to the child's stdin
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobRequest {
+    pub command: String,
+    pub args: Value,
+    pub limits: ResourceLimits,
+}
+
+struct JobHandle {
+    status: Arc<Mutex<JobStatus>>,
+    cancel: Arc<AtomicBool>,
+}
+
+// Held in AppState
+pub struct ResourceGovernor {
+    program: PathBuf,
+    program_args: Vec<String>,
+    next_id: u64,
+    jobs: BTreeMap<u64, JobHandle>,
+}
+
+impl Default for ResourceGovernor {
+    fn default() -> Self {
+        let program = std::env::current_exe().unwrap_or_else(|_| PathBuf::from("qratum-desktop"));
+        Self::with_program(program, vec!["--job".to_string()])
+    }
+}
+
+impl ResourceGovernor {
+    // Worker command; it reads a JobRequest on stdin and prints a JobOutput
+    pub fn with_program(program: PathBuf, program_args: Vec<String>) -> Self {
+        ResourceGovernor {
+            program,
+            program_args,
+            next_id: 1,
+            jobs: BTreeMap::new(),
+        }
+    }
+
+    pub fn submit(
+        &mut self,
+        command: &str,
+        args: Value,
+        limits: ResourceLimits,
+    ) -> Result<u64, String> {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let request = serde_json::to_vec(&JobRequest {
+            command: command.to_string(),
+            args,
+            limits,
+        })
+        .map_err(|e| format!("Failed to encode job: {}", e))?;
+
+        let mut cmd = Command::new(&self.program);
+        cmd.args(&self.program_args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+        platform::configure(&mut cmd);
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| format!("Failed to start job worker: {}", e))?;
+
+        let sandbox = platform::Sandbox::attach(id, &child, &limits);
+        let mut enforcement = sandbox.mechanisms();
+        enforcement.push("watchdog".to_string());
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(&request)
+                .map_err(|e| format!("Failed to send job: {}", e))?;
+        }
+
+        let status = Arc::new(Mutex::new(JobStatus {
+            id,
+            command: command.to_string(),
+            state: JobState::Running,
+            limits,
+            usage: ResourceUsage::default(),
+            enforcement,
+            result: None,
+            error: None,
+        }));
+        let cancel = Arc::new(AtomicBool::new(false));
+        monitor(child, sandbox, status.clone(), cancel.clone());
+
+        self.jobs.insert(id, JobHandle { status, cancel });
+        self.prune();
+        Ok(id)
+    }
+
+    pub fn status(&self, id: u64) -> Option<JobStatus> {
+        self.jobs
+            .get(&id)
+            .map(|job| job.status.lock().unwrap().clone())
+    }
+
+    pub fn list(&self) -> Vec<JobStatus> {
+        self.jobs
+            .values()
+            .map(|job| job.status.lock().unwrap().clone())
+            .collect()
+    }
+
+    pub fn cancel(&self, id: u64) -> Result<(), String> {
+        let job = self
+            .jobs
+            .get(&id)
+            .ok_or_else(|| format!("Unknown job {}", id))?;
+        job.cancel.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn prune(&mut self) {
+        let finished: Vec<u64> = self
+            .jobs
+            .iter()
+            .filter(|(_, job)| job.status.lock().unwrap().state != JobState::Running)
+            .map(|(id, _)| *id)
+            .collect();
+        let excess = finished.len().saturating_sub(MAX_FINISHED_JOBS);
+        for id in finished.into_iter().take(excess) {
+            self.jobs.remove(&id);
+        }
+    }
+}
+
+// Result line printed by the worker
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobOutput {
+    Ok(Value),
+    Error(String),
+}
+
+fn monitor(
+    mut child: Child,
+    sandbox: platform::Sandbox,
+    status: Arc<Mutex<JobStatus>>,
+    cancel: Arc<AtomicBool>,
+) {
+    // Drain stdout concurrently so a large result cannot block the worker
+    let mut stdout = child.stdout.take();
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(stdout) = stdout.as_mut() {
+            let _ = stdout.read_to_string(&mut output);
+        }
+        output
+    });
+
+    thread::spawn(move || {
+        let started = Instant::now();
+        let limits = status.lock().unwrap().limits;
+        let mut usage = ResourceUsage::default();
+        let mut stopped = None;
+
+        let exit = loop {
+            if let Some(sample) = sandbox.sample(&child) {
+                usage.cpu_seconds = sample.cpu_seconds;
+                usage.memory_mb = sample.memory_mb;
+                usage.peak_memory_mb = usage.peak_memory_mb.max(sample.peak_memory_mb);
+            }
+            usage.wall_seconds = started.elapsed().as_secs_f64();
+            status.lock().unwrap().usage = usage;
+
+            match child.try_wait() {
+                Ok(Some(exit)) => break Some(exit),
+                Ok(None) => {}
+                Err(_) => break None,
+            }
+
+            if cancel.load(Ordering::SeqCst) {
+                stopped = Some((JobState::Cancelled, "Cancelled by user".to_string()));
+            } else if usage.memory_mb > limits.max_memory_mb as f64 {
+                stopped = Some((
+                    JobState::LimitExceeded,
+                    format!("Memory limit of {} MB exceeded", limits.max_memory_mb),
+                ));
+            } else if usage.cpu_seconds > limits.max_cpu_seconds as f64 {
+                stopped = Some((
+                    JobState::LimitExceeded,
+                    format!("CPU limit of {} s exceeded", limits.max_cpu_seconds),
+                ));
+            }
+            if stopped.is_some() {
+                let _ = child.kill();
+                break child.wait().ok();
+            }
+            thread::sleep(POLL_INTERVAL);
+        };
+        sandbox.release();
+
+        let (state, error, result) = match (stopped, exit) {
+            // The worker's stdout is abandoned; an orphaned grandchild may hold it
+            (Some((state, reason)), _) => (state, Some(reason), None),
+            (None, Some(exit)) => {
+                let output = reader.join().unwrap_or_default();
+                match serde_json::from_str::<JobOutput>(output.trim()) {
+                    Ok(JobOutput::Ok(value)) if exit.success() => {
+                        (JobState::Completed, None, Some(value))
+                    }
+                    Ok(JobOutput::Error(e)) => (JobState::Failed, Some(e), None),
+                    _ if exit.success() => (
+                        JobState::Failed,
+                        Some("Worker produced no result".to_string()),
+                        None,
+                    ),
+                    // Killed by an OS limit (rlimit signal, cgroup OOM, job object)
+                    _ => (
+                        JobState::LimitExceeded,
+                        Some(format!("Worker terminated: {}", exit)),
+                        None,
+                    ),
+                }
+            }
+            (None, None) => (
+                JobState::Failed,
+                Some("Lost track of worker process".to_string()),
+                None,
+            ),
+        };
+        let mut status = status.lock().unwrap();
+        status.usage.wall_seconds = started.elapsed().as_secs_f64();
+        status.state = state;
+        status.error = error;
+        status.result = result;
+    });
+}
+
+// Worker side of `qratum-desktop --job`: apply limits to this process, run
+// the command and print a JobOutput line. Returns the process exit code.
+pub fn run_job_worker() -> i32 {
+    let mut input = String::new();
+    if std::io::stdin().read_to_string(&mut input).is_err() {
+        return 2;
+    }
+    let output = match serde_json::from_str::<JobRequest>(&input) {
+        Ok(request) => {
+            platform::limit_current_process(&request.limits);
+            run_request(&request)
+        }
+        Err(e) => JobOutput::Error(format!("Invalid job request: {}", e)),
+    };
+    let code = match output {
+        JobOutput::Ok(_) => 0,
+        JobOutput::Error(_) => 1,
+    };
+    println!("{}", serde_json::to_string(&output).unwrap_or_default());
+    code
+}
+
+fn run_request(request: &JobRequest) -> JobOutput {
+    let runtime = match tokio::runtime::Builder::new_current_thread().build() {
+        Ok(runtime) => runtime,
+        Err(e) => return JobOutput::Error(format!("Failed to start runtime: {}", e)),
+    };
+    match runtime.block_on(crate::scenario::dispatch(&request.command, &request.args)) {
+        Ok(value) => JobOutput::Ok(value),
+        Err(e) => JobOutput::Error(e),
+    }
+}
+
+pub struct UsageSample {
+    pub cpu_seconds: f64,
+    pub memory_mb: f64,
+    pub peak_memory_mb: f64,
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::{ResourceLimits, UsageSample};
+    use std::path::PathBuf;
+    use std::process::{Child, Command};
+
+    pub fn configure(_cmd: &mut Command) {}
+
+    // Optional delegated cgroup v2 directory for per-job memory/CPU limits
+    pub struct Sandbox {
+        cgroup: Option<PathBuf>,
+    }
+
+    impl Sandbox {
+        pub fn attach(id: u64, child: &Child, limits: &ResourceLimits) -> Self {
+            let cgroup = std::env::var_os("QRATUM_CGROUP_ROOT").and_then(|root| {
+                let dir =
+                    PathBuf::from(root).join(format!("qratum-job-{}-{}", std::process::id(), id));
+                let memory = limits.max_memory_mb * 1024 * 1024;
+                let quota = limits.cpu_percent as u64 * 1000;
+                let applied = std::fs::create_dir(&dir).is_ok()
+                    && std::fs::write(dir.join("memory.max"), memory.to_string()).is_ok()
+                    && std::fs::write(dir.join("cpu.max"), format!("{} 100000", quota)).is_ok()
+                    && std::fs::write(dir.join("cgroup.procs"), child.id().to_string()).is_ok();
+                if applied {
+                    Some(dir)
+                } else {
+                    let _ = std::fs::remove_dir(&dir);
+                    None
+                }
+            });
+            Sandbox { cgroup }
+        }
+
+        pub fn mechanisms(&self) -> Vec<String> {
+            let mut mechanisms = vec!["rlimit".to_string(), "nice".to_string()];
+            if self.cgroup.is_some() {
+                mechanisms.push("cgroup".to_string());
+            }
+            mechanisms
+        }
+
+        pub fn sample(&self, child: &Child) -> Option<UsageSample> {
+            let pid = child.id();
+            let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+            // Fields after the parenthesised command name; utime/stime are 14 and 15
+            let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+            let ticks: u64 =
+                fields.get(11)?.parse::<u64>().ok()? + fields.get(12)?.parse::<u64>().ok()?;
+            let hz = unsafe { libc::sysconf(libc::_SC_CLK_TCK) }.max(1) as f64;
+
+            let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+            let field_mb = |name: &str| {
+                status
+                    .lines()
+                    .find(|line| line.starts_with(name))
+                    .and_then(|line| line.split_whitespace().nth(1))
+                    .and_then(|kb| kb.parse::<f64>().ok())
+                    .map(|kb| kb / 1024.0)
+                    .unwrap_or(0.0)
+            };
+            Some(UsageSample {
+                cpu_seconds: ticks as f64 / hz,
+                memory_mb: field_mb("VmRSS:"),
+                peak_memory_mb: field_mb("VmHWM:"),
+            })
+        }
+
+        pub fn release(&self) {
+            if let Some(dir) = &self.cgroup {
+                let _ = std::fs::remove_dir(dir);
+            }
+        }
+    }
+
+    pub fn limit_current_process(limits: &ResourceLimits) {
+        super::unix::set_rlimits(limits);
+    }
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+mod platform {
+    use super::{ResourceLimits, UsageSample};
+    use std::process::{Child, Command};
+
+    pub fn configure(_cmd: &mut Command) {}
+
+    pub struct Sandbox;
+
+    impl Sandbox {
+        pub fn attach(_id: u64, _child: &Child, _limits: &ResourceLimits) -> Self {
+            Sandbox
+        }
+
+        pub fn mechanisms(&self) -> Vec<String> {
+            let mut mechanisms = vec!["rlimit".to_string(), "nice".to_string()];
+            if cfg!(target_os = "macos") {
+                mechanisms.push("qos".to_string());
+            }
+            mechanisms
+        }
+
+        // No procfs: ask ps for resident size (KB) and CPU time ([[dd-]hh:]mm:ss)
+        pub fn sample(&self, child: &Child) -> Option<UsageSample> {
+            let output = std::process::Command::new("ps")
+                .args(["-o", "rss=,time=", "-p", &child.id().to_string()])
+                .output()
+                .ok()?;
+            let text = String::from_utf8_lossy(&output.stdout);
+            let mut parts = text.split_whitespace();
+            let memory_mb = parts.next()?.parse::<f64>().ok()? / 1024.0;
+            let time = parts.next()?;
+            let (days, clock) = match time.split_once('-') {
+                Some((days, clock)) => (days.parse::<f64>().ok()?, clock),
+                None => (0.0, time),
+            };
+            let cpu_seconds = clock.split(':').try_fold(0.0, |acc, part| {
+                part.parse::<f64>().ok().map(|v| acc * 60.0 + v)
+            })? + days * 86400.0;
+            Some(UsageSample {
+                cpu_seconds,
+                memory_mb,
+                peak_memory_mb: memory_mb,
+            })
+        }
+
+        pub fn release(&self) {}
+    }
+
+    pub fn limit_current_process(limits: &ResourceLimits) {
+        super::unix::set_rlimits(limits);
+        #[cfg(target_os = "macos")]
+        unsafe {
+            libc::pthread_set_qos_class_self_np(libc::qos_class_t::QOS_CLASS_UTILITY, 0);
+        }
+    }
+}
+
+#[cfg(unix)]
+mod unix {
+    use super::ResourceLimits;
+
+    // Address-space and CPU-time rlimits plus a lower scheduling priority
+    pub fn set_rlimits(limits: &ResourceLimits) {
+        let memory = (limits.max_memory_mb * 1024 * 1024) as libc::rlim_t;
+        let cpu = limits.max_cpu_seconds as libc::rlim_t;
+        unsafe {
+            libc::setrlimit(
+                libc::RLIMIT_AS,
+                &libc::rlimit {
+                    rlim_cur: memory,
+                    rlim_max: memory,
+                },
+            );
+            libc::setrlimit(
+                libc::RLIMIT_CPU,
+                &libc::rlimit {
+                    rlim_cur: cpu,
+                    rlim_max: cpu,
+                },
+            );
+            libc::setpriority(libc::PRIO_PROCESS, 0, 10);
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::{ResourceLimits, UsageSample};
+    use std::os::windows::io::AsRawHandle;
+    use std::os::windows::process::CommandExt;
+    use std::process::{Child, Command};
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::jobapi2::{
+        AssignProcessToJobObject, CreateJobObjectW, QueryInformationJobObject,
+        SetInformationJobObject,
+    };
+    use winapi::um::winbase::BELOW_NORMAL_PRIORITY_CLASS;
+    use winapi::um::winnt::{
+        JobObjectBasicAccountingInformation, JobObjectExtendedLimitInformation, HANDLE,
+        JOBOBJECT_BASIC_ACCOUNTING_INFORMATION, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JOB_OBJECT_LIMIT_JOB_TIME, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+        JOB_OBJECT_LIMIT_PROCESS_MEMORY,
+    };
+
+    pub fn configure(cmd: &mut Command) {
+        cmd.creation_flags(BELOW_NORMAL_PRIORITY_CLASS);
+    }
+
+    // Job object owning the worker; closing it kills the worker
+    pub struct Sandbox {
+        job: HANDLE,
+    }
+
+    // The handle is only used from the monitor thread that owns the Sandbox
+    unsafe impl Send for Sandbox {}
+
+    impl Sandbox {
+        pub fn attach(_id: u64, child: &Child, limits: &ResourceLimits) -> Self {
+            unsafe {
+                let job = CreateJobObjectW(std::ptr::null_mut(), std::ptr::null());
+                if job.is_null() {
+                    return Sandbox { job };
+                }
+                let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_PROCESS_MEMORY
+                    | JOB_OBJECT_LIMIT_JOB_TIME
+                    | JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+                // User time in 100ns units
+                *info
+                    .BasicLimitInformation
+                    .PerJobUserTimeLimit
+                    .QuadPart_mut() = (limits.max_cpu_seconds * 10_000_000) as i64;
+                info.ProcessMemoryLimit = (limits.max_memory_mb * 1024 * 1024) as usize;
+                let applied = SetInformationJobObject(
+                    job,
+                    JobObjectExtendedLimitInformation,
+                    &mut info as *mut _ as *mut _,
+                    std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                ) != 0
+                    && AssignProcessToJobObject(job, child.as_raw_handle() as HANDLE) != 0;
+                if !applied {
+                    CloseHandle(job);
+                    return Sandbox {
+                        job: std::ptr::null_mut(),
+                    };
+                }
+                Sandbox { job }
+            }
+        }
+
+        pub fn mechanisms(&self) -> Vec<String> {
+            let mut mechanisms = vec!["priority_class".to_string()];
+            if !self.job.is_null() {
+                mechanisms.push("job_object".to_string());
+            }
+            mechanisms
+        }
+
+        pub fn sample(&self, _child: &Child) -> Option<UsageSample> {
+            if self.job.is_null() {
+                return None;
+            }
+            unsafe {
+                let mut accounting: JOBOBJECT_BASIC_ACCOUNTING_INFORMATION = std::mem::zeroed();
+                let mut limits: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                let ok = QueryInformationJobObject(
+                    self.job,
+                    JobObjectBasicAccountingInformation,
+                    &mut accounting as *mut _ as *mut _,
+                    std::mem::size_of::<JOBOBJECT_BASIC_ACCOUNTING_INFORMATION>() as u32,
+                    std::ptr::null_mut(),
+                ) != 0
+                    && QueryInformationJobObject(
+                        self.job,
+                        JobObjectExtendedLimitInformation,
+                        &mut limits as *mut _ as *mut _,
+                        std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                        std::ptr::null_mut(),
+                    ) != 0;
+                if !ok {
+                    return None;
+                }
+                let ticks =
+                    *accounting.TotalUserTime.QuadPart() + *accounting.TotalKernelTime.QuadPart();
+                // Job objects only track peak commit, reported for both figures
+                let peak_mb = limits.PeakProcessMemoryUsed as f64 / 1_048_576.0;
+                Some(UsageSample {
+                    cpu_seconds: ticks as f64 / 10_000_000.0,
+                    memory_mb: peak_mb,
+                    peak_memory_mb: peak_mb,
+                })
+            }
+        }
+
+        pub fn release(&self) {
+            if !self.job.is_null() {
+                unsafe {
+                    CloseHandle(self.job);
+                }
+            }
+        }
+    }
+
+    // Limits are applied by the parent through the job object
+    pub fn limit_current_process(_limits: &ResourceLimits) {}
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn shell(script: &str) -> ResourceGovernor {
+        ResourceGovernor::with_program(
+            PathBuf::from("sh"),
+            vec!["-c".to_string(), script.to_string()],
+        )
+    }
+
+    fn wait(governor: &ResourceGovernor, id: u64) -> JobStatus {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            let status = governor.status(id).unwrap();
+            if status.state != JobState::Running || Instant::now() > deadline {
+                return status;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    #[test]
+    fn test_job_result_and_failure() {
+        let mut governor = shell(r#"cat >/dev/null; echo '{"ok":{"p00":0.5}}'"#);
+        let id = governor
+            .submit("run_bell_state", json!({}), ResourceLimits::default())
+            .unwrap();
+        let status = wait(&governor, id);
+        assert_eq!(status.state, JobState::Completed);
+        assert_eq!(status.result, Some(json!({ "p00": 0.5 })));
+        assert!(status.enforcement.contains(&"watchdog".to_string()));
+
+        let mut governor =
+            shell(r#"cat >/dev/null; echo '{"error":"Command is not replayable"}'; exit 1"#);
+        let id = governor
+            .submit("get_health", json!({}), ResourceLimits::default())
+            .unwrap();
+        let status = wait(&governor, id);
+        assert_eq!(status.state, JobState::Failed);
+        assert_eq!(status.error.as_deref(), Some("Command is not replayable"));
+        assert_eq!(governor.list().len(), 1);
+    }
+
+    #[test]
+    fn test_cancel_and_cpu_limit() {
+        let mut governor = shell("cat >/dev/null; exec sleep 5");
+        let id = governor
+            .submit("run_ghz_state", json!({}), ResourceLimits::default())
+            .unwrap();
+        governor.cancel(id).unwrap();
+        assert_eq!(wait(&governor, id).state, JobState::Cancelled);
+        assert!(governor.cancel(99).is_err());
+
+        // Busy loop is stopped by the watchdog once its CPU time passes the limit
+        let mut governor = shell("cat >/dev/null; while :; do :; done");
+        let limits = ResourceLimits {
+            max_cpu_seconds: 0,
+            ..ResourceLimits::default()
+        };
+        let id = governor
+            .submit("run_supremacy_test", json!({}), limits)
+            .unwrap();
+        let status = wait(&governor, id);
+        assert_eq!(status.state, JobState::LimitExceeded);
+        assert!(status.usage.cpu_seconds > 0.0);
+        assert!(status.error.unwrap().contains("CPU limit"));
+    }
+}
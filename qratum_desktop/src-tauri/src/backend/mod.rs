@@ -1,3 +1,4 @@
+pub mod governor;
 pub mod health;
 pub mod kernel;
 pub mod wasm_runtime;
@@ -1,8 +1,11 @@
+use crate::backend::governor::{JobStatus, ResourceLimits};
 use crate::backend::{health, kernel, HealthResponse, LogEntry};
 use crate::codegen::{ast::IntentSpec, CodeGenerator};
+use crate::files::{self, Circuit, CircuitFile, OpenedFile, RecentFile, SessionFile};
 use crate::qr_os_supreme::{
     GateOperation, IntentClassification, OSSupreme, OSSupremeStats, QubitStateInfo, WasmPodConfig,
 };
+use crate::scenario::{self, ReplayReport, Scenario};
 use crate::AppState;
 use serde::{Deserialize, Serialize};
 use tauri::State;
@@ -124,7 +127,7 @@ pub async fn run_ghz_state() -> Result<GHZResult, String> {
 }
 
 // Apply individual gates
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GateRequest {
     pub gate: String,
     pub qubits: Vec<usize>,
@@ -283,3 +286,165 @@ pub async fn get_failure_modes() -> Result<Vec<FailureMode>, String> {
         },
     ])
 }
+
+// Scenario recording and replay
+#[tauri::command]
+pub fn start_recording(state: State<AppState>) {
+    state.recorder.lock().unwrap().start();
+}
+
+#[tauri::command]
+pub fn stop_recording(state: State<AppState>, path: Option<String>) -> Result<Scenario, String> {
+    let scenario = state
+        .recorder
+        .lock()
+        .unwrap()
+        .stop()
+        .ok_or_else(|| "No recording in progress".to_string())?;
+    if let Some(path) = path {
+        scenario.save(&path)?;
+    }
+    Ok(scenario)
+}
+
+// Run a replayable command, recording it if a recording is in progress
+#[tauri::command]
+pub async fn run_scenario_command(
+    state: State<'_, AppState>,
+    command: String,
+    args: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let result = scenario::dispatch(&command, &args).await?;
+    state.recorder.lock().unwrap().record(&command, &args, &result);
+    Ok(result)
+}
+
+#[tauri::command]
+pub async fn replay_scenario(path: String) -> Result<ReplayReport, String> {
+    let scenario = Scenario::load(&path)?;
+    Ok(scenario::replay(&scenario).await)
+}
+
+// Circuit (.qcirc) and session (.qsess) files
+#[tauri::command]
+pub fn open_file(state: State<AppState>, path: String) -> Result<OpenedFile, String> {
+    let opened = files::open(&path)?;
+    state.recent_files.lock().unwrap().touch(opened.recent_entry(&path));
+    Ok(opened)
+}
+
+#[tauri::command]
+pub fn save_circuit(
+    state: State<AppState>,
+    path: String,
+    circuit: Circuit,
+) -> Result<CircuitFile, String> {
+    if files::FileKind::from_path(&path)? != files::FileKind::Circuit {
+        return Err(format!("Circuits are saved as .{} files", files::CIRCUIT_EXTENSION));
+    }
+    let file = CircuitFile::new(circuit)?;
+    files::save(&path, &file.to_json()?)?;
+    state.recent_files.lock().unwrap().touch(file.recent_entry(&path));
+    Ok(file)
+}
+
+#[tauri::command]
+pub fn save_session(
+    state: State<AppState>,
+    path: String,
+    name: String,
+    circuits: Vec<Circuit>,
+    scenarios: Vec<Scenario>,
+) -> Result<SessionFile, String> {
+    if files::FileKind::from_path(&path)? != files::FileKind::Session {
+        return Err(format!("Sessions are saved as .{} files", files::SESSION_EXTENSION));
+    }
+    let file = SessionFile::new(name, circuits, scenarios)?;
+    files::save(&path, &file.to_json()?)?;
+    state.recent_files.lock().unwrap().touch(file.recent_entry(&path));
+    Ok(file)
+}
+
+#[tauri::command]
+pub fn get_recent_files(state: State<AppState>) -> Vec<RecentFile> {
+    state.recent_files.lock().unwrap().list()
+}
+
+#[tauri::command]
+pub fn clear_recent_files(state: State<AppState>) {
+    state.recent_files.lock().unwrap().clear();
+}
+
+// Heavy replayable commands run in a governed worker process; the frontend
+// polls get_job/list_jobs for live resource usage
+#[tauri::command]
+pub fn submit_job(
+    state: State<AppState>,
+    command: String,
+    args: serde_json::Value,
+    limits: Option<ResourceLimits>,
+) -> Result<u64, String> {
+    state
+        .governor
+        .lock()
+        .unwrap()
+        .submit(&command, args, limits.unwrap_or_default())
+}
+
+#[tauri::command]
+pub fn get_job(state: State<AppState>, id: u64) -> Result<JobStatus, String> {
+    state
+        .governor
+        .lock()
+        .unwrap()
+        .status(id)
+        .ok_or_else(|| format!("Unknown job {}", id))
+}
+
+#[tauri::command]
+pub fn list_jobs(state: State<AppState>) -> Vec<JobStatus> {
+    state.governor.lock().unwrap().list()
+}
+
+#[tauri::command]
+pub fn cancel_job(state: State<AppState>, id: u64) -> Result<(), String> {
+    state.governor.lock().unwrap().cancel(id)
+}
+
+// Keystore: keys never cross to the frontend, only names and fingerprints
+#[tauri::command]
+pub async fn unlock_keystore(state: State<'_, AppState>, passphrase: String) -> Result<(), String> {
+    state.keystore.lock().unwrap().unlock(&passphrase)
+}
+
+#[tauri::command]
+pub fn lock_keystore(state: State<AppState>) {
+    state.keystore.lock().unwrap().lock();
+}
+
+#[tauri::command]
+pub fn is_keystore_unlocked(state: State<AppState>) -> bool {
+    state.keystore.lock().unwrap().is_unlocked()
+}
+
+#[tauri::command]
+pub fn list_keys(state: State<AppState>) -> Result<Vec<String>, String> {
+    state.keystore.lock().unwrap().list()
+}
+
+#[tauri::command]
+pub fn generate_key(state: State<AppState>, name: String) -> Result<String, String> {
+    let keystore = state.keystore.lock().unwrap();
+    keystore.generate(&name)?;
+    keystore.fingerprint(&name)
+}
+
+#[tauri::command]
+pub fn get_key_fingerprint(state: State<AppState>, name: String) -> Result<String, String> {
+    state.keystore.lock().unwrap().fingerprint(&name)
+}
+
+#[tauri::command]
+pub fn delete_key(state: State<AppState>, name: String) -> Result<(), String> {
+    state.keystore.lock().unwrap().delete(&name)
+}
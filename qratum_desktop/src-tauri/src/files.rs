@@ -0,0 +1,552 @@
+// Circuit (.qcirc) and session (.qsess) files
+// Versioned JSON documents carrying provenance (seed, app version, content
+// hash). Imports are validated against the bundled JSON schemas, then checked
+// for version, seed, gate arity and hash integrity before deserializing.
+
+use crate::commands::GateRequest;
+use crate::scenario::{Scenario, SCENARIO_SEED};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::path::Path;
+
+pub const CIRCUIT_EXTENSION: &str = "qcirc";
+pub const SESSION_EXTENSION: &str = "qsess";
+pub const FILE_FORMAT_VERSION: u32 = 1;
+
+// Entries kept in the recent-files list
+pub const MAX_RECENT_FILES: usize = 10;
+
+const CIRCUIT_SCHEMA: &str = include_str!("../schemas/qcirc.schema.json");
+const SESSION_SCHEMA: &str = include_str!("../schemas/qsess.schema.json");
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Provenance {
+    pub seed: u32,
+    pub app_version: String,
+    // FNV-1a 64 over the canonical JSON of the document, this field excluded
+    pub content_hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Circuit {
+    pub name: String,
+    pub gates: Vec<GateRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CircuitFile {
+    pub format: String,
+    pub format_version: u32,
+    pub provenance: Provenance,
+    pub circuit: Circuit,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionFile {
+    pub format: String,
+    pub format_version: u32,
+    pub provenance: Provenance,
+    pub name: String,
+    // Each entry is a complete .qcirc document and can be extracted as-is
+    pub circuits: Vec<CircuitFile>,
+    pub scenarios: Vec<Scenario>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "file", rename_all = "snake_case")]
+pub enum OpenedFile {
+    Circuit(CircuitFile),
+    Session(SessionFile),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileKind {
+    Circuit,
+    Session,
+}
+
+impl FileKind {
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        match Path::new(path).extension().and_then(|e| e.to_str()) {
+            Some(CIRCUIT_EXTENSION) => Ok(FileKind::Circuit),
+            Some(SESSION_EXTENSION) => Ok(FileKind::Session),
+            _ => Err(format!(
+                "Unsupported file type for {} (expected .{} or .{})",
+                path, CIRCUIT_EXTENSION, SESSION_EXTENSION
+            )),
+        }
+    }
+}
+
+fn provenance() -> Provenance {
+    Provenance {
+        seed: SCENARIO_SEED,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        content_hash: String::new(),
+    }
+}
+
+impl CircuitFile {
+    pub fn new(circuit: Circuit) -> Result<Self, String> {
+        validate_gates(&circuit)?;
+        let mut file = CircuitFile {
+            format: CIRCUIT_EXTENSION.to_string(),
+            format_version: FILE_FORMAT_VERSION,
+            provenance: provenance(),
+            circuit,
+        };
+        file.provenance.content_hash = hash_of(&file)?;
+        Ok(file)
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to encode circuit: {}", e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let file: CircuitFile = import(json, CIRCUIT_SCHEMA, "circuit")?;
+        validate_gates(&file.circuit)?;
+        Ok(file)
+    }
+}
+
+impl SessionFile {
+    pub fn new(
+        name: String,
+        circuits: Vec<Circuit>,
+        scenarios: Vec<Scenario>,
+    ) -> Result<Self, String> {
+        let circuits = circuits
+            .into_iter()
+            .map(CircuitFile::new)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut file = SessionFile {
+            format: SESSION_EXTENSION.to_string(),
+            format_version: FILE_FORMAT_VERSION,
+            provenance: provenance(),
+            name,
+            circuits,
+            scenarios,
+        };
+        validate_scenarios(&file.scenarios)?;
+        file.provenance.content_hash = hash_of(&file)?;
+        Ok(file)
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to encode session: {}", e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let document = parse(json)?;
+        // Embedded circuits are full .qcirc documents with their own hashes
+        if let Some(circuits) = document.get("circuits").and_then(Value::as_array) {
+            for (index, circuit) in circuits.iter().enumerate() {
+                CircuitFile::from_json(&circuit.to_string())
+                    .map_err(|e| format!("Session circuit {}: {}", index, e))?;
+            }
+        }
+        let file: SessionFile = import(json, SESSION_SCHEMA, "session")?;
+        validate_scenarios(&file.scenarios)?;
+        Ok(file)
+    }
+}
+
+pub fn save(path: &str, json: &str) -> Result<(), String> {
+    std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path, e))
+}
+
+pub fn open(path: &str) -> Result<OpenedFile, String> {
+    let kind = FileKind::from_path(path)?;
+    let json =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    match kind {
+        FileKind::Circuit => CircuitFile::from_json(&json).map(OpenedFile::Circuit),
+        FileKind::Session => SessionFile::from_json(&json).map(OpenedFile::Session),
+    }
+}
+
+fn parse(json: &str) -> Result<Value, String> {
+    serde_json::from_str(json).map_err(|e| format!("Invalid JSON: {}", e))
+}
+
+// Shared import path: schema, format version, seed, then content hash
+fn import<T: DeserializeOwned>(json: &str, schema: &str, what: &str) -> Result<T, String> {
+    let document = parse(json)?;
+    let schema: Value = serde_json::from_str(schema).expect("bundled schema is valid JSON");
+    let mut errors = Vec::new();
+    validate_schema(&schema, &document, "", &mut errors);
+    if !errors.is_empty() {
+        return Err(format!("Invalid {} file: {}", what, errors.join("; ")));
+    }
+
+    let version = document["format_version"].as_u64().unwrap_or(0);
+    if version != FILE_FORMAT_VERSION as u64 {
+        return Err(format!("Unsupported {} format version {}", what, version));
+    }
+    let seed = document["provenance"]["seed"].as_u64().unwrap_or(0);
+    if seed != SCENARIO_SEED as u64 {
+        return Err(format!(
+            "Unsupported {} file: recorded with seed {}, this build runs seed {}",
+            what, seed, SCENARIO_SEED
+        ));
+    }
+    let recorded = document["provenance"]["content_hash"]
+        .as_str()
+        .unwrap_or("");
+    let actual = content_hash(&document);
+    if recorded != actual {
+        return Err(format!(
+            "Corrupt {} file: content hash mismatch (recorded {}, computed {})",
+            what, recorded, actual
+        ));
+    }
+
+    serde_json::from_value(document).map_err(|e| format!("Invalid {} file: {}", what, e))
+}
+
+fn validate_gates(circuit: &Circuit) -> Result<(), String> {
+    for (index, gate) in circuit.gates.iter().enumerate() {
+        let arity = match gate.gate.as_str() {
+            "CNOT" | "CZ" | "SWAP" => 2,
+            "TOFFOLI" => 3,
+            _ => 1,
+        };
+        if gate.qubits.len() != arity {
+            return Err(format!(
+                "Gate {} ({}) takes {} qubit(s), got {}",
+                index,
+                gate.gate,
+                arity,
+                gate.qubits.len()
+            ));
+        }
+        let mut distinct = gate.qubits.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        if distinct.len() != gate.qubits.len() {
+            return Err(format!("Gate {} ({}) repeats a qubit", index, gate.gate));
+        }
+    }
+    Ok(())
+}
+
+fn validate_scenarios(scenarios: &[Scenario]) -> Result<(), String> {
+    for (index, scenario) in scenarios.iter().enumerate() {
+        // Round-trip through the scenario loader for its version/seed checks
+        Scenario::from_json(&scenario.to_json()?)
+            .map_err(|e| format!("Session scenario {}: {}", index, e))?;
+    }
+    Ok(())
+}
+
+// Hash the document as it will be read back, so f32 fields hash identically
+// before writing and after parsing
+fn hash_of<T: Serialize>(file: &T) -> Result<String, String> {
+    let json = serde_json::to_string(file).map_err(|e| format!("Failed to encode file: {}", e))?;
+    Ok(content_hash(&parse(&json)?))
+}
+
+// serde_json maps are sorted, so serializing a Value is canonical
+pub fn content_hash(document: &Value) -> String {
+    let mut document = document.clone();
+    if let Some(provenance) = document
+        .get_mut("provenance")
+        .and_then(Value::as_object_mut)
+    {
+        provenance.remove("content_hash");
+    }
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in document.to_string().bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+// Minimal JSON Schema (draft-07 subset) validator for the bundled schemas:
+// type, const, enum, required, properties, additionalProperties, items,
+// minItems/maxItems, minLength/maxLength, minimum/maximum
+pub fn validate_schema(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    let at = if path.is_empty() { "/" } else { path };
+
+    if let Some(types) = schema.get("type") {
+        let allowed: Vec<&str> = match types {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.iter().any(|t| type_matches(t, value)) {
+            errors.push(format!("{}: expected {}", at, allowed.join(" or ")));
+            return;
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if value != expected {
+            errors.push(format!("{}: expected {}", at, expected));
+        }
+    }
+    if let Some(Value::Array(options)) = schema.get("enum") {
+        if !options.contains(value) {
+            errors.push(format!(
+                "{}: {} is not one of {}",
+                at,
+                value,
+                Value::Array(options.clone())
+            ));
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for field in required.iter().filter_map(Value::as_str) {
+                    if !map.contains_key(field) {
+                        errors.push(format!("{}: missing required field '{}'", at, field));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            let closed = schema.get("additionalProperties") == Some(&Value::Bool(false));
+            for (key, child) in map {
+                match properties.and_then(|p| p.get(key)) {
+                    Some(child_schema) => {
+                        validate_schema(child_schema, child, &format!("{}/{}", path, key), errors)
+                    }
+                    None if closed => errors.push(format!("{}: unexpected field '{}'", at, key)),
+                    None => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            let len = items.len() as u64;
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                if len < min {
+                    errors.push(format!("{}: expected at least {} items", at, min));
+                }
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+                if len > max {
+                    errors.push(format!("{}: expected at most {} items", at, max));
+                }
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_schema(item_schema, item, &format!("{}/{}", path, index), errors);
+                }
+            }
+        }
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+                if len < min {
+                    errors.push(format!("{}: shorter than {} characters", at, min));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+                if len > max {
+                    errors.push(format!("{}: longer than {} characters", at, max));
+                }
+            }
+        }
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or_default();
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                if n < min {
+                    errors.push(format!("{}: below minimum {}", at, min));
+                }
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                if n > max {
+                    errors.push(format!("{}: above maximum {}", at, max));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => value.is_u64() || value.is_i64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentFile {
+    pub path: String,
+    pub kind: FileKind,
+    pub name: String,
+    pub content_hash: String,
+}
+
+// Most-recently-used list held in AppState
+#[derive(Default)]
+pub struct RecentFiles {
+    entries: VecDeque<RecentFile>,
+}
+
+impl RecentFiles {
+    pub fn touch(&mut self, entry: RecentFile) {
+        self.entries.retain(|e| e.path != entry.path);
+        self.entries.push_front(entry);
+        self.entries.truncate(MAX_RECENT_FILES);
+    }
+
+    pub fn list(&self) -> Vec<RecentFile> {
+        self.entries.iter().cloned().collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl RecentFile {
+    fn new(path: &str, kind: FileKind, name: &str, provenance: &Provenance) -> Self {
+        RecentFile {
+            path: path.to_string(),
+            kind,
+            name: name.to_string(),
+            content_hash: provenance.content_hash.clone(),
+        }
+    }
+}
+
+impl CircuitFile {
+    pub fn recent_entry(&self, path: &str) -> RecentFile {
+        RecentFile::new(
+            path,
+            FileKind::Circuit,
+            &self.circuit.name,
+            &self.provenance,
+        )
+    }
+}
+
+impl SessionFile {
+    pub fn recent_entry(&self, path: &str) -> RecentFile {
+        RecentFile::new(path, FileKind::Session, &self.name, &self.provenance)
+    }
+}
+
+impl OpenedFile {
+    pub fn recent_entry(&self, path: &str) -> RecentFile {
+        match self {
+            OpenedFile::Circuit(file) => file.recent_entry(path),
+            OpenedFile::Session(file) => file.recent_entry(path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gate(name: &str, qubits: &[usize], theta: Option<f32>) -> GateRequest {
+        GateRequest {
+            gate: name.to_string(),
+            qubits: qubits.to_vec(),
+            theta,
+        }
+    }
+
+    fn bell() -> Circuit {
+        Circuit {
+            name: "bell".to_string(),
+            gates: vec![
+                gate("H", &[0], None),
+                gate("CNOT", &[0, 1], None),
+                gate("RZ", &[1], Some(0.1)),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_circuit_round_trip() {
+        let file = CircuitFile::new(bell()).unwrap();
+        assert_eq!(file.provenance.seed, SCENARIO_SEED);
+        assert_eq!(file.provenance.content_hash.len(), 16);
+
+        let loaded = CircuitFile::from_json(&file.to_json().unwrap()).unwrap();
+        assert_eq!(loaded, file);
+
+        // Any edit after saving breaks the content hash
+        let tampered = file.to_json().unwrap().replace("\"bell\"", "\"bell2\"");
+        assert!(CircuitFile::from_json(&tampered)
+            .unwrap_err()
+            .contains("hash mismatch"));
+
+        // Arity is checked on construction and on import
+        let mut bad = bell();
+        bad.gates.push(gate("CNOT", &[2], None));
+        assert!(CircuitFile::new(bad).is_err());
+    }
+
+    #[test]
+    fn test_schema_rejects_malformed_circuits() {
+        let mut json: Value =
+            serde_json::from_str(&CircuitFile::new(bell()).unwrap().to_json().unwrap()).unwrap();
+        json["circuit"]["gates"][0]["gate"] = Value::from("FOO");
+        json["circuit"]["gates"][1]["qubits"][1] = Value::from(12);
+        json["extra"] = Value::Bool(true);
+        let err = CircuitFile::from_json(&json.to_string()).unwrap_err();
+        assert!(err.contains("/circuit/gates/0/gate"));
+        assert!(err.contains("/circuit/gates/1/qubits/1: above maximum"));
+        assert!(err.contains("unexpected field 'extra'"));
+
+        json = serde_json::from_str(&CircuitFile::new(bell()).unwrap().to_json().unwrap()).unwrap();
+        json["format_version"] = Value::from(2);
+        json["provenance"]["content_hash"] = Value::from(content_hash(&json));
+        assert!(CircuitFile::from_json(&json.to_string())
+            .unwrap_err()
+            .contains("format version 2"));
+    }
+
+    #[test]
+    fn test_session_bundle_and_recent_files() {
+        let session =
+            SessionFile::new("demo".to_string(), vec![bell()], vec![Scenario::new()]).unwrap();
+        let dir = std::env::temp_dir().join(format!("qratum-files-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("demo.qsess").to_string_lossy().into_owned();
+        save(&path, &session.to_json().unwrap()).unwrap();
+
+        let opened = open(&path).unwrap();
+        assert_eq!(opened, OpenedFile::Session(session.clone()));
+        assert!(open(&dir.join("demo.txt").to_string_lossy()).is_err());
+
+        // A tampered embedded circuit fails even though the bundle is re-hashed
+        let mut json: Value = serde_json::from_str(&session.to_json().unwrap()).unwrap();
+        json["circuits"][0]["circuit"]["name"] = Value::from("other");
+        json["provenance"]["content_hash"] = Value::from(content_hash(&json));
+        assert!(SessionFile::from_json(&json.to_string())
+            .unwrap_err()
+            .starts_with("Session circuit 0"));
+
+        let mut recent = RecentFiles::default();
+        for i in 0..(MAX_RECENT_FILES + 2) {
+            let mut entry = opened.recent_entry(&format!("{}.qsess", i));
+            entry.name = i.to_string();
+            recent.touch(entry);
+        }
+        recent.touch(opened.recent_entry("3.qsess"));
+        let list = recent.list();
+        assert_eq!(list.len(), MAX_RECENT_FILES);
+        assert_eq!(list[0].path, "3.qsess");
+        assert_eq!(list[0].name, "demo");
+        assert_eq!(list[1].path, "11.qsess");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,278 @@
+// Desktop keystore
+// Named keys are stored at rest as qratum keywrap blobs (RFC 3394 AES-KW,
+// versioned format), one .qkey file per key. The KEK is derived from the
+// user's passphrase with Argon2id over a random per-keystore salt and only
+// lives in memory while the keystore is unlocked.
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use qratum::keywrap::{WrapError, WrappedKey};
+use sha3::{Digest, Sha3_256};
+use std::path::{Path, PathBuf};
+
+pub const KEY_EXTENSION: &str = "qkey";
+
+// Bytes of key material created by `generate`
+pub const GENERATED_KEY_LEN: usize = 32;
+
+const SALT_FILE: &str = "keystore.salt";
+const SALT_LEN: usize = 16;
+
+// Wrapped random value that only the right passphrase unwraps
+const VERIFIER_FILE: &str = "keystore.verifier";
+
+// Argon2id cost: 64 MiB, 3 passes (RFC 9106 second recommended option)
+const ARGON2_MEMORY_KIB: u32 = 64 * 1024;
+const ARGON2_PASSES: u32 = 3;
+const ARGON2_LANES: u32 = 4;
+
+pub struct Keystore {
+    dir: PathBuf,
+    kek: Option<[u8; 32]>,
+}
+
+impl Default for Keystore {
+    fn default() -> Self {
+        Keystore::new(default_dir())
+    }
+}
+
+impl Drop for Keystore {
+    fn drop(&mut self) {
+        self.lock();
+    }
+}
+
+// QRATUM_KEYSTORE_DIR, else ~/.qratum/keystore
+fn default_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("QRATUM_KEYSTORE_DIR") {
+        return PathBuf::from(dir);
+    }
+    let home = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .unwrap_or_default();
+    Path::new(&home).join(".qratum").join("keystore")
+}
+
+fn wrap_error(what: &str, e: WrapError) -> String {
+    format!("{}: {:?}", what, e)
+}
+
+impl Keystore {
+    pub fn new(dir: PathBuf) -> Self {
+        Keystore { dir, kek: None }
+    }
+
+    pub fn is_unlocked(&self) -> bool {
+        self.kek.is_some()
+    }
+
+    // Derive the KEK from the passphrase. The first unlock initializes the
+    // salt and verifier; later unlocks must unwrap the verifier.
+    pub fn unlock(&mut self, passphrase: &str) -> Result<(), String> {
+        self.lock();
+        std::fs::create_dir_all(&self.dir)
+            .map_err(|e| format!("Failed to create {}: {}", self.dir.display(), e))?;
+        let salt_path = self.dir.join(SALT_FILE);
+        let verifier_path = self.dir.join(VERIFIER_FILE);
+        let initialized = salt_path.exists();
+
+        let salt = if initialized {
+            read(&salt_path)?
+        } else {
+            let mut salt = vec![0u8; SALT_LEN];
+            random(&mut salt)?;
+            salt
+        };
+        let mut kek = derive_kek(passphrase, &salt)?;
+
+        if initialized {
+            let verifier = WrappedKey::from_bytes(&read(&verifier_path)?)
+                .map_err(|e| wrap_error("Corrupt keystore verifier", e))?;
+            if let Err(e) = verifier.unwrap(&kek, None) {
+                kek.iter_mut().for_each(|b| *b = 0);
+                return Err(match e {
+                    WrapError::IntegrityCheckFailed => "Wrong keystore passphrase".to_string(),
+                    e => wrap_error("Corrupt keystore verifier", e),
+                });
+            }
+        } else {
+            let mut check = [0u8; 32];
+            random(&mut check)?;
+            let verifier = WrappedKey::wrap(&kek, &check);
+            check.iter_mut().for_each(|b| *b = 0);
+            let verifier = verifier.map_err(|e| wrap_error("Failed to create verifier", e))?;
+            write(&verifier_path, &verifier.to_bytes())?;
+            write(&salt_path, &salt)?;
+        }
+
+        self.kek = Some(kek);
+        Ok(())
+    }
+
+    pub fn lock(&mut self) {
+        if let Some(kek) = self.kek.as_mut() {
+            kek.iter_mut().for_each(|b| *b = 0);
+        }
+        self.kek = None;
+    }
+
+    fn kek(&self) -> Result<&[u8; 32], String> {
+        self.kek.as_ref().ok_or_else(|| "Keystore is locked".to_string())
+    }
+
+    fn key_path(&self, name: &str) -> Result<PathBuf, String> {
+        let valid = !name.is_empty()
+            && name.len() <= 64
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(format!(
+                "Invalid key name '{}' (1-64 of A-Z, a-z, 0-9, '-', '_')",
+                name
+            ));
+        }
+        Ok(self.dir.join(format!("{}.{}", name, KEY_EXTENSION)))
+    }
+
+    // Key material must be a multiple of 8 bytes, at least 16 (AES-KW)
+    pub fn store(&self, name: &str, key_data: &[u8]) -> Result<(), String> {
+        let path = self.key_path(name)?;
+        let wrapped = WrappedKey::wrap(self.kek()?, key_data)
+            .map_err(|e| wrap_error(&format!("Failed to wrap key '{}'", name), e))?;
+        write(&path, &wrapped.to_bytes())
+    }
+
+    pub fn load(&self, name: &str) -> Result<Vec<u8>, String> {
+        let path = self.key_path(name)?;
+        let kek = self.kek()?;
+        if !path.exists() {
+            return Err(format!("No key named '{}'", name));
+        }
+        WrappedKey::from_bytes(&read(&path)?)
+            .and_then(|wrapped| wrapped.unwrap(kek, None))
+            .map_err(|e| wrap_error(&format!("Failed to unwrap key '{}'", name), e))
+    }
+
+    pub fn generate(&self, name: &str) -> Result<(), String> {
+        if self.key_path(name)?.exists() {
+            return Err(format!("Key '{}' already exists", name));
+        }
+        let mut key = [0u8; GENERATED_KEY_LEN];
+        random(&mut key)?;
+        let stored = self.store(name, &key);
+        key.iter_mut().for_each(|b| *b = 0);
+        stored
+    }
+
+    // SHA3-256 of the key material, hex; identifies a key without exposing it
+    pub fn fingerprint(&self, name: &str) -> Result<String, String> {
+        let mut key = self.load(name)?;
+        let digest = Sha3_256::digest(&key);
+        key.iter_mut().for_each(|b| *b = 0);
+        Ok(digest.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
+    pub fn delete(&self, name: &str) -> Result<(), String> {
+        let path = self.key_path(name)?;
+        std::fs::remove_file(&path).map_err(|e| format!("Failed to delete key '{}': {}", name, e))
+    }
+
+    // Names of stored keys, sorted; listing does not need the KEK
+    pub fn list(&self) -> Result<Vec<String>, String> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(format!("Failed to read {}: {}", self.dir.display(), e)),
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|e| e.to_str()) == Some(KEY_EXTENSION))
+            .filter_map(|path| path.file_stem().and_then(|s| s.to_str()).map(str::to_string))
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+}
+
+fn derive_kek(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let params = Params::new(ARGON2_MEMORY_KIB, ARGON2_PASSES, ARGON2_LANES, Some(32))
+        .map_err(|e| format!("Invalid Argon2 parameters: {}", e))?;
+    let mut kek = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut kek)
+        .map_err(|e| format!("Failed to derive keystore KEK: {}", e))?;
+    Ok(kek)
+}
+
+fn random(buf: &mut [u8]) -> Result<(), String> {
+    getrandom::getrandom(buf).map_err(|e| format!("OS randomness unavailable: {}", e))
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    std::fs::write(path, bytes).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keystore(tag: &str) -> Keystore {
+        let dir = std::env::temp_dir().join(format!("qratum-keystore-{}-{}", tag, std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        Keystore::new(dir)
+    }
+
+    #[test]
+    fn test_keys_are_wrapped_at_rest() {
+        let mut store = keystore("wrap");
+        assert_eq!(store.generate("escrow").unwrap_err(), "Keystore is locked");
+        store.unlock("correct horse").unwrap();
+
+        let key = [0x42u8; 32];
+        store.store("snapshot", &key).unwrap();
+        store.generate("escrow").unwrap();
+        assert!(store.generate("escrow").is_err());
+        assert_eq!(store.load("snapshot").unwrap(), key);
+        assert_eq!(store.list().unwrap(), vec!["escrow", "snapshot"]);
+        assert_eq!(store.fingerprint("snapshot").unwrap().len(), 64);
+
+        // On disk the key is a versioned AES-KW blob, never the raw bytes
+        let blob = std::fs::read(store.dir.join("snapshot.qkey")).unwrap();
+        let wrapped = WrappedKey::from_bytes(&blob).unwrap();
+        assert_eq!(wrapped.version, qratum::keywrap::WRAP_FORMAT_VERSION);
+        assert!(!blob.windows(key.len()).any(|w| w == key));
+
+        assert!(store.key_path("../escape").is_err());
+        assert!(store.store("short", &[1u8; 8]).is_err());
+        store.delete("escrow").unwrap();
+        assert_eq!(store.load("escrow").unwrap_err(), "No key named 'escrow'");
+
+        std::fs::remove_dir_all(&store.dir).ok();
+    }
+
+    #[test]
+    fn test_unlock_checks_passphrase() {
+        let mut store = keystore("unlock");
+        store.unlock("first").unwrap();
+        store.store("session", &[7u8; 16]).unwrap();
+        store.lock();
+        assert!(!store.is_unlocked());
+        assert_eq!(store.load("session").unwrap_err(), "Keystore is locked");
+
+        assert_eq!(store.unlock("second").unwrap_err(), "Wrong keystore passphrase");
+        assert!(!store.is_unlocked());
+
+        // A fresh handle on the same directory reuses the salt and verifier
+        let mut reopened = Keystore::new(store.dir.clone());
+        reopened.unlock("first").unwrap();
+        assert_eq!(reopened.load("session").unwrap(), vec![7u8; 16]);
+
+        std::fs::remove_dir_all(&store.dir).ok();
+    }
+}
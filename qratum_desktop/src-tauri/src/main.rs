@@ -6,16 +6,33 @@ use tauri::{CustomMenuItem, Manager, SystemTray, SystemTrayMenu};
 mod backend;
 mod codegen;
 mod commands;
+mod files;
+mod keystore;
 mod qr_os_supreme;
+mod scenario;
 mod tray;
 
 // Lightweight in-memory database (no SQLite)
 #[derive(Default)]
 pub struct AppState {
     logs: Arc<Mutex<Vec<backend::LogEntry>>>,
+    recorder: Arc<Mutex<scenario::ScenarioRecorder>>,
+    recent_files: Arc<Mutex<files::RecentFiles>>,
+    governor: Arc<Mutex<backend::governor::ResourceGovernor>>,
+    keystore: Arc<Mutex<keystore::Keystore>>,
 }
 
 fn main() {
+    // Headless scenario replay: qratum-desktop --replay <scenario.json>
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(pos) = args.iter().position(|a| a == "--replay") {
+        std::process::exit(run_headless_replay(args.get(pos + 1)));
+    }
+    // Governed job worker spawned by the resource governor
+    if args.iter().any(|a| a == "--job") {
+        std::process::exit(backend::governor::run_job_worker());
+    }
+
     // System tray setup
     let tray_menu = SystemTrayMenu::new()
         .add_item(CustomMenuItem::new("show".to_string(), "Show"))
@@ -54,6 +71,30 @@ fn main() {
             commands::run_dcge_benchmark,
             commands::get_binary_metrics,
             commands::get_failure_modes,
+            // Scenario recording and replay
+            commands::start_recording,
+            commands::stop_recording,
+            commands::run_scenario_command,
+            commands::replay_scenario,
+            // Circuit and session files
+            commands::open_file,
+            commands::save_circuit,
+            commands::save_session,
+            commands::get_recent_files,
+            commands::clear_recent_files,
+            // Resource-governed jobs
+            commands::submit_job,
+            commands::get_job,
+            commands::list_jobs,
+            commands::cancel_job,
+            // Keystore
+            commands::unlock_keystore,
+            commands::lock_keystore,
+            commands::is_keystore_unlocked,
+            commands::list_keys,
+            commands::generate_key,
+            commands::get_key_fingerprint,
+            commands::delete_key,
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application");
@@ -65,3 +106,35 @@ fn main() {
         _ => {}
     });
 }
+
+fn run_headless_replay(path: Option<&String>) -> i32 {
+    let Some(path) = path else {
+        eprintln!("usage: qratum-desktop --replay <scenario.json>");
+        return 2;
+    };
+    match scenario::replay_file(path) {
+        Ok(report) if report.passed() => {
+            println!("Scenario {}: {} steps replayed, all identical", path, report.steps);
+            0
+        }
+        Ok(report) => {
+            for mismatch in &report.mismatches {
+                eprintln!(
+                    "step {} ({}): expected {}, got {}",
+                    mismatch.index, mismatch.command, mismatch.expected, mismatch.actual
+                );
+            }
+            eprintln!(
+                "Scenario {}: {} of {} steps diverged",
+                path,
+                report.mismatches.len(),
+                report.steps
+            );
+            1
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            2
+        }
+    }
+}
@@ -0,0 +1,285 @@
+// Scenario recorder/replayer
+// Captures UI-driven command sequences into a deterministic scenario file and
+// re-executes them headlessly, asserting identical results.
+
+use crate::commands;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const SCENARIO_FORMAT_VERSION: u32 = 1;
+
+// Seed every OS Supreme pod starts from; replay is only meaningful under it
+pub const SCENARIO_SEED: u32 = 42;
+
+// Wall-clock fields stripped from results before recording or comparing
+const VOLATILE_FIELDS: &[&str] = &["timestamp_ns", "generation_time_ms"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioStep {
+    pub command: String,
+    pub args: Value,
+    pub result: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scenario {
+    pub format_version: u32,
+    pub seed: u32,
+    pub app_version: String,
+    pub steps: Vec<ScenarioStep>,
+}
+
+impl Scenario {
+    pub fn new() -> Self {
+        Scenario {
+            format_version: SCENARIO_FORMAT_VERSION,
+            seed: SCENARIO_SEED,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to encode scenario: {}", e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut scenario: Scenario =
+            serde_json::from_str(json).map_err(|e| format!("Invalid scenario file: {}", e))?;
+        if scenario.format_version != SCENARIO_FORMAT_VERSION {
+            return Err(format!(
+                "Unsupported scenario format version {}",
+                scenario.format_version
+            ));
+        }
+        if scenario.seed != SCENARIO_SEED {
+            return Err(format!(
+                "Scenario recorded with seed {}, this build runs seed {}",
+                scenario.seed, SCENARIO_SEED
+            ));
+        }
+        for step in &mut scenario.steps {
+            step.result = normalize(std::mem::take(&mut step.result));
+        }
+        Ok(scenario)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        std::fs::write(path, self.to_json()?).map_err(|e| format!("Failed to write {}: {}", path, e))
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        Self::from_json(&json)
+    }
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Recording session held in AppState
+#[derive(Default)]
+pub struct ScenarioRecorder {
+    active: Option<Scenario>,
+}
+
+impl ScenarioRecorder {
+    pub fn start(&mut self) {
+        self.active = Some(Scenario::new());
+    }
+
+    pub fn stop(&mut self) -> Option<Scenario> {
+        self.active.take()
+    }
+
+    pub fn record(&mut self, command: &str, args: &Value, result: &Value) {
+        if let Some(scenario) = self.active.as_mut() {
+            scenario.steps.push(ScenarioStep {
+                command: command.to_string(),
+                args: args.clone(),
+                result: normalize(result.clone()),
+            });
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepMismatch {
+    pub index: usize,
+    pub command: String,
+    pub expected: Value,
+    pub actual: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayReport {
+    pub steps: usize,
+    pub mismatches: Vec<StepMismatch>,
+}
+
+impl ReplayReport {
+    pub fn passed(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+fn arg<T: DeserializeOwned>(args: &Value, name: &str) -> Result<T, String> {
+    let value = args.get(name).cloned().unwrap_or(Value::Null);
+    serde_json::from_value(value).map_err(|e| format!("Invalid argument '{}': {}", name, e))
+}
+
+fn to_value<T: Serialize>(result: T) -> Result<Value, String> {
+    serde_json::to_value(result).map_err(|e| format!("Failed to encode result: {}", e))
+}
+
+// Strip wall-clock fields and round floats to f32 (the precision of every
+// simulator output) so results compare across runs and JSON round-trips
+pub fn normalize(mut value: Value) -> Value {
+    normalize_in_place(&mut value);
+    value
+}
+
+fn normalize_in_place(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for field in VOLATILE_FIELDS {
+                map.remove(*field);
+            }
+            map.values_mut().for_each(normalize_in_place);
+        }
+        Value::Array(items) => items.iter_mut().for_each(normalize_in_place),
+        Value::Number(n) if n.is_f64() => {
+            let rounded = n.as_f64().map(|f| f as f32 as f64);
+            if let Some(number) = rounded.and_then(serde_json::Number::from_f64) {
+                *n = number;
+            }
+        }
+        _ => {}
+    }
+}
+
+// Execute a replayable command by name. Replayable commands are those whose
+// results are a pure function of their arguments and the seed.
+pub async fn dispatch(command: &str, args: &Value) -> Result<Value, String> {
+    match command {
+        "run_bell_state" => to_value(commands::run_bell_state().await?),
+        "run_quantum_teleportation" => to_value(commands::run_quantum_teleportation().await?),
+        "run_ghz_state" => to_value(commands::run_ghz_state().await?),
+        "get_quantum_state" => to_value(commands::get_quantum_state().await?),
+        "apply_quantum_gate" => to_value(commands::apply_quantum_gate(arg(args, "request")?).await?),
+        "run_ai_inference" => to_value(commands::run_ai_inference(arg(args, "input")?).await?),
+        "classify_text" => to_value(commands::classify_text(arg(args, "text")?).await?),
+        "embed_text" => to_value(commands::embed_text(arg(args, "text")?).await?),
+        "run_supremacy_test" => to_value(commands::run_supremacy_test(arg(args, "input")?).await?),
+        "generate_code" => to_value(commands::generate_code(arg(args, "intent")?).await?),
+        "validate_code" => to_value(
+            commands::validate_code(arg(args, "language")?, arg(args, "source")?).await?,
+        ),
+        "run_dcge_benchmark" => to_value(commands::run_dcge_benchmark(arg(args, "intent")?).await?),
+        _ => Err(format!("Command '{}' is not replayable", command)),
+    }
+}
+
+// Re-execute every step and compare against the recorded results
+pub async fn replay(scenario: &Scenario) -> ReplayReport {
+    let mut mismatches = Vec::new();
+    for (index, step) in scenario.steps.iter().enumerate() {
+        let actual = match dispatch(&step.command, &step.args).await {
+            Ok(value) => normalize(value),
+            Err(e) => Value::String(format!("error: {}", e)),
+        };
+        if actual != step.result {
+            mismatches.push(StepMismatch {
+                index,
+                command: step.command.clone(),
+                expected: step.result.clone(),
+                actual,
+            });
+        }
+    }
+    ReplayReport {
+        steps: scenario.steps.len(),
+        mismatches,
+    }
+}
+
+// Headless entry point: `qratum-desktop --replay <scenario.json>`
+pub fn replay_file(path: &str) -> Result<ReplayReport, String> {
+    let scenario = Scenario::load(path)?;
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .map_err(|e| format!("Failed to start runtime: {}", e))?;
+    Ok(runtime.block_on(replay(&scenario)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    fn record(recorder: &mut ScenarioRecorder, command: &str, args: Value) {
+        let result = block_on(dispatch(command, &args)).unwrap();
+        recorder.record(command, &args, &result);
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let mut recorder = ScenarioRecorder::default();
+        recorder.start();
+        record(&mut recorder, "run_bell_state", json!({}));
+        record(
+            &mut recorder,
+            "apply_quantum_gate",
+            json!({ "request": { "gate": "H", "qubits": [3], "theta": null } }),
+        );
+        record(&mut recorder, "run_supremacy_test", json!({ "input": [42, 43, 44] }));
+        record(&mut recorder, "classify_text", json!({ "text": "create a function" }));
+        let scenario = recorder.stop().unwrap();
+        assert!(recorder.stop().is_none());
+
+        // Gate history timestamps are stripped, so the file is deterministic
+        assert!(!scenario.to_json().unwrap().contains("timestamp_ns"));
+
+        let loaded = Scenario::from_json(&scenario.to_json().unwrap()).unwrap();
+        assert_eq!(loaded, scenario);
+        let report = block_on(replay(&loaded));
+        assert_eq!(report.steps, 4);
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn test_replay_detects_divergence() {
+        let mut recorder = ScenarioRecorder::default();
+        recorder.start();
+        record(&mut recorder, "run_ai_inference", json!({ "input": [1, 2, 3] }));
+        record(&mut recorder, "run_ghz_state", json!({}));
+        let mut scenario = recorder.stop().unwrap();
+
+        scenario.steps[1].result = json!({ "p000": 1.0, "p111": 0.0 });
+        scenario.steps.push(ScenarioStep {
+            command: "get_health".to_string(),
+            args: json!({}),
+            result: Value::Null,
+        });
+
+        let report = block_on(replay(&scenario));
+        let indices: Vec<usize> = report.mismatches.iter().map(|m| m.index).collect();
+        assert_eq!(indices, vec![1, 2]);
+
+        let mut json: Value = serde_json::from_str(&scenario.to_json().unwrap()).unwrap();
+        json["seed"] = json!(7);
+        assert!(Scenario::from_json(&json.to_string()).is_err());
+    }
+}
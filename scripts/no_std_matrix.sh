@@ -0,0 +1,49 @@
+#!/usr/bin/env bash
+# no_std feature-matrix verification for the Rust crates.
+#
+# Builds every crate/feature combination that claims no_std support for a
+# bare-metal target (no `std` available at all), so a stray std-only path
+# fails the build instead of silently relying on the host's std.
+#
+# Usage: scripts/no_std_matrix.sh [target]
+#   target defaults to $NO_STD_TARGET or thumbv7em-none-eabihf. The target
+#   must be installed: a host build links std and cannot catch std leaking in.
+set -euo pipefail
+
+ROOT="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+TARGET="${1:-${NO_STD_TARGET:-thumbv7em-none-eabihf}}"
+
+# crate directory | cargo feature arguments
+MATRIX=(
+    "Aethernet|--no-default-features"
+    "Aethernet|--no-default-features --features light-verify"
+    "Aethernet|--no-default-features --features runtime"
+    "qratum-rust|--no-default-features"
+    "q-substrate|--no-default-features"
+    "crypto/rng|--no-default-features --features deterministic"
+)
+
+if ! rustup target list --installed 2>/dev/null | grep -qx "$TARGET"; then
+    echo "error: target $TARGET not installed (rustup target add $TARGET)" >&2
+    echo "no_std matrix: FAILED" >&2
+    exit 1
+fi
+TARGET_ARGS=(--target "$TARGET")
+
+failed=0
+for entry in "${MATRIX[@]}"; do
+    crate="${entry%%|*}"
+    features="${entry#*|}"
+    echo "==> $crate [$features] ($TARGET)"
+    # shellcheck disable=SC2086
+    if ! (cd "$ROOT/$crate" && cargo build --lib $features "${TARGET_ARGS[@]}"); then
+        echo "FAILED: $crate [$features]" >&2
+        failed=1
+    fi
+done
+
+if [ "$failed" -ne 0 ]; then
+    echo "no_std matrix: FAILED" >&2
+    exit 1
+fi
+echo "no_std matrix: OK"
@@ -1,8 +1,15 @@
+use rustls::client::{ServerCertVerified, ServerCertVerifier, WebPkiVerifier};
+use rustls::{Certificate, ClientConfig, OwnedTrustAnchor, RootCertStore, ServerName};
+use sha2::{Digest, Sha256};
 use std::ffi::{CStr, CString};
+use std::net::TcpStream;
 use std::os::raw::c_char;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::runtime::Runtime;
-use tungstenite::connect;
+use tungstenite::client_tls_with_config;
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Connector, WebSocket};
 use url::Url;
 
 // -- 1. Internal State Structures --
@@ -12,28 +19,537 @@ struct QradleState {
     validator_zone_heatmap: [f32; 4], // Z0-Z3
     slashing_vector: f32,
     latest_zk_proof: String,
+    /// Opaque token an endpoint issues so a client can resume elsewhere
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    session_token: Option<String>,
+}
+
+/// Default aggregation window in milliseconds
+const DEFAULT_WINDOW_MS: u64 = 100;
+
+/// Reducer selectors accepted by the aggregated FFI getters
+const REDUCER_MIN: u32 = 0;
+const REDUCER_MAX: u32 = 1;
+const REDUCER_MEAN: u32 = 2;
+const REDUCER_LAST: u32 = 3;
+
+/// min/max/mean/last over one window for a single metric
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq)]
+struct Reduced {
+    min: f32,
+    max: f32,
+    mean: f32,
+    last: f32,
+}
+
+impl Reduced {
+    fn get(&self, reducer: u32) -> f32 {
+        match reducer {
+            REDUCER_MIN => self.min,
+            REDUCER_MAX => self.max,
+            REDUCER_MEAN => self.mean,
+            REDUCER_LAST => self.last,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Running reduction for a single metric
+#[derive(Clone, Copy, Default)]
+struct Accumulator {
+    min: f32,
+    max: f32,
+    sum: f64,
+    last: f32,
+}
+
+impl Accumulator {
+    fn push(&mut self, value: f32, first: bool) {
+        if first {
+            *self = Accumulator { min: value, max: value, sum: 0.0, last: value };
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum += value as f64;
+        self.last = value;
+    }
+
+    fn reduce(&self, count: u32) -> Reduced {
+        Reduced {
+            min: self.min,
+            max: self.max,
+            mean: (self.sum / count.max(1) as f64) as f32,
+            last: self.last,
+        }
+    }
+}
+
+/// Completed aggregation window exposed to the render thread
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+struct AggregatedWindow {
+    window_start_ms: u64,
+    window_ms: u64,
+    sample_count: u32,
+    last_epoch: u64,
+    slashing_vector: Reduced,
+    validator_zone_heatmap: [Reduced; 4],
+}
+
+/// Folds high-frequency state updates into fixed windows
+///
+/// The render thread reads the last completed window, so values change at
+/// most once per window regardless of the upstream update rate.
+struct Aggregator {
+    window_ms: u64,
+    window_start_ms: u64,
+    count: u32,
+    last_epoch: u64,
+    slashing: Accumulator,
+    zones: [Accumulator; 4],
+    completed: Option<AggregatedWindow>,
+}
+
+impl Aggregator {
+    fn new(window_ms: u64) -> Self {
+        Aggregator {
+            window_ms: window_ms.max(1),
+            window_start_ms: 0,
+            count: 0,
+            last_epoch: 0,
+            slashing: Accumulator::default(),
+            zones: [Accumulator::default(); 4],
+            completed: None,
+        }
+    }
+
+    /// Change the window length; the open window is discarded
+    fn set_window(&mut self, window_ms: u64) {
+        *self = Aggregator { completed: self.completed.take(), ..Aggregator::new(window_ms) };
+    }
+
+    /// Close the open window if `now_ms` is past its end
+    fn roll(&mut self, now_ms: u64) {
+        if self.count == 0 || now_ms < self.window_start_ms + self.window_ms {
+            return;
+        }
+        self.completed = Some(AggregatedWindow {
+            window_start_ms: self.window_start_ms,
+            window_ms: self.window_ms,
+            sample_count: self.count,
+            last_epoch: self.last_epoch,
+            slashing_vector: self.slashing.reduce(self.count),
+            validator_zone_heatmap: std::array::from_fn(|i| self.zones[i].reduce(self.count)),
+        });
+        self.count = 0;
+    }
+
+    fn push(&mut self, state: &QradleState, now_ms: u64) {
+        self.roll(now_ms);
+        let first = self.count == 0;
+        if first {
+            // Align windows to multiples of the window length
+            self.window_start_ms = now_ms - now_ms % self.window_ms;
+        }
+        self.slashing.push(state.slashing_vector, first);
+        for (acc, heat) in self.zones.iter_mut().zip(state.validator_zone_heatmap) {
+            acc.push(heat, first);
+        }
+        self.last_epoch = state.epoch;
+        self.count += 1;
+    }
+
+    fn completed(&mut self, now_ms: u64) -> Option<&AggregatedWindow> {
+        self.roll(now_ms);
+        self.completed.as_ref()
+    }
 }
 
 lazy_static::lazy_static! {
     static ref GLOBAL_STATE: Arc<Mutex<QradleState>> = Arc::new(Mutex::new(QradleState::default()));
+    static ref AGGREGATOR: Mutex<Aggregator> = Mutex::new(Aggregator::new(DEFAULT_WINDOW_MS));
+    static ref CLOCK: Instant = Instant::now();
+    static ref CONNECTION_INFO: Mutex<ConnectionInfo> = Mutex::new(ConnectionInfo::default());
+    static ref ENDPOINTS: Mutex<EndpointPool> = Mutex::new(EndpointPool::new(Vec::new()));
     static ref RUNTIME: Runtime = Runtime::new().unwrap();
 }
 
-// -- 2. Background Telemetry Loop --
-fn start_telemetry_stream(url_str: String) {
-    RUNTIME.spawn(async move {
-        let (mut socket, _) = connect(Url::parse(&url_str).unwrap()).expect("Can't connect to Aethernet");
-        
-        loop {
-            if let Ok(msg) = socket.read() {
+/// Milliseconds on the telemetry core's monotonic clock
+fn now_ms() -> u64 {
+    CLOCK.elapsed().as_millis() as u64
+}
+
+// -- 2. Transport and Background Telemetry Loop --
+
+/// SHA-256 leaf certificate fingerprints embedded at build time, e.g.
+/// `SOI_PINNED_FINGERPRINTS="ab12...,cd34..." cargo build`. When set, only
+/// wss endpoints presenting one of these certificates are accepted.
+const EMBEDDED_PINS: Option<&str> = option_env!("SOI_PINNED_FINGERPRINTS");
+
+type TelemetrySocket = WebSocket<MaybeTlsStream<TcpStream>>;
+
+/// Negotiated transport details exposed via `soi_get_connection_info`
+#[derive(serde::Serialize, Clone, Default, Debug, PartialEq)]
+struct ConnectionInfo {
+    endpoint: String,
+    secure: bool,
+    pinned: bool,
+    protocol_version: Option<String>,
+    cipher_suite: Option<String>,
+    peer_server_name: Option<String>,
+    peer_fingerprint: Option<String>,
+    error: Option<String>,
+}
+
+fn fingerprint(der: &[u8]) -> [u8; 32] {
+    Sha256::digest(der).into()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Parse a comma-separated list of hex fingerprints (`:` separators allowed)
+fn parse_pins(list: &str) -> Result<Vec<[u8; 32]>, String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let hex: String = entry.chars().filter(|c| *c != ':').collect();
+            if hex.len() != 64 || !hex.is_ascii() {
+                return Err(format!("invalid SHA-256 fingerprint: {}", entry));
+            }
+            let mut pin = [0u8; 32];
+            for (i, byte) in pin.iter_mut().enumerate() {
+                *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16)
+                    .map_err(|_| format!("invalid SHA-256 fingerprint: {}", entry))?;
+            }
+            Ok(pin)
+        })
+        .collect()
+}
+
+/// Web PKI chain validation plus a leaf fingerprint check
+struct PinnedVerifier {
+    inner: WebPkiVerifier,
+    pins: Vec<[u8; 32]>,
+}
+
+impl ServerCertVerifier for PinnedVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        intermediates: &[Certificate],
+        server_name: &ServerName,
+        scts: &mut dyn Iterator<Item = &[u8]>,
+        ocsp_response: &[u8],
+        now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        self.inner
+            .verify_server_cert(end_entity, intermediates, server_name, scts, ocsp_response, now)?;
+        if self.pins.contains(&fingerprint(&end_entity.0)) {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(rustls::Error::General("server certificate is not pinned".into()))
+        }
+    }
+}
+
+/// rustls client config trusting the Mozilla root set, pinned if `pins` is non-empty
+fn tls_config(pins: &[[u8; 32]]) -> ClientConfig {
+    let mut roots = RootCertStore::empty();
+    roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|ta| {
+        OwnedTrustAnchor::from_subject_spki_name_constraints(ta.subject, ta.spki, ta.name_constraints)
+    }));
+
+    let builder = ClientConfig::builder().with_safe_defaults();
+    if pins.is_empty() {
+        builder.with_root_certificates(roots).with_no_client_auth()
+    } else {
+        let verifier = PinnedVerifier {
+            inner: WebPkiVerifier::new(roots, None),
+            pins: pins.to_vec(),
+        };
+        builder
+            .with_custom_certificate_verifier(Arc::new(verifier))
+            .with_no_client_auth()
+    }
+}
+
+/// Open a ws:// or wss:// connection and describe the negotiated transport
+fn connect_endpoint(url_str: &str, pins: &[[u8; 32]]) -> Result<(TelemetrySocket, ConnectionInfo), String> {
+    let url = Url::parse(url_str).map_err(|e| e.to_string())?;
+    let secure = match url.scheme() {
+        "ws" => false,
+        "wss" => true,
+        other => return Err(format!("unsupported scheme: {}", other)),
+    };
+    if !secure && !pins.is_empty() {
+        return Err("certificate pinning is enabled; plain ws is not allowed".into());
+    }
+    let host = url.host_str().ok_or("endpoint has no host")?.to_string();
+    let port = url.port_or_known_default().ok_or("endpoint has no port")?;
+
+    let tcp = TcpStream::connect((host.as_str(), port)).map_err(|e| e.to_string())?;
+    let connector = if secure {
+        Connector::Rustls(Arc::new(tls_config(pins)))
+    } else {
+        Connector::Plain
+    };
+    let (socket, _) = client_tls_with_config(url_str, tcp, None, Some(connector)).map_err(|e| e.to_string())?;
+
+    let mut info = ConnectionInfo {
+        endpoint: url_str.into(),
+        secure,
+        pinned: !pins.is_empty(),
+        ..Default::default()
+    };
+    if let MaybeTlsStream::Rustls(tls) = socket.get_ref() {
+        info.protocol_version = tls.conn.protocol_version().map(|v| format!("{:?}", v));
+        info.cipher_suite = tls.conn.negotiated_cipher_suite().map(|s| format!("{:?}", s.suite()));
+        info.peer_server_name = Some(host);
+        info.peer_fingerprint = tls
+            .conn
+            .peer_certificates()
+            .and_then(|chain| chain.first())
+            .map(|leaf| to_hex(&fingerprint(&leaf.0)));
+    }
+    Ok((socket, info))
+}
+
+/// A read blocking longer than this counts as a stalled endpoint
+const STALL_TIMEOUT_MS: u64 = 5_000;
+
+/// Pause before retrying once every endpoint has failed
+const RETRY_DELAY_MS: u64 = 1_000;
+
+/// Score penalty (ms-equivalent) for a fully gapped stream
+const GAP_PENALTY_MS: f64 = 2_000.0;
+
+/// Score penalty (ms-equivalent) per consecutive failure
+const FAILURE_PENALTY_MS: f64 = 10_000.0;
+
+/// Why the active endpoint was chosen
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+enum SelectionReason {
+    Initial,
+    ConnectFailed,
+    ConnectionLost,
+    Stalled,
+}
+
+/// Passive health for one endpoint
+#[derive(serde::Serialize, Clone, Debug, Default, PartialEq)]
+struct EndpointHealth {
+    url: String,
+    /// EWMA of connect + handshake time
+    latency_ms: Option<f64>,
+    /// EWMA of the fraction of updates that skipped epochs
+    gap_rate: f64,
+    consecutive_failures: u32,
+    #[serde(skip)]
+    last_epoch: Option<u64>,
+}
+
+impl EndpointHealth {
+    /// Lower is better; unprobed endpoints score on failures alone
+    fn score(&self) -> f64 {
+        self.latency_ms.unwrap_or(0.0)
+            + GAP_PENALTY_MS * self.gap_rate
+            + FAILURE_PENALTY_MS * self.consecutive_failures as f64
+    }
+}
+
+/// Configured endpoints with health-ranked selection and session resumption
+struct EndpointPool {
+    endpoints: Vec<EndpointHealth>,
+    active: Option<usize>,
+    reason: SelectionReason,
+    failovers: u32,
+    session_token: Option<String>,
+    last_epoch: Option<u64>,
+}
+
+impl EndpointPool {
+    fn new(urls: Vec<String>) -> Self {
+        EndpointPool {
+            endpoints: urls
+                .into_iter()
+                .map(|url| EndpointHealth { url, ..Default::default() })
+                .collect(),
+            active: None,
+            reason: SelectionReason::Initial,
+            failovers: 0,
+            session_token: None,
+            last_epoch: None,
+        }
+    }
+
+    /// Best-scoring endpoint; ties keep configuration order
+    fn select(&self) -> Option<usize> {
+        (0..self.endpoints.len()).min_by(|&a, &b| {
+            self.endpoints[a].score().total_cmp(&self.endpoints[b].score())
+        })
+    }
+
+    /// Whether every endpoint failed since it last connected
+    fn all_failing(&self) -> bool {
+        self.endpoints.iter().all(|e| e.consecutive_failures > 0)
+    }
+
+    /// URL to dial, carrying the resumption token once one was issued
+    fn dial_url(&self, idx: usize) -> String {
+        let base = &self.endpoints[idx].url;
+        match (&self.session_token, Url::parse(base)) {
+            (Some(token), Ok(mut url)) => {
+                url.query_pairs_mut().append_pair("resume", token);
+                if let Some(epoch) = self.last_epoch {
+                    url.query_pairs_mut().append_pair("from_epoch", &epoch.to_string());
+                }
+                url.into()
+            }
+            _ => base.clone(),
+        }
+    }
+
+    fn record_connect(&mut self, idx: usize, latency_ms: f64, reason: SelectionReason) {
+        let endpoint = &mut self.endpoints[idx];
+        endpoint.latency_ms = Some(match endpoint.latency_ms {
+            Some(prev) => 0.7 * prev + 0.3 * latency_ms,
+            None => latency_ms,
+        });
+        endpoint.consecutive_failures = 0;
+        endpoint.last_epoch = None;
+        if self.active.is_some_and(|prev| prev != idx) {
+            self.failovers += 1;
+        }
+        self.active = Some(idx);
+        self.reason = reason;
+    }
+
+    fn record_failure(&mut self, idx: usize) {
+        self.endpoints[idx].consecutive_failures += 1;
+    }
+
+    fn record_update(&mut self, idx: usize, state: &QradleState) {
+        let endpoint = &mut self.endpoints[idx];
+        if let Some(prev) = endpoint.last_epoch {
+            let gapped = state.epoch > prev + 1;
+            endpoint.gap_rate = 0.9 * endpoint.gap_rate + if gapped { 0.1 } else { 0.0 };
+        }
+        endpoint.last_epoch = Some(state.epoch);
+        self.last_epoch = Some(state.epoch);
+        if let Some(token) = &state.session_token {
+            self.session_token = Some(token.clone());
+        }
+    }
+
+    fn status(&self) -> serde_json::Value {
+        serde_json::json!({
+            "active": self.active,
+            "active_url": self.active.map(|i| &self.endpoints[i].url),
+            "reason": self.reason,
+            "failovers": self.failovers,
+            "endpoints": self.endpoints.iter().map(|e| serde_json::json!({
+                "url": e.url,
+                "score": e.score(),
+                "latency_ms": e.latency_ms,
+                "gap_rate": e.gap_rate,
+                "consecutive_failures": e.consecutive_failures,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+fn set_read_timeout(socket: &TelemetrySocket, timeout: Duration) {
+    let tcp = match socket.get_ref() {
+        MaybeTlsStream::Plain(tcp) => tcp,
+        MaybeTlsStream::Rustls(tls) => &tls.sock,
+        _ => return,
+    };
+    let _ = tcp.set_read_timeout(Some(timeout));
+}
+
+/// Read from one connection until it fails; returns why it ended
+fn stream_from(socket: &mut TelemetrySocket, idx: usize) -> SelectionReason {
+    set_read_timeout(socket, Duration::from_millis(STALL_TIMEOUT_MS));
+    loop {
+        match socket.read() {
+            Ok(msg) => {
                 if let Ok(text) = msg.to_text() {
                     // Zero-copy parsing could be added here for optimization
                     if let Ok(new_state) = serde_json::from_str::<QradleState>(text) {
+                        ENDPOINTS.lock().unwrap().record_update(idx, &new_state);
+                        AGGREGATOR.lock().unwrap().push(&new_state, now_ms());
                         let mut lock = GLOBAL_STATE.lock().unwrap();
                         *lock = new_state;
                     }
                 }
             }
+            Err(tungstenite::Error::Io(e))
+                if matches!(e.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut) =>
+            {
+                return SelectionReason::Stalled;
+            }
+            Err(_) => return SelectionReason::ConnectionLost,
+        }
+    }
+}
+
+/// Start streaming from a comma-separated list of endpoints
+fn start_telemetry_stream(endpoints: String) {
+    let urls = endpoints
+        .split(',')
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .map(String::from)
+        .collect();
+    *ENDPOINTS.lock().unwrap() = EndpointPool::new(urls);
+
+    RUNTIME.spawn(async move {
+        let pins = match EMBEDDED_PINS.map(parse_pins).unwrap_or_else(|| Ok(Vec::new())) {
+            Ok(pins) => pins,
+            Err(e) => {
+                *CONNECTION_INFO.lock().unwrap() = ConnectionInfo { error: Some(e), ..Default::default() };
+                return;
+            }
+        };
+
+        let mut reason = SelectionReason::Initial;
+        loop {
+            let (idx, url) = {
+                let pool = ENDPOINTS.lock().unwrap();
+                match pool.select() {
+                    Some(idx) => (idx, pool.dial_url(idx)),
+                    None => return,
+                }
+            };
+
+            let started = Instant::now();
+            match connect_endpoint(&url, &pins) {
+                Ok((mut socket, info)) => {
+                    let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+                    ENDPOINTS.lock().unwrap().record_connect(idx, latency_ms, reason);
+                    *CONNECTION_INFO.lock().unwrap() = info;
+                    reason = stream_from(&mut socket, idx);
+                    ENDPOINTS.lock().unwrap().record_failure(idx);
+                }
+                Err(e) => {
+                    *CONNECTION_INFO.lock().unwrap() = ConnectionInfo {
+                        endpoint: url,
+                        error: Some(e),
+                        ..Default::default()
+                    };
+                    let mut pool = ENDPOINTS.lock().unwrap();
+                    pool.record_failure(idx);
+                    if pool.active.is_some() {
+                        reason = SelectionReason::ConnectFailed;
+                    }
+                    if pool.all_failing() {
+                        drop(pool);
+                        std::thread::sleep(Duration::from_millis(RETRY_DELAY_MS));
+                    }
+                }
+            }
         }
     });
 }
@@ -74,7 +590,43 @@ pub extern "C" fn soi_get_proof(buffer: *mut c_char, length: usize) {
     }
 }
 
-// -- 4. Additional Helper Functions --
+// -- 4. Aggregated Telemetry --
+
+/// Set the aggregation window length in milliseconds (default 100)
+#[no_mangle]
+pub extern "C" fn soi_set_aggregation_window(window_ms: u64) {
+    AGGREGATOR.lock().unwrap().set_window(window_ms);
+}
+
+/// Number of updates folded into the last completed window (0 if none yet)
+#[no_mangle]
+pub extern "C" fn soi_get_window_sample_count() -> u32 {
+    AGGREGATOR.lock().unwrap().completed(now_ms()).map_or(0, |w| w.sample_count)
+}
+
+/// Last epoch seen in the last completed window
+#[no_mangle]
+pub extern "C" fn soi_get_window_epoch() -> u64 {
+    AGGREGATOR.lock().unwrap().completed(now_ms()).map_or(0, |w| w.last_epoch)
+}
+
+/// Aggregated slashing vector; reducer 0=min, 1=max, 2=mean, 3=last
+#[no_mangle]
+pub extern "C" fn soi_get_slashing_vector_agg(reducer: u32) -> f32 {
+    AGGREGATOR.lock().unwrap().completed(now_ms()).map_or(0.0, |w| w.slashing_vector.get(reducer))
+}
+
+/// Aggregated zone heat; reducer 0=min, 1=max, 2=mean, 3=last
+#[no_mangle]
+pub extern "C" fn soi_get_zone_heat_agg(zone_idx: usize, reducer: u32) -> f32 {
+    let mut aggregator = AGGREGATOR.lock().unwrap();
+    match aggregator.completed(now_ms()) {
+        Some(w) if zone_idx < 4 => w.validator_zone_heatmap[zone_idx].get(reducer),
+        _ => 0.0,
+    }
+}
+
+// -- 5. Additional Helper Functions --
 
 /// Get the current status as a JSON string
 #[no_mangle]
@@ -91,6 +643,43 @@ pub extern "C" fn soi_get_status_json(buffer: *mut c_char, length: usize) -> i32
     }
 }
 
+/// Get the negotiated transport (TLS version, cipher, peer identity) as JSON
+///
+/// # Safety
+/// `buffer` must be valid for writes of `length` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn soi_get_connection_info(buffer: *mut c_char, length: usize) -> i32 {
+    let info = CONNECTION_INFO.lock().unwrap();
+    let json = serde_json::to_string(&*info).unwrap_or_else(|_| "{}".to_string());
+    let c_str = CString::new(json).unwrap();
+
+    let bytes = c_str.as_bytes_with_nul();
+    let copy_len = std::cmp::min(bytes.len(), length);
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer as *mut u8, copy_len);
+    copy_len as i32
+}
+
+/// Get endpoint health, the active endpoint and why it was selected as JSON
+///
+/// # Safety
+/// `buffer` must be valid for writes of `length` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn soi_get_endpoint_status(buffer: *mut c_char, length: usize) -> i32 {
+    let json = ENDPOINTS.lock().unwrap().status().to_string();
+    let c_str = CString::new(json).unwrap();
+
+    let bytes = c_str.as_bytes_with_nul();
+    let copy_len = std::cmp::min(bytes.len(), length);
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer as *mut u8, copy_len);
+    copy_len as i32
+}
+
+/// Index of the active endpoint in the configured list, -1 if none
+#[no_mangle]
+pub extern "C" fn soi_get_active_endpoint() -> i32 {
+    ENDPOINTS.lock().unwrap().active.map_or(-1, |idx| idx as i32)
+}
+
 /// Check if the telemetry system is initialized
 #[no_mangle]
 pub extern "C" fn soi_is_initialized() -> bool {
@@ -116,4 +705,137 @@ mod tests {
         assert_eq!(state.slashing_vector, 0.0);
         assert_eq!(state.latest_zk_proof, "");
     }
+
+    fn sample(epoch: u64, slashing: f32, heat0: f32) -> QradleState {
+        QradleState {
+            epoch,
+            validator_zone_heatmap: [heat0, 0.5, 0.5, 0.5],
+            slashing_vector: slashing,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_pins() {
+        let hex = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        let colons = "BA:78:16:BF:8F:01:CF:EA:41:41:40:DE:5D:AE:22:23:B0:03:61:A3:96:17:7A:9C:B4:10:FF:61:F2:00:15:AD";
+        let pins = parse_pins(&format!("{}, {},", hex, colons)).unwrap();
+        assert_eq!(pins, vec![fingerprint(b"abc"); 2]);
+        assert_eq!(to_hex(&pins[0]), hex);
+
+        assert!(parse_pins("").unwrap().is_empty());
+        assert!(parse_pins("abcd").is_err());
+        assert!(parse_pins(&hex.replace('b', "z")).is_err());
+    }
+
+    #[test]
+    fn test_connect_rejects_bad_endpoints() {
+        let pin = [fingerprint(b"abc")];
+        assert!(connect_endpoint("http://localhost:1/", &[]).unwrap_err().contains("scheme"));
+        assert!(connect_endpoint("ws://localhost:1/", &pin).unwrap_err().contains("pinning"));
+        assert!(connect_endpoint("not a url", &[]).is_err());
+    }
+
+    #[test]
+    fn test_tls_config_pinning() {
+        // Both variants build; the pinned one installs the custom verifier
+        let _ = tls_config(&[]);
+        let _ = tls_config(&[fingerprint(b"abc")]);
+
+        let info = ConnectionInfo::default();
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["secure"], false);
+        assert!(json["cipher_suite"].is_null());
+    }
+
+    fn pool() -> EndpointPool {
+        EndpointPool::new(vec!["wss://a.example/t".into(), "wss://b.example/t".into()])
+    }
+
+    #[test]
+    fn test_failover_ranks_by_health() {
+        let mut pool = pool();
+        assert_eq!(pool.select(), Some(0));
+        pool.record_connect(0, 40.0, SelectionReason::Initial);
+
+        // Active endpoint drops; the untried one now ranks first
+        pool.record_failure(0);
+        assert_eq!(pool.select(), Some(1));
+        pool.record_connect(1, 80.0, SelectionReason::ConnectionLost);
+        assert_eq!(pool.active, Some(1));
+        assert_eq!(pool.failovers, 1);
+
+        // Both healthy: lower latency wins
+        pool.endpoints[0].consecutive_failures = 0;
+        assert_eq!(pool.select(), Some(0));
+
+        // A gapped stream loses its lead
+        pool.endpoints[0].gap_rate = 0.5;
+        assert_eq!(pool.select(), Some(1));
+
+        let status = pool.status();
+        assert_eq!(status["active"], 1);
+        assert_eq!(status["reason"], "ConnectionLost");
+        assert_eq!(status["endpoints"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_gap_rate_and_resumption() {
+        let mut pool = pool();
+        pool.record_connect(0, 10.0, SelectionReason::Initial);
+        pool.record_update(0, &sample(1, 0.0, 0.0));
+        pool.record_update(0, &sample(2, 0.0, 0.0));
+        assert_eq!(pool.endpoints[0].gap_rate, 0.0);
+        pool.record_update(0, &QradleState { epoch: 5, session_token: Some("tok 1".into()), ..Default::default() });
+        assert!((pool.endpoints[0].gap_rate - 0.1).abs() < 1e-9);
+
+        assert_eq!(pool.dial_url(1), "wss://b.example/t?resume=tok+1&from_epoch=5");
+        assert!(!pool.all_failing());
+        pool.record_failure(0);
+        pool.record_failure(1);
+        assert!(pool.all_failing());
+    }
+
+    #[test]
+    fn test_window_reducers() {
+        let mut agg = Aggregator::new(100);
+        agg.push(&sample(1, 0.2, 1.0), 1000);
+        agg.push(&sample(2, 0.6, 0.0), 1030);
+        agg.push(&sample(3, 0.4, 0.5), 1090);
+        // Window still open
+        assert!(agg.completed(1099).is_none());
+
+        let w = agg.completed(1100).unwrap().clone();
+        assert_eq!(w.sample_count, 3);
+        assert_eq!(w.last_epoch, 3);
+        assert_eq!(w.window_start_ms, 1000);
+        assert_eq!(w.slashing_vector.get(REDUCER_MIN), 0.2);
+        assert_eq!(w.slashing_vector.get(REDUCER_MAX), 0.6);
+        assert!((w.slashing_vector.get(REDUCER_MEAN) - 0.4).abs() < 1e-6);
+        assert_eq!(w.slashing_vector.get(REDUCER_LAST), 0.4);
+        assert_eq!(w.validator_zone_heatmap[0].get(REDUCER_MAX), 1.0);
+        assert_eq!(w.slashing_vector.get(99), 0.0);
+    }
+
+    #[test]
+    fn test_completed_window_stable_until_next_closes() {
+        let mut agg = Aggregator::new(100);
+        agg.push(&sample(1, 0.1, 0.0), 0);
+        agg.push(&sample(2, 0.9, 0.0), 150);
+        let first = agg.completed(150).unwrap().clone();
+        assert_eq!(first.sample_count, 1);
+
+        // Updates in the open window do not change what readers see
+        agg.push(&sample(3, 0.3, 0.0), 160);
+        assert_eq!(agg.completed(199).unwrap(), &first);
+
+        let second = agg.completed(200).unwrap();
+        assert_eq!(second.window_start_ms, 100);
+        assert_eq!(second.sample_count, 2);
+        assert_eq!(second.last_epoch, 3);
+
+        // Changing the window keeps the last completed result
+        agg.set_window(50);
+        assert_eq!(agg.completed(1000).unwrap().sample_count, 2);
+    }
 }
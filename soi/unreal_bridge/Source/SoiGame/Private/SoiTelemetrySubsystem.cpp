@@ -187,3 +187,70 @@ bool USoiTelemetrySubsystem::IsConnected() const
 {
     return bIsConnected && soi_is_initialized();
 }
+
+FString USoiTelemetrySubsystem::GetConnectionInfoJSON() const
+{
+    char InfoBuffer[1024];
+    const int32 BytesWritten = soi_get_connection_info(InfoBuffer, sizeof(InfoBuffer));
+    
+    if (BytesWritten > 0)
+    {
+        return FString(UTF8_TO_TCHAR(InfoBuffer));
+    }
+    
+    return TEXT("{}");
+}
+
+void USoiTelemetrySubsystem::SetAggregationWindow(int32 WindowMs)
+{
+    soi_set_aggregation_window(static_cast<uint64>(FMath::Max(WindowMs, 1)));
+}
+
+float USoiTelemetrySubsystem::GetAggregatedSlashingVector(ESoiReducer Reducer) const
+{
+    if (!bIsConnected)
+    {
+        return 0.0f;
+    }
+    return soi_get_slashing_vector_agg(static_cast<uint32>(Reducer));
+}
+
+float USoiTelemetrySubsystem::GetAggregatedZoneHeat(int32 ZoneIndex, ESoiReducer Reducer) const
+{
+    if (!bIsConnected || ZoneIndex < 0 || ZoneIndex >= 4)
+    {
+        return 0.0f;
+    }
+    return soi_get_zone_heat_agg(static_cast<size_t>(ZoneIndex), static_cast<uint32>(Reducer));
+}
+
+int32 USoiTelemetrySubsystem::GetWindowSampleCount() const
+{
+    if (!bIsConnected)
+    {
+        return 0;
+    }
+    return static_cast<int32>(soi_get_window_sample_count());
+}
+
+int32 USoiTelemetrySubsystem::GetActiveEndpointIndex() const
+{
+    if (!bIsConnected)
+    {
+        return -1;
+    }
+    return soi_get_active_endpoint();
+}
+
+FString USoiTelemetrySubsystem::GetEndpointStatusJSON() const
+{
+    char StatusBuffer[4096];
+    const int32 BytesWritten = soi_get_endpoint_status(StatusBuffer, sizeof(StatusBuffer));
+    
+    if (BytesWritten > 0)
+    {
+        return FString(UTF8_TO_TCHAR(StatusBuffer));
+    }
+    
+    return TEXT("{}");
+}
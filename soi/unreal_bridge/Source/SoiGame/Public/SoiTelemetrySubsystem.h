@@ -17,8 +17,30 @@ extern "C" {
     int32 soi_get_status_json(char* buffer, size_t length);
     bool soi_is_initialized();
     void soi_shutdown();
+    int32 soi_get_connection_info(char* buffer, size_t length);
+    int32 soi_get_endpoint_status(char* buffer, size_t length);
+    int32 soi_get_active_endpoint();
+
+    // Windowed aggregates (reducer: 0=min, 1=max, 2=mean, 3=last)
+    void soi_set_aggregation_window(uint64 window_ms);
+    uint32 soi_get_window_sample_count();
+    uint64 soi_get_window_epoch();
+    float soi_get_slashing_vector_agg(uint32 reducer);
+    float soi_get_zone_heat_agg(size_t zone_idx, uint32 reducer);
 }
 
+/**
+ * Reducer applied over an aggregation window
+ */
+UENUM(BlueprintType)
+enum class ESoiReducer : uint8
+{
+    Min = 0,
+    Max = 1,
+    Mean = 2,
+    Last = 3
+};
+
 /**
  * State update delegate - broadcasts when telemetry state changes
  * @param Epoch - Current blockchain epoch
@@ -63,7 +85,9 @@ public:
 
     /**
      * Connect to the Aethernet telemetry endpoint
-     * @param Endpoint - WebSocket URL (e.g., "ws://localhost:8000/soi/telemetry")
+     * @param Endpoint - WebSocket URL (e.g., "wss://aethernet.example/soi/telemetry";
+     *                   plain ws:// is refused when certificate pins are embedded).
+     *                   A comma-separated list enables health-ranked failover.
      */
     UFUNCTION(BlueprintCallable, Category = "SOI|Telemetry")
     void ConnectToAethernet(FString Endpoint);
@@ -111,6 +135,58 @@ public:
     UFUNCTION(BlueprintPure, Category = "SOI|Telemetry")
     bool IsConnected() const;
 
+    /**
+     * Get negotiated transport details as JSON
+     * @return Endpoint, TLS version, cipher suite, peer name and certificate fingerprint
+     */
+    UFUNCTION(BlueprintCallable, Category = "SOI|Telemetry")
+    FString GetConnectionInfoJSON() const;
+
+    /**
+     * Get the index of the active endpoint in the configured list
+     * @return Endpoint index, or -1 if none is connected yet
+     */
+    UFUNCTION(BlueprintPure, Category = "SOI|Telemetry|Failover")
+    int32 GetActiveEndpointIndex() const;
+
+    /**
+     * Get endpoint health and the reason the active endpoint was selected
+     * @return JSON with per-endpoint score, latency, gap rate and failures
+     */
+    UFUNCTION(BlueprintCallable, Category = "SOI|Telemetry|Failover")
+    FString GetEndpointStatusJSON() const;
+
+    /**
+     * Set the Rust-side aggregation window
+     * @param WindowMs - Window length in milliseconds (default 100)
+     */
+    UFUNCTION(BlueprintCallable, Category = "SOI|Telemetry|Aggregation")
+    void SetAggregationWindow(int32 WindowMs);
+
+    /**
+     * Get the slashing vector reduced over the last completed window
+     * @param Reducer - Min, Max, Mean or Last
+     * @return Aggregated value (0.0 until the first window completes)
+     */
+    UFUNCTION(BlueprintPure, Category = "SOI|Telemetry|Aggregation")
+    float GetAggregatedSlashingVector(ESoiReducer Reducer) const;
+
+    /**
+     * Get zone heat reduced over the last completed window
+     * @param ZoneIndex - Zone to query (0=Z0, 1=Z1, 2=Z2, 3=Z3)
+     * @param Reducer - Min, Max, Mean or Last
+     * @return Aggregated value (0.0 until the first window completes)
+     */
+    UFUNCTION(BlueprintPure, Category = "SOI|Telemetry|Aggregation")
+    float GetAggregatedZoneHeat(int32 ZoneIndex, ESoiReducer Reducer) const;
+
+    /**
+     * Get the number of updates folded into the last completed window
+     * @return Sample count
+     */
+    UFUNCTION(BlueprintPure, Category = "SOI|Telemetry|Aggregation")
+    int32 GetWindowSampleCount() const;
+
     // Event delegates for Blueprint
     UPROPERTY(BlueprintAssignable, Category = "SOI|Telemetry|Events")
     FOnStateUpdate OnStateUpdated;